@@ -0,0 +1,138 @@
+//! A `nitrocop` binary with an extra in-house `Acme` department.
+//!
+//! It accepts the same arguments as `nitrocop`:
+//!
+//! ```sh
+//! cargo run --example custom_cop -- --only Acme app/
+//! ```
+//!
+//! The new cops are configured in `.rubocop.yml` like any other:
+//!
+//! ```yaml
+//! Acme:
+//!   Exclude:
+//!     - 'script/**/*'
+//!
+//! Acme/TodoNeedsTicket:
+//!   TicketPrefix: 'ACME-'
+//! ```
+//!
+//! To test cops like these with nitrocop's fixture macros, enable the
+//! `testutil` feature for the `nitrocop` dev-dependency and put fixtures
+//! under `tests/fixtures/cops/acme/<cop>/{offense,no_offense}.rb`:
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod tests {
+//!     use super::*;
+//!     nitrocop::cop_fixture_tests!(NoSleep, "cops/acme/no_sleep");
+//! }
+//! ```
+
+use std::process;
+
+use clap::Parser;
+use nitrocop::cli::Args;
+use nitrocop::cop::shared::node_type::CALL_NODE;
+use nitrocop::{Cop, CopConfig, Correction, Diagnostic, Linter, Severity, SourceFile, ruby_prism};
+
+/// Flags bare `sleep` calls, which stall request threads.
+struct NoSleep;
+
+impl Cop for NoSleep {
+    fn name(&self) -> &'static str {
+        "Acme/NoSleep"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        &["app/**/*.rb", "lib/**/*.rb"]
+    }
+
+    fn interested_node_types(&self) -> &'static [u8] {
+        &[CALL_NODE]
+    }
+
+    fn check_node(
+        &self,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
+        _parse_result: &ruby_prism::ParseResult<'_>,
+        _config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        _corrections: Option<&mut Vec<Correction>>,
+    ) {
+        let Some(call) = node.as_call_node() else {
+            return;
+        };
+        if call.receiver().is_some() || call.name().as_slice() != b"sleep" {
+            return;
+        }
+        let loc = call.location();
+        let (line, column) = source.offset_to_line_col(loc.start_offset());
+        diagnostics.push(self.diagnostic(
+            source,
+            line,
+            column,
+            "Do not call `sleep` in application code.".to_string(),
+        ));
+    }
+}
+
+/// Requires `TODO` comments to reference a ticket, e.g. `# TODO(ACME-123): ...`.
+struct TodoNeedsTicket;
+
+impl Cop for TodoNeedsTicket {
+    fn name(&self) -> &'static str {
+        "Acme/TodoNeedsTicket"
+    }
+
+    fn check_lines(
+        &self,
+        source: &SourceFile,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        _corrections: Option<&mut Vec<Correction>>,
+    ) {
+        let prefix = config.get_str("TicketPrefix", "ACME-");
+        for (i, line) in source.lines().enumerate() {
+            let Ok(line) = std::str::from_utf8(line) else {
+                continue;
+            };
+            let trimmed = line.trim_start();
+            let Some(comment) = trimmed.strip_prefix('#') else {
+                continue;
+            };
+            if comment.trim_start().starts_with("TODO") && !comment.contains(prefix) {
+                let column = line.len() - trimmed.len();
+                diagnostics.push(self.diagnostic(
+                    source,
+                    i + 1,
+                    column,
+                    format!("Reference a `{prefix}` ticket in TODO comments."),
+                ));
+            }
+        }
+    }
+}
+
+fn main() {
+    // Same worker stack size as the stock binary; deeply nested Ruby overflows the default.
+    rayon::ThreadPoolBuilder::new()
+        .stack_size(32 * 1024 * 1024)
+        .build_global()
+        .ok();
+
+    let args = Args::parse();
+    let linter = Linter::new().with_extra_cops(vec![Box::new(NoSleep), Box::new(TodoNeedsTicket)]);
+    match linter.run(args) {
+        Ok(code) => process::exit(code),
+        Err(e) => {
+            eprintln!("error: {e:#}");
+            process::exit(3);
+        }
+    }
+}
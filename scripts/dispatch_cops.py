@@ -142,6 +142,19 @@ SHARED_MODULE_CONSUMERS: dict[str, set[str]] = {
         "Style/Next",
         "Style/RedundantParentheses",
     },
+    "documentation_comment": {
+        "Style/Documentation",
+        "Style/DocumentationMethod",
+    },
+    "multiline_element_indentation": {
+        "Layout/FirstArgumentIndentation",
+        "Layout/FirstArrayElementIndentation",
+        "Layout/FirstHashElementIndentation",
+    },
+    "ordered_gem_node": {
+        "Bundler/OrderedGems",
+        "Gemspec/OrderedDependencies",
+    },
     "literal_predicates": {
         "Lint/LiteralAsCondition",
         "Style/InfiniteLoop",
@@ -0,0 +1,164 @@
+//! Linting a string in-process: the supported entry point for embedding.
+//!
+//! [`lint_string`] runs the built-in cops over one source with the same
+//! config resolution, cop filters, and autocorrect loop as the CLI, and
+//! returns plain data. It never exits, and config warnings come back in
+//! [`LintOutcome::config_warnings`] instead of going to stderr. `--stdin`
+//! goes through the same code, so the two cannot drift apart.
+//!
+//! ```no_run
+//! use nitrocop::api::{ConfigSource, LintOptions, lint_string};
+//!
+//! let outcome = lint_string(
+//!     "x = 1   \n",
+//!     "app/models/user.rb",
+//!     LintOptions {
+//!         config: ConfigSource::Discover(std::path::Path::new("/srv/repo")),
+//!         only: vec!["Layout/TrailingWhitespace".to_string()],
+//!         ..LintOptions::default()
+//!     },
+//! )?;
+//! for d in &outcome.diagnostics {
+//!     println!("{}:{} {}", d.location.line, d.cop_name, d.message);
+//! }
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use clap::Parser;
+
+use crate::cli::{Args, AutocorrectMode};
+use crate::config::{ResolvedConfig, load_config};
+use crate::cop::autocorrect_allowlist::AutocorrectAllowlist;
+use crate::cop::registry::CopRegistry;
+use crate::cop::tiers::{SkipSummary, TierMap};
+use crate::diagnostic::Diagnostic;
+use crate::linter::{LintResult, lint_source};
+use crate::parse::source::SourceFile;
+
+/// Where [`lint_string`] gets its configuration.
+#[derive(Default)]
+pub enum ConfigSource<'a> {
+    /// Built-in defaults only, as with `--force-default-config`.
+    #[default]
+    Defaults,
+    /// Find `.rubocop.yml` by walking up from this directory, as the CLI does.
+    Discover(&'a Path),
+    /// Load this config file and everything it inherits from.
+    File(&'a Path),
+    /// A config already loaded with [`load_config`]. Reusing one avoids
+    /// resolving the config on every call.
+    Resolved(&'a ResolvedConfig),
+}
+
+/// Options for [`lint_string`]. The defaults match a plain CLI run.
+#[derive(Default)]
+pub struct LintOptions<'a> {
+    pub config: ConfigSource<'a>,
+    /// Run only these cops (like `--only`).
+    pub only: Vec<String>,
+    /// Skip these cops (like `--except`).
+    pub except: Vec<String>,
+    /// `Safe` is `-a`, `All` is `-A`.
+    pub autocorrect: AutocorrectMode,
+    /// Also run preview-tier cops (like `--preview`).
+    pub preview: bool,
+}
+
+/// The result of [`lint_string`].
+#[derive(Debug, Clone)]
+pub struct LintOutcome {
+    /// Offenses sorted by location. With autocorrect, the ones fixed in
+    /// `corrected_source` have `corrected` set.
+    pub diagnostics: Vec<Diagnostic>,
+    /// The source with corrections applied; `None` when nothing changed.
+    pub corrected_source: Option<String>,
+    /// Cops the config enabled that did not run.
+    pub skip_summary: SkipSummary,
+    /// Problems met while loading the config that did not stop it, such as
+    /// a missing `inherit_from` target. The CLI prints these as `warning:`
+    /// lines.
+    pub config_warnings: Vec<String>,
+}
+
+impl From<LintResult> for LintOutcome {
+    fn from(result: LintResult) -> Self {
+        Self {
+            diagnostics: result.diagnostics,
+            corrected_source: result
+                .corrected_source
+                .map(|bytes| String::from_utf8_lossy(&bytes).into_owned()),
+            skip_summary: result.skip_summary,
+            config_warnings: Vec::new(),
+        }
+    }
+}
+
+/// Lint `source` as if it were the file at `path_hint`, which is used for
+/// config matching (Include/Exclude, per-directory configs) and as the
+/// diagnostics' path. The file itself is never read.
+///
+/// Errors only when the config cannot be loaded.
+pub fn lint_string(source: &str, path_hint: &str, options: LintOptions<'_>) -> Result<LintOutcome> {
+    let registry = CopRegistry::default_registry();
+    let loaded;
+    let config = match options.config {
+        ConfigSource::Defaults => {
+            let mut cfg = ResolvedConfig::empty();
+            cfg.register_departments_from_only(&options.only);
+            loaded = cfg;
+            &loaded
+        }
+        ConfigSource::Discover(dir) => {
+            loaded = load_config(None, Some(dir), None)?;
+            &loaded
+        }
+        ConfigSource::File(path) => {
+            loaded = load_config(Some(path), None, None)?;
+            &loaded
+        }
+        ConfigSource::Resolved(config) => config,
+    };
+
+    let mut args = Args::parse_from(["nitrocop"]);
+    args.only = options.only;
+    args.except = options.except;
+    args.autocorrect = options.autocorrect == AutocorrectMode::Safe;
+    args.autocorrect_all = options.autocorrect == AutocorrectMode::All;
+    args.preview = options.preview;
+
+    let mut tier_map = TierMap::load();
+    for name in config.preview_cops() {
+        tier_map.promote(name);
+    }
+
+    let result = lint_with(
+        source.to_string(),
+        Path::new(path_hint),
+        config,
+        &registry,
+        &args,
+        &tier_map,
+        &AutocorrectAllowlist::load(),
+    );
+    Ok(LintOutcome {
+        config_warnings: config.warnings().to_vec(),
+        ..result.into()
+    })
+}
+
+/// The shared core of [`lint_string`] and `--stdin`.
+pub(crate) fn lint_with(
+    source: String,
+    path: &Path,
+    config: &ResolvedConfig,
+    registry: &CopRegistry,
+    args: &Args,
+    tier_map: &TierMap,
+    allowlist: &AutocorrectAllowlist,
+) -> LintResult {
+    let source = SourceFile::from_string(PathBuf::from(path), source);
+    lint_source(&source, config, registry, args, tier_map, allowlist)
+}
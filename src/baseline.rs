@@ -0,0 +1,399 @@
+//! Offense baselines: grandfather existing offenses without editing config.
+//!
+//! `--baseline-generate PATH` records every current offense as a fingerprint
+//! of file path, cop name, and a hash of the offending source line. Line
+//! numbers are deliberately left out so entries keep matching when code above
+//! them is added or removed. `--baseline PATH` then drops matching offenses at
+//! report time. Entries that no longer match anything are reported as stale
+//! so the baseline can shrink as offenses get fixed.
+//!
+//! Paths are stored relative to the baseline file's directory, so a baseline
+//! checked into the repository root works from any working directory.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::diagnostic::Diagnostic;
+
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    schema: u32,
+    offenses: Vec<Entry>,
+}
+
+/// One grandfathered offense. Identical offenses on identical lines of the
+/// same file are recorded once per occurrence.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Entry {
+    pub path: String,
+    pub cop: String,
+    /// Truncated SHA-256 of the offending line, with surrounding whitespace trimmed.
+    pub hash: String,
+}
+
+/// Suppression counts handed to formatters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BaselineSummary {
+    pub suppressed: usize,
+    pub stale: usize,
+}
+
+/// Result of filtering a run's diagnostics through a baseline.
+pub struct BaselineOutcome {
+    /// Diagnostics not covered by the baseline.
+    pub diagnostics: Vec<Diagnostic>,
+    pub suppressed: usize,
+    /// Entries for inspected files that matched no offense, sorted.
+    pub stale: Vec<Entry>,
+}
+
+impl BaselineOutcome {
+    pub fn summary(&self) -> BaselineSummary {
+        BaselineSummary {
+            suppressed: self.suppressed,
+            stale: self.stale.len(),
+        }
+    }
+}
+
+/// Reads and caches file contents for fingerprinting.
+#[derive(Default)]
+pub struct SourceCache {
+    files: HashMap<String, Option<Vec<u8>>>,
+}
+
+impl SourceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `content` for `path` instead of reading it from disk (e.g. `--stdin`).
+    pub fn insert(&mut self, path: &str, content: Vec<u8>) {
+        self.files.insert(path.to_string(), Some(content));
+    }
+
+    fn line_hash(&mut self, path: &str, line: usize) -> String {
+        let content = self
+            .files
+            .entry(path.to_string())
+            .or_insert_with(|| std::fs::read(path).ok());
+        let text = content
+            .as_deref()
+            .and_then(|c| c.split(|&b| b == b'\n').nth(line.saturating_sub(1)))
+            .unwrap_or_default();
+        let mut hasher = Sha256::new();
+        hasher.update(text.trim_ascii());
+        format!("{:x}", hasher.finalize())[..16].to_string()
+    }
+}
+
+pub struct Baseline {
+    entries: Vec<Entry>,
+    base_dir: PathBuf,
+}
+
+impl Baseline {
+    /// Record every uncorrected offense in `diagnostics`. `baseline_path` is
+    /// where the baseline will be written; paths are stored relative to it.
+    pub fn generate(
+        diagnostics: &[Diagnostic],
+        baseline_path: &Path,
+        sources: &mut SourceCache,
+    ) -> Self {
+        let base_dir = base_dir_of(baseline_path);
+        let mut entries: Vec<Entry> = diagnostics
+            .iter()
+            .filter(|d| !d.corrected)
+            .map(|d| fingerprint(d, &base_dir, sources))
+            .collect();
+        entries.sort();
+        Self { entries, base_dir }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read baseline {}", path.display()))?;
+        let file: BaselineFile = serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse baseline {}", path.display()))?;
+        if file.schema != SCHEMA_VERSION {
+            anyhow::bail!(
+                "unsupported baseline schema {} in {} (expected {SCHEMA_VERSION}); regenerate it with --baseline-generate",
+                file.schema,
+                path.display()
+            );
+        }
+        Ok(Self {
+            entries: file.offenses,
+            base_dir: base_dir_of(path),
+        })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let file = BaselineFile {
+            schema: SCHEMA_VERSION,
+            offenses: self.entries.clone(),
+        };
+        let json = serde_json::to_string_pretty(&file)?;
+        std::fs::write(path, json + "\n")
+            .with_context(|| format!("failed to write baseline {}", path.display()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop offenses covered by the baseline.
+    ///
+    /// Each entry suppresses at most one offense. Leftover entries count as
+    /// stale when their file was inspected in this run (or no longer exists)
+    /// and their cop ran, so linting a subset of files or cops does not make
+    /// the rest of the baseline look stale.
+    pub fn apply(
+        &self,
+        diagnostics: Vec<Diagnostic>,
+        inspected: &[PathBuf],
+        cop_ran: impl Fn(&str) -> bool,
+        sources: &mut SourceCache,
+    ) -> BaselineOutcome {
+        let mut remaining: HashMap<&Entry, usize> = HashMap::new();
+        for entry in &self.entries {
+            *remaining.entry(entry).or_default() += 1;
+        }
+
+        let mut kept = Vec::with_capacity(diagnostics.len());
+        let mut suppressed = 0;
+        for d in diagnostics {
+            if !d.corrected {
+                let entry = fingerprint(&d, &self.base_dir, sources);
+                if let Some(count) = remaining.get_mut(&entry).filter(|c| **c > 0) {
+                    *count -= 1;
+                    suppressed += 1;
+                    continue;
+                }
+            }
+            kept.push(d);
+        }
+
+        let inspected: HashSet<String> = inspected
+            .iter()
+            .map(|p| path_key(&p.to_string_lossy(), &self.base_dir))
+            .collect();
+        let mut stale: Vec<Entry> = remaining
+            .into_iter()
+            .filter(|(entry, _)| {
+                cop_ran(&entry.cop)
+                    && (inspected.contains(&entry.path)
+                        || !self.base_dir.join(&entry.path).exists())
+            })
+            .flat_map(|(entry, count)| std::iter::repeat_n(entry.clone(), count))
+            .collect();
+        stale.sort();
+
+        BaselineOutcome {
+            diagnostics: kept,
+            suppressed,
+            stale,
+        }
+    }
+}
+
+/// Print stale baseline entries to stderr.
+pub fn print_stale(stale: &[Entry], baseline_path: &Path) {
+    if stale.is_empty() {
+        return;
+    }
+    let (word, verb) = if stale.len() == 1 {
+        ("entry", "matches")
+    } else {
+        ("entries", "match")
+    };
+    eprintln!(
+        "{} stale baseline {word} in {} no longer {verb} any offense; regenerate it with --baseline-generate:",
+        stale.len(),
+        baseline_path.display()
+    );
+    for entry in stale {
+        eprintln!("  {}: {}", entry.path, entry.cop);
+    }
+}
+
+fn fingerprint(d: &Diagnostic, base_dir: &Path, sources: &mut SourceCache) -> Entry {
+    Entry {
+        path: path_key(&d.path, base_dir),
+        cop: d.cop_name.clone(),
+        hash: sources.line_hash(&d.path, d.location.line),
+    }
+}
+
+fn base_dir_of(baseline_path: &Path) -> PathBuf {
+    let dir = match baseline_path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    dir.canonicalize().unwrap_or(dir)
+}
+
+/// The path as stored in the baseline: relative to `base_dir` with `/`
+/// separators when the file lives under it, otherwise as given.
+fn path_key(path: &str, base_dir: &Path) -> String {
+    let p = Path::new(path);
+    let absolute = p
+        .canonicalize()
+        .ok()
+        .or_else(|| std::env::current_dir().ok().map(|cwd| cwd.join(p)));
+    match absolute
+        .as_deref()
+        .and_then(|a| a.strip_prefix(base_dir).ok())
+    {
+        Some(rel) => rel
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+        None => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::{Location, Severity};
+
+    fn diag(path: &Path, line: usize, cop: &str) -> Diagnostic {
+        Diagnostic {
+            path: path.to_string_lossy().to_string(),
+            location: Location { line, column: 0 },
+            severity: Severity::Convention,
+            cop_name: cop.to_string(),
+            message: "msg".to_string(),
+            corrected: false,
+            end_column: None,
+        }
+    }
+
+    #[test]
+    fn survives_line_drift_and_reports_new_offenses() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.rb");
+        let baseline_path = dir.path().join("baseline.json");
+        std::fs::write(&file, "x = 1  \ny = 2  \n").unwrap();
+        let before = vec![diag(&file, 1, "Layout/TrailingWhitespace")];
+        Baseline::generate(&before, &baseline_path, &mut SourceCache::new())
+            .write(&baseline_path)
+            .unwrap();
+
+        // Two lines inserted above the grandfathered one, plus a new offense.
+        std::fs::write(&file, "# a\n# b\nx = 1  \ny = 2  \n").unwrap();
+        let after = vec![
+            diag(&file, 3, "Layout/TrailingWhitespace"),
+            diag(&file, 4, "Layout/TrailingWhitespace"),
+        ];
+        let baseline = Baseline::load(&baseline_path).unwrap();
+        let outcome = baseline.apply(
+            after,
+            std::slice::from_ref(&file),
+            |_| true,
+            &mut SourceCache::new(),
+        );
+        assert_eq!(outcome.suppressed, 1);
+        assert_eq!(outcome.diagnostics.len(), 1);
+        assert_eq!(outcome.diagnostics[0].location.line, 4);
+        assert!(outcome.stale.is_empty());
+    }
+
+    #[test]
+    fn stored_paths_are_relative_to_baseline() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("lib/a.rb");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(&file, "x\n").unwrap();
+        let baseline = Baseline::generate(
+            &[diag(&file, 1, "Style/Foo")],
+            &dir.path().join("baseline.json"),
+            &mut SourceCache::new(),
+        );
+        assert_eq!(baseline.entries[0].path, "lib/a.rb");
+    }
+
+    #[test]
+    fn fixed_offenses_become_stale() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.rb");
+        let other = dir.path().join("b.rb");
+        let baseline_path = dir.path().join("baseline.json");
+        std::fs::write(&file, "x = 1\n").unwrap();
+        std::fs::write(&other, "y = 1\n").unwrap();
+        let baseline = Baseline::generate(
+            &[
+                diag(&file, 1, "Style/Foo"),
+                diag(&file, 1, "Style/Bar"),
+                diag(&other, 1, "Style/Foo"),
+            ],
+            &baseline_path,
+            &mut SourceCache::new(),
+        );
+
+        // Only a.rb is inspected, and Style/Bar did not run: neither the
+        // b.rb entry nor the Style/Bar entry is reported.
+        let outcome = baseline.apply(
+            vec![],
+            std::slice::from_ref(&file),
+            |cop| cop != "Style/Bar",
+            &mut SourceCache::new(),
+        );
+        assert_eq!(outcome.suppressed, 0);
+        assert_eq!(
+            outcome
+                .stale
+                .iter()
+                .map(|e| (e.path.as_str(), e.cop.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("a.rb", "Style/Foo")]
+        );
+
+        // Entries for deleted files are always stale.
+        std::fs::remove_file(&other).unwrap();
+        let outcome = baseline.apply(vec![], &[], |_| true, &mut SourceCache::new());
+        assert_eq!(outcome.stale.len(), 1);
+        assert_eq!(outcome.stale[0].path, "b.rb");
+    }
+
+    #[test]
+    fn each_entry_suppresses_one_occurrence() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.rb");
+        let baseline_path = dir.path().join("baseline.json");
+        std::fs::write(&file, "x = 1\nx = 1\n").unwrap();
+        let baseline = Baseline::generate(
+            &[diag(&file, 1, "Style/Foo")],
+            &baseline_path,
+            &mut SourceCache::new(),
+        );
+        let outcome = baseline.apply(
+            vec![diag(&file, 1, "Style/Foo"), diag(&file, 2, "Style/Foo")],
+            std::slice::from_ref(&file),
+            |_| true,
+            &mut SourceCache::new(),
+        );
+        assert_eq!(outcome.suppressed, 1);
+        assert_eq!(outcome.diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn rejects_unknown_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        std::fs::write(&path, r#"{"schema":99,"offenses":[]}"#).unwrap();
+        assert!(Baseline::load(&path).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use sha2::{Digest, Sha256};
 
@@ -18,15 +18,24 @@ use crate::diagnostic::{Diagnostic, Location, Severity};
 /// └── <session_hash>.index    # all entries for this session (JSON)
 /// ```
 ///
+/// A session is one project (the working directory) linted with one config
+/// and set of CLI filters, so changing the config starts over, and projects
+/// sharing a config don't evict each other's entries.
+///
 /// Two-tier lookup per file:
 /// 1. **Stat check** (mtime + size) — no file read needed, instant for local dev
 /// 2. **Content hash** fallback — handles CI, git checkout, and other mtime-unreliable scenarios
 ///
+/// `--paranoid` skips the stat check, for filesystems where an edit can keep
+/// both the mtime and the size.
+///
 /// Thread safety: all entries stored in a `RwLock<HashMap>`. Rayon workers take
 /// read locks on stat hits (zero contention), write locks only on cache misses.
 pub struct ResultCache {
     index_path: PathBuf,
     enabled: bool,
+    /// nitrocop version stamped on every entry written.
+    version: String,
     /// All cache entries loaded into memory at construction.
     /// Key: path_hash (String), Value: CacheEntry
     entries: Arc<RwLock<HashMap<String, CacheEntry>>>,
@@ -47,6 +56,21 @@ struct CacheEntry {
     content_hash: String,
     /// Cached lint diagnostics.
     diagnostics: Vec<CachedDiagnostic>,
+    /// How long linting the file took, in microseconds. 0 for entries
+    /// written before timings were recorded.
+    #[serde(default)]
+    lint_micros: u64,
+    /// nitrocop version that produced the entry. Entries from any other
+    /// version (or written before versions were recorded) are dropped on
+    /// load, so they miss.
+    #[serde(default)]
+    version: String,
+}
+
+impl CacheEntry {
+    fn lint_time(&self) -> Option<Duration> {
+        (self.lint_micros > 0).then(|| Duration::from_micros(self.lint_micros))
+    }
 }
 
 /// Compact diagnostic without the file path (implied by cache key).
@@ -86,6 +110,7 @@ impl CachedDiagnostic {
             cop_name: self.cop.clone(),
             message: self.message.clone(),
             corrected: false,
+            end_column: None,
         }
     }
 }
@@ -101,17 +126,43 @@ pub enum CacheLookup {
     Miss,
 }
 
+/// What a lookup would find, without changing the cache. For
+/// `--list-target-files --estimate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheProbe {
+    /// mtime and size match the entry.
+    StatHit,
+    /// mtime or size changed but the content hash matches.
+    ContentHit,
+    /// The entry is for different content. Carries the last lint time.
+    Changed(Option<Duration>),
+    /// No entry for this path in the current session.
+    NoEntry,
+}
+
 impl ResultCache {
-    /// Create a new result cache with session-level key.
-    pub fn new(version: &str, base_configs: &[CopConfig], args: &Args) -> Self {
+    /// Create a new result cache with session-level key. `preview_cops` are
+    /// the preview cops promoted for this run, which change what runs just
+    /// like `--preview` does.
+    pub fn new(
+        version: &str,
+        base_configs: &[CopConfig],
+        args: &Args,
+        preview_cops: &[String],
+    ) -> Self {
         let cache_root = cache_root_dir();
         let _ = std::fs::create_dir_all(&cache_root);
-        let session_hash = compute_session_hash(version, base_configs, args);
+        let project = std::env::current_dir()
+            .and_then(|dir| dir.canonicalize())
+            .unwrap_or_default();
+        let session_hash =
+            compute_session_hash(version, &project, base_configs, args, preview_cops);
         let index_path = cache_root.join(format!("{session_hash}.index"));
-        let entries = load_index(&index_path);
+        let entries = load_index(&index_path, version);
         Self {
             index_path,
             enabled: true,
+            version: version.to_string(),
             entries: Arc::new(RwLock::new(entries)),
             dirty: Arc::new(AtomicBool::new(false)),
         }
@@ -119,12 +170,13 @@ impl ResultCache {
 
     /// Create a cache rooted at the given directory (for testing).
     pub fn with_root(root: &Path, version: &str, base_configs: &[CopConfig], args: &Args) -> Self {
-        let session_hash = compute_session_hash(version, base_configs, args);
+        let session_hash = compute_session_hash(version, root, base_configs, args, &[]);
         let index_path = root.join(format!("{session_hash}.index"));
-        let entries = load_index(&index_path);
+        let entries = load_index(&index_path, version);
         Self {
             index_path,
             enabled: true,
+            version: version.to_string(),
             entries: Arc::new(RwLock::new(entries)),
             dirty: Arc::new(AtomicBool::new(false)),
         }
@@ -135,6 +187,7 @@ impl ResultCache {
         Self {
             index_path: PathBuf::new(),
             enabled: false,
+            version: String::new(),
             entries: Arc::new(RwLock::new(HashMap::new())),
             dirty: Arc::new(AtomicBool::new(false)),
         }
@@ -243,9 +296,60 @@ impl ResultCache {
         CacheLookup::ContentHit(result)
     }
 
-    /// Store results for a file. Best-effort — writes to in-memory map only.
-    /// Call `flush()` to persist to disk.
-    pub fn put(&self, path: &Path, content: &[u8], diagnostics: &[Diagnostic]) {
+    /// Classify `path` the way `get_by_stat` and `get_by_content` would,
+    /// without updating the entry. Reads the file only when the stat check
+    /// fails.
+    pub fn probe(&self, path: &Path) -> CacheProbe {
+        if !self.enabled {
+            return CacheProbe::NoEntry;
+        }
+        let entries = self.entries.read().unwrap();
+        let Some(entry) = entries.get(&compute_path_hash(path)) else {
+            return CacheProbe::NoEntry;
+        };
+
+        if let Ok(meta) = std::fs::metadata(path) {
+            let (mtime_secs, mtime_nanos) = systemtime_to_parts(meta.modified().ok());
+            if entry.mtime_secs == mtime_secs
+                && entry.mtime_nanos == mtime_nanos
+                && entry.size == meta.len()
+            {
+                return CacheProbe::StatHit;
+            }
+        }
+        match std::fs::read(path) {
+            Ok(content) if compute_content_hash(&content) == entry.content_hash => {
+                CacheProbe::ContentHit
+            }
+            _ => CacheProbe::Changed(entry.lint_time()),
+        }
+    }
+
+    /// Mean lint time of the entries that recorded one.
+    pub fn mean_lint_time(&self) -> Option<Duration> {
+        let entries = self.entries.read().unwrap();
+        let timed: Vec<u64> = entries
+            .values()
+            .map(|e| e.lint_micros)
+            .filter(|&m| m > 0)
+            .collect();
+        if timed.is_empty() {
+            return None;
+        }
+        let total: u64 = timed.iter().sum();
+        Some(Duration::from_micros(total / timed.len() as u64))
+    }
+
+    /// Store results for a file along with how long linting it took.
+    /// Best-effort — writes to in-memory map only. Call `flush()` to persist
+    /// to disk.
+    pub fn put(
+        &self,
+        path: &Path,
+        content: &[u8],
+        diagnostics: &[Diagnostic],
+        lint_time: Duration,
+    ) {
         if !self.enabled {
             return;
         }
@@ -264,6 +368,8 @@ impl ResultCache {
                 .iter()
                 .map(CachedDiagnostic::from_diagnostic)
                 .collect(),
+            lint_micros: lint_time.as_micros().try_into().unwrap_or(u64::MAX),
+            version: self.version.clone(),
         };
 
         let hash = compute_path_hash(path);
@@ -302,14 +408,17 @@ impl ResultCache {
     }
 }
 
-/// Load the index file into a HashMap. Returns empty map if file doesn't exist
-/// or can't be parsed.
-fn load_index(index_path: &Path) -> HashMap<String, CacheEntry> {
+/// Load the index file into a HashMap, keeping only entries written by
+/// `version`. Returns empty map if file doesn't exist or can't be parsed.
+fn load_index(index_path: &Path, version: &str) -> HashMap<String, CacheEntry> {
     let data = match std::fs::read(index_path) {
         Ok(d) => d,
         Err(_) => return HashMap::new(),
     };
-    serde_json::from_slice(&data).unwrap_or_default()
+    let mut entries: HashMap<String, CacheEntry> =
+        serde_json::from_slice(&data).unwrap_or_default();
+    entries.retain(|_, entry| entry.version == version);
+    entries
 }
 
 /// Convert SystemTime to (secs, nanos) since UNIX epoch.
@@ -359,16 +468,24 @@ pub(crate) fn cache_root_dir() -> PathBuf {
     PathBuf::from(".nitrocop-cache")
 }
 
-/// Compute the session hash from version + config + CLI args.
+/// Compute the session hash from version + project directory + config + CLI args.
 ///
 /// The config fingerprint must be deterministic across runs. Since CopConfig
 /// contains `HashMap<String, Value>` (non-deterministic iteration order), we
 /// sort keys before hashing rather than relying on serde_json serialization.
-fn compute_session_hash(version: &str, base_configs: &[CopConfig], args: &Args) -> String {
+fn compute_session_hash(
+    version: &str,
+    project: &Path,
+    base_configs: &[CopConfig],
+    args: &Args,
+    preview_cops: &[String],
+) -> String {
     let mut hasher = Sha256::new();
     hasher.update(b"nitrocop-session-v3:");
     hasher.update(version.as_bytes());
     hasher.update(b":");
+    hasher.update(project.as_os_str().as_encoded_bytes());
+    hasher.update(b":");
 
     for config in base_configs {
         hasher.update(format!("{:?}", config.enabled).as_bytes());
@@ -408,11 +525,64 @@ fn compute_session_hash(version: &str, base_configs: &[CopConfig], args: &Args)
     if args.ignore_disable_comments {
         hasher.update(b"ignore_disable_comments");
     }
+    if args.preview {
+        hasher.update(b"preview");
+    }
+    let mut preview_cops: Vec<&String> = preview_cops.iter().collect();
+    preview_cops.sort();
+    for cop in preview_cops {
+        hasher.update(b"preview_cop:");
+        hasher.update(cop.as_bytes());
+    }
 
     let hash = hasher.finalize();
     format!("{:x}", hash)[..16].to_string()
 }
 
+/// Fingerprint of everything that determines a run's output: version, config
+/// and CLI filters (the session hash), the file list and the enabled cop set.
+///
+/// Printed by `--debug` so two machines can check they lint the same thing.
+/// File paths are hashed as given, so compare runs started from the same
+/// directory with the same path arguments.
+pub(crate) fn compute_run_fingerprint(
+    version: &str,
+    base_configs: &[CopConfig],
+    args: &Args,
+    files: &[PathBuf],
+    cop_names: &[&str],
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"nitrocop-run-v1:");
+    // The enabled cop set below already reflects preview promotion. The
+    // project directory is left out so runs on different machines compare.
+    hasher.update(compute_session_hash(version, Path::new(""), base_configs, args, &[]).as_bytes());
+    let mut paths: Vec<String> = files
+        .iter()
+        .map(|f| f.to_string_lossy().into_owned())
+        .collect();
+    paths.sort();
+    for path in &paths {
+        hasher.update(b"file:");
+        hasher.update(path.as_bytes());
+    }
+    let mut cops = cop_names.to_vec();
+    cops.sort_unstable();
+    for cop in cops {
+        hasher.update(b"cop:");
+        hasher.update(cop.as_bytes());
+    }
+    let hash = hasher.finalize();
+    format!("{:x}", hash)[..16].to_string()
+}
+
+/// Fingerprint of the resolved cop configs and the CLI cop filters, without
+/// the version, project directory or file list. Recorded by `--history-file`
+/// so a jump in offense counts can be told apart from a config change.
+pub(crate) fn compute_config_fingerprint(base_configs: &[CopConfig], args: &Args) -> String {
+    compute_session_hash("", Path::new(""), base_configs, args, &[])
+}
+
 /// Remove the entire cache directory.
 pub fn clear_cache() -> std::io::Result<()> {
     let cache_root = cache_root_dir();
@@ -482,36 +652,59 @@ mod tests {
             only: vec![],
             except: vec![],
             no_color: false,
+            sort_severity: false,
             debug: false,
+            debug_json: false,
             rubocop_only: false,
             list_cops: false,
             list_autocorrectable_cops: false,
+            show_cops: None,
             migrate: false,
             doctor: false,
             rules: false,
             tier: None,
+            department: None,
+            history_file: None,
+            history_summary: false,
+            emit_schema: None,
+            validate_config: false,
             stdin: None,
             init: false,
             no_cache: false,
             cache: "true".to_string(),
             cache_clear: false,
+            paranoid: false,
             fail_level: "convention".to_string(),
+            display_only_fail_level_offenses: false,
+            max_reported_offenses: None,
             fail_fast: false,
             force_exclusion: false,
             list_target_files: false,
+            estimate: false,
+            preview_cop: Vec::new(),
+            show_config_chain: false,
             display_cop_names: false,
             parallel: false,
+            no_parallel: false,
             require_libs: vec![],
             ignore_disable_comments: false,
             force_default_config: false,
             autocorrect: false,
             autocorrect_all: false,
+            autocorrect_changed_lines: None,
+            autocorrect_cop: Vec::new(),
+            copy_to: None,
+            copy_all: false,
             preview: false,
+            erb: false,
+            markdown: false,
             quiet_skips: false,
             strict: None,
             verify: false,
             rubocop_cmd: "bundle exec rubocop".to_string(),
             corpus_check: None,
+            baseline: None,
+            baseline_generate: None,
         }
     }
 
@@ -547,8 +740,9 @@ mod tests {
             cop_name: "Layout/TrailingWhitespace".to_string(),
             message: "Trailing whitespace detected.".to_string(),
             corrected: false,
+            end_column: None,
         }];
-        cache.put(&rb_file, b"x = 1 \n", &diagnostics);
+        cache.put(&rb_file, b"x = 1 \n", &diagnostics, Duration::ZERO);
 
         // Stat hit (mtime+size unchanged since we just wrote the file)
         match cache.get_by_stat(&rb_file) {
@@ -580,7 +774,7 @@ mod tests {
         std::fs::write(&rb_file, b"y = 2\n").unwrap();
 
         // Store results
-        cache.put(&rb_file, b"y = 2\n", &[]);
+        cache.put(&rb_file, b"y = 2\n", &[], Duration::ZERO);
 
         // Simulate mtime change by touching the file (same content)
         std::thread::sleep(std::time::Duration::from_millis(50));
@@ -620,7 +814,7 @@ mod tests {
 
         let rb_file = tmp.path().join("changed.rb");
         std::fs::write(&rb_file, b"x = 1\n").unwrap();
-        cache.put(&rb_file, b"x = 1\n", &[]);
+        cache.put(&rb_file, b"x = 1\n", &[], Duration::ZERO);
 
         // Change content with a different size so stat detects the change even
         // when both writes land in the same filesystem timestamp granularity
@@ -635,6 +829,36 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn probe_classifies_without_updating() {
+        let tmp = tempfile::tempdir().unwrap();
+        let args = test_args();
+        let configs = vec![CopConfig::default()];
+        let cache = ResultCache::with_root(tmp.path(), "0.1.0-test", &configs, &args);
+
+        let rb_file = tmp.path().join("probe.rb");
+        std::fs::write(&rb_file, b"x = 1\n").unwrap();
+        assert_eq!(cache.probe(&rb_file), CacheProbe::NoEntry);
+        assert_eq!(cache.mean_lint_time(), None);
+
+        cache.put(&rb_file, b"x = 1\n", &[], Duration::from_millis(4));
+        assert_eq!(cache.probe(&rb_file), CacheProbe::StatHit);
+        assert_eq!(cache.mean_lint_time(), Some(Duration::from_millis(4)));
+
+        // Same content, stale stat: a content hit that is not recorded
+        cache.entries.write().unwrap().values_mut().for_each(|e| {
+            e.mtime_secs = 0;
+        });
+        assert_eq!(cache.probe(&rb_file), CacheProbe::ContentHit);
+        assert_eq!(cache.probe(&rb_file), CacheProbe::ContentHit);
+
+        std::fs::write(&rb_file, b"x = 22\n").unwrap();
+        assert_eq!(
+            cache.probe(&rb_file),
+            CacheProbe::Changed(Some(Duration::from_millis(4)))
+        );
+    }
+
     #[test]
     fn config_change_invalidates_session() {
         let tmp = tempfile::tempdir().unwrap();
@@ -645,7 +869,7 @@ mod tests {
 
         let configs1 = vec![CopConfig::default()];
         let cache1 = ResultCache::with_root(tmp.path(), "0.1.0-test", &configs1, &args);
-        cache1.put(&rb_file, b"x = 1\n", &[]);
+        cache1.put(&rb_file, b"x = 1\n", &[], Duration::ZERO);
 
         // Same config = cache hit (same in-memory instance)
         assert!(matches!(
@@ -687,12 +911,13 @@ mod tests {
             cop_name: "Lint/UselessAssignment".to_string(),
             message: "Useless assignment.".to_string(),
             corrected: false,
+            end_column: None,
         }];
-        cache1.put(&rb_file, b"z = 3\n", &diagnostics);
+        cache1.put(&rb_file, b"z = 3\n", &diagnostics, Duration::ZERO);
         cache1.flush();
 
         // Verify index file exists
-        let session_hash = compute_session_hash("0.1.0-test", &configs, &args);
+        let session_hash = compute_session_hash("0.1.0-test", tmp.path(), &configs, &args, &[]);
         let index_path = tmp.path().join(format!("{session_hash}.index"));
         assert!(index_path.exists(), "index file should exist after flush");
 
@@ -707,6 +932,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn entries_from_another_version_miss() {
+        let tmp = tempfile::tempdir().unwrap();
+        let args = test_args();
+        let configs = vec![CopConfig::default()];
+
+        let rb_file = tmp.path().join("versioned.rb");
+        std::fs::write(&rb_file, b"v = 1\n").unwrap();
+
+        let old = ResultCache::with_root(tmp.path(), "0.1.0-test", &configs, &args);
+        old.put(&rb_file, b"v = 1\n", &[], Duration::ZERO);
+        old.flush();
+
+        // An index carried over to a session of another version (the session
+        // hash normally keeps them apart) must not serve its entries.
+        let session_hash = compute_session_hash("0.2.0-test", tmp.path(), &configs, &args, &[]);
+        std::fs::copy(
+            &old.index_path,
+            tmp.path().join(format!("{session_hash}.index")),
+        )
+        .unwrap();
+        let new = ResultCache::with_root(tmp.path(), "0.2.0-test", &configs, &args);
+        assert!(matches!(new.get_by_stat(&rb_file), CacheLookup::Miss));
+        assert!(matches!(
+            new.get_by_content(&rb_file, b"v = 1\n"),
+            CacheLookup::Miss
+        ));
+        assert_eq!(new.probe(&rb_file), CacheProbe::NoEntry);
+
+        let same = ResultCache::with_root(tmp.path(), "0.1.0-test", &configs, &args);
+        assert!(matches!(
+            same.get_by_stat(&rb_file),
+            CacheLookup::StatHit(_)
+        ));
+    }
+
+    #[test]
+    fn preview_promotion_starts_a_new_session() {
+        let args = test_args();
+        let configs = vec![CopConfig::default()];
+        let hash = |preview_cops: &[String]| {
+            compute_session_hash(
+                "0.1.0-test",
+                Path::new("/app"),
+                &configs,
+                &args,
+                preview_cops,
+            )
+        };
+        assert_ne!(hash(&[]), hash(&["Style/A".to_string()]));
+        assert_eq!(
+            hash(&["Style/A".to_string(), "Lint/B".to_string()]),
+            hash(&["Lint/B".to_string(), "Style/A".to_string()])
+        );
+    }
+
+    #[test]
+    fn each_project_gets_its_own_session() {
+        let args = test_args();
+        let configs = vec![CopConfig::default()];
+        let hash = |project: &str| {
+            compute_session_hash("0.1.0-test", Path::new(project), &configs, &args, &[])
+        };
+        assert_eq!(hash("/work/app"), hash("/work/app"));
+        assert_ne!(hash("/work/app"), hash("/work/lib"));
+    }
+
+    #[test]
+    fn run_fingerprint_ignores_order_but_not_content() {
+        let args = test_args();
+        let configs = vec![CopConfig::default()];
+        let files = vec![PathBuf::from("b.rb"), PathBuf::from("a.rb")];
+        let reversed: Vec<PathBuf> = files.iter().rev().cloned().collect();
+        let fp = |files: &[PathBuf], cops: &[&str]| {
+            compute_run_fingerprint("0.1.0-test", &configs, &args, files, cops)
+        };
+
+        let base = fp(&files, &["Style/A", "Lint/B"]);
+        assert_eq!(base, fp(&reversed, &["Lint/B", "Style/A"]));
+        assert_ne!(base, fp(&files[..1], &["Style/A", "Lint/B"]));
+        assert_ne!(base, fp(&files, &["Style/A"]));
+    }
+
     #[test]
     fn no_flush_when_not_dirty() {
         let tmp = tempfile::tempdir().unwrap();
@@ -718,7 +1026,7 @@ mod tests {
         cache.flush();
 
         // No index file should be written
-        let session_hash = compute_session_hash("0.1.0-test", &configs, &args);
+        let session_hash = compute_session_hash("0.1.0-test", tmp.path(), &configs, &args, &[]);
         let index_path = tmp.path().join(format!("{session_hash}.index"));
         assert!(
             !index_path.exists(),
@@ -736,7 +1044,7 @@ mod tests {
         let cache1 = ResultCache::with_root(tmp.path(), "0.1.0-test", &configs1, &args);
         let f = tmp.path().join("f0.rb");
         std::fs::write(&f, b"x0").unwrap();
-        cache1.put(&f, b"x0", &[]);
+        cache1.put(&f, b"x0", &[], Duration::ZERO);
         cache1.flush();
 
         // Small delay so mtimes differ
@@ -749,7 +1057,7 @@ mod tests {
         let cache2 = ResultCache::with_root(tmp.path(), "0.1.0-test", &configs2, &args);
         let g = tmp.path().join("g0.rb");
         std::fs::write(&g, b"y0").unwrap();
-        cache2.put(&g, b"y0", &[]);
+        cache2.put(&g, b"y0", &[], Duration::ZERO);
         cache2.flush();
 
         // Both index files should exist
@@ -0,0 +1,405 @@
+//! Lines changed relative to a git ref, for `--autocorrect-changed-lines`.
+//!
+//! `git diff --unified=0 <ref>` gives the changed line ranges of every
+//! modified file (working tree against the ref, so staged and unstaged edits
+//! both count). Untracked files are entirely new and count as fully changed.
+//! Files with no changes correct nothing.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+use crate::correction::CorrectionSet;
+use crate::parse::source::SourceFile;
+
+/// Changed lines of every file in the repository, keyed by absolute path.
+#[derive(Debug, Default)]
+pub struct DiffChanges {
+    files: HashMap<PathBuf, FileChanges>,
+}
+
+impl DiffChanges {
+    /// Read the changes between `git_ref` and the working tree of the
+    /// repository containing the current directory.
+    pub fn from_git(git_ref: &str) -> Result<Self> {
+        let toplevel = git(None, &["rev-parse", "--show-toplevel"])?;
+        let root = PathBuf::from(toplevel.trim());
+        let root = root.canonicalize().unwrap_or(root);
+
+        let diff = git(
+            Some(&root),
+            &[
+                // Keep non-ASCII paths verbatim and the `b/` prefix fixed,
+                // whatever the user's git config says.
+                "-c",
+                "core.quotePath=false",
+                "diff",
+                "--no-color",
+                "--no-ext-diff",
+                "--src-prefix=a/",
+                "--dst-prefix=b/",
+                "--unified=0",
+                git_ref,
+                "--",
+            ],
+        )?;
+        let mut files: HashMap<PathBuf, FileChanges> = parse_unified_diff(&diff)
+            .into_iter()
+            .map(|(rel, changes)| (root.join(rel), changes))
+            .collect();
+
+        let untracked = git(
+            Some(&root),
+            &["ls-files", "--others", "--exclude-standard", "-z"],
+        )?;
+        for rel in untracked.split('\0').filter(|rel| !rel.is_empty()) {
+            files.insert(root.join(rel), FileChanges::All);
+        }
+        Ok(Self { files })
+    }
+
+    /// The changed lines of `path`; empty when the file did not change.
+    pub fn for_file(&self, path: &Path) -> FileChanges {
+        let abs = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        self.files
+            .get(&abs)
+            .cloned()
+            .unwrap_or(FileChanges::Lines(Vec::new()))
+    }
+}
+
+fn git(dir: Option<&Path>, args: &[&str]) -> Result<String> {
+    let mut cmd = Command::new("git");
+    if let Some(dir) = dir {
+        cmd.arg("-C").arg(dir);
+    }
+    let output = cmd
+        .args(args)
+        .output()
+        .with_context(|| format!("failed to run git {}", args.join(" ")))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Changed lines of one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileChanges {
+    /// A new file: every line counts as changed.
+    All,
+    /// Sorted, non-adjacent, inclusive 1-indexed line ranges.
+    Lines(Vec<(usize, usize)>),
+}
+
+/// How much of a line span lies on changed lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coverage {
+    Full,
+    Partial,
+    None,
+}
+
+impl FileChanges {
+    fn from_ranges(mut ranges: Vec<(usize, usize)>) -> Self {
+        ranges.sort_unstable();
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        FileChanges::Lines(merged)
+    }
+
+    /// How much of the inclusive line span `first..=last` changed.
+    pub fn coverage(&self, first: usize, last: usize) -> Coverage {
+        let ranges = match self {
+            FileChanges::All => return Coverage::Full,
+            FileChanges::Lines(ranges) => ranges,
+        };
+        // Ranges are merged, so a fully changed span lies within one range.
+        let mut touched = false;
+        for &(start, end) in ranges {
+            if end < first {
+                continue;
+            }
+            if start > last {
+                break;
+            }
+            if start <= first && last <= end {
+                return Coverage::Full;
+            }
+            touched = true;
+        }
+        if touched {
+            Coverage::Partial
+        } else {
+            Coverage::None
+        }
+    }
+
+    /// The changed lines of an embedded snippet (a Markdown code block)
+    /// whose line 1 is line `first_line` of this file.
+    pub fn starting_at(&self, first_line: usize) -> Self {
+        let ranges = match self {
+            FileChanges::All => return FileChanges::All,
+            FileChanges::Lines(ranges) => ranges,
+        };
+        let offset = first_line - 1;
+        FileChanges::Lines(
+            ranges
+                .iter()
+                .filter(|&&(_, end)| end > offset)
+                .map(|&(start, end)| (start.saturating_sub(offset).max(1), end - offset))
+                .collect(),
+        )
+    }
+
+    /// The changed lines of the source produced by applying `corrections`
+    /// to `source`. Every correction must lie fully on changed lines.
+    pub fn after_corrections(&self, source: &SourceFile, corrections: &CorrectionSet) -> Self {
+        let ranges = match self {
+            FileChanges::All => return FileChanges::All,
+            FileChanges::Lines(ranges) => ranges,
+        };
+        // (last line of the correction, lines added minus lines removed)
+        let shifts: Vec<(usize, isize)> = corrections
+            .iter()
+            .map(|c| {
+                let (_, last) = c.line_span(source);
+                let removed = source.as_bytes()[c.start..c.end]
+                    .iter()
+                    .filter(|&&b| b == b'\n')
+                    .count();
+                let added = c.replacement.bytes().filter(|&b| b == b'\n').count();
+                (last, added as isize - removed as isize)
+            })
+            .collect();
+        let shift_through = |line: usize| -> isize {
+            shifts
+                .iter()
+                .filter(|&&(last, _)| last <= line)
+                .map(|&(_, delta)| delta)
+                .sum()
+        };
+        let remapped = ranges
+            .iter()
+            .map(|&(start, end)| {
+                let start = start as isize + shift_through(start - 1);
+                let end = end as isize + shift_through(end);
+                (start.max(1) as usize, end.max(start).max(1) as usize)
+            })
+            .collect();
+        FileChanges::from_ranges(remapped)
+    }
+}
+
+/// Parse `git diff --unified=0` output into the changed line ranges of each
+/// file's new side, keyed by repository-relative path. Deleted files and
+/// pure deletions contribute no lines.
+fn parse_unified_diff(diff: &str) -> HashMap<PathBuf, FileChanges> {
+    let mut files = HashMap::new();
+    let mut current: Option<(PathBuf, Vec<(usize, usize)>)> = None;
+    // Body lines left in the current hunk; they may look like headers.
+    let mut body_left = 0;
+    for line in diff.lines() {
+        if body_left > 0 {
+            body_left -= 1;
+            continue;
+        }
+        if let Some(target) = line.strip_prefix("+++ ") {
+            if let Some((path, ranges)) = current.take() {
+                files.insert(path, FileChanges::from_ranges(ranges));
+            }
+            current = diff_path(target)
+                .and_then(|target| target.strip_prefix("b/").map(PathBuf::from))
+                .map(|rel| (rel, Vec::new()));
+        } else if let Some(hunk) = line.strip_prefix("@@ ") {
+            // `@@ -a,b +c,d @@`: b lines removed at a, d lines added at c
+            // (a count of 1 when `,n` is omitted).
+            let mut sides = hunk
+                .split_whitespace()
+                .take(2)
+                .map(|side| side.get(1..).and_then(parse_hunk_side));
+            let (Some(Some((_, removed))), Some(Some((start, added)))) =
+                (sides.next(), sides.next())
+            else {
+                continue;
+            };
+            body_left = removed + added;
+            if let Some((_, ranges)) = current.as_mut() {
+                if added > 0 {
+                    ranges.push((start, start + added - 1));
+                }
+            }
+        }
+    }
+    if let Some((path, ranges)) = current {
+        files.insert(path, FileChanges::from_ranges(ranges));
+    }
+    files
+}
+
+/// The path of a `+++` header. Git appends a tab to paths containing a
+/// space, and C-quotes paths with special characters (`"b/a\"b.rb"`).
+fn diff_path(target: &str) -> Option<String> {
+    let target = target.strip_suffix('\t').unwrap_or(target);
+    let Some(quoted) = target
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    else {
+        return Some(target.to_string());
+    };
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut rest = quoted.bytes();
+    while let Some(b) = rest.next() {
+        if b != b'\\' {
+            bytes.push(b);
+            continue;
+        }
+        let unescaped = match rest.next()? {
+            b'a' => 0x07,
+            b'b' => 0x08,
+            b't' => b'\t',
+            b'n' => b'\n',
+            b'v' => 0x0b,
+            b'f' => 0x0c,
+            b'r' => b'\r',
+            // `\ooo`: a byte in octal
+            d @ b'0'..=b'3' => {
+                let mut value = d - b'0';
+                for _ in 0..2 {
+                    match rest.next()? {
+                        d @ b'0'..=b'7' => value = value * 8 + (d - b'0'),
+                        _ => return None,
+                    }
+                }
+                value
+            }
+            other => other,
+        };
+        bytes.push(unescaped);
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Parse `start,count` (or just `start`, meaning a count of 1).
+fn parse_hunk_side(side: &str) -> Option<(usize, usize)> {
+    match side.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((side.parse().ok()?, 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::correction::Correction;
+
+    #[test]
+    fn parses_hunks_per_file() {
+        let diff = "\
+diff --git a/app/a.rb b/app/a.rb
+index 1111111..2222222 100644
+--- a/app/a.rb
++++ b/app/a.rb
+@@ -3 +3 @@ def foo
+-  x
++  y
+@@ -10,0 +11,2 @@ end
++z
++++ b/looks_like_a_header.rb
+@@ -20,2 +22,0 @@
+-gone
+-gone
+diff --git a/old.rb b/old.rb
+deleted file mode 100644
+--- a/old.rb
++++ /dev/null
+@@ -1,2 +0,0 @@
+-a
+-b
+";
+        let files = parse_unified_diff(diff);
+        assert_eq!(
+            files.get(Path::new("app/a.rb")),
+            Some(&FileChanges::Lines(vec![(3, 3), (11, 12)]))
+        );
+        assert!(!files.contains_key(Path::new("old.rb")));
+        assert!(!files.contains_key(Path::new("looks_like_a_header.rb")));
+    }
+
+    #[test]
+    fn parses_quoted_and_spaced_paths() {
+        let diff = "\
+diff --git \"a/app/say \\\"hi\\\".rb\" \"b/app/say \\\"hi\\\".rb\"
+--- \"a/app/say \\\"hi\\\".rb\"
++++ \"b/app/say \\\"hi\\\".rb\"
+@@ -1 +1 @@
+-a
++b
+diff --git \"a/caf\\303\\251.rb\" \"b/caf\\303\\251.rb\"
+--- \"a/caf\\303\\251.rb\"
++++ \"b/caf\\303\\251.rb\"
+@@ -2,0 +3 @@
++c
+diff --git a/my file.rb b/my file.rb
+--- a/my file.rb\t
++++ b/my file.rb\t
+@@ -4 +4,2 @@
+-d
++e
++f
+";
+        let files = parse_unified_diff(diff);
+        assert_eq!(
+            files.get(Path::new("app/say \"hi\".rb")),
+            Some(&FileChanges::Lines(vec![(1, 1)]))
+        );
+        assert_eq!(
+            files.get(Path::new("caf\u{e9}.rb")),
+            Some(&FileChanges::Lines(vec![(3, 3)]))
+        );
+        assert_eq!(
+            files.get(Path::new("my file.rb")),
+            Some(&FileChanges::Lines(vec![(4, 5)]))
+        );
+    }
+
+    #[test]
+    fn coverage_of_spans() {
+        let changes = FileChanges::from_ranges(vec![(5, 6), (3, 4), (10, 10)]);
+        assert_eq!(changes, FileChanges::Lines(vec![(3, 6), (10, 10)]));
+        assert_eq!(changes.coverage(4, 5), Coverage::Full);
+        assert_eq!(changes.coverage(6, 7), Coverage::Partial);
+        assert_eq!(changes.coverage(7, 9), Coverage::None);
+        assert_eq!(FileChanges::All.coverage(1, 100), Coverage::Full);
+    }
+
+    #[test]
+    fn ranges_follow_corrections() {
+        // Line 2 changed; the correction on it joins two lines into one.
+        let source = SourceFile::from_vec(PathBuf::from("t.rb"), b"a\nb(\n)\nc\nd\n".to_vec());
+        let changes = FileChanges::from_ranges(vec![(2, 3), (5, 5)]);
+        let set = CorrectionSet::from_vec(vec![Correction {
+            start: 3,
+            end: 5,
+            replacement: String::new(),
+            cop_name: "Test/Cop",
+            cop_index: 0,
+        }]);
+        assert_eq!(set.apply(source.as_bytes()), b"a\nb)\nc\nd\n");
+        assert_eq!(
+            changes.after_corrections(&source, &set),
+            FileChanges::Lines(vec![(2, 2), (4, 4)])
+        );
+    }
+}
@@ -2,8 +2,9 @@ use std::path::PathBuf;
 
 use clap::Parser;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum AutocorrectMode {
+    #[default]
     Off,
     /// `-a` / `--autocorrect`: safe corrections only.
     Safe,
@@ -32,8 +33,9 @@ pub struct Args {
     #[arg(short, long, value_name = "FILE")]
     pub config: Option<PathBuf>,
 
-    /// Output format
-    #[arg(short, long, default_value = "progress", value_parser = ["progress", "text", "json", "github", "pacman", "quiet", "files", "emacs", "simple"])]
+    /// Output format (`files0` lists offending files NUL-separated, for `xargs -0`;
+    /// `grouped` lists offenses under a header per file)
+    #[arg(short, long, default_value = "progress", value_parser = ["progress", "text", "json", "github", "pacman", "quiet", "files", "files0", "grouped", "emacs", "simple"])]
     pub format: String,
 
     /// Run only the specified cops (comma-separated)
@@ -48,10 +50,18 @@ pub struct Args {
     #[arg(long)]
     pub no_color: bool,
 
+    /// With --format grouped, list each file's offenses by severity (most severe first), then line
+    #[arg(long)]
+    pub sort_severity: bool,
+
     /// Enable debug output
     #[arg(long)]
     pub debug: bool,
 
+    /// Emit newline-delimited JSON debug events to stderr (see `debug_log` docs)
+    #[arg(long)]
+    pub debug_json: bool,
+
     /// Print comma-separated list of cops not covered by nitrocop, then exit
     #[arg(long)]
     pub rubocop_only: bool,
@@ -64,6 +74,16 @@ pub struct Args {
     #[arg(long)]
     pub list_autocorrectable_cops: bool,
 
+    /// Print the effective config of COPS (comma-separated; all if omitted), noting
+    /// which config file set each setting, then exit
+    #[arg(long, value_name = "COPS", num_args = 0..=1, default_missing_value = "")]
+    pub show_cops: Option<String>,
+
+    /// Print every config file loaded, in load order, with what pulled it in
+    /// and how many cops it configures, then exit (JSON with --format json)
+    #[arg(long)]
+    pub show_config_chain: bool,
+
     /// Analyze config and report cop coverage (no linting), then exit
     #[arg(long)]
     pub migrate: bool,
@@ -76,10 +96,30 @@ pub struct Args {
     #[arg(long)]
     pub rules: bool,
 
-    /// Filter --rules output by tier
+    /// Append a JSON line with this run's offense counts to PATH, for trend tracking
+    #[arg(long, value_name = "PATH", conflicts_with = "stdin")]
+    pub history_file: Option<PathBuf>,
+
+    /// With --history-file, print per-department changes since the previous run
+    #[arg(long, requires = "history_file")]
+    pub history_summary: bool,
+
+    /// Filter --rules and --list-cops output by tier
     #[arg(long, value_name = "TIER", value_parser = ["stable", "preview"])]
     pub tier: Option<String>,
 
+    /// Filter --rules and --list-cops output by department (e.g. `Style`)
+    #[arg(long, value_name = "DEPT")]
+    pub department: Option<String>,
+
+    /// Write a JSON Schema for .rubocop.yml to PATH (stdout if omitted), then exit
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "-")]
+    pub emit_schema: Option<PathBuf>,
+
+    /// Validate the config file and everything it inherits from (no linting), then exit
+    #[arg(long)]
+    pub validate_config: bool,
+
     /// Read source from stdin, use PATH for display and config matching
     #[arg(long, value_name = "PATH")]
     pub stdin: Option<PathBuf>,
@@ -100,10 +140,24 @@ pub struct Args {
     #[arg(long)]
     pub cache_clear: bool,
 
+    /// Re-hash every file instead of trusting an unchanged mtime and size in
+    /// the result cache
+    #[arg(long)]
+    pub paranoid: bool,
+
     /// Minimum severity for a non-zero exit code (convention, warning, error, fatal, or C/W/E/F)
     #[arg(long, value_name = "SEVERITY", default_value = "convention")]
     pub fail_level: String,
 
+    /// Only display offenses at or above --fail-level
+    #[arg(long)]
+    pub display_only_fail_level_offenses: bool,
+
+    /// Hand at most N offenses to the formatter, keeping the most severe
+    /// (the exit status still counts all of them)
+    #[arg(long, value_name = "N")]
+    pub max_reported_offenses: Option<usize>,
+
     /// Stop after first file with offenses
     #[arg(short = 'F', long)]
     pub fail_fast: bool,
@@ -116,6 +170,10 @@ pub struct Args {
     #[arg(short = 'L', long)]
     pub list_target_files: bool,
 
+    /// With -L, mark each file as a result-cache hit or miss and estimate the lint time
+    #[arg(long, requires = "list_target_files")]
+    pub estimate: bool,
+
     /// Display cop names in offense output (accepted for RuboCop compatibility; always enabled)
     #[arg(short = 'D', long)]
     pub display_cop_names: bool,
@@ -124,6 +182,10 @@ pub struct Args {
     #[arg(short = 'P', long)]
     pub parallel: bool,
 
+    /// Deterministic mode: lint files one at a time in sorted path order, without the result cache
+    #[arg(long, conflicts_with = "parallel")]
+    pub no_parallel: bool,
+
     /// Load additional Ruby files (accepted for RuboCop compatibility; ignored)
     #[arg(short = 'r', long = "require")]
     pub require_libs: Vec<String>,
@@ -144,10 +206,41 @@ pub struct Args {
     #[arg(short = 'A', long = "autocorrect-all")]
     pub autocorrect_all: bool,
 
+    /// With -a/-A, only apply corrections on lines changed since REF (default HEAD)
+    #[arg(long, value_name = "REF", num_args = 0..=1, default_missing_value = "HEAD")]
+    pub autocorrect_changed_lines: Option<String>,
+
+    /// Only apply corrections from these cops, including `AutoCorrect: contextual`
+    /// ones (comma-separated; implies -a unless -A is given)
+    #[arg(long, value_delimiter = ',', value_name = "COPS")]
+    pub autocorrect_cop: Vec<String>,
+
+    /// With -a/-A, write corrected files into a mirror tree under DIR and
+    /// leave the originals untouched
+    #[arg(long, value_name = "DIR", conflicts_with = "stdin")]
+    pub copy_to: Option<PathBuf>,
+
+    /// With --copy-to, also copy files that needed no corrections
+    #[arg(long, requires = "copy_to")]
+    pub copy_all: bool,
+
     /// Enable preview-tier cops (unstable, may have false positives)
     #[arg(long)]
     pub preview: bool,
 
+    /// Run these preview-tier cops without --preview (comma-separated or
+    /// repeated; adds to `AllCops: PreviewCops`)
+    #[arg(long, value_delimiter = ',', value_name = "COP")]
+    pub preview_cop: Vec<String>,
+
+    /// Also lint Ruby embedded in `.erb` templates (same as `AllCops: Erb: true`)
+    #[arg(long)]
+    pub erb: bool,
+
+    /// Also lint Ruby code blocks in Markdown files (implied by `require: rubocop-md`)
+    #[arg(long)]
+    pub markdown: bool,
+
     /// Suppress the skip summary notice at the end of a run
     #[arg(long)]
     pub quiet_skips: bool,
@@ -167,6 +260,14 @@ pub struct Args {
     /// Batch corpus check: lint each subdirectory as a separate repo, output per-repo JSON
     #[arg(long, value_name = "DIR")]
     pub corpus_check: Option<PathBuf>,
+
+    /// Suppress offenses recorded in this baseline file; only new offenses are reported
+    #[arg(long, value_name = "FILE")]
+    pub baseline: Option<PathBuf>,
+
+    /// Record all current offenses to a baseline file, then exit 0
+    #[arg(long, value_name = "FILE", conflicts_with = "baseline")]
+    pub baseline_generate: Option<PathBuf>,
 }
 
 impl Args {
@@ -175,7 +276,7 @@ impl Args {
     pub fn autocorrect_mode(&self) -> AutocorrectMode {
         if self.autocorrect_all {
             AutocorrectMode::All
-        } else if self.autocorrect {
+        } else if self.autocorrect || !self.autocorrect_cop.is_empty() {
             AutocorrectMode::Safe
         } else {
             AutocorrectMode::Off
@@ -206,36 +307,59 @@ mod tests {
             only: vec![],
             except: vec![],
             no_color: false,
+            sort_severity: false,
             debug: false,
+            debug_json: false,
             rubocop_only: false,
             list_cops: false,
             list_autocorrectable_cops: false,
+            show_cops: None,
             migrate: false,
             doctor: false,
             rules: false,
             tier: None,
+            department: None,
+            history_file: None,
+            history_summary: false,
+            emit_schema: None,
+            validate_config: false,
             stdin: None,
             init: false,
             no_cache: false,
             cache: "true".to_string(),
             cache_clear: false,
+            paranoid: false,
             fail_level: "convention".to_string(),
+            display_only_fail_level_offenses: false,
+            max_reported_offenses: None,
             fail_fast: false,
             force_exclusion: false,
             list_target_files: false,
+            estimate: false,
+            preview_cop: Vec::new(),
+            show_config_chain: false,
             display_cop_names: false,
             parallel: false,
+            no_parallel: false,
             require_libs: vec![],
             ignore_disable_comments: false,
             force_default_config: false,
             autocorrect: false,
             autocorrect_all: false,
+            autocorrect_changed_lines: None,
+            autocorrect_cop: Vec::new(),
+            copy_to: None,
+            copy_all: false,
             preview: false,
+            erb: false,
+            markdown: false,
             quiet_skips: false,
             strict: val.map(|s| s.to_string()),
             verify: false,
             rubocop_cmd: "bundle exec rubocop".to_string(),
             corpus_check: None,
+            baseline: None,
+            baseline_generate: None,
         }
     }
 
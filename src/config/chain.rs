@@ -0,0 +1,184 @@
+//! The config files behind a resolved config, in load order, for
+//! `--show-config-chain`.
+//!
+//! The recursive loader enters every file through `LoadChain::enter`, which
+//! also does the repeat detection the loader relies on: a file already loaded
+//! through another path (a diamond, e.g. standard's `base.yml` reached both
+//! directly and through a version file) is skipped, and a file that inherits
+//! itself is skipped with a warning naming the cycle. Warnings are collected
+//! rather than printed and end up in `ResolvedConfig::warnings`.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+/// What pulled a config file into the chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadMechanism {
+    /// RuboCop's own `config/default.yml`.
+    Default,
+    /// The project config (`.rubocop.yml`, `--config`, or `.standard.yml`).
+    Local,
+    /// A gem's default config, through `require:`.
+    Require,
+    /// A gem's default config, through `plugins:`.
+    Plugins,
+    InheritGem,
+    InheritFrom,
+    /// A `.rubocop.yml` in a subdirectory, applied to files below it.
+    Nested,
+}
+
+impl LoadMechanism {
+    pub fn label(self) -> &'static str {
+        match self {
+            LoadMechanism::Default => "default",
+            LoadMechanism::Local => "local",
+            LoadMechanism::Require => "require",
+            LoadMechanism::Plugins => "plugins",
+            LoadMechanism::InheritGem => "inherit_gem",
+            LoadMechanism::InheritFrom => "inherit_from",
+            LoadMechanism::Nested => "nested",
+        }
+    }
+}
+
+/// One config file in the chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainEntry {
+    /// Absolute path.
+    pub path: PathBuf,
+    pub mechanism: LoadMechanism,
+    /// 0 for files loaded directly, one more for each inheritance hop.
+    pub depth: usize,
+    /// Cop sections in the file itself, not counting what it inherits.
+    pub cop_keys: usize,
+    /// Already loaded through another path (or part of a cycle), so its
+    /// settings were not applied a second time.
+    pub skipped: bool,
+}
+
+/// Files seen while loading a config, and the files being loaded right now.
+#[derive(Debug, Default)]
+pub(crate) struct LoadChain {
+    /// Absolute paths loaded so far; a repeat is skipped.
+    pub(crate) visited: HashSet<PathBuf>,
+    /// The files currently being loaded, outermost first.
+    stack: Vec<PathBuf>,
+    pub(crate) entries: Vec<ChainEntry>,
+    /// Problems met while loading, without the `warning: ` prefix.
+    pub(crate) warnings: Vec<String>,
+}
+
+impl LoadChain {
+    /// Start loading `path`. Returns its entry index, or `None` when the file
+    /// was already loaded and must be skipped. Every `Some` must be paired
+    /// with a `leave`.
+    pub(crate) fn enter(&mut self, path: &Path, mechanism: LoadMechanism) -> Option<usize> {
+        let abs_path = absolute(path);
+        let first_visit = self.visited.insert(abs_path.clone());
+        if !first_visit {
+            if let Some(cycle) = self.cycle_to(&abs_path) {
+                self.warn(format!(
+                    "circular config inheritance: {cycle} (skipping the repeat)"
+                ));
+            }
+        }
+        self.entries.push(ChainEntry {
+            path: abs_path.clone(),
+            mechanism,
+            depth: self.stack.len(),
+            cop_keys: 0,
+            skipped: !first_visit,
+        });
+        if !first_visit {
+            return None;
+        }
+        self.stack.push(abs_path);
+        Some(self.entries.len() - 1)
+    }
+
+    /// Note a problem that does not stop the config from loading.
+    pub(crate) fn warn(&mut self, message: String) {
+        self.warnings.push(message);
+    }
+
+    /// Finish the file entered last.
+    pub(crate) fn leave(&mut self) {
+        self.stack.pop();
+    }
+
+    /// Note how many cop sections the file at `index` contains.
+    pub(crate) fn set_cop_keys(&mut self, index: usize, cop_keys: usize) {
+        self.entries[index].cop_keys = cop_keys;
+    }
+
+    /// Record a file that is read without following any inheritance.
+    pub(crate) fn record(&mut self, path: &Path, mechanism: LoadMechanism, cop_keys: usize) {
+        self.entries.push(ChainEntry {
+            path: absolute(path),
+            mechanism,
+            depth: self.stack.len(),
+            cop_keys,
+            skipped: false,
+        });
+    }
+
+    /// `a.yml -> b.yml -> a.yml` when `path` is being loaded already.
+    fn cycle_to(&self, path: &Path) -> Option<String> {
+        let start = self.stack.iter().position(|p| p == path)?;
+        let mut hops: Vec<String> = self.stack[start..]
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect();
+        hops.push(path.display().to_string());
+        Some(hops.join(" -> "))
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().unwrap_or_default().join(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_are_skipped_and_cycles_named() {
+        let mut chain = LoadChain::default();
+        let a = Path::new("/cfg/a.yml");
+        let b = Path::new("/cfg/b.yml");
+
+        assert_eq!(chain.enter(a, LoadMechanism::Local), Some(0));
+        assert_eq!(chain.enter(b, LoadMechanism::InheritFrom), Some(1));
+        assert_eq!(
+            chain.cycle_to(a).as_deref(),
+            Some("/cfg/a.yml -> /cfg/b.yml -> /cfg/a.yml")
+        );
+        assert_eq!(chain.enter(a, LoadMechanism::InheritFrom), None);
+        chain.leave();
+        chain.leave();
+
+        // A diamond: b.yml again, after it finished loading
+        assert_eq!(chain.cycle_to(b), None);
+        assert_eq!(chain.enter(b, LoadMechanism::InheritGem), None);
+
+        let summary: Vec<(usize, bool)> =
+            chain.entries.iter().map(|e| (e.depth, e.skipped)).collect();
+        assert_eq!(summary, [(0, false), (1, false), (2, true), (0, true)]);
+        assert_eq!(
+            chain.warnings,
+            [
+                "circular config inheritance: /cfg/a.yml -> /cfg/b.yml -> /cfg/a.yml \
+              (skipping the repeat)"
+            ]
+        );
+    }
+}
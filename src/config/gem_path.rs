@@ -36,6 +36,15 @@ pub fn resolve_gem_path(gem_name: &str, working_dir: &Path) -> Result<PathBuf> {
         if let Some(ref c) = *cache {
             if c.working_dir == working_dir && c.lockfile_mtime == lockfile_mtime {
                 if let Some(path) = c.entries.get(&cache_key) {
+                    crate::debug_log::emit(
+                        "gem_resolved",
+                        serde_json::json!({
+                            "gem": gem_name,
+                            "path": path.display().to_string(),
+                            "duration_ms": 0.0,
+                            "cached": true,
+                        }),
+                    );
                     return Ok(path.clone());
                 }
             }
@@ -119,6 +128,15 @@ pub fn resolve_gem_path(gem_name: &str, working_dir: &Path) -> Result<PathBuf> {
         c.entries.insert(cache_key, path.clone());
     }
 
+    crate::debug_log::emit(
+        "gem_resolved",
+        serde_json::json!({
+            "gem": gem_name,
+            "path": path.display().to_string(),
+            "duration_ms": crate::debug_log::millis(bundle_elapsed),
+            "cached": false,
+        }),
+    );
     Ok(path)
 }
 
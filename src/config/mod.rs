@@ -1,6 +1,9 @@
+pub mod chain;
 pub mod gem_path;
 pub mod lockfile;
+pub mod provenance;
 
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
@@ -10,8 +13,10 @@ use regex::RegexSet;
 use serde_yml::Value;
 
 use crate::cop::registry::CopRegistry;
-use crate::cop::{CopConfig, EnabledState};
+use crate::cop::{Cop, CopConfig, EnabledState};
 use crate::diagnostic::Severity;
+use chain::{ChainEntry, LoadChain, LoadMechanism};
+use provenance::CopProvenance;
 
 /// Policy for handling `Enabled: pending` cops, controlled by `AllCops.NewCops`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,12 +28,17 @@ pub enum NewCopsPolicy {
 /// Department-level configuration (e.g., `RSpec:`, `Rails:`).
 ///
 /// Plugin default configs use bare department keys to set Include/Exclude
-/// patterns and Enabled state for all cops in that department.
+/// patterns and Enabled state for all cops in that department. User configs
+/// may also set a department-wide `Severity:`.
 #[derive(Debug, Clone, Default)]
 struct DepartmentConfig {
     enabled: EnabledState,
+    severity: Option<Severity>,
     include: Vec<String>,
     exclude: Vec<String>,
+    /// rubocop-rspec's `Language:` mapping of DSL method names (RSpec
+    /// department only), passed to each of its cops as the `Language` option.
+    language: Option<Value>,
 }
 
 /// Controls how arrays are merged during config inheritance.
@@ -53,6 +63,13 @@ pub struct CopFilter {
     exclude_set: Option<GlobSet>, // None = exclude no files
     include_re: Option<RegexSet>, // Ruby regexp include patterns
     exclude_re: Option<RegexSet>, // Ruby regexp exclude patterns
+    /// Some Exclude pattern is absolute (`/srv/app/vendor/**`, `~/shared/**`),
+    /// so relative file paths are also tried in canonical absolute form.
+    absolute_exclude: bool,
+    /// Per Include pattern, the path classes (`PATH_*`) every path it
+    /// matches is in. Empty when the Include list is empty or has a pattern
+    /// that constrains no class, i.e. the cop cannot be skipped by class.
+    include_classes: Vec<u8>,
 }
 
 impl CopFilter {
@@ -127,6 +144,57 @@ impl CopFilter {
     }
 }
 
+/// Why a registered cop does not run at all, from
+/// [`ResolvedConfig::disabled_reason`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisabledReason {
+    /// `Enabled: false` on the cop, or disabled in the defaults and never
+    /// enabled.
+    Config,
+    /// `Enabled: false` on its department.
+    Department,
+    /// `Enabled: pending` without `AllCops: NewCops: enable`.
+    Pending,
+    /// `AllCops: DisabledByDefault: true` and nothing enables it.
+    DisabledByDefault,
+    /// Its department's gem is not loaded through `plugins:` or `require:`.
+    PluginNotLoaded { gem: &'static str },
+    /// The installed rubocop (or plugin gem) does not have this cop yet.
+    NotInInstalledVersion,
+    /// A preview cop, without `--preview`.
+    Preview,
+    /// Turned off while the named cop is enabled.
+    ConflictsWith(&'static str),
+}
+
+/// Config-wide facts `disabled_reason` needs, computed once per filter build.
+struct EnablementContext<'a> {
+    /// Departments the installed rubocop gem knows about. Core version
+    /// awareness only applies to these, so cops in custom departments
+    /// (registered by an embedding binary) are not mistaken for core cops
+    /// missing from the installed version.
+    rubocop_known_depts: HashSet<&'a str>,
+    /// Style/RedundantConstantBase disables itself when
+    /// Lint/ConstantResolution is enabled (they have conflicting requirements).
+    lint_constant_resolution_enabled: bool,
+}
+
+impl<'a> EnablementContext<'a> {
+    fn new(config: &'a ResolvedConfig) -> Self {
+        Self {
+            rubocop_known_depts: config
+                .rubocop_known_cops
+                .iter()
+                .filter_map(|c| c.split_once('/').map(|(dept, _)| dept))
+                .collect(),
+            lint_constant_resolution_enabled: config
+                .cop_configs
+                .get("Lint/ConstantResolution")
+                .is_some_and(|c| c.enabled == EnabledState::True),
+        }
+    }
+}
+
 /// Pre-compiled filter set for all cops + global excludes.
 ///
 /// Built once from `ResolvedConfig` + `CopRegistry`, then shared across
@@ -157,6 +225,9 @@ pub struct CopFilterSet {
     universal_cop_indices: Vec<usize>,
     /// Indices of enabled cops that need per-file Include/Exclude pattern matching.
     pattern_cop_indices: Vec<usize>,
+    /// Groups of `pattern_cop_indices` sharing Include path classes, so a
+    /// file's path class rules out whole groups before any glob matching.
+    pattern_cop_groups: PatternCopGroups,
     /// AllCops.MigratedSchemaVersion — when set, files whose basename contains a
     /// 14-digit run that is <= this value have all offenses suppressed.
     /// Implements rubocop-rails' MigrationFileSkippable behavior.
@@ -224,6 +295,17 @@ impl CopFilterSet {
                 }
             }
         }
+        // Absolute patterns (`/srv/app/vendor/**`, `~/shared/**`) from configs
+        // written by other tools only match the absolute form of the path.
+        if self
+            .global_exclude_patterns
+            .iter()
+            .any(|p| is_absolute_pattern(p))
+        {
+            if let Some(abs) = canonical_form(path) {
+                return self.matches_global_exclude_glob(&abs);
+            }
+        }
         false
     }
 
@@ -273,6 +355,23 @@ impl CopFilterSet {
         &self.pattern_cop_indices
     }
 
+    /// The `pattern_cop_indices` whose Include patterns can match `path`'s
+    /// path class, in registry order. Only skips cops that cannot match:
+    /// callers still check each one with `is_cop_match`.
+    pub fn pattern_cop_indices_for(&self, path: &Path) -> impl Iterator<Item = usize> + '_ {
+        let live = self.pattern_cop_groups.live_groups(path_classes(path));
+        self.pattern_cop_indices
+            .iter()
+            .enumerate()
+            .filter(move |&(k, _)| {
+                self.pattern_cop_groups
+                    .group_of
+                    .get(k)
+                    .is_none_or(|&group| live[group])
+            })
+            .map(|(_, &index)| index)
+    }
+
     /// Find the nearest sub-config directory for a file path.
     /// Returns the deepest `.rubocop.yml` directory that is an ancestor of `path`,
     /// falling back to the root `config_dir`.
@@ -350,7 +449,9 @@ impl CopFilterSet {
         let excluded = filter.is_excluded(path)
             || rel_path.is_some_and(|rel| filter.is_excluded(rel))
             || rel_to_base.is_some_and(|rel| filter.is_excluded(rel))
-            || stripped.is_some_and(|s| filter.is_excluded(s));
+            || stripped.is_some_and(|s| filter.is_excluded(s))
+            || (filter.absolute_exclude
+                && canonical_form(path).is_some_and(|abs| filter.is_excluded(&abs)));
         if excluded {
             return false;
         }
@@ -390,6 +491,8 @@ impl CopFilterSet {
             || rel_to_root.is_some_and(|rel| filter.is_excluded(rel))
             || rel_to_base.is_some_and(|rel| filter.is_excluded(rel))
             || stripped.is_some_and(|s| filter.is_excluded(s))
+            || (filter.absolute_exclude
+                && canonical_form(path).is_some_and(|abs| filter.is_excluded(&abs)))
     }
 
     /// Check whether a file path would be matched (not excluded) by a cop's
@@ -492,7 +595,7 @@ fn discover_sub_config_dirs(root: &Path) -> Vec<PathBuf> {
 /// directory, so nested `Enabled`, `DisabledByDefault`, department settings,
 /// and path filters affect execution.
 /// Returns a list of (directory, config_layer) pairs sorted deepest-first.
-fn load_dir_overrides(root: &Path) -> Vec<(PathBuf, ConfigLayer)> {
+fn load_dir_overrides(root: &Path, chain: &mut LoadChain) -> Vec<(PathBuf, ConfigLayer)> {
     let sub_dirs = discover_sub_config_dirs(root);
     let mut overrides = Vec::new();
 
@@ -506,14 +609,15 @@ fn load_dir_overrides(root: &Path) -> Vec<(PathBuf, ConfigLayer)> {
         let raw: Value = match serde_yml::from_str(&contents) {
             Ok(v) => v,
             Err(e) => {
-                eprintln!(
-                    "warning: failed to parse nested config {}: {e}",
+                chain.warn(format!(
+                    "failed to parse nested config {}: {e}",
                     config_path.display()
-                );
+                ));
                 continue;
             }
         };
         let layer = parse_config_layer(&raw);
+        chain.record(&config_path, LoadMechanism::Nested, layer.cop_configs.len());
         let has_effect = !layer.cop_configs.is_empty()
             || !layer.department_configs.is_empty()
             || !layer.global_excludes.is_empty()
@@ -522,7 +626,8 @@ fn load_dir_overrides(root: &Path) -> Vec<(PathBuf, ConfigLayer)> {
             || layer.target_ruby_version.is_some()
             || layer.target_rails_version.is_some()
             || layer.active_support_extensions_enabled.is_some()
-            || layer.migrated_schema_version.is_some();
+            || layer.migrated_schema_version.is_some()
+            || layer.erb.is_some();
         if has_effect {
             overrides.push((dir, layer));
         }
@@ -553,6 +658,158 @@ fn extract_ruby_regexp(s: &str) -> Option<&str> {
     None
 }
 
+/// Expand a leading `~` in a path pattern to the home directory, like Ruby's
+/// `File.expand_path`. Other patterns are returned unchanged.
+fn expand_home(pattern: &str) -> Cow<'_, str> {
+    let Some(rest) = pattern
+        .strip_prefix('~')
+        .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+    else {
+        return Cow::Borrowed(pattern);
+    };
+    match std::env::var("HOME") {
+        Ok(home) if !home.is_empty() => Cow::Owned(format!("{}{rest}", home.trim_end_matches('/'))),
+        _ => Cow::Borrowed(pattern),
+    }
+}
+
+/// Whether a path pattern names an absolute location (`/srv/app/vendor/**`
+/// or `~/shared/**`) rather than one relative to the project.
+fn is_absolute_pattern(pattern: &str) -> bool {
+    (pattern.starts_with('/') || pattern == "~" || pattern.starts_with("~/"))
+        && extract_ruby_regexp(pattern).is_none()
+}
+
+/// The canonical absolute form of `path`, when it differs from `path`.
+/// Lets absolute patterns match files discovered under a relative path.
+fn canonical_form(path: &Path) -> Option<PathBuf> {
+    path.canonicalize().ok().filter(|abs| abs != path)
+}
+
+/// Path classes: a path is in any number of them, and a path in none is
+/// plain Ruby. Coarse on purpose, to rule out whole groups of cops (RSpec,
+/// FactoryBot, Bundler, migration cops) for a file without glob matching.
+///
+/// A `spec`, `test` or `features` directory, or a `*_spec.rb`/`*_test.rb` file.
+const PATH_SPEC: u8 = 1 << 0;
+/// `Gemfile`, `gems.rb` or `*.gemfile`.
+const PATH_GEMFILE: u8 = 1 << 1;
+/// `*.gemspec`.
+const PATH_GEMSPEC: u8 = 1 << 2;
+/// A `db` or `migrate` directory.
+const PATH_MIGRATION: u8 = 1 << 3;
+
+/// The path classes `path` is in. Looks at every component, so a class of
+/// any relativized form of `path` (what `is_cop_match` matches) is included.
+fn path_classes(path: &Path) -> u8 {
+    let mut classes = 0;
+    for component in path.components() {
+        let component = component.as_os_str();
+        if component == "spec" || component == "test" || component == "features" {
+            classes |= PATH_SPEC;
+        } else if component == "db" || component == "migrate" {
+            classes |= PATH_MIGRATION;
+        }
+    }
+    if let Some(name) = path.file_name() {
+        classes |= basename_classes(&name.to_string_lossy(), true);
+    }
+    classes
+}
+
+/// The classes implied by a file name that ends with `suffix`, or that is
+/// exactly `suffix` when `exact`.
+fn basename_classes(suffix: &str, exact: bool) -> u8 {
+    let mut classes = 0;
+    if suffix.ends_with("_spec.rb") || suffix.ends_with("_test.rb") {
+        classes |= PATH_SPEC;
+    }
+    if suffix.ends_with(".gemfile") || (exact && matches!(suffix, "Gemfile" | "gems.rb")) {
+        classes |= PATH_GEMFILE;
+    }
+    if suffix.ends_with(".gemspec") {
+        classes |= PATH_GEMSPEC;
+    }
+    classes
+}
+
+/// The path classes every path matching the Include glob `pattern` is in,
+/// read off its literal directory names and file name suffix. 0 when the
+/// pattern constrains no class (or is a regexp, or uses braces or escapes,
+/// whose alternatives are not analyzed).
+fn include_pattern_classes(pattern: &str) -> u8 {
+    if extract_ruby_regexp(pattern).is_some() || pattern.contains(['{', '}', '\\']) {
+        return 0;
+    }
+    let is_glob = |segment: &str| segment.contains(['*', '?', '[', ']']);
+    let segments: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let Some((&name, _)) = segments.split_last() else {
+        return 0;
+    };
+    let mut classes = 0;
+    for &segment in segments.iter().filter(|s| !is_glob(s)) {
+        classes |= path_classes(Path::new(segment)) & (PATH_SPEC | PATH_MIGRATION);
+    }
+    if !is_glob(name) {
+        classes |= basename_classes(name, true);
+    } else if let Some((_, tail)) = name.rsplit_once('*').filter(|(_, tail)| !is_glob(tail)) {
+        // `*` stops at `/`, so the file name ends with the literal tail.
+        classes |= basename_classes(tail, false);
+    }
+    classes
+}
+
+/// [`include_pattern_classes`] for each Include pattern of a cop, or empty
+/// when some pattern is unconstrained.
+fn include_classes(patterns: &[&str]) -> Vec<u8> {
+    let mut classes: Vec<u8> = patterns
+        .iter()
+        .map(|p| include_pattern_classes(p))
+        .collect();
+    if classes.contains(&0) {
+        return Vec::new();
+    }
+    classes.sort_unstable();
+    classes.dedup();
+    classes
+}
+
+/// Pattern cops grouped by their Include path classes.
+#[derive(Debug, Default)]
+struct PatternCopGroups {
+    /// Each group's Include classes (see `CopFilter::include_classes`).
+    classes: Vec<Vec<u8>>,
+    /// The group of each entry of `pattern_cop_indices`.
+    group_of: Vec<usize>,
+}
+
+impl PatternCopGroups {
+    fn new(filters: &[CopFilter], pattern_cop_indices: &[usize]) -> Self {
+        let mut groups = Self::default();
+        for &index in pattern_cop_indices {
+            let classes = &filters[index].include_classes;
+            let group = match groups.classes.iter().position(|c| c == classes) {
+                Some(group) => group,
+                None => {
+                    groups.classes.push(classes.clone());
+                    groups.classes.len() - 1
+                }
+            };
+            groups.group_of.push(group);
+        }
+        groups
+    }
+
+    /// Per group, whether a path in `path_classes` can match its cops: it is
+    /// in all the classes of one of the group's Include patterns.
+    fn live_groups(&self, path_classes: u8) -> Vec<bool> {
+        self.classes
+            .iter()
+            .map(|classes| classes.is_empty() || classes.iter().any(|&c| c & !path_classes == 0))
+            .collect()
+    }
+}
+
 /// Build a `GlobSet` from a list of pattern strings, skipping any that are
 /// Ruby regexp patterns (these are handled separately by `build_regex_set`).
 /// Returns `None` if no glob patterns remain.
@@ -566,7 +823,10 @@ fn build_glob_set(patterns: &[&str]) -> Option<GlobSet> {
         if extract_ruby_regexp(pat).is_some() {
             continue; // Skip regex patterns — handled by build_regex_set
         }
-        if let Ok(glob) = GlobBuilder::new(pat).literal_separator(true).build() {
+        if let Ok(glob) = GlobBuilder::new(&expand_home(pat))
+            .literal_separator(true)
+            .build()
+        {
             builder.add(glob);
             count += 1;
         }
@@ -604,6 +864,8 @@ pub struct ResolvedConfig {
     /// Department-level configs keyed by department name (e.g. "RSpec", "Rails")
     department_configs: HashMap<String, DepartmentConfig>,
     global_excludes: Vec<String>,
+    /// The config file that was loaded; None when running on defaults.
+    config_path: Option<PathBuf>,
     /// Directory containing the resolved config file (for relative path resolution).
     config_dir: Option<PathBuf>,
     /// How to handle `Enabled: pending` cops.
@@ -665,6 +927,22 @@ pub struct ResolvedConfig {
     /// have ALL offenses suppressed (rubocop-rails' MigrationFileSkippable).
     /// Default sentinel from rubocop-rails: `'19700101000000'`.
     migrated_schema_version: Option<String>,
+    /// AllCops.Erb (or `--erb`): discover `.erb` templates and lint the Ruby
+    /// embedded in them.
+    erb: bool,
+    /// `require: rubocop-md` (or `--markdown`): discover Markdown files and
+    /// lint their Ruby code blocks.
+    markdown: bool,
+    /// AllCops.PreviewCops: preview-tier cops this project runs without
+    /// `--preview`.
+    preview_cops: Vec<String>,
+    /// Which file set each cop setting; empty unless provenance tracking is
+    /// on (`--show-cops`).
+    provenance: HashMap<String, CopProvenance>,
+    /// Every config file read, in load order (`--show-config-chain`).
+    config_chain: Vec<ChainEntry>,
+    /// Problems met while loading that did not stop it.
+    warnings: Vec<String>,
 }
 
 impl ResolvedConfig {
@@ -673,6 +951,7 @@ impl ResolvedConfig {
             cop_configs: HashMap::new(),
             department_configs: HashMap::new(),
             global_excludes: Vec::new(),
+            config_path: None,
             config_dir: None,
             new_cops: NewCopsPolicy::Disable,
             disabled_by_default: false,
@@ -690,6 +969,31 @@ impl ResolvedConfig {
             rack_version: None,
             base_dir: None,
             migrated_schema_version: None,
+            erb: false,
+            markdown: false,
+            preview_cops: Vec::new(),
+            provenance: HashMap::new(),
+            config_chain: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// The config of a `--force-default-config` run: built-in cop defaults
+    /// and RuboCop's default AllCops.Exclude. Like any config that is not a
+    /// `.rubocop*` file, the patterns resolve against the working directory.
+    pub fn defaults_only() -> Self {
+        let base_dir = std::env::current_dir().ok();
+        let mut chain = LoadChain::default();
+        let global_excludes = match &base_dir {
+            Some(dir) => default_global_excludes(dir, None, &mut chain),
+            None => fallback_default_excludes().global_excludes,
+        };
+        Self {
+            global_excludes,
+            base_dir,
+            config_chain: chain.entries,
+            warnings: chain.warnings,
+            ..Self::empty()
         }
     }
 
@@ -711,6 +1015,8 @@ impl ResolvedConfig {
 #[derive(Debug, Clone)]
 struct ConfigLayer {
     cop_configs: HashMap<String, CopConfig>,
+    /// Which file set each cop setting, when provenance tracking is on.
+    provenance: HashMap<String, CopProvenance>,
     department_configs: HashMap<String, DepartmentConfig>,
     global_excludes: Vec<String>,
     new_cops: Option<String>,
@@ -741,12 +1047,19 @@ struct ConfigLayer {
     /// When set, files whose basename contains a 14+ digit "timestamp" <= this value
     /// have ALL offenses suppressed (MigrationFileSkippable).
     migrated_schema_version: Option<String>,
+    /// AllCops.Erb: lint Ruby embedded in `.erb` templates.
+    erb: Option<bool>,
+    /// AllCops.PreviewCops: preview-tier cops promoted out of the `--preview` gate.
+    preview_cops: Option<Vec<String>>,
+    /// Whether `rubocop-md` is in `require:`/`plugins:`.
+    markdown: bool,
 }
 
 impl ConfigLayer {
     fn empty() -> Self {
         Self {
             cop_configs: HashMap::new(),
+            provenance: HashMap::new(),
             department_configs: HashMap::new(),
             global_excludes: Vec::new(),
             new_cops: None,
@@ -762,6 +1075,9 @@ impl ConfigLayer {
             target_rails_version: None,
             active_support_extensions_enabled: None,
             migrated_schema_version: None,
+            erb: None,
+            preview_cops: None,
+            markdown: false,
         }
     }
 }
@@ -810,6 +1126,20 @@ fn find_config(start_dir: &Path) -> Option<PathBuf> {
     walk_up_for(start_dir, ".standard.yml")
 }
 
+/// The config file `load_config` starts from: `path` if given, otherwise the
+/// nearest config file above `target_dir` (or the current directory).
+pub fn find_config_path(path: Option<&Path>, target_dir: Option<&Path>) -> Option<PathBuf> {
+    if let Some(p) = path {
+        return Some(p.to_path_buf());
+    }
+    let start_dir = match target_dir {
+        Some(p) if p.is_file() => p.parent().unwrap_or(p).to_path_buf(),
+        Some(p) => p.to_path_buf(),
+        None => std::env::current_dir().ok()?,
+    };
+    find_config(&start_dir)
+}
+
 /// Convert a `.standard.yml` file into a synthetic `.rubocop.yml`-compatible
 /// YAML string. This allows the existing config loading pipeline to handle
 /// pure-standardrb projects without modification.
@@ -999,12 +1329,16 @@ pub fn load_config(
             // which includes these excludes, regardless of whether a project has
             // .rubocop.yml. Without this, repos without config files get zero file
             // exclusion, causing false positives on vendored code.
-            let defaults = fallback_default_excludes();
+            let mut chain = LoadChain::default();
+            let global_excludes = default_global_excludes(&config_dir, gem_cache, &mut chain);
+            let dir_overrides = load_dir_overrides(&config_dir, &mut chain);
             return Ok(ResolvedConfig {
                 config_dir: Some(config_dir.clone()),
-                dir_overrides: load_dir_overrides(&config_dir),
+                dir_overrides,
+                config_chain: chain.entries,
+                warnings: chain.warnings,
                 base_dir: Some(base_dir),
-                global_excludes: defaults.global_excludes,
+                global_excludes,
                 ..ResolvedConfig::empty()
             });
         }
@@ -1037,9 +1371,10 @@ pub fn load_config(
     // Load rubocop's own config/default.yml as the lowest-priority base layer.
     // This provides correct default Enabled states, EnforcedStyle values, etc.
     // Also collect the set of known cops for version awareness.
-    let (mut base, rubocop_known_cops) = try_load_rubocop_defaults(&config_dir, gem_cache);
+    let mut chain = LoadChain::default();
+    let (mut base, rubocop_known_cops) =
+        try_load_rubocop_defaults(&config_dir, gem_cache, &mut chain);
 
-    let mut visited = HashSet::new();
     let is_standard_yml = config_path
         .file_name()
         .is_some_and(|f| f == ".standard.yml");
@@ -1049,12 +1384,19 @@ pub fn load_config(
         load_config_recursive_inner(
             &config_path,
             &config_dir,
-            &mut visited,
+            &mut chain,
+            LoadMechanism::Local,
             gem_cache,
             Some(&synthetic_yaml),
         )?
     } else {
-        load_config_recursive(&config_path, &config_dir, &mut visited, gem_cache)?
+        load_config_recursive(
+            &config_path,
+            &config_dir,
+            &mut chain,
+            LoadMechanism::Local,
+            gem_cache,
+        )?
     };
 
     // Collect cop/department names explicitly mentioned in user config files
@@ -1078,6 +1420,10 @@ pub fn load_config(
         .map(|(name, _)| name.clone())
         .collect();
 
+    for warning in deprecated_parameter_warnings(&project_layer.cop_configs) {
+        chain.warn(warning);
+    }
+
     // Merge project config on top of rubocop defaults
     merge_layer_into(&mut base, &project_layer, None);
 
@@ -1092,6 +1438,9 @@ pub fn load_config(
         for (cop_name, cop_cfg) in base.cop_configs.iter_mut() {
             if cop_cfg.enabled == EnabledState::True && !project_mentioned_cops.contains(cop_name) {
                 cop_cfg.enabled = EnabledState::Unset;
+                if let Some(prov) = base.provenance.get_mut(cop_name) {
+                    prov.settings.remove("Enabled");
+                }
             }
         }
         for (dept_name, dept_cfg) in base.department_configs.iter_mut() {
@@ -1179,12 +1528,14 @@ pub fn load_config(
     }
 
     // Discover and parse nested .rubocop.yml files for per-directory config layers.
-    let dir_overrides = load_dir_overrides(&config_dir);
+    let dir_overrides = load_dir_overrides(&config_dir, &mut chain);
 
     Ok(ResolvedConfig {
         cop_configs: base.cop_configs,
+        provenance: base.provenance,
         department_configs: base.department_configs,
         global_excludes: base.global_excludes,
+        config_path: Some(config_path),
         config_dir: Some(config_dir),
         new_cops: match base.new_cops.as_deref() {
             Some("enable") => NewCopsPolicy::Enable,
@@ -1206,21 +1557,57 @@ pub fn load_config(
         rack_version,
         base_dir: Some(base_dir),
         migrated_schema_version: base.migrated_schema_version,
+        erb: base.erb.unwrap_or(false),
+        markdown: base.markdown,
+        preview_cops: base.preview_cops.unwrap_or_default(),
+        config_chain: chain.entries,
+        warnings: chain.warnings,
     })
 }
 
-/// Try to load rubocop's own `config/default.yml` as the base config layer.
-///
-/// This provides correct default Enabled states (52 cops disabled by default),
-/// EnforcedStyle values, and other option defaults for all cops. Returns an
-/// empty layer if the rubocop gem is not installed or the file can't be parsed.
-///
-/// Also returns the set of all cop names found in the installed gem's config,
-/// used for core cop version awareness (cops not in the installed gem don't exist).
+/// Cop parameters that were renamed but are still honored as aliases, as
+/// `(cops, parameter, replacement)`. Mirrors the `changed_parameters` entries
+/// with `severity: warning` in RuboCop's `config/obsoletion.yml`.
+const DEPRECATED_COP_PARAMETERS: &[(&[&str], &str, &str)] = &[
+    (
+        &["Metrics/BlockLength", "Metrics/MethodLength"],
+        "ExcludedMethods",
+        "`AllowedMethods` and/or `AllowedPatterns`",
+    ),
+    (
+        &["Metrics/BlockLength", "Metrics/MethodLength"],
+        "IgnoredMethods",
+        "`AllowedMethods` and/or `AllowedPatterns`",
+    ),
+    (
+        &["Metrics/BlockLength", "Metrics/MethodLength"],
+        "IgnoredPatterns",
+        "`AllowedPatterns`",
+    ),
+];
+
+/// Warnings for deprecated cop parameters set in the project's config.
+fn deprecated_parameter_warnings(cop_configs: &HashMap<String, CopConfig>) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for (cops, parameter, replacement) in DEPRECATED_COP_PARAMETERS {
+        for cop in *cops {
+            if cop_configs
+                .get(*cop)
+                .is_some_and(|cfg| cfg.options.contains_key(*parameter))
+            {
+                warnings.push(format!(
+                    "obsolete parameter `{parameter}` (for `{cop}`) found in config; \
+                     it has been renamed to {replacement}"
+                ));
+            }
+        }
+    }
+    warnings
+}
+
 /// RuboCop's built-in AllCops.Exclude defaults. Used as a fallback when the
 /// rubocop gem can't be found (e.g., corpus repos without their own bundle).
 /// These match the patterns in rubocop's `config/default.yml`.
-/// Minimal set of patterns matching rubocop's config/default.yml AllCops.Exclude.
 fn fallback_default_excludes() -> ConfigLayer {
     let mut layer = ConfigLayer::empty();
     layer.global_excludes = vec![
@@ -1232,9 +1619,36 @@ fn fallback_default_excludes() -> ConfigLayer {
     layer
 }
 
+/// RuboCop's default AllCops.Exclude, for runs without a project config:
+/// the installed gem's `config/default.yml` when there is one, otherwise the
+/// built-in fallback list, so both cases skip the same directories.
+fn default_global_excludes(
+    working_dir: &Path,
+    gem_cache: Option<&HashMap<String, PathBuf>>,
+    chain: &mut LoadChain,
+) -> Vec<String> {
+    let (layer, _) = try_load_rubocop_defaults(working_dir, gem_cache, chain);
+    if layer.global_excludes.is_empty() {
+        fallback_default_excludes().global_excludes
+    } else {
+        layer.global_excludes
+    }
+}
+
+/// Try to load rubocop's own `config/default.yml` as the base config layer.
+///
+/// This provides correct default Enabled states (52 cops disabled by default),
+/// EnforcedStyle values, and other option defaults for all cops, and the
+/// default AllCops.Exclude that project excludes are appended to. Returns a
+/// layer with just the fallback excludes if the rubocop gem is not installed
+/// or the file can't be parsed.
+///
+/// Also returns the set of all cop names found in the installed gem's config,
+/// used for core cop version awareness (cops not in the installed gem don't exist).
 fn try_load_rubocop_defaults(
     working_dir: &Path,
     gem_cache: Option<&HashMap<String, PathBuf>>,
+    chain: &mut LoadChain,
 ) -> (ConfigLayer, HashSet<String>) {
     let gem_root = if let Some(path) = gem_cache.and_then(|c| c.get("rubocop")) {
         path.clone()
@@ -1261,10 +1675,10 @@ fn try_load_rubocop_defaults(
     let raw: Value = match serde_yml::from_str(&contents) {
         Ok(v) => v,
         Err(e) => {
-            eprintln!(
-                "warning: failed to parse rubocop default config {}: {e}",
+            chain.warn(format!(
+                "failed to parse rubocop default config {}: {e}",
                 default_config.display()
-            );
+            ));
             return (fallback_default_excludes(), HashSet::new());
         }
     };
@@ -1280,49 +1694,92 @@ fn try_load_rubocop_defaults(
         HashSet::new()
     };
 
-    (parse_config_layer(&raw), known_cops)
+    let mut layer = parse_config_layer(&raw);
+    record_provenance(&mut layer, &default_config);
+    chain.record(
+        &default_config,
+        LoadMechanism::Default,
+        layer.cop_configs.len(),
+    );
+    (layer, known_cops)
+}
+
+/// Attribute every cop setting in `layer` to `path`, if provenance tracking is on.
+fn record_provenance(layer: &mut ConfigLayer, path: &Path) {
+    if !provenance::enabled() {
+        return;
+    }
+    layer.provenance = layer
+        .cop_configs
+        .iter()
+        .map(|(name, config)| (name.clone(), CopProvenance::from_config(config, path)))
+        .collect();
 }
 
 /// Recursively load a config file and all its inherited configs.
 ///
 /// `working_dir` is the top-level config directory used for gem path resolution
 /// (where `Gemfile.lock` typically lives).
-/// `visited` tracks absolute paths to detect circular inheritance.
+/// `chain` records every file loaded, with the `mechanism` that pulled it in,
+/// and skips files loaded already (diamonds and cycles).
 /// `override_contents` — if Some, use this YAML string instead of reading from disk
 /// (used for synthetic configs generated from `.standard.yml`).
 fn load_config_recursive(
     config_path: &Path,
     working_dir: &Path,
-    visited: &mut HashSet<PathBuf>,
+    chain: &mut LoadChain,
+    mechanism: LoadMechanism,
     gem_cache: Option<&HashMap<String, PathBuf>>,
 ) -> Result<ConfigLayer> {
-    load_config_recursive_inner(config_path, working_dir, visited, gem_cache, None)
+    load_config_recursive_inner(config_path, working_dir, chain, mechanism, gem_cache, None)
 }
 
 fn load_config_recursive_inner(
     config_path: &Path,
     working_dir: &Path,
-    visited: &mut HashSet<PathBuf>,
+    chain: &mut LoadChain,
+    mechanism: LoadMechanism,
     gem_cache: Option<&HashMap<String, PathBuf>>,
     override_contents: Option<&str>,
 ) -> Result<ConfigLayer> {
-    let abs_path = if config_path.is_absolute() {
-        config_path.to_path_buf()
-    } else {
-        std::env::current_dir()
-            .unwrap_or_default()
-            .join(config_path)
-    };
-
     // Diamond dependency detection: if this file was already loaded via a different
     // inheritance path, return an empty layer. This handles cases like standard's
     // base.yml being referenced both directly (inherit_gem: standard: config/base.yml)
-    // and indirectly (ruby-3.3.yml -> inherit_from: ./base.yml). True circular
-    // inheritance can't happen because we return early before recursing.
-    if !visited.insert(abs_path.clone()) {
+    // and indirectly (ruby-3.3.yml -> inherit_from: ./base.yml). A true cycle
+    // (a file inheriting itself) is broken the same way, with a warning.
+    let Some(index) = chain.enter(config_path, mechanism) else {
         return Ok(ConfigLayer::empty());
-    }
+    };
+    let start = std::time::Instant::now();
+    let layer = load_config_layer(
+        config_path,
+        working_dir,
+        chain,
+        index,
+        gem_cache,
+        override_contents,
+    );
+    chain.leave();
+    let layer = layer?;
+    crate::debug_log::emit(
+        "config_layer_loaded",
+        serde_json::json!({
+            "path": config_path.display().to_string(),
+            "duration_ms": crate::debug_log::millis(start.elapsed()),
+        }),
+    );
+    Ok(layer)
+}
 
+/// Load one config file entered as `chain` entry `index`.
+fn load_config_layer(
+    config_path: &Path,
+    working_dir: &Path,
+    chain: &mut LoadChain,
+    index: usize,
+    gem_cache: Option<&HashMap<String, PathBuf>>,
+    override_contents: Option<&str>,
+) -> Result<ConfigLayer> {
     let contents = if let Some(s) = override_contents {
         s.to_string()
     } else {
@@ -1362,18 +1819,24 @@ fn load_config_recursive_inner(
         //    A project may have both keys (e.g., `plugins: [rubocop-rspec]` and
         //    `require: [./custom_cop.rb]`), so we must process both.
         let mut gems = Vec::new();
-        for key in &["plugins", "require"] {
+        // What listed each gem, for the config chain
+        let mut gem_mechanisms: HashMap<String, LoadMechanism> = HashMap::new();
+        for (key, mechanism) in [
+            ("plugins", LoadMechanism::Plugins),
+            ("require", LoadMechanism::Require),
+        ] {
             if let Some(val) = map.get(Value::String(key.to_string())) {
-                match val {
-                    Value::String(s) => gems.push(s.clone()),
-                    Value::Sequence(seq) => {
-                        for v in seq {
-                            if let Some(s) = v.as_str() {
-                                gems.push(s.to_string());
-                            }
-                        }
-                    }
-                    _ => {}
+                let listed: Vec<String> = match val {
+                    Value::String(s) => vec![s.clone()],
+                    Value::Sequence(seq) => seq
+                        .iter()
+                        .filter_map(|v| v.as_str().map(String::from))
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                for gem in listed {
+                    gem_mechanisms.entry(gem.clone()).or_insert(mechanism);
+                    gems.push(gem);
                 }
             }
         }
@@ -1410,6 +1873,9 @@ fn load_config_recursive_inner(
             }
             // Insert in reverse order to maintain correct indices
             for (i, gem) in injected.into_iter().rev() {
+                if let Some(&mechanism) = gem_mechanisms.get(&gems[i]) {
+                    gem_mechanisms.insert(gem.clone(), mechanism);
+                }
                 gems.insert(i, gem);
             }
         }
@@ -1422,10 +1888,14 @@ fn load_config_recursive_inner(
         // inherit_gem load is skipped (file already visited), and cops
         // from that config stay in require_enabled_cops — incorrectly
         // causing them to be disabled under DisabledByDefault.
-        let visited_before_require = visited.clone();
+        let visited_before_require = chain.visited.clone();
 
         if !gems.is_empty() {
             for gem_name in &gems {
+                let mechanism = gem_mechanisms
+                    .get(gem_name)
+                    .copied()
+                    .unwrap_or(LoadMechanism::Require);
                 // Determine what config file to load for this gem.
                 // rubocop-* gems use config/default.yml.
                 // standard-family gems use version-specific or base config.
@@ -1445,7 +1915,7 @@ fn load_config_recursive_inner(
                     match gem_path::resolve_gem_path(gem_name, working_dir) {
                         Ok(p) => p,
                         Err(e) => {
-                            eprintln!("warning: require '{}': {e:#}", gem_name);
+                            chain.warn(format!("require '{}': {e:#}", gem_name));
                             continue;
                         }
                     }
@@ -1457,21 +1927,27 @@ fn load_config_recursive_inner(
                     if !gem_name.starts_with("rubocop-") {
                         let fallback = gem_root.join("config").join("base.yml");
                         if fallback.exists() {
-                            match load_config_recursive(&fallback, working_dir, visited, gem_cache)
-                            {
+                            match load_config_recursive(
+                                &fallback,
+                                working_dir,
+                                chain,
+                                mechanism,
+                                gem_cache,
+                            ) {
                                 Ok(layer) => merge_layer_into(&mut base_layer, &layer, None),
                                 Err(e) => {
-                                    eprintln!(
-                                        "warning: failed to load default config for {}: {e:#}",
+                                    chain.warn(format!(
+                                        "failed to load default config for {}: {e:#}",
                                         gem_name
-                                    );
+                                    ));
                                 }
                             }
                         }
                     }
                     continue;
                 }
-                match load_config_recursive(&config_file, working_dir, visited, gem_cache) {
+                match load_config_recursive(&config_file, working_dir, chain, mechanism, gem_cache)
+                {
                     // WARNING: Do NOT make gem Exclude patterns absolute relative to
                     // the gem's config directory. Gem default configs (e.g., rubocop's
                     // config/default.yml) contain Exclude patterns like `spec/**/*`
@@ -1487,20 +1963,20 @@ fn load_config_recursive_inner(
                     Ok(mut layer) => {
                         // RuboCop 1.84+ plugins: / require: does NOT merge
                         // AllCops.Exclude from plugin gem defaults into the
-                        // project config. Only per-cop settings are loaded.
-                        // rubocop-rails' default.yml has AllCops.Exclude patterns
-                        // (db/*schema.rb, **/app/assets/**/*) that would incorrectly
-                        // suppress offenses on those files if merged.
+                        // project-wide excludes: rubocop-rails' db/*schema.rb
+                        // must not silence Layout cops. The patterns still
+                        // apply to the plugin's own cops. See
+                        // scope_plugin_excludes.
                         if gem_name.starts_with("rubocop-") {
-                            layer.global_excludes.clear();
+                            scope_plugin_excludes(&mut layer);
                         }
                         merge_layer_into(&mut base_layer, &layer, None);
                     }
                     Err(e) => {
-                        eprintln!(
-                            "warning: failed to load default config for {}: {e:#}",
+                        chain.warn(format!(
+                            "failed to load default config for {}: {e:#}",
                             gem_name
-                        );
+                        ));
                     }
                 }
             }
@@ -1525,7 +2001,7 @@ fn load_config_recursive_inner(
 
         // Restore visited set so inherit_gem: can re-load files that were
         // also loaded by require:. See comment above visited_before_require.
-        *visited = visited_before_require;
+        chain.visited = visited_before_require;
 
         // Track ALL cops mentioned in require: gem configs (for version awareness).
         // Cops from plugin departments not in this set don't exist in the
@@ -1544,13 +2020,15 @@ fn load_config_recursive_inner(
                 }
             }
         }
+        if gems.iter().any(|g| g == "rubocop-md") {
+            base_layer.markdown = true;
+        }
 
         // 1. Process inherit_gem
         if let Some(Value::Mapping(gem_map)) = map.get(Value::String("inherit_gem".to_string())) {
             for (gem_key, gem_paths) in gem_map {
                 if let Some(gem_name) = gem_key.as_str() {
-                    match resolve_inherit_gem(gem_name, gem_paths, working_dir, visited, gem_cache)
-                    {
+                    match resolve_inherit_gem(gem_name, gem_paths, working_dir, chain, gem_cache) {
                         Ok(gem_layers) => {
                             for layer in gem_layers {
                                 // Propagate user_mentioned from the layer's recursive loading.
@@ -1568,7 +2046,7 @@ fn load_config_recursive_inner(
                             // Match RuboCop: warn and continue loading the rest
                             // of the config. The gem's config layer is skipped,
                             // but local overrides (Max, Exclude, etc.) still apply.
-                            eprintln!("warning: {e:#}");
+                            chain.warn(format!("{e:#}"));
                         }
                     }
                 }
@@ -1589,14 +2067,20 @@ fn load_config_recursive_inner(
             for rel_path in &paths {
                 let inherited_path = config_dir.join(rel_path);
                 if !inherited_path.exists() {
-                    eprintln!(
-                        "warning: inherit_from target not found: {} (from {})",
+                    chain.warn(format!(
+                        "inherit_from target not found: {} (from {})",
                         inherited_path.display(),
                         config_path.display()
-                    );
+                    ));
                     continue;
                 }
-                match load_config_recursive(&inherited_path, working_dir, visited, gem_cache) {
+                match load_config_recursive(
+                    &inherited_path,
+                    working_dir,
+                    chain,
+                    LoadMechanism::InheritFrom,
+                    gem_cache,
+                ) {
                     Ok(layer) => {
                         // Propagate user_mentioned from the layer's recursive loading.
                         // Don't use cop_configs.keys() — that includes require: defaults.
@@ -1609,10 +2093,10 @@ fn load_config_recursive_inner(
                         merge_layer_into(&mut base_layer, &layer, None);
                     }
                     Err(e) => {
-                        eprintln!(
-                            "warning: failed to load inherited config {}: {e:#}",
+                        chain.warn(format!(
+                            "failed to load inherited config {}: {e:#}",
                             inherited_path.display()
-                        );
+                        ));
                     }
                 }
             }
@@ -1620,7 +2104,9 @@ fn load_config_recursive_inner(
     }
 
     // 3. Parse the local config layer and merge it on top (highest priority)
-    let local_layer = parse_config_layer(&raw);
+    let mut local_layer = parse_config_layer(&raw);
+    record_provenance(&mut local_layer, config_path);
+    chain.set_cop_keys(index, local_layer.cop_configs.len());
     // Track cops from the local config file as user-mentioned
     base_layer
         .user_mentioned_cops
@@ -1637,6 +2123,40 @@ fn load_config_recursive_inner(
     Ok(base_layer)
 }
 
+/// Move a plugin default config's `AllCops.Exclude` onto the departments it
+/// configures, so the patterns skip files for the plugin's cops only.
+///
+/// Department configs sit under cop configs (RuboCop's `for_badge` merge), so
+/// a cop whose own `Exclude` would hide the department one gets the patterns
+/// appended as well.
+fn scope_plugin_excludes(layer: &mut ConfigLayer) {
+    let excludes = std::mem::take(&mut layer.global_excludes);
+    if excludes.is_empty() {
+        return;
+    }
+    let departments: HashSet<String> = layer
+        .cop_configs
+        .keys()
+        .filter_map(|name| name.split_once('/').map(|(dept, _)| dept.to_string()))
+        .chain(layer.department_configs.keys().cloned())
+        .collect();
+    let append = |target: &mut Vec<String>| {
+        for exc in &excludes {
+            if !target.contains(exc) {
+                target.push(exc.clone());
+            }
+        }
+    };
+    for dept in departments {
+        append(&mut layer.department_configs.entry(dept).or_default().exclude);
+    }
+    for cop_config in layer.cop_configs.values_mut() {
+        if !cop_config.exclude.is_empty() {
+            append(&mut cop_config.exclude);
+        }
+    }
+}
+
 /// Resolve `inherit_gem` entries. Each gem name maps to one or more YAML paths
 /// relative to the gem's root directory.
 ///
@@ -1647,7 +2167,7 @@ fn resolve_inherit_gem(
     gem_name: &str,
     paths_value: &Value,
     working_dir: &Path,
-    visited: &mut HashSet<PathBuf>,
+    chain: &mut LoadChain,
     gem_cache: Option<&HashMap<String, PathBuf>>,
 ) -> Result<Vec<ConfigLayer>> {
     let gem_root = if let Some(path) = gem_cache.and_then(|c| c.get(gem_name)) {
@@ -1679,8 +2199,14 @@ fn resolve_inherit_gem(
                 full_path.display(),
             );
         }
-        match load_config_recursive(&full_path, working_dir, visited, gem_cache) {
-            // See WARNING in load_config_recursive_inner — do NOT make excludes
+        match load_config_recursive(
+            &full_path,
+            working_dir,
+            chain,
+            LoadMechanism::InheritGem,
+            gem_cache,
+        ) {
+            // See WARNING in load_config_layer — do NOT make excludes
             // absolute relative to the gem config dir. Patterns are project-relative.
             Ok(layer) => layers.push(layer),
             Err(e) => {
@@ -1708,6 +2234,8 @@ fn parse_config_layer(raw: &Value) -> ConfigLayer {
     let mut target_rails_version = None;
     let mut active_support_extensions_enabled = None;
     let mut migrated_schema_version: Option<String> = None;
+    let mut erb = None;
+    let mut preview_cops = None;
 
     if let Value::Mapping(map) = raw {
         for (key, value) in map {
@@ -1766,6 +2294,12 @@ fn parse_config_layer(raw: &Value) -> ConfigLayer {
                                 .or_else(|| msv.as_u64().map(|u| u.to_string()))
                                 .or_else(|| msv.as_i64().map(|i| i.to_string()));
                         }
+                        if let Some(e) = ac_map.get(Value::String("Erb".to_string())) {
+                            erb = e.as_bool();
+                        }
+                        if let Some(p) = ac_map.get(Value::String("PreviewCops".to_string())) {
+                            preview_cops = value_to_string_list(p);
+                        }
                     }
                     continue;
                 }
@@ -1786,6 +2320,7 @@ fn parse_config_layer(raw: &Value) -> ConfigLayer {
 
     ConfigLayer {
         cop_configs,
+        provenance: HashMap::new(),
         department_configs,
         global_excludes,
         new_cops,
@@ -1801,6 +2336,9 @@ fn parse_config_layer(raw: &Value) -> ConfigLayer {
         target_rails_version,
         active_support_extensions_enabled,
         migrated_schema_version,
+        erb,
+        preview_cops,
+        markdown: false,
     }
 }
 
@@ -1868,6 +2406,19 @@ fn merge_layer_into(
             .clone_from(&overlay.migrated_schema_version);
     }
 
+    // Erb: last writer wins
+    if overlay.erb.is_some() {
+        base.erb = overlay.erb;
+    }
+
+    // PreviewCops: last writer wins
+    if overlay.preview_cops.is_some() {
+        base.preview_cops.clone_from(&overlay.preview_cops);
+    }
+
+    // rubocop-md required by any layer
+    base.markdown |= overlay.markdown;
+
     // Merge department configs
     for (dept_name, overlay_dept) in &overlay.department_configs {
         match base.department_configs.get_mut(dept_name) {
@@ -1892,6 +2443,12 @@ fn merge_layer_into(
                     .insert(cop_name.clone(), overlay_config.clone());
             }
         }
+        if let Some(overlay_prov) = overlay.provenance.get(cop_name) {
+            base.provenance
+                .entry(cop_name.clone())
+                .or_default()
+                .merge(overlay_prov, &base.cop_configs[cop_name]);
+        }
         // Track require-originated enabled state through merges.
         if overlay.require_enabled_cops.contains(cop_name) {
             base.require_enabled_cops.insert(cop_name.clone());
@@ -1928,6 +2485,15 @@ fn merge_department_config(
     if overlay.enabled != EnabledState::Unset {
         base.enabled = overlay.enabled;
     }
+    if overlay.severity.is_some() {
+        base.severity = overlay.severity;
+    }
+    if let Some(overlay_language) = &overlay.language {
+        match &mut base.language {
+            Some(base_language) => deep_merge_value(base_language, overlay_language),
+            None => base.language = Some(overlay_language.clone()),
+        }
+    }
 
     let should_merge_include = inherit_mode
         .map(|im| im.merge.contains("Include"))
@@ -1963,6 +2529,24 @@ fn merge_department_config(
     }
 }
 
+/// Merge `overlay` into `base` the way RuboCop merges nested config hashes:
+/// mappings key by key at every level, anything else replaced.
+fn deep_merge_value(base: &mut Value, overlay: &Value) {
+    match (base, overlay) {
+        (Value::Mapping(base_map), Value::Mapping(overlay_map)) => {
+            for (k, v) in overlay_map {
+                match base_map.get_mut(k) {
+                    Some(existing) => deep_merge_value(existing, v),
+                    None => {
+                        base_map.insert(k.clone(), v.clone());
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay.clone(),
+    }
+}
+
 /// Merge a single cop's overlay config into its base config.
 fn merge_cop_config(base: &mut CopConfig, overlay: &CopConfig, inherit_mode: Option<&InheritMode>) {
     // Enabled: last writer wins (only if overlay explicitly set it)
@@ -2169,7 +2753,12 @@ impl ResolvedConfig {
 
         // Core cop version awareness: if the installed rubocop gem's config was
         // loaded and this core cop isn't mentioned, it doesn't exist in that version.
-        if !self.rubocop_known_cops.is_empty()
+        // Departments the gem doesn't know at all hold custom cops, not core ones.
+        let dept_is_core = self
+            .rubocop_known_cops
+            .iter()
+            .any(|c| c.starts_with(dept) && c.as_bytes().get(dept.len()) == Some(&b'/'));
+        if dept_is_core
             && !is_plugin_department(dept)
             && !self.rubocop_known_cops.contains(name)
             && config.is_none_or(|c| c.enabled == EnabledState::Unset)
@@ -2238,20 +2827,29 @@ impl ResolvedConfig {
     /// cop's options so individual cops can access them without special plumbing.
     pub fn cop_config(&self, name: &str) -> CopConfig {
         let mut config = self.cop_configs.get(name).cloned().unwrap_or_default();
-        // Inject TargetRubyVersion from AllCops into cop options
-        // (only if the cop doesn't already have it set explicitly)
-        if let Some(version) = self.target_ruby_version {
-            config
-                .options
-                .entry("TargetRubyVersion".to_string())
-                .or_insert_with(|| Value::Number(serde_yml::Number::from(version)));
+        // A department-level `Severity:` applies to cops that don't set their
+        // own (RuboCop merges the department config under the cop config).
+        if config.severity.is_none() {
+            let dept = name.split('/').next().unwrap_or("");
+            config.severity = self.department_configs.get(dept).and_then(|dc| dc.severity);
         }
-        // Inject TargetRailsVersion from AllCops into cop options
-        if let Some(version) = self.target_rails_version {
-            config
-                .options
-                .entry("TargetRailsVersion".to_string())
-                .or_insert_with(|| Value::Number(serde_yml::Number::from(version)));
+        inject_target_versions(
+            &mut config,
+            self.target_ruby_version,
+            self.target_rails_version,
+        );
+        // rubocop-rspec cops read DSL aliases from the department's `Language:`
+        if name.starts_with("RSpec/") {
+            if let Some(language) = self
+                .department_configs
+                .get("RSpec")
+                .and_then(|dc| dc.language.as_ref())
+            {
+                config
+                    .options
+                    .entry("Language".to_string())
+                    .or_insert_with(|| language.clone());
+            }
         }
         // Inject railties_in_lockfile flag so cops can check requires_gem('railties')
         config
@@ -2282,6 +2880,7 @@ impl ResolvedConfig {
                 | "Style/GuardClause"
                 | "Style/SoleNestedConditional"
                 | "Style/MultilineMethodSignature"
+                | "Style/EmptyMethod"
                 | "Layout/RedundantLineBreak"
         ) {
             let line_length_config = self.cop_configs.get("Layout/LineLength");
@@ -2329,6 +2928,21 @@ impl ResolvedConfig {
                 .entry("ArgumentAlignmentStyle".to_string())
                 .or_insert_with(|| Value::String(aa_style.to_string()));
         }
+        // Inject Style/BlockDelimiters EnforcedStyle into Layout/SpaceBeforeBlockBraces
+        // (mirrors RuboCop's `block_delimiters_style`, which keeps `no_space` from
+        // fighting `line_count_based` over multiline blocks)
+        if name == "Layout/SpaceBeforeBlockBraces" {
+            let bd_style = self
+                .cop_configs
+                .get("Style/BlockDelimiters")
+                .and_then(|cc| cc.options.get("EnforcedStyle"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("line_count_based");
+            config
+                .options
+                .entry("BlockDelimitersStyle".to_string())
+                .or_insert_with(|| Value::String(bd_style.to_string()));
+        }
         // Inject sibling Layout cop styles that other cops consult at runtime.
         if name == "Layout/ElseAlignment" || name == "Layout/IndentationWidth" {
             let end_config = self.cop_configs.get("Layout/EndAlignment");
@@ -2372,6 +2986,30 @@ impl ResolvedConfig {
                 .entry("IndentationStyleEnforced".to_string())
                 .or_insert_with(|| Value::String(indentation_style.to_string()));
         }
+        // Fall back to Layout/IndentationWidth `Width` when a cop leaves its own
+        // `IndentationWidth` unset (mirrors RuboCop's `configured_indentation_width`).
+        if matches!(
+            name,
+            "Layout/FirstArgumentIndentation"
+                | "Layout/FirstArrayElementIndentation"
+                | "Layout/FirstHashElementIndentation"
+                | "Style/RescueModifier"
+        ) && config
+            .options
+            .get("IndentationWidth")
+            .is_none_or(|v| v.is_null())
+        {
+            let width = self
+                .cop_configs
+                .get("Layout/IndentationWidth")
+                .and_then(|cc| cc.options.get("Width"))
+                .and_then(|v| v.as_u64())
+                .unwrap_or(2);
+            config.options.insert(
+                "IndentationWidth".to_string(),
+                Value::Number(serde_yml::Number::from(width)),
+            );
+        }
         // Inject Layout/SpaceInsideHashLiteralBraces EnforcedStyle for Layout/SpaceAfterComma
         // (mirrors RuboCop's `space_forbidden_before_rcurly?` which reads the sibling cop's style)
         if name == "Layout/SpaceAfterComma" {
@@ -2461,6 +3099,7 @@ impl ResolvedConfig {
         let mut effective = self.clone();
         let mut merged = ConfigLayer {
             cop_configs: effective.cop_configs.clone(),
+            provenance: effective.provenance.clone(),
             department_configs: effective.department_configs.clone(),
             global_excludes: effective.global_excludes.clone(),
             new_cops: Some(match effective.new_cops {
@@ -2479,10 +3118,15 @@ impl ResolvedConfig {
             target_rails_version: effective.target_rails_version,
             active_support_extensions_enabled: Some(effective.active_support_extensions_enabled),
             migrated_schema_version: effective.migrated_schema_version.clone(),
+            erb: Some(effective.erb),
+            // Promotion is project-wide; nested configs cannot change it
+            preview_cops: None,
+            markdown: effective.markdown,
         };
         merge_layer_into(&mut merged, layer, Some(&layer.inherit_mode));
 
         effective.cop_configs = merged.cop_configs;
+        effective.provenance = merged.provenance;
         effective.department_configs = merged.department_configs;
         effective.global_excludes = merged.global_excludes;
         effective.new_cops = match merged.new_cops.as_deref() {
@@ -2500,6 +3144,8 @@ impl ResolvedConfig {
             .active_support_extensions_enabled
             .unwrap_or(effective.active_support_extensions_enabled);
         effective.migrated_schema_version = merged.migrated_schema_version;
+        effective.erb = merged.erb.unwrap_or(effective.erb);
+        effective.markdown = merged.markdown;
 
         effective
             .project_mentioned_cops
@@ -2567,22 +3213,225 @@ impl ResolvedConfig {
         None
     }
 
+    /// Which file set each of the cop's settings, when provenance tracking
+    /// was on while the config loaded.
+    pub fn cop_provenance(&self, name: &str) -> Option<&CopProvenance> {
+        self.provenance.get(name)
+    }
+
+    /// Every config file read while resolving this config, in load order.
+    pub fn config_chain(&self) -> &[ChainEntry] {
+        &self.config_chain
+    }
+
+    /// Problems met while loading, such as a missing `inherit_from` target,
+    /// without the `warning: ` prefix. Loading never prints them; the CLI
+    /// does.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
     /// Global exclude patterns from AllCops.Exclude.
     pub fn global_excludes(&self) -> &[String] {
         &self.global_excludes
     }
 
+    /// The config file that was loaded, if any.
+    pub fn config_path(&self) -> Option<&Path> {
+        self.config_path.as_deref()
+    }
+
     /// Directory containing the resolved config file.
     pub fn config_dir(&self) -> Option<&Path> {
         self.config_dir.as_deref()
     }
 
+    /// AllCops.TargetRubyVersion, after defaulting and `.ruby-version` lookup.
+    pub fn target_ruby_version(&self) -> Option<f64> {
+        self.target_ruby_version
+    }
+
+    /// AllCops.TargetRailsVersion, after Gemfile.lock lookup.
+    pub fn target_rails_version(&self) -> Option<f64> {
+        self.target_rails_version
+    }
+
     /// Base directory for resolving Include/Exclude path patterns.
     /// Falls back to `config_dir` if not set.
     pub fn base_dir(&self) -> Option<&Path> {
         self.base_dir.as_deref().or(self.config_dir.as_deref())
     }
 
+    /// Whether `.erb` templates are discovered and linted (AllCops.Erb or `--erb`).
+    pub fn erb_enabled(&self) -> bool {
+        self.erb
+    }
+
+    /// AllCops.PreviewCops: preview-tier cops that run without `--preview`.
+    pub fn preview_cops(&self) -> &[String] {
+        &self.preview_cops
+    }
+
+    /// Promote more preview cops on top of AllCops.PreviewCops (the
+    /// `--preview-cop` flag).
+    pub fn add_preview_cops(&mut self, names: &[String]) {
+        for name in names {
+            if !self.preview_cops.contains(name) {
+                self.preview_cops.push(name.clone());
+            }
+        }
+    }
+
+    /// Turn on ERB mode regardless of AllCops.Erb (the `--erb` flag).
+    pub fn enable_erb(&mut self) {
+        self.erb = true;
+    }
+
+    /// Whether Markdown code blocks are linted (`require: rubocop-md` or
+    /// `--markdown`).
+    pub fn markdown_enabled(&self) -> bool {
+        self.markdown
+    }
+
+    /// Turn on Markdown mode without `rubocop-md` in the config (the
+    /// `--markdown` flag).
+    pub fn enable_markdown(&mut self) {
+        self.markdown = true;
+    }
+
+    /// Why `cop` does not run at all under this config: RuboCop's
+    /// `enable_cop?`, plus plugin, version, and preview gating. `None` when
+    /// it runs on the files its Include/Exclude select.
+    pub fn disabled_reason(
+        &self,
+        cop: &dyn Cop,
+        tier_map: &crate::cop::tiers::TierMap,
+        preview: bool,
+    ) -> Option<DisabledReason> {
+        self.disabled_reason_with(cop, tier_map, preview, &EnablementContext::new(self))
+    }
+
+    fn disabled_reason_with(
+        &self,
+        cop: &dyn Cop,
+        tier_map: &crate::cop::tiers::TierMap,
+        preview: bool,
+        context: &EnablementContext<'_>,
+    ) -> Option<DisabledReason> {
+        let name = cop.name();
+        let config = self.cop_configs.get(name);
+        let dept = name.split('/').next().unwrap_or("");
+        let dept_config = self.department_configs.get(dept);
+
+        // Determine enabled state following RuboCop's enable_cop? logic
+        // (vendor/rubocop/lib/rubocop/config.rb:380-390):
+        //   1. Enabled: true on cop → enabled (any source)
+        //   2. Department Enabled: false → disabled (unless cop has explicit Enabled: true)
+        //   3. No Enabled key → use DisabledByDefault to decide
+        let cop_enabled_state = config.map(|c| c.enabled).unwrap_or(EnabledState::Unset);
+        let dept_enabled_state = dept_config
+            .map(|dc| dc.enabled)
+            .unwrap_or(EnabledState::Unset);
+
+        if cop_enabled_state == EnabledState::True {
+            // Department-level Enabled:false overrides cop-level Enabled:true
+            // when the cop's True came from defaults (not user config).
+            if !self.disabled_by_default
+                && dept_enabled_state == EnabledState::False
+                && !self.project_mentioned_cops.contains(name)
+            {
+                return Some(DisabledReason::Department);
+            }
+        } else if cop_enabled_state == EnabledState::False {
+            return Some(DisabledReason::Config);
+        } else if cop_enabled_state == EnabledState::Pending {
+            if self.new_cops != NewCopsPolicy::Enable {
+                return Some(DisabledReason::Pending);
+            }
+        } else if dept_enabled_state == EnabledState::False {
+            // Department explicitly disabled, cop has no explicit setting
+            return Some(DisabledReason::Department);
+        } else {
+            // No explicit cop setting; department may be True/Unset.
+            // Without DisabledByDefault, department True promotes cop.
+            // With DisabledByDefault + department explicitly enabled by user
+            // (i.e., `Security: Enabled: true`, not just `Performance: Exclude:`),
+            // restore the cop's default Enabled value. This matches RuboCop's
+            // handle_disabled_by_default which re-enables default-enabled cops
+            // in explicitly-enabled departments.
+            let promoted = dept_enabled_state == EnabledState::True
+                && (!self.disabled_by_default
+                    || self.project_enabled_depts.contains(dept) && cop.default_enabled());
+            if !promoted {
+                if self.disabled_by_default {
+                    return Some(DisabledReason::DisabledByDefault);
+                }
+                if !cop.default_enabled() {
+                    return Some(DisabledReason::Config);
+                }
+            }
+        }
+
+        // Plugin department awareness: cops from plugin departments should
+        // only run if the corresponding gem was loaded via require:/plugins:.
+        let explicitly_enabled = config.is_some_and(|c| c.enabled == EnabledState::True);
+        if is_plugin_department(dept)
+            && !self.require_departments.contains(dept)
+            && !explicitly_enabled
+        {
+            let gem = PLUGIN_GEM_DEPARTMENTS
+                .iter()
+                .find(|(d, _)| *d == dept)
+                .map_or("", |(_, gem)| gem);
+            return Some(DisabledReason::PluginNotLoaded { gem });
+        }
+
+        // Plugin version awareness: if this cop's department comes from a
+        // `require:` gem but the cop itself is NOT mentioned in the installed
+        // gem's config/default.yml, the cop doesn't exist in that gem version.
+        // Disable it unless the user explicitly configured it.
+        // Only apply this check when the gem's config was actually loaded
+        // (i.e., require_known_cops contains at least one cop from this dept).
+        let dept_has_known_cops = self
+            .require_known_cops
+            .iter()
+            .any(|c| c.starts_with(dept) && c.as_bytes().get(dept.len()) == Some(&b'/'));
+        if dept_has_known_cops
+            && self.require_departments.contains(dept)
+            && !self.require_known_cops.contains(name)
+            && !explicitly_enabled
+        {
+            return Some(DisabledReason::NotInInstalledVersion);
+        }
+
+        // Core cop version awareness: if the installed rubocop gem's
+        // config/default.yml was loaded and this cop is from one of its
+        // (core) departments but NOT mentioned in that
+        // config, the cop doesn't exist in the project's rubocop version.
+        // Disable it unless the user explicitly configured it.
+        if context.rubocop_known_depts.contains(dept)
+            && !is_plugin_department(dept)
+            && !self.rubocop_known_cops.contains(name)
+            && !explicitly_enabled
+        {
+            return Some(DisabledReason::NotInInstalledVersion);
+        }
+
+        // Preview tier gating: preview cops are disabled unless --preview
+        // or promoted (AllCops.PreviewCops, --preview-cop)
+        if !preview && tier_map.is_preview_gated(name) {
+            return Some(DisabledReason::Preview);
+        }
+
+        // Cross-cop dependency: Style/RedundantConstantBase is disabled
+        // when Lint/ConstantResolution is enabled (conflicting rules).
+        if name == "Style/RedundantConstantBase" && context.lint_constant_resolution_enabled {
+            return Some(DisabledReason::ConflictsWith("Lint/ConstantResolution"));
+        }
+
+        None
+    }
+
     /// Build pre-compiled cop filters for fast per-file enablement checks.
     ///
     /// This resolves all enabled states, include/exclude patterns, and global
@@ -2606,13 +3455,7 @@ impl ResolvedConfig {
             .collect();
         let global_exclude_re = build_regex_set(&global_exclude_pats);
 
-        // Cross-cop dependency: Style/RedundantConstantBase disables itself when
-        // Lint/ConstantResolution is enabled (they have conflicting requirements).
-        let lint_constant_resolution_enabled = self
-            .cop_configs
-            .get("Lint/ConstantResolution")
-            .is_some_and(|c| c.enabled == EnabledState::True);
-
+        let enablement = EnablementContext::new(self);
         let filters: Vec<CopFilter> = registry
             .cops()
             .iter()
@@ -2622,120 +3465,9 @@ impl ResolvedConfig {
                 let dept = name.split('/').next().unwrap_or("");
                 let dept_config = self.department_configs.get(dept);
 
-                // Determine enabled state following RuboCop's enable_cop? logic
-                // (vendor/rubocop/lib/rubocop/config.rb:380-390):
-                //   1. Enabled: true on cop → enabled (any source)
-                //   2. Department Enabled: false → disabled (unless cop has explicit Enabled: true)
-                //   3. No Enabled key → use DisabledByDefault to decide
-                let cop_enabled_state = config.map(|c| c.enabled).unwrap_or(EnabledState::Unset);
-                let dept_enabled_state = dept_config
-                    .map(|dc| dc.enabled)
-                    .unwrap_or(EnabledState::Unset);
-
-                let enabled_state = if cop_enabled_state == EnabledState::True {
-                    // Department-level Enabled:false overrides cop-level Enabled:true
-                    // when the cop's True came from defaults (not user config).
-                    if !self.disabled_by_default
-                        && dept_enabled_state == EnabledState::False
-                        && !self.project_mentioned_cops.contains(name)
-                    {
-                        EnabledState::False
-                    } else {
-                        EnabledState::True
-                    }
-                } else if cop_enabled_state != EnabledState::Unset {
-                    // Cop has explicit False or Pending
-                    cop_enabled_state
-                } else if dept_enabled_state == EnabledState::False {
-                    // Department explicitly disabled, cop has no explicit setting
-                    EnabledState::False
-                } else {
-                    // No explicit cop setting; department may be True/Unset.
-                    // Without DisabledByDefault, department True promotes cop.
-                    // With DisabledByDefault + department explicitly enabled by user
-                    // (i.e., `Security: Enabled: true`, not just `Performance: Exclude:`),
-                    // restore the cop's default Enabled value. This matches RuboCop's
-                    // handle_disabled_by_default which re-enables default-enabled cops
-                    // in explicitly-enabled departments.
-                    if dept_enabled_state == EnabledState::True {
-                        if !self.disabled_by_default
-                            || self.project_enabled_depts.contains(dept) && cop.default_enabled()
-                        {
-                            EnabledState::True
-                        } else {
-                            EnabledState::Unset
-                        }
-                    } else {
-                        EnabledState::Unset
-                    }
-                };
-
-                let mut enabled = match enabled_state {
-                    EnabledState::False => false,
-                    EnabledState::Pending => self.new_cops == NewCopsPolicy::Enable,
-                    EnabledState::Unset => !self.disabled_by_default && cop.default_enabled(),
-                    EnabledState::True => true,
-                };
-
-                // Plugin department awareness: cops from plugin departments should
-                // only run if the corresponding gem was loaded via require:/plugins:.
-                if enabled
-                    && is_plugin_department(dept)
-                    && !self.require_departments.contains(dept)
-                    && config.is_none_or(|c| c.enabled != EnabledState::True)
-                {
-                    enabled = false;
-                }
-
-                // Plugin version awareness: if this cop's department comes from a
-                // `require:` gem but the cop itself is NOT mentioned in the installed
-                // gem's config/default.yml, the cop doesn't exist in that gem version.
-                // Disable it unless the user explicitly configured it.
-                // Only apply this check when the gem's config was actually loaded
-                // (i.e., require_known_cops contains at least one cop from this dept).
-                let dept_has_known_cops = self
-                    .require_known_cops
-                    .iter()
-                    .any(|c| c.starts_with(dept) && c.as_bytes().get(dept.len()) == Some(&b'/'));
-                if enabled
-                    && dept_has_known_cops
-                    && self.require_departments.contains(dept)
-                    && !self.require_known_cops.contains(name)
-                    && config.is_none_or(|c| c.enabled != EnabledState::True)
-                {
-                    enabled = false;
-                }
-
-                // Core cop version awareness: if the installed rubocop gem's
-                // config/default.yml was loaded (rubocop_known_cops is non-empty)
-                // and this cop is from a core department but NOT mentioned in that
-                // config, the cop doesn't exist in the project's rubocop version.
-                // Disable it unless the user explicitly configured it.
-                if enabled
-                    && !self.rubocop_known_cops.is_empty()
-                    && !is_plugin_department(dept)
-                    && !self.rubocop_known_cops.contains(name)
-                    && config.is_none_or(|c| c.enabled != EnabledState::True)
-                {
-                    enabled = false;
-                }
-
-                // Preview tier gating: preview cops are disabled unless --preview
-                if enabled
-                    && !preview
-                    && tier_map.tier_for(name) == crate::cop::tiers::Tier::Preview
-                {
-                    enabled = false;
-                }
-
-                // Cross-cop dependency: Style/RedundantConstantBase is disabled
-                // when Lint/ConstantResolution is enabled (conflicting rules).
-                if enabled
-                    && name == "Style/RedundantConstantBase"
-                    && lint_constant_resolution_enabled
-                {
-                    enabled = false;
-                }
+                let enabled = self
+                    .disabled_reason_with(cop.as_ref(), tier_map, preview, &enablement)
+                    .is_none();
 
                 if !enabled {
                     return CopFilter {
@@ -2744,6 +3476,8 @@ impl ResolvedConfig {
                         exclude_set: None,
                         include_re: None,
                         exclude_re: None,
+                        absolute_exclude: false,
+                        include_classes: Vec::new(),
                     };
                 }
 
@@ -2779,6 +3513,8 @@ impl ResolvedConfig {
                     exclude_set: build_glob_set(&exclude_patterns),
                     include_re: build_regex_set(&include_patterns),
                     exclude_re: build_regex_set(&exclude_patterns),
+                    absolute_exclude: exclude_patterns.iter().any(|p| is_absolute_pattern(p)),
+                    include_classes: include_classes(&include_patterns),
                 }
             })
             .collect();
@@ -2809,6 +3545,7 @@ impl ResolvedConfig {
             }
             // disabled cops go in neither list
         }
+        let pattern_cop_groups = PatternCopGroups::new(&filters, &pattern_cop_indices);
 
         CopFilterSet {
             global_exclude,
@@ -2820,6 +3557,7 @@ impl ResolvedConfig {
             sub_config_dirs,
             universal_cop_indices,
             pattern_cop_indices,
+            pattern_cop_groups,
             migrated_schema_version: self.migrated_schema_version.clone(),
         }
     }
@@ -2861,7 +3599,7 @@ impl ResolvedConfig {
         for name in self.enabled_cop_names() {
             if registry_names.contains(name.as_str()) {
                 // Implemented — check if preview-gated
-                if !preview && tier_map.tier_for(&name) == crate::cop::tiers::Tier::Preview {
+                if !preview && tier_map.is_preview_gated(&name) {
                     summary.preview_gated.push(name);
                 }
             } else if baseline.contains(name.as_str()) {
@@ -2882,7 +3620,28 @@ impl ResolvedConfig {
     }
 }
 
-fn parse_cop_config(value: &Value) -> CopConfig {
+/// Inject `TargetRubyVersion` / `TargetRailsVersion` from AllCops into cop
+/// options, unless the cop sets them explicitly.
+pub(crate) fn inject_target_versions(
+    config: &mut CopConfig,
+    target_ruby_version: Option<f64>,
+    target_rails_version: Option<f64>,
+) {
+    if let Some(version) = target_ruby_version {
+        config
+            .options
+            .entry("TargetRubyVersion".to_string())
+            .or_insert_with(|| Value::Number(serde_yml::Number::from(version)));
+    }
+    if let Some(version) = target_rails_version {
+        config
+            .options
+            .entry("TargetRailsVersion".to_string())
+            .or_insert_with(|| Value::Number(serde_yml::Number::from(version)));
+    }
+}
+
+pub(crate) fn parse_cop_config(value: &Value) -> CopConfig {
     let mut config = CopConfig::default();
 
     if let Value::Mapping(map) = value {
@@ -2946,6 +3705,11 @@ fn parse_department_config(value: &Value) -> DepartmentConfig {
                         config.enabled = EnabledState::Pending;
                     }
                 }
+                Some("Severity") => {
+                    if let Some(s) = v.as_str() {
+                        config.severity = Severity::from_str(s);
+                    }
+                }
                 Some("Include") => {
                     if let Some(list) = value_to_string_list(v) {
                         config.include = list;
@@ -2956,6 +3720,9 @@ fn parse_department_config(value: &Value) -> DepartmentConfig {
                         config.exclude = list;
                     }
                 }
+                Some("Language") if v.is_mapping() => {
+                    config.language = Some(v.clone());
+                }
                 _ => {}
             }
         }
@@ -3017,7 +3784,10 @@ const PLUGIN_GEM_DEPARTMENTS: &[(&str, &str)] = &[
     ("FactoryBot", "rubocop-factory_bot"),
     ("Capybara", "rubocop-capybara"),
     ("Rake", "rubocop-rake"),
+    ("Minitest", "rubocop-minitest"),
     ("Performance", "rubocop-performance"),
+    ("Sorbet", "rubocop-sorbet"),
+    ("ThreadSafety", "rubocop-thread_safety"),
     // standard-family wrapper gems
     ("Rails", "standard-rails"),
     ("Migration", "standard-rails"),
@@ -3196,7 +3966,10 @@ fn glob_matches(pattern: &str, path: &Path) -> bool {
         }
         return false;
     }
-    let glob = match GlobBuilder::new(pattern).literal_separator(false).build() {
+    let glob = match GlobBuilder::new(&expand_home(pattern))
+        .literal_separator(false)
+        .build()
+    {
         Ok(g) => g,
         Err(_) => return false,
     };
@@ -3257,6 +4030,32 @@ mod tests {
         fs::remove_dir_all(&dir).ok();
     }
 
+    #[test]
+    fn core_version_awareness_ignores_custom_departments() {
+        struct NamedCop(&'static str);
+        impl crate::cop::Cop for NamedCop {
+            fn name(&self) -> &'static str {
+                self.0
+            }
+        }
+
+        let mut registry = CopRegistry::new();
+        registry.register(Box::new(NamedCop("Layout/NotInInstalledGem")));
+        registry.register(Box::new(NamedCop("Acme/NoSleep")));
+        let config = ResolvedConfig {
+            rubocop_known_cops: HashSet::from(["Layout/LineLength".to_string()]),
+            ..ResolvedConfig::empty()
+        };
+        let mut tier_map = crate::cop::tiers::TierMap::load();
+        tier_map.set_tier("Acme/NoSleep", crate::cop::tiers::Tier::Stable);
+        let filters = config.build_cop_filters(&registry, &tier_map, true);
+
+        assert!(!filters.cop_filter(0).is_enabled());
+        assert!(filters.cop_filter(1).is_enabled());
+        assert!(!config.is_cop_enabled("Layout/NotInInstalledGem", Path::new("a.rb"), &[], &[]));
+        assert!(config.is_cop_enabled("Acme/NoSleep", Path::new("a.rb"), &[], &[]));
+    }
+
     #[test]
     fn cop_enabled_false() {
         let dir = std::env::temp_dir().join("nitrocop_test_config_disabled");
@@ -3280,6 +4079,55 @@ mod tests {
         fs::remove_dir_all(&dir).ok();
     }
 
+    #[test]
+    fn department_severity_override() {
+        let dir = std::env::temp_dir().join("nitrocop_test_config_dept_severity");
+        fs::create_dir_all(&dir).unwrap();
+        let path = write_config(
+            &dir,
+            "Lint:\n  Severity: error\nLint/Foo:\n  Severity: convention\n",
+        );
+        let config = load_config(Some(&path), None, None).unwrap();
+        assert_eq!(
+            config.cop_config("Lint/Bar").severity,
+            Some(Severity::Error)
+        );
+        // Cop-level Severity takes precedence over the department's
+        assert_eq!(
+            config.cop_config("Lint/Foo").severity,
+            Some(Severity::Convention)
+        );
+        assert!(config.cop_config("Style/Bar").severity.is_none());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn rspec_language_merges_across_layers_into_rspec_cops() {
+        let dir = std::env::temp_dir().join("nitrocop_test_config_rspec_language");
+        fs::create_dir_all(&dir).unwrap();
+        write_yaml(
+            &dir,
+            "base.yml",
+            "RSpec:\n  Language:\n    Examples:\n      Regular:\n        - check\n",
+        );
+        let path = write_config(
+            &dir,
+            "inherit_from: base.yml\nRSpec:\n  Language:\n    Examples:\n      Focused:\n        - fcheck\n",
+        );
+        let config = load_config(Some(&path), None, None).unwrap();
+        let focus = config.cop_config("RSpec/Focus");
+        let examples = &focus.options["Language"]["Examples"];
+        assert_eq!(examples["Regular"][0].as_str(), Some("check"));
+        assert_eq!(examples["Focused"][0].as_str(), Some("fcheck"));
+        assert!(
+            !config
+                .cop_config("Style/Foo")
+                .options
+                .contains_key("Language")
+        );
+        fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn cop_exclude_include_patterns() {
         let dir = std::env::temp_dir().join("nitrocop_test_config_patterns");
@@ -3306,6 +4154,36 @@ mod tests {
         fs::remove_dir_all(&dir).ok();
     }
 
+    #[test]
+    fn line_length_settings_injected_into_guard_clause() {
+        let dir = std::env::temp_dir().join("nitrocop_test_config_guard_clause_line_length");
+        fs::create_dir_all(&dir).unwrap();
+        let path = write_config(&dir, "Layout/LineLength:\n  Max: 80\n");
+        let config = load_config(Some(&path), None, None).unwrap();
+        let cc = config.cop_config("Style/GuardClause");
+        assert_eq!(
+            cc.options.get("MaxLineLength").and_then(|v| v.as_u64()),
+            Some(80)
+        );
+        assert_eq!(
+            cc.options
+                .get("LineLengthEnabled")
+                .and_then(|v| v.as_bool()),
+            Some(true)
+        );
+
+        let path = write_config(&dir, "Layout/LineLength:\n  Enabled: false\n");
+        let config = load_config(Some(&path), None, None).unwrap();
+        let cc = config.cop_config("Style/GuardClause");
+        assert_eq!(
+            cc.options
+                .get("LineLengthEnabled")
+                .and_then(|v| v.as_bool()),
+            Some(false)
+        );
+        fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn non_cop_keys_ignored() {
         let dir = std::env::temp_dir().join("nitrocop_test_config_noncop");
@@ -3411,6 +4289,52 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn expand_home_only_touches_leading_tilde() {
+        let Ok(home) = std::env::var("HOME") else {
+            return;
+        };
+        let home = home.trim_end_matches('/');
+        assert_eq!(expand_home("~/shared/**"), format!("{home}/shared/**"));
+        assert_eq!(expand_home("~"), home);
+        assert_eq!(expand_home("~other/shared/**"), "~other/shared/**");
+        assert_eq!(expand_home("vendor/~/**"), "vendor/~/**");
+        assert!(is_absolute_pattern("~/shared/**"));
+        assert!(is_absolute_pattern("/srv/app/vendor/**"));
+        assert!(!is_absolute_pattern("vendor/**"));
+        assert!(!is_absolute_pattern("/(vendor|bundle)($|\\/.*)/"));
+    }
+
+    #[test]
+    fn absolute_global_exclude_matches_relative_path() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .canonicalize()
+            .unwrap();
+        let pattern = format!("{}/src/config/**/*", root.display());
+        let pats = [pattern.as_str()];
+        let filter_set = CopFilterSet {
+            global_exclude: build_glob_set(&pats).unwrap(),
+            global_exclude_patterns: vec![pattern.clone()],
+            global_exclude_re: None,
+            filters: vec![make_filter(true, &[], &pats)],
+            config_dir: None,
+            base_dir: None,
+            sub_config_dirs: Vec::new(),
+            universal_cop_indices: Vec::new(),
+            pattern_cop_indices: vec![0],
+            pattern_cop_groups: PatternCopGroups::default(),
+            migrated_schema_version: None,
+        };
+        // Cargo runs unit tests from the package root.
+        let relative = Path::new("src/config/mod.rs");
+        assert!(filter_set.is_globally_excluded(relative));
+        assert!(filter_set.is_globally_excluded(&Path::new(".").join(relative)));
+        assert!(!filter_set.is_globally_excluded(Path::new("src/lib.rs")));
+        assert!(!filter_set.is_cop_match(0, relative));
+        assert!(filter_set.is_cop_excluded(0, relative));
+        assert!(filter_set.is_cop_match(0, Path::new("src/lib.rs")));
+    }
+
     // ---- Ruby regexp tests ----
 
     #[test]
@@ -3528,6 +4452,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         // Glob pattern should work
@@ -3613,6 +4538,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
 
@@ -3650,6 +4576,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
 
@@ -4218,6 +5145,18 @@ mod tests {
         fs::remove_dir_all(&dir).ok();
     }
 
+    #[test]
+    fn defaults_only_applies_default_excludes() {
+        let config = ResolvedConfig::defaults_only();
+        for pattern in ["vendor/**/*", "node_modules/**/*", "tmp/**/*"] {
+            assert!(
+                config.global_excludes().iter().any(|e| e == pattern),
+                "{pattern} missing: {:?}",
+                config.global_excludes()
+            );
+        }
+    }
+
     // ---- EnabledState / Pending / NewCops tests ----
 
     #[test]
@@ -4502,6 +5441,33 @@ mod tests {
         fs::remove_dir_all(&dir).ok();
     }
 
+    #[test]
+    fn department_include_limits_debugger_in_cop_filters() {
+        // `Lint: Include:` keeps Lint/Debugger to app code on the production
+        // path, where the department patterns have no cop-level override.
+        let dir = std::env::temp_dir().join("nitrocop_test_dept_include_debugger");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = write_config(&dir, "Lint:\n  Include:\n    - 'app/**/*.rb'\n");
+        let config = load_config(Some(&path), None, None).unwrap();
+        let registry = crate::cop::registry::CopRegistry::default_registry();
+        let tier_map = crate::cop::tiers::TierMap::load();
+
+        let filters = config.build_cop_filters(&registry, &tier_map, false);
+        let idx = registry
+            .cops()
+            .iter()
+            .position(|c| c.name() == "Lint/Debugger")
+            .unwrap();
+
+        assert!(filters.is_cop_match(idx, &dir.join("app/models/user.rb")));
+        assert!(!filters.is_cop_match(idx, &dir.join("spec/models/user_spec.rb")));
+        assert!(!filters.is_cop_match(idx, &dir.join("script/console.rb")));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn department_enabled_false_disables_all_cops() {
         let dir = std::env::temp_dir().join("nitrocop_test_dept_disabled");
@@ -4528,6 +5494,10 @@ mod tests {
         assert!(!config.is_cop_enabled("Rails/Output", Path::new("a.rb"), &[], &[]));
         assert!(!config.is_cop_enabled("RSpec/ExampleLength", Path::new("a.rb"), &[], &[]));
         assert!(!config.is_cop_enabled("Performance/Count", Path::new("a.rb"), &[], &[]));
+        assert!(!config.is_cop_enabled("Minitest/AssertEqual", Path::new("a.rb"), &[], &[]));
+        assert!(!config.is_cop_enabled("Rake/Desc", Path::new("a.rake"), &[], &[]));
+        assert!(!config.is_cop_enabled("ThreadSafety/NewThread", Path::new("a.rb"), &[], &[]));
+        assert!(!config.is_cop_enabled("Sorbet/ValidSigil", Path::new("a.rb"), &[], &[]));
         // Core departments still work
         assert!(config.is_cop_enabled("Style/Foo", Path::new("a.rb"), &[], &[]));
         assert!(config.is_cop_enabled("Lint/Foo", Path::new("a.rb"), &[], &[]));
@@ -4580,6 +5550,52 @@ mod tests {
         fs::remove_dir_all(&dir).ok();
     }
 
+    #[test]
+    fn deprecated_allowed_methods_aliases_warn_and_merge() {
+        let dir = std::env::temp_dir().join("nitrocop_test_deprecated_allowed_methods");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        write_yaml(
+            &dir,
+            "base.yml",
+            "Metrics/BlockLength:\n  AllowedMethods:\n    - describe\n    - context\n",
+        );
+        let path = write_yaml(
+            &dir,
+            ".rubocop.yml",
+            "inherit_from: base.yml\nMetrics/BlockLength:\n  inherit_mode:\n    merge:\n      - AllowedMethods\n  AllowedMethods:\n    - namespace\n  ExcludedMethods:\n    - task\n",
+        );
+
+        let layer = load_config_recursive(
+            &path,
+            &dir,
+            &mut LoadChain::default(),
+            LoadMechanism::Local,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            deprecated_parameter_warnings(&layer.cop_configs),
+            vec![
+                "obsolete parameter `ExcludedMethods` (for `Metrics/BlockLength`) found in config; \
+                 it has been renamed to `AllowedMethods` and/or `AllowedPatterns`"
+            ]
+        );
+
+        let config = load_config(Some(&path), None, None).unwrap();
+        let cc = config.cop_config("Metrics/BlockLength");
+        let allowed = crate::cop::shared::allowed_methods::allowed_methods(&cc);
+        for method in ["describe", "context", "namespace", "task"] {
+            assert!(
+                allowed.iter().any(|m| m == method),
+                "{method} in {allowed:?}"
+            );
+        }
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn inherit_mode_override_exclude() {
         let dir = std::env::temp_dir().join("nitrocop_test_inherit_mode_override");
@@ -4662,9 +5678,153 @@ mod tests {
             exclude_set: build_glob_set(exclude),
             include_re: build_regex_set(include),
             exclude_re: build_regex_set(exclude),
+            absolute_exclude: exclude.iter().any(|p| is_absolute_pattern(p)),
+            include_classes: include_classes(include),
         }
     }
 
+    #[test]
+    fn include_pattern_classes_from_literal_parts() {
+        assert_eq!(include_pattern_classes("**/*_spec.rb"), PATH_SPEC);
+        assert_eq!(include_pattern_classes("**/spec/**/*"), PATH_SPEC);
+        assert_eq!(
+            include_pattern_classes("**/features/support/factories/**/*.rb"),
+            PATH_SPEC
+        );
+        assert_eq!(include_pattern_classes("**/Gemfile"), PATH_GEMFILE);
+        assert_eq!(include_pattern_classes("**/*.gemfile"), PATH_GEMFILE);
+        assert_eq!(include_pattern_classes("**/*.gemspec"), PATH_GEMSPEC);
+        assert_eq!(include_pattern_classes("db/migrate/*.rb"), PATH_MIGRATION);
+        assert_eq!(
+            include_pattern_classes("spec/fixtures/*.gemspec"),
+            PATH_SPEC | PATH_GEMSPEC
+        );
+        // Unconstrained: wildcards only, a non-literal tail, alternatives.
+        assert_eq!(include_pattern_classes("**/*.rb"), 0);
+        assert_eq!(include_pattern_classes("app/**/*.rb"), 0);
+        assert_eq!(include_pattern_classes("**/*Gemfile"), 0);
+        assert_eq!(include_pattern_classes("**/*_spec.r?"), 0);
+        assert_eq!(include_pattern_classes("{spec,app}/**/*.rb"), 0);
+        assert_eq!(include_pattern_classes("/_spec\\.rb$/"), 0);
+        assert_eq!(
+            include_classes(&["**/*_spec.rb", "**/*.rb"]),
+            Vec::<u8>::new()
+        );
+    }
+
+    fn pattern_filter_set() -> (CopRegistry, CopFilterSet) {
+        let dir = std::env::temp_dir().join("nitrocop_test_pattern_cop_groups");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        // RSpec/Focus is widened by user config beyond its department default.
+        let path = write_config(
+            &dir,
+            "plugins:\n  - rubocop-rspec\n  - rubocop-factory_bot\n  - rubocop-rails\n\
+             RSpec/Focus:\n  Include:\n    - '**/*.rb'\n",
+        );
+        let config = load_config(Some(&path), None, None).unwrap();
+        let registry = CopRegistry::default_registry();
+        let tier_map = crate::cop::tiers::TierMap::load();
+        let filters = config.build_cop_filters(&registry, &tier_map, false);
+        fs::remove_dir_all(&dir).ok();
+        (registry, filters)
+    }
+
+    fn sample_paths(count: usize) -> Vec<PathBuf> {
+        let shapes = [
+            "app/models/user_{}.rb",
+            "lib/tasks/task_{}.rake",
+            "spec/models/user_{}_spec.rb",
+            "spec/factories/user_{}.rb",
+            "test/models/user_{}_test.rb",
+            "db/migrate/2024010100000{}_create_users.rb",
+            "gems/gem_{}/Gemfile",
+            "gems/gem_{}/gem_{}.gemspec",
+            "config/initializers/init_{}.rb",
+            "app/controllers/users_{}_controller.rb",
+        ];
+        (0..count)
+            .map(|i| PathBuf::from(shapes[i % shapes.len()].replace("{}", &i.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn pattern_cop_groups_skip_only_cops_that_cannot_match() {
+        let (registry, filters) = pattern_filter_set();
+        let all = filters.pattern_cop_indices();
+        let focus = registry
+            .cops()
+            .iter()
+            .position(|c| c.name() == "RSpec/Focus")
+            .unwrap();
+        assert!(all.contains(&focus));
+        for path in sample_paths(200) {
+            let expected: Vec<usize> = all
+                .iter()
+                .copied()
+                .filter(|&i| filters.is_cop_match(i, &path))
+                .collect();
+            let candidates: Vec<usize> = filters.pattern_cop_indices_for(&path).collect();
+            let grouped: Vec<usize> = candidates
+                .iter()
+                .copied()
+                .filter(|&i| filters.is_cop_match(i, &path))
+                .collect();
+            assert_eq!(grouped, expected, "{}", path.display());
+            if path.extension().is_some_and(|e| e == "rb") {
+                assert!(
+                    candidates.contains(&focus),
+                    "widened Include must not be skipped"
+                );
+            }
+        }
+        let plain = filters
+            .pattern_cop_indices_for(Path::new("app/models/user.rb"))
+            .count();
+        let spec = filters
+            .pattern_cop_indices_for(Path::new("spec/models/user_spec.rb"))
+            .count();
+        assert!(
+            plain < spec,
+            "spec-only groups should be skipped: {plain} vs {spec}"
+        );
+        assert!(spec <= all.len());
+    }
+
+    /// Micro-benchmark: `is_cop_match` over 10k paths, for every pattern cop
+    /// versus only the ones left after ruling out groups by path class.
+    /// Run with `cargo test --release pattern_cop_groups_benchmark -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "timing benchmark; run with --ignored --nocapture"]
+    fn pattern_cop_groups_benchmark() {
+        let (_, filters) = pattern_filter_set();
+        let paths = sample_paths(10_000);
+        let run = |grouped: bool| {
+            let start = std::time::Instant::now();
+            let (mut checks, mut matches) = (0usize, 0usize);
+            for path in &paths {
+                let indices: Vec<usize> = if grouped {
+                    filters.pattern_cop_indices_for(path).collect()
+                } else {
+                    filters.pattern_cop_indices().to_vec()
+                };
+                for i in indices {
+                    checks += 1;
+                    matches += usize::from(filters.is_cop_match(i, path));
+                }
+            }
+            (start.elapsed(), checks, matches)
+        };
+        let (all_time, all_checks, all_matches) = run(false);
+        let (grouped_time, grouped_checks, grouped_matches) = run(true);
+        assert_eq!(all_matches, grouped_matches);
+        eprintln!(
+            "is_cop_match over {} paths: all pattern cops {all_checks} checks in {all_time:?}; \
+             grouped {grouped_checks} checks in {grouped_time:?}",
+            paths.len()
+        );
+    }
+
     #[test]
     fn is_cop_match_exclude_works_on_relativized_path() {
         // Simulates running `nitrocop bench/repos/mastodon` where file paths
@@ -4680,6 +5840,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         let path = Path::new("bench/repos/mastodon/lib/tasks/emojis.rake");
@@ -4703,6 +5864,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         let path = Path::new("/tmp/test/db/migrate/001_create_users.rb");
@@ -4727,6 +5889,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         let path = Path::new("bench/repos/discourse/spec/models/user_spec.rb");
@@ -4751,6 +5914,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         let path = Path::new("bench/repos/discourse/spec/requests/api/invites_spec.rb");
@@ -4774,6 +5938,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         assert!(filter_set.is_cop_match(0, Path::new("app/models/user.rb")));
@@ -4793,6 +5958,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         assert!(!filter_set.is_cop_match(0, Path::new("anything.rb")));
@@ -4827,6 +5993,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
 
@@ -4884,6 +6051,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: vec![0],
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
 
@@ -4919,6 +6087,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         // File doesn't match Include, but is_cop_excluded only checks Exclude
@@ -4941,6 +6110,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         assert!(
@@ -4968,6 +6138,7 @@ mod tests {
             sub_config_dirs: vec![PathBuf::from("bench/repos/mastodon/app/controllers")],
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         // File in sub-config dir: nearest_config_dir is the sub-dir,
@@ -4994,6 +6165,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: Some("19700101000000".to_string()),
         };
         // SHA hash containing 14-digit run <= 19700101000000 → migrated
@@ -5021,6 +6193,7 @@ mod tests {
             sub_config_dirs: Vec::new(),
             universal_cop_indices: Vec::new(),
             pattern_cop_indices: Vec::new(),
+            pattern_cop_groups: PatternCopGroups::default(),
             migrated_schema_version: None,
         };
         assert!(!no_version.is_migrated_file(Path::new("19700101000000_init.rb")));
@@ -5712,4 +6885,26 @@ mod tests {
 
         fs::remove_dir_all(&dir).ok();
     }
+
+    #[test]
+    fn first_element_indentation_width_falls_back_to_layout_indentation_width() {
+        let dir = std::env::temp_dir().join("nitrocop_test_config_first_indent_width");
+        fs::create_dir_all(&dir).unwrap();
+        let path = write_config(
+            &dir,
+            "Layout/IndentationWidth:\n  Width: 4\nLayout/FirstHashElementIndentation:\n  IndentationWidth: 3\n",
+        );
+        let config = load_config(Some(&path), None, None).unwrap();
+        let width = |name: &str| {
+            config
+                .cop_config(name)
+                .options
+                .get("IndentationWidth")
+                .and_then(|v| v.as_u64())
+        };
+        assert_eq!(width("Layout/FirstArgumentIndentation"), Some(4));
+        assert_eq!(width("Layout/FirstArrayElementIndentation"), Some(4));
+        assert_eq!(width("Layout/FirstHashElementIndentation"), Some(3));
+        fs::remove_dir_all(&dir).ok();
+    }
 }
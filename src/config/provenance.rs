@@ -0,0 +1,113 @@
+//! Which config file set each cop setting, for `--show-cops`.
+//!
+//! Tracking is off by default so normal runs pay nothing for it; `--show-cops`
+//! turns it on before loading the config. Each layer records the file it was
+//! parsed from against every setting it writes, and merging keeps the last
+//! writer, mirroring how the settings themselves are merged.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::cop::{CopConfig, EnabledState};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Record provenance for every config layer loaded from now on.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// The file that last set each setting of one cop.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CopProvenance {
+    /// `Enabled`, `Severity`, and option names.
+    pub settings: HashMap<String, PathBuf>,
+    /// Include patterns.
+    pub include: HashMap<String, PathBuf>,
+    /// Exclude patterns.
+    pub exclude: HashMap<String, PathBuf>,
+}
+
+impl CopProvenance {
+    /// Everything `config` sets, attributed to `path`.
+    pub(crate) fn from_config(config: &CopConfig, path: &Path) -> Self {
+        let mut settings = HashMap::new();
+        if config.enabled != EnabledState::Unset {
+            settings.insert("Enabled".to_string(), path.to_path_buf());
+        }
+        if config.severity.is_some() {
+            settings.insert("Severity".to_string(), path.to_path_buf());
+        }
+        for key in config.options.keys() {
+            // A merge directive, not a setting.
+            if key != "inherit_mode" {
+                settings.insert(key.clone(), path.to_path_buf());
+            }
+        }
+        let attribute = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| (p.clone(), path.to_path_buf()))
+                .collect()
+        };
+        Self {
+            settings,
+            include: attribute(&config.include),
+            exclude: attribute(&config.exclude),
+        }
+    }
+
+    /// Apply an overlay layer's provenance, given the cop config that
+    /// resulted from merging that layer. Patterns the merge dropped (an
+    /// overriding Include or Exclude) lose their attribution.
+    pub(crate) fn merge(&mut self, overlay: &CopProvenance, merged: &CopConfig) {
+        self.settings.extend(overlay.settings.clone());
+        self.include.retain(|p, _| merged.include.contains(p));
+        self.include.extend(overlay.include.clone());
+        self.exclude.retain(|p, _| merged.exclude.contains(p));
+        self.exclude.extend(overlay.exclude.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn later_layer_wins_and_overridden_patterns_are_dropped() {
+        let defaults = CopConfig {
+            enabled: EnabledState::True,
+            include: vec!["**/*.rb".to_string()],
+            options: HashMap::from([("Max".to_string(), serde_yml::Value::from(80))]),
+            ..CopConfig::default()
+        };
+        let local = CopConfig {
+            include: vec!["app/**/*.rb".to_string()],
+            options: HashMap::from([("Max".to_string(), serde_yml::Value::from(120))]),
+            ..CopConfig::default()
+        };
+        let merged = CopConfig {
+            enabled: EnabledState::True,
+            include: vec!["app/**/*.rb".to_string()],
+            options: local.options.clone(),
+            ..CopConfig::default()
+        };
+
+        let mut prov = CopProvenance::from_config(&defaults, Path::new("default.yml"));
+        prov.merge(
+            &CopProvenance::from_config(&local, Path::new(".rubocop.yml")),
+            &merged,
+        );
+        assert_eq!(prov.settings["Enabled"], Path::new("default.yml"));
+        assert_eq!(prov.settings["Max"], Path::new(".rubocop.yml"));
+        assert_eq!(
+            prov.include,
+            HashMap::from([("app/**/*.rb".to_string(), PathBuf::from(".rubocop.yml"))])
+        );
+    }
+}
@@ -0,0 +1,663 @@
+//! `--emit-schema` command: JSON Schema for `.rubocop.yml`.
+//!
+//! Describes `AllCops`, department keys, and every cop known to nitrocop or
+//! the RuboCop baseline, so editors can validate and autocomplete config
+//! files. Per-cop option keys, defaults, and descriptions come from the
+//! `config/default.yml` of the rubocop gem and its plugins, found the same
+//! way config loading finds them.
+//!
+//! The schema is deliberately permissive: unknown keys are allowed
+//! everywhere, and options whose default gives no type hint accept any value.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde_json::{Map, Value, json};
+
+use crate::config::gem_path;
+use crate::cop::registry::CopRegistry;
+use crate::rules::load_baseline_cops;
+
+/// Per-cop defaults: cop name → option key → default value.
+pub type CopOptions = BTreeMap<String, Map<String, Value>>;
+
+/// Gems whose `config/default.yml` declares cop options. Later gems win.
+const OPTION_GEMS: &[&str] = &[
+    "rubocop",
+    "rubocop-rails",
+    "rubocop-rspec",
+    "rubocop-performance",
+    "rubocop-factory_bot",
+    "rubocop-rspec_rails",
+    "rubocop-rake",
+    "rubocop-minitest",
+];
+
+/// Load the cop defaults of the gems installed for `working_dir`. Gem roots
+/// come from `gem_cache` (the lockfile) when available, falling back to
+/// `bundle info`. Plugins are only looked up when rubocop itself is found;
+/// without it the result is empty.
+pub fn load_cop_options(
+    working_dir: &Path,
+    gem_cache: Option<&HashMap<String, PathBuf>>,
+) -> CopOptions {
+    let mut options = CopOptions::new();
+    for &gem_name in OPTION_GEMS {
+        let gem_root = match gem_cache.and_then(|c| c.get(gem_name)) {
+            Some(root) => root.clone(),
+            None => match gem_path::resolve_gem_path(gem_name, working_dir) {
+                Ok(root) => root,
+                Err(_) if gem_name == "rubocop" => break,
+                Err(_) => continue,
+            },
+        };
+        match read_default_options(&gem_root.join("config").join("default.yml")) {
+            Some(gem_options) => options.extend(gem_options),
+            None if gem_name == "rubocop" => break,
+            None => {}
+        }
+    }
+    options
+}
+
+/// The cop entries of a `default.yml`.
+fn read_default_options(path: &Path) -> Option<CopOptions> {
+    // Strip Ruby-specific YAML tags (e.g., !ruby/regexp) that serde_yml can't handle
+    let contents = std::fs::read_to_string(path)
+        .ok()?
+        .replace("!ruby/regexp ", "");
+    let yaml: serde_yml::Value = serde_yml::from_str(&contents).ok()?;
+    let Value::Object(entries) = serde_json::to_value(&yaml).ok()? else {
+        return None;
+    };
+    Some(
+        entries
+            .into_iter()
+            .filter(|(key, _)| key.contains('/'))
+            .filter_map(|(key, value)| match value {
+                Value::Object(options) => Some((key, options)),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Keys every cop accepts; described once in `definitions/cop`.
+const COMMON_COP_KEYS: &[&str] = &[
+    "AutoCorrect",
+    "Description",
+    "Details",
+    "Enabled",
+    "Exclude",
+    "Include",
+    "Reference",
+    "References",
+    "Safe",
+    "SafeAutoCorrect",
+    "Severity",
+    "StyleGuide",
+    "VersionAdded",
+    "VersionChanged",
+    "VersionRemoved",
+    "inherit_mode",
+];
+
+/// Build the schema for the registry plus all baseline cops, typing cop
+/// options from `cop_options` (see [`load_cop_options`]).
+pub fn build_schema(registry: &CopRegistry, cop_options: &CopOptions) -> Value {
+    let mut names: BTreeSet<String> = registry.names().iter().map(|s| s.to_string()).collect();
+    names.extend(load_baseline_cops().into_keys());
+    build_schema_from(&names, cop_options)
+}
+
+fn build_schema_from(cop_names: &BTreeSet<String>, cop_options: &CopOptions) -> Value {
+    let mut properties = Map::new();
+    properties.insert("inherit_from".into(), string_or_string_array());
+    properties.insert(
+        "inherit_gem".into(),
+        json!({ "type": "object", "additionalProperties": string_or_string_array() }),
+    );
+    properties.insert(
+        "inherit_mode".into(),
+        json!({ "$ref": "#/definitions/inheritMode" }),
+    );
+    properties.insert(
+        "require".into(),
+        json!({ "description": "Extensions to load.", "type": ["string", "array"] }),
+    );
+    properties.insert(
+        "plugins".into(),
+        json!({ "description": "Plugins to load.", "type": ["string", "array"] }),
+    );
+    properties.insert("AllCops".into(), json!({ "$ref": "#/definitions/allCops" }));
+
+    let mut names: BTreeSet<&str> = cop_names.iter().map(String::as_str).collect();
+    names.extend(cop_options.keys().map(String::as_str));
+    for name in names {
+        if let Some((department, _)) = name.split_once('/') {
+            properties
+                .entry(department.to_string())
+                .or_insert_with(|| json!({ "$ref": "#/definitions/department" }));
+        }
+        properties.insert(name.to_string(), cop_schema(cop_options.get(name)));
+    }
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "RuboCop configuration",
+        "description": "Schema for .rubocop.yml files, as understood by nitrocop.",
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": true,
+        "definitions": definitions(),
+    })
+}
+
+/// Schema for one cop: the common keys plus whatever options its
+/// `default.yml` entry declares.
+fn cop_schema(options: Option<&Map<String, Value>>) -> Value {
+    let mut schema = Map::new();
+    schema.insert("allOf".into(), json!([{ "$ref": "#/definitions/cop" }]));
+    let Some(options) = options else {
+        return Value::Object(schema);
+    };
+
+    if let Some(description) = options.get("Description").and_then(Value::as_str) {
+        schema.insert("description".into(), description.into());
+    }
+    let properties: Map<String, Value> = options
+        .iter()
+        .filter(|(key, _)| !COMMON_COP_KEYS.contains(&key.as_str()))
+        .map(|(key, default)| (key.clone(), option_schema(key, default, options)))
+        .collect();
+    if !properties.is_empty() {
+        schema.insert("properties".into(), Value::Object(properties));
+    }
+    Value::Object(schema)
+}
+
+/// Schema for one option, typed from its default value. `Enforced*` options
+/// with a matching `Supported*` list become enums.
+fn option_schema(key: &str, default: &Value, options: &Map<String, Value>) -> Value {
+    let mut schema = Map::new();
+    if let Some(supported) = supported_values(key, options) {
+        schema.insert("enum".into(), Value::Array(supported.clone()));
+    } else {
+        let type_name = match default {
+            Value::Bool(_) => Some("boolean"),
+            Value::Number(n) if n.is_f64() => Some("number"),
+            Value::Number(_) => Some("integer"),
+            Value::String(_) => Some("string"),
+            Value::Array(_) => Some("array"),
+            Value::Object(_) => Some("object"),
+            Value::Null => None,
+        };
+        if let Some(type_name) = type_name {
+            schema.insert("type".into(), type_name.into());
+        }
+    }
+    if !default.is_null() {
+        schema.insert("default".into(), default.clone());
+    }
+    Value::Object(schema)
+}
+
+/// The `Supported*` values for an `Enforced*` option, following RuboCop's
+/// naming: `EnforcedStyle` → `SupportedStyles`, `EnforcedStyleAlignWith` →
+/// `SupportedStylesAlignWith`, `EnforcedShorthandSyntax` →
+/// `SupportedShorthandSyntax`.
+fn supported_values<'a>(key: &str, options: &'a Map<String, Value>) -> Option<&'a Vec<Value>> {
+    let rest = key.strip_prefix("Enforced")?;
+    let mut candidates = vec![format!("Supported{rest}s"), format!("Supported{rest}")];
+    if let Some(suffix) = rest.strip_prefix("Style") {
+        candidates.push(format!("SupportedStyles{suffix}"));
+    }
+    candidates
+        .iter()
+        .find_map(|candidate| options.get(candidate)?.as_array())
+}
+
+fn string_or_string_array() -> Value {
+    json!({
+        "anyOf": [
+            { "type": "string" },
+            { "type": "array", "items": { "type": "string" } },
+        ],
+    })
+}
+
+fn definitions() -> Value {
+    json!({
+        "stringArray": { "type": "array", "items": { "type": "string" } },
+        "inheritMode": {
+            "type": "object",
+            "properties": {
+                "merge": { "$ref": "#/definitions/stringArray" },
+                "override": { "$ref": "#/definitions/stringArray" },
+            },
+        },
+        "severity": {
+            "enum": ["info", "refactor", "convention", "warning", "error", "fatal"],
+        },
+        "cop": {
+            "type": "object",
+            "properties": {
+                "Enabled": {
+                    "description": "Whether the cop runs. `pending` cops run only with `NewCops: enable`.",
+                    "enum": [true, false, "pending"],
+                },
+                "Severity": { "$ref": "#/definitions/severity" },
+                "Include": {
+                    "description": "Only inspect files matching these patterns.",
+                    "$ref": "#/definitions/stringArray",
+                },
+                "Exclude": {
+                    "description": "Skip files matching these patterns.",
+                    "$ref": "#/definitions/stringArray",
+                },
+                "AutoCorrect": { "enum": [true, false, "always", "contextual", "disabled"] },
+                "Safe": { "type": "boolean" },
+                "SafeAutoCorrect": { "type": "boolean" },
+                "Description": { "type": "string" },
+                "Details": { "type": "string" },
+                "StyleGuide": { "type": "string" },
+                "Reference": string_or_string_array(),
+                "References": { "$ref": "#/definitions/stringArray" },
+                "VersionAdded": { "type": "string" },
+                "VersionChanged": { "type": "string" },
+                "VersionRemoved": { "type": "string" },
+                "inherit_mode": { "$ref": "#/definitions/inheritMode" },
+            },
+            "additionalProperties": true,
+        },
+        "department": {
+            "allOf": [{ "$ref": "#/definitions/cop" }],
+            "properties": {
+                "StyleGuideBaseURL": { "type": "string" },
+                "DocumentationBaseURL": { "type": "string" },
+                "DocumentationExtension": { "type": "string" },
+            },
+        },
+        "allCops": {
+            "type": "object",
+            "properties": {
+                "TargetRubyVersion": {
+                    "description": "Ruby version to target, e.g. 3.3.",
+                    "type": ["number", "string"],
+                },
+                "TargetRailsVersion": {
+                    "description": "Rails version to target, e.g. 7.1.",
+                    "type": ["number", "string"],
+                },
+                "Include": { "$ref": "#/definitions/stringArray" },
+                "Exclude": { "$ref": "#/definitions/stringArray" },
+                "NewCops": {
+                    "description": "Whether pending cops are enabled.",
+                    "enum": ["pending", "enable", "disable"],
+                },
+                "DisabledByDefault": { "type": "boolean" },
+                "EnabledByDefault": { "type": "boolean" },
+                "SuggestExtensions": { "type": ["boolean", "object"] },
+                "ActiveSupportExtensionsEnabled": { "type": "boolean" },
+                "StringLiteralsFrozenByDefault": { "type": ["boolean", "null"] },
+                "ParserEngine": { "enum": ["default", "parser_whitequark", "parser_prism"] },
+                "RubyInterpreters": { "$ref": "#/definitions/stringArray" },
+                "UseCache": { "type": "boolean" },
+                "CacheRootDirectory": { "type": ["string", "null"] },
+                "MaxFilesInCache": { "type": "integer" },
+                "AllowSymlinksInCacheRootDirectory": { "type": "boolean" },
+                "DisplayCopNames": { "type": "boolean" },
+                "DisplayStyleGuide": { "type": "boolean" },
+                "ExtraDetails": { "type": "boolean" },
+                "StyleGuideCopsOnly": { "type": "boolean" },
+                "StyleGuideBaseURL": { "type": "string" },
+                "DocumentationBaseURL": { "type": "string" },
+                "DocumentationExtension": { "type": "string" },
+                "MigratedSchemaVersion": { "type": ["string", "integer"] },
+                "DefaultFormatter": { "type": "string" },
+                "Erb": {
+                    "description": "Also lint Ruby embedded in .erb templates (nitrocop only).",
+                    "type": "boolean",
+                },
+                "PreviewCops": {
+                    "description": "Preview-tier cops to run without --preview (nitrocop only).",
+                    "$ref": "#/definitions/stringArray",
+                },
+            },
+            "additionalProperties": true,
+        },
+    })
+}
+
+/// Write the schema as pretty-printed JSON to `path`, or stdout for `-`.
+pub fn write_schema(schema: &Value, path: &Path) -> Result<()> {
+    let mut json = serde_json::to_string_pretty(schema).expect("schema should be serializable");
+    json.push('\n');
+    if path == Path::new("-") {
+        print!("{json}");
+    } else {
+        std::fs::write(path, json)
+            .with_context(|| format!("failed to write schema to {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// A value that does not match the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Dotted YAML path, e.g. `Layout/LineLength.Max` or `inherit_from[1]`.
+    pub path: String,
+    pub message: String,
+}
+
+/// Validate `instance` against `schema`, which must be the root of a schema
+/// produced by `build_schema` — only the keywords it emits are supported.
+pub fn validate(schema: &Value, instance: &Value) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    validate_at(schema, schema, instance, "", &mut violations);
+    violations
+}
+
+fn validate_at(
+    root: &Value,
+    schema: &Value,
+    instance: &Value,
+    path: &str,
+    out: &mut Vec<Violation>,
+) {
+    let schema = resolve_ref(root, schema);
+    let mut violation = |message: String| {
+        out.push(Violation {
+            path: path.to_string(),
+            message,
+        })
+    };
+
+    if let Some(any_of) = schema.get("anyOf").and_then(Value::as_array) {
+        let matches_any = any_of.iter().any(|sub| {
+            let mut sub_violations = Vec::new();
+            validate_at(root, sub, instance, path, &mut sub_violations);
+            sub_violations.is_empty()
+        });
+        if !matches_any {
+            violation(format!("unexpected {}", type_name(instance)));
+            return;
+        }
+    }
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        if !values.contains(instance) {
+            let expected: Vec<String> = values.iter().map(Value::to_string).collect();
+            violation(format!("{instance} is not one of {}", expected.join(", ")));
+            return;
+        }
+    }
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if !types.is_empty() && !types.iter().any(|t| has_type(instance, t)) {
+        violation(format!(
+            "expected {}, got {}",
+            types.join(" or "),
+            type_name(instance)
+        ));
+        return;
+    }
+
+    if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+        for sub in all_of {
+            validate_at(root, sub, instance, path, out);
+        }
+    }
+    match instance {
+        Value::Object(map) => {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (key, value) in map {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                if let Some(property) = properties.and_then(|p| p.get(key)) {
+                    validate_at(root, property, value, &child, out);
+                } else if let Some(additional) =
+                    schema.get("additionalProperties").filter(|a| a.is_object())
+                {
+                    validate_at(root, additional, value, &child, out);
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    validate_at(root, item_schema, item, &format!("{path}[{i}]"), out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Follow a local `#/definitions/...` reference, if `schema` is one.
+pub fn resolve_ref<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    match schema.get("$ref").and_then(Value::as_str) {
+        Some(reference) => reference
+            .strip_prefix("#/")
+            .map(|pointer| root.pointer(&format!("/{pointer}")).unwrap_or(&Value::Null))
+            .unwrap_or(schema),
+        None => schema,
+    }
+}
+
+fn has_type(instance: &Value, type_name: &str) -> bool {
+    match type_name {
+        "integer" => instance.is_i64() || instance.is_u64(),
+        "number" => instance.is_number(),
+        "string" => instance.is_string(),
+        "boolean" => instance.is_boolean(),
+        "array" => instance.is_array(),
+        "object" => instance.is_object(),
+        "null" => instance.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(instance: &Value) -> &'static str {
+    match instance {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(yaml_json: Value) -> CopOptions {
+        serde_json::from_value(yaml_json).unwrap()
+    }
+
+    #[test]
+    fn enforced_style_becomes_enum() {
+        let opts = options(json!({
+            "Style/StringLiterals": {
+                "Description": "Checks if uses of quotes match the configured preference.",
+                "EnforcedStyle": "single_quotes",
+                "SupportedStyles": ["single_quotes", "double_quotes"],
+                "ConsistentQuotesInMultiline": false,
+            },
+        }));
+        let names = BTreeSet::from(["Style/StringLiterals".to_string()]);
+        let schema = build_schema_from(&names, &opts);
+
+        let cop = &schema["properties"]["Style/StringLiterals"];
+        assert_eq!(
+            cop["description"],
+            "Checks if uses of quotes match the configured preference."
+        );
+        assert_eq!(
+            cop["properties"]["EnforcedStyle"],
+            json!({ "enum": ["single_quotes", "double_quotes"], "default": "single_quotes" })
+        );
+        assert_eq!(
+            cop["properties"]["ConsistentQuotesInMultiline"],
+            json!({ "type": "boolean", "default": false })
+        );
+        assert!(cop["properties"].get("Description").is_none());
+        assert_eq!(
+            schema["properties"]["Style"],
+            json!({ "$ref": "#/definitions/department" })
+        );
+    }
+
+    #[test]
+    fn supported_lists_follow_rubocop_naming() {
+        let opts = options(json!({
+            "Layout/Cop": {
+                "EnforcedStyleAlignWith": "start_of_line",
+                "SupportedStylesAlignWith": ["start_of_line", "either"],
+                "EnforcedColonStyle": "key",
+                "SupportedColonStyles": ["key", "separator", "table"],
+                "EnforcedShorthandSyntax": "always",
+                "SupportedShorthandSyntax": ["always", "never"],
+            },
+        }));
+        let cop = &opts["Layout/Cop"];
+        for (key, first) in [
+            ("EnforcedStyleAlignWith", "start_of_line"),
+            ("EnforcedColonStyle", "key"),
+            ("EnforcedShorthandSyntax", "always"),
+        ] {
+            let values = supported_values(key, cop).unwrap_or_else(|| panic!("{key}"));
+            assert_eq!(values[0], first);
+        }
+    }
+
+    #[test]
+    fn freeform_options_are_permissive() {
+        let opts = options(json!({
+            "Naming/Cop": { "Max": 10, "Ratio": 0.5, "AllowedNames": ["x"], "Pattern": null },
+        }));
+        let props = &cop_schema(opts.get("Naming/Cop"))["properties"];
+        assert_eq!(props["Max"]["type"], "integer");
+        assert_eq!(props["Ratio"]["type"], "number");
+        assert_eq!(props["AllowedNames"]["type"], "array");
+        assert_eq!(props["Pattern"], json!({}));
+    }
+
+    #[test]
+    fn validate_reports_type_and_enum_violations() {
+        let opts = options(json!({
+            "Layout/LineLength": { "Max": 120 },
+            "Style/StringLiterals": {
+                "EnforcedStyle": "single_quotes",
+                "SupportedStyles": ["single_quotes", "double_quotes"],
+            },
+        }));
+        let names: BTreeSet<String> = opts.keys().cloned().collect();
+        let schema = build_schema_from(&names, &opts);
+        let config = json!({
+            "inherit_from": [".rubocop_todo.yml", 3],
+            "AllCops": { "NewCops": "enable", "Exclude": ["vendor/**/*"] },
+            "Layout": { "Enabled": true },
+            "Layout/LineLength": { "Max": "long", "Enabled": "maybe", "Custom": 1 },
+            "Style/StringLiterals": { "EnforcedStyle": "backticks" },
+            "Custom/Cop": { "Anything": [1, 2] },
+        });
+
+        let violations = validate(&schema, &config);
+        let paths: Vec<&str> = violations.iter().map(|v| v.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "Layout/LineLength.Enabled",
+                "Layout/LineLength.Max",
+                "Style/StringLiterals.EnforcedStyle",
+                "inherit_from",
+            ]
+        );
+        assert_eq!(violations[1].message, "expected integer, got string");
+        assert_eq!(
+            violations[2].message,
+            r#""backticks" is not one of "single_quotes", "double_quotes""#
+        );
+        assert_eq!(violations[3].message, "unexpected array");
+    }
+
+    #[test]
+    fn cops_without_defaults_still_listed() {
+        let names = BTreeSet::from(["Lint/Debugger".to_string()]);
+        let schema = build_schema_from(&names, &CopOptions::new());
+        assert_eq!(
+            schema["properties"]["Lint/Debugger"],
+            json!({ "allOf": [{ "$ref": "#/definitions/cop" }] })
+        );
+        assert_eq!(schema["additionalProperties"], true);
+    }
+
+    /// Gem roots of the `default.yml` excerpts under
+    /// `tests/fixtures/config_schema/gems`. Gems without an excerpt point at
+    /// missing directories, so nothing is resolved through `bundle`.
+    fn fixture_gem_cache() -> HashMap<String, PathBuf> {
+        let gems = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config_schema/gems");
+        OPTION_GEMS
+            .iter()
+            .map(|name| (name.to_string(), gems.join(name)))
+            .collect()
+    }
+
+    #[test]
+    fn loads_options_from_gem_defaults() {
+        let gem_cache = fixture_gem_cache();
+        let opts = load_cop_options(Path::new("."), Some(&gem_cache));
+        assert_eq!(
+            opts["Style/StringLiterals"]["SupportedStyles"],
+            json!(["single_quotes", "double_quotes"])
+        );
+        assert_eq!(opts["RSpec/ExampleLength"]["Max"], 5);
+        // Department entries and AllCops are not cops
+        assert!(!opts.contains_key("AllCops"));
+        assert!(!opts.contains_key("RSpec"));
+    }
+
+    #[test]
+    fn missing_rubocop_gem_gives_no_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let gem_cache = HashMap::from([("rubocop".to_string(), dir.path().to_path_buf())]);
+        assert!(load_cop_options(dir.path(), Some(&gem_cache)).is_empty());
+    }
+
+    /// The committed schema snapshot must match what the registry and the
+    /// fixture gem defaults produce. Regenerate it deliberately with
+    /// `NITROCOP_UPDATE_SCHEMA_SNAPSHOT=1 cargo test config_schema`.
+    #[test]
+    fn schema_matches_snapshot() {
+        let snapshot_path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/rubocop_config_schema.json");
+        let gem_cache = fixture_gem_cache();
+        let schema = build_schema(
+            &CopRegistry::default_registry(),
+            &load_cop_options(Path::new("."), Some(&gem_cache)),
+        );
+        if std::env::var("NITROCOP_UPDATE_SCHEMA_SNAPSHOT").is_ok() {
+            write_schema(&schema, &snapshot_path).unwrap();
+            return;
+        }
+        let snapshot: Value = serde_json::from_str(
+            &std::fs::read_to_string(&snapshot_path).expect("schema snapshot should exist"),
+        )
+        .expect("schema snapshot should be valid JSON");
+        assert!(
+            schema == snapshot,
+            "schema differs from {}; regenerate with NITROCOP_UPDATE_SCHEMA_SNAPSHOT=1",
+            snapshot_path.display()
+        );
+    }
+}
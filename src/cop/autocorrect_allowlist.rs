@@ -21,6 +21,12 @@ impl AutocorrectAllowlist {
     pub fn contains(&self, name: &str) -> bool {
         self.cops.contains(name)
     }
+
+    /// Allow `-a` to apply a cop's corrections, e.g. for a cop registered by
+    /// an embedding binary that vouches for its safety.
+    pub fn insert(&mut self, name: &str) {
+        self.cops.insert(name.to_string());
+    }
 }
 
 #[cfg(test)]
@@ -3,7 +3,7 @@ use std::collections::hash_map::Entry;
 
 use ruby_prism::Visit;
 
-use crate::cop::shared::util;
+use super::GemDeclaration;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
@@ -366,14 +366,6 @@ fn collect_case_matchable_sources(
     matchable
 }
 
-fn gem_name_from_call(call: &ruby_prism::CallNode<'_>) -> Option<Vec<u8>> {
-    if call.receiver().is_some() || call.name().as_slice() != b"gem" {
-        return None;
-    }
-    let first_arg = util::first_positional_arg(call)?;
-    util::string_value(&first_arg)
-}
-
 /// Check if a node is a "transparent" wrapper that should not create an
 /// opaque block frame.
 ///
@@ -515,7 +507,7 @@ impl<'pr> Visit<'pr> for GemDeclarationVisitor<'_> {
     }
 
     fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
-        if let Some(gem_name) = gem_name_from_call(node) {
+        if let Some(GemDeclaration { name: gem_name, .. }) = GemDeclaration::from_call(node) {
             let loc = node.message_loc().unwrap_or(node.location());
             let (line, column) = self.source.offset_to_line_col(loc.start_offset());
             let (conditional_root, mut blocks_above_conditional) = self.conditional_info();
@@ -6,7 +6,7 @@ use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
-use super::extract_gem_name;
+use super::{GemDeclaration, extract_gem_name};
 
 pub struct GemComment;
 
@@ -90,16 +90,19 @@ impl Cop for GemComment {
     ) {
         let ignored_gems = config.get_string_array("IgnoredGems").unwrap_or_default();
         let only_for = config.get_string_array("OnlyFor").unwrap_or_default();
-        let check_version_specifiers = only_for.iter().any(|s| s == "version_specifiers");
 
-        // Use AST visitor to find gem lines inside modifier if/unless
-        let mut visitor = ModifierGemVisitor {
+        // Use AST visitor to find gem lines inside modifier if/unless, and
+        // the gems that use an `OnlyFor` option
+        let mut visitor = GemCallVisitor {
             source,
+            only_for: &only_for,
             modifier_gem_lines: HashSet::new(),
+            checked_gem_lines: HashSet::new(),
             in_modifier_conditional: false,
         };
         visitor.visit(&parse_result.node());
         let modifier_gem_lines = visitor.modifier_gem_lines;
+        let checked_gem_lines = visitor.checked_gem_lines;
 
         // Line-based gem detection and comment checking (proven approach)
         let lines: Vec<&[u8]> = source.lines().collect();
@@ -142,13 +145,13 @@ impl Cop for GemComment {
                     continue;
                 }
 
-                // When OnlyFor includes "version_specifiers", only flag gems with version constraints
-                if check_version_specifiers && !has_version_specifier(line_str) {
+                let line_num = i + 1; // 1-based
+
+                // With OnlyFor set, only gems using one of the listed options need a comment
+                if !only_for.is_empty() && !checked_gem_lines.contains(&line_num) {
                     continue;
                 }
 
-                let line_num = i + 1; // 1-based
-
                 // Check if this gem line is inside a modifier if/unless
                 let is_modifier = modifier_gem_lines.contains(&line_num);
 
@@ -182,22 +185,29 @@ impl Cop for GemComment {
 }
 
 /// AST visitor that collects 1-based line numbers of gem CallNodes
-/// that are directly inside a modifier if/unless.
-struct ModifierGemVisitor<'a> {
+/// that are directly inside a modifier if/unless, and of gem declarations
+/// using an option listed in `OnlyFor`.
+struct GemCallVisitor<'a> {
     source: &'a SourceFile,
+    only_for: &'a [String],
     modifier_gem_lines: HashSet<usize>,
+    checked_gem_lines: HashSet<usize>,
     in_modifier_conditional: bool,
 }
 
-impl<'pr> Visit<'pr> for ModifierGemVisitor<'_> {
+impl<'pr> Visit<'pr> for GemCallVisitor<'_> {
     fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
-        if self.in_modifier_conditional
-            && node.receiver().is_none()
-            && node.name().as_slice() == b"gem"
-        {
+        if node.receiver().is_none() && node.name().as_slice() == b"gem" {
             let loc = node.location();
             let (line, _) = self.source.offset_to_line_col(loc.start_offset());
-            self.modifier_gem_lines.insert(line);
+            if self.in_modifier_conditional {
+                self.modifier_gem_lines.insert(line);
+            }
+            if GemDeclaration::from_call(node)
+                .is_some_and(|gem| has_checked_option(&gem, self.only_for))
+            {
+                self.checked_gem_lines.insert(line);
+            }
         }
         // Don't propagate in_modifier_conditional into block bodies.
         // `group :dev do...end unless cond` — the modifier wraps the block call,
@@ -304,57 +314,38 @@ fn has_continuation_comment(lines: &[&[u8]], gem_line_idx: usize) -> bool {
     false
 }
 
-/// Check if a gem declaration line has a version specifier.
-/// Version specifiers look like: '~> 1.0', '>= 2.0', '1.0', etc.
-fn has_version_specifier(line: &str) -> bool {
-    let trimmed = line.trim();
-    // After `gem 'name'`, look for version-like arguments
-    // Find the closing quote of the gem name
-    let first_quote = match trimmed.find(['\'', '"']) {
-        Some(idx) => idx,
-        None => return false,
-    };
-    let quote_char = trimmed.as_bytes()[first_quote];
-    let after_name_start = first_quote + 1;
-    let name_end = match trimmed[after_name_start..].find(|c: char| c as u8 == quote_char) {
-        Some(idx) => after_name_start + idx + 1,
-        None => return false,
-    };
-
-    let rest = &trimmed[name_end..];
-    // Look for version string patterns after a comma
-    // A version string starts with optional operator (>=, ~>, <=, >, <, =, !=) then digits
-    if let Some(comma_idx) = rest.find(',') {
-        let after_comma = rest[comma_idx + 1..].trim();
-        // Check if next argument is a quoted version string
-        if after_comma.starts_with('\'') || after_comma.starts_with('"') {
-            let q = after_comma.as_bytes()[0];
-            if let Some(end) = after_comma[1..].find(|c: char| c as u8 == q) {
-                let val = &after_comma[1..1 + end];
-                if is_version_string(val) {
-                    return true;
-                }
-            }
+/// Whether the gem uses an option listed in `OnlyFor`: a version
+/// (`version_specifiers`), a version that pins or caps it
+/// (`restrictive_version_specifiers`), or an options hash key such as
+/// `github` or `platforms`.
+fn has_checked_option(gem: &GemDeclaration<'_, '_>, only_for: &[String]) -> bool {
+    let includes = |option: &str| only_for.iter().any(|o| o == option);
+    if gem.has_version_argument() {
+        if includes("version_specifiers") {
+            return true;
+        }
+        if includes("restrictive_version_specifiers")
+            && gem
+                .version_requirements()
+                .iter()
+                .any(|req| is_restrictive_version(req))
+        {
+            return true;
         }
     }
-    false
+    gem.option_keys()
+        .iter()
+        .any(|key| only_for.iter().any(|o| o.as_bytes() == key.as_slice()))
 }
 
-/// Check if a string looks like a version specifier.
-/// Examples: "1.0", "~> 1.0", ">= 2.0", "< 3.0"
-fn is_version_string(s: &str) -> bool {
-    let s = s.trim();
-    let s = s
-        .trim_start_matches("~>")
-        .trim_start_matches(">=")
-        .trim_start_matches("<=")
-        .trim_start_matches("!=")
-        .trim_start_matches('>')
-        .trim_start_matches('<')
-        .trim_start_matches('=')
-        .trim();
-    // Should start with a digit
-    s.starts_with(|c: char| c.is_ascii_digit())
+/// RuboCop's `/\A\s*(?:<|~>|\d|=)/`: an exact, upper-bounded or pessimistic
+/// requirement, as opposed to a lower bound like `>= 1.0`.
+fn is_restrictive_version(requirement: &[u8]) -> bool {
+    let rest = requirement.trim_ascii_start();
+    rest.starts_with(b"<")
+        || rest.starts_with(b"~>")
+        || rest.starts_with(b"=")
+        || rest.first().is_some_and(u8::is_ascii_digit)
 }
 
 /// Check if the line has an inline comment (# after the gem declaration).
@@ -419,4 +410,56 @@ fn is_magic_comment(line: &str) -> bool {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(GemComment, "cops/bundler/gem_comment");
+
+    fn assert_only_for_fixtures(yaml: &str, offense: &[u8], no_offense: &[u8]) {
+        crate::testutil::assert_cop_offenses_with_yaml(&GemComment, yaml, offense);
+        crate::testutil::assert_cop_no_offenses_with_yaml(&GemComment, yaml, no_offense);
+    }
+
+    #[test]
+    fn only_for_version_specifiers_fixtures() {
+        assert_only_for_fixtures(
+            "OnlyFor:\n  - version_specifiers\n",
+            include_bytes!(
+                "../../../tests/fixtures/cops/bundler/gem_comment/offense.version_specifiers.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/bundler/gem_comment/no_offense.version_specifiers.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn only_for_restrictive_version_specifiers_fixtures() {
+        assert_only_for_fixtures(
+            "OnlyFor:\n  - restrictive_version_specifiers\n",
+            include_bytes!(
+                "../../../tests/fixtures/cops/bundler/gem_comment/offense.restrictive.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/bundler/gem_comment/no_offense.restrictive.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn only_for_options_fixtures() {
+        assert_only_for_fixtures(
+            "OnlyFor: [github, git, source, platforms]\nIgnoredGems: [rubocop]\n",
+            include_bytes!("../../../tests/fixtures/cops/bundler/gem_comment/offense.options.rb"),
+            include_bytes!(
+                "../../../tests/fixtures/cops/bundler/gem_comment/no_offense.options.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn restrictive_version_patterns() {
+        for req in ["~> 1.0", "< 2", "= 1.2.3", "1.2.3", "  ~> 0.9"] {
+            assert!(is_restrictive_version(req.as_bytes()), "{req}");
+        }
+        for req in [">= 1.0", "> 1", "!= 1.0", ""] {
+            assert!(!is_restrictive_version(req.as_bytes()), "{req}");
+        }
+    }
 }
@@ -4,6 +4,8 @@ use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 use ruby_prism::Visit;
 
+use super::GemDeclaration;
+
 pub struct GemVersion;
 
 /// ## Corpus investigation (2026-03-03)
@@ -105,25 +107,23 @@ struct GemVersionVisitor<'a> {
 
 impl<'pr> Visit<'pr> for GemVersionVisitor<'_> {
     fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
-        if node.receiver().is_some() || node.name().as_slice() != b"gem" {
-            ruby_prism::visit_call_node(self, node);
-            return;
-        }
-
-        let Some(gem_name) = gem_name_from_call(node) else {
+        let Some(gem) = GemDeclaration::from_call(node) else {
             ruby_prism::visit_call_node(self, node);
             return;
         };
         if self
             .allowed_gems
             .iter()
-            .any(|allowed| allowed.as_bytes() == gem_name.as_slice())
+            .any(|allowed| allowed.as_bytes() == gem.name.as_slice())
         {
             ruby_prism::visit_call_node(self, node);
             return;
         }
 
-        let has_version_spec = includes_version_specification(node);
+        let has_version_spec = gem
+            .version_requirements()
+            .iter()
+            .any(|req| is_version_specification(req));
         let has_commit_ref = includes_commit_reference(node);
         let offense = match self.enforced_style {
             "required" => !has_version_spec && !has_commit_ref,
@@ -151,38 +151,6 @@ impl<'pr> Visit<'pr> for GemVersionVisitor<'_> {
     }
 }
 
-fn gem_name_from_call(call: &ruby_prism::CallNode<'_>) -> Option<Vec<u8>> {
-    let first_arg = util::first_positional_arg(call)?;
-    util::string_value(&first_arg)
-}
-
-fn includes_version_specification(call: &ruby_prism::CallNode<'_>) -> bool {
-    let Some(args) = call.arguments() else {
-        return false;
-    };
-
-    let mut positional_index = 0usize;
-    for arg in args.arguments().iter() {
-        if arg.as_keyword_hash_node().is_some() {
-            continue;
-        }
-
-        if positional_index == 0 {
-            positional_index += 1;
-            continue;
-        }
-        positional_index += 1;
-
-        if let Some(s) = arg.as_string_node() {
-            if is_version_specification(s.unescaped()) {
-                return true;
-            }
-        }
-    }
-
-    false
-}
-
 fn includes_commit_reference(call: &ruby_prism::CallNode<'_>) -> bool {
     [b"branch".as_slice(), b"ref".as_slice(), b"tag".as_slice()]
         .iter()
@@ -7,6 +7,7 @@ pub mod insecure_protocol_source;
 pub mod ordered_gems;
 
 use super::registry::CopRegistry;
+use super::shared::util;
 
 pub fn register_all(registry: &mut CopRegistry) {
     registry.register(Box::new(duplicated_gem::DuplicatedGem));
@@ -41,3 +42,72 @@ pub fn extract_gem_name(line: &str) -> Option<&str> {
     }
     Some(name)
 }
+
+/// A `gem` declaration read from the AST: a receiverless `gem` call whose
+/// first argument is a plain string literal.
+pub struct GemDeclaration<'a, 'pr> {
+    call: &'a ruby_prism::CallNode<'pr>,
+    pub name: Vec<u8>,
+}
+
+impl<'a, 'pr> GemDeclaration<'a, 'pr> {
+    pub fn from_call(call: &'a ruby_prism::CallNode<'pr>) -> Option<Self> {
+        if call.receiver().is_some() || call.name().as_slice() != b"gem" {
+            return None;
+        }
+        let first_arg = util::first_positional_arg(call)?;
+        let name = util::string_value(&first_arg)?;
+        Some(Self { call, name })
+    }
+
+    /// Whether the argument right after the name is a string, as in
+    /// `gem 'rails', '~> 7.0'`.
+    pub fn has_version_argument(&self) -> bool {
+        self.call
+            .arguments()
+            .and_then(|args| args.arguments().iter().nth(1))
+            .is_some_and(|arg| arg.as_string_node().is_some())
+    }
+
+    /// The string literal arguments after the name, i.e. the version
+    /// requirements.
+    pub fn version_requirements(&self) -> Vec<Vec<u8>> {
+        let Some(args) = self.call.arguments() else {
+            return Vec::new();
+        };
+        args.arguments()
+            .iter()
+            .skip(1)
+            .filter_map(|arg| util::string_value(&arg))
+            .collect()
+    }
+
+    /// The keys of the trailing options hash (`github:`, `require:`, ...).
+    pub fn option_keys(&self) -> Vec<Vec<u8>> {
+        let Some(last) = self
+            .call
+            .arguments()
+            .and_then(|args| args.arguments().iter().last())
+        else {
+            return Vec::new();
+        };
+        let elements = if let Some(hash) = last.as_keyword_hash_node() {
+            hash.elements()
+        } else if let Some(hash) = last.as_hash_node() {
+            hash.elements()
+        } else {
+            return Vec::new();
+        };
+        elements
+            .iter()
+            .filter_map(|elem| {
+                let key = elem.as_assoc_node()?.key();
+                if let Some(sym) = key.as_symbol_node() {
+                    Some(sym.unescaped().to_vec())
+                } else {
+                    util::string_value(&key)
+                }
+            })
+            .collect()
+    }
+}
@@ -1,3 +1,6 @@
+use crate::cop::shared::ordered_gem_node::{
+    OrderedEntry, line_byte_ranges, sort_key, sorted_window_replacement,
+};
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
@@ -18,19 +21,16 @@ use crate::parse::source::SourceFile;
 /// Fixed by adding `b'/'` to the boundary exclusion set in `extract_literal_gem_name`.
 /// In Gemfiles, `gem` method calls are never preceded by `/`; this only occurs in
 /// regex literals or path strings.
+///
+/// ## Autocorrect
+///
+/// With `TreatCommentsAsGroupSeparators: false`, comment lines directly above a
+/// gem are attached to it and move with it when the window is reordered
+/// (RuboCop's `OrderedGemCorrector` uses `declaration_with_comment`). Blank
+/// lines, `group`/`platforms` blocks, and any other non-gem statement close
+/// the current window.
 pub struct OrderedGems;
 
-/// A gem entry within a group, tracking its byte range for autocorrect.
-struct GemEntry {
-    #[allow(dead_code)]
-    name: String,
-    sort_key: String,
-    /// Byte offset of the start of the line (inclusive).
-    line_start: usize,
-    /// Byte offset past the end of the line including newline (exclusive).
-    line_end: usize,
-}
-
 impl Cop for OrderedGems {
     fn name(&self) -> &'static str {
         "Bundler/OrderedGems"
@@ -58,54 +58,32 @@ impl Cop for OrderedGems {
         let mut prev_gem: Option<(String, String)> = None; // (original_name, sort_key)
         let mut in_block_comment = false;
 
-        // Track gem groups for autocorrect: groups of contiguous gem lines
-        let mut gem_group: Vec<GemEntry> = Vec::new();
+        // Contiguous gem declarations forming one ordering window (for autocorrect)
+        let mut gem_group: Vec<OrderedEntry> = Vec::new();
+        // Start of the comment lines directly above the next gem, which move
+        // together with it when comments are not group separators.
+        let mut pending_comment_start: Option<usize> = None;
 
-        // Compute line byte ranges
-        let mut line_offsets: Vec<(usize, usize)> = Vec::new(); // (start, end) for each line
-        {
-            let mut offset = 0;
-            for line in source.lines() {
-                let start = offset;
-                offset += line.len();
-                // Skip past newline
-                if offset < bytes.len() && bytes[offset] == b'\n' {
-                    offset += 1;
-                }
-                line_offsets.push((start, offset));
-            }
-        }
+        let line_offsets = line_byte_ranges(source);
 
-        let flush_group = |gem_group: &mut Vec<GemEntry>,
-                           corrections: &mut Option<&mut Vec<crate::correction::Correction>>,
-                           bytes: &[u8]| {
-            if gem_group.len() >= 2 {
+        let flush_group =
+            |gem_group: &mut Vec<OrderedEntry>,
+             corrections: &mut Option<&mut Vec<crate::correction::Correction>>| {
                 if let Some(corr) = corrections {
-                    // Check if the group is already sorted
-                    let is_sorted = gem_group.windows(2).all(|w| w[0].sort_key <= w[1].sort_key);
-                    if !is_sorted {
-                        let group_start = gem_group.first().unwrap().line_start;
-                        let group_end = gem_group.last().unwrap().line_end;
-                        // Sort by sort_key using index array
-                        let mut indices: Vec<usize> = (0..gem_group.len()).collect();
-                        indices.sort_by(|&a, &b| gem_group[a].sort_key.cmp(&gem_group[b].sort_key));
-                        let sorted: Vec<&[u8]> = indices
-                            .iter()
-                            .map(|&i| &bytes[gem_group[i].line_start..gem_group[i].line_end])
-                            .collect();
-                        let replacement: Vec<u8> = sorted.concat();
+                    if let Some((start, end, replacement)) =
+                        sorted_window_replacement(bytes, gem_group)
+                    {
                         corr.push(crate::correction::Correction {
-                            start: group_start,
-                            end: group_end,
-                            replacement: String::from_utf8_lossy(&replacement).to_string(),
+                            start,
+                            end,
+                            replacement,
                             cop_name: "Bundler/OrderedGems",
                             cop_index: 0,
                         });
                     }
                 }
-            }
-            gem_group.clear();
-        };
+                gem_group.clear();
+            };
 
         for (i, line) in source.lines().enumerate() {
             let line_str = std::str::from_utf8(line).unwrap_or("");
@@ -117,7 +95,7 @@ impl Cop for OrderedGems {
                 if trimmed.starts_with("=end") {
                     in_block_comment = false;
                     prev_gem = None;
-                    flush_group(&mut gem_group, &mut corrections, bytes);
+                    flush_group(&mut gem_group, &mut corrections);
                 }
                 continue;
             }
@@ -125,22 +103,27 @@ impl Cop for OrderedGems {
             if trimmed.starts_with("=begin") {
                 in_block_comment = true;
                 prev_gem = None;
-                flush_group(&mut gem_group, &mut corrections, bytes);
+                pending_comment_start = None;
+                flush_group(&mut gem_group, &mut corrections);
                 continue;
             }
 
             // Blank lines reset the ordering group
             if trimmed.is_empty() {
-                flush_group(&mut gem_group, &mut corrections, bytes);
+                flush_group(&mut gem_group, &mut corrections);
                 prev_gem = None;
+                pending_comment_start = None;
                 continue;
             }
 
-            // Comments may reset the ordering group
+            // Comments may reset the ordering group; otherwise they attach to
+            // the gem declaration that follows them.
             if trimmed.starts_with('#') {
                 if treat_comments_as_separators {
-                    flush_group(&mut gem_group, &mut corrections, bytes);
+                    flush_group(&mut gem_group, &mut corrections);
                     prev_gem = None;
+                } else if pending_comment_start.is_none() {
+                    pending_comment_start = Some(line_start);
                 }
                 continue;
             }
@@ -148,7 +131,7 @@ impl Cop for OrderedGems {
             // Non-gem, non-blank, non-comment lines (like `group`, `source`, etc.)
             // also reset the ordering group
             if let Some(gem_name) = extract_literal_gem_name(line_str) {
-                let sort_key = make_sort_key(gem_name, consider_punctuation);
+                let sort_key = sort_key(gem_name, consider_punctuation);
                 let col = line_str.len() - line_str.trim_start().len();
 
                 if let Some((ref prev_name, ref prev_key)) = prev_gem {
@@ -169,11 +152,10 @@ impl Cop for OrderedGems {
                     }
                 }
 
-                gem_group.push(GemEntry {
-                    name: gem_name.to_string(),
+                gem_group.push(OrderedEntry {
                     sort_key: sort_key.clone(),
-                    line_start,
-                    line_end,
+                    start: pending_comment_start.take().unwrap_or(line_start),
+                    end: line_end,
                 });
 
                 prev_gem = Some((gem_name.to_string(), sort_key));
@@ -181,17 +163,18 @@ impl Cop for OrderedGems {
                 // Continuation lines of multi-line gem declarations — extend the last
                 // gem entry's range to include this line
                 if let Some(last) = gem_group.last_mut() {
-                    last.line_end = line_end;
+                    last.end = line_end;
                 }
             } else {
                 // Non-gem declaration resets the group (group, source, platforms, etc.)
-                flush_group(&mut gem_group, &mut corrections, bytes);
+                flush_group(&mut gem_group, &mut corrections);
                 prev_gem = None;
+                pending_comment_start = None;
             }
         }
 
         // Flush remaining group
-        flush_group(&mut gem_group, &mut corrections, bytes);
+        flush_group(&mut gem_group, &mut corrections);
     }
 }
 
@@ -316,17 +299,6 @@ fn strip_comment(line: &str) -> &str {
     line
 }
 
-/// Create a sort key for case-insensitive comparison.
-/// When consider_punctuation is false, strip `-` and `_` for comparison.
-fn make_sort_key(name: &str, consider_punctuation: bool) -> String {
-    let lower = name.to_lowercase();
-    if consider_punctuation {
-        lower
-    } else {
-        lower.replace(['-', '_'], "")
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -365,6 +337,92 @@ mod tests {
         assert_eq!(corrected, b"gem 'a'\ngem 'b'\n\ngem 'c'\ngem 'd'\n");
     }
 
+    fn config(options: &[(&str, bool)]) -> CopConfig {
+        CopConfig {
+            options: options
+                .iter()
+                .map(|(k, v)| (k.to_string(), serde_yml::Value::Bool(*v)))
+                .collect(),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn comments_attach_to_following_gem_when_not_separators() {
+        let input = b"# Web server\ngem 'puma'\n# XML\n# parsing\ngem 'nokogiri'\n";
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect_with_config(
+            &OrderedGems,
+            input,
+            config(&[("TreatCommentsAsGroupSeparators", false)]),
+        );
+        assert_eq!(diags.len(), 1);
+        let cs = crate::correction::CorrectionSet::from_vec(corrections);
+        assert_eq!(
+            cs.apply(input),
+            b"# XML\n# parsing\ngem 'nokogiri'\n# Web server\ngem 'puma'\n"
+        );
+    }
+
+    #[test]
+    fn offense_comments() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &OrderedGems,
+            include_bytes!("../../../tests/fixtures/cops/bundler/ordered_gems/offense.comments.rb"),
+            config(&[("TreatCommentsAsGroupSeparators", false)]),
+        );
+    }
+
+    #[test]
+    fn autocorrect_comments() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &OrderedGems,
+            include_bytes!("../../../tests/fixtures/cops/bundler/ordered_gems/offense.comments.rb"),
+            include_bytes!(
+                "../../../tests/fixtures/cops/bundler/ordered_gems/corrected.comments.rb"
+            ),
+            config(&[("TreatCommentsAsGroupSeparators", false)]),
+        );
+    }
+
+    #[test]
+    fn comments_separate_windows_by_default() {
+        let input = b"gem 'puma'\n# XML\ngem 'nokogiri'\n";
+        assert!(crate::testutil::run_cop(&OrderedGems, input).is_empty());
+    }
+
+    #[test]
+    fn consider_punctuation_changes_comparison() {
+        let input = b"gem 'rspec_junit_formatter'\ngem 'rspec-rails'\n";
+        assert!(crate::testutil::run_cop(&OrderedGems, input).is_empty());
+        let diags = crate::testutil::run_cop_with_config(
+            &OrderedGems,
+            input,
+            config(&[("ConsiderPunctuation", true)]),
+        );
+        assert_eq!(diags.len(), 1);
+    }
+
+    #[test]
+    fn autocorrect_last_line_without_newline() {
+        let input = b"gem 'zoo'\ngem 'alpha'";
+        let (_diags, corrections) = crate::testutil::run_cop_autocorrect(&OrderedGems, input);
+        let cs = crate::correction::CorrectionSet::from_vec(corrections);
+        assert_eq!(cs.apply(input), b"gem 'alpha'\ngem 'zoo'");
+    }
+
+    #[test]
+    fn autocorrect_group_blocks_are_separate_windows() {
+        let input =
+            b"gem 'zoo'\ngem 'alpha'\ngroup :test do\n  gem 'rspec'\n  gem 'capybara'\nend\n";
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect(&OrderedGems, input);
+        assert_eq!(diags.len(), 2);
+        let cs = crate::correction::CorrectionSet::from_vec(corrections);
+        assert_eq!(
+            cs.apply(input),
+            b"gem 'alpha'\ngem 'zoo'\ngroup :test do\n  gem 'capybara'\n  gem 'rspec'\nend\n"
+        );
+    }
+
     #[test]
     fn autocorrect_multiline_gem() {
         let input = b"gem 'rubocop',\n    '0.1.1'\ngem 'rspec'\n";
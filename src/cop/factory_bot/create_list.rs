@@ -7,6 +7,7 @@ use crate::cop::shared::node_type::{
     STATEMENTS_NODE, STRING_NODE, SYMBOL_NODE,
 };
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
@@ -39,6 +40,15 @@ use crate::parse::source::SourceFile;
 /// checked explicit `BlockParametersNode` params, missing `NumberedParametersNode`.
 /// Fix: detect `NumberedParametersNode` and return true (Prism only assigns it when
 /// numbered params appear in the body, so presence = used). Commit below.
+///
+/// ## Autocorrect (2026-10-17)
+///
+/// `create_list` style rewrites `n.times { create :user, attrs }` (and
+/// `n.times.map`, `Array.new(n)`, repeated array elements) to
+/// `create_list :user, n, attrs`; a block passed to `create` moves to
+/// `create_list`. `n_times` style rewrites `create_list :user, n, attrs` to
+/// `n.times.map { create :user, attrs }`, moving a one-line block that takes
+/// only the record. Blocks that use their index are never flagged.
 pub struct CreateList;
 
 impl Cop for CreateList {
@@ -73,6 +83,10 @@ impl Cop for CreateList {
         ]
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_node(
         &self,
         source: &SourceFile,
@@ -80,14 +94,21 @@ impl Cop for CreateList {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let style = config.get_str("EnforcedStyle", "create_list");
         let explicit_only = config.get_bool("ExplicitOnly", false);
 
         // Check array literals with repeated create calls
         if let Some(array) = node.as_array_node() {
-            diagnostics.extend(self.check_array_literal(source, &array, style, explicit_only));
+            self.check_array_literal(
+                source,
+                &array,
+                style,
+                explicit_only,
+                diagnostics,
+                corrections,
+            );
             return;
         }
 
@@ -97,11 +118,15 @@ impl Cop for CreateList {
         };
 
         if style == "create_list" {
-            diagnostics.extend(self.check_for_create_list_style(source, &call, explicit_only));
-        }
-
-        if style == "n_times" {
-            diagnostics.extend(self.check_for_n_times_style(source, &call, explicit_only));
+            self.check_for_create_list_style(
+                source,
+                &call,
+                explicit_only,
+                diagnostics,
+                corrections,
+            );
+        } else if style == "n_times" {
+            self.check_for_n_times_style(source, &call, explicit_only, diagnostics, corrections);
         }
     }
 }
@@ -114,10 +139,12 @@ impl CreateList {
         array: &ruby_prism::ArrayNode<'_>,
         style: &str,
         explicit_only: bool,
-    ) -> Vec<Diagnostic> {
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
+    ) {
         let elements: Vec<_> = array.elements().iter().collect();
         if elements.len() < 2 {
-            return Vec::new();
+            return;
         }
 
         // All elements must be create calls
@@ -125,13 +152,13 @@ impl CreateList {
         for elem in &elements {
             let call = match elem.as_call_node() {
                 Some(c) => c,
-                None => return Vec::new(),
+                None => return,
             };
             if call.name().as_slice() != b"create" {
-                return Vec::new();
+                return;
             }
             if !is_factory_call(call.receiver(), explicit_only) {
-                return Vec::new();
+                return;
             }
             create_calls.push(call);
         }
@@ -142,7 +169,7 @@ impl CreateList {
             .iter()
             .any(|c| c.block().is_some() != first_has_block)
         {
-            return Vec::new();
+            return;
         }
 
         // All create calls must have the same source representation of arguments
@@ -151,7 +178,7 @@ impl CreateList {
 
         for call in &create_calls[1..] {
             if get_args_source(call, source) != first_args_src {
-                return Vec::new();
+                return;
             }
         }
 
@@ -160,7 +187,7 @@ impl CreateList {
         if let Some(args) = first.arguments() {
             let arg_list: Vec<_> = args.arguments().iter().collect();
             if !arg_list.is_empty() && arg_list[0].as_interpolated_symbol_node().is_some() {
-                return Vec::new();
+                return;
             }
         }
 
@@ -175,14 +202,35 @@ impl CreateList {
         let loc = array.location();
         let (line, column) = source.offset_to_line_col(loc.start_offset());
         let count = elements.len();
+        let prefer_n_times = has_method_calls || style == "n_times";
 
-        let msg = if has_method_calls || style == "n_times" {
+        let msg = if prefer_n_times {
             format!("Prefer {}.times.map.", count)
         } else {
             "Prefer create_list.".to_string()
         };
 
-        vec![self.diagnostic(source, line, column, msg)]
+        let mut diagnostic = self.diagnostic(source, line, column, msg);
+        let replacement = if prefer_n_times {
+            Some(format!(
+                "{}.times.map {{ {} }}",
+                count,
+                node_source(&first.location())
+            ))
+        } else {
+            create_list_replacement(source, first, &count.to_string(), column)
+        };
+        if let (Some(corrections), Some(replacement)) = (corrections, replacement) {
+            corrections.push(Correction {
+                start: loc.start_offset(),
+                end: loc.end_offset(),
+                replacement,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diagnostic.corrected = true;
+        }
+        diagnostics.push(diagnostic);
     }
 
     /// With create_list style: flag `n.times { create :user }` and `n.times.map { create ... }` blocks
@@ -191,67 +239,83 @@ impl CreateList {
         source: &SourceFile,
         call: &ruby_prism::CallNode<'_>,
         explicit_only: bool,
-    ) -> Vec<Diagnostic> {
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
+    ) {
         // Check if this call has a block containing a single create
         let block = match call.block() {
             Some(b) => b,
-            None => return Vec::new(),
+            None => return,
         };
 
         let block_node = match block.as_block_node() {
             Some(b) => b,
-            None => return Vec::new(),
+            None => return,
         };
 
         // Extract repeat count from the call (n.times, n.times.map)
         let count = match get_repeat_count_from_source(call, source) {
             Some(c) if c > 1 => c,
-            _ => return Vec::new(),
+            _ => return,
         };
 
         // Check if block arg is used
         if block_param_is_used(&block_node) {
-            return Vec::new();
+            return;
         }
 
         let body = match block_node.body() {
             Some(b) => b,
-            None => return Vec::new(),
+            None => return,
         };
 
         // Body must be a single factory create call (or create with a sub-block)
         let body_call = match get_single_create_call(&body) {
             Some(c) => c,
-            None => return Vec::new(),
+            None => return,
         };
 
         if body_call.name().as_slice() != b"create" {
-            return Vec::new();
+            return;
         }
 
         if !is_factory_call(body_call.receiver(), explicit_only) {
-            return Vec::new();
+            return;
         }
 
         // Must have arguments, first must be symbol
         let args = match body_call.arguments() {
             Some(a) => a,
-            None => return Vec::new(),
+            None => return,
         };
         let arg_list: Vec<_> = args.arguments().iter().collect();
         if arg_list.is_empty() || arg_list[0].as_symbol_node().is_none() {
-            return Vec::new();
+            return;
         }
 
         // Check if arguments include a method call (rand, etc.)
         if arguments_include_method_call(&arg_list) {
-            return Vec::new();
+            return;
         }
 
-        let _ = count;
         let loc = call.location();
         let (line, column) = source.offset_to_line_col(loc.start_offset());
-        vec![self.diagnostic(source, line, column, "Prefer create_list.".to_string())]
+        let mut diagnostic =
+            self.diagnostic(source, line, column, "Prefer create_list.".to_string());
+        // The call's location includes its block, so the whole
+        // `n.times { ... }` expression is replaced.
+        let replacement = create_list_replacement(source, &body_call, &count.to_string(), column);
+        if let (Some(corrections), Some(replacement)) = (corrections, replacement) {
+            corrections.push(Correction {
+                start: loc.start_offset(),
+                end: loc.end_offset(),
+                replacement,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diagnostic.corrected = true;
+        }
+        diagnostics.push(diagnostic);
     }
 
     /// With n_times style: flag `create_list :user, 3` calls
@@ -260,46 +324,213 @@ impl CreateList {
         source: &SourceFile,
         call: &ruby_prism::CallNode<'_>,
         explicit_only: bool,
-    ) -> Vec<Diagnostic> {
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
+    ) {
         if call.name().as_slice() != b"create_list" {
-            return Vec::new();
+            return;
         }
 
         if !is_factory_call(call.receiver(), explicit_only) {
-            return Vec::new();
+            return;
         }
 
         let args = match call.arguments() {
             Some(a) => a,
-            None => return Vec::new(),
+            None => return,
         };
 
         let arg_list: Vec<_> = args.arguments().iter().collect();
         if arg_list.len() < 2 {
-            return Vec::new();
+            return;
         }
 
         // First arg: symbol or string (factory name)
         if arg_list[0].as_symbol_node().is_none() && arg_list[0].as_string_node().is_none() {
-            return Vec::new();
+            return;
         }
 
         // Second arg: integer (count)
         let count = match get_integer_value(&arg_list[1], source) {
             Some(c) => c,
-            None => return Vec::new(),
+            None => return,
         };
 
         if count < 2 {
-            return Vec::new();
+            return;
         }
 
         let msg_loc = call.message_loc().unwrap_or(call.location());
         let (line, column) = source.offset_to_line_col(msg_loc.start_offset());
-        vec![self.diagnostic(source, line, column, format!("Prefer {}.times.map.", count))]
+        let mut diagnostic =
+            self.diagnostic(source, line, column, format!("Prefer {}.times.map.", count));
+        if let (Some(corrections), Some(replacement)) =
+            (corrections, n_times_replacement(call, &arg_list))
+        {
+            let loc = call.location();
+            corrections.push(Correction {
+                start: loc.start_offset(),
+                end: loc.end_offset(),
+                replacement,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diagnostic.corrected = true;
+        }
+        diagnostics.push(diagnostic);
     }
 }
 
+/// Build `create_list(:factory, count, *options)` from a single `create` call,
+/// keeping its receiver and any block passed to it. `column` is where the
+/// replaced expression starts, used to place `end` of a multi-line block.
+/// Returns `None` when the call can't be rewritten faithfully.
+fn create_list_replacement(
+    source: &SourceFile,
+    create: &ruby_prism::CallNode<'_>,
+    count: &str,
+    column: usize,
+) -> Option<String> {
+    let args = create.arguments()?;
+    let arg_list: Vec<_> = args.arguments().iter().collect();
+    let (factory, options) = arg_list.split_first()?;
+
+    let mut arguments = vec![node_source(&factory.location()), count.to_string()];
+    arguments.extend(options.iter().map(|o| node_source(&o.location())));
+
+    let block = match create.block() {
+        Some(b) => Some(b.as_block_node()?),
+        None => None,
+    };
+    // `create_list :user, 3 { ... }` would attach the block to `3`, so a
+    // block always gets parentheses.
+    let parenthesized = create.opening_loc().is_some() || block.is_some();
+
+    let mut replacement = format_method_call(
+        create.receiver(),
+        "create_list",
+        &arguments.join(", "),
+        parenthesized,
+    );
+    if let Some(block) = block {
+        replacement.push_str(&format_block(source, &block, column)?);
+    }
+    Some(replacement)
+}
+
+/// Build `n.times.map { create(:factory, *options) }` from a `create_list` call.
+/// A block passed to `create_list` moves to `create` when it fits on one line
+/// and takes only the record; `create` doesn't yield an index.
+fn n_times_replacement(
+    call: &ruby_prism::CallNode<'_>,
+    arg_list: &[ruby_prism::Node<'_>],
+) -> Option<String> {
+    let mut arguments = vec![node_source(&arg_list[0].location())];
+    arguments.extend(arg_list[2..].iter().map(|o| node_source(&o.location())));
+
+    let block_source = match call.block() {
+        Some(block) => {
+            let block = block.as_block_node()?;
+            let block_src = node_source(&block.location());
+            if block_src.contains('\n') || block_param_count(&block)? > 1 {
+                return None;
+            }
+            Some(block_src)
+        }
+        None => None,
+    };
+    let parenthesized = call.opening_loc().is_some() || block_source.is_some();
+
+    let mut create = format_method_call(
+        call.receiver(),
+        "create",
+        &arguments.join(", "),
+        parenthesized,
+    );
+    if let Some(block_src) = block_source {
+        create.push(' ');
+        create.push_str(&block_src);
+    }
+    Some(format!(
+        "{}.times.map {{ {} }}",
+        node_source(&arg_list[1].location()),
+        create
+    ))
+}
+
+fn format_method_call(
+    receiver: Option<ruby_prism::Node<'_>>,
+    method: &str,
+    arguments: &str,
+    parenthesized: bool,
+) -> String {
+    let receiver = receiver
+        .map(|r| format!("{}.", node_source(&r.location())))
+        .unwrap_or_default();
+    if parenthesized {
+        format!("{receiver}{method}({arguments})")
+    } else {
+        format!("{receiver}{method} {arguments}")
+    }
+}
+
+/// Re-emit the block passed to `create`: a single statement stays on one line
+/// in braces, several statements become a `do ... end` block that keeps each
+/// statement's column and closes at `column`.
+fn format_block(
+    source: &SourceFile,
+    block: &ruby_prism::BlockNode<'_>,
+    column: usize,
+) -> Option<String> {
+    let params = match block.parameters() {
+        Some(p) => {
+            p.as_block_parameters_node()?;
+            format!("{} ", node_source(&p.location()))
+        }
+        None => String::new(),
+    };
+    let stmts = block.body()?.as_statements_node()?;
+    let statements: Vec<_> = stmts.body().iter().collect();
+
+    if let [statement] = statements.as_slice() {
+        return Some(format!(
+            " {{ {}{} }}",
+            params,
+            node_source(&statement.location())
+        ));
+    }
+
+    let mut out = format!(" do {params}").trim_end().to_string();
+    for statement in &statements {
+        let loc = statement.location();
+        let (_, statement_column) = source.offset_to_line_col(loc.start_offset());
+        out.push('\n');
+        out.push_str(&" ".repeat(statement_column));
+        out.push_str(&node_source(&loc));
+    }
+    out.push('\n');
+    out.push_str(&" ".repeat(column));
+    out.push_str("end");
+    Some(out)
+}
+
+fn node_source(loc: &ruby_prism::Location<'_>) -> String {
+    String::from_utf8_lossy(loc.as_slice()).into_owned()
+}
+
+/// Number of required parameters a block declares.
+fn block_param_count(block: &ruby_prism::BlockNode<'_>) -> Option<usize> {
+    let params = match block.parameters() {
+        Some(p) => p.as_block_parameters_node()?,
+        None => return Some(0),
+    };
+    Some(
+        params
+            .parameters()
+            .map_or(0, |p| p.requireds().iter().count()),
+    )
+}
+
 /// Get repeat count from source bytes (since integer value parsing needs source).
 fn get_repeat_count_from_source(
     call: &ruby_prism::CallNode<'_>,
@@ -540,4 +771,71 @@ fn get_integer_value(node: &ruby_prism::Node<'_>, source: &SourceFile) -> Option
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(CreateList, "cops/factorybot/create_list");
+    crate::cop_autocorrect_fixture_tests!(CreateList, "cops/factorybot/create_list");
+
+    fn n_times_config() -> CopConfig {
+        crate::testutil::cop_config_from_yaml("EnforcedStyle: n_times\n")
+    }
+
+    fn explicit_only_config() -> CopConfig {
+        crate::testutil::cop_config_from_yaml("ExplicitOnly: true\n")
+    }
+
+    #[test]
+    fn offense_n_times() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &CreateList,
+            include_bytes!(
+                "../../../tests/fixtures/cops/factorybot/create_list/offense.n_times.rb"
+            ),
+            n_times_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_n_times() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &CreateList,
+            include_bytes!(
+                "../../../tests/fixtures/cops/factorybot/create_list/no_offense.n_times.rb"
+            ),
+            n_times_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_n_times() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &CreateList,
+            include_bytes!(
+                "../../../tests/fixtures/cops/factorybot/create_list/offense.n_times.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/factorybot/create_list/corrected.n_times.rb"
+            ),
+            n_times_config(),
+        );
+    }
+
+    #[test]
+    fn offense_explicit_only() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &CreateList,
+            include_bytes!(
+                "../../../tests/fixtures/cops/factorybot/create_list/offense.explicit_only.rb"
+            ),
+            explicit_only_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_explicit_only() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &CreateList,
+            include_bytes!(
+                "../../../tests/fixtures/cops/factorybot/create_list/no_offense.explicit_only.rb"
+            ),
+            explicit_only_config(),
+        );
+    }
 }
@@ -155,6 +155,7 @@ impl<'pr> Visit<'pr> for StrategyFinder<'_> {
                                                 message: "Use an implicit, explicit or inline definition instead of hard coding a strategy for setting association within factory.".to_string(),
 
                                                 corrected: false,
+                                                end_column: None,
                                             });
                                         }
                                     }
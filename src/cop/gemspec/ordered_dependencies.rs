@@ -1,3 +1,6 @@
+use crate::cop::shared::ordered_gem_node::{
+    OrderedEntry, line_byte_ranges, sort_key, sorted_window_replacement,
+};
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
@@ -5,6 +8,10 @@ use crate::parse::source::SourceFile;
 /// ## Corpus investigation (2026-03-03)
 ///
 /// Corpus oracle (run 22651309591) reported FP=0, FN=0. 100% conformance.
+///
+/// Shares ordering and correction logic with `Bundler/OrderedGems` via
+/// `shared::ordered_gem_node`, including comment attachment when
+/// `TreatCommentsAsGroupSeparators` is false.
 pub struct OrderedDependencies;
 
 const DEP_METHODS: &[&str] = &[
@@ -15,13 +22,9 @@ const DEP_METHODS: &[&str] = &[
 
 struct DepEntry {
     gem_name: String,
-    sort_key: String,
     line_num: usize,
     col: usize,
-    /// Byte offset of the start of the line (inclusive).
-    line_start: usize,
-    /// Byte offset past the end of the line including newline (exclusive).
-    line_end: usize,
+    range: OrderedEntry,
 }
 
 impl Cop for OrderedDependencies {
@@ -50,30 +53,17 @@ impl Cop for OrderedDependencies {
         let bytes = source.as_bytes();
         let mut current_method: Option<String> = None;
         let mut group: Vec<DepEntry> = Vec::new();
+        // Start of the comment lines directly above the next dependency, which
+        // move together with it when comments are not group separators.
+        let mut pending_comment_start: Option<usize> = None;
 
-        // Compute line byte ranges
-        let mut line_offsets: Vec<(usize, usize)> = Vec::new();
-        {
-            let mut offset = 0;
-            for line in source.lines() {
-                let start = offset;
-                offset += line.len();
-                if offset < bytes.len() && bytes[offset] == b'\n' {
-                    offset += 1;
-                }
-                line_offsets.push((start, offset));
-            }
-        }
+        let line_offsets = line_byte_ranges(source);
 
         let flush_group_autocorrect = |group: &mut Vec<DepEntry>,
                                        diagnostics: &mut Vec<Diagnostic>,
-                                       source: &SourceFile,
-                                       cop: &OrderedDependencies,
-                                       _consider_punctuation: bool,
                                        corrections: &mut Option<
             &mut Vec<crate::correction::Correction>,
-        >,
-                                       bytes: &[u8]| {
+        >| {
             if group.len() < 2 {
                 group.clear();
                 return;
@@ -81,12 +71,12 @@ impl Cop for OrderedDependencies {
 
             // Report diagnostics
             for i in 1..group.len() {
-                let prev_key = &group[i - 1].sort_key;
-                let curr_key = &group[i].sort_key;
+                let prev_key = &group[i - 1].range.sort_key;
+                let curr_key = &group[i].range.sort_key;
                 if *curr_key < *prev_key {
                     let prev_name = &group[i - 1].gem_name;
                     let curr_name = &group[i].gem_name;
-                    let mut diag = cop.diagnostic(
+                    let mut diag = self.diagnostic(
                             source,
                             group[i].line_num,
                             group[i].col,
@@ -103,21 +93,12 @@ impl Cop for OrderedDependencies {
 
             // Generate correction if needed
             if let Some(corr) = corrections {
-                let is_sorted = group.windows(2).all(|w| w[0].sort_key <= w[1].sort_key);
-                if !is_sorted {
-                    let group_start = group.first().unwrap().line_start;
-                    let group_end = group.last().unwrap().line_end;
-                    let mut indices: Vec<usize> = (0..group.len()).collect();
-                    indices.sort_by(|&a, &b| group[a].sort_key.cmp(&group[b].sort_key));
-                    let sorted: Vec<&[u8]> = indices
-                        .iter()
-                        .map(|&i| &bytes[group[i].line_start..group[i].line_end])
-                        .collect();
-                    let replacement: Vec<u8> = sorted.concat();
+                let ranges: Vec<OrderedEntry> = group.drain(..).map(|e| e.range).collect();
+                if let Some((start, end, replacement)) = sorted_window_replacement(bytes, &ranges) {
                     corr.push(crate::correction::Correction {
-                        start: group_start,
-                        end: group_end,
-                        replacement: String::from_utf8_lossy(&replacement).to_string(),
+                        start,
+                        end,
+                        replacement,
                         cop_name: "Gemspec/OrderedDependencies",
                         cop_index: 0,
                     });
@@ -132,16 +113,9 @@ impl Cop for OrderedDependencies {
             let line_str = match std::str::from_utf8(line) {
                 Ok(s) => s,
                 Err(_) => {
-                    flush_group_autocorrect(
-                        &mut group,
-                        diagnostics,
-                        source,
-                        self,
-                        consider_punctuation,
-                        &mut corrections,
-                        bytes,
-                    );
+                    flush_group_autocorrect(&mut group, diagnostics, &mut corrections);
                     current_method = None;
+                    pending_comment_start = None;
                     continue;
                 }
             };
@@ -150,32 +124,20 @@ impl Cop for OrderedDependencies {
 
             // Blank lines act as group separators
             if trimmed.is_empty() {
-                flush_group_autocorrect(
-                    &mut group,
-                    diagnostics,
-                    source,
-                    self,
-                    consider_punctuation,
-                    &mut corrections,
-                    bytes,
-                );
+                flush_group_autocorrect(&mut group, diagnostics, &mut corrections);
                 current_method = None;
+                pending_comment_start = None;
                 continue;
             }
 
-            // Check if this is a comment line
+            // Comments may act as group separators; otherwise they attach to
+            // the dependency that follows them.
             if trimmed.starts_with('#') {
                 if treat_comments_as_separators {
-                    flush_group_autocorrect(
-                        &mut group,
-                        diagnostics,
-                        source,
-                        self,
-                        consider_punctuation,
-                        &mut corrections,
-                        bytes,
-                    );
+                    flush_group_autocorrect(&mut group, diagnostics, &mut corrections);
                     current_method = None;
+                } else if pending_comment_start.is_none() {
+                    pending_comment_start = Some(line_start);
                 }
                 continue;
             }
@@ -189,25 +151,19 @@ impl Cop for OrderedDependencies {
                     if let Some(gem_name) = extract_gem_name(after) {
                         if current_method.as_deref() != Some(method) {
                             // Different dependency type, flush previous group
-                            flush_group_autocorrect(
-                                &mut group,
-                                diagnostics,
-                                source,
-                                self,
-                                consider_punctuation,
-                                &mut corrections,
-                                bytes,
-                            );
+                            flush_group_autocorrect(&mut group, diagnostics, &mut corrections);
                             current_method = Some(method.to_string());
                         }
-                        let sk = sort_key(&gem_name, consider_punctuation);
+                        let sort_key = sort_key(&gem_name, consider_punctuation);
                         group.push(DepEntry {
                             gem_name,
-                            sort_key: sk,
                             line_num: line_idx + 1,
                             col: pos + 1, // after the dot
-                            line_start,
-                            line_end,
+                            range: OrderedEntry {
+                                sort_key,
+                                start: pending_comment_start.take().unwrap_or(line_start),
+                                end: line_end,
+                            },
                         });
                         found_dep = true;
                     }
@@ -216,45 +172,14 @@ impl Cop for OrderedDependencies {
             }
 
             if !found_dep && !trimmed.is_empty() {
-                flush_group_autocorrect(
-                    &mut group,
-                    diagnostics,
-                    source,
-                    self,
-                    consider_punctuation,
-                    &mut corrections,
-                    bytes,
-                );
+                flush_group_autocorrect(&mut group, diagnostics, &mut corrections);
                 current_method = None;
+                pending_comment_start = None;
             }
         }
 
         // Flush remaining group
-        flush_group_autocorrect(
-            &mut group,
-            diagnostics,
-            source,
-            self,
-            consider_punctuation,
-            &mut corrections,
-            bytes,
-        );
-    }
-}
-
-fn sort_key(name: &str, consider_punctuation: bool) -> String {
-    if consider_punctuation {
-        name.to_lowercase()
-    } else {
-        // Remove all hyphens and underscores, then lowercase.
-        // This matches RuboCop's `gem_canonical_name`: `name.tr('-_', '').downcase`
-        let mut result = String::with_capacity(name.len());
-        for c in name.chars() {
-            if c != '-' && c != '_' {
-                result.push(c.to_ascii_lowercase());
-            }
-        }
-        result
+        flush_group_autocorrect(&mut group, diagnostics, &mut corrections);
     }
 }
 
@@ -339,6 +264,55 @@ mod tests {
         );
     }
 
+    fn comments_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "TreatCommentsAsGroupSeparators".into(),
+                serde_yml::Value::Bool(false),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_comments() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &OrderedDependencies,
+            include_bytes!(
+                "../../../tests/fixtures/cops/gemspec/ordered_dependencies/offense.comments.rb"
+            ),
+            comments_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_comments() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &OrderedDependencies,
+            include_bytes!(
+                "../../../tests/fixtures/cops/gemspec/ordered_dependencies/offense.comments.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/gemspec/ordered_dependencies/corrected.comments.rb"
+            ),
+            comments_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_moves_attached_comments() {
+        let config = comments_config();
+        let input = b"Gem::Specification.new do |s|\n  # HTTP\n  s.add_dependency 'zoo'\n  # Parsing\n  s.add_dependency 'alpha'\nend\n";
+        let (diags, corrections) =
+            crate::testutil::run_cop_autocorrect_with_config(&OrderedDependencies, input, config);
+        assert_eq!(diags.len(), 1);
+        let cs = crate::correction::CorrectionSet::from_vec(corrections);
+        assert_eq!(
+            cs.apply(input),
+            b"Gem::Specification.new do |s|\n  # Parsing\n  s.add_dependency 'alpha'\n  # HTTP\n  s.add_dependency 'zoo'\nend\n"
+        );
+    }
+
     #[test]
     fn autocorrect_preserves_group_separators() {
         let input = b"Gem::Specification.new do |s|\n  s.add_dependency 'b'\n  s.add_dependency 'a'\n\n  s.add_development_dependency 'd'\n  s.add_development_dependency 'c'\nend\n";
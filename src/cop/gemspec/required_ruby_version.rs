@@ -1,3 +1,5 @@
+use ruby_prism::Visit;
+
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
@@ -38,38 +40,33 @@ use crate::parse::source::SourceFile;
 /// yielding ` = spec` as the RHS, which was treated as a local variable
 /// (dynamic). Fixed by using `find` + slice to get the full remainder after
 /// the first `.required_ruby_version`, so the RHS includes the actual value.
+///
+/// ## AST rewrite
+///
+/// The line scanner approximated RuboCop's node patterns and missed
+/// multi-line values and constraint arrays: it compared the first quoted
+/// string, so `['< 4', '>= 2.7']` read as `4`. The cop now visits
+/// `required_ruby_version=` calls and follows RuboCop directly: a string, a
+/// two-string array, or `Gem::Requirement.new` with string arguments gives
+/// the floor (the first constraint containing `>` or `=`); any other
+/// non-dynamic value, `'>= 3.1'.freeze` included, has no extractable version
+/// and is reported. Offenses sit at the start of the assigned value.
 pub struct RequiredRubyVersion;
 
+const MISSING_MSG: &str = "`required_ruby_version` should be specified.";
+
 /// Extract version digits from a version string like RuboCop does:
 /// scan for digits and take the first two, joined with '.'.
 /// Single-digit versions (e.g. ">= 3") return just that digit (e.g. "3").
 /// e.g. ">= 2.7.0" → "2.7", "~> 3.4" → "3.4", ">= 3" → "3"
-fn extract_version_digits(s: &str) -> Option<String> {
-    let digits: Vec<char> = s.chars().filter(|c| c.is_ascii_digit()).collect();
-    if digits.len() >= 2 {
-        Some(format!("{}.{}", digits[0], digits[1]))
-    } else if digits.len() == 1 {
-        Some(digits[0].to_string())
-    } else {
-        None
-    }
-}
-
-/// Check if a trimmed RHS string looks like a bare local variable (e.g. `version`).
-/// Local variables in Ruby start with a lowercase letter or underscore and contain
-/// only alphanumeric characters and underscores. RuboCop treats these as dynamic.
-fn is_local_variable(rhs: &str) -> bool {
-    let ident = rhs.trim();
-    if ident.is_empty() {
-        return false;
-    }
-    let first = ident.as_bytes()[0];
-    if !(first.is_ascii_lowercase() || first == b'_') {
-        return false;
-    }
-    ident
-        .bytes()
-        .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+fn extract_version_digits(s: &[u8]) -> String {
+    let digits: Vec<String> = s
+        .iter()
+        .filter(|b| b.is_ascii_digit())
+        .take(2)
+        .map(|&b| (b as char).to_string())
+        .collect();
+    digits.join(".")
 }
 
 /// Format a TargetRubyVersion f64 as "X.Y".
@@ -79,35 +76,137 @@ fn format_target_version(v: f64) -> String {
     format!("{major}.{minor}")
 }
 
-/// Check if a trimmed RHS string looks dynamic — contains method calls, hash
-/// access, constant references, or other non-literal expressions. RuboCop's
-/// `dynamic_version?` returns true when the expression has send descendants
-/// (which includes `[]` calls), variables, or constant references.
-fn is_dynamic_rhs(rhs: &str) -> bool {
-    let trimmed = rhs.trim();
-    // Hash access like gemspec['key'] or config[:key], but NOT array literals
-    // starting with '['. Array literals (e.g. [">= 2.7", "< 4.0"]) are not
-    // dynamic — they're non-literal if versions can't be extracted.
-    if trimmed.contains('[') && !trimmed.starts_with('[') {
+/// RuboCop's `dynamic_version?`: a receiverless call, a variable, or a value
+/// containing a call or variable anywhere below it. Such values are skipped.
+fn is_dynamic_version(node: &ruby_prism::Node<'_>) -> bool {
+    if is_variable(node) {
         return true;
     }
-    // Method calls with parens like Foo.bar() or obj.method()
-    if trimmed.contains('(') {
-        return true;
-    }
-    // Method call on a local variable: e.g. `common_gemspec.required_ruby_version`.
-    // RuboCop's dynamic_version? checks descendants for :send and variable types.
-    // `(send (lvar :common_gemspec) :method)` has an lvar descendant → dynamic.
-    // But `(send (const ...) :method)` has only const descendants → NOT dynamic.
-    // So only treat as dynamic when the part before the first dot is a local variable
-    // (starts with lowercase/underscore, all alphanumeric).
-    if let Some(dot_pos) = trimmed.find('.') {
-        let before_dot = &trimmed[..dot_pos];
-        if is_local_variable(before_dot) {
+    let mut finder = DynamicFinder { found: false };
+    if let Some(call) = node.as_call_node() {
+        if call.receiver().is_none() {
             return true;
         }
+        // The call itself is not a descendant
+        if let Some(receiver) = call.receiver() {
+            finder.visit(&receiver);
+        }
+        if let Some(args) = call.arguments() {
+            finder.visit_arguments_node(&args);
+        }
+        if let Some(block) = call.block() {
+            finder.visit(&block);
+        }
+    } else {
+        finder.visit(node);
+    }
+    finder.found
+}
+
+fn is_variable(node: &ruby_prism::Node<'_>) -> bool {
+    node.as_local_variable_read_node().is_some()
+        || node.as_instance_variable_read_node().is_some()
+        || node.as_class_variable_read_node().is_some()
+        || node.as_global_variable_read_node().is_some()
+}
+
+/// Finds any call or variable read in a subtree.
+struct DynamicFinder {
+    found: bool,
+}
+
+impl<'pr> Visit<'pr> for DynamicFinder {
+    fn visit_call_node(&mut self, _node: &ruby_prism::CallNode<'pr>) {
+        self.found = true;
+    }
+
+    fn visit_local_variable_read_node(&mut self, _node: &ruby_prism::LocalVariableReadNode<'pr>) {
+        self.found = true;
+    }
+
+    fn visit_instance_variable_read_node(
+        &mut self,
+        _node: &ruby_prism::InstanceVariableReadNode<'pr>,
+    ) {
+        self.found = true;
+    }
+
+    fn visit_class_variable_read_node(&mut self, _node: &ruby_prism::ClassVariableReadNode<'pr>) {
+        self.found = true;
+    }
+
+    fn visit_global_variable_read_node(&mut self, _node: &ruby_prism::GlobalVariableReadNode<'pr>) {
+        self.found = true;
+    }
+}
+
+/// RuboCop's `defined_ruby_version` and `extract_ruby_version`: the first
+/// two digits of a string, or of the first constraint containing `>` or `=`
+/// in an array of exactly two strings or `Gem::Requirement.new` with string
+/// arguments. `None` for any other shape.
+fn defined_ruby_version(node: &ruby_prism::Node<'_>) -> Option<String> {
+    if let Some(string) = node.as_string_node() {
+        return Some(extract_version_digits(string.unescaped()));
+    }
+    let constraints: Vec<Vec<u8>> = if let Some(array) = node.as_array_node() {
+        if array.elements().len() != 2 {
+            return None;
+        }
+        string_values(array.elements())?
+    } else {
+        let call = node.as_call_node()?;
+        if call.name().as_slice() != b"new" || !is_gem_requirement(&call.receiver()?) {
+            return None;
+        }
+        let args = call.arguments()?;
+        if args.arguments().is_empty() {
+            return None;
+        }
+        string_values(args.arguments())?
+    };
+    let floor = constraints
+        .iter()
+        .find(|c| c.iter().any(|&b| b == b'>' || b == b'='))?;
+    Some(extract_version_digits(floor))
+}
+
+/// The contents of `nodes` when every one is a plain string.
+fn string_values(nodes: ruby_prism::NodeList<'_>) -> Option<Vec<Vec<u8>>> {
+    nodes
+        .iter()
+        .map(|n| n.as_string_node().map(|s| s.unescaped().to_vec()))
+        .collect()
+}
+
+/// `Gem::Requirement`, without a leading `::`.
+fn is_gem_requirement(node: &ruby_prism::Node<'_>) -> bool {
+    let Some(path) = node.as_constant_path_node() else {
+        return false;
+    };
+    path.name().is_some_and(|n| n.as_slice() == b"Requirement")
+        && path.parent().is_some_and(|parent| {
+            parent
+                .as_constant_read_node()
+                .is_some_and(|c| c.name().as_slice() == b"Gem")
+        })
+}
+
+/// Every `required_ruby_version=` call's assigned value, in source order.
+struct AssignmentFinder<'pr> {
+    found: bool,
+    values: Vec<ruby_prism::Node<'pr>>,
+}
+
+impl<'pr> Visit<'pr> for AssignmentFinder<'pr> {
+    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+        if node.name().as_slice() == b"required_ruby_version=" {
+            self.found = true;
+            if let Some(value) = node.arguments().and_then(|a| a.arguments().iter().next()) {
+                self.values.push(value);
+            }
+        }
+        ruby_prism::visit_call_node(self, node);
     }
-    false
 }
 
 impl Cop for RequiredRubyVersion {
@@ -119,120 +218,23 @@ impl Cop for RequiredRubyVersion {
         &["**/*.gemspec"]
     }
 
-    fn check_lines(
+    fn check_source(
         &self,
         source: &SourceFile,
+        parse_result: &ruby_prism::ParseResult<'_>,
+        _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
-        let mut found = false;
-        // Collect all required_ruby_version assignments for processing.
-        // Each entry: (line_1based, col, extracted_version_string), or None if dynamic.
-        let mut assignments: Vec<Option<(usize, usize, String)>> = Vec::new();
-
-        for (line_idx, line) in source.lines().enumerate() {
-            let line_str = match std::str::from_utf8(line) {
-                Ok(s) => s,
-                Err(_) => continue,
-            };
-            let trimmed = line_str.trim();
-            if trimmed.starts_with('#') {
-                continue;
-            }
-
-            // Check for required_ruby_version assignment
-            if trimmed.contains(".required_ruby_version") {
-                // Find the first .required_ruby_version and take everything after it.
-                // Using find+slice instead of split().nth(1) so we get the full
-                // remainder (split truncates at the next occurrence for double
-                // assignments like `a.required_ruby_version = a.required_ruby_version = val`).
-                let after = if let Some(pos) = trimmed.find(".required_ruby_version") {
-                    &trimmed[pos + ".required_ruby_version".len()..]
-                } else {
-                    ""
-                };
-                let after_trimmed = after.trim_start();
-                // Must be an assignment (= or >=) not just a method call check
-                if after_trimmed.starts_with('=') || after_trimmed.is_empty() {
-                    found = true;
-
-                    if let Some(eq_pos) = after_trimmed.find('=') {
-                        let rhs = &after_trimmed[eq_pos + 1..].trim_start();
-
-                        // Handle .freeze: RuboCop's dynamic_version? checks
-                        // descendants for send nodes. For bare `"str".freeze`,
-                        // the .freeze IS the node (no send descendants) → NOT
-                        // dynamic. For `Gem::Requirement.new("str".freeze)`,
-                        // .freeze is a descendant → IS dynamic.
-                        // We approximate: if .freeze is at the end of the RHS,
-                        // strip it (bare string case). Otherwise (nested inside
-                        // parens), treat as dynamic.
-                        let rhs_str;
-                        let rhs = if let Some(stripped) = rhs.strip_suffix(".freeze") {
-                            rhs_str = stripped.trim_end().to_string();
-                            rhs_str.as_str()
-                        } else if rhs.contains(".freeze") {
-                            assignments.push(None); // dynamic (nested .freeze)
-                            continue;
-                        } else {
-                            rhs
-                        };
-
-                        // Check if RHS is a bare local variable (lowercase identifier, no
-                        // quotes, no ::, no .). RuboCop treats these as dynamic.
-                        if is_local_variable(rhs) {
-                            assignments.push(None); // dynamic
-                            continue;
-                        }
-
-                        // Try to extract the version string from a quoted string.
-                        // This handles both plain strings ('>=2.7') and
-                        // Gem::Requirement.new(">= 3.4") — both have quotes.
-                        let mut extracted_version = false;
-                        let quote_char = rhs
-                            .find(['\'', '"'])
-                            .map(|p| (p, rhs.as_bytes()[p] as char));
-                        if let Some((start, qc)) = quote_char {
-                            let after_open = &rhs[start + 1..];
-                            if let Some(end) = after_open.find(qc) {
-                                let ver_str = &after_open[..end];
-                                if let Some(extracted) = extract_version_digits(ver_str) {
-                                    let ver_literal = &rhs[start..=start + 1 + end];
-                                    let col = line_str.find(ver_literal).unwrap_or(0);
-                                    assignments.push(Some((line_idx + 1, col, extracted)));
-                                    extracted_version = true;
-                                }
-                            }
-                        }
-
-                        if !extracted_version {
-                            // No quoted version found. Check if RHS looks dynamic
-                            // (hash access like gemspec['key']). RuboCop's
-                            // dynamic_version? returns true for send descendants
-                            // ([] is a send).
-                            if is_dynamic_rhs(rhs) {
-                                assignments.push(None); // dynamic
-                            } else {
-                                // Non-literal (constant, method call, etc.).
-                                // RuboCop fires a mismatch offense for these.
-                                let col = line_str.find(".required_ruby_version").unwrap_or(0)
-                                    + ".required_ruby_version = ".len();
-                                assignments.push(Some((line_idx + 1, col, String::new())));
-                            }
-                        }
-                    }
-                }
-            }
-        }
-
-        if !found {
-            diagnostics.push(self.diagnostic(
-                source,
-                1,
-                0,
-                "`required_ruby_version` should be specified.".to_string(),
-            ));
+        let mut finder = AssignmentFinder {
+            found: false,
+            values: Vec::new(),
+        };
+        finder.visit(&parse_result.node());
+
+        if !finder.found {
+            diagnostics.push(self.diagnostic(source, 1, 0, MISSING_MSG.to_string()));
             return;
         }
 
@@ -241,23 +243,28 @@ impl Cop for RequiredRubyVersion {
             .options
             .get("TargetRubyVersion")
             .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)));
-        if let Some(target_ver) = target {
-            let target_str = format_target_version(target_ver);
-            for assignment in &assignments {
-                if let Some((line, col, ref gemspec_version)) = *assignment {
-                    if *gemspec_version != target_str {
-                        diagnostics.push(self.diagnostic(
-                            source,
-                            line,
-                            col,
-                            format!(
-                                "`required_ruby_version` and `TargetRubyVersion` \
-                                 ({target_str}, which may be specified in .rubocop.yml) should be equal."
-                            ),
-                        ));
-                    }
-                }
+        let Some(target_ver) = target else {
+            return;
+        };
+        let target_str = format_target_version(target_ver);
+        for value in &finder.values {
+            if is_dynamic_version(value) {
+                continue;
             }
+            let version = defined_ruby_version(value);
+            if version.as_deref() == Some(target_str.as_str()) {
+                continue;
+            }
+            let (line, column) = source.offset_to_line_col(value.location().start_offset());
+            diagnostics.push(self.diagnostic(
+                source,
+                line,
+                column,
+                format!(
+                    "`required_ruby_version` and `TargetRubyVersion` \
+                     ({target_str}, which may be specified in .rubocop.yml) should be equal."
+                ),
+            ));
         }
     }
 }
@@ -404,15 +411,12 @@ mod tests {
 
     #[test]
     fn freeze_version_mismatch() {
-        // ">= 2.4".freeze — not dynamic per RuboCop, version "2.4" != target "4.0" → offense
-        let source = crate::parse::source::SourceFile::from_bytes(
-            "example.gemspec",
-            b"Gem::Specification.new do |s|\n  s.required_ruby_version = \">= 2.4\".freeze\nend\n"
-                .to_vec(),
+        // ">= 2.4".freeze — not dynamic per RuboCop and has no extractable version → offense
+        let diags = crate::testutil::run_cop_full_with_config(
+            &RequiredRubyVersion,
+            b"Gem::Specification.new do |s|\n  s.required_ruby_version = \">= 2.4\".freeze\nend\n",
+            config_with_target_ruby(4.0),
         );
-        let config = config_with_target_ruby(4.0);
-        let mut diags = vec![];
-        RequiredRubyVersion.check_lines(&source, &config, &mut diags, None);
         assert_eq!(
             diags.len(),
             1,
@@ -423,14 +427,11 @@ mod tests {
     #[test]
     fn double_assignment_fires_offense() {
         // spec.required_ruby_version = spec.required_ruby_version = Gem::Requirement.new('~> 2.5')
-        let source = crate::parse::source::SourceFile::from_bytes(
-            "example.gemspec",
-            b"Gem::Specification.new do |spec|\n  spec.required_ruby_version = spec.required_ruby_version = Gem::Requirement.new('~> 2.5')\nend\n"
-                .to_vec(),
+        let diags = crate::testutil::run_cop_full_with_config(
+            &RequiredRubyVersion,
+            b"Gem::Specification.new do |spec|\n  spec.required_ruby_version = spec.required_ruby_version = Gem::Requirement.new('~> 2.5')\nend\n",
+            config_with_target_ruby(4.0),
         );
-        let config = config_with_target_ruby(4.0);
-        let mut diags = vec![];
-        RequiredRubyVersion.check_lines(&source, &config, &mut diags, None);
         assert!(
             !diags.is_empty(),
             "should fire mismatch for double assignment: {diags:?}"
@@ -449,6 +450,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn array_floor() {
+        // The floor is the constraint with `>` or `=`, wherever it sits
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &RequiredRubyVersion,
+            include_bytes!(
+                "../../../tests/fixtures/cops/gemspec/required_ruby_version/offense/array_floor.rb"
+            ),
+            config_with_target_ruby(3.1),
+        );
+    }
+
+    #[test]
+    fn array_floor_no_offense() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &RequiredRubyVersion,
+            include_bytes!(
+                "../../../tests/fixtures/cops/gemspec/required_ruby_version/no_offense_array.rb"
+            ),
+            config_with_target_ruby(3.1),
+        );
+    }
+
     #[test]
     fn conditional_branches() {
         crate::testutil::assert_cop_offenses_full_with_config(
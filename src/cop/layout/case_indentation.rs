@@ -1,5 +1,6 @@
-use crate::cop::shared::node_type::{CASE_MATCH_NODE, CASE_NODE, WHEN_NODE};
+use crate::cop::shared::node_type::{CASE_MATCH_NODE, CASE_NODE};
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -14,15 +15,36 @@ use crate::parse::source::SourceFile;
 ///   RuboCop. When using `end` style, RuboCop skips the check if the `end` keyword is on
 ///   the same line as the last conditional (`else` or last `when`/`in`). Without this
 ///   guard, nitrocop would flag `when`/`in` in compact trailing forms as misindented.
+///
+/// `EnforcedStyle: end` anchors `when`/`in` to the `end` keyword instead of
+/// `case`, which differs when the case is the value of an assignment
+/// (`x = case y` with `end` at the assignment's indentation). `IndentOneStep`
+/// adds `IndentationWidth` (which falls back to `Layout/IndentationWidth`) to
+/// either anchor. Autocorrect only re-indents the `when`/`in` keyword lines;
+/// their bodies are left to `Layout/IndentationWidth`.
 pub struct CaseIndentation;
 
+/// The parts of `case/when` and `case/in` this cop looks at.
+struct CaseShape {
+    case_offset: usize,
+    end_offset: usize,
+    /// `else` keyword, or the last branch keyword when there is no `else`.
+    last_conditional_offset: Option<usize>,
+    branch_offsets: Vec<usize>,
+    branch_type: &'static str,
+}
+
 impl Cop for CaseIndentation {
     fn name(&self) -> &'static str {
         "Layout/CaseIndentation"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
-        &[CASE_NODE, CASE_MATCH_NODE, WHEN_NODE]
+        &[CASE_NODE, CASE_MATCH_NODE]
     }
 
     fn check_node(
@@ -32,148 +54,118 @@ impl Cop for CaseIndentation {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
+    ) {
+        // Handle both CaseNode (case/when) and CaseMatchNode (case/in pattern matching)
+        let shape = if let Some(case_node) = node.as_case_node() {
+            let branch_offsets: Vec<usize> = case_node
+                .conditions()
+                .iter()
+                .filter_map(|c| c.as_when_node().map(|w| w.keyword_loc().start_offset()))
+                .collect();
+            CaseShape {
+                case_offset: case_node.case_keyword_loc().start_offset(),
+                end_offset: case_node.end_keyword_loc().start_offset(),
+                last_conditional_offset: case_node
+                    .else_clause()
+                    .map(|e| e.else_keyword_loc().start_offset())
+                    .or_else(|| branch_offsets.last().copied()),
+                branch_offsets,
+                branch_type: "when",
+            }
+        } else if let Some(case_match_node) = node.as_case_match_node() {
+            let branch_offsets: Vec<usize> = case_match_node
+                .conditions()
+                .iter()
+                .filter_map(|c| c.as_in_node().map(|i| i.in_loc().start_offset()))
+                .collect();
+            CaseShape {
+                case_offset: case_match_node.case_keyword_loc().start_offset(),
+                end_offset: case_match_node.end_keyword_loc().start_offset(),
+                last_conditional_offset: case_match_node
+                    .else_clause()
+                    .map(|e| e.else_keyword_loc().start_offset())
+                    .or_else(|| branch_offsets.last().copied()),
+                branch_offsets,
+                branch_type: "in",
+            }
+        } else {
+            return;
+        };
+
+        self.check_case(source, &shape, config, diagnostics, corrections);
+    }
+}
+
+impl CaseIndentation {
+    fn check_case(
+        &self,
+        source: &SourceFile,
+        shape: &CaseShape,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        mut corrections: Option<&mut Vec<Correction>>,
     ) {
         let style = config.get_str("EnforcedStyle", "case");
         let indent_one_step = config.get_bool("IndentOneStep", false);
         let indent_width = config.get_usize("IndentationWidth", 2);
 
-        // Handle both CaseNode (case/when) and CaseMatchNode (case/in pattern matching)
-        if let Some(case_node) = node.as_case_node() {
-            let case_loc = case_node.case_keyword_loc();
-            let (case_line, case_col) = source.offset_to_line_col(case_loc.start_offset());
-
-            // Skip single-line case expressions (RuboCop skips these)
-            let (end_line, _) =
-                source.offset_to_line_col(case_node.end_keyword_loc().start_offset());
-            if case_line == end_line {
-                return;
-            }
-
-            // Skip when "end" style and the end keyword is on the same line as
-            // the last conditional (else or last when). RuboCop skips these to
-            // avoid false positives on compact trailing forms.
-            if style == "end" {
-                let last_cond_line = if let Some(else_clause) = case_node.else_clause() {
-                    Some(
-                        source
-                            .offset_to_line_col(else_clause.else_keyword_loc().start_offset())
-                            .0,
-                    )
-                } else {
-                    // Use the last when's keyword line
-                    case_node.conditions().iter().last().and_then(|c| {
-                        c.as_when_node()
-                            .map(|w| source.offset_to_line_col(w.keyword_loc().start_offset()).0)
-                    })
-                };
-                if last_cond_line == Some(end_line) {
-                    return;
-                }
-            }
+        let (case_line, case_col) = source.offset_to_line_col(shape.case_offset);
+        let (end_line, end_col) = source.offset_to_line_col(shape.end_offset);
 
-            let base_col = if style == "end" {
-                source
-                    .offset_to_line_col(case_node.end_keyword_loc().start_offset())
-                    .1
-            } else {
-                case_col
-            };
-
-            let expected_col = if indent_one_step {
-                base_col + indent_width
-            } else {
-                base_col
-            };
-
-            let message = if indent_one_step {
-                "Indent `when` one step more than `case`.".to_string()
-            } else if style == "end" {
-                "Indent `when` as deep as `end`.".to_string()
-            } else {
-                "Indent `when` as deep as `case`.".to_string()
-            };
-
-            for condition in case_node.conditions().iter() {
-                if let Some(when_node) = condition.as_when_node() {
-                    let when_loc = when_node.keyword_loc();
-                    let (when_line, when_col) = source.offset_to_line_col(when_loc.start_offset());
-
-                    if when_col != expected_col {
-                        diagnostics.push(self.diagnostic(
-                            source,
-                            when_line,
-                            when_col,
-                            message.clone(),
-                        ));
-                    }
-                }
-            }
-        } else if let Some(case_match_node) = node.as_case_match_node() {
-            let case_loc = case_match_node.case_keyword_loc();
-            let (case_line, case_col) = source.offset_to_line_col(case_loc.start_offset());
+        // Skip single-line case expressions (RuboCop skips these)
+        if case_line == end_line {
+            return;
+        }
 
-            // Skip single-line case expressions (RuboCop skips these)
-            let (end_line, _) =
-                source.offset_to_line_col(case_match_node.end_keyword_loc().start_offset());
-            if case_line == end_line {
+        // Skip when "end" style and the end keyword is on the same line as
+        // the last conditional (else or last when/in). RuboCop skips these to
+        // avoid false positives on compact trailing forms.
+        let (base, base_col) = if style == "end" {
+            let last_cond_line = shape
+                .last_conditional_offset
+                .map(|off| source.offset_to_line_col(off).0);
+            if last_cond_line == Some(end_line) {
                 return;
             }
+            ("end", end_col)
+        } else {
+            ("case", case_col)
+        };
 
-            // Skip when "end" style and the end keyword is on the same line as
-            // the last conditional (else or last in). RuboCop skips these to
-            // avoid false positives on compact trailing forms.
-            if style == "end" {
-                let last_cond_line = if let Some(else_clause) = case_match_node.else_clause() {
-                    Some(
-                        source
-                            .offset_to_line_col(else_clause.else_keyword_loc().start_offset())
-                            .0,
-                    )
-                } else {
-                    // Use the last in's keyword line
-                    case_match_node.conditions().iter().last().and_then(|c| {
-                        c.as_in_node()
-                            .map(|i| source.offset_to_line_col(i.in_loc().start_offset()).0)
-                    })
-                };
-                if last_cond_line == Some(end_line) {
-                    return;
-                }
+        let (expected_col, depth) = if indent_one_step {
+            (base_col + indent_width, "one step more than")
+        } else {
+            (base_col, "as deep as")
+        };
+        let message = format!("Indent `{}` {depth} `{base}`.", shape.branch_type);
+
+        let bytes = source.as_bytes();
+        for &offset in &shape.branch_offsets {
+            let (line, col) = source.offset_to_line_col(offset);
+            if col == expected_col {
+                continue;
             }
 
-            let base_col = if style == "end" {
-                source
-                    .offset_to_line_col(case_match_node.end_keyword_loc().start_offset())
-                    .1
-            } else {
-                case_col
-            };
-
-            let expected_col = if indent_one_step {
-                base_col + indent_width
-            } else {
-                base_col
-            };
-
-            let message = if indent_one_step {
-                "Indent `in` one step more than `case`.".to_string()
-            } else if style == "end" {
-                "Indent `in` as deep as `end`.".to_string()
-            } else {
-                "Indent `in` as deep as `case`.".to_string()
-            };
-
-            for condition in case_match_node.conditions().iter() {
-                if let Some(in_node) = condition.as_in_node() {
-                    let in_loc = in_node.in_loc();
-                    let (in_line, in_col) = source.offset_to_line_col(in_loc.start_offset());
-
-                    if in_col != expected_col {
-                        diagnostics.push(self.diagnostic(source, in_line, in_col, message.clone()));
-                    }
+            let mut diag = self.diagnostic(source, line, col, message.clone());
+            if let Some(corr) = corrections.as_deref_mut() {
+                // Only re-indent when the keyword starts its line.
+                let line_start = offset - col;
+                if bytes[line_start..offset]
+                    .iter()
+                    .all(|&b| b == b' ' || b == b'\t')
+                {
+                    corr.push(Correction {
+                        start: line_start,
+                        end: offset,
+                        replacement: " ".repeat(expected_col),
+                        cop_name: self.name(),
+                        cop_index: 0,
+                    });
+                    diag.corrected = true;
                 }
             }
+            diagnostics.push(diag);
         }
     }
 }
@@ -184,6 +176,128 @@ mod tests {
     use crate::testutil::run_cop_full;
 
     crate::cop_fixture_tests!(CaseIndentation, "cops/layout/case_indentation");
+    crate::cop_autocorrect_fixture_tests!(CaseIndentation, "cops/layout/case_indentation");
+
+    #[test]
+    fn offense_end_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &CaseIndentation,
+            "EnforcedStyle: end",
+            include_bytes!("../../../tests/fixtures/cops/layout/case_indentation/offense.end.rb"),
+        );
+    }
+
+    #[test]
+    fn no_offense_end_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &CaseIndentation,
+            "EnforcedStyle: end",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/case_indentation/no_offense.end.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_end_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &CaseIndentation,
+            include_bytes!("../../../tests/fixtures/cops/layout/case_indentation/offense.end.rb"),
+            include_bytes!("../../../tests/fixtures/cops/layout/case_indentation/corrected.end.rb"),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: end"),
+        );
+    }
+
+    #[test]
+    fn offense_indent_one_step_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &CaseIndentation,
+            "IndentOneStep: true",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/case_indentation/offense.indent_one_step.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn no_offense_indent_one_step_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &CaseIndentation,
+            "IndentOneStep: true",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/case_indentation/no_offense.indent_one_step.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_indent_one_step_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &CaseIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/case_indentation/offense.indent_one_step.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/case_indentation/corrected.indent_one_step.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("IndentOneStep: true"),
+        );
+    }
+
+    #[test]
+    fn offense_end_indent_one_step_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &CaseIndentation,
+            "EnforcedStyle: end\nIndentOneStep: true",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/case_indentation/offense.end_indent_one_step.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn no_offense_end_indent_one_step_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &CaseIndentation,
+            "EnforcedStyle: end\nIndentOneStep: true",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/case_indentation/no_offense.end_indent_one_step.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_end_indent_one_step_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &CaseIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/case_indentation/offense.end_indent_one_step.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/case_indentation/corrected.end_indent_one_step.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: end\nIndentOneStep: true"),
+        );
+    }
+
+    #[test]
+    fn indent_one_step_uses_cop_indentation_width() {
+        let config =
+            crate::testutil::cop_config_from_yaml("IndentOneStep: true\nIndentationWidth: 4");
+        let src = b"case x\n  when 1\n    :a\nend\n";
+        let diags =
+            crate::testutil::run_cop_full_with_config(&CaseIndentation, src, config.clone());
+        assert_eq!(
+            diags.len(),
+            1,
+            "two-space step should be flagged with width 4"
+        );
+
+        let (_, corrections) =
+            crate::testutil::run_cop_autocorrect_with_config(&CaseIndentation, src, config);
+        let corrected = crate::correction::CorrectionSet::from_vec(corrections).apply(src);
+        assert_eq!(corrected, b"case x\n    when 1\n    :a\nend\n");
+    }
 
     #[test]
     fn nested_case_respects_own_indent() {
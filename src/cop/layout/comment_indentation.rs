@@ -48,6 +48,15 @@ use crate::parse::source::SourceFile;
 /// Fix: track Prism's actual comment columns per line, use RuboCop's line-prefix
 /// rule to decide whether the line counts as an "own-line comment", and then
 /// report against the real comment column from Prism.
+///
+/// ## AllowForAlignment and autocorrect
+///
+/// `AllowForAlignment` follows RuboCop's
+/// `correctly_aligned_with_preceding_comment?`: the nearest earlier comment at
+/// the same column decides, and the comment is accepted only when that one is
+/// an end-of-line comment. Autocorrect re-indents to the expected column (the
+/// keyword's, before `else`/`when`/...) and moves the comments directly above
+/// that share the flagged comment's column along with it.
 pub struct CommentIndentation;
 
 fn first_non_whitespace_column(line: &[u8]) -> Option<usize> {
@@ -90,11 +99,30 @@ fn is_less_indented(line: &[u8]) -> bool {
         || trimmed.starts_with(b"]")
 }
 
+/// A Prism comment's position: 0-based line index, column and byte offset.
+#[derive(Clone, Copy)]
+struct CommentPos {
+    line_idx: usize,
+    column: usize,
+    start: usize,
+}
+
+/// RuboCop's `own_line_comment?`: the comment's physical line starts with `#`
+/// after indentation.
+fn is_own_line(lines: &[&[u8]], comment: &CommentPos) -> bool {
+    let line = lines[comment.line_idx];
+    first_non_whitespace_column(line).is_some_and(|t| line[t] == b'#')
+}
+
 impl Cop for CommentIndentation {
     fn name(&self) -> &'static str {
         "Layout/CommentIndentation"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -102,131 +130,134 @@ impl Cop for CommentIndentation {
         _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        mut corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let allow_for_alignment = config.get_bool("AllowForAlignment", false);
         let indent_width = config.get_usize("IndentationWidth", 2);
         let lines: Vec<&[u8]> = source.lines().collect();
 
-        // Track actual comment columns per line from Prism so we can distinguish
-        // real comments from `#` inside strings/regex/heredocs while still
-        // matching RuboCop's line-based own-line comment check.
-        let mut comment_columns_by_line: Vec<Vec<usize>> = vec![Vec::new(); lines.len()];
-        for comment in parse_result.comments() {
-            let (line, column) = source.offset_to_line_col(comment.location().start_offset());
-            let line_idx = line - 1;
-            if let Some(columns) = comment_columns_by_line.get_mut(line_idx) {
-                columns.push(column);
-            }
+        // Track actual comment positions from Prism so we can distinguish real
+        // comments from `#` inside strings/regex/heredocs while still matching
+        // RuboCop's line-based own-line comment check.
+        let comments: Vec<CommentPos> = parse_result
+            .comments()
+            .filter_map(|comment| {
+                let start = comment.location().start_offset();
+                let (line, column) = source.offset_to_line_col(start);
+                (line <= lines.len()).then_some(CommentPos {
+                    line_idx: line - 1,
+                    column,
+                    start,
+                })
+            })
+            .collect();
+        let mut has_comment = vec![false; lines.len()];
+        for comment in &comments {
+            has_comment[comment.line_idx] = true;
         }
 
-        for (i, line) in lines.iter().enumerate() {
-            let trimmed = first_non_whitespace_column(line);
-            let trimmed = match trimmed {
-                Some(t) => t,
-                None => continue, // blank line
-            };
+        for (ix, comment) in comments.iter().enumerate() {
+            let i = comment.line_idx;
+            let comment_col = comment.column;
 
             // Match RuboCop's `own_line_comment?`: the line must start with `#`
-            // after indentation, and Prism must confirm there is a real comment
+            // after indentation. Prism confirms there is a real comment
             // somewhere on the line.
-            if line[trimmed] != b'#' || comment_columns_by_line[i].is_empty() {
+            if !is_own_line(&lines, comment) {
                 continue;
             }
 
-            for &comment_col in &comment_columns_by_line[i] {
-                // Find the next non-blank line (including comments).
-                // This matches RuboCop's `line_after_comment` which finds the first
-                // non-blank line, regardless of whether it's a comment or code.
-                let mut next_line: Option<&[u8]> = None;
-                let mut next_col = None;
-                let mut next_line_idx = 0;
-                for (j, ln) in lines.iter().enumerate().skip(i + 1) {
-                    let next_trimmed = first_non_whitespace_column(ln);
-                    if let Some(nt) = next_trimmed {
-                        next_line = Some(ln);
-                        next_col = Some(nt);
-                        next_line_idx = j;
-                        break;
-                    }
-                }
+            // Find the next non-blank line (including comments).
+            // This matches RuboCop's `line_after_comment` which finds the first
+            // non-blank line, regardless of whether it's a comment or code.
+            let next = lines
+                .iter()
+                .enumerate()
+                .skip(i + 1)
+                .find_map(|(j, ln)| first_non_whitespace_column(ln).map(|nc| (j, *ln, nc)));
 
-                // When no next line exists, expected indentation is 0
-                // (matches RuboCop: `return 0 unless next_line`)
-                let (expected, next_is_code) = if let Some(nc) = next_col {
-                    let nl = next_line.unwrap();
+            // When no next line exists, expected indentation is 0
+            // (matches RuboCop: `return 0 unless next_line`)
+            let (expected, next_code_line) = match next {
+                Some((j, nl, nc)) => {
                     // Apply the same own-line-comment rule to the next line.
-                    let is_comment =
-                        nl[nc] == b'#' && !comment_columns_by_line[next_line_idx].is_empty();
-                    let exp = if !is_comment && is_less_indented(nl) {
-                        nc + indent_width
+                    let is_comment = nl[nc] == b'#' && has_comment[j];
+                    if is_comment {
+                        (nc, None)
+                    } else if is_less_indented(nl) {
+                        (nc + indent_width, Some(nl))
                     } else {
-                        nc
-                    };
-                    (exp, !is_comment)
-                } else {
-                    (0, false)
-                };
-
-                if comment_col == expected {
-                    continue;
-                }
-
-                // Two-alternative keywords: comment can match keyword indent OR body indent
-                // Only applies when next line is code (not a comment)
-                if next_is_code {
-                    if let Some(nl) = next_line {
-                        if is_two_alternative_keyword(nl) {
-                            let nc = next_col.unwrap();
-                            let alt = nc + indent_width;
-                            if comment_col == nc || comment_col == alt {
-                                continue;
-                            }
-                        }
+                        (nc, Some(nl))
                     }
                 }
+                None => (0, None),
+            };
 
-                // AllowForAlignment: if enabled, check if this comment is aligned
-                // with a preceding inline (end-of-line) comment
-                if allow_for_alignment {
-                    let mut aligned_with_preceding = false;
-                    // Walk backwards through preceding comments looking for an
-                    // end-of-line comment at the same column
-                    for k in (0..i).rev() {
-                        let prev = lines[k];
-                        let prev_first = first_non_whitespace_column(prev);
-                        match prev_first {
-                            Some(pos) if prev[pos] == b'#' => {
-                                // own-line comment — skip
-                                continue;
-                            }
-                            Some(_) => {
-                                // code line — check if it has an inline comment at our column
-                                if let Some(hash_pos) = prev.iter().position(|&b| b == b'#') {
-                                    if hash_pos == comment_col {
-                                        aligned_with_preceding = true;
-                                    }
-                                }
-                                break;
-                            }
-                            None => break, // blank line
-                        }
-                    }
-                    if aligned_with_preceding {
+            if comment_col == expected {
+                continue;
+            }
+
+            // Two-alternative keywords: comment can match keyword indent OR body indent
+            // Only applies when next line is code (not a comment). Autocorrect
+            // still aligns with the keyword.
+            if next_code_line.is_some_and(is_two_alternative_keyword)
+                && comment_col == expected + indent_width
+            {
+                continue;
+            }
+
+            // AllowForAlignment: the nearest preceding comment at the same
+            // column decides; it must be an end-of-line comment.
+            if allow_for_alignment
+                && comments[..ix]
+                    .iter()
+                    .rev()
+                    .find(|other| other.column == comment_col)
+                    .is_some_and(|other| !is_own_line(&lines, other))
+            {
+                continue;
+            }
+
+            let mut diag = self.diagnostic(
+                source,
+                i + 1,
+                comment_col,
+                format!(
+                    "Incorrect indentation detected (column {} instead of column {}).",
+                    comment_col, expected
+                ),
+            );
+            if let Some(ref mut corr) = corrections {
+                // Like RuboCop, also move the comments directly above that share
+                // this comment's column, so a block is fixed in one pass.
+                let block = comments[..=ix]
+                    .iter()
+                    .rev()
+                    .zip(comments[..ix].iter().rev())
+                    .take_while(|(below, above)| {
+                        above.line_idx + 1 == below.line_idx
+                            && above.column == below.column
+                            && is_own_line(&lines, above)
+                    })
+                    .map(|(_, above)| above);
+                for moved in std::iter::once(comment).chain(block) {
+                    let line_start = source.line_start_offset(moved.line_idx + 1);
+                    let indent = &source.as_bytes()[line_start..moved.start];
+                    // Leave lines whose real comment follows interpolation alone.
+                    if !indent.iter().all(|&b| b == b' ' || b == b'\t') {
                         continue;
                     }
+                    corr.push(crate::correction::Correction {
+                        start: line_start,
+                        end: moved.start,
+                        replacement: " ".repeat(expected),
+                        cop_name: self.name(),
+                        cop_index: 0,
+                    });
+                    diag.corrected = true;
                 }
-
-                diagnostics.push(self.diagnostic(
-                    source,
-                    i + 1,
-                    comment_col,
-                    format!(
-                        "Incorrect indentation detected (column {} instead of column {}).",
-                        comment_col, expected
-                    ),
-                ));
             }
+            diagnostics.push(diag);
         }
     }
 }
@@ -236,6 +267,7 @@ mod tests {
     use super::*;
 
     crate::cop_fixture_tests!(CommentIndentation, "cops/layout/comment_indentation");
+    crate::cop_autocorrect_fixture_tests!(CommentIndentation, "cops/layout/comment_indentation");
 
     #[test]
     fn crlf_blank_lines_not_treated_as_content() {
@@ -244,4 +276,38 @@ mod tests {
         let source = b"def foo\r\n  # comment\r\n\r\n  x = 1\r\nend\r\n";
         crate::testutil::assert_cop_no_offenses(&CommentIndentation, source);
     }
+
+    #[test]
+    fn allow_for_alignment_with_preceding_trailing_comment() {
+        let source = b"a = 1 # trailing\n      # continued\nb = 2\n";
+        let diags = crate::testutil::run_cop_full(&CommentIndentation, source);
+        assert_eq!(diags.len(), 1);
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &CommentIndentation,
+            "AllowForAlignment: true\n",
+            source,
+        );
+    }
+
+    #[test]
+    fn allow_for_alignment_ignores_own_line_comments() {
+        // The nearest comment at the same column is an own-line comment, so
+        // the trailing one further up does not count.
+        let source = b"a = 1 # trailing\n      # own line\nb = 2\n      # misplaced\nc = 3\n";
+        let config = crate::testutil::cop_config_from_yaml("AllowForAlignment: true\n");
+        let diags = crate::testutil::run_cop_full_with_config(&CommentIndentation, source, config);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].location.line, 4);
+    }
+
+    #[test]
+    fn autocorrect_moves_the_block_above_along() {
+        // Only the last comment of the block is flagged.
+        let source = b"def m\n  x\n# one\n# two\n# three\nend\n";
+        let (diags, corrections) =
+            crate::testutil::run_cop_autocorrect(&CommentIndentation, source);
+        assert_eq!(diags.len(), 1);
+        let corrected = crate::correction::CorrectionSet::from_vec(corrections).apply(source);
+        assert_eq!(corrected, b"def m\n  x\n  # one\n  # two\n  # three\nend\n");
+    }
 }
@@ -1,5 +1,6 @@
 use crate::cop::shared::node_type::CALL_NODE;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -9,6 +10,18 @@ use crate::parse::source::SourceFile;
 ///
 /// FP=2: Fixed by skipping `::` scope resolution operators — only `.` and `&.` should be checked.
 /// The 2 FPs were from rufo's spec file with `foo::\n bar` patterns.
+///
+/// ## Autocorrect
+///
+/// Mirrors RuboCop: the dot (or `&.`) is removed — with its whole line when
+/// it stands alone — and reinserted before the selector (`leading`) or right
+/// after the receiver (`trailing`). Whitespace left dangling by the move is
+/// dropped, and comments after the dot stay where they are. A receiver whose
+/// heredoc body follows the call ends at the heredoc terminator, so the
+/// selector is compared against that line; the trailing dot still goes right
+/// after the receiver, before the body. A comment or blank line between the
+/// receiver (or dot) and the selector skips the call, as moving the dot
+/// across it could change how the code parses.
 pub struct DotPosition;
 
 impl Cop for DotPosition {
@@ -20,6 +33,10 @@ impl Cop for DotPosition {
         &[CALL_NODE]
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_node(
         &self,
         source: &SourceFile,
@@ -27,7 +44,7 @@ impl Cop for DotPosition {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let style = config.get_str("EnforcedStyle", "leading");
 
@@ -53,69 +70,157 @@ impl Cop for DotPosition {
             None => return,
         };
 
-        // Must have a method name (message)
-        let msg_loc = match call.message_loc() {
-            Some(loc) => loc,
+        // The method name, or the `(` of `foo.()`
+        let selector_start = match call.message_loc().or_else(|| call.opening_loc()) {
+            Some(loc) => loc.start_offset(),
             None => return,
         };
 
+        let line_of = |offset: usize| source.offset_to_line_col(offset).0;
+        let recv_end = receiver.location().end_offset();
         let (dot_line, dot_col) = source.offset_to_line_col(dot_loc.start_offset());
-        let (recv_line, _) =
-            source.offset_to_line_col(receiver.location().end_offset().saturating_sub(1));
-        let (msg_line, _) = source.offset_to_line_col(msg_loc.start_offset());
+        let selector_line = line_of(selector_start);
 
         // Single line call — no issue
-        if recv_line == msg_line {
+        if line_of(recv_end.saturating_sub(1)) == selector_line {
             return;
         }
 
-        // If there's a blank line between dot and selector, skip (could be reformatted oddly)
-        if (msg_line as i64 - dot_line as i64).abs() > 1
-            || (dot_line as i64 - recv_line as i64).abs() > 1
-        {
+        // A heredoc receiver ends at its terminator, after the dot's line
+        let recv_line = match heredoc_end(&receiver) {
+            Some(end) => line_of(end),
+            None => line_of(recv_end.saturating_sub(1)),
+        };
+
+        // A comment or blank line before the selector: moving the dot across
+        // it could break the code, so leave it alone
+        if selector_line > recv_line.max(dot_line) + 1 {
             return;
         }
 
         let dot_str = std::str::from_utf8(dot_loc.as_slice()).unwrap_or(".");
 
-        match style {
-            "trailing" => {
-                // Dot should be on the same line as the receiver (trailing)
-                if dot_line != recv_line {
-                    diagnostics.push(self.diagnostic(
-                        source,
-                        dot_line,
-                        dot_col,
-                        format!(
-                            "Place the `{}` on the previous line, together with the method call receiver.",
-                            dot_str
-                        ),
-                    ));
-                }
-            }
-            _ => {
-                // "leading" (default): dot should be on the same line as the method name
-                if dot_line != msg_line {
-                    diagnostics.push(self.diagnostic(
-                        source,
-                        dot_line,
-                        dot_col,
-                        format!(
-                            "Place the `{}` on the next line, together with the method name.",
-                            dot_str
-                        ),
-                    ));
-                }
-            }
+        let message = match style {
+            // Dot should end the receiver's line
+            "trailing" if dot_line == selector_line => format!(
+                "Place the `{}` on the previous line, together with the method call receiver.",
+                dot_str
+            ),
+            "trailing" => return,
+            // "leading" (default): dot should be on the same line as the method name
+            _ if dot_line != selector_line => format!(
+                "Place the `{}` on the next line, together with the method name.",
+                dot_str
+            ),
+            _ => return,
+        };
+
+        let mut diag = self.diagnostic(source, dot_line, dot_col, message);
+        if let Some(corr) = corrections {
+            let (remove_start, remove_end) =
+                dot_removal_range(source, dot_loc.start_offset(), dot_loc.end_offset());
+            corr.push(Correction {
+                start: remove_start,
+                end: remove_end,
+                replacement: String::new(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            let insert_at = if style == "trailing" {
+                recv_end
+            } else {
+                selector_start
+            };
+            corr.push(Correction {
+                start: insert_at,
+                end: insert_at,
+                replacement: dot_str.to_string(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
         }
+        diagnostics.push(diag);
+    }
+}
+
+/// The start of the terminator line when `node` is a heredoc, or a call
+/// whose arguments include heredocs (the last terminator of those).
+fn heredoc_end(node: &ruby_prism::Node<'_>) -> Option<usize> {
+    let is_heredoc = |opening: &ruby_prism::Location<'_>| opening.as_slice().starts_with(b"<<");
+    if let Some(s) = node.as_string_node() {
+        return s
+            .opening_loc()
+            .filter(is_heredoc)
+            .and(s.closing_loc())
+            .map(|c| c.start_offset());
+    }
+    if let Some(s) = node.as_interpolated_string_node() {
+        return s
+            .opening_loc()
+            .filter(is_heredoc)
+            .and(s.closing_loc())
+            .map(|c| c.start_offset());
+    }
+    if let Some(s) = node.as_x_string_node() {
+        return is_heredoc(&s.opening_loc()).then(|| s.closing_loc().start_offset());
+    }
+    if let Some(s) = node.as_interpolated_x_string_node() {
+        return is_heredoc(&s.opening_loc()).then(|| s.closing_loc().start_offset());
+    }
+    let call = node.as_call_node()?;
+    call.arguments()?
+        .arguments()
+        .iter()
+        .filter_map(|arg| heredoc_end(&arg))
+        .max()
+}
+
+/// The bytes to delete when moving the dot at `start..end`: its whole line
+/// when it stands alone there, otherwise the dot plus the whitespace the move
+/// would leave dangling (before a dot that ends its line, after one that
+/// starts its line).
+fn dot_removal_range(source: &SourceFile, start: usize, end: usize) -> (usize, usize) {
+    let bytes = source.as_bytes();
+    let is_space = |b: &u8| *b == b' ' || *b == b'\t';
+    let line_start = bytes[..start]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+    let line_end = bytes[end..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |i| end + i);
+    let starts_line = bytes[line_start..start].iter().all(is_space);
+    let ends_line = bytes[end..line_end].iter().all(is_space);
+
+    if starts_line && ends_line {
+        (line_start, (line_end + 1).min(bytes.len()))
+    } else if ends_line {
+        let spaces_before = bytes[line_start..start]
+            .iter()
+            .rev()
+            .take_while(|b| is_space(b))
+            .count();
+        (start - spaces_before, end)
+    } else if starts_line {
+        let spaces_after = bytes[end..line_end]
+            .iter()
+            .take_while(|b| is_space(b))
+            .count();
+        (start, end + spaces_after)
+    } else {
+        (start, end)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cop::layout::multiline_method_call_indentation::MultilineMethodCallIndentation;
 
     crate::cop_fixture_tests!(DotPosition, "cops/layout/dot_position");
+    crate::cop_autocorrect_fixture_tests!(DotPosition, "cops/layout/dot_position");
 
     fn trailing_config() -> crate::cop::CopConfig {
         let mut options = std::collections::HashMap::new();
@@ -148,4 +253,54 @@ mod tests {
             trailing_config(),
         );
     }
+
+    #[test]
+    fn autocorrect_trailing() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &DotPosition,
+            include_bytes!("../../../tests/fixtures/cops/layout/dot_position/offense.trailing.rb"),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/dot_position/corrected.trailing.rb"
+            ),
+            trailing_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_trailing_idempotent() {
+        crate::testutil::assert_cop_autocorrect_idempotent_with_config(
+            &DotPosition,
+            include_bytes!("../../../tests/fixtures/cops/layout/dot_position/offense.trailing.rb"),
+            trailing_config(),
+        );
+    }
+
+    #[test]
+    fn converges_with_multiline_method_call_indentation() {
+        let corrected = crate::testutil::assert_autocorrect_converges(
+            &[&DotPosition, &MultilineMethodCallIndentation],
+            include_bytes!("../../../tests/fixtures/cops/layout/dot_position/offense.rb"),
+        );
+        assert_eq!(
+            corrected,
+            include_bytes!("../../../tests/fixtures/cops/layout/dot_position/corrected.rb")
+        );
+
+        let corrected = crate::testutil::assert_autocorrect_converges_with_configs(
+            &[
+                (&DotPosition, trailing_config()),
+                (
+                    &MultilineMethodCallIndentation,
+                    crate::cop::CopConfig::default(),
+                ),
+            ],
+            include_bytes!("../../../tests/fixtures/cops/layout/dot_position/offense.trailing.rb"),
+        );
+        assert_eq!(
+            corrected,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/dot_position/corrected.trailing.rb"
+            )
+        );
+    }
 }
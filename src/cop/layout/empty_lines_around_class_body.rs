@@ -1,5 +1,5 @@
+use crate::cop::shared::empty_lines_around_body;
 use crate::cop::shared::node_type::{CLASS_NODE, SINGLETON_CLASS_NODE};
-use crate::cop::shared::util;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
@@ -17,6 +17,10 @@ use crate::parse::source::SourceFile;
 /// using `superclass.location().end_offset()` as the keyword offset when
 /// a superclass is present, so the utility correctly identifies the first
 /// body line after the superclass.
+///
+/// All six `EnforcedStyle` values, with autocorrect, come from
+/// `shared::empty_lines_around_body`, which this shares with
+/// `Layout/EmptyLinesAroundModuleBody`.
 pub struct EmptyLinesAroundClassBody;
 
 impl Cop for EmptyLinesAroundClassBody {
@@ -42,7 +46,7 @@ impl Cop for EmptyLinesAroundClassBody {
         corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let style = config.get_str("EnforcedStyle", "no_empty_lines");
-        let (kw_offset, end_offset) = if let Some(class_node) = node.as_class_node() {
+        let (kw_offset, end_offset, body) = if let Some(class_node) = node.as_class_node() {
             // For multiline class declarations (class Foo <\n  Bar), use the
             // superclass end line so the utility correctly identifies the body start.
             let kw = if let Some(superclass) = class_node.superclass() {
@@ -50,89 +54,32 @@ impl Cop for EmptyLinesAroundClassBody {
             } else {
                 class_node.class_keyword_loc().start_offset()
             };
-            (kw, class_node.end_keyword_loc().start_offset())
+            (
+                kw,
+                class_node.end_keyword_loc().start_offset(),
+                class_node.body(),
+            )
         } else if let Some(sclass_node) = node.as_singleton_class_node() {
             (
                 sclass_node.class_keyword_loc().start_offset(),
                 sclass_node.end_keyword_loc().start_offset(),
+                sclass_node.body(),
             )
         } else {
             return;
         };
 
-        match style {
-            "empty_lines" => {
-                diagnostics.extend(
-                    util::check_missing_empty_lines_around_body_with_corrections(
-                        self.name(),
-                        source,
-                        kw_offset,
-                        end_offset,
-                        "class",
-                        corrections,
-                    ),
-                );
-            }
-            "beginning_only" => {
-                // Require blank line at beginning, flag blank at end
-                let mut diags = util::check_missing_empty_lines_around_body(
-                    self.name(),
-                    source,
-                    kw_offset,
-                    end_offset,
-                    "class",
-                );
-                // Keep only "beginning" diagnostics
-                diags.retain(|d| d.message.contains("beginning"));
-                // Also flag extra blank at end
-                let extra_end = util::check_empty_lines_around_body(
-                    self.name(),
-                    source,
-                    kw_offset,
-                    end_offset,
-                    "class",
-                );
-                diags.extend(extra_end.into_iter().filter(|d| d.message.contains("end")));
-                diagnostics.extend(diags);
-            }
-            "ending_only" => {
-                // Require blank line at end, flag blank at beginning
-                let mut diags = util::check_missing_empty_lines_around_body(
-                    self.name(),
-                    source,
-                    kw_offset,
-                    end_offset,
-                    "class",
-                );
-                // Keep only "end" diagnostics
-                diags.retain(|d| d.message.contains("end"));
-                // Also flag extra blank at beginning
-                let extra_begin = util::check_empty_lines_around_body(
-                    self.name(),
-                    source,
-                    kw_offset,
-                    end_offset,
-                    "class",
-                );
-                diags.extend(
-                    extra_begin
-                        .into_iter()
-                        .filter(|d| d.message.contains("beginning")),
-                );
-                diagnostics.extend(diags);
-            }
-            _ => {
-                // "no_empty_lines" (default)
-                diagnostics.extend(util::check_empty_lines_around_body_with_corrections(
-                    self.name(),
-                    source,
-                    kw_offset,
-                    end_offset,
-                    "class",
-                    corrections,
-                ));
-            }
-        }
+        empty_lines_around_body::check(
+            self.name(),
+            source,
+            style,
+            kw_offset,
+            end_offset,
+            body,
+            "class",
+            diagnostics,
+            corrections,
+        );
     }
 }
 
@@ -209,4 +156,151 @@ mod tests {
             "beginning_only should flag missing blank at beginning"
         );
     }
+
+    #[test]
+    fn offense_empty_lines_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &EmptyLinesAroundClassBody,
+            "EnforcedStyle: empty_lines",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.empty_lines.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_empty_lines_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &EmptyLinesAroundClassBody,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.empty_lines.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/corrected.empty_lines.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: empty_lines"),
+        );
+    }
+
+    #[test]
+    fn offense_empty_lines_except_namespace_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &EmptyLinesAroundClassBody,
+            "EnforcedStyle: empty_lines_except_namespace",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.empty_lines_except_namespace.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn no_offense_empty_lines_except_namespace_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &EmptyLinesAroundClassBody,
+            "EnforcedStyle: empty_lines_except_namespace",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/no_offense.empty_lines_except_namespace.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_empty_lines_except_namespace_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &EmptyLinesAroundClassBody,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.empty_lines_except_namespace.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/corrected.empty_lines_except_namespace.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: empty_lines_except_namespace"),
+        );
+    }
+
+    #[test]
+    fn offense_empty_lines_special_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &EmptyLinesAroundClassBody,
+            "EnforcedStyle: empty_lines_special",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.empty_lines_special.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn no_offense_empty_lines_special_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &EmptyLinesAroundClassBody,
+            "EnforcedStyle: empty_lines_special",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/no_offense.empty_lines_special.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_empty_lines_special_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &EmptyLinesAroundClassBody,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.empty_lines_special.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/corrected.empty_lines_special.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: empty_lines_special"),
+        );
+    }
+
+    #[test]
+    fn offense_beginning_only_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &EmptyLinesAroundClassBody,
+            "EnforcedStyle: beginning_only",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.beginning_only.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_beginning_only_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &EmptyLinesAroundClassBody,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.beginning_only.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/corrected.beginning_only.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: beginning_only"),
+        );
+    }
+
+    #[test]
+    fn offense_ending_only_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &EmptyLinesAroundClassBody,
+            "EnforcedStyle: ending_only",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.ending_only.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_ending_only_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &EmptyLinesAroundClassBody,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/offense.ending_only.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_class_body/corrected.ending_only.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: ending_only"),
+        );
+    }
 }
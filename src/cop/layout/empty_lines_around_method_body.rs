@@ -112,6 +112,7 @@ impl Cop for EmptyLinesAroundMethodBody {
                         cop_name: self.name().to_string(),
                         message: "Extra empty line detected at method body beginning.".to_string(),
                         corrected: false,
+                        end_column: None,
                     };
                     if let Some(ref mut corr) = corrections {
                         if let (Some(start), Some(end)) = (
@@ -177,6 +178,7 @@ fn check_empty_line_after_empty_multiline_method_definition(
         cop_name: cop_name.to_string(),
         message: "Extra empty line detected at method body beginning.".to_string(),
         corrected: false,
+        end_column: None,
     };
     if let Some(ref mut corr) = corrections {
         if let Some(start) = source.line_col_to_offset(blank_line, 0) {
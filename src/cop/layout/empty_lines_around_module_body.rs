@@ -1,5 +1,5 @@
+use crate::cop::shared::empty_lines_around_body;
 use crate::cop::shared::node_type::MODULE_NODE;
-use crate::cop::shared::util;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
@@ -49,34 +49,17 @@ impl Cop for EmptyLinesAroundModuleBody {
             None => return,
         };
 
-        let kw_offset = module_node.module_keyword_loc().start_offset();
-        let end_offset = module_node.end_keyword_loc().start_offset();
-
-        match style {
-            "empty_lines" => {
-                diagnostics.extend(
-                    util::check_missing_empty_lines_around_body_with_corrections(
-                        self.name(),
-                        source,
-                        kw_offset,
-                        end_offset,
-                        "module",
-                        corrections,
-                    ),
-                );
-            }
-            _ => {
-                // "no_empty_lines" (default)
-                diagnostics.extend(util::check_empty_lines_around_body_with_corrections(
-                    self.name(),
-                    source,
-                    kw_offset,
-                    end_offset,
-                    "module",
-                    corrections,
-                ));
-            }
-        }
+        empty_lines_around_body::check(
+            self.name(),
+            source,
+            style,
+            module_node.module_keyword_loc().start_offset(),
+            module_node.end_keyword_loc().start_offset(),
+            module_node.body(),
+            "module",
+            diagnostics,
+            corrections,
+        );
     }
 }
 
@@ -143,4 +126,65 @@ mod tests {
             "CRLF empty lines should still be treated as empty"
         );
     }
+
+    #[test]
+    fn offense_empty_lines_except_namespace_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &EmptyLinesAroundModuleBody,
+            "EnforcedStyle: empty_lines_except_namespace",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_module_body/offense.empty_lines_except_namespace.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_empty_lines_except_namespace_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &EmptyLinesAroundModuleBody,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_module_body/offense.empty_lines_except_namespace.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_module_body/corrected.empty_lines_except_namespace.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: empty_lines_except_namespace"),
+        );
+    }
+
+    #[test]
+    fn offense_empty_lines_special_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &EmptyLinesAroundModuleBody,
+            "EnforcedStyle: empty_lines_special",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_module_body/offense.empty_lines_special.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn no_offense_empty_lines_special_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &EmptyLinesAroundModuleBody,
+            "EnforcedStyle: empty_lines_special",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_module_body/no_offense.empty_lines_special.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_empty_lines_special_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &EmptyLinesAroundModuleBody,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_module_body/offense.empty_lines_special.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/empty_lines_around_module_body/corrected.empty_lines_special.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: empty_lines_special"),
+        );
+    }
 }
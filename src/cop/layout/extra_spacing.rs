@@ -1,9 +1,12 @@
+use crate::cop::shared::preceding_following_alignment::{
+    aligned_with_adjacent_line, byte_to_char_col, char_col_to_byte, line_indentation,
+};
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::codemap::CodeMap;
 use crate::parse::source::SourceFile;
 use ruby_prism::Visit;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::ops::Range;
 
 /// Layout/ExtraSpacing: flags unnecessary whitespace between tokens.
@@ -72,11 +75,19 @@ use std::ops::Range;
 /// - Works with raw text scanning (not tokens), using CodeMap to skip non-code regions
 /// - Alignment detection mirrors RuboCop's PrecedingFollowingAlignment mixin:
 ///   Pass 1 checks nearest non-blank non-comment line, Pass 2 checks nearest
-///   line with same indentation
+///   line with same indentation. The line search is shared with
+///   Layout/SpaceAroundOperators (`shared::preceding_following_alignment`).
 /// - Hash pair ranges in multiline hashes are ignored (handled by Layout/HashAlignment)
 /// - Word/symbol array ranges (%w/%i/%W/%I) are ignored (spacing is element separation)
-/// - ForceEqualSignAlignment is read from config but not yet implemented (produces
-///   a different offense message)
+/// - ForceEqualSignAlignment: the first assignment operator of each line
+///   (from the AST, so parameter defaults are excluded) must line up with the
+///   assignment on the preceding line of its alignment group. A group is a run
+///   of assignment lines at the same indentation; a blank line, a dedent or a
+///   non-assignment line at that indentation ends it, while comment-only and
+///   more deeply indented (continuation) lines are skipped. Spacing before
+///   those operators is not reported as unnecessary, and autocorrect pads or
+///   trims the space before every `=` of the group to the widest one, like
+///   RuboCop's `align_equal_signs`.
 ///
 /// ## Investigation findings (2026-03-23)
 ///
@@ -223,7 +234,7 @@ impl Cop for ExtraSpacing {
     ) {
         let allow_for_alignment = config.get_bool("AllowForAlignment", true);
         let allow_before_trailing_comments = config.get_bool("AllowBeforeTrailingComments", false);
-        let _force_equal_sign_alignment = config.get_bool("ForceEqualSignAlignment", false);
+        let force_equal_sign_alignment = config.get_bool("ForceEqualSignAlignment", false);
 
         let lines: Vec<&[u8]> = source.lines().map(trim_terminal_cr).collect();
         let src_bytes = source.as_bytes();
@@ -262,8 +273,13 @@ impl Cop for ExtraSpacing {
         // comments that start at the same column are both considered "aligned".
         let aligned_comment_lines = build_aligned_comment_lines(parse_result, source);
 
-        // Identify comment-only lines (0-indexed) for skipping during alignment search
-        let comment_only_lines = build_comment_only_lines(&lines);
+        // First assignment operator of each line (0-indexed), for
+        // ForceEqualSignAlignment.
+        let assignment_operators = if force_equal_sign_alignment {
+            collect_assignment_operators(parse_result, source)
+        } else {
+            BTreeMap::new()
+        };
 
         for (line_idx, &line) in lines.iter().enumerate() {
             let line_num = line_idx + 1;
@@ -306,6 +322,15 @@ impl Cop for ExtraSpacing {
                         // Get the byte offset in the full source
                         let abs_offset = line_start_offset + space_start;
 
+                        // With ForceEqualSignAlignment, the space before an
+                        // assignment is checked by `check_equal_sign_alignment`.
+                        if assignment_operators
+                            .get(&line_idx)
+                            .is_some_and(|&(start, _)| start == line_start_offset + i)
+                        {
+                            continue;
+                        }
+
                         // Skip if inside string/comment, except for code inside
                         // #{...} interpolation within heredocs.
                         if !code_map.is_code(abs_offset)
@@ -347,7 +372,6 @@ impl Cop for ExtraSpacing {
                                     line_start_offset,
                                     source,
                                     &heredoc_opener_starts,
-                                    &comment_only_lines,
                                 )
                             {
                                 continue;
@@ -378,9 +402,242 @@ impl Cop for ExtraSpacing {
                 }
             }
         }
+
+        if force_equal_sign_alignment {
+            self.check_equal_sign_alignment(
+                source,
+                &lines,
+                &assignment_operators,
+                diagnostics,
+                &mut corrections,
+            );
+        }
+    }
+}
+
+impl ExtraSpacing {
+    /// ForceEqualSignAlignment: flag each assignment that isn't aligned with
+    /// the preceding assignment of its group, and align the whole group.
+    fn check_equal_sign_alignment(
+        &self,
+        source: &SourceFile,
+        lines: &[&[u8]],
+        assignment_operators: &BTreeMap<usize, (usize, usize)>,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: &mut Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let end_column = |line_idx: usize| {
+            let (_, end) = assignment_operators[&line_idx];
+            byte_to_char_col(
+                lines[line_idx],
+                end - source.line_start_offset(line_idx + 1),
+            )
+        };
+        // Groups already aligned by an earlier offense, keyed by first line.
+        let mut corrected_groups = HashSet::new();
+
+        for (&line_idx, &(start, _)) in assignment_operators {
+            let Some(preceding) =
+                assignment_group_neighbor(lines, assignment_operators, line_idx, true)
+            else {
+                continue;
+            };
+            if end_column(preceding) == end_column(line_idx) {
+                continue;
+            }
+
+            let (line, column) = source.offset_to_line_col(start);
+            let mut diag = self.diagnostic(
+                source,
+                line,
+                column,
+                "`=` is not aligned with the preceding assignment.".to_string(),
+            );
+            if let Some(corr) = corrections.as_deref_mut() {
+                let group = assignment_group(lines, assignment_operators, line_idx);
+                if corrected_groups.insert(group[0]) {
+                    // The column each operator would end at with a single
+                    // space before it.
+                    let align_columns: Vec<(usize, usize)> = group
+                        .iter()
+                        .map(|&idx| {
+                            let (op_start, _) = assignment_operators[&idx];
+                            let spaces = source.as_bytes()[..op_start]
+                                .iter()
+                                .rev()
+                                .take_while(|&&b| b == b' ')
+                                .count();
+                            (idx, end_column(idx) + 1 - spaces)
+                        })
+                        .collect();
+                    let align_to = align_columns.iter().map(|&(_, col)| col).max();
+                    for &(idx, _) in &align_columns {
+                        let (op_start, _) = assignment_operators[&idx];
+                        let current = end_column(idx);
+                        let target = align_to.unwrap_or(current);
+                        if target > current {
+                            corr.push(crate::correction::Correction {
+                                start: op_start,
+                                end: op_start,
+                                replacement: " ".repeat(target - current),
+                                cop_name: self.name(),
+                                cop_index: 0,
+                            });
+                        } else if target < current {
+                            corr.push(crate::correction::Correction {
+                                start: op_start - (current - target),
+                                end: op_start,
+                                replacement: String::new(),
+                                cop_name: self.name(),
+                                cop_index: 0,
+                            });
+                        }
+                    }
+                }
+                diag.corrected = true;
+            }
+            diagnostics.push(diag);
+        }
+    }
+}
+
+// -- Assignment alignment groups --
+
+/// Collect the first assignment operator on each line as a byte range,
+/// keyed by 0-indexed line.
+fn collect_assignment_operators(
+    parse_result: &ruby_prism::ParseResult<'_>,
+    source: &SourceFile,
+) -> BTreeMap<usize, (usize, usize)> {
+    let mut collector = AssignmentOperatorCollector {
+        source,
+        operators: BTreeMap::new(),
+    };
+    collector.visit(&parse_result.node());
+    collector.operators
+}
+
+struct AssignmentOperatorCollector<'a> {
+    source: &'a SourceFile,
+    operators: BTreeMap<usize, (usize, usize)>,
+}
+
+impl<'pr> Visit<'pr> for AssignmentOperatorCollector<'_> {
+    fn visit_branch_node_enter(&mut self, node: ruby_prism::Node<'pr>) {
+        if let Some(loc) = assignment_operator_loc(&node) {
+            let (start, end) = (loc.start_offset(), loc.end_offset());
+            let (line, _) = self.source.offset_to_line_col(start);
+            let entry = self.operators.entry(line - 1).or_insert((start, end));
+            if start < entry.0 {
+                *entry = (start, end);
+            }
+        }
+    }
+}
+
+/// The `=` / `op=` operator of an assignment node. Parameter defaults are
+/// not assignments here.
+fn assignment_operator_loc<'pr>(node: &ruby_prism::Node<'pr>) -> Option<ruby_prism::Location<'pr>> {
+    macro_rules! operator_loc {
+        ($($as_node:ident => $loc:ident),* $(,)?) => {
+            $(
+                if let Some(n) = node.$as_node() {
+                    return Some(n.$loc());
+                }
+            )*
+        };
+    }
+    operator_loc!(
+        as_local_variable_write_node => operator_loc,
+        as_instance_variable_write_node => operator_loc,
+        as_class_variable_write_node => operator_loc,
+        as_global_variable_write_node => operator_loc,
+        as_constant_write_node => operator_loc,
+        as_constant_path_write_node => operator_loc,
+        as_multi_write_node => operator_loc,
+        as_local_variable_operator_write_node => binary_operator_loc,
+        as_instance_variable_operator_write_node => binary_operator_loc,
+        as_class_variable_operator_write_node => binary_operator_loc,
+        as_global_variable_operator_write_node => binary_operator_loc,
+        as_constant_operator_write_node => binary_operator_loc,
+        as_constant_path_operator_write_node => binary_operator_loc,
+        as_call_operator_write_node => binary_operator_loc,
+        as_index_operator_write_node => binary_operator_loc,
+        as_local_variable_or_write_node => operator_loc,
+        as_local_variable_and_write_node => operator_loc,
+        as_instance_variable_or_write_node => operator_loc,
+        as_instance_variable_and_write_node => operator_loc,
+        as_class_variable_or_write_node => operator_loc,
+        as_class_variable_and_write_node => operator_loc,
+        as_global_variable_or_write_node => operator_loc,
+        as_global_variable_and_write_node => operator_loc,
+        as_constant_or_write_node => operator_loc,
+        as_constant_and_write_node => operator_loc,
+        as_constant_path_or_write_node => operator_loc,
+        as_constant_path_and_write_node => operator_loc,
+        as_call_or_write_node => operator_loc,
+        as_call_and_write_node => operator_loc,
+        as_index_or_write_node => operator_loc,
+        as_index_and_write_node => operator_loc,
+    );
+    // Attribute and index writes: `foo.bar = 1`, `foo[:bar] = 1`.
+    node.as_call_node().and_then(|call| call.equal_loc())
+}
+
+/// The previous (`going_up`) or next assignment line in the same alignment
+/// group as `line_idx`.
+fn assignment_group_neighbor(
+    lines: &[&[u8]],
+    assignment_operators: &BTreeMap<usize, (usize, usize)>,
+    line_idx: usize,
+    going_up: bool,
+) -> Option<usize> {
+    let indent = line_indentation(lines[line_idx]);
+    let mut idx = line_idx;
+    loop {
+        if going_up {
+            idx = idx.checked_sub(1)?;
+        } else {
+            idx += 1;
+            if idx >= lines.len() {
+                return None;
+            }
+        }
+
+        let line = lines[idx];
+        // A blank line ends the group.
+        let first = line.iter().position(|&b| b != b' ' && b != b'\t')?;
+        if line[first] == b'#' {
+            continue;
+        }
+        let this_indent = line_indentation(line);
+        if this_indent > indent {
+            continue;
+        }
+        return (this_indent == indent && assignment_operators.contains_key(&idx)).then_some(idx);
     }
 }
 
+/// All lines of the alignment group containing `line_idx`, in order.
+fn assignment_group(
+    lines: &[&[u8]],
+    assignment_operators: &BTreeMap<usize, (usize, usize)>,
+    line_idx: usize,
+) -> Vec<usize> {
+    let mut group = vec![line_idx];
+    let mut idx = line_idx;
+    while let Some(prev) = assignment_group_neighbor(lines, assignment_operators, idx, true) {
+        group.insert(0, prev);
+        idx = prev;
+    }
+    idx = line_idx;
+    while let Some(next) = assignment_group_neighbor(lines, assignment_operators, idx, false) {
+        group.push(next);
+        idx = next;
+    }
+    group
+}
+
 // -- Multiline hash pair ignored ranges --
 
 /// Collect byte ranges between keys and values in multiline hash pairs.
@@ -761,28 +1018,9 @@ fn build_aligned_comment_lines(
     aligned
 }
 
-// -- Comment-only lines --
-
-fn build_comment_only_lines(lines: &[&[u8]]) -> HashSet<usize> {
-    let mut set = HashSet::new();
-    for (idx, line) in lines.iter().enumerate() {
-        let first_non_ws = line.iter().position(|&b| b != b' ' && b != b'\t');
-        if let Some(pos) = first_non_ws {
-            if line[pos] == b'#' {
-                set.insert(idx);
-            }
-        }
-    }
-    set
-}
-
 // -- Alignment detection --
 
 /// Check if the token at `col` aligns with a token on a nearby line.
-///
-/// Implements RuboCop's PrecedingFollowingAlignment:
-/// 1. First pass: nearest non-blank, non-comment-only line in each direction.
-/// 2. Second pass: nearest line with the same indentation in each direction.
 fn is_aligned_with_adjacent(
     lines: &[&[u8]],
     line_idx: usize,
@@ -790,15 +1028,10 @@ fn is_aligned_with_adjacent(
     line_start_offset: usize,
     source: &SourceFile,
     heredoc_opener_starts: &HashSet<usize>,
-    comment_only_lines: &HashSet<usize>,
 ) -> bool {
-    let base_indent = line_indentation(lines[line_idx]);
-
     let current_line = lines[line_idx];
-
-    // Pass 1: nearest non-blank, non-comment-only line
-    if let Some(adj) = find_nearest_line(lines, line_idx, true, comment_only_lines, None) {
-        if check_alignment(current_line, lines[adj], col)
+    aligned_with_adjacent_line(lines, line_idx, |adj| {
+        check_alignment(current_line, lines[adj], col)
             || check_equals_alignment(
                 current_line,
                 lines[adj],
@@ -807,110 +1040,7 @@ fn is_aligned_with_adjacent(
                 source.line_start_offset(adj + 1),
                 heredoc_opener_starts,
             )
-        {
-            return true;
-        }
-    }
-    if let Some(adj) = find_nearest_line(lines, line_idx, false, comment_only_lines, None) {
-        if check_alignment(current_line, lines[adj], col)
-            || check_equals_alignment(
-                current_line,
-                lines[adj],
-                col,
-                line_start_offset,
-                source.line_start_offset(adj + 1),
-                heredoc_opener_starts,
-            )
-        {
-            return true;
-        }
-    }
-
-    // Pass 2: nearest line with same indentation
-    if let Some(adj) =
-        find_nearest_line(lines, line_idx, true, comment_only_lines, Some(base_indent))
-    {
-        if check_alignment(current_line, lines[adj], col)
-            || check_equals_alignment(
-                current_line,
-                lines[adj],
-                col,
-                line_start_offset,
-                source.line_start_offset(adj + 1),
-                heredoc_opener_starts,
-            )
-        {
-            return true;
-        }
-    }
-    if let Some(adj) = find_nearest_line(
-        lines,
-        line_idx,
-        false,
-        comment_only_lines,
-        Some(base_indent),
-    ) {
-        if check_alignment(current_line, lines[adj], col)
-            || check_equals_alignment(
-                current_line,
-                lines[adj],
-                col,
-                line_start_offset,
-                source.line_start_offset(adj + 1),
-                heredoc_opener_starts,
-            )
-        {
-            return true;
-        }
-    }
-
-    false
-}
-
-/// Find the nearest non-blank, non-comment-only line in the given direction.
-/// When `required_indent` is None, returns the very first non-blank, non-comment line.
-/// When `required_indent` is Some, skips lines with different indentation (matching
-/// RuboCop's PrecedingFollowingAlignment behavior which walks through all lines).
-fn find_nearest_line(
-    lines: &[&[u8]],
-    start_idx: usize,
-    going_up: bool,
-    comment_only_lines: &HashSet<usize>,
-    required_indent: Option<usize>,
-) -> Option<usize> {
-    let mut idx = start_idx;
-    loop {
-        if going_up {
-            if idx == 0 {
-                return None;
-            }
-            idx -= 1;
-        } else {
-            idx += 1;
-            if idx >= lines.len() {
-                return None;
-            }
-        }
-
-        if comment_only_lines.contains(&idx) {
-            continue;
-        }
-
-        let line = lines[idx];
-
-        if line.iter().all(|&b| b == b' ' || b == b'\t' || b == b'\r') {
-            continue;
-        }
-
-        if let Some(indent) = required_indent {
-            let this_indent = line_indentation(line);
-            if this_indent != indent {
-                continue;
-            }
-        }
-
-        return Some(idx);
-    }
+    })
 }
 
 /// Check alignment: mirrors RuboCop's `aligned_words?` check.
@@ -1355,12 +1485,6 @@ fn find_lshift_col(line: &[u8], col: usize) -> Option<usize> {
     None
 }
 
-fn line_indentation(line: &[u8]) -> usize {
-    line.iter()
-        .take_while(|&&b| b == b' ' || b == b'\t')
-        .count()
-}
-
 fn location_spans_multiple_lines(source: &SourceFile, loc: &ruby_prism::Location<'_>) -> bool {
     let (start_line, _) = source.offset_to_line_col(loc.start_offset());
     let end_offset = loc.end_offset().saturating_sub(1);
@@ -1372,32 +1496,6 @@ fn trim_terminal_cr(line: &[u8]) -> &[u8] {
     line.strip_suffix(b"\r").unwrap_or(line)
 }
 
-/// Convert a byte offset to a character column (0-indexed).
-/// Each UTF-8 character (regardless of byte length) counts as 1 column.
-fn byte_to_char_col(line: &[u8], byte_col: usize) -> usize {
-    let end = byte_col.min(line.len());
-    let s = std::str::from_utf8(&line[..end]).unwrap_or("");
-    s.chars().count()
-}
-
-/// Convert a character column to a byte offset on the given line.
-/// Returns None if the line is shorter than the requested character column.
-fn char_col_to_byte(line: &[u8], char_col: usize) -> Option<usize> {
-    let s = std::str::from_utf8(line).unwrap_or("");
-    let mut byte_offset = 0;
-    for (i, ch) in s.chars().enumerate() {
-        if i == char_col {
-            return Some(byte_offset);
-        }
-        byte_offset += ch.len_utf8();
-    }
-    if char_col == s.chars().count() {
-        Some(byte_offset)
-    } else {
-        None
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1405,6 +1503,66 @@ mod tests {
     crate::cop_fixture_tests!(ExtraSpacing, "cops/layout/extra_spacing");
     crate::cop_autocorrect_fixture_tests!(ExtraSpacing, "cops/layout/extra_spacing");
 
+    fn force_equal_sign_alignment_config() -> crate::cop::CopConfig {
+        let mut options = std::collections::HashMap::new();
+        options.insert(
+            "ForceEqualSignAlignment".to_string(),
+            serde_yml::Value::Bool(true),
+        );
+        crate::cop::CopConfig {
+            options,
+            ..crate::cop::CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_force_equal_sign_alignment() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &ExtraSpacing,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/extra_spacing/offense.force_equal_sign_alignment.rb"
+            ),
+            force_equal_sign_alignment_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_force_equal_sign_alignment() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &ExtraSpacing,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/extra_spacing/no_offense.force_equal_sign_alignment.rb"
+            ),
+            force_equal_sign_alignment_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_force_equal_sign_alignment() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &ExtraSpacing,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/extra_spacing/offense.force_equal_sign_alignment.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/extra_spacing/corrected.force_equal_sign_alignment.rb"
+            ),
+            force_equal_sign_alignment_config(),
+        );
+    }
+
+    #[test]
+    fn force_equal_sign_alignment_corrects_each_group_once() {
+        // Both `b` and `c` are flagged, but the group is aligned only once.
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect_with_config(
+            &ExtraSpacing,
+            b"aaa = 1\nb = 2\ncc = 3\n",
+            force_equal_sign_alignment_config(),
+        );
+        assert_eq!(diags.len(), 2);
+        assert_eq!(corrections.len(), 2);
+    }
+
     #[test]
     fn coincidental_alignment_not_preceded_by_space() {
         use crate::testutil::run_cop_full;
@@ -1,6 +1,7 @@
 use ruby_prism::Visit;
 
 use crate::cop::shared::method_identifier_predicates;
+use crate::cop::shared::multiline_element_indentation::{previous_code_line_indent, reindent_line};
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
@@ -95,6 +96,12 @@ use crate::parse::source::SourceFile;
 /// path. Fixed by treating only `BlockNode` values as attached blocks and
 /// keeping `BlockArgumentNode` (`&blk`) eligible for special inner-call
 /// indentation and the quoted base-range message.
+///
+/// **Autocorrect / config:** the first-argument line is re-indented via
+/// `shared::multiline_element_indentation::reindent_line` (RuboCop's
+/// `AlignmentCorrector`); only leading whitespace of that line changes. When
+/// the cop has no `IndentationWidth`, `Layout/IndentationWidth.Width` is
+/// injected by the config loader.
 pub struct FirstArgumentIndentation;
 
 impl Cop for FirstArgumentIndentation {
@@ -109,7 +116,7 @@ impl Cop for FirstArgumentIndentation {
         _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let style = config.get_str(
             "EnforcedStyle",
@@ -122,11 +129,19 @@ impl Cop for FirstArgumentIndentation {
             style,
             width,
             diagnostics: Vec::new(),
+            corrections: corrections.is_some().then(Vec::new),
             // Stack of parent call info: (is_parenthesized, call_start_offset)
             parent_call_stack: Vec::new(),
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let (Some(corrections), Some(found)) = (corrections, visitor.corrections) {
+            corrections.extend(found);
+        }
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
     }
 }
 
@@ -136,6 +151,8 @@ struct FirstArgVisitor<'a> {
     style: &'a str,
     width: usize,
     diagnostics: Vec<Diagnostic>,
+    /// `Some` when autocorrecting; collects first-argument re-indents.
+    corrections: Option<Vec<crate::correction::Correction>>,
     /// Stack of parent call info: (is_parenthesized, call_node_start_col)
     /// call_node_start_col is the column of the start of the entire call expression
     /// (including receiver), matching RuboCop's node.source_range.begin_pos
@@ -217,7 +234,7 @@ impl FirstArgVisitor<'_> {
         );
 
         if arg_col != expected {
-            self.diagnostics.push(self.cop.diagnostic(
+            let mut diagnostic = self.cop.diagnostic(
                 self.source,
                 arg_line,
                 arg_col,
@@ -226,7 +243,19 @@ impl FirstArgVisitor<'_> {
                     first_arg_loc.start_offset(),
                     metadata.has_attached_block,
                 ),
-            ));
+            );
+            if let Some(corrections) = self.corrections.as_mut() {
+                if let Some(correction) = reindent_line(
+                    self.source,
+                    first_arg_loc.start_offset(),
+                    expected,
+                    "Layout/FirstArgumentIndentation",
+                ) {
+                    corrections.push(correction);
+                    diagnostic.corrected = true;
+                }
+            }
+            self.diagnostics.push(diagnostic);
         }
     }
 
@@ -401,48 +430,6 @@ fn trim_end_whitespace(bytes: &[u8]) -> &[u8] {
     &bytes[..end]
 }
 
-/// Find the indentation of the previous non-blank, non-comment code line.
-/// This matches RuboCop's `previous_code_line` behavior: the position of the
-/// first non-whitespace character (`=~ /\S/`), counting both tabs and spaces
-/// as 1 character each.
-fn previous_code_line_indent(source: &SourceFile, line_number: usize) -> usize {
-    let mut line_num = line_number;
-    loop {
-        if line_num <= 1 {
-            return 0;
-        }
-        line_num -= 1;
-        let line_bytes = source.lines().nth(line_num - 1).unwrap_or(b"");
-        // Skip blank lines
-        if line_bytes
-            .iter()
-            .all(|&b| b == b' ' || b == b'\t' || b == b'\n' || b == b'\r')
-        {
-            continue;
-        }
-        // Skip comment lines (lines where first non-whitespace is #)
-        // but NOT interpolation openings (#{...}) which appear in heredocs
-        let mut after_ws = line_bytes
-            .iter()
-            .skip_while(|&&b| b == b' ' || b == b'\t')
-            .copied();
-        if after_ws.next() == Some(b'#') && after_ws.next() != Some(b'{') {
-            continue;
-        }
-        // Count all leading whitespace characters (tabs and spaces),
-        // matching RuboCop's `=~ /\S/` which treats each tab as 1 char
-        return leading_whitespace_count(line_bytes);
-    }
-}
-
-/// Count the number of leading whitespace characters (spaces and tabs).
-/// Each tab counts as 1, matching Ruby's regex `=~ /\S/` behavior.
-fn leading_whitespace_count(line: &[u8]) -> usize {
-    line.iter()
-        .take_while(|&&b| b == b' ' || b == b'\t')
-        .count()
-}
-
 fn is_bare_operator(name: &str, has_regular_dot: bool) -> bool {
     method_identifier_predicates::is_operator_method(name.as_bytes()) && !has_regular_dot
 }
@@ -560,6 +547,10 @@ mod tests {
         FirstArgumentIndentation,
         "cops/layout/first_argument_indentation"
     );
+    crate::cop_autocorrect_fixture_tests!(
+        FirstArgumentIndentation,
+        "cops/layout/first_argument_indentation"
+    );
 
     #[test]
     fn args_on_same_line_ignored() {
@@ -648,4 +639,132 @@ mod tests {
             "Indent the first argument one step more than `foo.bar(`."
         );
     }
+
+    fn style_config(style: &str) -> CopConfig {
+        use std::collections::HashMap;
+        CopConfig {
+            options: HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String(style.into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_consistent_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &FirstArgumentIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/offense.consistent.rb"
+            ),
+            style_config("consistent"),
+        );
+    }
+
+    #[test]
+    fn no_offense_consistent_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &FirstArgumentIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/no_offense.consistent.rb"
+            ),
+            style_config("consistent"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_consistent_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &FirstArgumentIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/offense.consistent.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/corrected.consistent.rb"
+            ),
+            style_config("consistent"),
+        );
+    }
+
+    #[test]
+    fn offense_consistent_relative_to_receiver_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &FirstArgumentIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/offense.consistent_relative_to_receiver.rb"
+            ),
+            style_config("consistent_relative_to_receiver"),
+        );
+    }
+
+    #[test]
+    fn no_offense_consistent_relative_to_receiver_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &FirstArgumentIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/no_offense.consistent_relative_to_receiver.rb"
+            ),
+            style_config("consistent_relative_to_receiver"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_consistent_relative_to_receiver_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &FirstArgumentIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/offense.consistent_relative_to_receiver.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/corrected.consistent_relative_to_receiver.rb"
+            ),
+            style_config("consistent_relative_to_receiver"),
+        );
+    }
+
+    #[test]
+    fn offense_special_for_inner_method_call_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &FirstArgumentIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/offense.special_for_inner_method_call.rb"
+            ),
+            style_config("special_for_inner_method_call"),
+        );
+    }
+
+    #[test]
+    fn no_offense_special_for_inner_method_call_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &FirstArgumentIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/no_offense.special_for_inner_method_call.rb"
+            ),
+            style_config("special_for_inner_method_call"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_special_for_inner_method_call_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &FirstArgumentIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/offense.special_for_inner_method_call.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_argument_indentation/corrected.special_for_inner_method_call.rb"
+            ),
+            style_config("special_for_inner_method_call"),
+        );
+    }
 }
@@ -1,17 +1,9 @@
+use crate::cop::shared::multiline_element_indentation::{IndentBase, line_indent, reindent_line};
 use crate::cop::shared::node_type::ARRAY_NODE;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
-/// Byte offset of the first non-whitespace character on a line.
-/// Equivalent to RuboCop's `source_line =~ /\S/`. Handles both spaces and tabs.
-/// Returns the byte count of leading whitespace (tabs count as 1 byte each).
-fn first_non_whitespace_column(line: &[u8]) -> usize {
-    line.iter()
-        .take_while(|&&b| b == b' ' || b == b'\t')
-        .count()
-}
-
 /// Convert a byte offset within a line to a character (codepoint) offset.
 /// Counts non-continuation bytes (bytes where (b & 0xC0) != 0x80) in the
 /// range [0, byte_col). For ASCII-only lines, byte_col == char_col.
@@ -44,7 +36,7 @@ fn byte_col_to_char_col(line_bytes: &[u8], byte_col: usize) -> usize {
 /// a) Tab indentation: `indentation_of()` only counted spaces, returning 0 for
 ///    tab-indented lines. But `offset_to_line_col` counted tabs as 1 character.
 ///    So closing bracket `\t]` had close_col=1 but indent_base=0. Fix: use
-///    `first_non_whitespace_column()` (byte offset of first non-whitespace char,
+///    `line_indent()` (byte offset of first non-whitespace char,
 ///    matching RuboCop's `source_line =~ /\S/`) for both sides of the comparison.
 ///    This fixed WhatWeb (~54 FPs) and phlex (~23 FPs).
 /// b) Array inside hash that is chained: `method({ key: [...], k2: v }.to_json)`
@@ -209,21 +201,13 @@ fn byte_col_to_char_col(line_bytes: &[u8], byte_col: usize) -> usize {
 ///    line indent instead of the hash pair start because the backward scan only
 ///    stopped at `{` or `,`. Fix: treat an enclosing top-level `(` as a valid
 ///    boundary and return the first token after it.
+///
+/// **Autocorrect / config:** the first-element line and the right bracket are
+/// re-indented with the shared `reindent_line` helper; the base rules
+/// (`IndentBase`) are shared with `Layout/FirstHashElementIndentation`.
+/// `IndentationWidth` falls back to `Layout/IndentationWidth.Width`.
 pub struct FirstArrayElementIndentation;
 
-/// Describes what the expected indentation is relative to.
-#[derive(Clone, Copy)]
-enum IndentBaseType {
-    /// `align_brackets` style: relative to the opening bracket `[`
-    LeftBracket,
-    /// `special_inside_parentheses`: relative to the first position after `(`
-    FirstColumnAfterLeftParenthesis,
-    /// Default: relative to the start of the line where `[` appears
-    StartOfLine,
-    /// Hash-key-relative: relative to the parent hash key (multi-pair hashes)
-    ParentHashKey,
-}
-
 /// Result of scanning backwards from `[` to find an enclosing `(`.
 struct ParenScanResult {
     /// Column of the unmatched `(`, if found.
@@ -478,7 +462,7 @@ fn find_pair_start_before_rocket(line_bytes: &[u8], rocket_gt_col: usize) -> Opt
         }
     }
 
-    Some(first_non_whitespace_column(line_bytes))
+    Some(line_indent(line_bytes))
 }
 
 /// Skip whitespace and full-line comments after an expression so follow-up
@@ -907,6 +891,10 @@ impl Cop for FirstArrayElementIndentation {
         &[ARRAY_NODE]
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_node(
         &self,
         source: &SourceFile,
@@ -914,7 +902,7 @@ impl Cop for FirstArrayElementIndentation {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        mut corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let array_node = match node.as_array_node() {
             Some(a) => a,
@@ -941,7 +929,7 @@ impl Cop for FirstArrayElementIndentation {
 
         // Get the indentation of the line where `[` appears
         let open_line_bytes = source.lines().nth(open_line - 1).unwrap_or(b"");
-        let open_line_indent = first_non_whitespace_column(open_line_bytes);
+        let open_line_indent = line_indent(open_line_bytes);
         let (_, open_col) = source.offset_to_line_col(opening_loc.start_offset());
 
         // Check if `[` is preceded by a hash key on the same line.
@@ -963,7 +951,7 @@ impl Cop for FirstArrayElementIndentation {
         // 4. Default -> line-relative
         let (indent_base, base_type) = {
             if style == "align_brackets" {
-                (open_col, IndentBaseType::LeftBracket)
+                (open_col, IndentBase::LeftBraceOrBracket)
             } else {
                 // Check hash-key-relative BEFORE style-specific checks.
                 // RuboCop uses `parent_hash_key` when: array is hash pair value,
@@ -975,9 +963,9 @@ impl Cop for FirstArrayElementIndentation {
                     && has_right_sibling_on_subsequent_line(source.as_bytes(), closing_end_offset);
 
                 if use_hash_key {
-                    (hash_key_col.unwrap(), IndentBaseType::ParentHashKey)
+                    (hash_key_col.unwrap(), IndentBase::ParentHashKey)
                 } else if style == "consistent" {
-                    (open_line_indent, IndentBaseType::StartOfLine)
+                    (open_line_indent, IndentBase::StartOfLine)
                 } else {
                     // "special_inside_parentheses" (default):
                     let paren_scan = find_left_paren_on_line(open_line_bytes, open_byte_col);
@@ -1004,15 +992,12 @@ impl Cop for FirstArrayElementIndentation {
                                 paren_scan.has_unmatched_brace,
                             );
                         if use_paren_relative {
-                            (
-                                paren_col + 1,
-                                IndentBaseType::FirstColumnAfterLeftParenthesis,
-                            )
+                            (paren_col + 1, IndentBase::FirstColumnAfterLeftParenthesis)
                         } else {
-                            (open_line_indent, IndentBaseType::StartOfLine)
+                            (open_line_indent, IndentBase::StartOfLine)
                         }
                     } else {
-                        (open_line_indent, IndentBaseType::StartOfLine)
+                        (open_line_indent, IndentBase::StartOfLine)
                     }
                 }
             }
@@ -1034,17 +1019,8 @@ impl Cop for FirstArrayElementIndentation {
             let expected_elem = indent_base + width;
 
             if elem_col != expected_elem {
-                let base_description = match base_type {
-                    IndentBaseType::LeftBracket => "the position of the opening bracket",
-                    IndentBaseType::FirstColumnAfterLeftParenthesis => {
-                        "the first position after the preceding left parenthesis"
-                    }
-                    IndentBaseType::ParentHashKey => "the parent hash key",
-                    IndentBaseType::StartOfLine => {
-                        "the start of the line where the left square bracket is"
-                    }
-                };
-                diagnostics.push(self.diagnostic(
+                let base_description = base_type.description("bracket", "left square bracket");
+                let mut diagnostic = self.diagnostic(
                     source,
                     elem_line,
                     elem_col,
@@ -1052,7 +1028,16 @@ impl Cop for FirstArrayElementIndentation {
                         "Use {} spaces for indentation in an array, relative to {}.",
                         width, base_description
                     ),
-                ));
+                );
+                if let Some(corrections) = corrections.as_deref_mut() {
+                    if let Some(correction) =
+                        reindent_line(source, first_loc.start_offset(), expected_elem, self.name())
+                    {
+                        corrections.push(correction);
+                        diagnostic.corrected = true;
+                    }
+                }
+                diagnostics.push(diagnostic);
             }
         }
 
@@ -1081,7 +1066,7 @@ impl Cop for FirstArrayElementIndentation {
             // of character column — this matches RuboCop's `source_line =~ /\S/`
             // and handles tab-indented files correctly (tabs count as 1 byte).
             let effective_close_col = match base_type {
-                IndentBaseType::StartOfLine => first_non_whitespace_column(close_line_bytes),
+                IndentBase::StartOfLine => line_indent(close_line_bytes),
                 _ => close_col,
             };
 
@@ -1091,32 +1076,41 @@ impl Cop for FirstArrayElementIndentation {
                 // flag closing brackets for arrays that are single-pair hash
                 // values in line-relative mode.
                 // In paren-relative mode, closing bracket must match indent_base.
-                if matches!(base_type, IndentBaseType::StartOfLine)
+                if matches!(base_type, IndentBase::StartOfLine)
                     && hash_key_col.is_some()
-                    && !matches!(base_type, IndentBaseType::ParentHashKey)
+                    && !matches!(base_type, IndentBase::ParentHashKey)
                     && effective_close_col == open_line_indent
                 {
                     return;
                 }
                 let msg = match base_type {
-                    IndentBaseType::LeftBracket => {
+                    IndentBase::LeftBraceOrBracket => {
                         "Indent the right bracket the same as the left bracket.".to_string()
                     }
-                    IndentBaseType::FirstColumnAfterLeftParenthesis => {
+                    IndentBase::FirstColumnAfterLeftParenthesis => {
                         "Indent the right bracket the same as the first position \
                          after the preceding left parenthesis."
                             .to_string()
                     }
-                    IndentBaseType::ParentHashKey => {
+                    IndentBase::ParentHashKey => {
                         "Indent the right bracket the same as the parent hash key.".to_string()
                     }
-                    IndentBaseType::StartOfLine => {
+                    IndentBase::StartOfLine => {
                         "Indent the right bracket the same as the start of the line \
                          where the left bracket is."
                             .to_string()
                     }
                 };
-                diagnostics.push(self.diagnostic(source, close_line, close_col, msg));
+                let mut diagnostic = self.diagnostic(source, close_line, close_col, msg);
+                if let Some(corrections) = corrections.as_deref_mut() {
+                    if let Some(correction) =
+                        reindent_line(source, closing_loc.start_offset(), indent_base, self.name())
+                    {
+                        corrections.push(correction);
+                        diagnostic.corrected = true;
+                    }
+                }
+                diagnostics.push(diagnostic);
             }
         }
     }
@@ -1131,6 +1125,10 @@ mod tests {
         FirstArrayElementIndentation,
         "cops/layout/first_array_element_indentation"
     );
+    crate::cop_autocorrect_fixture_tests!(
+        FirstArrayElementIndentation,
+        "cops/layout/first_array_element_indentation"
+    );
 
     #[test]
     fn same_line_elements_ignored() {
@@ -1523,4 +1521,93 @@ mod tests {
             diags
         );
     }
+
+    fn style_config(style: &str) -> CopConfig {
+        use std::collections::HashMap;
+        CopConfig {
+            options: HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String(style.into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_consistent_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &FirstArrayElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_array_element_indentation/offense.consistent.rb"
+            ),
+            style_config("consistent"),
+        );
+    }
+
+    #[test]
+    fn no_offense_consistent_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &FirstArrayElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_array_element_indentation/no_offense.consistent.rb"
+            ),
+            style_config("consistent"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_consistent_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &FirstArrayElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_array_element_indentation/offense.consistent.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_array_element_indentation/corrected.consistent.rb"
+            ),
+            style_config("consistent"),
+        );
+    }
+
+    #[test]
+    fn offense_align_brackets_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &FirstArrayElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_array_element_indentation/offense.align_brackets.rb"
+            ),
+            style_config("align_brackets"),
+        );
+    }
+
+    #[test]
+    fn no_offense_align_brackets_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &FirstArrayElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_array_element_indentation/no_offense.align_brackets.rb"
+            ),
+            style_config("align_brackets"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_align_brackets_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &FirstArrayElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_array_element_indentation/offense.align_brackets.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_array_element_indentation/corrected.align_brackets.rb"
+            ),
+            style_config("align_brackets"),
+        );
+    }
 }
@@ -1,18 +1,10 @@
 use ruby_prism::Visit;
 
+use crate::cop::shared::multiline_element_indentation::{IndentBase, line_indent, reindent_line};
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
-/// Count leading whitespace characters (spaces and tabs) as columns.
-/// Unlike `indentation_of()` which only counts spaces, this counts both spaces
-/// and tabs as 1 column each, matching `offset_to_line_col()`'s character counting.
-fn leading_whitespace_columns(line: &[u8]) -> usize {
-    line.iter()
-        .take_while(|&&b| b == b' ' || b == b'\t')
-        .count()
-}
-
 /// ## Corpus investigation (2026-03-08)
 ///
 /// Corpus oracle reported high FN volume concentrated in closing-brace sites.
@@ -36,7 +28,7 @@ fn leading_whitespace_columns(line: &[u8]) -> usize {
 ///
 /// 1. Tab-indented files (WhatWeb, phlex, crowdint, iobridge, puppetlabs): `indentation_of()`
 ///    only counts spaces, returning 0 for tab-indented lines, while `offset_to_line_col()` counts
-///    tabs as column positions. Fix: use `leading_whitespace_columns()` that counts both tabs and
+///    tabs as column positions. Fix: use `line_indent()` that counts both tabs and
 ///    spaces as single columns, consistent with `offset_to_line_col()`.
 ///
 /// 2. Splat FP (Shopify/shipit-engine): hashes whose only elements are `**var` (AssocSplatNode)
@@ -67,6 +59,10 @@ fn leading_whitespace_columns(line: &[u8]) -> usize {
 /// block body for nested call arguments, while still skipping nested call
 /// receivers and argument lists so outer-parenthesis indentation does not leak
 /// into unrelated inner sends.
+///
+/// **Autocorrect / config:** the first-element line and the right brace are
+/// re-indented with the shared `reindent_line` helper. `IndentationWidth`
+/// falls back to `Layout/IndentationWidth.Width`.
 pub struct FirstHashElementIndentation;
 
 impl Cop for FirstHashElementIndentation {
@@ -81,7 +77,7 @@ impl Cop for FirstHashElementIndentation {
         _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let style = config.get_str("EnforcedStyle", "special_inside_parentheses");
         let width = config.get_usize("IndentationWidth", 2);
@@ -91,11 +87,19 @@ impl Cop for FirstHashElementIndentation {
             style,
             width,
             diagnostics: Vec::new(),
+            corrections: corrections.is_some().then(Vec::new),
             handled_hashes: Vec::new(),
             parent_pair_col: None,
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let (Some(corrections), Some(found)) = (corrections, visitor.corrections) {
+            corrections.extend(found);
+        }
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
     }
 }
 
@@ -105,6 +109,8 @@ struct HashIndentVisitor<'a> {
     style: &'a str,
     width: usize,
     diagnostics: Vec<Diagnostic>,
+    /// `Some` when autocorrecting; collects first-element and right-brace re-indents.
+    corrections: Option<Vec<crate::correction::Correction>>,
     /// Start offsets of hash nodes already checked via a parent call with parentheses.
     handled_hashes: Vec<usize>,
     /// When visiting a hash that is a value in a pair (AssocNode), this stores
@@ -112,14 +118,6 @@ struct HashIndentVisitor<'a> {
     parent_pair_col: Option<usize>,
 }
 
-#[derive(Clone, Copy)]
-enum IndentBaseKind {
-    StartOfLine,
-    LeftBrace,
-    FirstPositionAfterLeftParenthesis,
-    ParentHashKey,
-}
-
 impl HashIndentVisitor<'_> {
     fn find_hash_args_in_body(
         &mut self,
@@ -199,39 +197,49 @@ impl HashIndentVisitor<'_> {
         &self,
         opening_loc: ruby_prism::Location<'_>,
         left_paren_col: Option<usize>,
-    ) -> (usize, IndentBaseKind) {
+    ) -> (usize, IndentBase) {
         let (open_line, open_col) = self.source.offset_to_line_col(opening_loc.start_offset());
         let open_line_bytes = self.source.lines().nth(open_line - 1).unwrap_or(b"");
-        let open_line_indent = leading_whitespace_columns(open_line_bytes);
+        let open_line_indent = line_indent(open_line_bytes);
 
         match self.style {
-            "consistent" => (open_line_indent, IndentBaseKind::StartOfLine),
-            "align_braces" => (open_col, IndentBaseKind::LeftBrace),
+            "consistent" => (open_line_indent, IndentBase::StartOfLine),
+            "align_braces" => (open_col, IndentBase::LeftBraceOrBracket),
             _ => {
                 if let Some(pair_col) = self.parent_pair_col {
-                    (pair_col, IndentBaseKind::ParentHashKey)
+                    (pair_col, IndentBase::ParentHashKey)
                 } else if let Some(paren_col) = left_paren_col {
-                    (
-                        paren_col + 1,
-                        IndentBaseKind::FirstPositionAfterLeftParenthesis,
-                    )
+                    (paren_col + 1, IndentBase::FirstColumnAfterLeftParenthesis)
                 } else {
-                    (open_line_indent, IndentBaseKind::StartOfLine)
+                    (open_line_indent, IndentBase::StartOfLine)
                 }
             }
         }
     }
 
-    fn right_brace_message(&self, base_kind: IndentBaseKind) -> &'static str {
+    fn push_with_reindent(&mut self, mut diagnostic: Diagnostic, offset: usize, expected: usize) {
+        if let Some(corrections) = self.corrections.as_mut() {
+            if let Some(correction) = reindent_line(
+                self.source,
+                offset,
+                expected,
+                "Layout/FirstHashElementIndentation",
+            ) {
+                corrections.push(correction);
+                diagnostic.corrected = true;
+            }
+        }
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn right_brace_message(&self, base_kind: IndentBase) -> &'static str {
         match base_kind {
-            IndentBaseKind::LeftBrace => "Indent the right brace the same as the left brace.",
-            IndentBaseKind::FirstPositionAfterLeftParenthesis => {
+            IndentBase::LeftBraceOrBracket => "Indent the right brace the same as the left brace.",
+            IndentBase::FirstColumnAfterLeftParenthesis => {
                 "Indent the right brace the same as the first position after the preceding left parenthesis."
             }
-            IndentBaseKind::ParentHashKey => {
-                "Indent the right brace the same as the parent hash key."
-            }
-            IndentBaseKind::StartOfLine => {
+            IndentBase::ParentHashKey => "Indent the right brace the same as the parent hash key.",
+            IndentBase::StartOfLine => {
                 "Indent the right brace the same as the start of the line where the left brace is."
             }
         }
@@ -263,12 +271,13 @@ impl HashIndentVisitor<'_> {
 
         let (expected_col, base_kind) = self.indent_base(hash_node.opening_loc(), left_paren_col);
         if brace_col != expected_col {
-            self.diagnostics.push(self.cop.diagnostic(
+            let diagnostic = self.cop.diagnostic(
                 self.source,
                 brace_line,
                 brace_col,
                 self.right_brace_message(base_kind).to_string(),
-            ));
+            );
+            self.push_with_reindent(diagnostic, brace_start, expected_col);
         }
     }
 
@@ -297,7 +306,7 @@ impl HashIndentVisitor<'_> {
             let expected = base_indent + self.width;
 
             if elem_col != expected {
-                self.diagnostics.push(self.cop.diagnostic(
+                let diagnostic = self.cop.diagnostic(
                     self.source,
                     elem_line,
                     elem_col,
@@ -306,7 +315,8 @@ impl HashIndentVisitor<'_> {
                         self.width,
                         elem_col.saturating_sub(base_indent)
                     ),
-                ));
+                );
+                self.push_with_reindent(diagnostic, first_loc.start_offset(), expected);
             }
         }
 
@@ -546,6 +556,10 @@ mod tests {
         FirstHashElementIndentation,
         "cops/layout/first_hash_element_indentation"
     );
+    crate::cop_autocorrect_fixture_tests!(
+        FirstHashElementIndentation,
+        "cops/layout/first_hash_element_indentation"
+    );
 
     #[test]
     fn same_line_elements_ignored() {
@@ -685,12 +699,12 @@ mod tests {
         );
     }
 
-    fn consistent_config() -> CopConfig {
+    fn style_config(style: &str) -> CopConfig {
         use std::collections::HashMap;
         CopConfig {
             options: HashMap::from([(
                 "EnforcedStyle".into(),
-                serde_yml::Value::String("consistent".into()),
+                serde_yml::Value::String(style.into()),
             )]),
             ..CopConfig::default()
         }
@@ -704,7 +718,7 @@ mod tests {
             include_bytes!(
                 "../../../tests/fixtures/cops/layout/first_hash_element_indentation/offense.consistent.rb"
             ),
-            consistent_config(),
+            style_config("consistent"),
         );
     }
 
@@ -716,7 +730,61 @@ mod tests {
             include_bytes!(
                 "../../../tests/fixtures/cops/layout/first_hash_element_indentation/no_offense.consistent.rb"
             ),
-            consistent_config(),
+            style_config("consistent"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_consistent_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &FirstHashElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_hash_element_indentation/offense.consistent.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_hash_element_indentation/corrected.consistent.rb"
+            ),
+            style_config("consistent"),
+        );
+    }
+
+    #[test]
+    fn offense_align_braces_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &FirstHashElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_hash_element_indentation/offense.align_braces.rb"
+            ),
+            style_config("align_braces"),
+        );
+    }
+
+    #[test]
+    fn no_offense_align_braces_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &FirstHashElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_hash_element_indentation/no_offense.align_braces.rb"
+            ),
+            style_config("align_braces"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_align_braces_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &FirstHashElementIndentation,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_hash_element_indentation/offense.align_braces.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/first_hash_element_indentation/corrected.align_braces.rb"
+            ),
+            style_config("align_braces"),
         );
     }
 }
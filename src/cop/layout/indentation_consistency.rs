@@ -658,6 +658,38 @@ mod tests {
         "cops/layout/indentation_consistency"
     );
 
+    fn indented_internal_methods_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String("indented_internal_methods".into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_indented_internal_methods() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &IndentationConsistency,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/indentation_consistency/offense.indented_internal_methods.rb"
+            ),
+            indented_internal_methods_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_indented_internal_methods() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &IndentationConsistency,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/indentation_consistency/no_offense.indented_internal_methods.rb"
+            ),
+            indented_internal_methods_config(),
+        );
+    }
+
     #[test]
     fn single_statement_body() {
         let source = b"def foo\n  x = 1\nend\n";
@@ -61,6 +61,14 @@ use crate::parse::source::SourceFile;
 ///   walk. RuboCop's `check_members_for_normal_style` skips ALL access modifier
 ///   calls (via `member.access_modifier?`), not just bare ones. Changed to use
 ///   `is_any_access_modifier_call` which matches all forms. Resolved ~83 FP.
+///
+/// 2026-10-17:
+/// - Under `indented_internal_methods`, only bare `private` and `protected` open
+///   an extra-indented section, as in RuboCop's `special_modifier?`. A `public`
+///   between sections no longer makes the next method's indentation checked
+///   relative to it.
+/// - `AllowedPatterns` is also tested against the access modifier line that a
+///   member is indented from, matching RuboCop's `allowed_line?(base_loc)`.
 pub struct IndentationWidth;
 
 /// Check if a node is a bare access modifier call (for example `private` with no
@@ -70,6 +78,16 @@ fn is_access_modifier_call(node: &ruby_prism::Node<'_>) -> bool {
         .is_some_and(|call| access_modifier_predicates::is_bare_access_modifier(&call))
 }
 
+/// Check if a node is a bare `private` or `protected`, the modifiers that open
+/// an extra-indented section under `indented_internal_methods`. Matches RuboCop's
+/// `special_modifier?`; a bare `public` or `module_function` does not.
+fn is_section_modifier_call(node: &ruby_prism::Node<'_>) -> bool {
+    node.as_call_node().is_some_and(|call| {
+        access_modifier_predicates::is_bare_access_modifier(&call)
+            && matches!(call.name().as_slice(), b"private" | b"protected")
+    })
+}
+
 /// Check if a node is ANY access modifier call (with or without arguments).
 /// Matches RuboCop's `access_modifier?` which includes `private`, `private :method`,
 /// and `private def foo`. Used in the normal-style member walk to skip access modifier
@@ -116,7 +134,7 @@ fn body_contains_access_modifier(body: Option<ruby_prism::Node<'_>>) -> bool {
     body.map(body_members)
         .unwrap_or_default()
         .iter()
-        .any(is_access_modifier_call)
+        .any(is_section_modifier_call)
 }
 
 /// Check if a StatementsNode's first child is a bare access modifier.
@@ -178,9 +196,26 @@ struct MemberStyles<'a> {
 }
 
 #[derive(Clone, Copy)]
-struct IndentationOptions {
+struct IndentationOptions<'a> {
     width: usize,
     skip_tabs: bool,
+    allowed_patterns: &'a [regex::Regex],
+}
+
+impl IndentationOptions<'_> {
+    /// Whether the 1-based `line` matches one of the `AllowedPatterns`.
+    /// Mirrors RuboCop's `allowed_line?`, which tests the line of the base
+    /// the body is indented from.
+    fn allowed_line(&self, source: &SourceFile, line: usize) -> bool {
+        if self.allowed_patterns.is_empty() {
+            return false;
+        }
+        source
+            .lines()
+            .nth(line - 1)
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .is_some_and(|text| self.allowed_patterns.iter().any(|re| re.is_match(text)))
+    }
 }
 
 impl IndentationWidth {
@@ -210,10 +245,13 @@ impl IndentationWidth {
         base_offset: usize,
         base_col: usize,
         member: &ruby_prism::Node<'_>,
-        options: IndentationOptions,
+        options: IndentationOptions<'_>,
         style_name: Option<&str>,
     ) -> Option<Diagnostic> {
         let (base_line, _) = source.offset_to_line_col(base_offset);
+        if options.allowed_line(source, base_line) {
+            return None;
+        }
         let loc = member.location();
         let (member_line, member_col) = source.offset_to_line_col(loc.start_offset());
 
@@ -252,7 +290,7 @@ impl IndentationWidth {
         base_offset: usize,
         base_col: usize,
         body: Option<ruby_prism::Node<'_>>,
-        options: IndentationOptions,
+        options: IndentationOptions<'_>,
         styles: MemberStyles<'_>,
     ) -> Vec<Diagnostic> {
         let body = match body {
@@ -296,7 +334,7 @@ impl IndentationWidth {
 
             let mut previous_modifier: Option<&ruby_prism::Node<'_>> = None;
             for member in &members {
-                if is_access_modifier_call(member) {
+                if is_section_modifier_call(member) {
                     previous_modifier = Some(member);
                     continue;
                 }
@@ -353,7 +391,7 @@ impl IndentationWidth {
         end_offset: usize,
         end_col: usize,
         body: Option<ruby_prism::Node<'_>>,
-        options: IndentationOptions,
+        options: IndentationOptions<'_>,
         access_modifier_style: &str,
     ) -> Vec<Diagnostic> {
         let body = match body {
@@ -382,7 +420,7 @@ impl IndentationWidth {
 
         let mut previous_modifier: Option<&ruby_prism::Node<'_>> = None;
         for member in &members {
-            if is_access_modifier_call(member) {
+            if is_section_modifier_call(member) {
                 previous_modifier = Some(member);
                 continue;
             }
@@ -415,7 +453,7 @@ impl IndentationWidth {
         keyword_offset: usize,
         base_col: usize,
         body: Option<ruby_prism::Node<'_>>,
-        options: IndentationOptions,
+        options: IndentationOptions<'_>,
     ) -> Vec<Diagnostic> {
         let body = match body {
             Some(b) => b,
@@ -485,7 +523,7 @@ impl IndentationWidth {
         base_col: usize,
         alt_base_col: Option<usize>,
         stmts: Option<ruby_prism::StatementsNode<'_>>,
-        options: IndentationOptions,
+        options: IndentationOptions<'_>,
     ) -> Vec<Diagnostic> {
         let stmts = match stmts {
             Some(s) => s,
@@ -553,7 +591,7 @@ impl IndentationWidth {
         &self,
         source: &SourceFile,
         begin_node: &ruby_prism::BeginNode<'_>,
-        options: IndentationOptions,
+        options: IndentationOptions<'_>,
         diagnostics: &mut Vec<Diagnostic>,
     ) {
         // Check rescue clause(s)
@@ -608,7 +646,7 @@ impl IndentationWidth {
         &self,
         source: &SourceFile,
         else_node: &ruby_prism::ElseNode<'_>,
-        options: IndentationOptions,
+        options: IndentationOptions<'_>,
         diagnostics: &mut Vec<Diagnostic>,
     ) {
         let kw_offset = else_node.else_keyword_loc().start_offset();
@@ -664,28 +702,22 @@ impl Cop for IndentationWidth {
         let consistency_style = config.get_str("IndentationConsistencyStyle", "normal");
         let access_modifier_style = config.get_str("AccessModifierIndentationStyle", "indent");
         let indentation_style = config.get_str("IndentationStyleEnforced", "spaces");
+        let allowed_patterns: Vec<regex::Regex> = config
+            .get_string_array("AllowedPatterns")
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|pattern| regex::Regex::new(pattern).ok())
+            .collect();
         let options = IndentationOptions {
             width,
             skip_tabs: indentation_style == "tabs",
+            allowed_patterns: &allowed_patterns,
         };
-        let allowed_patterns = config
-            .get_string_array("AllowedPatterns")
-            .unwrap_or_default();
 
         // Skip if the node's source line matches any allowed pattern
-        if !allowed_patterns.is_empty() {
-            let (node_line, _) = source.offset_to_line_col(node.location().start_offset());
-            if let Some(line_bytes) = source.lines().nth(node_line - 1) {
-                if let Ok(line_str) = std::str::from_utf8(line_bytes) {
-                    for pattern in &allowed_patterns {
-                        if let Ok(re) = regex::Regex::new(pattern) {
-                            if re.is_match(line_str) {
-                                return;
-                            }
-                        }
-                    }
-                }
-            }
+        let (node_line, _) = source.offset_to_line_col(node.location().start_offset());
+        if options.allowed_line(source, node_line) {
+            return;
         }
 
         // begin...end blocks (Prism's BeginNode for explicit `begin` keyword).
@@ -1084,6 +1116,80 @@ mod tests {
 
     crate::cop_fixture_tests!(IndentationWidth, "cops/layout/indentation_width");
 
+    fn indented_internal_methods_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "IndentationConsistencyStyle".into(),
+                serde_yml::Value::String("indented_internal_methods".into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    fn width_4_config() -> CopConfig {
+        let mut config = indented_internal_methods_config();
+        config
+            .options
+            .insert("Width".into(), serde_yml::Value::Number(4.into()));
+        config
+    }
+
+    #[test]
+    fn offense_indented_internal_methods() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &IndentationWidth,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/indentation_width/offense.indented_internal_methods.rb"
+            ),
+            indented_internal_methods_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_indented_internal_methods() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &IndentationWidth,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/indentation_width/no_offense.indented_internal_methods.rb"
+            ),
+            indented_internal_methods_config(),
+        );
+    }
+
+    #[test]
+    fn offense_width_4() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &IndentationWidth,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/indentation_width/offense.width_4.rb"
+            ),
+            width_4_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_width_4() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &IndentationWidth,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/indentation_width/no_offense.width_4.rb"
+            ),
+            width_4_config(),
+        );
+    }
+
+    #[test]
+    fn allowed_patterns_skip_members_of_matching_modifier_line() {
+        let mut config = indented_internal_methods_config();
+        config.options.insert(
+            "AllowedPatterns".into(),
+            serde_yml::Value::Sequence(vec![serde_yml::Value::String("# legacy".into())]),
+        );
+        let source = b"class Foo\n  private # legacy\n\n  def bar\n  end\nend\n";
+        let diags = run_cop_full_with_config(&IndentationWidth, source, config);
+        assert!(diags.is_empty(), "got: {:?}", diags);
+    }
+
     #[test]
     fn custom_width() {
         use std::collections::HashMap;
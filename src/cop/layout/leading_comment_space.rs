@@ -99,6 +99,12 @@ use crate::parse::source::SourceFile;
 /// `#!` on line 1, so it incorrectly flagged the continuation line. Fixed by
 /// tracking whether the immediately previous comment was an allowed shebang and
 /// only exempting directly consecutive `#!` lines in that narrow case.
+///
+/// ## Config options
+///
+/// `AllowDoxygenCommentStyle` (`#*`), `AllowGemfileRubyComment` (`#ruby` in a
+/// file named `Gemfile`), `AllowRBSInlineAnnotation` (`#:`, `#[...]`, `#|`)
+/// and `AllowSteepAnnotation` (`#$`, `#:`) exempt RuboCop's comment prefixes.
 pub struct LeadingCommentSpace;
 
 impl Cop for LeadingCommentSpace {
@@ -119,10 +125,11 @@ impl Cop for LeadingCommentSpace {
         diagnostics: &mut Vec<Diagnostic>,
         mut corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
-        let _allow_doxygen = config.get_bool("AllowDoxygenCommentStyle", false);
-        let _allow_gemfile_ruby = config.get_bool("AllowGemfileRubyComment", false);
-        let _allow_rbs_inline = config.get_bool("AllowRBSInlineAnnotation", false);
-        let _allow_steep = config.get_bool("AllowSteepAnnotation", false);
+        let allow_doxygen = config.get_bool("AllowDoxygenCommentStyle", false);
+        let allow_gemfile_ruby =
+            config.get_bool("AllowGemfileRubyComment", false) && is_gemfile(source);
+        let allow_rbs_inline = config.get_bool("AllowRBSInlineAnnotation", false);
+        let allow_steep = config.get_bool("AllowSteepAnnotation", false);
         let bytes = source.as_bytes();
         let mut previous_comment_line = None;
         let mut previous_comment_allowed_shebang = false;
@@ -173,6 +180,17 @@ impl Cop for LeadingCommentSpace {
                 previous_comment_allowed_shebang = false;
                 continue;
             }
+
+            let allowed = (allow_doxygen && text.starts_with(b"#*"))
+                || (allow_gemfile_ruby && text.starts_with(b"#ruby"))
+                || (allow_rbs_inline && is_rbs_inline_annotation(text))
+                || (allow_steep && (text.starts_with(b"#$") || text.starts_with(b"#:")));
+            if allowed {
+                previous_comment_line = Some(line);
+                previous_comment_allowed_shebang = false;
+                continue;
+            }
+
             let mut diag =
                 self.diagnostic(source, line, column, "Missing space after `#`.".to_string());
             if let Some(ref mut corr) = corrections {
@@ -197,6 +215,19 @@ fn is_config_ru(source: &SourceFile) -> bool {
     path.file_name().and_then(|n| n.to_str()) == Some("config.ru")
 }
 
+fn is_gemfile(source: &SourceFile) -> bool {
+    let path = std::path::Path::new(source.path_str());
+    path.file_name().and_then(|n| n.to_str()) == Some("Gemfile")
+}
+
+/// RuboCop's `/#:|#\[.+\]|#\|/` prefix: `#:` type annotations, `#[...]`
+/// generics and `#|` continuation lines.
+fn is_rbs_inline_annotation(text: &[u8]) -> bool {
+    text.starts_with(b"#:")
+        || text.starts_with(b"#|")
+        || (text.starts_with(b"#[") && text.len() > 3 && text[3..].contains(&b']'))
+}
+
 /// Returns true if a Prism error is one that RuboCop's parser gem considers
 /// valid syntax (valid_syntax? = true). These are "semantic" errors that don't
 /// affect the AST structure: `break`/`next`/`redo` outside loops and `yield`
@@ -350,4 +381,74 @@ mod tests {
         );
         assert_eq!(diags.len(), 1);
     }
+
+    #[test]
+    fn allow_doxygen_comment_style() {
+        let source = b"#**\n#* Doxygen block\n#*/\nx = 1\n";
+        assert_eq!(
+            crate::testutil::run_cop_full(&LeadingCommentSpace, source).len(),
+            3
+        );
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &LeadingCommentSpace,
+            "AllowDoxygenCommentStyle: true\n",
+            source,
+        );
+    }
+
+    #[test]
+    fn allow_gemfile_ruby_comment_only_in_gemfile() {
+        let source = b"source 'https://rubygems.org'\n#ruby=3.3.0\n#ruby-gemset=app\n";
+        let config = || crate::testutil::cop_config_from_yaml("AllowGemfileRubyComment: true\n");
+        let diags = crate::testutil::run_cop_full_internal(
+            &LeadingCommentSpace,
+            source,
+            config(),
+            "Gemfile",
+        );
+        assert!(diags.is_empty());
+        let diags = crate::testutil::run_cop_full_internal(
+            &LeadingCommentSpace,
+            source,
+            config(),
+            "app.rb",
+        );
+        assert_eq!(diags.len(), 2);
+    }
+
+    #[test]
+    fn allow_rbs_inline_and_steep_annotations() {
+        let rbs = b"def m(x) #: (Integer) -> void\nend\n#[Integer]\n#| String\n";
+        assert_eq!(
+            crate::testutil::run_cop_full(&LeadingCommentSpace, rbs).len(),
+            3
+        );
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &LeadingCommentSpace,
+            "AllowRBSInlineAnnotation: true\n",
+            rbs,
+        );
+
+        let steep = b"x = foo #$ String\ny = bar #: Integer\n";
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &LeadingCommentSpace,
+            "AllowSteepAnnotation: true\n",
+            steep,
+        );
+    }
+
+    #[test]
+    fn options_keep_shebang_rules() {
+        let config = crate::testutil::cop_config_from_yaml(
+            "AllowDoxygenCommentStyle: true\nAllowGemfileRubyComment: true\n",
+        );
+        let diags = crate::testutil::run_cop_full_internal(
+            &LeadingCommentSpace,
+            b"#!/usr/bin/env ruby\nx = 1\n#!not a shebang\n",
+            config,
+            "Gemfile",
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].location.line, 3);
+    }
 }
@@ -526,6 +526,7 @@ impl<'a, 'pr> RedundantLineBreakVisitor<'a, 'pr> {
             cop_name: self.cop_name.to_string(),
             message: "Redundant line break detected.".to_string(),
             corrected: false,
+            end_column: None,
         });
     }
 }
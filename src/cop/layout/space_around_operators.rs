@@ -1,5 +1,8 @@
 use std::collections::HashSet;
 
+use crate::cop::shared::preceding_following_alignment::{
+    aligned_with_adjacent_line, byte_to_char_col, char_col_to_byte,
+};
 use crate::cop::shared::util;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
@@ -111,6 +114,17 @@ use ruby_prism::Visit;
 ///    RuboCop returns false (no offense). Added
 ///    `has_subsequent_assignment_neighbor` to replicate this behavior for
 ///    plain `=` assignments in the text scanner.
+///
+/// ## Configuration
+///
+/// - `AllowForAlignment: false` used to switch off the text scanner's
+///   extra-space check entirely. Like RuboCop's `excess_leading_space?`, it
+///   now only skips extra space *before* an operator (left to
+///   Layout/ExtraSpacing); extra space after an operator is always flagged.
+///   The neighbor-line search is shared with Layout/ExtraSpacing
+///   (`shared::preceding_following_alignment`).
+/// - `EnforcedStyleForRationalLiterals` applies when the right operand of
+///   `/` is a rational literal node, so `1 / 2.5r` is covered too.
 pub struct SpaceAroundOperators;
 
 /// Collect byte offsets of `=` signs that are part of parameter defaults,
@@ -353,8 +367,7 @@ impl Cop for SpaceAroundOperators {
                             diag.corrected = true;
                         }
                         diagnostics.push(diag);
-                    } else if allow_for_alignment && space_before && (space_after || newline_after)
-                    {
+                    } else if space_before && (space_after || newline_after) {
                         // Check for extra spaces around operator (alignment check)
                         let multi_before = i >= 2 && bytes[i - 1] == b' ' && bytes[i - 2] == b' ';
                         let multi_after =
@@ -370,6 +383,7 @@ impl Cop for SpaceAroundOperators {
                                 multi_before,
                                 multi_after,
                                 false,
+                                allow_for_alignment,
                                 code_map,
                                 diagnostics,
                                 &mut corrections,
@@ -466,7 +480,7 @@ impl Cop for SpaceAroundOperators {
                         diag.corrected = true;
                     }
                     diagnostics.push(diag);
-                } else if allow_for_alignment && space_before && (space_after || newline_after) {
+                } else if space_before && (space_after || newline_after) {
                     // Check for extra spaces around `=` (alignment check)
                     let multi_before = i >= 2 && bytes[i - 1] == b' ' && bytes[i - 2] == b' ';
                     let multi_after = i + 2 < len && bytes[i + 1] == b' ' && bytes[i + 2] == b' ';
@@ -481,6 +495,7 @@ impl Cop for SpaceAroundOperators {
                             multi_before,
                             multi_after,
                             plain_assignment_offsets.contains(&i),
+                            allow_for_alignment,
                             code_map,
                             diagnostics,
                             &mut corrections,
@@ -508,12 +523,15 @@ fn check_text_scanner_extra_space(
     multi_before: bool,
     multi_after: bool,
     is_plain_assignment: bool,
+    allow_for_alignment: bool,
     code_map: &CodeMap,
     diagnostics: &mut Vec<Diagnostic>,
     corrections: &mut Option<&mut Vec<crate::correction::Correction>>,
 ) {
     let bytes = source.as_bytes();
-    let mut multi_before = multi_before;
+    // RuboCop only checks leading space when alignment is allowed; without
+    // it, extra space before an operator is left to Layout/ExtraSpacing.
+    let mut multi_before = multi_before && allow_for_alignment;
     let mut multi_after = multi_after;
 
     // Skip if operator is at start of line (spaces are indentation)
@@ -549,7 +567,7 @@ fn check_text_scanner_extra_space(
         }
         if p < bytes.len() && bytes[p] == b'#' {
             multi_after = false;
-        } else if is_plain_assignment || op_bytes != b"=" {
+        } else if allow_for_alignment && (is_plain_assignment || op_bytes != b"=") {
             if let Some(rhs_start) = util::first_non_space_on_line(bytes, op_end) {
                 if is_aligned_rhs_standalone(source, rhs_start) {
                     multi_after = false;
@@ -611,170 +629,37 @@ fn check_text_scanner_extra_space(
     diagnostics.push(diag);
 }
 
-/// Count UTF-8 codepoints from the start of `line` up to `byte_col` bytes.
-/// For ASCII-only lines this equals `byte_col`; for lines with multi-byte chars
-/// (e.g. curly quotes) it returns the visual character column.
-fn bytes_to_char_col(line: &[u8], byte_col: usize) -> usize {
-    let capped = byte_col.min(line.len());
-    let mut chars = 0usize;
-    let mut i = 0usize;
-    while i < capped {
-        let b = line[i];
-        let width = if b < 0x80 {
-            1
-        } else if b & 0xE0 == 0xC0 {
-            2
-        } else if b & 0xF0 == 0xE0 {
-            3
-        } else {
-            4
-        };
-        i += width;
-        chars += 1;
-    }
-    chars
-}
-
-/// Return the byte offset within `line` that starts character column `char_col`.
-/// Returns `None` if the line is shorter than `char_col` characters.
-fn char_col_to_bytes(line: &[u8], char_col: usize) -> Option<usize> {
-    let mut chars = 0usize;
-    let mut i = 0usize;
-    while i < line.len() {
-        if chars == char_col {
-            return Some(i);
-        }
-        let b = line[i];
-        let width = if b < 0x80 {
-            1
-        } else if b & 0xE0 == 0xC0 {
-            2
-        } else if b & 0xF0 == 0xE0 {
-            3
-        } else {
-            4
-        };
-        i += width;
-        chars += 1;
-    }
-    if chars == char_col { Some(i) } else { None }
-}
-
 /// Check if the operator at byte offset `start` is aligned with an operator
-/// on an adjacent non-blank, non-comment line. Supports:
+/// on an adjacent non-blank, non-comment line (see
+/// `shared::preceding_following_alignment` for which lines are compared).
+/// Supports:
 ///
 /// 1. Same operator at same char column
-/// 2. Word/space boundary at same column (aligned_words in RuboCop)
-/// 3. Cross-operator alignment (operators ending at same column)
+/// 2. Cross-operator alignment (operators ending at same column)
 fn is_aligned_standalone(source: &SourceFile, start: usize, op_bytes: &[u8]) -> bool {
-    let bytes = source.as_bytes();
-    let mut ls = start;
-    while ls > 0 && bytes[ls - 1] != b'\n' {
-        ls -= 1;
-    }
-    let byte_col = start - ls;
     let lines: Vec<&[u8]> = source.lines().collect();
     let (line, _) = source.offset_to_line_col(start);
     let line_idx = line - 1;
     // Use character column so that multi-byte UTF-8 chars (e.g. curly quotes)
     // before the operator don't break alignment detection on adjacent ASCII lines.
-    let char_col = bytes_to_char_col(lines[line_idx], byte_col);
+    let char_col = byte_to_char_col(lines[line_idx], start - source.line_start_offset(line));
     // All alignment operators are ASCII, so char length == byte length.
     let char_end_col = char_col + op_bytes.len();
-    // Pass 1: closest non-blank, non-comment line (no indentation filter)
-    if check_alignment_standalone(&lines, line_idx, char_col, char_end_col, op_bytes, None) {
-        return true;
-    }
-    // Pass 2: search for same-indentation lines further out
-    let my_indent = lines[line_idx]
-        .iter()
-        .position(|&b| b != b' ' && b != b'\t')
-        .unwrap_or(0);
-    check_alignment_standalone(
-        &lines,
-        line_idx,
-        char_col,
-        char_end_col,
-        op_bytes,
-        Some(my_indent),
-    )
-}
-
-fn check_alignment_standalone(
-    lines: &[&[u8]],
-    line_idx: usize,
-    char_col: usize,
-    char_end_col: usize,
-    op_bytes: &[u8],
-    indent_filter: Option<usize>,
-) -> bool {
-    for up in [true, false] {
-        let mut check_idx = if up {
-            if line_idx == 0 {
-                continue;
-            }
-            line_idx - 1
-        } else {
-            line_idx + 1
-        };
-        loop {
-            if check_idx >= lines.len() {
-                break;
-            }
-            let line_bytes = lines[check_idx];
-            let first_non_ws = line_bytes.iter().position(|&b| b != b' ' && b != b'\t');
-            match first_non_ws {
-                None => {}                               // Empty line — skip
-                Some(fs) if line_bytes[fs] == b'#' => {} // Comment line — skip
-                Some(indent) => {
-                    if let Some(required) = indent_filter {
-                        if indent != required {
-                            if up {
-                                if check_idx == 0 {
-                                    break;
-                                }
-                                check_idx -= 1;
-                            } else {
-                                check_idx += 1;
-                            }
-                            continue;
-                        }
-                    }
-                    // Convert char_col back to byte offset for this specific line.
-                    // This handles lines where multi-byte chars (e.g. curly-quote string
-                    // keys) appear before the operator, shifting the byte offset.
-                    if let Some(byte_col) = char_col_to_bytes(line_bytes, char_col) {
-                        // Check 1: same operator at same char column
-                        if byte_col + op_bytes.len() <= line_bytes.len()
-                            && &line_bytes[byte_col..byte_col + op_bytes.len()] == op_bytes
-                        {
-                            return true;
-                        }
-                    }
-                    // Check 3: cross-operator alignment (operators ending at same char column)
-                    if line_has_operator_ending_at_char_col(line_bytes, char_end_col) {
-                        return true;
-                    }
-                    break;
-                }
-            }
-            if up {
-                if check_idx == 0 {
-                    break;
-                }
-                check_idx -= 1;
-            } else {
-                check_idx += 1;
-            }
-        }
-    }
-    false
+    aligned_with_adjacent_line(&lines, line_idx, |adj| {
+        let line_bytes = lines[adj];
+        // Convert char_col back to byte offset for this specific line.
+        // This handles lines where multi-byte chars (e.g. curly-quote string
+        // keys) appear before the operator, shifting the byte offset.
+        char_col_to_byte(line_bytes, char_col)
+            .is_some_and(|byte_col| line_bytes[byte_col..].starts_with(op_bytes))
+            || line_has_operator_ending_at_char_col(line_bytes, char_end_col)
+    })
 }
 
 /// Check if a line has an assignment/comparison operator ending at the given
 /// *character* column (codepoint index, not byte index).
 fn line_has_operator_ending_at_char_col(line: &[u8], target_char_end_col: usize) -> bool {
-    let Some(target_end_col) = char_col_to_bytes(line, target_char_end_col) else {
+    let Some(target_end_col) = char_col_to_byte(line, target_char_end_col) else {
         return false;
     };
     line_has_operator_ending_at_col(line, target_end_col)
@@ -950,93 +835,17 @@ fn line_has_equals_sign_in_code(line: &[u8], line_abs_start: usize, code_map: &C
 }
 
 fn is_aligned_rhs_standalone(source: &SourceFile, start: usize) -> bool {
-    let bytes = source.as_bytes();
-    let mut ls = start;
-    while ls > 0 && bytes[ls - 1] != b'\n' {
-        ls -= 1;
-    }
-
-    let byte_col = start - ls;
     let lines: Vec<&[u8]> = source.lines().collect();
     let (line, _) = source.offset_to_line_col(start);
     let line_idx = line - 1;
-    let char_col = bytes_to_char_col(lines[line_idx], byte_col);
-
-    if check_rhs_alignment_standalone(&lines, line_idx, char_col, None) {
-        return true;
-    }
-
-    let my_indent = lines[line_idx]
-        .iter()
-        .position(|&b| b != b' ' && b != b'\t')
-        .unwrap_or(0);
-    check_rhs_alignment_standalone(&lines, line_idx, char_col, Some(my_indent))
-}
-
-fn check_rhs_alignment_standalone(
-    lines: &[&[u8]],
-    line_idx: usize,
-    char_col: usize,
-    indent_filter: Option<usize>,
-) -> bool {
-    for up in [true, false] {
-        let mut check_idx = if up {
-            if line_idx == 0 {
-                continue;
-            }
-            line_idx - 1
-        } else {
-            line_idx + 1
-        };
-
-        loop {
-            if check_idx >= lines.len() {
-                break;
-            }
-
-            let line_bytes = lines[check_idx];
-            let first_non_ws = line_bytes.iter().position(|&b| b != b' ' && b != b'\t');
-            match first_non_ws {
-                None => {}
-                Some(fs) if line_bytes[fs] == b'#' => {}
-                Some(indent) => {
-                    if let Some(required) = indent_filter {
-                        if indent != required {
-                            if up {
-                                if check_idx == 0 {
-                                    break;
-                                }
-                                check_idx -= 1;
-                            } else {
-                                check_idx += 1;
-                            }
-                            continue;
-                        }
-                    }
-
-                    if line_has_aligned_rhs_at_char_col(line_bytes, char_col) {
-                        return true;
-                    }
-                    break;
-                }
-            }
-
-            if up {
-                if check_idx == 0 {
-                    break;
-                }
-                check_idx -= 1;
-            } else {
-                check_idx += 1;
-            }
-        }
-    }
-
-    false
+    let char_col = byte_to_char_col(lines[line_idx], start - source.line_start_offset(line));
+    aligned_with_adjacent_line(&lines, line_idx, |adj| {
+        line_has_aligned_rhs_at_char_col(lines[adj], char_col)
+    })
 }
 
 fn line_has_aligned_rhs_at_char_col(line: &[u8], target_char_col: usize) -> bool {
-    let Some(byte_col) = char_col_to_bytes(line, target_char_col) else {
+    let Some(byte_col) = char_col_to_byte(line, target_char_col) else {
         return false;
     };
 
@@ -1047,6 +856,14 @@ fn line_has_aligned_rhs_at_char_col(line: &[u8], target_char_col: usize) -> bool
         && line[byte_col] != b'\t'
 }
 
+/// `a / 3r`: a division whose right operand is a rational literal, which
+/// `EnforcedStyleForRationalLiterals` governs instead of the usual rule.
+fn is_rational_division(node: &ruby_prism::CallNode<'_>) -> bool {
+    node.arguments()
+        .and_then(|args| args.arguments().iter().next())
+        .is_some_and(|arg| arg.as_rational_node().is_some())
+}
+
 const BINARY_OPERATORS: &[&[u8]] = &[
     b"+", b"-", b"*", b"/", b"%", b"**", b"&", b"|", b"^", b"<<", b">>", b"<", b">", b"<=", b">=",
     b"<=>",
@@ -1092,21 +909,6 @@ impl OperatorChecker<'_> {
         let bytes = self.source.as_bytes();
         let op_str = std::str::from_utf8(op_loc.as_slice()).unwrap_or("??");
 
-        // Skip ** when exponent style is no_space — no-space offenses are handled by
-        // check_no_space_operator instead.
-        if op_str == "**" && self.exponent_no_space {
-            return;
-        }
-
-        // Skip / for rational literals when rational style is no_space
-        // (rational no-space offenses handled separately)
-        if op_str == "/" && self.rational_no_space {
-            // Check if the right operand is a rational literal (ends with 'r')
-            if self.is_rational_division(end) {
-                return;
-            }
-        }
-
         let has_space_before = start > 0 && (bytes[start - 1] == b' ' || bytes[start - 1] == b'\t');
         let has_space_after = end < bytes.len() && (bytes[end] == b' ' || bytes[end] == b'\t');
         let newline_after = end >= bytes.len() || bytes[end] == b'\n' || bytes[end] == b'\r';
@@ -1190,9 +992,10 @@ impl OperatorChecker<'_> {
             }
         }
 
-        if self.allow_for_alignment
-            && multi_space_before
-            && self.is_aligned_with_adjacent(start, op_bytes)
+        // RuboCop only checks leading space when alignment is allowed; without
+        // it, extra space before an operator is left to Layout/ExtraSpacing.
+        if multi_space_before
+            && (!self.allow_for_alignment || self.is_aligned_with_adjacent(start, op_bytes))
         {
             multi_space_before = false;
         }
@@ -1327,26 +1130,6 @@ impl OperatorChecker<'_> {
             self.diagnostics.push(diag);
         }
     }
-
-    /// Check if the bytes after a `/` operator indicate a rational literal
-    /// (a number immediately followed by 'r').
-    fn is_rational_division(&self, slash_end: usize) -> bool {
-        let bytes = self.source.as_bytes();
-        let mut i = slash_end;
-        // Skip spaces after /
-        while i < bytes.len() && bytes[i] == b' ' {
-            i += 1;
-        }
-        // Look for digits followed by 'r'
-        let digit_start = i;
-        while i < bytes.len() && bytes[i].is_ascii_digit() {
-            i += 1;
-        }
-        if i > digit_start && i < bytes.len() && bytes[i] == b'r' {
-            return true;
-        }
-        false
-    }
 }
 
 impl<'pr> Visit<'pr> for OperatorChecker<'_> {
@@ -1366,9 +1149,7 @@ impl<'pr> Visit<'pr> for OperatorChecker<'_> {
                 // Handle ** no_space and / rational no_space:
                 // these operators should NOT have space around them
                 let should_have_no_space = (op_bytes == b"**" && self.exponent_no_space)
-                    || (op_bytes == b"/"
-                        && self.rational_no_space
-                        && self.is_rational_division(msg_loc.end_offset()));
+                    || (op_bytes == b"/" && self.rational_no_space && is_rational_division(node));
                 if should_have_no_space {
                     self.check_no_space_operator(&msg_loc);
                 } else {
@@ -1640,4 +1421,110 @@ mod tests {
         SpaceAroundOperators,
         "cops/layout/space_around_operators"
     );
+
+    fn config(key: &str, value: serde_yml::Value) -> crate::cop::CopConfig {
+        let mut options = std::collections::HashMap::new();
+        options.insert(key.to_string(), value);
+        crate::cop::CopConfig {
+            options,
+            ..crate::cop::CopConfig::default()
+        }
+    }
+
+    fn no_alignment_config() -> crate::cop::CopConfig {
+        config("AllowForAlignment", serde_yml::Value::Bool(false))
+    }
+
+    fn style_config(key: &str, style: &str) -> crate::cop::CopConfig {
+        config(key, serde_yml::Value::String(style.to_string()))
+    }
+
+    #[test]
+    fn offense_no_alignment() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &SpaceAroundOperators,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_around_operators/offense.no_alignment.rb"
+            ),
+            no_alignment_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_no_alignment() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &SpaceAroundOperators,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_around_operators/no_offense.no_alignment.rb"
+            ),
+            no_alignment_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_no_alignment() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SpaceAroundOperators,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_around_operators/offense.no_alignment.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_around_operators/corrected.no_alignment.rb"
+            ),
+            no_alignment_config(),
+        );
+    }
+
+    #[test]
+    fn exponent_operator_space_style() {
+        let config = style_config("EnforcedStyleForExponentOperator", "space");
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &SpaceAroundOperators,
+            b"x = a**b\n     ^^ Layout/SpaceAroundOperators: Surrounding space missing for operator `**`.\ny = a ** b\n",
+            config.clone(),
+        );
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SpaceAroundOperators,
+            b"x = a**b\n",
+            b"x = a ** b\n",
+            config,
+        );
+    }
+
+    #[test]
+    fn exponent_operator_no_space_style() {
+        crate::testutil::assert_cop_offenses_full(
+            &SpaceAroundOperators,
+            b"x = a ** b\n      ^^ Layout/SpaceAroundOperators: Space around operator `**` detected.\ny = a**b\n",
+        );
+        crate::testutil::assert_cop_autocorrect(
+            &SpaceAroundOperators,
+            b"x = a ** b\n",
+            b"x = a**b\n",
+        );
+    }
+
+    #[test]
+    fn rational_literal_no_space_style() {
+        crate::testutil::assert_cop_offenses_full(
+            &SpaceAroundOperators,
+            b"x = 1 / 3r\n      ^ Layout/SpaceAroundOperators: Space around operator `/` detected.\ny = 1 / 2.5r\n      ^ Layout/SpaceAroundOperators: Space around operator `/` detected.\nz = 1/3r\nw = 1 / 3\n",
+        );
+    }
+
+    #[test]
+    fn rational_literal_space_style() {
+        let config = style_config("EnforcedStyleForRationalLiterals", "space");
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &SpaceAroundOperators,
+            b"x = 1/3r\n     ^ Layout/SpaceAroundOperators: Surrounding space missing for operator `/`.\ny = 1 / 3r\n",
+            config.clone(),
+        );
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SpaceAroundOperators,
+            b"x = 1/3r\n",
+            b"x = 1 / 3r\n",
+            config,
+        );
+    }
 }
@@ -14,6 +14,11 @@ use crate::parse::source::SourceFile;
 ///   `LambdaNode`. In Prism, `-> { }` parses as a `LambdaNode`, not a `BlockNode`.
 ///   RuboCop's `on_block` also handles lambdas via `on_numblock`/`on_itblock` aliases,
 ///   since in Parser AST lambdas are block nodes. Fixed by also handling `LambdaNode`.
+/// - **`no_space` options:** the whole run of spaces and tabs before `{` is
+///   reported and removed, not just one space; `EnforcedStyleForEmptyBraces`
+///   falls back to `EnforcedStyle` when unset; and multiline blocks are skipped
+///   while Style/BlockDelimiters is `line_count_based` (injected by the config
+///   as `BlockDelimitersStyle`), as RuboCop does.
 pub struct SpaceBeforeBlockBraces;
 
 impl Cop for SpaceBeforeBlockBraces {
@@ -39,7 +44,8 @@ impl Cop for SpaceBeforeBlockBraces {
         mut corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let style = config.get_str("EnforcedStyle", "space");
-        let empty_style = config.get_str("EnforcedStyleForEmptyBraces", "space");
+        // An unset empty-braces style follows EnforcedStyle
+        let empty_style = config.get_str("EnforcedStyleForEmptyBraces", style);
 
         // Extract opening/closing from either BlockNode or LambdaNode
         let (opening, closing) = if let Some(block) = node.as_block_node() {
@@ -55,8 +61,26 @@ impl Cop for SpaceBeforeBlockBraces {
             return;
         }
 
+        // `no_space` leaves multiline blocks alone while Style/BlockDelimiters
+        // is `line_count_based`, whose autocorrect turns them into do...end
+        // (rubocop/rubocop#7534).
+        if style == "no_space"
+            && config.get_str("BlockDelimitersStyle", "line_count_based") == "line_count_based"
+        {
+            let (open_line, _) = source.offset_to_line_col(opening.start_offset());
+            let (close_line, _) = source.offset_to_line_col(closing.start_offset());
+            if open_line != close_line {
+                return;
+            }
+        }
+
         let bytes = source.as_bytes();
         let before = opening.start_offset();
+        let space_len = bytes[..before]
+            .iter()
+            .rev()
+            .take_while(|&&b| b == b' ' || b == b'\t')
+            .count();
 
         // Check if this is an empty block {}
         let is_empty = closing.start_offset() == opening.end_offset();
@@ -66,8 +90,9 @@ impl Cop for SpaceBeforeBlockBraces {
 
         match effective_style {
             "no_space" => {
-                if before > 0 && bytes[before - 1] == b' ' {
-                    let (line, column) = source.offset_to_line_col(before - 1);
+                if space_len > 0 {
+                    let space_start = before - space_len;
+                    let (line, column) = source.offset_to_line_col(space_start);
                     let mut diag = self.diagnostic(
                         source,
                         line,
@@ -76,7 +101,7 @@ impl Cop for SpaceBeforeBlockBraces {
                     );
                     if let Some(ref mut corr) = corrections {
                         corr.push(crate::correction::Correction {
-                            start: before - 1,
+                            start: space_start,
                             end: before,
                             replacement: String::new(),
                             cop_name: self.name(),
@@ -91,7 +116,7 @@ impl Cop for SpaceBeforeBlockBraces {
                 // "space" (default)
                 // Accept any whitespace (space or tab) before the brace.
                 // Tab characters are used for visual alignment in some codebases.
-                if before > 0 && bytes[before - 1] != b' ' && bytes[before - 1] != b'\t' {
+                if before > 0 && space_len == 0 {
                     let (line, column) = source.offset_to_line_col(before);
                     let mut diag = self.diagnostic(
                         source,
@@ -129,6 +154,78 @@ mod tests {
         "cops/layout/space_before_block_braces"
     );
 
+    #[test]
+    fn offense_no_space_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &SpaceBeforeBlockBraces,
+            "EnforcedStyle: no_space",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_before_block_braces/offense.no_space.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn no_offense_no_space_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &SpaceBeforeBlockBraces,
+            "EnforcedStyle: no_space",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_before_block_braces/no_offense.no_space.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_no_space_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SpaceBeforeBlockBraces,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_before_block_braces/offense.no_space.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_before_block_braces/corrected.no_space.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: no_space"),
+        );
+    }
+
+    #[test]
+    fn offense_empty_no_space_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &SpaceBeforeBlockBraces,
+            "EnforcedStyleForEmptyBraces: no_space",
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_before_block_braces/offense.empty_no_space.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_empty_no_space_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SpaceBeforeBlockBraces,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_before_block_braces/offense.empty_no_space.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_before_block_braces/corrected.empty_no_space.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("EnforcedStyleForEmptyBraces: no_space"),
+        );
+    }
+
+    #[test]
+    fn no_space_checks_multiline_blocks_without_line_count_based() {
+        let config = crate::testutil::cop_config_from_yaml(
+            "EnforcedStyle: no_space\nBlockDelimitersStyle: braces_for_chaining\n",
+        );
+        let src = b"items.each {\n  run\n}\n";
+        let diags = crate::testutil::run_cop_full_with_config(&SpaceBeforeBlockBraces, src, config);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].location.column, 10);
+    }
+
     #[test]
     fn no_space_style_flags_space() {
         use crate::testutil::run_cop_full_with_config;
@@ -35,6 +35,10 @@ use crate::parse::source::SourceFile;
 ///    whitespace. RuboCop accepts tabs here, so the trailing-whitespace scan
 ///    now recognizes any ASCII whitespace before `}` and removes the full run
 ///    when `EnforcedStyle: no_space`.
+/// 7. Under `EnforcedStyle: no_space`, a multiline block whose `}` follows
+///    content on its last line (`  puts x }`) was never checked. RuboCop
+///    flags the space there too; a `}` on a line of its own is still skipped
+///    (RuboCop's aligned-brace exemption, without the misaligned case).
 pub struct SpaceInsideBlockBraces;
 
 impl Cop for SpaceInsideBlockBraces {
@@ -298,63 +302,64 @@ impl Cop for SpaceInsideBlockBraces {
             }
         }
 
-        // Check right brace (only for single-line blocks)
-        if !is_multiline {
-            let enforced = config.get_str("EnforcedStyle", "space");
-            let trailing_whitespace_len = bytes[..close_start]
-                .iter()
-                .rev()
-                .take_while(|b| b.is_ascii_whitespace())
-                .count();
-
-            match enforced {
-                "space" => {
-                    if trailing_whitespace_len == 0 {
-                        let (line, column) = source.offset_to_line_col(closing.start_offset());
-                        let mut diag = self.diagnostic(
-                            source,
-                            line,
-                            column,
-                            "Space missing inside }.".to_string(),
-                        );
-                        if let Some(ref mut corr) = corrections {
-                            corr.push(crate::correction::Correction {
-                                start: close_start,
-                                end: close_start,
-                                replacement: " ".to_string(),
-                                cop_name: self.name(),
-                                cop_index: 0,
-                            });
-                            diag.corrected = true;
-                        }
-                        diagnostics.push(diag);
+        // Check right brace. A multiline block's `}` on a line of its own is
+        // left to the alignment cops; one that follows content on its line is
+        // checked like a single-line block's under `no_space`.
+        let trailing_whitespace_len = bytes[..close_start]
+            .iter()
+            .rev()
+            .take_while(|b| b.is_ascii_whitespace())
+            .count();
+        let whitespace_start = close_start - trailing_whitespace_len;
+        if is_multiline && bytes[whitespace_start..close_start].contains(&b'\n') {
+            return;
+        }
+        match enforced {
+            "space" if !is_multiline => {
+                if trailing_whitespace_len == 0 {
+                    let (line, column) = source.offset_to_line_col(closing.start_offset());
+                    let mut diag = self.diagnostic(
+                        source,
+                        line,
+                        column,
+                        "Space missing inside }.".to_string(),
+                    );
+                    if let Some(ref mut corr) = corrections {
+                        corr.push(crate::correction::Correction {
+                            start: close_start,
+                            end: close_start,
+                            replacement: " ".to_string(),
+                            cop_name: self.name(),
+                            cop_index: 0,
+                        });
+                        diag.corrected = true;
                     }
+                    diagnostics.push(diag);
                 }
-                "no_space" => {
-                    if trailing_whitespace_len > 0 {
-                        let whitespace_start = close_start - trailing_whitespace_len;
-                        let (line, column) = source.offset_to_line_col(whitespace_start);
-                        let mut diag = self.diagnostic(
-                            source,
-                            line,
-                            column,
-                            "Space inside } detected.".to_string(),
-                        );
-                        if let Some(ref mut corr) = corrections {
-                            corr.push(crate::correction::Correction {
-                                start: whitespace_start,
-                                end: close_start,
-                                replacement: String::new(),
-                                cop_name: self.name(),
-                                cop_index: 0,
-                            });
-                            diag.corrected = true;
-                        }
-                        diagnostics.push(diag);
+            }
+            "no_space" => {
+                if trailing_whitespace_len > 0 {
+                    let (line, column) = source.offset_to_line_col(whitespace_start);
+                    let mut diag = self.diagnostic(
+                        source,
+                        line,
+                        column,
+                        "Space inside } detected.".to_string(),
+                    );
+                    if let Some(ref mut corr) = corrections {
+                        corr.push(crate::correction::Correction {
+                            start: whitespace_start,
+                            end: close_start,
+                            replacement: String::new(),
+                            cop_name: self.name(),
+                            cop_index: 0,
+                        });
+                        diag.corrected = true;
                     }
+                    diagnostics.push(diag);
                 }
-                _ => {}
             }
+            _ => {}
         }
     }
 }
@@ -372,6 +377,83 @@ mod tests {
         "cops/layout/space_inside_block_braces"
     );
 
+    const NO_SPACE_CONFIG: &str = "EnforcedStyle: no_space\n\
+                                   EnforcedStyleForEmptyBraces: space\n\
+                                   SpaceBeforeBlockParameters: false\n";
+
+    #[test]
+    fn offense_no_space_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &SpaceInsideBlockBraces,
+            NO_SPACE_CONFIG,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_inside_block_braces/offense.no_space.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn no_offense_no_space_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &SpaceInsideBlockBraces,
+            NO_SPACE_CONFIG,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_inside_block_braces/no_offense.no_space.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_no_space_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SpaceInsideBlockBraces,
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_inside_block_braces/offense.no_space.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/layout/space_inside_block_braces/corrected.no_space.rb"
+            ),
+            crate::testutil::cop_config_from_yaml(NO_SPACE_CONFIG),
+        );
+    }
+
+    #[test]
+    fn brace_corrections_converge_with_space_before_block_braces() {
+        use crate::cop::layout::space_before_block_braces::SpaceBeforeBlockBraces;
+        use crate::testutil::{assert_autocorrect_converges_with_configs, cop_config_from_yaml};
+
+        let src = b"items.each { |x| x }\nitems.map{x}\nitems.each  { }\n";
+        let no_space = assert_autocorrect_converges_with_configs(
+            &[
+                (
+                    &SpaceBeforeBlockBraces,
+                    cop_config_from_yaml("EnforcedStyle: no_space\n"),
+                ),
+                (
+                    &SpaceInsideBlockBraces,
+                    cop_config_from_yaml(NO_SPACE_CONFIG),
+                ),
+            ],
+            src,
+        );
+        assert_eq!(
+            no_space,
+            b"items.each{|x| x}\nitems.map{x}\nitems.each{ }\n".to_vec()
+        );
+
+        let space = assert_autocorrect_converges_with_configs(
+            &[
+                (&SpaceBeforeBlockBraces, CopConfig::default()),
+                (&SpaceInsideBlockBraces, CopConfig::default()),
+            ],
+            src,
+        );
+        assert_eq!(
+            space,
+            b"items.each { |x| x }\nitems.map { x }\nitems.each  {}\n".to_vec()
+        );
+    }
+
     #[test]
     fn empty_braces_space_style_flags_no_space() {
         use crate::testutil::run_cop_full_with_config;
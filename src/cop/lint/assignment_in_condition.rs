@@ -78,6 +78,13 @@ use crate::parse::source::SourceFile;
 /// - `RescueNode`: rescue handlers inside begin blocks
 /// - `EnsureNode`: ensure clauses inside begin blocks
 /// - `RescueModifierNode`: inline rescue (`expr rescue fallback`)
+///
+/// ## Operator assignments
+///
+/// `if (count += 1) > limit`-style operator assignments (`+=`, `<<=`, ...) are
+/// reported on the operator, like plain `=`. Conditional assignments (`||=`,
+/// `&&=`) stay allowed. With `AllowSafeAssignment`, a parenthesized operator
+/// assignment is accepted the same way as `(x = y)`.
 pub struct AssignmentInCondition;
 
 impl Cop for AssignmentInCondition {
@@ -215,8 +222,16 @@ fn traverse_condition(
         return;
     }
 
+    // Operator assignments (+=, <<=, ...) — report on the operator
+    if let Some((op_loc, value)) = get_operator_assignment(node) {
+        let (line, column) = source.offset_to_line_col(op_loc);
+        diagnostics.push(cop.diagnostic(source, line, column, msg.to_string()));
+        traverse_condition(source, &value, allow_safe, msg, cop, diagnostics);
+        return;
+    }
+
     // Conditional assignments (||=, &&=) — allowed, skip (RuboCop's conditional_assignment?)
-    if is_conditional_or_operator_assignment(node) {
+    if is_conditional_assignment(node) {
         return;
     }
 
@@ -234,6 +249,7 @@ fn is_assignment_node(node: &ruby_prism::Node<'_>) -> bool {
         || node.as_constant_path_write_node().is_some()
         || node.as_multi_write_node().is_some()
         || node.as_call_node().is_some_and(|c| c.is_attribute_write())
+        || get_operator_assignment(node).is_some()
 }
 
 /// Get the operator location (byte offset of `=`) for equals assignment nodes
@@ -289,8 +305,8 @@ fn get_assignment_value<'a>(node: &ruby_prism::Node<'a>) -> Option<ruby_prism::N
     None
 }
 
-/// Check if node is a conditional assignment (||=, &&=) or operator assignment (+=, etc.)
-fn is_conditional_or_operator_assignment(node: &ruby_prism::Node<'_>) -> bool {
+/// Check if node is a conditional assignment (||=, &&=)
+fn is_conditional_assignment(node: &ruby_prism::Node<'_>) -> bool {
     node.as_local_variable_or_write_node().is_some()
         || node.as_local_variable_and_write_node().is_some()
         || node.as_instance_variable_or_write_node().is_some()
@@ -303,12 +319,42 @@ fn is_conditional_or_operator_assignment(node: &ruby_prism::Node<'_>) -> bool {
         || node.as_constant_and_write_node().is_some()
         || node.as_constant_path_or_write_node().is_some()
         || node.as_constant_path_and_write_node().is_some()
-        || node.as_local_variable_operator_write_node().is_some()
-        || node.as_instance_variable_operator_write_node().is_some()
-        || node.as_class_variable_operator_write_node().is_some()
-        || node.as_global_variable_operator_write_node().is_some()
-        || node.as_constant_operator_write_node().is_some()
-        || node.as_constant_path_operator_write_node().is_some()
+        || node.as_call_or_write_node().is_some()
+        || node.as_call_and_write_node().is_some()
+        || node.as_index_or_write_node().is_some()
+        || node.as_index_and_write_node().is_some()
+}
+
+/// Get the operator offset and value of an operator assignment (`x += 1`,
+/// `a.b -= 1`, `a[i] <<= 1`).
+fn get_operator_assignment<'a>(
+    node: &ruby_prism::Node<'a>,
+) -> Option<(usize, ruby_prism::Node<'a>)> {
+    if let Some(n) = node.as_local_variable_operator_write_node() {
+        return Some((n.binary_operator_loc().start_offset(), n.value()));
+    }
+    if let Some(n) = node.as_instance_variable_operator_write_node() {
+        return Some((n.binary_operator_loc().start_offset(), n.value()));
+    }
+    if let Some(n) = node.as_class_variable_operator_write_node() {
+        return Some((n.binary_operator_loc().start_offset(), n.value()));
+    }
+    if let Some(n) = node.as_global_variable_operator_write_node() {
+        return Some((n.binary_operator_loc().start_offset(), n.value()));
+    }
+    if let Some(n) = node.as_constant_operator_write_node() {
+        return Some((n.binary_operator_loc().start_offset(), n.value()));
+    }
+    if let Some(n) = node.as_constant_path_operator_write_node() {
+        return Some((n.binary_operator_loc().start_offset(), n.value()));
+    }
+    if let Some(n) = node.as_call_operator_write_node() {
+        return Some((n.binary_operator_loc().start_offset(), n.value()));
+    }
+    if let Some(n) = node.as_index_operator_write_node() {
+        return Some((n.binary_operator_loc().start_offset(), n.value()));
+    }
+    None
 }
 
 /// Find the byte offset of the `=` sign in a call assignment method.
@@ -607,4 +653,30 @@ fn recurse_children(
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(AssignmentInCondition, "cops/lint/assignment_in_condition");
+
+    fn disallow_safe_config() -> CopConfig {
+        crate::testutil::cop_config_from_yaml("AllowSafeAssignment: false")
+    }
+
+    #[test]
+    fn offense_disallow_safe_assignment_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &AssignmentInCondition,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/assignment_in_condition/offense.disallow_safe_assignment.rb"
+            ),
+            disallow_safe_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_disallow_safe_assignment_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &AssignmentInCondition,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/assignment_in_condition/no_offense.disallow_safe_assignment.rb"
+            ),
+            disallow_safe_config(),
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::codemap::CodeMap;
+use crate::parse::pragma::{parse_cop_list, pragma_comments};
 use crate::parse::source::SourceFile;
 
 /// Checks that `# rubocop:` directives are strictly formatted.
@@ -51,6 +52,12 @@ use crate::parse::source::SourceFile;
 /// FN=1: `# rubocop:disable Layout/LineLength,` (trailing comma after cop name).
 /// `is_malformed_cop_list` split by comma, got `["Layout/LineLength", ""]`, and skipped
 /// the empty trailing element. Fix: detect trailing comma in `cop_part` before splitting.
+///
+/// ## nitrocop pragmas
+///
+/// Also checks the per-file `# nitrocop:only <cops>` / `# nitrocop:except <cops>`
+/// pragmas, which are silently ignored when malformed or when they appear after
+/// the first line of code. Those cases are reported here instead.
 pub struct CopDirectiveSyntax;
 
 impl Cop for CopDirectiveSyntax {
@@ -162,6 +169,35 @@ impl Cop for CopDirectiveSyntax {
 
             byte_offset += line.len() + 1;
         }
+
+        for pragma in pragma_comments(source.as_bytes()) {
+            let offset = source.line_start_offset(pragma.line) + pragma.column;
+            if !code_map.is_not_string(offset) {
+                continue;
+            }
+            let message = if pragma
+                .cop_list
+                .split("--")
+                .next()
+                .unwrap_or("")
+                .trim()
+                .is_empty()
+            {
+                "Malformed directive comment detected. The cop name is missing."
+            } else if parse_cop_list(pragma.cop_list).is_none() {
+                "Malformed directive comment detected. Cop names must be separated by commas. Comment in the directive must start with `--`."
+            } else if !pragma.in_header {
+                "Misplaced directive comment detected. `nitrocop:only` and `nitrocop:except` must appear before the first line of code."
+            } else {
+                continue;
+            };
+            diagnostics.push(self.diagnostic(
+                source,
+                pragma.line,
+                pragma.column,
+                message.to_string(),
+            ));
+        }
     }
 }
 
@@ -318,4 +354,24 @@ fn has_invalid_cop_name(token: &str) -> bool {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(CopDirectiveSyntax, "cops/lint/cop_directive_syntax");
+
+    #[test]
+    fn offense_pragma_fixture() {
+        crate::testutil::assert_cop_offenses_full(
+            &CopDirectiveSyntax,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/cop_directive_syntax/offense.pragma.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn no_offense_pragma_fixture() {
+        crate::testutil::assert_cop_no_offenses_full(
+            &CopDirectiveSyntax,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/cop_directive_syntax/no_offense.pragma.rb"
+            ),
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
+use crate::cop::shared::method_dispatch_predicates;
 use crate::cop::shared::node_type::CALL_NODE;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
@@ -123,6 +124,10 @@ fn matches_parts(call: &ruby_prism::CallNode<'_>, parts: &[&str]) -> bool {
     if receiver_parts.is_empty() {
         return call.receiver().is_none();
     }
+    // RuboCop only matches `send`: `binding&.pry` is not a debugger call
+    if method_dispatch_predicates::is_safe_navigation(call) {
+        return false;
+    }
     let recv = match call.receiver() {
         Some(r) => r,
         None => return false,
@@ -136,9 +141,10 @@ fn matches_parts(call: &ruby_prism::CallNode<'_>, parts: &[&str]) -> bool {
         if let Some(const_read) = recv.as_constant_read_node() {
             return const_read.name().as_slice() == name.as_bytes();
         }
+        // `::Kernel` is the top-level constant; `Foo::Kernel` is not
         if let Some(const_path) = recv.as_constant_path_node() {
             if let Some(child) = const_path.name() {
-                return child.as_slice() == name.as_bytes();
+                return const_path.parent().is_none() && child.as_slice() == name.as_bytes();
             }
         }
         return false;
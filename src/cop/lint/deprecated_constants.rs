@@ -99,14 +99,6 @@ fn normalize_constant_name(constant_name: &str) -> &str {
     constant_name.strip_prefix("::").unwrap_or(constant_name)
 }
 
-fn target_ruby_version(config: &CopConfig) -> f64 {
-    config
-        .options
-        .get("TargetRubyVersion")
-        .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-        .unwrap_or(2.7)
-}
-
 fn deprecated_info(constant_name: &str, config: &CopConfig) -> Option<DeprecatedConstantInfo> {
     let normalized_name = normalize_constant_name(constant_name);
 
@@ -156,7 +148,7 @@ fn deprecated_message(constant_name: &str, config: &CopConfig) -> Option<String>
 
     if let Some(version) = &info.deprecated_version {
         let version_number = version.parse::<f64>().ok()?;
-        if target_ruby_version(config) < version_number {
+        if config.target_ruby_version() < version_number {
             return None;
         }
     }
@@ -182,42 +174,25 @@ mod tests {
 
     #[test]
     fn flags_cbase_configured_constant_paths() {
-        let mut config = CopConfig::default();
-        config.options.insert(
-            "TargetRubyVersion".to_string(),
-            serde_yml::from_str("3.0").unwrap(),
-        );
-        config.options.insert(
-            "DeprecatedConstants".to_string(),
-            serde_yml::from_str(
-                r#"
-Random::DEFAULT:
-  Alternative: Random.new
-  DeprecatedVersion: "3.0"
-"#,
-            )
-            .unwrap(),
-        );
-
-        crate::testutil::assert_cop_offenses_full_with_config(
+        crate::testutil::assert_cop_offenses_with_yaml(
             &DeprecatedConstants,
+            r#"
+TargetRubyVersion: 3.0
+DeprecatedConstants:
+  Random::DEFAULT:
+    Alternative: Random.new
+    DeprecatedVersion: "3.0"
+"#,
             b"::Random::DEFAULT\n^^^^^^^^^^^^^^^^^ Lint/DeprecatedConstants: Use `Random.new` instead of `::Random::DEFAULT`, deprecated since Ruby 3.0.\n",
-            config,
         );
     }
 
     #[test]
     fn flags_builtin_random_default_when_used_as_receiver() {
-        let mut config = CopConfig::default();
-        config.options.insert(
-            "TargetRubyVersion".to_string(),
-            serde_yml::from_str("3.0").unwrap(),
-        );
-
-        crate::testutil::assert_cop_offenses_full_with_config(
+        crate::testutil::assert_cop_offenses_with_yaml(
             &DeprecatedConstants,
+            "TargetRubyVersion: 3.0\n",
             b"::Random::DEFAULT.rand(max)\n^^^^^^^^^^^^^^^^^ Lint/DeprecatedConstants: Use `Random.new` instead of `::Random::DEFAULT`, deprecated since Ruby 3.0.\n",
-            config,
         );
     }
 }
@@ -38,11 +38,8 @@ impl Cop for DuplicateMagicComment {
         mut corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let mut seen_keys = HashSet::new();
-        let total_len = source.as_bytes().len();
-        let mut byte_offset: usize = 0;
 
         for (i, line) in source.lines().enumerate() {
-            let line_len = line.len() + 1; // +1 for newline
             let trimmed = line
                 .iter()
                 .position(|&b| b != b' ' && b != b'\t')
@@ -51,13 +48,11 @@ impl Cop for DuplicateMagicComment {
 
             // Only check leading comments (magic comments must be at top of file)
             if trimmed.is_empty() {
-                byte_offset += line_len;
                 continue;
             }
 
             // Shebang line
             if trimmed.starts_with(b"#!") {
-                byte_offset += line_len;
                 continue;
             }
 
@@ -126,7 +121,6 @@ impl Cop for DuplicateMagicComment {
                     && comment[colon_pos + 1] != b' '
                     && comment[colon_pos + 1] != b'\t'
                 {
-                    byte_offset += line_len;
                     continue;
                 }
 
@@ -152,21 +146,17 @@ impl Cop for DuplicateMagicComment {
                         "Duplicate magic comment detected.".to_string(),
                     );
                     if let Some(ref mut corr) = corrections {
-                        let end = std::cmp::min(byte_offset + line_len, total_len);
-                        corr.push(crate::correction::Correction {
-                            start: byte_offset,
-                            end,
-                            replacement: String::new(),
-                            cop_name: self.name(),
-                            cop_index: 0,
-                        });
+                        corr.push(crate::correction::Correction::delete_lines(
+                            source,
+                            i + 1,
+                            i + 1,
+                            self.name(),
+                        ));
                         diag.corrected = true;
                     }
                     diagnostics.push(diag);
                 }
             }
-
-            byte_offset += line_len;
         }
     }
 }
@@ -190,4 +180,23 @@ mod tests {
         let corrected = cs.apply(input);
         assert_eq!(corrected, b"# frozen_string_literal: true\nx = 1\n");
     }
+
+    #[test]
+    fn autocorrect_duplicates_at_eof() {
+        let autocorrect = |input: &[u8]| {
+            let (_diags, corrections) =
+                crate::testutil::run_cop_autocorrect(&DuplicateMagicComment, input);
+            crate::correction::CorrectionSet::from_vec(corrections).apply(input)
+        };
+        assert_eq!(
+            autocorrect(b"# frozen_string_literal: true\n# frozen_string_literal: true"),
+            b"# frozen_string_literal: true\n"
+        );
+        assert_eq!(
+            autocorrect(
+                b"# encoding: utf-8\n# encoding: utf-8\n# frozen_string_literal: true\n# encoding: utf-8\n"
+            ),
+            b"# encoding: utf-8\n# frozen_string_literal: true\n"
+        );
+    }
 }
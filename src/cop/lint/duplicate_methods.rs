@@ -1309,10 +1309,17 @@ impl<'pr> Visit<'pr> for DupMethodVisitor<'_, '_> {
             let method_name = std::str::from_utf8(call.name().as_slice()).unwrap_or("");
             if method_name == "new" && call.block().is_some() {
                 if let Some(recv) = call.receiver() {
-                    if let Some(const_read) = recv.as_constant_read_node() {
-                        let recv_name =
-                            std::str::from_utf8(const_read.name().as_slice()).unwrap_or("");
-                        if recv_name == "Class" || recv_name == "Module" {
+                    // `Class` or `::Class` (rubocop-ast compares the short name)
+                    let recv_name = if let Some(const_read) = recv.as_constant_read_node() {
+                        Some(const_read.name().as_slice())
+                    } else {
+                        recv.as_constant_path_node()
+                            .filter(|path| path.parent().is_none())
+                            .and_then(|path| path.name())
+                            .map(|name| name.as_slice())
+                    };
+                    if let Some(recv_name) = recv_name {
+                        if recv_name == b"Class" || recv_name == b"Module" {
                             let const_name =
                                 std::str::from_utf8(node.name().as_slice()).unwrap_or("");
                             self.scope_stack.push(ScopeEntry {
@@ -1430,6 +1437,13 @@ mod tests {
         assert_eq!(n, 0, "Struct.new blocks should be ignored (no scope)");
     }
 
+    #[test]
+    fn test_root_class_new_is_scope() {
+        // `::Class.new` is matched on the short name, like `Class.new`
+        let n = count_offenses(b"A = ::Class.new do\n  def foo; 1; end\n  def foo; 2; end\nend\n");
+        assert_eq!(n, 1, "A = ::Class.new should be a scope for A");
+    }
+
     #[test]
     fn test_class_eval_with_string() {
         // class_eval with a string argument (not block) should not create scope
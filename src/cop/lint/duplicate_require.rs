@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::cop::shared::util;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
@@ -57,6 +58,15 @@ impl Cop for DuplicateRequire {
         Severity::Warning
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    /// Deleting a `require` can change the order in which features load.
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -64,20 +74,25 @@ impl Cop for DuplicateRequire {
         _code_map: &crate::parse::codemap::CodeMap,
         _config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let mut visitor = RequireVisitor {
             cop: self,
             source,
+            autocorrect: corrections.is_some(),
             // Per RuboCop: keyed by parent node identity.
             // We use the parent node's start offset as a proxy for identity.
             required: HashMap::new(),
             single_statement_block_bodies: HashSet::new(),
             current_parent_offset: 0,
             diagnostics: Vec::new(),
+            corrections: Vec::new(),
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let Some(corrections) = corrections {
+            corrections.extend(visitor.corrections);
+        }
     }
 }
 
@@ -93,6 +108,7 @@ type RequireKey = (Vec<u8>, RequireArgKey);
 struct RequireVisitor<'a, 'src> {
     cop: &'a DuplicateRequire,
     source: &'src SourceFile,
+    autocorrect: bool,
     /// Seen requires keyed by parent node start offset (proxy for identity).
     required: HashMap<usize, HashSet<RequireKey>>,
     /// StatementsNode offsets that should keep their enclosing BlockNode as the
@@ -101,6 +117,7 @@ struct RequireVisitor<'a, 'src> {
     /// Start offset of the current parent node being visited.
     current_parent_offset: usize,
     diagnostics: Vec<Diagnostic>,
+    corrections: Vec<crate::correction::Correction>,
 }
 
 impl RequireVisitor<'_, '_> {
@@ -163,12 +180,32 @@ impl RequireVisitor<'_, '_> {
                             .or_default();
                         if parent_set.contains(&key) {
                             let (line, column) = self.source.offset_to_line_col(loc.start_offset());
-                            self.diagnostics.push(self.cop.diagnostic(
+                            let mut diag = self.cop.diagnostic(
                                 self.source,
                                 line,
                                 column,
                                 "Duplicate `require` detected.".to_string(),
-                            ));
+                            );
+                            if self.autocorrect
+                                && util::stands_alone(
+                                    self.source,
+                                    loc.start_offset(),
+                                    loc.end_offset(),
+                                )
+                            {
+                                let (last_line, _) = self
+                                    .source
+                                    .offset_to_line_col(loc.end_offset().saturating_sub(1));
+                                self.corrections
+                                    .push(crate::correction::Correction::delete_lines(
+                                        self.source,
+                                        line,
+                                        last_line,
+                                        self.cop.name(),
+                                    ));
+                                diag.corrected = true;
+                            }
+                            self.diagnostics.push(diag);
                         } else {
                             parent_set.insert(key);
                         }
@@ -235,4 +272,18 @@ impl<'pr> Visit<'pr> for RequireVisitor<'_, '_> {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(DuplicateRequire, "cops/lint/duplicate_require");
+    crate::cop_autocorrect_fixture_tests!(DuplicateRequire, "cops/lint/duplicate_require");
+
+    #[test]
+    fn autocorrect_leaves_no_blank_line_runs() {
+        use crate::cop::layout::empty_lines::EmptyLines;
+        use crate::cop::layout::trailing_empty_lines::TrailingEmptyLines;
+
+        let input = b"require 'a'\n\nrequire 'a'\n\nrequire 'b'\n\nrequire 'b'\n";
+        let (_diags, corrections) = crate::testutil::run_cop_autocorrect(&DuplicateRequire, input);
+        let corrected = crate::correction::CorrectionSet::from_vec(corrections).apply(input);
+        assert_eq!(corrected, b"require 'a'\n\nrequire 'b'\n");
+        assert!(crate::testutil::run_cop_full(&EmptyLines, &corrected).is_empty());
+        assert!(crate::testutil::run_cop_full(&TrailingEmptyLines, &corrected).is_empty());
+    }
 }
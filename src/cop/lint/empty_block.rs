@@ -1,4 +1,4 @@
-use crate::cop::shared::node_type::{CALL_NODE, FORWARDING_SUPER_NODE, SUPER_NODE};
+use crate::cop::shared::node_type::{CALL_NODE, FORWARDING_SUPER_NODE, LAMBDA_NODE, SUPER_NODE};
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
@@ -42,6 +42,10 @@ use crate::parse::source::SourceFile;
 /// `create_table` block suppress the offense. Fix: expanded the AllowComments
 /// comment search range from `block_node.location()` (just `do...end`) to the
 /// full expression range (call_node start through block_node end).
+///
+/// Stabby lambdas (`-> {}`) are a `LambdaNode` in Prism but a block on
+/// `lambda` in Parser, so they are only flagged with `AllowEmptyLambdas: false`,
+/// under the same comment rule as other blocks.
 pub struct EmptyBlock;
 
 /// Check if a comment is a rubocop:disable directive for a specific cop.
@@ -75,6 +79,24 @@ fn is_disable_comment_for_cop(comment_bytes: &[u8], cop_name: &[u8]) -> bool {
     })
 }
 
+/// Whether a comment sits on any line of `start..end`, other than a
+/// rubocop:disable for this cop (the disable mechanism handles that
+/// separately).
+fn has_comment_on_lines(
+    source: &SourceFile,
+    parse_result: &ruby_prism::ParseResult<'_>,
+    start: usize,
+    end: usize,
+) -> bool {
+    let (start_line, _) = source.offset_to_line_col(start);
+    let (end_line, _) = source.offset_to_line_col(end.saturating_sub(1));
+    parse_result.comments().any(|comment| {
+        let (comment_line, _) = source.offset_to_line_col(comment.location().start_offset());
+        (start_line..=end_line).contains(&comment_line)
+            && !is_disable_comment_for_cop(comment.location().as_slice(), b"Lint/EmptyBlock")
+    })
+}
+
 impl Cop for EmptyBlock {
     fn name(&self) -> &'static str {
         "Lint/EmptyBlock"
@@ -85,7 +107,7 @@ impl Cop for EmptyBlock {
     }
 
     fn interested_node_types(&self) -> &'static [u8] {
-        &[CALL_NODE, SUPER_NODE, FORWARDING_SUPER_NODE]
+        &[CALL_NODE, SUPER_NODE, FORWARDING_SUPER_NODE, LAMBDA_NODE]
     }
 
     fn check_node(
@@ -97,6 +119,36 @@ impl Cop for EmptyBlock {
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        let allow_empty_lambdas = config.get_bool("AllowEmptyLambdas", true);
+        let allow_comments = config.get_bool("AllowComments", true);
+
+        if let Some(lambda) = node.as_lambda_node() {
+            let body_empty = lambda
+                .body()
+                .is_none_or(|b| b.as_statements_node().is_some_and(|s| s.body().is_empty()));
+            let loc = lambda.location();
+            if allow_empty_lambdas
+                || !body_empty
+                || (allow_comments
+                    && has_comment_on_lines(
+                        source,
+                        parse_result,
+                        loc.start_offset(),
+                        loc.end_offset(),
+                    ))
+            {
+                return;
+            }
+            let (line, column) = source.offset_to_line_col(loc.start_offset());
+            diagnostics.push(self.diagnostic(
+                source,
+                line,
+                column,
+                "Empty block detected.".to_string(),
+            ));
+            return;
+        }
+
         let (call_node, super_node, forwarding_super_node, block_node) =
             if let Some(call_node) = node.as_call_node() {
                 let block_node = match call_node.block().and_then(|b| b.as_block_node()) {
@@ -137,7 +189,6 @@ impl Cop for EmptyBlock {
 
         // AllowEmptyLambdas: skip lambda/proc blocks
         // RuboCop's lambda_or_proc? covers: lambda {}, proc {}, Proc.new {}, ::Proc.new {}
-        let allow_empty_lambdas = config.get_bool("AllowEmptyLambdas", true);
         if allow_empty_lambdas {
             if let Some(call_node) = call_node.as_ref() {
                 let name = call_node.name().as_slice();
@@ -165,7 +216,6 @@ impl Cop for EmptyBlock {
         // AllowComments: when true, blocks with comments on or inside them are not offenses.
         // RuboCop checks for any comment within the block's source range OR on the same line,
         // UNLESS the comment is a rubocop:disable directive for this specific cop.
-        let allow_comments = config.get_bool("AllowComments", true);
         if allow_comments {
             // RuboCop checks the full block expression range (including receiver)
             // for comments. For chained calls like `create_table(...) do...end.define_model do end`,
@@ -181,21 +231,8 @@ impl Cop for EmptyBlock {
                 block_node.location().start_offset()
             };
             let end_offset = block_node.location().end_offset();
-            let (start_line, _) = source.offset_to_line_col(start_offset);
-            let (end_line, _) = source.offset_to_line_col(end_offset.saturating_sub(1));
-
-            for comment in parse_result.comments() {
-                let comment_offset = comment.location().start_offset();
-                let (comment_line, _) = source.offset_to_line_col(comment_offset);
-                if comment_line >= start_line && comment_line <= end_line {
-                    // Found a comment on the block's lines.
-                    // Skip if the comment is a rubocop:disable for this cop
-                    // (the disable mechanism handles that separately).
-                    let comment_text = comment.location().as_slice();
-                    if !is_disable_comment_for_cop(comment_text, b"Lint/EmptyBlock") {
-                        return;
-                    }
-                }
+            if has_comment_on_lines(source, parse_result, start_offset, end_offset) {
+                return;
             }
         }
 
@@ -223,4 +260,24 @@ impl Cop for EmptyBlock {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(EmptyBlock, "cops/lint/empty_block");
+
+    #[test]
+    fn offense_disallow_empty_lambdas() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &EmptyBlock,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/empty_block/offense.disallow_empty_lambdas.rb"
+            ),
+            crate::testutil::cop_config_from_yaml("AllowEmptyLambdas: false\n"),
+        );
+    }
+
+    #[test]
+    fn allow_comments_false_flags_comment_only_blocks() {
+        let config = crate::testutil::cop_config_from_yaml("AllowComments: false\n");
+        let source = b"items.each do |x|\n  # TODO: implement\nend\nnoop = lambda {}\n";
+        let diags = crate::testutil::run_cop_full_with_config(&EmptyBlock, source, config);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].location.line, 1);
+    }
 }
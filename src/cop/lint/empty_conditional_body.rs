@@ -10,6 +10,9 @@ use crate::parse::source::SourceFile;
 /// Root cause: RuboCop's `on_if` returns early when `same_line?(node.loc.begin, node.loc.end)`,
 /// i.e., the keyword and `end` are on the same line. We now replicate that check
 /// by comparing the line of the if/unless keyword with the line of the `end` keyword.
+///
+/// An empty `elsif` branch is reported as "Avoid empty `elsif` conditions.",
+/// matching RuboCop's `node.keyword`; it used to say `if`.
 pub struct EmptyConditionalBody;
 
 /// Check if there are any comments within a byte offset range.
@@ -90,12 +93,14 @@ impl Cop for EmptyConditionalBody {
                         return;
                     }
                 }
+                // `if` or `elsif`
+                let keyword = std::str::from_utf8(kw_loc.as_slice()).unwrap_or("if");
                 let (line, column) = source.offset_to_line_col(kw_loc.start_offset());
                 diagnostics.push(self.diagnostic(
                     source,
                     line,
                     column,
-                    "Avoid empty `if` conditions.".to_string(),
+                    format!("Avoid empty `{keyword}` conditions."),
                 ));
             }
         }
@@ -149,4 +154,14 @@ impl Cop for EmptyConditionalBody {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(EmptyConditionalBody, "cops/lint/empty_conditional_body");
+
+    #[test]
+    fn allow_comments_false_flags_comment_only_branches() {
+        let config = crate::testutil::cop_config_from_yaml("AllowComments: false\n");
+        let source = b"if condition\n  # TODO: handle this case\nend\nunless other\n  # Not yet implemented\nend\n";
+        let diags =
+            crate::testutil::run_cop_full_with_config(&EmptyConditionalBody, source, config);
+        let lines: Vec<usize> = diags.iter().map(|d| d.location.line).collect();
+        assert_eq!(lines, [1, 4]);
+    }
 }
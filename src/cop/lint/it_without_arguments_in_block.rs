@@ -1,11 +1,23 @@
+use crate::cop::shared::node_type::IT_LOCAL_VARIABLE_READ_NODE;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
-/// No-op: `it` is a block parameter in Ruby 3.4+, making this cop obsolete.
-/// Retained for configuration compatibility only.
+/// Lint/ItWithoutArgumentsInBlock: a bare `it` in a block without parameters,
+/// which Ruby 3.3 warns about because 3.4 reads it as the first block
+/// parameter rather than a method call.
+///
+/// RuboCop sets `maximum_target_ruby_version 3.3`, so the cop is silent when
+/// the target is 3.4 or later. Prism always parses with 3.4 semantics: such an
+/// `it` is an `ItLocalVariableReadNode`, which appears only where RuboCop's
+/// `(send nil :it)` matches — receiverless, no arguments or parentheses, no
+/// block literal, innermost block without parameters (`it()`, `self.it`,
+/// `{ || it }` and `{ |x| it }` all parse as calls instead).
 pub struct ItWithoutArgumentsInBlock;
 
+const MSG: &str = "`it` calls without arguments will refer to the first block param in Ruby 3.4; \
+                   use `it()` or `self.it`.";
+
 impl Cop for ItWithoutArgumentsInBlock {
     fn name(&self) -> &'static str {
         "Lint/ItWithoutArgumentsInBlock"
@@ -16,18 +28,28 @@ impl Cop for ItWithoutArgumentsInBlock {
     }
 
     fn interested_node_types(&self) -> &'static [u8] {
-        &[]
+        &[IT_LOCAL_VARIABLE_READ_NODE]
     }
 
     fn check_node(
         &self,
-        _source: &SourceFile,
-        _node: &ruby_prism::Node<'_>,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
-        _diagnostics: &mut Vec<Diagnostic>,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: maximum_target_ruby_version 3.3
+        if !config.ruby_version_at_most(3.3) {
+            return;
+        }
+
+        let Some(it) = node.as_it_local_variable_read_node() else {
+            return;
+        };
+        let (line, column) = source.offset_to_line_col(it.location().start_offset());
+        diagnostics.push(self.diagnostic(source, line, column, MSG.to_string()));
     }
 }
 
@@ -35,13 +57,17 @@ impl Cop for ItWithoutArgumentsInBlock {
 mod tests {
     use super::*;
 
+    crate::cop_fixture_tests!(
+        ItWithoutArgumentsInBlock,
+        "cops/lint/it_without_arguments_in_block"
+    );
+
     #[test]
-    fn never_fires() {
+    fn silent_on_ruby_34() {
+        let config = crate::testutil::cop_config_from_yaml_with_targets("{}\n", Some(3.4), None);
         let source = b"0.times { it }\ndo_something { it }\nfoo.each { it }\n";
-        let diags = crate::testutil::run_cop(&ItWithoutArgumentsInBlock, source);
-        assert!(
-            diags.is_empty(),
-            "No-op cop should never produce diagnostics"
-        );
+        let diags =
+            crate::testutil::run_cop_full_with_config(&ItWithoutArgumentsInBlock, source, config);
+        assert!(diags.is_empty(), "`it` is a block parameter in Ruby 3.4+");
     }
 }
@@ -5,7 +5,9 @@ use crate::cop::shared::node_type::{
     AND_NODE, CALL_NODE, CASE_MATCH_NODE, CASE_NODE, IF_NODE, OR_NODE, UNLESS_NODE, UNTIL_NODE,
     WHILE_NODE,
 };
+use crate::cop::shared::util;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
@@ -153,6 +155,27 @@ use crate::parse::source::SourceFile;
 /// Fix: Replaced `if_has_empty_body_and_empty_else` with
 /// `if_should_skip_for_empty_else` / `unless_should_skip_for_empty_else`
 /// which account for the truthiness of the condition.
+///
+/// ## Upstream alignment
+///
+/// `case` subjects and `!` operands now go through RuboCop's
+/// `handle_node`/`check_node` recursion instead of a direct literal check, so
+/// `case (1)`, `case !nil` and `!(!1)` are flagged. A literal reached twice
+/// (`if !1` from both the `if` and the `!`) is reported once. Modifier
+/// `x while 1` / `x until nil` follow the block forms, including the
+/// `while true` / `until false` exemption.
+///
+/// ## Autocorrect
+///
+/// The behavior-preserving part of RuboCop's corrections:
+/// - `1 && foo` / `nil || foo` become `foo`, except before `return`,
+///   `break` or `next`.
+/// - A loop literal that keeps looping becomes `true` (`false` for `until`);
+///   a loop that never runs is deleted, except `begin..end while nil`.
+/// - A conditional is replaced by the branch that runs, or deleted when none
+///   does. Not for `elsif`, a multi-statement branch whose value is used, or
+///   when a comment or heredoc is in the dropped code. Deletions only apply
+///   to a statement on lines of its own.
 pub struct LiteralAsCondition;
 
 fn is_literal(node: &ruby_prism::Node<'_>) -> bool {
@@ -182,27 +205,51 @@ fn is_primitive_array(node: &ruby_prism::Node<'_>) -> bool {
     }
 }
 
-/// Check and report a literal inside a `!` or `not()` receiver.
-/// Unwraps one level of parentheses. Does NOT recurse into compound
-/// expressions (and/or) since those are handled by on_and/on_or.
-fn check_bang_receiver(
+/// RuboCop's `handle_node`: report a literal reached through `!`,
+/// `&&`/`||`/`and`/`or` or single-statement parentheses. A direct operand of
+/// `&&`/`||` is left to on_and/on_or, which only flag the left side when it
+/// decides the result.
+fn handle_node(
     cop: &LiteralAsCondition,
     source: &SourceFile,
-    recv: &ruby_prism::Node<'_>,
+    node: &ruby_prism::Node<'_>,
+    operand: bool,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
-    if is_literal(recv) {
-        add_literal_offense(cop, source, recv, diagnostics);
-        return;
-    }
-    // Unwrap parentheses: `!(expr)` or `not(expr)`
-    if let Some(parens) = recv.as_parentheses_node() {
-        if let Some(body) = parens.body() {
-            if let Some(stmts) = body.as_statements_node() {
-                let body_nodes: Vec<_> = stmts.body().iter().collect();
-                if body_nodes.len() == 1 && is_literal(&body_nodes[0]) {
-                    add_literal_offense(cop, source, &body_nodes[0], diagnostics);
-                }
+    if is_literal(node) {
+        if !operand {
+            add_literal_offense(cop, source, node, diagnostics);
+        }
+    } else {
+        check_operands(cop, source, node, diagnostics);
+    }
+}
+
+/// RuboCop's `check_node`: descend into the operand of a prefix `!`, both
+/// sides of `&&`/`||` and the body of `(expr)`.
+fn check_operands(
+    cop: &LiteralAsCondition,
+    source: &SourceFile,
+    node: &ruby_prism::Node<'_>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if let Some(call) = node.as_call_node() {
+        let prefix_bang = call.name().as_slice() == b"!"
+            && call.message_loc().is_some_and(|m| m.as_slice() == b"!");
+        if let Some(recv) = call.receiver().filter(|_| prefix_bang) {
+            handle_node(cop, source, &recv, false, diagnostics);
+        }
+    } else if let Some(and_node) = node.as_and_node() {
+        handle_node(cop, source, &and_node.left(), true, diagnostics);
+        handle_node(cop, source, &and_node.right(), true, diagnostics);
+    } else if let Some(or_node) = node.as_or_node() {
+        handle_node(cop, source, &or_node.left(), true, diagnostics);
+        handle_node(cop, source, &or_node.right(), true, diagnostics);
+    } else if let Some(parens) = node.as_parentheses_node() {
+        if let Some(stmts) = parens.body().and_then(|b| b.as_statements_node()) {
+            let body: Vec<_> = stmts.body().iter().collect();
+            if body.len() == 1 {
+                handle_node(cop, source, &body[0], false, diagnostics);
             }
         }
     }
@@ -303,21 +350,179 @@ fn unless_should_skip_for_empty_else(
     is_truthy_literal(predicate)
 }
 
+/// Report `node` unless an earlier handler already did: `!1` inside an `if`
+/// predicate is reached both from the `!` call and from the conditional.
+/// Returns whether an offense was added.
 fn add_literal_offense(
     cop: &LiteralAsCondition,
     source: &SourceFile,
     node: &ruby_prism::Node<'_>,
     diagnostics: &mut Vec<Diagnostic>,
-) {
+) -> bool {
     let loc = node.location();
     let literal_text = node_source_text(node);
     let (line, column) = source.offset_to_line_col(loc.start_offset());
+    if diagnostics
+        .iter()
+        .any(|d| d.location.line == line && d.location.column == column)
+    {
+        return false;
+    }
     diagnostics.push(cop.diagnostic(
         source,
         line,
         column,
         format!("Literal `{literal_text}` appeared as a condition."),
     ));
+    true
+}
+
+/// Attach `correction` to the offense `add_literal_offense` just added.
+fn push_correction(
+    diagnostics: &mut [Diagnostic],
+    corrections: &mut Vec<Correction>,
+    correction: Option<Correction>,
+) {
+    if let Some(correction) = correction {
+        corrections.push(correction);
+        if let Some(diag) = diagnostics.last_mut() {
+            diag.corrected = true;
+        }
+    }
+}
+
+fn replacement(node: &ruby_prism::Node<'_>, text: &str) -> Correction {
+    let loc = node.location();
+    Correction {
+        start: loc.start_offset(),
+        end: loc.end_offset(),
+        replacement: text.to_string(),
+        cop_name: "Lint/LiteralAsCondition",
+        cop_index: 0,
+    }
+}
+
+/// `1 && foo` and `nil || foo` become `foo`. Not when the right side is
+/// `return`, `break` or `next`: leftmost, it can be a void value expression.
+fn operator_correction(
+    node: &ruby_prism::Node<'_>,
+    rhs: &ruby_prism::Node<'_>,
+) -> Option<Correction> {
+    if rhs.as_return_node().is_some()
+        || rhs.as_break_node().is_some()
+        || rhs.as_next_node().is_some()
+    {
+        return None;
+    }
+    Some(replacement(node, node_source_text(rhs)))
+}
+
+/// Delete `node` with its lines, when it is a statement on lines of its own
+/// and nothing inside it would be lost beyond the dead code: no comment, no
+/// heredoc whose body follows the node.
+fn removal(
+    source: &SourceFile,
+    parse_result: &ruby_prism::ParseResult<'_>,
+    node: &ruby_prism::Node<'_>,
+) -> Option<Correction> {
+    let loc = node.location();
+    let (start, end) = (loc.start_offset(), loc.end_offset());
+    if !util::stands_alone(source, start, end)
+        || !util::is_statement(parse_result, start, end)
+        || has_comment(parse_result, start, end, None)
+        || loc.as_slice().windows(2).any(|w| w == b"<<")
+    {
+        return None;
+    }
+    let (first, _) = source.offset_to_line_col(start);
+    let (last, _) = source.offset_to_line_col(end.saturating_sub(1));
+    Some(Correction::delete_lines(
+        source,
+        first,
+        last,
+        "Lint/LiteralAsCondition",
+    ))
+}
+
+/// Whether a comment starts within `start..end` outside of `keep`.
+fn has_comment(
+    parse_result: &ruby_prism::ParseResult<'_>,
+    start: usize,
+    end: usize,
+    keep: Option<(usize, usize)>,
+) -> bool {
+    parse_result.comments().any(|comment| {
+        let offset = comment.location().start_offset();
+        (start..end).contains(&offset)
+            && keep.is_none_or(|(from, to)| !(from..to).contains(&offset))
+    })
+}
+
+/// RuboCop's `correct_if_node`: the branch the literal selects replaces the
+/// conditional, and a conditional left with nothing to run is deleted.
+/// `branch` is the statements that run (`None` when nothing does).
+///
+/// Only the rewrites that keep behavior and comments are made: a branch of
+/// several statements replaces the conditional only where its value is
+/// unused, and nothing is rewritten when a comment or heredoc sits in the
+/// dropped code.
+fn conditional_correction(
+    source: &SourceFile,
+    parse_result: &ruby_prism::ParseResult<'_>,
+    node: &ruby_prism::Node<'_>,
+    branch: Option<ruby_prism::StatementsNode<'_>>,
+) -> Option<Correction> {
+    let body: Vec<_> = branch
+        .map(|b| b.body().iter().collect())
+        .unwrap_or_default();
+    let (Some(first), Some(last)) = (body.first(), body.last()) else {
+        return removal(source, parse_result, node);
+    };
+    let loc = node.location();
+    let (start, end) = (loc.start_offset(), loc.end_offset());
+    let (keep_start, keep_end) = (
+        first.location().start_offset(),
+        last.location().end_offset(),
+    );
+    let dropped = [
+        &source.as_bytes()[start..keep_start],
+        &source.as_bytes()[keep_end..end],
+    ];
+    if (body.len() > 1 && !util::is_statement(parse_result, start, end))
+        || has_comment(parse_result, start, end, Some((keep_start, keep_end)))
+        || dropped
+            .iter()
+            .any(|text| text.windows(2).any(|w| w == b"<<"))
+    {
+        return None;
+    }
+    Some(replacement(
+        node,
+        source.byte_slice(keep_start, keep_end, ""),
+    ))
+}
+
+/// RuboCop's loop correction. A literal that keeps the loop running becomes
+/// `keyword` (`true` for `while`, `false` for `until`), and a loop that never
+/// runs is deleted. `begin..end while nil` runs its body once; RuboCop
+/// unwraps it, but a `break` or `next` in the body would then be invalid, so
+/// it is left alone.
+fn loop_correction(
+    source: &SourceFile,
+    parse_result: &ruby_prism::ParseResult<'_>,
+    node: &ruby_prism::Node<'_>,
+    predicate: &ruby_prism::Node<'_>,
+    begin_modifier: bool,
+    runs: bool,
+    keyword: &str,
+) -> Option<Correction> {
+    if runs {
+        Some(replacement(predicate, keyword))
+    } else if begin_modifier {
+        None
+    } else {
+        removal(source, parse_result, node)
+    }
 }
 
 impl Cop for LiteralAsCondition {
@@ -343,20 +548,27 @@ impl Cop for LiteralAsCondition {
         ]
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_node(
         &self,
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
-        _parse_result: &ruby_prism::ParseResult<'_>,
+        parse_result: &ruby_prism::ParseResult<'_>,
         _config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         // on_and: truthy literal on LHS of &&
         if let Some(and_node) = node.as_and_node() {
             let lhs = and_node.left();
-            if is_truthy_literal(&lhs) {
-                add_literal_offense(self, source, &lhs, diagnostics);
+            if is_truthy_literal(&lhs) && add_literal_offense(self, source, &lhs, diagnostics) {
+                if let Some(corrections) = corrections {
+                    let correction = operator_correction(node, &and_node.right());
+                    push_correction(diagnostics, corrections, correction);
+                }
             }
             return;
         }
@@ -364,22 +576,25 @@ impl Cop for LiteralAsCondition {
         // on_or: falsey literal on LHS of ||
         if let Some(or_node) = node.as_or_node() {
             let lhs = or_node.left();
-            if is_falsey_literal(&lhs) {
-                add_literal_offense(self, source, &lhs, diagnostics);
+            if is_falsey_literal(&lhs) && add_literal_offense(self, source, &lhs, diagnostics) {
+                if let Some(corrections) = corrections {
+                    let correction = operator_correction(node, &or_node.right());
+                    push_correction(diagnostics, corrections, correction);
+                }
             }
             return;
         }
 
-        // on_send: ! and not() operators
-        // Only flags direct literal receivers. Nested &&/||/! within the receiver
-        // are handled by on_and/on_or and recursive on_send calls on inner CallNodes.
+        // on_send: `!` and `not`. A literal operand is reported; otherwise
+        // check_node descends through `!`, `&&`/`||` and parentheses.
         if let Some(call) = node.as_call_node() {
-            let name = call.name();
-            if name.as_slice() == b"!" {
-                // This handles both `!expr` (prefix bang) and `not(expr)`
-                // In Prism, `not(expr)` is also a CallNode with name `!`
+            if call.name().as_slice() == b"!" {
                 if let Some(recv) = call.receiver() {
-                    check_bang_receiver(self, source, &recv, diagnostics);
+                    if is_literal(&recv) {
+                        add_literal_offense(self, source, &recv, diagnostics);
+                    } else {
+                        check_operands(self, source, &recv, diagnostics);
+                    }
                 }
             }
             return;
@@ -397,10 +612,31 @@ impl Cop for LiteralAsCondition {
             if if_should_skip_for_empty_else(&if_node, &predicate) {
                 return;
             }
-
-            if is_falsey_literal(&predicate) || is_truthy_literal(&predicate) {
-                add_literal_offense(self, source, &predicate, diagnostics);
+            let truthy = is_truthy_literal(&predicate);
+            if !(truthy || is_falsey_literal(&predicate))
+                || !add_literal_offense(self, source, &predicate, diagnostics)
+            {
+                return;
             }
+            let Some(corrections) = corrections else {
+                return;
+            };
+            // `elsif` chains are left alone: the branch to keep would need
+            // its own `if`/`else` keywords.
+            let is_elsif = if_node
+                .if_keyword_loc()
+                .is_some_and(|kw| kw.as_slice() == b"elsif");
+            let branch = match if_node.subsequent() {
+                _ if is_elsif => return,
+                _ if truthy => if_node.statements(),
+                None => None,
+                Some(subsequent) => match subsequent.as_else_node() {
+                    Some(else_node) => else_node.statements(),
+                    None => return,
+                },
+            };
+            let correction = conditional_correction(source, parse_result, node, branch);
+            push_correction(diagnostics, corrections, correction);
             return;
         }
 
@@ -414,13 +650,26 @@ impl Cop for LiteralAsCondition {
             if unless_should_skip_for_empty_else(&unless_node, &predicate) {
                 return;
             }
-            if is_falsey_literal(&predicate) || is_truthy_literal(&predicate) {
-                add_literal_offense(self, source, &predicate, diagnostics);
+            let truthy = is_truthy_literal(&predicate);
+            if !(truthy || is_falsey_literal(&predicate))
+                || !add_literal_offense(self, source, &predicate, diagnostics)
+            {
+                return;
+            }
+            if let Some(corrections) = corrections {
+                let branch = if truthy {
+                    unless_node.else_clause().and_then(|e| e.statements())
+                } else {
+                    unless_node.statements()
+                };
+                let correction = conditional_correction(source, parse_result, node, branch);
+                push_correction(diagnostics, corrections, correction);
             }
             return;
         }
 
-        // on_while (includes begin..end while via is_begin_modifier)
+        // on_while (includes modifier `x while 1` and begin..end while via
+        // is_begin_modifier)
         if let Some(while_node) = node.as_while_node() {
             let predicate = while_node.predicate();
             let pred_text = node_source_text(&predicate);
@@ -430,13 +679,26 @@ impl Cop for LiteralAsCondition {
                 return;
             }
 
-            if is_literal(&predicate) {
-                add_literal_offense(self, source, &predicate, diagnostics);
+            if is_literal(&predicate) && add_literal_offense(self, source, &predicate, diagnostics)
+            {
+                if let Some(corrections) = corrections {
+                    let correction = loop_correction(
+                        source,
+                        parse_result,
+                        node,
+                        &predicate,
+                        while_node.is_begin_modifier(),
+                        is_truthy_literal(&predicate),
+                        "true",
+                    );
+                    push_correction(diagnostics, corrections, correction);
+                }
             }
             return;
         }
 
-        // on_until (includes begin..end until via is_begin_modifier)
+        // on_until (includes modifier `x until nil` and begin..end until via
+        // is_begin_modifier)
         if let Some(until_node) = node.as_until_node() {
             let predicate = until_node.predicate();
             let pred_text = node_source_text(&predicate);
@@ -446,8 +708,20 @@ impl Cop for LiteralAsCondition {
                 return;
             }
 
-            if is_literal(&predicate) {
-                add_literal_offense(self, source, &predicate, diagnostics);
+            if is_literal(&predicate) && add_literal_offense(self, source, &predicate, diagnostics)
+            {
+                if let Some(corrections) = corrections {
+                    let correction = loop_correction(
+                        source,
+                        parse_result,
+                        node,
+                        &predicate,
+                        until_node.is_begin_modifier(),
+                        is_falsey_literal(&predicate),
+                        "false",
+                    );
+                    push_correction(diagnostics, corrections, correction);
+                }
             }
             return;
         }
@@ -464,12 +738,9 @@ impl Cop for LiteralAsCondition {
                     return;
                 }
 
-                // Only flag direct literals. Nested &&/||/! are handled by
-                // on_and/on_or/on_send. RuboCop's check_case only recurses through
-                // keyword `and`/`or` (operator_keyword?), not &&/||, which is rare.
-                if is_falsey_literal(&predicate) || is_truthy_literal(&predicate) {
-                    add_literal_offense(self, source, &predicate, diagnostics);
-                }
+                // RuboCop's check_case: `case 1`, `case (1)`, `case !1`; a
+                // literal operand of `&&`/`||` is left to on_and/on_or.
+                handle_node(self, source, &predicate, false, diagnostics);
             } else {
                 // Case without predicate: check when branches
                 for condition in case_node.conditions().iter() {
@@ -523,9 +794,7 @@ impl Cop for LiteralAsCondition {
                     return;
                 }
 
-                if is_literal(&predicate) {
-                    add_literal_offense(self, source, &predicate, diagnostics);
-                }
+                handle_node(self, source, &predicate, false, diagnostics);
             } else {
                 // case/in without predicate: check in_pattern branches
                 for condition in case_match.conditions().iter() {
@@ -587,6 +856,41 @@ mod tests {
     use super::*;
     crate::cop_fixture_tests!(LiteralAsCondition, "cops/lint/literal_as_condition");
 
+    #[test]
+    fn autocorrect_fixtures() {
+        let offense = include_bytes!(
+            "../../../tests/fixtures/cops/lint/literal_as_condition/offense.autocorrect.rb"
+        );
+        crate::testutil::assert_cop_offenses_with_yaml(&LiteralAsCondition, "{}\n", offense);
+        crate::testutil::assert_cop_autocorrect(
+            &LiteralAsCondition,
+            offense,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/literal_as_condition/corrected.autocorrect.rb"
+            ),
+        );
+        crate::testutil::assert_cop_autocorrect_idempotent(&LiteralAsCondition, offense);
+    }
+
+    #[test]
+    fn no_correction_for_elsif_or_value_position() {
+        let cop = LiteralAsCondition;
+        let src = b"if a\n  b\nelsif 1\n  c\nend\nfoo((bar if false))\nx = [(1 while false)]\n";
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect(&cop, src);
+        assert_eq!(diags.len(), 3);
+        assert!(corrections.is_empty(), "got {corrections:?}");
+    }
+
+    #[test]
+    fn reports_a_negated_literal_once() {
+        let cop = LiteralAsCondition;
+        let diags = crate::testutil::run_cop_full(
+            &cop,
+            b"if !1\n  top\nend\ncase !(!nil)\nwhen x then top\nend\n",
+        );
+        assert_eq!(diags.len(), 2, "{diags:?}");
+    }
+
     #[test]
     fn test_if_true_semicolon() {
         let cop = LiteralAsCondition;
@@ -53,6 +53,14 @@ use ruby_prism::Visit;
 /// Fix:
 /// - In `is_literal()`, treat `StringNode`s whose source contains a newline as non-literal,
 ///   matching the parser gem's `:dstr` representation for multi-line strings.
+///
+/// ## Upstream alignment
+///
+/// RuboCop checks every `=` assignment node in `if`/`unless`/`while`/`until`
+/// conditions, modifier forms and ternaries included: `Foo::BAR = 1`
+/// (`ConstantPathWriteNode`) and `a, b = 1, 2` (`MultiWriteNode`) were
+/// missing. `case` subjects are not conditions for this cop, and it has no
+/// autocorrect upstream either.
 pub struct LiteralAssignmentInCondition;
 
 impl Cop for LiteralAssignmentInCondition {
@@ -185,6 +193,18 @@ impl<'pr> Visit<'pr> for LiteralAssignmentFinder<'_, '_> {
         self.check_assignment(&node.as_node(), &node.value());
         ruby_prism::visit_constant_write_node(self, node);
     }
+
+    fn visit_constant_path_write_node(&mut self, node: &ruby_prism::ConstantPathWriteNode<'pr>) {
+        self.check_assignment(&node.as_node(), &node.value());
+        ruby_prism::visit_constant_path_write_node(self, node);
+    }
+
+    // `a, b = 1, 2` (RuboCop's `masgn`): the value is an implicit array, so a
+    // splat (`a, b = *list`) is never all literals.
+    fn visit_multi_write_node(&mut self, node: &ruby_prism::MultiWriteNode<'pr>) {
+        self.check_assignment(&node.as_node(), &node.value());
+        ruby_prism::visit_multi_write_node(self, node);
+    }
 }
 
 impl LiteralAssignmentFinder<'_, '_> {
@@ -49,6 +49,15 @@ use crate::parse::source::SourceFile;
 /// comments when closing a multi-line disable, use the directive's actual
 /// column, and dedupe multiple missing cops on the same directive location so
 /// we match RuboCop's single reported offense per comment range.
+///
+/// ## MaximumRangeSize
+///
+/// With a finite `MaximumRangeSize` (default `.inf`), a block disable that is
+/// re-enabled is only flagged when more than that many lines separate the
+/// `disable` and `enable` comments. A disable that is never re-enabled stays
+/// open to the end of the file and is always flagged, as in RuboCop where its
+/// range ends at infinity. End-of-line disables only cover their own line and
+/// never need an enable.
 pub struct MissingCopEnableDirective;
 
 #[derive(Clone)]
@@ -158,15 +167,11 @@ impl Cop for MissingCopEnableDirective {
             byte_offset += line.len() + 1;
         }
 
-        // Report all remaining open disables (never re-enabled).
-        let mut remaining: Vec<(String, OpenDisable)> = open_disables.into_iter().collect();
-        if max_range.is_finite() {
-            let max_range = max_range as usize;
-            remaining.retain(|(_, info)| lines.len().saturating_sub(info.line) > max_range);
-            push_unique_diagnostics(self, source, diagnostics, remaining, Some(max_range));
-        } else {
-            push_unique_diagnostics(self, source, diagnostics, remaining, None);
-        }
+        // Report all remaining open disables (never re-enabled). Their range
+        // is unbounded, so they exceed any finite MaximumRangeSize too.
+        let remaining: Vec<(String, OpenDisable)> = open_disables.into_iter().collect();
+        let max_range = max_range.is_finite().then_some(max_range as usize);
+        push_unique_diagnostics(self, source, diagnostics, remaining, max_range);
 
         // Sort by location for deterministic output.
         diagnostics.sort_by(|a, b| {
@@ -344,4 +349,36 @@ mod tests {
         missing_enable_two = "missing_enable_two.rb",
         missing_enable_spaced = "missing_enable_spaced.rb",
     );
+
+    fn max_range_size_config() -> CopConfig {
+        CopConfig {
+            options: HashMap::from([(
+                "MaximumRangeSize".into(),
+                serde_yml::Value::Number(2.into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_max_range_size() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &MissingCopEnableDirective,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/missing_cop_enable_directive/offense.max_range_size.rb"
+            ),
+            max_range_size_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_max_range_size() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &MissingCopEnableDirective,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/missing_cop_enable_directive/no_offense.max_range_size.rb"
+            ),
+            max_range_size_config(),
+        );
+    }
 }
@@ -115,8 +115,12 @@ impl MixedCaseRange {
 
         if is_unsafe_char_range(left_char, right_char) {
             let loc = range.location();
-            let (line, column) = source.offset_to_line_col(loc.start_offset());
-            return vec![self.diagnostic(source, line, column, MSG.to_string())];
+            return vec![self.diagnostic_for_range(
+                source,
+                loc.start_offset(),
+                loc.end_offset(),
+                MSG.to_string(),
+            )];
         }
 
         Vec::new()
@@ -239,8 +243,21 @@ impl MixedCaseRange {
 
                 if is_unsafe_regexp_range(chars[i], range_end) {
                     if let Some(abs_offset) = offsets.get(i).copied().flatten() {
-                        let (line, column) = source.offset_to_line_col(abs_offset);
-                        diagnostics.push(self.diagnostic(source, line, column, MSG.to_string()));
+                        // Interpolated segments have no source offset, so the
+                        // range end may be unknown.
+                        let diagnostic = match offsets.get(i + 2).copied().flatten() {
+                            Some(end) => self.diagnostic_for_range(
+                                source,
+                                abs_offset,
+                                end + range_end.len_utf8(),
+                                MSG.to_string(),
+                            ),
+                            None => {
+                                let (line, column) = source.offset_to_line_col(abs_offset);
+                                self.diagnostic(source, line, column, MSG.to_string())
+                            }
+                        };
+                        diagnostics.push(diagnostic);
                     }
                 }
 
@@ -100,10 +100,89 @@ use crate::diagnostic::Severity;
 /// masked clearly-unused directives for abstract `raise NotImplementedError`
 /// stubs. `Security/YAMLLoad` remains special: RuboCop only runs it for
 /// TargetRubyVersion <= 3.0, so Ruby 3.1+ can safely flag inline directives
-/// as redundant even though nitrocop keeps the cop as a compatibility stub.
+/// as redundant: the cop never fires there.
 /// Standalone block disables remain conservative to match RuboCop.
+///
+/// ## Autocorrect
+///
+/// When every cop named in a directive is redundant, the whole comment is
+/// removed: a directive on its own line takes the line with it, and an
+/// end-of-line directive takes the spaces before it. Otherwise only the
+/// redundant names are removed, together with the comma and spaces that
+/// separate them from the rest of the list (see
+/// `redundant_directive_removals`). The corrections are built in the linter
+/// next to the detection.
 pub struct RedundantCopDisableDirective;
 
+/// Byte ranges to delete from the directive comment spanning `comment` when
+/// the cop names at `redundant` are unnecessary. `names` are the ranges of all
+/// cop names in the comment, in source order.
+pub(crate) fn redundant_directive_removals(
+    bytes: &[u8],
+    comment: (usize, usize),
+    names: &[(usize, usize)],
+    redundant: &[(usize, usize)],
+) -> Vec<(usize, usize)> {
+    let is_blank = |b: &u8| *b == b' ' || *b == b'\t';
+
+    if names.iter().all(|name| redundant.contains(name)) {
+        let line_start = bytes[..comment.0]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |p| p + 1);
+        if bytes[line_start..comment.0].iter().all(is_blank) {
+            // Own line: remove the whole line, including its newline.
+            let line_end = bytes[comment.1..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(bytes.len(), |p| comment.1 + p + 1);
+            return vec![(line_start, line_end)];
+        }
+        // End of line: remove the comment and the spaces before it.
+        let start = comment.0
+            - bytes[..comment.0]
+                .iter()
+                .rev()
+                .take_while(|b| is_blank(b))
+                .count();
+        return vec![(start, comment.1)];
+    }
+
+    let last = names.iter().map(|name| name.1).max().unwrap_or(0);
+    redundant
+        .iter()
+        .map(|&(start, end)| {
+            if end < last {
+                // Not the last name: eat the comma after it and the spaces
+                // before the next name.
+                let mut pos = end + bytes[end..].iter().take_while(|b| is_blank(b)).count();
+                if bytes.get(pos) == Some(&b',') {
+                    pos += 1;
+                }
+                pos += bytes[pos..].iter().take_while(|b| is_blank(b)).count();
+                (start, pos)
+            } else {
+                // The last name: eat the comma before it and the spaces around it.
+                let mut pos = start
+                    - bytes[..start]
+                        .iter()
+                        .rev()
+                        .take_while(|b| is_blank(b))
+                        .count();
+                if pos > 0 && bytes[pos - 1] == b',' {
+                    pos -= 1;
+                    pos -= bytes[..pos]
+                        .iter()
+                        .rev()
+                        .take_while(|b| is_blank(b))
+                        .count();
+                }
+                (pos, end)
+            }
+        })
+        .collect()
+}
+
 pub(crate) fn allow_redundant_disable_flagging_for_known_gap_cop(
     cop_name: &str,
     target_ruby_version: f64,
@@ -125,6 +204,10 @@ impl Cop for RedundantCopDisableDirective {
         Severity::Warning
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     // This cop is intentionally a no-op in check_lines/check_node/check_source.
     // The actual detection happens in lint_source_inner after all cops have run,
     // where we can determine which disable directives actually suppressed an offense.
@@ -187,6 +270,65 @@ mod tests {
         ));
     }
 
+    fn removed(src: &str, names: &[&str], redundant: &[&str]) -> String {
+        let comment_start = src.find('#').unwrap();
+        let comment_end = src[comment_start..]
+            .find('\n')
+            .map_or(src.len(), |p| comment_start + p);
+        let range = |name: &&str| {
+            let start = src[comment_start..].find(name).unwrap() + comment_start;
+            (start, start + name.len())
+        };
+        let names: Vec<_> = names.iter().map(range).collect();
+        let redundant: Vec<_> = redundant.iter().map(range).collect();
+        let mut out = src.to_string();
+        let mut removals = redundant_directive_removals(
+            src.as_bytes(),
+            (comment_start, comment_end),
+            &names,
+            &redundant,
+        );
+        removals.sort_by(|a, b| b.cmp(a));
+        for (start, end) in removals {
+            out.replace_range(start..end, "");
+        }
+        out
+    }
+
+    #[test]
+    fn removes_whole_directive_when_all_names_are_redundant() {
+        assert_eq!(
+            removed(
+                "x = 1 # rubocop:disable A/B, C/D\ny\n",
+                &["A/B", "C/D"],
+                &["A/B", "C/D"]
+            ),
+            "x = 1\ny\n"
+        );
+        assert_eq!(
+            removed("a\n  # rubocop:disable A/B\n  b\n", &["A/B"], &["A/B"]),
+            "a\n  b\n"
+        );
+    }
+
+    #[test]
+    fn removes_only_redundant_names_from_a_list() {
+        let src = "x = 1 # rubocop:disable A/B, C/D, E/F -- reason\n";
+        let names = ["A/B", "C/D", "E/F"];
+        assert_eq!(
+            removed(src, &names, &["A/B"]),
+            "x = 1 # rubocop:disable C/D, E/F -- reason\n"
+        );
+        assert_eq!(
+            removed(src, &names, &["C/D"]),
+            "x = 1 # rubocop:disable A/B, E/F -- reason\n"
+        );
+        assert_eq!(
+            removed(src, &names, &["E/F"]),
+            "x = 1 # rubocop:disable A/B, C/D -- reason\n"
+        );
+    }
+
     // Full-pipeline tests for this cop live in tests/integration.rs because
     // they need the complete linter pipeline (all cops running + post-processing).
 }
@@ -37,12 +37,7 @@ impl Cop for RedundantDirGlobSort {
         // RuboCop: minimum_target_ruby_version 3.0
         // Dir.glob and Dir[] return sorted results in Ruby 3.0+, so `.sort` is
         // redundant only when targeting 3.0 or later.
-        let ruby_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-            .unwrap_or(3.4);
-        if ruby_version < 3.0 {
+        if !config.ruby_version_at_least(3.0) {
             return;
         }
         let call = match node.as_call_node() {
@@ -120,5 +115,33 @@ impl Cop for RedundantDirGlobSort {
 #[cfg(test)]
 mod tests {
     use super::*;
-    crate::cop_fixture_tests!(RedundantDirGlobSort, "cops/lint/redundant_dir_glob_sort");
+
+    fn ruby30_config() -> CopConfig {
+        let mut config = CopConfig::default();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::Number::from(3.0)),
+        );
+        config
+    }
+
+    #[test]
+    fn offense_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &RedundantDirGlobSort,
+            include_bytes!("../../../tests/fixtures/cops/lint/redundant_dir_glob_sort/offense.rb"),
+            ruby30_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &RedundantDirGlobSort,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/redundant_dir_glob_sort/no_offense.rb"
+            ),
+            ruby30_config(),
+        );
+    }
 }
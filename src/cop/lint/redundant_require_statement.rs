@@ -46,16 +46,6 @@ const RUBY_32_REDUNDANT: &[&[u8]] = &[b"set"];
 /// Features redundant since Ruby 4.0+.
 const RUBY_40_REDUNDANT: &[&[u8]] = &[b"pathname"];
 
-/// Get the target Ruby version from cop config, defaulting to 2.7
-/// (matching RuboCop's default when no version is specified).
-fn target_ruby_version(config: &CopConfig) -> f64 {
-    config
-        .options
-        .get("TargetRubyVersion")
-        .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-        .unwrap_or(2.7)
-}
-
 /// Check if a feature is redundant given the target Ruby version.
 fn is_redundant_feature(feature: &[u8], ruby_version: f64) -> bool {
     if ALWAYS_REDUNDANT.contains(&feature) {
@@ -140,7 +130,7 @@ impl Cop for RedundantRequireStatement {
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
-        let ruby_ver = target_ruby_version(config);
+        let ruby_ver = config.target_ruby_version();
 
         let mut visitor = RequireVisitor {
             cop: self,
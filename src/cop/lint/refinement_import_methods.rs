@@ -57,12 +57,7 @@ impl Cop for RefinementImportMethods {
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // RuboCop: minimum_target_ruby_version 3.1
-        let ruby_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-            .unwrap_or(2.7);
-        if ruby_version < 3.1 {
+        if !config.ruby_version_at_least(3.1) {
             return;
         }
 
@@ -147,14 +142,10 @@ impl RefineVisitor<'_, '_> {
 mod tests {
     use super::*;
     use crate::cop::CopConfig;
+    use crate::testutil::cop_config_from_yaml_with_targets;
 
     fn ruby31_config() -> CopConfig {
-        let mut config = CopConfig::default();
-        config.options.insert(
-            "TargetRubyVersion".to_string(),
-            serde_yml::Value::Number(serde_yml::Number::from(3.1)),
-        );
-        config
+        cop_config_from_yaml_with_targets("", Some(3.1), None)
     }
 
     #[test]
@@ -181,15 +172,10 @@ mod tests {
 
     #[test]
     fn no_offense_below_ruby31() {
-        let mut config = CopConfig::default();
-        config.options.insert(
-            "TargetRubyVersion".to_string(),
-            serde_yml::Value::Number(serde_yml::Number::from(3.0)),
-        );
         crate::testutil::assert_cop_no_offenses_full_with_config(
             &RefinementImportMethods,
             b"refine Foo do\n  include Bar\nend\n",
-            config,
+            cop_config_from_yaml_with_targets("", Some(3.0), None),
         );
     }
 }
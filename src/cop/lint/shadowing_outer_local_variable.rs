@@ -363,6 +363,14 @@ impl ShadowingContext {
 ///    (params + body) plus singleton-class body ranges, and suppress only when
 ///    the match crosses those boundaries.
 ///
+/// 5. **FP: `case ... in` had no branch context.** Only `if`/`unless` and
+///    `case/when` recorded branch intervals, so a block in one `in` clause was
+///    flagged for a variable bound by another clause's pattern, or by its own.
+///    RuboCop's `variable_node` steps from a `when` (and likewise an `in`
+///    clause) to the enclosing case, which then matches the case found above
+///    the binding. Fix: `case ... in` records the same intervals as
+///    `case/when`, with each `in` pattern treated as a `when` condition.
+///
 /// ## Migration to VariableForce
 ///
 /// This cop was migrated from a 1,857-line standalone AST visitor to use the shared
@@ -380,7 +388,8 @@ impl ShadowingContext {
 ///    - Same-conditional different-branch suppression (Check 1)
 ///    - Adjacent elsif suppression (Check 2)
 ///    - Same-conditional-node condition-assignment suppression (Check 3)
-///    - When-condition assignment suppression
+///    - When-condition assignment suppression (and `in` pattern bindings,
+///      which RuboCop treats the same way)
 ///    - Inherited conditional context through single-statement block chains
 ///    - Expression depth tracking for nested-in-expression detection
 ///    - Per-branch variable write tracking for sibling-branch assignment detection
@@ -862,6 +871,48 @@ impl ContextCollector {
             self.in_when_body_of_case = saved_body;
         }
     }
+
+    /// `in` clauses mirror `when` clauses: RuboCop's `variable_node` treats a
+    /// block directly in an `in` body like one in a `when` body, so pattern
+    /// bindings are recorded as condition ranges of the enclosing case.
+    fn visit_in_node_with_case_offset(
+        &mut self,
+        node: &ruby_prism::InNode<'_>,
+        case_offset: usize,
+    ) {
+        let pattern = node.pattern();
+        let start = pattern.location().start_offset();
+        let end = pattern.location().end_offset();
+        self.when_condition_ranges.push((start, end, case_offset));
+
+        let saved = self.when_condition_case_offset;
+        self.when_condition_case_offset = Some(case_offset);
+        let pattern_entry = CondBranchEntry {
+            cond_offset: case_offset,
+            branch_offset: node.location().start_offset(),
+            subsequent_offset: None,
+            is_body: false,
+            is_if_type: false,
+            single_stmt: false,
+            is_else_clause: false,
+            expression_depth_base: self.expression_depth,
+        };
+        self.push_branch(pattern_entry, start, end);
+        self.visit(&pattern);
+        self.pop_branch();
+        self.when_condition_case_offset = saved;
+
+        if let Some(stmts) = node.statements() {
+            let saved_body = self.in_when_body_of_case;
+            self.in_when_body_of_case = Some(case_offset);
+            let body_start = stmts.location().start_offset();
+            let body_end = stmts.location().end_offset();
+            self.when_body_ranges
+                .push((body_start, body_end, case_offset));
+            self.visit_statements_node(&stmts);
+            self.in_when_body_of_case = saved_body;
+        }
+    }
 }
 
 impl<'pr> Visit<'pr> for ContextCollector {
@@ -1338,6 +1389,75 @@ impl<'pr> Visit<'pr> for ContextCollector {
             self.pop_branch();
         }
     }
+
+    fn visit_case_match_node(&mut self, node: &ruby_prism::CaseMatchNode<'pr>) {
+        let case_offset = node.location().start_offset();
+
+        if let Some(pred) = node.predicate() {
+            let pred_start = pred.location().start_offset();
+            let pred_end = pred.location().end_offset();
+            let pred_entry = CondBranchEntry {
+                cond_offset: case_offset,
+                branch_offset: pred_start,
+                subsequent_offset: None,
+                is_body: false,
+                is_if_type: false,
+                single_stmt: true,
+                is_else_clause: false,
+                expression_depth_base: self.expression_depth,
+            };
+            self.push_branch(pred_entry, pred_start, pred_end);
+            self.visit(&pred);
+            self.pop_branch();
+        }
+
+        for condition in node.conditions().iter() {
+            let branch_offset = condition.location().start_offset();
+            let in_start = condition.location().start_offset();
+            let in_end = condition.location().end_offset();
+            let in_single_stmt = condition
+                .as_in_node()
+                .and_then(|i| i.statements())
+                .is_none_or(|s| s.body().len() <= 1);
+            let in_entry = CondBranchEntry {
+                cond_offset: case_offset,
+                branch_offset,
+                subsequent_offset: None,
+                is_body: true,
+                is_if_type: false,
+                single_stmt: in_single_stmt,
+                is_else_clause: false,
+                expression_depth_base: self.expression_depth,
+            };
+            self.push_branch(in_entry, in_start, in_end);
+            if let Some(in_node) = condition.as_in_node() {
+                self.visit_in_node_with_case_offset(&in_node, case_offset);
+            } else {
+                self.visit(&condition);
+            }
+            self.pop_branch();
+        }
+
+        if let Some(else_clause) = node.else_clause() {
+            let branch_offset = else_clause.location().start_offset();
+            let else_start = else_clause.location().start_offset();
+            let else_end = else_clause.location().end_offset();
+            let else_single_stmt = else_clause.statements().is_none_or(|s| s.body().len() <= 1);
+            let else_entry = CondBranchEntry {
+                cond_offset: case_offset,
+                branch_offset,
+                subsequent_offset: None,
+                is_body: true,
+                is_if_type: false,
+                single_stmt: else_single_stmt,
+                is_else_clause: true,
+                expression_depth_base: self.expression_depth,
+            };
+            self.push_branch(else_entry, else_start, else_end);
+            self.visit_else_node(&else_clause);
+            self.pop_branch();
+        }
+    }
 }
 
 /// Check if a CallNode is `Ractor.new(...)` or `::Ractor.new(...)`.
@@ -63,6 +63,19 @@ pub fn is_rubocop_reported_semantic_error(message: &str) -> bool {
 /// from the semantic filter in `emit_syntax_diagnostics`. Also added
 /// "Invalid return in class/module body" to `check_source` for files
 /// without structural errors. Resolved all 4 FN.
+///
+/// ## Parser warnings
+///
+/// With `ReportWarnings: true`, Prism's warnings (`found '= literal' in
+/// conditional`, duplicated hash keys, `END in method`, ...) are also reported,
+/// at `Warning` severity and at Prism's location. RuboCop only repacks parser
+/// errors, and several warnings overlap dedicated cops (Lint/AmbiguousOperator,
+/// Lint/Void, Lint/DuplicateHashKey, Lint/UselessAssignment), so this is off by
+/// default. Warnings are only reported for files without structural errors,
+/// which still short-circuit every other cop in the linter.
+///
+/// A configured `Severity:` replaces both the `Fatal` error severity and the
+/// `Warning` severity of parser warnings.
 pub struct Syntax;
 
 impl Cop for Syntax {
@@ -79,7 +92,7 @@ impl Cop for Syntax {
         source: &crate::parse::source::SourceFile,
         parse_result: &ruby_prism::ParseResult<'_>,
         _code_map: &crate::parse::codemap::CodeMap,
-        _config: &crate::cop::CopConfig,
+        config: &crate::cop::CopConfig,
         diagnostics: &mut Vec<crate::diagnostic::Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
@@ -91,6 +104,17 @@ impl Cop for Syntax {
             let (line, column) = source.offset_to_line_col(err.location().start_offset());
             diagnostics.push(self.diagnostic(source, line, column, err.message().to_string()));
         }
+
+        if !config.get_bool("ReportWarnings", false) {
+            return;
+        }
+        for warning in parse_result.warnings() {
+            let (line, column) = source.offset_to_line_col(warning.location().start_offset());
+            let mut diagnostic =
+                self.diagnostic(source, line, column, warning.message().to_string());
+            diagnostic.severity = Severity::Warning;
+            diagnostics.push(diagnostic);
+        }
     }
 
     // Syntax errors are reported by the parser (Prism), not by this cop.
@@ -104,6 +128,46 @@ mod tests {
 
     crate::cop_fixture_tests!(Syntax, "cops/lint/syntax");
 
+    const REPORT_WARNINGS: &str = "ReportWarnings: true";
+
+    #[test]
+    fn offense_report_warnings_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &Syntax,
+            REPORT_WARNINGS,
+            include_bytes!("../../../tests/fixtures/cops/lint/syntax/offense.report_warnings.rb"),
+        );
+    }
+
+    #[test]
+    fn no_offense_report_warnings_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &Syntax,
+            REPORT_WARNINGS,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/syntax/no_offense.report_warnings.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn warnings_are_ignored_by_default() {
+        let diags = crate::testutil::run_cop_full(&Syntax, b"puts 'yes' if 'yes'\n");
+        assert!(diags.is_empty(), "got {diags:?}");
+    }
+
+    #[test]
+    fn warnings_have_warning_severity() {
+        let diags = crate::testutil::run_cop_full_with_config(
+            &Syntax,
+            b"puts 'yes' if 'yes'\n",
+            crate::testutil::cop_config_from_yaml(REPORT_WARNINGS),
+        );
+        assert_eq!(diags.len(), 1, "got {diags:?}");
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert_eq!(diags[0].message, "string literal in condition");
+    }
+
     #[test]
     fn cop_name() {
         assert_eq!(Syntax.name(), "Lint/Syntax");
@@ -131,36 +195,59 @@ mod tests {
             only: vec!["Lint/Syntax".to_string()],
             except: vec![],
             no_color: false,
+            sort_severity: false,
             debug: false,
+            debug_json: false,
             rubocop_only: false,
             list_cops: false,
             list_autocorrectable_cops: false,
+            show_cops: None,
             migrate: false,
             doctor: false,
             rules: false,
             tier: None,
+            department: None,
+            history_file: None,
+            history_summary: false,
+            emit_schema: None,
+            validate_config: false,
             stdin: None,
             init: false,
             no_cache: false,
             cache: "true".to_string(),
             cache_clear: false,
+            paranoid: false,
             fail_level: "convention".to_string(),
+            display_only_fail_level_offenses: false,
+            max_reported_offenses: None,
             fail_fast: false,
             force_exclusion: false,
             list_target_files: false,
+            estimate: false,
+            preview_cop: Vec::new(),
+            show_config_chain: false,
             display_cop_names: false,
             parallel: false,
+            no_parallel: false,
             require_libs: vec![],
             ignore_disable_comments: false,
             force_default_config: false,
             autocorrect: false,
             autocorrect_all: false,
+            autocorrect_changed_lines: None,
+            autocorrect_cop: Vec::new(),
+            copy_to: None,
+            copy_all: false,
             preview: true,
+            erb: false,
+            markdown: false,
             quiet_skips: false,
             strict: None,
             verify: false,
             rubocop_cmd: "bundle exec rubocop".to_string(),
             corpus_check: None,
+            baseline: None,
+            baseline_generate: None,
         }
     }
 
@@ -190,6 +277,7 @@ mod tests {
             &base_configs,
             false,
             None,
+            None,
             &allowlist,
         );
         diags
@@ -77,6 +77,10 @@ use ruby_prism::Visit;
 ///   `private_class_method :foo` was treated as `Public == Public` (repeated/useless)
 ///   instead of a new visibility change. Fixed by using `Option<AccessKind>` for
 ///   `cur_vis` and setting it to `None` after `private_class_method` with args.
+/// - `ContextCreatingMethods` only matched receiverless calls. RuboCop's matcher is
+///   `(send {nil? const} :method ...)`, so `Concern.class_methods do` is a new scope
+///   too. Both the scope-boundary check and the block visit now share
+///   `is_context_creating_call`.
 pub struct UselessAccessModifier;
 
 impl Cop for UselessAccessModifier {
@@ -243,17 +247,29 @@ fn is_new_scope(node: &ruby_prism::Node<'_>, context_creating_methods: &[String]
                 }
             }
             // ContextCreatingMethods (e.g., class_methods from rubocop-rails)
-            if !context_creating_methods.is_empty() && call.receiver().is_none() {
-                let name_str = std::str::from_utf8(name).unwrap_or("");
-                if context_creating_methods.iter().any(|m| m == name_str) {
-                    return true;
-                }
+            if is_context_creating_call(&call, context_creating_methods) {
+                return true;
             }
         }
     }
     false
 }
 
+/// Check if a call is one of the configured ContextCreatingMethods, called bare
+/// or on a constant (RuboCop's `(send {nil? const} :method ...)`).
+fn is_context_creating_call(
+    call: &ruby_prism::CallNode<'_>,
+    context_creating_methods: &[String],
+) -> bool {
+    let receiver_ok = call.receiver().is_none_or(|recv| {
+        recv.as_constant_read_node().is_some() || recv.as_constant_path_node().is_some()
+    });
+    receiver_ok
+        && context_creating_methods
+            .iter()
+            .any(|m| m.as_bytes() == call.name().as_slice())
+}
+
 /// Check if a receiver node is Class, Module, Struct, or their ::prefixed variants.
 fn is_class_constructor_receiver(node: &ruby_prism::Node<'_>) -> bool {
     if let Some(const_read) = node.as_constant_read_node() {
@@ -768,12 +784,7 @@ impl<'pr> Visit<'pr> for UselessAccessVisitor<'_, '_> {
                         .as_ref()
                         .is_some_and(|r| is_class_constructor_receiver(r));
                 let is_context_scope =
-                    if !self.context_creating_methods.is_empty() && node.receiver().is_none() {
-                        let name_str = std::str::from_utf8(name).unwrap_or("");
-                        self.context_creating_methods.iter().any(|m| m == name_str)
-                    } else {
-                        false
-                    };
+                    is_context_creating_call(node, &self.context_creating_methods);
                 let should_check_scope = if self.in_def {
                     !is_eval_macro_scope && (is_constructor_scope || is_context_scope)
                 } else {
@@ -821,6 +832,42 @@ mod tests {
 
     crate::cop_fixture_tests!(UselessAccessModifier, "cops/lint/useless_access_modifier");
 
+    const CONFIGURED_YAML: &str = "ContextCreatingMethods:\n  - concerning\n  - class_methods\nMethodCreatingMethods:\n  - delegate\n  - def_delegator\n";
+
+    #[test]
+    fn offense_fixture_with_creating_methods() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &UselessAccessModifier,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/useless_access_modifier/offense.configured.rb"
+            ),
+            crate::testutil::cop_config_from_yaml(CONFIGURED_YAML),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture_with_creating_methods() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &UselessAccessModifier,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/useless_access_modifier/no_offense.configured.rb"
+            ),
+            crate::testutil::cop_config_from_yaml(CONFIGURED_YAML),
+        );
+    }
+
+    #[test]
+    fn creating_methods_are_ordinary_calls_without_config() {
+        // The configured no_offense fixture is exactly what the options fix.
+        let diagnostics = crate::testutil::run_cop_full(
+            &UselessAccessModifier,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/useless_access_modifier/no_offense.configured.rb"
+            ),
+        );
+        assert!(!diagnostics.is_empty());
+    }
+
     #[test]
     fn offense_in_included_block_with_context_creating_methods_config() {
         let fixture = b"module WithIncludedSingletonMethod\n  extend ActiveSupport::Concern\n\n  included do\n    private\n    ^^^^^^^ Lint/UselessAccessModifier: Useless `private` access modifier.\n\n    def self.singleton_method_added(method_name)\n      method_name\n    end\n  end\nend\n";
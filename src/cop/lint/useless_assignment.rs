@@ -6,6 +6,7 @@ use ruby_prism::Visit;
 use crate::cop::variable_force::engine::{Engine, RegisteredConsumer};
 use crate::cop::variable_force::{self, Scope, VariableTable};
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
@@ -37,6 +38,18 @@ use crate::parse::source::SourceFile;
 /// assignments for these captures, but they should never be reported as
 /// useless. Fixed by collecting all pattern match target offsets from the
 /// AST and skipping those offsets during offense emission.
+///
+/// ## Autocorrect
+///
+/// Only plain `x = value` writes are corrected; operator assignments,
+/// multiple-assignment targets, `rescue => e` and `for` variables are left
+/// alone. When the value has no side effects (literals, variables, constants)
+/// and the write is a statement of its own that isn't a body's last one, the
+/// whole line is removed. Otherwise the write is replaced by its value, so
+/// method calls still run and the enclosing expression keeps its value. The
+/// fix-point autocorrect loop then picks up assignments that only became
+/// useless because a later one was removed. Files with parse errors are not
+/// corrected.
 pub struct UselessAssignment;
 
 impl Cop for UselessAssignment {
@@ -48,6 +61,10 @@ impl Cop for UselessAssignment {
         Severity::Warning
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -55,7 +72,7 @@ impl Cop for UselessAssignment {
         _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let collector = PendingOffenseCollector::default();
         let consumers = [RegisteredConsumer {
@@ -69,6 +86,12 @@ impl Cop for UselessAssignment {
         let rescue_contexts = collect_multi_rescue_contexts(parse_result);
         let conditional_operator_offsets = collect_conditional_operator_write_offsets(parse_result);
         let pattern_match_offsets = collect_pattern_match_target_offsets(parse_result);
+        let writes = if corrections.is_some() && parse_result.errors().next().is_none() {
+            collect_local_writes(source, parse_result)
+        } else {
+            HashMap::new()
+        };
+        let mut new_corrections = Vec::new();
         let mut candidates = collector.take_candidates();
         candidates.sort_by_key(|candidate| candidate.node_offset);
 
@@ -90,7 +113,7 @@ impl Cop for UselessAssignment {
             }
 
             let (line, column) = source.offset_to_line_col(candidate.node_offset);
-            diagnostics.push(self.diagnostic(
+            let mut diagnostic = self.diagnostic(
                 source,
                 line,
                 column,
@@ -98,7 +121,22 @@ impl Cop for UselessAssignment {
                     "Useless assignment to variable - `{}`.",
                     String::from_utf8_lossy(&candidate.name)
                 ),
-            ));
+            );
+            if let Some(write) = writes.get(&candidate.node_offset) {
+                new_corrections.push(Correction {
+                    start: write.start,
+                    end: write.end,
+                    replacement: write.replacement.clone(),
+                    cop_name: self.name(),
+                    cop_index: 0,
+                });
+                diagnostic.corrected = true;
+            }
+            diagnostics.push(diagnostic);
+        }
+
+        if let Some(corrections) = corrections {
+            corrections.extend(new_corrections);
         }
     }
 }
@@ -468,8 +506,218 @@ impl<'pr> Visit<'pr> for PatternMatchTargetCollector {
     }
 }
 
+/// The correction for a `x = value` write, keyed by the write's offset.
+struct LocalWriteCorrection {
+    start: usize,
+    end: usize,
+    replacement: String,
+}
+
+fn collect_local_writes(
+    source: &SourceFile,
+    parse_result: &ruby_prism::ParseResult<'_>,
+) -> HashMap<usize, LocalWriteCorrection> {
+    let mut collector = LocalWriteCollector {
+        bytes: source.as_bytes(),
+        non_last_statements: HashSet::new(),
+        writes: HashMap::new(),
+    };
+    collector.visit(&parse_result.node());
+    collector.writes
+}
+
+struct LocalWriteCollector<'a> {
+    bytes: &'a [u8],
+    /// `(start, end)` of statements followed by another statement.
+    non_last_statements: HashSet<(usize, usize)>,
+    writes: HashMap<usize, LocalWriteCorrection>,
+}
+
+impl LocalWriteCollector<'_> {
+    /// The range of the lines spanned by `start..end`, including the final
+    /// newline, when nothing else is on those lines.
+    fn whole_lines(&self, start: usize, end: usize) -> Option<(usize, usize)> {
+        let line_start = self.bytes[..start]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |p| p + 1);
+        if !self.bytes[line_start..start]
+            .iter()
+            .all(|&b| b == b' ' || b == b'\t')
+        {
+            return None;
+        }
+        let rest = &self.bytes[end..];
+        let line_len = rest.iter().position(|&b| b == b'\n');
+        let line_end = line_len.map_or(self.bytes.len(), |p| end + p);
+        if !self.bytes[end..line_end]
+            .iter()
+            .all(|&b| b == b' ' || b == b'\t' || b == b'\r')
+        {
+            return None;
+        }
+        Some((line_start, line_len.map_or(line_end, |_| line_end + 1)))
+    }
+}
+
+impl<'pr> Visit<'pr> for LocalWriteCollector<'_> {
+    fn visit_statements_node(&mut self, node: &ruby_prism::StatementsNode<'pr>) {
+        let body = node.body();
+        let count = body.len();
+        for stmt in body.iter().take(count.saturating_sub(1)) {
+            let loc = stmt.location();
+            self.non_last_statements
+                .insert((loc.start_offset(), loc.end_offset()));
+        }
+        ruby_prism::visit_statements_node(self, node);
+    }
+
+    fn visit_local_variable_write_node(&mut self, node: &ruby_prism::LocalVariableWriteNode<'pr>) {
+        let loc = node.location();
+        let (start, end) = (loc.start_offset(), loc.end_offset());
+        let value = node.value();
+        let removable = self.non_last_statements.contains(&(start, end))
+            && is_side_effect_free(&value)
+            && !contains_heredoc(&value);
+        let whole_lines = if removable {
+            self.whole_lines(start, end)
+        } else {
+            None
+        };
+        let correction = match whole_lines {
+            Some((line_start, line_end)) => LocalWriteCorrection {
+                start: line_start,
+                end: line_end,
+                replacement: String::new(),
+            },
+            None => {
+                let value_loc = value.location();
+                LocalWriteCorrection {
+                    start,
+                    end,
+                    replacement: String::from_utf8_lossy(
+                        &self.bytes[value_loc.start_offset()..value_loc.end_offset()],
+                    )
+                    .into_owned(),
+                }
+            }
+        };
+        self.writes.insert(start, correction);
+        ruby_prism::visit_local_variable_write_node(self, node);
+    }
+}
+
+/// Whether evaluating a node can't have side effects: literals without
+/// interpolation, variables, constants, `self`, and arrays, hashes and
+/// ranges built from those.
+fn is_side_effect_free(node: &ruby_prism::Node<'_>) -> bool {
+    if let Some(array) = node.as_array_node() {
+        return array.elements().iter().all(|e| is_side_effect_free(&e));
+    }
+    if let Some(hash) = node.as_hash_node() {
+        return hash.elements().iter().all(|element| {
+            element.as_assoc_node().is_some_and(|assoc| {
+                is_side_effect_free(&assoc.key()) && is_side_effect_free(&assoc.value())
+            })
+        });
+    }
+    if let Some(range) = node.as_range_node() {
+        return range.left().is_none_or(|n| is_side_effect_free(&n))
+            && range.right().is_none_or(|n| is_side_effect_free(&n));
+    }
+    if let Some(parens) = node.as_parentheses_node() {
+        return parens.body().is_none_or(|body| {
+            body.as_statements_node()
+                .is_some_and(|stmts| stmts.body().iter().all(|stmt| is_side_effect_free(&stmt)))
+        });
+    }
+    node.as_true_node().is_some()
+        || node.as_false_node().is_some()
+        || node.as_nil_node().is_some()
+        || node.as_self_node().is_some()
+        || node.as_integer_node().is_some()
+        || node.as_float_node().is_some()
+        || node.as_rational_node().is_some()
+        || node.as_imaginary_node().is_some()
+        || node.as_string_node().is_some()
+        || node.as_symbol_node().is_some()
+        || node.as_regular_expression_node().is_some()
+        || node.as_local_variable_read_node().is_some()
+        || node.as_instance_variable_read_node().is_some()
+        || node.as_class_variable_read_node().is_some()
+        || node.as_global_variable_read_node().is_some()
+        || node.as_constant_read_node().is_some()
+        || node
+            .as_constant_path_node()
+            .is_some_and(|path| path.parent().is_none_or(|p| is_side_effect_free(&p)))
+}
+
+/// Whether a node contains a heredoc, whose body lives outside the node's
+/// own lines.
+fn contains_heredoc(node: &ruby_prism::Node<'_>) -> bool {
+    struct HeredocFinder {
+        found: bool,
+    }
+    impl<'pr> Visit<'pr> for HeredocFinder {
+        fn visit_string_node(&mut self, node: &ruby_prism::StringNode<'pr>) {
+            if node
+                .opening_loc()
+                .is_some_and(|loc| loc.as_slice().starts_with(b"<<"))
+            {
+                self.found = true;
+            }
+        }
+    }
+    let mut finder = HeredocFinder { found: false };
+    finder.visit(node);
+    finder.found
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(UselessAssignment, "cops/lint/useless_assignment");
+
+    #[test]
+    fn autocorrect_fixture() {
+        crate::testutil::assert_cop_autocorrect(
+            &UselessAssignment,
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/useless_assignment/offense.autocorrect.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/lint/useless_assignment/corrected.autocorrect.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_keeps_side_effects() {
+        // The call must still run, even in a position where a literal would
+        // be dropped.
+        crate::testutil::assert_cop_autocorrect(
+            &UselessAssignment,
+            b"def foo\n  x = bar.save!\n  y = [baz]\n  z = @a\n  done\nend\n",
+            b"def foo\n  bar.save!\n  [baz]\n  done\nend\n",
+        );
+    }
+
+    #[test]
+    fn autocorrect_nested_write_keeps_value() {
+        crate::testutil::assert_cop_autocorrect(
+            &UselessAssignment,
+            b"def foo\n  bar(x = 1)\nend\n",
+            b"def foo\n  bar(1)\nend\n",
+        );
+    }
+
+    #[test]
+    fn no_autocorrect_with_parse_errors() {
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect(
+            &UselessAssignment,
+            b"def foo\n  x = 1\n  bar(\nend\n",
+        );
+        assert!(corrections.is_empty());
+        assert!(diags.iter().all(|d| !d.corrected));
+    }
 }
@@ -24,9 +24,18 @@
 ///    which matches `"&block"` == `"&block"`. Nitrocop only checked
 ///    `LocalVariableReadNode` names, missing `BlockArgumentNode`. Fixed by
 ///    using source-text comparison for super args matching.
+///
+/// ## Autocorrect
+///
+/// Like RuboCop, the correction removes the definition, together with an
+/// access modifier wrapping it (`private def foo; super; end`). It is unsafe
+/// for the same reason the cop is: the definition may exist to change the
+/// method's visibility. Only definitions standing alone on their lines are
+/// removed, whole lines at a time.
 use ruby_prism::Visit;
 
 use crate::cop::shared::access_modifier_predicates;
+use crate::cop::shared::util;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
@@ -42,6 +51,14 @@ impl Cop for UselessMethodDefinition {
         Severity::Warning
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -49,16 +66,22 @@ impl Cop for UselessMethodDefinition {
         _code_map: &crate::parse::codemap::CodeMap,
         _config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let mut visitor = UselessMethodVisitor {
             cop: self,
             source,
             diagnostics: Vec::new(),
+            autocorrect: corrections.is_some(),
+            corrections: Vec::new(),
             inside_non_access_modifier_call: false,
+            access_modifier_call: None,
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let Some(corrections) = corrections {
+            corrections.extend(visitor.corrections);
+        }
     }
 }
 
@@ -66,10 +89,15 @@ struct UselessMethodVisitor<'a, 'src> {
     cop: &'a UselessMethodDefinition,
     source: &'src SourceFile,
     diagnostics: Vec<Diagnostic>,
+    autocorrect: bool,
+    corrections: Vec<crate::correction::Correction>,
     /// True when we are inside a CallNode that is NOT an access modifier
     /// (e.g., `memoize def foo; super; end`). DefNodes in this context should
     /// not be flagged.
     inside_non_access_modifier_call: bool,
+    /// The `private def ...` call whose def argument is being visited; the
+    /// correction removes the whole call.
+    access_modifier_call: Option<(usize, usize)>,
 }
 
 impl<'pr> Visit<'pr> for UselessMethodVisitor<'_, '_> {
@@ -89,6 +117,13 @@ impl<'pr> Visit<'pr> for UselessMethodVisitor<'_, '_> {
             self.inside_non_access_modifier_call = true;
             ruby_prism::visit_call_node(self, node);
             self.inside_non_access_modifier_call = prev;
+        } else if has_def_argument(node) {
+            let loc = node.location();
+            let prev = self
+                .access_modifier_call
+                .replace((loc.start_offset(), loc.end_offset()));
+            ruby_prism::visit_call_node(self, node);
+            self.access_modifier_call = prev;
         } else {
             ruby_prism::visit_call_node(self, node);
         }
@@ -96,7 +131,10 @@ impl<'pr> Visit<'pr> for UselessMethodVisitor<'_, '_> {
 
     fn visit_def_node(&mut self, node: &ruby_prism::DefNode<'pr>) {
         self.check_def(node);
+        // A def nested in this one is not wrapped by the modifier call.
+        let prev = self.access_modifier_call.take();
         ruby_prism::visit_def_node(self, node);
+        self.access_modifier_call = prev;
     }
 }
 
@@ -169,12 +207,30 @@ impl UselessMethodVisitor<'_, '_> {
     fn report(&mut self, def_node: &ruby_prism::DefNode<'_>) {
         let loc = def_node.location();
         let (line, column) = self.source.offset_to_line_col(loc.start_offset());
-        self.diagnostics.push(self.cop.diagnostic(
+        let mut diag = self.cop.diagnostic(
             self.source,
             line,
             column,
             "Useless method definition detected. The method just delegates to `super`.".to_string(),
-        ));
+        );
+        if self.autocorrect {
+            let (start, end) = self
+                .access_modifier_call
+                .unwrap_or((loc.start_offset(), loc.end_offset()));
+            if util::stands_alone(self.source, start, end) {
+                let (first, _) = self.source.offset_to_line_col(start);
+                let (last, _) = self.source.offset_to_line_col(end.saturating_sub(1));
+                self.corrections
+                    .push(crate::correction::Correction::delete_lines(
+                        self.source,
+                        first,
+                        last,
+                        self.cop.name(),
+                    ));
+                diag.corrected = true;
+            }
+        }
+        self.diagnostics.push(diag);
     }
 }
 
@@ -288,4 +344,17 @@ mod tests {
         UselessMethodDefinition,
         "cops/lint/useless_method_definition"
     );
+    crate::cop_autocorrect_fixture_tests!(
+        UselessMethodDefinition,
+        "cops/lint/useless_method_definition"
+    );
+
+    #[test]
+    fn no_correction_when_sharing_a_line() {
+        let source = b"class Foo\n  def bar; super; end; def baz; 1; end\nend\n";
+        let (diags, corrections) =
+            crate::testutil::run_cop_autocorrect(&UselessMethodDefinition, source);
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty());
+    }
 }
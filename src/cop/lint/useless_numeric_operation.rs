@@ -19,6 +19,9 @@ use crate::parse::source::SourceFile;
 /// no arguments or block. RuboCop's `(call nil? $_)` only matches bare method
 /// calls without arguments (e.g., `x`), not `Complex(1, 2)` or `foo(arg)`.
 /// Fix: added `arguments().is_none() && block().is_none()` to the receiver check.
+///
+/// Autocorrect follows RuboCop: `x + 0` becomes `x`, and `x += 0` becomes
+/// `x = x`.
 pub struct UselessNumericOperation;
 
 const MSG: &str = "Do not apply inconsequential numeric operations to variables.";
@@ -32,6 +35,10 @@ impl Cop for UselessNumericOperation {
         Severity::Warning
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[CALL_NODE, INTEGER_NODE, LOCAL_VARIABLE_OPERATOR_WRITE_NODE]
     }
@@ -43,7 +50,7 @@ impl Cop for UselessNumericOperation {
         _parse_result: &ruby_prism::ParseResult<'_>,
         _config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        mut corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // Check for binary operator calls: x + 0, x - 0, x * 1, x / 1, x ** 1
         // RuboCop only matches `(call (call nil? $_) $_ (int $_))`, meaning the
@@ -94,7 +101,21 @@ impl Cop for UselessNumericOperation {
             if is_useless {
                 let loc = call.location();
                 let (line, column) = source.offset_to_line_col(loc.start_offset());
-                diagnostics.push(self.diagnostic(source, line, column, MSG.to_string()));
+                let mut diag = self.diagnostic(source, line, column, MSG.to_string());
+                if let Some(ref mut corr) = corrections {
+                    let recv_loc = recv.location();
+                    corr.push(crate::correction::Correction {
+                        start: loc.start_offset(),
+                        end: loc.end_offset(),
+                        replacement: source
+                            .byte_slice(recv_loc.start_offset(), recv_loc.end_offset(), "")
+                            .to_string(),
+                        cop_name: self.name(),
+                        cop_index: 0,
+                    });
+                    diag.corrected = true;
+                }
+                diagnostics.push(diag);
             }
         }
 
@@ -112,7 +133,19 @@ impl Cop for UselessNumericOperation {
             if is_useless {
                 let loc = op_assign.location();
                 let (line, column) = source.offset_to_line_col(loc.start_offset());
-                diagnostics.push(self.diagnostic(source, line, column, MSG.to_string()));
+                let mut diag = self.diagnostic(source, line, column, MSG.to_string());
+                if let Some(ref mut corr) = corrections {
+                    let name = String::from_utf8_lossy(op_assign.name().as_slice());
+                    corr.push(crate::correction::Correction {
+                        start: loc.start_offset(),
+                        end: loc.end_offset(),
+                        replacement: format!("{name} = {name}"),
+                        cop_name: self.name(),
+                        cop_index: 0,
+                    });
+                    diag.corrected = true;
+                }
+                diagnostics.push(diag);
             }
         }
     }
@@ -143,4 +176,8 @@ mod tests {
         UselessNumericOperation,
         "cops/lint/useless_numeric_operation"
     );
+    crate::cop_autocorrect_fixture_tests!(
+        UselessNumericOperation,
+        "cops/lint/useless_numeric_operation"
+    );
 }
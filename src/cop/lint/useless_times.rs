@@ -1,5 +1,9 @@
+use ruby_prism::Visit;
+
 use crate::cop::shared::node_type::{CALL_NODE, INTEGER_NODE};
+use crate::cop::shared::util;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
@@ -12,6 +16,14 @@ use crate::parse::source::SourceFile;
 /// `(send (int $_) :times (block-pass (sym $_))?)` — block-pass with a symbol is
 /// flagged, but block-pass with a local variable is not. Fixed by checking whether
 /// the block argument's expression is a SymbolNode; non-symbol block args skip.
+///
+/// ## Autocorrect
+///
+/// Follows RuboCop: `0.times` calls and empty blocks are deleted,
+/// `1.times { |i| ... }` is replaced by its body with `i` read as `0`, and
+/// `1.times(&:foo)` becomes `foo`. Only statements standing alone on their
+/// lines are corrected. Unlike RuboCop, blocks that assign locals are left
+/// alone, since unwrapping them would leak the locals into the enclosing scope.
 pub struct UselessTimes;
 
 impl Cop for UselessTimes {
@@ -23,6 +35,15 @@ impl Cop for UselessTimes {
         Severity::Warning
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    /// `times` returns its receiver, which the unwrapped body does not.
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[CALL_NODE, INTEGER_NODE]
     }
@@ -31,10 +52,10 @@ impl Cop for UselessTimes {
         &self,
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
-        _parse_result: &ruby_prism::ParseResult<'_>,
+        parse_result: &ruby_prism::ParseResult<'_>,
         _config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // Look for `N.times` where N is 0, 1, or negative
         let call = match node.as_call_node() {
@@ -88,32 +109,244 @@ impl Cop for UselessTimes {
             "N",
         );
 
-        // Report on the call up to the `.times` part (excluding any block or chained methods)
-        // Find the end of `.times`
-        let msg_loc = match call.message_loc() {
-            Some(loc) => loc,
-            None => return,
-        };
-
-        let start = call.location().start_offset();
-        let _end = msg_loc.end_offset();
-        let (line, column) = source.offset_to_line_col(start);
-
-        // If the call has a block, include it in the range
-        let report_end = call.location().end_offset();
-
-        // Use the full call range for the offense
-        let _full_src = &source.as_bytes()[start..report_end];
+        if call.message_loc().is_none() {
+            return;
+        }
 
-        diagnostics.push(self.diagnostic(
+        let loc = call.location();
+        let (line, column) = source.offset_to_line_col(loc.start_offset());
+        let mut diag = self.diagnostic(
             source,
             line,
             column,
             format!("Useless call to `{}.times` detected.", recv_text),
-        ));
+        );
+        if let Some(corrections) = corrections {
+            if let Some(correction) = self.correction(source, parse_result, &call, value) {
+                corrections.push(correction);
+                diag.corrected = true;
+            }
+        }
+        diagnostics.push(diag);
+    }
+}
+
+impl UselessTimes {
+    /// RuboCop's correction: drop `0.times`/empty blocks, unwrap the body of
+    /// `1.times { ... }`, and call the method of `1.times(&:name)` directly.
+    fn correction(
+        &self,
+        source: &SourceFile,
+        parse_result: &ruby_prism::ParseResult<'_>,
+        call: &ruby_prism::CallNode<'_>,
+        count: i64,
+    ) -> Option<Correction> {
+        let loc = call.location();
+        let (start, end) = (loc.start_offset(), loc.end_offset());
+        // Only a statement on its own lines: `x = 1.times { ... }` and
+        // `1.times { ... }.tap { ... }` use the return value.
+        if !util::stands_alone(source, start, end) || !util::is_statement(parse_result, start, end)
+        {
+            return None;
+        }
+        let block = call.block();
+        let block_node = block.as_ref().and_then(|b| b.as_block_node());
+        let empty_block = block_node.as_ref().is_some_and(|b| b.body().is_none());
+        if count < 1 || empty_block {
+            let (first, _) = source.offset_to_line_col(start);
+            let (last, _) = source.offset_to_line_col(end.saturating_sub(1));
+            return Some(Correction::delete_lines(source, first, last, self.name()));
+        }
+        let replacement = match (block, block_node) {
+            (_, Some(block_node)) => {
+                unwrapped_block_body(source, &block_node, column_of(source, start))?
+            }
+            (Some(block_arg), None) => {
+                let symbol = block_arg.as_block_argument_node()?.expression()?;
+                let name = symbol.as_symbol_node()?.unescaped().to_vec();
+                is_callable_name(&name).then(|| String::from_utf8_lossy(&name).into_owned())?
+            }
+            (None, None) => return None,
+        };
+        Some(Correction {
+            start,
+            end,
+            replacement,
+            cop_name: self.name(),
+            cop_index: 0,
+        })
     }
 }
 
+fn column_of(source: &SourceFile, offset: usize) -> usize {
+    source.offset_to_line_col(offset).1
+}
+
+/// The body of `1.times { |i| ... }` with `i` replaced by `0` and the
+/// block's extra indentation removed, or `None` when unwrapping would change
+/// meaning: `rescue`/`ensure` in the block, numbered or multiple parameters,
+/// a reassigned parameter, or locals that would leak into the outer scope.
+fn unwrapped_block_body(
+    source: &SourceFile,
+    block: &ruby_prism::BlockNode<'_>,
+    call_column: usize,
+) -> Option<String> {
+    let body = block.body()?.as_statements_node()?;
+    let param = match block.parameters() {
+        None => None,
+        Some(params) => {
+            let params = params.as_block_parameters_node()?;
+            if params.locals().iter().next().is_some() {
+                return None;
+            }
+            let params = params.parameters()?;
+            let mut requireds = params.requireds().iter();
+            let name = requireds.next()?.as_required_parameter_node()?.name();
+            if requireds.next().is_some()
+                || !params.optionals().is_empty()
+                || params.rest().is_some()
+                || !params.posts().is_empty()
+                || !params.keywords().is_empty()
+                || params.keyword_rest().is_some()
+                || params.block().is_some()
+            {
+                return None;
+            }
+            Some(name.as_slice().to_vec())
+        }
+    };
+    // Any local besides the parameter was assigned in the block.
+    if block
+        .locals()
+        .iter()
+        .any(|local| Some(local.as_slice()) != param.as_deref())
+    {
+        return None;
+    }
+
+    let body_loc = body.location();
+    let mut finder = ParamReadFinder {
+        name: param.as_deref().unwrap_or_default(),
+        depth: 0,
+        reads: Vec::new(),
+        reassigned: false,
+    };
+    if param.is_some() {
+        finder.visit_statements_node(&body);
+        if finder.reassigned {
+            return None;
+        }
+    }
+    finder.reads.sort_unstable();
+    let bytes = source.as_bytes();
+    let mut text = Vec::new();
+    let mut pos = body_loc.start_offset();
+    for (read_start, read_end) in finder.reads {
+        text.extend_from_slice(&bytes[pos..read_start]);
+        text.push(b'0');
+        pos = read_end;
+    }
+    text.extend_from_slice(&bytes[pos..body_loc.end_offset()]);
+
+    let text = String::from_utf8(text).ok()?;
+    let dedent = column_of(source, body_loc.start_offset()).saturating_sub(call_column);
+    let mut lines = text.split('\n');
+    let mut unwrapped = lines.next().unwrap_or_default().to_string();
+    for line in lines {
+        let indent = line
+            .bytes()
+            .take(dedent)
+            .take_while(|&b| b == b' ' || b == b'\t')
+            .count();
+        unwrapped.push('\n');
+        unwrapped.push_str(&line[indent..]);
+    }
+    Some(unwrapped)
+}
+
+/// Reads of the block parameter `name`, and whether it is ever assigned.
+struct ParamReadFinder<'n> {
+    name: &'n [u8],
+    /// Blocks and lambdas entered below the `times` block.
+    depth: u32,
+    reads: Vec<(usize, usize)>,
+    reassigned: bool,
+}
+
+impl<'pr> Visit<'pr> for ParamReadFinder<'_> {
+    fn visit_local_variable_read_node(&mut self, node: &ruby_prism::LocalVariableReadNode<'pr>) {
+        if node.name().as_slice() == self.name && node.depth() == self.depth {
+            let loc = node.location();
+            self.reads.push((loc.start_offset(), loc.end_offset()));
+        }
+    }
+
+    fn visit_local_variable_write_node(&mut self, node: &ruby_prism::LocalVariableWriteNode<'pr>) {
+        self.reassigned |= node.name().as_slice() == self.name && node.depth() == self.depth;
+        ruby_prism::visit_local_variable_write_node(self, node);
+    }
+
+    fn visit_local_variable_operator_write_node(
+        &mut self,
+        node: &ruby_prism::LocalVariableOperatorWriteNode<'pr>,
+    ) {
+        self.reassigned |= node.name().as_slice() == self.name && node.depth() == self.depth;
+        ruby_prism::visit_local_variable_operator_write_node(self, node);
+    }
+
+    fn visit_local_variable_or_write_node(
+        &mut self,
+        node: &ruby_prism::LocalVariableOrWriteNode<'pr>,
+    ) {
+        self.reassigned |= node.name().as_slice() == self.name && node.depth() == self.depth;
+        ruby_prism::visit_local_variable_or_write_node(self, node);
+    }
+
+    fn visit_local_variable_and_write_node(
+        &mut self,
+        node: &ruby_prism::LocalVariableAndWriteNode<'pr>,
+    ) {
+        self.reassigned |= node.name().as_slice() == self.name && node.depth() == self.depth;
+        ruby_prism::visit_local_variable_and_write_node(self, node);
+    }
+
+    fn visit_local_variable_target_node(
+        &mut self,
+        node: &ruby_prism::LocalVariableTargetNode<'pr>,
+    ) {
+        self.reassigned |= node.name().as_slice() == self.name && node.depth() == self.depth;
+    }
+
+    fn visit_block_node(&mut self, node: &ruby_prism::BlockNode<'pr>) {
+        self.depth += 1;
+        ruby_prism::visit_block_node(self, node);
+        self.depth -= 1;
+    }
+
+    fn visit_lambda_node(&mut self, node: &ruby_prism::LambdaNode<'pr>) {
+        self.depth += 1;
+        ruby_prism::visit_lambda_node(self, node);
+        self.depth -= 1;
+    }
+
+    // New scopes cannot see the parameter.
+    fn visit_def_node(&mut self, _node: &ruby_prism::DefNode<'pr>) {}
+    fn visit_class_node(&mut self, _node: &ruby_prism::ClassNode<'pr>) {}
+    fn visit_module_node(&mut self, _node: &ruby_prism::ModuleNode<'pr>) {}
+    fn visit_singleton_class_node(&mut self, _node: &ruby_prism::SingletonClassNode<'pr>) {}
+}
+
+/// A symbol a bare call can name: `foo`, `foo?` or `foo!`.
+fn is_callable_name(name: &[u8]) -> bool {
+    let stem = name
+        .strip_suffix(b"?")
+        .or_else(|| name.strip_suffix(b"!"))
+        .unwrap_or(name);
+    stem.first()
+        .is_some_and(|&b| b == b'_' || b.is_ascii_lowercase())
+        && stem.iter().all(|&b| b == b'_' || b.is_ascii_alphanumeric())
+}
+
 /// Extract the integer value from a node (handling negatives).
 fn get_integer_value(node: &ruby_prism::Node<'_>, source: &SourceFile) -> Option<i64> {
     if let Some(int_node) = node.as_integer_node() {
@@ -143,4 +376,24 @@ fn get_integer_value(node: &ruby_prism::Node<'_>, source: &SourceFile) -> Option
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(UselessTimes, "cops/lint/useless_times");
+    crate::cop_autocorrect_fixture_tests!(UselessTimes, "cops/lint/useless_times");
+
+    #[test]
+    fn no_correction_for_chained_or_nested_calls() {
+        for source in [
+            &b"1.times { work }.then { done }\n"[..],
+            b"run(\n  1.times { work }\n)\n",
+            b"1.times { |i; tmp| tmp = i }\n",
+            b"1.times { puts _1 }\n",
+            b"1.times do\n  work\nrescue StandardError\n  nil\nend\n",
+        ] {
+            let (diags, corrections) = crate::testutil::run_cop_autocorrect(&UselessTimes, source);
+            assert_eq!(diags.len(), 1, "{}", String::from_utf8_lossy(source));
+            assert!(
+                corrections.is_empty(),
+                "{}",
+                String::from_utf8_lossy(source)
+            );
+        }
+    }
 }
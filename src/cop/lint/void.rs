@@ -172,6 +172,20 @@ use ruby_prism::Visit;
 /// them as missing because non-default-config runs against that cloned repo fail
 /// early with `No lockfile found ...`, so the remaining mismatch is in
 /// config/root resolution rather than `Lint/Void` detection.
+///
+/// ## Investigation findings (2026-10-16)
+///
+/// **FN: explicit `begin` as a void-context body** — `def initialize; begin;
+/// ...; end; end`, `tap do begin ... end end`, and the same inside `for` and
+/// `ensure`: Parser's `kwbegin` has the def/block/for/ensure as its parent, so
+/// `in_void_context?` is true and its last expression is checked. We only
+/// checked the non-last statements. Fixed via `visit_void_context_stmt`;
+/// `begin` with rescue/else/ensure is excluded (Parser wraps those statements
+/// in a `rescue`/`ensure` node).
+///
+/// **FN/FP: `CheckForMethodsWithNoSideEffects` gaps** — nonmutating methods
+/// were not unwrapped through `if`/`unless` bodies (`x.sort if y`), and
+/// `x&.sort` was flagged although RuboCop skips safe-navigation sends.
 pub struct Void;
 
 impl Cop for Void {
@@ -340,6 +354,8 @@ impl VoidVisitor<'_, '_> {
                         column,
                         "Void value expression detected.".to_string(),
                     ));
+                } else if self.check_methods {
+                    self.check_nonmutating(inner);
                 }
             }
         }
@@ -350,13 +366,12 @@ impl VoidVisitor<'_, '_> {
     fn check_nonmutating(&mut self, stmt: &ruby_prism::Node<'_>) {
         // Get the method name from a call node (either direct send or block with send)
         let (method_name, loc) = if let Some(call) = stmt.as_call_node() {
-            // Direct send: `x.sort`
-            if call.block().is_some() {
-                // Block call: `[1,2,3].collect do |n| ... end`
-                (call.name().as_slice(), stmt.location())
-            } else {
-                (call.name().as_slice(), stmt.location())
+            // `x&.sort` is skipped (RuboCop's `safe_navigation?` guard), but a
+            // block call `x&.map { }` is a block node in Parser and still flagged.
+            if call.block().is_none() && call.is_safe_navigation() {
+                return;
             }
+            (call.name().as_slice(), stmt.location())
         } else {
             return;
         };
@@ -377,6 +392,22 @@ impl VoidVisitor<'_, '_> {
         ));
     }
 
+    /// Visit the sole statement of a void-context body. An explicit
+    /// `begin ... end` there is itself in void context (Parser's `kwbegin`
+    /// whose parent is the def/block/for/ensure), so its last expression is
+    /// checked too.
+    fn visit_void_context_stmt(&mut self, stmt: &ruby_prism::Node<'_>) {
+        if let Some(stmts) = plain_begin_statements(stmt) {
+            let body: Vec<_> = stmts.body().iter().collect();
+            self.check_statements(&body, true);
+            for inner in &body {
+                self.visit(inner);
+            }
+        } else {
+            self.visit(stmt);
+        }
+    }
+
     /// Check statements in a body, optionally including the last expression
     /// (when in void context).
     fn check_statements(&mut self, body: &[ruby_prism::Node<'_>], void_context: bool) {
@@ -396,6 +427,23 @@ impl VoidVisitor<'_, '_> {
     }
 }
 
+/// Statements of an explicit `begin ... end` with no rescue/else/ensure
+/// clauses. With any of those, Parser wraps the statements in a `rescue` or
+/// `ensure` node, which is not a void context.
+fn plain_begin_statements<'pr>(
+    node: &ruby_prism::Node<'pr>,
+) -> Option<ruby_prism::StatementsNode<'pr>> {
+    let begin = node.as_begin_node()?;
+    begin.begin_keyword_loc()?;
+    if begin.rescue_clause().is_some()
+        || begin.else_clause().is_some()
+        || begin.ensure_clause().is_some()
+    {
+        return None;
+    }
+    begin.statements()
+}
+
 /// Check if a node is an `each` or `tap` method call (for void context detection).
 fn is_void_context_method(call: &ruby_prism::CallNode<'_>) -> bool {
     let name = call.name().as_slice();
@@ -693,11 +741,13 @@ impl<'pr> Visit<'pr> for VoidVisitor<'_, '_> {
                     if body_stmts.len() > 1 {
                         // Multi-statement: check all including last (void context)
                         self.check_statements(&body_stmts, true);
-                    }
-                    // Visit children but don't re-check via visit_statements_node
-                    // We need to visit into child nodes for nested blocks, etc.
-                    for stmt in &body_stmts {
-                        self.visit(stmt);
+                        // Visit children but don't re-check via visit_statements_node
+                        // We need to visit into child nodes for nested blocks, etc.
+                        for stmt in &body_stmts {
+                            self.visit(stmt);
+                        }
+                    } else if let Some(stmt) = body_stmts.first() {
+                        self.visit_void_context_stmt(stmt);
                     }
                     return;
                 }
@@ -726,8 +776,12 @@ impl<'pr> Visit<'pr> for VoidVisitor<'_, '_> {
                             let body_stmts: Vec<_> = stmts.body().iter().collect();
                             // Void context: check all including last
                             self.check_statements(&body_stmts, true);
-                            for stmt in &body_stmts {
-                                self.visit(stmt);
+                            if let [stmt] = body_stmts.as_slice() {
+                                self.visit_void_context_stmt(stmt);
+                            } else {
+                                for stmt in &body_stmts {
+                                    self.visit(stmt);
+                                }
                             }
                         } else {
                             // Single expression block body — check it (void context)
@@ -778,9 +832,11 @@ impl<'pr> Visit<'pr> for VoidVisitor<'_, '_> {
             let body: Vec<_> = stmts.body().iter().collect();
             if body.len() > 1 {
                 self.check_statements(&body, true);
-            }
-            for stmt in &body {
-                self.visit(stmt);
+                for stmt in &body {
+                    self.visit(stmt);
+                }
+            } else if let Some(stmt) = body.first() {
+                self.visit_void_context_stmt(stmt);
             }
         }
         // Visit collection
@@ -800,6 +856,8 @@ impl<'pr> Visit<'pr> for VoidVisitor<'_, '_> {
             } else if body.len() == 1 {
                 // Single expression: check only non-operators (matches RuboCop)
                 self.check_void_expression_no_op(&body[0]);
+                self.visit_void_context_stmt(&body[0]);
+                return;
             }
             for stmt in &body {
                 self.visit(stmt);
@@ -886,4 +944,34 @@ mod tests {
             config_with_check_methods(),
         );
     }
+
+    #[test]
+    fn test_check_methods_through_conditional() {
+        let source = b"x.sort if y\n^^^^^^ Lint/Void: Void value expression detected.\ntop(x)\n";
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &Void,
+            source,
+            config_with_check_methods(),
+        );
+    }
+
+    #[test]
+    fn test_check_methods_skips_safe_navigation() {
+        let source = b"x&.sort\ntop(x)\n";
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &Void,
+            source,
+            config_with_check_methods(),
+        );
+    }
+
+    #[test]
+    fn test_check_methods_in_void_begin_body() {
+        let source = b"def initialize\n  begin\n    @a = 1\n    @a.merge(b)\n    ^^^^^^^^^^^ Lint/Void: Void value expression detected.\n  end\nend\n";
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &Void,
+            source,
+            config_with_check_methods(),
+        );
+    }
 }
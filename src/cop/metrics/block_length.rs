@@ -1,3 +1,6 @@
+use crate::cop::shared::allowed_methods::{
+    allowed_methods, allowed_patterns, matches_allowed_pattern,
+};
 use crate::cop::shared::node_type::{CALL_NODE, FORWARDING_SUPER_NODE, LAMBDA_NODE, SUPER_NODE};
 use crate::cop::shared::util::{
     collect_foldable_ranges, collect_heredoc_ranges, count_body_lines_ex,
@@ -204,6 +207,13 @@ use crate::parse::source::SourceFile;
 /// verify_cop_locations.py: FP 0 fixed / 1 remain, FN 52 fixed / 0 remain.
 /// All FN verified fixed. Remaining FP=1: stackneveroverflow (vendored
 /// rails_admin gem). No cop-level fix needed.
+///
+/// ## Allowed methods
+///
+/// `AllowedMethods` entries match the block's method name with any receiver
+/// (`describe`), or with a specific receiver (`RSpec.describe`).
+/// `AllowedPatterns` match the method name. The deprecated `ExcludedMethods`
+/// and `IgnoredMethods` keys are read as additions to `AllowedMethods`.
 pub struct BlockLength;
 
 impl Cop for BlockLength {
@@ -243,8 +253,15 @@ impl Cop for BlockLength {
             if is_class_constructor(&call_node) {
                 return;
             }
+            // RuboCop compares the receiver's source with whitespace removed.
+            let receiver = call_node.receiver().map(|receiver| {
+                String::from_utf8_lossy(receiver.location().as_slice())
+                    .split_whitespace()
+                    .collect::<String>()
+            });
             self.check_invocation_block(
                 source,
+                receiver.as_deref(),
                 std::str::from_utf8(call_node.name().as_slice()).unwrap_or(""),
                 call_node.location().start_offset(),
                 &block_node,
@@ -261,6 +278,7 @@ impl Cop for BlockLength {
             };
             self.check_invocation_block(
                 source,
+                None,
                 "super",
                 super_node.location().start_offset(),
                 &block_node,
@@ -277,6 +295,7 @@ impl Cop for BlockLength {
             };
             self.check_invocation_block(
                 source,
+                None,
                 "super",
                 forwarding_super_node.location().start_offset(),
                 &block_node,
@@ -291,6 +310,7 @@ impl BlockLength {
     fn check_invocation_block(
         &self,
         source: &SourceFile,
+        receiver: Option<&str>,
         method_name: &str,
         offense_offset: usize,
         block_node: &ruby_prism::BlockNode<'_>,
@@ -300,22 +320,13 @@ impl BlockLength {
         let max = config.get_usize("Max", 25);
         let count_comments = config.get_bool("CountComments", false);
         let count_as_one = config.get_string_array("CountAsOne");
-        let allowed_methods = config.get_string_array("AllowedMethods");
-        let allowed_patterns = config.get_string_array("AllowedPatterns");
 
-        if let Some(allowed) = &allowed_methods {
-            if allowed.iter().any(|m| m == method_name) {
-                return;
-            }
-        }
-        if let Some(patterns) = &allowed_patterns {
-            for pat in patterns {
-                if let Ok(re) = regex::Regex::new(pat) {
-                    if re.is_match(method_name) {
-                        return;
-                    }
-                }
-            }
+        if allowed_methods(config)
+            .iter()
+            .any(|allowed| method_receiver_allowed(allowed, receiver, method_name))
+            || matches_allowed_pattern(&allowed_patterns(config), method_name)
+        {
+            return;
         }
 
         let end_offset = block_node.closing_loc().start_offset();
@@ -686,6 +697,18 @@ fn is_heredoc_node(source: &SourceFile, node: &ruby_prism::Node<'_>) -> bool {
     false
 }
 
+/// Whether an `AllowedMethods` entry matches a block's send, mirroring
+/// RuboCop's `method_receiver_excluded?`: `describe` matches the method with
+/// any receiver, while `RSpec.describe` also requires the receiver `RSpec`.
+fn method_receiver_allowed(allowed: &str, receiver: Option<&str>, method_name: &str) -> bool {
+    match allowed.split_once('.') {
+        Some((allowed_receiver, allowed_method)) => {
+            allowed_method == method_name && receiver == Some(allowed_receiver)
+        }
+        None => allowed == method_name,
+    }
+}
+
 /// Check if a call is a class constructor like `Struct.new`, `Class.new`, `Module.new`, etc.
 /// RuboCop's Metrics/BlockLength does not count these blocks.
 ///
@@ -715,6 +738,53 @@ mod tests {
     use super::*;
     crate::cop_fixture_tests!(BlockLength, "cops/metrics/block_length");
 
+    fn allowed_methods_config() -> CopConfig {
+        // Mixes the current keys with their deprecated aliases.
+        let strings = |values: &[&str]| {
+            serde_yml::Value::Sequence(
+                values
+                    .iter()
+                    .map(|v| serde_yml::Value::String(v.to_string()))
+                    .collect(),
+            )
+        };
+        CopConfig {
+            options: std::collections::HashMap::from([
+                ("Max".into(), serde_yml::Value::Number(3.into())),
+                (
+                    "AllowedMethods".into(),
+                    strings(&["describe", "context", "namespace", "task", "Foo.configure"]),
+                ),
+                ("ExcludedMethods".into(), strings(&["shared_examples"])),
+                ("IgnoredMethods".into(), strings(&["feature"])),
+                ("AllowedPatterns".into(), strings(&["\\Ascenario"])),
+            ]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_allowed_methods() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &BlockLength,
+            include_bytes!(
+                "../../../tests/fixtures/cops/metrics/block_length/offense.allowed_methods.rb"
+            ),
+            allowed_methods_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_allowed_methods() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &BlockLength,
+            include_bytes!(
+                "../../../tests/fixtures/cops/metrics/block_length/no_offense.allowed_methods.rb"
+            ),
+            allowed_methods_config(),
+        );
+    }
+
     #[test]
     fn config_custom_max() {
         use crate::testutil::run_cop_full_with_config;
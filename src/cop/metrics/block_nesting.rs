@@ -140,6 +140,7 @@ impl Cop for BlockNesting {
             cop_name: self.name().to_string(),
             message,
             corrected: false,
+            end_column: None,
         }
     }
 }
@@ -167,6 +168,7 @@ impl NestingVisitor<'_> {
                 cop_name: "Metrics/BlockNesting".to_string(),
                 message: format!("Avoid more than {} levels of block nesting.", self.max),
                 corrected: false,
+                end_column: None,
             });
             return !self.inline_disabled_lines.contains(&line);
         }
@@ -1,3 +1,6 @@
+use crate::cop::shared::allowed_methods::{
+    allowed_methods, allowed_patterns, matches_allowed_pattern,
+};
 use crate::cop::shared::node_type::{CALL_NODE, DEF_NODE};
 use crate::cop::shared::util::{
     collect_foldable_ranges, collect_heredoc_ranges, count_body_lines_ex,
@@ -212,8 +215,8 @@ struct MethodLengthConfig {
     max: usize,
     count_comments: bool,
     count_as_one: Option<Vec<String>>,
-    allowed_methods: Option<Vec<String>>,
-    allowed_patterns: Option<Vec<String>>,
+    allowed_methods: Vec<String>,
+    allowed_patterns: Vec<String>,
 }
 
 impl MethodLengthConfig {
@@ -222,28 +225,16 @@ impl MethodLengthConfig {
             max: config.get_usize("Max", 10),
             count_comments: config.get_bool("CountComments", false),
             count_as_one: config.get_string_array("CountAsOne"),
-            allowed_methods: config.get_string_array("AllowedMethods"),
-            allowed_patterns: config.get_string_array("AllowedPatterns"),
+            allowed_methods: allowed_methods(config),
+            allowed_patterns: allowed_patterns(config),
         }
     }
 
-    /// Check if a method name is allowed by AllowedMethods or AllowedPatterns.
+    /// Check if a method name is allowed by AllowedMethods or AllowedPatterns
+    /// (including their deprecated `ExcludedMethods`/`IgnoredMethods` aliases).
     fn is_allowed(&self, method_name: &str) -> bool {
-        if let Some(allowed) = &self.allowed_methods {
-            if allowed.iter().any(|m| m == method_name) {
-                return true;
-            }
-        }
-        if let Some(patterns) = &self.allowed_patterns {
-            for pat in patterns {
-                if let Ok(re) = regex::Regex::new(pat) {
-                    if re.is_match(method_name) {
-                        return true;
-                    }
-                }
-            }
-        }
-        false
+        self.allowed_methods.iter().any(|m| m == method_name)
+            || matches_allowed_pattern(&self.allowed_patterns, method_name)
     }
 }
 
@@ -960,6 +951,50 @@ mod tests {
     use super::*;
     crate::cop_fixture_tests!(MethodLength, "cops/metrics/method_length");
 
+    fn allowed_methods_config() -> CopConfig {
+        // Mixes the current keys with their deprecated aliases.
+        let strings = |values: &[&str]| {
+            serde_yml::Value::Sequence(
+                values
+                    .iter()
+                    .map(|v| serde_yml::Value::String(v.to_string()))
+                    .collect(),
+            )
+        };
+        CopConfig {
+            options: std::collections::HashMap::from([
+                ("Max".into(), serde_yml::Value::Number(3.into())),
+                ("AllowedMethods".into(), strings(&["to_h"])),
+                ("ExcludedMethods".into(), strings(&["as_json"])),
+                ("IgnoredMethods".into(), strings(&["render"])),
+                ("AllowedPatterns".into(), strings(&["\\Abuild_"])),
+            ]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_allowed_methods() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &MethodLength,
+            include_bytes!(
+                "../../../tests/fixtures/cops/metrics/method_length/offense.allowed_methods.rb"
+            ),
+            allowed_methods_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_allowed_methods() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &MethodLength,
+            include_bytes!(
+                "../../../tests/fixtures/cops/metrics/method_length/no_offense.allowed_methods.rb"
+            ),
+            allowed_methods_config(),
+        );
+    }
+
     #[test]
     fn heredoc_in_block_no_offense() {
         use crate::testutil::run_cop_full;
@@ -0,0 +1,108 @@
+use crate::cop::minitest::{MINITEST_DEFAULT_INCLUDE, call_arguments, node_source};
+use crate::cop::shared::method_dispatch_predicates;
+use crate::cop::shared::node_type::CALL_NODE;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// Minitest/AssertEqual
+///
+/// Enforces `assert_equal(expected, actual)` over `assert(expected == actual)`.
+/// A custom failure message passed as the second argument is kept.
+pub struct AssertEqual;
+
+impl Cop for AssertEqual {
+    fn name(&self) -> &'static str {
+        "Minitest/AssertEqual"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        MINITEST_DEFAULT_INCLUDE
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn interested_node_types(&self) -> &'static [u8] {
+        &[CALL_NODE]
+    }
+
+    fn check_node(
+        &self,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
+        _parse_result: &ruby_prism::ParseResult<'_>,
+        _config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let call = match node.as_call_node() {
+            Some(c) => c,
+            None => return,
+        };
+        if !method_dispatch_predicates::is_command(&call, b"assert") {
+            return;
+        }
+
+        let args = call_arguments(&call);
+        let Some(first_arg) = args.first() else {
+            return;
+        };
+        let comparison = match first_arg.as_call_node() {
+            Some(c) if c.name().as_slice() == b"==" => c,
+            _ => return,
+        };
+        let comparison_args = call_arguments(&comparison);
+        let (Some(expected), [actual]) = (comparison.receiver(), comparison_args.as_slice()) else {
+            return;
+        };
+
+        let basic_arguments = format!(
+            "{}, {}",
+            node_source(source, &expected),
+            node_source(source, actual)
+        );
+        let preferred = match args.get(1) {
+            Some(message) => format!("{basic_arguments}, {}", node_source(source, message)),
+            None => basic_arguments.clone(),
+        };
+
+        let (line, column) = source.offset_to_line_col(call.location().start_offset());
+        let mut diag = self.diagnostic(
+            source,
+            line,
+            column,
+            format!("Prefer using `assert_equal({preferred})`."),
+        );
+        if let (Some(corr), Some(selector)) = (corrections, call.message_loc()) {
+            corr.push(crate::correction::Correction {
+                start: selector.start_offset(),
+                end: selector.end_offset(),
+                replacement: "assert_equal".to_string(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            corr.push(crate::correction::Correction {
+                start: first_arg.location().start_offset(),
+                end: first_arg.location().end_offset(),
+                replacement: basic_arguments,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(AssertEqual, "cops/minitest/assert_equal");
+    crate::cop_autocorrect_fixture_tests!(AssertEqual, "cops/minitest/assert_equal");
+}
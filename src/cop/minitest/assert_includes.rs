@@ -0,0 +1,58 @@
+use crate::cop::minitest::{MINITEST_DEFAULT_INCLUDE, PredicateRule, check_predicate_rule};
+use crate::cop::shared::node_type::CALL_NODE;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// Minitest/AssertIncludes
+///
+/// Enforces `assert_includes(collection, object)` over
+/// `assert(collection.include?(object))`.
+pub struct AssertIncludes;
+
+const RULE: PredicateRule = PredicateRule {
+    assertion_method: b"assert",
+    target_method: b"include?",
+    preferred_method: "assert_includes",
+};
+
+impl Cop for AssertIncludes {
+    fn name(&self) -> &'static str {
+        "Minitest/AssertIncludes"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        MINITEST_DEFAULT_INCLUDE
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn interested_node_types(&self) -> &'static [u8] {
+        &[CALL_NODE]
+    }
+
+    fn check_node(
+        &self,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
+        _parse_result: &ruby_prism::ParseResult<'_>,
+        _config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        check_predicate_rule(self, &RULE, source, node, diagnostics, corrections);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(AssertIncludes, "cops/minitest/assert_includes");
+    crate::cop_autocorrect_fixture_tests!(AssertIncludes, "cops/minitest/assert_includes");
+}
@@ -0,0 +1,144 @@
+use crate::cop::minitest::{MINITEST_DEFAULT_INCLUDE, call_arguments, node_source};
+use crate::cop::shared::method_dispatch_predicates;
+use crate::cop::shared::node_type::CALL_NODE;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// Minitest/AssertNil
+///
+/// Enforces `assert_nil(actual)` over `assert_equal(nil, actual)`,
+/// `assert(actual.nil?)` and `assert_predicate(actual, :nil?)`, mirroring
+/// rubocop-minitest's `NilAssertionHandleable`. A custom failure message is
+/// kept in both the suggestion and the correction.
+pub struct AssertNil;
+
+impl Cop for AssertNil {
+    fn name(&self) -> &'static str {
+        "Minitest/AssertNil"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        MINITEST_DEFAULT_INCLUDE
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn interested_node_types(&self) -> &'static [u8] {
+        &[CALL_NODE]
+    }
+
+    fn check_node(
+        &self,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
+        _parse_result: &ruby_prism::ParseResult<'_>,
+        _config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let call = match node.as_call_node() {
+            Some(c) => c,
+            None => return,
+        };
+        if call.receiver().is_some() {
+            return;
+        }
+        let args = call_arguments(&call);
+
+        // The nil-checked expression, the optional failure message, and the
+        // (start, end, replacement) edit applied to the arguments.
+        let (actual, message, argument_edit) = match (call.name().as_slice(), args.as_slice()) {
+            (b"assert_equal", [expected, actual, rest @ ..])
+                if expected.as_nil_node().is_some() =>
+            {
+                let actual_source = node_source(source, actual);
+                let edit = (
+                    expected.location().start_offset(),
+                    actual.location().end_offset(),
+                    actual_source.to_string(),
+                );
+                (actual_source, rest.first(), edit)
+            }
+            (b"assert_predicate", [actual, predicate, rest @ ..])
+                if predicate
+                    .as_symbol_node()
+                    .is_some_and(|sym| sym.unescaped() == b"nil?") =>
+            {
+                let actual_source = node_source(source, actual);
+                let edit = (
+                    actual.location().start_offset(),
+                    predicate.location().end_offset(),
+                    actual_source.to_string(),
+                );
+                (actual_source, rest.first(), edit)
+            }
+            (b"assert", [first, rest @ ..]) => {
+                let Some(nil_call) = first.as_call_node() else {
+                    return;
+                };
+                if nil_call.name().as_slice() != b"nil?" || nil_call.arguments().is_some() {
+                    return;
+                }
+                let (Some(receiver), Some(selector)) =
+                    (nil_call.receiver(), nil_call.message_loc())
+                else {
+                    return;
+                };
+                // Drop the `.nil?` call, keeping its receiver in place.
+                let edit = (
+                    receiver.location().end_offset(),
+                    selector.end_offset(),
+                    String::new(),
+                );
+                (node_source(source, &receiver), rest.first(), edit)
+            }
+            _ => return,
+        };
+
+        let preferred_args = match message {
+            Some(message) => format!("{actual}, {}", node_source(source, message)),
+            None => actual.to_string(),
+        };
+
+        let (line, column) = source.offset_to_line_col(call.location().start_offset());
+        let mut diag = self.diagnostic(
+            source,
+            line,
+            column,
+            format!("Prefer using `assert_nil({preferred_args})`."),
+        );
+        if let (Some(corr), Some(selector)) = (corrections, call.message_loc()) {
+            corr.push(crate::correction::Correction {
+                start: selector.start_offset(),
+                end: selector.end_offset(),
+                replacement: "assert_nil".to_string(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            let (start, end, replacement) = argument_edit;
+            corr.push(crate::correction::Correction {
+                start,
+                end,
+                replacement,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(AssertNil, "cops/minitest/assert_nil");
+    crate::cop_autocorrect_fixture_tests!(AssertNil, "cops/minitest/assert_nil");
+}
@@ -0,0 +1,104 @@
+use crate::cop::minitest::{MINITEST_DEFAULT_INCLUDE, call_arguments, node_source};
+use crate::cop::shared::method_dispatch_predicates;
+use crate::cop::shared::node_type::CALL_NODE;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// Minitest/AssertTruthy
+///
+/// Enforces `assert(actual)` over `assert_equal(true, actual)`. Upstream marks
+/// the cop `Safe: false` because `assert` accepts any truthy value while
+/// `assert_equal(true, ...)` requires exactly `true`.
+pub struct AssertTruthy;
+
+impl Cop for AssertTruthy {
+    fn name(&self) -> &'static str {
+        "Minitest/AssertTruthy"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        MINITEST_DEFAULT_INCLUDE
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
+    fn interested_node_types(&self) -> &'static [u8] {
+        &[CALL_NODE]
+    }
+
+    fn check_node(
+        &self,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
+        _parse_result: &ruby_prism::ParseResult<'_>,
+        _config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let call = match node.as_call_node() {
+            Some(c) => c,
+            None => return,
+        };
+        if !method_dispatch_predicates::is_command(&call, b"assert_equal") {
+            return;
+        }
+
+        let args = call_arguments(&call);
+        let [expected, actual, rest @ ..] = args.as_slice() else {
+            return;
+        };
+        if expected.as_true_node().is_none() {
+            return;
+        }
+
+        let actual_source = node_source(source, actual);
+        let arguments = match rest.first() {
+            Some(message) => format!("{actual_source}, {}", node_source(source, message)),
+            None => actual_source.to_string(),
+        };
+
+        let (line, column) = source.offset_to_line_col(call.location().start_offset());
+        let mut diag = self.diagnostic(
+            source,
+            line,
+            column,
+            format!("Prefer using `assert({arguments})`."),
+        );
+        if let (Some(corr), Some(selector)) = (corrections, call.message_loc()) {
+            corr.push(crate::correction::Correction {
+                start: selector.start_offset(),
+                end: selector.end_offset(),
+                replacement: "assert".to_string(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            corr.push(crate::correction::Correction {
+                start: expected.location().start_offset(),
+                end: actual.location().end_offset(),
+                replacement: actual_source.to_string(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(AssertTruthy, "cops/minitest/assert_truthy");
+    crate::cop_autocorrect_fixture_tests!(AssertTruthy, "cops/minitest/assert_truthy");
+}
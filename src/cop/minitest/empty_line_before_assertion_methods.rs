@@ -0,0 +1,172 @@
+use crate::cop::minitest::{MINITEST_DEFAULT_INCLUDE, is_assertion_method};
+use crate::cop::shared::node_type::STATEMENTS_NODE;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// Minitest/EmptyLineBeforeAssertionMethods
+///
+/// Requires an empty line between an assertion and a preceding non-assertion
+/// statement. Consecutive assertions are fine, as is an assertion that follows
+/// a block whose last statement is itself an assertion.
+///
+/// When the previous statement ends with a heredoc argument, the empty line is
+/// expected after the heredoc terminator rather than after the opener.
+pub struct EmptyLineBeforeAssertionMethods;
+
+/// The assertion call a statement is made of, if any.
+fn assertion_call<'pr>(node: &ruby_prism::Node<'pr>) -> Option<ruby_prism::CallNode<'pr>> {
+    node.as_call_node()
+        .filter(|call| is_assertion_method(call.name().as_slice()))
+}
+
+/// `assertion_method?` for the previous statement, which also looks through
+/// assignments such as `error = assert_raises(Foo) { ... }`.
+fn is_assertion_statement(node: &ruby_prism::Node<'_>) -> bool {
+    let value = if let Some(w) = node.as_local_variable_write_node() {
+        w.value()
+    } else if let Some(w) = node.as_instance_variable_write_node() {
+        w.value()
+    } else if let Some(w) = node.as_class_variable_write_node() {
+        w.value()
+    } else if let Some(w) = node.as_global_variable_write_node() {
+        w.value()
+    } else if let Some(w) = node.as_constant_write_node() {
+        w.value()
+    } else {
+        return assertion_call(node).is_some();
+    };
+    assertion_call(&value).is_some()
+}
+
+/// Whether a block attached to the previous statement ends with an assertion.
+fn ends_with_assertion_block(node: &ruby_prism::Node<'_>) -> bool {
+    let Some(block) = node
+        .as_call_node()
+        .and_then(|call| call.block())
+        .and_then(|block| block.as_block_node())
+    else {
+        return false;
+    };
+    let Some(stmts) = block.body().and_then(|body| body.as_statements_node()) else {
+        return false;
+    };
+    stmts
+        .body()
+        .iter()
+        .last()
+        .is_some_and(|last| is_assertion_statement(&last))
+}
+
+/// Start offset of the terminator line of a heredoc passed as the last
+/// argument of the previous statement.
+fn heredoc_terminator_offset(node: &ruby_prism::Node<'_>) -> Option<usize> {
+    let call = node.as_call_node()?;
+    let last_arg = call.arguments()?.arguments().iter().last()?;
+    let (opening, closing) = if let Some(s) = last_arg.as_string_node() {
+        (s.opening_loc()?, s.closing_loc()?)
+    } else if let Some(s) = last_arg.as_interpolated_string_node() {
+        (s.opening_loc()?, s.closing_loc()?)
+    } else if let Some(s) = last_arg.as_x_string_node() {
+        (s.opening_loc(), s.closing_loc())
+    } else if let Some(s) = last_arg.as_interpolated_x_string_node() {
+        (s.opening_loc(), s.closing_loc())
+    } else {
+        return None;
+    };
+    opening
+        .as_slice()
+        .starts_with(b"<<")
+        .then_some(closing.start_offset())
+}
+
+impl Cop for EmptyLineBeforeAssertionMethods {
+    fn name(&self) -> &'static str {
+        "Minitest/EmptyLineBeforeAssertionMethods"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        MINITEST_DEFAULT_INCLUDE
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn interested_node_types(&self) -> &'static [u8] {
+        &[STATEMENTS_NODE]
+    }
+
+    fn check_node(
+        &self,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
+        _parse_result: &ruby_prism::ParseResult<'_>,
+        _config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        mut corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let stmts = match node.as_statements_node() {
+            Some(s) => s,
+            None => return,
+        };
+        let body: Vec<_> = stmts.body().iter().collect();
+
+        for pair in body.windows(2) {
+            let [previous, current] = pair else {
+                continue;
+            };
+            let Some(assertion) = assertion_call(current) else {
+                continue;
+            };
+            if is_assertion_statement(previous) || ends_with_assertion_block(previous) {
+                continue;
+            }
+
+            let previous_end = heredoc_terminator_offset(previous)
+                .unwrap_or_else(|| previous.location().end_offset());
+            let (previous_line, _) = source.offset_to_line_col(previous_end);
+            let start = assertion.location().start_offset();
+            let (line, column) = source.offset_to_line_col(start);
+            if previous_line + 1 != line {
+                continue;
+            }
+
+            let mut diag = self.diagnostic(
+                source,
+                line,
+                column,
+                "Add empty line before assertion.".to_string(),
+            );
+            if let Some(ref mut corr) = corrections {
+                let insert_at = source.line_start_offset(line);
+                corr.push(crate::correction::Correction {
+                    start: insert_at,
+                    end: insert_at,
+                    replacement: "\n".to_string(),
+                    cop_name: self.name(),
+                    cop_index: 0,
+                });
+                diag.corrected = true;
+            }
+            diagnostics.push(diag);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(
+        EmptyLineBeforeAssertionMethods,
+        "cops/minitest/empty_line_before_assertion_methods"
+    );
+    crate::cop_autocorrect_fixture_tests!(
+        EmptyLineBeforeAssertionMethods,
+        "cops/minitest/empty_line_before_assertion_methods"
+    );
+}
@@ -0,0 +1,139 @@
+pub mod assert_empty;
+pub mod assert_equal;
+pub mod assert_includes;
+pub mod assert_nil;
+pub mod assert_truthy;
+pub mod empty_line_before_assertion_methods;
+pub mod multiple_assertions;
+pub mod refute_equal;
+
+use super::registry::CopRegistry;
+use crate::cop::Cop;
+use crate::cop::shared::method_dispatch_predicates;
+use crate::cop::shared::util::unwrap_parentheses;
+use crate::diagnostic::Diagnostic;
+use crate::parse::source::SourceFile;
+
+/// Default Include patterns for Minitest cops (from the `Minitest` department
+/// config in rubocop-minitest).
+pub const MINITEST_DEFAULT_INCLUDE: &[&str] = &["**/test/**/*", "**/*_test.rb"];
+
+/// Mirrors rubocop-minitest's `MinitestExplorationHelpers#assertion_method?`
+/// name check: any method starting with `assert` or `refute`, plus `flunk`.
+pub fn is_assertion_method(name: &[u8]) -> bool {
+    name.starts_with(b"assert") || name.starts_with(b"refute") || name == b"flunk"
+}
+
+/// Source text of a node.
+pub fn node_source<'a>(source: &'a SourceFile, node: &ruby_prism::Node<'_>) -> &'a str {
+    let loc = node.location();
+    source.byte_slice(loc.start_offset(), loc.end_offset(), "")
+}
+
+/// The positional arguments of a call.
+pub fn call_arguments<'pr>(call: &ruby_prism::CallNode<'pr>) -> Vec<ruby_prism::Node<'pr>> {
+    call.arguments()
+        .map(|args| args.arguments().iter().collect())
+        .unwrap_or_default()
+}
+
+/// Port of rubocop-minitest's `MinitestCopRule.define_rule` for the
+/// `assert(receiver.target(arg))` → `preferred(receiver, arg)` family.
+pub struct PredicateRule {
+    pub assertion_method: &'static [u8],
+    pub target_method: &'static [u8],
+    pub preferred_method: &'static str,
+}
+
+/// Check a call against a `PredicateRule`, reporting the whole assertion and
+/// correcting the selector and first argument. Redundant parentheses around
+/// the first argument are peeled as upstream does.
+pub fn check_predicate_rule(
+    cop: &dyn Cop,
+    rule: &PredicateRule,
+    source: &SourceFile,
+    node: &ruby_prism::Node<'_>,
+    diagnostics: &mut Vec<Diagnostic>,
+    corrections: Option<&mut Vec<crate::correction::Correction>>,
+) {
+    let Some(call) = node.as_call_node() else {
+        return;
+    };
+    if !method_dispatch_predicates::is_command(&call, rule.assertion_method) {
+        return;
+    }
+    let args = call_arguments(&call);
+    let Some(first_arg) = args.first() else {
+        return;
+    };
+    let Some(peeled) = call_arguments(&call)
+        .into_iter()
+        .next()
+        .map(unwrap_parentheses)
+    else {
+        return;
+    };
+    let Some(target_call) = peeled.as_call_node() else {
+        return;
+    };
+    if target_call.name().as_slice() != rule.target_method {
+        return;
+    }
+
+    let mut new_arguments = vec![match target_call.receiver() {
+        Some(recv) => node_source(source, &recv).to_string(),
+        None => "self".to_string(),
+    }];
+    if let Some(method_arg) = call_arguments(&target_call).first() {
+        new_arguments.push(node_source(source, method_arg).to_string());
+    }
+    let corrected_arguments = new_arguments.join(", ");
+
+    let mut message_arguments = new_arguments;
+    if let [_, .., message_arg] = args.as_slice() {
+        message_arguments.push(node_source(source, message_arg).to_string());
+    }
+
+    let (line, column) = source.offset_to_line_col(call.location().start_offset());
+    let mut diag = cop.diagnostic(
+        source,
+        line,
+        column,
+        format!(
+            "Prefer using `{}({})`.",
+            rule.preferred_method,
+            message_arguments.join(", ")
+        ),
+    );
+    if let (Some(corr), Some(selector)) = (corrections, call.message_loc()) {
+        corr.push(crate::correction::Correction {
+            start: selector.start_offset(),
+            end: selector.end_offset(),
+            replacement: rule.preferred_method.to_string(),
+            cop_name: cop.name(),
+            cop_index: 0,
+        });
+        corr.push(crate::correction::Correction {
+            start: first_arg.location().start_offset(),
+            end: first_arg.location().end_offset(),
+            replacement: corrected_arguments,
+            cop_name: cop.name(),
+            cop_index: 0,
+        });
+        diag.corrected = true;
+    }
+    diagnostics.push(diag);
+}
+
+pub fn register_all(registry: &mut CopRegistry) {
+    registry.register(Box::new(assert_empty::AssertEmpty));
+    registry.register(Box::new(assert_equal::AssertEqual));
+    registry.register(Box::new(assert_includes::AssertIncludes));
+    registry.register(Box::new(assert_nil::AssertNil));
+    registry.register(Box::new(assert_truthy::AssertTruthy));
+    registry.register(Box::new(
+        empty_line_before_assertion_methods::EmptyLineBeforeAssertionMethods,
+    ));
+    registry.register(Box::new(multiple_assertions::MultipleAssertions));
+    registry.register(Box::new(refute_equal::RefuteEqual));
+}
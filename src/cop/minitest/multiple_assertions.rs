@@ -0,0 +1,233 @@
+use ruby_prism::Visit;
+
+use crate::cop::minitest::{MINITEST_DEFAULT_INCLUDE, is_assertion_method};
+use crate::cop::shared::access_modifier_predicates;
+use crate::cop::shared::node_type::CLASS_NODE;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// Minitest/MultipleAssertions
+///
+/// Flags test cases with more than `Max` (default 3) assertions. Test cases
+/// are public `test_*` methods without parameters and `test "..." do` blocks
+/// inside a class with a superclass whose name ends in `Test`.
+///
+/// As in rubocop-minitest, only the branch with the most assertions counts
+/// for `if`/`unless`/`case`/`case ... in`, and for `rescue` the begin body is
+/// added to the busiest rescue/else branch.
+pub struct MultipleAssertions;
+
+fn count_assertions(node: &ruby_prism::Node<'_>) -> usize {
+    let mut counter = AssertionCounter { count: 0 };
+    counter.visit(node);
+    counter.count
+}
+
+fn count_statements(stmts: Option<ruby_prism::StatementsNode<'_>>) -> usize {
+    stmts.map_or(0, |s| count_assertions(&s.as_node()))
+}
+
+fn count_else(else_clause: Option<ruby_prism::ElseNode<'_>>) -> usize {
+    else_clause.map_or(0, |e| count_statements(e.statements()))
+}
+
+struct AssertionCounter {
+    count: usize,
+}
+
+impl<'pr> Visit<'pr> for AssertionCounter {
+    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+        if is_assertion_method(node.name().as_slice()) {
+            self.count += 1;
+        }
+        ruby_prism::visit_call_node(self, node);
+    }
+
+    fn visit_if_node(&mut self, node: &ruby_prism::IfNode<'pr>) {
+        let if_branch = count_statements(node.statements());
+        let else_branch = node.subsequent().map_or(0, |n| count_assertions(&n));
+        self.count += if_branch.max(else_branch);
+    }
+
+    fn visit_unless_node(&mut self, node: &ruby_prism::UnlessNode<'pr>) {
+        let unless_branch = count_statements(node.statements());
+        self.count += unless_branch.max(count_else(node.else_clause()));
+    }
+
+    fn visit_case_node(&mut self, node: &ruby_prism::CaseNode<'pr>) {
+        let busiest_when = node
+            .conditions()
+            .iter()
+            .filter_map(|c| c.as_when_node())
+            .map(|when| count_statements(when.statements()))
+            .max()
+            .unwrap_or(0);
+        self.count += busiest_when.max(count_else(node.else_clause()));
+    }
+
+    fn visit_case_match_node(&mut self, node: &ruby_prism::CaseMatchNode<'pr>) {
+        let busiest_in = node
+            .conditions()
+            .iter()
+            .filter_map(|c| c.as_in_node())
+            .map(|in_node| count_statements(in_node.statements()))
+            .max()
+            .unwrap_or(0);
+        self.count += busiest_in.max(count_else(node.else_clause()));
+    }
+
+    fn visit_begin_node(&mut self, node: &ruby_prism::BeginNode<'pr>) {
+        let Some(rescue_clause) = node.rescue_clause() else {
+            ruby_prism::visit_begin_node(self, node);
+            return;
+        };
+        let mut busiest_branch = count_else(node.else_clause());
+        let mut rescue = Some(rescue_clause);
+        while let Some(r) = rescue {
+            busiest_branch = busiest_branch.max(count_statements(r.statements()));
+            rescue = r.subsequent();
+        }
+        let ensure_count = node
+            .ensure_clause()
+            .map_or(0, |e| count_statements(e.statements()));
+        self.count += count_statements(node.statements()) + busiest_branch + ensure_count;
+    }
+}
+
+/// Counts assertions in `test "..." do ... end` blocks anywhere under the
+/// class body (Active Support's declarative tests).
+struct TestBlockFinder {
+    test_cases: Vec<(usize, usize)>,
+}
+
+impl<'pr> Visit<'pr> for TestBlockFinder {
+    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+        if node.name().as_slice() == b"test" {
+            if let Some(block) = node.block().and_then(|b| b.as_block_node()) {
+                let count = block.body().map_or(0, |b| count_assertions(&b));
+                self.test_cases
+                    .push((node.location().start_offset(), count));
+            }
+        }
+        ruby_prism::visit_call_node(self, node);
+    }
+}
+
+impl Cop for MultipleAssertions {
+    fn name(&self) -> &'static str {
+        "Minitest/MultipleAssertions"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        MINITEST_DEFAULT_INCLUDE
+    }
+
+    fn interested_node_types(&self) -> &'static [u8] {
+        &[CLASS_NODE]
+    }
+
+    fn check_node(
+        &self,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
+        _parse_result: &ruby_prism::ParseResult<'_>,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let class = match node.as_class_node() {
+            Some(c) => c,
+            None => return,
+        };
+        if class.superclass().is_none()
+            || !class
+                .constant_path()
+                .location()
+                .as_slice()
+                .ends_with(b"Test")
+        {
+            return;
+        }
+        let Some(body) = class.body() else {
+            return;
+        };
+        let max = config.get_usize("Max", 3);
+
+        // (offense start offset, assertion count) per test case.
+        let mut test_cases = Vec::new();
+
+        if let Some(stmts) = body.as_statements_node() {
+            let mut public = true;
+            for stmt in stmts.body().iter() {
+                if let Some(call) = stmt.as_call_node() {
+                    if access_modifier_predicates::is_bare_access_modifier(&call) {
+                        public = call.name().as_slice() == b"public";
+                    }
+                    continue;
+                }
+                let Some(def) = stmt.as_def_node() else {
+                    continue;
+                };
+                if public
+                    && def.receiver().is_none()
+                    && def.parameters().is_none()
+                    && def.name().as_slice().starts_with(b"test_")
+                {
+                    let count = def.body().map_or(0, |b| count_assertions(&b));
+                    test_cases.push((def.location().start_offset(), count));
+                }
+            }
+        }
+
+        let mut finder = TestBlockFinder {
+            test_cases: Vec::new(),
+        };
+        finder.visit(&body);
+        test_cases.extend(finder.test_cases);
+
+        for (start, count) in test_cases {
+            if count <= max {
+                continue;
+            }
+            let (line, column) = source.offset_to_line_col(start);
+            diagnostics.push(self.diagnostic(
+                source,
+                line,
+                column,
+                format!("Test case has too many assertions [{count}/{max}]."),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(MultipleAssertions, "cops/minitest/multiple_assertions");
+
+    #[test]
+    fn config_max() {
+        use crate::testutil::{assert_cop_no_offenses_full_with_config, run_cop_full_with_config};
+        use std::collections::HashMap;
+
+        let config = CopConfig {
+            options: HashMap::from([("Max".into(), serde_yml::Value::Number(1.into()))]),
+            ..CopConfig::default()
+        };
+        let source = b"class FooTest < Minitest::Test\n  def test_foo\n    assert foo\n    assert bar\n  end\nend\n";
+        let diags = run_cop_full_with_config(&MultipleAssertions, source, config.clone());
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "Test case has too many assertions [2/1].");
+
+        let config = CopConfig {
+            options: HashMap::from([("Max".into(), serde_yml::Value::Number(2.into()))]),
+            ..CopConfig::default()
+        };
+        assert_cop_no_offenses_full_with_config(&MultipleAssertions, source, config);
+    }
+}
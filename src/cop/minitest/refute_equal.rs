@@ -0,0 +1,133 @@
+use crate::cop::minitest::{MINITEST_DEFAULT_INCLUDE, call_arguments, node_source};
+use crate::cop::shared::node_type::CALL_NODE;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// Minitest/RefuteEqual
+///
+/// Enforces `refute_equal(expected, actual)` over `assert(expected != actual)`,
+/// `assert(!expected == actual)` and `refute(expected == actual)`.
+pub struct RefuteEqual;
+
+/// The `(expected, actual)` operands of the comparison passed to `assert` or
+/// `refute`, if it is one the cop rewrites.
+fn comparison_operands<'pr>(
+    assertion: &[u8],
+    comparison: &ruby_prism::CallNode<'pr>,
+) -> Option<(ruby_prism::Node<'pr>, ruby_prism::Node<'pr>)> {
+    let mut args = call_arguments(comparison).into_iter();
+    let (Some(actual), None) = (args.next(), args.next()) else {
+        return None;
+    };
+    let receiver = comparison.receiver()?;
+    match (assertion, comparison.name().as_slice()) {
+        (b"assert", b"!=") | (b"refute", b"==") => Some((receiver, actual)),
+        (b"assert", b"==") => {
+            let negation = receiver.as_call_node()?;
+            if negation.name().as_slice() != b"!" || negation.arguments().is_some() {
+                return None;
+            }
+            Some((negation.receiver()?, actual))
+        }
+        _ => None,
+    }
+}
+
+impl Cop for RefuteEqual {
+    fn name(&self) -> &'static str {
+        "Minitest/RefuteEqual"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        MINITEST_DEFAULT_INCLUDE
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn interested_node_types(&self) -> &'static [u8] {
+        &[CALL_NODE]
+    }
+
+    fn check_node(
+        &self,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
+        _parse_result: &ruby_prism::ParseResult<'_>,
+        _config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let call = match node.as_call_node() {
+            Some(c) => c,
+            None => return,
+        };
+        if call.receiver().is_some() {
+            return;
+        }
+        let assertion = call.name().as_slice();
+        if assertion != b"assert" && assertion != b"refute" {
+            return;
+        }
+
+        let args = call_arguments(&call);
+        let Some(first_arg) = args.first() else {
+            return;
+        };
+        let Some(comparison) = first_arg.as_call_node() else {
+            return;
+        };
+        let Some((expected, actual)) = comparison_operands(assertion, &comparison) else {
+            return;
+        };
+
+        let basic_arguments = format!(
+            "{}, {}",
+            node_source(source, &expected),
+            node_source(source, &actual)
+        );
+        let preferred = match args.get(1) {
+            Some(message) => format!("{basic_arguments}, {}", node_source(source, message)),
+            None => basic_arguments.clone(),
+        };
+
+        let (line, column) = source.offset_to_line_col(call.location().start_offset());
+        let mut diag = self.diagnostic(
+            source,
+            line,
+            column,
+            format!("Prefer using `refute_equal({preferred})`."),
+        );
+        if let (Some(corr), Some(selector)) = (corrections, call.message_loc()) {
+            corr.push(crate::correction::Correction {
+                start: selector.start_offset(),
+                end: selector.end_offset(),
+                replacement: "refute_equal".to_string(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            corr.push(crate::correction::Correction {
+                start: first_arg.location().start_offset(),
+                end: first_arg.location().end_offset(),
+                replacement: basic_arguments,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(RefuteEqual, "cops/minitest/refute_equal");
+    crate::cop_autocorrect_fixture_tests!(RefuteEqual, "cops/minitest/refute_equal");
+}
@@ -6,6 +6,7 @@ pub mod layout;
 pub mod lint;
 pub mod metrics;
 pub mod migration;
+pub mod minitest;
 pub mod naming;
 pub mod performance;
 pub mod rails;
@@ -15,7 +16,9 @@ pub mod rspec;
 pub mod rspec_rails;
 pub mod security;
 pub mod shared;
+pub mod sorbet;
 pub mod style;
+pub mod thread_safety;
 pub mod tiers;
 pub mod variable_force;
 pub mod walker;
@@ -167,6 +170,32 @@ impl CopConfig {
         }
     }
 
+    /// The target Ruby version, injected from `AllCops: TargetRubyVersion`
+    /// into every cop's options. Defaults to 2.7, RuboCop's default, when
+    /// absent (only in tests that build a `CopConfig` by hand).
+    pub fn target_ruby_version(&self) -> f64 {
+        self.options
+            .get("TargetRubyVersion")
+            .and_then(|v| {
+                v.as_f64()
+                    .or_else(|| v.as_u64().map(|u| u as f64))
+                    .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+            })
+            .unwrap_or(2.7)
+    }
+
+    /// Whether the target Ruby version is at least `minimum`, for cops with
+    /// RuboCop's `minimum_target_ruby_version`.
+    pub fn ruby_version_at_least(&self, minimum: f64) -> bool {
+        self.target_ruby_version() >= minimum
+    }
+
+    /// Whether the target Ruby version is at most `maximum`, for cops with
+    /// RuboCop's `maximum_target_ruby_version`.
+    pub fn ruby_version_at_most(&self, maximum: f64) -> bool {
+        self.target_ruby_version() <= maximum
+    }
+
     /// Whether the project has a known Rails version AND `railties` is in the lockfile.
     /// Non-Rails projects won't have `railties` in their lockfile.
     ///
@@ -258,6 +287,13 @@ impl CopConfig {
             AutocorrectMode::All => self.autocorrect_setting() != "disabled",
         }
     }
+
+    /// Whether the cop's corrections only apply when it is targeted with
+    /// `--autocorrect-cop` (`AutoCorrect: contextual`). A bulk -a/-A still
+    /// reports its offenses but leaves them uncorrected.
+    pub fn is_contextual_autocorrect(&self) -> bool {
+        self.autocorrect_setting() == "contextual"
+    }
 }
 
 /// A lint rule. Implementations must be Send + Sync so they can be shared
@@ -307,6 +343,25 @@ pub trait Cop: Send + Sync {
             cop_name: self.name().to_string(),
             message,
             corrected: false,
+            end_column: None,
+        }
+    }
+
+    /// Create a Diagnostic for an offense covering the byte range
+    /// `start..end`. The end column is recorded when the range ends on the
+    /// line it starts on, so fixture tests can check the caret width.
+    fn diagnostic_for_range(
+        &self,
+        source: &SourceFile,
+        start: usize,
+        end: usize,
+        message: String,
+    ) -> Diagnostic {
+        let (line, column) = source.offset_to_line_col(start);
+        let (end_line, end_column) = source.offset_to_line_col(end);
+        Diagnostic {
+            end_column: (end_line == line).then_some(end_column),
+            ..self.diagnostic(source, line, column, message)
         }
     }
 
@@ -377,6 +432,10 @@ pub trait Cop: Send + Sync {
 
 /// Generate standard offense/no_offense fixture tests for a cop.
 ///
+/// Fixture paths are relative to `tests/fixtures/` in the calling crate, so
+/// crates that embed nitrocop can use these macros for their own cops by
+/// enabling the `testutil` feature (as `nitrocop::cop_fixture_tests!`).
+///
 /// Usage:
 /// ```ignore
 /// #[cfg(test)]
@@ -393,7 +452,12 @@ macro_rules! cop_fixture_tests {
         fn offense_fixture() {
             $crate::testutil::assert_cop_offenses_full(
                 &$cop,
-                include_bytes!(concat!("../../../tests/fixtures/", $path, "/offense.rb")),
+                include_bytes!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/tests/fixtures/",
+                    $path,
+                    "/offense.rb"
+                )),
             );
         }
 
@@ -401,7 +465,12 @@ macro_rules! cop_fixture_tests {
         fn no_offense_fixture() {
             $crate::testutil::assert_cop_no_offenses_full(
                 &$cop,
-                include_bytes!(concat!("../../../tests/fixtures/", $path, "/no_offense.rb")),
+                include_bytes!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/tests/fixtures/",
+                    $path,
+                    "/no_offense.rb"
+                )),
             );
         }
     };
@@ -434,7 +503,7 @@ macro_rules! cop_scenario_fixture_tests {
             fn $name() {
                 $crate::testutil::assert_cop_offenses_full(
                     &$cop,
-                    include_bytes!(concat!("../../../tests/fixtures/", $path, "/offense/", $file)),
+                    include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/", $path, "/offense/", $file)),
                 );
             }
         )+
@@ -443,7 +512,7 @@ macro_rules! cop_scenario_fixture_tests {
         fn no_offense_fixture() {
             $crate::testutil::assert_cop_no_offenses_full(
                 &$cop,
-                include_bytes!(concat!("../../../tests/fixtures/", $path, "/no_offense.rb")),
+                include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/", $path, "/no_offense.rb")),
             );
         }
     };
@@ -457,6 +526,10 @@ macro_rules! cop_scenario_fixture_tests {
 /// 3. Applies corrections to produce corrected source
 /// 4. Asserts the output matches `corrected.rb` byte-for-byte
 ///
+/// It also generates a test that the corrections are idempotent: a second
+/// run of the cop on the corrected output must not flag or correct the
+/// lines the first run rewrote (see `assert_cop_autocorrect_idempotent`).
+///
 /// Usage:
 /// ```ignore
 /// #[cfg(test)]
@@ -473,8 +546,31 @@ macro_rules! cop_autocorrect_fixture_tests {
         fn autocorrect_fixture() {
             $crate::testutil::assert_cop_autocorrect(
                 &$cop,
-                include_bytes!(concat!("../../../tests/fixtures/", $path, "/offense.rb")),
-                include_bytes!(concat!("../../../tests/fixtures/", $path, "/corrected.rb")),
+                include_bytes!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/tests/fixtures/",
+                    $path,
+                    "/offense.rb"
+                )),
+                include_bytes!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/tests/fixtures/",
+                    $path,
+                    "/corrected.rb"
+                )),
+            );
+        }
+
+        #[test]
+        fn autocorrect_idempotent() {
+            $crate::testutil::assert_cop_autocorrect_idempotent(
+                &$cop,
+                include_bytes!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/tests/fixtures/",
+                    $path,
+                    "/offense.rb"
+                )),
             );
         }
     };
@@ -519,7 +615,7 @@ macro_rules! cop_rails_fixture_tests {
         fn offense_fixture() {
             $crate::testutil::assert_cop_offenses_full_with_config(
                 &$cop,
-                include_bytes!(concat!("../../../tests/fixtures/", $path, "/offense.rb")),
+                include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/", $path, "/offense.rb")),
                 rails_config(),
             );
         }
@@ -528,7 +624,7 @@ macro_rules! cop_rails_fixture_tests {
         fn no_offense_fixture() {
             $crate::testutil::assert_cop_no_offenses_full_with_config(
                 &$cop,
-                include_bytes!(concat!("../../../tests/fixtures/", $path, "/no_offense.rb")),
+                include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/", $path, "/no_offense.rb")),
                 rails_config(),
             );
         }
@@ -536,7 +632,7 @@ macro_rules! cop_rails_fixture_tests {
         #[test]
         fn skipped_when_no_target_rails_version() {
             // Non-Rails projects have no TargetRailsVersion — cop should not fire.
-            let source = include_bytes!(concat!("../../../tests/fixtures/", $path, "/offense.rb"));
+            let source = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/", $path, "/offense.rb"));
             let parsed = $crate::testutil::parse_fixture(source);
             let diagnostics = $crate::testutil::run_cop_full_internal(
                 &$cop,
@@ -629,6 +725,19 @@ mod tests {
         assert!(!cfg.should_autocorrect(AutocorrectMode::All));
     }
 
+    #[test]
+    fn contextual_autocorrect_is_allowed_but_flagged() {
+        use crate::cli::AutocorrectMode;
+        let cfg = config_with(HashMap::from([(
+            "AutoCorrect".into(),
+            serde_yml::Value::String("contextual".into()),
+        )]));
+        assert!(cfg.should_autocorrect(AutocorrectMode::Safe));
+        assert!(cfg.should_autocorrect(AutocorrectMode::All));
+        assert!(cfg.is_contextual_autocorrect());
+        assert!(!config_with(HashMap::new()).is_contextual_autocorrect());
+    }
+
     #[test]
     fn autocorrect_setting_bool_true_is_always() {
         let cfg = config_with(HashMap::from([(
@@ -690,6 +799,258 @@ mod tests {
         assert_eq!(hash.get("collect").unwrap(), "map");
     }
 
+    #[test]
+    fn target_ruby_version_reads_injected_option() {
+        use serde_yml::Value;
+        assert_eq!(CopConfig::default().target_ruby_version(), 2.7);
+        let cfg = config_with(HashMap::from([(
+            "TargetRubyVersion".into(),
+            Value::String("3.3".into()),
+        )]));
+        assert_eq!(cfg.target_ruby_version(), 3.3);
+        assert!(cfg.ruby_version_at_least(3.3));
+        assert!(!cfg.ruby_version_at_least(3.4));
+        assert!(cfg.ruby_version_at_most(3.3));
+        assert!(!cfg.ruby_version_at_most(3.2));
+    }
+
+    /// RuboCop's `minimum_target_ruby_version` / `maximum_target_ruby_version`
+    /// for cops that gate as a whole: (cop, fixture dir, minimum, maximum).
+    const RUBY_VERSION_GATES: &[(&str, &str, Option<f64>, Option<f64>)] = &[
+        (
+            "Lint/ErbNewArguments",
+            "lint/erb_new_arguments",
+            Some(2.6),
+            None,
+        ),
+        (
+            "Lint/ItWithoutArgumentsInBlock",
+            "lint/it_without_arguments_in_block",
+            None,
+            Some(3.3),
+        ),
+        (
+            "Lint/RedundantDirGlobSort",
+            "lint/redundant_dir_glob_sort",
+            Some(3.0),
+            None,
+        ),
+        (
+            "Lint/RefinementImportMethods",
+            "lint/refinement_import_methods",
+            Some(3.1),
+            None,
+        ),
+        (
+            "Naming/BlockForwarding",
+            "naming/block_forwarding",
+            Some(3.1),
+            None,
+        ),
+        (
+            "Performance/BindCall",
+            "performance/bind_call",
+            Some(2.7),
+            None,
+        ),
+        (
+            "Performance/DeletePrefix",
+            "performance/delete_prefix",
+            Some(2.5),
+            None,
+        ),
+        (
+            "Performance/DeleteSuffix",
+            "performance/delete_suffix",
+            Some(2.5),
+            None,
+        ),
+        (
+            "Performance/MapCompact",
+            "performance/map_compact",
+            Some(2.7),
+            None,
+        ),
+        (
+            "Performance/RedundantEqualityComparisonBlock",
+            "performance/redundant_equality_comparison_block",
+            Some(2.5),
+            None,
+        ),
+        (
+            "Performance/RegexpMatch",
+            "performance/regexp_match",
+            Some(2.4),
+            None,
+        ),
+        (
+            "Performance/SelectMap",
+            "performance/select_map",
+            Some(2.7),
+            None,
+        ),
+        ("Performance/Sum", "performance/sum", Some(2.4), None),
+        (
+            "Performance/UnfreezeString",
+            "performance/unfreeze_string",
+            Some(2.3),
+            None,
+        ),
+        (
+            "Style/AmbiguousEndlessMethodDefinition",
+            "style/ambiguous_endless_method_definition",
+            Some(3.0),
+            None,
+        ),
+        (
+            "Style/ArgumentsForwarding",
+            "style/arguments_forwarding",
+            Some(2.7),
+            None,
+        ),
+        (
+            "Style/ArrayIntersect",
+            "style/array_intersect",
+            Some(3.1),
+            None,
+        ),
+        (
+            "Style/CollectionCompact",
+            "style/collection_compact",
+            Some(2.4),
+            None,
+        ),
+        (
+            "Style/ComparableClamp",
+            "style/comparable_clamp",
+            Some(2.4),
+            None,
+        ),
+        (
+            "Style/DataInheritance",
+            "style/data_inheritance",
+            Some(3.2),
+            None,
+        ),
+        (
+            "Style/EndlessMethod",
+            "style/endless_method",
+            Some(3.0),
+            None,
+        ),
+        ("Style/HashExcept", "style/hash_except", Some(3.0), None),
+        (
+            "Style/HashFetchChain",
+            "style/hash_fetch_chain",
+            Some(2.3),
+            None,
+        ),
+        ("Style/HashSlice", "style/hash_slice", Some(2.5), None),
+        (
+            "Style/HashTransformKeys",
+            "style/hash_transform_keys",
+            Some(2.5),
+            None,
+        ),
+        (
+            "Style/HashTransformValues",
+            "style/hash_transform_values",
+            Some(2.4),
+            None,
+        ),
+        (
+            "Style/InPatternThen",
+            "style/in_pattern_then",
+            Some(2.7),
+            None,
+        ),
+        (
+            "Style/ItBlockParameter",
+            "style/it_block_parameter",
+            Some(3.4),
+            None,
+        ),
+        ("Style/MapToHash", "style/map_to_hash", Some(2.6), None),
+        (
+            "Style/MultilineInPatternThen",
+            "style/multiline_in_pattern_then",
+            Some(2.7),
+            None,
+        ),
+        (
+            "Style/NestedFileDirname",
+            "style/nested_file_dirname",
+            Some(3.1),
+            None,
+        ),
+        (
+            "Style/NumberedParameters",
+            "style/numbered_parameters",
+            Some(2.7),
+            None,
+        ),
+        (
+            "Style/NumberedParametersLimit",
+            "style/numbered_parameters_limit",
+            Some(2.7),
+            None,
+        ),
+        ("Style/ObjectThen", "style/object_then", Some(2.6), None),
+        (
+            "Style/RedundantInterpolationUnfreeze",
+            "style/redundant_interpolation_unfreeze",
+            Some(3.0),
+            None,
+        ),
+        (
+            "Style/SafeNavigation",
+            "style/safe_navigation",
+            Some(2.3),
+            None,
+        ),
+        (
+            "Style/SlicingWithRange",
+            "style/slicing_with_range",
+            Some(2.6),
+            None,
+        ),
+        ("Style/UnpackFirst", "style/unpack_first", Some(2.4), None),
+    ];
+
+    /// Each gated cop fires on its offense fixture at the edge of its
+    /// supported range and stays silent one minor version outside it.
+    #[test]
+    fn ruby_version_gates_match_rubocop() {
+        let registry = registry::CopRegistry::default_registry();
+        let mut failures = Vec::new();
+        for &(name, dir, minimum, maximum) in RUBY_VERSION_GATES {
+            let cop = registry
+                .get(name)
+                .unwrap_or_else(|| panic!("{name} is not registered"));
+            let path = format!(
+                "{}/tests/fixtures/cops/{dir}/offense.rb",
+                env!("CARGO_MANIFEST_DIR")
+            );
+            let fixture = crate::testutil::parse_fixture(&std::fs::read(&path).unwrap());
+            let fires = |version: f64| {
+                let config =
+                    crate::testutil::cop_config_from_yaml_with_targets("{}\n", Some(version), None);
+                !crate::testutil::run_cop_full_internal(cop, &fixture.source, config, "test.rb")
+                    .is_empty()
+            };
+            let edges = [minimum.map(|v| (v, v - 0.1)), maximum.map(|v| (v, v + 0.1))];
+            for (inside, outside) in edges.into_iter().flatten() {
+                if !fires(inside) {
+                    failures.push(format!("{name} should fire on Ruby {inside:.1}"));
+                }
+                if fires(outside) {
+                    failures.push(format!("{name} should be silent on Ruby {outside:.1}"));
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
     mod prop_tests {
         use super::*;
         use proptest::prelude::*;
@@ -125,19 +125,10 @@ impl Cop for BlockForwarding {
         mut corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // Anonymous block forwarding requires Ruby 3.1+
-        // Default TargetRubyVersion is 3.4 (matching RuboCop's behavior when unset)
-        let target_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| {
-                v.as_f64()
-                    .or_else(|| v.as_u64().map(|u| u as f64))
-                    .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
-            })
-            .unwrap_or(3.4);
-        if target_version < 3.1 {
+        if !config.ruby_version_at_least(3.1) {
             return;
         }
+        let target_version = config.target_ruby_version();
 
         let enforced_style = config.get_str("EnforcedStyle", "anonymous");
         let _block_forwarding_name = config.get_str("BlockForwardingName", "block");
@@ -561,19 +552,59 @@ impl<'pr> Visit<'pr> for BlockUsageChecker<'_> {
 mod tests {
     use super::*;
 
-    crate::cop_fixture_tests!(BlockForwarding, "cops/naming/block_forwarding");
-    crate::cop_autocorrect_fixture_tests!(BlockForwarding, "cops/naming/block_forwarding");
+    fn ruby34_config() -> crate::cop::CopConfig {
+        let mut config = crate::cop::CopConfig::default();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::Number::from(3.4)),
+        );
+        config
+    }
 
     fn explicit_config() -> crate::cop::CopConfig {
-        let mut options = std::collections::HashMap::new();
-        options.insert(
+        let mut config = ruby34_config();
+        config.options.insert(
             "EnforcedStyle".to_string(),
             serde_yml::Value::String("explicit".to_string()),
         );
-        crate::cop::CopConfig {
-            options,
-            ..crate::cop::CopConfig::default()
-        }
+        config
+    }
+
+    #[test]
+    fn offense_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &BlockForwarding,
+            include_bytes!("../../../tests/fixtures/cops/naming/block_forwarding/offense.rb"),
+            ruby34_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &BlockForwarding,
+            include_bytes!("../../../tests/fixtures/cops/naming/block_forwarding/no_offense.rb"),
+            ruby34_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &BlockForwarding,
+            include_bytes!("../../../tests/fixtures/cops/naming/block_forwarding/offense.rb"),
+            include_bytes!("../../../tests/fixtures/cops/naming/block_forwarding/corrected.rb"),
+            ruby34_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_idempotent() {
+        crate::testutil::assert_cop_autocorrect_idempotent_with_config(
+            &BlockForwarding,
+            include_bytes!("../../../tests/fixtures/cops/naming/block_forwarding/offense.rb"),
+            ruby34_config(),
+        );
     }
 
     #[test]
@@ -601,7 +632,11 @@ mod tests {
     #[test]
     fn autocorrect_simple_forwarding() {
         let input = b"def foo(&block)\n  bar(&block)\nend\n";
-        let (diags, corrections) = crate::testutil::run_cop_autocorrect(&BlockForwarding, input);
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect_with_config(
+            &BlockForwarding,
+            input,
+            ruby34_config(),
+        );
         assert_eq!(diags.len(), 2);
         assert!(diags.iter().all(|d| d.corrected));
         let cs = crate::correction::CorrectionSet::from_vec(corrections);
@@ -612,7 +647,11 @@ mod tests {
     #[test]
     fn autocorrect_unused_param() {
         let input = b"def foo(&block)\nend\n";
-        let (diags, corrections) = crate::testutil::run_cop_autocorrect(&BlockForwarding, input);
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect_with_config(
+            &BlockForwarding,
+            input,
+            ruby34_config(),
+        );
         assert_eq!(diags.len(), 1);
         assert!(diags[0].corrected);
         let cs = crate::correction::CorrectionSet::from_vec(corrections);
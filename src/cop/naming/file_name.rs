@@ -1,5 +1,8 @@
 use std::path::Path;
 
+use ruby_prism::Visit;
+
+use crate::cop::shared::constant_predicates::{constant_path_segments, constant_short_name};
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
@@ -122,6 +125,15 @@ fn is_filename_snake_case(segment: &str) -> bool {
 /// Fix: confirm global exclude hits with the Ruby-like `glob_matches()` helper
 /// before skipping the file. This preserves true repo-root excludes while matching
 /// the corpus oracle's prefixed-path behavior.
+///
+/// ## ExpectMatchingDefinition
+///
+/// The filename check stays in `check_lines` so non-UTF-8 files still get it;
+/// the definition check runs in `check_source` over the AST (class, module,
+/// and `Class.new`/`Module.new` constant assignments), mirroring RuboCop's
+/// `find_class_or_module`, including `AllowedAcronyms`. As in RuboCop,
+/// `IgnoreExecutableScripts` only excuses a bad filename: a well-named script
+/// is still expected to define its constant.
 pub struct FileName;
 
 /// Well-known Ruby files that don't follow snake_case convention.
@@ -154,87 +166,231 @@ const ALLOWED_NAMES: &[&str] = &[
 ];
 
 /// Default roots for definition path hierarchy matching.
-const DEFAULT_PATH_ROOTS: &[&str] = &["lib", "spec", "test", "app"];
-
-/// Convert a snake_case filename stem to a CamelCase module name.
-fn to_module_name(stem: &str) -> String {
-    stem.split('_')
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(c) => c.to_uppercase().collect::<String>() + chars.as_str(),
-            }
-        })
-        .collect()
+const DEFAULT_PATH_ROOTS: &[&str] = &["lib", "spec", "test", "src"];
+
+/// RuboCop's `to_module_name`: drop everything from the first dot, then
+/// capitalize each `_`-separated word (`bar_baz.rb` → `BarBaz`).
+fn to_module_name(basename: &str) -> String {
+    let stem = basename.split('.').next().unwrap_or("");
+    stem.split('_').map(capitalize).collect()
+}
+
+/// Ruby's `String#capitalize`: first character upcased, the rest downcased.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(c) => c
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+    }
 }
 
-/// Build expected namespace from a file path.
+/// The constant path a file is expected to define, from the path components
+/// after the last `CheckDefinitionPathHierarchyRoots` directory in it
+/// (`lib/foo/bar_baz.rb` → `Foo::BarBaz`). Without a root in the path, just
+/// the file's own module name.
+///
+/// A root may span several directories (`app/models`); RuboCop only matches
+/// single directory names, which these are a superset of.
 fn build_expected_namespace(path: &Path, roots: &Option<Vec<String>>) -> Vec<String> {
-    let root_list: Vec<&str> = match roots {
-        Some(list) => list.iter().map(|s| s.as_str()).collect(),
-        None => DEFAULT_PATH_ROOTS.to_vec(),
+    let root_list: Vec<Vec<&str>> = match roots {
+        Some(list) => list
+            .iter()
+            .map(|root| root.split('/').filter(|c| !c.is_empty()).collect())
+            .collect(),
+        None => DEFAULT_PATH_ROOTS.iter().map(|root| vec![*root]).collect(),
     };
 
     let components: Vec<&str> = path
         .components()
-        .filter_map(|c| c.as_os_str().to_str())
+        .filter_map(|c| match c {
+            std::path::Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
         .collect();
 
-    // Find the last occurrence of a root directory in the path
-    let mut start_index = None;
-    for (i, comp) in components.iter().enumerate().rev() {
-        if root_list.contains(comp) {
-            start_index = Some(i + 1);
-            break;
-        }
-    }
+    // Work backwards so the closest root wins, e.g. in /home/me/src/project/lib
+    let start_index = (1..=components.len()).rev().find(|&end| {
+        root_list
+            .iter()
+            .any(|root| !root.is_empty() && components[..end].ends_with(root))
+    });
 
     match start_index {
         Some(idx) => components[idx..]
             .iter()
-            .map(|c| {
-                let stem = Path::new(c)
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or(c);
-                to_module_name(stem)
-            })
+            .map(|c| to_module_name(c))
             .collect(),
-        None => {
-            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
-            vec![to_module_name(stem)]
-        }
+        None => vec![to_module_name(components.last().copied().unwrap_or(""))],
     }
 }
 
-/// Simple text-based check for whether the source defines a class/module matching
-/// the expected namespace.
-fn has_matching_definition(source: &str, expected_namespace: &[String]) -> bool {
-    if expected_namespace.is_empty() {
-        return true;
+/// RuboCop's `match_acronym?`: `name` is `expected` with an allowed acronym
+/// written in capitals (`ApiClient` vs `APIClient` with `API` allowed).
+fn matches_acronym(expected: &str, name: &str, acronyms: &[String]) -> bool {
+    acronyms
+        .iter()
+        .any(|acronym| expected.replace(&capitalize(acronym), acronym) == name)
+}
+
+/// Finds a class, module, or `Class.new`/`Module.new` constant assignment
+/// that defines the expected constant path, following RuboCop's
+/// `find_class_or_module`.
+///
+/// A definition matches when its own name is the last expected segment and
+/// the remaining segments can be found, innermost first, in its written
+/// namespace (`class Foo::Bar`) and the definitions around it. Segments that
+/// don't match are skipped rather than failing the match. Anything inside
+/// `class << self` never matches.
+struct DefinitionFinder<'a> {
+    expected: &'a [String],
+    acronyms: &'a [String],
+    /// Full constant paths of the enclosing definitions, outermost first;
+    /// `None` for `class << self`.
+    ancestors: Vec<Option<Vec<String>>>,
+    found: bool,
+}
+
+impl DefinitionFinder<'_> {
+    fn names_match(&self, expected: &str, name: &str) -> bool {
+        expected == name || matches_acronym(expected, name, self.acronyms)
     }
 
-    let fqn = expected_namespace.join("::");
-    let last_name = expected_namespace.last().unwrap();
+    /// Pop expected segments matched by `names`, innermost first.
+    fn consume(&self, names: &[String], remaining: &mut Vec<String>) {
+        for name in names.iter().rev() {
+            if remaining
+                .last()
+                .is_some_and(|last| self.names_match(last, name))
+            {
+                remaining.pop();
+            }
+        }
+    }
 
-    for line in source.lines() {
-        let trimmed = line.trim();
-        if let Some(rest) = trimmed
-            .strip_prefix("class ")
-            .or_else(|| trimmed.strip_prefix("module "))
-        {
-            let def_name = rest
-                .split(|c: char| c == '<' || c == '(' || c.is_whitespace())
-                .next()
-                .unwrap_or("")
-                .trim();
-            if def_name == fqn || def_name == last_name {
-                return true;
+    fn check_definition(&mut self, path: &[String]) {
+        let Some((name, namespace)) = path.split_last() else {
+            return;
+        };
+        let Some((expected_name, expected_namespace)) = self.expected.split_last() else {
+            return;
+        };
+        if !self.names_match(expected_name, name) {
+            return;
+        }
+        let mut remaining = expected_namespace.to_vec();
+        if remaining.is_empty() {
+            self.found = true;
+            return;
+        }
+        self.consume(namespace, &mut remaining);
+        for ancestor in self.ancestors.iter().rev() {
+            match ancestor {
+                Some(names) => self.consume(names, &mut remaining),
+                None => return,
             }
         }
+        if remaining.is_empty() || remaining == ["Object"] {
+            self.found = true;
+        }
     }
-    false
+
+    fn with_definition(&mut self, path: Option<Vec<String>>, visit: impl FnOnce(&mut Self)) {
+        if let Some(names) = &path {
+            self.check_definition(names);
+        }
+        self.ancestors.push(path);
+        visit(self);
+        self.ancestors.pop();
+    }
+}
+
+fn segments(node: &ruby_prism::Node<'_>) -> Vec<String> {
+    constant_path_segments(node)
+        .into_iter()
+        .map(|s| String::from_utf8_lossy(s).into_owned())
+        .collect()
+}
+
+/// `Class.new` or `Module.new` (optionally `::Class`), with or without a block.
+fn is_class_or_module_new(value: &ruby_prism::Node<'_>) -> bool {
+    let Some(call) = value.as_call_node() else {
+        return false;
+    };
+    if call.name().as_slice() != b"new" {
+        return false;
+    }
+    let Some(receiver) = call.receiver() else {
+        return false;
+    };
+    let global = receiver.as_constant_read_node().is_some()
+        || receiver
+            .as_constant_path_node()
+            .is_some_and(|path| path.parent().is_none());
+    global
+        && constant_short_name(&receiver).is_some_and(|name| name == b"Class" || name == b"Module")
+}
+
+impl<'pr> Visit<'pr> for DefinitionFinder<'_> {
+    fn visit_class_node(&mut self, node: &ruby_prism::ClassNode<'pr>) {
+        let path = segments(&node.constant_path());
+        self.with_definition(Some(path), |this| {
+            ruby_prism::visit_class_node(this, node);
+        });
+    }
+
+    fn visit_module_node(&mut self, node: &ruby_prism::ModuleNode<'pr>) {
+        let path = segments(&node.constant_path());
+        self.with_definition(Some(path), |this| {
+            ruby_prism::visit_module_node(this, node);
+        });
+    }
+
+    fn visit_singleton_class_node(&mut self, node: &ruby_prism::SingletonClassNode<'pr>) {
+        self.with_definition(None, |this| {
+            ruby_prism::visit_singleton_class_node(this, node);
+        });
+    }
+
+    fn visit_constant_write_node(&mut self, node: &ruby_prism::ConstantWriteNode<'pr>) {
+        if !is_class_or_module_new(&node.value()) {
+            ruby_prism::visit_constant_write_node(self, node);
+            return;
+        }
+        let path = vec![String::from_utf8_lossy(node.name().as_slice()).into_owned()];
+        self.with_definition(Some(path), |this| {
+            ruby_prism::visit_constant_write_node(this, node);
+        });
+    }
+
+    fn visit_constant_path_write_node(&mut self, node: &ruby_prism::ConstantPathWriteNode<'pr>) {
+        if !is_class_or_module_new(&node.value()) {
+            ruby_prism::visit_constant_path_write_node(self, node);
+            return;
+        }
+        let path = segments(&node.target().as_node());
+        self.with_definition(Some(path), |this| {
+            ruby_prism::visit_constant_path_write_node(this, node);
+        });
+    }
+}
+
+/// Whether the program defines the constant path `expected`.
+fn has_matching_definition(
+    parse_result: &ruby_prism::ParseResult<'_>,
+    expected: &[String],
+    acronyms: &[String],
+) -> bool {
+    let mut finder = DefinitionFinder {
+        expected,
+        acronyms,
+        ancestors: Vec::new(),
+        found: false,
+    };
+    finder.visit(&parse_result.node());
+    finder.found
 }
 
 fn skip_for_invalid_utf8_without_magic_encoding(source: &SourceFile) -> bool {
@@ -257,11 +413,68 @@ fn has_magic_encoding_comment(source: &[u8]) -> bool {
     false
 }
 
+/// The file's stem when its name is subject to this cop: not a gemspec and
+/// not one of the well-known CamelCase files.
+fn checked_stem(path: &Path) -> Option<&str> {
+    let file_stem = path.file_stem().and_then(|s| s.to_str())?;
+
+    // Gemspecs are allowed to have dashes (bundler convention for namespaced gems)
+    if path.extension().and_then(|e| e.to_str()) == Some("gemspec") {
+        return None;
+    }
+
+    // Allow well-known Ruby files — only when the full filename (with extension)
+    // exactly matches an allowed name. RuboCop's `allowed_camel_case_file?` checks
+    // AllCops/Include patterns like `**/Rakefile` which match the exact filename,
+    // NOT `Rakefile.rb` or `Vagrantfile.spec`.
+    let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+    if ALLOWED_NAMES.contains(&file_name) {
+        return None;
+    }
+
+    // Allow files whose full name (with extension) ends with a known CamelCase name
+    // (e.g., ImportFastfile, SwitcherFastfile). This matches RuboCop's
+    // `allowed_camel_case_file?` which checks AllCops/Include patterns containing
+    // uppercase letters like `**/*Fastfile`.
+    if ALLOWED_NAMES
+        .iter()
+        .any(|name| file_name.len() > name.len() && file_name.ends_with(name))
+    {
+        return None;
+    }
+    Some(file_stem)
+}
+
+/// RuboCop's `filename_good?`: the stem matches `Regex` when one is
+/// configured, snake_case otherwise. An invalid `Regex` accepts every name.
+fn filename_good(file_stem: &str, regex_pattern: &str) -> bool {
+    // RuboCop strips leading dot from dotfiles before checking (e.g., .pryrc -> pryrc)
+    // RuboCop replaces + with _ before the snake_case check, to support
+    // Action Pack Variants filenames like `some_file.xlsx+mobile.axlsx`.
+    // Note: AllowedAcronyms is NOT applied to the filename check — RuboCop's
+    // filename_good? only uses the SNAKE_CASE regex without acronym substitution.
+    // AllowedAcronyms is only used for ExpectMatchingDefinition matching.
+    let check_name = file_stem
+        .strip_prefix('.')
+        .unwrap_or(file_stem)
+        .replacen('+', "_", 1);
+
+    if !regex_pattern.is_empty() {
+        return regex::Regex::new(regex_pattern).map_or(true, |re| re.is_match(&check_name));
+    }
+
+    // RuboCop allows dots in filenames (e.g., show.html.haml_spec).
+    // Check snake_case on each dot-separated segment individually.
+    check_name.split('.').all(is_filename_snake_case)
+}
+
 impl Cop for FileName {
     fn name(&self) -> &'static str {
         "Naming/FileName"
     }
 
+    /// The filename check. Runs on every file, including non-UTF-8 ones with
+    /// an encoding comment, which never reach `check_source`.
     fn check_lines(
         &self,
         source: &SourceFile,
@@ -272,114 +485,79 @@ impl Cop for FileName {
         if skip_for_invalid_utf8_without_magic_encoding(source) {
             return;
         }
-
-        let expect_matching_definition = config.get_bool("ExpectMatchingDefinition", false);
-        let check_def_path_hierarchy = config.get_bool("CheckDefinitionPathHierarchy", true);
-        let check_def_path_roots = config.get_string_array("CheckDefinitionPathHierarchyRoots");
-        let regex_pattern = config.get_str("Regex", "");
-        let ignore_executable_scripts = config.get_bool("IgnoreExecutableScripts", true);
-        let _allowed_acronyms = config.get_string_array("AllowedAcronyms");
-
-        let path = Path::new(source.path_str());
-        let file_stem = match path.file_stem().and_then(|s| s.to_str()) {
-            Some(s) => s,
-            None => return,
+        let Some(file_stem) = checked_stem(Path::new(source.path_str())) else {
+            return;
         };
-
-        // Gemspecs are allowed to have dashes (bundler convention for namespaced gems)
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        if ext == "gemspec" {
+        let regex_pattern = config.get_str("Regex", "");
+        if filename_good(file_stem, regex_pattern) {
             return;
         }
 
-        // IgnoreExecutableScripts: skip files with shebang (#!) on first line
-        if ignore_executable_scripts {
-            let bytes = source.as_bytes();
-            if bytes.starts_with(b"#!") {
-                return;
-            }
+        // IgnoreExecutableScripts: a bad name is fine for a script with a
+        // shebang (#!) on the first line
+        if config.get_bool("IgnoreExecutableScripts", true) && source.as_bytes().starts_with(b"#!")
+        {
+            return;
         }
 
-        // Allow well-known Ruby files — only when the full filename (with extension)
-        // exactly matches an allowed name. RuboCop's `allowed_camel_case_file?` checks
-        // AllCops/Include patterns like `**/Rakefile` which match the exact filename,
-        // NOT `Rakefile.rb` or `Vagrantfile.spec`.
-        let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
-        if ALLOWED_NAMES.contains(&file_name) {
+        let message = if regex_pattern.is_empty() {
+            format!("The name of this source file (`{file_stem}`) should use snake_case.")
+        } else {
+            format!("`{file_stem}` should match `{regex_pattern}`.")
+        };
+        diagnostics.push(self.diagnostic(source, 1, 0, message));
+    }
+
+    /// ExpectMatchingDefinition: a well-named file must define the class or
+    /// module its path implies.
+    fn check_source(
+        &self,
+        source: &SourceFile,
+        parse_result: &ruby_prism::ParseResult<'_>,
+        _code_map: &crate::parse::codemap::CodeMap,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        if !config.get_bool("ExpectMatchingDefinition", false) {
             return;
         }
-
-        // Allow files whose full name (with extension) ends with a known CamelCase name
-        // (e.g., ImportFastfile, SwitcherFastfile). This matches RuboCop's
-        // `allowed_camel_case_file?` which checks AllCops/Include patterns containing
-        // uppercase letters like `**/*Fastfile`.
-        if ALLOWED_NAMES
-            .iter()
-            .any(|name| file_name.len() > name.len() && file_name.ends_with(name))
-        {
+        let path = Path::new(source.path_str());
+        let Some(file_stem) = checked_stem(path) else {
+            return;
+        };
+        if !filename_good(file_stem, config.get_str("Regex", "")) {
             return;
         }
 
-        // Regex: if a custom regex is provided, use it instead of snake_case check
-        if !regex_pattern.is_empty() {
-            if let Ok(re) = regex::Regex::new(regex_pattern) {
-                if re.is_match(file_stem) {
-                    return;
-                }
-                diagnostics.push(self.diagnostic(
-                    source,
-                    1,
-                    0,
-                    format!(
-                        "The name of this source file (`{file_stem}`) should match the configured Regex."
-                    ),
-                ));
+        let acronyms = config
+            .get_string_array("AllowedAcronyms")
+            .unwrap_or_default();
+        let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+        let mut expected = None;
+        if config.get_bool("CheckDefinitionPathHierarchy", true) {
+            let roots = config.get_string_array("CheckDefinitionPathHierarchyRoots");
+            let namespace = build_expected_namespace(path, &roots);
+            if !has_matching_definition(parse_result, &namespace, &acronyms) {
+                expected = Some(namespace);
+            }
+        }
+        if expected.is_none() {
+            let own = vec![to_module_name(file_name)];
+            if !has_matching_definition(parse_result, &own, &acronyms) {
+                expected = Some(own);
             }
         }
 
-        // RuboCop strips leading dot from dotfiles before checking (e.g., .pryrc -> pryrc)
-        // RuboCop replaces + with _ before the snake_case check, to support
-        // Action Pack Variants filenames like `some_file.xlsx+mobile.axlsx`.
-        // Note: AllowedAcronyms is NOT applied to the filename check — RuboCop's
-        // filename_good? only uses the SNAKE_CASE regex without acronym substitution.
-        // AllowedAcronyms is only used for ExpectMatchingDefinition matching.
-        let mut check_name = file_stem.strip_prefix('.').unwrap_or(file_stem).to_string();
-        check_name = check_name.replacen('+', "_", 1);
-
-        // RuboCop allows dots in filenames (e.g., show.html.haml_spec).
-        // Check snake_case on each dot-separated segment individually.
-        let all_segments_snake = check_name.split('.').all(is_filename_snake_case);
-        if !all_segments_snake {
+        if let Some(expected) = expected {
+            let namespace_str = expected.join("::");
             diagnostics.push(self.diagnostic(
                 source,
                 1,
                 0,
-                format!("The name of this source file (`{file_stem}`) should use snake_case."),
+                format!("`{file_stem}` should define a class or module called `{namespace_str}`."),
             ));
         }
-
-        // ExpectMatchingDefinition: require that the file defines a class/module matching the filename
-        if expect_matching_definition {
-            let source_text = std::str::from_utf8(&source.content).unwrap_or("");
-
-            let expected_namespace = if check_def_path_hierarchy {
-                build_expected_namespace(path, &check_def_path_roots)
-            } else {
-                vec![to_module_name(file_stem)]
-            };
-
-            if !has_matching_definition(source_text, &expected_namespace) {
-                let namespace_str = expected_namespace.join("::");
-                diagnostics.push(self.diagnostic(
-                    source,
-                    1,
-                    0,
-                    format!(
-                        "`{file_stem}` should define a class or module called `{namespace_str}`."
-                    ),
-                ));
-            }
-        }
     }
 }
 
@@ -573,9 +751,8 @@ mod tests {
             )]),
             ..CopConfig::default()
         };
-        let source = SourceFile::from_bytes("my_class.rb", b"x = 1\n".to_vec());
-        let mut diags = Vec::new();
-        FileName.check_lines(&source, &config, &mut diags, None);
+        let diags =
+            crate::testutil::run_cop_full_internal(&FileName, b"x = 1\n", config, "my_class.rb");
         assert!(
             !diags.is_empty(),
             "ExpectMatchingDefinition should flag file without matching class"
@@ -593,9 +770,12 @@ mod tests {
             )]),
             ..CopConfig::default()
         };
-        let source = SourceFile::from_bytes("my_class.rb", b"class MyClass\nend\n".to_vec());
-        let mut diags = Vec::new();
-        FileName.check_lines(&source, &config, &mut diags, None);
+        let diags = crate::testutil::run_cop_full_internal(
+            &FileName,
+            b"class MyClass\nend\n",
+            config,
+            "my_class.rb",
+        );
         assert!(
             diags.is_empty(),
             "ExpectMatchingDefinition should accept matching class"
@@ -618,12 +798,12 @@ mod tests {
             ]),
             ..CopConfig::default()
         };
-        let source = SourceFile::from_bytes(
+        let diags = crate::testutil::run_cop_full_internal(
+            &FileName,
+            b"class MyGem::MyClass\nend\n",
+            config,
             "lib/my_gem/my_class.rb",
-            b"class MyGem::MyClass\nend\n".to_vec(),
         );
-        let mut diags = Vec::new();
-        FileName.check_lines(&source, &config, &mut diags, None);
         assert!(diags.is_empty(), "Should accept matching namespaced class");
     }
 
@@ -772,13 +952,89 @@ mod tests {
             ]),
             ..CopConfig::default()
         };
-        let source =
-            SourceFile::from_bytes("lib/my_gem/my_class.rb", b"class MyClass\nend\n".to_vec());
-        let mut diags = Vec::new();
-        FileName.check_lines(&source, &config, &mut diags, None);
+        let diags = crate::testutil::run_cop_full_internal(
+            &FileName,
+            b"class MyClass\nend\n",
+            config,
+            "lib/my_gem/my_class.rb",
+        );
         assert!(
             diags.is_empty(),
             "Without hierarchy check, just the class name should match"
         );
     }
+
+    const MATCHING_DEFINITION_YAML: &str = "\
+ExpectMatchingDefinition: true
+CheckDefinitionPathHierarchyRoots: [lib, app/models]
+AllowedAcronyms: [API, HTTP]
+";
+
+    #[test]
+    fn matching_definition_offense_fixtures() {
+        for fixture in [
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/offense/nested_namespace.rb").as_slice(),
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/offense/acronym_not_allowed.rb").as_slice(),
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/offense/missing_root_namespace.rb").as_slice(),
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/offense/singleton_class.rb").as_slice(),
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/offense/executable_script.rb").as_slice(),
+        ] {
+            crate::testutil::assert_cop_offenses_with_yaml(
+                &FileName,
+                MATCHING_DEFINITION_YAML,
+                fixture,
+            );
+        }
+    }
+
+    #[test]
+    fn matching_definition_no_offense_fixtures() {
+        for fixture in [
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/no_offense/nested_namespace.rb").as_slice(),
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/no_offense/compact_namespace.rb").as_slice(),
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/no_offense/allowed_acronyms.rb").as_slice(),
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/no_offense/multi_directory_root.rb").as_slice(),
+            include_bytes!("../../../tests/fixtures/cops/naming/file_name/matching_definition/no_offense/executable_script_bad_name.rb").as_slice(),
+        ] {
+            crate::testutil::assert_cop_no_offenses_with_yaml(
+                &FileName,
+                MATCHING_DEFINITION_YAML,
+                fixture,
+            );
+        }
+    }
+
+    #[test]
+    fn regex_replaces_snake_case_check() {
+        let config = crate::testutil::cop_config_from_yaml(r"Regex: '\A[A-Z][a-zA-Z]*\z'");
+        let source = SourceFile::from_bytes("MyClass.rb", b"x = 1\n".to_vec());
+        let mut diags = Vec::new();
+        FileName.check_lines(&source, &config, &mut diags, None);
+        assert!(diags.is_empty(), "CamelCase name matches the Regex");
+
+        let source = SourceFile::from_bytes("my_class.rb", b"x = 1\n".to_vec());
+        FileName.check_lines(&source, &config, &mut diags, None);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            r"`my_class` should match `\A[A-Z][a-zA-Z]*\z`."
+        );
+    }
+
+    #[test]
+    fn expected_namespace_uses_closest_root() {
+        let roots = Some(vec!["lib".to_string(), "app/models".to_string()]);
+        assert_eq!(
+            build_expected_namespace(Path::new("/src/lib/gem/lib/foo/bar_baz.rb"), &roots),
+            ["Foo", "BarBaz"]
+        );
+        assert_eq!(
+            build_expected_namespace(Path::new("app/models/billing/invoice.rb"), &roots),
+            ["Billing", "Invoice"]
+        );
+        assert_eq!(
+            build_expected_namespace(Path::new("app/controllers/home.rb"), &roots),
+            ["Home"]
+        );
+    }
 }
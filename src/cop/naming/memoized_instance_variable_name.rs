@@ -105,6 +105,14 @@ use crate::parse::source::SourceFile;
 /// has shebang `#!/usr/bin/env rbx` (Rubinius), and `has_ruby_shebang()` in `fs.rs` only
 /// checked for "ruby" in the shebang line. Fix: expanded `has_ruby_shebang()` to recognize
 /// all interpreters from RuboCop's `AllCops.RubyInterpreters` (ruby, macruby, rake, jruby, rbx).
+///
+/// ## EnforcedStyleForLeadingUnderscores
+///
+/// `required` and `optional` now use RuboCop's full candidate lists (see
+/// `variable_name_candidates`): under `required`, a method that itself starts
+/// with `_` may also memoize into `@_name`; `optional` also accepts the name
+/// without the method's leading underscore. Messages name the ivar to use,
+/// and `required` reports a missing underscore as such.
 pub struct MemoizedInstanceVariableName;
 
 impl MemoizedInstanceVariableName {
@@ -155,36 +163,18 @@ impl MemoizedInstanceVariableName {
         let ivar_str = std::str::from_utf8(ivar_name).unwrap_or("");
         let ivar_base = ivar_str.strip_prefix('@').unwrap_or(ivar_str);
 
-        let matches = match leading_underscore_style {
-            "required" => {
-                // @_method_name is the only valid form
-                let expected = format!("_{base_name}");
-                ivar_base == expected
-            }
-            "optional" => {
-                // Both @method_name and @_method_name are valid
-                let with_underscore = format!("_{base_name}");
-                ivar_base == base_name || ivar_base == with_underscore
-            }
-            _ => {
-                // "disallowed" (default): @method_name or @method_name_without_leading_underscore
-                // RuboCop's variable_name_candidates returns [method_name, no_underscore]
-                ivar_base == base_name
-                    || base_name
-                        .strip_prefix('_')
-                        .is_some_and(|stripped| ivar_base == stripped)
-            }
-        };
-
-        if !matches {
+        if !matches_method(ivar_base, base_name, leading_underscore_style) {
             let loc = or_write.name_loc();
             let (line, column) = source.offset_to_line_col(loc.start_offset());
             return vec![self.diagnostic(
                 source,
                 line,
                 column,
-                format!(
-                    "Memoized variable `@{ivar_base}` does not match method name `{method_name_str}`."
+                offense_message(
+                    ivar_base,
+                    method_name_str,
+                    base_name,
+                    leading_underscore_style,
                 ),
             )];
         }
@@ -324,36 +314,11 @@ impl MemoizedInstanceVariableName {
         method_name_str: &str,
         enforced_style: &str,
     ) -> Vec<Diagnostic> {
-        let matches = match enforced_style {
-            "required" => {
-                let expected = format!("_{base_name}");
-                ivar_base == expected
-            }
-            "optional" => {
-                let with_underscore = format!("_{base_name}");
-                ivar_base == base_name || ivar_base == with_underscore
-            }
-            _ => {
-                // "disallowed" (default): @method_name or @method_name_without_leading_underscore
-                ivar_base == base_name
-                    || base_name
-                        .strip_prefix('_')
-                        .is_some_and(|stripped| ivar_base == stripped)
-            }
-        };
-
-        if matches {
+        if matches_method(ivar_base, base_name, enforced_style) {
             return Vec::new();
         }
 
-        let suggested = match enforced_style {
-            "required" => format!("_{base_name}"),
-            _ => base_name.to_string(),
-        };
-
-        let msg = format!(
-            "Memoized variable `@{ivar_base}` does not match method name `{method_name_str}`. Use `@{suggested}` instead."
-        );
+        let msg = offense_message(ivar_base, method_name_str, base_name, enforced_style);
 
         // Collect all ivar locations from the defined? pattern:
         // 1. defined?(@ivar) — the ivar inside defined?
@@ -418,6 +383,53 @@ impl MemoizedInstanceVariableName {
     }
 }
 
+/// RuboCop's `variable_name_candidates`: the ivar names (without `@`) that
+/// may memoize a method whose name, minus any trailing `?`, `!` or `=`, is
+/// `base_name`.
+/// - `disallowed` (default): `@name`, or `@name` without the method's
+///   leading underscore.
+/// - `required`: `@_name`, or `@name` when the method itself starts with `_`.
+/// - `optional`: any of the above.
+fn variable_name_candidates(base_name: &str, style: &str) -> Vec<String> {
+    let no_underscore = base_name.strip_prefix('_').unwrap_or(base_name);
+    let with_underscore = format!("_{base_name}");
+    match style {
+        "required" if base_name.starts_with('_') => vec![with_underscore, base_name.to_string()],
+        "required" => vec![with_underscore],
+        "optional" => vec![
+            base_name.to_string(),
+            with_underscore,
+            no_underscore.to_string(),
+        ],
+        _ => vec![base_name.to_string(), no_underscore.to_string()],
+    }
+}
+
+fn matches_method(ivar_base: &str, base_name: &str, style: &str) -> bool {
+    variable_name_candidates(base_name, style)
+        .iter()
+        .any(|candidate| candidate == ivar_base)
+}
+
+/// RuboCop's message, naming the ivar to use. Under `required`, an ivar
+/// without the leading underscore gets its own wording.
+fn offense_message(ivar_base: &str, method_name: &str, base_name: &str, style: &str) -> String {
+    let suggested = if style == "required" {
+        format!("_{base_name}")
+    } else {
+        base_name.to_string()
+    };
+    if style == "required" && !ivar_base.starts_with('_') {
+        format!(
+            "Memoized variable `@{ivar_base}` does not start with `_`. Use `@{suggested}` instead."
+        )
+    } else {
+        format!(
+            "Memoized variable `@{ivar_base}` does not match method name `{method_name}`. Use `@{suggested}` instead."
+        )
+    }
+}
+
 /// Get the "last child" of a node in the Parser AST sense: one level of
 /// `node.children.last`. This replicates how RuboCop's
 /// `body.children.last == node` reaches through different node types.
@@ -891,9 +903,7 @@ impl Cop for MemoizedInstanceVariableName {
                             source,
                             line,
                             column,
-                            format!(
-                                "Memoized variable `@{ivar_base}` does not match method name `{method_name_str}`.",
-                            ),
+                            offense_message(ivar_base, method_name_str, base_name, enforced_style),
                         ));
                     }
                 }
@@ -930,6 +940,56 @@ mod tests {
         "cops/naming/memoized_instance_variable_name"
     );
 
+    #[test]
+    fn leading_underscore_style_fixtures() {
+        let yaml = |style| format!("EnforcedStyleForLeadingUnderscores: {style}\n");
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &MemoizedInstanceVariableName,
+            &yaml("required"),
+            include_bytes!(
+                "../../../tests/fixtures/cops/naming/memoized_instance_variable_name/offense.required.rb"
+            ),
+        );
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &MemoizedInstanceVariableName,
+            &yaml("required"),
+            include_bytes!(
+                "../../../tests/fixtures/cops/naming/memoized_instance_variable_name/no_offense.required.rb"
+            ),
+        );
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &MemoizedInstanceVariableName,
+            &yaml("optional"),
+            include_bytes!(
+                "../../../tests/fixtures/cops/naming/memoized_instance_variable_name/offense.optional.rb"
+            ),
+        );
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &MemoizedInstanceVariableName,
+            &yaml("optional"),
+            include_bytes!(
+                "../../../tests/fixtures/cops/naming/memoized_instance_variable_name/no_offense.optional.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn variable_name_candidates_per_style() {
+        assert_eq!(
+            variable_name_candidates("_foo", "disallowed"),
+            ["_foo", "foo"]
+        );
+        assert_eq!(variable_name_candidates("foo", "required"), ["_foo"]);
+        assert_eq!(
+            variable_name_candidates("_foo", "required"),
+            ["__foo", "_foo"]
+        );
+        assert_eq!(
+            variable_name_candidates("_foo", "optional"),
+            ["_foo", "__foo", "foo"]
+        );
+    }
+
     #[test]
     fn required_style_allows_leading_underscore() {
         use crate::cop::CopConfig;
@@ -81,11 +81,23 @@ use ruby_prism::Visit;
 /// `c.loc.name.is?(name.to_s)` compares the full constant path text
 /// ("HighLine::String" != "String"), so it correctly flags the offense.
 /// Fixed by using the full constant path text in `collect_emitter_name`.
+///
+/// ## Configuration
+///
+/// `EnforcedStyle` is `snake_case` (default) or `camelCase`. Names listed in
+/// `AllowedIdentifiers` or matching one of `AllowedPatterns` (Ruby-style
+/// regexes such as `\AGET_`) are exempt from both the style check and the
+/// forbidden-name checks. The same checks apply to every way of defining a
+/// method: `def`, `define_method`, `attr_*` symbols, `alias`/`alias_method`
+/// new names, and `Struct.new`/`Data.define` members. Operator methods are
+/// skipped, and the `!`, `?` and `=` suffixes are ignored, so setters are
+/// checked by their base name.
 pub struct MethodName;
 
 /// Bundles config values needed for method name checking.
 struct MethodNameConfig {
     enforced_style: String,
+    allowed_identifiers: Option<Vec<String>>,
     allowed_patterns: Option<Vec<String>>,
     forbidden_identifiers: Option<Vec<String>>,
     forbidden_patterns: Option<Vec<String>>,
@@ -95,6 +107,7 @@ impl MethodNameConfig {
     fn from_cop_config(config: &CopConfig) -> Self {
         Self {
             enforced_style: config.get_str("EnforcedStyle", "snake_case").to_string(),
+            allowed_identifiers: config.get_string_array("AllowedIdentifiers"),
             allowed_patterns: config.get_string_array("AllowedPatterns"),
             forbidden_identifiers: config.get_string_array("ForbiddenIdentifiers"),
             forbidden_patterns: config.get_string_array("ForbiddenPatterns"),
@@ -102,9 +115,17 @@ impl MethodNameConfig {
     }
 }
 
-/// Check if a method name matches AllowedPatterns using regex matching.
-fn matches_allowed_pattern(name: &str, allowed_patterns: &Option<Vec<String>>) -> bool {
-    if let Some(patterns) = allowed_patterns {
+/// Check if a method name is listed in AllowedIdentifiers or matches
+/// AllowedPatterns using regex matching.
+fn is_allowed_name(name: &str, cfg: &MethodNameConfig) -> bool {
+    if cfg
+        .allowed_identifiers
+        .as_ref()
+        .is_some_and(|ids| ids.iter().any(|id| id == name))
+    {
+        return true;
+    }
+    if let Some(patterns) = &cfg.allowed_patterns {
         for p in patterns {
             if let Ok(re) = regex::Regex::new(p) {
                 if re.is_match(name) {
@@ -279,7 +300,7 @@ fn check_def_node(
         return;
     }
 
-    if matches_allowed_pattern(method_name_str, &cfg.allowed_patterns) {
+    if is_allowed_name(method_name_str, cfg) {
         return;
     }
 
@@ -434,7 +455,7 @@ fn check_attr_accessor(
             Err(_) => continue,
         };
 
-        if matches_allowed_pattern(name_str, &cfg.allowed_patterns) {
+        if is_allowed_name(name_str, cfg) {
             continue;
         }
 
@@ -548,7 +569,7 @@ fn emit_method_name_offense(
     cfg: &MethodNameConfig,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
-    if matches_allowed_pattern(name_str, &cfg.allowed_patterns) {
+    if is_allowed_name(name_str, cfg) {
         return;
     }
 
@@ -607,7 +628,8 @@ fn is_lower_camel_case(name: &str) -> bool {
     let lead = if first == '_' {
         match chars.next() {
             Some(ch) => ch,
-            None => return false,
+            // A lone `_` is valid in both styles.
+            None => return true,
         }
     } else {
         first
@@ -677,6 +699,72 @@ mod tests {
     use super::*;
     crate::cop_fixture_tests!(MethodName, "cops/naming/method_name");
 
+    fn camel_case_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String("camelCase".into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    fn allowed_config() -> CopConfig {
+        let strings = |values: &[&str]| {
+            serde_yml::Value::Sequence(
+                values
+                    .iter()
+                    .map(|v| serde_yml::Value::String(v.to_string()))
+                    .collect(),
+            )
+        };
+        CopConfig {
+            options: std::collections::HashMap::from([
+                ("AllowedPatterns".into(), strings(&["\\AGET_", "\\APOST_"])),
+                ("AllowedIdentifiers".into(), strings(&["toJSON"])),
+            ]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_camel_case() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &MethodName,
+            include_bytes!("../../../tests/fixtures/cops/naming/method_name/offense.camel_case.rb"),
+            camel_case_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_camel_case() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &MethodName,
+            include_bytes!(
+                "../../../tests/fixtures/cops/naming/method_name/no_offense.camel_case.rb"
+            ),
+            camel_case_config(),
+        );
+    }
+
+    #[test]
+    fn offense_allowed() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &MethodName,
+            include_bytes!("../../../tests/fixtures/cops/naming/method_name/offense.allowed.rb"),
+            allowed_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_allowed() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &MethodName,
+            include_bytes!("../../../tests/fixtures/cops/naming/method_name/no_offense.allowed.rb"),
+            allowed_config(),
+        );
+    }
+
     #[test]
     fn config_enforced_style_camel_case() {
         use crate::testutil::run_cop_full_with_config;
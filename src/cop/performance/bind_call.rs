@@ -30,10 +30,15 @@ impl Cop for BindCall {
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.7
+        if !config.ruby_version_at_least(2.7) {
+            return;
+        }
+
         // Detect: receiver.bind(obj).call(args...)
         // Pattern: (send (send _ :bind $arg) :call $...)
         let outer_call = match node.as_call_node() {
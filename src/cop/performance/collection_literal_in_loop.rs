@@ -306,11 +306,7 @@ impl Cop for CollectionLiteralInLoop {
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let min_size = config.get_usize("MinSize", 1);
-        let target_ruby_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-            .unwrap_or(2.7);
+        let target_ruby_version = config.target_ruby_version();
 
         let mut visitor = CollectionLiteralVisitor {
             cop: self,
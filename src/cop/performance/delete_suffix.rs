@@ -130,6 +130,11 @@ impl Cop for DeleteSuffix {
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.5
+        if !config.ruby_version_at_least(2.5) {
+            return;
+        }
+
         let safe_multiline = config.get_bool("SafeMultiline", true);
         let call = match node.as_call_node() {
             Some(c) => c,
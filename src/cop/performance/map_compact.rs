@@ -19,10 +19,15 @@ impl Cop for MapCompact {
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.7
+        if !config.ruby_version_at_least(2.7) {
+            return;
+        }
+
         let chain = match as_method_chain(node) {
             Some(c) => c,
             None => return,
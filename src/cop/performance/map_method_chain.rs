@@ -1,20 +1,25 @@
-use crate::cop::shared::node_type::{BLOCK_ARGUMENT_NODE, CALL_NODE, SYMBOL_NODE};
+use crate::cop::shared::node_type::CALL_NODE;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
+/// Performance/MapMethodChain
+///
+/// Flags chains like `map(&:foo).map(&:bar)` and suggests a single block,
+/// e.g. "Use `map { |x| x.foo.bar }` instead of `map` method chain." The
+/// method name in the message is the one at the start of the chain. Upstream
+/// marks the cop `Safe: false` and provides no autocorrect.
 pub struct MapMethodChain;
 
-/// Check if a call node has a block_pass argument with a symbol (e.g., `&:foo`).
+/// The symbol name of a `&:foo` block_pass argument, if the call has one.
+fn symbol_block_pass(call: &ruby_prism::CallNode<'_>) -> Option<String> {
+    let block_arg = call.block()?.as_block_argument_node()?;
+    let sym = block_arg.expression()?.as_symbol_node()?;
+    Some(String::from_utf8_lossy(sym.unescaped()).into_owned())
+}
+
 fn has_symbol_block_pass(call: &ruby_prism::CallNode<'_>) -> bool {
-    if let Some(block) = call.block() {
-        if let Some(bp) = block.as_block_argument_node() {
-            if let Some(expr) = bp.expression() {
-                return expr.as_symbol_node().is_some();
-            }
-        }
-    }
-    false
+    symbol_block_pass(call).is_some()
 }
 
 /// Check if a call is a map/collect call.
@@ -33,7 +38,7 @@ impl Cop for MapMethodChain {
     }
 
     fn interested_node_types(&self) -> &'static [u8] {
-        &[BLOCK_ARGUMENT_NODE, CALL_NODE, SYMBOL_NODE]
+        &[CALL_NODE]
     }
 
     fn check_node(
@@ -58,32 +63,44 @@ impl Cop for MapMethodChain {
             return;
         }
 
-        if !is_map_or_collect(&outer_call) || !has_symbol_block_pass(&outer_call) {
+        if !is_map_or_collect(&outer_call) {
             return;
         }
+        let Some(outer_arg) = symbol_block_pass(&outer_call) else {
+            return;
+        };
 
         // The receiver must also be a map/collect with symbol block_pass
         let inner_node = match outer_call.receiver() {
             Some(r) => r,
             None => return,
         };
-        let inner_call = match inner_node.as_call_node() {
-            Some(c) if is_map_or_collect(&c) && has_symbol_block_pass(&c) => c,
+        let (inner_call, inner_arg) = match inner_node.as_call_node() {
+            Some(c) if is_map_or_collect(&c) => match symbol_block_pass(&c) {
+                Some(arg) => (c, arg),
+                None => return,
+            },
             _ => return,
         };
 
         // Walk down the receiver chain to find the deepest consecutive
-        // map/collect call with symbol block_pass (the chain start).
+        // map/collect call with symbol block_pass (the chain start),
+        // collecting the mapped method names outermost-first.
+        let mut map_args = vec![outer_arg, inner_arg];
         let mut chain_start = inner_call;
         while let Some(recv) = chain_start.receiver() {
             if let Some(c) = recv.as_call_node() {
-                if is_map_or_collect(&c) && has_symbol_block_pass(&c) {
-                    chain_start = c;
-                    continue;
+                if is_map_or_collect(&c) {
+                    if let Some(arg) = symbol_block_pass(&c) {
+                        map_args.push(arg);
+                        chain_start = c;
+                        continue;
+                    }
                 }
             }
             break;
         }
+        map_args.reverse();
 
         // RuboCop quirk: when walking down the chain, if the receiver of the
         // chain start is a non-map/collect call that also has a symbol block_pass
@@ -114,12 +131,15 @@ impl Cop for MapMethodChain {
             return;
         }
 
+        let method_name = String::from_utf8_lossy(chain_start.name().as_slice());
         diagnostics.push(self.diagnostic(
             source,
             line,
             column,
-            "Use `map` with a block instead of chaining multiple `map` calls with symbol arguments."
-                .to_string(),
+            format!(
+                "Use `{method_name} {{ |x| x.{} }}` instead of `{method_name}` method chain.",
+                map_args.join(".")
+            ),
         ));
     }
 }
@@ -98,6 +98,11 @@ impl Cop for RedundantEqualityComparisonBlock {
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.5
+        if !config.ruby_version_at_least(2.5) {
+            return;
+        }
+
         let allow_regexp_match = config.get_bool("AllowRegexpMatch", true);
         let call = match node.as_call_node() {
             Some(c) => c,
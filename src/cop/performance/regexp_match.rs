@@ -64,12 +64,7 @@ impl Cop for RegexpMatch {
     ) {
         // RuboCop: minimum_target_ruby_version 2.4
         // match? was added in Ruby 2.4, so this cop only applies for 2.4+.
-        let ruby_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-            .unwrap_or(2.7);
-        if ruby_version < 2.4 {
+        if !config.ruby_version_at_least(2.4) {
             return;
         }
 
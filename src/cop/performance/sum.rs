@@ -51,6 +51,11 @@ impl Cop for Sum {
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.4
+        if !config.ruby_version_at_least(2.4) {
+            return;
+        }
+
         let only_sum_or_with_initial_value = config.get_bool("OnlySumOrWithInitialValue", false);
         let call = match node.as_call_node() {
             Some(c) => c,
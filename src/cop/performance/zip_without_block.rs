@@ -1,8 +1,4 @@
-use crate::cop::shared::node_type::{
-    ARRAY_NODE, BLOCK_NODE, BLOCK_PARAMETERS_NODE, CALL_NODE, IT_LOCAL_VARIABLE_READ_NODE,
-    IT_PARAMETERS_NODE, LOCAL_VARIABLE_READ_NODE, NUMBERED_PARAMETERS_NODE,
-    REQUIRED_PARAMETER_NODE, STATEMENTS_NODE,
-};
+use crate::cop::shared::node_type::CALL_NODE;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
@@ -17,6 +13,10 @@ use crate::parse::source::SourceFile;
 /// Missed `NumberedParametersNode` (`_1`) and `ItParametersNode` (`it`).
 /// Fix: added branches for both implicit parameter styles, checking that the
 /// block body is `[_1]` / `[it]` respectively.
+///
+/// The offense spans from the selector to the end of the block and is
+/// autocorrected to `zip`. Upstream marks the cop `Safe: false` (the receiver
+/// may not be an Array), so the correction only runs with `-A`.
 pub struct ZipWithoutBlock;
 
 impl Cop for ZipWithoutBlock {
@@ -28,19 +28,16 @@ impl Cop for ZipWithoutBlock {
         Severity::Convention
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
-        &[
-            ARRAY_NODE,
-            BLOCK_NODE,
-            BLOCK_PARAMETERS_NODE,
-            CALL_NODE,
-            IT_LOCAL_VARIABLE_READ_NODE,
-            IT_PARAMETERS_NODE,
-            LOCAL_VARIABLE_READ_NODE,
-            NUMBERED_PARAMETERS_NODE,
-            REQUIRED_PARAMETER_NODE,
-            STATEMENTS_NODE,
-        ]
+        &[CALL_NODE]
     }
 
     fn check_node(
@@ -50,7 +47,7 @@ impl Cop for ZipWithoutBlock {
         _parse_result: &ruby_prism::ParseResult<'_>,
         _config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // Look for CallNode .map or .collect with a block
         let call = match node.as_call_node() {
@@ -174,13 +171,27 @@ impl Cop for ZipWithoutBlock {
             None => return,
         };
 
-        let (line, column) = source.offset_to_line_col(msg_loc.start_offset());
-        diagnostics.push(self.diagnostic(
+        let start = msg_loc.start_offset();
+        let end = block_node.location().end_offset();
+
+        let (line, column) = source.offset_to_line_col(start);
+        let mut diag = self.diagnostic(
             source,
             line,
             column,
             "Use `zip` without a block argument instead.".to_string(),
-        ));
+        );
+        if let Some(corr) = corrections {
+            corr.push(crate::correction::Correction {
+                start,
+                end,
+                replacement: "zip".to_string(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
     }
 }
 
@@ -188,4 +199,5 @@ impl Cop for ZipWithoutBlock {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(ZipWithoutBlock, "cops/performance/zip_without_block");
+    crate::cop_autocorrect_fixture_tests!(ZipWithoutBlock, "cops/performance/zip_without_block");
 }
@@ -1,10 +1,31 @@
-use crate::cop::shared::node_type::{ASSOC_NODE, CALL_NODE, KEYWORD_HASH_NODE, SYMBOL_NODE};
+use crate::cop::shared::node_type::CALL_NODE;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
+/// Rails/EnumSyntax
+///
+/// Flags the pre-Rails 7 keyword-argument enum declaration
+/// (`enum status: { active: 0 }`) and underscore-prefixed options
+/// (`_prefix:`, `_scopes:`, ...) on positional declarations.
+///
+/// Gated on `minimum_target_rails_version 7.0` and
+/// `minimum_target_ruby_version 3.0`, matching rubocop-rails.
+///
+/// The offense for a keyword-argument declaration is reported on the enum
+/// values, once per enum key. Autocorrect rewrites the whole `enum` call as
+/// `enum :name, values, options...` but, like rubocop-rails, skips calls that
+/// declare more than one enum (`enum a: {...}, b: {...}`), which would need a
+/// multi-line rewrite. Option keys are corrected by dropping the `_` prefix.
 pub struct EnumSyntax;
 
+const MSG: &str = "Enum defined with keyword arguments in `%ENUM%` enum declaration. Use positional arguments instead.";
+const MSG_OPTIONS: &str =
+    "Enum defined with deprecated options in `%ENUM%` enum declaration. Remove the `_` prefix.";
+
+/// Option names accepted by `ActiveRecord::Enum#enum` (Rails 7.2).
+const OPTION_NAMES: &[&str] = &["prefix", "suffix", "scopes", "default", "instance_methods"];
+
 impl Cop for EnumSyntax {
     fn name(&self) -> &'static str {
         "Rails/EnumSyntax"
@@ -14,8 +35,16 @@ impl Cop for EnumSyntax {
         Severity::Convention
     }
 
+    fn default_include(&self) -> &'static [&'static str] {
+        &["**/app/models/**/*.rb"]
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
-        &[ASSOC_NODE, CALL_NODE, KEYWORD_HASH_NODE, SYMBOL_NODE]
+        &[CALL_NODE]
     }
 
     fn check_node(
@@ -25,10 +54,9 @@ impl Cop for EnumSyntax {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        mut corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
-        // minimum_target_rails_version 7.0
-        if !config.rails_version_at_least(7.0) {
+        if !config.rails_version_at_least(7.0) || config.target_ruby_version() < 3.0 {
             return;
         }
 
@@ -37,52 +65,219 @@ impl Cop for EnumSyntax {
             None => return,
         };
 
-        if call.receiver().is_some() {
+        if call.receiver().is_some() || call.name().as_slice() != b"enum" {
             return;
         }
 
-        if call.name().as_slice() != b"enum" {
-            return;
-        }
-
-        let args = match call.arguments() {
-            Some(a) => a,
+        let args: Vec<_> = match call.arguments() {
+            Some(a) => a.arguments().iter().collect(),
             None => return,
         };
 
-        let arg_list: Vec<_> = args.arguments().iter().collect();
-        if arg_list.is_empty() {
-            return;
-        }
+        match args.as_slice() {
+            // Old syntax: `enum status: { active: 0 }, _prefix: true`
+            [hash] => {
+                let Some(pairs) = hash_pairs(hash) else {
+                    return;
+                };
+                for (i, pair) in pairs.iter().enumerate() {
+                    if is_option_key(source, &pair.key()) {
+                        continue;
+                    }
+                    let value = pair.value();
+                    let message = MSG.replace("%ENUM%", &enum_name_value(source, &pair.key()));
+                    let (line, column) = source.offset_to_line_col(value.location().start_offset());
+                    let mut diag = self.diagnostic(source, line, column, message);
 
-        // Old syntax: enum status: { active: 0 }
-        // The first argument is a KeywordHashNode containing status: { ... }
-        // New syntax: enum :status, { active: 0 }
-        // The first argument is a SymbolNode
-        if arg_list[0].as_symbol_node().is_some() {
-            // Already using new syntax
-            return;
-        }
+                    if let Some(ref mut corr) = corrections {
+                        if !multiple_enum_definitions(source, &pairs) {
+                            let loc = node.location();
+                            corr.push(crate::correction::Correction {
+                                start: loc.start_offset(),
+                                end: loc.end_offset(),
+                                replacement: preferred_syntax(source, pair, &pairs[i + 1..]),
+                                cop_name: self.name(),
+                                cop_index: 0,
+                            });
+                            diag.corrected = true;
+                        }
+                    }
+                    diagnostics.push(diag);
+                }
+            }
+            // New syntax with old options: `enum :status, { active: 0 }, _prefix: true`
+            [name, values, options]
+                if values.as_array_node().is_some() || values.as_hash_node().is_some() =>
+            {
+                let Some(option_pairs) = hash_pairs(options) else {
+                    return;
+                };
+                for option in &option_pairs {
+                    let key = option.key();
+                    if !is_option_key(source, &key) {
+                        continue;
+                    }
+                    let (start, _) = key_range(&key);
+                    let message = MSG_OPTIONS.replace("%ENUM%", &enum_name_value(source, name));
+                    let (line, column) = source.offset_to_line_col(start);
+                    let mut diag = self.diagnostic(source, line, column, message);
 
-        // Check if first arg is a keyword hash with a symbol key mapped to a hash value
-        if let Some(kw) = arg_list[0].as_keyword_hash_node() {
-            for elem in kw.elements().iter() {
-                if let Some(assoc) = elem.as_assoc_node() {
-                    if assoc.key().as_symbol_node().is_some() {
-                        // This is old syntax: enum status: { ... } or enum status: [...]
-                        let loc = node.location();
-                        let (line, column) = source.offset_to_line_col(loc.start_offset());
-                        diagnostics.push(self.diagnostic(
-                            source,
-                            line,
-                            column,
-                            "Use Rails 7+ enum syntax: `enum :status, { active: 0 }`.".to_string(),
-                        ));
+                    if let Some(ref mut corr) = corrections {
+                        // Drop the leading `_`.
+                        corr.push(crate::correction::Correction {
+                            start,
+                            end: start + 1,
+                            replacement: String::new(),
+                            cop_name: self.name(),
+                            cop_index: 0,
+                        });
+                        diag.corrected = true;
                     }
+                    diagnostics.push(diag);
                 }
             }
+            _ => {}
+        }
+    }
+}
+
+/// The `key => value` pairs of a hash or keyword-hash argument. Double
+/// splats are skipped.
+fn hash_pairs<'pr>(node: &ruby_prism::Node<'pr>) -> Option<Vec<ruby_prism::AssocNode<'pr>>> {
+    let elements = if let Some(hash) = node.as_keyword_hash_node() {
+        hash.elements()
+    } else if let Some(hash) = node.as_hash_node() {
+        hash.elements()
+    } else {
+        return None;
+    };
+    Some(elements.iter().filter_map(|e| e.as_assoc_node()).collect())
+}
+
+/// Byte range of a hash key as Parser sees it: a label key (`status:`)
+/// excludes its trailing colon.
+fn key_range(key: &ruby_prism::Node<'_>) -> (usize, usize) {
+    let loc = key.location();
+    let mut end = loc.end_offset();
+    if let Some(sym) = key.as_symbol_node() {
+        if sym.opening_loc().is_none() {
+            if let Some(value) = sym.value_loc() {
+                end = value.end_offset();
+            }
+        }
+    }
+    (loc.start_offset(), end)
+}
+
+fn key_source<'a>(source: &'a SourceFile, key: &ruby_prism::Node<'_>) -> &'a str {
+    let (start, end) = key_range(key);
+    source.byte_slice(start, end, "")
+}
+
+fn node_source<'a>(source: &'a SourceFile, node: &ruby_prism::Node<'_>) -> &'a str {
+    let loc = node.location();
+    source.byte_slice(loc.start_offset(), loc.end_offset(), "")
+}
+
+/// RuboCop's `option_key?`: the key source is one of `_prefix`, `_suffix`,
+/// `_scopes`, `_default`, `_instance_methods`.
+fn is_option_key(source: &SourceFile, key: &ruby_prism::Node<'_>) -> bool {
+    key_source(source, key)
+        .strip_prefix('_')
+        .is_some_and(|name| OPTION_NAMES.contains(&name))
+}
+
+fn multiple_enum_definitions(source: &SourceFile, pairs: &[ruby_prism::AssocNode<'_>]) -> bool {
+    pairs
+        .iter()
+        .map(|pair| {
+            let key = key_source(source, &pair.key());
+            key.strip_prefix('_').unwrap_or(key).to_string()
+        })
+        .filter(|key| !OPTION_NAMES.contains(&key.as_str()))
+        .count()
+        >= 2
+}
+
+/// The enum name as shown in messages: the value of a symbol or string key,
+/// otherwise its source.
+fn enum_name_value(source: &SourceFile, key: &ruby_prism::Node<'_>) -> String {
+    if let Some(sym) = key.as_symbol_node() {
+        return String::from_utf8_lossy(sym.unescaped()).into_owned();
+    }
+    if let Some(s) = key.as_string_node() {
+        return String::from_utf8_lossy(s.unescaped()).into_owned();
+    }
+    node_source(source, key).to_string()
+}
+
+/// The enum name as written in the corrected call: `:status` for symbols
+/// (Ruby's `Symbol#inspect`), `"status"` for strings (`String#dump`).
+fn enum_name(source: &SourceFile, key: &ruby_prism::Node<'_>) -> String {
+    if let Some(sym) = key.as_symbol_node() {
+        let value = String::from_utf8_lossy(sym.unescaped()).into_owned();
+        if is_plain_symbol(&value) {
+            return format!(":{value}");
+        }
+        return format!(":{}", ruby_dump(&value));
+    }
+    if let Some(s) = key.as_string_node() {
+        return ruby_dump(&String::from_utf8_lossy(s.unescaped()));
+    }
+    node_source(source, key).to_string()
+}
+
+fn is_plain_symbol(value: &str) -> bool {
+    let name = value.strip_suffix(['?', '!', '=']).unwrap_or(value);
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn ruby_dump(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            '#' if matches!(chars.peek(), Some('{' | '$' | '@')) => out.push_str("\\#"),
+            c => out.push(c),
         }
     }
+    out.push('"');
+    out
+}
+
+fn preferred_syntax(
+    source: &SourceFile,
+    pair: &ruby_prism::AssocNode<'_>,
+    options: &[ruby_prism::AssocNode<'_>],
+) -> String {
+    let mut result = format!(
+        "enum {}, {}",
+        enum_name(source, &pair.key()),
+        node_source(source, &pair.value())
+    );
+    let corrected_options: Vec<String> = options
+        .iter()
+        .map(|option| {
+            let key = key_source(source, &option.key());
+            let name = key.strip_prefix('_').unwrap_or(key);
+            format!("{name}: {}", node_source(source, &option.value()))
+        })
+        .collect();
+    if !corrected_options.is_empty() {
+        result.push_str(", ");
+        result.push_str(&corrected_options.join(", "));
+    }
+    result
 }
 
 #[cfg(test)]
@@ -91,11 +286,15 @@ mod tests {
     use crate::cop::CopConfig;
     use std::collections::HashMap;
 
-    fn config_with_rails(version: f64) -> CopConfig {
+    fn config_with_versions(rails: f64, ruby: f64) -> CopConfig {
         let mut options = HashMap::new();
         options.insert(
             "TargetRailsVersion".to_string(),
-            serde_yml::Value::Number(serde_yml::value::Number::from(version)),
+            serde_yml::Value::Number(serde_yml::value::Number::from(rails)),
+        );
+        options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::value::Number::from(ruby)),
         );
         options.insert(
             "__RailtiesInLockfile".to_string(),
@@ -107,6 +306,10 @@ mod tests {
         }
     }
 
+    fn config_with_rails(version: f64) -> CopConfig {
+        config_with_versions(version, 3.0)
+    }
+
     #[test]
     fn offense_fixture() {
         crate::testutil::assert_cop_offenses_full_with_config(
@@ -125,6 +328,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn autocorrect_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &EnumSyntax,
+            include_bytes!("../../../tests/fixtures/cops/rails/enum_syntax/offense.rb"),
+            include_bytes!("../../../tests/fixtures/cops/rails/enum_syntax/corrected.rb"),
+            config_with_rails(7.0),
+        );
+    }
+
     #[test]
     fn skipped_when_rails_below_7() {
         let source = b"enum status: { active: 0, archived: 1 }\n";
@@ -137,6 +350,18 @@ mod tests {
         assert!(diagnostics.is_empty(), "Should not fire on Rails < 7.0");
     }
 
+    #[test]
+    fn skipped_when_ruby_below_3() {
+        let source = b"enum status: { active: 0, archived: 1 }\n";
+        let diagnostics = crate::testutil::run_cop_full_internal(
+            &EnumSyntax,
+            source,
+            config_with_versions(7.0, 2.7),
+            "test.rb",
+        );
+        assert!(diagnostics.is_empty(), "Should not fire on Ruby < 3.0");
+    }
+
     #[test]
     fn skipped_when_no_rails_version() {
         let source = b"enum status: { active: 0, archived: 1 }\n";
@@ -151,4 +376,18 @@ mod tests {
             "Should not fire when TargetRailsVersion defaults to 5.0"
         );
     }
+
+    #[test]
+    fn string_key_is_dumped_in_correction() {
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect_with_config(
+            &EnumSyntax,
+            b"enum \"kind\" => { a: 0 }\n",
+            config_with_rails(7.0),
+        );
+        assert_eq!(
+            diags[0].message,
+            "Enum defined with keyword arguments in `kind` enum declaration. Use positional arguments instead."
+        );
+        assert_eq!(corrections[0].replacement, "enum \"kind\", { a: 0 }");
+    }
 }
@@ -1,14 +1,37 @@
 use crate::cop::shared::method_dispatch_predicates;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::codemap::CodeMap;
 use crate::parse::source::SourceFile;
 use ruby_prism::Visit;
 
+/// Rails/HttpPositionalArguments — flags Rails 4 style positional arguments to
+/// controller test request helpers (`get :show, { id: 1 }, { user_id: 2 }`).
+///
+/// Matches RuboCop's `(send nil? {:get :post ...} !nil? $_ ...)`: the second
+/// argument is the data. It needs converting unless it is a lone `**splat` or
+/// a hash that already uses one of the request keywords (`params:`,
+/// `session:`, ...). Calls inside `draw`/`routes` blocks and files that
+/// `include Rack::Test::Methods` are skipped.
+///
+/// Autocorrect rewrites the positional data as keywords in Rails 4 order:
+/// `params`, then `session`, then `flash`. Empty `{}` hashes are dropped.
 pub struct HttpPositionalArguments;
 
 const HTTP_METHODS: &[&[u8]] = &[b"get", b"post", b"put", b"patch", b"delete", b"head"];
 
+/// Keywords accepted by the Rails 5 request helpers.
+const KEYWORD_ARGS: &[&[u8]] = &[
+    b"method", b"params", b"session", b"body", b"flash", b"xhr", b"format", b"headers",
+];
+
+/// Blocks whose `get`/`post` calls are route definitions, not requests.
+const ROUTING_METHODS: &[&[u8]] = &[b"draw", b"routes"];
+
+/// Keyword names for the positional data arguments, in Rails 4 order.
+const POSITIONAL_KEYWORDS: &[&str] = &["params", "session", "flash"];
+
 impl Cop for HttpPositionalArguments {
     fn name(&self) -> &'static str {
         "Rails/HttpPositionalArguments"
@@ -18,6 +41,10 @@ impl Cop for HttpPositionalArguments {
         Severity::Convention
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -25,7 +52,7 @@ impl Cop for HttpPositionalArguments {
         _code_map: &CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         // minimum_target_rails_version 5.0
         if !config.rails_version_at_least(5.0) {
@@ -43,9 +70,14 @@ impl Cop for HttpPositionalArguments {
             cop: self,
             source,
             diagnostics: Vec::new(),
+            corrections: corrections.is_some().then(Vec::new),
+            in_routing_block: false,
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let (Some(corrections), Some(found)) = (corrections, visitor.corrections) {
+            corrections.extend(found);
+        }
     }
 }
 
@@ -101,30 +133,147 @@ struct HttpPosArgsVisitor<'a> {
     cop: &'a HttpPositionalArguments,
     source: &'a SourceFile,
     diagnostics: Vec<Diagnostic>,
+    corrections: Option<Vec<Correction>>,
+    in_routing_block: bool,
 }
 
-impl<'pr> Visit<'pr> for HttpPosArgsVisitor<'_> {
-    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+impl HttpPosArgsVisitor<'_> {
+    fn check_request(&mut self, node: &ruby_prism::CallNode<'_>) {
         let method_name = node.name().as_slice();
-        if HTTP_METHODS.contains(&method_name) && node.receiver().is_none() {
-            if let Some(args) = node.arguments() {
-                let arg_list: Vec<_> = args.arguments().iter().collect();
-                // Only flag explicit HashNode (old-style positional: `get path, {params}, headers`).
-                // A keyword_hash_node means keyword args (`get path, params: ...`), which is
-                // the correct new-style syntax this cop promotes — don't flag it.
-                if arg_list.len() >= 3 && arg_list[1].as_hash_node().is_some() {
-                    let loc = node.location();
-                    let (line, column) = self.source.offset_to_line_col(loc.start_offset());
-                    self.diagnostics.push(self.cop.diagnostic(
-                        self.source,
-                        line,
-                        column,
-                        "Use keyword arguments for HTTP request methods.".to_string(),
-                    ));
-                }
+        if !HTTP_METHODS.contains(&method_name) || node.receiver().is_some() {
+            return;
+        }
+        let Some(args) = node.arguments() else {
+            return;
+        };
+        let arg_list: Vec<_> = args.arguments().iter().collect();
+        if arg_list.len() < 2 || arg_list[0].as_nil_node().is_some() {
+            return;
+        }
+        if !needs_conversion(&arg_list[1]) {
+            return;
+        }
+
+        let start = arg_list[1].location().start_offset();
+        let (line, column) = self.source.offset_to_line_col(start);
+        let verb = std::str::from_utf8(method_name).unwrap_or("get");
+        let mut diag = self.cop.diagnostic(
+            self.source,
+            line,
+            column,
+            format!(
+                "Use keyword arguments instead of positional arguments for http call: `{verb}`."
+            ),
+        );
+
+        if let Some(ref mut corrections) = self.corrections {
+            if let Some((start, end, replacement)) = correction(self.source, node, verb, &arg_list)
+            {
+                corrections.push(Correction {
+                    start,
+                    end,
+                    replacement,
+                    cop_name: self.cop.name(),
+                    cop_index: 0,
+                });
+                diag.corrected = true;
             }
         }
+        self.diagnostics.push(diag);
+    }
+}
+
+/// Whether the data argument is still positional (RuboCop's `needs_conversion?`).
+fn needs_conversion(data: &ruby_prism::Node<'_>) -> bool {
+    let elements: Vec<_> = if let Some(hash) = data.as_hash_node() {
+        hash.elements().iter().collect()
+    } else if let Some(hash) = data.as_keyword_hash_node() {
+        hash.elements().iter().collect()
+    } else {
+        return true;
+    };
+
+    if elements.len() == 1 && elements[0].as_assoc_splat_node().is_some() {
+        return false;
+    }
+
+    !elements.iter().any(|element| {
+        element
+            .as_assoc_node()
+            .and_then(|pair| pair.key().as_symbol_node())
+            .is_some_and(|sym| KEYWORD_ARGS.contains(&sym.unescaped()))
+    })
+}
+
+/// Rewrite `verb path, data...` as `verb path, params: ..., session: ...`.
+///
+/// Returns the replaced byte range and its replacement, or `None` when there
+/// are more positional arguments than Rails 4 had.
+fn correction(
+    source: &SourceFile,
+    node: &ruby_prism::CallNode<'_>,
+    verb: &str,
+    args: &[ruby_prism::Node<'_>],
+) -> Option<(usize, usize, String)> {
+    let (path, data) = args.split_first()?;
+    if data.len() > POSITIONAL_KEYWORDS.len() {
+        return None;
+    }
+
+    let bytes = source.as_bytes();
+    let src = |node: &ruby_prism::Node<'_>| {
+        let loc = node.location();
+        String::from_utf8_lossy(&bytes[loc.start_offset()..loc.end_offset()]).into_owned()
+    };
+
+    let mut replacement = format!(
+        "{verb}{}",
+        if node.opening_loc().is_some() {
+            "("
+        } else {
+            " "
+        }
+    );
+    replacement.push_str(&src(path));
+    for (keyword, arg) in POSITIONAL_KEYWORDS.iter().zip(data) {
+        let value = if let Some(hash) = arg.as_hash_node() {
+            let elements: Vec<_> = hash.elements().iter().map(|e| src(&e)).collect();
+            if elements.is_empty() {
+                continue;
+            }
+            format!("{{ {} }}", elements.join(", "))
+        } else if let Some(hash) = arg.as_keyword_hash_node() {
+            let elements: Vec<_> = hash.elements().iter().map(|e| src(&e)).collect();
+            format!("{{ {} }}", elements.join(", "))
+        } else {
+            src(arg)
+        };
+        replacement.push_str(&format!(", {keyword}: {value}"));
+    }
+
+    let end = match node.closing_loc() {
+        Some(closing) => {
+            replacement.push(')');
+            closing.end_offset()
+        }
+        None => args.last()?.location().end_offset(),
+    };
+
+    Some((node.location().start_offset(), end, replacement))
+}
+
+impl<'pr> Visit<'pr> for HttpPosArgsVisitor<'_> {
+    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+        if !self.in_routing_block {
+            self.check_request(node);
+        }
+
+        let is_routing = node.block().is_some_and(|b| b.as_block_node().is_some())
+            && ROUTING_METHODS.contains(&node.name().as_slice());
+        let saved = self.in_routing_block;
+        self.in_routing_block |= is_routing;
         ruby_prism::visit_call_node(self, node);
+        self.in_routing_block = saved;
     }
 }
 
@@ -172,6 +321,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn autocorrect_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &HttpPositionalArguments,
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/http_positional_arguments/offense.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/http_positional_arguments/corrected.rb"
+            ),
+            config_with_rails(5.0),
+        );
+    }
+
+    #[test]
+    fn skipped_in_files_using_rack_test_methods() {
+        // Rack::Test's request helpers take positional arguments.
+        let source = b"class RedirectorTest
+  include Rack::Test::Methods
+  get \"/specs.4.8.gz\", {}, \"HTTP_HOST\" => \"example.com\"
+end
+";
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &HttpPositionalArguments,
+            source,
+            config_with_rails(5.0),
+        );
+    }
+
     #[test]
     fn skipped_when_no_target_rails_version() {
         // Non-Rails projects (e.g. sinatra) have no TargetRailsVersion.
@@ -1,6 +1,7 @@
 use crate::cop::shared::node_type::{CALL_NODE, INTEGER_NODE, STRING_NODE, SYMBOL_NODE};
 use crate::cop::shared::util::keyword_arg_value;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
@@ -108,6 +109,7 @@ fn status_code_to_symbol(code: i64) -> Option<&'static str> {
         506 => Some(":variant_also_negotiates"),
         507 => Some(":insufficient_storage"),
         508 => Some(":loop_detected"),
+        509 => Some(":bandwidth_limit_exceeded"),
         510 => Some(":not_extended"),
         511 => Some(":network_authentication_required"),
         _ => None,
@@ -175,7 +177,11 @@ fn symbol_to_status_code(sym: &[u8]) -> Option<i64> {
         b"variant_also_negotiates" => Some(506),
         b"insufficient_storage" => Some(507),
         b"loop_detected" => Some(508),
+        b"bandwidth_limit_exceeded" => Some(509),
         b"not_extended" => Some(510),
+        // Rack 3 renamed these; the old names stay valid aliases.
+        b"content_too_large" => Some(413),
+        b"unprocessable_content" => Some(422),
         b"network_authentication_required" => Some(511),
         _ => None,
     }
@@ -246,6 +252,7 @@ fn status_code_to_rack_reason(code: i64) -> Option<&'static str> {
         506 => Some("Variant Also Negotiates"),
         507 => Some("Insufficient Storage"),
         508 => Some("Loop Detected"),
+        509 => Some("Bandwidth Limit Exceeded"),
         510 => Some("Not Extended"),
         511 => Some("Network Authentication Required"),
         _ => None,
@@ -275,6 +282,10 @@ impl Cop for HttpStatus {
         Severity::Convention
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[CALL_NODE, INTEGER_NODE, STRING_NODE, SYMBOL_NODE]
     }
@@ -286,7 +297,7 @@ impl Cop for HttpStatus {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        mut corrections: Option<&mut Vec<Correction>>,
     ) {
         let style = config.get_str("EnforcedStyle", "symbolic");
 
@@ -316,7 +327,8 @@ impl Cop for HttpStatus {
             keyword_arg_value(&call, b"status")
         };
 
-        let check_status = |status_value: &ruby_prism::Node<'_>| -> Option<Diagnostic> {
+        // Returns the offense and the preferred status literal that replaces the value.
+        let check_status = |status_value: &ruby_prism::Node<'_>| -> Option<(Diagnostic, String)> {
             match style {
                 "numeric" => {
                     if let Some(sym) = status_value.as_symbol_node() {
@@ -328,13 +340,16 @@ impl Cop for HttpStatus {
                             let sym_str = std::str::from_utf8(sym_name).unwrap_or("?");
                             let val_loc = status_value.location();
                             let (line, column) = source.offset_to_line_col(val_loc.start_offset());
-                            return Some(self.diagnostic(
-                                source,
-                                line,
-                                column,
-                                format!(
-                                    "Prefer `{code}` over `:{sym_str}` to define HTTP status code."
+                            return Some((
+                                self.diagnostic(
+                                    source,
+                                    line,
+                                    column,
+                                    format!(
+                                        "Prefer `{code}` over `:{sym_str}` to define HTTP status code."
+                                    ),
                                 ),
+                                code.to_string(),
                             ));
                         }
                     }
@@ -388,13 +403,16 @@ impl Cop for HttpStatus {
                     if let Some(code_num) = code_num_opt {
                         if let Some(sym) = status_code_to_symbol(code_num) {
                             let (line, column) = source.offset_to_line_col(val_loc.start_offset());
-                            return Some(self.diagnostic(
-                                source,
-                                line,
-                                column,
-                                format!(
-                                    "Prefer `{sym}` over `{display_val}` to define HTTP status code."
+                            return Some((
+                                self.diagnostic(
+                                    source,
+                                    line,
+                                    column,
+                                    format!(
+                                        "Prefer `{sym}` over `{display_val}` to define HTTP status code."
+                                    ),
                                 ),
+                                sym.to_string(),
                             ));
                         }
                     }
@@ -403,27 +421,38 @@ impl Cop for HttpStatus {
             }
         };
 
-        if let Some(ref kw) = keyword_status {
-            if let Some(diag) = check_status(kw) {
-                diagnostics.push(diag);
-            }
-        }
+        let mut status_node = keyword_status;
 
-        // For head and assert_response, also check first direct argument
-        if keyword_status.is_none() && DIRECT_STATUS_METHODS.contains(&method_name) {
-            if let Some(args) = call.arguments() {
-                for first in args.arguments().iter().take(1) {
-                    if first.as_integer_node().is_some()
+        // For head and assert_response, check the first direct argument instead
+        if status_node.is_none() && DIRECT_STATUS_METHODS.contains(&method_name) {
+            status_node = call
+                .arguments()
+                .and_then(|args| args.arguments().iter().next())
+                .filter(|first| {
+                    first.as_integer_node().is_some()
                         || first.as_symbol_node().is_some()
                         || first.as_string_node().is_some()
-                    {
-                        if let Some(diag) = check_status(&first) {
-                            diagnostics.push(diag);
-                        }
-                    }
-                }
-            }
+                });
+        }
+
+        let Some(status_node) = status_node else {
+            return;
+        };
+        let Some((mut diag, preferred)) = check_status(&status_node) else {
+            return;
+        };
+        if let Some(ref mut corr) = corrections {
+            let loc = status_node.location();
+            corr.push(Correction {
+                start: loc.start_offset(),
+                end: loc.end_offset(),
+                replacement: preferred,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
         }
+        diagnostics.push(diag);
     }
 }
 
@@ -431,6 +460,35 @@ impl Cop for HttpStatus {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(HttpStatus, "cops/rails/http_status");
+    crate::cop_autocorrect_fixture_tests!(HttpStatus, "cops/rails/http_status");
+
+    #[test]
+    fn offense_numeric_fixture() {
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &HttpStatus,
+            "EnforcedStyle: numeric",
+            include_bytes!("../../../tests/fixtures/cops/rails/http_status/offense.numeric.rb"),
+        );
+    }
+
+    #[test]
+    fn no_offense_numeric_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &HttpStatus,
+            "EnforcedStyle: numeric",
+            include_bytes!("../../../tests/fixtures/cops/rails/http_status/no_offense.numeric.rb"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_numeric_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &HttpStatus,
+            include_bytes!("../../../tests/fixtures/cops/rails/http_status/offense.numeric.rb"),
+            include_bytes!("../../../tests/fixtures/cops/rails/http_status/corrected.numeric.rb"),
+            crate::testutil::cop_config_from_yaml("EnforcedStyle: numeric"),
+        );
+    }
 
     #[test]
     fn numeric_style_flags_symbolic_status() {
@@ -4,6 +4,7 @@ use std::ops::Range;
 use crate::cop::shared::method_identifier_predicates;
 use crate::cop::variable_force::{self, Scope, VariableTable};
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 use ruby_prism::Visit;
@@ -67,6 +68,13 @@ impl SaveBangData {
 ///
 /// FactoryBot-style literal-leading signatures such as `create :user, ...`
 /// remain excluded by the signature check, so this fix stays narrow.
+///
+/// ## Autocorrect
+///
+/// Unsafe (`SafeAutoCorrect: false`): appends `!` to the method selector,
+/// which turns a failed save into an exception. Only offenses from the
+/// on_send path are corrected; the VariableForce hooks cannot emit
+/// corrections, so `x = Model.create(...)` offenses are reported only.
 pub struct SaveBang;
 
 /// Info about a create-type persist call in a local variable assignment.
@@ -165,6 +173,14 @@ impl Cop for SaveBang {
         Severity::Convention
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -172,7 +188,7 @@ impl Cop for SaveBang {
         _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let allow_implicit_return = config.get_bool("AllowImplicitReturn", true);
         let allowed_receivers = config
@@ -199,13 +215,18 @@ impl Cop for SaveBang {
             source,
             allow_implicit_return,
             allowed_receivers,
+            autocorrect: corrections.is_some(),
             diagnostics: Vec::new(),
+            corrections: Vec::new(),
             context_stack: Vec::new(),
             in_compound_boolean: false,
             in_transparent_container: false,
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let Some(corrections) = corrections {
+            corrections.extend(visitor.corrections);
+        }
     }
 
     fn as_variable_force_consumer(&self) -> Option<&dyn variable_force::VariableForceConsumer> {
@@ -397,7 +418,9 @@ struct SaveBangVisitor<'a, 'src> {
     source: &'src SourceFile,
     allow_implicit_return: bool,
     allowed_receivers: Vec<String>,
+    autocorrect: bool,
     diagnostics: Vec<Diagnostic>,
+    corrections: Vec<Correction>,
     context_stack: Vec<Context>,
     /// When true, we are inside an `||` or `&&` (compound boolean) expression.
     in_compound_boolean: bool,
@@ -566,22 +589,36 @@ impl SaveBangVisitor<'_, '_> {
 
     fn flag_void_context(&mut self, call: &ruby_prism::CallNode<'_>) {
         let method_name = std::str::from_utf8(call.name().as_slice()).unwrap_or("save");
-        let msg_loc = call.message_loc().unwrap_or(call.location());
-        let (line, column) = self.source.offset_to_line_col(msg_loc.start_offset());
         let message = MSG
             .replace("%prefer%", &format!("{method_name}!"))
             .replace("%current%", method_name);
-        self.diagnostics
-            .push(self.cop.diagnostic(self.source, line, column, message));
+        self.add_offense(call, message);
     }
 
     fn flag_create_conditional(&mut self, call: &ruby_prism::CallNode<'_>) {
         let method_name = std::str::from_utf8(call.name().as_slice()).unwrap_or("create");
+        let message = CREATE_CONDITIONAL_MSG.replace("%current%", method_name);
+        self.add_offense(call, message);
+    }
+
+    /// Report at the selector and, when correcting, replace it with the bang method.
+    fn add_offense(&mut self, call: &ruby_prism::CallNode<'_>, message: String) {
         let msg_loc = call.message_loc().unwrap_or(call.location());
         let (line, column) = self.source.offset_to_line_col(msg_loc.start_offset());
-        let message = CREATE_CONDITIONAL_MSG.replace("%current%", method_name);
-        self.diagnostics
-            .push(self.cop.diagnostic(self.source, line, column, message));
+        let mut diag = self.cop.diagnostic(self.source, line, column, message);
+        if self.autocorrect {
+            if let Some(selector) = call.message_loc() {
+                self.corrections.push(Correction {
+                    start: selector.start_offset(),
+                    end: selector.end_offset(),
+                    replacement: format!("{}!", String::from_utf8_lossy(call.name().as_slice())),
+                    cop_name: self.cop.name(),
+                    cop_index: 0,
+                });
+                diag.corrected = true;
+            }
+        }
+        self.diagnostics.push(diag);
     }
 
     /// Process a call node that has been identified as a persist method.
@@ -1357,6 +1394,82 @@ impl<'pr> Visit<'pr> for SaveBangVisitor<'_, '_> {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(SaveBang::new(), "cops/rails/save_bang");
+    crate::cop_autocorrect_fixture_tests!(SaveBang::new(), "cops/rails/save_bang");
+
+    const ALLOW_IMPLICIT_RETURN_FALSE: &str = "AllowImplicitReturn: false";
+    const ALLOWED_RECEIVERS: &str =
+        "AllowedReceivers:\n  - merchant.gateway\n  - API::Client\n  - cache";
+
+    #[test]
+    fn offense_allow_implicit_return_false_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &SaveBang::new(),
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/save_bang/offense.allow_implicit_return_false.rb"
+            ),
+            crate::testutil::cop_config_from_yaml(ALLOW_IMPLICIT_RETURN_FALSE),
+        );
+    }
+
+    #[test]
+    fn no_offense_allow_implicit_return_false_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &SaveBang::new(),
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/save_bang/no_offense.allow_implicit_return_false.rb"
+            ),
+            crate::testutil::cop_config_from_yaml(ALLOW_IMPLICIT_RETURN_FALSE),
+        );
+    }
+
+    #[test]
+    fn autocorrect_allow_implicit_return_false_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SaveBang::new(),
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/save_bang/offense.allow_implicit_return_false.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/save_bang/corrected.allow_implicit_return_false.rb"
+            ),
+            crate::testutil::cop_config_from_yaml(ALLOW_IMPLICIT_RETURN_FALSE),
+        );
+    }
+
+    #[test]
+    fn offense_allowed_receivers_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &SaveBang::new(),
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/save_bang/offense.allowed_receivers.rb"
+            ),
+            crate::testutil::cop_config_from_yaml(ALLOWED_RECEIVERS),
+        );
+    }
+
+    #[test]
+    fn no_offense_allowed_receivers_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &SaveBang::new(),
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/save_bang/no_offense.allowed_receivers.rb"
+            ),
+            crate::testutil::cop_config_from_yaml(ALLOWED_RECEIVERS),
+        );
+    }
+
+    /// The VariableForce path reports create-in-assignment offenses without
+    /// a correction.
+    #[test]
+    fn create_in_assignment_is_not_corrected() {
+        let source = b"user = User.create(name: 'x')\n";
+        assert_eq!(
+            crate::testutil::run_cop_full(&SaveBang::new(), source).len(),
+            1
+        );
+        let (_, corrections) = crate::testutil::run_cop_autocorrect(&SaveBang::new(), source);
+        assert!(corrections.is_empty());
+    }
 
     /// Regression: FactoryBot-style bare create with a literal leading argument
     /// should stay exempt in the VF create-in-assignment path.
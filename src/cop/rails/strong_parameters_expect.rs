@@ -1,21 +1,100 @@
 use crate::cop::shared::method_dispatch_predicates;
-use crate::cop::shared::node_type::{CALL_NODE, LOCAL_VARIABLE_READ_NODE};
+use crate::cop::shared::node_type::CALL_NODE;
 use crate::cop::shared::util::as_method_chain;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
+/// Rails/StrongParametersExpect
+///
+/// Suggests `params.expect(user: [:name])` over
+/// `params.require(:user).permit(:name)` and the inverse
+/// `params.permit(user: [:name]).require(:user)` on Rails 8.0+.
+///
+/// Mirrors rubocop-rails: the receiver must be the `params` method (a local
+/// variable named `params` is not matched), `require` takes a single key and
+/// `permit` at least one argument. The inverse form only matches when the
+/// single permitted hash key equals the required key, so dynamic keys are
+/// left alone there. A literal `require` key is written as `user:`, any other
+/// expression as `key =>`. The offense spans from the inner selector to the
+/// end of the chain; autocorrect replaces that range with the suggestion.
 pub struct StrongParametersExpect;
 
-/// Check if a node is a `params` receiver (local variable or method call).
+const MSG: &str = "Use `%PREFER%` instead.";
+
+/// The bare `params` method call.
 fn is_params_receiver(node: &ruby_prism::Node<'_>) -> bool {
-    if let Some(call) = node.as_call_node() {
-        return method_dispatch_predicates::is_command(&call, b"params");
+    node.as_call_node()
+        .is_some_and(|call| method_dispatch_predicates::is_command(&call, b"params"))
+}
+
+/// The literal value of a symbol or string key.
+fn literal_key_value(node: &ruby_prism::Node<'_>) -> Option<Vec<u8>> {
+    if let Some(sym) = node.as_symbol_node() {
+        return Some(sym.unescaped().to_vec());
+    }
+    node.as_string_node().map(|s| s.unescaped().to_vec())
+}
+
+fn node_source<'a>(source: &'a SourceFile, node: &ruby_prism::Node<'_>) -> &'a str {
+    let loc = node.location();
+    source.byte_slice(loc.start_offset(), loc.end_offset(), "")
+}
+
+fn single_argument<'pr>(call: &ruby_prism::CallNode<'pr>) -> Option<ruby_prism::Node<'pr>> {
+    let args: Vec<_> = call.arguments()?.arguments().iter().collect();
+    match <[_; 1]>::try_from(args) {
+        Ok([arg]) => Some(arg),
+        Err(_) => None,
+    }
+}
+
+/// `expect(user: [:name, :age])` for `require(:user).permit(:name, :age)`.
+fn require_permit_suggestion(
+    source: &SourceFile,
+    require_call: &ruby_prism::CallNode<'_>,
+    permit_call: &ruby_prism::CallNode<'_>,
+) -> Option<String> {
+    let key = single_argument(require_call)?;
+    let permit_args: Vec<_> = permit_call.arguments()?.arguments().iter().collect();
+    if permit_args.is_empty() {
+        return None;
     }
-    if let Some(lvar) = node.as_local_variable_read_node() {
-        return lvar.name().as_slice() == b"params";
+    let require_key = match literal_key_value(&key) {
+        Some(value) => format!("{}: ", String::from_utf8_lossy(&value)),
+        None => format!("{} => ", node_source(source, &key)),
+    };
+    let permitted: Vec<&str> = permit_args
+        .iter()
+        .map(|arg| node_source(source, arg))
+        .collect();
+    Some(format!("expect({require_key}[{}])", permitted.join(", ")))
+}
+
+/// `expect(user: [:name])` for `permit(user: [:name]).require(:user)`.
+fn permit_require_suggestion(
+    source: &SourceFile,
+    permit_call: &ruby_prism::CallNode<'_>,
+    require_call: &ruby_prism::CallNode<'_>,
+) -> Option<String> {
+    let permitted = single_argument(permit_call)?;
+    let elements = if let Some(hash) = permitted.as_keyword_hash_node() {
+        hash.elements()
+    } else if let Some(hash) = permitted.as_hash_node() {
+        hash.elements()
+    } else {
+        return None;
+    };
+    let pairs: Vec<_> = elements.iter().collect();
+    let [pair] = pairs.as_slice() else {
+        return None;
+    };
+    let permitted_key = literal_key_value(&pair.as_assoc_node()?.key())?;
+    let required_key = literal_key_value(&single_argument(require_call)?)?;
+    if permitted_key != required_key {
+        return None;
     }
-    false
+    Some(format!("expect({})", node_source(source, &permitted)))
 }
 
 impl Cop for StrongParametersExpect {
@@ -31,8 +110,12 @@ impl Cop for StrongParametersExpect {
         &["**/app/controllers/**/*.rb"]
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
-        &[CALL_NODE, LOCAL_VARIABLE_READ_NODE]
+        &[CALL_NODE]
     }
 
     fn check_node(
@@ -42,7 +125,7 @@ impl Cop for StrongParametersExpect {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // minimum_target_rails_version 8.0
         if !config.rails_version_at_least(8.0) {
@@ -55,45 +138,50 @@ impl Cop for StrongParametersExpect {
             Some(c) => c,
             None => return,
         };
-
-        let is_require_permit = chain.inner_method == b"require" && chain.outer_method == b"permit";
-        let is_permit_require = chain.inner_method == b"permit" && chain.outer_method == b"require";
-
-        if !is_require_permit && !is_permit_require {
-            return;
-        }
-
-        // Check if the innermost receiver is `params`
-        let inner_receiver = match chain.inner_call.receiver() {
-            Some(r) => r,
+        let outer_call = match node.as_call_node() {
+            Some(c) => c,
             None => return,
         };
 
-        if !is_params_receiver(&inner_receiver) {
-            return;
+        match chain.inner_call.receiver() {
+            Some(recv) if is_params_receiver(&recv) => {}
+            _ => return,
         }
 
-        // For require.permit, permit must have arguments
-        if is_require_permit {
-            let outer_call = node.as_call_node().unwrap();
-            if outer_call.arguments().is_none() {
-                return;
+        let prefer = match (chain.inner_method, chain.outer_method) {
+            (b"require", b"permit") => {
+                require_permit_suggestion(source, &chain.inner_call, &outer_call)
             }
-        }
+            (b"permit", b"require") => {
+                permit_require_suggestion(source, &chain.inner_call, &outer_call)
+            }
+            _ => None,
+        };
+        let Some(prefer) = prefer else {
+            return;
+        };
 
-        let msg_loc = chain
+        let start = chain
             .inner_call
             .message_loc()
-            .unwrap_or(chain.inner_call.location());
+            .unwrap_or(chain.inner_call.location())
+            .start_offset();
+        let end = node.location().end_offset();
 
-        let (line, column) = source.offset_to_line_col(msg_loc.start_offset());
+        let (line, column) = source.offset_to_line_col(start);
+        let mut diag = self.diagnostic(source, line, column, MSG.replace("%PREFER%", &prefer));
 
-        diagnostics.push(self.diagnostic(
-            source,
-            line,
-            column,
-            "Use `expect(...)` instead.".to_string(),
-        ));
+        if let Some(corr) = corrections {
+            corr.push(crate::correction::Correction {
+                start,
+                end,
+                replacement: prefer,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
     }
 }
 
@@ -141,6 +229,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn autocorrect_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &StrongParametersExpect,
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/strong_parameters_expect/offense.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/rails/strong_parameters_expect/corrected.rb"
+            ),
+            config_with_rails(8.0),
+        );
+    }
+
     #[test]
     fn skipped_when_rails_below_8() {
         // On Rails 7.x, the cop should never fire
@@ -1,10 +1,65 @@
-use crate::cop::shared::node_type::{CALL_NODE, STRING_NODE};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::cop::shared::node_type::CALL_NODE;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
+/// Rails/WhereRange
+///
+/// Flags `where`/`where.not` calls whose SQL template is a simple column
+/// comparison (`column >= ?`, `column < :max`, `column >= ? AND column <= ?`)
+/// and suggests the equivalent range condition, e.g.
+/// `where('age >= ?', 18)` -> `where(age: 18..)`.
+///
+/// Mirrors rubocop-rails: the template must be a plain string (first argument
+/// or first element of an array argument) followed by at least one value;
+/// named placeholders are resolved against a hash argument. Beginless ranges
+/// (`..max`) need Ruby 2.7, endless ranges Ruby 2.6. The offense spans from
+/// the method selector to the end of the call, which is also what autocorrect
+/// replaces. A `table.column` template becomes `where(table: { column: ... })`.
 pub struct WhereRange;
 
+const MSG: &str = "Use `%GOOD%` instead of manually constructing SQL.";
+
+const COLUMN: &str = r"[A-Za-z0-9_.]+";
+const NAME: &str = r"[A-Za-z0-9_]+";
+const WS: &str = r"[ \t\r\n\f\v]";
+
+// column >= ?
+static GTEQ_ANONYMOUS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"\A{WS}*({COLUMN}){WS}+>={WS}+\?{WS}*\z")).unwrap());
+// column <[=] ?
+static LTEQ_ANONYMOUS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"\A{WS}*({COLUMN}){WS}+(<=?){WS}+\?{WS}*\z")).unwrap());
+// column >= ? AND column <[=] ?
+static RANGE_ANONYMOUS_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\A{WS}*({COLUMN}){WS}+>={WS}+\?{WS}+AND{WS}+({COLUMN}){WS}+(<=?){WS}+\?{WS}*\z"
+    ))
+    .unwrap()
+});
+// column >= :value
+static GTEQ_NAMED_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"\A{WS}*({COLUMN}){WS}+>={WS}+:({NAME}){WS}*\z")).unwrap()
+});
+// column <[=] :value
+static LTEQ_NAMED_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"\A{WS}*({COLUMN}){WS}+(<=?){WS}+:({NAME}){WS}*\z"
+    ))
+    .unwrap()
+});
+// column >= :value1 AND column <[=] :value2
+static RANGE_NAMED_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\A{WS}*({COLUMN}){WS}+>={WS}+:({NAME}){WS}+AND{WS}+({COLUMN}){WS}+(<=?){WS}+:({NAME}){WS}*\z"
+    ))
+    .unwrap()
+});
+
 impl Cop for WhereRange {
     fn name(&self) -> &'static str {
         "Rails/WhereRange"
@@ -14,8 +69,12 @@ impl Cop for WhereRange {
         Severity::Convention
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
-        &[CALL_NODE, STRING_NODE]
+        &[CALL_NODE]
     }
 
     fn check_node(
@@ -25,10 +84,11 @@ impl Cop for WhereRange {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
-        // minimum_target_rails_version 6.0
-        if !config.rails_version_at_least(6.0) {
+        // minimum_target_rails_version 6.0, minimum_target_ruby_version 2.6
+        let ruby_version = config.target_ruby_version();
+        if !config.rails_version_at_least(6.0) || ruby_version < 2.6 {
             return;
         }
 
@@ -44,167 +104,187 @@ impl Cop for WhereRange {
 
         // For `not`, check that the receiver is a `where` call
         if method == b"not" {
-            if let Some(recv) = call.receiver() {
-                if let Some(recv_call) = recv.as_call_node() {
-                    if recv_call.name().as_slice() != b"where" {
-                        return;
-                    }
-                } else {
-                    return;
-                }
-            } else {
+            let is_where_not = call
+                .receiver()
+                .and_then(|recv| recv.as_call_node())
+                .is_some_and(|recv| recv.name().as_slice() == b"where");
+            if !is_where_not {
                 return;
             }
         }
 
-        let args = match call.arguments() {
-            Some(a) => a,
+        let args: Vec<_> = match call.arguments() {
+            Some(a) => a.arguments().iter().collect(),
             None => return,
         };
 
-        let arg_list: Vec<_> = args.arguments().iter().collect();
-        if arg_list.is_empty() {
+        // `where('age >= ?', 18)` or `where(['age >= ?', 18])`
+        let array_elements: Vec<_>;
+        let (template, values) = match args.as_slice() {
+            [single] if single.as_array_node().is_some() => {
+                array_elements = single.as_array_node().unwrap().elements().iter().collect();
+                match array_elements.split_first() {
+                    Some((template, values)) => (template, values),
+                    None => return,
+                }
+            }
+            [template, values @ ..] => (template, values),
+            [] => return,
+        };
+        if values.is_empty() {
             return;
         }
 
-        // First argument should be a string containing a simple comparison pattern
-        let string_node = match arg_list[0].as_string_node() {
+        let template = match template.as_string_node() {
             Some(s) => s,
             None => return,
         };
+        let Ok(content) = std::str::from_utf8(template.unescaped()) else {
+            return;
+        };
 
-        let content = string_node.unescaped();
-
-        // Must match one of RuboCop's specific patterns:
-        // - "column >= ?" (with optional table prefix)
-        // - "column <[=] ?"
-        // - "column >= ? AND column <[=] ?"
-        // - "column >= :name"
-        // - "column <[=] :name"
-        // - "column >= :name1 AND column <[=] :name2"
-        if !matches_where_range_pattern(content) {
+        let Some((column, value)) = extract_column_and_value(source, content, values, ruby_version)
+        else {
             return;
-        }
+        };
+
+        let method_name = std::str::from_utf8(method).unwrap_or("where");
+        let good_method = build_good_method(method_name, &column, &value);
+
+        let start = call
+            .message_loc()
+            .map_or(node.location().start_offset(), |loc| loc.start_offset());
+        let end = node.location().end_offset();
+        let (line, column) = source.offset_to_line_col(start);
+        let mut diag = self.diagnostic(source, line, column, MSG.replace("%GOOD%", &good_method));
 
-        let loc = node.location();
-        let (line, column) = source.offset_to_line_col(loc.start_offset());
-        diagnostics.push(self.diagnostic(
-            source,
-            line,
-            column,
-            "Use a range in `where` instead of manually constructing SQL conditions.".to_string(),
-        ));
+        if let Some(corr) = corrections {
+            corr.push(crate::correction::Correction {
+                start,
+                end,
+                replacement: good_method,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
     }
 }
 
-/// Check if content matches one of the specific SQL patterns that RuboCop's WhereRange flags.
-/// These are simple column comparisons, not complex SQL expressions.
-fn matches_where_range_pattern(content: &[u8]) -> bool {
-    let s = match std::str::from_utf8(content) {
-        Ok(s) => s.trim(),
-        Err(_) => return false,
-    };
+/// Match the SQL template and build the range source. Returns the column
+/// and range expression, or `None` when the template is not one of the
+/// recognized forms or its placeholders cannot be resolved.
+fn extract_column_and_value(
+    source: &SourceFile,
+    content: &str,
+    values: &[ruby_prism::Node<'_>],
+    ruby_version: f64,
+) -> Option<(String, String)> {
+    let first = &values[0];
 
-    // Pattern: column >= ?
-    if matches_simple_pattern(s, ">=", "?") {
-        return true;
+    if let Some(caps) = GTEQ_ANONYMOUS_RE.captures(content) {
+        let value = format!("{}..", node_source(source, first));
+        return Some((caps[1].to_string(), value));
     }
-    // Pattern: column <= ? or column < ?
-    if matches_simple_pattern(s, "<=", "?") || matches_simple_pattern(s, "<", "?") {
-        return true;
+    if let Some(caps) = LTEQ_ANONYMOUS_RE.captures(content) {
+        if ruby_version < 2.7 {
+            return None;
+        }
+        let value = format!("{}{}", range_operator(&caps[2]), node_source(source, first));
+        return Some((caps[1].to_string(), value));
     }
-    // Pattern: column >= :name
-    if matches_named_pattern(s, ">=") {
-        return true;
+    if let Some(caps) = RANGE_ANONYMOUS_RE.captures(content) {
+        // RuboCop's `\1` back-reference under `/i`.
+        if !caps[1].eq_ignore_ascii_case(&caps[2]) || values.len() < 2 {
+            return None;
+        }
+        let value = format!(
+            "{}{}{}",
+            node_source(source, first),
+            range_operator(&caps[3]),
+            node_source(source, &values[1])
+        );
+        return Some((caps[1].to_string(), value));
     }
-    // Pattern: column <= :name or column < :name
-    if matches_named_pattern(s, "<=") || matches_named_pattern(s, "<") {
-        return true;
+    if let Some(caps) = GTEQ_NAMED_RE.captures(content) {
+        let pair_value = find_pair_value(first, &caps[2])?;
+        let value = format!("{}..", node_source(source, &pair_value));
+        return Some((caps[1].to_string(), value));
     }
-    // Pattern: column >= ? AND column <[=] ?
-    if matches_range_anonymous_pattern(s) {
-        return true;
+    if let Some(caps) = LTEQ_NAMED_RE.captures(content) {
+        let pair_value = find_pair_value(first, &caps[3])?;
+        if ruby_version < 2.7 {
+            return None;
+        }
+        let value = format!(
+            "{}{}",
+            range_operator(&caps[2]),
+            node_source(source, &pair_value)
+        );
+        return Some((caps[1].to_string(), value));
     }
-    // Pattern: column >= :name AND column <[=] :name
-    if matches_range_named_pattern(s) {
-        return true;
+    if let Some(caps) = RANGE_NAMED_RE.captures(content) {
+        if !caps[1].eq_ignore_ascii_case(&caps[3]) {
+            return None;
+        }
+        let low = find_pair_value(first, &caps[2])?;
+        let high = find_pair_value(first, &caps[5])?;
+        let value = format!(
+            "{}{}{}",
+            node_source(source, &low),
+            range_operator(&caps[4]),
+            node_source(source, &high)
+        );
+        return Some((caps[1].to_string(), value));
     }
-
-    false
+    None
 }
 
-/// Match pattern: `identifier >= ?` (simple column with anonymous placeholder)
-fn matches_simple_pattern(s: &str, op: &str, placeholder: &str) -> bool {
-    let parts: Vec<&str> = s.split_whitespace().collect();
-    if parts.len() != 3 {
-        return false;
+fn range_operator(comparison_operator: &str) -> &'static str {
+    if comparison_operator == "<" {
+        "..."
+    } else {
+        ".."
     }
-    is_column_identifier(parts[0]) && parts[1] == op && parts[2] == placeholder
-}
-
-/// Match pattern: `identifier >= :name` (simple column with named placeholder)
-fn matches_named_pattern(s: &str, op: &str) -> bool {
-    let parts: Vec<&str> = s.split_whitespace().collect();
-    if parts.len() != 3 {
-        return false;
-    }
-    is_column_identifier(parts[0])
-        && parts[1] == op
-        && parts[2].starts_with(':')
-        && parts[2].len() > 1
-        && parts[2][1..]
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
 
-/// Match pattern: `column >= ? AND column <[=] ?`
-fn matches_range_anonymous_pattern(s: &str) -> bool {
-    let upper = s.to_uppercase();
-    let parts: Vec<&str> = upper.split_whitespace().collect();
-    // column >= ? AND column <[=] ?
-    if parts.len() != 7 {
-        return false;
-    }
-    // Use original case for column names
-    let orig_parts: Vec<&str> = s.split_whitespace().collect();
-    is_column_identifier(orig_parts[0])
-        && parts[1] == ">="
-        && parts[2] == "?"
-        && parts[3] == "AND"
-        && orig_parts[0] == orig_parts[4] // same column
-        && (parts[5] == "<" || parts[5] == "<=")
-        && parts[6] == "?"
+/// Value of the pair keyed `name` (symbol or string key) in a hash argument.
+fn find_pair_value<'pr>(hash: &ruby_prism::Node<'pr>, name: &str) -> Option<ruby_prism::Node<'pr>> {
+    let elements = if let Some(h) = hash.as_keyword_hash_node() {
+        h.elements()
+    } else if let Some(h) = hash.as_hash_node() {
+        h.elements()
+    } else {
+        return None;
+    };
+    elements.iter().find_map(|element| {
+        let pair = element.as_assoc_node()?;
+        let key = pair.key();
+        let key_name = if let Some(sym) = key.as_symbol_node() {
+            sym.unescaped().to_vec()
+        } else if let Some(s) = key.as_string_node() {
+            s.unescaped().to_vec()
+        } else {
+            return None;
+        };
+        (key_name == name.as_bytes()).then(|| pair.value())
+    })
 }
 
-/// Match pattern: `column >= :name AND column <[=] :name`
-fn matches_range_named_pattern(s: &str) -> bool {
-    let upper = s.to_uppercase();
-    let parts: Vec<&str> = upper.split_whitespace().collect();
-    if parts.len() != 7 {
-        return false;
-    }
-    let orig_parts: Vec<&str> = s.split_whitespace().collect();
-    is_column_identifier(orig_parts[0])
-        && parts[1] == ">="
-        && orig_parts[2].starts_with(':')
-        && parts[3] == "AND"
-        && orig_parts[0] == orig_parts[4]
-        && (parts[5] == "<" || parts[5] == "<=")
-        && orig_parts[6].starts_with(':')
+fn node_source<'a>(source: &'a SourceFile, node: &ruby_prism::Node<'_>) -> &'a str {
+    let loc = node.location();
+    source.byte_slice(loc.start_offset(), loc.end_offset(), "")
 }
 
-/// Check if the string looks like a simple SQL column identifier (with optional table prefix).
-/// Matches: word, word.word (but not word.word.word or expressions with parens)
-fn is_column_identifier(s: &str) -> bool {
-    if s.is_empty() || s.contains('(') || s.contains(')') {
-        return false;
-    }
-    let dot_count = s.chars().filter(|&c| c == '.').count();
-    if dot_count > 1 {
-        return false;
+fn build_good_method(method_name: &str, column: &str, value: &str) -> String {
+    let mut parts = column.split('.');
+    match (parts.next(), parts.next()) {
+        (Some(table), Some(column)) => {
+            format!("{method_name}({table}: {{ {column}: {value} }})")
+        }
+        _ => format!("{method_name}({column}: {value})"),
     }
-    s.chars()
-        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
 }
 
 #[cfg(test)]
@@ -212,6 +292,16 @@ mod tests {
     use super::*;
     crate::cop_rails_fixture_tests!(WhereRange, "cops/rails/where_range", 6.0);
 
+    #[test]
+    fn autocorrect_fixture() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &WhereRange,
+            include_bytes!("../../../tests/fixtures/cops/rails/where_range/offense.rb"),
+            include_bytes!("../../../tests/fixtures/cops/rails/where_range/corrected.rb"),
+            rails_config(),
+        );
+    }
+
     #[test]
     fn does_not_flag_complex_sql() {
         let config = rails_config();
@@ -233,4 +323,20 @@ mod tests {
         );
         assert!(diags.is_empty());
     }
+
+    #[test]
+    fn beginless_range_requires_ruby_2_7() {
+        let mut config = rails_config();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::value::Number::from(2.6)),
+        );
+        let diags = crate::testutil::run_cop_full_with_config(
+            &WhereRange,
+            b"User.where('age < ?', 18)\nUser.where('age >= ?', 18)\n",
+            config,
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].location.line, 2);
+    }
 }
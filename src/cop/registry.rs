@@ -25,6 +25,7 @@ impl CopRegistry {
         super::gemspec::register_all(&mut registry);
         super::layout::register_all(&mut registry);
         super::migration::register_all(&mut registry);
+        super::minitest::register_all(&mut registry);
         super::lint::register_all(&mut registry);
         super::metrics::register_all(&mut registry);
         super::naming::register_all(&mut registry);
@@ -34,12 +35,20 @@ impl CopRegistry {
         super::rspec::register_all(&mut registry);
         super::rspec_rails::register_all(&mut registry);
         super::security::register_all(&mut registry);
+        super::sorbet::register_all(&mut registry);
         super::style::register_all(&mut registry);
+        super::thread_safety::register_all(&mut registry);
         registry
     }
 
+    /// Add a cop. A cop with the same name as one already registered
+    /// replaces it.
     pub fn register(&mut self, cop: Box<dyn Cop>) {
         let name = cop.name();
+        if let Some(&idx) = self.index.get(name) {
+            self.cops[idx] = cop;
+            return;
+        }
         let idx = self.cops.len();
         self.cops.push(cop);
         self.index.insert(name, idx);
@@ -53,6 +62,11 @@ impl CopRegistry {
         self.index.get(name).map(|&idx| &*self.cops[idx])
     }
 
+    /// The position of `name` in `cops()`.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
     pub fn names(&self) -> Vec<&'static str> {
         self.cops.iter().map(|c| c.name()).collect()
     }
@@ -88,8 +102,8 @@ mod tests {
     fn default_registry_has_cops() {
         let reg = CopRegistry::default_registry();
         assert!(!reg.is_empty());
-        // 915 supported + 5 no-ops (obsolete on Ruby 3.4+)
-        assert_eq!(reg.len(), 915 + 5);
+        // 930 supported + 5 no-ops (obsolete on Ruby 3.4+)
+        assert_eq!(reg.len(), 930 + 5);
         // Spot-check cops from each department
         assert!(reg.get("Layout/TrailingWhitespace").is_some());
         assert!(reg.get("Layout/LineLength").is_some());
@@ -121,6 +135,29 @@ mod tests {
         assert_eq!(cop.default_severity(), Severity::Warning);
     }
 
+    #[test]
+    fn register_replaces_same_name() {
+        struct ErrorFake;
+        impl Cop for ErrorFake {
+            fn name(&self) -> &'static str {
+                "Style/Fake"
+            }
+
+            fn default_severity(&self) -> Severity {
+                Severity::Error
+            }
+        }
+
+        let mut reg = CopRegistry::new();
+        reg.register(Box::new(FakeCop));
+        reg.register(Box::new(ErrorFake));
+        assert_eq!(reg.len(), 1);
+        assert_eq!(
+            reg.get("Style/Fake").unwrap().default_severity(),
+            Severity::Error
+        );
+    }
+
     #[test]
     fn get_nonexistent() {
         let reg = CopRegistry::new();
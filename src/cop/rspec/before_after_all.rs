@@ -113,11 +113,10 @@ impl Cop for BeforeAfterAll {
         };
         let hook = String::from_utf8_lossy(&source.as_bytes()[call_start..hook_end]);
 
-        let (line, column) = source.offset_to_line_col(call_start);
-        diagnostics.push(self.diagnostic(
+        diagnostics.push(self.diagnostic_for_range(
             source,
-            line,
-            column,
+            call_start,
+            hook_end,
             format!(
                 "Beware of using `{hook}` as it may cause state to leak between tests. \
                  If you are using `rspec-rails`, and `use_transactional_fixtures` is enabled, \
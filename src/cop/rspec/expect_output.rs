@@ -90,6 +90,7 @@ impl<'pr> Visit<'pr> for ExpectOutputVisitor<'_> {
                         "Use `expect {{ ... }}.to output(...).to_{stream}` instead of mutating ${stream}."
                     ),
                     corrected: false,
+                    end_column: None,
                 });
             }
         }
@@ -120,6 +121,7 @@ impl<'pr> Visit<'pr> for ExpectOutputVisitor<'_> {
                                 "Use `expect {{ ... }}.to output(...).to_{stream}` instead of mutating ${stream}."
                             ),
                             corrected: false,
+                            end_column: None,
                         });
                     }
                 }
@@ -147,6 +149,7 @@ impl<'pr> Visit<'pr> for ExpectOutputVisitor<'_> {
                                 "Use `expect {{ ... }}.to output(...).to_{stream}` instead of mutating ${stream}."
                             ),
                             corrected: false,
+                            end_column: None,
                         });
                     }
                 }
@@ -1,6 +1,8 @@
 use crate::cop::shared::constant_predicates;
+use crate::cop::shared::rspec_language::RspecLanguage;
 use crate::cop::shared::util::{RSPEC_DEFAULT_INCLUDE, is_rspec_focused};
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 use ruby_prism::Visit;
@@ -22,6 +24,17 @@ use ruby_prism::Visit;
 /// Fix: removed the block requirement for focused methods. Added a chaining check
 /// (source text peek for `.` / `&.` after the call) to match RuboCop's
 /// `node.chained?` guard, preventing FPs on patterns like `fit.id`.
+///
+/// ## Aliases and autocorrect
+///
+/// Names configured under the department's `Language:` (`ExampleGroups` and
+/// `Examples`, see `rspec_language`) count alongside the defaults, so a
+/// suite's own `fcheck` alias is caught like `fit`.
+///
+/// Autocorrect mirrors rubocop-rspec: `fit` becomes `it` (only when the name
+/// without its `f` is a known example or group method, so `focus` is left
+/// alone), and `:focus` / `focus: true` metadata is removed together with the
+/// comma and whitespace that separated it from its neighbour.
 pub struct Focus;
 
 /// All RSpec methods that can have focus metadata or be f-prefixed.
@@ -48,9 +61,49 @@ const RSPEC_FOCUSABLE: &[&str] = &[
     "shared_context",
 ];
 
-fn is_focusable_method(name: &[u8]) -> bool {
+fn is_focusable_method(name: &[u8], language: &RspecLanguage<'_>) -> bool {
     let s = std::str::from_utf8(name).unwrap_or("");
     RSPEC_FOCUSABLE.contains(&s)
+        || language.includes_any("ExampleGroups", &["Regular", "Skipped"], name)
+        || language.includes_any("Examples", &["Regular", "Skipped", "Pending"], name)
+        || language.includes_any("SharedGroups", &["Examples", "Context"], name)
+}
+
+fn is_focused_method(name: &[u8], language: &RspecLanguage<'_>) -> bool {
+    is_rspec_focused(name)
+        || language.includes("ExampleGroups", "Focused", name)
+        || language.includes("Examples", "Focused", name)
+}
+
+/// The range to delete with focus metadata at `start..end`: the comma and
+/// whitespace before it, or, when it is the first argument, the comma and
+/// whitespace after it.
+fn metadata_removal_range(source: &SourceFile, start: usize, end: usize) -> (usize, usize) {
+    let bytes = source.as_bytes();
+    let mut before = start;
+    while before > 0 && bytes[before - 1].is_ascii_whitespace() {
+        before -= 1;
+    }
+    if before > 0 && bytes[before - 1] == b',' {
+        return (before - 1, end);
+    }
+    // First argument: keep the whitespace after the method name
+    let mut before = start;
+    while before > 0 && matches!(bytes[before - 1], b' ' | b'\t') {
+        before -= 1;
+    }
+    let mut after = end;
+    while after < bytes.len() && matches!(bytes[after], b' ' | b'\t') {
+        after += 1;
+    }
+    if after < bytes.len() && bytes[after] == b',' {
+        after += 1;
+        while after < bytes.len() && bytes[after].is_ascii_whitespace() {
+            after += 1;
+        }
+        return (start, after);
+    }
+    (before, end)
 }
 
 /// Check if a call node is "chained" — i.e., used as the receiver of another
@@ -86,19 +139,25 @@ impl Cop for Focus {
         RSPEC_DEFAULT_INCLUDE
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
         parse_result: &ruby_prism::ParseResult<'_>,
         _code_map: &crate::parse::codemap::CodeMap,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let mut visitor = FocusVisitor {
             cop: self,
             source,
+            language: RspecLanguage::from_config(config),
             diagnostics,
+            corrections,
             def_depth: 0,
         };
         visitor.visit(&parse_result.node());
@@ -108,7 +167,9 @@ impl Cop for Focus {
 struct FocusVisitor<'a> {
     cop: &'a Focus,
     source: &'a SourceFile,
+    language: RspecLanguage<'a>,
     diagnostics: &'a mut Vec<Diagnostic>,
+    corrections: Option<&'a mut Vec<Correction>>,
     /// Depth inside method definitions (def/defs). When > 0, skip flagging.
     /// Matches RuboCop's `node.each_ancestor(:any_def).any?`.
     def_depth: u32,
@@ -131,32 +192,53 @@ impl<'pr> Visit<'pr> for FocusVisitor<'_> {
 }
 
 impl FocusVisitor<'_> {
+    /// Report a focus at `start`; `fix` is the edit that removes it, if any.
+    fn flag(&mut self, start: usize, fix: Option<(usize, usize, String)>) {
+        let (line, column) = self.source.offset_to_line_col(start);
+        let mut diag =
+            self.cop
+                .diagnostic(self.source, line, column, "Focused spec found.".to_string());
+        if let (Some(corr), Some((start, end, replacement))) = (self.corrections.as_mut(), fix) {
+            corr.push(Correction {
+                start,
+                end,
+                replacement,
+                cop_name: self.cop.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        self.diagnostics.push(diag);
+    }
+
     fn check_focus(&mut self, call: &ruby_prism::CallNode<'_>) {
         let method_name = call.name().as_slice();
 
         // Check for f-prefixed methods (fit, fdescribe, fcontext, etc.)
         // Also matches bare `focus` calls from Ruby 3.1+ shorthand `focus:`.
-        if is_rspec_focused(method_name) {
+        if is_focused_method(method_name, &self.language) {
             // Only flag receiverless calls. Calls like analyzer.fit(x) have a
             // receiver and are not RSpec focus.
             if call.receiver().is_none() && !is_chained_call(self.source, call) {
-                let loc = call.location();
-                let (line, column) = self.source.offset_to_line_col(loc.start_offset());
-                self.diagnostics.push(Diagnostic {
-                    path: self.source.path_str().to_string(),
-                    location: crate::diagnostic::Location { line, column },
-                    severity: Severity::Convention,
-                    cop_name: self.cop.name().to_string(),
-                    message: "Focused spec found.".to_string(),
-                    corrected: false,
-                });
+                let unfocused = method_name.strip_prefix(b"f").unwrap_or(method_name);
+                let fix = call
+                    .message_loc()
+                    .filter(|_| is_focusable_method(unfocused, &self.language))
+                    .map(|selector| {
+                        (
+                            selector.start_offset(),
+                            selector.end_offset(),
+                            String::from_utf8_lossy(unfocused).into_owned(),
+                        )
+                    });
+                self.flag(call.location().start_offset(), fix);
             }
             return;
         }
 
         // Check for focus metadata on RSpec methods
         let is_rspec_method = if call.receiver().is_none() {
-            is_focusable_method(method_name)
+            is_focusable_method(method_name, &self.language)
         } else if let Some(recv) = call.receiver() {
             constant_predicates::constant_short_name(&recv).is_some_and(|n| n == b"RSpec")
                 && (method_name == b"describe" || method_name == b"fdescribe")
@@ -169,40 +251,28 @@ impl FocusVisitor<'_> {
         }
 
         // Check for focus: true or :focus in arguments
-        if let Some(args) = call.arguments() {
-            for arg in args.arguments().iter() {
-                // Check for :focus symbol in arguments
-                if let Some(sym) = arg.as_symbol_node() {
-                    if sym.unescaped() == b"focus" {
-                        let loc = sym.location();
-                        let (line, column) = self.source.offset_to_line_col(loc.start_offset());
-                        self.diagnostics.push(self.cop.diagnostic(
-                            self.source,
-                            line,
-                            column,
-                            "Focused spec found.".to_string(),
-                        ));
-                        return;
-                    }
+        let Some(args) = call.arguments() else {
+            return;
+        };
+        for arg in args.arguments().iter() {
+            // Check for :focus symbol in arguments
+            if let Some(sym) = arg.as_symbol_node() {
+                if sym.unescaped() == b"focus" {
+                    let loc = sym.location();
+                    self.flag_metadata(loc.start_offset(), loc.end_offset());
+                    return;
                 }
-                // Check for focus: true in hash arguments
-                if let Some(hash) = arg.as_keyword_hash_node() {
-                    for elem in hash.elements().iter() {
-                        if let Some(pair) = elem.as_assoc_node() {
-                            if let Some(key) = pair.key().as_symbol_node() {
-                                if key.unescaped() == b"focus"
-                                    && pair.value().as_true_node().is_some()
-                                {
-                                    let start = key.location().start_offset();
-                                    let (line, column) = self.source.offset_to_line_col(start);
-                                    self.diagnostics.push(self.cop.diagnostic(
-                                        self.source,
-                                        line,
-                                        column,
-                                        "Focused spec found.".to_string(),
-                                    ));
-                                    return;
-                                }
+            }
+            // Check for focus: true in hash arguments
+            if let Some(hash) = arg.as_keyword_hash_node() {
+                for elem in hash.elements().iter() {
+                    if let Some(pair) = elem.as_assoc_node() {
+                        if let Some(key) = pair.key().as_symbol_node() {
+                            if key.unescaped() == b"focus" && pair.value().as_true_node().is_some()
+                            {
+                                let loc = pair.location();
+                                self.flag_metadata(loc.start_offset(), loc.end_offset());
+                                return;
                             }
                         }
                     }
@@ -210,10 +280,41 @@ impl FocusVisitor<'_> {
             }
         }
     }
+
+    fn flag_metadata(&mut self, start: usize, end: usize) {
+        let (remove_start, remove_end) = metadata_removal_range(self.source, start, end);
+        self.flag(start, Some((remove_start, remove_end, String::new())));
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(Focus, "cops/rspec/focus");
+    crate::cop_autocorrect_fixture_tests!(Focus, "cops/rspec/focus");
+
+    fn alias_config() -> CopConfig {
+        crate::testutil::cop_config_from_yaml(
+            "Language:\n  Examples:\n    Regular:\n      - check\n    Focused:\n      - fcheck\n",
+        )
+    }
+
+    #[test]
+    fn offense_custom_alias() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &Focus,
+            include_bytes!("../../../tests/fixtures/cops/rspec/focus/offense.custom_alias.rb"),
+            alias_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_custom_alias() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &Focus,
+            include_bytes!("../../../tests/fixtures/cops/rspec/focus/offense.custom_alias.rb"),
+            include_bytes!("../../../tests/fixtures/cops/rspec/focus/corrected.custom_alias.rb"),
+            alias_config(),
+        );
+    }
 }
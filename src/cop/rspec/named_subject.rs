@@ -313,6 +313,38 @@ mod tests {
     use super::*;
     crate::cop_fixture_tests!(NamedSubject, "cops/rspec/named_subject");
 
+    fn named_only_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String("named_only".into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_named_only() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &NamedSubject,
+            include_bytes!(
+                "../../../tests/fixtures/cops/rspec/named_subject/offense.named_only.rb"
+            ),
+            named_only_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_named_only() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &NamedSubject,
+            include_bytes!(
+                "../../../tests/fixtures/cops/rspec/named_subject/no_offense.named_only.rb"
+            ),
+            named_only_config(),
+        );
+    }
+
     #[test]
     fn named_only_style_skips_without_named_subject() {
         use crate::cop::CopConfig;
@@ -1,4 +1,5 @@
 use crate::cop::shared::constant_predicates;
+use crate::cop::shared::rspec_language::RspecLanguage;
 use crate::cop::shared::util::RSPEC_DEFAULT_INCLUDE;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
@@ -36,6 +37,9 @@ use crate::parse::source::SourceFile;
 ///
 /// Fix: treat `BlockArgumentNode` as satisfying the "example has arguments but no
 /// real body" requirement, while still requiring the absence of a real `BlockNode`.
+///
+/// Names configured under the department's `Language:` (see `rspec_language`)
+/// are matched alongside each table below.
 pub struct Pending;
 
 /// X-prefixed example group methods (skipped groups).
@@ -132,7 +136,7 @@ impl Cop for Pending {
         source: &SourceFile,
         parse_result: &ruby_prism::ParseResult<'_>,
         _code_map: &CodeMap,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
@@ -141,6 +145,7 @@ impl Cop for Pending {
         struct Visitor<'a> {
             cop: &'a Pending,
             source: &'a SourceFile,
+            language: RspecLanguage<'a>,
             diagnostics: &'a mut Vec<Diagnostic>,
         }
 
@@ -158,24 +163,30 @@ impl Cop for Pending {
 
             fn check_call(&mut self, call: &ruby_prism::CallNode<'_>) {
                 let method_name = call.name().as_slice();
+                let language = self.language;
+                let in_table = |table: &[&[u8]], group: &str, kind: &str| {
+                    table.contains(&method_name) || language.includes(group, kind, method_name)
+                };
 
                 // 1. X-prefixed example groups (xdescribe, xcontext, xfeature)
                 //    Matches with nil or RSpec receiver, with or without block.
-                if XGROUP_METHODS.contains(&method_name) && has_rspec_or_nil_receiver(call) {
+                if in_table(XGROUP_METHODS, "ExampleGroups", "Skipped")
+                    && has_rspec_or_nil_receiver(call)
+                {
                     self.flag(call);
                     return;
                 }
 
                 // 2. X-prefixed examples (xit, xspecify, xexample, xscenario)
                 //    Nil receiver only, with or without block.
-                if XEXAMPLE_METHODS.contains(&method_name) && call.receiver().is_none() {
+                if in_table(XEXAMPLE_METHODS, "Examples", "Skipped") && call.receiver().is_none() {
                     self.flag(call);
                     return;
                 }
 
                 // 3. `skip`/`pending` as example-defining or standalone calls.
                 //    Nil receiver, any args (or none), with or without block.
-                if (method_name == b"skip" || method_name == b"pending")
+                if in_table(&[b"skip".as_slice(), b"pending"], "Examples", "Pending")
                     && call.receiver().is_none()
                 {
                     self.flag(call);
@@ -183,7 +194,7 @@ impl Cop for Pending {
                 }
 
                 // 4. Regular example groups with :skip/:pending metadata.
-                if REGULAR_GROUPS.contains(&method_name)
+                if in_table(REGULAR_GROUPS, "ExampleGroups", "Regular")
                     && has_rspec_or_nil_receiver(call)
                     && has_skip_or_pending_metadata(call)
                 {
@@ -192,7 +203,8 @@ impl Cop for Pending {
                 }
 
                 // 5. Regular examples with :skip/:pending metadata.
-                if REGULAR_EXAMPLES.contains(&method_name)
+                let regular_example = in_table(REGULAR_EXAMPLES, "Examples", "Regular");
+                if regular_example
                     && call.receiver().is_none()
                     && has_skip_or_pending_metadata(call)
                 {
@@ -202,7 +214,7 @@ impl Cop for Pending {
 
                 // 6. Examples without bodies (e.g., `it 'test'` with no block).
                 //    Must have at least one argument (to avoid matching `it` as block param).
-                if REGULAR_EXAMPLES.contains(&method_name)
+                if regular_example
                     && call.receiver().is_none()
                     && call.block().and_then(|b| b.as_block_node()).is_none()
                     && has_example_arguments(call)
@@ -222,6 +234,7 @@ impl Cop for Pending {
         let mut visitor = Visitor {
             cop: self,
             source,
+            language: RspecLanguage::from_config(config),
             diagnostics,
         };
         let root = parse_result.node();
@@ -233,4 +246,15 @@ impl Cop for Pending {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(Pending, "cops/rspec/pending");
+
+    #[test]
+    fn offense_custom_alias() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &Pending,
+            include_bytes!("../../../tests/fixtures/cops/rspec/pending/offense.custom_alias.rb"),
+            crate::testutil::cop_config_from_yaml(
+                "Language:\n  Examples:\n    Regular:\n      - check\n    Skipped:\n      - xcheck\n",
+            ),
+        );
+    }
 }
@@ -51,6 +51,10 @@ impl Cop for VariableDefinition {
         RSPEC_DEFAULT_INCLUDE
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -58,7 +62,7 @@ impl Cop for VariableDefinition {
         _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let enforced_style = config.get_str("EnforcedStyle", "symbols");
 
@@ -75,9 +79,14 @@ impl Cop for VariableDefinition {
             top_level_offsets: &top_level_offsets,
             enforced_style,
             diags: Vec::new(),
+            autocorrect: corrections.is_some(),
+            corrections: Vec::new(),
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diags);
+        if let Some(corrections) = corrections {
+            corrections.extend(visitor.corrections);
+        }
     }
 }
 
@@ -88,6 +97,8 @@ struct VariableDefinitionChecker<'a> {
     top_level_offsets: &'a HashSet<usize>,
     enforced_style: &'a str,
     diags: Vec<Diagnostic>,
+    autocorrect: bool,
+    corrections: Vec<crate::correction::Correction>,
 }
 
 impl<'pr> Visit<'pr> for VariableDefinitionChecker<'_> {
@@ -152,16 +163,61 @@ impl VariableDefinitionChecker<'_> {
                 } else {
                     "Use symbols for variable names."
                 };
-                self.diags.push(
-                    self.cop
-                        .diagnostic(self.source, line, column, msg.to_string()),
-                );
+                let mut diag = self
+                    .cop
+                    .diagnostic(self.source, line, column, msg.to_string());
+                if self.autocorrect {
+                    if let Some(replacement) = converted_name(&arg) {
+                        self.corrections.push(crate::correction::Correction {
+                            start: loc.start_offset(),
+                            end: loc.end_offset(),
+                            replacement,
+                            cop_name: self.cop.name(),
+                            cop_index: 0,
+                        });
+                        diag.corrected = true;
+                    }
+                }
+                self.diags.push(diag);
             }
             break;
         }
     }
 }
 
+/// The name `node` written the other way: `:foo` as `"foo"` and `'foo'` as
+/// `:foo`. Interpolated symbols and names that would need escaping are left
+/// alone.
+fn converted_name(node: &ruby_prism::Node<'_>) -> Option<String> {
+    let (name, to_symbol) = if let Some(sym) = node.as_symbol_node() {
+        (sym.unescaped(), false)
+    } else {
+        (node.as_string_node()?.unescaped(), true)
+    };
+    let name = std::str::from_utf8(name).ok()?;
+    if name.is_empty()
+        || name
+            .chars()
+            .any(|c| matches!(c, '"' | '\\' | '#') || c.is_control())
+    {
+        return None;
+    }
+    if !to_symbol {
+        Some(format!("\"{name}\""))
+    } else if is_plain_symbol_name(name) {
+        Some(format!(":{name}"))
+    } else {
+        Some(format!(":\"{name}\""))
+    }
+}
+
+/// Whether `:name` is a valid symbol literal without quotes.
+fn is_plain_symbol_name(name: &str) -> bool {
+    let body = name.strip_suffix(['?', '!', '=']).unwrap_or(name);
+    body.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && body.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 /// Find the byte offsets of all top-level spec group call nodes.
 /// Matches RuboCop's TopLevelGroup logic (same as RSpec/InstanceVariable).
 fn find_top_level_group_offsets(program: &ruby_prism::ProgramNode<'_>) -> HashSet<usize> {
@@ -248,6 +304,54 @@ fn is_rspec_receiver(call: &ruby_prism::CallNode<'_>) -> bool {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(VariableDefinition, "cops/rspec/variable_definition");
+    crate::cop_autocorrect_fixture_tests!(VariableDefinition, "cops/rspec/variable_definition");
+
+    #[test]
+    fn strings_style_fixtures() {
+        let yaml = "EnforcedStyle: strings\n";
+        let offense = include_bytes!(
+            "../../../tests/fixtures/cops/rspec/variable_definition/offense.strings.rb"
+        );
+        crate::testutil::assert_cop_offenses_with_yaml(&VariableDefinition, yaml, offense);
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &VariableDefinition,
+            yaml,
+            include_bytes!(
+                "../../../tests/fixtures/cops/rspec/variable_definition/no_offense.strings.rb"
+            ),
+        );
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &VariableDefinition,
+            offense,
+            include_bytes!(
+                "../../../tests/fixtures/cops/rspec/variable_definition/corrected.strings.rb"
+            ),
+            crate::testutil::cop_config_from_yaml(yaml),
+        );
+    }
+
+    #[test]
+    fn converted_name_quotes_when_needed() {
+        let convert = |src: &[u8]| {
+            let result = ruby_prism::parse(src);
+            let node = result
+                .node()
+                .as_program_node()
+                .unwrap()
+                .statements()
+                .body()
+                .iter()
+                .next()
+                .unwrap();
+            converted_name(&node)
+        };
+        assert_eq!(convert(b"'foo'").as_deref(), Some(":foo"));
+        assert_eq!(convert(b"'foo bar'").as_deref(), Some(":\"foo bar\""));
+        assert_eq!(convert(b"'1st'").as_deref(), Some(":\"1st\""));
+        assert_eq!(convert(b":foo?").as_deref(), Some("\"foo?\""));
+        assert_eq!(convert(b"'a\\\"b'"), None);
+        assert_eq!(convert(b"'#foo'"), None);
+    }
 
     #[test]
     fn strings_style_flags_symbol_names() {
@@ -197,6 +197,25 @@ mod tests {
     use super::*;
     crate::cop_fixture_tests!(VariableName, "cops/rspec/variable_name");
 
+    #[test]
+    fn allowed_patterns_fixtures() {
+        let yaml = "AllowedPatterns:\n  - '\\AJWT_'\n";
+        crate::testutil::assert_cop_offenses_with_yaml(
+            &VariableName,
+            yaml,
+            include_bytes!(
+                "../../../tests/fixtures/cops/rspec/variable_name/offense.allowed_patterns.rb"
+            ),
+        );
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &VariableName,
+            yaml,
+            include_bytes!(
+                "../../../tests/fixtures/cops/rspec/variable_name/no_offense.allowed_patterns.rb"
+            ),
+        );
+    }
+
     #[test]
     fn camel_case_style_flags_snake_case() {
         use crate::cop::CopConfig;
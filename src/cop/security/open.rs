@@ -31,6 +31,15 @@ use crate::parse::source::SourceFile;
 /// files matched by the `vendor/**/*` AllCops.Exclude pattern when run from the
 /// project root. Running from the repo's own directory (as CI does) finds all
 /// offenses correctly.
+///
+/// ## Receiver and argument scope
+///
+/// Only bare `open` and `URI.open`/`::URI.open` are checked; `Foo.open`,
+/// `Foo::URI.open`, and explicit `Kernel.open` are not, matching RuboCop's
+/// `(send {nil? (const {nil? cbase} :URI)} :open ...)`. A first argument that
+/// is a string literal, or an interpolation or `+` concatenation that starts
+/// with one, is safe unless it starts with `|`. A string that starts with an
+/// interpolation (`"#{BASE}/x"`) is flagged, as upstream does.
 pub struct Open;
 
 /// Check if the argument is a "safe" string literal.
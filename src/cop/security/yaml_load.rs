@@ -1,9 +1,19 @@
+use crate::cop::shared::node_type::CALL_NODE;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::{Diagnostic, Severity};
 use crate::parse::source::SourceFile;
 
-/// No-op: `YAML.load` is safe since Ruby 3.1 (Psych 4 default), making this cop obsolete.
-/// Retained for configuration compatibility only.
+/// Security/YAMLLoad — prefer `YAML.safe_load` over `YAML.load`.
+///
+/// RuboCop declares `maximum_target_ruby_version 3.0`: Ruby 3.1 ships Psych 4,
+/// where `YAML.load` is `safe_load`, so the cop only fires for older targets.
+/// This was previously a no-op stub, which missed every offense in projects
+/// still targeting Ruby 3.0 or earlier.
+///
+/// Only `YAML.load` and `::YAML.load` match (`(send (const {nil? cbase} :YAML)
+/// :load ...)`); `Psych.load` and `Foo::YAML.load` are left alone, as upstream
+/// does. Autocorrect swaps the method for `safe_load`, which is unsafe: it
+/// rejects aliases and arbitrary classes that the document may rely on.
 pub struct YamlLoad;
 
 impl Cop for YamlLoad {
@@ -15,33 +25,101 @@ impl Cop for YamlLoad {
         Severity::Warning
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
-        &[]
+        &[CALL_NODE]
     }
 
     fn check_node(
         &self,
-        _source: &SourceFile,
-        _node: &ruby_prism::Node<'_>,
+        source: &SourceFile,
+        node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
-        _diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: maximum_target_ruby_version 3.0
+        if !config.ruby_version_at_most(3.0) {
+            return;
+        }
+
+        let Some(call) = node.as_call_node() else {
+            return;
+        };
+        if call.name().as_slice() != b"load" {
+            return;
+        }
+        if !call.receiver().is_some_and(|recv| is_top_level_yaml(&recv)) {
+            return;
+        }
+
+        let Some(msg_loc) = call.message_loc() else {
+            return;
+        };
+        let (line, column) = source.offset_to_line_col(msg_loc.start_offset());
+        let mut diag = self.diagnostic(
+            source,
+            line,
+            column,
+            "Prefer using `YAML.safe_load` over `YAML.load`.".to_string(),
+        );
+        if let Some(corr) = corrections {
+            corr.push(crate::correction::Correction {
+                start: msg_loc.start_offset(),
+                end: msg_loc.end_offset(),
+                replacement: "safe_load".to_string(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
     }
 }
 
+/// `YAML` or `::YAML`.
+fn is_top_level_yaml(node: &ruby_prism::Node<'_>) -> bool {
+    if let Some(read) = node.as_constant_read_node() {
+        return read.name().as_slice() == b"YAML";
+    }
+    node.as_constant_path_node().is_some_and(|path| {
+        path.parent().is_none() && path.name().is_some_and(|name| name.as_slice() == b"YAML")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testutil::cop_config_from_yaml_with_targets;
+
+    crate::cop_fixture_tests!(YamlLoad, "cops/security/yaml_load");
+    crate::cop_autocorrect_fixture_tests!(YamlLoad, "cops/security/yaml_load");
+
+    #[test]
+    fn fires_up_to_ruby_3_0() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &YamlLoad,
+            include_bytes!("../../../tests/fixtures/cops/security/yaml_load/offense.rb"),
+            cop_config_from_yaml_with_targets("", Some(3.0), None),
+        );
+    }
 
     #[test]
-    fn never_fires() {
-        let source = b"YAML.load(data)\nPsych.load(data)\n::YAML.load(x)\n";
-        let diags = crate::testutil::run_cop(&YamlLoad, source);
-        assert!(
-            diags.is_empty(),
-            "No-op cop should never produce diagnostics"
+    fn no_offense_from_ruby_3_1() {
+        // Psych 4 made `YAML.load` safe by default
+        let diagnostics = crate::testutil::run_cop_full_with_config(
+            &YamlLoad,
+            b"YAML.load(data)\n::YAML.load(io)\n",
+            cop_config_from_yaml_with_targets("", Some(3.1), None),
         );
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
     }
 }
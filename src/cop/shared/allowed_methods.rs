@@ -0,0 +1,82 @@
+//! Shared `AllowedMethods` / `AllowedPatterns` lookup, mirroring RuboCop's
+//! `AllowedMethods` and `AllowedPattern` mixins.
+//!
+//! Canonical source:
+//! `vendor/rubocop/lib/rubocop/cop/mixin/allowed_methods.rb`
+//! `vendor/rubocop/lib/rubocop/cop/mixin/allowed_pattern.rb`
+//!
+//! The deprecated keys are still read as additions to the current ones, as
+//! RuboCop does; the config loader warns when a project sets them.
+
+use crate::cop::CopConfig;
+
+/// Deprecated aliases of `AllowedMethods`.
+pub const DEPRECATED_ALLOWED_METHODS_KEYS: &[&str] = &["IgnoredMethods", "ExcludedMethods"];
+
+/// Deprecated aliases of `AllowedPatterns`.
+pub const DEPRECATED_ALLOWED_PATTERNS_KEYS: &[&str] = &["IgnoredPatterns"];
+
+/// `AllowedMethods` followed by any values under its deprecated aliases.
+pub fn allowed_methods(config: &CopConfig) -> Vec<String> {
+    collect_values(config, "AllowedMethods", DEPRECATED_ALLOWED_METHODS_KEYS)
+}
+
+/// `AllowedPatterns` followed by any values under its deprecated aliases.
+pub fn allowed_patterns(config: &CopConfig) -> Vec<String> {
+    collect_values(config, "AllowedPatterns", DEPRECATED_ALLOWED_PATTERNS_KEYS)
+}
+
+/// Whether `name` matches one of `patterns`. Invalid regexes never match.
+pub fn matches_allowed_pattern(patterns: &[String], name: &str) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| regex::Regex::new(pattern).is_ok_and(|re| re.is_match(name)))
+}
+
+fn collect_values(config: &CopConfig, key: &str, deprecated: &[&str]) -> Vec<String> {
+    std::iter::once(key)
+        .chain(deprecated.iter().copied())
+        .filter_map(|key| config.get_string_array(key))
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> serde_yml::Value {
+        serde_yml::Value::Sequence(
+            values
+                .iter()
+                .map(|v| serde_yml::Value::String(v.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn deprecated_keys_extend_current_ones() {
+        let config = CopConfig {
+            options: std::collections::HashMap::from([
+                ("AllowedMethods".into(), strings(&["describe"])),
+                ("ExcludedMethods".into(), strings(&["context"])),
+                ("IgnoredMethods".into(), strings(&["task"])),
+                ("IgnoredPatterns".into(), strings(&["\\Ascenario"])),
+            ]),
+            ..CopConfig::default()
+        };
+        assert_eq!(
+            allowed_methods(&config),
+            vec!["describe", "task", "context"]
+        );
+        assert_eq!(allowed_patterns(&config), vec!["\\Ascenario"]);
+        assert!(matches_allowed_pattern(
+            &allowed_patterns(&config),
+            "scenario_one"
+        ));
+        assert!(!matches_allowed_pattern(
+            &allowed_patterns(&config),
+            "a_scenario"
+        ));
+    }
+}
@@ -0,0 +1,209 @@
+//! Shared documentation-comment helpers, mirroring RuboCop's
+//! `DocumentationComment` mixin used by `Style/Documentation` and
+//! `Style/DocumentationMethod`.
+//!
+//! Canonical source:
+//! `vendor/rubocop/lib/rubocop/cop/mixin/documentation_comment.rb`
+//!
+//! A comment counts as documentation unless it is an annotation (`# TODO: ...`),
+//! an interpreter directive (`# frozen_string_literal: true`, `# encoding: ...`),
+//! a shebang, or a `# rubocop:` directive. `:nodoc:` markers are parsed here too
+//! so callers agree on the `# :nodoc: all` syntax.
+
+/// Check if a comment line is a magic/annotation/directive comment that doesn't count
+/// as documentation. These include:
+/// - `# frozen_string_literal: true`
+/// - `# encoding: ...`
+/// - `# rubocop:disable ...`
+/// - `# TODO: ...`, `# FIXME: ...`, etc.
+pub fn is_annotation_or_directive(comment: &str) -> bool {
+    let text = comment.trim_start_matches('#').trim();
+
+    // Shebang / interpreter directive comments do not count as documentation.
+    // Must start with `!/` to match actual shebangs like `#!/usr/bin/env ruby`,
+    // not comments like `# !deny policy entry`.
+    if text.starts_with("!/") {
+        return true;
+    }
+
+    if is_interpreter_directive_comment(text) {
+        return true;
+    }
+    // RuboCop directives
+    if text.starts_with("rubocop:") {
+        return true;
+    }
+
+    if is_annotation_comment(text) {
+        return true;
+    }
+
+    false
+}
+
+pub fn is_annotation_comment(text: &str) -> bool {
+    const DEFAULT_ANNOTATION_KEYWORDS: &[&str] =
+        &["TODO", "FIXME", "OPTIMIZE", "HACK", "REVIEW", "NOTE"];
+
+    for keyword in DEFAULT_ANNOTATION_KEYWORDS {
+        if !text
+            .get(..keyword.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(keyword))
+        {
+            continue;
+        }
+
+        let keyword_text = &text[..keyword.len()];
+        let mut rest = &text[keyword.len()..];
+
+        if let Some(next_byte) = rest.as_bytes().first() {
+            if next_byte.is_ascii_alphanumeric() || *next_byte == b'_' {
+                continue;
+            }
+        }
+
+        let has_colon = {
+            let trimmed = rest.trim_start();
+            if let Some(after_colon) = trimmed.strip_prefix(':') {
+                rest = after_colon;
+                true
+            } else {
+                false
+            }
+        };
+
+        let has_space = if !rest.is_empty() && rest.as_bytes()[0].is_ascii_whitespace() {
+            rest = rest.trim_start();
+            true
+        } else {
+            false
+        };
+
+        let has_note = !rest.is_empty();
+
+        if !has_colon && !has_space {
+            continue;
+        }
+
+        if just_keyword_of_sentence(keyword_text, has_colon, has_space, has_note) {
+            continue;
+        }
+
+        return true;
+    }
+
+    false
+}
+
+fn just_keyword_of_sentence(
+    keyword_text: &str,
+    has_colon: bool,
+    has_space: bool,
+    has_note: bool,
+) -> bool {
+    if has_colon || !has_space || !has_note {
+        return false;
+    }
+
+    let mut chars = keyword_text.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_uppercase() => {}
+        _ => return false,
+    }
+
+    chars.all(|c| c.is_ascii_lowercase())
+}
+
+fn is_interpreter_directive_comment(text: &str) -> bool {
+    has_magic_comment_key(text, "frozen_string_literal")
+        || has_magic_comment_key(text, "shareable_constant_value")
+        || has_magic_comment_key(text, "warn_indent")
+        || has_magic_comment_key(text, "coding")
+        || has_magic_comment_key(text, "encoding")
+}
+
+pub fn is_emacs_style_magic_comment(text: &str) -> bool {
+    wrapped_magic_comment_inner(text).is_some()
+}
+
+fn wrapped_magic_comment_inner(text: &str) -> Option<&str> {
+    let text = text.trim();
+    if text.starts_with("-*-") && text.ends_with("-*-") {
+        return Some(
+            text.trim_start_matches("-*-")
+                .trim_end_matches("-*-")
+                .trim(),
+        );
+    }
+    if text.starts_with("~*~") && text.ends_with("~*~") {
+        return Some(
+            text.trim_start_matches("~*~")
+                .trim_end_matches("~*~")
+                .trim(),
+        );
+    }
+    None
+}
+
+fn has_magic_comment_key(text: &str, key: &str) -> bool {
+    text.strip_prefix(key)
+        .is_some_and(|rest| rest.trim_start().starts_with(':'))
+}
+
+pub fn trim_bytes(line: &[u8]) -> &[u8] {
+    let start = line
+        .iter()
+        .position(|&b| b != b' ' && b != b'\t')
+        .unwrap_or(line.len());
+    let end = line
+        .iter()
+        .rposition(|&b| b != b' ' && b != b'\t' && b != b'\r' && b != b'\n')
+        .map_or(start, |e| e + 1);
+    if end > start { &line[start..end] } else { &[] }
+}
+
+/// Parse a `:nodoc:` marker from a comment (including its leading `#`).
+/// Returns `Some(true)` for `# :nodoc: all`, `Some(false)` for a plain
+/// `# :nodoc:`, and `None` when the comment does not start with `:nodoc:`.
+///
+/// Matches RuboCop's `/^#\s*:nodoc:\s+all\s*$/` for the `all` form: the
+/// whitespace before `all` is required and nothing may follow it.
+pub fn nodoc_comment(comment: &str) -> Option<bool> {
+    let text = comment
+        .strip_prefix('#')
+        .unwrap_or(comment)
+        .trim_start_matches([' ', '\t']);
+    let after_nodoc = text.strip_prefix(":nodoc:")?;
+    let has_space = after_nodoc.starts_with(' ') || after_nodoc.starts_with('\t');
+    let all = has_space && {
+        let trimmed = after_nodoc.trim_start();
+        trimmed.starts_with("all") && trimmed[3..].trim().is_empty()
+    };
+    Some(all)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nodoc_comment_forms() {
+        assert_eq!(nodoc_comment("#:nodoc:"), Some(false));
+        assert_eq!(nodoc_comment("# :nodoc: all"), Some(true));
+        assert_eq!(nodoc_comment("# :nodoc:all"), Some(false));
+        assert_eq!(nodoc_comment("# :nodoc: all::Plugin"), Some(false));
+        assert_eq!(nodoc_comment("# @private :nodoc:"), None);
+    }
+
+    #[test]
+    fn directives_and_annotations_are_not_documentation() {
+        assert!(is_annotation_or_directive("# frozen_string_literal: true"));
+        assert!(is_annotation_or_directive("# rubocop:disable Style/Foo"));
+        assert!(is_annotation_or_directive("# TODO: document"));
+        assert!(is_annotation_or_directive("#!/usr/bin/env ruby"));
+        assert!(!is_annotation_or_directive(
+            "# Note that this is documentation"
+        ));
+        assert!(!is_annotation_or_directive("# Parses the config."));
+    }
+}
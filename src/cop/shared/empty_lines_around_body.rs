@@ -0,0 +1,165 @@
+//! The `EnforcedStyle` values of Layout/EmptyLinesAroundClassBody and
+//! Layout/EmptyLinesAroundModuleBody, mirroring RuboCop's
+//! `EmptyLinesAroundBody` mixin.
+//!
+//! Canonical source:
+//! `vendor/rubocop/lib/rubocop/cop/mixin/empty_lines_around_body.rb`
+//!
+//! Each style decides, per edge of the body, whether the line there must be
+//! blank or must not be; `util::check_body_edge` does the checking and the
+//! correcting. `empty_lines_special` additionally wants a blank line before
+//! the first method, class, or module definition when the body does not
+//! start with one.
+
+use crate::cop::shared::util::{self, BodyEdge};
+use crate::correction::Correction;
+use crate::diagnostic::{Diagnostic, Location, Severity};
+use crate::parse::source::SourceFile;
+
+/// Check the body between `keyword_offset` and `end_offset` against `style`.
+/// `body` is the node's body (`None` when it is empty); `body_kind` names the
+/// node in messages ("class", "module").
+#[allow(clippy::too_many_arguments)]
+pub fn check(
+    cop_name: &'static str,
+    source: &SourceFile,
+    style: &str,
+    keyword_offset: usize,
+    end_offset: usize,
+    body: Option<ruby_prism::Node<'_>>,
+    body_kind: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+    mut corrections: Option<&mut Vec<Correction>>,
+) {
+    // Only `no_empty_lines` has anything to say about an empty body
+    if body.is_none() && style != "no_empty_lines" {
+        return;
+    }
+    if source.offset_to_line_col(keyword_offset).0 == source.offset_to_line_col(end_offset).0 {
+        return;
+    }
+    let children = body.as_ref().map(body_children).unwrap_or_default();
+    let one_child_namespace =
+        matches!(children.as_slice(), [child] if is_constant_definition(child));
+
+    // Whether each edge must be blank
+    let (beginning, ending) = match style {
+        "empty_lines" => (true, true),
+        "empty_lines_except_namespace" => (!one_child_namespace, !one_child_namespace),
+        "empty_lines_special" if one_child_namespace => (false, false),
+        "empty_lines_special" => {
+            let first_requires = children.first().is_some_and(requires_empty_line);
+            if !first_requires {
+                if let Some(definition) = children.iter().find(|c| requires_empty_line(c)) {
+                    diagnostics.extend(check_deferred_empty_line(
+                        cop_name,
+                        source,
+                        definition,
+                        corrections.as_deref_mut(),
+                    ));
+                }
+            }
+            (first_requires, true)
+        }
+        "beginning_only" => (true, false),
+        "ending_only" => (false, true),
+        // "no_empty_lines" (default)
+        _ => (false, false),
+    };
+
+    for (edge, require_empty) in [(BodyEdge::Beginning, beginning), (BodyEdge::End, ending)] {
+        diagnostics.extend(util::check_body_edge(
+            cop_name,
+            source,
+            keyword_offset,
+            end_offset,
+            body_kind,
+            edge,
+            require_empty,
+            corrections.as_deref_mut(),
+        ));
+    }
+}
+
+/// The top-level statements of a body.
+fn body_children<'pr>(body: &ruby_prism::Node<'pr>) -> Vec<ruby_prism::Node<'pr>> {
+    match body.as_statements_node() {
+        Some(statements) => statements.body().iter().collect(),
+        None => vec![body.clone()],
+    }
+}
+
+fn is_constant_definition(node: &ruby_prism::Node<'_>) -> bool {
+    node.as_class_node().is_some() || node.as_module_node().is_some()
+}
+
+/// Method, class, and module definitions, which `empty_lines_special` wants
+/// set apart by a blank line.
+fn requires_empty_line(node: &ruby_prism::Node<'_>) -> bool {
+    node.as_def_node().is_some() || is_constant_definition(node)
+}
+
+/// RuboCop's node type name for `requires_empty_line` nodes.
+fn definition_type(node: &ruby_prism::Node<'_>) -> &'static str {
+    match node.as_def_node() {
+        Some(def) if def.receiver().is_some() => "defs",
+        Some(_) => "def",
+        None if node.as_class_node().is_some() => "class",
+        None => "module",
+    }
+}
+
+/// `empty_lines_special` for a body that does not start with a definition:
+/// the first definition, together with the comments directly above it, must
+/// follow a blank line.
+fn check_deferred_empty_line(
+    cop_name: &'static str,
+    source: &SourceFile,
+    definition: &ruby_prism::Node<'_>,
+    corrections: Option<&mut Vec<Correction>>,
+) -> Option<Diagnostic> {
+    let (def_line, _) = source.offset_to_line_col(definition.location().start_offset());
+    if def_line <= 1 {
+        return None;
+    }
+    let mut line_no = def_line - 1;
+    while line_no > 1
+        && util::line_at(source, line_no)
+            .is_some_and(|line| line.trim_ascii_start().starts_with(b"#"))
+    {
+        line_no -= 1;
+    }
+    if util::line_at(source, line_no).is_none_or(util::is_blank_line) {
+        return None;
+    }
+
+    let offense_line = line_no + 1;
+    let mut diag = Diagnostic {
+        path: source.path_str().to_string(),
+        location: Location {
+            line: offense_line,
+            column: 0,
+        },
+        severity: Severity::Convention,
+        cop_name: cop_name.to_string(),
+        message: format!(
+            "Empty line missing before first {} definition.",
+            definition_type(definition)
+        ),
+        corrected: false,
+        end_column: None,
+    };
+    if let Some(corr) = corrections {
+        if let Some(offset) = source.line_col_to_offset(offense_line, 0) {
+            corr.push(Correction {
+                start: offset,
+                end: offset,
+                replacement: "\n".to_string(),
+                cop_name,
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+    }
+    Some(diag)
+}
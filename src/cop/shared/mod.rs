@@ -1,10 +1,17 @@
 pub mod access_modifier_predicates;
+pub mod allowed_methods;
 pub mod constant_predicates;
+pub mod documentation_comment;
+pub mod empty_lines_around_body;
 pub mod literal_predicates;
 pub mod method_dispatch_predicates;
 pub mod method_identifier_predicates;
+pub mod multiline_element_indentation;
 pub mod node_type;
 pub mod node_type_groups;
 pub mod numeric_predicates;
+pub mod ordered_gem_node;
+pub mod preceding_following_alignment;
 pub mod predicate_operator_predicates;
+pub mod rspec_language;
 pub mod util;
@@ -0,0 +1,127 @@
+//! Shared indentation helpers for the `First*Indentation` cops, mirroring
+//! RuboCop's `MultilineElementIndentation` mixin and `AlignmentCorrector`.
+//!
+//! Canonical source:
+//! `vendor/rubocop/lib/rubocop/cop/mixin/multiline_element_indentation.rb`
+//! `vendor/rubocop/lib/rubocop/cop/correctors/alignment_corrector.rb`
+//!
+//! Used by `Layout/FirstArgumentIndentation`,
+//! `Layout/FirstArrayElementIndentation`, and
+//! `Layout/FirstHashElementIndentation`. Each cop decides which base applies
+//! (the rules differ per style); the column math, the base descriptions, and
+//! the single-line re-indent correction live here.
+
+use crate::correction::Correction;
+use crate::parse::source::SourceFile;
+
+/// What the expected indentation of a first element is measured from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndentBase {
+    /// Indentation of the line holding the opening delimiter.
+    StartOfLine,
+    /// Column of the opening `[` / `{` (`align_brackets` / `align_braces`).
+    LeftBraceOrBracket,
+    /// Column right after the `(` of the enclosing method call.
+    FirstColumnAfterLeftParenthesis,
+    /// Column of the hash key whose value is the literal.
+    ParentHashKey,
+}
+
+impl IndentBase {
+    /// RuboCop's `base_description`. `delimiter` is `"bracket"` or `"brace"`;
+    /// `left_delimiter` is how the start-of-line form names it
+    /// (`"left square bracket"` / `"left brace"`).
+    pub fn description(self, delimiter: &str, left_delimiter: &str) -> String {
+        match self {
+            IndentBase::LeftBraceOrBracket => format!("the position of the opening {delimiter}"),
+            IndentBase::FirstColumnAfterLeftParenthesis => {
+                "the first position after the preceding left parenthesis".to_string()
+            }
+            IndentBase::ParentHashKey => "the parent hash key".to_string(),
+            IndentBase::StartOfLine => {
+                format!("the start of the line where the {left_delimiter} is")
+            }
+        }
+    }
+}
+
+/// Column of the first non-whitespace character on a line, counting tabs
+/// and spaces as one column each (RuboCop's `source_line =~ /\S/`).
+pub fn line_indent(line: &[u8]) -> usize {
+    line.iter()
+        .take_while(|&&b| b == b' ' || b == b'\t')
+        .count()
+}
+
+/// Indentation of the nearest non-blank, non-comment line above
+/// `line_number` (RuboCop's `previous_code_line`). Lines starting with `#{`
+/// are heredoc interpolation, not comments.
+pub fn previous_code_line_indent(source: &SourceFile, line_number: usize) -> usize {
+    let lines: Vec<&[u8]> = source.lines().collect();
+    let mut line_num = line_number;
+    loop {
+        if line_num <= 1 {
+            return 0;
+        }
+        line_num -= 1;
+        let line_bytes = lines.get(line_num - 1).copied().unwrap_or(b"");
+        if line_bytes
+            .iter()
+            .all(|&b| b == b' ' || b == b'\t' || b == b'\n' || b == b'\r')
+        {
+            continue;
+        }
+        let mut after_ws = line_bytes
+            .iter()
+            .skip_while(|&&b| b == b' ' || b == b'\t')
+            .copied();
+        if after_ws.next() == Some(b'#') && after_ws.next() != Some(b'{') {
+            continue;
+        }
+        return line_indent(line_bytes);
+    }
+}
+
+/// Re-indent the line holding `offset` so that `offset` lands on
+/// `expected_col`. Only the leading whitespace of that one line changes.
+/// Returns `None` when code precedes `offset` on its line.
+pub fn reindent_line(
+    source: &SourceFile,
+    offset: usize,
+    expected_col: usize,
+    cop_name: &'static str,
+) -> Option<Correction> {
+    let (line, _) = source.offset_to_line_col(offset);
+    let line_start = source.line_start_offset(line);
+    let prefix = source.as_bytes().get(line_start..offset)?;
+    if !prefix.iter().all(|&b| b == b' ' || b == b'\t') {
+        return None;
+    }
+    Some(Correction {
+        start: line_start,
+        end: offset,
+        replacement: " ".repeat(expected_col),
+        cop_name,
+        cop_index: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn previous_code_line_skips_blank_and_comment_lines() {
+        let source = SourceFile::from_bytes("test.rb", b"  foo(\n\n    # note\n      1\n".to_vec());
+        assert_eq!(previous_code_line_indent(&source, 4), 2);
+    }
+
+    #[test]
+    fn reindent_line_only_touches_leading_whitespace() {
+        let source = SourceFile::from_bytes("test.rb", b"foo(\n      1\n)\n".to_vec());
+        let correction = reindent_line(&source, 11, 2, "Layout/FirstArgumentIndentation").unwrap();
+        assert_eq!((correction.start, correction.end), (5, 11));
+        assert_eq!(correction.replacement, "  ");
+        assert!(reindent_line(&source, 3, 2, "Layout/FirstArgumentIndentation").is_none());
+    }
+}
@@ -0,0 +1,135 @@
+//! Shared ordering helpers for `Bundler/OrderedGems` and
+//! `Gemspec/OrderedDependencies`, mirroring RuboCop's `OrderedGemNode` mixin
+//! and `OrderedGemCorrector`.
+//!
+//! Canonical source:
+//! `vendor/rubocop/lib/rubocop/cop/mixin/ordered_gem_node.rb`
+//! `vendor/rubocop/lib/rubocop/cop/correctors/ordered_gem_corrector.rb`
+//!
+//! Both cops are line-based: each declaration is tracked as a byte range that
+//! starts at its first attached comment line (when comments are not group
+//! separators) and ends after its last continuation line.
+
+/// A declaration inside an ordering window, tracked by byte range.
+pub struct OrderedEntry {
+    pub sort_key: String,
+    /// Byte offset of the first line of the declaration, including any
+    /// preceding comment lines attached to it.
+    pub start: usize,
+    /// Byte offset past the end of the declaration's last line, including
+    /// its newline when present.
+    pub end: usize,
+}
+
+/// Build the comparison key for a gem name.
+///
+/// Matches RuboCop's `case_insensitive_out_of_order?`: names are compared
+/// case-insensitively, and unless `ConsiderPunctuation` is set, `-` and `_`
+/// are dropped first (`gem_canonical_name`).
+pub fn sort_key(name: &str, consider_punctuation: bool) -> String {
+    let lower = name.to_lowercase();
+    if consider_punctuation {
+        lower
+    } else {
+        lower.replace(['-', '_'], "")
+    }
+}
+
+/// Compute the sorted replacement for an ordering window.
+///
+/// RuboCop's corrector swaps each out-of-order declaration (with its attached
+/// comments) with its predecessor and relies on the autocorrect loop to reach
+/// a fix point. Sorting the whole window with a stable sort produces the same
+/// final text in a single pass. Returns `None` when the window is already
+/// sorted.
+///
+/// Every chunk is normalized to end with a newline so a declaration on the
+/// last line of the file without a trailing newline can be moved safely; the
+/// window keeps its original trailing-newline state.
+pub fn sorted_window_replacement(
+    bytes: &[u8],
+    entries: &[OrderedEntry],
+) -> Option<(usize, usize, String)> {
+    if entries.len() < 2 || entries.windows(2).all(|w| w[0].sort_key <= w[1].sort_key) {
+        return None;
+    }
+
+    let start = entries.first()?.start;
+    let end = entries.last()?.end;
+
+    let mut indices: Vec<usize> = (0..entries.len()).collect();
+    indices.sort_by(|&a, &b| entries[a].sort_key.cmp(&entries[b].sort_key));
+
+    let mut replacement: Vec<u8> = Vec::with_capacity(end - start + 1);
+    for &i in &indices {
+        let chunk = &bytes[entries[i].start..entries[i].end];
+        replacement.extend_from_slice(chunk);
+        if !chunk.ends_with(b"\n") {
+            replacement.push(b'\n');
+        }
+    }
+    if !bytes[start..end].ends_with(b"\n") {
+        replacement.pop();
+    }
+
+    Some((
+        start,
+        end,
+        String::from_utf8_lossy(&replacement).into_owned(),
+    ))
+}
+
+/// Byte ranges `(start, end)` of every line in `source`, where `end` includes
+/// the line's newline when present.
+pub fn line_byte_ranges(source: &crate::parse::source::SourceFile) -> Vec<(usize, usize)> {
+    let bytes = source.as_bytes();
+    let mut ranges = Vec::new();
+    let mut offset = 0;
+    for line in source.lines() {
+        let start = offset;
+        offset += line.len();
+        if offset < bytes.len() && bytes[offset] == b'\n' {
+            offset += 1;
+        }
+        ranges.push((start, offset));
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: &str, start: usize, end: usize) -> OrderedEntry {
+        OrderedEntry {
+            sort_key: key.to_string(),
+            start,
+            end,
+        }
+    }
+
+    #[test]
+    fn sort_key_ignores_punctuation_by_default() {
+        assert_eq!(
+            sort_key("Rspec_Junit-Formatter", false),
+            "rspecjunitformatter"
+        );
+        assert_eq!(sort_key("rspec-rails", true), "rspec-rails");
+    }
+
+    #[test]
+    fn sorted_window_keeps_missing_trailing_newline() {
+        let src = b"gem 'b'\ngem 'a'";
+        let entries = [entry("b", 0, 8), entry("a", 8, 15)];
+        let (start, end, replacement) = sorted_window_replacement(src, &entries).unwrap();
+        assert_eq!((start, end), (0, 15));
+        assert_eq!(replacement, "gem 'a'\ngem 'b'");
+    }
+
+    #[test]
+    fn sorted_window_returns_none_when_sorted() {
+        let src = b"gem 'a'\ngem 'b'\n";
+        let entries = [entry("a", 0, 8), entry("b", 8, 16)];
+        assert!(sorted_window_replacement(src, &entries).is_none());
+    }
+}
@@ -0,0 +1,136 @@
+//! Shared alignment search for cops with an `AllowForAlignment` option,
+//! mirroring RuboCop's `PrecedingFollowingAlignment` mixin.
+//!
+//! Canonical source:
+//! `vendor/rubocop/lib/rubocop/cop/mixin/preceding_following_alignment.rb`
+//!
+//! Used by `Layout/ExtraSpacing` and `Layout/SpaceAroundOperators`. The
+//! search for the lines to compare against lives here; what counts as
+//! "aligned" on such a line differs per cop and is passed in as a predicate.
+
+/// Whether `predicate` holds for a line near `line_idx`, searched the way
+/// RuboCop's `aligned_with_something?` does:
+///
+/// 1. the nearest line above and below that is neither blank nor a
+///    comment-only line;
+/// 2. the nearest such lines above and below with the same indentation as
+///    the current line.
+///
+/// `predicate` receives the index of the candidate line in `lines`.
+pub fn aligned_with_adjacent_line(
+    lines: &[&[u8]],
+    line_idx: usize,
+    mut predicate: impl FnMut(usize) -> bool,
+) -> bool {
+    let indent = line_indentation(lines[line_idx]);
+    for required_indent in [None, Some(indent)] {
+        for going_up in [true, false] {
+            if let Some(adj) = nearest_line(lines, line_idx, going_up, required_indent) {
+                if predicate(adj) {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+/// The nearest line above or below `start_idx` that is neither blank nor a
+/// comment-only line, optionally restricted to lines with the given
+/// indentation.
+pub fn nearest_line(
+    lines: &[&[u8]],
+    start_idx: usize,
+    going_up: bool,
+    required_indent: Option<usize>,
+) -> Option<usize> {
+    let mut idx = start_idx;
+    loop {
+        if going_up {
+            idx = idx.checked_sub(1)?;
+        } else {
+            idx += 1;
+            if idx >= lines.len() {
+                return None;
+            }
+        }
+
+        let line = lines[idx];
+        match line
+            .iter()
+            .position(|&b| !matches!(b, b' ' | b'\t' | b'\r'))
+        {
+            None => continue,
+            Some(first) if line[first] == b'#' => continue,
+            Some(_) => {}
+        }
+        if required_indent.is_some_and(|indent| line_indentation(line) != indent) {
+            continue;
+        }
+        return Some(idx);
+    }
+}
+
+/// Number of leading spaces and tabs.
+pub fn line_indentation(line: &[u8]) -> usize {
+    line.iter()
+        .take_while(|&&b| b == b' ' || b == b'\t')
+        .count()
+}
+
+/// Convert a byte offset within `line` to a character column, so that
+/// multibyte characters count as one column when comparing lines.
+pub fn byte_to_char_col(line: &[u8], byte_col: usize) -> usize {
+    let end = byte_col.min(line.len());
+    line[..end].iter().filter(|&&b| b & 0xC0 != 0x80).count()
+}
+
+/// Convert a character column to a byte offset within `line`. Returns `None`
+/// if the line is shorter than `char_col` characters.
+pub fn char_col_to_byte(line: &[u8], char_col: usize) -> Option<usize> {
+    let mut chars = 0;
+    for (i, &b) in line.iter().enumerate() {
+        if b & 0xC0 == 0x80 {
+            continue;
+        }
+        if chars == char_col {
+            return Some(i);
+        }
+        chars += 1;
+    }
+    (chars == char_col).then_some(line.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_line_skips_blank_and_comment_lines() {
+        let lines: Vec<&[u8]> = vec![b"a = 1", b"", b"  # note", b"  b = 2", b"c  = 3"];
+        assert_eq!(nearest_line(&lines, 4, true, None), Some(3));
+        assert_eq!(nearest_line(&lines, 4, true, Some(0)), Some(0));
+        assert_eq!(nearest_line(&lines, 0, false, None), Some(3));
+        assert_eq!(nearest_line(&lines, 0, true, None), None);
+    }
+
+    #[test]
+    fn searches_nearest_then_same_indentation() {
+        let lines: Vec<&[u8]> = vec![b"x   = 1", b"  foo", b"yy  = 2"];
+        let mut seen = Vec::new();
+        aligned_with_adjacent_line(&lines, 2, |adj| {
+            seen.push(adj);
+            false
+        });
+        assert_eq!(seen, vec![1, 0]);
+    }
+
+    #[test]
+    fn char_columns_count_multibyte_characters_once() {
+        let line = "é = 1".as_bytes();
+        assert_eq!(byte_to_char_col(line, 3), 2);
+        assert_eq!(char_col_to_byte(line, 2), Some(3));
+        assert_eq!(char_col_to_byte(line, 5), Some(6));
+        assert_eq!(char_col_to_byte(line, 6), None);
+    }
+}
@@ -0,0 +1,80 @@
+//! RSpec DSL method names added through the `RSpec: Language:` department
+//! config, as rubocop-rspec's `Language` module reads them.
+//!
+//! Canonical source:
+//! `vendor/rubocop-rspec/lib/rubocop/rspec/language.rb`
+//!
+//! The built-in tables in `util` are rubocop-rspec's defaults. A suite that
+//! defines its own aliases (`config.alias_example_to :fcheck, focus: true`)
+//! lists them under the department, and the config loader passes that
+//! mapping to every RSpec cop as its `Language` option:
+//!
+//! ```yaml
+//! RSpec:
+//!   Language:
+//!     Examples:
+//!       Regular:
+//!         - check
+//!       Focused:
+//!         - fcheck
+//! ```
+//!
+//! Configured names extend the defaults; they never remove one.
+
+use serde_yml::Value;
+
+use crate::cop::CopConfig;
+
+/// The `Language` mapping of one cop's config, if any.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RspecLanguage<'a> {
+    language: Option<&'a Value>,
+}
+
+impl<'a> RspecLanguage<'a> {
+    pub fn from_config(config: &'a CopConfig) -> Self {
+        Self {
+            language: config.options.get("Language"),
+        }
+    }
+
+    /// Whether `name` is configured under `group` and `kind`, e.g.
+    /// `("Examples", "Focused")`.
+    pub fn includes(&self, group: &str, kind: &str, name: &[u8]) -> bool {
+        self.language
+            .and_then(|language| language.get(group))
+            .and_then(|group| group.get(kind))
+            .and_then(Value::as_sequence)
+            .is_some_and(|names| {
+                names
+                    .iter()
+                    .any(|n| n.as_str().is_some_and(|n| n.as_bytes() == name))
+            })
+    }
+
+    /// Whether `name` is configured under any of `kinds` of `group`.
+    pub fn includes_any(&self, group: &str, kinds: &[&str], name: &[u8]) -> bool {
+        kinds.iter().any(|kind| self.includes(group, kind, name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::cop_config_from_yaml;
+
+    #[test]
+    fn reads_configured_names() {
+        let config = cop_config_from_yaml(
+            "Language:\n  Examples:\n    Focused:\n      - fcheck\n    Regular: [check]\n",
+        );
+        let language = RspecLanguage::from_config(&config);
+        assert!(language.includes("Examples", "Focused", b"fcheck"));
+        assert!(!language.includes("Examples", "Focused", b"check"));
+        assert!(!language.includes("ExampleGroups", "Focused", b"fcheck"));
+        assert!(language.includes_any("Examples", &["Skipped", "Regular"], b"check"));
+
+        let unset = CopConfig::default();
+        assert!(!RspecLanguage::from_config(&unset).includes("Examples", "Focused", b"fit"));
+    }
+}
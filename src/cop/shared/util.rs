@@ -650,88 +650,21 @@ pub fn check_empty_lines_around_body_with_corrections(
     body_kind: &str,
     mut corrections: Option<&mut Vec<crate::correction::Correction>>,
 ) -> Vec<Diagnostic> {
-    let (keyword_line, _) = source.offset_to_line_col(keyword_offset);
-    let (end_line, _) = source.offset_to_line_col(end_offset);
-
-    if keyword_line == end_line {
-        return Vec::new();
-    }
-
-    let mut diagnostics = Vec::new();
-
-    // Check for blank line after keyword
-    let after_keyword = keyword_line + 1;
-    if let Some(line) = line_at(source, after_keyword) {
-        if is_blank_line(line) && after_keyword < end_line {
-            let mut diag = Diagnostic {
-                path: source.path_str().to_string(),
-                location: Location {
-                    line: after_keyword,
-                    column: 0,
-                },
-                severity: Severity::Convention,
-                cop_name: cop_name.to_string(),
-                message: format!("Extra empty line detected at {body_kind} body beginning."),
-                corrected: false,
-            };
-            if let Some(ref mut corr) = corrections {
-                if let (Some(start), Some(end)) = (
-                    source.line_col_to_offset(after_keyword, 0),
-                    source.line_col_to_offset(after_keyword + 1, 0),
-                ) {
-                    corr.push(crate::correction::Correction {
-                        start,
-                        end,
-                        replacement: String::new(),
-                        cop_name,
-                        cop_index: 0,
-                    });
-                    diag.corrected = true;
-                }
-            }
-            diagnostics.push(diag);
-        }
-    }
-
-    // Check for blank line before end
-    if end_line > 1 {
-        let before_end = end_line - 1;
-        if before_end > keyword_line {
-            if let Some(line) = line_at(source, before_end) {
-                if is_blank_line(line) {
-                    let mut diag = Diagnostic {
-                        path: source.path_str().to_string(),
-                        location: Location {
-                            line: before_end,
-                            column: 0,
-                        },
-                        severity: Severity::Convention,
-                        cop_name: cop_name.to_string(),
-                        message: format!("Extra empty line detected at {body_kind} body end."),
-                        corrected: false,
-                    };
-                    if let Some(ref mut corr) = corrections {
-                        if let (Some(start), Some(end)) = (
-                            source.line_col_to_offset(before_end, 0),
-                            source.line_col_to_offset(before_end + 1, 0),
-                        ) {
-                            corr.push(crate::correction::Correction {
-                                start,
-                                end,
-                                replacement: String::new(),
-                                cop_name,
-                                cop_index: 0,
-                            });
-                            diag.corrected = true;
-                        }
-                    }
-                    diagnostics.push(diag);
-                }
-            }
-        }
-    }
-
-    diagnostics
+    [BodyEdge::Beginning, BodyEdge::End]
+        .into_iter()
+        .filter_map(|edge| {
+            check_body_edge(
+                cop_name,
+                source,
+                keyword_offset,
+                end_offset,
+                body_kind,
+                edge,
+                false,
+                corrections.as_deref_mut(),
+            )
+        })
+        .collect()
 }
 
 /// Check for MISSING empty lines at the beginning/end of a body.
@@ -762,84 +695,117 @@ pub fn check_missing_empty_lines_around_body_with_corrections(
     body_kind: &str,
     mut corrections: Option<&mut Vec<crate::correction::Correction>>,
 ) -> Vec<Diagnostic> {
+    [BodyEdge::Beginning, BodyEdge::End]
+        .into_iter()
+        .filter_map(|edge| {
+            check_body_edge(
+                cop_name,
+                source,
+                keyword_offset,
+                end_offset,
+                body_kind,
+                edge,
+                true,
+                corrections.as_deref_mut(),
+            )
+        })
+        .collect()
+}
+
+/// Which edge of a body `check_body_edge` looks at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyEdge {
+    /// The line after the opening keyword.
+    Beginning,
+    /// The line before `end`.
+    End,
+}
+
+/// Check one edge of a body. With `require_empty` the edge line must be
+/// blank ("Empty line missing at ..."); without it, it must not be ("Extra
+/// empty line detected at ..."). Extra lines are corrected by deleting them,
+/// missing ones by inserting a newline.
+#[allow(clippy::too_many_arguments)]
+pub fn check_body_edge(
+    cop_name: &'static str,
+    source: &SourceFile,
+    keyword_offset: usize,
+    end_offset: usize,
+    body_kind: &str,
+    edge: BodyEdge,
+    require_empty: bool,
+    corrections: Option<&mut Vec<crate::correction::Correction>>,
+) -> Option<Diagnostic> {
     let (keyword_line, _) = source.offset_to_line_col(keyword_offset);
     let (end_line, _) = source.offset_to_line_col(end_offset);
 
-    // Skip single-line or empty bodies
-    if end_line <= keyword_line + 1 {
-        return Vec::new();
+    // Skip single-line bodies, and for missing lines also empty ones
+    if end_line <= keyword_line + usize::from(require_empty) {
+        return None;
     }
 
-    let mut diagnostics = Vec::new();
-
-    // Check for missing blank line after keyword
-    let after_keyword = keyword_line + 1;
-    if let Some(line) = line_at(source, after_keyword) {
-        if !is_blank_line(line) && after_keyword < end_line {
-            let mut diag = Diagnostic {
-                path: source.path_str().to_string(),
-                location: Location {
-                    line: after_keyword,
-                    column: 0,
-                },
-                severity: Severity::Convention,
-                cop_name: cop_name.to_string(),
-                message: format!("Empty line missing at {body_kind} body beginning."),
-                corrected: false,
-            };
-            if let Some(ref mut corr) = corrections {
-                if let Some(offset) = source.line_col_to_offset(after_keyword, 0) {
-                    corr.push(crate::correction::Correction {
-                        start: offset,
-                        end: offset,
-                        replacement: "\n".to_string(),
-                        cop_name,
-                        cop_index: 0,
-                    });
-                    diag.corrected = true;
-                }
-            }
-            diagnostics.push(diag);
-        }
+    let line_no = match edge {
+        BodyEdge::Beginning => keyword_line + 1,
+        BodyEdge::End => end_line - 1,
+    };
+    if line_no >= end_line || line_no <= keyword_line {
+        return None;
+    }
+    let line = line_at(source, line_no)?;
+    if is_blank_line(line) == require_empty {
+        return None;
     }
 
-    // Check for missing blank line before end
-    if end_line > 1 {
-        let before_end = end_line - 1;
-        if before_end > keyword_line {
-            if let Some(line) = line_at(source, before_end) {
-                if !is_blank_line(line) {
-                    let mut diag = Diagnostic {
-                        path: source.path_str().to_string(),
-                        location: Location {
-                            line: before_end,
-                            column: 0,
-                        },
-                        severity: Severity::Convention,
-                        cop_name: cop_name.to_string(),
-                        message: format!("Empty line missing at {body_kind} body end."),
-                        corrected: false,
-                    };
-                    if let Some(ref mut corr) = corrections {
-                        // Insert \n before the end line
-                        if let Some(offset) = source.line_col_to_offset(end_line, 0) {
-                            corr.push(crate::correction::Correction {
-                                start: offset,
-                                end: offset,
-                                replacement: "\n".to_string(),
-                                cop_name,
-                                cop_index: 0,
-                            });
-                            diag.corrected = true;
-                        }
-                    }
-                    diagnostics.push(diag);
-                }
-            }
+    let place = match edge {
+        BodyEdge::Beginning => "beginning",
+        BodyEdge::End => "end",
+    };
+    let message = if require_empty {
+        format!("Empty line missing at {body_kind} body {place}.")
+    } else {
+        format!("Extra empty line detected at {body_kind} body {place}.")
+    };
+    let mut diag = Diagnostic {
+        path: source.path_str().to_string(),
+        location: Location {
+            line: line_no,
+            column: 0,
+        },
+        severity: Severity::Convention,
+        cop_name: cop_name.to_string(),
+        message,
+        corrected: false,
+        end_column: None,
+    };
+
+    if let Some(corr) = corrections {
+        let correction = if require_empty {
+            // Insert the blank line before the edge line, or before `end`
+            let insert_line = match edge {
+                BodyEdge::Beginning => line_no,
+                BodyEdge::End => end_line,
+            };
+            source
+                .line_col_to_offset(insert_line, 0)
+                .map(|offset| (offset, offset, "\n"))
+        } else {
+            source
+                .line_col_to_offset(line_no, 0)
+                .zip(source.line_col_to_offset(line_no + 1, 0))
+                .map(|(start, end)| (start, end, ""))
+        };
+        if let Some((start, end, replacement)) = correction {
+            corr.push(crate::correction::Correction {
+                start,
+                end,
+                replacement: replacement.to_string(),
+                cop_name,
+                cop_index: 0,
+            });
+            diag.corrected = true;
         }
     }
-
-    diagnostics
+    Some(diag)
 }
 
 /// Check that `end` is aligned with the opening keyword.
@@ -882,6 +848,7 @@ pub fn check_keyword_end_alignment(
             message: format!("Align `end` with `{keyword_name}`."),
 
             corrected: false,
+            end_column: None,
         }];
     }
 
@@ -905,6 +872,48 @@ pub fn begins_its_line(source: &SourceFile, offset: usize) -> bool {
     true
 }
 
+/// Whether the code at `start..end` is the only code on its lines, so that
+/// deleting the lines removes nothing else. A trailing comment goes with it.
+pub fn stands_alone(source: &SourceFile, start: usize, end: usize) -> bool {
+    let bytes = source.as_bytes();
+    let line_end = bytes[end..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |i| end + i);
+    let rest = bytes[end..line_end].trim_ascii();
+    begins_its_line(source, start) && (rest.is_empty() || rest.starts_with(b"#"))
+}
+
+/// Whether the node at `start..end` is a statement of a body rather than an
+/// argument, receiver or assigned value.
+pub fn is_statement(parse_result: &ruby_prism::ParseResult<'_>, start: usize, end: usize) -> bool {
+    use ruby_prism::Visit;
+    struct Finder {
+        start: usize,
+        end: usize,
+        found: bool,
+    }
+    impl<'pr> Visit<'pr> for Finder {
+        fn visit_statements_node(&mut self, node: &ruby_prism::StatementsNode<'pr>) {
+            let (start, end) = (self.start, self.end);
+            self.found |= node.body().iter().any(|stmt| {
+                let loc = stmt.location();
+                loc.start_offset() == start && loc.end_offset() == end
+            });
+            if !self.found {
+                ruby_prism::visit_statements_node(self, node);
+            }
+        }
+    }
+    let mut finder = Finder {
+        start,
+        end,
+        found: false,
+    };
+    finder.visit(&parse_result.node());
+    finder.found
+}
+
 /// Check first element indentation relative to an opening delimiter.
 /// Used by FirstArgument/Array/HashElementIndentation.
 pub fn check_first_element_indentation(
@@ -941,6 +950,7 @@ pub fn check_first_element_indentation(
                 elem_col.saturating_sub(open_indent)
             ),
             corrected: false,
+            end_column: None,
         }];
     }
 
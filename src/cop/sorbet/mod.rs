@@ -0,0 +1,282 @@
+pub mod false_sigil;
+pub mod true_sigil;
+pub mod valid_sigil;
+
+use std::sync::LazyLock;
+
+use regex::bytes::Regex;
+
+use super::registry::CopRegistry;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::Diagnostic;
+use crate::parse::source::SourceFile;
+
+/// Default Include patterns for the sigil cops (from rubocop-sorbet's
+/// `config/default.yml`).
+pub const SIGIL_DEFAULT_INCLUDE: &[&str] = &["**/*.rb", "**/*.rbi", "**/*.rake", "**/*.ru"];
+
+/// Default Exclude patterns for the sigil cops.
+pub const SIGIL_DEFAULT_EXCLUDE: &[&str] = &["bin/**/*", "db/**/*.rb", "script/**/*"];
+
+/// Sorbet strictness levels, weakest first.
+pub const STRICTNESS_LEVELS: &[&str] = &["ignore", "false", "true", "strict", "strong"];
+
+/// rubocop-sorbet's `ValidSigil::SIGIL_REGEX`; the capture is the strictness.
+static SIGIL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[[:blank:]]*(?:#[[:blank:]]*)?# typed:(?:[[:blank:]]+(\S+))?").unwrap()
+});
+
+static DOUBLE_COMMENT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[[:blank:]]*#[[:blank:]]*#").unwrap());
+
+/// Options shared by `Sorbet/ValidSigil` and its `*Sigil` subclasses.
+pub struct SigilOptions {
+    pub require_sigil_on_all_files: bool,
+    pub suggested_strictness: String,
+    pub minimum_strictness: Option<String>,
+    pub exact_strictness: Option<String>,
+}
+
+impl SigilOptions {
+    /// Reads `ExactStrictness` from config; the other options are up to the
+    /// calling cop.
+    pub fn new(
+        config: &CopConfig,
+        require_sigil_on_all_files: bool,
+        suggested_default: &str,
+        minimum_strictness: Option<String>,
+    ) -> Self {
+        Self {
+            require_sigil_on_all_files,
+            suggested_strictness: strictness_option(config, "SuggestedStrictness")
+                .unwrap_or_else(|| suggested_default.to_string()),
+            minimum_strictness,
+            exact_strictness: strictness_option(config, "ExactStrictness"),
+        }
+    }
+
+    /// The level suggested for missing sigils and used when upgrading one.
+    fn suggested_level(&self) -> &str {
+        if let Some(minimum) = &self.minimum_strictness {
+            return minimum;
+        }
+        if let Some(exact) = &self.exact_strictness {
+            return exact;
+        }
+        if STRICTNESS_LEVELS.contains(&self.suggested_strictness.as_str()) {
+            &self.suggested_strictness
+        } else {
+            "false"
+        }
+    }
+}
+
+/// A strictness option. Unquoted `true`/`false` in YAML are booleans, so those
+/// are accepted too.
+pub fn strictness_option(config: &CopConfig, key: &str) -> Option<String> {
+    match config.options.get(key)? {
+        serde_yml::Value::String(s) if !s.is_empty() => Some(s.clone()),
+        serde_yml::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn strictness_level(strictness: &str) -> Option<usize> {
+    STRICTNESS_LEVELS.iter().position(|&l| l == strictness)
+}
+
+fn first_non_blank(line: &[u8]) -> Option<usize> {
+    line.iter()
+        .position(|&b| !matches!(b, b' ' | b'\t' | b'\r'))
+}
+
+fn is_encoding_comment(line: &[u8]) -> bool {
+    let lower = line.to_ascii_lowercase();
+    let Some(rest) = lower.trim_ascii_start().strip_prefix(b"#") else {
+        return false;
+    };
+    let rest = rest.trim_ascii_start();
+    rest.starts_with(b"encoding:")
+        || rest.starts_with(b"coding:")
+        || (rest.starts_with(b"-*-") && lower.windows(6).any(|w| w == b"coding"))
+}
+
+/// Line index (0-based) where a new sigil goes: after a shebang and an
+/// encoding comment, which Ruby only honours on the first two lines.
+fn sigil_insert_line(lines: &[&[u8]]) -> usize {
+    let mut idx = 0;
+    if lines.first().is_some_and(|l| l.starts_with(b"#!")) {
+        idx += 1;
+    }
+    if lines.get(idx).is_some_and(|l| is_encoding_comment(l)) {
+        idx += 1;
+    }
+    idx
+}
+
+/// Shared implementation of rubocop-sorbet's `ValidSigil` investigation.
+///
+/// The sigil is the first leading comment (before any code) matching
+/// `# typed:`. Beyond upstream, autocorrect also upgrades a sigil that is
+/// below `MinimumStrictness` or differs from `ExactStrictness`.
+pub fn check_sigil(
+    cop: &dyn Cop,
+    source: &SourceFile,
+    options: &SigilOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+    mut corrections: Option<&mut Vec<crate::correction::Correction>>,
+) {
+    let lines: Vec<&[u8]> = source.lines().collect();
+    // RuboCop skips files without tokens.
+    let Some((first_idx, first_col)) = lines
+        .iter()
+        .enumerate()
+        .find_map(|(i, line)| first_non_blank(line).map(|col| (i, col)))
+    else {
+        return;
+    };
+
+    let sigil = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| first_non_blank(line).map(|col| (i, col, *line)))
+        .take_while(|(_, col, line)| line[*col] == b'#')
+        .find_map(|(i, col, line)| SIGIL_RE.captures(line).map(|caps| (i, col, line, caps)));
+
+    let Some((idx, col, line, caps)) = sigil else {
+        if !options.require_sigil_on_all_files {
+            return;
+        }
+        let level = options.suggested_level();
+        let mut diag = cop.diagnostic(
+            source,
+            first_idx + 1,
+            first_col,
+            format!(
+                "No Sorbet sigil found in file. Try a `typed: {level}` to start \
+                 (you can also use `rubocop -a` to automatically add this)."
+            ),
+        );
+        if let Some(corr) = corrections {
+            let insert_line = sigil_insert_line(&lines);
+            let bytes = source.as_bytes();
+            let (offset, replacement) = match source.line_col_to_offset(insert_line + 1, 0) {
+                Some(offset) if insert_line < lines.len() => {
+                    (offset, format!("# typed: {level}\n"))
+                }
+                _ if bytes.ends_with(b"\n") => (bytes.len(), format!("# typed: {level}\n")),
+                _ => (bytes.len(), format!("\n# typed: {level}")),
+            };
+            corr.push(crate::correction::Correction {
+                start: offset,
+                end: offset,
+                replacement,
+                cop_name: cop.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
+        return;
+    };
+
+    let line_start = source.line_start_offset(idx + 1);
+    let strictness = caps.get(1).map(|m| {
+        (
+            String::from_utf8_lossy(m.as_bytes()).into_owned(),
+            m.range(),
+        )
+    });
+
+    // The level the sigil should be upgraded to, if any.
+    let expected = strictness.as_ref().and_then(|(s, _)| {
+        let current = strictness_level(s)?;
+        if let Some(exact) = &options.exact_strictness {
+            let exact_level = strictness_level(exact)?;
+            return (current != exact_level).then(|| {
+                (
+                    exact.clone(),
+                    format!("Sorbet sigil should be `{exact}` but got `{s}`."),
+                )
+            });
+        }
+        let minimum = options.minimum_strictness.as_ref()?;
+        let minimum_level = strictness_level(minimum)?;
+        (current < minimum_level).then(|| {
+            (
+                minimum.clone(),
+                format!("Sorbet sigil should be at least `{minimum}` got `{s}`."),
+            )
+        })
+    });
+
+    let double_commented = DOUBLE_COMMENT_RE.is_match(line);
+    if double_commented {
+        let fixed = expected
+            .as_ref()
+            .map(|(level, _)| level.as_str())
+            .or(strictness.as_ref().map(|(s, _)| s.as_str()))
+            .unwrap_or("");
+        let mut diag = cop.diagnostic(
+            source,
+            idx + 1,
+            col,
+            "Sorbet sigil should not be double commented.".to_string(),
+        );
+        if let Some(ref mut corr) = corrections {
+            let content_end = line.trim_ascii_end().len();
+            corr.push(crate::correction::Correction {
+                start: line_start + col,
+                end: line_start + content_end,
+                replacement: format!("# typed: {fixed}"),
+                cop_name: cop.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
+    }
+
+    let Some((strictness, range)) = strictness else {
+        diagnostics.push(cop.diagnostic(
+            source,
+            idx + 1,
+            col,
+            "Sorbet sigil should not be empty.".to_string(),
+        ));
+        return;
+    };
+    if strictness_level(&strictness).is_none() {
+        diagnostics.push(cop.diagnostic(
+            source,
+            idx + 1,
+            col,
+            format!("Invalid Sorbet sigil `{strictness}`."),
+        ));
+        return;
+    }
+    let Some((level, message)) = expected else {
+        return;
+    };
+    let mut diag = cop.diagnostic(source, idx + 1, col, message);
+    if let Some(corr) = corrections {
+        // A double-commented sigil is rewritten as a whole above.
+        if !double_commented {
+            corr.push(crate::correction::Correction {
+                start: line_start + range.start,
+                end: line_start + range.end,
+                replacement: level,
+                cop_name: cop.name(),
+                cop_index: 0,
+            });
+        }
+        diag.corrected = true;
+    }
+    diagnostics.push(diag);
+}
+
+pub fn register_all(registry: &mut CopRegistry) {
+    registry.register(Box::new(false_sigil::FalseSigil));
+    registry.register(Box::new(true_sigil::TrueSigil));
+    registry.register(Box::new(valid_sigil::ValidSigil));
+}
@@ -0,0 +1,80 @@
+use crate::cop::sorbet::{SIGIL_DEFAULT_EXCLUDE, SIGIL_DEFAULT_INCLUDE, SigilOptions, check_sigil};
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::Diagnostic;
+use crate::parse::source::SourceFile;
+
+/// Sorbet/TrueSigil
+///
+/// `Sorbet/ValidSigil` with a sigil required on every file and a minimum
+/// strictness of `true`. Disabled by default upstream.
+pub struct TrueSigil;
+
+impl Cop for TrueSigil {
+    fn name(&self) -> &'static str {
+        "Sorbet/TrueSigil"
+    }
+
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        SIGIL_DEFAULT_INCLUDE
+    }
+
+    fn default_exclude(&self) -> &'static [&'static str] {
+        SIGIL_DEFAULT_EXCLUDE
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn check_lines(
+        &self,
+        source: &SourceFile,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let options = SigilOptions::new(config, true, "true", Some("true".to_string()));
+        check_sigil(self, source, &options, diagnostics, corrections);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::cop_scenario_fixture_tests!(
+        TrueSigil,
+        "cops/sorbet/true_sigil",
+        missing_sigil = "missing_sigil.rb",
+        shebang_missing_sigil = "shebang_missing_sigil.rb",
+        weaker_sigil = "weaker_sigil.rb",
+    );
+
+    #[test]
+    fn autocorrect_inserts_sigil_after_shebang() {
+        let input = b"#!/usr/bin/env ruby\n# frozen_string_literal: true\nputs 'hello'\n";
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect(&TrueSigil, input);
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].corrected);
+        let corrected = crate::correction::CorrectionSet::from_vec(corrections).apply(input);
+        assert_eq!(
+            corrected,
+            b"#!/usr/bin/env ruby\n# typed: true\n# frozen_string_literal: true\nputs 'hello'\n"
+        );
+    }
+
+    #[test]
+    fn autocorrect_upgrades_weaker_sigil() {
+        let input = b"# frozen_string_literal: true\n# typed: false\nputs 'hello'\n";
+        let (_diags, corrections) = crate::testutil::run_cop_autocorrect(&TrueSigil, input);
+        let corrected = crate::correction::CorrectionSet::from_vec(corrections).apply(input);
+        assert_eq!(
+            corrected,
+            b"# frozen_string_literal: true\n# typed: true\nputs 'hello'\n"
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::cop::sorbet::{
+    SIGIL_DEFAULT_EXCLUDE, SIGIL_DEFAULT_INCLUDE, SigilOptions, check_sigil, strictness_option,
+};
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::Diagnostic;
+use crate::parse::source::SourceFile;
+
+/// Sorbet/ValidSigil
+///
+/// Checks that the `# typed:` sigil, if present, is well formed and names a
+/// known strictness level. With `RequireSigilOnAllFiles`, files without a sigil
+/// are flagged too, and autocorrect inserts `# typed: <SuggestedStrictness>`
+/// after any shebang and encoding comment. `MinimumStrictness` and
+/// `ExactStrictness` constrain the level; autocorrect upgrades the sigil.
+pub struct ValidSigil;
+
+impl Cop for ValidSigil {
+    fn name(&self) -> &'static str {
+        "Sorbet/ValidSigil"
+    }
+
+    fn default_include(&self) -> &'static [&'static str] {
+        SIGIL_DEFAULT_INCLUDE
+    }
+
+    fn default_exclude(&self) -> &'static [&'static str] {
+        SIGIL_DEFAULT_EXCLUDE
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn check_lines(
+        &self,
+        source: &SourceFile,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let options = SigilOptions::new(
+            config,
+            config.get_bool("RequireSigilOnAllFiles", false),
+            "false",
+            strictness_option(config, "MinimumStrictness"),
+        );
+        check_sigil(self, source, &options, diagnostics, corrections);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    crate::cop_scenario_fixture_tests!(
+        ValidSigil,
+        "cops/sorbet/valid_sigil",
+        invalid_strictness = "invalid_strictness.rb",
+        empty_strictness = "empty_strictness.rb",
+        double_commented = "double_commented.rb",
+    );
+
+    fn config(options: &[(&str, serde_yml::Value)]) -> CopConfig {
+        CopConfig {
+            options: options
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect::<HashMap<_, _>>(),
+            ..CopConfig::default()
+        }
+    }
+
+    fn autocorrect(input: &[u8], config: CopConfig) -> Vec<u8> {
+        let (_diags, corrections) =
+            crate::testutil::run_cop_autocorrect_with_config(&ValidSigil, input, config);
+        crate::correction::CorrectionSet::from_vec(corrections).apply(input)
+    }
+
+    fn require_all() -> CopConfig {
+        config(&[("RequireSigilOnAllFiles", serde_yml::Value::Bool(true))])
+    }
+
+    #[test]
+    fn missing_sigil_allowed_by_default() {
+        crate::testutil::assert_cop_no_offenses_full(&ValidSigil, b"puts 'hello'\n");
+    }
+
+    #[test]
+    fn missing_sigil_flagged_when_required() {
+        let diags = crate::testutil::run_cop_full_with_config(
+            &ValidSigil,
+            b"# frozen_string_literal: true\n\nputs 'hello'\n",
+            require_all(),
+        );
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].location.line, 1);
+        assert_eq!(
+            diags[0].message,
+            "No Sorbet sigil found in file. Try a `typed: false` to start \
+             (you can also use `rubocop -a` to automatically add this)."
+        );
+    }
+
+    #[test]
+    fn autocorrect_inserts_before_frozen_string_literal() {
+        assert_eq!(
+            autocorrect(
+                b"# frozen_string_literal: true\n\nputs 'hello'\n",
+                require_all()
+            ),
+            b"# typed: false\n# frozen_string_literal: true\n\nputs 'hello'\n"
+        );
+    }
+
+    #[test]
+    fn autocorrect_inserts_after_shebang_and_encoding() {
+        assert_eq!(
+            autocorrect(b"#!/usr/bin/env ruby\nputs 'hello'\n", require_all()),
+            b"#!/usr/bin/env ruby\n# typed: false\nputs 'hello'\n"
+        );
+        assert_eq!(
+            autocorrect(
+                b"#!/usr/bin/env ruby\n# encoding: utf-8\n# frozen_string_literal: true\nputs 'hello'\n",
+                require_all()
+            ),
+            b"#!/usr/bin/env ruby\n# encoding: utf-8\n# typed: false\n# frozen_string_literal: true\nputs 'hello'\n"
+        );
+    }
+
+    #[test]
+    fn autocorrect_uses_suggested_strictness() {
+        let config = config(&[
+            ("RequireSigilOnAllFiles", serde_yml::Value::Bool(true)),
+            (
+                "SuggestedStrictness",
+                serde_yml::Value::String("strict".into()),
+            ),
+        ]);
+        assert_eq!(
+            autocorrect(b"puts 'hello'\n", config),
+            b"# typed: strict\nputs 'hello'\n"
+        );
+    }
+
+    #[test]
+    fn minimum_strictness_upgrades_sigil() {
+        let config = config(&[("MinimumStrictness", serde_yml::Value::String("true".into()))]);
+        let input = b"# frozen_string_literal: true\n# typed: false\nputs 'hello'\n";
+        let diags = crate::testutil::run_cop_full_with_config(&ValidSigil, input, config.clone());
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].location.line, 2);
+        assert_eq!(
+            diags[0].message,
+            "Sorbet sigil should be at least `true` got `false`."
+        );
+        assert_eq!(
+            autocorrect(input, config.clone()),
+            b"# frozen_string_literal: true\n# typed: true\nputs 'hello'\n"
+        );
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &ValidSigil,
+            b"# typed: strict\nputs 'hello'\n",
+            config,
+        );
+    }
+
+    #[test]
+    fn exact_strictness_accepts_unquoted_yaml_boolean() {
+        let config = config(&[("ExactStrictness", serde_yml::Value::Bool(true))]);
+        let input = b"# typed: strict\nputs 'hello'\n";
+        let diags = crate::testutil::run_cop_full_with_config(&ValidSigil, input, config.clone());
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "Sorbet sigil should be `true` but got `strict`."
+        );
+        assert_eq!(autocorrect(input, config), b"# typed: true\nputs 'hello'\n");
+    }
+
+    #[test]
+    fn double_commented_sigil_is_rewritten() {
+        assert_eq!(
+            autocorrect(b"# # typed: true\nputs 'hello'\n", CopConfig::default()),
+            b"# typed: true\nputs 'hello'\n"
+        );
+    }
+}
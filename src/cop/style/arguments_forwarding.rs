@@ -101,14 +101,10 @@ impl Cop for ArgumentsForwarding {
             .get_string_array("RedundantBlockArgumentNames")
             .unwrap_or_else(|| vec!["blk".to_string(), "block".to_string(), "proc".to_string()]);
 
-        let ruby_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-            .unwrap_or(3.4);
-        if ruby_version < 2.7 {
+        if !config.ruby_version_at_least(2.7) {
             return;
         }
+        let ruby_version = config.target_ruby_version();
 
         let def_node = match node.as_def_node() {
             Some(d) => d,
@@ -1137,13 +1133,39 @@ impl<'pr> Visit<'pr> for NonForwardingRefFinder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    crate::cop_fixture_tests!(ArgumentsForwarding, "cops/style/arguments_forwarding");
+
+    fn ruby_config(version: f64) -> CopConfig {
+        let mut config = CopConfig::default();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::Number::from(version)),
+        );
+        config
+    }
+
+    #[test]
+    fn offense_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &ArgumentsForwarding,
+            include_bytes!("../../../tests/fixtures/cops/style/arguments_forwarding/offense.rb"),
+            ruby_config(3.4),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &ArgumentsForwarding,
+            include_bytes!("../../../tests/fixtures/cops/style/arguments_forwarding/no_offense.rb"),
+            ruby_config(3.4),
+        );
+    }
 
     #[test]
     fn detects_triple_forwarding() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         let source = b"def foo(*args, **opts, &block)\n  bar(*args, **opts, &block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1154,10 +1176,10 @@ mod tests {
 
     #[test]
     fn detects_super_forwarding() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // Ruby 3.2+ with *args, &block (no **kwrest) → anonymous * and &
         let source = b"def foo(*args, &block)\n  super(*args, &block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             4,
@@ -1168,10 +1190,10 @@ mod tests {
 
     #[test]
     fn no_false_positive_different_calls_non_redundant_names() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // *items and &handler are not redundant names — cannot suggest anonymous forwarding
         let source = b"def foo(*items, &handler)\n  bar(*items)\n  baz(&handler)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             0,
@@ -1182,10 +1204,10 @@ mod tests {
 
     #[test]
     fn detects_self_class_method_forwarding() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // Ruby 3.2+ with *args, &block (no **kwrest) → anonymous * and &
         let source = b"def self.foo(*args, &block)\n  bar(*args, &block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             4,
@@ -1196,9 +1218,9 @@ mod tests {
 
     #[test]
     fn detects_forwarding_without_kwargs() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         let source = b"def foo(*args, **options, &block)\n  bar(*args, **options, &block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1209,11 +1231,11 @@ mod tests {
 
     #[test]
     fn args_referenced_but_block_still_flagged() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // args is used as a local variable (args.first), so can't use ... or *
         // But &block is NOT referenced, so &block -> & is still flagged (Ruby 3.2+)
         let source = b"def foo(*args, &block)\n  bar(*args, &block)\n  args.first\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1225,11 +1247,11 @@ mod tests {
 
     #[test]
     fn block_referenced_but_args_still_flagged() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // block is called directly, so can't use ... or &
         // But *args is NOT referenced, so *args -> * is still flagged (Ruby 3.2+)
         let source = b"def foo(*args, &block)\n  bar(*args, &block)\n  block.call\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1241,9 +1263,9 @@ mod tests {
 
     #[test]
     fn detects_super_with_triple_forwarding() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         let source = b"def foo(*args, **opts, &block)\n  super(*args, **opts, &block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1254,10 +1276,10 @@ mod tests {
 
     #[test]
     fn detects_anonymous_block_forwarding() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // &block forwarding only — should suggest &
         let source = b"def foo(&block)\n  bar(&block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1273,9 +1295,9 @@ mod tests {
 
     #[test]
     fn detects_anonymous_block_with_extra_positional() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         let source = b"def foo(name, &block)\n  run(name, &block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1286,10 +1308,10 @@ mod tests {
 
     #[test]
     fn detects_anonymous_with_leading_call_args() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // def post(*args, &block) with extra args in call site — Ruby 3.2+ uses * and &
         let source = b"def post(*args, &block)\n  future_on(executor, *args, &block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             4,
@@ -1305,11 +1327,11 @@ mod tests {
 
     #[test]
     fn detects_forward_all_with_leading_call_args_triple() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // def post(*args, **opts, &block) — has both rest+kwrest → ... forwarding
         let source =
             b"def post(*args, **opts, &block)\n  future_on(executor, *args, **opts, &block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1325,9 +1347,9 @@ mod tests {
 
     #[test]
     fn detects_forward_all_with_leading_def_and_call_args() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         let source = b"def method_missing(m, *args, **kwargs, &block)\n  @template.send(m, *args, **kwargs, &block)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1338,11 +1360,11 @@ mod tests {
 
     #[test]
     fn non_redundant_block_name_only_flags_rest() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // &task is not in RedundantBlockArgumentNames, so no & or ... suggestion
         // But *args IS redundant, so *args -> * is flagged (Ruby 3.2+)
         let source = b"def post(*args, &task)\n  @executor&.post(*args, &task)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1354,10 +1376,10 @@ mod tests {
 
     #[test]
     fn no_false_positive_all_non_redundant_names() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // Neither *items nor &handler are in redundant lists
         let source = b"def post(*items, &handler)\n  @executor&.post(*items, &handler)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             0,
@@ -1368,9 +1390,9 @@ mod tests {
 
     #[test]
     fn detects_yield_rest_forwarding() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         let source = b"def foo(*args)\n  yield(*args)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1382,9 +1404,9 @@ mod tests {
 
     #[test]
     fn detects_yield_kwrest_forwarding() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         let source = b"def foo(**kwargs)\n  yield(**kwargs)\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             2,
@@ -1396,20 +1418,10 @@ mod tests {
 
     #[test]
     fn no_anonymous_forwarding_inside_block_ruby33() {
-        use crate::cop::CopConfig;
         use crate::testutil::run_cop_full_with_config;
         // Ruby 3.3: anonymous block forwarding inside a block is a syntax error
-        let mut options = std::collections::HashMap::new();
-        options.insert(
-            "TargetRubyVersion".to_string(),
-            serde_yml::Value::Number(serde_yml::Number::from(3.3_f64)),
-        );
-        let config = CopConfig {
-            options,
-            ..CopConfig::default()
-        };
         let source = b"def foo(*args, &block)\n  wrapper do\n    bar(*args, &block)\n  end\nend\n";
-        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, config);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.3));
         assert_eq!(
             diags.len(),
             0,
@@ -1420,20 +1432,10 @@ mod tests {
 
     #[test]
     fn anonymous_forwarding_inside_block_ruby34() {
-        use crate::cop::CopConfig;
         use crate::testutil::run_cop_full_with_config;
         // Ruby 3.4: anonymous forwarding inside blocks is fixed
-        let mut options = std::collections::HashMap::new();
-        options.insert(
-            "TargetRubyVersion".to_string(),
-            serde_yml::Value::Number(serde_yml::Number::from(3.4_f64)),
-        );
-        let config = CopConfig {
-            options,
-            ..CopConfig::default()
-        };
         let source = b"def foo(*args, &block)\n  wrapper do\n    bar(*args, &block)\n  end\nend\n";
-        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, config);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             4,
@@ -1444,11 +1446,11 @@ mod tests {
 
     #[test]
     fn no_anonymous_to_dots_forwarding_inside_block() {
-        use crate::testutil::run_cop_full;
+        use crate::testutil::run_cop_full_with_config;
         // def with anonymous (*, **, &) inside a block (e.g. RSpec.describe do...end)
         // should NOT suggest ... because RuboCop suppresses this via block ancestor check
         let source = b"RSpec.describe do\n  def render_component(*, **, &)\n    render(described_class.new(*, **, &))\n  end\nend\n";
-        let diags = run_cop_full(&ArgumentsForwarding, source);
+        let diags = run_cop_full_with_config(&ArgumentsForwarding, source, ruby_config(3.4));
         assert_eq!(
             diags.len(),
             0,
@@ -191,14 +191,10 @@ impl Cop for ArrayIntersect {
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // intersect? requires Ruby >= 3.1
-        let ruby_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-            .unwrap_or(3.4);
-        if ruby_version < 3.1 {
+        if !config.ruby_version_at_least(3.1) {
             return;
         }
+        let ruby_version = config.target_ruby_version();
 
         let call = match node.as_call_node() {
             Some(c) => c,
@@ -413,18 +409,46 @@ impl Cop for ArrayIntersect {
 #[cfg(test)]
 mod tests {
     use super::*;
-    crate::cop_fixture_tests!(ArrayIntersect, "cops/style/array_intersect");
+
+    fn ruby34_config() -> CopConfig {
+        let mut config = CopConfig::default();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::Number::from(3.4)),
+        );
+        config
+    }
+
+    fn active_support_config() -> CopConfig {
+        let mut config = ruby34_config();
+        config.options.insert(
+            "ActiveSupportExtensionsEnabled".to_string(),
+            serde_yml::Value::Bool(true),
+        );
+        config
+    }
+
+    #[test]
+    fn offense_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &ArrayIntersect,
+            include_bytes!("../../../tests/fixtures/cops/style/array_intersect/offense.rb"),
+            ruby34_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &ArrayIntersect,
+            include_bytes!("../../../tests/fixtures/cops/style/array_intersect/no_offense.rb"),
+            ruby34_config(),
+        );
+    }
 
     #[test]
     fn present_with_active_support() {
-        let config = {
-            let mut c = CopConfig::default();
-            c.options.insert(
-                "ActiveSupportExtensionsEnabled".to_string(),
-                serde_yml::Value::Bool(true),
-            );
-            c
-        };
+        let config = active_support_config();
         let diags = crate::testutil::run_cop_full_with_config(
             &ArrayIntersect,
             b"(a & b).present?\n",
@@ -439,14 +463,7 @@ mod tests {
 
     #[test]
     fn blank_with_active_support() {
-        let config = {
-            let mut c = CopConfig::default();
-            c.options.insert(
-                "ActiveSupportExtensionsEnabled".to_string(),
-                serde_yml::Value::Bool(true),
-            );
-            c
-        };
+        let config = active_support_config();
         let diags =
             crate::testutil::run_cop_full_with_config(&ArrayIntersect, b"(a & b).blank?\n", config);
         assert_eq!(diags.len(), 1);
@@ -458,20 +475,17 @@ mod tests {
 
     #[test]
     fn present_without_active_support_is_ok() {
-        let diags = crate::testutil::run_cop_full(&ArrayIntersect, b"(a & b).present?\n");
+        let diags = crate::testutil::run_cop_full_with_config(
+            &ArrayIntersect,
+            b"(a & b).present?\n",
+            ruby34_config(),
+        );
         assert_eq!(diags.len(), 0);
     }
 
     #[test]
     fn intersection_present_with_active_support() {
-        let config = {
-            let mut c = CopConfig::default();
-            c.options.insert(
-                "ActiveSupportExtensionsEnabled".to_string(),
-                serde_yml::Value::Bool(true),
-            );
-            c
-        };
+        let config = active_support_config();
         let diags = crate::testutil::run_cop_full_with_config(
             &ArrayIntersect,
             b"a.intersection(b).present?\n",
@@ -486,14 +500,7 @@ mod tests {
 
     #[test]
     fn nested_present_with_active_support() {
-        let config = {
-            let mut c = CopConfig::default();
-            c.options.insert(
-                "ActiveSupportExtensionsEnabled".to_string(),
-                serde_yml::Value::Bool(true),
-            );
-            c
-        };
+        let config = active_support_config();
         let diags = crate::testutil::run_cop_full_with_config(
             &ArrayIntersect,
             b"(cost_keys.to_set & report_cols).present?\n",
@@ -508,9 +515,10 @@ mod tests {
 
     #[test]
     fn any_block_member_detection() {
-        let diags = crate::testutil::run_cop_full(
+        let diags = crate::testutil::run_cop_full_with_config(
             &ArrayIntersect,
             b"array1.any? { |e| array2.member?(e) }\n",
+            ruby34_config(),
         );
         assert_eq!(diags.len(), 1);
         assert_eq!(
@@ -521,9 +529,10 @@ mod tests {
 
     #[test]
     fn none_block_member_detection_with_numbered_params() {
-        let diags = crate::testutil::run_cop_full(
+        let diags = crate::testutil::run_cop_full_with_config(
             &ArrayIntersect,
             b"array1.none? { array2.member?(_1) }\n",
+            ruby34_config(),
         );
         assert_eq!(diags.len(), 1);
         assert_eq!(
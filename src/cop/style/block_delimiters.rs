@@ -1,4 +1,10 @@
+use crate::cop::shared::node_type::{
+    AND_NODE, ARRAY_NODE, IF_NODE, OR_NODE, PARENTHESES_NODE, RANGE_NODE, UNLESS_NODE,
+    node_type_tag,
+};
+use crate::cop::shared::node_type_groups::{is_assignment_type, is_call_type};
 use crate::cop::{CodeMap, Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 use ruby_prism::Visit;
@@ -100,13 +106,62 @@ use std::collections::HashSet;
 /// `ignore_node`. In Prism, all blocks are `BlockNode` regardless of parameter style.
 /// Fix: added `is_explicit_block()` check that returns false when the block's parameters
 /// are `ItParametersNode` or `NumberedParametersNode`, matching Parser's type distinction.
+///
+/// ## Styles and autocorrect
+///
+/// Besides `line_count_based`, `semantic` (braces for functional blocks, i.e.
+/// blocks whose value is used, `do...end` for procedural ones),
+/// `braces_for_chaining` (braces only for multi-line blocks that are chained
+/// onto) and `always_braces` are supported. The semantic and chaining checks
+/// need the block's Parser-style parent, which is reconstructed from an
+/// ancestor stack (`parents`) plus the statement positions recorded in
+/// `visit_statements_node`.
+///
+/// Autocorrect swaps the delimiters. `do...end` is not converted to braces
+/// when the call has unparenthesized arguments (`foo bar do ... end` would
+/// rebind the block to `bar`) or when a multi-line body has `rescue`/`ensure`,
+/// and braces are left alone when a comment follows the closing `}`.
 pub struct BlockDelimiters;
 
+const SINGLE_LINE_MSG: &str = "Prefer `{...}` over `do...end` for single-line blocks.";
+const MULTI_LINE_MSG: &str = "Prefer `do...end` over `{...}` for multi-line blocks.";
+const PROCEDURAL_MSG: &str = "Prefer `do...end` over `{...}` for procedural blocks.";
+const FUNCTIONAL_MSG: &str = "Prefer `{...}` over `do...end` for functional blocks.";
+const CHAINED_MSG: &str = "Prefer `{...}` over `do...end` for multi-line chained blocks.";
+const NOT_CHAINED_MSG: &str = "Prefer `do...end` for multi-line blocks without chaining.";
+const ALWAYS_BRACES_MSG: &str = "Prefer `{...}` over `do...end` for blocks.";
+
+const DEFAULT_PROCEDURAL_METHODS: &[&str] = &[
+    "benchmark",
+    "bm",
+    "bmbm",
+    "create",
+    "each_with_object",
+    "measure",
+    "new",
+    "realtime",
+    "tap",
+    "with_object",
+];
+const DEFAULT_FUNCTIONAL_METHODS: &[&str] = &["let", "let!", "subject", "watch"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    LineCountBased,
+    Semantic,
+    BracesForChaining,
+    AlwaysBraces,
+}
+
 impl Cop for BlockDelimiters {
     fn name(&self) -> &'static str {
         "Style/BlockDelimiters"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -114,56 +169,126 @@ impl Cop for BlockDelimiters {
         _code_map: &CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
-        let enforced_style = config.get_str("EnforcedStyle", "line_count_based");
-        let _procedural_methods = config.get_string_array("ProceduralMethods");
-        let _functional_methods = config.get_string_array("FunctionalMethods");
-        let allowed_methods = config.get_string_array("AllowedMethods");
+        let style = match config.get_str("EnforcedStyle", "line_count_based") {
+            "line_count_based" => Style::LineCountBased,
+            "semantic" => Style::Semantic,
+            "braces_for_chaining" => Style::BracesForChaining,
+            "always_braces" => Style::AlwaysBraces,
+            _ => return,
+        };
+        let procedural_methods = config.get_string_array("ProceduralMethods");
+        let functional_methods = config.get_string_array("FunctionalMethods");
+        // `IgnoredMethods` is the deprecated name of `AllowedMethods`.
+        let allowed_methods = config
+            .get_string_array("AllowedMethods")
+            .or_else(|| config.get_string_array("IgnoredMethods"));
         let allowed_patterns = config.get_string_array("AllowedPatterns");
-        let _allow_braces_on_procedural =
+        let allow_braces_on_procedural_one_liners =
             config.get_bool("AllowBracesOnProceduralOneLiners", false);
         let braces_required_methods = config.get_string_array("BracesRequiredMethods");
 
-        if enforced_style != "line_count_based" {
-            return;
-        }
-
         let allowed = allowed_methods
             .unwrap_or_else(|| vec!["lambda".to_string(), "proc".to_string(), "it".to_string()]);
         let patterns = allowed_patterns.unwrap_or_default();
         let braces_required = braces_required_methods.unwrap_or_default();
+        let to_strings =
+            |names: &[&str]| -> Vec<String> { names.iter().map(|m| m.to_string()).collect() };
+
+        let program_statements = parse_result.node().as_program_node().map(|program| {
+            let loc = program.statements().location();
+            (loc.start_offset(), loc.end_offset())
+        });
 
         let mut visitor = BlockDelimitersVisitor {
             source,
             cop: self,
             diagnostics: Vec::new(),
+            corrections: Vec::new(),
+            autocorrect: corrections.is_some(),
+            comment_offsets: parse_result
+                .comments()
+                .map(|c| c.location().start_offset())
+                .collect(),
             ignored_blocks: HashSet::new(),
             suppressed_ranges: Vec::new(),
+            style,
             allowed_methods: allowed,
             allowed_patterns: patterns,
             braces_required_methods: braces_required,
+            procedural_methods: procedural_methods
+                .unwrap_or_else(|| to_strings(DEFAULT_PROCEDURAL_METHODS)),
+            functional_methods: functional_methods
+                .unwrap_or_else(|| to_strings(DEFAULT_FUNCTIONAL_METHODS)),
+            allow_braces_on_procedural_one_liners,
+            parents: Vec::new(),
+            program_statements,
+            last_statements: HashSet::new(),
+            non_last_statements: HashSet::new(),
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let Some(corrections) = corrections {
+            corrections.extend(visitor.corrections);
+        }
     }
 }
 
-struct BlockDelimitersVisitor<'a> {
+/// Where a block-bearing call sits, in Parser terms.
+#[derive(Debug, Clone, Copy, Default)]
+struct BlockContext {
+    /// `return_value_used?`: the parent is an assignment or a method call.
+    used: bool,
+    /// `return_value_of_scope?`: the parent is a conditional, array or range,
+    /// or the block is its parent's last child.
+    scope_value: bool,
+    /// `chained?`: the block's call is the receiver of another call.
+    chained: bool,
+}
+
+/// The block-bearing call being checked.
+struct BlockCall<'b, 'pr> {
+    block: &'b ruby_prism::BlockNode<'pr>,
+    method_name: &'b [u8],
+    start: usize,
+    end: usize,
+    /// The call has arguments without parentheses.
+    unparenthesized_args: bool,
+}
+
+struct BlockDelimitersVisitor<'a, 'pr> {
     source: &'a SourceFile,
     cop: &'a BlockDelimiters,
     diagnostics: Vec<Diagnostic>,
+    corrections: Vec<Correction>,
+    autocorrect: bool,
+    comment_offsets: Vec<usize>,
     ignored_blocks: HashSet<usize>,
     /// Byte ranges of blocks that suppress nested block checks.
     /// Includes: (1) blocks in non-parenthesized arg positions (binding change),
     /// (2) blocks that already received an offense (RuboCop `ignore_node` behavior).
     suppressed_ranges: Vec<(usize, usize)>,
+    style: Style,
     allowed_methods: Vec<String>,
     allowed_patterns: Vec<String>,
     braces_required_methods: Vec<String>,
+    procedural_methods: Vec<String>,
+    functional_methods: Vec<String>,
+    allow_braces_on_procedural_one_liners: bool,
+    /// Ancestors entered through the generic `visit` dispatch. Nodes visited
+    /// through typed fields (most `StatementsNode`s, `ArgumentsNode`s) are not
+    /// on the stack, which conveniently matches Parser's flatter tree.
+    parents: Vec<ruby_prism::Node<'pr>>,
+    /// Range of the program's top-level statements.
+    program_statements: Option<(usize, usize)>,
+    /// Ranges of statements that are the value of their body.
+    last_statements: HashSet<(usize, usize)>,
+    /// Ranges of statements that are followed by another statement.
+    non_last_statements: HashSet<(usize, usize)>,
 }
 
-impl<'a> BlockDelimitersVisitor<'a> {
+impl<'a, 'pr> BlockDelimitersVisitor<'a, 'pr> {
     /// Check if a block's byte range is contained within any suppressed range.
     fn is_suppressed(&self, start: usize, end: usize) -> bool {
         self.suppressed_ranges
@@ -181,8 +306,113 @@ impl<'a> BlockDelimitersVisitor<'a> {
         self.suppressed_ranges.push((start, end));
     }
 
-    fn check_block(&mut self, block_node: &ruby_prism::BlockNode<'_>, method_name: &[u8]) -> bool {
-        let method_str = std::str::from_utf8(method_name).unwrap_or("");
+    /// Index just past the nearest ancestor of the node at `start..end`,
+    /// skipping statement lists (which Parser doesn't have for single
+    /// statements, and which are `begin` nodes otherwise).
+    fn parent_index(&self, start: usize, end: usize) -> Option<usize> {
+        let mut idx = self.parents.len();
+        if let Some(last) = self.parents.last() {
+            let loc = last.location();
+            if loc.start_offset() == start && loc.end_offset() == end {
+                idx -= 1;
+            }
+        }
+        self.parents[..idx]
+            .iter()
+            .rposition(|n| n.as_statements_node().is_none())
+    }
+
+    fn block_context(&self, start: usize, end: usize) -> BlockContext {
+        if self.non_last_statements.contains(&(start, end)) {
+            return BlockContext::default();
+        }
+        let Some(idx) = self.parent_index(start, end) else {
+            return BlockContext::default();
+        };
+        let parent = &self.parents[idx];
+        let chained = parent
+            .as_call_node()
+            .and_then(|call| call.receiver())
+            .is_some_and(|recv| {
+                let loc = recv.location();
+                loc.start_offset() == start && loc.end_offset() == end
+            });
+        BlockContext {
+            used: self.return_value_used(idx),
+            scope_value: self.last_statements.contains(&(start, end))
+                || is_scope_value_parent(parent, start, end),
+            chained,
+        }
+    }
+
+    /// Parser's `return_value_used?`, looking through parentheses.
+    fn return_value_used(&self, parent_idx: usize) -> bool {
+        let parent = &self.parents[parent_idx];
+        if parent.as_parentheses_node().is_some() {
+            let loc = parent.location();
+            let (start, end) = (loc.start_offset(), loc.end_offset());
+            return self.parents[..parent_idx]
+                .iter()
+                .rposition(|n| n.as_statements_node().is_none())
+                .is_some_and(|idx| {
+                    !self.non_last_statements.contains(&(start, end)) && self.return_value_used(idx)
+                });
+        }
+        let tag = node_type_tag(parent);
+        is_assignment_type(tag) || is_call_type(tag)
+    }
+
+    fn proper_block_style(
+        &self,
+        block: &BlockCall<'_, '_>,
+        braces: bool,
+        single_line: bool,
+    ) -> bool {
+        match self.style {
+            Style::LineCountBased => single_line == braces,
+            Style::Semantic => {
+                let method = std::str::from_utf8(block.method_name).unwrap_or("");
+                let ctx = self.block_context(block.start, block.end);
+                if braces {
+                    self.functional_methods.iter().any(|m| m == method)
+                        || ctx.used
+                        || ctx.scope_value
+                        || (self.allow_braces_on_procedural_one_liners && single_line)
+                } else {
+                    self.procedural_methods.iter().any(|m| m == method) || !ctx.used
+                }
+            }
+            Style::BracesForChaining => {
+                if single_line {
+                    braces
+                } else {
+                    braces == self.block_context(block.start, block.end).chained
+                }
+            }
+            Style::AlwaysBraces => braces,
+        }
+    }
+
+    fn message(&self, block: &BlockCall<'_, '_>, braces: bool, single_line: bool) -> &'static str {
+        match self.style {
+            Style::LineCountBased if single_line => SINGLE_LINE_MSG,
+            Style::LineCountBased => MULTI_LINE_MSG,
+            Style::Semantic if braces => PROCEDURAL_MSG,
+            Style::Semantic => FUNCTIONAL_MSG,
+            Style::BracesForChaining if single_line => SINGLE_LINE_MSG,
+            Style::BracesForChaining => {
+                if self.block_context(block.start, block.end).chained {
+                    CHAINED_MSG
+                } else {
+                    NOT_CHAINED_MSG
+                }
+            }
+            Style::AlwaysBraces => ALWAYS_BRACES_MSG,
+        }
+    }
+
+    fn check_block(&mut self, block: BlockCall<'_, '_>) -> bool {
+        let method_str = std::str::from_utf8(block.method_name).unwrap_or("");
 
         // Skip AllowedMethods (default: lambda, proc, it)
         if self.allowed_methods.iter().any(|m| m == method_str) {
@@ -198,64 +428,190 @@ impl<'a> BlockDelimitersVisitor<'a> {
             }
         }
 
-        let opening_loc = block_node.opening_loc();
-        let closing_loc = block_node.closing_loc();
-        let opening = opening_loc.as_slice();
+        let opening_loc = block.block.opening_loc();
+        let closing_loc = block.block.closing_loc();
+        let braces = opening_loc.as_slice() == b"{";
 
         let (open_line, _) = self.source.offset_to_line_col(opening_loc.start_offset());
         let (close_line, _) = self.source.offset_to_line_col(closing_loc.start_offset());
         let is_single_line = open_line == close_line;
 
-        // BracesRequiredMethods: must use braces
-        if self.braces_required_methods.iter().any(|m| m == method_str) {
-            if opening == b"do" {
-                let (line, column) = self.source.offset_to_line_col(opening_loc.start_offset());
-                self.diagnostics.push(self.cop.diagnostic(
-                    self.source,
-                    line,
-                    column,
-                    format!(
-                        "Brace delimiters `{{...}}` required for '{}' method.",
-                        method_str
-                    ),
-                ));
-                return true;
+        let message = if self.braces_required_methods.iter().any(|m| m == method_str) {
+            // BracesRequiredMethods: must use braces
+            if braces {
+                return false;
+            }
+            format!(
+                "Brace delimiters `{{...}}` required for '{}' method.",
+                method_str
+            )
+        } else {
+            // require_do_end: single-line do-end blocks with rescue/ensure clauses
+            // cannot be converted to braces (syntax error). Skip these.
+            if is_single_line && !braces && block_has_rescue_or_ensure(block.block) {
+                return false;
+            }
+            if self.proper_block_style(&block, braces, is_single_line) {
+                return false;
+            }
+            self.message(&block, braces, is_single_line).to_string()
+        };
+
+        let (line, column) = self.source.offset_to_line_col(opening_loc.start_offset());
+        let mut diagnostic = self.cop.diagnostic(self.source, line, column, message);
+        if self.autocorrect {
+            let corrections = if braces {
+                self.braces_to_do_end(block.block)
+            } else {
+                self.do_end_to_braces(&block)
+            };
+            if let Some(corrections) = corrections {
+                self.corrections.extend(corrections);
+                diagnostic.corrected = true;
             }
-            return false;
         }
+        self.diagnostics.push(diagnostic);
+        true
+    }
 
-        // require_do_end: single-line do-end blocks with rescue/ensure clauses
-        // cannot be converted to braces (syntax error). Skip these.
-        if is_single_line && opening == b"do" && block_has_rescue_or_ensure(block_node) {
-            return false;
+    fn correction(&self, start: usize, end: usize, replacement: String) -> Correction {
+        Correction {
+            start,
+            end,
+            replacement,
+            cop_name: self.cop.name(),
+            cop_index: 0,
+        }
+    }
+
+    fn braces_to_do_end(&self, block: &ruby_prism::BlockNode<'_>) -> Option<[Correction; 2]> {
+        let bytes = self.source.as_bytes();
+        let (open_start, open_end) = (
+            block.opening_loc().start_offset(),
+            block.opening_loc().end_offset(),
+        );
+        let (close_start, close_end) = (
+            block.closing_loc().start_offset(),
+            block.closing_loc().end_offset(),
+        );
+
+        // RuboCop moves a trailing comment above the block; leave those alone.
+        let line_end = bytes[close_end..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(bytes.len(), |p| close_end + p);
+        if self
+            .comment_offsets
+            .iter()
+            .any(|&c| close_end <= c && c < line_end)
+        {
+            return None;
+        }
+
+        let space_before = |offset: usize| offset > 0 && bytes[offset - 1].is_ascii_whitespace();
+        let mut opening = String::new();
+        if !space_before(open_start) {
+            opening.push(' ');
+        }
+        opening.push_str("do");
+        if !bytes.get(open_end).is_some_and(|b| b.is_ascii_whitespace()) {
+            opening.push(' ');
         }
+        let closing = if space_before(close_start) {
+            "end"
+        } else {
+            " end"
+        };
+        Some([
+            self.correction(open_start, open_end, opening),
+            self.correction(close_start, close_end, closing.to_string()),
+        ])
+    }
 
-        // line_count_based style
-        if is_single_line && opening == b"do" {
-            let (line, column) = self.source.offset_to_line_col(opening_loc.start_offset());
-            self.diagnostics.push(self.cop.diagnostic(
-                self.source,
-                line,
-                column,
-                "Prefer `{...}` over `do...end` for single-line blocks.".to_string(),
-            ));
-            return true;
-        } else if !is_single_line && opening == b"{" {
-            let (line, column) = self.source.offset_to_line_col(opening_loc.start_offset());
-            self.diagnostics.push(self.cop.diagnostic(
-                self.source,
-                line,
-                column,
-                "Prefer `do...end` over `{...}` for multi-line blocks.".to_string(),
-            ));
-            return true;
-        }
-        false
+    fn do_end_to_braces(&self, block: &BlockCall<'_, '_>) -> Option<[Correction; 2]> {
+        // `foo bar do ... end` -> `foo bar { ... }` would bind the block to `bar`.
+        if block.unparenthesized_args {
+            return None;
+        }
+        // `{ ... }` can't hold `rescue`/`ensure` without a `begin`.
+        if block_has_rescue_or_ensure(block.block) {
+            return None;
+        }
+        let bytes = self.source.as_bytes();
+        let opening_loc = block.block.opening_loc();
+        let closing_loc = block.block.closing_loc();
+        let opening = if bytes
+            .get(opening_loc.end_offset())
+            .is_some_and(|b| b.is_ascii_whitespace())
+        {
+            "{"
+        } else {
+            "{ "
+        };
+        Some([
+            self.correction(
+                opening_loc.start_offset(),
+                opening_loc.end_offset(),
+                opening.to_string(),
+            ),
+            self.correction(
+                closing_loc.start_offset(),
+                closing_loc.end_offset(),
+                "}".to_string(),
+            ),
+        ])
+    }
+
+    /// Check a block attached to a call-like node spanning `start..end`,
+    /// suppressing nested blocks when it's flagged.
+    fn check_call_block(&mut self, block: BlockCall<'_, '_>) {
+        let offset = block.block.opening_loc().start_offset();
+        let block_end = block.block.closing_loc().end_offset();
+        let (call_start, call_end) = (block.start, block.end);
+
+        if self.ignored_blocks.contains(&offset) {
+            // Block is in non-parenthesized arg position — suppress it
+            // and all nested blocks (RuboCop's part_of_ignored_node? behavior)
+            self.suppress_range(call_start, call_end);
+        } else if !self.is_suppressed(offset, block_end) {
+            // Block is not inside a suppressed range — check it
+            let flagged = self.check_block(block);
+            if flagged {
+                // Suppress nested blocks (RuboCop's ignore_node in add_offense)
+                self.suppress_range(call_start, call_end);
+            }
+        }
     }
 }
 
-impl<'a> Visit<'_> for BlockDelimitersVisitor<'a> {
-    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'_>) {
+impl<'a, 'pr> Visit<'pr> for BlockDelimitersVisitor<'a, 'pr> {
+    fn visit_branch_node_enter(&mut self, node: ruby_prism::Node<'pr>) {
+        self.parents.push(node);
+    }
+
+    fn visit_branch_node_leave(&mut self) {
+        self.parents.pop();
+    }
+
+    fn visit_statements_node(&mut self, node: &ruby_prism::StatementsNode<'pr>) {
+        let body = node.body();
+        let loc = node.location();
+        // A lone top-level statement has no parent in Parser.
+        let lone_top_level = body.len() == 1
+            && self.program_statements == Some((loc.start_offset(), loc.end_offset()));
+        let count = body.len();
+        for (i, stmt) in body.iter().enumerate() {
+            let range = (stmt.location().start_offset(), stmt.location().end_offset());
+            if i + 1 < count {
+                self.non_last_statements.insert(range);
+            } else if !lone_top_level {
+                self.last_statements.insert(range);
+            }
+        }
+        ruby_prism::visit_statements_node(self, node);
+    }
+
+    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
         // For non-parenthesized calls with arguments, mark argument blocks
         // as ignored. Changing delimiters on these blocks would change binding
         // semantics (braces bind tighter than do..end).
@@ -308,29 +664,18 @@ impl<'a> Visit<'_> for BlockDelimitersVisitor<'a> {
         // Phase 2: Check this call's block (if any)
         if let Some(block) = node.block() {
             if let Some(block_node) = block.as_block_node() {
-                let offset = block_node.opening_loc().start_offset();
-                let block_end = block_node.closing_loc().end_offset();
-
                 // Use the call node's full range for suppression. In Prism,
                 // chained calls like `a.select { }.reject { }` have the outer
                 // CallNode covering the entire chain [0..end], while BlockNode
                 // ranges only cover `{...}`. Using the call node's range ensures
                 // inner blocks in a chain are contained within the suppressed range.
-                let call_start = node.location().start_offset();
-                let call_end = node.location().end_offset();
-
-                if self.ignored_blocks.contains(&offset) {
-                    // Block is in non-parenthesized arg position — suppress it
-                    // and all nested blocks (RuboCop's part_of_ignored_node? behavior)
-                    self.suppress_range(call_start, call_end);
-                } else if !self.is_suppressed(offset, block_end) {
-                    // Block is not inside a suppressed range — check it
-                    let flagged = self.check_block(&block_node, method_name);
-                    if flagged {
-                        // Suppress nested blocks (RuboCop's ignore_node in add_offense)
-                        self.suppress_range(call_start, call_end);
-                    }
-                }
+                self.check_call_block(BlockCall {
+                    block: &block_node,
+                    method_name,
+                    start: node.location().start_offset(),
+                    end: node.location().end_offset(),
+                    unparenthesized_args: !is_parenthesized && node.arguments().is_some(),
+                });
             }
         }
 
@@ -338,45 +683,68 @@ impl<'a> Visit<'_> for BlockDelimitersVisitor<'a> {
         ruby_prism::visit_call_node(self, node);
     }
 
-    fn visit_super_node(&mut self, node: &ruby_prism::SuperNode<'_>) {
+    fn visit_super_node(&mut self, node: &ruby_prism::SuperNode<'pr>) {
         // SuperNode: `super(args) { ... }` or `super(args) do ... end`
         if let Some(block) = node.block() {
             if let Some(block_node) = block.as_block_node() {
-                let offset = block_node.opening_loc().start_offset();
-                let block_end = block_node.closing_loc().end_offset();
-                let call_start = node.location().start_offset();
-                let call_end = node.location().end_offset();
-
-                if !self.is_suppressed(offset, block_end) {
-                    let flagged = self.check_block(&block_node, b"super");
-                    if flagged {
-                        self.suppress_range(call_start, call_end);
-                    }
-                }
+                self.check_call_block(BlockCall {
+                    block: &block_node,
+                    method_name: b"super",
+                    start: node.location().start_offset(),
+                    end: node.location().end_offset(),
+                    unparenthesized_args: node.lparen_loc().is_none() && node.arguments().is_some(),
+                });
             }
         }
         ruby_prism::visit_super_node(self, node);
     }
 
-    fn visit_forwarding_super_node(&mut self, node: &ruby_prism::ForwardingSuperNode<'_>) {
+    fn visit_forwarding_super_node(&mut self, node: &ruby_prism::ForwardingSuperNode<'pr>) {
         // ForwardingSuperNode: `super { ... }` or `super do ... end` (no explicit args)
         if let Some(block_node) = node.block() {
-            let offset = block_node.opening_loc().start_offset();
-            let block_end = block_node.closing_loc().end_offset();
-            let call_start = node.location().start_offset();
-            let call_end = node.location().end_offset();
-
-            if !self.is_suppressed(offset, block_end) {
-                let flagged = self.check_block(&block_node, b"super");
-                if flagged {
-                    self.suppress_range(call_start, call_end);
-                }
-            }
+            self.check_call_block(BlockCall {
+                block: &block_node,
+                method_name: b"super",
+                start: node.location().start_offset(),
+                end: node.location().end_offset(),
+                unparenthesized_args: false,
+            });
         }
         ruby_prism::visit_forwarding_super_node(self, node);
     }
 }
 
+/// Parser's `return_value_of_scope?` for parents other than statement lists:
+/// conditionals (including `&&`/`||`), arrays and ranges, and nodes whose
+/// last child is the block's call.
+fn is_scope_value_parent(parent: &ruby_prism::Node<'_>, start: usize, end: usize) -> bool {
+    let is_node = |node: &ruby_prism::Node<'_>| {
+        node.location().start_offset() == start && node.location().end_offset() == end
+    };
+    let tag = node_type_tag(parent);
+    if matches!(
+        tag,
+        IF_NODE | UNLESS_NODE | AND_NODE | OR_NODE | ARRAY_NODE | RANGE_NODE | PARENTHESES_NODE
+    ) {
+        return true;
+    }
+    if let Some(assoc) = parent.as_assoc_node() {
+        return is_node(&assoc.value());
+    }
+    let arguments = if let Some(node) = parent.as_return_node() {
+        node.arguments()
+    } else if let Some(node) = parent.as_next_node() {
+        node.arguments()
+    } else if let Some(node) = parent.as_break_node() {
+        node.arguments()
+    } else {
+        None
+    };
+    arguments
+        .and_then(|args| args.arguments().iter().last())
+        .is_some_and(|last| is_node(&last))
+}
+
 /// Check if a block corresponds to Parser's `:block` type (not `:itblock` or `:numblock`).
 /// Returns false for blocks with `it` parameters (`:itblock` in Parser) or numbered
 /// parameters like `_1` (`:numblock` in Parser). Blocks with explicit parameters
@@ -589,6 +957,183 @@ mod tests {
     use super::*;
     crate::cop_fixture_tests!(BlockDelimiters, "cops/style/block_delimiters");
 
+    fn style_config(style: &str) -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "EnforcedStyle".to_string(),
+                serde_yml::Value::String(style.to_string()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_semantic() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &BlockDelimiters,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/block_delimiters/offense.semantic.rb"
+            ),
+            style_config("semantic"),
+        );
+    }
+
+    #[test]
+    fn no_offense_semantic() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &BlockDelimiters,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/block_delimiters/no_offense.semantic.rb"
+            ),
+            style_config("semantic"),
+        );
+    }
+
+    #[test]
+    fn offense_braces_for_chaining() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &BlockDelimiters,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/block_delimiters/offense.braces_for_chaining.rb"
+            ),
+            style_config("braces_for_chaining"),
+        );
+    }
+
+    #[test]
+    fn no_offense_braces_for_chaining() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &BlockDelimiters,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/block_delimiters/no_offense.braces_for_chaining.rb"
+            ),
+            style_config("braces_for_chaining"),
+        );
+    }
+
+    #[test]
+    fn offense_always_braces() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &BlockDelimiters,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/block_delimiters/offense.always_braces.rb"
+            ),
+            style_config("always_braces"),
+        );
+    }
+
+    #[test]
+    fn no_offense_always_braces() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &BlockDelimiters,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/block_delimiters/no_offense.always_braces.rb"
+            ),
+            style_config("always_braces"),
+        );
+    }
+
+    #[test]
+    fn semantic_procedural_one_liners() {
+        let mut config = style_config("semantic");
+        config.options.insert(
+            "AllowBracesOnProceduralOneLiners".to_string(),
+            serde_yml::Value::Bool(true),
+        );
+        let source = b"items.each { |x| puts x }\nitems.each { |x|\n  puts x\n}\ndone\n";
+        let diags = crate::testutil::run_cop_full_with_config(&BlockDelimiters, source, config);
+        assert_eq!(diags.len(), 1, "got: {:?}", diags);
+        assert_eq!(diags[0].location.line, 2);
+    }
+
+    #[test]
+    fn semantic_custom_method_lists() {
+        let mut config = style_config("semantic");
+        config.options.insert(
+            "FunctionalMethods".to_string(),
+            serde_yml::Value::Sequence(vec![serde_yml::Value::String("given".to_string())]),
+        );
+        config.options.insert(
+            "ProceduralMethods".to_string(),
+            serde_yml::Value::Sequence(vec![serde_yml::Value::String("build".to_string())]),
+        );
+        let source = b"given { 1 }\nx = build do\n  1\nend\nlet(:a) { 1 }\ndone\n";
+        let diags = crate::testutil::run_cop_full_with_config(&BlockDelimiters, source, config);
+        assert_eq!(diags.len(), 1, "got: {:?}", diags);
+        assert_eq!(diags[0].location.line, 5);
+    }
+
+    #[test]
+    fn ignored_methods_fallback() {
+        let mut config = style_config("always_braces");
+        config.options.insert(
+            "IgnoredMethods".to_string(),
+            serde_yml::Value::Sequence(vec![serde_yml::Value::String("describe".to_string())]),
+        );
+        let source = b"describe do\n  it_works\nend\n";
+        let diags = crate::testutil::run_cop_full_with_config(&BlockDelimiters, source, config);
+        assert!(diags.is_empty(), "got: {:?}", diags);
+    }
+
+    #[test]
+    fn braces_required_methods() {
+        let mut config = style_config("line_count_based");
+        config.options.insert(
+            "BracesRequiredMethods".to_string(),
+            serde_yml::Value::Sequence(vec![serde_yml::Value::String("sig".to_string())]),
+        );
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &BlockDelimiters,
+            b"sig do\n  params(x: Integer)\nend\nsig {\n  void\n}\n",
+            b"sig {\n  params(x: Integer)\n}\nsig {\n  void\n}\n",
+            config,
+        );
+    }
+
+    #[test]
+    fn autocorrect_line_count_based() {
+        crate::testutil::assert_cop_autocorrect(
+            &BlockDelimiters,
+            b"items.each do |x| puts x end\nitems.map{|x|\n  x * 2\n}\nfoo(1) do|x| x end\n",
+            b"items.each { |x| puts x }\nitems.map do |x|\n  x * 2\nend\nfoo(1) { |x| x }\n",
+        );
+    }
+
+    #[test]
+    fn autocorrect_semantic() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &BlockDelimiters,
+            b"items.each { |x| puts x }\nresult = items.map do |x|\n  x * 2\nend\ndone\n",
+            b"items.each do |x| puts x end\nresult = items.map { |x|\n  x * 2\n}\ndone\n",
+            style_config("semantic"),
+        );
+    }
+
+    #[test]
+    fn no_autocorrect_when_binding_would_change() {
+        // `foo bar { ... }` would pass the block to `bar` instead of `foo`.
+        let (diags, corrections) =
+            crate::testutil::run_cop_autocorrect(&BlockDelimiters, b"foo bar do |x| x end\n");
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty());
+        assert!(!diags[0].corrected);
+    }
+
+    #[test]
+    fn no_autocorrect_with_rescue_or_trailing_comment() {
+        let source = b"items.map { |x|\n  x\n} # note\nitems.each do |x|\n  x\nrescue StandardError\n  nil\nend\n";
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect_with_config(
+            &BlockDelimiters,
+            source,
+            style_config("always_braces"),
+        );
+        assert_eq!(diags.len(), 1, "got: {:?}", diags);
+        assert!(corrections.is_empty());
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect(&BlockDelimiters, source);
+        assert_eq!(diags.len(), 1, "got: {:?}", diags);
+        assert!(corrections.is_empty());
+    }
+
     #[test]
     fn no_offense_proc_in_keyword_arg() {
         // Proc block in keyword arg without parens — changing braces would change semantics
@@ -1,7 +1,11 @@
+use std::collections::HashMap;
+
 use ruby_prism::Visit;
 
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
+use crate::parse::codemap::CodeMap;
 use crate::parse::source::SourceFile;
 
 /// FN fix #1: The `inside_class_or_module` boolean was too broad — it suppressed
@@ -24,6 +28,24 @@ use crate::parse::source::SourceFile;
 /// (`x = module Foo::Bar`, `@var = class Foo::Bar < Base`), producing
 /// 0 offenses. Skip class/module nodes that are direct values of variable
 /// assignments to match the observable behavior.
+///
+/// ## Autocorrect
+///
+/// - `nested`: `class Foo::Bar < Base` is expanded into one definition per
+///   namespace segment, indented by `IndentationWidth`, with the superclass
+///   kept on the innermost definition. Namespace segments become `module`
+///   unless the immediately preceding sibling defines a class with that name
+///   (RuboCop's `replace_namespace_keyword`). Unlike RuboCop, which splits one
+///   `::` per pass and leaves the body to Layout cops, every segment is
+///   expanded and the body is reindented in a single pass.
+/// - `compact`: a chain of single-child definitions is folded into one
+///   `class A::B::C` definition, comments between the headers are moved above
+///   it, and the body is dedented to the outer definition's column.
+///
+/// Corrections are skipped for `::Foo::Bar` (expanding or folding a cbase
+/// path changes constant lookup), for definitions that do not start and end on
+/// their own lines, and when code other than comments sits between the nested
+/// headers or `end`s. String and heredoc content is never reindented.
 pub struct ClassAndModuleChildren;
 
 impl Cop for ClassAndModuleChildren {
@@ -35,26 +57,49 @@ impl Cop for ClassAndModuleChildren {
         &self,
         source: &SourceFile,
         parse_result: &ruby_prism::ParseResult<'_>,
-        _code_map: &crate::parse::codemap::CodeMap,
+        code_map: &CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let enforced_style = config.get_str("EnforcedStyle", "nested").to_string();
         let enforced_for_classes = config.get_str("EnforcedStyleForClasses", "").to_string();
         let enforced_for_modules = config.get_str("EnforcedStyleForModules", "").to_string();
+        let indentation_width = config.get_usize("IndentationWidth", 2);
 
         let mut visitor = ChildrenVisitor {
             source,
+            code_map,
             enforced_style,
             enforced_for_classes,
             enforced_for_modules,
+            indentation_width,
+            autocorrect: corrections.is_some(),
+            comment_ranges: parse_result
+                .comments()
+                .map(|c| (c.location().start_offset(), c.location().end_offset()))
+                .collect(),
+            left_sibling_classes: HashMap::new(),
+            squiggly_heredocs: Vec::new(),
             parent_is_class_or_module: false,
             skip_next_class_or_module: false,
             diagnostics: Vec::new(),
+            corrections: Vec::new(),
         };
+        if visitor.autocorrect {
+            let mut collector = SquigglyHeredocCollector { ranges: Vec::new() };
+            collector.visit(&parse_result.node());
+            visitor.squiggly_heredocs = collector.ranges;
+        }
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let Some(corrections) = corrections {
+            corrections.extend(visitor.corrections);
+        }
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
     }
 
     fn diagnostic(
@@ -71,15 +116,27 @@ impl Cop for ClassAndModuleChildren {
             cop_name: self.name().to_string(),
             message,
             corrected: false,
+            end_column: None,
         }
     }
 }
 
 struct ChildrenVisitor<'a> {
     source: &'a SourceFile,
+    code_map: &'a CodeMap,
     enforced_style: String,
     enforced_for_classes: String,
     enforced_for_modules: String,
+    indentation_width: usize,
+    autocorrect: bool,
+    comment_ranges: Vec<(usize, usize)>,
+    /// Constant paths of classes defined in the left sibling of a statement,
+    /// keyed by the statement's start offset. Only populated for compact
+    /// definitions when autocorrecting (see `replace_namespace_keyword`).
+    left_sibling_classes: HashMap<usize, Vec<String>>,
+    /// Body-through-terminator ranges of `<<~` heredocs. Their lines can be
+    /// shifted with the surrounding code since common indentation is stripped.
+    squiggly_heredocs: Vec<(usize, usize)>,
     /// Mirrors RuboCop's `node.parent&.type?(:class, :module)`.
     /// True when the current node is the sole body statement of a class/module,
     /// meaning its AST parent (in parser gem terms) IS the class/module itself.
@@ -91,6 +148,61 @@ struct ChildrenVisitor<'a> {
     /// patterns, producing 0 offenses. We skip them to match observable behavior.
     skip_next_class_or_module: bool,
     diagnostics: Vec<Diagnostic>,
+    corrections: Vec<Correction>,
+}
+
+/// The parts of a `class` or `module` definition needed for autocorrection.
+struct Definition<'pr> {
+    keyword: &'static str,
+    keyword_start: usize,
+    constant_path: ruby_prism::Node<'pr>,
+    has_superclass: bool,
+    body: Option<ruby_prism::Node<'pr>>,
+    end_start: usize,
+    end_end: usize,
+}
+
+impl<'pr> Definition<'pr> {
+    fn from_node(node: &ruby_prism::Node<'pr>) -> Option<Self> {
+        if let Some(class) = node.as_class_node() {
+            Some(Self::from_class(&class))
+        } else {
+            node.as_module_node()
+                .map(|module| Self::from_module(&module))
+        }
+    }
+
+    fn from_class(node: &ruby_prism::ClassNode<'pr>) -> Self {
+        Definition {
+            keyword: "class",
+            keyword_start: node.class_keyword_loc().start_offset(),
+            constant_path: node.constant_path(),
+            has_superclass: node.superclass().is_some(),
+            body: node.body(),
+            end_start: node.end_keyword_loc().start_offset(),
+            end_end: node.end_keyword_loc().end_offset(),
+        }
+    }
+
+    fn from_module(node: &ruby_prism::ModuleNode<'pr>) -> Self {
+        Definition {
+            keyword: "module",
+            keyword_start: node.module_keyword_loc().start_offset(),
+            constant_path: node.constant_path(),
+            has_superclass: false,
+            body: node.body(),
+            end_start: node.end_keyword_loc().start_offset(),
+            end_end: node.end_keyword_loc().end_offset(),
+        }
+    }
+
+    fn name_start(&self) -> usize {
+        self.constant_path.location().start_offset()
+    }
+
+    fn name_end(&self) -> usize {
+        self.constant_path.location().end_offset()
+    }
 }
 
 impl<'a> ChildrenVisitor<'a> {
@@ -119,6 +231,7 @@ impl<'a> ChildrenVisitor<'a> {
             cop_name: "Style/ClassAndModuleChildren".to_string(),
             message,
             corrected: false,
+            end_column: None,
         });
     }
 
@@ -141,7 +254,7 @@ impl<'a> ChildrenVisitor<'a> {
         body_node.as_class_node().is_some() || body_node.as_module_node().is_some()
     }
 
-    fn check_nested_style(&mut self, is_compact: bool, name_offset: usize) {
+    fn check_nested_style(&mut self, is_compact: bool, def: &Definition<'a>) {
         // For nested style: flag compact-style definitions (with ::)
         if !is_compact {
             return;
@@ -152,24 +265,369 @@ impl<'a> ChildrenVisitor<'a> {
             return;
         }
         self.add_diagnostic(
-            name_offset,
+            def.name_start(),
             "Use nested module/class definitions instead of compact style.".to_string(),
         );
+        if self.autocorrect {
+            if let Some(correction) = self.nest_definition(def) {
+                self.push_correction(correction);
+            }
+        }
     }
 
-    fn check_compact_style(&mut self, body: &Option<ruby_prism::Node<'a>>, name_offset: usize) {
+    fn check_compact_style(&mut self, def: &Definition<'a>) {
         // For compact style: flag outer nodes whose body is a single class/module
         // RuboCop: return if parent&.type?(:class, :module)
         if self.parent_is_class_or_module {
             return;
         }
-        if !self.body_is_single_class_or_module(body) {
+        if !self.body_is_single_class_or_module(&def.body) {
             return;
         }
         self.add_diagnostic(
-            name_offset,
+            def.name_start(),
             "Use compact module/class definition instead of nested style.".to_string(),
         );
+        if self.autocorrect {
+            if let Some(correction) = self.compact_definition(def) {
+                self.push_correction(correction);
+            }
+        }
+    }
+
+    fn push_correction(&mut self, correction: Correction) {
+        self.corrections.push(correction);
+        if let Some(diag) = self.diagnostics.last_mut() {
+            diag.corrected = true;
+        }
+    }
+
+    /// Expand `class Foo::Bar` into `module Foo` / `class Bar` definitions.
+    fn nest_definition(&self, def: &Definition<'a>) -> Option<Correction> {
+        let bytes = self.source.as_bytes();
+        let segments = constant_segments(bytes, &def.constant_path)?;
+        if segments.len() < 2
+            || !starts_line(bytes, def.keyword_start)
+            || !starts_line(bytes, def.end_start)
+            || !only_comment_follows(bytes, def.end_end)
+        {
+            return None;
+        }
+
+        let indent = String::from_utf8_lossy(
+            &bytes[line_start(bytes, def.keyword_start)..def.keyword_start],
+        )
+        .into_owned();
+        let step = " ".repeat(self.indentation_width);
+        let depth = segments.len() - 1;
+        let sibling_classes = self.left_sibling_classes.get(&def.keyword_start);
+
+        let mut out = String::new();
+        for (i, segment) in segments.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+                out.push_str(&indent);
+                out.push_str(&step.repeat(i));
+            }
+            let keyword = if i == depth {
+                def.keyword
+            } else {
+                let namespace = segments[..=i].join("::");
+                if sibling_classes.is_some_and(|names| names.contains(&namespace)) {
+                    "class"
+                } else {
+                    "module"
+                }
+            };
+            out.push_str(keyword);
+            out.push(' ');
+            out.push_str(segment);
+        }
+
+        let end_line = line_start(bytes, def.end_start);
+        let added = step.repeat(depth);
+        for (index, (start, end)) in lines_in(bytes, def.name_end(), end_line).enumerate() {
+            let line = &bytes[start..end];
+            if index > 0 && self.reindentable(bytes, start) {
+                out.push_str(&added);
+            }
+            out.push_str(&String::from_utf8_lossy(line));
+        }
+
+        for level in (0..=depth).rev() {
+            if level < depth {
+                out.push('\n');
+            }
+            out.push_str(&indent);
+            out.push_str(&step.repeat(level));
+            out.push_str("end");
+        }
+
+        Some(self.correction(def.keyword_start, def.end_end, out))
+    }
+
+    /// Fold a chain of single-child definitions into one compact definition.
+    fn compact_definition(&self, outer: &Definition<'a>) -> Option<Correction> {
+        let bytes = self.source.as_bytes();
+        if !starts_line(bytes, outer.keyword_start) {
+            return None;
+        }
+
+        let mut names = vec![node_source(bytes, &outer.constant_path)];
+        let mut comments = Vec::new();
+        let mut current = None::<Definition<'a>>;
+        loop {
+            let parent = current.as_ref().unwrap_or(outer);
+            let Some(child) = single_child_definition(&parent.body) else {
+                break;
+            };
+            if constant_root_is_cbase(&child.constant_path)
+                || !starts_line(bytes, child.keyword_start)
+                || !starts_line(bytes, parent.end_start)
+                || !bytes[child.end_end..parent.end_start]
+                    .iter()
+                    .all(u8::is_ascii_whitespace)
+            {
+                break;
+            }
+            let Some(gap_comments) = self.comments_between(parent.name_end(), child.keyword_start)
+            else {
+                break;
+            };
+            comments.extend(gap_comments);
+            names.push(node_source(bytes, &child.constant_path));
+
+            // The folded definition takes the child's keyword, so whether it
+            // can keep folding depends on the child's own style.
+            let style = if child.keyword == "class" {
+                self.style_for_class()
+            } else {
+                self.style_for_module()
+            };
+            let keep_folding = style == "compact" && !child.has_superclass;
+            current = Some(child);
+            if !keep_folding {
+                break;
+            }
+        }
+        let inner = current?;
+
+        let indent_len = outer.keyword_start - line_start(bytes, outer.keyword_start);
+        let indent = String::from_utf8_lossy(
+            &bytes[line_start(bytes, outer.keyword_start)..outer.keyword_start],
+        )
+        .into_owned();
+        let removed = inner.keyword_start - line_start(bytes, inner.keyword_start) - indent_len;
+
+        let mut out = String::new();
+        for (start, end) in comments {
+            out.push_str(&String::from_utf8_lossy(&bytes[start..end]));
+            out.push('\n');
+            out.push_str(&indent);
+        }
+        out.push_str(inner.keyword);
+        out.push(' ');
+        out.push_str(&names.join("::"));
+        for (index, (start, end)) in lines_in(bytes, inner.name_end(), inner.end_end).enumerate() {
+            let mut line = &bytes[start..end];
+            if index > 0 && self.reindentable(bytes, start) {
+                let leading = line
+                    .iter()
+                    .take(removed)
+                    .take_while(|&&b| b == b' ' || b == b'\t')
+                    .count();
+                line = &line[leading..];
+            }
+            out.push_str(&String::from_utf8_lossy(line));
+        }
+
+        Some(self.correction(outer.keyword_start, outer.end_end, out))
+    }
+
+    /// Comments between two offsets, or `None` when anything other than
+    /// whitespace and comments sits there.
+    fn comments_between(&self, start: usize, end: usize) -> Option<Vec<(usize, usize)>> {
+        let bytes = self.source.as_bytes();
+        let mut found = Vec::new();
+        let mut pos = start;
+        while pos < end {
+            if bytes[pos].is_ascii_whitespace() {
+                pos += 1;
+            } else if let Some(&(c_start, c_end)) =
+                self.comment_ranges.iter().find(|&&(s, _)| s == pos)
+            {
+                found.push((c_start, c_end));
+                pos = c_end;
+            } else {
+                return None;
+            }
+        }
+        Some(found)
+    }
+
+    /// Whether a line starting at `offset` can be shifted without changing
+    /// string/heredoc content or `=begin`/`=end` comment markers.
+    fn reindentable(&self, bytes: &[u8], offset: usize) -> bool {
+        let line = &bytes[offset..];
+        let blank = line
+            .iter()
+            .take_while(|&&b| b != b'\n')
+            .all(|b| b.is_ascii_whitespace());
+        if blank || line.starts_with(b"=") {
+            return false;
+        }
+        self.code_map.is_not_string(offset)
+            || self
+                .squiggly_heredocs
+                .iter()
+                .any(|&(start, end)| start <= offset && offset < end)
+    }
+
+    fn correction(&self, start: usize, end: usize, replacement: String) -> Correction {
+        Correction {
+            start,
+            end,
+            replacement,
+            cop_name: "Style/ClassAndModuleChildren",
+            cop_index: 0,
+        }
+    }
+}
+
+/// Constant names of a compact definition (`Foo::Bar::Baz` → `[Foo, Bar, Baz]`).
+/// Returns `None` for cbase paths and dynamic namespaces (`self::Foo`).
+fn constant_segments(bytes: &[u8], node: &ruby_prism::Node<'_>) -> Option<Vec<String>> {
+    if node.as_constant_read_node().is_some() {
+        return Some(vec![node_source(bytes, node)]);
+    }
+    let path = node.as_constant_path_node()?;
+    let mut segments = constant_segments(bytes, &path.parent()?)?;
+    let name = path.name_loc();
+    segments
+        .push(String::from_utf8_lossy(&bytes[name.start_offset()..name.end_offset()]).into_owned());
+    Some(segments)
+}
+
+/// True when the leftmost segment of a constant path is cbase (`::Foo::Bar`).
+fn constant_root_is_cbase(node: &ruby_prism::Node<'_>) -> bool {
+    let mut current = node.as_constant_path_node();
+    while let Some(path) = current {
+        match path.parent() {
+            None => return true,
+            Some(parent) => current = parent.as_constant_path_node(),
+        }
+    }
+    false
+}
+
+/// The sole class/module statement of a body, if any.
+fn single_child_definition<'pr>(body: &Option<ruby_prism::Node<'pr>>) -> Option<Definition<'pr>> {
+    let stmts = body.as_ref()?.as_statements_node()?;
+    let mut children = stmts.body().iter();
+    let child = children.next()?;
+    if children.next().is_some() {
+        return None;
+    }
+    Definition::from_node(&child)
+}
+
+fn node_source(bytes: &[u8], node: &ruby_prism::Node<'_>) -> String {
+    let loc = node.location();
+    String::from_utf8_lossy(&bytes[loc.start_offset()..loc.end_offset()]).into_owned()
+}
+
+fn line_start(bytes: &[u8], offset: usize) -> usize {
+    bytes[..offset]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |pos| pos + 1)
+}
+
+/// True when only indentation precedes `offset` on its line.
+fn starts_line(bytes: &[u8], offset: usize) -> bool {
+    bytes[line_start(bytes, offset)..offset]
+        .iter()
+        .all(|&b| b == b' ' || b == b'\t')
+}
+
+/// True when the rest of the line after `offset` is blank or a comment.
+fn only_comment_follows(bytes: &[u8], offset: usize) -> bool {
+    bytes[offset..]
+        .iter()
+        .take_while(|&&b| b != b'\n')
+        .find(|&&b| b != b' ' && b != b'\t')
+        .is_none_or(|&b| b == b'#')
+}
+
+/// Byte ranges of the lines in `start..end`, each including its newline.
+fn lines_in(bytes: &[u8], start: usize, end: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut pos = start;
+    std::iter::from_fn(move || {
+        if pos >= end {
+            return None;
+        }
+        let line_end = bytes[pos..end]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(end, |i| pos + i + 1);
+        let range = (pos, line_end);
+        pos = line_end;
+        Some(range)
+    })
+}
+
+/// Collects the ranges of `<<~` heredoc bodies, including the terminator line.
+struct SquigglyHeredocCollector {
+    ranges: Vec<(usize, usize)>,
+}
+
+impl SquigglyHeredocCollector {
+    fn record(
+        &mut self,
+        opening: Option<ruby_prism::Location<'_>>,
+        content_start: usize,
+        closing: Option<ruby_prism::Location<'_>>,
+    ) {
+        if let (Some(opening), Some(closing)) = (opening, closing) {
+            if opening.as_slice().starts_with(b"<<~") {
+                self.ranges.push((content_start, closing.end_offset()));
+            }
+        }
+    }
+}
+
+impl<'pr> Visit<'pr> for SquigglyHeredocCollector {
+    fn visit_string_node(&mut self, node: &ruby_prism::StringNode<'pr>) {
+        self.record(
+            node.opening_loc(),
+            node.content_loc().start_offset(),
+            node.closing_loc(),
+        );
+    }
+
+    fn visit_interpolated_string_node(&mut self, node: &ruby_prism::InterpolatedStringNode<'pr>) {
+        if let Some(first) = node.parts().iter().next() {
+            self.record(
+                node.opening_loc(),
+                first.location().start_offset(),
+                node.closing_loc(),
+            );
+        }
+        ruby_prism::visit_interpolated_string_node(self, node);
+    }
+}
+
+/// Collects the constant paths of every class defined in a subtree.
+struct ClassNameCollector<'b> {
+    bytes: &'b [u8],
+    names: Vec<String>,
+}
+
+impl<'pr> Visit<'pr> for ClassNameCollector<'_> {
+    fn visit_class_node(&mut self, node: &ruby_prism::ClassNode<'pr>) {
+        self.names
+            .push(node_source(self.bytes, &node.constant_path()));
+        ruby_prism::visit_class_node(self, node);
     }
 }
 
@@ -208,6 +666,28 @@ fn is_class_or_module_node(node: &ruby_prism::Node<'_>) -> bool {
 }
 
 impl<'a> Visit<'a> for ChildrenVisitor<'a> {
+    fn visit_statements_node(&mut self, node: &ruby_prism::StatementsNode<'a>) {
+        if self.autocorrect {
+            let body: Vec<_> = node.body().iter().collect();
+            for pair in body.windows(2) {
+                let Some(def) = Definition::from_node(&pair[1]) else {
+                    continue;
+                };
+                if def.constant_path.as_constant_path_node().is_none() {
+                    continue;
+                }
+                let mut collector = ClassNameCollector {
+                    bytes: self.source.as_bytes(),
+                    names: Vec::new(),
+                };
+                collector.visit(&pair[0]);
+                self.left_sibling_classes
+                    .insert(def.keyword_start, collector.names);
+            }
+        }
+        ruby_prism::visit_statements_node(self, node);
+    }
+
     // Skip class/module definitions used as assignment values.
     // RuboCop crashes on `x = class Foo::Bar; end` patterns, producing 0 offenses.
     fn visit_local_variable_write_node(&mut self, node: &ruby_prism::LocalVariableWriteNode<'a>) {
@@ -288,9 +768,9 @@ impl<'a> Visit<'a> for ChildrenVisitor<'a> {
         }
 
         let style = self.style_for_class().to_string();
+        let def = Definition::from_class(node);
         let constant_path = node.constant_path();
         let is_compact = constant_path.as_constant_path_node().is_some();
-        let name_offset = constant_path.location().start_offset();
 
         // RuboCop: return if node.identifier.namespace&.cbase_type?
         // Skip single-name cbase paths (e.g., ::Foo) but NOT multi-segment (::Foo::Bar)
@@ -315,10 +795,9 @@ impl<'a> Visit<'a> for ChildrenVisitor<'a> {
         }
 
         if style == "nested" {
-            self.check_nested_style(is_compact, name_offset);
+            self.check_nested_style(is_compact, &def);
         } else if style == "compact" {
-            let body = node.body();
-            self.check_compact_style(&body, name_offset);
+            self.check_compact_style(&def);
         }
 
         // Visit children: set parent_is_class_or_module based on body count
@@ -341,9 +820,9 @@ impl<'a> Visit<'a> for ChildrenVisitor<'a> {
         }
 
         let style = self.style_for_module().to_string();
+        let def = Definition::from_module(node);
         let constant_path = node.constant_path();
         let is_compact = constant_path.as_constant_path_node().is_some();
-        let name_offset = constant_path.location().start_offset();
 
         // RuboCop: return if node.identifier.namespace&.cbase_type?
         if is_namespace_cbase(&constant_path) {
@@ -355,10 +834,9 @@ impl<'a> Visit<'a> for ChildrenVisitor<'a> {
         }
 
         if style == "nested" {
-            self.check_nested_style(is_compact, name_offset);
+            self.check_nested_style(is_compact, &def);
         } else if style == "compact" {
-            let body = node.body();
-            self.check_compact_style(&body, name_offset);
+            self.check_compact_style(&def);
         }
 
         // Visit children: set parent_is_class_or_module based on body count
@@ -377,6 +855,58 @@ mod tests {
         ClassAndModuleChildren,
         "cops/style/class_and_module_children"
     );
+    crate::cop_autocorrect_fixture_tests!(
+        ClassAndModuleChildren,
+        "cops/style/class_and_module_children"
+    );
+
+    fn compact_config() -> CopConfig {
+        CopConfig {
+            options: HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String("compact".into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn autocorrect_compact_folds_deep_nesting() {
+        let source = b"module Api\n  module V1\n    # Users endpoint\n    class UsersController < ApplicationController\n      def index\n        render plain: <<~TEXT\n          users\n        TEXT\n      end\n    end\n  end\nend\n";
+        let expected = b"# Users endpoint\nclass Api::V1::UsersController < ApplicationController\n  def index\n    render plain: <<~TEXT\n      users\n    TEXT\n  end\nend\n";
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &ClassAndModuleChildren,
+            source,
+            expected,
+            compact_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_compact_skips_cbase_child() {
+        let source = b"module Outer\n  class ::Absolute\n  end\nend\n";
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect_with_config(
+            &ClassAndModuleChildren,
+            source,
+            compact_config(),
+        );
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty());
+    }
+
+    #[test]
+    fn autocorrect_nested_keeps_plain_heredoc_content() {
+        let source = b"class Foo::Bar\n  SQL = <<SQL\nselect 1\nSQL\nend\n";
+        let expected = b"module Foo\n  class Bar\n    SQL = <<SQL\nselect 1\nSQL\n  end\nend\n";
+        crate::testutil::assert_cop_autocorrect(&ClassAndModuleChildren, source, expected);
+    }
+
+    #[test]
+    fn autocorrect_nested_uses_class_for_sibling_class_namespace() {
+        let source = b"class Foo\nend\nclass Foo::Bar\nend\n";
+        let expected = b"class Foo\nend\nclass Foo\n  class Bar\n  end\nend\n";
+        crate::testutil::assert_cop_autocorrect(&ClassAndModuleChildren, source, expected);
+    }
 
     #[test]
     fn config_compact_style_only_flags_nested() {
@@ -61,6 +61,11 @@ impl Cop for CollectionCompact {
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.4
+        if !config.ruby_version_at_least(2.4) {
+            return;
+        }
+
         let allowed_receivers = config
             .get_string_array("AllowedReceivers")
             .unwrap_or_default();
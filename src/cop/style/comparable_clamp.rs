@@ -26,10 +26,15 @@ impl Cop for ComparableClamp {
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.4
+        if !config.ruby_version_at_least(2.4) {
+            return;
+        }
+
         // Check for array min/max pattern: [[a, b].max, c].min and variants
         if let Some(call) = node.as_call_node() {
             if check_array_min_max(&call) {
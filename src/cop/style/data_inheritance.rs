@@ -26,10 +26,15 @@ impl Cop for DataInheritance {
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 3.2
+        if !config.ruby_version_at_least(3.2) {
+            return;
+        }
+
         let class_node = match node.as_class_node() {
             Some(c) => c,
             None => return,
@@ -84,5 +89,31 @@ fn is_data_const(node: &ruby_prism::Node<'_>) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    crate::cop_fixture_tests!(DataInheritance, "cops/style/data_inheritance");
+
+    fn ruby32_config() -> CopConfig {
+        let mut config = CopConfig::default();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::Number::from(3.2)),
+        );
+        config
+    }
+
+    #[test]
+    fn offense_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &DataInheritance,
+            include_bytes!("../../../tests/fixtures/cops/style/data_inheritance/offense.rb"),
+            ruby32_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &DataInheritance,
+            include_bytes!("../../../tests/fixtures/cops/style/data_inheritance/no_offense.rb"),
+            ruby32_config(),
+        );
+    }
 }
@@ -1,5 +1,8 @@
 use ruby_prism::Visit;
 
+use crate::cop::shared::documentation_comment::{
+    is_annotation_or_directive, is_emacs_style_magic_comment, nodoc_comment, trim_bytes,
+};
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
@@ -130,6 +133,14 @@ use crate::parse::source::SourceFile;
 /// whether it counts as documentation.  Lines with expressions (e.g., `module A # comment`) are NOT
 /// included because their AST nodes capture the comment in `ast_with_comments`, preventing it from
 /// reaching the next class/module node.
+///
+/// ## Outer-module `:nodoc:`
+///
+/// RuboCop's `nodoc_self_or_outer_module?` also exempts a compact definition such as
+/// `class Foo::Bar` when a `module Foo` in the same file is marked `:nodoc:`. Modules carrying
+/// the marker are collected up front and matched against the compact definition's namespace.
+/// Comment classification and `:nodoc:` parsing live in `shared::documentation_comment` so
+/// `Style/DocumentationMethod` can use the same rules.
 pub struct Documentation;
 
 /// Extract the short (unqualified) name from a constant node.
@@ -293,23 +304,9 @@ fn check_nodoc(
     let lines: Vec<&[u8]> = source.lines().collect();
     if let Some(line) = lines.get(line_num - 1) {
         let line_str = String::from_utf8_lossy(line);
-        // Look for #:nodoc: or # :nodoc: (with optional spaces)
-        if let Some(pos) = line_str.find("#") {
-            let comment = &line_str[pos..];
-            let comment_text = comment
-                .strip_prefix('#')
-                .unwrap_or(comment)
-                .trim_start_matches([' ', '\t']);
-            if let Some(after_nodoc) = comment_text.strip_prefix(":nodoc:") {
-                // RuboCop's regex: /^#\s*:nodoc:\s+all\s*$/
-                // Requires whitespace between :nodoc: and 'all', and 'all' must be
-                // at the end of the comment (no trailing code like `::Plugin`).
-                let has_space = after_nodoc.starts_with(' ') || after_nodoc.starts_with('\t');
-                let has_all = has_space && {
-                    let trimmed = after_nodoc.trim_start();
-                    trimmed.starts_with("all") && trimmed[3..].trim().is_empty()
-                };
-                return (true, has_all);
+        if let Some(pos) = line_str.find('#') {
+            if let Some(all) = nodoc_comment(&line_str[pos..]) {
+                return (true, all);
             }
         }
     }
@@ -457,158 +454,6 @@ fn has_documentation_comment_in_context(
     found_doc_comment
 }
 
-/// Check if a comment line is a magic/annotation/directive comment that doesn't count
-/// as documentation. These include:
-/// - `# frozen_string_literal: true`
-/// - `# encoding: ...`
-/// - `# rubocop:disable ...`
-/// - `# TODO: ...`, `# FIXME: ...`, etc.
-pub(crate) fn is_annotation_or_directive(comment: &str) -> bool {
-    let text = comment.trim_start_matches('#').trim();
-
-    // Shebang / interpreter directive comments do not count as documentation.
-    // Must start with `!/` to match actual shebangs like `#!/usr/bin/env ruby`,
-    // not comments like `# !deny policy entry`.
-    if text.starts_with("!/") {
-        return true;
-    }
-
-    if is_interpreter_directive_comment(text) {
-        return true;
-    }
-    // RuboCop directives
-    if text.starts_with("rubocop:") {
-        return true;
-    }
-
-    if is_annotation_comment(text) {
-        return true;
-    }
-
-    false
-}
-
-pub fn is_annotation_comment(text: &str) -> bool {
-    const DEFAULT_ANNOTATION_KEYWORDS: &[&str] =
-        &["TODO", "FIXME", "OPTIMIZE", "HACK", "REVIEW", "NOTE"];
-
-    for keyword in DEFAULT_ANNOTATION_KEYWORDS {
-        if !text
-            .get(..keyword.len())
-            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(keyword))
-        {
-            continue;
-        }
-
-        let keyword_text = &text[..keyword.len()];
-        let mut rest = &text[keyword.len()..];
-
-        if let Some(next_byte) = rest.as_bytes().first() {
-            if next_byte.is_ascii_alphanumeric() || *next_byte == b'_' {
-                continue;
-            }
-        }
-
-        let has_colon = {
-            let trimmed = rest.trim_start();
-            if let Some(after_colon) = trimmed.strip_prefix(':') {
-                rest = after_colon;
-                true
-            } else {
-                false
-            }
-        };
-
-        let has_space = if !rest.is_empty() && rest.as_bytes()[0].is_ascii_whitespace() {
-            rest = rest.trim_start();
-            true
-        } else {
-            false
-        };
-
-        let has_note = !rest.is_empty();
-
-        if !has_colon && !has_space {
-            continue;
-        }
-
-        if just_keyword_of_sentence(keyword_text, has_colon, has_space, has_note) {
-            continue;
-        }
-
-        return true;
-    }
-
-    false
-}
-
-fn just_keyword_of_sentence(
-    keyword_text: &str,
-    has_colon: bool,
-    has_space: bool,
-    has_note: bool,
-) -> bool {
-    if has_colon || !has_space || !has_note {
-        return false;
-    }
-
-    let mut chars = keyword_text.chars();
-    match chars.next() {
-        Some(c) if c.is_ascii_uppercase() => {}
-        _ => return false,
-    }
-
-    chars.all(|c| c.is_ascii_lowercase())
-}
-
-fn is_interpreter_directive_comment(text: &str) -> bool {
-    has_magic_comment_key(text, "frozen_string_literal")
-        || has_magic_comment_key(text, "shareable_constant_value")
-        || has_magic_comment_key(text, "warn_indent")
-        || has_magic_comment_key(text, "coding")
-        || has_magic_comment_key(text, "encoding")
-}
-
-fn is_emacs_style_magic_comment(text: &str) -> bool {
-    wrapped_magic_comment_inner(text).is_some()
-}
-
-fn wrapped_magic_comment_inner(text: &str) -> Option<&str> {
-    let text = text.trim();
-    if text.starts_with("-*-") && text.ends_with("-*-") {
-        return Some(
-            text.trim_start_matches("-*-")
-                .trim_end_matches("-*-")
-                .trim(),
-        );
-    }
-    if text.starts_with("~*~") && text.ends_with("~*~") {
-        return Some(
-            text.trim_start_matches("~*~")
-                .trim_end_matches("~*~")
-                .trim(),
-        );
-    }
-    None
-}
-
-fn has_magic_comment_key(text: &str, key: &str) -> bool {
-    text.strip_prefix(key)
-        .is_some_and(|rest| rest.trim_start().starts_with(':'))
-}
-
-pub(crate) fn trim_bytes(line: &[u8]) -> &[u8] {
-    let start = line
-        .iter()
-        .position(|&b| b != b' ' && b != b'\t')
-        .unwrap_or(line.len());
-    let end = line
-        .iter()
-        .rposition(|&b| b != b' ' && b != b'\t' && b != b'\r' && b != b'\n')
-        .map_or(start, |e| e + 1);
-    if end > start { &line[start..end] } else { &[] }
-}
-
 impl Cop for Documentation {
     fn name(&self) -> &'static str {
         "Style/Documentation"
@@ -631,11 +476,18 @@ impl Cop for Documentation {
             .get_string_array("AllowedConstants")
             .unwrap_or_default();
 
+        let mut nodoc_modules = NodocModuleCollector {
+            source,
+            names: Vec::new(),
+        };
+        nodoc_modules.visit(&parse_result.node());
+
         let mut visitor = DocumentationVisitor {
             cop: self,
             source,
             diagnostics: Vec::new(),
             allowed_constants,
+            nodoc_modules: nodoc_modules.names,
             nodoc_all_depth: 0,
             rescue_begin_first_child: false,
         };
@@ -649,6 +501,8 @@ struct DocumentationVisitor<'a> {
     source: &'a SourceFile,
     diagnostics: Vec<Diagnostic>,
     allowed_constants: Vec<String>,
+    /// Identifiers of modules marked `:nodoc:` anywhere in the file.
+    nodoc_modules: Vec<String>,
     /// Depth counter: >0 means we're inside a `:nodoc: all` parent
     nodoc_all_depth: usize,
     /// True when visiting the first direct child of a `begin ... rescue/ensure/else`.
@@ -658,6 +512,41 @@ struct DocumentationVisitor<'a> {
     rescue_begin_first_child: bool,
 }
 
+impl DocumentationVisitor<'_> {
+    /// RuboCop's `nodoc_self_or_outer_module?` second half: a compact
+    /// definition like `class Foo::Bar` is exempt when a `module Foo` in the
+    /// same file is marked `:nodoc:`.
+    fn outer_module_is_nodoc(&self, path: &ruby_prism::Node<'_>) -> bool {
+        let Some(namespace) = path.as_constant_path_node().and_then(|cp| cp.parent()) else {
+            return false;
+        };
+        let namespace = namespace.location().as_slice();
+        self.nodoc_modules
+            .iter()
+            .any(|name| name.as_bytes() == namespace)
+    }
+}
+
+/// Collects the identifiers of modules whose keyword line carries `:nodoc:`.
+struct NodocModuleCollector<'a> {
+    source: &'a SourceFile,
+    names: Vec<String>,
+}
+
+impl<'pr> Visit<'pr> for NodocModuleCollector<'_> {
+    fn visit_module_node(&mut self, node: &ruby_prism::ModuleNode<'pr>) {
+        let path = node.constant_path();
+        let start = node.module_keyword_loc().start_offset();
+        let allow_inline_nodoc =
+            !line_has_code_before_offset(self.source, start) && !has_cbase_prefix(&path);
+        if check_nodoc(self.source, start, allow_inline_nodoc).0 {
+            self.names
+                .push(String::from_utf8_lossy(path.location().as_slice()).into_owned());
+        }
+        ruby_prism::visit_module_node(self, node);
+    }
+}
+
 impl<'pr> Visit<'pr> for DocumentationVisitor<'_> {
     fn visit_class_node(&mut self, node: &ruby_prism::ClassNode<'pr>) {
         let name = extract_short_name(&node.constant_path());
@@ -683,6 +572,7 @@ impl<'pr> Visit<'pr> for DocumentationVisitor<'_> {
         if self.nodoc_all_depth == 0
             && !self.allowed_constants.iter().any(|c| c == &name)
             && !has_nodoc
+            && !self.outer_module_is_nodoc(&node.constant_path())
             && !is_namespace_only(&node.body(), true)
             && !is_include_only(&node.body())
             && !has_documentation_comment_in_context(self.source, start, allow_preceding_comment)
@@ -730,6 +620,7 @@ impl<'pr> Visit<'pr> for DocumentationVisitor<'_> {
         if self.nodoc_all_depth == 0
             && !self.allowed_constants.iter().any(|c| c == &name)
             && !has_nodoc
+            && !self.outer_module_is_nodoc(&node.constant_path())
             && !is_namespace_only(&node.body(), false)
             && !is_include_only(&node.body())
             && !has_documentation_comment_in_context(self.source, start, allow_preceding_comment)
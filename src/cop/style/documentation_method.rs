@@ -1,5 +1,5 @@
 use crate::cop::shared::access_modifier_predicates;
-use crate::cop::style::documentation::trim_bytes;
+use crate::cop::shared::documentation_comment::trim_bytes;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
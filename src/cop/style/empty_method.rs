@@ -14,6 +14,15 @@ use crate::parse::source::SourceFile;
 /// only body lines.
 ///
 /// FN=0: no missed detections were reported by the corpus oracle for this run.
+///
+/// ## Autocorrect
+///
+/// Mirrors RuboCop: the definition is rebuilt as `def name(args); end`
+/// (compact) or with `end` on its own line aligned with `def` (expanded). The
+/// parameters are joined with `, `, a receiver is written as `recv.`, and
+/// empty parentheses are dropped. A compact correction longer than
+/// `Layout/LineLength`'s `Max` is skipped. Methods with comments anywhere in
+/// their span are never flagged, so no comment is ever removed.
 pub struct EmptyMethod;
 
 fn method_has_comment(
@@ -28,11 +37,64 @@ fn method_has_comment(
     })
 }
 
+/// RuboCop's `corrected(node)`: the definition in the enforced style.
+fn corrected_definition(
+    source: &SourceFile,
+    def_node: &ruby_prism::DefNode<'_>,
+    compact: bool,
+) -> String {
+    let bytes = source.as_bytes();
+    let slice = |loc: ruby_prism::Location<'_>| {
+        String::from_utf8_lossy(&bytes[loc.start_offset()..loc.end_offset()]).into_owned()
+    };
+    let mut signature = String::new();
+    if let Some(receiver) = def_node.receiver() {
+        signature.push_str(&slice(receiver.location()));
+        signature.push('.');
+    }
+    signature.push_str(&slice(def_node.name_loc()));
+    if let Some(params) = def_node.parameters() {
+        let mut locs: Vec<ruby_prism::Location<'_>> = params
+            .requireds()
+            .iter()
+            .chain(params.optionals().iter())
+            .chain(params.rest())
+            .chain(params.posts().iter())
+            .chain(params.keywords().iter())
+            .chain(params.keyword_rest())
+            .map(|param| param.location())
+            .collect();
+        if let Some(block) = params.block() {
+            locs.push(block.location());
+        }
+        locs.sort_by_key(|loc| loc.start_offset());
+        let args: Vec<String> = locs.into_iter().map(slice).collect();
+        if !args.is_empty() {
+            if def_node.lparen_loc().is_some() {
+                signature.push_str(&format!("({})", args.join(", ")));
+            } else {
+                signature.push_str(&format!(" {}", args.join(", ")));
+            }
+        }
+    }
+    let joint = if compact {
+        "; ".to_string()
+    } else {
+        let (_, column) = source.offset_to_line_col(def_node.location().start_offset());
+        format!("\n{}", " ".repeat(column))
+    };
+    format!("def {signature}{joint}end")
+}
+
 impl Cop for EmptyMethod {
     fn name(&self) -> &'static str {
         "Style/EmptyMethod"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[DEF_NODE, STATEMENTS_NODE]
     }
@@ -44,7 +106,7 @@ impl Cop for EmptyMethod {
         parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let enforced_style = config.get_str("EnforcedStyle", "compact");
         let def_node = match node.as_def_node() {
@@ -82,27 +144,32 @@ impl Cop for EmptyMethod {
             return;
         }
 
-        match enforced_style {
-            "compact" if !is_single_line => {
-                let (line, column) = source.offset_to_line_col(def_loc.start_offset());
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Put empty method definitions on a single line.".to_string(),
-                ));
-            }
-            "expanded" if is_single_line => {
-                let (line, column) = source.offset_to_line_col(def_loc.start_offset());
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Put the `end` on the next line.".to_string(),
-                ));
+        let message = match enforced_style {
+            "compact" if !is_single_line => "Put empty method definitions on a single line.",
+            "expanded" if is_single_line => "Put the `end` on the next line.",
+            _ => return,
+        };
+        let (line, column) = source.offset_to_line_col(def_loc.start_offset());
+        let mut diag = self.diagnostic(source, line, column, message.to_string());
+        if let Some(corrections) = corrections {
+            let compact = enforced_style == "compact";
+            let replacement = corrected_definition(source, &def_node, compact);
+            let too_long = compact
+                && config.get_bool("LineLengthEnabled", true)
+                && replacement.chars().count() > config.get_usize("MaxLineLength", 120);
+            if !too_long {
+                let loc = def_node.location();
+                corrections.push(crate::correction::Correction {
+                    start: loc.start_offset(),
+                    end: loc.end_offset(),
+                    replacement,
+                    cop_name: self.name(),
+                    cop_index: 0,
+                });
+                diag.corrected = true;
             }
-            _ => {}
         }
+        diagnostics.push(diag);
     }
 }
 
@@ -112,6 +179,49 @@ mod tests {
     use crate::testutil::{run_cop_full, run_cop_full_with_config};
 
     crate::cop_fixture_tests!(EmptyMethod, "cops/style/empty_method");
+    crate::cop_autocorrect_fixture_tests!(EmptyMethod, "cops/style/empty_method");
+
+    #[test]
+    fn expanded_fixtures() {
+        let yaml = "EnforcedStyle: expanded\n";
+        let offense =
+            include_bytes!("../../../tests/fixtures/cops/style/empty_method/offense.expanded.rb");
+        crate::testutil::assert_cop_offenses_with_yaml(&EmptyMethod, yaml, offense);
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &EmptyMethod,
+            yaml,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/empty_method/no_offense.expanded.rb"
+            ),
+        );
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &EmptyMethod,
+            offense,
+            include_bytes!("../../../tests/fixtures/cops/style/empty_method/corrected.expanded.rb"),
+            crate::testutil::cop_config_from_yaml(yaml),
+        );
+        crate::testutil::assert_cop_autocorrect_idempotent_with_config(
+            &EmptyMethod,
+            offense,
+            crate::testutil::cop_config_from_yaml(yaml),
+        );
+    }
+
+    #[test]
+    fn compact_correction_respects_max_line_length() {
+        let source = b"def configure(first_option, second_option)\nend\n";
+        let config = crate::testutil::cop_config_from_yaml("MaxLineLength: 40\n");
+        let (diags, corrections) =
+            crate::testutil::run_cop_autocorrect_with_config(&EmptyMethod, source, config);
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty(), "the compact form would be too long");
+
+        let config =
+            crate::testutil::cop_config_from_yaml("MaxLineLength: 40\nLineLengthEnabled: false\n");
+        let (_, corrections) =
+            crate::testutil::run_cop_autocorrect_with_config(&EmptyMethod, source, config);
+        assert_eq!(corrections.len(), 1);
+    }
 
     #[test]
     fn expanded_style_flags_single_line() {
@@ -81,12 +81,7 @@ impl Cop for EndlessMethod {
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // RuboCop: minimum_target_ruby_version 3.0
-        let ruby_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-            .unwrap_or(2.7);
-        if ruby_version < 3.0 {
+        if !config.ruby_version_at_least(3.0) {
             return;
         }
 
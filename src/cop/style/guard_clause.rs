@@ -74,6 +74,12 @@ use ruby_prism::Visit;
 ///     `MaxLineLength`, RuboCop suppresses it when the opposite branch has no
 ///     statements. Previously nitrocop always emitted the multi-line rewrite,
 ///     causing long false positives inside `case`/`when` and similar contexts.
+///
+/// `AllowConsecutiveConditionals` accepts a conditional that directly follows
+/// another `if`/`unless` statement, like RuboCop's `consecutive_conditionals?`.
+/// The single-line rewrite is measured against the `MaxLineLength` and
+/// `LineLengthEnabled` values injected from `Layout/LineLength`; with that cop
+/// disabled, every rewrite fits.
 pub struct GuardClause;
 
 const GUARD_METHODS: &[&[u8]] = &[b"raise", b"fail"];
@@ -93,14 +99,20 @@ impl Cop for GuardClause {
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let min_body_length = config.get_usize("MinBodyLength", 1);
-        let _allow_consecutive = config.get_bool("AllowConsecutiveConditionals", false);
+        let allow_consecutive_conditionals = config.get_bool("AllowConsecutiveConditionals", false);
         let max_line_length = config.get_usize("MaxLineLength", 120);
+        let line_length_enabled = config.get_bool("LineLengthEnabled", true);
         let mut visitor = GuardClauseVisitor {
             cop: self,
             source,
             diagnostics: Vec::new(),
             min_body_length,
-            max_line_length,
+            allow_consecutive_conditionals,
+            max_line_length: if line_length_enabled {
+                max_line_length
+            } else {
+                0
+            },
             ancestors: Vec::new(),
         };
         visitor.visit(&parse_result.node());
@@ -113,6 +125,8 @@ struct GuardClauseVisitor<'a, 'src, 'pr> {
     source: &'src SourceFile,
     diagnostics: Vec<Diagnostic>,
     min_body_length: usize,
+    allow_consecutive_conditionals: bool,
+    /// 0 when `Layout/LineLength` is disabled (no limit).
     max_line_length: usize,
     ancestors: Vec<ruby_prism::Node<'pr>>,
 }
@@ -121,23 +135,62 @@ impl<'a, 'src, 'pr> GuardClauseVisitor<'a, 'src, 'pr> {
     /// Check if the ending of a method body is an if/unless that could be a guard clause.
     fn check_ending_body(&mut self, body: &ruby_prism::Node<'_>) {
         if let Some(if_node) = body.as_if_node() {
-            self.check_ending_if_node(&if_node);
+            self.check_ending_if_node(&if_node, false);
         } else if let Some(unless_node) = body.as_unless_node() {
-            self.check_ending_unless_node(&unless_node);
+            self.check_ending_unless_node(&unless_node, false);
         } else if let Some(stmts) = body.as_statements_node() {
             // Body is a StatementsNode (begin block) - check last statement
-            let body_nodes: Vec<_> = stmts.body().iter().collect();
-            if let Some(last) = body_nodes.last() {
-                if let Some(if_node) = last.as_if_node() {
-                    self.check_ending_if_node(&if_node);
-                } else if let Some(unless_node) = last.as_unless_node() {
-                    self.check_ending_unless_node(&unless_node);
-                }
+            self.check_last_statement(&stmts);
+        }
+    }
+
+    /// Check the last statement of a branch or body for an ending guard clause.
+    fn check_last_statement(&mut self, stmts: &ruby_prism::StatementsNode<'_>) {
+        let body_nodes: Vec<_> = stmts.body().iter().collect();
+        let Some(last) = body_nodes.last() else {
+            return;
+        };
+        let follows_conditional = body_nodes
+            .len()
+            .checked_sub(2)
+            .is_some_and(|idx| is_conditional(&body_nodes[idx]));
+        if let Some(if_node) = last.as_if_node() {
+            self.check_ending_if_node(&if_node, follows_conditional);
+        } else if let Some(unless_node) = last.as_unless_node() {
+            self.check_ending_unless_node(&unless_node, follows_conditional);
+        }
+    }
+
+    /// Whether `AllowConsecutiveConditionals` accepts a conditional that
+    /// follows another `if`/`unless` sibling.
+    fn allowed_consecutive(&self, follows_conditional: bool) -> bool {
+        self.allow_consecutive_conditionals && follows_conditional
+    }
+
+    /// Whether the node being visited directly follows an `if`/`unless`
+    /// statement in its parent's statement list.
+    fn visited_node_follows_conditional(&self, node: &ruby_prism::Node<'_>) -> bool {
+        let Some(stmts) = self
+            .immediate_parent()
+            .and_then(|parent| parent.as_statements_node())
+        else {
+            return false;
+        };
+        let loc = node.location();
+        let mut previous_is_conditional = false;
+        for child in stmts.body().iter() {
+            let child_loc = child.location();
+            if (child_loc.start_offset(), child_loc.end_offset())
+                == (loc.start_offset(), loc.end_offset())
+            {
+                return previous_is_conditional;
             }
+            previous_is_conditional = is_conditional(&child);
         }
+        false
     }
 
-    fn check_ending_if_node(&mut self, node: &ruby_prism::IfNode<'_>) {
+    fn check_ending_if_node(&mut self, node: &ruby_prism::IfNode<'_>, follows_conditional: bool) {
         // if_keyword_loc() is None for ternary
         let if_keyword_loc = match node.if_keyword_loc() {
             Some(loc) => loc,
@@ -183,6 +236,10 @@ impl<'a, 'src, 'pr> GuardClauseVisitor<'a, 'src, 'pr> {
             return;
         }
 
+        if self.allowed_consecutive(follows_conditional) {
+            return;
+        }
+
         let condition_src = self.node_source(&predicate);
         let inline_example = format!("return unless {}", condition_src);
         let (line, column) = self
@@ -215,14 +272,7 @@ impl<'a, 'src, 'pr> GuardClauseVisitor<'a, 'src, 'pr> {
 
         // Recurse into the if-branch to check its ending body (matches RuboCop behavior)
         if let Some(body_stmts) = node.statements() {
-            let body_nodes: Vec<_> = body_stmts.body().iter().collect();
-            if let Some(last) = body_nodes.last() {
-                if let Some(inner_if) = last.as_if_node() {
-                    self.check_ending_if_node(&inner_if);
-                } else if let Some(inner_unless) = last.as_unless_node() {
-                    self.check_ending_unless_node(&inner_unless);
-                }
-            }
+            self.check_last_statement(&body_stmts);
         }
     }
 
@@ -255,6 +305,10 @@ impl<'a, 'src, 'pr> GuardClauseVisitor<'a, 'src, 'pr> {
             return;
         }
 
+        if self.allowed_consecutive(self.visited_node_follows_conditional(&node.as_node())) {
+            return;
+        }
+
         let predicate = node.predicate();
         if self.condition_is_multiline(&predicate) {
             return;
@@ -300,7 +354,11 @@ impl<'a, 'src, 'pr> GuardClauseVisitor<'a, 'src, 'pr> {
         }
     }
 
-    fn check_ending_unless_node(&mut self, node: &ruby_prism::UnlessNode<'_>) {
+    fn check_ending_unless_node(
+        &mut self,
+        node: &ruby_prism::UnlessNode<'_>,
+        follows_conditional: bool,
+    ) {
         // Check for modifier form: in modifier unless, the node location starts
         // before the keyword (at the expression). If the node start != keyword start,
         // it's a modifier form.
@@ -338,6 +396,10 @@ impl<'a, 'src, 'pr> GuardClauseVisitor<'a, 'src, 'pr> {
             return;
         }
 
+        if self.allowed_consecutive(follows_conditional) {
+            return;
+        }
+
         let condition_src = self.node_source(&predicate);
         let inline_example = format!("return if {}", condition_src);
         let (line, column) = self.source.offset_to_line_col(keyword_loc.start_offset());
@@ -368,14 +430,7 @@ impl<'a, 'src, 'pr> GuardClauseVisitor<'a, 'src, 'pr> {
 
         // Recurse into the unless-branch to check its ending body (matches RuboCop behavior)
         if let Some(body_stmts) = node.statements() {
-            let body_nodes: Vec<_> = body_stmts.body().iter().collect();
-            if let Some(last) = body_nodes.last() {
-                if let Some(inner_if) = last.as_if_node() {
-                    self.check_ending_if_node(&inner_if);
-                } else if let Some(inner_unless) = last.as_unless_node() {
-                    self.check_ending_unless_node(&inner_unless);
-                }
-            }
+            self.check_last_statement(&body_stmts);
         }
     }
 
@@ -396,6 +451,10 @@ impl<'a, 'src, 'pr> GuardClauseVisitor<'a, 'src, 'pr> {
             return;
         }
 
+        if self.allowed_consecutive(self.visited_node_follows_conditional(&node.as_node())) {
+            return;
+        }
+
         let predicate = node.predicate();
         if self.condition_is_multiline(&predicate) {
             return;
@@ -554,8 +613,7 @@ impl<'a, 'src, 'pr> GuardClauseVisitor<'a, 'src, 'pr> {
         statements: Option<ruby_prism::StatementsNode<'_>>,
         has_else: bool,
     ) -> bool {
-        let total_len = column + example.len();
-        if total_len <= self.max_line_length {
+        if !self.too_long_for_single_line(column, example) {
             return false;
         }
         // Too long -- check if body is trivial
@@ -778,6 +836,11 @@ fn collect_parser_equivalent_lvar_read_names(
     }
 }
 
+/// Parser's `if_type?`: `if`, `unless`, modifier forms and ternaries.
+fn is_conditional(node: &ruby_prism::Node<'_>) -> bool {
+    node.as_if_node().is_some() || node.as_unless_node().is_some()
+}
+
 fn is_guard_stmt(node: &ruby_prism::Node<'_>) -> bool {
     if let Some(call) = node.as_call_node() {
         let name = call.name().as_slice();
@@ -902,4 +965,59 @@ impl<'a, 'src, 'pr> Visit<'pr> for GuardClauseVisitor<'a, 'src, 'pr> {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(GuardClause, "cops/style/guard_clause");
+
+    use crate::cop::CopConfig;
+    use crate::testutil::run_cop_full_with_config;
+    use std::collections::HashMap;
+
+    fn config(options: &[(&str, serde_yml::Value)]) -> CopConfig {
+        CopConfig {
+            options: options
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect::<HashMap<_, _>>(),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn allow_consecutive_conditionals() {
+        let ending = b"def test\n  if foo\n    work\n  end\n\n  if bar\n    work\n  end\nend\n";
+        let branch =
+            b"def test\n  raise if foo\n  if bar\n    raise 'x'\n  else\n    work\n  end\nend\n";
+        for source in [&ending[..], &branch[..]] {
+            let diags = run_cop_full_with_config(&GuardClause, source, config(&[]));
+            assert_eq!(diags.len(), 1, "{diags:?}");
+            let allowed = config(&[("AllowConsecutiveConditionals", serde_yml::Value::Bool(true))]);
+            assert!(run_cop_full_with_config(&GuardClause, source, allowed).is_empty());
+        }
+    }
+
+    #[test]
+    fn min_body_length() {
+        let source = b"def test\n  if something\n    work\n  end\nend\n";
+        let min_two = config(&[(
+            "MinBodyLength",
+            serde_yml::Value::Number(serde_yml::Number::from(2)),
+        )]);
+        assert!(run_cop_full_with_config(&GuardClause, source, min_two).is_empty());
+    }
+
+    #[test]
+    fn disabled_line_length_never_suppresses_long_guards() {
+        let source = b"def test\n  if something_really_long_condition\n    work\n  end\nend\n";
+        let max = ("MaxLineLength", serde_yml::Value::Number(20.into()));
+        // Too long for one line with a trivial body: accepted.
+        let diags = run_cop_full_with_config(&GuardClause, source, config(&[max.clone()]));
+        assert!(diags.is_empty(), "{diags:?}");
+
+        let disabled = config(&[max, ("LineLengthEnabled", serde_yml::Value::Bool(false))]);
+        let diags = run_cop_full_with_config(&GuardClause, source, disabled);
+        assert_eq!(diags.len(), 1);
+        assert!(
+            diags[0]
+                .message
+                .contains("`return unless something_really_long_condition`")
+        );
+    }
 }
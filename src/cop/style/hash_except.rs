@@ -30,10 +30,15 @@ impl Cop for HashExcept {
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 3.0
+        if !config.ruby_version_at_least(3.0) {
+            return;
+        }
+
         hash_subset::check_hash_subset(self, HashSubsetMode::Except, source, node, diagnostics);
     }
 }
@@ -41,5 +46,31 @@ impl Cop for HashExcept {
 #[cfg(test)]
 mod tests {
     use super::*;
-    crate::cop_fixture_tests!(HashExcept, "cops/style/hash_except");
+
+    fn ruby30_config() -> CopConfig {
+        let mut config = CopConfig::default();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::Number::from(3.0)),
+        );
+        config
+    }
+
+    #[test]
+    fn offense_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &HashExcept,
+            include_bytes!("../../../tests/fixtures/cops/style/hash_except/offense.rb"),
+            ruby30_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &HashExcept,
+            include_bytes!("../../../tests/fixtures/cops/style/hash_except/no_offense.rb"),
+            ruby30_config(),
+        );
+    }
 }
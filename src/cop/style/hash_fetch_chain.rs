@@ -69,10 +69,15 @@ impl Cop for HashFetchChain {
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.3
+        if !config.ruby_version_at_least(2.3) {
+            return;
+        }
+
         let call = match node.as_call_node() {
             Some(c) => c,
             None => return,
@@ -60,11 +60,12 @@ impl Cop for HashSyntax {
         let use_rockets_symbol_vals = config.get_bool("UseHashRocketsWithSymbolValues", false);
         let prefer_rockets_nonalnum =
             config.get_bool("PreferHashRocketsForNonAlnumEndingSymbols", false);
-        let target_ruby_version = target_ruby_version(config);
+        let target_ruby_version = config.target_ruby_version();
 
         // EnforcedShorthandSyntax: check Ruby 3.1 hash value omission syntax
-        // This is checked separately from the main EnforcedStyle
-        if enforced_shorthand != "either" {
+        // This is checked separately from the main EnforcedStyle, and only
+        // when the target Ruby has the syntax (RuboCop's HashShorthandSyntax)
+        if enforced_shorthand != "either" && target_ruby_version >= 3.1 {
             let mut shorthand_diags = Vec::new();
             check_shorthand_syntax(
                 self,
@@ -346,18 +347,6 @@ fn is_acceptable_19_symbol(
     false
 }
 
-fn target_ruby_version(config: &CopConfig) -> f64 {
-    config
-        .options
-        .get("TargetRubyVersion")
-        .and_then(|value| {
-            value
-                .as_f64()
-                .or_else(|| value.as_u64().map(|value| value as f64))
-        })
-        .unwrap_or(2.7)
-}
-
 /// Check if a symbol's unescaped name is a simple Ruby identifier.
 /// Valid: `foo`, `foo_bar`, `foo?`, `foo!`
 /// Invalid: `foo bar`, `123`, `foo=`, empty
@@ -440,10 +429,16 @@ mod tests {
     #[test]
     fn shorthand_never_flags_omission() {
         let config = CopConfig {
-            options: HashMap::from([(
-                "EnforcedShorthandSyntax".into(),
-                serde_yml::Value::String("never".into()),
-            )]),
+            options: HashMap::from([
+                (
+                    "EnforcedShorthandSyntax".into(),
+                    serde_yml::Value::String("never".into()),
+                ),
+                (
+                    "TargetRubyVersion".into(),
+                    serde_yml::Value::Number(serde_yml::value::Number::from(3.1)),
+                ),
+            ]),
             ..CopConfig::default()
         };
         // Ruby 3.1 hash value omission: `{x:}` (shorthand)
@@ -457,6 +452,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shorthand_requires_ruby_31() {
+        let config = crate::testutil::cop_config_from_yaml_with_targets(
+            "EnforcedShorthandSyntax: always\n",
+            Some(3.0),
+            None,
+        );
+        let source = b"x = 1; {x: x}\n";
+        let diags = run_cop_full_with_config(&HashSyntax, source, config);
+        assert!(
+            !diags.iter().any(|d| d.message.contains("hash value")),
+            "Shorthand syntax is not checked before Ruby 3.1"
+        );
+    }
+
     #[test]
     fn quoted_symbol_keys_require_ruby_22() {
         let config = CopConfig {
@@ -37,10 +37,15 @@ impl Cop for HashTransformKeys {
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.5
+        if !config.ruby_version_at_least(2.5) {
+            return;
+        }
+
         hash_transform_method::check_hash_transform(
             self,
             TransformMode::Keys,
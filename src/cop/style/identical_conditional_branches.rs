@@ -1687,6 +1687,7 @@ mod tests {
                 cop_name: "Lint/OtherCop".to_string(),
                 message: "Move `x` out of the conditional.".to_string(),
                 corrected: false,
+                end_column: None,
             },
             Diagnostic {
                 path: "test.rb".to_string(),
@@ -1698,6 +1699,7 @@ mod tests {
                 cop_name: "Style/IdenticalConditionalBranches".to_string(),
                 message: "Move `x` out of the conditional.".to_string(),
                 corrected: false,
+                end_column: None,
             },
             Diagnostic {
                 path: "test.rb".to_string(),
@@ -1709,6 +1711,7 @@ mod tests {
                 cop_name: "Style/IdenticalConditionalBranches".to_string(),
                 message: "Move `x` out of the conditional.".to_string(),
                 corrected: false,
+                end_column: None,
             },
             Diagnostic {
                 path: "test.rb".to_string(),
@@ -1720,6 +1723,7 @@ mod tests {
                 cop_name: "Style/IdenticalConditionalBranches".to_string(),
                 message: "Move `y` out of the conditional.".to_string(),
                 corrected: false,
+                end_column: None,
             },
         ];
 
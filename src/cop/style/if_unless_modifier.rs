@@ -1,5 +1,6 @@
 use crate::cop::shared::node_type::{IF_NODE, UNLESS_NODE};
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 use regex::Regex;
@@ -63,6 +64,17 @@ use ruby_prism::Visit;
 /// rejecting chained/operator continuations, then measuring the full rendered
 /// modifier line as RuboCop does: `code_before + expression + code_after`, with
 /// UTF-8 character counts instead of raw byte counts.
+///
+/// ## Autocorrect
+///
+/// A block `if`/`unless` becomes `body if cond`, parenthesized where the
+/// modifier would otherwise bind differently (assignments, `||`/`&&`
+/// operands), with any comment on the condition line kept after it. A
+/// modifier form that makes the line too long becomes a block with a
+/// two-space body, keeping a trailing comment on the body line; when only the
+/// comment overflows the line, the comment is moved above instead. Modifier
+/// forms whose condition assigns a local, or whose body holds a heredoc, are
+/// left alone.
 pub struct IfUnlessModifier;
 
 /// Check if a node (or any descendant) contains a heredoc.
@@ -318,6 +330,15 @@ fn single_line_direct_collection_context(
     false
 }
 
+/// Whether the code after `end` continues a `||`/`&&`/`or`/`and` expression.
+fn continues_boolean_operator(after_end: &[u8]) -> bool {
+    let trimmed = after_end.trim_ascii_start();
+    trimmed.starts_with(b"||")
+        || trimmed.starts_with(b"&&")
+        || trimmed.starts_with(b"or ")
+        || trimmed.starts_with(b"and ")
+}
+
 fn code_after_end_is_disallowed(after_end: &[u8]) -> bool {
     let trimmed = after_end
         .iter()
@@ -616,6 +637,92 @@ fn first_line_comment_text(
     Some(comment.to_string())
 }
 
+/// Replacement for a modifier `if`/`unless` that makes its line too long,
+/// mirroring RuboCop's `replacement_for_modifier_form`. Returns the replaced
+/// range and its text, or `None` when the conversion is skipped.
+///
+/// A trailing comment is moved onto its own line above when the line only
+/// overflows because of it; otherwise the statement is rewritten in block
+/// form and the comment stays on the body line. Conditions that assign a
+/// local (plainly or through a named capture) are skipped: in modifier form
+/// the body is parsed before the assignment, so moving the condition first
+/// can turn a method call in the body into a local variable read.
+fn block_form_correction(
+    source: &SourceFile,
+    node: &ruby_prism::Node<'_>,
+    body_node: &ruby_prism::Node<'_>,
+    predicate: &ruby_prism::Node<'_>,
+    keyword: &str,
+    config: &CopConfig,
+) -> Option<(usize, usize, String)> {
+    if condition_contains_lvasgn(predicate)
+        || condition_contains_named_capture(predicate)
+        || node_contains_heredoc(body_node)
+    {
+        return None;
+    }
+
+    let bytes = source.as_bytes();
+    let start = node.location().start_offset();
+    let end = node.location().end_offset();
+    let (line, _) = source.offset_to_line_col(start);
+    let line_start = source.line_start_offset(line);
+    let indent = &bytes[line_start..start];
+    if !indent.iter().all(|&b| b == b' ' || b == b'\t') {
+        return None;
+    }
+    let indent = String::from_utf8_lossy(indent);
+
+    let line_end = bytes[end..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |pos| end + pos);
+    let after = &bytes[end..line_end];
+    let after = after.strip_suffix(b"\r").unwrap_or(after);
+    let comment_start = after
+        .iter()
+        .position(|&b| b != b' ' && b != b'\t')
+        .map(|pos| end + pos);
+    let comment = match comment_start {
+        None => None,
+        Some(pos) if bytes[pos] == b'#' => {
+            let text = String::from_utf8_lossy(&bytes[pos..end + after.len()]);
+            Some((pos, text.trim_end().to_string()))
+        }
+        Some(_) => return None,
+    };
+
+    let node_src = String::from_utf8_lossy(&bytes[start..end]);
+    if let Some((comment_pos, comment)) = &comment {
+        let max_line_length = config.get_usize("MaxLineLength", 120);
+        let code_len = String::from_utf8_lossy(&bytes[line_start..*comment_pos])
+            .trim_end()
+            .chars()
+            .count();
+        if code_len <= max_line_length {
+            return Some((
+                start,
+                comment_pos + comment.len(),
+                format!("{comment}\n{indent}{node_src}"),
+            ));
+        }
+    }
+
+    let body_src = String::from_utf8_lossy(body_node.location().as_slice());
+    let cond_src = String::from_utf8_lossy(predicate.location().as_slice());
+    let (replace_end, body_line) = match &comment {
+        Some((comment_pos, comment)) => {
+            (comment_pos + comment.len(), format!("{body_src} {comment}"))
+        }
+        None => (end, body_src.into_owned()),
+    };
+    Some((
+        start,
+        replace_end,
+        format!("{keyword} {cond_src}\n{indent}  {body_line}\n{indent}end"),
+    ))
+}
+
 fn code_after_end(source: &SourceFile, end_loc: ruby_prism::Location<'_>) -> Option<String> {
     let (end_line, end_col) = source.offset_to_line_col(end_loc.start_offset());
     let lines: Vec<&[u8]> = source.lines().collect();
@@ -637,6 +744,10 @@ impl Cop for IfUnlessModifier {
         "Style/IfUnlessModifier"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[IF_NODE, UNLESS_NODE]
     }
@@ -648,7 +759,7 @@ impl Cop for IfUnlessModifier {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         // Extract keyword location, predicate, statements, has_else, and keyword name
         // from either IfNode or UnlessNode
@@ -738,12 +849,27 @@ impl Cop for IfUnlessModifier {
             }
 
             let (line, column) = source.offset_to_line_col(node.location().start_offset());
-            diagnostics.push(self.diagnostic(
+            let mut diagnostic = self.diagnostic(
                 source,
                 line,
                 column,
                 format!("Modifier form of `{keyword}` makes the line too long."),
-            ));
+            );
+            if let Some(corrections) = corrections {
+                if let Some((start, end, replacement)) =
+                    block_form_correction(source, node, &body_node, &predicate, keyword, config)
+                {
+                    corrections.push(Correction {
+                        start,
+                        end,
+                        replacement,
+                        cop_name: self.name(),
+                        cop_index: 0,
+                    });
+                    diagnostic.corrected = true;
+                }
+            }
+            diagnostics.push(diagnostic);
             return;
         }
 
@@ -920,15 +1046,6 @@ impl Cop for IfUnlessModifier {
         )
         .into_owned();
 
-        let mut expression = format!("{body_text} {keyword} {cond_text}");
-        if parenthesize_modifier_form(source, &kw_loc) {
-            expression = format!("({expression})");
-        }
-        if let Some(comment) = first_line_comment_text(source, kw_line, &predicate) {
-            expression.push(' ');
-            expression.push_str(&comment);
-        }
-
         let code_after = if let Some(if_node) = node.as_if_node() {
             if_node
                 .end_keyword_loc()
@@ -942,6 +1059,20 @@ impl Cop for IfUnlessModifier {
         }
         .unwrap_or_default();
 
+        // RuboCop's `parenthesize?` also wraps the expression when it is an
+        // operand of `||`/`&&`, which bind tighter than a modifier `if`.
+        let mut expression = format!("{body_text} {keyword} {cond_text}");
+        if parenthesize_modifier_form(source, &kw_loc)
+            || continues_boolean_operator(code_after.as_bytes())
+        {
+            expression = format!("({expression})");
+        }
+        let first_line_comment = first_line_comment_text(source, kw_line, &predicate);
+        if let Some(comment) = &first_line_comment {
+            expression.push(' ');
+            expression.push_str(comment);
+        }
+
         let modifier_line = format!("{code_before}{expression}{code_after}");
         let indentation_width = config.get_usize("IndentationWidth", 2);
         let modifier_len = modifier_line.chars().count()
@@ -949,14 +1080,28 @@ impl Cop for IfUnlessModifier {
 
         if !line_length_enabled || modifier_len <= max_line_length {
             let (line, column) = source.offset_to_line_col(kw_loc.start_offset());
-            diagnostics.push(self.diagnostic(
+            let mut diagnostic = self.diagnostic(
                 source,
                 line,
                 column,
                 format!(
                     "Favor modifier `{keyword}` usage when having a single-line body. Another good alternative is the usage of control flow `&&`/`||`."
                 ),
-            ));
+            );
+            // A comment moved after the modifier form would swallow any code
+            // that follows `end` on its line.
+            let correctable = first_line_comment.is_none() || code_after.trim().is_empty();
+            if let Some(corrections) = corrections.filter(|_| correctable) {
+                corrections.push(Correction {
+                    start: node.location().start_offset(),
+                    end: node.location().end_offset(),
+                    replacement: expression,
+                    cop_name: self.name(),
+                    cop_index: 0,
+                });
+                diagnostic.corrected = true;
+            }
+            diagnostics.push(diagnostic);
         }
     }
 }
@@ -966,6 +1111,19 @@ mod tests {
     use super::*;
 
     crate::cop_fixture_tests!(IfUnlessModifier, "cops/style/if_unless_modifier");
+    crate::cop_autocorrect_fixture_tests!(IfUnlessModifier, "cops/style/if_unless_modifier");
+
+    #[test]
+    fn named_capture_modifier_is_not_corrected() {
+        let source = format!(
+            "puts(\"matched the configured naming scheme: {}\") if /(?<name>[a-z_]+)_v\\d+/ =~ input_identifier\n",
+            "x".repeat(40)
+        );
+        let (diags, corrections) =
+            crate::testutil::run_cop_autocorrect(&IfUnlessModifier, source.as_bytes());
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty());
+    }
 
     #[test]
     fn config_max_line_length() {
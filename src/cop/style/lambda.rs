@@ -1,5 +1,6 @@
 use crate::cop::shared::node_type::{BLOCK_NODE, CALL_NODE, LAMBDA_NODE};
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -18,6 +19,15 @@ use crate::parse::source::SourceFile;
 /// reproduction already matches RuboCop in fixtures, which suggests the
 /// remaining corpus misses are likely file/context-specific rather than this
 /// cop's core selector logic.
+///
+/// ## Autocorrect
+///
+/// `lambda { |x| ... }` becomes `->(x) { ... }` and `->(x) do ... end` becomes
+/// `lambda do |x| ... end`, keeping the original block delimiters. Corrections
+/// are skipped when the lambda is called immediately (`.()`) or passed as a
+/// block argument (`&`), and when a `do...end` literal sits in the arguments of
+/// an unparenthesized call, where `lambda do` would bind the block to the
+/// outer call instead.
 pub struct Lambda;
 
 impl Cop for Lambda {
@@ -25,6 +35,10 @@ impl Cop for Lambda {
         "Style/Lambda"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[BLOCK_NODE, CALL_NODE, LAMBDA_NODE]
     }
@@ -33,16 +47,23 @@ impl Cop for Lambda {
         &self,
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
-        _parse_result: &ruby_prism::ParseResult<'_>,
+        parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let style = config.get_str("EnforcedStyle", "line_count_dependent");
 
         // Check -> (lambda literal) nodes
         if let Some(lambda_node) = node.as_lambda_node() {
-            self.check_lambda_literal(source, &lambda_node, style, diagnostics);
+            self.check_lambda_literal(
+                source,
+                &lambda_node,
+                parse_result,
+                style,
+                diagnostics,
+                corrections,
+            );
             return;
         }
 
@@ -67,7 +88,7 @@ impl Cop for Lambda {
             return;
         }
 
-        self.check_lambda_method(source, &call, style, diagnostics);
+        self.check_lambda_method(source, &call, style, diagnostics, corrections);
     }
 }
 
@@ -76,8 +97,10 @@ impl Lambda {
         &self,
         source: &SourceFile,
         lambda_node: &ruby_prism::LambdaNode<'_>,
+        parse_result: &ruby_prism::ParseResult<'_>,
         style: &str,
         diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let loc = lambda_node.operator_loc();
         let (start_line, _) = source.offset_to_line_col(lambda_node.location().start_offset());
@@ -89,35 +112,27 @@ impl Lambda {
         let (end_line, _) = source.offset_to_line_col(end_off);
         let is_multiline = start_line != end_line;
 
-        match style {
-            "lambda" => {
-                // Always flag `->` — use `lambda` instead
-                let (line, column) = source.offset_to_line_col(loc.start_offset());
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Use the `lambda` method for all lambdas.".to_string(),
-                ));
-            }
-            "literal" => {
-                // `->` is preferred — no offense
-            }
-            _ => {
-                // "line_count_dependent" (default):
-                // Single-line `-> { }` is correct.
-                // Multi-line `->() do ... end` should use `lambda` instead.
-                if is_multiline {
-                    let (line, column) = source.offset_to_line_col(loc.start_offset());
-                    diagnostics.push(self.diagnostic(
-                        source,
-                        line,
-                        column,
-                        "Use the `lambda` method for multiline lambdas.".to_string(),
-                    ));
-                }
+        let message = match style {
+            // Always flag `->` — use `lambda` instead
+            "lambda" => "Use the `lambda` method for all lambdas.",
+            // `->` is preferred — no offense
+            "literal" => return,
+            // "line_count_dependent" (default):
+            // Single-line `-> { }` is correct.
+            // Multi-line `->() do ... end` should use `lambda` instead.
+            _ if is_multiline => "Use the `lambda` method for multiline lambdas.",
+            _ => return,
+        };
+
+        let (line, column) = source.offset_to_line_col(loc.start_offset());
+        let mut diag = self.diagnostic(source, line, column, message.to_string());
+        if let Some(corr) = corrections {
+            if let Some(edits) = self.literal_to_method(source, lambda_node, parse_result) {
+                corr.extend(edits);
+                diag.corrected = true;
             }
         }
+        diagnostics.push(diag);
     }
 
     fn check_lambda_method(
@@ -126,22 +141,13 @@ impl Lambda {
         call: &ruby_prism::CallNode<'_>,
         style: &str,
         diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
-        match style {
-            "literal" => {
-                // Always flag `lambda` — use `->` instead
-                let loc = call.message_loc().unwrap_or_else(|| call.location());
-                let (line, column) = source.offset_to_line_col(loc.start_offset());
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Use the `-> {}` lambda literal syntax for all lambdas.".to_string(),
-                ));
-            }
-            "lambda" => {
-                // Never flag `lambda` — it's preferred
-            }
+        let message = match style {
+            // Always flag `lambda` — use `->` instead
+            "literal" => "Use the `-> {}` lambda literal syntax for all lambdas.",
+            // Never flag `lambda` — it's preferred
+            "lambda" => return,
             _ => {
                 // "line_count_dependent" (default): only flag single-line `lambda`
                 let block = match call.block() {
@@ -163,22 +169,187 @@ impl Lambda {
                         .max(block_node.location().start_offset()),
                 );
 
-                if start_line == end_line {
-                    let loc = call.message_loc().unwrap_or_else(|| call.location());
-                    let (line, column) = source.offset_to_line_col(loc.start_offset());
-                    diagnostics.push(
-                        self.diagnostic(
-                            source,
-                            line,
-                            column,
-                            "Use the `-> {}` lambda literal syntax for single-line lambdas."
-                                .to_string(),
-                        ),
-                    );
+                if start_line != end_line {
+                    return;
                 }
+                "Use the `-> {}` lambda literal syntax for single-line lambdas."
+            }
+        };
+
+        let loc = call.message_loc().unwrap_or_else(|| call.location());
+        let (line, column) = source.offset_to_line_col(loc.start_offset());
+        let mut diag = self.diagnostic(source, line, column, message.to_string());
+        if let Some(corr) = corrections {
+            if let Some(edits) = self.method_to_literal(source, call) {
+                corr.extend(edits);
+                diag.corrected = true;
             }
         }
+        diagnostics.push(diag);
     }
+
+    /// `lambda { |x| body }` -> `->(x) { body }`.
+    fn method_to_literal(
+        &self,
+        source: &SourceFile,
+        call: &ruby_prism::CallNode<'_>,
+    ) -> Option<Vec<Correction>> {
+        let block = call.block()?.as_block_node()?;
+        let selector = call.message_loc()?;
+        if !correctable_position(
+            source,
+            call.location().start_offset(),
+            call.location().end_offset(),
+        ) {
+            return None;
+        }
+
+        let bytes = source.as_bytes();
+        let mut literal = String::from("->");
+        let mut edits = Vec::new();
+        if let Some(params) = block
+            .parameters()
+            .and_then(|p| p.as_block_parameters_node())
+        {
+            // `|x, y|` -> `(x, y)`; drop the pipes and the space before them.
+            let (open, close) = (params.opening_loc()?, params.closing_loc()?);
+            let inner = trimmed(&bytes[open.end_offset()..close.start_offset()]);
+            if !inner.is_empty() {
+                literal.push('(');
+                literal.push_str(inner);
+                literal.push(')');
+            }
+            edits.push(self.correction(
+                block.opening_loc().end_offset(),
+                close.end_offset(),
+                String::new(),
+            ));
+        }
+        edits.push(self.correction(selector.start_offset(), selector.end_offset(), literal));
+        Some(edits)
+    }
+
+    /// `->(x) { body }` -> `lambda { |x| body }`.
+    fn literal_to_method(
+        &self,
+        source: &SourceFile,
+        lambda_node: &ruby_prism::LambdaNode<'_>,
+        parse_result: &ruby_prism::ParseResult<'_>,
+    ) -> Option<Vec<Correction>> {
+        let loc = lambda_node.location();
+        if !correctable_position(source, loc.start_offset(), loc.end_offset()) {
+            return None;
+        }
+        let opening = lambda_node.opening_loc();
+        if opening.as_slice() == b"do"
+            && in_unparenthesized_arguments(parse_result, loc.start_offset(), loc.end_offset())
+        {
+            return None;
+        }
+
+        let bytes = source.as_bytes();
+        let operator = lambda_node.operator_loc();
+        let mut selector = String::new();
+        // `html->{ }` needs a separator once `->` becomes a word.
+        if operator.start_offset() > 0 && is_word_byte(bytes[operator.start_offset() - 1]) {
+            selector.push(' ');
+        }
+        selector.push_str("lambda ");
+
+        let mut edits =
+            vec![self.correction(operator.start_offset(), opening.start_offset(), selector)];
+        if let Some(params) = lambda_node
+            .parameters()
+            .and_then(|p| p.as_block_parameters_node())
+        {
+            let inner = match (params.opening_loc(), params.closing_loc()) {
+                (Some(open), Some(close)) => &bytes[open.end_offset()..close.start_offset()],
+                _ => params.location().as_slice(),
+            };
+            let inner = trimmed(inner);
+            if !inner.is_empty() {
+                edits.push(self.correction(
+                    opening.end_offset(),
+                    opening.end_offset(),
+                    format!(" |{inner}|"),
+                ));
+            }
+        }
+        Some(edits)
+    }
+
+    fn correction(&self, start: usize, end: usize, replacement: String) -> Correction {
+        Correction {
+            start,
+            end,
+            replacement,
+            cop_name: self.name(),
+            cop_index: 0,
+        }
+    }
+}
+
+/// Lambdas that are called on the spot (`lambda { }.()`) or passed as a block
+/// argument (`&-> { }`) are reported but left alone.
+fn correctable_position(source: &SourceFile, start: usize, end: usize) -> bool {
+    let bytes = source.as_bytes();
+    let before = bytes[..start]
+        .iter()
+        .rev()
+        .find(|b| !b.is_ascii_whitespace());
+    if before == Some(&b'&') {
+        return false;
+    }
+    let after = &bytes[end.min(bytes.len())..];
+    !(after.starts_with(b".(") || after.starts_with(b"&.("))
+}
+
+/// Whether `start..end` lies inside the arguments of a call written without
+/// parentheses, where a `do...end` block binds to the outer call.
+fn in_unparenthesized_arguments(
+    parse_result: &ruby_prism::ParseResult<'_>,
+    start: usize,
+    end: usize,
+) -> bool {
+    use ruby_prism::Visit;
+
+    struct Finder {
+        start: usize,
+        end: usize,
+        found: bool,
+    }
+
+    impl<'pr> Visit<'pr> for Finder {
+        fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+            if node.opening_loc().is_none() {
+                if let Some(args) = node.arguments() {
+                    let loc = args.location();
+                    if loc.start_offset() <= self.start && self.end <= loc.end_offset() {
+                        self.found = true;
+                    }
+                }
+            }
+            if !self.found {
+                ruby_prism::visit_call_node(self, node);
+            }
+        }
+    }
+
+    let mut finder = Finder {
+        start,
+        end,
+        found: false,
+    };
+    finder.visit(&parse_result.node());
+    finder.found
+}
+
+fn trimmed(bytes: &[u8]) -> &str {
+    std::str::from_utf8(bytes).unwrap_or("").trim()
+}
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
 }
 
 #[cfg(test)]
@@ -187,6 +358,7 @@ mod tests {
     use crate::testutil::run_cop_full;
 
     crate::cop_fixture_tests!(Lambda, "cops/style/lambda");
+    crate::cop_autocorrect_fixture_tests!(Lambda, "cops/style/lambda");
 
     #[test]
     fn lambda_with_receiver_is_ignored() {
@@ -194,4 +366,77 @@ mod tests {
         let diags = run_cop_full(&Lambda, source);
         assert!(diags.is_empty());
     }
+
+    fn style_config(style: &str) -> CopConfig {
+        use std::collections::HashMap;
+        CopConfig {
+            options: HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String(style.into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_literal_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &Lambda,
+            include_bytes!("../../../tests/fixtures/cops/style/lambda/offense.literal.rb"),
+            style_config("literal"),
+        );
+    }
+
+    #[test]
+    fn no_offense_literal_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &Lambda,
+            include_bytes!("../../../tests/fixtures/cops/style/lambda/no_offense.literal.rb"),
+            style_config("literal"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_literal_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &Lambda,
+            include_bytes!("../../../tests/fixtures/cops/style/lambda/offense.literal.rb"),
+            include_bytes!("../../../tests/fixtures/cops/style/lambda/corrected.literal.rb"),
+            style_config("literal"),
+        );
+    }
+
+    #[test]
+    fn offense_lambda_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &Lambda,
+            include_bytes!("../../../tests/fixtures/cops/style/lambda/offense.lambda.rb"),
+            style_config("lambda"),
+        );
+    }
+
+    #[test]
+    fn no_offense_lambda_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &Lambda,
+            include_bytes!("../../../tests/fixtures/cops/style/lambda/no_offense.lambda.rb"),
+            style_config("lambda"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_lambda_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &Lambda,
+            include_bytes!("../../../tests/fixtures/cops/style/lambda/offense.lambda.rb"),
+            include_bytes!("../../../tests/fixtures/cops/style/lambda/corrected.lambda.rb"),
+            style_config("lambda"),
+        );
+    }
 }
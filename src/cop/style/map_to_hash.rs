@@ -20,14 +20,6 @@ use crate::parse::source::SourceFile;
 /// reported Expected=395, Actual=395, Excess=0, Missing=0.
 pub struct MapToHash;
 
-fn target_ruby_version(config: &CopConfig) -> f64 {
-    config
-        .options
-        .get("TargetRubyVersion")
-        .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-        .unwrap_or(2.7)
-}
-
 fn is_map_or_collect(call: &ruby_prism::CallNode<'_>) -> bool {
     matches!(call.name().as_slice(), b"map" | b"collect")
 }
@@ -63,7 +55,7 @@ impl Cop for MapToHash {
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // RuboCop: minimum_target_ruby_version 2.6
-        if target_ruby_version(config) < 2.6 {
+        if config.target_ruby_version() < 2.6 {
             return;
         }
 
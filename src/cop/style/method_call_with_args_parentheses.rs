@@ -151,6 +151,16 @@ use crate::parse::source::SourceFile;
 /// Validation: `python3 scripts/check_cop.py Style/MethodCallWithArgsParentheses
 /// --rerun --clone --sample 15` reported `0` new FP, `0` new FN, and all `41`
 /// sampled oracle FN resolved.
+///
+/// ## Autocorrect
+///
+/// Mirrors RuboCop's two correctors. `require_parentheses` replaces the space
+/// after the selector with `(` and closes after the last argument (or
+/// `&block`), leaving any `do`/`{}` block outside. `omit_parentheses` swaps
+/// `(` for a space (` \` when the arguments start on the next line) and
+/// drops `)`. Ambiguous cases are already excluded by
+/// `legitimate_call_with_parentheses`; corrections are additionally skipped
+/// for trailing commas, a comment after `(`, or a `,` following `)`.
 pub struct MethodCallWithArgsParentheses;
 
 /// Check if a method name matches any pattern in the list (regex-style).
@@ -251,6 +261,10 @@ impl Cop for MethodCallWithArgsParentheses {
         false
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -258,7 +272,7 @@ impl Cop for MethodCallWithArgsParentheses {
         _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         if source.path.file_name().and_then(|name| name.to_str()) == Some(".coverage") {
             return;
@@ -279,6 +293,7 @@ impl Cop for MethodCallWithArgsParentheses {
             cop: self,
             source,
             diagnostics: Vec::new(),
+            corrections,
             enforced_style,
             ignore_macros,
             allowed_methods: allowed_methods.as_deref(),
@@ -304,6 +319,7 @@ struct ParenVisitor<'a> {
     cop: &'a MethodCallWithArgsParentheses,
     source: &'a SourceFile,
     diagnostics: Vec<Diagnostic>,
+    corrections: Option<&'a mut Vec<crate::correction::Correction>>,
     enforced_style: &'a str,
     ignore_macros: bool,
     allowed_methods: Option<&'a [String]>,
@@ -442,15 +458,15 @@ impl ParenVisitor<'_> {
 
         // RuboCop reports the offense at the start of the full expression (including
         // receiver), not at the method name. Use call.location() to match.
-        let (line, column) = self
-            .source
-            .offset_to_line_col(call.location().start_offset());
-        self.diagnostics.push(self.cop.diagnostic(
-            self.source,
-            line,
-            column,
-            "Use parentheses for method calls with arguments.".to_string(),
-        ));
+        let edits = call.message_loc().and_then(|selector| {
+            let args = call_arguments_range(call)?;
+            self.add_parentheses_edits(selector.end_offset(), args)
+        });
+        self.add_offense(
+            call.location().start_offset(),
+            "Use parentheses for method calls with arguments.",
+            edits,
+        );
     }
 
     fn check_omit_parentheses(&mut self, call: &ruby_prism::CallNode<'_>) {
@@ -503,17 +519,135 @@ impl ParenVisitor<'_> {
             return;
         }
 
-        let open_loc = match call.opening_loc() {
-            Some(loc) => loc,
-            None => return,
+        let (open_loc, close_loc) = match (call.opening_loc(), call.closing_loc()) {
+            (Some(open), Some(close)) => (open, close),
+            _ => return,
+        };
+        let edits = match call.message_loc() {
+            Some(selector) if call_arguments_range(call).is_some() => self.omit_parentheses_edits(
+                selector.end_offset(),
+                open_loc.start_offset(),
+                close_loc.start_offset(),
+            ),
+            _ => None,
         };
-        let (line, column) = self.source.offset_to_line_col(open_loc.start_offset());
-        self.diagnostics.push(self.cop.diagnostic(
-            self.source,
-            line,
-            column,
-            "Omit parentheses for method calls with arguments.".to_string(),
-        ));
+        self.add_offense(
+            open_loc.start_offset(),
+            "Omit parentheses for method calls with arguments.",
+            edits,
+        );
+    }
+
+    fn add_offense(
+        &mut self,
+        offset: usize,
+        message: &str,
+        edits: Option<Vec<(usize, usize, &str)>>,
+    ) {
+        let (line, column) = self.source.offset_to_line_col(offset);
+        let mut diag = self
+            .cop
+            .diagnostic(self.source, line, column, message.to_string());
+        if let (Some(corrections), Some(edits)) = (self.corrections.as_mut(), edits) {
+            for (start, end, replacement) in edits {
+                corrections.push(crate::correction::Correction {
+                    start,
+                    end,
+                    replacement: replacement.to_string(),
+                    cop_name: self.cop.name(),
+                    cop_index: 0,
+                });
+            }
+            diag.corrected = true;
+        }
+        self.diagnostics.push(diag);
+    }
+
+    /// Edits for RuboCop's `require_parentheses` corrector: replace the space
+    /// after the selector with `(` and close after the last argument. A lone
+    /// parenthesized argument (`foo (bar)`) only loses the space.
+    ///
+    /// Returns `None` when the arguments are not separated from the selector
+    /// by a single-line space (e.g. `foo"bar"` or a `\` continuation), where a
+    /// blind replacement would corrupt the source.
+    fn add_parentheses_edits(
+        &self,
+        selector_end: usize,
+        (args_start, args_end, single_parenthesized): (usize, usize, bool),
+    ) -> Option<Vec<(usize, usize, &'static str)>> {
+        let bytes = self.source.as_bytes();
+        if !matches!(bytes.get(selector_end), Some(b' ' | b'\t')) {
+            return None;
+        }
+        if single_parenthesized && args_start == selector_end + 1 {
+            return Some(vec![(selector_end, selector_end + 2, "(")]);
+        }
+        Some(vec![
+            (selector_end, selector_end + 1, "("),
+            (args_end, args_end, ")"),
+        ])
+    }
+
+    /// Edits for RuboCop's `omit_parentheses` corrector: replace `(` with a
+    /// space (or ` \` when the arguments start on the next line) and drop
+    /// `)`.
+    ///
+    /// Returns `None` when dropping the parentheses would change how the code
+    /// parses: a trailing comma inside the parentheses, a comment right after
+    /// `(`, or a `,` right after `)` that would fold the following expression
+    /// into the arguments (`return foo(a), b`).
+    fn omit_parentheses_edits(
+        &self,
+        selector_end: usize,
+        open: usize,
+        close: usize,
+    ) -> Option<Vec<(usize, usize, &'static str)>> {
+        let bytes = self.source.as_bytes();
+        if open != selector_end {
+            return None;
+        }
+        let before_close = bytes[open + 1..close]
+            .iter()
+            .rev()
+            .find(|b| !b.is_ascii_whitespace());
+        if before_close == Some(&b',') {
+            return None;
+        }
+        let after_close = bytes[close + 1..]
+            .iter()
+            .find(|b| !matches!(b, b' ' | b'\t'));
+        if after_close == Some(&b',') {
+            return None;
+        }
+
+        let rest_of_line_end = bytes[open + 1..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(bytes.len(), |p| open + 1 + p);
+        if bytes[open + 1..rest_of_line_end]
+            .iter()
+            .find(|b| !b.is_ascii_whitespace())
+            == Some(&b'#')
+        {
+            return None;
+        }
+        let open_edit = if bytes[open + 1..rest_of_line_end]
+            .iter()
+            .all(|b| b.is_ascii_whitespace())
+            && rest_of_line_end < close
+        {
+            // Whitespace after a line continuation is a syntax error, so the
+            // continuation replaces the trailing spaces as well.
+            let ws_end = if bytes[rest_of_line_end - 1] == b'\r' {
+                rest_of_line_end - 1
+            } else {
+                rest_of_line_end
+            };
+            (open, ws_end, " \\")
+        } else {
+            (open, open + 1, " ")
+        };
+        Some(vec![open_edit, (close, close + 1, "")])
     }
 
     /// Check require_parentheses_for_hash_value_omission?
@@ -798,15 +932,14 @@ impl ParenVisitor<'_> {
         }
 
         // Report at the yield keyword location
-        let (line, column) = self
-            .source
-            .offset_to_line_col(node.keyword_loc().start_offset());
-        self.diagnostics.push(self.cop.diagnostic(
-            self.source,
-            line,
-            column,
-            "Use parentheses for method calls with arguments.".to_string(),
-        ));
+        let keyword = node.keyword_loc();
+        let edits = yield_arguments_range(node)
+            .and_then(|args| self.add_parentheses_edits(keyword.end_offset(), args));
+        self.add_offense(
+            keyword.start_offset(),
+            "Use parentheses for method calls with arguments.",
+            edits,
+        );
     }
 
     /// Check yield node in omit_parentheses mode.
@@ -844,20 +977,59 @@ impl ParenVisitor<'_> {
             }
         }
 
-        let open_loc = match node.lparen_loc() {
-            Some(loc) => loc,
-            None => return,
+        let (open_loc, close_loc) = match (node.lparen_loc(), node.rparen_loc()) {
+            (Some(open), Some(close)) => (open, close),
+            _ => return,
         };
-        let (line, column) = self.source.offset_to_line_col(open_loc.start_offset());
-        self.diagnostics.push(self.cop.diagnostic(
-            self.source,
-            line,
-            column,
-            "Omit parentheses for method calls with arguments.".to_string(),
-        ));
+        let edits = yield_arguments_range(node).and_then(|_| {
+            self.omit_parentheses_edits(
+                node.keyword_loc().end_offset(),
+                open_loc.start_offset(),
+                close_loc.start_offset(),
+            )
+        });
+        self.add_offense(
+            open_loc.start_offset(),
+            "Omit parentheses for method calls with arguments.",
+            edits,
+        );
     }
 }
 
+/// Byte range covered by a call's arguments, including a trailing `&block`
+/// argument, plus whether the only argument is a parenthesized expression.
+fn call_arguments_range(call: &ruby_prism::CallNode<'_>) -> Option<(usize, usize, bool)> {
+    let block_arg = call
+        .block()
+        .filter(|b| b.as_block_argument_node().is_some());
+    let args: Vec<ruby_prism::Node<'_>> = call
+        .arguments()
+        .map(|a| a.arguments().iter().collect())
+        .unwrap_or_default();
+    let start = args
+        .first()
+        .map(|a| a.location().start_offset())
+        .or_else(|| block_arg.as_ref().map(|b| b.location().start_offset()))?;
+    let end = block_arg
+        .as_ref()
+        .map(|b| b.location().end_offset())
+        .or_else(|| args.last().map(|a| a.location().end_offset()))?;
+    let single_parenthesized =
+        block_arg.is_none() && args.len() == 1 && args[0].as_parentheses_node().is_some();
+    Some((start, end, single_parenthesized))
+}
+
+fn yield_arguments_range(node: &ruby_prism::YieldNode<'_>) -> Option<(usize, usize, bool)> {
+    let args: Vec<ruby_prism::Node<'_>> = node.arguments()?.arguments().iter().collect();
+    let first = args.first()?;
+    let last = args.last()?;
+    Some((
+        first.location().start_offset(),
+        last.location().end_offset(),
+        args.len() == 1 && first.as_parentheses_node().is_some(),
+    ))
+}
+
 /// Check if a hash node has value omission (Ruby 3.1 shorthand `{foo:}`)
 fn has_hash_value_omission(hash: &ruby_prism::HashNode<'_>) -> bool {
     for elem in hash.elements().iter() {
@@ -1883,6 +2055,30 @@ mod tests {
         MethodCallWithArgsParentheses,
         "cops/style/method_call_with_args_parentheses"
     );
+    crate::cop_autocorrect_fixture_tests!(
+        MethodCallWithArgsParentheses,
+        "cops/style/method_call_with_args_parentheses"
+    );
+
+    fn omit_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String("omit_parentheses".into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    fn autocorrect(source: &[u8], config: CopConfig) -> String {
+        let (_diags, corrections) = crate::testutil::run_cop_autocorrect_with_config(
+            &MethodCallWithArgsParentheses,
+            source,
+            config,
+        );
+        let cs = crate::correction::CorrectionSet::from_vec(corrections);
+        String::from_utf8(cs.apply(source)).unwrap()
+    }
 
     #[test]
     fn operators_are_ignored() {
@@ -2612,4 +2808,54 @@ mod tests {
             "Receiverless call inside lambda in class body should be treated as macro"
         );
     }
+
+    #[test]
+    fn autocorrect_require_single_parenthesized_argument() {
+        assert_eq!(
+            autocorrect(b"foo.bar (baz)\n", CopConfig::default()),
+            "foo.bar(baz)\n"
+        );
+    }
+
+    #[test]
+    fn autocorrect_require_keeps_do_block_outside() {
+        assert_eq!(
+            autocorrect(b"foo.each_slice 2 do |x|\nend\n", CopConfig::default()),
+            "foo.each_slice(2) do |x|\nend\n"
+        );
+    }
+
+    #[test]
+    fn autocorrect_omit_parentheses() {
+        assert_eq!(
+            autocorrect(
+                b"def foo\n  bar.baz(1, 2)\n  yield(item)\nend\n",
+                omit_config()
+            ),
+            "def foo\n  bar.baz 1, 2\n  yield item\nend\n"
+        );
+    }
+
+    #[test]
+    fn autocorrect_omit_parentheses_multiline_uses_continuation() {
+        assert_eq!(
+            autocorrect(b"foo.bar(\n  1,\n  2)\n", omit_config()),
+            "foo.bar \\\n  1,\n  2\n"
+        );
+    }
+
+    #[test]
+    fn autocorrect_omit_parentheses_skips_ambiguous_edits() {
+        let trailing_comma = b"foo.bar(\n  1,\n  2,\n)\n";
+        assert_eq!(
+            autocorrect(trailing_comma, omit_config()),
+            "foo.bar(\n  1,\n  2,\n)\n"
+        );
+
+        let followed_by_comma = b"def x\n  return foo.bar(1), 2\nend\n";
+        assert_eq!(
+            autocorrect(followed_by_comma, omit_config()),
+            "def x\n  return foo.bar(1), 2\nend\n"
+        );
+    }
 }
@@ -144,6 +144,7 @@ impl BlockChainVisitor<'_> {
                 cop_name: self.cop_name.to_string(),
                 message: "Avoid multi-line chains of blocks.".to_string(),
                 corrected: false,
+                end_column: None,
             });
         }
     }
@@ -16,10 +16,15 @@ impl Cop for MultilineInPatternThen {
         source: &SourceFile,
         parse_result: &ruby_prism::ParseResult<'_>,
         _code_map: &crate::parse::codemap::CodeMap,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.7
+        if !config.ruby_version_at_least(2.7) {
+            return;
+        }
+
         let mut visitor = MultilineInPatternThenVisitor {
             cop: self,
             source,
@@ -82,6 +82,7 @@ impl TernaryVisitor<'_> {
             cop_name: self.cop_name.to_string(),
             message: message.to_string(),
             corrected: false,
+            end_column: None,
         });
         true
     }
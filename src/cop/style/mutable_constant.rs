@@ -52,7 +52,7 @@ impl MutableConstant {
 
     /// Check if node is a non-interpolated string literal (StringNode only, no heredocs
     /// with interpolation). `frozen_string_literal: true` only freezes these.
-    fn is_plain_string(_source: &SourceFile, node: &ruby_prism::Node<'_>) -> bool {
+    pub(crate) fn is_plain_string(_source: &SourceFile, node: &ruby_prism::Node<'_>) -> bool {
         if let Some(s) = node.as_string_node() {
             // Heredocs are mutable even with frozen_string_literal: true in Ruby 3.0+
             // ... actually no: plain (non-interpolated) heredocs ARE frozen with the magic comment.
@@ -138,7 +138,7 @@ impl MutableConstant {
             || Self::is_parenthesized_range(node)
     }
 
-    fn is_parenthesized_range(node: &ruby_prism::Node<'_>) -> bool {
+    pub(crate) fn is_parenthesized_range(node: &ruby_prism::Node<'_>) -> bool {
         if let Some(paren) = node.as_parentheses_node() {
             if let Some(body) = paren.body() {
                 if let Some(stmts) = body.as_statements_node() {
@@ -154,7 +154,7 @@ impl MutableConstant {
 
     /// For `strict` mode: check if operation produces an immutable object.
     /// Matches RuboCop's `operation_produces_immutable_object?` NodePattern.
-    fn operation_produces_immutable_object(node: &ruby_prism::Node<'_>) -> bool {
+    pub(crate) fn operation_produces_immutable_object(node: &ruby_prism::Node<'_>) -> bool {
         // Constants (OTHER_CONST, Namespace::CONST) are immutable references
         if node.as_constant_read_node().is_some() || node.as_constant_path_node().is_some() {
             return true;
@@ -356,7 +356,7 @@ impl MutableConstant {
 
     /// Check if the source file has a leading frozen string literal magic comment.
     /// This makes plain string literals frozen, but not interpolated strings.
-    fn has_frozen_string_literal_true(source: &SourceFile) -> bool {
+    pub(crate) fn has_frozen_string_literal_true(source: &SourceFile) -> bool {
         let mut iter = source.lines();
         while let Some(line) = iter.next() {
             if Self::is_blank_line(line) {
@@ -431,7 +431,7 @@ impl MutableConstant {
     }
 
     /// Check if a `CallNode` wraps a Struct.new with a block (strict mode immutable).
-    fn is_struct_new_block(node: &ruby_prism::Node<'_>) -> bool {
+    pub(crate) fn is_struct_new_block(node: &ruby_prism::Node<'_>) -> bool {
         if let Some(call) = node.as_call_node() {
             if call.name().as_slice() == b"new" && call.block().is_some() {
                 if let Some(recv) = call.receiver() {
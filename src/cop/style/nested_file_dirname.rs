@@ -22,12 +22,7 @@ impl Cop for NestedFileDirname {
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // minimum_target_ruby_version 3.1
-        let ruby_ver = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64())
-            .unwrap_or(3.4);
-        if ruby_ver < 3.1 {
+        if !config.ruby_version_at_least(3.1) {
             return;
         }
 
@@ -145,5 +140,31 @@ fn is_file_const(node: &ruby_prism::Node<'_>) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    crate::cop_fixture_tests!(NestedFileDirname, "cops/style/nested_file_dirname");
+
+    fn ruby31_config() -> CopConfig {
+        let mut config = CopConfig::default();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::Number::from(3.1)),
+        );
+        config
+    }
+
+    #[test]
+    fn offense_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &NestedFileDirname,
+            include_bytes!("../../../tests/fixtures/cops/style/nested_file_dirname/offense.rb"),
+            ruby31_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &NestedFileDirname,
+            include_bytes!("../../../tests/fixtures/cops/style/nested_file_dirname/no_offense.rb"),
+            ruby31_config(),
+        );
+    }
 }
@@ -1,7 +1,9 @@
 use ruby_prism::Visit;
 
 use crate::cop::shared::method_identifier_predicates;
+use crate::cop::shared::util::collect_heredoc_ranges;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -35,6 +37,14 @@ use crate::parse::source::SourceFile;
 /// - Removed `any?`/`none?` (not in RuboCop's ENUMERATOR_METHODS, caused FP).
 /// - Removed `filter` (not in RuboCop's ENUMERATOR_METHODS, caused FP in
 ///   bluepotion `filter do` blocks).
+/// - `AllowConsecutiveConditionals` accepts a trailing conditional whose left
+///   sibling is also an `if`/`unless`.
+///
+/// Autocorrect follows RuboCop: the conditional becomes a `next unless`/`next if`
+/// guard and its body is dedented to the conditional's indentation (heredoc
+/// bodies and blank lines are left alone). Modifier forms become a guard line
+/// followed by the body. Offenses remapped to a nested conditional, multi-line
+/// conditions, and `end` keywords sharing a line with code are only reported.
 pub struct Next;
 
 /// Check if a method name is an iteration method for Style/Next.
@@ -51,6 +61,10 @@ impl Cop for Next {
         "Style/Next"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -58,20 +72,26 @@ impl Cop for Next {
         _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let style = config.get_str("EnforcedStyle", "skip_modifier_ifs");
         let min_body_length = config.get_usize("MinBodyLength", 3);
-        let _allow_consecutive = config.get_bool("AllowConsecutiveConditionals", false);
+        let allow_consecutive_conditionals = config.get_bool("AllowConsecutiveConditionals", false);
         let mut visitor = NextVisitor {
             cop: self,
             source,
             style,
             min_body_length,
+            allow_consecutive_conditionals,
+            autocorrect: corrections.is_some(),
             diagnostics: Vec::new(),
+            corrections: Vec::new(),
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let Some(corrections) = corrections {
+            corrections.extend(visitor.corrections);
+        }
     }
 }
 
@@ -80,7 +100,20 @@ struct NextVisitor<'a> {
     source: &'a SourceFile,
     style: &'a str,
     min_body_length: usize,
+    allow_consecutive_conditionals: bool,
+    autocorrect: bool,
     diagnostics: Vec<Diagnostic>,
+    corrections: Vec<Correction>,
+}
+
+/// The parts of a trailing `if`/`unless` that the autocorrect rewrites.
+struct Guard<'pr> {
+    node: ruby_prism::Node<'pr>,
+    inverse_keyword: &'static str,
+    predicate: ruby_prism::Node<'pr>,
+    statements: Option<ruby_prism::StatementsNode<'pr>>,
+    then_keyword_loc: Option<ruby_prism::Location<'pr>>,
+    end_keyword_loc: Option<ruby_prism::Location<'pr>>,
 }
 
 enum NestedConditional<'pr> {
@@ -186,6 +219,136 @@ impl NextVisitor<'_> {
             .is_some_and(|nested| nested.has_keyword_else())
     }
 
+    fn add_offense(&mut self, offset: usize, correction: Option<Correction>) {
+        let (line, column) = self.source.offset_to_line_col(offset);
+        let mut diagnostic = self.cop.diagnostic(
+            self.source,
+            line,
+            column,
+            "Use `next` to skip iteration.".to_string(),
+        );
+        if self.autocorrect {
+            if let Some(correction) = correction {
+                self.corrections.push(correction);
+                diagnostic.corrected = true;
+            }
+        }
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Rewrite a trailing conditional as a `next` guard followed by its
+    /// (dedented) body.
+    fn next_correction(&self, guard: Guard<'_>) -> Option<Correction> {
+        let bytes = self.source.as_bytes();
+        let node_loc = guard.node.location();
+        let predicate_loc = guard.predicate.location();
+        let condition =
+            std::str::from_utf8(&bytes[predicate_loc.start_offset()..predicate_loc.end_offset()])
+                .ok()?;
+        let next_code = format!("next {} {condition}", guard.inverse_keyword);
+
+        let Some(end_loc) = guard.end_keyword_loc else {
+            // Modifier form: the guard goes on its own line, above the body.
+            let body_loc = guard.statements?.location();
+            let body =
+                std::str::from_utf8(&bytes[body_loc.start_offset()..body_loc.end_offset()]).ok()?;
+            let (_, column) = self.source.offset_to_line_col(node_loc.start_offset());
+            return Some(self.correction(
+                node_loc.start_offset(),
+                node_loc.end_offset(),
+                format!("{next_code}\n{}{body}", " ".repeat(column)),
+            ));
+        };
+
+        let (if_line, _) = self.source.offset_to_line_col(node_loc.start_offset());
+        let condition_end = guard
+            .then_keyword_loc
+            .map_or(predicate_loc.end_offset(), |loc| loc.end_offset());
+        if self.source.offset_to_line_col(condition_end).0 != if_line {
+            return None;
+        }
+
+        let (end_line, _) = self.source.offset_to_line_col(end_loc.start_offset());
+        let end_line_start = self.source.line_start_offset(end_line);
+        if !bytes[end_line_start..end_loc.start_offset()]
+            .iter()
+            .all(u8::is_ascii_whitespace)
+        {
+            return None;
+        }
+        // Take the newline before `end` along when nothing follows it.
+        let followed_by_whitespace = bytes[end_loc.end_offset()..]
+            .iter()
+            .take_while(|&&b| b != b'\n')
+            .all(u8::is_ascii_whitespace);
+        let body_end = if followed_by_whitespace {
+            end_line_start.saturating_sub(1)
+        } else {
+            end_line_start
+        };
+        if body_end < condition_end {
+            return None;
+        }
+
+        let heredoc_lines = guard
+            .statements
+            .map(|stmts| collect_heredoc_ranges(self.source, &stmts.as_node()))
+            .unwrap_or_default();
+        let in_heredoc = |line: usize| {
+            heredoc_lines
+                .iter()
+                .any(|&(start, end)| line > start && line <= end)
+        };
+        let indentation = |line: &[u8]| {
+            line.iter()
+                .take_while(|&&b| b == b' ' || b == b'\t')
+                .count()
+        };
+        let is_blank = |line: &[u8]| line.iter().all(u8::is_ascii_whitespace);
+
+        let body = &bytes[condition_end..body_end];
+        let lines: Vec<&[u8]> = body.split(|&b| b == b'\n').collect();
+        let reindentable =
+            |idx: usize| idx > 0 && !is_blank(lines[idx]) && !in_heredoc(if_line + idx);
+
+        let target = indentation(&bytes[self.source.line_start_offset(if_line)..]);
+        let actual = (0..lines.len())
+            .filter(|&idx| reindentable(idx))
+            .map(|idx| indentation(lines[idx]))
+            .min()
+            .unwrap_or(target);
+        let delta = actual.saturating_sub(target);
+
+        let mut replacement = next_code.into_bytes();
+        for (idx, line) in lines.iter().enumerate() {
+            if idx > 0 {
+                replacement.push(b'\n');
+            }
+            let strip = if reindentable(idx) {
+                delta.min(indentation(line))
+            } else {
+                0
+            };
+            replacement.extend_from_slice(&line[strip..]);
+        }
+
+        Some(self.correction(
+            node_loc.start_offset(),
+            end_loc.end_offset(),
+            String::from_utf8(replacement).ok()?,
+        ))
+    }
+
+    fn correction(&self, start: usize, end: usize, replacement: String) -> Correction {
+        Correction {
+            start,
+            end,
+            replacement,
+            cop_name: self.cop.name(),
+            cop_index: 0,
+        }
+    }
+
     fn check_block_body(&mut self, body: &ruby_prism::Node<'_>) {
         let stmts = match body.as_statements_node() {
             Some(s) => s,
@@ -201,6 +364,13 @@ impl NextVisitor<'_> {
         // RuboCop checks if the LAST statement is an if/unless (ends_with_condition?)
         let stmt = &body_stmts[body_stmts.len() - 1];
 
+        if self.allow_consecutive_conditionals
+            && body_stmts.len() >= 2
+            && is_conditional(&body_stmts[body_stmts.len() - 2])
+        {
+            return;
+        }
+
         // Check for if/unless that wraps the entire block body
         if let Some(if_node) = stmt.as_if_node() {
             // Skip if it has an else branch
@@ -230,13 +400,15 @@ impl NextVisitor<'_> {
                 return;
             }
 
-            let (line, column) = self.source.offset_to_line_col(kw_loc.start_offset());
-            self.diagnostics.push(self.cop.diagnostic(
-                self.source,
-                line,
-                column,
-                "Use `next` to skip iteration.".to_string(),
-            ));
+            let correction = self.next_correction(Guard {
+                node: if_node.as_node(),
+                inverse_keyword: "unless",
+                predicate: if_node.predicate(),
+                statements: if_node.statements(),
+                then_keyword_loc: if_node.then_keyword_loc(),
+                end_keyword_loc: if_node.end_keyword_loc(),
+            });
+            self.add_offense(kw_loc.start_offset(), correction);
         } else if let Some(unless_node) = stmt.as_unless_node() {
             // Skip if it has an else branch
             if unless_node.else_clause().is_some() {
@@ -277,17 +449,28 @@ impl NextVisitor<'_> {
             } else {
                 kw_loc.start_offset()
             };
-            let (line, column) = self.source.offset_to_line_col(start_offset);
-            self.diagnostics.push(self.cop.diagnostic(
-                self.source,
-                line,
-                column,
-                "Use `next` to skip iteration.".to_string(),
-            ));
+            let correction = if start_offset == kw_loc.start_offset() {
+                self.next_correction(Guard {
+                    node: unless_node.as_node(),
+                    inverse_keyword: "if",
+                    predicate: unless_node.predicate(),
+                    statements: unless_node.statements(),
+                    then_keyword_loc: unless_node.then_keyword_loc(),
+                    end_keyword_loc: unless_node.end_keyword_loc(),
+                })
+            } else {
+                None
+            };
+            self.add_offense(start_offset, correction);
         }
     }
 }
 
+/// Parser's `if_type?`: `if`, `unless`, modifier forms and ternaries.
+fn is_conditional(node: &ruby_prism::Node<'_>) -> bool {
+    node.as_if_node().is_some() || node.as_unless_node().is_some()
+}
+
 impl<'pr> Visit<'pr> for NextVisitor<'_> {
     fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
         let method_bytes = node.name().as_slice();
@@ -352,4 +535,70 @@ impl<'pr> Visit<'pr> for NextVisitor<'_> {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(Next, "cops/style/next");
+
+    use crate::cop::CopConfig;
+    use crate::testutil::{
+        assert_cop_autocorrect, assert_cop_autocorrect_with_config, run_cop_full_with_config,
+    };
+    use std::collections::HashMap;
+
+    fn config(key: &str, value: serde_yml::Value) -> CopConfig {
+        CopConfig {
+            options: HashMap::from([(key.to_string(), value)]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn autocorrect_inverts_condition_and_dedents_body() {
+        assert_cop_autocorrect(
+            &Next,
+            b"items.each do |item|\n  if item.valid?\n    a(item)\n    b(item)\n    c(item)\n  end\nend\n",
+            b"items.each do |item|\n  next unless item.valid?\n  a(item)\n  b(item)\n  c(item)\nend\n",
+        );
+    }
+
+    #[test]
+    fn autocorrect_keeps_heredoc_and_blank_lines() {
+        assert_cop_autocorrect(
+            &Next,
+            b"for x in xs\n  unless x.nil?\n    a(x)\n\n    puts <<~TEXT\n      hi\n    TEXT\n  end\nend\n",
+            b"for x in xs\n  next if x.nil?\n  a(x)\n\n  puts <<~TEXT\n      hi\n    TEXT\nend\n",
+        );
+    }
+
+    #[test]
+    fn always_style_flags_and_corrects_modifier_forms() {
+        let source = b"items.each do |item|\n  process(item) if item.valid?\nend\n";
+        let always = || config("EnforcedStyle", serde_yml::Value::String("always".into()));
+        assert_eq!(run_cop_full_with_config(&Next, source, always()).len(), 1);
+        assert_cop_autocorrect_with_config(
+            &Next,
+            source,
+            b"items.each do |item|\n  next unless item.valid?\n  process(item)\nend\n",
+            always(),
+        );
+    }
+
+    #[test]
+    fn allow_consecutive_conditionals() {
+        let source = b"items.each do |item|\n  if a\n    x\n  end\n  if item.valid?\n    a(item)\n    b(item)\n    c(item)\n  end\nend\n";
+        assert_eq!(
+            run_cop_full_with_config(&Next, source, CopConfig::default()).len(),
+            1
+        );
+        let allowed = config("AllowConsecutiveConditionals", serde_yml::Value::Bool(true));
+        assert!(run_cop_full_with_config(&Next, source, allowed).is_empty());
+    }
+
+    #[test]
+    fn min_body_length_and_break_bodies() {
+        let min_one = || config("MinBodyLength", serde_yml::Value::Number(1.into()));
+        let short = b"items.each do |item|\n  if item.valid?\n    work(item)\n  end\nend\n";
+        assert!(run_cop_full_with_config(&Next, short, CopConfig::default()).is_empty());
+        assert_eq!(run_cop_full_with_config(&Next, short, min_one()).len(), 1);
+
+        let breaking = b"items.each do |item|\n  if item.last?\n    break\n  end\nend\n";
+        assert!(run_cop_full_with_config(&Next, breaking, min_one()).is_empty());
+    }
 }
@@ -26,6 +26,11 @@ impl Cop for NumberedParameters {
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.7
+        if !config.ruby_version_at_least(2.7) {
+            return;
+        }
+
         let style = config.get_str("EnforcedStyle", "allow_single_line");
 
         // Handle LambdaNode (-> do...end / -> {...}) with numbered parameters.
@@ -64,6 +64,11 @@ impl Cop for NumberedParametersLimit {
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.7
+        if !config.ruby_version_at_least(2.7) {
+            return;
+        }
+
         let max = config.get_usize("Max", 1).min(9);
 
         let (location, unique_count) = if let Some(lambda) = node.as_lambda_node() {
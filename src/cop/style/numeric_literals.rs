@@ -1,5 +1,6 @@
 use crate::cop::shared::node_type::{FLOAT_NODE, INTEGER_NODE};
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -40,6 +41,13 @@ const MSG: &str =
 ///   (for example `1000000000000000000000000000000i` and `12345.6i`). RuboCop's `on_int` and
 ///   `on_float` callbacks only run for standalone numeric nodes, so these suffixed literals
 ///   must be skipped when the next source byte is `i` or `r`.
+///
+/// ## Autocorrect
+///
+/// Like RuboCop's `format_number`, the integer part is regrouped in threes
+/// from the right (existing underscores are dropped first) and the sign,
+/// fraction and exponent are kept as written: `-1234567.5e3` becomes
+/// `-1_234_567.5e3`.
 pub struct NumericLiterals;
 
 /// Check if a numeric string has underscores at every 3-digit grouping from the right.
@@ -81,6 +89,44 @@ fn has_bad_grouping(text: &str, strict: bool) -> bool {
         .any(|group| matches!(group.len(), 1 | 2))
 }
 
+/// Regroup the integer part of a numeric literal in threes from the right,
+/// leaving its sign and any fraction/exponent part untouched.
+fn format_number(text: &str) -> String {
+    let unsigned = text.trim_start_matches(['-', '+']);
+    let sign = &text[..text.len() - unsigned.len()];
+    let split = unsigned.find(['e', 'E', '.']).unwrap_or(unsigned.len());
+    let (int_part, rest) = unsigned.split_at(split);
+
+    let digits: Vec<char> = int_part.chars().filter(|c| c.is_ascii_digit()).collect();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push('_');
+        }
+        grouped.push(*digit);
+    }
+    format!("{sign}{grouped}{rest}")
+}
+
+/// `AllowedNumbers` entries as strings; RuboCop accepts both `10000` and
+/// `'10000'` in the config.
+fn allowed_numbers(config: &CopConfig) -> Vec<String> {
+    config
+        .options
+        .get("AllowedNumbers")
+        .and_then(|v| v.as_sequence())
+        .map(|seq| {
+            seq.iter()
+                .filter_map(|item| match item {
+                    serde_yml::Value::String(s) => Some(s.clone()),
+                    serde_yml::Value::Number(n) => Some(n.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 impl NumericLiterals {
     fn has_complex_or_rational_suffix(
         &self,
@@ -103,12 +149,11 @@ impl NumericLiterals {
         int_part: &str,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let min_digits = config.get_usize("MinDigits", 5);
         let strict = config.get_bool("Strict", false);
-        let allowed_numbers = config
-            .get_string_array("AllowedNumbers")
-            .unwrap_or_default();
+        let allowed_numbers = allowed_numbers(config);
         let allowed_patterns = config
             .get_string_array("AllowedPatterns")
             .unwrap_or_default();
@@ -140,22 +185,28 @@ impl NumericLiterals {
             }
         }
 
-        let has_underscores = int_part.contains('_');
-
         if int_part.len() < min_digits {
             return;
         }
 
-        if !has_underscores {
-            let (line, column) = source.offset_to_line_col(loc.start_offset());
-            diagnostics.push(self.diagnostic(source, line, column, MSG.to_string()));
+        if int_part.contains('_') && !has_bad_grouping(int_part, strict) {
             return;
         }
 
-        if has_bad_grouping(int_part, strict) {
-            let (line, column) = source.offset_to_line_col(loc.start_offset());
-            diagnostics.push(self.diagnostic(source, line, column, MSG.to_string()));
+        let (line, column) = source.offset_to_line_col(loc.start_offset());
+        let mut diagnostic = self.diagnostic(source, line, column, MSG.to_string());
+        if let Some(corrections) = corrections {
+            let text = std::str::from_utf8(loc.as_slice()).unwrap_or("");
+            corrections.push(Correction {
+                start: loc.start_offset(),
+                end: loc.end_offset(),
+                replacement: format_number(text),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diagnostic.corrected = true;
         }
+        diagnostics.push(diagnostic);
     }
 }
 
@@ -164,6 +215,10 @@ impl Cop for NumericLiterals {
         "Style/NumericLiterals"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[INTEGER_NODE, FLOAT_NODE]
     }
@@ -175,7 +230,7 @@ impl Cop for NumericLiterals {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         // Get the source location and text for either integer or float nodes
         let loc = if let Some(int_node) = node.as_integer_node() {
@@ -201,7 +256,7 @@ impl Cop for NumericLiterals {
             .unwrap_or(text);
         let int_part = unsigned.split(['e', 'E', '.']).next().unwrap_or(unsigned);
 
-        self.check_integer_part(source, &loc, int_part, config, diagnostics);
+        self.check_integer_part(source, &loc, int_part, config, diagnostics, corrections);
     }
 }
 
@@ -211,6 +266,68 @@ mod tests {
 
     crate::cop_fixture_tests!(NumericLiterals, "cops/style/numeric_literals");
 
+    fn strict_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([
+                ("MinDigits".into(), serde_yml::Value::Number(7.into())),
+                ("Strict".into(), serde_yml::Value::Bool(true)),
+            ]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn autocorrect_groups_integer_part() {
+        crate::testutil::assert_cop_autocorrect(
+            &NumericLiterals,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/numeric_literals/offense.autocorrect.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/numeric_literals/corrected.autocorrect.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn offense_strict() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &NumericLiterals,
+            include_bytes!("../../../tests/fixtures/cops/style/numeric_literals/offense.strict.rb"),
+            strict_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_strict() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &NumericLiterals,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/numeric_literals/no_offense.strict.rb"
+            ),
+            strict_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_strict() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &NumericLiterals,
+            include_bytes!("../../../tests/fixtures/cops/style/numeric_literals/offense.strict.rb"),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/numeric_literals/corrected.strict.rb"
+            ),
+            strict_config(),
+        );
+    }
+
+    #[test]
+    fn format_number_keeps_sign_fraction_and_exponent() {
+        assert_eq!(format_number("1234"), "1_234");
+        assert_eq!(format_number("123"), "123");
+        assert_eq!(format_number("-1_23_4567.0_1e5"), "-1_234_567.0_1e5");
+    }
+
     #[test]
     fn config_min_digits_3() {
         use crate::testutil::run_cop_full_with_config;
@@ -276,4 +393,20 @@ mod tests {
         let diags = run_cop_full_with_config(&NumericLiterals, source, config);
         assert!(diags.is_empty(), "AllowedNumbers should exempt 10000");
     }
+
+    #[test]
+    fn allowed_numbers_accepts_integer_entries() {
+        use crate::testutil::run_cop_full_with_config;
+        use std::collections::HashMap;
+
+        let config = CopConfig {
+            options: HashMap::from([(
+                "AllowedNumbers".into(),
+                serde_yml::Value::Sequence(vec![serde_yml::Value::Number(3000000.into())]),
+            )]),
+            ..CopConfig::default()
+        };
+        let diags = run_cop_full_with_config(&NumericLiterals, b"x = 3000000\n", config);
+        assert!(diags.is_empty(), "AllowedNumbers should exempt 3000000");
+    }
 }
@@ -23,6 +23,11 @@ impl Cop for ObjectThen {
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
+        // RuboCop: minimum_target_ruby_version 2.6
+        if !config.ruby_version_at_least(2.6) {
+            return;
+        }
+
         let enforced_style = config.get_str("EnforcedStyle", "then");
 
         let call = match node.as_call_node() {
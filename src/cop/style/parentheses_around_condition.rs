@@ -244,34 +244,98 @@ fn should_skip(
     false
 }
 
+/// Whether the condition uses a low-precedence `and`/`or` keyword outside of
+/// nested parentheses. Unwrapping `foo if (a or b)` would give
+/// `(foo if a) or b`.
+fn has_keyword_operator(node: &ruby_prism::Node<'_>) -> bool {
+    if let Some(and) = node.as_and_node() {
+        return and.operator_loc().as_slice() == b"and"
+            || has_keyword_operator(&and.left())
+            || has_keyword_operator(&and.right());
+    }
+    if let Some(or) = node.as_or_node() {
+        return or.operator_loc().as_slice() == b"or"
+            || has_keyword_operator(&or.left())
+            || has_keyword_operator(&or.right());
+    }
+    false
+}
+
+/// Whether an `if`/`unless`/`while`/`until` is in modifier form (`foo if bar`).
+fn is_modifier_form(node: &ruby_prism::Node<'_>, keyword_offset: usize) -> bool {
+    node.location().start_offset() != keyword_offset
+}
+
 /// Add corrections to remove opening and closing parentheses.
+///
+/// When the parentheses sit on their own lines (`if (\n  cond\n)`), the
+/// line breaks next to them go too, joining the condition onto the keyword
+/// line.
 fn add_paren_corrections(
     cop: &ParenthesesAroundCondition,
+    source: &SourceFile,
     paren: &ruby_prism::ParenthesesNode<'_>,
+    is_modifier: bool,
     corrections: &mut Option<&mut Vec<crate::correction::Correction>>,
     diag: &mut Diagnostic,
 ) {
-    if let Some(corr) = corrections {
-        let open_loc = paren.opening_loc();
-        let close_loc = paren.closing_loc();
-        // Remove opening paren
-        corr.push(crate::correction::Correction {
-            start: open_loc.start_offset(),
-            end: open_loc.end_offset(),
-            replacement: String::new(),
-            cop_name: cop.name(),
-            cop_index: 0,
-        });
-        // Remove closing paren
-        corr.push(crate::correction::Correction {
-            start: close_loc.start_offset(),
-            end: close_loc.end_offset(),
-            replacement: String::new(),
-            cop_name: cop.name(),
-            cop_index: 0,
-        });
-        diag.corrected = true;
+    let Some(corr) = corrections else {
+        return;
+    };
+    if is_modifier && get_single_inner_node(paren).is_some_and(|n| has_keyword_operator(&n)) {
+        return;
     }
+
+    let bytes = source.as_bytes();
+    let is_blank = |b: &u8| *b == b' ' || *b == b'\t' || *b == b'\r';
+
+    let open_start = paren.opening_loc().start_offset();
+    let mut open_end = paren.opening_loc().end_offset();
+    let after_open = open_end + bytes[open_end..].iter().take_while(|b| is_blank(b)).count();
+    if bytes.get(after_open) == Some(&b'\n') {
+        open_end = after_open
+            + 1
+            + bytes[after_open + 1..]
+                .iter()
+                .take_while(|b| is_blank(b))
+                .count();
+    }
+
+    let close_end = paren.closing_loc().end_offset();
+    let mut close_start = paren.closing_loc().start_offset();
+    let before_close = close_start
+        - bytes[..close_start]
+            .iter()
+            .rev()
+            .take_while(|b| is_blank(b))
+            .count();
+    if before_close > open_end && bytes[before_close - 1] == b'\n' {
+        close_start = before_close
+            - 1
+            - bytes[..before_close - 1]
+                .iter()
+                .rev()
+                .take_while(|b| is_blank(b))
+                .count();
+    }
+
+    // Remove opening paren
+    corr.push(crate::correction::Correction {
+        start: open_start,
+        end: open_end,
+        replacement: String::new(),
+        cop_name: cop.name(),
+        cop_index: 0,
+    });
+    // Remove closing paren
+    corr.push(crate::correction::Correction {
+        start: close_start,
+        end: close_end,
+        replacement: String::new(),
+        cop_name: cop.name(),
+        cop_index: 0,
+    });
+    diag.corrected = true;
 }
 
 impl Cop for ParenthesesAroundCondition {
@@ -316,8 +380,9 @@ impl Cop for ParenthesesAroundCondition {
                 ) {
                     return;
                 }
-                let keyword = if kw_loc.as_slice() == b"unless" {
-                    "unless"
+                // `if` or `elsif`
+                let keyword = if kw_loc.as_slice() == b"elsif" {
+                    "elsif"
                 } else {
                     "if"
                 };
@@ -329,7 +394,15 @@ impl Cop for ParenthesesAroundCondition {
                     column,
                     format!("Don't use parentheses around the condition of an `{keyword}`."),
                 );
-                add_paren_corrections(self, &paren, &mut corrections, &mut diag);
+                let is_modifier = is_modifier_form(node, kw_loc.start_offset());
+                add_paren_corrections(
+                    self,
+                    source,
+                    &paren,
+                    is_modifier,
+                    &mut corrections,
+                    &mut diag,
+                );
                 diagnostics.push(diag);
             }
         } else if let Some(unless_node) = node.as_unless_node() {
@@ -351,7 +424,15 @@ impl Cop for ParenthesesAroundCondition {
                     column,
                     "Don't use parentheses around the condition of an `unless`.".to_string(),
                 );
-                add_paren_corrections(self, &paren, &mut corrections, &mut diag);
+                let is_modifier = is_modifier_form(node, unless_node.keyword_loc().start_offset());
+                add_paren_corrections(
+                    self,
+                    source,
+                    &paren,
+                    is_modifier,
+                    &mut corrections,
+                    &mut diag,
+                );
                 diagnostics.push(diag);
             }
         } else if let Some(while_node) = node.as_while_node() {
@@ -376,7 +457,15 @@ impl Cop for ParenthesesAroundCondition {
                     column,
                     "Don't use parentheses around the condition of a `while`.".to_string(),
                 );
-                add_paren_corrections(self, &paren, &mut corrections, &mut diag);
+                let is_modifier = is_modifier_form(node, while_node.keyword_loc().start_offset());
+                add_paren_corrections(
+                    self,
+                    source,
+                    &paren,
+                    is_modifier,
+                    &mut corrections,
+                    &mut diag,
+                );
                 diagnostics.push(diag);
             }
         } else if let Some(until_node) = node.as_until_node() {
@@ -401,7 +490,15 @@ impl Cop for ParenthesesAroundCondition {
                     column,
                     "Don't use parentheses around the condition of an `until`.".to_string(),
                 );
-                add_paren_corrections(self, &paren, &mut corrections, &mut diag);
+                let is_modifier = is_modifier_form(node, until_node.keyword_loc().start_offset());
+                add_paren_corrections(
+                    self,
+                    source,
+                    &paren,
+                    is_modifier,
+                    &mut corrections,
+                    &mut diag,
+                );
                 diagnostics.push(diag);
             }
         }
@@ -466,4 +563,44 @@ mod tests {
         let diags = run_cop_full(&ParenthesesAroundCondition, source);
         assert_eq!(diags.len(), 1, "Should flag multiline parens by default");
     }
+
+    #[test]
+    fn no_offense_allow_in_multiline_conditions_fixture() {
+        crate::testutil::assert_cop_no_offenses_with_yaml(
+            &ParenthesesAroundCondition,
+            "AllowInMultilineConditions: true",
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/parentheses_around_condition/no_offense.allow_in_multiline_conditions.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn autocorrect_keeps_parens_that_group_keyword_operators_in_modifiers() {
+        use crate::testutil::run_cop_autocorrect;
+
+        // `foo if a or b` would parse as `(foo if a) or b`.
+        for source in [
+            &b"foo if (a or b)\n"[..],
+            b"foo unless (a and b)\n",
+            b"foo while (a && b or c)\n",
+            b"foo until (a and b)\n",
+        ] {
+            let (diags, corrections) = run_cop_autocorrect(&ParenthesesAroundCondition, source);
+            assert_eq!(diags.len(), 1);
+            assert!(!diags[0].corrected);
+            assert!(
+                corrections.is_empty(),
+                "{:?}",
+                String::from_utf8_lossy(source)
+            );
+        }
+
+        // Keyword operators are fine to unwrap in the non-modifier form, and
+        // symbolic operators in either form.
+        for source in [&b"if (a or b)\n  foo\nend\n"[..], b"foo if (a || b)\n"] {
+            let (_, corrections) = run_cop_autocorrect(&ParenthesesAroundCondition, source);
+            assert_eq!(corrections.len(), 2);
+        }
+    }
 }
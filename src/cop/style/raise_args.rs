@@ -1,5 +1,6 @@
 use crate::cop::shared::node_type::CALL_NODE;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -7,14 +8,24 @@ use crate::parse::source::SourceFile;
 /// - FP in rom-rb/rom: `raise(MissingAttribute.new { "..." })` — `.new` called with a block,
 ///   not regular args. The block form can't be converted to exploded `raise Error, msg` style.
 ///   Fixed by checking `arg_call.block().is_some()` before flagging.
+///
+/// ## Autocorrect
+///
+/// Only the arguments are rewritten, so `raise(...)` keeps its parentheses:
+/// `exploded` turns `raise Foo.new(msg)` into `raise Foo, msg`, and
+/// `compact` turns `raise Foo, msg` into `raise Foo.new(msg)`. Compact
+/// offenses with a splat or hash, a block argument, or more than a class and a
+/// message (`raise Foo, msg, backtrace`) are reported but not corrected.
 pub struct RaiseArgs;
 
-/// Extract the constant name from a node by reading its source text.
-fn extract_const_name(node: &ruby_prism::Node<'_>) -> String {
-    let loc = node.location();
-    std::str::from_utf8(loc.as_slice())
-        .unwrap_or("")
-        .to_string()
+/// The constant name of `AllowedCompactTypes` entries: `Foo::Bar` for
+/// `Foo::Bar` and `::Foo::Bar`. `None` for receivers that aren't constants.
+fn const_name(node: &ruby_prism::Node<'_>) -> Option<String> {
+    if node.as_constant_read_node().is_none() && node.as_constant_path_node().is_none() {
+        return None;
+    }
+    let text = std::str::from_utf8(node.location().as_slice()).ok()?;
+    Some(text.trim_start_matches("::").to_string())
 }
 
 /// Check if the argument to `.new` is an acceptable type that can't be
@@ -32,6 +43,10 @@ impl Cop for RaiseArgs {
         "Style/RaiseArgs"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[CALL_NODE]
     }
@@ -43,7 +58,7 @@ impl Cop for RaiseArgs {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let call = match node.as_call_node() {
             Some(c) => c,
@@ -64,8 +79,10 @@ impl Cop for RaiseArgs {
         let enforced_style = config.get_str("EnforcedStyle", "exploded");
 
         match enforced_style {
-            "exploded" => self.check_exploded(source, &call, config, diagnostics, method_name),
-            "compact" => self.check_compact(source, &call, diagnostics, method_name),
+            "exploded" => {
+                self.check_exploded(source, &call, config, diagnostics, corrections, method_name)
+            }
+            "compact" => self.check_compact(source, &call, diagnostics, corrections, method_name),
             _ => {}
         }
     }
@@ -78,6 +95,7 @@ impl RaiseArgs {
         call: &ruby_prism::CallNode<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
         method_name: &str,
     ) {
         let args = match call.arguments() {
@@ -111,34 +129,50 @@ impl RaiseArgs {
         };
 
         // Check if .new has multiple arguments — allow raise Ex.new(arg1, arg2)
-        if let Some(new_args) = arg_call.arguments() {
-            let new_arg_list: Vec<_> = new_args.arguments().iter().collect();
-            if new_arg_list.len() > 1 {
-                return;
-            }
-            // Single arg: check if it's a hash, splat, or forwarding arg
-            if new_arg_list.len() == 1 && is_acceptable_exploded_arg(&new_arg_list[0]) {
-                return;
-            }
+        let new_arg_list: Vec<_> = arg_call
+            .arguments()
+            .map(|a| a.arguments().iter().collect())
+            .unwrap_or_default();
+        if new_arg_list.len() > 1 {
+            return;
+        }
+        // Single arg: check if it's a hash, splat, or forwarding arg
+        if new_arg_list.len() == 1 && is_acceptable_exploded_arg(&new_arg_list[0]) {
+            return;
         }
 
         // Check AllowedCompactTypes
         let allowed_compact_types = config
             .get_string_array("AllowedCompactTypes")
             .unwrap_or_default();
-        let const_name = extract_const_name(&receiver);
-        if !const_name.is_empty() && allowed_compact_types.iter().any(|t| t == &const_name) {
+        if const_name(&receiver).is_some_and(|name| allowed_compact_types.contains(&name)) {
             return;
         }
 
         let loc = call.message_loc().unwrap_or_else(|| call.location());
         let (line, column) = source.offset_to_line_col(loc.start_offset());
-        diagnostics.push(self.diagnostic(
+        let mut diagnostic = self.diagnostic(
             source,
             line,
             column,
             format!("Provide an exception class and message as arguments to `{method_name}`."),
-        ));
+        );
+        if let Some(corrections) = corrections {
+            let mut replacement = receiver.location().as_slice().to_vec();
+            if let Some(message) = new_arg_list.first() {
+                replacement.extend_from_slice(b", ");
+                replacement.extend_from_slice(message.location().as_slice());
+            }
+            corrections.push(Correction {
+                start: args.location().start_offset(),
+                end: args.location().end_offset(),
+                replacement: String::from_utf8_lossy(&replacement).into_owned(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diagnostic.corrected = true;
+        }
+        diagnostics.push(diagnostic);
     }
 
     fn check_compact(
@@ -146,6 +180,7 @@ impl RaiseArgs {
         source: &SourceFile,
         call: &ruby_prism::CallNode<'_>,
         diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
         method_name: &str,
     ) {
         let args = match call.arguments() {
@@ -177,12 +212,30 @@ impl RaiseArgs {
 
         let loc = call.message_loc().unwrap_or_else(|| call.location());
         let (line, column) = source.offset_to_line_col(loc.start_offset());
-        diagnostics.push(self.diagnostic(
+        let mut diagnostic = self.diagnostic(
             source,
             line,
             column,
             format!("Provide an exception object as an argument to `{method_name}`."),
-        ));
+        );
+        let correctable = arg_list.len() == 2
+            && call.block().is_none()
+            && !arg_list.iter().any(is_acceptable_exploded_arg);
+        if let Some(corrections) = corrections.filter(|_| correctable) {
+            let mut replacement = arg_list[0].location().as_slice().to_vec();
+            replacement.extend_from_slice(b".new(");
+            replacement.extend_from_slice(arg_list[1].location().as_slice());
+            replacement.push(b')');
+            corrections.push(Correction {
+                start: args.location().start_offset(),
+                end: args.location().end_offset(),
+                replacement: String::from_utf8_lossy(&replacement).into_owned(),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diagnostic.corrected = true;
+        }
+        diagnostics.push(diagnostic);
     }
 }
 
@@ -192,6 +245,91 @@ mod tests {
     use crate::testutil::{run_cop_full, run_cop_full_with_config};
 
     crate::cop_fixture_tests!(RaiseArgs, "cops/style/raise_args");
+    crate::cop_autocorrect_fixture_tests!(RaiseArgs, "cops/style/raise_args");
+
+    fn compact_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String("compact".into()),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    fn allowed_compact_types_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "AllowedCompactTypes".into(),
+                serde_yml::Value::Sequence(vec![
+                    serde_yml::Value::String("MyWrappedError".into()),
+                    serde_yml::Value::String("Errors::Wrapped".into()),
+                ]),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_compact() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &RaiseArgs,
+            include_bytes!("../../../tests/fixtures/cops/style/raise_args/offense.compact.rb"),
+            compact_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_compact() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &RaiseArgs,
+            include_bytes!("../../../tests/fixtures/cops/style/raise_args/no_offense.compact.rb"),
+            compact_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_compact() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &RaiseArgs,
+            include_bytes!("../../../tests/fixtures/cops/style/raise_args/offense.compact.rb"),
+            include_bytes!("../../../tests/fixtures/cops/style/raise_args/corrected.compact.rb"),
+            compact_config(),
+        );
+    }
+
+    #[test]
+    fn offense_allowed_compact_types() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &RaiseArgs,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/raise_args/offense.allowed_compact_types.rb"
+            ),
+            allowed_compact_types_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_allowed_compact_types() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &RaiseArgs,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/raise_args/no_offense.allowed_compact_types.rb"
+            ),
+            allowed_compact_types_config(),
+        );
+    }
+
+    #[test]
+    fn compact_keeps_block_argument_uncorrected() {
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect_with_config(
+            &RaiseArgs,
+            b"raise MyError, msg, &blk\n",
+            compact_config(),
+        );
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty());
+    }
 
     #[test]
     fn bare_raise_is_ignored() {
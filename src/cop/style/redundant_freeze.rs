@@ -243,11 +243,7 @@ impl Cop for RedundantFreeze {
             None => return,
         };
 
-        let target_ruby_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-            .unwrap_or(2.7);
+        let target_ruby_version = config.target_ruby_version();
 
         let frozen_strings = Self::frozen_string_literals_enabled(source);
 
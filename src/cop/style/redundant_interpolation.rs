@@ -32,7 +32,7 @@ impl Cop for RedundantInterpolation {
             source,
             in_implicit_concat: false,
             in_percent_array: false,
-            target_ruby_version: target_ruby_version(config),
+            target_ruby_version: config.target_ruby_version(),
             diagnostics: Vec::new(),
         };
         visitor.visit(&parse_result.node());
@@ -152,18 +152,6 @@ impl RedundantInterpVisitor<'_, '_> {
     }
 }
 
-fn target_ruby_version(config: &CopConfig) -> f64 {
-    config
-        .options
-        .get("TargetRubyVersion")
-        .and_then(|value| {
-            value
-                .as_f64()
-                .or_else(|| value.as_u64().map(|value| value as f64))
-        })
-        .unwrap_or(2.7)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,12 +24,7 @@ impl Cop for RedundantInterpolationUnfreeze {
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         // minimum_target_ruby_version 3.0 — only applies for Ruby 3.0+
-        let ruby_version = config
-            .options
-            .get("TargetRubyVersion")
-            .and_then(|v| v.as_f64())
-            .unwrap_or(3.4);
-        if ruby_version < 3.0 {
+        if !config.ruby_version_at_least(3.0) {
             return;
         }
 
@@ -104,8 +99,35 @@ impl Cop for RedundantInterpolationUnfreeze {
 #[cfg(test)]
 mod tests {
     use super::*;
-    crate::cop_fixture_tests!(
-        RedundantInterpolationUnfreeze,
-        "cops/style/redundant_interpolation_unfreeze"
-    );
+
+    fn ruby30_config() -> CopConfig {
+        let mut config = CopConfig::default();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::Number::from(3.0)),
+        );
+        config
+    }
+
+    #[test]
+    fn offense_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &RedundantInterpolationUnfreeze,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/redundant_interpolation_unfreeze/offense.rb"
+            ),
+            ruby30_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &RedundantInterpolationUnfreeze,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/redundant_interpolation_unfreeze/no_offense.rb"
+            ),
+            ruby30_config(),
+        );
+    }
 }
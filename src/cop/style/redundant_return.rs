@@ -1,5 +1,6 @@
 use crate::cop::shared::node_type::{CALL_NODE, DEF_NODE, LAMBDA_NODE};
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -17,6 +18,12 @@ use crate::parse::source::SourceFile;
 /// flag `return` inside ternary branches. Also skips checking the main body
 /// of `begin/rescue/else` when an else clause is present, since the else
 /// clause determines the return value (not the main body).
+///
+/// With `AllowMultipleReturnValues: false` (the default), `return a, b` is
+/// reported with a hint to use an array, and autocorrected to `[a, b]`.
+/// Autocorrect drops the `return` keyword, bracing a braceless hash
+/// (`return a: 1` → `{a: 1}`) and unwrapping a single splat. As in RuboCop,
+/// a bare `return` is replaced with `nil` rather than removed.
 pub struct RedundantReturn;
 
 impl Cop for RedundantReturn {
@@ -24,6 +31,10 @@ impl Cop for RedundantReturn {
         "Style/RedundantReturn"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[DEF_NODE, CALL_NODE, LAMBDA_NODE]
     }
@@ -35,14 +46,20 @@ impl Cop for RedundantReturn {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
-        let allow_multiple = config.get_bool("AllowMultipleReturnValues", false);
+        let mut checker = TerminalChecker {
+            cop: self,
+            source,
+            allow_multiple: config.get_bool("AllowMultipleReturnValues", false),
+            diagnostics,
+            corrections,
+        };
 
         // DefNode: check the method body
         if let Some(def_node) = node.as_def_node() {
             if let Some(body) = def_node.body() {
-                check_terminal(self, source, &body, allow_multiple, diagnostics);
+                checker.check_terminal(&body);
             }
             return;
         }
@@ -56,7 +73,7 @@ impl Cop for RedundantReturn {
                     if let Some(block) = call_node.block() {
                         if let Some(block_node) = block.as_block_node() {
                             if let Some(body) = block_node.body() {
-                                check_terminal(self, source, &body, allow_multiple, diagnostics);
+                                checker.check_terminal(&body);
                             }
                         }
                     }
@@ -69,194 +86,224 @@ impl Cop for RedundantReturn {
         // LambdaNode: check stabby lambda body (-> { ... })
         if let Some(lambda_node) = node.as_lambda_node() {
             if let Some(body) = lambda_node.body() {
-                check_terminal(self, source, &body, allow_multiple, diagnostics);
+                checker.check_terminal(&body);
             }
         }
     }
 }
 
-/// Recursively check terminal positions for redundant `return` statements.
-/// A terminal position is the last expression that would be implicitly returned.
-fn check_terminal(
-    cop: &RedundantReturn,
-    source: &SourceFile,
-    node: &ruby_prism::Node<'_>,
+/// Walks the terminal positions of one method, block or lambda body.
+struct TerminalChecker<'a> {
+    cop: &'a RedundantReturn,
+    source: &'a SourceFile,
     allow_multiple: bool,
-    diagnostics: &mut Vec<Diagnostic>,
-) {
-    // StatementsNode: check the last statement
-    if let Some(stmts) = node.as_statements_node() {
-        if let Some(last) = stmts.body().last() {
-            check_terminal(cop, source, &last, allow_multiple, diagnostics);
-        }
-        return;
-    }
+    diagnostics: &'a mut Vec<Diagnostic>,
+    corrections: Option<&'a mut Vec<Correction>>,
+}
 
-    // ReturnNode: this is a redundant return in terminal position
-    if let Some(ret_node) = node.as_return_node() {
-        if allow_multiple {
-            let arg_count = ret_node.arguments().map_or(0, |a| a.arguments().len());
-            if arg_count > 1 {
-                return;
+impl TerminalChecker<'_> {
+    /// Recursively check terminal positions for redundant `return` statements.
+    /// A terminal position is the last expression that would be implicitly returned.
+    fn check_terminal(&mut self, node: &ruby_prism::Node<'_>) {
+        // StatementsNode: check the last statement
+        if let Some(stmts) = node.as_statements_node() {
+            if let Some(last) = stmts.body().last() {
+                self.check_terminal(&last);
             }
+            return;
         }
-        let loc = node.location();
-        let (line, column) = source.offset_to_line_col(loc.start_offset());
-        diagnostics.push(cop.diagnostic(
-            source,
-            line,
-            column,
-            "Redundant `return` detected.".to_string(),
-        ));
-        return;
-    }
-
-    // RescueModifierNode: `return expr rescue fallback` — check the inner expression
-    if let Some(rescue_mod) = node.as_rescue_modifier_node() {
-        check_terminal(
-            cop,
-            source,
-            &rescue_mod.expression(),
-            allow_multiple,
-            diagnostics,
-        );
-        return;
-    }
 
-    // IfNode: check terminal position in each branch (skip ternary expressions)
-    if let Some(if_node) = node.as_if_node() {
-        // Ternary expressions (a ? b : c) have no if_keyword_loc
-        if if_node.if_keyword_loc().is_none() {
+        // ReturnNode: this is a redundant return in terminal position
+        if let Some(ret_node) = node.as_return_node() {
+            self.check_return(&ret_node);
             return;
         }
-        if let Some(stmts) = if_node.statements() {
-            check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
+
+        // RescueModifierNode: `return expr rescue fallback` — check the inner expression
+        if let Some(rescue_mod) = node.as_rescue_modifier_node() {
+            self.check_terminal(&rescue_mod.expression());
+            return;
         }
-        if let Some(subsequent) = if_node.subsequent() {
-            if let Some(elsif) = subsequent.as_if_node() {
-                check_terminal(cop, source, &elsif.as_node(), allow_multiple, diagnostics);
-            } else if let Some(else_node) = subsequent.as_else_node() {
-                if let Some(stmts) = else_node.statements() {
-                    check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
+
+        // IfNode: check terminal position in each branch (skip ternary expressions)
+        if let Some(if_node) = node.as_if_node() {
+            // Ternary expressions (a ? b : c) have no if_keyword_loc
+            if if_node.if_keyword_loc().is_none() {
+                return;
+            }
+            if let Some(stmts) = if_node.statements() {
+                self.check_terminal_stmts(&stmts);
+            }
+            if let Some(subsequent) = if_node.subsequent() {
+                if let Some(elsif) = subsequent.as_if_node() {
+                    self.check_terminal(&elsif.as_node());
+                } else if let Some(else_node) = subsequent.as_else_node() {
+                    if let Some(stmts) = else_node.statements() {
+                        self.check_terminal_stmts(&stmts);
+                    }
                 }
             }
+            return;
         }
-        return;
-    }
 
-    // UnlessNode: check terminal position in each branch
-    if let Some(unless_node) = node.as_unless_node() {
-        if let Some(stmts) = unless_node.statements() {
-            check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
-        }
-        if let Some(else_clause) = unless_node.else_clause() {
-            if let Some(stmts) = else_clause.statements() {
-                check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
+        // UnlessNode: check terminal position in each branch
+        if let Some(unless_node) = node.as_unless_node() {
+            if let Some(stmts) = unless_node.statements() {
+                self.check_terminal_stmts(&stmts);
             }
+            if let Some(else_clause) = unless_node.else_clause() {
+                if let Some(stmts) = else_clause.statements() {
+                    self.check_terminal_stmts(&stmts);
+                }
+            }
+            return;
         }
-        return;
-    }
 
-    // CaseNode: check terminal position in each when/else branch
-    if let Some(case_node) = node.as_case_node() {
-        for condition in case_node.conditions().iter() {
-            if let Some(when_node) = condition.as_when_node() {
-                if let Some(stmts) = when_node.statements() {
-                    check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
+        // CaseNode: check terminal position in each when/else branch
+        if let Some(case_node) = node.as_case_node() {
+            for condition in case_node.conditions().iter() {
+                if let Some(when_node) = condition.as_when_node() {
+                    if let Some(stmts) = when_node.statements() {
+                        self.check_terminal_stmts(&stmts);
+                    }
                 }
             }
-        }
-        if let Some(else_clause) = case_node.else_clause() {
-            if let Some(stmts) = else_clause.statements() {
-                check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
+            if let Some(else_clause) = case_node.else_clause() {
+                if let Some(stmts) = else_clause.statements() {
+                    self.check_terminal_stmts(&stmts);
+                }
             }
+            return;
         }
-        return;
-    }
 
-    // CaseMatchNode: check terminal position in each in/else branch (pattern matching)
-    if let Some(case_match_node) = node.as_case_match_node() {
-        for condition in case_match_node.conditions().iter() {
-            if let Some(in_node) = condition.as_in_node() {
-                if let Some(stmts) = in_node.statements() {
-                    check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
+        // CaseMatchNode: check terminal position in each in/else branch (pattern matching)
+        if let Some(case_match_node) = node.as_case_match_node() {
+            for condition in case_match_node.conditions().iter() {
+                if let Some(in_node) = condition.as_in_node() {
+                    if let Some(stmts) = in_node.statements() {
+                        self.check_terminal_stmts(&stmts);
+                    }
                 }
             }
-        }
-        if let Some(else_clause) = case_match_node.else_clause() {
-            if let Some(stmts) = else_clause.statements() {
-                check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
+            if let Some(else_clause) = case_match_node.else_clause() {
+                if let Some(stmts) = else_clause.statements() {
+                    self.check_terminal_stmts(&stmts);
+                }
             }
+            return;
         }
-        return;
-    }
 
-    // BeginNode: check statements body and rescue clauses
-    if let Some(begin_node) = node.as_begin_node() {
-        let has_rescue = begin_node.rescue_clause().is_some();
-        let has_else = begin_node.else_clause().is_some();
+        // BeginNode: check statements body and rescue clauses
+        if let Some(begin_node) = node.as_begin_node() {
+            let has_rescue = begin_node.rescue_clause().is_some();
+            let has_else = begin_node.else_clause().is_some();
 
-        // Only check main body if there's no rescue+else combination.
-        // When rescue has an else clause, the else clause's value is the
-        // return value (not the main body), so returns in the main body
-        // are early exits, not redundant.
-        if !has_rescue || !has_else {
-            if let Some(stmts) = begin_node.statements() {
-                check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
+            // Only check main body if there's no rescue+else combination.
+            // When rescue has an else clause, the else clause's value is the
+            // return value (not the main body), so returns in the main body
+            // are early exits, not redundant.
+            if !has_rescue || !has_else {
+                if let Some(stmts) = begin_node.statements() {
+                    self.check_terminal_stmts(&stmts);
+                }
             }
+            // Check rescue clauses
+            if let Some(rescue) = begin_node.rescue_clause() {
+                self.check_rescue_terminal(&rescue);
+            }
+            // Check else clause on begin/rescue/else
+            if let Some(else_clause) = begin_node.else_clause() {
+                if let Some(stmts) = else_clause.statements() {
+                    self.check_terminal_stmts(&stmts);
+                }
+            }
+            return;
         }
-        // Check rescue clauses
-        if let Some(rescue) = begin_node.rescue_clause() {
-            check_rescue_terminal(cop, source, &rescue, allow_multiple, diagnostics);
-        }
-        // Check else clause on begin/rescue/else
-        if let Some(else_clause) = begin_node.else_clause() {
-            if let Some(stmts) = else_clause.statements() {
-                check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
+
+        // RescueNode (implicit rescue on def body): check each rescue clause
+        if let Some(rescue_node) = node.as_rescue_node() {
+            // The rescue node's own statements
+            if let Some(stmts) = rescue_node.statements() {
+                self.check_terminal_stmts(&stmts);
+            }
+            // Subsequent rescue clauses
+            if let Some(subsequent) = rescue_node.subsequent() {
+                self.check_rescue_terminal(&subsequent);
             }
         }
-        return;
     }
 
-    // RescueNode (implicit rescue on def body): check each rescue clause
-    if let Some(rescue_node) = node.as_rescue_node() {
-        // The rescue node's own statements
-        if let Some(stmts) = rescue_node.statements() {
-            check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
-        }
-        // Subsequent rescue clauses
-        if let Some(subsequent) = rescue_node.subsequent() {
-            check_rescue_terminal(cop, source, &subsequent, allow_multiple, diagnostics);
+    /// Check the last statement in a StatementsNode as a terminal position.
+    fn check_terminal_stmts(&mut self, stmts: &ruby_prism::StatementsNode<'_>) {
+        if let Some(last) = stmts.body().last() {
+            self.check_terminal(&last);
         }
     }
-}
 
-/// Check the last statement in a StatementsNode as a terminal position.
-fn check_terminal_stmts(
-    cop: &RedundantReturn,
-    source: &SourceFile,
-    stmts: &ruby_prism::StatementsNode<'_>,
-    allow_multiple: bool,
-    diagnostics: &mut Vec<Diagnostic>,
-) {
-    if let Some(last) = stmts.body().last() {
-        check_terminal(cop, source, &last, allow_multiple, diagnostics);
+    /// Recursively check rescue clause chains for redundant returns.
+    fn check_rescue_terminal(&mut self, rescue: &ruby_prism::RescueNode<'_>) {
+        if let Some(stmts) = rescue.statements() {
+            self.check_terminal_stmts(&stmts);
+        }
+        if let Some(subsequent) = rescue.subsequent() {
+            self.check_rescue_terminal(&subsequent);
+        }
     }
-}
 
-/// Recursively check rescue clause chains for redundant returns.
-fn check_rescue_terminal(
-    cop: &RedundantReturn,
-    source: &SourceFile,
-    rescue: &ruby_prism::RescueNode<'_>,
-    allow_multiple: bool,
-    diagnostics: &mut Vec<Diagnostic>,
-) {
-    if let Some(stmts) = rescue.statements() {
-        check_terminal_stmts(cop, source, &stmts, allow_multiple, diagnostics);
-    }
-    if let Some(subsequent) = rescue.subsequent() {
-        check_rescue_terminal(cop, source, &subsequent, allow_multiple, diagnostics);
+    /// Report a `return` in terminal position and drop its keyword, wrapping
+    /// multiple values in an array and a braceless hash in braces. A bare
+    /// `return` becomes `nil`.
+    fn check_return(&mut self, ret_node: &ruby_prism::ReturnNode<'_>) {
+        let args: Vec<_> = ret_node
+            .arguments()
+            .map(|a| a.arguments().iter().collect())
+            .unwrap_or_default();
+        if self.allow_multiple && args.len() > 1 {
+            return;
+        }
+        let message = if args.len() > 1 {
+            "Redundant `return` detected. To return multiple values, use an array."
+        } else {
+            "Redundant `return` detected."
+        };
+        let loc = ret_node.location();
+        let (line, column) = self.source.offset_to_line_col(loc.start_offset());
+        let mut diagnostic = self
+            .cop
+            .diagnostic(self.source, line, column, message.to_string());
+        if let Some(corrections) = self.corrections.as_deref_mut() {
+            let bytes = self.source.as_bytes();
+            let replacement = match args.as_slice() {
+                [] => "nil".to_string(),
+                [arg] => {
+                    let text = String::from_utf8_lossy(arg.location().as_slice());
+                    if let Some(splat) = arg.as_splat_node() {
+                        splat
+                            .expression()
+                            .map(|e| String::from_utf8_lossy(e.location().as_slice()).into_owned())
+                            .unwrap_or_else(|| text.into_owned())
+                    } else if arg.as_keyword_hash_node().is_some() {
+                        format!("{{{text}}}")
+                    } else {
+                        text.into_owned()
+                    }
+                }
+                [first, .., last] => {
+                    let start = first.location().start_offset();
+                    let end = last.location().end_offset();
+                    format!("[{}]", String::from_utf8_lossy(&bytes[start..end]))
+                }
+            };
+            corrections.push(Correction {
+                start: loc.start_offset(),
+                end: loc.end_offset(),
+                replacement,
+                cop_name: self.cop.name(),
+                cop_index: 0,
+            });
+            diagnostic.corrected = true;
+        }
+        self.diagnostics.push(diagnostic);
     }
 }
 
@@ -266,6 +313,7 @@ mod tests {
     use crate::testutil::{run_cop_full, run_cop_full_with_config};
 
     crate::cop_fixture_tests!(RedundantReturn, "cops/style/redundant_return");
+    crate::cop_autocorrect_fixture_tests!(RedundantReturn, "cops/style/redundant_return");
 
     #[test]
     fn allow_multiple_return_values() {
@@ -315,4 +363,13 @@ mod tests {
         let diags = run_cop_full_with_config(&RedundantReturn, source, config);
         assert_eq!(diags.len(), 1, "Single return should still be flagged");
     }
+
+    #[test]
+    fn autocorrect_unwraps_single_splat() {
+        crate::testutil::assert_cop_autocorrect(
+            &RedundantReturn,
+            b"def foo\n  return *items\n  ^^^^^^^^^^^^^ Style/RedundantReturn: Redundant `return` detected.\nend\n",
+            b"def foo\n  items\nend\n",
+        );
+    }
 }
@@ -1,8 +1,28 @@
 use crate::cop::shared::node_type::RESCUE_MODIFIER_NODE;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
+/// ## Autocorrect
+///
+/// Mirrors RuboCop: `foo rescue bar` becomes a `begin`/`rescue`/`end` block
+/// aligned with the column the modifier expression starts at, so an
+/// assignment's right-hand side stays under the `=`:
+///
+/// ```ruby
+/// x = begin
+///       foo
+///     rescue
+///       bar
+///     end
+/// ```
+///
+/// The body is indented by `IndentationWidth` (Layout/IndentationWidth's
+/// `Width` when unset). Only a modifier that stands alone on its line, or is
+/// the value of an assignment, is corrected; one inside parentheses, a
+/// one-line block, or followed by more code on the line is reported only, as
+/// is one whose expression contains a heredoc.
 pub struct RescueModifier;
 
 impl Cop for RescueModifier {
@@ -14,14 +34,18 @@ impl Cop for RescueModifier {
         &[RESCUE_MODIFIER_NODE]
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_node(
         &self,
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let rescue_mod = match node.as_rescue_modifier_node() {
             Some(r) => r,
@@ -31,13 +55,70 @@ impl Cop for RescueModifier {
         // RuboCop points at the whole rescue modifier expression, not just the `rescue` keyword
         let loc = rescue_mod.location();
         let (line, column) = source.offset_to_line_col(loc.start_offset());
-        diagnostics.push(self.diagnostic(
+        let mut diag = self.diagnostic(
             source,
             line,
             column,
             "Avoid rescuing without specifying an error class.".to_string(),
-        ));
+        );
+
+        if let Some(corr) = corrections {
+            if is_correctable(source, node, loc.start_offset(), loc.end_offset()) {
+                let offset = " ".repeat(column);
+                let indentation = " ".repeat(column + config.get_usize("IndentationWidth", 2));
+                let expression = rescue_mod.expression().location();
+                let rescue_expression = rescue_mod.rescue_expression().location();
+                let rescue_source = String::from_utf8_lossy(rescue_expression.as_slice());
+                corr.push(Correction {
+                    start: expression.start_offset(),
+                    end: expression.start_offset(),
+                    replacement: format!("begin\n{indentation}"),
+                    cop_name: self.name(),
+                    cop_index: 0,
+                });
+                corr.push(Correction {
+                    start: expression.end_offset(),
+                    end: loc.end_offset(),
+                    replacement: format!(
+                        "\n{offset}rescue\n{indentation}{rescue_source}\n{offset}end"
+                    ),
+                    cop_name: self.name(),
+                    cop_index: 0,
+                });
+                diag.corrected = true;
+            }
+        }
+        diagnostics.push(diag);
+    }
+}
+
+/// Whether the modifier at `start..end` can be expanded in place: only
+/// whitespace (or an assignment's `=`) before it on its line, only
+/// whitespace or a comment after it, and no heredoc inside.
+fn is_correctable(
+    source: &SourceFile,
+    node: &ruby_prism::Node<'_>,
+    start: usize,
+    end: usize,
+) -> bool {
+    let bytes = source.as_bytes();
+    let line_start = bytes[..start]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+    let before = bytes[line_start..start].trim_ascii();
+    if !before.is_empty() && !before.ends_with(b"=") {
+        return false;
+    }
+    let line_end = bytes[end..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |i| end + i);
+    let after = bytes[end..line_end].trim_ascii();
+    if !after.is_empty() && !after.starts_with(b"#") {
+        return false;
     }
+    crate::cop::shared::util::collect_heredoc_ranges(source, node).is_empty()
 }
 
 #[cfg(test)]
@@ -46,6 +127,7 @@ mod tests {
     use crate::testutil::run_cop_full;
 
     crate::cop_fixture_tests!(RescueModifier, "cops/style/rescue_modifier");
+    crate::cop_autocorrect_fixture_tests!(RescueModifier, "cops/style/rescue_modifier");
 
     #[test]
     fn inline_rescue_fires() {
@@ -54,4 +136,14 @@ mod tests {
         assert_eq!(diags.len(), 1);
         assert!(diags[0].message.contains("Avoid rescuing"));
     }
+
+    #[test]
+    fn autocorrect_uses_indentation_width() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &RescueModifier,
+            b"def load\n  data = read rescue nil\nend\n",
+            b"def load\n  data = begin\n             read\n         rescue\n             nil\n         end\nend\n",
+            crate::testutil::cop_config_from_yaml("IndentationWidth: 4\n"),
+        );
+    }
 }
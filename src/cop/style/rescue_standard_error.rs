@@ -1,8 +1,14 @@
 use crate::cop::shared::node_type::BEGIN_NODE;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
+/// ## Autocorrect
+///
+/// Mirrors RuboCop: `implicit` removes the `StandardError` class (leaving
+/// `rescue => e`), and `explicit` inserts ` StandardError` after the
+/// `rescue` keyword. A rescue listing several classes is never touched.
 pub struct RescueStandardError;
 
 fn check_rescue_node(
@@ -10,55 +16,66 @@ fn check_rescue_node(
     source: &SourceFile,
     rescue_node: &ruby_prism::RescueNode<'_>,
     enforced_style: &str,
-) -> Vec<Diagnostic> {
-    let mut diags = Vec::new();
-
+    diagnostics: &mut Vec<Diagnostic>,
+    mut corrections: Option<&mut Vec<Correction>>,
+) {
     let exceptions: Vec<_> = rescue_node.exceptions().iter().collect();
+    let kw_loc = rescue_node.keyword_loc();
 
-    match enforced_style {
+    // The message, and the edit that fixes it
+    let offense = match enforced_style {
         "implicit" => {
             // Handle both ConstantReadNode and constant_path_node (e.g. ::StandardError)
-            if exceptions.len() == 1 {
-                if let Some(name) =
-                    crate::cop::shared::constant_predicates::constant_short_name(&exceptions[0])
-                {
-                    if name == b"StandardError" {
-                        let kw_loc = rescue_node.keyword_loc();
-                        let (line, column) = source.offset_to_line_col(kw_loc.start_offset());
-                        diags.push(
-                            cop.diagnostic(
-                                source,
-                                line,
-                                column,
-                                "Omit the error class when rescuing `StandardError` by itself."
-                                    .to_string(),
-                            ),
-                        );
-                    }
-                }
-            }
+            let standard_error = exceptions.len() == 1
+                && crate::cop::shared::constant_predicates::constant_short_name(&exceptions[0])
+                    == Some(b"StandardError".as_slice());
+            standard_error.then(|| {
+                (
+                    "Omit the error class when rescuing `StandardError` by itself.",
+                    kw_loc.end_offset(),
+                    exceptions[0].location().end_offset(),
+                    "",
+                )
+            })
         }
-        "explicit" => {
-            if exceptions.is_empty() {
-                let kw_loc = rescue_node.keyword_loc();
-                let (line, column) = source.offset_to_line_col(kw_loc.start_offset());
-                diags.push(cop.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Specify `StandardError` explicitly when rescuing.".to_string(),
-                ));
-            }
+        "explicit" => exceptions.is_empty().then(|| {
+            (
+                "Specify `StandardError` explicitly when rescuing.",
+                kw_loc.end_offset(),
+                kw_loc.end_offset(),
+                " StandardError",
+            )
+        }),
+        _ => None,
+    };
+
+    if let Some((message, start, end, replacement)) = offense {
+        let (line, column) = source.offset_to_line_col(kw_loc.start_offset());
+        let mut diag = cop.diagnostic(source, line, column, message.to_string());
+        if let Some(corr) = corrections.as_deref_mut() {
+            corr.push(Correction {
+                start,
+                end,
+                replacement: replacement.to_string(),
+                cop_name: cop.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
         }
-        _ => {}
+        diagnostics.push(diag);
     }
 
     // Check subsequent rescue clauses in the chain
     if let Some(subsequent) = rescue_node.subsequent() {
-        diags.extend(check_rescue_node(cop, source, &subsequent, enforced_style));
+        check_rescue_node(
+            cop,
+            source,
+            &subsequent,
+            enforced_style,
+            diagnostics,
+            corrections,
+        );
     }
-
-    diags
 }
 
 impl Cop for RescueStandardError {
@@ -70,6 +87,10 @@ impl Cop for RescueStandardError {
         &[BEGIN_NODE]
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_node(
         &self,
         source: &SourceFile,
@@ -77,7 +98,7 @@ impl Cop for RescueStandardError {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let begin_node = match node.as_begin_node() {
             Some(b) => b,
@@ -91,12 +112,14 @@ impl Cop for RescueStandardError {
 
         let enforced_style = config.get_str("EnforcedStyle", "implicit");
 
-        diagnostics.extend(check_rescue_node(
+        check_rescue_node(
             self,
             source,
             &rescue_clause,
             enforced_style,
-        ));
+            diagnostics,
+            corrections,
+        );
     }
 }
 
@@ -106,6 +129,7 @@ mod tests {
     use crate::testutil::{run_cop_full, run_cop_full_with_config};
 
     crate::cop_fixture_tests!(RescueStandardError, "cops/style/rescue_standard_error");
+    crate::cop_autocorrect_fixture_tests!(RescueStandardError, "cops/style/rescue_standard_error");
 
     #[test]
     fn explicit_style_flags_bare_rescue() {
@@ -163,4 +187,42 @@ mod tests {
             explicit_config(),
         );
     }
+
+    #[test]
+    fn autocorrect_explicit() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &RescueStandardError,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/rescue_standard_error/offense.explicit.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/rescue_standard_error/corrected.explicit.rb"
+            ),
+            explicit_config(),
+        );
+        crate::testutil::assert_cop_autocorrect_idempotent_with_config(
+            &RescueStandardError,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/rescue_standard_error/offense.explicit.rb"
+            ),
+            explicit_config(),
+        );
+    }
+
+    #[test]
+    fn expanded_rescue_modifier_settles_with_explicit_style() {
+        use crate::cop::style::rescue_modifier::RescueModifier;
+
+        let corrected = crate::testutil::assert_autocorrect_converges_with_configs(
+            &[
+                (&RescueModifier, CopConfig::default()),
+                (&RescueStandardError, explicit_config()),
+            ],
+            b"def load\n  data = read rescue nil\nend\n",
+        );
+        assert_eq!(
+            corrected,
+            b"def load\n  data = begin\n           read\n         rescue StandardError\n           nil\n         end\nend\n"
+        );
+    }
 }
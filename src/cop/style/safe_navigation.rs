@@ -1,5 +1,6 @@
 use crate::cop::shared::method_dispatch_predicates;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 use ruby_prism::Visit;
@@ -34,8 +35,18 @@ use ruby_prism::Visit;
 /// - `visit_assoc_node` no longer resets `in_unsafe_parent` for hash values, fixing FPs
 ///   where `&&` inside lambda hash values of dotless calls (e.g.,
 ///   `before_save :foo, if: -> { x && x.bar }`) was incorrectly flagged.
+///
+/// `ConvertCodeThatCanStartToReturnNil` also treats `!foo.nil? && foo.bar` as a
+/// guard on `foo` (the rewrite returns `nil` instead of `false` when `foo` is nil).
+/// The cop needs Ruby 2.3. Autocorrect is unsafe (the guarded expression can
+/// return `false` where `&.` returns `nil`) and replaces the whole guarded
+/// expression with the chain rewritten to `&.`; guards whose checked call sits
+/// inside a larger right-hand side, or that span comments, are only reported.
 pub struct SafeNavigation;
 
+const MSG: &str =
+    "Use safe navigation (`&.`) instead of checking if an object exists before calling the method.";
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum AndOperatorKind {
     KeywordAnd,
@@ -540,6 +551,62 @@ impl SafeNavigation {
         }
     }
 
+    /// The checked receiver behind `!foo.nil?`, when
+    /// `ConvertCodeThatCanStartToReturnNil` lets it guard an `&&`.
+    fn not_nil_check_receiver<'a>(node: &ruby_prism::Node<'a>) -> Option<ruby_prism::Node<'a>> {
+        let not = node.as_call_node()?;
+        if not.name().as_slice() != b"!" {
+            return None;
+        }
+        let nil_check = not.receiver()?.as_call_node()?;
+        if nil_check.name().as_slice() != b"nil?"
+            || method_dispatch_predicates::is_safe_navigation(&nil_check)
+        {
+            return None;
+        }
+        nil_check.receiver()
+    }
+
+    /// The source of the chain's outermost call with every `.` in the chain
+    /// turned into `&.`. `None` if a call in the chain uses `::`.
+    fn safe_navigation_source(chain: &[ruby_prism::CallNode<'_>], bytes: &[u8]) -> Option<String> {
+        let outer = chain.last()?.location();
+        let mut out = Vec::with_capacity(outer.end_offset() - outer.start_offset() + chain.len());
+        let mut cursor = outer.start_offset();
+        for call in chain {
+            let operator = call.call_operator_loc()?;
+            match operator.as_slice() {
+                b"." => {
+                    out.extend_from_slice(&bytes[cursor..operator.start_offset()]);
+                    out.extend_from_slice(b"&.");
+                    cursor = operator.end_offset();
+                }
+                b"&." => {}
+                _ => return None,
+            }
+        }
+        out.extend_from_slice(&bytes[cursor..outer.end_offset()]);
+        String::from_utf8(out).ok()
+    }
+
+    /// Replace the guarded expression `start..end` with the safe-navigation
+    /// chain.
+    fn guard_correction(
+        &self,
+        start: usize,
+        end: usize,
+        chain: &[ruby_prism::CallNode<'_>],
+        bytes: &[u8],
+    ) -> Option<Correction> {
+        Some(Correction {
+            start,
+            end,
+            replacement: Self::safe_navigation_source(chain, bytes)?,
+            cop_name: self.name(),
+            cop_index: 0,
+        })
+    }
+
     fn top_level_and_clauses<'a>(
         node: &ruby_prism::AndNode<'a>,
         bytes: &[u8],
@@ -556,6 +623,14 @@ impl Cop for SafeNavigation {
         "Style/SafeNavigation"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -563,10 +638,14 @@ impl Cop for SafeNavigation {
         _code_map: &crate::cop::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
+        if !config.ruby_version_at_least(2.3) {
+            return;
+        }
+
         let max_chain_length = config.get_usize("MaxChainLength", 2);
-        let _convert_nil = config.get_bool("ConvertCodeThatCanStartToReturnNil", false);
+        let convert_nil = config.get_bool("ConvertCodeThatCanStartToReturnNil", false);
         let allowed_methods = config
             .get_string_array("AllowedMethods")
             .or_else(|| Some(vec!["present?".to_string(), "blank?".to_string()]));
@@ -575,7 +654,14 @@ impl Cop for SafeNavigation {
             cop: self,
             source,
             diagnostics: Vec::new(),
+            corrections: Vec::new(),
+            autocorrect: corrections.is_some(),
+            comment_ranges: parse_result
+                .comments()
+                .map(|c| (c.location().start_offset(), c.location().end_offset()))
+                .collect(),
             max_chain_length,
+            convert_nil,
             allowed_methods,
             in_unsafe_parent: 0,
             in_nil_safe_call_ancestor: 0,
@@ -594,6 +680,9 @@ impl Cop for SafeNavigation {
         };
         visitor.visit(&parse_result.node());
         diagnostics.extend(visitor.diagnostics);
+        if let Some(corrections) = corrections {
+            corrections.extend(visitor.corrections);
+        }
     }
 }
 
@@ -604,7 +693,11 @@ struct SafeNavVisitor<'a> {
     cop: &'a SafeNavigation,
     source: &'a SourceFile,
     diagnostics: Vec<Diagnostic>,
+    corrections: Vec<Correction>,
+    autocorrect: bool,
+    comment_ranges: Vec<(usize, usize)>,
     max_chain_length: usize,
+    convert_nil: bool,
     allowed_methods: Option<Vec<String>>,
     in_unsafe_parent: usize,
     in_nil_safe_call_ancestor: usize,
@@ -652,6 +745,46 @@ impl<'a> SafeNavVisitor<'a> {
         result
     }
 
+    fn add_offense(&mut self, offset: usize, correction: Option<Correction>) {
+        let (line, column) = self.source.offset_to_line_col(offset);
+        let mut diagnostic = self
+            .cop
+            .diagnostic(self.source, line, column, MSG.to_string());
+        if let Some(correction) = correction {
+            let spans_comment = self
+                .comment_ranges
+                .iter()
+                .any(|&(start, end)| start >= correction.start && end <= correction.end);
+            if self.autocorrect && !spans_comment {
+                self.corrections.push(correction);
+                diagnostic.corrected = true;
+            }
+        }
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Correction for `lhs && rhs`, only when `rhs` is the checked chain itself.
+    fn and_correction(
+        &self,
+        lhs: &ruby_prism::Node<'_>,
+        rhs: &ruby_prism::Node<'_>,
+        chain: &[ruby_prism::CallNode<'_>],
+    ) -> Option<Correction> {
+        let outer = chain.last()?.location();
+        let rhs_loc = rhs.location();
+        if (outer.start_offset(), outer.end_offset())
+            != (rhs_loc.start_offset(), rhs_loc.end_offset())
+        {
+            return None;
+        }
+        self.cop.guard_correction(
+            lhs.location().start_offset(),
+            rhs_loc.end_offset(),
+            chain,
+            self.source.as_bytes(),
+        )
+    }
+
     fn visit_flattened_and_clauses<'pr>(&mut self, node: &ruby_prism::AndNode<'pr>) {
         let bytes = self.source.as_bytes();
         for clause in SafeNavigation::top_level_and_clauses(node, bytes) {
@@ -671,9 +804,13 @@ impl<'a> SafeNavVisitor<'a> {
         }
         let bytes = self.source.as_bytes();
         let operator = SafeNavigation::and_operator_kind(node, bytes);
+        let not_nil_receiver = self
+            .convert_nil
+            .then(|| SafeNavigation::not_nil_check_receiver(&lhs))
+            .flatten();
         let chain = match SafeNavigation::first_safe_chain_in_expression(
             &rhs,
-            &lhs,
+            not_nil_receiver.as_ref().unwrap_or(&lhs),
             bytes,
             operator,
             self.max_chain_length,
@@ -698,14 +835,8 @@ impl<'a> SafeNavVisitor<'a> {
             return;
         }
 
-        let loc = node.location();
-        let (line, column) = self.source.offset_to_line_col(loc.start_offset());
-        self.diagnostics.push(self.cop.diagnostic(
-            self.source,
-            line,
-            column,
-            "Use safe navigation (`&.`) instead of checking if an object exists before calling the method.".to_string(),
-        ));
+        let correction = self.and_correction(&lhs, &rhs, &chain);
+        self.add_offense(node.location().start_offset(), correction);
     }
 
     fn is_assignment_or_operator_parent_call(call: &ruby_prism::CallNode<'_>) -> bool {
@@ -1112,9 +1243,13 @@ impl<'a, 'pr> Visit<'pr> for SafeNavVisitor<'a> {
                 continue;
             }
 
+            let not_nil_receiver = self
+                .convert_nil
+                .then(|| SafeNavigation::not_nil_check_receiver(lhs))
+                .flatten();
             let chain = match SafeNavigation::first_safe_chain_in_expression(
                 rhs,
-                lhs,
+                not_nil_receiver.as_ref().unwrap_or(lhs),
                 bytes,
                 operator,
                 self.max_chain_length,
@@ -1134,14 +1269,8 @@ impl<'a, 'pr> Visit<'pr> for SafeNavVisitor<'a> {
                 continue;
             }
 
-            let loc = lhs.location();
-            let (line, column) = self.source.offset_to_line_col(loc.start_offset());
-            self.diagnostics.push(self.cop.diagnostic(
-                self.source,
-                line,
-                column,
-                "Use safe navigation (`&.`) instead of checking if an object exists before calling the method.".to_string(),
-            ));
+            let correction = self.and_correction(lhs, rhs, &chain);
+            self.add_offense(lhs.location().start_offset(), correction);
             found_offense = true;
         }
 
@@ -1169,7 +1298,7 @@ impl<'a, 'pr> Visit<'pr> for SafeNavVisitor<'a> {
                 return;
             }
 
-            let diags = self.cop.check_ternary(
+            let offense = self.cop.check_ternary(
                 self.source,
                 if_node,
                 TernaryCheckContext {
@@ -1181,7 +1310,9 @@ impl<'a, 'pr> Visit<'pr> for SafeNavVisitor<'a> {
                         .is_direct_receiver_block_body(&node.as_node()),
                 },
             );
-            self.diagnostics.extend(diags);
+            if let Some((offset, correction)) = offense {
+                self.add_offense(offset, correction);
+            }
             ruby_prism::visit_if_node(self, node);
             return;
         }
@@ -1222,7 +1353,7 @@ impl<'a, 'pr> Visit<'pr> for SafeNavVisitor<'a> {
             return;
         }
 
-        let diags = self.cop.check_modifier_if(
+        let offense = self.cop.check_modifier_if(
             self.source,
             &node_loc,
             if_node,
@@ -1234,7 +1365,9 @@ impl<'a, 'pr> Visit<'pr> for SafeNavVisitor<'a> {
                     .is_direct_receiver_block_body(&node.as_node()),
             },
         );
-        self.diagnostics.extend(diags);
+        if let Some((offset, correction)) = offense {
+            self.add_offense(offset, correction);
+        }
 
         ruby_prism::visit_if_node(self, node);
     }
@@ -1353,13 +1486,13 @@ impl<'a, 'pr> Visit<'pr> for SafeNavVisitor<'a> {
             return;
         }
 
-        let (line, column) = self.source.offset_to_line_col(node_loc.start_offset());
-        self.diagnostics.push(self.cop.diagnostic(
-            self.source,
-            line,
-            column,
-            "Use safe navigation (`&.`) instead of checking if an object exists before calling the method.".to_string(),
-        ));
+        let correction = self.cop.guard_correction(
+            node_loc.start_offset(),
+            node_loc.end_offset(),
+            &chain,
+            bytes,
+        );
+        self.add_offense(node_loc.start_offset(), correction);
 
         ruby_prism::visit_unless_node(self, node);
     }
@@ -1371,7 +1504,7 @@ impl SafeNavigation {
         source: &SourceFile,
         if_node: &ruby_prism::IfNode<'_>,
         context: TernaryCheckContext<'_>,
-    ) -> Vec<Diagnostic> {
+    ) -> Option<(usize, Option<Correction>)> {
         let condition = if_node.predicate();
         let bytes = source.as_bytes();
 
@@ -1386,7 +1519,7 @@ impl SafeNavigation {
             let name = call.name().as_slice();
             if name == b"nil?" {
                 if method_dispatch_predicates::is_safe_navigation(&call) {
-                    return Vec::new();
+                    return None;
                 }
                 // foo.nil? ? nil : foo.bar
                 if let Some(recv) = call.receiver() {
@@ -1396,11 +1529,11 @@ impl SafeNavigation {
                         .and_then(|s| Self::single_stmt_from_stmts(&s))
                         .is_none_or(|n| Self::is_nil(&n));
                     if !if_is_nil {
-                        return Vec::new();
+                        return None;
                     }
                     (recv, true) // body is else branch
                 } else {
-                    return Vec::new();
+                    return None;
                 }
             } else if name == b"!" {
                 // !foo or !foo.nil?
@@ -1408,18 +1541,18 @@ impl SafeNavigation {
                     if let Some(inner_call) = recv.as_call_node() {
                         if inner_call.name().as_slice() == b"nil?" {
                             if method_dispatch_predicates::is_safe_navigation(&inner_call) {
-                                return Vec::new();
+                                return None;
                             }
                             // !foo.nil? ? foo.bar : nil
                             if let Some(inner_recv) = inner_call.receiver() {
                                 // else_branch must be nil
                                 let else_is_nil = self.else_branch_is_nil(if_node);
                                 if !else_is_nil {
-                                    return Vec::new();
+                                    return None;
                                 }
                                 (inner_recv, false) // body is if branch
                             } else {
-                                return Vec::new();
+                                return None;
                             }
                         } else {
                             // !foo ? nil : foo.bar
@@ -1428,7 +1561,7 @@ impl SafeNavigation {
                                 .and_then(|s| Self::single_stmt_from_stmts(&s))
                                 .is_none_or(|n| Self::is_nil(&n));
                             if !if_is_nil {
-                                return Vec::new();
+                                return None;
                             }
                             (recv, true) // body is else branch
                         }
@@ -1439,19 +1572,19 @@ impl SafeNavigation {
                             .and_then(|s| Self::single_stmt_from_stmts(&s))
                             .is_none_or(|n| Self::is_nil(&n));
                         if !if_is_nil {
-                            return Vec::new();
+                            return None;
                         }
                         (recv, true)
                     }
                 } else {
-                    return Vec::new();
+                    return None;
                 }
             } else {
                 // foo ? foo.bar : nil => plain variable/expression check
                 // else_branch must be nil
                 let else_is_nil = self.else_branch_is_nil(if_node);
                 if !else_is_nil {
-                    return Vec::new();
+                    return None;
                 }
                 (condition, false) // body is if branch
             }
@@ -1459,7 +1592,7 @@ impl SafeNavigation {
             // Non-call condition: foo ? foo.bar : nil
             let else_is_nil = self.else_branch_is_nil(if_node);
             if !else_is_nil {
-                return Vec::new();
+                return None;
             }
             (condition, false)
         };
@@ -1467,82 +1600,63 @@ impl SafeNavigation {
         // Get the body node (the non-nil branch)
         let body = if body_is_else {
             // Body is in else branch
-            let subsequent = match if_node.subsequent() {
-                Some(s) => s,
-                None => return Vec::new(),
-            };
-            let else_node = match subsequent.as_else_node() {
-                Some(e) => e,
-                None => return Vec::new(),
-            };
-            match else_node
+            let subsequent = if_node.subsequent()?;
+            let else_node = subsequent.as_else_node()?;
+            else_node
                 .statements()
-                .and_then(|s| Self::single_stmt_from_stmts(&s))
-            {
-                Some(n) => n,
-                None => return Vec::new(),
-            }
+                .and_then(|s| Self::single_stmt_from_stmts(&s))?
         } else {
             // Body is in if branch
-            match if_node
+            if_node
                 .statements()
-                .and_then(|s| Self::single_stmt_from_stmts(&s))
-            {
-                Some(n) => n,
-                None => return Vec::new(),
-            }
+                .and_then(|s| Self::single_stmt_from_stmts(&s))?
         };
 
         // Body must be a method call chain with a dot operator
-        let body_call = match body.as_call_node() {
-            Some(c) => c,
-            None => return Vec::new(),
-        };
+        let body_call = body.as_call_node()?;
 
         if context.skip_nested_block_call_args && body_call.block().is_some() {
-            return Vec::new();
+            return None;
         }
 
         if body_call.call_operator_loc().is_none() {
-            return Vec::new();
+            return None;
         }
 
         // Find matching receiver using source byte comparison
-        let chain = match Self::call_chain_from_checked_receiver(&body, &checked_node, bytes) {
-            Some(chain) => chain,
-            None => return Vec::new(),
-        };
+        let chain = Self::call_chain_from_checked_receiver(&body, &checked_node, bytes)?;
 
         if context.skip_direct_receiver_block_body_block_calls
             && chain.iter().any(|call| call.block().is_some())
         {
-            return Vec::new();
+            return None;
         }
 
         if chain.len() > context.max_chain_length {
-            return Vec::new();
+            return None;
         }
 
         if Self::chain_has_dotless_operator(&chain) {
-            return Vec::new();
+            return None;
         }
 
         if Self::has_unsafe_method_after_checked_receiver(&chain, context.allowed_methods) {
-            return Vec::new();
+            return None;
         }
 
         let node_loc = if_node.location();
-        let (line, column) = source.offset_to_line_col(
+        let correction = self.guard_correction(
+            node_loc.start_offset(),
+            node_loc.end_offset(),
+            &chain,
+            bytes,
+        );
+        Some((
             context
                 .offense_start_offset
                 .unwrap_or(node_loc.start_offset()),
-        );
-        vec![self.diagnostic(
-            source,
-            line,
-            column,
-            "Use safe navigation (`&.`) instead of checking if an object exists before calling the method.".to_string(),
-        )]
+            correction,
+        ))
     }
 
     fn else_branch_is_nil(&self, if_node: &ruby_prism::IfNode<'_>) -> bool {
@@ -1574,18 +1688,12 @@ impl SafeNavigation {
         if_node: &ruby_prism::IfNode<'_>,
         is_unless: bool,
         context: ModifierIfCheckContext<'_>,
-    ) -> Vec<Diagnostic> {
+    ) -> Option<(usize, Option<Correction>)> {
         let condition = if_node.predicate();
-        let body_stmts = match if_node.statements() {
-            Some(s) => s,
-            None => return Vec::new(),
-        };
+        let body_stmts = if_node.statements()?;
 
         // Must have exactly one body statement
-        let body = match Self::single_stmt_from_stmts(&body_stmts) {
-            Some(n) => n,
-            None => return Vec::new(),
-        };
+        let body = Self::single_stmt_from_stmts(&body_stmts)?;
 
         let bytes = source.as_bytes();
 
@@ -1597,13 +1705,13 @@ impl SafeNavigation {
                 let name = call.name().as_slice();
                 if name == b"nil?" {
                     if method_dispatch_predicates::is_safe_navigation(&call) {
-                        return Vec::new();
+                        return None;
                     }
                     // unless foo.nil? => check foo
                     if is_unless {
                         call.receiver()
                     } else {
-                        return Vec::new();
+                        return None;
                     }
                 } else if name == b"!" {
                     // if !foo or if !foo.nil?
@@ -1630,7 +1738,7 @@ impl SafeNavigation {
                     if !is_unless {
                         Some(if_node.predicate())
                     } else {
-                        return Vec::new();
+                        return None;
                     }
                 }
             } else {
@@ -1638,61 +1746,52 @@ impl SafeNavigation {
                 if !is_unless {
                     Some(if_node.predicate())
                 } else {
-                    return Vec::new();
+                    return None;
                 }
             };
 
-        let checked_node = match checked_node {
-            Some(s) => s,
-            None => return Vec::new(),
-        };
+        let checked_node = checked_node?;
 
         // Body must be a method call chain
-        let body_call = match body.as_call_node() {
-            Some(c) => c,
-            None => return Vec::new(),
-        };
+        let body_call = body.as_call_node()?;
 
         if body_call.call_operator_loc().is_none() {
-            return Vec::new();
+            return None;
         }
 
-        let chain = match Self::call_chain_from_checked_receiver(&body, &checked_node, bytes) {
-            Some(chain) => chain,
-            None => return Vec::new(),
-        };
+        let chain = Self::call_chain_from_checked_receiver(&body, &checked_node, bytes)?;
 
         if context.skip_direct_receiver_block_body_block_calls
             && chain.iter().any(|call| call.block().is_some())
         {
-            return Vec::new();
+            return None;
         }
 
         if chain.len() > context.max_chain_length {
-            return Vec::new();
+            return None;
         }
 
         if Self::chain_has_dotless_operator(&chain) {
-            return Vec::new();
+            return None;
         }
 
         if Self::has_unsafe_method_after_checked_receiver(&chain, context.allowed_methods) {
-            return Vec::new();
+            return None;
         }
 
         // RuboCop: use_var_only_in_unless_modifier? — for `unless foo`, skip
         // if the checked variable is used only in the condition (not a method call)
         if is_unless && !condition_is_method_call {
-            return Vec::new();
+            return None;
         }
 
-        let (line, column) = source.offset_to_line_col(node_loc.start_offset());
-        vec![self.diagnostic(
-            source,
-            line,
-            column,
-            "Use safe navigation (`&.`) instead of checking if an object exists before calling the method.".to_string(),
-        )]
+        let correction = self.guard_correction(
+            node_loc.start_offset(),
+            node_loc.end_offset(),
+            &chain,
+            bytes,
+        );
+        Some((node_loc.start_offset(), correction))
     }
 
     /// Check if the condition node is a method call (has a parent send)
@@ -1710,4 +1809,105 @@ impl SafeNavigation {
 mod tests {
     use super::*;
     crate::cop_fixture_tests!(SafeNavigation, "cops/style/safe_navigation");
+
+    use crate::cop::CopConfig;
+    use crate::testutil::{
+        assert_cop_autocorrect, assert_cop_autocorrect_with_config, run_cop_autocorrect,
+        run_cop_full_with_config,
+    };
+
+    fn config_with(key: &str, value: serde_yml::Value) -> CopConfig {
+        let mut options = std::collections::HashMap::new();
+        options.insert(key.to_string(), value);
+        CopConfig {
+            options,
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn autocorrect_and_guard() {
+        assert_cop_autocorrect(&SafeNavigation, b"foo && foo.bar.baz\n", b"foo&.bar&.baz\n");
+        assert_cop_autocorrect(
+            &SafeNavigation,
+            b"a && foo && foo.bar(1)\n",
+            b"a && foo&.bar(1)\n",
+        );
+    }
+
+    #[test]
+    fn autocorrect_ternary_and_conditionals() {
+        assert_cop_autocorrect(
+            &SafeNavigation,
+            b"x = foo.nil? ? nil : foo.bar(1)\n",
+            b"x = foo&.bar(1)\n",
+        );
+        assert_cop_autocorrect(&SafeNavigation, b"obj.bar if obj\n", b"obj&.bar\n");
+        assert_cop_autocorrect(
+            &SafeNavigation,
+            b"if data\n  data.each { |d| p d }\nend\n",
+            b"data&.each { |d| p d }\n",
+        );
+        assert_cop_autocorrect(
+            &SafeNavigation,
+            b"callback.call unless callback.nil?\n",
+            b"callback&.call\n",
+        );
+    }
+
+    #[test]
+    fn reports_without_correction_when_rewrite_is_not_local() {
+        // The checked call is nested inside the right-hand side.
+        let (diags, corrections) =
+            run_cop_autocorrect(&SafeNavigation, b"mail.cc && (mail.cc.include? 'x')\n");
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty());
+
+        // Rewriting would drop the comment.
+        let (diags, corrections) =
+            run_cop_autocorrect(&SafeNavigation, b"if foo\n  # note\n  foo.bar\nend\n");
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty());
+    }
+
+    #[test]
+    fn convert_code_that_can_start_to_return_nil() {
+        let source = b"!foo.nil? && foo.bar\n";
+        let diags = run_cop_full_with_config(&SafeNavigation, source, CopConfig::default());
+        assert!(diags.is_empty());
+
+        let config = config_with(
+            "ConvertCodeThatCanStartToReturnNil",
+            serde_yml::Value::Bool(true),
+        );
+        assert_cop_autocorrect_with_config(&SafeNavigation, source, b"foo&.bar\n", config);
+    }
+
+    #[test]
+    fn max_chain_length() {
+        let config = config_with(
+            "MaxChainLength",
+            serde_yml::Value::Number(serde_yml::Number::from(1)),
+        );
+        let diags = run_cop_full_with_config(&SafeNavigation, b"foo && foo.bar.baz\n", config);
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn requires_ruby_2_3() {
+        let source = b"foo && foo.bar\n";
+        let config = config_with(
+            "TargetRubyVersion",
+            serde_yml::Value::Number(serde_yml::Number::from(2.2_f64)),
+        );
+        assert!(run_cop_full_with_config(&SafeNavigation, source, config).is_empty());
+        let config = config_with(
+            "TargetRubyVersion",
+            serde_yml::Value::Number(serde_yml::Number::from(2.3_f64)),
+        );
+        assert_eq!(
+            run_cop_full_with_config(&SafeNavigation, source, config).len(),
+            1
+        );
+    }
 }
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use ruby_prism::Visit;
 
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::codemap::CodeMap;
 use crate::parse::source::SourceFile;
@@ -77,6 +78,22 @@ use crate::parse::source::SourceFile;
 ///   `ensure_clause()`. The previous skip logic treated all explicit `BeginNode`s the
 ///   same and dropped those body lines. Fix: only suppress expression-separator scanning
 ///   for plain explicit `begin...end` without rescue/else/ensure clauses.
+///
+/// ## Configuration and autocorrect
+///
+/// With `AllowAsExpressionSeparator: true`, semicolons separating two
+/// expressions are accepted; trailing and leading semicolons, those before a
+/// closing brace, and empty statements (`foo;; bar`) are still offenses.
+///
+/// Semicolons inside strings, comments and `%w` literals are ignored on all
+/// lines, including expression separator lines. RuboCop scans those lines'
+/// raw text, but a semicolon inside a string can't be corrected away.
+///
+/// Autocorrect removes redundant semicolons and splits a line at separator
+/// semicolons, indenting the new lines like the original one. Lines opening
+/// a heredoc are not split (the code would move into the heredoc body), and
+/// a semicolon after a range operator (`1..;`) is kept so the range doesn't
+/// become endless.
 pub struct Semicolon;
 
 impl Cop for Semicolon {
@@ -84,6 +101,10 @@ impl Cop for Semicolon {
         "Style/Semicolon"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -91,7 +112,7 @@ impl Cop for Semicolon {
         code_map: &CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        mut corrections: Option<&mut Vec<Correction>>,
     ) {
         let bytes = source.as_bytes();
         if !bytes.contains(&b';') {
@@ -115,9 +136,10 @@ impl Cop for Semicolon {
             HashSet::new()
         };
 
-        // Phase 2: For expression separator lines, scan raw source for ALL semicolons
-        // (including inside strings on the line), matching RuboCop's find_semicolon_positions.
-        // Track which offsets have already been reported to avoid duplicates.
+        // Phase 2: For expression separator lines, flag every code semicolon on the
+        // line. Separators are corrected by splitting the line; trailing and empty
+        // statement semicolons are removed. Track which offsets have already been
+        // reported to avoid duplicates.
         let mut reported: HashSet<usize> = HashSet::new();
 
         for &line in &expr_sep_lines {
@@ -128,22 +150,38 @@ impl Cop for Semicolon {
                 .position(|&b| b == b'\n')
                 .map_or(bytes.len(), |p| line_start + p);
             let line_bytes = &bytes[line_start..line_end];
+            let indent = &line_bytes[..line_bytes
+                .iter()
+                .take_while(|&&b| b == b' ' || b == b'\t')
+                .count()];
+            // Splitting a line that opens a heredoc would move code into its body.
+            let opens_heredoc = line_end + 1 < bytes.len() && code_map.is_heredoc(line_end + 1);
             for (j, &ch) in line_bytes.iter().enumerate() {
-                if ch == b';' {
-                    let offset = line_start + j;
-                    // Skip $; — Ruby's $FIELD_SEPARATOR global variable
-                    if offset > 0 && bytes[offset - 1] == b'$' {
-                        continue;
-                    }
-                    let (l, column) = source.offset_to_line_col(offset);
-                    diagnostics.push(self.diagnostic(
-                        source,
-                        l,
-                        column,
-                        "Do not use semicolons to terminate expressions.".to_string(),
-                    ));
-                    reported.insert(offset);
+                let offset = line_start + j;
+                if ch != b';' || !code_map.is_code(offset) {
+                    continue;
+                }
+                // Skip $; — Ruby's $FIELD_SEPARATOR global variable
+                if offset > 0 && bytes[offset - 1] == b'$' {
+                    continue;
                 }
+                let fix = if is_trailing_semicolon(bytes, offset) {
+                    removal_fix(bytes, offset, true)
+                } else if is_empty_statement_semicolon(bytes, offset, code_map) {
+                    removal_fix(bytes, offset, false)
+                } else if opens_heredoc {
+                    None
+                } else {
+                    let mut replacement = String::from("\n");
+                    replacement.push_str(&String::from_utf8_lossy(indent));
+                    Some((
+                        whitespace_before(bytes, offset),
+                        whitespace_after(bytes, offset + 1),
+                        replacement,
+                    ))
+                };
+                self.report(source, offset, fix, diagnostics, &mut corrections);
+                reported.insert(offset);
             }
         }
 
@@ -163,29 +201,27 @@ impl Cop for Semicolon {
                 continue;
             }
 
-            let (line, column) = source.offset_to_line_col(i);
-
             // Check if trailing: no non-whitespace content after the semicolon on this line.
             // Note: comments after the semicolon do NOT make it trailing — RuboCop's token-based
             // approach sees the comment token as the last token, masking the semicolon.
             if is_trailing_semicolon(bytes, i) {
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Do not use semicolons to terminate expressions.".to_string(),
-                ));
+                let fix = removal_fix(bytes, i, true);
+                self.report(source, i, fix, diagnostics, &mut corrections);
                 continue;
             }
 
             // Check if leading: nothing meaningful before the semicolon on this line.
             if is_leading_semicolon(bytes, i) {
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Do not use semicolons to terminate expressions.".to_string(),
-                ));
+                let fix = removal_fix(bytes, i, false);
+                self.report(source, i, fix, diagnostics, &mut corrections);
+                continue;
+            }
+
+            // An empty statement (`foo;; bar`) is flagged even when semicolons are
+            // allowed as expression separators.
+            if is_empty_statement_semicolon(bytes, i, code_map) {
+                let fix = removal_fix(bytes, i, true);
+                self.report(source, i, fix, diagnostics, &mut corrections);
                 continue;
             }
 
@@ -194,31 +230,99 @@ impl Cop for Semicolon {
             // - Block trailing semicolons: `foo { bar; }`
             // - String interpolation: `"#{foo;}"`
             // RuboCop catches these via token position checks (tokens[-2] is `}`, tokens[-3] is `;`).
-            if is_semicolon_before_closing_brace(bytes, i, code_map) {
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Do not use semicolons to terminate expressions.".to_string(),
-                ));
-                continue;
-            }
-
-            // Check if semicolon is directly after `#{` in string interpolation
+            //
+            // Also checks if semicolon is directly after `#{` in string interpolation
             // (only whitespace between `{` and `;`). Catches `"#{;foo}"`.
-            if is_semicolon_after_interpolation_open(bytes, i) {
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Do not use semicolons to terminate expressions.".to_string(),
-                ));
+            if is_semicolon_before_closing_brace(bytes, i, code_map)
+                || is_semicolon_after_interpolation_open(bytes, i)
+            {
+                let fix = Some((i, i + 1, String::new()));
+                self.report(source, i, fix, diagnostics, &mut corrections);
                 continue;
             }
         }
     }
 }
 
+impl Semicolon {
+    /// Report the semicolon at `offset`, with `fix` as a `(start, end, replacement)`
+    /// correction when one is safe.
+    fn report(
+        &self,
+        source: &SourceFile,
+        offset: usize,
+        fix: Option<(usize, usize, String)>,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: &mut Option<&mut Vec<Correction>>,
+    ) {
+        let (line, column) = source.offset_to_line_col(offset);
+        let mut diagnostic = self.diagnostic(
+            source,
+            line,
+            column,
+            "Do not use semicolons to terminate expressions.".to_string(),
+        );
+        if let (Some(corrections), Some((start, end, replacement))) = (corrections.as_mut(), fix) {
+            corrections.push(Correction {
+                start,
+                end,
+                replacement,
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diagnostic.corrected = true;
+        }
+        diagnostics.push(diagnostic);
+    }
+}
+
+/// Correction removing the semicolon at `pos`, together with the whitespace
+/// before it (`with_space_before`) or after it. `None` after a range operator, where
+/// removing the semicolon would turn `1..;` into an endless range continuing
+/// on the next line.
+fn removal_fix(
+    bytes: &[u8],
+    pos: usize,
+    with_space_before: bool,
+) -> Option<(usize, usize, String)> {
+    let before = whitespace_before(bytes, pos);
+    if bytes[..before].ends_with(b"..") {
+        return None;
+    }
+    if with_space_before {
+        Some((before, whitespace_after(bytes, pos + 1), String::new()))
+    } else {
+        Some((pos, whitespace_after(bytes, pos + 1), String::new()))
+    }
+}
+
+/// Start of the run of spaces and tabs ending at `pos`.
+fn whitespace_before(bytes: &[u8], pos: usize) -> usize {
+    pos - bytes[..pos]
+        .iter()
+        .rev()
+        .take_while(|&&b| b == b' ' || b == b'\t')
+        .count()
+}
+
+/// End of the run of spaces and tabs starting at `pos`.
+fn whitespace_after(bytes: &[u8], pos: usize) -> usize {
+    pos + bytes[pos..]
+        .iter()
+        .take_while(|&&b| b == b' ' || b == b'\t')
+        .count()
+}
+
+/// Check if the semicolon at `pos` ends an empty statement: the previous
+/// non-whitespace byte on the line is another code semicolon (`foo;; bar`).
+fn is_empty_statement_semicolon(bytes: &[u8], pos: usize, code_map: &CodeMap) -> bool {
+    let before = whitespace_before(bytes, pos);
+    before > 0
+        && bytes[before - 1] == b';'
+        && code_map.is_code(before - 1)
+        && !(before > 1 && bytes[before - 2] == b'$')
+}
+
 /// Check if a semicolon at byte position `pos` is trailing:
 /// nothing non-whitespace follows it on the same line.
 ///
@@ -454,4 +558,66 @@ mod tests {
     use super::*;
 
     crate::cop_fixture_tests!(Semicolon, "cops/style/semicolon");
+    crate::cop_autocorrect_fixture_tests!(Semicolon, "cops/style/semicolon");
+
+    fn allow_separator_config() -> CopConfig {
+        CopConfig {
+            options: std::collections::HashMap::from([(
+                "AllowAsExpressionSeparator".into(),
+                serde_yml::Value::Bool(true),
+            )]),
+            ..CopConfig::default()
+        }
+    }
+
+    #[test]
+    fn offense_allow_as_expression_separator() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &Semicolon,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/semicolon/offense.allow_as_expression_separator.rb"
+            ),
+            allow_separator_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_allow_as_expression_separator() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &Semicolon,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/semicolon/no_offense.allow_as_expression_separator.rb"
+            ),
+            allow_separator_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_allow_as_expression_separator() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &Semicolon,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/semicolon/offense.allow_as_expression_separator.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/semicolon/corrected.allow_as_expression_separator.rb"
+            ),
+            allow_separator_config(),
+        );
+    }
+
+    #[test]
+    fn split_keeps_line_indentation() {
+        let (_, corrections) =
+            crate::testutil::run_cop_autocorrect(&Semicolon, b"def foo\n  a = 1;  b = 2\nend\n");
+        assert_eq!(corrections.len(), 1);
+        assert_eq!(corrections[0].replacement, "\n  ");
+    }
+
+    #[test]
+    fn semicolon_after_range_is_not_corrected() {
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect(&Semicolon, b"x = 1..;\n");
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty());
+    }
 }
@@ -1,5 +1,6 @@
 use ruby_prism::Visit;
 
+use crate::cop::shared::method_dispatch_predicates;
 use crate::cop::{Cop, CopConfig};
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
@@ -21,13 +22,40 @@ use crate::parse::source::SourceFile;
 /// No cop-local detection change is needed here; the correct fix belongs in the
 /// config/file-selection pipeline (`src/config/mod.rs` or corpus overlay path
 /// handling) so absolute exclude globs keep directory-segment boundaries.
+///
+/// ## Styles and autocorrect
+///
+/// `only_raise` (the default) and `only_fail` flag the other method
+/// everywhere. `semantic` wants `fail` to signal and `raise` to re-raise: the
+/// nearest enclosing rescue construct decides, so a call in a `rescue` clause
+/// (including inside blocks there) should be `raise`, while one in the
+/// protected body, the `else` clause or outside any rescue should be `fail`.
+/// This mirrors RuboCop's `on_rescue` scopes, which stop at nested rescues.
+///
+/// Like RuboCop, only receiverless calls and `Kernel.raise`/`Kernel.fail` are
+/// checked, and `only_raise` stays silent in files that define their own
+/// `fail` method. Autocorrect swaps the method name.
 pub struct SignalException;
 
+const RAISE_MSG: &str = "Use `raise` instead of `fail` to rethrow exceptions.";
+const FAIL_MSG: &str = "Use `fail` instead of `raise` to signal exceptions.";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Style {
+    OnlyRaise,
+    OnlyFail,
+    Semantic,
+}
+
 impl Cop for SignalException {
     fn name(&self) -> &'static str {
         "Style/SignalException"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn check_source(
         &self,
         source: &SourceFile,
@@ -35,26 +63,38 @@ impl Cop for SignalException {
         _code_map: &crate::parse::codemap::CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
-        let enforced_style = config.get_str("EnforcedStyle", "only_raise");
+        let style = match config.get_str("EnforcedStyle", "only_raise") {
+            "only_fail" => Style::OnlyFail,
+            "semantic" => Style::Semantic,
+            _ => Style::OnlyRaise,
+        };
 
         let mut visitor = SignalExceptionVisitor {
             cop: self,
             source,
-            enforced_style,
+            style,
             custom_fail_defined: false,
-            pending_fail_diagnostics: Vec::new(),
-            raise_diagnostics: Vec::new(),
+            in_rescue: false,
+            offenses: Vec::new(),
         };
         visitor.visit(&parse_result.node());
 
-        // Emit raise diagnostics unconditionally (only_fail style)
-        diagnostics.extend(visitor.raise_diagnostics);
+        // A custom `fail` method makes bare `fail` calls legitimate (only_raise)
+        if style == Style::OnlyRaise && visitor.custom_fail_defined {
+            return;
+        }
 
-        // Only emit fail diagnostics if no custom fail method is defined
-        if !visitor.custom_fail_defined {
-            diagnostics.extend(visitor.pending_fail_diagnostics);
+        match corrections {
+            Some(corrections) => {
+                for (mut diag, correction) in visitor.offenses {
+                    corrections.push(correction);
+                    diag.corrected = true;
+                    diagnostics.push(diag);
+                }
+            }
+            None => diagnostics.extend(visitor.offenses.into_iter().map(|(diag, _)| diag)),
         }
     }
 }
@@ -62,12 +102,19 @@ impl Cop for SignalException {
 struct SignalExceptionVisitor<'a> {
     cop: &'a SignalException,
     source: &'a SourceFile,
-    enforced_style: &'a str,
+    style: Style,
     custom_fail_defined: bool,
-    /// Diagnostics for bare `fail` calls (only emitted if no custom fail defined)
-    pending_fail_diagnostics: Vec<Diagnostic>,
-    /// Diagnostics for bare `raise` calls (always emitted for only_fail style)
-    raise_diagnostics: Vec<Diagnostic>,
+    /// Inside a `rescue` clause, up to the next nested rescue construct.
+    in_rescue: bool,
+    offenses: Vec<(Diagnostic, crate::correction::Correction)>,
+}
+
+impl SignalExceptionVisitor<'_> {
+    fn visit_in_rescue(&mut self, in_rescue: bool, node: &ruby_prism::Node<'_>) {
+        let outer = std::mem::replace(&mut self.in_rescue, in_rescue);
+        self.visit(node);
+        self.in_rescue = outer;
+    }
 }
 
 impl Visit<'_> for SignalExceptionVisitor<'_> {
@@ -79,36 +126,56 @@ impl Visit<'_> for SignalExceptionVisitor<'_> {
         ruby_prism::visit_def_node(self, node);
     }
 
+    fn visit_begin_node(&mut self, node: &ruby_prism::BeginNode<'_>) {
+        let Some(rescue_clause) = node.rescue_clause() else {
+            ruby_prism::visit_begin_node(self, node);
+            return;
+        };
+        if let Some(statements) = node.statements() {
+            self.visit_in_rescue(false, &statements.as_node());
+        }
+        self.visit_in_rescue(true, &rescue_clause.as_node());
+        if let Some(else_clause) = node.else_clause() {
+            self.visit_in_rescue(false, &else_clause.as_node());
+        }
+        // `ensure` sits outside the rescue construct.
+        if let Some(ensure_clause) = node.ensure_clause() {
+            self.visit_ensure_node(&ensure_clause);
+        }
+    }
+
+    fn visit_rescue_modifier_node(&mut self, node: &ruby_prism::RescueModifierNode<'_>) {
+        self.visit_in_rescue(false, &node.expression());
+        self.visit_in_rescue(true, &node.rescue_expression());
+    }
+
     fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'_>) {
-        // Only bare raise/fail (no receiver)
-        if node.receiver().is_none() {
-            let name = node.name().as_slice();
-            let loc = node.message_loc().unwrap_or_else(|| node.location());
-
-            match self.enforced_style {
-                "only_raise" => {
-                    if name == b"fail" {
-                        let (line, column) = self.source.offset_to_line_col(loc.start_offset());
-                        self.pending_fail_diagnostics.push(self.cop.diagnostic(
-                            self.source,
-                            line,
-                            column,
-                            "Use `raise` instead of `fail` to rethrow exceptions.".to_string(),
-                        ));
-                    }
-                }
-                "only_fail" => {
-                    if name == b"raise" {
-                        let (line, column) = self.source.offset_to_line_col(loc.start_offset());
-                        self.raise_diagnostics.push(self.cop.diagnostic(
-                            self.source,
-                            line,
-                            column,
-                            "Use `fail` instead of `raise` to rethrow exceptions.".to_string(),
-                        ));
-                    }
-                }
-                _ => {}
+        let name = node.name().as_slice();
+        if (name == b"raise" || name == b"fail") && is_command_or_kernel_call(node) {
+            let wanted = match self.style {
+                Style::OnlyRaise => "raise",
+                Style::OnlyFail => "fail",
+                Style::Semantic if self.in_rescue => "raise",
+                Style::Semantic => "fail",
+            };
+            if let Some(loc) = node.message_loc().filter(|_| name != wanted.as_bytes()) {
+                let (line, column) = self.source.offset_to_line_col(loc.start_offset());
+                let message = if wanted == "raise" {
+                    RAISE_MSG
+                } else {
+                    FAIL_MSG
+                };
+                let diag = self
+                    .cop
+                    .diagnostic(self.source, line, column, message.to_string());
+                let correction = crate::correction::Correction {
+                    start: loc.start_offset(),
+                    end: loc.end_offset(),
+                    replacement: wanted.to_string(),
+                    cop_name: self.cop.name(),
+                    cop_index: 0,
+                };
+                self.offenses.push((diag, correction));
             }
         }
 
@@ -117,12 +184,82 @@ impl Visit<'_> for SignalExceptionVisitor<'_> {
     }
 }
 
+/// RuboCop's `command_or_kernel_call?`: no receiver, or `Kernel`/`::Kernel`.
+fn is_command_or_kernel_call(node: &ruby_prism::CallNode<'_>) -> bool {
+    let Some(receiver) = node.receiver() else {
+        return true;
+    };
+    if method_dispatch_predicates::is_safe_navigation(node) {
+        return false;
+    }
+    if let Some(constant) = receiver.as_constant_read_node() {
+        return constant.name().as_slice() == b"Kernel";
+    }
+    receiver.as_constant_path_node().is_some_and(|path| {
+        path.parent().is_none() && path.name().is_some_and(|n| n.as_slice() == b"Kernel")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::testutil::run_cop_full_with_config;
 
     crate::cop_fixture_tests!(SignalException, "cops/style/signal_exception");
+    crate::cop_autocorrect_fixture_tests!(SignalException, "cops/style/signal_exception");
+
+    fn assert_style_fixtures(style: &str, offense: &[u8], corrected: &[u8], no_offense: &[u8]) {
+        let yaml = format!("EnforcedStyle: {style}\n");
+        crate::testutil::assert_cop_offenses_with_yaml(&SignalException, &yaml, offense);
+        crate::testutil::assert_cop_no_offenses_with_yaml(&SignalException, &yaml, no_offense);
+        let config = crate::testutil::cop_config_from_yaml(&yaml);
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SignalException,
+            offense,
+            corrected,
+            config,
+        );
+    }
+
+    #[test]
+    fn only_fail_fixtures() {
+        assert_style_fixtures(
+            "only_fail",
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/signal_exception/offense.only_fail.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/signal_exception/corrected.only_fail.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/signal_exception/no_offense.only_fail.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn semantic_fixtures() {
+        assert_style_fixtures(
+            "semantic",
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/signal_exception/offense.semantic.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/signal_exception/corrected.semantic.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/signal_exception/no_offense.semantic.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn only_raise_respects_custom_fail_under_autocorrect() {
+        let source = b"def fail(msg)\n  log(msg)\nend\n\nfail \"oops\"\n";
+        let (diags, corrections) = crate::testutil::run_cop_autocorrect(&SignalException, source);
+        assert!(diags.is_empty());
+        assert!(corrections.is_empty());
+    }
 
     #[test]
     fn config_only_fail() {
@@ -138,6 +275,6 @@ mod tests {
         let source = b"raise RuntimeError, \"msg\"\n";
         let diags = run_cop_full_with_config(&SignalException, source, config);
         assert_eq!(diags.len(), 1);
-        assert!(diags[0].message.contains("Use `fail`"));
+        assert_eq!(diags[0].message, FAIL_MSG);
     }
 }
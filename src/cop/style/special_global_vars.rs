@@ -1,6 +1,9 @@
-use crate::cop::shared::node_type::GLOBAL_VARIABLE_READ_NODE;
+use ruby_prism::Visit;
+
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
+use crate::parse::codemap::CodeMap;
 use crate::parse::source::SourceFile;
 
 /// Style/SpecialGlobalVars: Flags Perl-style global variables and suggests English equivalents.
@@ -10,6 +13,18 @@ use crate::parse::source::SourceFile;
 /// Also fixed message generation: builtin globals (`$LOAD_PATH`, `$LOADED_FEATURES`,
 /// `$PROGRAM_NAME`) do not need `require 'English'` — they are always available in Ruby.
 /// The "require 'English'" hint is now only appended for non-builtin English names.
+///
+/// Styles follow RuboCop: `use_english_names` flags Perl names,
+/// `use_perl_names` flags English names (including the short aliases such as
+/// `$PID` and `$RS`), and `use_builtin_english_names` is `use_perl_names` that
+/// also accepts the three builtin English names. Only plain reads are
+/// checked; back-references like `$&` are a different node in both ASTs.
+///
+/// With `RequireEnglish` (default true) and `use_english_names`, the hint is
+/// dropped once a top-level `require 'English'` precedes the variable, and
+/// autocorrect inserts that require before the first top-level statement
+/// (once per file), removing any later top-level copy, as RuboCop's
+/// `RequireLibrary` mixin does. `"#$!"` is corrected to `"#{$ERROR_INFO}"`.
 pub struct SpecialGlobalVars;
 
 fn perl_to_english(name: &[u8]) -> Option<&'static str> {
@@ -70,6 +85,12 @@ fn english_to_perl(name: &[u8]) -> Option<&'static str> {
         b"$DEFAULT_INPUT" => Some("$<"),
         b"$IGNORECASE" => Some("$="),
         b"$ARGV" => Some("$*"),
+        b"$FS" => Some("$;"),
+        b"$OFS" => Some("$,"),
+        b"$RS" => Some("$/"),
+        b"$ORS" => Some("$\\"),
+        b"$NR" => Some("$."),
+        b"$PID" => Some("$$"),
         _ => None,
     }
 }
@@ -79,89 +100,186 @@ impl Cop for SpecialGlobalVars {
         "Style/SpecialGlobalVars"
     }
 
-    fn interested_node_types(&self) -> &'static [u8] {
-        &[GLOBAL_VARIABLE_READ_NODE]
-    }
-
     fn supports_autocorrect(&self) -> bool {
         true
     }
 
-    fn check_node(
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
+    fn check_source(
         &self,
         source: &SourceFile,
-        node: &ruby_prism::Node<'_>,
-        _parse_result: &ruby_prism::ParseResult<'_>,
+        parse_result: &ruby_prism::ParseResult<'_>,
+        _code_map: &CodeMap,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        mut corrections: Option<&mut Vec<crate::correction::Correction>>,
+        mut corrections: Option<&mut Vec<Correction>>,
     ) {
         let require_english = config.get_bool("RequireEnglish", true);
         let enforced_style = config.get_str("EnforcedStyle", "use_english_names");
-        let gvar = match node.as_global_variable_read_node() {
-            Some(g) => g,
-            None => return,
+
+        let mut visitor = GlobalVarCollector {
+            reads: Vec::new(),
+            in_embedded_variable: false,
         };
+        visitor.visit(&parse_result.node());
 
-        let loc = gvar.location();
-        let var_name = loc.as_slice();
-
-        match enforced_style {
-            "use_perl_names" | "use_builtin_english_names" => {
-                // Flag English-style names, suggest perl equivalents
-                if let Some(perl) = english_to_perl(var_name) {
-                    let english_name = std::str::from_utf8(var_name).unwrap_or("$?");
-                    let (line, column) = source.offset_to_line_col(loc.start_offset());
-                    let mut diag = self.diagnostic(
-                        source,
-                        line,
-                        column,
-                        format!("Prefer `{}` over `{}`.", perl, english_name),
-                    );
-                    if let Some(ref mut corr) = corrections {
-                        corr.push(crate::correction::Correction {
-                            start: loc.start_offset(),
-                            end: loc.end_offset(),
-                            replacement: perl.to_string(),
-                            cop_name: self.name(),
-                            cop_index: 0,
-                        });
-                        diag.corrected = true;
-                    }
-                    diagnostics.push(diag);
-                }
-            }
-            _ => {
+        // Top-level statements, and the `require 'English'` among them
+        let program = parse_result.node();
+        let statements: Vec<ruby_prism::Node<'_>> = program
+            .as_program_node()
+            .map(|p| p.statements().body().iter().collect())
+            .unwrap_or_default();
+        let english_requires: Vec<(usize, usize)> = statements
+            .iter()
+            .filter(|stmt| is_require_english(stmt))
+            .map(|stmt| (stmt.location().start_offset(), stmt.location().end_offset()))
+            .collect();
+        let mut require_inserted = false;
+
+        for read in visitor.reads {
+            let var_name = &source.as_bytes()[read.start..read.end];
+            let name = std::str::from_utf8(var_name).unwrap_or("$?");
+            let (line, column) = source.offset_to_line_col(read.start);
+
+            let (preferred, needs_english) = match enforced_style {
+                "use_perl_names" => match english_to_perl(var_name) {
+                    Some(perl) => (perl, false),
+                    None => continue,
+                },
+                "use_builtin_english_names" => match english_to_perl(var_name) {
+                    Some(perl) if !is_builtin_english(name) => (perl, false),
+                    _ => continue,
+                },
                 // "use_english_names" (default): flag Perl-style names
-                if let Some(english) = perl_to_english(var_name) {
-                    let perl_name = std::str::from_utf8(var_name).unwrap_or("$?");
-                    let (line, column) = source.offset_to_line_col(loc.start_offset());
-                    let msg = if require_english && !is_builtin_english(english) {
-                        format!(
-                            "Prefer `{}` over `{}`. Use `require 'English'` to access it.",
-                            english, perl_name
-                        )
-                    } else {
-                        format!("Prefer `{}` over `{}`.", english, perl_name)
-                    };
-                    let mut diag = self.diagnostic(source, line, column, msg);
-                    if let Some(ref mut corr) = corrections {
-                        corr.push(crate::correction::Correction {
-                            start: loc.start_offset(),
-                            end: loc.end_offset(),
-                            replacement: english.to_string(),
+                _ => match perl_to_english(var_name) {
+                    Some(english) => (english, require_english && !is_builtin_english(english)),
+                    None => continue,
+                },
+            };
+            let required_before = english_requires.iter().any(|&(_, end)| end <= read.start);
+
+            let msg = if needs_english && !required_before {
+                format!(
+                    "Prefer `{}` over `{}`. Use `require 'English'` to access it.",
+                    preferred, name
+                )
+            } else {
+                format!("Prefer `{}` over `{}`.", preferred, name)
+            };
+            let mut diag = self.diagnostic(source, line, column, msg);
+            if let Some(ref mut corr) = corrections {
+                if needs_english && !required_before && !require_inserted {
+                    if let Some(first) = statements.first() {
+                        corr.push(Correction {
+                            start: first.location().start_offset(),
+                            end: first.location().start_offset(),
+                            replacement: "require 'English'\n".to_string(),
                             cop_name: self.name(),
                             cop_index: 0,
                         });
-                        diag.corrected = true;
+                        for &(start, end) in &english_requires {
+                            let (start, end) = require_line(source, start, end);
+                            corr.push(Correction {
+                                start,
+                                end,
+                                replacement: String::new(),
+                                cop_name: self.name(),
+                                cop_index: 0,
+                            });
+                        }
+                        require_inserted = true;
                     }
-                    diagnostics.push(diag);
                 }
+                // `"#$!"` becomes `"#{$ERROR_INFO}"`
+                let replacement = if read.embedded && enforced_style == "use_english_names" {
+                    format!("{{{}}}", preferred)
+                } else {
+                    preferred.to_string()
+                };
+                corr.push(Correction {
+                    start: read.start,
+                    end: read.end,
+                    replacement,
+                    cop_name: self.name(),
+                    cop_index: 0,
+                });
+                diag.corrected = true;
             }
+            diagnostics.push(diag);
         }
     }
 }
 
+/// A global variable read, and whether it is the `$x` of a `"#$x"`
+/// interpolation.
+struct GlobalVarRead {
+    start: usize,
+    end: usize,
+    embedded: bool,
+}
+
+struct GlobalVarCollector {
+    reads: Vec<GlobalVarRead>,
+    in_embedded_variable: bool,
+}
+
+impl<'pr> Visit<'pr> for GlobalVarCollector {
+    fn visit_global_variable_read_node(&mut self, node: &ruby_prism::GlobalVariableReadNode<'pr>) {
+        self.reads.push(GlobalVarRead {
+            start: node.location().start_offset(),
+            end: node.location().end_offset(),
+            embedded: self.in_embedded_variable,
+        });
+    }
+
+    fn visit_embedded_variable_node(&mut self, node: &ruby_prism::EmbeddedVariableNode<'pr>) {
+        self.in_embedded_variable = true;
+        ruby_prism::visit_embedded_variable_node(self, node);
+        self.in_embedded_variable = false;
+    }
+}
+
+/// `require 'English'` (or `require "English"`) without a receiver.
+fn is_require_english(node: &ruby_prism::Node<'_>) -> bool {
+    let Some(call) = node.as_call_node() else {
+        return false;
+    };
+    if call.receiver().is_some() || call.name().as_slice() != b"require" {
+        return false;
+    }
+    let Some(args) = call.arguments() else {
+        return false;
+    };
+    let args: Vec<_> = args.arguments().iter().collect();
+    matches!(
+        args.as_slice(),
+        [arg] if arg.as_string_node().is_some_and(|s| s.unescaped() == b"English")
+    )
+}
+
+/// Widen `start..end` to its whole line, with the final newline, when
+/// nothing else shares that line.
+fn require_line(source: &SourceFile, start: usize, end: usize) -> (usize, usize) {
+    let bytes = source.as_bytes();
+    let line_start = bytes[..start]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+    let line_end = bytes[end..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |i| end + i);
+    let is_space = |b: &u8| *b == b' ' || *b == b'\t';
+    if bytes[line_start..start].iter().all(is_space) && bytes[end..line_end].iter().all(is_space) {
+        (line_start, (line_end + 1).min(bytes.len()))
+    } else {
+        (start, end)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +288,119 @@ mod tests {
     crate::cop_fixture_tests!(SpecialGlobalVars, "cops/style/special_global_vars");
     crate::cop_autocorrect_fixture_tests!(SpecialGlobalVars, "cops/style/special_global_vars");
 
+    fn style_config(style: &str) -> CopConfig {
+        crate::testutil::cop_config_from_yaml(&format!("EnforcedStyle: {style}\n"))
+    }
+
+    #[test]
+    fn offense_perl_names() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &SpecialGlobalVars,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/special_global_vars/offense.perl_names.rb"
+            ),
+            style_config("use_perl_names"),
+        );
+    }
+
+    #[test]
+    fn no_offense_perl_names() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &SpecialGlobalVars,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/special_global_vars/no_offense.perl_names.rb"
+            ),
+            style_config("use_perl_names"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_perl_names() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SpecialGlobalVars,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/special_global_vars/offense.perl_names.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/special_global_vars/corrected.perl_names.rb"
+            ),
+            style_config("use_perl_names"),
+        );
+    }
+
+    #[test]
+    fn offense_builtin_english_names() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &SpecialGlobalVars,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/special_global_vars/offense.builtin_english_names.rb"
+            ),
+            style_config("use_builtin_english_names"),
+        );
+    }
+
+    #[test]
+    fn no_offense_builtin_english_names() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &SpecialGlobalVars,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/special_global_vars/no_offense.builtin_english_names.rb"
+            ),
+            style_config("use_builtin_english_names"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_builtin_english_names() {
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &SpecialGlobalVars,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/special_global_vars/offense.builtin_english_names.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/special_global_vars/corrected.builtin_english_names.rb"
+            ),
+            style_config("use_builtin_english_names"),
+        );
+    }
+
+    fn autocorrect(source: &[u8], config: CopConfig) -> Vec<u8> {
+        let (_, corrections) =
+            crate::testutil::run_cop_autocorrect_with_config(&SpecialGlobalVars, source, config);
+        crate::correction::CorrectionSet::from_vec(corrections).apply(source)
+    }
+
+    #[test]
+    fn existing_require_drops_the_hint_and_is_not_repeated() {
+        let source = b"# frozen_string_literal: true\n\nrequire 'English'\n\nputs $!\n";
+        let diags = run_cop_full(&SpecialGlobalVars, source);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].message, "Prefer `$ERROR_INFO` over `$!`.");
+        assert_eq!(
+            autocorrect(source, CopConfig::default()),
+            b"# frozen_string_literal: true\n\nrequire 'English'\n\nputs $ERROR_INFO\n"
+        );
+    }
+
+    #[test]
+    fn require_is_added_once_and_a_later_one_moved_up() {
+        let source = b"# frozen_string_literal: true\n\nputs $!\nputs $?\nrequire \"English\"\n";
+        assert_eq!(
+            autocorrect(source, CopConfig::default()),
+            b"# frozen_string_literal: true\n\nrequire 'English'\nputs $ERROR_INFO\nputs $CHILD_STATUS\n"
+        );
+    }
+
+    #[test]
+    fn builtin_names_and_require_english_false_add_no_require() {
+        assert_eq!(
+            autocorrect(b"puts $0\n", CopConfig::default()),
+            b"puts $PROGRAM_NAME\n"
+        );
+        let config = crate::testutil::cop_config_from_yaml("RequireEnglish: false\n");
+        assert_eq!(autocorrect(b"puts $!\n", config), b"puts $ERROR_INFO\n");
+    }
+
     #[test]
     fn regular_global_is_ignored() {
         let source = b"x = $foo\n";
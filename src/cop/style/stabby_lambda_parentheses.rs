@@ -1,5 +1,6 @@
 use crate::cop::shared::node_type::LAMBDA_NODE;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -26,6 +27,12 @@ use crate::parse::source::SourceFile;
 /// parentheses in default values like `-> a=a() { }`. Fixed to check if the
 /// first non-whitespace character after `->` is `(`, which correctly
 /// distinguishes parameter parens from default value method calls.
+///
+/// ## Autocorrect
+///
+/// `-> x { }` becomes `->(x) { }` (dropping the space after `->`), and
+/// `->(x) { }` becomes `->x { }`, keeping a space before the body when the
+/// closing paren was directly followed by `{` or `do`.
 pub struct StabbyLambdaParentheses;
 
 impl Cop for StabbyLambdaParentheses {
@@ -33,6 +40,10 @@ impl Cop for StabbyLambdaParentheses {
         "Style/StabbyLambdaParentheses"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[LAMBDA_NODE]
     }
@@ -44,7 +55,7 @@ impl Cop for StabbyLambdaParentheses {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let lambda_node = match node.as_lambda_node() {
             Some(l) => l,
@@ -104,30 +115,63 @@ impl Cop for StabbyLambdaParentheses {
             .find(|&&b| b != b' ' && b != b'\t' && b != b'\n' && b != b'\r')
             .is_some_and(|&b| b == b'(');
 
-        match enforced_style {
-            "require_parentheses" => {
-                if !has_paren {
-                    let (line, column) = source.offset_to_line_col(operator_loc.start_offset());
-                    diagnostics.push(self.diagnostic(
-                        source,
-                        line,
-                        column,
-                        "Use parentheses for stabby lambda arguments.".to_string(),
-                    ));
-                }
+        let (message, edits) = match enforced_style {
+            "require_parentheses" if !has_paren => (
+                "Use parentheses for stabby lambda arguments.",
+                vec![
+                    self.correction(operator_end, block_params.location().start_offset(), "("),
+                    self.correction(
+                        block_params.location().end_offset(),
+                        block_params.location().end_offset(),
+                        ")",
+                    ),
+                ],
+            ),
+            "require_no_parentheses" if has_paren => {
+                let (Some(open), Some(close)) =
+                    (block_params.opening_loc(), block_params.closing_loc())
+                else {
+                    return;
+                };
+                let after_close = bytes.get(close.end_offset()).copied().unwrap_or(b'\n');
+                let close_replacement = if after_close.is_ascii_whitespace() {
+                    ""
+                } else {
+                    " "
+                };
+                (
+                    "Do not use parentheses for stabby lambda arguments.",
+                    vec![
+                        self.correction(open.start_offset(), open.end_offset(), ""),
+                        self.correction(
+                            close.start_offset(),
+                            close.end_offset(),
+                            close_replacement,
+                        ),
+                    ],
+                )
             }
-            "require_no_parentheses" => {
-                if has_paren {
-                    let (line, column) = source.offset_to_line_col(operator_loc.start_offset());
-                    diagnostics.push(self.diagnostic(
-                        source,
-                        line,
-                        column,
-                        "Do not use parentheses for stabby lambda arguments.".to_string(),
-                    ));
-                }
-            }
-            _ => {}
+            _ => return,
+        };
+
+        let (line, column) = source.offset_to_line_col(operator_loc.start_offset());
+        let mut diag = self.diagnostic(source, line, column, message.to_string());
+        if let Some(corr) = corrections {
+            corr.extend(edits);
+            diag.corrected = true;
+        }
+        diagnostics.push(diag);
+    }
+}
+
+impl StabbyLambdaParentheses {
+    fn correction(&self, start: usize, end: usize, replacement: &str) -> Correction {
+        Correction {
+            start,
+            end,
+            replacement: replacement.to_string(),
+            cop_name: self.name(),
+            cop_index: 0,
         }
     }
 }
@@ -141,6 +185,10 @@ mod tests {
         StabbyLambdaParentheses,
         "cops/style/stabby_lambda_parentheses"
     );
+    crate::cop_autocorrect_fixture_tests!(
+        StabbyLambdaParentheses,
+        "cops/style/stabby_lambda_parentheses"
+    );
 
     #[test]
     fn config_require_no_parentheses() {
@@ -198,4 +246,47 @@ mod tests {
             "numbered params should not trigger offense under require_no_parentheses"
         );
     }
+
+    fn no_parentheses_config() -> CopConfig {
+        crate::testutil::cop_config_from_yaml("EnforcedStyle: require_no_parentheses")
+    }
+
+    #[test]
+    fn offense_require_no_parentheses_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &StabbyLambdaParentheses,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/stabby_lambda_parentheses/offense.require_no_parentheses.rb"
+            ),
+            no_parentheses_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_require_no_parentheses_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &StabbyLambdaParentheses,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/stabby_lambda_parentheses/no_offense.require_no_parentheses.rb"
+            ),
+            no_parentheses_config(),
+        );
+    }
+
+    #[test]
+    fn autocorrect_require_no_parentheses_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &StabbyLambdaParentheses,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/stabby_lambda_parentheses/offense.require_no_parentheses.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/stabby_lambda_parentheses/corrected.require_no_parentheses.rb"
+            ),
+            no_parentheses_config(),
+        );
+    }
 }
@@ -3,6 +3,7 @@ use crate::cop::shared::node_type::{
     REQUIRED_PARAMETER_NODE, STATEMENTS_NODE, SUPER_NODE,
 };
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -60,6 +61,15 @@ use crate::parse::source::SourceFile;
 /// `check_super_block` and `check_forwarding_super_block` methods to handle
 /// both cases, with appropriate config checks (AllowMethodsWithArguments
 /// applies to SuperNode arguments; ForwardingSuperNode has no arguments).
+///
+/// ## Autocorrect
+///
+/// Unsafe, as upstream: `&:foo` behaves differently for methods that inspect
+/// the block's arity, and when `foo` is private. The block is replaced by
+/// `(&:foo)`, or `&:foo` is appended to the existing arguments, adding
+/// parentheses when the call had none (`foo a do |x| x.bar end` becomes
+/// `foo(a, &:bar)`). `[]` calls are left alone, since a block argument inside
+/// brackets is a syntax error in Ruby 3.4.
 pub struct SymbolProc;
 
 impl Cop for SymbolProc {
@@ -67,6 +77,14 @@ impl Cop for SymbolProc {
         "Style/SymbolProc"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[
             BLOCK_NODE,
@@ -87,10 +105,14 @@ impl Cop for SymbolProc {
         parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let allow_methods_with_arguments = config.get_bool("AllowMethodsWithArguments", false);
-        let allowed_methods = config.get_string_array("AllowedMethods");
+        let allowed_methods = Some(
+            config
+                .get_string_array("AllowedMethods")
+                .unwrap_or_else(|| vec!["define_method".to_string()]),
+        );
         let allowed_patterns = config.get_string_array("AllowedPatterns");
         let allow_comments = config.get_bool("AllowComments", false);
         let _active_support = config.get_bool("ActiveSupportExtensionsEnabled", false);
@@ -107,6 +129,7 @@ impl Cop for SymbolProc {
                 &allowed_patterns,
                 allow_comments,
                 diagnostics,
+                corrections,
             );
         } else if let Some(super_node) = node.as_super_node() {
             self.check_super_block(
@@ -116,6 +139,7 @@ impl Cop for SymbolProc {
                 allow_methods_with_arguments,
                 allow_comments,
                 diagnostics,
+                corrections,
             );
         } else if let Some(fwd_super) = node.as_forwarding_super_node() {
             self.check_forwarding_super_block(
@@ -124,6 +148,7 @@ impl Cop for SymbolProc {
                 parse_result,
                 allow_comments,
                 diagnostics,
+                corrections,
             );
         }
     }
@@ -142,6 +167,7 @@ impl SymbolProc {
         allowed_patterns: &Option<Vec<String>>,
         allow_comments: bool,
         diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let block = match call_with_block.block() {
             Some(b) => match b.as_block_node() {
@@ -214,17 +240,42 @@ impl SymbolProc {
         }
 
         // Check the block body for symbol proc pattern
-        self.check_block_body(
+        let Some(method_name) = self.check_block_body(
             source,
             &block,
             parse_result,
             "the method",
             allow_comments,
             diagnostics,
-        );
+        ) else {
+            return;
+        };
+        if let Some(corrections) = corrections {
+            if outer_method == b"[]" {
+                return;
+            }
+            let parens = call_with_block
+                .opening_loc()
+                .zip(call_with_block.closing_loc())
+                .map(|(open, close)| (open.start_offset(), close.end_offset()));
+            if let Some(correction) = self.correction(
+                source,
+                &block,
+                call_with_block.arguments(),
+                call_with_block.message_loc().map(|loc| loc.end_offset()),
+                parens,
+                &method_name,
+            ) {
+                corrections.push(correction);
+                if let Some(diag) = diagnostics.last_mut() {
+                    diag.corrected = true;
+                }
+            }
+        }
     }
 
     /// Check a SuperNode with an attached block (e.g., `super(args) { |x| x.foo }`).
+    #[allow(clippy::too_many_arguments)]
     fn check_super_block(
         &self,
         source: &SourceFile,
@@ -233,6 +284,7 @@ impl SymbolProc {
         allow_methods_with_arguments: bool,
         allow_comments: bool,
         diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let block = match super_node.block() {
             Some(b) => match b.as_block_node() {
@@ -248,14 +300,35 @@ impl SymbolProc {
         }
 
         // Check the block body for symbol proc pattern
-        self.check_block_body(
+        let Some(method_name) = self.check_block_body(
             source,
             &block,
             parse_result,
             "`super`",
             allow_comments,
             diagnostics,
-        );
+        ) else {
+            return;
+        };
+        if let Some(corrections) = corrections {
+            let parens = super_node
+                .lparen_loc()
+                .zip(super_node.rparen_loc())
+                .map(|(open, close)| (open.start_offset(), close.end_offset()));
+            if let Some(correction) = self.correction(
+                source,
+                &block,
+                super_node.arguments(),
+                Some(super_node.keyword_loc().end_offset()),
+                parens,
+                &method_name,
+            ) {
+                corrections.push(correction);
+                if let Some(diag) = diagnostics.last_mut() {
+                    diag.corrected = true;
+                }
+            }
+        }
     }
 
     /// Check a ForwardingSuperNode with an attached block (e.g., `super { |x| x.foo }`).
@@ -266,6 +339,7 @@ impl SymbolProc {
         parse_result: &ruby_prism::ParseResult<'_>,
         allow_comments: bool,
         diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let block = match fwd_super.block() {
             Some(bn) => bn,
@@ -273,17 +347,91 @@ impl SymbolProc {
         };
 
         // Check the block body for symbol proc pattern
-        self.check_block_body(
+        let Some(method_name) = self.check_block_body(
             source,
             &block,
             parse_result,
             "`super`",
             allow_comments,
             diagnostics,
-        );
+        ) else {
+            return;
+        };
+        if let Some(corrections) = corrections {
+            if let Some(correction) =
+                self.correction(source, &block, None, None, None, &method_name)
+            {
+                corrections.push(correction);
+                if let Some(diag) = diagnostics.last_mut() {
+                    diag.corrected = true;
+                }
+            }
+        }
     }
 
-    /// Check the body of a block for the symbol proc pattern.
+    /// Rewrite the block as a `&:method` argument of the call it's attached
+    /// to. `parens` is the range of the call's argument parentheses and
+    /// `selector_end` the end of its method name (or `super` keyword).
+    fn correction(
+        &self,
+        source: &SourceFile,
+        block: &ruby_prism::BlockNode<'_>,
+        arguments: Option<ruby_prism::ArgumentsNode<'_>>,
+        selector_end: Option<usize>,
+        parens: Option<(usize, usize)>,
+        method_name: &[u8],
+    ) -> Option<Correction> {
+        let bytes = source.as_bytes();
+        let block_end = block.location().end_offset();
+        let symbol = format!("&:{}", String::from_utf8_lossy(method_name));
+        let args: Vec<_> = arguments
+            .map(|args| args.arguments().iter().collect())
+            .unwrap_or_default();
+
+        let (start, replacement) = match (args.first(), args.last(), parens) {
+            // `foo() { |x| x.bar }` -> `foo(&:bar)`
+            (None, _, Some((open, _))) => (open, format!("({symbol})")),
+            // `foo { |x| x.bar }` -> `foo(&:bar)`
+            (None, _, None) => {
+                let mut start = block.location().start_offset();
+                while start > 0 && matches!(bytes[start - 1], b' ' | b'\t') {
+                    start -= 1;
+                }
+                (start, format!("({symbol})"))
+            }
+            // `foo(a) { |x| x.bar }` -> `foo(a, &:bar)`, keeping a trailing comma
+            (Some(_), Some(last), Some((_, close_end))) => {
+                let arg_end = last.location().end_offset();
+                let rest = String::from_utf8_lossy(&bytes[arg_end..close_end]);
+                let replacement = match rest.strip_prefix(',') {
+                    Some(after_comma) => format!(", {symbol}{after_comma}"),
+                    None => format!(", {symbol}{rest}"),
+                };
+                (arg_end, replacement)
+            }
+            // `foo a do |x| x.bar end` -> `foo(a, &:bar)`
+            (Some(first), Some(last), None) => {
+                let selector_end = selector_end?;
+                let args_source = String::from_utf8_lossy(
+                    &bytes[first.location().start_offset()..last.location().end_offset()],
+                );
+                (selector_end, format!("({args_source}, {symbol})"))
+            }
+            (Some(_), None, _) => return None,
+        };
+
+        Some(Correction {
+            start,
+            end: block_end,
+            replacement,
+            cop_name: self.name(),
+            cop_index: 0,
+        })
+    }
+
+    /// Check the body of a block for the symbol proc pattern. Returns the
+    /// name of the method called on the block parameter when an offense was
+    /// registered.
     fn check_block_body(
         &self,
         source: &SourceFile,
@@ -292,49 +440,37 @@ impl SymbolProc {
         method_desc: &str,
         allow_comments: bool,
         diagnostics: &mut Vec<Diagnostic>,
-    ) {
+    ) -> Option<Vec<u8>> {
         // Extract the single method call from the block body
-        let body = match block.body() {
-            Some(b) => b,
-            None => return,
-        };
+        let body = block.body()?;
 
-        let stmts = match body.as_statements_node() {
-            Some(s) => s,
-            None => return,
-        };
+        let stmts = body.as_statements_node()?;
 
         let body_nodes: Vec<_> = stmts.body().iter().collect();
         if body_nodes.len() != 1 {
-            return;
+            return None;
         }
 
-        let call = match body_nodes[0].as_call_node() {
-            Some(c) => c,
-            None => return,
-        };
+        let call = body_nodes[0].as_call_node()?;
 
         // Must not use safe navigation (&.) - can't convert to &:method
         if let Some(op) = call.call_operator_loc() {
             if op.as_slice() == b"&." {
-                return;
+                return None;
             }
         }
 
         // Inner method must not have arguments — can't convert { |x| x.foo(bar) } to &:foo
         if call.arguments().is_some() {
-            return;
+            return None;
         }
 
         // Must not have a block
         if call.block().is_some() {
-            return;
+            return None;
         }
 
-        let receiver = match call.receiver() {
-            Some(r) => r,
-            None => return,
-        };
+        let receiver = call.receiver()?;
 
         // Check if receiver matches the block parameter (three paths)
         let receiver_is_param = match block.parameters() {
@@ -346,23 +482,20 @@ impl SymbolProc {
                     let params_source =
                         &source.as_bytes()[params_loc.start_offset()..params_loc.end_offset()];
                     if params_source.contains(&b',') {
-                        return;
+                        return None;
                     }
 
-                    let param_node = match block_params.parameters() {
-                        Some(p) => p,
-                        None => return,
-                    };
+                    let param_node = block_params.parameters()?;
 
                     let requireds: Vec<_> = param_node.requireds().iter().collect();
                     if requireds.len() != 1 {
-                        return;
+                        return None;
                     }
 
                     let param_name = if let Some(rp) = requireds[0].as_required_parameter_node() {
                         rp.name().as_slice()
                     } else {
-                        return;
+                        return None;
                     };
 
                     // Must have no rest, keyword, optional, or block params
@@ -372,7 +505,7 @@ impl SymbolProc {
                         || param_node.keyword_rest().is_some()
                         || param_node.block().is_some()
                     {
-                        return;
+                        return None;
                     }
 
                     receiver
@@ -395,7 +528,7 @@ impl SymbolProc {
         };
 
         if !receiver_is_param {
-            return;
+            return None;
         }
 
         // AllowComments: when true, skip if the block contains any comments
@@ -406,7 +539,7 @@ impl SymbolProc {
                 block_loc.start_offset(),
                 block_loc.end_offset(),
             ) {
-                return;
+                return None;
             }
         }
 
@@ -424,6 +557,7 @@ impl SymbolProc {
                 method_desc,
             ),
         ));
+        Some(method_name.to_vec())
     }
 }
 
@@ -479,6 +613,33 @@ mod tests {
     use crate::testutil::{assert_cop_no_offenses_full_with_config, run_cop_full_with_config};
 
     crate::cop_fixture_tests!(SymbolProc, "cops/style/symbol_proc");
+    crate::cop_autocorrect_fixture_tests!(SymbolProc, "cops/style/symbol_proc");
+
+    #[test]
+    fn autocorrect_keeps_trailing_comma() {
+        crate::testutil::assert_cop_autocorrect(
+            &SymbolProc,
+            b"foo(\n  a,\n) { |x| x.bar }\n",
+            b"foo(\n  a, &:bar\n)\n",
+        );
+    }
+
+    #[test]
+    fn no_autocorrect_for_index_call() {
+        let (diags, corrections) =
+            crate::testutil::run_cop_autocorrect(&SymbolProc, b"Hash[pairs] { |x| x.foo }\n");
+        assert_eq!(diags.len(), 1);
+        assert!(corrections.is_empty());
+    }
+
+    #[test]
+    fn define_method_allowed_by_default() {
+        let source = b"define_method(:foo) { |x| x.bar }\n";
+        let diags = run_cop_full_with_config(&SymbolProc, source, CopConfig::default());
+        assert!(diags.is_empty());
+        let diags = run_cop_full_with_config(&SymbolProc, source, config_with_allowed(&[]));
+        assert_eq!(diags.len(), 1);
+    }
 
     fn config_with_allowed(methods: &[&str]) -> CopConfig {
         let mut config = CopConfig::default();
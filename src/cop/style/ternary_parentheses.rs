@@ -1,3 +1,4 @@
+use crate::cop::shared::node_type::node_type_tag;
 use crate::cop::shared::node_type::{
     CALL_NODE, CLASS_VARIABLE_READ_NODE, CLASS_VARIABLE_WRITE_NODE, CONSTANT_PATH_NODE,
     CONSTANT_READ_NODE, CONSTANT_WRITE_NODE, FALSE_NODE, GLOBAL_VARIABLE_READ_NODE,
@@ -5,7 +6,9 @@ use crate::cop::shared::node_type::{
     LOCAL_VARIABLE_READ_NODE, LOCAL_VARIABLE_WRITE_NODE, NIL_NODE, PARENTHESES_NODE, SELF_NODE,
     STATEMENTS_NODE, TRUE_NODE,
 };
+use crate::cop::shared::node_type_groups::is_assignment_type;
 use crate::cop::{Cop, CopConfig};
+use crate::correction::Correction;
 use crate::diagnostic::Diagnostic;
 use crate::parse::source::SourceFile;
 
@@ -16,6 +19,15 @@ use crate::parse::source::SourceFile;
 ///
 /// 2026-03-31: parenthesized one-line pattern matching like
 /// `(foo in bar) ? a : b` is accepted by RuboCop and must not be flagged.
+///
+/// `require_parentheses_when_complex` follows RuboCop: a parenthesized
+/// condition is complex if any of its statements is, so `(foo) ? a : b` asks
+/// for the parentheses to go while `(x > 1) ? a : b` keeps them.
+///
+/// Autocorrect wraps or unwraps the condition. Unwrapping is skipped when it
+/// would change how the ternary parses: assignments, unparenthesized calls
+/// with arguments, `defined?`, `not`/`and`/`or`, ranges (flip-flops) and nested
+/// conditionals all bind looser than (or swallow) the `?`.
 pub struct TernaryParentheses;
 
 /// Check if a parenthesized node contains a safe assignment (=) in ternary context.
@@ -79,7 +91,17 @@ fn is_indexed_assign(node: &ruby_prism::Node<'_>) -> bool {
 }
 
 /// Check if a condition is "complex" (not a simple variable/constant/method call).
+/// A parenthesized condition is complex if any statement inside it is.
 fn is_complex_condition(node: &ruby_prism::Node<'_>) -> bool {
+    if let Some(paren) = node.as_parentheses_node() {
+        return match paren.body() {
+            Some(body) => match body.as_statements_node() {
+                Some(stmts) => stmts.body().iter().any(|n| is_complex_condition(&n)),
+                None => is_complex_condition(&body),
+            },
+            None => false,
+        };
+    }
     // Simple: variables, constants, method calls
     if node.as_local_variable_read_node().is_some()
         || node.as_instance_variable_read_node().is_some()
@@ -114,6 +136,10 @@ impl Cop for TernaryParentheses {
         "Style/TernaryParentheses"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[
             CALL_NODE,
@@ -145,7 +171,7 @@ impl Cop for TernaryParentheses {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<Correction>>,
     ) {
         let enforced_style = config.get_str("EnforcedStyle", "require_no_parentheses");
         let allow_safe = config.get_bool("AllowSafeAssignment", true);
@@ -162,13 +188,17 @@ impl Cop for TernaryParentheses {
         let predicate = if_node.predicate();
         let is_parenthesized = predicate.as_parentheses_node().is_some();
 
-        // AllowSafeAssignment: skip if condition is a parenthesized assignment
-        if allow_safe && is_parenthesized {
-            if let Some(paren) = predicate.as_parentheses_node() {
-                if is_ternary_safe_assignment(&paren) {
-                    return;
-                }
-            }
+        if only_closing_paren_on_last_line(&predicate) {
+            return;
+        }
+
+        // AllowSafeAssignment: a parenthesized assignment is either always
+        // accepted or, when disallowed, always an offense (as in RuboCop).
+        let safe_assignment = predicate
+            .as_parentheses_node()
+            .is_some_and(|paren| is_ternary_safe_assignment(&paren));
+        if safe_assignment && allow_safe {
+            return;
         }
 
         if is_parenthesized {
@@ -179,64 +209,150 @@ impl Cop for TernaryParentheses {
             }
         }
 
-        match enforced_style {
-            "require_parentheses" => {
-                if !is_parenthesized {
-                    let loc = predicate.location();
-                    let (line, column) = source.offset_to_line_col(loc.start_offset());
-                    diagnostics.push(self.diagnostic(
-                        source,
-                        line,
-                        column,
-                        "Use parentheses for ternary conditions.".to_string(),
-                    ));
-                }
-            }
+        let (offense, message) = match enforced_style {
+            "require_parentheses" => (!is_parenthesized, "Use parentheses for ternary conditions."),
             "require_parentheses_when_complex" => {
-                let is_complex = is_complex_condition(&predicate);
-                if is_complex && !is_parenthesized {
-                    let loc = predicate.location();
-                    let (line, column) = source.offset_to_line_col(loc.start_offset());
-                    diagnostics.push(
-                        self.diagnostic(
-                            source,
-                            line,
-                            column,
-                            "Use parentheses for ternary expressions with complex conditions."
-                                .to_string(),
-                        ),
-                    );
-                } else if !is_complex && is_parenthesized {
-                    let paren = predicate.as_parentheses_node().unwrap();
-                    let open_loc = paren.opening_loc();
-                    let (line, column) = source.offset_to_line_col(open_loc.start_offset());
-                    diagnostics.push(
-                        self.diagnostic(
-                            source,
-                            line,
-                            column,
-                            "Only use parentheses for ternary expressions with complex conditions."
-                                .to_string(),
-                        ),
-                    );
-                }
+                let offense = if is_complex_condition(&predicate) {
+                    !is_parenthesized
+                } else {
+                    is_parenthesized
+                };
+                let message = if is_parenthesized {
+                    "Only use parentheses for ternary expressions with complex conditions."
+                } else {
+                    "Use parentheses for ternary expressions with complex conditions."
+                };
+                (offense, message)
+            }
+            // "require_no_parentheses" (default)
+            _ => (
+                is_parenthesized,
+                "Ternary conditions should not be wrapped in parentheses.",
+            ),
+        };
+        if !offense && !safe_assignment {
+            return;
+        }
+
+        let loc = predicate.location();
+        let (line, column) = source.offset_to_line_col(loc.start_offset());
+        let mut diag = self.diagnostic(source, line, column, message.to_string());
+        if let Some(corr) = corrections {
+            let edits = match predicate.as_parentheses_node() {
+                Some(paren) => self.unwrap_condition(source, &paren),
+                None => Some(vec![
+                    self.correction(loc.start_offset(), loc.start_offset(), "("),
+                    self.correction(loc.end_offset(), loc.end_offset(), ")"),
+                ]),
+            };
+            if let Some(edits) = edits {
+                corr.extend(edits);
+                diag.corrected = true;
             }
-            _ => {
-                // "require_no_parentheses" (default)
-                if is_parenthesized {
-                    let paren = predicate.as_parentheses_node().unwrap();
-                    let open_loc = paren.opening_loc();
-                    let (line, column) = source.offset_to_line_col(open_loc.start_offset());
-                    diagnostics.push(self.diagnostic(
-                        source,
-                        line,
-                        column,
-                        "Ternary conditions should not be wrapped in parentheses.".to_string(),
-                    ));
+        }
+        diagnostics.push(diag);
+    }
+}
+
+impl TernaryParentheses {
+    fn unwrap_condition(
+        &self,
+        source: &SourceFile,
+        paren: &ruby_prism::ParenthesesNode<'_>,
+    ) -> Option<Vec<Correction>> {
+        let body = paren.body()?;
+        let inner = match body.as_statements_node() {
+            Some(stmts) => {
+                let stmts_body = stmts.body();
+                if stmts_body.len() != 1 {
+                    return None;
                 }
+                stmts_body.iter().next()?
             }
+            None => body,
+        };
+        if unwrapping_changes_parse(&inner) {
+            return None;
         }
+
+        // `(foo)? a : b` needs a space once the `)` is gone.
+        let open = paren.opening_loc();
+        let close = paren.closing_loc();
+        let next = source.as_bytes().get(close.end_offset()).copied();
+        let close_replacement = if next.is_some_and(|b| !b.is_ascii_whitespace()) {
+            " "
+        } else {
+            ""
+        };
+        Some(vec![
+            self.correction(open.start_offset(), open.end_offset(), ""),
+            self.correction(close.start_offset(), close.end_offset(), close_replacement),
+        ])
     }
+
+    fn correction(&self, start: usize, end: usize, replacement: &str) -> Correction {
+        Correction {
+            start,
+            end,
+            replacement: replacement.to_string(),
+            cop_name: self.name(),
+            cop_index: 0,
+        }
+    }
+}
+
+/// RuboCop ignores conditions whose closing paren sits alone on the last line.
+fn only_closing_paren_on_last_line(predicate: &ruby_prism::Node<'_>) -> bool {
+    predicate
+        .location()
+        .as_slice()
+        .split(|&b| b == b'\n')
+        .next_back()
+        .is_some_and(|last| last == b")")
+}
+
+/// Whether dropping the parentheses around `inner` would make `?` bind to
+/// something else, e.g. `(foo bar) ? a : b` becoming `foo(bar ? a : b)`.
+fn unwrapping_changes_parse(inner: &ruby_prism::Node<'_>) -> bool {
+    if is_assignment_type(node_type_tag(inner))
+        || inner.as_defined_node().is_some()
+        || inner.as_range_node().is_some()
+        || inner.as_flip_flop_node().is_some()
+        || inner.as_if_node().is_some()
+        || inner.as_unless_node().is_some()
+        || inner.as_while_node().is_some()
+        || inner.as_until_node().is_some()
+        || inner.as_rescue_modifier_node().is_some()
+    {
+        return true;
+    }
+    if let Some(and) = inner.as_and_node() {
+        return and.operator_loc().as_slice() == b"and";
+    }
+    if let Some(or) = inner.as_or_node() {
+        return or.operator_loc().as_slice() == b"or";
+    }
+    if let Some(call) = inner.as_call_node() {
+        // `not foo`
+        if call.message_loc().is_some_and(|m| m.as_slice() == b"not") {
+            return true;
+        }
+        // `foo.bar = 1`, `foo[k] = 1`
+        if call.is_attribute_write() {
+            return true;
+        }
+        let name = call.name().as_slice();
+        return call.arguments().is_some()
+            && call.opening_loc().is_none()
+            && name[0].is_ascii_alphabetic();
+    }
+    if let Some(yield_node) = inner.as_yield_node() {
+        return yield_node.arguments().is_some() && yield_node.lparen_loc().is_none();
+    }
+    if let Some(super_node) = inner.as_super_node() {
+        return super_node.arguments().is_some() && super_node.lparen_loc().is_none();
+    }
+    false
 }
 
 #[cfg(test)]
@@ -245,6 +361,7 @@ mod tests {
     use crate::testutil::{run_cop_full, run_cop_full_with_config};
 
     crate::cop_fixture_tests!(TernaryParentheses, "cops/style/ternary_parentheses");
+    crate::cop_autocorrect_fixture_tests!(TernaryParentheses, "cops/style/ternary_parentheses");
 
     #[test]
     fn require_parentheses_flags_missing() {
@@ -341,4 +458,126 @@ mod tests {
             diags
         );
     }
+
+    fn style_config(style: &str) -> CopConfig {
+        crate::testutil::cop_config_from_yaml(&format!("EnforcedStyle: {style}"))
+    }
+
+    #[test]
+    fn offense_require_parentheses_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &TernaryParentheses,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/ternary_parentheses/offense.require_parentheses.rb"
+            ),
+            style_config("require_parentheses"),
+        );
+    }
+
+    #[test]
+    fn no_offense_require_parentheses_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &TernaryParentheses,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/ternary_parentheses/no_offense.require_parentheses.rb"
+            ),
+            style_config("require_parentheses"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_require_parentheses_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &TernaryParentheses,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/ternary_parentheses/offense.require_parentheses.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/ternary_parentheses/corrected.require_parentheses.rb"
+            ),
+            style_config("require_parentheses"),
+        );
+    }
+
+    #[test]
+    fn offense_require_parentheses_when_complex_fixture() {
+        use crate::testutil::assert_cop_offenses_full_with_config;
+        assert_cop_offenses_full_with_config(
+            &TernaryParentheses,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/ternary_parentheses/offense.require_parentheses_when_complex.rb"
+            ),
+            style_config("require_parentheses_when_complex"),
+        );
+    }
+
+    #[test]
+    fn no_offense_require_parentheses_when_complex_fixture() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        assert_cop_no_offenses_full_with_config(
+            &TernaryParentheses,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/ternary_parentheses/no_offense.require_parentheses_when_complex.rb"
+            ),
+            style_config("require_parentheses_when_complex"),
+        );
+    }
+
+    #[test]
+    fn autocorrect_require_parentheses_when_complex_fixture() {
+        use crate::testutil::assert_cop_autocorrect_with_config;
+        assert_cop_autocorrect_with_config(
+            &TernaryParentheses,
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/ternary_parentheses/offense.require_parentheses_when_complex.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/ternary_parentheses/corrected.require_parentheses_when_complex.rb"
+            ),
+            style_config("require_parentheses_when_complex"),
+        );
+    }
+
+    /// `AllowSafeAssignment` is shared with `Lint/AssignmentInCondition`; both
+    /// cops must agree on which parenthesized assignments are acceptable.
+    #[test]
+    fn safe_assignment_with_assignment_in_condition() {
+        use crate::cop::lint::assignment_in_condition::AssignmentInCondition;
+        use crate::testutil::{assert_cop_no_offenses_full_with_config, cop_config_from_yaml};
+
+        let source = include_bytes!(
+            "../../../tests/fixtures/cops/style/ternary_parentheses/no_offense.safe_assignment.rb"
+        );
+        assert_cop_no_offenses_full_with_config(
+            &TernaryParentheses,
+            source,
+            style_config("require_parentheses_when_complex"),
+        );
+        assert_cop_no_offenses_full_with_config(
+            &AssignmentInCondition,
+            source,
+            CopConfig::default(),
+        );
+
+        crate::testutil::assert_cops_offenses_full_with_configs(
+            &[
+                (
+                    &TernaryParentheses,
+                    cop_config_from_yaml(
+                        "EnforcedStyle: require_parentheses_when_complex\nAllowSafeAssignment: false\n",
+                    ),
+                ),
+                (
+                    &AssignmentInCondition,
+                    cop_config_from_yaml("AllowSafeAssignment: false\n"),
+                ),
+            ],
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/ternary_parentheses/offense.safe_assignment_disallowed.rb"
+            ),
+        );
+    }
 }
@@ -43,7 +43,7 @@ impl Cop for YAMLFileRead {
         source: &SourceFile,
         node: &ruby_prism::Node<'_>,
         _parse_result: &ruby_prism::ParseResult<'_>,
-        _config: &CopConfig,
+        config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
         _corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
@@ -128,15 +128,8 @@ impl Cop for YAMLFileRead {
 
         // YAML.safe_load_file was introduced in Ruby 3.0;
         // skip this offense for safe_load when target Ruby version <= 2.7
-        if name == b"safe_load" {
-            let target_ruby = _config
-                .options
-                .get("TargetRubyVersion")
-                .and_then(|v| v.as_f64().or_else(|| v.as_u64().map(|u| u as f64)))
-                .unwrap_or(3.4);
-            if target_ruby <= 2.7 {
-                return;
-            }
+        if name == b"safe_load" && config.ruby_version_at_most(2.7) {
+            return;
         }
 
         let name_str = String::from_utf8_lossy(name);
@@ -157,5 +150,31 @@ impl Cop for YAMLFileRead {
 #[cfg(test)]
 mod tests {
     use super::*;
-    crate::cop_fixture_tests!(YAMLFileRead, "cops/style/yaml_file_read");
+
+    fn ruby30_config() -> CopConfig {
+        let mut config = CopConfig::default();
+        config.options.insert(
+            "TargetRubyVersion".to_string(),
+            serde_yml::Value::Number(serde_yml::Number::from(3.0)),
+        );
+        config
+    }
+
+    #[test]
+    fn offense_fixture() {
+        crate::testutil::assert_cop_offenses_full_with_config(
+            &YAMLFileRead,
+            include_bytes!("../../../tests/fixtures/cops/style/yaml_file_read/offense.rb"),
+            ruby30_config(),
+        );
+    }
+
+    #[test]
+    fn no_offense_fixture() {
+        crate::testutil::assert_cop_no_offenses_full_with_config(
+            &YAMLFileRead,
+            include_bytes!("../../../tests/fixtures/cops/style/yaml_file_read/no_offense.rb"),
+            ruby30_config(),
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::cop::shared::method_dispatch_predicates;
 use crate::cop::shared::node_type::{
     CALL_NODE, FALSE_NODE, FLOAT_NODE, INTEGER_NODE, NIL_NODE, STRING_NODE, SYMBOL_NODE, TRUE_NODE,
 };
@@ -34,6 +35,10 @@ use crate::parse::source::SourceFile;
 /// - FN: RuboCop treats `__FILE__` as a constant portion, except for the explicit
 ///   `__FILE__ == $0` / `$PROGRAM_NAME` exemption. Added SourceFileNode support and the
 ///   matching exemption.
+///
+/// Autocorrect (unsafe, as upstream) swaps the operands and mirrors ordering
+/// operators (`1 < x` → `x > 1`). Safe-navigation comparisons (`1&.==(x)`)
+/// are skipped entirely, since RuboCop handles `send` nodes only.
 pub struct YodaCondition;
 
 /// RuboCop's `constant_portion?` checks `node.literal? || node.const_type?`.
@@ -104,6 +109,14 @@ impl Cop for YodaCondition {
         "Style/YodaCondition"
     }
 
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
     fn interested_node_types(&self) -> &'static [u8] {
         &[
             CALL_NODE,
@@ -124,7 +137,7 @@ impl Cop for YodaCondition {
         _parse_result: &ruby_prism::ParseResult<'_>,
         config: &CopConfig,
         diagnostics: &mut Vec<Diagnostic>,
-        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
     ) {
         let enforced_style = config.get_str("EnforcedStyle", "forbid_for_all_comparison_operators");
         let call = match node.as_call_node() {
@@ -132,6 +145,11 @@ impl Cop for YodaCondition {
             None => return,
         };
 
+        // RuboCop only handles `send`; `1&.==(x)` is never reported
+        if method_dispatch_predicates::is_safe_navigation(&call) {
+            return;
+        }
+
         let name = call.name().as_slice();
 
         let is_equality = name == b"==" || name == b"!=";
@@ -184,31 +202,53 @@ impl Cop for YodaCondition {
             return;
         }
 
-        if require_yoda {
-            // Require Yoda: flag when literal is on the RIGHT (non-Yoda)
-            if !lhs_constant && rhs_constant {
-                let loc = call.location();
-                let (line, column) = source.offset_to_line_col(loc.start_offset());
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Prefer Yoda conditions.".to_string(),
-                ));
+        // Require Yoda: flag when literal is on the RIGHT (non-Yoda).
+        // Forbid Yoda: flag when literal is on the LEFT (Yoda).
+        let message = if require_yoda {
+            if lhs_constant {
+                return;
             }
+            "Prefer Yoda conditions."
         } else {
-            // Forbid Yoda: flag when literal is on the LEFT (Yoda)
-            if lhs_constant && !rhs_constant {
-                let loc = call.location();
-                let (line, column) = source.offset_to_line_col(loc.start_offset());
-                diagnostics.push(self.diagnostic(
-                    source,
-                    line,
-                    column,
-                    "Prefer non-Yoda conditions.".to_string(),
-                ));
+            if rhs_constant {
+                return;
             }
+            "Prefer non-Yoda conditions."
+        };
+
+        let loc = call.location();
+        let (line, column) = source.offset_to_line_col(loc.start_offset());
+        let mut diag = self.diagnostic(source, line, column, message.to_string());
+        if let Some(corrections) = corrections {
+            let lhs = receiver.location().as_slice();
+            let rhs = arg_list[0].location().as_slice();
+            corrections.push(crate::correction::Correction {
+                start: loc.start_offset(),
+                end: loc.end_offset(),
+                replacement: format!(
+                    "{} {} {}",
+                    String::from_utf8_lossy(rhs),
+                    reverse_comparison(name),
+                    String::from_utf8_lossy(lhs)
+                ),
+                cop_name: self.name(),
+                cop_index: 0,
+            });
+            diag.corrected = true;
         }
+        diagnostics.push(diag);
+    }
+}
+
+/// The operator that keeps a comparison's meaning once its operands swap.
+fn reverse_comparison(name: &[u8]) -> &'static str {
+    match name {
+        b"<" => ">",
+        b"<=" => ">=",
+        b">" => "<",
+        b">=" => "<=",
+        b"!=" => "!=",
+        _ => "==",
     }
 }
 
@@ -218,6 +258,67 @@ mod tests {
     use crate::testutil::run_cop_full;
 
     crate::cop_fixture_tests!(YodaCondition, "cops/style/yoda_condition");
+    crate::cop_autocorrect_fixture_tests!(YodaCondition, "cops/style/yoda_condition");
+
+    fn assert_style_fixtures(style: &str, offense: &[u8], corrected: &[u8], no_offense: &[u8]) {
+        let yaml = format!("EnforcedStyle: {style}\n");
+        crate::testutil::assert_cop_offenses_with_yaml(&YodaCondition, &yaml, offense);
+        crate::testutil::assert_cop_no_offenses_with_yaml(&YodaCondition, &yaml, no_offense);
+        crate::testutil::assert_cop_autocorrect_with_config(
+            &YodaCondition,
+            offense,
+            corrected,
+            crate::testutil::cop_config_from_yaml(&yaml),
+        );
+    }
+
+    #[test]
+    fn forbid_for_equality_operators_only_fixtures() {
+        assert_style_fixtures(
+            "forbid_for_equality_operators_only",
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/yoda_condition/offense.forbid_equality.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/yoda_condition/corrected.forbid_equality.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/yoda_condition/no_offense.forbid_equality.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn require_for_all_comparison_operators_fixtures() {
+        assert_style_fixtures(
+            "require_for_all_comparison_operators",
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/yoda_condition/offense.require_all.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/yoda_condition/corrected.require_all.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/yoda_condition/no_offense.require_all.rb"
+            ),
+        );
+    }
+
+    #[test]
+    fn require_for_equality_operators_only_fixtures() {
+        assert_style_fixtures(
+            "require_for_equality_operators_only",
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/yoda_condition/offense.require_equality.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/yoda_condition/corrected.require_equality.rb"
+            ),
+            include_bytes!(
+                "../../../tests/fixtures/cops/style/yoda_condition/no_offense.require_equality.rb"
+            ),
+        );
+    }
 
     #[test]
     fn both_literals_not_flagged() {
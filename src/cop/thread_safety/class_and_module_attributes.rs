@@ -0,0 +1,125 @@
+use ruby_prism::Visit;
+
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// ThreadSafety/ClassAndModuleAttributes
+///
+/// Flags class- and module-level attribute writers, which share mutable state
+/// between threads: `mattr_writer`/`mattr_accessor`/`cattr_writer`/
+/// `cattr_accessor`, Active Support's `class_attribute` (unless
+/// `ActiveSupportClassAttributeAllowed` is true), and `attr`/`attr_accessor`/
+/// `attr_writer`/`attr_internal*` inside `class << self` (but not inside a
+/// method defined there).
+pub struct ClassAndModuleAttributes;
+
+impl Cop for ClassAndModuleAttributes {
+    fn name(&self) -> &'static str {
+        "ThreadSafety/ClassAndModuleAttributes"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn check_source(
+        &self,
+        source: &SourceFile,
+        parse_result: &ruby_prism::ParseResult<'_>,
+        _code_map: &crate::parse::codemap::CodeMap,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let mut visitor = AttributeVisitor {
+            cop: self,
+            source,
+            class_attribute_allowed: config.get_bool("ActiveSupportClassAttributeAllowed", false),
+            in_singleton_class: false,
+            diagnostics: Vec::new(),
+        };
+        visitor.visit(&parse_result.node());
+        diagnostics.extend(visitor.diagnostics);
+    }
+}
+
+struct AttributeVisitor<'a> {
+    cop: &'a ClassAndModuleAttributes,
+    source: &'a SourceFile,
+    class_attribute_allowed: bool,
+    in_singleton_class: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl AttributeVisitor<'_> {
+    fn is_mutating_attribute(&self, name: &[u8]) -> bool {
+        match name {
+            b"mattr_writer" | b"mattr_accessor" | b"cattr_writer" | b"cattr_accessor" => true,
+            b"class_attribute" => !self.class_attribute_allowed,
+            b"attr"
+            | b"attr_accessor"
+            | b"attr_writer"
+            | b"attr_internal"
+            | b"attr_internal_accessor"
+            | b"attr_internal_writer" => self.in_singleton_class,
+            _ => false,
+        }
+    }
+}
+
+impl<'pr> Visit<'pr> for AttributeVisitor<'_> {
+    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+        if node.receiver().is_none() && self.is_mutating_attribute(node.name().as_slice()) {
+            let (line, column) = self
+                .source
+                .offset_to_line_col(node.location().start_offset());
+            self.diagnostics.push(self.cop.diagnostic(
+                self.source,
+                line,
+                column,
+                "Avoid mutating class and module attributes.".to_string(),
+            ));
+        }
+        ruby_prism::visit_call_node(self, node);
+    }
+
+    fn visit_singleton_class_node(&mut self, node: &ruby_prism::SingletonClassNode<'pr>) {
+        let was = self.in_singleton_class;
+        self.in_singleton_class = true;
+        ruby_prism::visit_singleton_class_node(self, node);
+        self.in_singleton_class = was;
+    }
+
+    fn visit_def_node(&mut self, node: &ruby_prism::DefNode<'pr>) {
+        let was = self.in_singleton_class;
+        self.in_singleton_class = false;
+        ruby_prism::visit_def_node(self, node);
+        self.in_singleton_class = was;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(
+        ClassAndModuleAttributes,
+        "cops/thread_safety/class_and_module_attributes"
+    );
+
+    #[test]
+    fn class_attribute_allowed() {
+        use crate::testutil::assert_cop_no_offenses_full_with_config;
+        use std::collections::HashMap;
+
+        let config = CopConfig {
+            options: HashMap::from([(
+                "ActiveSupportClassAttributeAllowed".into(),
+                serde_yml::Value::Bool(true),
+            )]),
+            ..CopConfig::default()
+        };
+        let source = b"class User\n  class_attribute :settings\nend\n";
+        assert_cop_no_offenses_full_with_config(&ClassAndModuleAttributes, source, config);
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::HashSet;
+
+use ruby_prism::Visit;
+
+use crate::cop::shared::constant_predicates;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// ThreadSafety/ClassInstanceVariable (formerly InstanceVariableInClassMethod)
+///
+/// Flags instance variables, and `instance_variable_get`/`instance_variable_set`
+/// calls, used in class-level code where they are shared between threads:
+/// - `def self.foo`, and any `def` nested under one
+/// - methods in `class << self`, in a `module ClassMethods`, or made module
+///   functions with a preceding bare `module_function` or a later
+///   `module_function :foo`
+/// - `class_methods do`, `define_singleton_method` and
+///   `class_eval`/`class_exec` blocks
+///
+/// As in rubocop-thread_safety, `define_method` blocks and anything inside a
+/// `synchronize` block are exempt, and `Class.new`/`Module.new` blocks start a
+/// fresh scope. Instance variables directly in a class body are not flagged.
+pub struct ClassInstanceVariable;
+
+impl Cop for ClassInstanceVariable {
+    fn name(&self) -> &'static str {
+        "ThreadSafety/ClassInstanceVariable"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn check_source(
+        &self,
+        source: &SourceFile,
+        parse_result: &ruby_prism::ParseResult<'_>,
+        _code_map: &crate::parse::codemap::CodeMap,
+        _config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let mut visitor = ClassIvarVisitor {
+            cop: self,
+            source,
+            scope: Scope::default(),
+            module_function_defs: HashSet::new(),
+            diagnostics: Vec::new(),
+        };
+        visitor.visit(&parse_result.node());
+        diagnostics.extend(visitor.diagnostics);
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Scope {
+    /// Under a `def self.foo`.
+    in_defs: bool,
+    /// Under `class << self`.
+    in_sclass: bool,
+    /// The nearest class/module is `module ClassMethods`.
+    in_class_methods_module: bool,
+    /// The current `def` is a class method through `class << self`,
+    /// `ClassMethods` or `module_function`.
+    def_is_class_method: bool,
+    /// The nearest block (without crossing a `def`) is `class_eval`/`class_exec`.
+    in_class_eval: bool,
+    /// Under a `class_methods do` or `define_singleton_method` block.
+    in_singleton_block: bool,
+    in_define_method: bool,
+    synchronized: bool,
+}
+
+impl Scope {
+    fn is_class_level(self) -> bool {
+        (self.in_defs || self.def_is_class_method || self.in_class_eval || self.in_singleton_block)
+            && !self.in_define_method
+            && !self.synchronized
+    }
+}
+
+struct ClassIvarVisitor<'a> {
+    cop: &'a ClassInstanceVariable,
+    source: &'a SourceFile,
+    scope: Scope,
+    /// Start offsets of `def`s turned into module functions.
+    module_function_defs: HashSet<usize>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+fn is_class_or_module_constant(node: &ruby_prism::Node<'_>) -> bool {
+    constant_predicates::is_simple_constant(node, b"Class")
+        || constant_predicates::is_simple_constant(node, b"Module")
+}
+
+impl ClassIvarVisitor<'_> {
+    fn add_offense(&mut self, start_offset: usize) {
+        if !self.scope.is_class_level() {
+            return;
+        }
+        let (line, column) = self.source.offset_to_line_col(start_offset);
+        self.diagnostics.push(self.cop.diagnostic(
+            self.source,
+            line,
+            column,
+            "Avoid class instance variables.".to_string(),
+        ));
+    }
+
+    fn with_scope(&mut self, scope: Scope, f: impl FnOnce(&mut Self)) {
+        let was = std::mem::replace(&mut self.scope, scope);
+        f(self);
+        self.scope = was;
+    }
+
+    /// The scope for a call's block, or `None` if the call has no block.
+    fn block_scope(&self, node: &ruby_prism::CallNode<'_>) -> Option<Scope> {
+        node.block()?.as_block_node()?;
+        let mut scope = self.scope;
+        let name = node.name().as_slice();
+        let receiver = node.receiver();
+        match name {
+            b"new" if receiver.as_ref().is_some_and(is_class_or_module_constant) => {
+                scope = Scope {
+                    synchronized: scope.synchronized,
+                    ..Scope::default()
+                };
+            }
+            b"define_method" if receiver.is_none() => scope.in_define_method = true,
+            b"define_singleton_method" | b"class_methods" if receiver.is_none() => {
+                scope.in_singleton_block = true;
+            }
+            b"class_eval" | b"class_exec" => scope.in_class_eval = true,
+            b"synchronize" => scope.synchronized = true,
+            _ => scope.in_class_eval = false,
+        }
+        Some(scope)
+    }
+}
+
+impl<'pr> Visit<'pr> for ClassIvarVisitor<'_> {
+    fn visit_statements_node(&mut self, node: &ruby_prism::StatementsNode<'pr>) {
+        let mut bare_module_function = false;
+        let mut defs = Vec::new();
+        for stmt in node.body().iter() {
+            if let Some(def) = stmt.as_def_node() {
+                if bare_module_function {
+                    self.module_function_defs
+                        .insert(def.location().start_offset());
+                }
+                defs.push((
+                    def.name().as_slice().to_vec(),
+                    def.location().start_offset(),
+                ));
+                continue;
+            }
+            let Some(call) = stmt.as_call_node() else {
+                continue;
+            };
+            if call.receiver().is_some() || call.name().as_slice() != b"module_function" {
+                continue;
+            }
+            let Some(args) = call.arguments() else {
+                bare_module_function = true;
+                continue;
+            };
+            for arg in args.arguments().iter() {
+                let Some(sym) = arg.as_symbol_node() else {
+                    continue;
+                };
+                for (name, offset) in &defs {
+                    if name.as_slice() == sym.unescaped() {
+                        self.module_function_defs.insert(*offset);
+                    }
+                }
+            }
+        }
+        ruby_prism::visit_statements_node(self, node);
+    }
+
+    fn visit_class_node(&mut self, node: &ruby_prism::ClassNode<'pr>) {
+        let scope = Scope {
+            in_sclass: self.scope.in_sclass,
+            synchronized: self.scope.synchronized,
+            ..Scope::default()
+        };
+        self.with_scope(scope, |v| ruby_prism::visit_class_node(v, node));
+    }
+
+    fn visit_module_node(&mut self, node: &ruby_prism::ModuleNode<'pr>) {
+        let constant_path = node.constant_path();
+        let name = constant_path.location().as_slice();
+        let scope = Scope {
+            in_sclass: self.scope.in_sclass,
+            in_class_methods_module: name == b"ClassMethods" || name.ends_with(b"::ClassMethods"),
+            synchronized: self.scope.synchronized,
+            ..Scope::default()
+        };
+        self.with_scope(scope, |v| ruby_prism::visit_module_node(v, node));
+    }
+
+    fn visit_singleton_class_node(&mut self, node: &ruby_prism::SingletonClassNode<'pr>) {
+        let scope = Scope {
+            in_sclass: true,
+            ..self.scope
+        };
+        self.with_scope(scope, |v| ruby_prism::visit_singleton_class_node(v, node));
+    }
+
+    fn visit_def_node(&mut self, node: &ruby_prism::DefNode<'pr>) {
+        let scope = Scope {
+            in_defs: self.scope.in_defs || node.receiver().is_some(),
+            def_is_class_method: self.scope.in_sclass
+                || self.scope.in_class_methods_module
+                || self
+                    .module_function_defs
+                    .contains(&node.location().start_offset()),
+            in_class_eval: false,
+            ..self.scope
+        };
+        self.with_scope(scope, |v| ruby_prism::visit_def_node(v, node));
+    }
+
+    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+        if node.receiver().is_none() {
+            let arg_count = node.arguments().map_or(0, |a| a.arguments().len());
+            let is_ivar_call = match node.name().as_slice() {
+                b"instance_variable_set" => arg_count == 2,
+                b"instance_variable_get" => arg_count == 1,
+                _ => false,
+            };
+            if is_ivar_call {
+                self.add_offense(node.location().start_offset());
+            }
+        }
+
+        match self.block_scope(node) {
+            Some(scope) => self.with_scope(scope, |v| ruby_prism::visit_call_node(v, node)),
+            None => ruby_prism::visit_call_node(self, node),
+        }
+    }
+
+    fn visit_instance_variable_read_node(
+        &mut self,
+        node: &ruby_prism::InstanceVariableReadNode<'pr>,
+    ) {
+        self.add_offense(node.location().start_offset());
+    }
+
+    fn visit_instance_variable_write_node(
+        &mut self,
+        node: &ruby_prism::InstanceVariableWriteNode<'pr>,
+    ) {
+        self.add_offense(node.name_loc().start_offset());
+        ruby_prism::visit_instance_variable_write_node(self, node);
+    }
+
+    fn visit_instance_variable_or_write_node(
+        &mut self,
+        node: &ruby_prism::InstanceVariableOrWriteNode<'pr>,
+    ) {
+        self.add_offense(node.name_loc().start_offset());
+        ruby_prism::visit_instance_variable_or_write_node(self, node);
+    }
+
+    fn visit_instance_variable_and_write_node(
+        &mut self,
+        node: &ruby_prism::InstanceVariableAndWriteNode<'pr>,
+    ) {
+        self.add_offense(node.name_loc().start_offset());
+        ruby_prism::visit_instance_variable_and_write_node(self, node);
+    }
+
+    fn visit_instance_variable_operator_write_node(
+        &mut self,
+        node: &ruby_prism::InstanceVariableOperatorWriteNode<'pr>,
+    ) {
+        self.add_offense(node.name_loc().start_offset());
+        ruby_prism::visit_instance_variable_operator_write_node(self, node);
+    }
+
+    fn visit_instance_variable_target_node(
+        &mut self,
+        node: &ruby_prism::InstanceVariableTargetNode<'pr>,
+    ) {
+        self.add_offense(node.location().start_offset());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(
+        ClassInstanceVariable,
+        "cops/thread_safety/class_instance_variable"
+    );
+}
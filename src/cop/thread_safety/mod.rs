@@ -0,0 +1,17 @@
+pub mod class_and_module_attributes;
+pub mod class_instance_variable;
+pub mod mutable_class_instance_variable;
+pub mod new_thread;
+
+use super::registry::CopRegistry;
+
+pub fn register_all(registry: &mut CopRegistry) {
+    registry.register(Box::new(
+        class_and_module_attributes::ClassAndModuleAttributes,
+    ));
+    registry.register(Box::new(class_instance_variable::ClassInstanceVariable));
+    registry.register(Box::new(
+        mutable_class_instance_variable::MutableClassInstanceVariable,
+    ));
+    registry.register(Box::new(new_thread::NewThread));
+}
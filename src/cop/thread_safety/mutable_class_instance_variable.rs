@@ -0,0 +1,278 @@
+use ruby_prism::Visit;
+
+use crate::cop::shared::{constant_predicates, literal_predicates};
+use crate::cop::style::mutable_constant::MutableConstant;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// ThreadSafety/MutableClassInstanceVariable
+///
+/// Flags mutable objects assigned to class instance variables, i.e. `@ivar =`,
+/// `@ivar ||=` and `@a, @b = ...` whose nearest enclosing scope is a class or
+/// module body rather than a method or `define_method`/`define_singleton_method`
+/// block.
+///
+/// `EnforcedStyle: literals` (default) only flags mutable literals (strings,
+/// arrays, hashes, regexps and ranges). `strict` flags any value that is not
+/// known to be immutable or thread-safe (`Queue.new`, `ThreadSafe::Hash.new`,
+/// `Concurrent::*.new`). Plain strings are accepted under
+/// `# frozen_string_literal: true`.
+///
+/// Autocorrect appends `.freeze`, bracketing implicit arrays, parenthesizing
+/// ranges and operator calls, and turning `[*range]` into `range.to_a`. It is
+/// unsafe because code may rely on mutating the object later.
+pub struct MutableClassInstanceVariable;
+
+const MSG: &str = "Freeze mutable objects assigned to class instance variables.";
+
+/// `operation_produces_threadsafe_object?`: `Queue.new`,
+/// `ThreadSafe::{Hash,Array}.new` and `Concurrent::...new` up to three levels
+/// deep.
+fn produces_threadsafe_object(node: &ruby_prism::Node<'_>) -> bool {
+    let Some(call) = node.as_call_node() else {
+        return false;
+    };
+    if call.name().as_slice() != b"new" {
+        return false;
+    }
+    let Some(receiver) = call.receiver() else {
+        return false;
+    };
+    match constant_predicates::constant_path_segments(&receiver).as_slice() {
+        [b"Queue"] => true,
+        [b"ThreadSafe", b"Hash" | b"Array"] => true,
+        [b"Concurrent", rest @ ..] => (1..=3).contains(&rest.len()),
+        _ => false,
+    }
+}
+
+/// `requires_parentheses?`: ranges and method calls without a dot
+/// (operators, bare method calls) need parentheses before `.freeze`.
+fn requires_parentheses(node: &ruby_prism::Node<'_>) -> bool {
+    node.as_range_node().is_some()
+        || node
+            .as_call_node()
+            .is_some_and(|call| call.call_operator_loc().is_none())
+}
+
+impl Cop for MutableClassInstanceVariable {
+    fn name(&self) -> &'static str {
+        "ThreadSafety/MutableClassInstanceVariable"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn supports_autocorrect(&self) -> bool {
+        true
+    }
+
+    fn safe_autocorrect(&self) -> bool {
+        false
+    }
+
+    fn check_source(
+        &self,
+        source: &SourceFile,
+        parse_result: &ruby_prism::ParseResult<'_>,
+        _code_map: &crate::parse::codemap::CodeMap,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let mut visitor = MutableIvarVisitor {
+            cop: self,
+            source,
+            strict: config.get_str("EnforcedStyle", "literals") == "strict",
+            frozen_strings: MutableConstant::has_frozen_string_literal_true(source),
+            in_class: false,
+            diagnostics: Vec::new(),
+            corrections: Vec::new(),
+        };
+        visitor.visit(&parse_result.node());
+        if let Some(corr) = corrections {
+            for diag in &mut visitor.diagnostics {
+                diag.corrected = true;
+            }
+            corr.extend(visitor.corrections);
+        }
+        diagnostics.extend(visitor.diagnostics);
+    }
+}
+
+struct MutableIvarVisitor<'a> {
+    cop: &'a MutableClassInstanceVariable,
+    source: &'a SourceFile,
+    strict: bool,
+    frozen_strings: bool,
+    /// Whether the nearest container (`class`, `module`, `def`, or a
+    /// `define_method`/`define_singleton_method` block) is a class or module.
+    in_class: bool,
+    diagnostics: Vec<Diagnostic>,
+    corrections: Vec<crate::correction::Correction>,
+}
+
+impl MutableIvarVisitor<'_> {
+    fn is_offending_value(&self, value: &ruby_prism::Node<'_>) -> bool {
+        if self.frozen_strings && MutableConstant::is_plain_string(self.source, value) {
+            return false;
+        }
+        if self.strict {
+            !(literal_predicates::is_immutable_literal(value)
+                || MutableConstant::operation_produces_immutable_object(value)
+                || MutableConstant::is_struct_new_block(value)
+                || produces_threadsafe_object(value))
+        } else {
+            literal_predicates::is_mutable_literal(value)
+                || MutableConstant::is_parenthesized_range(value)
+        }
+    }
+
+    fn frozen_replacement(&self, value: &ruby_prism::Node<'_>) -> String {
+        let loc = value.location();
+        let value_source = self
+            .source
+            .byte_slice(loc.start_offset(), loc.end_offset(), "");
+
+        if let Some(array) = value.as_array_node() {
+            let elements: Vec<_> = array.elements().iter().collect();
+            if let [element] = elements.as_slice() {
+                if let Some(splat) = element.as_splat_node() {
+                    if let Some(expr) = splat.expression() {
+                        let expr_loc = expr.location();
+                        let expr_source = self.source.byte_slice(
+                            expr_loc.start_offset(),
+                            expr_loc.end_offset(),
+                            "",
+                        );
+                        return if MutableConstant::is_parenthesized_range(&expr) {
+                            format!("{expr_source}.to_a.freeze")
+                        } else {
+                            format!("({expr_source}).to_a.freeze")
+                        };
+                    }
+                }
+            }
+            if array.opening_loc().is_none() {
+                return format!("[{value_source}].freeze");
+            }
+        }
+        if requires_parentheses(value) {
+            return format!("({value_source}).freeze");
+        }
+        format!("{value_source}.freeze")
+    }
+
+    fn check_value(&mut self, value: &ruby_prism::Node<'_>) {
+        if !self.in_class || !self.is_offending_value(value) {
+            return;
+        }
+        let loc = value.location();
+        let (line, column) = self.source.offset_to_line_col(loc.start_offset());
+        self.diagnostics.push(
+            self.cop
+                .diagnostic(self.source, line, column, MSG.to_string()),
+        );
+        self.corrections.push(crate::correction::Correction {
+            start: loc.start_offset(),
+            end: loc.end_offset(),
+            replacement: self.frozen_replacement(value),
+            cop_name: self.cop.name(),
+            cop_index: 0,
+        });
+    }
+
+    fn with_container(&mut self, in_class: bool, f: impl FnOnce(&mut Self)) {
+        let was = std::mem::replace(&mut self.in_class, in_class);
+        f(self);
+        self.in_class = was;
+    }
+}
+
+impl<'pr> Visit<'pr> for MutableIvarVisitor<'_> {
+    fn visit_class_node(&mut self, node: &ruby_prism::ClassNode<'pr>) {
+        self.with_container(true, |v| ruby_prism::visit_class_node(v, node));
+    }
+
+    fn visit_module_node(&mut self, node: &ruby_prism::ModuleNode<'pr>) {
+        self.with_container(true, |v| ruby_prism::visit_module_node(v, node));
+    }
+
+    fn visit_def_node(&mut self, node: &ruby_prism::DefNode<'pr>) {
+        self.with_container(false, |v| ruby_prism::visit_def_node(v, node));
+    }
+
+    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+        let defines_method = node.receiver().is_none()
+            && matches!(
+                node.name().as_slice(),
+                b"define_method" | b"define_singleton_method"
+            )
+            && node.block().is_some_and(|b| b.as_block_node().is_some());
+        if defines_method {
+            self.with_container(false, |v| ruby_prism::visit_call_node(v, node));
+        } else {
+            ruby_prism::visit_call_node(self, node);
+        }
+    }
+
+    fn visit_instance_variable_write_node(
+        &mut self,
+        node: &ruby_prism::InstanceVariableWriteNode<'pr>,
+    ) {
+        self.check_value(&node.value());
+        ruby_prism::visit_instance_variable_write_node(self, node);
+    }
+
+    fn visit_instance_variable_or_write_node(
+        &mut self,
+        node: &ruby_prism::InstanceVariableOrWriteNode<'pr>,
+    ) {
+        self.check_value(&node.value());
+        ruby_prism::visit_instance_variable_or_write_node(self, node);
+    }
+
+    fn visit_multi_write_node(&mut self, node: &ruby_prism::MultiWriteNode<'pr>) {
+        if let Some(values) = node.value().as_array_node() {
+            for (target, value) in node.lefts().iter().zip(values.elements().iter()) {
+                if target.as_instance_variable_target_node().is_some() {
+                    self.check_value(&value);
+                }
+            }
+        }
+        ruby_prism::visit_multi_write_node(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(
+        MutableClassInstanceVariable,
+        "cops/thread_safety/mutable_class_instance_variable"
+    );
+    crate::cop_autocorrect_fixture_tests!(
+        MutableClassInstanceVariable,
+        "cops/thread_safety/mutable_class_instance_variable"
+    );
+
+    #[test]
+    fn strict_style_flags_non_literal_values() {
+        use crate::testutil::run_cop_full_with_config;
+        use std::collections::HashMap;
+
+        let config = CopConfig {
+            options: HashMap::from([(
+                "EnforcedStyle".into(),
+                serde_yml::Value::String("strict".into()),
+            )]),
+            ..CopConfig::default()
+        };
+        let source = b"class Registry\n  @items = Set.new\n  @queue = Queue.new\n  @map = Concurrent::Map.new\n  @limit = 10\n  @name = NAME\nend\n";
+        let diags = run_cop_full_with_config(&MutableClassInstanceVariable, source, config);
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert_eq!(diags[0].location.line, 2);
+    }
+}
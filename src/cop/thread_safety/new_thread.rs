@@ -0,0 +1,114 @@
+use ruby_prism::Visit;
+
+use crate::cop::shared::constant_predicates;
+use crate::cop::{Cop, CopConfig};
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::parse::source::SourceFile;
+
+/// ThreadSafety/NewThread
+///
+/// Flags `Thread.new`, `Thread.fork` and `Thread.start` (including `::Thread`).
+/// Threads should be left to a framework such as Sidekiq or Puma.
+///
+/// `AllowedMethods` lists method names whose bodies may start threads, e.g. a
+/// supervisor's `start_workers`. The nearest enclosing `def` decides.
+pub struct NewThread;
+
+impl Cop for NewThread {
+    fn name(&self) -> &'static str {
+        "ThreadSafety/NewThread"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Convention
+    }
+
+    fn check_source(
+        &self,
+        source: &SourceFile,
+        parse_result: &ruby_prism::ParseResult<'_>,
+        _code_map: &crate::parse::codemap::CodeMap,
+        config: &CopConfig,
+        diagnostics: &mut Vec<Diagnostic>,
+        _corrections: Option<&mut Vec<crate::correction::Correction>>,
+    ) {
+        let mut visitor = NewThreadVisitor {
+            cop: self,
+            source,
+            allowed_methods: config
+                .get_string_array("AllowedMethods")
+                .unwrap_or_default(),
+            in_allowed_method: false,
+            diagnostics: Vec::new(),
+        };
+        visitor.visit(&parse_result.node());
+        diagnostics.extend(visitor.diagnostics);
+    }
+}
+
+struct NewThreadVisitor<'a> {
+    cop: &'a NewThread,
+    source: &'a SourceFile,
+    allowed_methods: Vec<String>,
+    in_allowed_method: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'pr> Visit<'pr> for NewThreadVisitor<'_> {
+    fn visit_def_node(&mut self, node: &ruby_prism::DefNode<'pr>) {
+        let was = self.in_allowed_method;
+        let name = node.name();
+        self.in_allowed_method = self
+            .allowed_methods
+            .iter()
+            .any(|m| m.as_bytes() == name.as_slice());
+        ruby_prism::visit_def_node(self, node);
+        self.in_allowed_method = was;
+    }
+
+    fn visit_call_node(&mut self, node: &ruby_prism::CallNode<'pr>) {
+        if !self.in_allowed_method
+            && matches!(node.name().as_slice(), b"new" | b"fork" | b"start")
+            && node
+                .receiver()
+                .is_some_and(|r| constant_predicates::is_simple_constant(&r, b"Thread"))
+        {
+            let (line, column) = self
+                .source
+                .offset_to_line_col(node.location().start_offset());
+            self.diagnostics.push(self.cop.diagnostic(
+                self.source,
+                line,
+                column,
+                "Avoid starting new threads.".to_string(),
+            ));
+        }
+        ruby_prism::visit_call_node(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::cop_fixture_tests!(NewThread, "cops/thread_safety/new_thread");
+
+    #[test]
+    fn allowed_methods_permit_threads_in_listed_defs() {
+        use crate::testutil::{assert_cop_no_offenses_full_with_config, run_cop_full_with_config};
+        use std::collections::HashMap;
+
+        let config = CopConfig {
+            options: HashMap::from([(
+                "AllowedMethods".into(),
+                serde_yml::Value::Sequence(vec![serde_yml::Value::String("start_workers".into())]),
+            )]),
+            ..CopConfig::default()
+        };
+        let source = b"def start_workers\n  Thread.new { work }\nend\n";
+        assert_cop_no_offenses_full_with_config(&NewThread, source, config.clone());
+
+        let source = b"def run\n  Thread.new { work }\nend\n";
+        let diags = run_cop_full_with_config(&NewThread, source, config);
+        assert_eq!(diags.len(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
@@ -23,6 +23,8 @@ struct TiersFile {
 pub struct TierMap {
     default_tier: Tier,
     overrides: HashMap<String, Tier>,
+    /// Preview cops run without `--preview` (AllCops.PreviewCops, `--preview-cop`).
+    promoted: HashSet<String>,
 }
 
 impl TierMap {
@@ -37,6 +39,7 @@ impl TierMap {
         Self {
             default_tier: data.default_tier,
             overrides: data.overrides,
+            promoted: HashSet::new(),
         }
     }
 
@@ -47,6 +50,28 @@ impl TierMap {
             .copied()
             .unwrap_or(self.default_tier)
     }
+
+    /// Override the tier for a cop, e.g. one registered by an embedding binary
+    /// that tiers.json doesn't know about.
+    pub fn set_tier(&mut self, cop_name: &str, tier: Tier) {
+        self.overrides.insert(cop_name.to_string(), tier);
+    }
+
+    /// Let a preview cop run without `--preview`. Its tier is unchanged, so
+    /// `--rules` still lists it as preview.
+    pub fn promote(&mut self, cop_name: &str) {
+        self.promoted.insert(cop_name.to_string());
+    }
+
+    /// Whether a preview cop was promoted with [`TierMap::promote`].
+    pub fn is_promoted(&self, cop_name: &str) -> bool {
+        self.tier_for(cop_name) == Tier::Preview && self.promoted.contains(cop_name)
+    }
+
+    /// Whether the cop only runs with `--preview`: preview tier and not promoted.
+    pub fn is_preview_gated(&self, cop_name: &str) -> bool {
+        self.tier_for(cop_name) == Tier::Preview && !self.promoted.contains(cop_name)
+    }
 }
 
 /// Tracks cops that were enabled by config but not run.
@@ -115,6 +140,29 @@ mod tests {
         assert_eq!(map.tier_for("Custom/MyCop"), Tier::Preview);
     }
 
+    #[test]
+    fn set_tier_overrides_default() {
+        let mut map = TierMap::load();
+        map.set_tier("Custom/MyCop", Tier::Stable);
+        assert_eq!(map.tier_for("Custom/MyCop"), Tier::Stable);
+    }
+
+    #[test]
+    fn promote_lifts_the_preview_gate_only() {
+        let mut map = TierMap::load();
+        assert!(map.is_preview_gated("Custom/MyCop"));
+        map.promote("Custom/MyCop");
+        assert!(!map.is_preview_gated("Custom/MyCop"));
+        assert!(map.is_promoted("Custom/MyCop"));
+        assert_eq!(map.tier_for("Custom/MyCop"), Tier::Preview);
+
+        // Promoting a stable cop is a no-op
+        map.set_tier("Custom/Stable", Tier::Stable);
+        map.promote("Custom/Stable");
+        assert!(!map.is_promoted("Custom/Stable"));
+        assert!(!map.is_preview_gated("Custom/Stable"));
+    }
+
     #[test]
     fn skip_summary_default_is_empty() {
         let s = SkipSummary::default();
@@ -0,0 +1,89 @@
+//! Warnings for `--only`/`--except` cops that will not run.
+//!
+//! A cop named on the command line can still be off: its plugin department
+//! may not be loaded, the config may disable it, or its Include/Exclude may
+//! match none of the target files. The run then reports nothing for it,
+//! which is easy to mistake for clean code, so each such name gets a warning
+//! naming the cause. Configs with nested `.rubocop.yml` files are only
+//! checked for unknown names, since a subdirectory can enable a cop the root
+//! config disables.
+
+use std::path::PathBuf;
+
+use crate::cli::Args;
+use crate::config::{CopFilterSet, DisabledReason, ResolvedConfig};
+use crate::cop::registry::CopRegistry;
+use crate::cop::tiers::TierMap;
+
+/// One warning per `--only`/`--except` cop that will not run on any of
+/// `files`, without the `warning: ` prefix.
+pub fn warnings(
+    args: &Args,
+    config: &ResolvedConfig,
+    registry: &CopRegistry,
+    tier_map: &TierMap,
+    filters: &CopFilterSet,
+    files: &[PathBuf],
+) -> Vec<String> {
+    let named = args
+        .only
+        .iter()
+        .map(|name| ("--only", name))
+        .chain(args.except.iter().map(|name| ("--except", name)));
+    let mut warnings = Vec::new();
+    for (flag, name) in named {
+        let Some(index) = registry.index_of(name) else {
+            warnings.push(format!("{flag} {name}: no such cop"));
+            continue;
+        };
+        if config.has_dir_overrides() {
+            continue;
+        }
+        let cop = registry.cops()[index].as_ref();
+        let cause = match config.disabled_reason(cop, tier_map, args.preview) {
+            Some(reason) => describe(reason, name, config),
+            None if !files.is_empty() && !files.iter().any(|f| filters.is_cop_match(index, f)) => {
+                format!(
+                    "its Include/Exclude patterns match none of the {} target files",
+                    files.len()
+                )
+            }
+            None => continue,
+        };
+        warnings.push(format!("{flag} {name} will not run: {cause}"));
+    }
+    warnings
+}
+
+/// Why the cop `name` is disabled, in words.
+fn describe(reason: DisabledReason, name: &str, config: &ResolvedConfig) -> String {
+    let dept = name.split('/').next().unwrap_or("");
+    match reason {
+        DisabledReason::Config => {
+            let set_by = config
+                .cop_provenance(name)
+                .and_then(|p| p.settings.get("Enabled"));
+            match set_by {
+                Some(path) => format!("`Enabled: false` in {}", path.display()),
+                None => "disabled by config".to_string(),
+            }
+        }
+        DisabledReason::Department => format!("the {dept} department is disabled by config"),
+        DisabledReason::Pending => {
+            "it is pending; set `Enabled: true` or `AllCops: NewCops: enable`".to_string()
+        }
+        DisabledReason::DisabledByDefault => {
+            "`AllCops: DisabledByDefault: true` and the config does not enable it".to_string()
+        }
+        DisabledReason::PluginNotLoaded { gem } => {
+            format!("the {dept} department is not loaded; add {gem} to `plugins:` in the config")
+        }
+        DisabledReason::NotInInstalledVersion => {
+            "the installed gem version does not have this cop".to_string()
+        }
+        DisabledReason::Preview => {
+            "it is a preview cop; run with --preview or --preview-cop".to_string()
+        }
+        DisabledReason::ConflictsWith(other) => format!("it is off while {other} is enabled"),
+    }
+}
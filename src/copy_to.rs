@@ -0,0 +1,186 @@
+//! `--copy-to DIR`: write autocorrected files into a mirror tree under DIR
+//! instead of over the originals.
+//!
+//! A file lands at its path relative to the working directory, so
+//! `lib/a.rb` becomes `DIR/lib/a.rb`. A file outside the working directory
+//! keeps its whole absolute path below DIR. With `--copy-all`, files that
+//! needed no corrections are copied as they are, and DIR holds every linted
+//! file.
+
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+
+use crate::cli::Args;
+
+/// The output directory of a `--copy-to` run and the files written to it.
+#[derive(Debug)]
+pub struct CopyTo {
+    dir: PathBuf,
+    cwd: PathBuf,
+    copy_all: bool,
+    corrected: Mutex<Vec<PathBuf>>,
+}
+
+/// What a `--copy-to` run wrote, for the end-of-run summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopySummary {
+    pub dir: PathBuf,
+    /// Files written with corrections applied.
+    pub corrected: usize,
+    /// Files copied unchanged under `--copy-all`.
+    pub unchanged: usize,
+}
+
+impl CopyTo {
+    pub fn from_args(args: &Args) -> Option<Self> {
+        let dir = args.copy_to.clone()?;
+        let cwd = std::env::current_dir().unwrap_or_default();
+        Some(Self {
+            dir,
+            cwd: normalize(&cwd),
+            copy_all: args.copy_all,
+            corrected: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Where the copy of `path` goes.
+    pub fn destination(&self, path: &Path) -> PathBuf {
+        let absolute = normalize(&self.cwd.join(path));
+        let relative = absolute.strip_prefix(&self.cwd).unwrap_or(&absolute);
+        let mut dest = self.dir.clone();
+        dest.extend(
+            relative
+                .components()
+                .filter(|c| matches!(c, Component::Normal(_))),
+        );
+        dest
+    }
+
+    /// Write the corrected source of `path` to its destination, creating
+    /// parent directories.
+    pub fn write_corrected(&self, path: &Path, bytes: &[u8]) -> Result<()> {
+        self.write(path, bytes)?;
+        self.corrected.lock().unwrap().push(path.to_path_buf());
+        Ok(())
+    }
+
+    /// Under `--copy-all`, copy every file of `files` that was not corrected.
+    /// Returns the summary of the run.
+    pub fn finish(self, files: &[&PathBuf]) -> CopySummary {
+        let written: HashSet<PathBuf> = self.corrected.lock().unwrap().drain(..).collect();
+        let mut unchanged = 0;
+        if self.copy_all {
+            for &path in files.iter().filter(|&&p| !written.contains(p)) {
+                let copied = std::fs::read(path)
+                    .with_context(|| format!("failed to read {}", path.display()))
+                    .and_then(|bytes| self.write(path, &bytes));
+                match copied {
+                    Ok(()) => unchanged += 1,
+                    Err(e) => eprintln!("error: {e:#}"),
+                }
+            }
+        }
+        CopySummary {
+            dir: self.dir,
+            corrected: written.len(),
+            unchanged,
+        }
+    }
+
+    fn write(&self, path: &Path, bytes: &[u8]) -> Result<()> {
+        let dest = self.destination(path);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        std::fs::write(&dest, bytes).with_context(|| format!("failed to write {}", dest.display()))
+    }
+}
+
+impl CopySummary {
+    /// The line printed to stderr at the end of the run.
+    pub fn message(&self) -> String {
+        let total = self.corrected + self.unchanged;
+        let word = if total == 1 { "file" } else { "files" };
+        let mut message = format!("Wrote {total} {word} to {}", self.dir.display());
+        if self.unchanged > 0 {
+            message.push_str(&format!(
+                " ({} corrected, {} unchanged)",
+                self.corrected, self.unchanged
+            ));
+        }
+        message.push('.');
+        message
+    }
+}
+
+/// `path` with `.` and `..` resolved lexically.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn copy_to(dir: &str, cwd: &str) -> CopyTo {
+        CopyTo {
+            dir: PathBuf::from(dir),
+            cwd: PathBuf::from(cwd),
+            copy_all: false,
+            corrected: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[test]
+    fn destination_mirrors_paths_below_the_working_directory() {
+        let target = copy_to("out", "/repo");
+        assert_eq!(
+            target.destination(Path::new("lib/a.rb")),
+            Path::new("out/lib/a.rb")
+        );
+        assert_eq!(
+            target.destination(Path::new("./lib/../b.rb")),
+            Path::new("out/b.rb")
+        );
+        assert_eq!(
+            target.destination(Path::new("/repo/app/models/user.rb")),
+            Path::new("out/app/models/user.rb")
+        );
+        assert_eq!(
+            target.destination(Path::new("/elsewhere/c.rb")),
+            Path::new("out/elsewhere/c.rb")
+        );
+        assert_eq!(
+            target.destination(Path::new("../d.rb")),
+            Path::new("out/d.rb")
+        );
+    }
+
+    #[test]
+    fn summary_message() {
+        let summary = |corrected, unchanged| CopySummary {
+            dir: PathBuf::from("out"),
+            corrected,
+            unchanged,
+        };
+        assert_eq!(summary(1, 0).message(), "Wrote 1 file to out.");
+        assert_eq!(
+            summary(2, 3).message(),
+            "Wrote 5 files to out (2 corrected, 3 unchanged)."
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::parse::source::SourceFile;
+
 /// A single source-level edit: replace byte range [start..end) with replacement.
 #[derive(Debug, Clone)]
 pub struct Correction {
@@ -13,6 +15,66 @@ pub struct Correction {
     pub cop_index: usize,
 }
 
+impl Correction {
+    /// First and last line (1-indexed, inclusive) of `source` that this
+    /// correction rewrites. An insertion touches the line it is inserted on.
+    pub fn line_span(&self, source: &SourceFile) -> (usize, usize) {
+        let (first, _) = source.offset_to_line_col(self.start);
+        let (last, _) = source.offset_to_line_col(self.end.max(self.start + 1) - 1);
+        (first, last.max(first))
+    }
+
+    /// Delete lines `first..=last` (1-indexed) of `source` whole, final
+    /// newline included. A blank line the deletion would leave after another
+    /// blank line, at the top of the file or at its end goes too, so removing
+    /// lines never creates a `Layout/EmptyLines`, `Layout/LeadingEmptyLines`
+    /// or `Layout/TrailingEmptyLines` offense.
+    pub fn delete_lines(
+        source: &SourceFile,
+        first: usize,
+        last: usize,
+        cop_name: &'static str,
+    ) -> Self {
+        let bytes = source.as_bytes();
+        let line_end = |start: usize| {
+            bytes[start..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(bytes.len(), |i| start + i + 1)
+        };
+        let prev_line_start = |start: usize| {
+            bytes[..start - 1]
+                .iter()
+                .rposition(|&b| b == b'\n')
+                .map_or(0, |i| i + 1)
+        };
+        let is_blank =
+            |start: usize, end: usize| bytes[start..end].iter().all(u8::is_ascii_whitespace);
+
+        let mut start = source.line_start_offset(first);
+        let mut end = line_end(source.line_start_offset(last));
+        let blank_before = start > 0 && is_blank(prev_line_start(start), start);
+        if end < bytes.len() {
+            let next_end = line_end(end);
+            if (start == 0 || blank_before) && is_blank(end, next_end) {
+                end = next_end;
+            }
+        } else if blank_before {
+            while start > 0 && is_blank(prev_line_start(start), start) {
+                start = prev_line_start(start);
+            }
+        }
+
+        Correction {
+            start,
+            end,
+            replacement: String::new(),
+            cop_name,
+            cop_index: 0,
+        }
+    }
+}
+
 /// A set of non-overlapping corrections, sorted by start offset.
 ///
 /// Built from an unsorted vec of corrections. Overlapping corrections are
@@ -82,6 +144,26 @@ impl CorrectionSet {
         result
     }
 
+    /// Byte ranges the replacements occupy in the output of `apply`, in
+    /// order. A deletion yields an empty range at the point of deletion.
+    pub fn output_ranges(&self) -> Vec<(usize, usize)> {
+        let mut ranges = Vec::with_capacity(self.corrections.len());
+        let mut removed = 0;
+        let mut added = 0;
+        for c in &self.corrections {
+            let start = c.start + added - removed;
+            ranges.push((start, start + c.replacement.len()));
+            removed += c.end - c.start;
+            added += c.replacement.len();
+        }
+        ranges
+    }
+
+    /// The accepted corrections, sorted by start offset.
+    pub fn iter(&self) -> impl Iterator<Item = &Correction> {
+        self.corrections.iter()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.corrections.is_empty()
     }
@@ -105,6 +187,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn line_span_covers_touched_lines() {
+        let source = SourceFile::from_vec("t.rb".into(), b"a = 1\nb = [\n  2\n]\n".to_vec());
+        // Replacement within one line.
+        assert_eq!(correction(4, 5, "2", 0).line_span(&source), (1, 1));
+        // Deleting a whole line including its newline stays on that line.
+        assert_eq!(correction(0, 6, "", 0).line_span(&source), (1, 1));
+        // Spanning lines 2-4.
+        assert_eq!(correction(10, 17, "[2]", 0).line_span(&source), (2, 4));
+        // Insertion.
+        assert_eq!(correction(6, 6, "# x\n", 0).line_span(&source), (2, 2));
+    }
+
+    #[test]
+    fn delete_lines_keeps_blank_lines_single() {
+        let delete = |src: &[u8], first, last| {
+            let source = SourceFile::from_vec("t.rb".into(), src.to_vec());
+            let correction = Correction::delete_lines(&source, first, last, "Test/Cop");
+            CorrectionSet::from_vec(vec![correction]).apply(src)
+        };
+        // A line between code lines.
+        assert_eq!(delete(b"a\nb\nc\n", 2, 2), b"a\nc\n");
+        // The last line, with and without a final newline.
+        assert_eq!(delete(b"a\nb\n", 2, 2), b"a\n");
+        assert_eq!(delete(b"a\nb", 2, 2), b"a\n");
+        // Several lines at once.
+        assert_eq!(delete(b"a\nb\nc\nd\n", 2, 3), b"a\nd\n");
+        // Between blank lines: one blank line remains.
+        assert_eq!(delete(b"a\n\nb\n\nc\n", 3, 3), b"a\n\nc\n");
+        // At the top of the file, followed by a blank line.
+        assert_eq!(delete(b"a\n\nb\n", 1, 1), b"b\n");
+        // At the end of the file, after blank lines.
+        assert_eq!(delete(b"a\n\n\nb\n", 4, 4), b"a\n");
+        // Next to a single blank line: nothing extra.
+        assert_eq!(delete(b"a\n\nb\nc\n", 3, 3), b"a\n\nc\n");
+    }
+
     #[test]
     fn empty_corrections_returns_source_unchanged() {
         let source = b"hello world";
@@ -228,4 +347,18 @@ mod tests {
         let cs = CorrectionSet::from_vec(vec![correction(0, 0, "hello", 0)]);
         assert_eq!(cs.apply(source), b"hello");
     }
+
+    #[test]
+    fn output_ranges_track_shifted_offsets() {
+        let source = b"aaa bbb ccc";
+        let cs = CorrectionSet::from_vec(vec![
+            correction(0, 3, "x", 0),      // "aaa" -> "x"
+            correction(4, 7, "", 0),       // delete "bbb"
+            correction(8, 11, "zzzzz", 0), // "ccc" -> "zzzzz"
+        ]);
+        let output = cs.apply(source);
+        assert_eq!(output, b"x  zzzzz");
+        assert_eq!(cs.output_ranges(), vec![(0, 1), (2, 2), (3, 8)]);
+        assert_eq!(&output[3..8], b"zzzzz");
+    }
 }
@@ -0,0 +1,90 @@
+//! Structured debug events for `--debug-json`.
+//!
+//! Each event is a single-line JSON object written to stderr, with the event
+//! name in `event`, milliseconds since logging was enabled in `t_ms`, and the
+//! event's own fields. Durations are milliseconds as floats. Events come from
+//! worker threads too, so their order across files is not deterministic.
+//!
+//! Event names and fields are a stable interface for support tooling: new
+//! fields may be added, but existing ones are not renamed or removed.
+//!
+//! | Event                 | Fields                                                        |
+//! |-----------------------|---------------------------------------------------------------|
+//! | `config_layer_loaded` | `path`, `duration_ms` (includes the layers it inherits)       |
+//! | `gem_resolved`        | `gem`, `path`, `duration_ms`, `cached`                        |
+//! | `lockfile_used`       | `path`, `gems` (number of cached gem paths)                   |
+//! | `files_discovered`    | `count` (files to lint), `excluded`, `duration_ms`            |
+//! | `cache_lookup`        | `path`, `result` (`stat_hit`, `content_hit`, or `miss`)       |
+//! | `file_linted`         | `path`, `duration_ms`, `offense_count`                        |
+//! | `phase_totals`        | `files`, `wall_ms`, `file_io_ms`, `parse_ms`, `codemap_ms`, `cop_exec_ms`, `cop_filter_ms`, `cop_ast_ms`, `disable_ms` |
+//!
+//! `phase_totals` carries the same per-phase counters that `--debug` prints
+//! as its "linter phase breakdown"; per-phase times are summed across threads.
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use serde_json::{Map, Value};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// Turn on event output for the rest of the process.
+pub fn enable() {
+    START.get_or_init(Instant::now);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Write one event to stderr if `--debug-json` is on. `fields` must be a JSON object.
+pub fn emit(event: &str, fields: Value) {
+    if !enabled() {
+        return;
+    }
+    let t_ms = START.get().map_or(0.0, |start| millis(start.elapsed()));
+    eprintln!("{}", format_event(event, t_ms, fields));
+}
+
+/// A duration in (fractional) milliseconds, as used by every `*_ms` field.
+pub fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+fn format_event(event: &str, t_ms: f64, fields: Value) -> String {
+    let mut obj = Map::new();
+    obj.insert("event".to_string(), Value::from(event));
+    obj.insert("t_ms".to_string(), Value::from(t_ms));
+    if let Value::Object(fields) = fields {
+        obj.extend(fields);
+    }
+    Value::Object(obj).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_is_one_json_line() {
+        let line = format_event(
+            "file_linted",
+            1.5,
+            serde_json::json!({ "path": "a.rb", "duration_ms": 0.25, "offense_count": 2 }),
+        );
+        assert!(!line.contains('\n'));
+        let parsed: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed["event"], "file_linted");
+        assert_eq!(parsed["t_ms"], 1.5);
+        assert_eq!(parsed["path"], "a.rb");
+        assert_eq!(parsed["offense_count"], 2);
+    }
+
+    #[test]
+    fn millis_keeps_fractions() {
+        assert_eq!(millis(Duration::from_micros(1500)), 1.5);
+    }
+}
@@ -57,6 +57,11 @@ pub struct Diagnostic {
     /// Whether this offense was corrected by autocorrect.
     #[serde(default)]
     pub corrected: bool,
+    /// 0-indexed column just past the offense, for cops that report the
+    /// offense's extent when it ends on its starting line. Fixture tests
+    /// check it against the width of the `^` annotation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
 }
 
 impl Diagnostic {
@@ -132,6 +137,7 @@ mod tests {
             message: "bad style".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         assert_eq!(format!("{d}"), "foo.rb:3:5: C: Style/Foo: bad style");
     }
@@ -146,6 +152,7 @@ mod tests {
             message: "m".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let d2 = Diagnostic {
             path: "a.rb".to_string(),
@@ -155,6 +162,7 @@ mod tests {
             message: "m".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let d3 = Diagnostic {
             path: "b.rb".to_string(),
@@ -164,6 +172,7 @@ mod tests {
             message: "m".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         assert!(d1.sort_key() < d2.sort_key());
         assert!(d2.sort_key() < d3.sort_key());
@@ -199,6 +208,7 @@ mod tests {
                         cop_name,
                         message,
                         corrected: false,
+                        end_column: None,
                     }
                 })
         }
@@ -1,6 +1,7 @@
 //! `--doctor` command: debug/support output.
 //!
-//! Displays baseline versions, config root + inheritance chain,
+//! Displays the run info block (the same fields as the JSON report's
+//! `metadata`), baseline versions, config root + inheritance chain,
 //! gem version mismatch warnings, and the skip summary.
 
 use std::collections::BTreeMap;
@@ -9,6 +10,7 @@ use std::path::Path;
 use crate::config::ResolvedConfig;
 use crate::cop::registry::CopRegistry;
 use crate::cop::tiers::TierMap;
+use crate::formatter::RunInfo;
 
 /// Load embedded baseline versions from resources/baseline.json.
 fn load_baseline() -> BTreeMap<String, String> {
@@ -97,10 +99,15 @@ pub fn run_doctor(
     registry: &CopRegistry,
     tier_map: &TierMap,
     target_dir: Option<&Path>,
+    run_info: &RunInfo,
 ) {
     let baseline = load_baseline();
 
+    // 0. Version and config, as in the JSON report metadata
+    print!("{}", run_info.render_text());
+
     // 1. Baseline versions
+    println!();
     println!("Baseline versions (vendored):");
     for (gem, version) in &baseline {
         println!("  {gem} {version}");
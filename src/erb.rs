@@ -0,0 +1,313 @@
+//! Linting Ruby embedded in ERB templates (`AllCops: Erb: true` / `--erb`).
+//!
+//! The Ruby in `<% %>` and `<%= %>` tags is stitched into a virtual source
+//! that keeps every Ruby byte at its template offset: template text is blanked
+//! to spaces (newlines are kept), each closing `%>` becomes a `;` so fragments
+//! on one line stay separate statements, and `<%=` becomes `_=` so output
+//! expressions aren't reported as void. A one-line `<%# %>` comment that
+//! ends its line becomes a Ruby comment, which keeps inline
+//! `# rubocop:disable` directives working; other comments are blanked.
+//!
+//! Only the cops in `is_erb_cop` run on templates: whole-file cops (magic
+//! comments, trailing blank lines, ...) and layout cops that would trip over
+//! the blanked template text don't apply to fragments. Autocorrect is not
+//! applied to templates.
+
+use std::path::Path;
+
+use crate::diagnostic::Location;
+use crate::parse::source::SourceFile;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FragmentKind {
+    /// `<% code %>` (also `<%- code -%>`).
+    Code,
+    /// `<%= expr %>` / `<%== expr %>`.
+    Output,
+    /// `<%# comment %>`; the range starts at the `#`.
+    Comment,
+}
+
+/// A Ruby fragment of a template. `start..end` is the byte range of the code
+/// between the tag delimiters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fragment {
+    pub kind: FragmentKind,
+    /// Offset of the opening `<%`.
+    pub tag_start: usize,
+    pub start: usize,
+    pub end: usize,
+    /// Offset of the closing `%>`, or `None` for an unterminated tag.
+    pub close: Option<usize>,
+}
+
+/// Cops outside Lint that make sense on individual fragments.
+const FRAGMENT_COPS: &[&str] = &[
+    "Layout/SpaceAfterColon",
+    "Layout/SpaceAfterComma",
+    "Layout/SpaceBeforeComma",
+    "Layout/SpaceInsideArrayLiteralBrackets",
+    "Layout/SpaceInsideParens",
+    "Layout/SpaceInsideStringInterpolation",
+    "Style/AndOr",
+    "Style/HashSyntax",
+    "Style/NegatedIf",
+    "Style/NilComparison",
+    "Style/Not",
+    "Style/StringLiterals",
+];
+
+/// Lint cops that don't apply to templates: they inspect the file as a whole,
+/// or flag Ruby bodies that are empty only because the template text between
+/// tags (`<% if x %>...<% end %>`) was blanked.
+const EXCLUDED_LINT_COPS: &[&str] = &[
+    "Lint/DuplicateMagicComment",
+    "Lint/EmptyBlock",
+    "Lint/EmptyConditionalBody",
+    "Lint/EmptyEnsure",
+    "Lint/EmptyFile",
+    "Lint/EmptyInPattern",
+    "Lint/EmptyWhen",
+    "Lint/OrderedMagicComments",
+    "Lint/ScriptPermission",
+];
+
+/// Whether a path is an ERB template (`foo.html.erb`, `foo.erb`, ...).
+pub fn is_erb_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("erb"))
+}
+
+/// Whether a cop's offenses are reported for ERB templates.
+pub fn is_erb_cop(name: &str) -> bool {
+    if name.starts_with("Lint/") {
+        return !EXCLUDED_LINT_COPS.contains(&name);
+    }
+    FRAGMENT_COPS.contains(&name)
+}
+
+fn find(haystack: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|pos| from + pos)
+}
+
+/// Extract the Ruby fragments of a template, in source order.
+///
+/// Like ERB itself, a tag ends at the first `%>`, even inside a string
+/// literal. `<%%` is a literal `<%` in the template text.
+pub fn extract_fragments(template: &[u8]) -> Vec<Fragment> {
+    let mut fragments = Vec::new();
+    let mut pos = 0;
+    while let Some(tag_start) = find(template, pos, b"<%") {
+        let mut start = tag_start + 2;
+        let kind = match template.get(start) {
+            Some(b'%') => {
+                pos = start + 1;
+                continue;
+            }
+            Some(b'#') => FragmentKind::Comment,
+            Some(b'=') => {
+                start += 1;
+                if template.get(start) == Some(&b'=') {
+                    start += 1;
+                }
+                FragmentKind::Output
+            }
+            Some(b'-') => {
+                start += 1;
+                FragmentKind::Code
+            }
+            _ => FragmentKind::Code,
+        };
+        let close = find(template, start, b"%>");
+        let mut end = close.unwrap_or(template.len());
+        if close.is_some() && end > start && template[end - 1] == b'-' {
+            end -= 1;
+        }
+        fragments.push(Fragment {
+            kind,
+            tag_start,
+            start,
+            end,
+            close,
+        });
+        pos = close.map_or(template.len(), |c| c + 2);
+    }
+    fragments
+}
+
+/// Build the virtual Ruby source for a template. It has the same length and
+/// line structure as the template, with every fragment at its template offset.
+pub fn virtual_source(template: &[u8]) -> Vec<u8> {
+    let mut out: Vec<u8> = template
+        .iter()
+        .map(|&b| if matches!(b, b'\n' | b'\r') { b } else { b' ' })
+        .collect();
+    let fragments = extract_fragments(template);
+    for (i, fragment) in fragments.iter().enumerate() {
+        if fragment.kind == FragmentKind::Comment
+            && !comment_fits_line(template, fragment, fragments.get(i + 1))
+        {
+            continue;
+        }
+        out[fragment.start..fragment.end].copy_from_slice(&template[fragment.start..fragment.end]);
+        if fragment.kind == FragmentKind::Output {
+            out[fragment.tag_start + 1..fragment.tag_start + 3].copy_from_slice(b"_=");
+        }
+        if let Some(close) = fragment.close {
+            if fragment.kind != FragmentKind::Comment {
+                out[close] = b';';
+            }
+        }
+    }
+    out
+}
+
+/// Whether a `<%# %>` comment can be copied as a Ruby comment. A Ruby comment
+/// runs to the end of the line, so comments spanning lines or followed by
+/// another tag on the same line are blanked instead.
+fn comment_fits_line(template: &[u8], comment: &Fragment, next: Option<&Fragment>) -> bool {
+    let is_newline = |b: &u8| matches!(b, b'\n' | b'\r');
+    if template[comment.start..comment.end].iter().any(is_newline) {
+        return false;
+    }
+    match (comment.close, next) {
+        (Some(close), Some(next)) => template[close + 2..next.tag_start].iter().any(is_newline),
+        _ => true,
+    }
+}
+
+/// Map a diagnostic location in the virtual source back to the template.
+///
+/// Offsets are shared, but columns count characters and template text is
+/// blanked byte by byte, so columns after multibyte template text differ.
+pub fn template_location(
+    virtual_source: &SourceFile,
+    template: &SourceFile,
+    location: Location,
+) -> Location {
+    match virtual_source.line_col_to_offset(location.line, location.column) {
+        Some(offset) => {
+            let (line, column) = template.offset_to_line_col(offset);
+            Location { line, column }
+        }
+        None => location,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn virtual_str(template: &str) -> String {
+        String::from_utf8(virtual_source(template.as_bytes())).unwrap()
+    }
+
+    #[test]
+    fn extracts_code_output_and_comment_tags() {
+        let template = b"<% if x %><%= y %><%# note %><%== z %>";
+        let fragments = extract_fragments(template);
+        let kinds: Vec<_> = fragments.iter().map(|f| f.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                FragmentKind::Code,
+                FragmentKind::Output,
+                FragmentKind::Comment,
+                FragmentKind::Output
+            ]
+        );
+        let code: Vec<_> = fragments
+            .iter()
+            .map(|f| std::str::from_utf8(&template[f.start..f.end]).unwrap())
+            .collect();
+        assert_eq!(code, vec![" if x ", " y ", "# note ", " z "]);
+    }
+
+    #[test]
+    fn trim_markers_and_literal_percent() {
+        let template = b"<%- foo -%> <%% bar %>";
+        let fragments = extract_fragments(template);
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(&template[fragments[0].start..fragments[0].end], b" foo ");
+    }
+
+    #[test]
+    fn unterminated_tag_runs_to_end() {
+        let fragments = extract_fragments(b"<p><% foo");
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(fragments[0].close, None);
+        assert_eq!(fragments[0].end, 9);
+    }
+
+    #[test]
+    fn virtual_source_keeps_offsets() {
+        assert_eq!(
+            virtual_str("<ul>\n<% items.each do |i| %>\n  <li><%= i %></li>\n<% end %>\n"),
+            "    \n   items.each do |i| ; \n       _= i ;      \n   end ; \n"
+        );
+        assert_eq!(
+            virtual_str("<%# rubocop:disable Lint/Foo %>"),
+            "  # rubocop:disable Lint/Foo   "
+        );
+    }
+
+    #[test]
+    fn comment_followed_by_tag_is_blanked() {
+        let template = "<%# note %><%= x %>\n<%# rubocop:disable Lint/Foo %>\n";
+        let fragments = extract_fragments(template.as_bytes());
+        assert_eq!(fragments.len(), 3);
+        assert_eq!(fragments[0].kind, FragmentKind::Comment);
+        assert_eq!(
+            virtual_str(template),
+            "            _= x ; \n  # rubocop:disable Lint/Foo   \n"
+        );
+    }
+
+    #[test]
+    fn multiline_comment_is_blanked() {
+        let template = "<%# line1\nfoo(bar %>\n<% baz %>";
+        let fragments = extract_fragments(template.as_bytes());
+        assert_eq!(fragments.len(), 2);
+        assert_eq!(fragments[0].kind, FragmentKind::Comment);
+        assert_eq!(fragments[0].end, template.find(" %>").unwrap() + 1);
+        assert_eq!(virtual_str(template), "         \n          \n   baz ; ");
+    }
+
+    #[test]
+    fn maps_columns_after_multibyte_text() {
+        let template = "<p>héllo <%= x %></p>\n";
+        let template_source = SourceFile::from_string("a.erb".into(), template.to_string());
+        let virtual_bytes = virtual_source(template.as_bytes());
+        let virtual_src = SourceFile::from_vec("a.erb".into(), virtual_bytes);
+        let x_offset = template.find("x ").unwrap();
+        let (line, column) = virtual_src.offset_to_line_col(x_offset);
+        let mapped = template_location(&virtual_src, &template_source, Location { line, column });
+        assert_eq!(mapped.line, 1);
+        assert_eq!(
+            mapped.column,
+            template_source.offset_to_line_col(x_offset).1
+        );
+        assert_eq!(mapped.column, 13);
+    }
+
+    #[test]
+    fn erb_cop_selection() {
+        assert!(is_erb_cop("Lint/UselessAssignment"));
+        assert!(is_erb_cop("Style/StringLiterals"));
+        assert!(!is_erb_cop("Lint/EmptyBlock"));
+        assert!(!is_erb_cop("Style/FrozenStringLiteralComment"));
+        assert!(!is_erb_cop("Layout/TrailingEmptyLines"));
+    }
+
+    #[test]
+    fn erb_file_detection() {
+        assert!(is_erb_file(Path::new("app/views/show.html.erb")));
+        assert!(is_erb_file(Path::new("mail.text.ERB")));
+        assert!(!is_erb_file(Path::new("app/models/user.rb")));
+    }
+}
@@ -0,0 +1,178 @@
+//! `--list-target-files --estimate`: which target files the result cache
+//! would answer, and a rough lint time for the rest.
+//!
+//! The cache is opened with the same session key a real run would use and
+//! probed without updating it, so the listing also explains why a file keeps
+//! missing the cache. The time estimate uses each changed file's last
+//! recorded lint time, falls back to the mean over the session for files
+//! with none, and divides by the worker count. No cops run.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cache::{CacheProbe, ResultCache};
+use crate::cli::Args;
+use crate::config::ResolvedConfig;
+use crate::cop::registry::CopRegistry;
+use crate::linter::{cache_disabled_reason, open_result_cache};
+
+/// Why a file would be linted rather than read from the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MissReason {
+    /// Cached under different content.
+    Changed,
+    /// Never linted in this session (new file, or a config or CLI change
+    /// started a new session).
+    New,
+    /// Has a cop selection pragma, which is never cached.
+    Pragma,
+    /// The result cache is off for this run.
+    Disabled,
+}
+
+impl MissReason {
+    fn label(self) -> &'static str {
+        match self {
+            MissReason::Changed => "changed",
+            MissReason::New => "not cached",
+            MissReason::Pragma => "cop selection pragma",
+            MissReason::Disabled => "cache disabled",
+        }
+    }
+}
+
+/// Hit or miss for one file, plus the time it would take if it is linted.
+struct FileEstimate {
+    miss: Option<MissReason>,
+    last_lint: Option<Duration>,
+}
+
+fn estimate_file(cache: &ResultCache, path: &Path) -> FileEstimate {
+    if !cache.is_enabled() {
+        return FileEstimate {
+            miss: Some(MissReason::Disabled),
+            last_lint: None,
+        };
+    }
+    let (miss, last_lint) = match cache.probe(path) {
+        CacheProbe::StatHit | CacheProbe::ContentHit => (None, None),
+        CacheProbe::Changed(last_lint) => (Some(MissReason::Changed), last_lint),
+        CacheProbe::NoEntry => {
+            let has_pragma = std::fs::read(path).is_ok_and(|content| {
+                crate::parse::pragma::CopSelection::from_source(&content).is_some()
+            });
+            let reason = if has_pragma {
+                MissReason::Pragma
+            } else {
+                MissReason::New
+            };
+            (Some(reason), None)
+        }
+    };
+    FileEstimate { miss, last_lint }
+}
+
+/// The listing and summary for `files`, run on `workers` threads.
+fn render(files: &[PathBuf], cache: &ResultCache, workers: usize) -> String {
+    let mean = cache.mean_lint_time();
+    let mut out = String::new();
+    let mut cached = 0;
+    let mut to_lint = 0;
+    let mut total = Duration::ZERO;
+    let mut untimed = 0;
+    for file in files {
+        let estimate = estimate_file(cache, file);
+        match estimate.miss {
+            None => {
+                cached += 1;
+                out.push_str(&format!("hit   {}\n", file.display()));
+            }
+            Some(reason) => {
+                to_lint += 1;
+                match estimate.last_lint.or(mean) {
+                    Some(time) => total += time,
+                    None => untimed += 1,
+                }
+                out.push_str(&format!("miss  {} ({})\n", file.display(), reason.label()));
+            }
+        }
+    }
+
+    let time = if to_lint == 0 {
+        "est. 0s".to_string()
+    } else if untimed == to_lint {
+        "no timings cached yet".to_string()
+    } else {
+        format!("est. {}", format_duration(total / workers.max(1) as u32))
+    };
+    out.push_str(&format!(
+        "{} cached, {} to lint, {time}\n",
+        group_thousands(cached),
+        group_thousands(to_lint)
+    ));
+    out
+}
+
+/// Print the `--estimate` listing of `files` to stdout.
+pub fn print_estimate(
+    files: &[PathBuf],
+    config: &ResolvedConfig,
+    registry: &CopRegistry,
+    args: &Args,
+) {
+    let base_configs = config.precompute_cop_configs(registry);
+    let cache = open_result_cache(config, args, &base_configs);
+    if let Some(reason) = cache_disabled_reason(config, args) {
+        eprintln!("note: result cache disabled ({reason}); every file would be linted");
+    }
+    print!("{}", render(files, &cache, rayon::current_num_threads()));
+}
+
+/// `1204` as `1,204`.
+fn group_thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Whole seconds, rounded up, as `12s` or `3m 20s`.
+fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs() + u64::from(d.subsec_nanos() > 0);
+    if secs < 60 {
+        format!("{secs}s")
+    } else {
+        format!("{}m {}s", secs / 60, secs % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_counts_and_durations() {
+        assert_eq!(group_thousands(7), "7");
+        assert_eq!(group_thousands(1204), "1,204");
+        assert_eq!(group_thousands(1234567), "1,234,567");
+        assert_eq!(format_duration(Duration::from_millis(11_200)), "12s");
+        assert_eq!(format_duration(Duration::from_secs(200)), "3m 20s");
+        assert_eq!(format_duration(Duration::ZERO), "0s");
+    }
+
+    #[test]
+    fn disabled_cache_lints_everything() {
+        let files = [PathBuf::from("a.rb"), PathBuf::from("b.rb")];
+        assert_eq!(
+            render(&files, &ResultCache::disabled(), 4),
+            "miss  a.rb (cache disabled)\n\
+             miss  b.rb (cache disabled)\n\
+             0 cached, 2 to lint, no timings cached yet\n"
+        );
+    }
+}
@@ -5,14 +5,28 @@ use std::path::PathBuf;
 use crate::diagnostic::Diagnostic;
 use crate::formatter::Formatter;
 
-pub struct FilesFormatter;
+/// Lists each file with offenses once, sorted. `files0` ends each path with
+/// a NUL byte instead of a newline so paths with spaces survive `xargs -0`.
+#[derive(Default)]
+pub struct FilesFormatter {
+    null_separated: bool,
+}
+
+impl FilesFormatter {
+    pub fn null_separated() -> Self {
+        Self {
+            null_separated: true,
+        }
+    }
+}
 
 impl Formatter for FilesFormatter {
     fn format_to(&self, diagnostics: &[Diagnostic], _files: &[PathBuf], out: &mut dyn Write) {
         // Deduplicate and sort file paths
         let paths: BTreeSet<&str> = diagnostics.iter().map(|d| d.path.as_str()).collect();
+        let terminator = if self.null_separated { "\0" } else { "\n" };
         for path in paths {
-            let _ = writeln!(out, "{path}");
+            let _ = write!(out, "{path}{terminator}");
         }
     }
 }
@@ -31,12 +45,13 @@ mod tests {
             message: "test".to_string(),
 
             corrected: false,
+            end_column: None,
         }
     }
 
     fn render(diagnostics: &[Diagnostic]) -> String {
         let mut buf = Vec::new();
-        FilesFormatter.format_to(diagnostics, &[], &mut buf);
+        FilesFormatter::default().format_to(diagnostics, &[], &mut buf);
         String::from_utf8(buf).unwrap()
     }
 
@@ -69,4 +84,19 @@ mod tests {
         assert!(!out.contains("inspected"));
         assert!(!out.contains("offense"));
     }
+
+    #[test]
+    fn null_separated_keeps_spaces_and_dedups() {
+        let mut buf = Vec::new();
+        FilesFormatter::null_separated().format_to(
+            &[
+                make_diag("my dir/b c.rb"),
+                make_diag("a.rb"),
+                make_diag("my dir/b c.rb"),
+            ],
+            &[],
+            &mut buf,
+        );
+        assert_eq!(buf, b"a.rb\0my dir/b c.rb\0");
+    }
 }
@@ -48,6 +48,7 @@ mod tests {
             message: "bad style".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d]);
         assert_eq!(
@@ -66,6 +67,7 @@ mod tests {
             message: "warn".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d]);
         assert!(out.starts_with("::warning "));
@@ -84,6 +86,7 @@ mod tests {
             message: "err".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d]);
         assert_eq!(out, "::error file=baz.rb,line=10,col=2::Lint/Y: err\n");
@@ -99,6 +102,7 @@ mod tests {
             message: "fatal".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d]);
         assert!(out.starts_with("::error "));
@@ -114,6 +118,7 @@ mod tests {
             message: "m1".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let d2 = Diagnostic {
             path: "b.rb".to_string(),
@@ -123,6 +128,7 @@ mod tests {
             message: "m2".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d1, d2]);
         let lines: Vec<&str> = out.lines().collect();
@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::baseline::BaselineSummary;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::formatter::{Formatter, baseline_suffix, contextual_suffix};
+
+/// Offenses grouped under a `== path ==` header per file, like RuboCop's
+/// `simple` formatter, with a per-severity breakdown in the summary.
+#[derive(Default)]
+pub struct GroupedFormatter {
+    baseline_suppressed: usize,
+    inspected_count: Option<usize>,
+    skipped_contextual: usize,
+    color: bool,
+    sort_severity: bool,
+}
+
+impl Formatter for GroupedFormatter {
+    fn set_baseline_summary(&mut self, summary: BaselineSummary) {
+        self.baseline_suppressed = summary.suppressed;
+    }
+
+    fn set_inspected_count(&mut self, count: usize) {
+        self.inspected_count = Some(count);
+    }
+
+    fn set_skipped_contextual(&mut self, count: usize) {
+        self.skipped_contextual = count;
+    }
+
+    fn set_color(&mut self, color: bool) {
+        self.color = color;
+    }
+
+    fn set_sort_severity(&mut self, sort_severity: bool) {
+        self.sort_severity = sort_severity;
+    }
+
+    fn format_to(&self, diagnostics: &[Diagnostic], files: &[PathBuf], out: &mut dyn Write) {
+        let mut by_file: BTreeMap<&str, Vec<&Diagnostic>> = BTreeMap::new();
+        for d in diagnostics {
+            by_file.entry(d.path.as_str()).or_default().push(d);
+        }
+        for (path, mut diags) in by_file {
+            if self.sort_severity {
+                diags.sort_by(|a, b| {
+                    b.severity
+                        .cmp(&a.severity)
+                        .then(a.location.line.cmp(&b.location.line))
+                        .then(a.location.column.cmp(&b.location.column))
+                });
+            }
+            let header = format!("== {path} ==");
+            let _ = writeln!(out, "{}", self.paint(&header, "33"));
+            for d in diags {
+                let prefix = if d.corrected { "[Corrected] " } else { "" };
+                let _ = writeln!(
+                    out,
+                    "{prefix}{}:{}: {}: {}: {}",
+                    d.location.line,
+                    d.location.column,
+                    self.paint(&d.severity.to_string(), severity_color(d.severity)),
+                    d.cop_name,
+                    d.message,
+                );
+            }
+        }
+
+        let file_count = self.inspected_count.unwrap_or(files.len());
+        let offense_word = if diagnostics.len() == 1 {
+            "offense"
+        } else {
+            "offenses"
+        };
+        let file_word = if file_count == 1 { "file" } else { "files" };
+        let corrected_count = diagnostics.iter().filter(|d| d.corrected).count();
+        let corrected = match corrected_count {
+            0 => String::new(),
+            1 => ", 1 offense corrected".to_string(),
+            n => format!(", {n} offenses corrected"),
+        };
+        let _ = writeln!(
+            out,
+            "\n{file_count} {file_word} inspected, {} {offense_word} detected{}{corrected}{}{}",
+            diagnostics.len(),
+            severity_breakdown(diagnostics),
+            contextual_suffix(self.skipped_contextual),
+            baseline_suffix(self.baseline_suppressed),
+        );
+    }
+}
+
+impl GroupedFormatter {
+    /// Wrap `text` in the ANSI color `code` when color is on.
+    fn paint(&self, text: &str, code: &str) -> String {
+        if self.color {
+            format!("\x1b[{code}m{text}\x1b[0m")
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// RuboCop's colors for severity letters.
+fn severity_color(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Convention => "33",
+        Severity::Warning => "35",
+        Severity::Error | Severity::Fatal => "31",
+    }
+}
+
+/// ` (3 errors, 12 warnings, 240 conventions)`, most severe first, omitting
+/// severities with no offenses; empty when there are no offenses.
+fn severity_breakdown(diagnostics: &[Diagnostic]) -> String {
+    let count = |severity: Severity| {
+        diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    };
+    let parts: Vec<String> = [
+        (Severity::Fatal, "fatal", "fatal"),
+        (Severity::Error, "error", "errors"),
+        (Severity::Warning, "warning", "warnings"),
+        (Severity::Convention, "convention", "conventions"),
+    ]
+    .into_iter()
+    .filter_map(|(severity, one, many)| match count(severity) {
+        0 => None,
+        1 => Some(format!("1 {one}")),
+        n => Some(format!("{n} {many}")),
+    })
+    .collect();
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Location;
+
+    fn make_diag(path: &str, line: usize, sev: Severity, cop: &str) -> Diagnostic {
+        Diagnostic {
+            path: path.to_string(),
+            location: Location { line, column: 0 },
+            severity: sev,
+            cop_name: cop.to_string(),
+            message: "msg".to_string(),
+
+            corrected: false,
+            end_column: None,
+        }
+    }
+
+    fn render(f: &GroupedFormatter, diagnostics: &[Diagnostic]) -> String {
+        let mut buf = Vec::new();
+        f.format_to(
+            diagnostics,
+            &[PathBuf::from("a.rb"), PathBuf::from("b.rb")],
+            &mut buf,
+        );
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn sample() -> Vec<Diagnostic> {
+        vec![
+            make_diag("a.rb", 1, Severity::Convention, "Style/A"),
+            make_diag("a.rb", 5, Severity::Error, "Lint/B"),
+            make_diag("a.rb", 9, Severity::Warning, "Lint/C"),
+            make_diag("b.rb", 2, Severity::Convention, "Style/A"),
+        ]
+    }
+
+    #[test]
+    fn groups_by_file_with_severity_summary() {
+        let out = render(&GroupedFormatter::default(), &sample());
+        assert_eq!(
+            out,
+            "== a.rb ==\n\
+             1:0: C: Style/A: msg\n\
+             5:0: E: Lint/B: msg\n\
+             9:0: W: Lint/C: msg\n\
+             == b.rb ==\n\
+             2:0: C: Style/A: msg\n\
+             \n\
+             2 files inspected, 4 offenses detected (1 error, 1 warning, 2 conventions)\n"
+        );
+    }
+
+    #[test]
+    fn sort_severity_orders_within_each_file() {
+        let mut f = GroupedFormatter::default();
+        f.set_sort_severity(true);
+        let out = render(&f, &sample());
+        let lines: Vec<&str> = out.lines().take(5).collect();
+        assert_eq!(
+            lines,
+            [
+                "== a.rb ==",
+                "5:0: E: Lint/B: msg",
+                "9:0: W: Lint/C: msg",
+                "1:0: C: Style/A: msg",
+                "== b.rb ==",
+            ]
+        );
+    }
+
+    #[test]
+    fn colors_severity_letters_only_when_enabled() {
+        let diags = [make_diag("a.rb", 1, Severity::Warning, "Lint/C")];
+        assert!(!render(&GroupedFormatter::default(), &diags).contains('\x1b'));
+        let mut f = GroupedFormatter::default();
+        f.set_color(true);
+        let out = render(&f, &diags);
+        assert!(
+            out.contains("1:0: \x1b[35mW\x1b[0m: Lint/C: msg"),
+            "{out:?}"
+        );
+    }
+
+    #[test]
+    fn no_offenses_has_no_breakdown() {
+        let out = render(&GroupedFormatter::default(), &[]);
+        assert_eq!(out, "\n2 files inspected, 0 offenses detected\n");
+    }
+}
@@ -3,19 +3,36 @@ use std::path::PathBuf;
 
 use serde::Serialize;
 
+use crate::baseline::BaselineSummary;
 use crate::cop::tiers::SkipSummary;
 use crate::diagnostic::Diagnostic;
-use crate::formatter::Formatter;
+use crate::formatter::{Formatter, RunInfo};
 
 pub struct JsonFormatter {
     skip_summary: Option<SkipSummary>,
+    pragma_files: Vec<PathBuf>,
+    baseline: Option<BaselineSummary>,
+    inspected_count: Option<usize>,
+    skipped_contextual: usize,
+    run_info: Option<RunInfo>,
+    total_offense_count: Option<usize>,
+    warnings: Vec<String>,
 }
 
 impl JsonFormatter {
     // Default impl not useful; formatter is always explicitly constructed.
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { skip_summary: None }
+        Self {
+            skip_summary: None,
+            pragma_files: Vec::new(),
+            baseline: None,
+            inspected_count: None,
+            skipped_contextual: 0,
+            run_info: None,
+            total_offense_count: None,
+            warnings: Vec::new(),
+        }
     }
 }
 
@@ -29,9 +46,35 @@ struct JsonOutput {
 
 #[derive(Serialize)]
 struct Metadata {
+    /// nitrocop version, config file, target versions, and autocorrect mode.
+    #[serde(flatten)]
+    run_info: Option<RunInfo>,
     files_inspected: usize,
     offense_count: usize,
+    /// Offenses listed under `offenses` (the same as `offense_count`).
+    reported_offense_count: usize,
+    /// Offenses found, before `--max-reported-offenses` truncated the list.
+    total_offense_count: usize,
     corrected_count: usize,
+    /// Files that selected their cops with a `# nitrocop:only`/`except` pragma.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pragma_files: Vec<String>,
+    /// Offenses hidden by `--baseline`; absent without a baseline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    baseline_suppressed_count: Option<usize>,
+    /// Baseline entries that no longer match any offense.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    baseline_stale_count: Option<usize>,
+    /// `AutoCorrect: contextual` offenses left uncorrected by -a/-A.
+    #[serde(skip_serializing_if = "is_zero")]
+    skipped_contextual_count: usize,
+    /// Warnings printed to stderr, e.g. for `--only` cops that will not run.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
 }
 
 #[derive(Serialize)]
@@ -58,6 +101,34 @@ impl Formatter for JsonFormatter {
         self.skip_summary = Some(summary);
     }
 
+    fn set_pragma_files(&mut self, files: Vec<PathBuf>) {
+        self.pragma_files = files;
+    }
+
+    fn set_baseline_summary(&mut self, summary: BaselineSummary) {
+        self.baseline = Some(summary);
+    }
+
+    fn set_inspected_count(&mut self, count: usize) {
+        self.inspected_count = Some(count);
+    }
+
+    fn set_skipped_contextual(&mut self, count: usize) {
+        self.skipped_contextual = count;
+    }
+
+    fn set_run_info(&mut self, info: RunInfo) {
+        self.run_info = Some(info);
+    }
+
+    fn set_total_offense_count(&mut self, count: usize) {
+        self.total_offense_count = Some(count);
+    }
+
+    fn set_warnings(&mut self, warnings: Vec<String>) {
+        self.warnings = warnings;
+    }
+
     fn format_to(&self, diagnostics: &[Diagnostic], files: &[PathBuf], out: &mut dyn Write) {
         let corrected_count = diagnostics.iter().filter(|d| d.corrected).count();
 
@@ -70,9 +141,21 @@ impl Formatter for JsonFormatter {
 
         let output = JsonOutput {
             metadata: Metadata {
-                files_inspected: files.len(),
+                run_info: self.run_info.clone(),
+                files_inspected: self.inspected_count.unwrap_or(files.len()),
                 offense_count: diagnostics.len(),
+                reported_offense_count: diagnostics.len(),
+                total_offense_count: self.total_offense_count.unwrap_or(diagnostics.len()),
                 corrected_count,
+                pragma_files: self
+                    .pragma_files
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect(),
+                baseline_suppressed_count: self.baseline.map(|b| b.suppressed),
+                baseline_stale_count: self.baseline.map(|b| b.stale),
+                skipped_contextual_count: self.skipped_contextual,
+                warnings: self.warnings.clone(),
             },
             offenses: diagnostics
                 .iter()
@@ -148,6 +231,98 @@ mod tests {
         assert_eq!(skipped["outside_baseline"].as_array().unwrap().len(), 1);
     }
 
+    #[test]
+    fn pragma_files_listed_in_metadata() {
+        let out = render(&[], &[]);
+        let parsed: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        assert!(parsed["metadata"].get("pragma_files").is_none());
+
+        let mut f = JsonFormatter::new();
+        f.set_pragma_files(vec![PathBuf::from("lib/generated/api_pb.rb")]);
+        let mut buf = Vec::new();
+        f.format_to(&[], &[PathBuf::from("lib/generated/api_pb.rb")], &mut buf);
+        let parsed: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(
+            parsed["metadata"]["pragma_files"],
+            serde_json::json!(["lib/generated/api_pb.rb"])
+        );
+    }
+
+    #[test]
+    fn baseline_counts_in_metadata() {
+        let out = render(&[], &[]);
+        let parsed: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        assert!(
+            parsed["metadata"]
+                .get("baseline_suppressed_count")
+                .is_none()
+        );
+        assert!(parsed["metadata"].get("baseline_stale_count").is_none());
+
+        let mut f = JsonFormatter::new();
+        f.set_baseline_summary(BaselineSummary {
+            suppressed: 3,
+            stale: 1,
+        });
+        let mut buf = Vec::new();
+        f.format_to(&[], &[], &mut buf);
+        let parsed: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(parsed["metadata"]["baseline_suppressed_count"], 3);
+        assert_eq!(parsed["metadata"]["baseline_stale_count"], 1);
+    }
+
+    #[test]
+    fn run_info_in_metadata() {
+        let out = render(&[], &[]);
+        let parsed: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        assert!(parsed["metadata"].get("nitrocop_version").is_none());
+
+        let mut f = JsonFormatter::new();
+        f.set_run_info(RunInfo {
+            nitrocop_version: "1.2.3",
+            config_path: None,
+            target_ruby_version: Some(3.3),
+            target_rails_version: Some(7.1),
+            autocorrect_mode: "all",
+        });
+        let mut buf = Vec::new();
+        f.format_to(&[], &[], &mut buf);
+        let parsed: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        let metadata = &parsed["metadata"];
+        assert_eq!(metadata["nitrocop_version"], "1.2.3");
+        assert!(metadata["config_path"].is_null());
+        assert_eq!(metadata["target_ruby_version"], 3.3);
+        assert_eq!(metadata["target_rails_version"], 7.1);
+        assert_eq!(metadata["autocorrect_mode"], "all");
+        assert_eq!(metadata["files_inspected"], 0);
+    }
+
+    #[test]
+    fn reported_and_total_offense_counts() {
+        let d = Diagnostic {
+            path: "foo.rb".to_string(),
+            location: Location { line: 1, column: 0 },
+            severity: Severity::Error,
+            cop_name: "Lint/Foo".to_string(),
+            message: "bad".to_string(),
+            corrected: false,
+            end_column: None,
+        };
+        let out = render(std::slice::from_ref(&d), &[]);
+        let parsed: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(parsed["metadata"]["reported_offense_count"], 1);
+        assert_eq!(parsed["metadata"]["total_offense_count"], 1);
+
+        let mut f = JsonFormatter::new();
+        f.set_total_offense_count(1_400_000);
+        let mut buf = Vec::new();
+        f.format_to(&[d], &[], &mut buf);
+        let parsed: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(parsed["metadata"]["offense_count"], 1);
+        assert_eq!(parsed["metadata"]["reported_offense_count"], 1);
+        assert_eq!(parsed["metadata"]["total_offense_count"], 1_400_000);
+    }
+
     #[test]
     fn offense_fields_present() {
         let d = Diagnostic {
@@ -157,6 +332,7 @@ mod tests {
             cop_name: "Style/Foo".to_string(),
             message: "bad".to_string(),
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d], &[PathBuf::from("foo.rb")]);
         let parsed: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
@@ -180,6 +356,7 @@ mod tests {
             cop_name: "Style/Foo".to_string(),
             message: "fixed".to_string(),
             corrected: true,
+            end_column: None,
         };
         let d2 = Diagnostic {
             path: "a.rb".to_string(),
@@ -188,6 +365,7 @@ mod tests {
             cop_name: "Style/Bar".to_string(),
             message: "not fixed".to_string(),
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d1, d2], &[PathBuf::from("a.rb")]);
         let parsed: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
@@ -1,14 +1,20 @@
 pub mod files;
 pub mod github;
+pub mod grouped;
 pub mod json;
 pub mod pacman;
 pub mod progress;
 pub mod quiet;
 pub mod text;
 
-use std::io::Write;
+use std::io::{IsTerminal, Write};
 use std::path::PathBuf;
 
+use serde::Serialize;
+
+use crate::baseline::BaselineSummary;
+use crate::cli::{Args, AutocorrectMode};
+use crate::config::ResolvedConfig;
 use crate::cop::tiers::SkipSummary;
 use crate::diagnostic::Diagnostic;
 
@@ -18,6 +24,38 @@ pub trait Formatter {
     /// Provide skip summary data for formatters that include it in output (e.g. JSON).
     fn set_skip_summary(&mut self, _summary: SkipSummary) {}
 
+    /// Provide the files whose cop selection pragma narrowed the cops run on them.
+    fn set_pragma_files(&mut self, _files: Vec<PathBuf>) {}
+
+    /// Provide baseline suppression counts when `--baseline` is in use.
+    fn set_baseline_summary(&mut self, _summary: BaselineSummary) {}
+
+    /// Provide the number of distinct files linted, for the "N files inspected"
+    /// count. Without it the count is the number of paths passed to `format_to`,
+    /// which can list the same file more than once (e.g. through a symlink).
+    fn set_inspected_count(&mut self, _count: usize) {}
+
+    /// Provide the number of `AutoCorrect: contextual` offenses a bulk -a/-A
+    /// left uncorrected.
+    fn set_skipped_contextual(&mut self, _count: usize) {}
+
+    /// Whether to emit ANSI colors (see [`color_enabled`]).
+    fn set_color(&mut self, _color: bool) {}
+
+    /// Order each file's offenses by severity, most severe first (`--sort-severity`).
+    fn set_sort_severity(&mut self, _sort_severity: bool) {}
+
+    /// Provide the version and config that produced the report.
+    fn set_run_info(&mut self, _info: RunInfo) {}
+
+    /// Provide the number of offenses before `--max-reported-offenses`
+    /// truncated the list passed to `format_to`.
+    fn set_total_offense_count(&mut self, _count: usize) {}
+
+    /// Provide the run's warnings (also printed to stderr), e.g. for
+    /// `--only` cops that will not run.
+    fn set_warnings(&mut self, _warnings: Vec<String>) {}
+
     fn print(&self, diagnostics: &[Diagnostic], files: &[PathBuf]) {
         let stdout = std::io::stdout();
         let mut lock = stdout.lock();
@@ -29,12 +67,107 @@ pub fn create_formatter(format: &str) -> Box<dyn Formatter> {
     match format {
         "json" => Box::new(json::JsonFormatter::new()),
         "github" => Box::new(github::GithubFormatter),
-        "pacman" => Box::new(pacman::PacmanFormatter),
-        "quiet" => Box::new(quiet::QuietFormatter),
-        "files" => Box::new(files::FilesFormatter),
-        "emacs" | "simple" | "text" => Box::new(text::TextFormatter),
+        "pacman" => Box::new(pacman::PacmanFormatter::default()),
+        "quiet" => Box::new(quiet::QuietFormatter::default()),
+        "files" => Box::new(files::FilesFormatter::default()),
+        "files0" => Box::new(files::FilesFormatter::null_separated()),
+        "grouped" => Box::new(grouped::GroupedFormatter::default()),
+        "emacs" | "simple" | "text" => Box::new(text::TextFormatter::default()),
         // "progress" and any unknown value
-        _ => Box::new(progress::ProgressFormatter),
+        _ => Box::new(progress::ProgressFormatter::default()),
+    }
+}
+
+/// Which nitrocop and which config produced a run: the `metadata` fields of
+/// the JSON report, and the block `--doctor` opens with.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunInfo {
+    pub nitrocop_version: &'static str,
+    /// The config file that was loaded; None when running on defaults.
+    pub config_path: Option<String>,
+    pub target_ruby_version: Option<f64>,
+    pub target_rails_version: Option<f64>,
+    /// "off", "safe" (-a), or "all" (-A).
+    pub autocorrect_mode: &'static str,
+}
+
+impl RunInfo {
+    pub fn new(config: &ResolvedConfig, args: &Args) -> Self {
+        // --stdin ignores -a/-A
+        let mode = if args.stdin.is_some() {
+            AutocorrectMode::Off
+        } else {
+            args.autocorrect_mode()
+        };
+        Self {
+            nitrocop_version: env!("CARGO_PKG_VERSION"),
+            config_path: config.config_path().map(|p| p.display().to_string()),
+            target_ruby_version: config.target_ruby_version(),
+            target_rails_version: config.target_rails_version(),
+            autocorrect_mode: match mode {
+                AutocorrectMode::Off => "off",
+                AutocorrectMode::Safe => "safe",
+                AutocorrectMode::All => "all",
+            },
+        }
+    }
+
+    /// The same fields as text, one per line.
+    pub fn render_text(&self) -> String {
+        let version = |v: Option<f64>| v.map_or("(not set)".to_string(), |v| format!("{v:.1}"));
+        format!(
+            "nitrocop version: {}\n\
+             Config file: {}\n\
+             Target Ruby version: {}\n\
+             Target Rails version: {}\n\
+             Autocorrect mode: {}\n",
+            self.nitrocop_version,
+            self.config_path
+                .as_deref()
+                .unwrap_or("(none, using defaults)"),
+            version(self.target_ruby_version),
+            version(self.target_rails_version),
+            self.autocorrect_mode,
+        )
+    }
+}
+
+/// The color policy: colors only on a terminal, and never with `--no-color`
+/// or a non-empty `NO_COLOR` environment variable.
+pub fn color_enabled(no_color: bool) -> bool {
+    !no_color
+        && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+        && std::io::stdout().is_terminal()
+}
+
+/// `--max-reported-offenses`: the `max` most severe of `diagnostics`, in
+/// their original order. Ties keep the earlier offense.
+pub fn most_severe(diagnostics: &[Diagnostic], max: usize) -> Vec<Diagnostic> {
+    let mut indices: Vec<usize> = (0..diagnostics.len()).collect();
+    indices.sort_by_key(|&i| std::cmp::Reverse(diagnostics[i].severity));
+    indices.truncate(max);
+    indices.sort_unstable();
+    indices
+        .into_iter()
+        .map(|i| diagnostics[i].clone())
+        .collect()
+}
+
+/// Summary-line suffix for offenses hidden by `--baseline` (empty when none were).
+fn baseline_suffix(suppressed: usize) -> String {
+    if suppressed == 0 {
+        String::new()
+    } else {
+        format!(", {suppressed} baseline-suppressed")
+    }
+}
+
+/// Summary-line suffix for contextual corrections held back by -a/-A (empty when none were).
+fn contextual_suffix(skipped: usize) -> String {
+    match skipped {
+        0 => String::new(),
+        1 => ", 1 contextual correction skipped".to_string(),
+        n => format!(", {n} contextual corrections skipped"),
     }
 }
 
@@ -52,6 +185,7 @@ mod tests {
             message: "test offense".to_string(),
 
             corrected: false,
+            end_column: None,
         }]
     }
 
@@ -59,6 +193,48 @@ mod tests {
         vec![PathBuf::from("foo.rb")]
     }
 
+    #[test]
+    fn run_info_text_lists_every_field() {
+        let info = RunInfo {
+            nitrocop_version: "1.2.3",
+            config_path: Some("/app/.rubocop.yml".to_string()),
+            target_ruby_version: Some(3.2),
+            target_rails_version: None,
+            autocorrect_mode: "safe",
+        };
+        assert_eq!(
+            info.render_text(),
+            "nitrocop version: 1.2.3\n\
+             Config file: /app/.rubocop.yml\n\
+             Target Ruby version: 3.2\n\
+             Target Rails version: (not set)\n\
+             Autocorrect mode: safe\n"
+        );
+    }
+
+    #[test]
+    fn most_severe_keeps_highest_severities_in_order() {
+        let diag = |line, severity| Diagnostic {
+            location: Location { line, column: 0 },
+            severity,
+            ..sample_diagnostics().remove(0)
+        };
+        let diagnostics = [
+            diag(1, Severity::Convention),
+            diag(2, Severity::Error),
+            diag(3, Severity::Warning),
+            diag(4, Severity::Convention),
+            diag(5, Severity::Error),
+        ];
+        let lines = |kept: Vec<Diagnostic>| -> Vec<usize> {
+            kept.iter().map(|d| d.location.line).collect()
+        };
+        assert_eq!(lines(most_severe(&diagnostics, 3)), [2, 3, 5]);
+        assert_eq!(lines(most_severe(&diagnostics, 4)), [1, 2, 3, 5]);
+        assert_eq!(lines(most_severe(&diagnostics, 10)), [1, 2, 3, 4, 5]);
+        assert!(most_severe(&diagnostics, 0).is_empty());
+    }
+
     #[test]
     fn create_text_formatter() {
         // Explicit "text" and aliases
@@ -82,7 +258,8 @@ mod tests {
     #[test]
     fn create_all_formatters() {
         for name in [
-            "progress", "text", "json", "github", "pacman", "quiet", "files", "emacs", "simple",
+            "progress", "text", "json", "github", "pacman", "quiet", "files", "files0", "grouped",
+            "emacs", "simple",
         ] {
             let _f = create_formatter(name);
         }
@@ -109,7 +286,8 @@ mod tests {
         let files = sample_files();
         let diags = sample_diagnostics();
         for name in [
-            "progress", "text", "json", "github", "pacman", "quiet", "files", "emacs", "simple",
+            "progress", "text", "json", "github", "pacman", "quiet", "files", "files0", "grouped",
+            "emacs", "simple",
         ] {
             let f = create_formatter(name);
             let mut buf = Vec::new();
@@ -148,6 +326,7 @@ mod tests {
                         cop_name,
                         message,
                         corrected: false,
+                        end_column: None,
                     }
                 })
         }
@@ -2,10 +2,16 @@ use std::collections::HashSet;
 use std::io::Write;
 use std::path::PathBuf;
 
+use crate::baseline::BaselineSummary;
 use crate::diagnostic::Diagnostic;
-use crate::formatter::Formatter;
+use crate::formatter::{Formatter, baseline_suffix, contextual_suffix};
 
-pub struct PacmanFormatter;
+#[derive(Default)]
+pub struct PacmanFormatter {
+    baseline_suppressed: usize,
+    inspected_count: Option<usize>,
+    skipped_contextual: usize,
+}
 
 /// Pac-Man character
 const PACMAN: char = '\u{15E7}'; // ᗧ
@@ -15,8 +21,20 @@ const GHOST: char = '\u{15E3}'; // ᗣ
 const PACDOT: char = '\u{2022}'; // •
 
 impl Formatter for PacmanFormatter {
+    fn set_baseline_summary(&mut self, summary: BaselineSummary) {
+        self.baseline_suppressed = summary.suppressed;
+    }
+
+    fn set_inspected_count(&mut self, count: usize) {
+        self.inspected_count = Some(count);
+    }
+
+    fn set_skipped_contextual(&mut self, count: usize) {
+        self.skipped_contextual = count;
+    }
+
     fn format_to(&self, diagnostics: &[Diagnostic], files: &[PathBuf], out: &mut dyn Write) {
-        let file_count = files.len();
+        let file_count = self.inspected_count.unwrap_or(files.len());
 
         // Collect files with offenses
         let offense_files: HashSet<&str> = diagnostics.iter().map(|d| d.path.as_str()).collect();
@@ -55,14 +73,18 @@ impl Formatter for PacmanFormatter {
             };
             let _ = writeln!(
                 out,
-                "\n{file_count} {file_word} inspected, {} {offense_word} detected, {corrected_count} {corrected_word} corrected",
+                "\n{file_count} {file_word} inspected, {} {offense_word} detected, {corrected_count} {corrected_word} corrected{}{}",
                 diagnostics.len(),
+                contextual_suffix(self.skipped_contextual),
+                baseline_suffix(self.baseline_suppressed),
             );
         } else {
             let _ = writeln!(
                 out,
-                "\n{file_count} {file_word} inspected, {} {offense_word} detected",
+                "\n{file_count} {file_word} inspected, {} {offense_word} detected{}{}",
                 diagnostics.len(),
+                contextual_suffix(self.skipped_contextual),
+                baseline_suffix(self.baseline_suppressed),
             );
         }
     }
@@ -82,12 +104,13 @@ mod tests {
             message: "test".to_string(),
 
             corrected: false,
+            end_column: None,
         }
     }
 
     fn render(diagnostics: &[Diagnostic], files: &[PathBuf]) -> String {
         let mut buf = Vec::new();
-        PacmanFormatter.format_to(diagnostics, files, &mut buf);
+        PacmanFormatter::default().format_to(diagnostics, files, &mut buf);
         String::from_utf8(buf).unwrap()
     }
 
@@ -132,6 +155,7 @@ mod tests {
             message: "bad thing".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d], &files);
         assert!(out.contains("foo.rb:5:3: W: Lint/Bad: bad thing"));
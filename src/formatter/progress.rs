@@ -2,14 +2,32 @@ use std::collections::HashMap;
 use std::io::Write;
 use std::path::PathBuf;
 
+use crate::baseline::BaselineSummary;
 use crate::diagnostic::{Diagnostic, Severity};
-use crate::formatter::Formatter;
+use crate::formatter::{Formatter, baseline_suffix, contextual_suffix};
 
-pub struct ProgressFormatter;
+#[derive(Default)]
+pub struct ProgressFormatter {
+    baseline_suppressed: usize,
+    inspected_count: Option<usize>,
+    skipped_contextual: usize,
+}
 
 impl Formatter for ProgressFormatter {
+    fn set_baseline_summary(&mut self, summary: BaselineSummary) {
+        self.baseline_suppressed = summary.suppressed;
+    }
+
+    fn set_inspected_count(&mut self, count: usize) {
+        self.inspected_count = Some(count);
+    }
+
+    fn set_skipped_contextual(&mut self, count: usize) {
+        self.skipped_contextual = count;
+    }
+
     fn format_to(&self, diagnostics: &[Diagnostic], files: &[PathBuf], out: &mut dyn Write) {
-        let file_count = files.len();
+        let file_count = self.inspected_count.unwrap_or(files.len());
 
         // Build map of file path -> worst severity
         let mut worst_by_file: HashMap<&str, Severity> = HashMap::new();
@@ -58,14 +76,18 @@ impl Formatter for ProgressFormatter {
             };
             let _ = writeln!(
                 out,
-                "\n{file_count} {file_word} inspected, {} {offense_word} detected, {corrected_count} {corrected_word} corrected",
+                "\n{file_count} {file_word} inspected, {} {offense_word} detected, {corrected_count} {corrected_word} corrected{}{}",
                 diagnostics.len(),
+                contextual_suffix(self.skipped_contextual),
+                baseline_suffix(self.baseline_suppressed),
             );
         } else {
             let _ = writeln!(
                 out,
-                "\n{file_count} {file_word} inspected, {} {offense_word} detected",
+                "\n{file_count} {file_word} inspected, {} {offense_word} detected{}{}",
                 diagnostics.len(),
+                contextual_suffix(self.skipped_contextual),
+                baseline_suffix(self.baseline_suppressed),
             );
         }
     }
@@ -85,12 +107,13 @@ mod tests {
             message: "test".to_string(),
 
             corrected: false,
+            end_column: None,
         }
     }
 
     fn render(diagnostics: &[Diagnostic], files: &[PathBuf]) -> String {
         let mut buf = Vec::new();
-        ProgressFormatter.format_to(diagnostics, files, &mut buf);
+        ProgressFormatter::default().format_to(diagnostics, files, &mut buf);
         String::from_utf8(buf).unwrap()
     }
 
@@ -157,6 +180,7 @@ mod tests {
             message: "bad thing".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d], &files);
         assert!(out.contains("foo.rb:5:3: W: Lint/Bad: bad thing"));
@@ -1,17 +1,35 @@
 use std::io::Write;
 use std::path::PathBuf;
 
+use crate::baseline::BaselineSummary;
 use crate::diagnostic::Diagnostic;
-use crate::formatter::Formatter;
+use crate::formatter::{Formatter, baseline_suffix, contextual_suffix};
 
-pub struct QuietFormatter;
+#[derive(Default)]
+pub struct QuietFormatter {
+    baseline_suppressed: usize,
+    inspected_count: Option<usize>,
+    skipped_contextual: usize,
+}
 
 impl Formatter for QuietFormatter {
+    fn set_baseline_summary(&mut self, summary: BaselineSummary) {
+        self.baseline_suppressed = summary.suppressed;
+    }
+
+    fn set_inspected_count(&mut self, count: usize) {
+        self.inspected_count = Some(count);
+    }
+
+    fn set_skipped_contextual(&mut self, count: usize) {
+        self.skipped_contextual = count;
+    }
+
     fn format_to(&self, diagnostics: &[Diagnostic], files: &[PathBuf], out: &mut dyn Write) {
         if diagnostics.is_empty() {
             return;
         }
-        let file_count = files.len();
+        let file_count = self.inspected_count.unwrap_or(files.len());
         for d in diagnostics {
             let _ = writeln!(out, "{d}");
         }
@@ -23,8 +41,10 @@ impl Formatter for QuietFormatter {
         let file_word = if file_count == 1 { "file" } else { "files" };
         let _ = writeln!(
             out,
-            "\n{file_count} {file_word} inspected, {} {offense_word} detected",
+            "\n{file_count} {file_word} inspected, {} {offense_word} detected{}{}",
             diagnostics.len(),
+            contextual_suffix(self.skipped_contextual),
+            baseline_suffix(self.baseline_suppressed),
         );
     }
 }
@@ -36,7 +56,7 @@ mod tests {
 
     fn render(diagnostics: &[Diagnostic], files: &[PathBuf]) -> String {
         let mut buf = Vec::new();
-        QuietFormatter.format_to(diagnostics, files, &mut buf);
+        QuietFormatter::default().format_to(diagnostics, files, &mut buf);
         String::from_utf8(buf).unwrap()
     }
 
@@ -56,6 +76,7 @@ mod tests {
             message: "bad style".to_string(),
 
             corrected: false,
+            end_column: None,
         };
         let out = render(&[d], &[PathBuf::from("foo.rb")]);
         assert!(out.contains("foo.rb:3:5: C: Style/Foo: bad style"));
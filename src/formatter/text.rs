@@ -1,14 +1,32 @@
 use std::io::Write;
 use std::path::PathBuf;
 
+use crate::baseline::BaselineSummary;
 use crate::diagnostic::Diagnostic;
-use crate::formatter::Formatter;
+use crate::formatter::{Formatter, baseline_suffix, contextual_suffix};
 
-pub struct TextFormatter;
+#[derive(Default)]
+pub struct TextFormatter {
+    baseline_suppressed: usize,
+    inspected_count: Option<usize>,
+    skipped_contextual: usize,
+}
 
 impl Formatter for TextFormatter {
+    fn set_baseline_summary(&mut self, summary: BaselineSummary) {
+        self.baseline_suppressed = summary.suppressed;
+    }
+
+    fn set_inspected_count(&mut self, count: usize) {
+        self.inspected_count = Some(count);
+    }
+
+    fn set_skipped_contextual(&mut self, count: usize) {
+        self.skipped_contextual = count;
+    }
+
     fn format_to(&self, diagnostics: &[Diagnostic], files: &[PathBuf], out: &mut dyn Write) {
-        let file_count = files.len();
+        let file_count = self.inspected_count.unwrap_or(files.len());
         for d in diagnostics {
             let _ = writeln!(out, "{d}");
         }
@@ -27,14 +45,18 @@ impl Formatter for TextFormatter {
             };
             let _ = writeln!(
                 out,
-                "\n{file_count} {file_word} inspected, {} {offense_word} detected, {corrected_count} {corrected_word} corrected",
+                "\n{file_count} {file_word} inspected, {} {offense_word} detected, {corrected_count} {corrected_word} corrected{}{}",
                 diagnostics.len(),
+                contextual_suffix(self.skipped_contextual),
+                baseline_suffix(self.baseline_suppressed),
             );
         } else {
             let _ = writeln!(
                 out,
-                "\n{file_count} {file_word} inspected, {} {offense_word} detected",
+                "\n{file_count} {file_word} inspected, {} {offense_word} detected{}{}",
                 diagnostics.len(),
+                contextual_suffix(self.skipped_contextual),
+                baseline_suffix(self.baseline_suppressed),
             );
         }
     }
@@ -61,12 +83,13 @@ mod tests {
             message: msg.to_string(),
 
             corrected: false,
+            end_column: None,
         }
     }
 
     fn render(diagnostics: &[Diagnostic], files: &[PathBuf]) -> String {
         let mut buf = Vec::new();
-        TextFormatter.format_to(diagnostics, files, &mut buf);
+        TextFormatter::default().format_to(diagnostics, files, &mut buf);
         String::from_utf8(buf).unwrap()
     }
 
@@ -100,6 +123,36 @@ mod tests {
         assert!(out.contains("2 files inspected, 2 offenses detected"));
     }
 
+    #[test]
+    fn inspected_count_overrides_path_count() {
+        let d1 = make_diag("a.rb", 1, 0, Severity::Convention, "X/Y", "m1");
+        let d2 = make_diag("link.rb", 1, 0, Severity::Convention, "X/Y", "m1");
+        let files = vec![PathBuf::from("a.rb"), PathBuf::from("link.rb")];
+        let mut f = TextFormatter::default();
+        f.set_inspected_count(1);
+        let mut buf = Vec::new();
+        f.format_to(&[d1, d2], &files, &mut buf);
+        let out = String::from_utf8(buf).unwrap();
+        assert!(
+            out.contains("1 file inspected, 2 offenses detected"),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn summary_counts_skipped_contextual_corrections() {
+        let d = make_diag("a.rb", 1, 0, Severity::Convention, "X/Y", "m1");
+        let mut f = TextFormatter::default();
+        f.set_skipped_contextual(1);
+        let mut buf = Vec::new();
+        f.format_to(&[d], &[PathBuf::from("a.rb")], &mut buf);
+        let out = String::from_utf8(buf).unwrap();
+        assert!(
+            out.contains("1 file inspected, 1 offense detected, 1 contextual correction skipped"),
+            "{out}"
+        );
+    }
+
     #[test]
     fn corrected_offense_shows_corrected_prefix() {
         let mut d = make_diag("foo.rb", 1, 5, Severity::Convention, "Style/Foo", "bad");
@@ -123,4 +176,21 @@ mod tests {
             "Expected corrected count in summary, got: {out}"
         );
     }
+
+    #[test]
+    fn summary_includes_baseline_suppressed_count() {
+        let d = make_diag("a.rb", 1, 0, Severity::Convention, "X/Y", "m1");
+        let mut f = TextFormatter::default();
+        f.set_baseline_summary(BaselineSummary {
+            suppressed: 4,
+            stale: 0,
+        });
+        let mut buf = Vec::new();
+        f.format_to(&[d], &[PathBuf::from("a.rb")], &mut buf);
+        let out = String::from_utf8(buf).unwrap();
+        assert!(
+            out.contains("1 file inspected, 1 offense detected, 4 baseline-suppressed"),
+            "Expected baseline count in summary, got: {out}"
+        );
+    }
 }
@@ -16,7 +16,8 @@ pub struct DiscoveredFiles {
 }
 
 /// Discover Ruby files from the given paths, respecting .gitignore
-/// and AllCops.Exclude patterns.
+/// and AllCops.Exclude patterns. ERB templates and Markdown files are
+/// included when their modes are on.
 pub fn discover_files(paths: &[PathBuf], config: &ResolvedConfig) -> Result<DiscoveredFiles> {
     let mut files = Vec::new();
     let mut explicit = HashSet::new();
@@ -41,7 +42,7 @@ pub fn discover_files(paths: &[PathBuf], config: &ResolvedConfig) -> Result<Disc
 }
 
 /// Exposed for testing only.
-fn walk_directory(dir: &Path, _config: &ResolvedConfig) -> Result<Vec<PathBuf>> {
+fn walk_directory(dir: &Path, config: &ResolvedConfig) -> Result<Vec<PathBuf>> {
     let mut builder = WalkBuilder::new(dir);
     builder
         .hidden(true)
@@ -63,7 +64,7 @@ fn walk_directory(dir: &Path, _config: &ResolvedConfig) -> Result<Vec<PathBuf>>
             Err(_) => continue, // skip broken symlinks / permission errors
         };
         let path = entry.path();
-        if path.is_file() && is_ruby_file(path) {
+        if path.is_file() && is_target_file(path, config) {
             files.push(path.to_path_buf());
         }
     }
@@ -72,12 +73,12 @@ fn walk_directory(dir: &Path, _config: &ResolvedConfig) -> Result<Vec<PathBuf>>
     // The ignore crate does not have git index awareness, so merge git-tracked
     // Ruby files to avoid false negatives (for example, tracked files under
     // ignored directories).
-    files.extend(tracked_ruby_files(dir));
+    files.extend(tracked_ruby_files(dir, config));
 
     Ok(files)
 }
 
-fn tracked_ruby_files(dir: &Path) -> Vec<PathBuf> {
+fn tracked_ruby_files(dir: &Path, config: &ResolvedConfig) -> Vec<PathBuf> {
     let toplevel = match Command::new("git")
         .arg("-C")
         .arg(dir)
@@ -130,7 +131,7 @@ fn tracked_ruby_files(dir: &Path) -> Vec<PathBuf> {
             };
             Some(dir.join(rel_to_dir))
         })
-        .filter(|path| path.is_file() && is_ruby_file(path))
+        .filter(|path| path.is_file() && is_target_file(path, config))
         .collect()
 }
 
@@ -217,6 +218,13 @@ fn has_hidden_dir_component(path: &Path) -> bool {
     false
 }
 
+/// Ruby files, plus ERB templates and Markdown files when their modes are on.
+fn is_target_file(path: &Path, config: &ResolvedConfig) -> bool {
+    (config.erb_enabled() && crate::erb::is_erb_file(path))
+        || (config.markdown_enabled() && crate::markdown::is_markdown_file(path))
+        || is_ruby_file(path)
+}
+
 fn is_ruby_file(path: &Path) -> bool {
     // Check by extension
     if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
@@ -368,6 +376,25 @@ mod tests {
         fs::remove_dir_all(&dir).ok();
     }
 
+    #[test]
+    fn discovers_erb_templates_only_in_erb_mode() {
+        let dir = setup_dir("discover_erb");
+        fs::write(dir.join("a.rb"), "").unwrap();
+        fs::write(dir.join("show.html.erb"), "").unwrap();
+
+        let mut config = load_config(Some(Path::new("/nonexistent")), None, None).unwrap();
+        let discovered = discover_files(&[dir.clone()], &config).unwrap();
+        assert_eq!(discovered.files, vec![dir.join("a.rb")]);
+
+        config.enable_erb();
+        let discovered = discover_files(&[dir.clone()], &config).unwrap();
+        assert_eq!(
+            discovered.files,
+            vec![dir.join("a.rb"), dir.join("show.html.erb")]
+        );
+        fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn direct_file_bypasses_extension_filter() {
         let dir = setup_dir("direct");
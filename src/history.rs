@@ -0,0 +1,303 @@
+//! Run history: `--history-file PATH` appends one JSON object per run, one
+//! per line, so offense counts can be tracked over time without parsing logs.
+//!
+//! Each line is a [`Record`] (schema 1):
+//!
+//! ```text
+//! {"schema":1,"timestamp":"2026-10-17T09:30:00Z","nitrocop_version":"0.1.0",
+//!  "git_sha":"3f2a…","config_fingerprint":"9c1d…","files_inspected":812,
+//!  "offenses":1024,"by_severity":{"convention":1000,"warning":24},
+//!  "by_department":{"Layout":700,"Lint":24,"Style":300},"corrected":0,
+//!  "duration_ms":1830}
+//! ```
+//!
+//! `offenses` and the two breakdowns count reported offenses, after any
+//! `--baseline` and without the ones autocorrect fixed, which `corrected`
+//! counts. `git_sha` is `null` outside a git checkout. Fields are only ever
+//! added; a change that breaks readers bumps `schema`.
+//!
+//! The record is written with a single append to a file opened in append
+//! mode, so parallel CI jobs sharing one history file do not interleave
+//! lines. `--history-summary` compares the new record with the last one
+//! already in the file.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::diagnostic::{Diagnostic, Severity};
+
+const SCHEMA_VERSION: u32 = 1;
+
+/// One run's line in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub schema: u32,
+    /// UTC, RFC 3339 with second precision.
+    pub timestamp: String,
+    pub nitrocop_version: String,
+    pub git_sha: Option<String>,
+    pub config_fingerprint: String,
+    pub files_inspected: usize,
+    pub offenses: usize,
+    #[serde(default)]
+    pub by_severity: BTreeMap<String, usize>,
+    #[serde(default)]
+    pub by_department: BTreeMap<String, usize>,
+    pub corrected: usize,
+    pub duration_ms: u64,
+}
+
+impl Record {
+    pub fn new(
+        diagnostics: &[Diagnostic],
+        files_inspected: usize,
+        corrected: usize,
+        config_fingerprint: String,
+        duration: Duration,
+    ) -> Self {
+        let mut by_severity = BTreeMap::new();
+        let mut by_department = BTreeMap::new();
+        let remaining: Vec<&Diagnostic> = diagnostics.iter().filter(|d| !d.corrected).collect();
+        for d in &remaining {
+            let severity = match d.severity {
+                Severity::Convention => "convention",
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+                Severity::Fatal => "fatal",
+            };
+            *by_severity.entry(severity.to_string()).or_insert(0) += 1;
+            let department = d.cop_name.rsplit_once('/').map_or("", |(dept, _)| dept);
+            *by_department.entry(department.to_string()).or_insert(0) += 1;
+        }
+        Self {
+            schema: SCHEMA_VERSION,
+            timestamp: rfc3339_utc(SystemTime::now()),
+            nitrocop_version: env!("CARGO_PKG_VERSION").to_string(),
+            git_sha: git_head_sha(),
+            config_fingerprint,
+            files_inspected,
+            offenses: remaining.len(),
+            by_severity,
+            by_department,
+            corrected,
+            duration_ms: duration.as_millis() as u64,
+        }
+    }
+}
+
+/// The last record in the history file at `path`, if any. Lines that do not
+/// parse (a truncated write, a future schema) are skipped.
+pub fn last_record(path: &Path) -> Option<Record> {
+    let content = std::fs::read_to_string(path).ok()?;
+    content
+        .lines()
+        .rev()
+        .find_map(|line| serde_json::from_str(line).ok())
+}
+
+/// Append `record` to the history file at `path`, creating it if needed.
+pub fn append(path: &Path, record: &Record) -> Result<()> {
+    let mut line = serde_json::to_string(record).context("failed to serialize history record")?;
+    line.push('\n');
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    // One write call, so concurrent appenders do not interleave.
+    file.write_all(line.as_bytes())
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// The `--history-summary` line: per-department changes since `previous`.
+pub fn summary(previous: Option<&Record>, current: &Record) -> String {
+    let Some(previous) = previous else {
+        return format!(
+            "History: first run recorded ({}).",
+            offense_count(current.offenses)
+        );
+    };
+    let departments: std::collections::BTreeSet<&String> = previous
+        .by_department
+        .keys()
+        .chain(current.by_department.keys())
+        .collect();
+    let changes: Vec<String> = departments
+        .into_iter()
+        .filter_map(|dept| {
+            let before = previous.by_department.get(dept).copied().unwrap_or(0) as i64;
+            let after = current.by_department.get(dept).copied().unwrap_or(0) as i64;
+            let delta = after - before;
+            (delta != 0).then(|| format!("{delta:+} {dept}"))
+        })
+        .collect();
+    let config_note = if previous.config_fingerprint != current.config_fingerprint {
+        ", config changed"
+    } else {
+        ""
+    };
+    if changes.is_empty() {
+        format!(
+            "History: no change since last run ({}{config_note}).",
+            offense_count(current.offenses)
+        )
+    } else {
+        format!(
+            "History: {} since last run ({}{config_note}).",
+            changes.join(", "),
+            offense_count(current.offenses)
+        )
+    }
+}
+
+fn offense_count(n: usize) -> String {
+    format!("{n} {}", if n == 1 { "offense" } else { "offenses" })
+}
+
+/// `git rev-parse HEAD` in the working directory, if it is a git checkout.
+fn git_head_sha() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let sha = String::from_utf8(output.stdout).ok()?;
+    Some(sha.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// `time` as `YYYY-MM-DDTHH:MM:SSZ`.
+fn rfc3339_utc(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Location;
+
+    fn diag(cop: &str, severity: Severity, corrected: bool) -> Diagnostic {
+        Diagnostic {
+            path: "a.rb".to_string(),
+            location: Location { line: 1, column: 0 },
+            severity,
+            cop_name: cop.to_string(),
+            message: "m".to_string(),
+            corrected,
+            end_column: None,
+        }
+    }
+
+    fn record(by_department: &[(&str, usize)], fingerprint: &str) -> Record {
+        Record {
+            schema: SCHEMA_VERSION,
+            timestamp: String::new(),
+            nitrocop_version: String::new(),
+            git_sha: None,
+            config_fingerprint: fingerprint.to_string(),
+            files_inspected: 1,
+            offenses: by_department.iter().map(|(_, n)| n).sum(),
+            by_severity: BTreeMap::new(),
+            by_department: by_department
+                .iter()
+                .map(|(d, n)| (d.to_string(), *n))
+                .collect(),
+            corrected: 0,
+            duration_ms: 0,
+        }
+    }
+
+    #[test]
+    fn counts_remaining_offenses_by_severity_and_department() {
+        let diagnostics = [
+            diag("Layout/TrailingWhitespace", Severity::Convention, false),
+            diag("Layout/IndentationWidth", Severity::Convention, true),
+            diag("Lint/Debugger", Severity::Warning, false),
+            diag("RSpec/Focus", Severity::Convention, false),
+        ];
+        let record = Record::new(&diagnostics, 3, 1, "abc".to_string(), Duration::ZERO);
+        assert_eq!(record.offenses, 3);
+        assert_eq!(record.corrected, 1);
+        assert_eq!(record.by_severity["convention"], 2);
+        assert_eq!(record.by_severity["warning"], 1);
+        assert_eq!(record.by_department["Layout"], 1);
+        assert_eq!(record.by_department["Lint"], 1);
+        assert_eq!(record.by_department["RSpec"], 1);
+    }
+
+    #[test]
+    fn summary_lists_department_deltas() {
+        let previous = record(&[("Layout", 250), ("Lint", 3), ("Style", 7)], "a");
+        let current = record(&[("Layout", 10), ("Lint", 15), ("Style", 7)], "a");
+        assert_eq!(
+            summary(Some(&previous), &current),
+            "History: -240 Layout, +12 Lint since last run (32 offenses)."
+        );
+        assert_eq!(
+            summary(Some(&current), &current),
+            "History: no change since last run (32 offenses)."
+        );
+        let reconfigured = record(&[("Layout", 10), ("Lint", 15)], "b");
+        assert_eq!(
+            summary(Some(&current), &reconfigured),
+            "History: -7 Style since last run (25 offenses, config changed)."
+        );
+        assert_eq!(
+            summary(None, &current),
+            "History: first run recorded (32 offenses)."
+        );
+    }
+
+    #[test]
+    fn formats_utc_timestamps() {
+        let at = |secs| rfc3339_utc(SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+        assert_eq!(at(0), "1970-01-01T00:00:00Z");
+        assert_eq!(at(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(at(1_792_236_645), "2026-10-17T11:30:45Z");
+    }
+
+    #[test]
+    fn appends_lines_and_reads_back_the_last_record() {
+        let dir = std::env::temp_dir().join(format!("nitrocop_history_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("history.jsonl");
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(last_record(&path), None);
+
+        let first = record(&[("Lint", 1)], "a");
+        let second = record(&[("Lint", 2)], "a");
+        append(&path, &first).unwrap();
+        append(&path, &second).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        assert_eq!(last_record(&path), Some(second));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
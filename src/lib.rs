@@ -1,37 +1,72 @@
+//! nitrocop: a fast Ruby linter, drop-in compatible with RuboCop.
+//!
+//! Besides the `nitrocop` binary, the crate can be embedded. [`lint_string`]
+//! lints source in-process and returns diagnostics and corrected source as
+//! plain data (see [`api`]). To run in-house cops, implement [`Cop`], hand
+//! it to [`Linter::with_extra_cops`], and test it with the fixture macros
+//! ([`cop_fixture_tests!`] and friends, behind the `testutil` feature). See
+//! `examples/custom_cop.rs`.
+
+pub mod api;
+pub mod baseline;
 pub mod cache;
+pub mod changed_lines;
 pub mod cli;
 pub mod config;
+pub mod config_schema;
 pub mod cop;
+pub mod cop_selection;
+pub mod copy_to;
 pub mod correction;
+pub mod debug_log;
 pub mod diagnostic;
 pub mod doctor;
+pub mod erb;
+pub mod estimate;
 pub mod formatter;
 pub mod fs;
+pub mod history;
 pub mod linter;
+pub mod markdown;
 pub mod migrate;
 pub mod node_pattern;
 pub mod parse;
 pub mod rules;
 pub mod schema;
+pub mod show_config_chain;
+pub mod show_cops;
+pub mod spec_examples;
+pub mod validate_config;
 pub mod verify;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "testutil"))]
 pub mod testutil;
 
+pub use api::{LintOptions, LintOutcome, lint_string};
+pub use cop::registry::CopRegistry;
+pub use cop::{Cop, CopConfig};
+pub use correction::Correction;
+pub use diagnostic::{Diagnostic, Severity};
+pub use parse::codemap::CodeMap;
+pub use parse::source::SourceFile;
+/// The Prism bindings cops are written against, re-exported so extra cops
+/// build against the same version.
+pub use ruby_prism;
+
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::io::Read;
 use std::path::PathBuf;
 
 use anyhow::Result;
 
+use baseline::{Baseline, BaselineSummary, SourceCache};
 use cli::{Args, StrictScope};
 use config::load_config;
-use cop::registry::CopRegistry;
 use cop::tiers::{SkipSummary, TierMap};
 use formatter::create_formatter;
 use fs::{DiscoveredFiles, discover_files};
-use linter::{lint_source, run_linter};
-use parse::source::SourceFile;
+use linter::run_linter;
 
 /// Check whether the skip summary violates the given strict scope.
 /// Returns `true` if the strict check fails (i.e., exit 2 should be used).
@@ -84,6 +119,108 @@ fn print_strict_warning(scope: StrictScope, summary: &SkipSummary) {
     );
 }
 
+/// The formatter for `--format`, with the run info, the color policy, and
+/// `--sort-severity` applied.
+fn configured_formatter(
+    args: &Args,
+    config: &config::ResolvedConfig,
+) -> Box<dyn formatter::Formatter> {
+    let mut formatter = create_formatter(&args.format);
+    formatter.set_run_info(formatter::RunInfo::new(config, args));
+    formatter.set_color(formatter::color_enabled(args.no_color));
+    formatter.set_sort_severity(args.sort_severity);
+    formatter
+}
+
+/// The diagnostics to print: all of them, or only those at or above the fail
+/// level with `--display-only-fail-level-offenses`, then at most
+/// `--max-reported-offenses` of the most severe. Also returns how many there
+/// were before that cap. The exit status is decided on the full list either
+/// way.
+fn displayed_diagnostics<'a>(
+    args: &Args,
+    fail_level: Severity,
+    diagnostics: &'a [Diagnostic],
+) -> (Cow<'a, [Diagnostic]>, usize) {
+    let shown: Cow<'a, [Diagnostic]> = if args.display_only_fail_level_offenses {
+        diagnostics
+            .iter()
+            .filter(|d| d.severity >= fail_level)
+            .cloned()
+            .collect()
+    } else {
+        Cow::Borrowed(diagnostics)
+    };
+    let total = shown.len();
+    match args.max_reported_offenses {
+        Some(max) if total > max => (Cow::Owned(formatter::most_severe(&shown, max)), total),
+        _ => (shown, total),
+    }
+}
+
+/// Hand the displayed diagnostics to `formatter`, and say on stderr when
+/// `--max-reported-offenses` left some out.
+fn print_report(
+    formatter: &mut dyn formatter::Formatter,
+    args: &Args,
+    fail_level: Severity,
+    diagnostics: &[Diagnostic],
+    files: &[PathBuf],
+) {
+    let (displayed, total) = displayed_diagnostics(args, fail_level, diagnostics);
+    formatter.set_total_offense_count(total);
+    formatter.print(&displayed, files);
+    if displayed.len() < total {
+        eprintln!(
+            "warning: reported the {} most severe of {total} offenses (--max-reported-offenses); \
+             the exit status counts all of them",
+            displayed.len()
+        );
+    }
+}
+
+/// Apply `--baseline` or `--baseline-generate` to the diagnostics of a finished run.
+///
+/// When generating, every offense is written to the baseline and all
+/// diagnostics are returned unchanged. When filtering, stale entries are
+/// printed to stderr and the suppression counts are returned for formatters.
+fn apply_baseline(
+    args: &Args,
+    baseline: Option<&Baseline>,
+    diagnostics: Vec<Diagnostic>,
+    inspected: &[PathBuf],
+    sources: &mut SourceCache,
+) -> Result<(Vec<Diagnostic>, Option<BaselineSummary>)> {
+    if let Some(ref path) = args.baseline_generate {
+        let generated = Baseline::generate(&diagnostics, path, sources);
+        generated.write(path)?;
+        let word = if generated.len() == 1 {
+            "offense"
+        } else {
+            "offenses"
+        };
+        eprintln!(
+            "Recorded {} {word} in baseline {}.",
+            generated.len(),
+            path.display()
+        );
+        return Ok((diagnostics, None));
+    }
+    let (Some(baseline), Some(path)) = (baseline, args.baseline.as_deref()) else {
+        return Ok((diagnostics, None));
+    };
+    // `--only`/`--except` take exact cop names; entries for cops that did not
+    // run cannot be judged stale.
+    let cop_ran = |cop: &str| {
+        (args.only.is_empty() || args.only.iter().any(|o| o == cop))
+            && !args.except.iter().any(|e| e == cop)
+    };
+    let outcome = baseline.apply(diagnostics, inspected, cop_ran, sources);
+    baseline::print_stale(&outcome.stale, path);
+    let summary = outcome.summary();
+    Ok((outcome.diagnostics, Some(summary)))
+}
+
 /// Batch corpus check: lint each subdirectory of `corpus_dir` as a separate repo.
 /// Outputs JSON with per-repo offense counts (deduplicated by path+line+cop).
 fn collect_corpus_check_results(
@@ -149,6 +286,7 @@ fn collect_corpus_check_results(
                     &base_configs,
                     has_dir_overrides,
                     None,
+                    None,
                     allowlist,
                 );
                 // Deduplicate by (path, line, cop_name) to match corpus oracle
@@ -189,6 +327,85 @@ fn run_corpus_check(
 
 /// Run the linter. Returns the exit code: 0 = clean, 1 = offenses, 2 = strict failure, 3 = error.
 pub fn run(args: Args) -> Result<i32> {
+    Linter::new().run(args)
+}
+
+/// Entry point for binaries that embed nitrocop with their own cops.
+///
+/// Extra cops get the full CLI behavior of `run`: config, `--only`,
+/// autocorrect, formatters, and caching. They are treated as stable (no
+/// `--preview` needed) and, when they support autocorrect, as verified for
+/// `-a`; their config `Safe`/`SafeAutoCorrect` flags still apply. A cop
+/// named like a built-in one replaces it.
+///
+/// ```no_run
+/// # use nitrocop::{Cop, Linter};
+/// # struct MyCop;
+/// # impl Cop for MyCop { fn name(&self) -> &'static str { "Acme/MyCop" } }
+/// use clap::Parser;
+///
+/// let args = nitrocop::cli::Args::parse();
+/// let code = Linter::new()
+///     .with_extra_cops(vec![Box::new(MyCop)])
+///     .run(args)
+///     .unwrap_or(3);
+/// std::process::exit(code);
+/// ```
+#[derive(Default)]
+pub struct Linter {
+    extra_cops: Vec<Box<dyn Cop>>,
+}
+
+impl Linter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register additional cops alongside the built-in ones.
+    pub fn with_extra_cops(mut self, cops: Vec<Box<dyn Cop>>) -> Self {
+        self.extra_cops.extend(cops);
+        self
+    }
+
+    /// Run with the given CLI arguments. Same exit codes as [`run`].
+    pub fn run(self, args: Args) -> Result<i32> {
+        run_with_cops(args, self.extra_cops)
+    }
+}
+
+/// Promote `--preview-cop` names and the project's `AllCops: PreviewCops` for
+/// `--rules` and `--list-cops`, which need no config but show the promotions
+/// when it loads.
+fn promote_configured_preview_cops(
+    args: &Args,
+    target_dir: Option<&std::path::Path>,
+    tier_map: &mut TierMap,
+) {
+    let mut promoted = args.preview_cop.clone();
+    if !args.force_default_config {
+        if let Ok(config) = load_config(args.config.as_deref(), target_dir, None) {
+            print_config_warnings(&config);
+            promoted.extend(config.preview_cops().iter().cloned());
+        }
+    }
+    for name in &promoted {
+        tier_map.promote(name);
+    }
+}
+
+/// Print what went wrong while loading `config` without stopping it.
+fn print_config_warnings(config: &config::ResolvedConfig) {
+    for warning in config.warnings() {
+        eprintln!("warning: {warning}");
+    }
+}
+
+fn run_with_cops(args: Args, extra_cops: Vec<Box<dyn Cop>>) -> Result<i32> {
+    let run_start = std::time::Instant::now();
+    if args.debug_json {
+        debug_log::enable();
+    }
+
     // Warn about unsupported --require flag
     if !args.require_libs.is_empty() {
         eprintln!("warning: --require is not supported; use `require:` in .rubocop.yml instead");
@@ -211,6 +428,11 @@ pub fn run(args: Args) -> Result<i32> {
         }
     }
 
+    // --copy-to only writes corrected files, so it needs an autocorrect mode
+    if args.copy_to.is_some() && args.autocorrect_mode() == cli::AutocorrectMode::Off {
+        anyhow::bail!("--copy-to requires -a, -A or --autocorrect-cop");
+    }
+
     let target_dir = args.paths.first().map(|p| {
         if p.is_file() {
             p.parent().unwrap_or(p)
@@ -219,16 +441,36 @@ pub fn run(args: Args) -> Result<i32> {
         }
     });
 
-    let registry = CopRegistry::default_registry();
-    let tier_map = TierMap::load();
-    let allowlist = cop::autocorrect_allowlist::AutocorrectAllowlist::load();
+    let mut registry = CopRegistry::default_registry();
+    let mut tier_map = TierMap::load();
+    let mut allowlist = cop::autocorrect_allowlist::AutocorrectAllowlist::load();
+    for cop in extra_cops {
+        tier_map.set_tier(cop.name(), cop::tiers::Tier::Stable);
+        if cop.supports_autocorrect() {
+            allowlist.insert(cop.name());
+        }
+        registry.register(cop);
+    }
+
+    let rule_filter = rules::RuleFilter {
+        tier: args.tier.as_deref(),
+        department: args.department.as_deref(),
+    };
 
-    // --list-cops: print all registered cop names and exit (no config needed)
+    // --list-cops: print all registered cop names and exit (no config needed),
+    // or their `--rules` entries with --format json
     if args.list_cops {
-        let mut names: Vec<&str> = registry.cops().iter().map(|c| c.name()).collect();
-        names.sort();
-        for name in names {
-            println!("{name}");
+        if args.format == "json" {
+            promote_configured_preview_cops(&args, target_dir, &mut tier_map);
+        }
+        let mut rule_list = rules::build_rules(&registry, &tier_map, rule_filter);
+        rule_list.retain(|rule| rule.implemented);
+        if args.format == "json" {
+            rules::print_json(&rule_list);
+        } else {
+            for rule in &rule_list {
+                println!("{}", rule.name);
+            }
         }
         return Ok(0);
     }
@@ -250,7 +492,8 @@ pub fn run(args: Args) -> Result<i32> {
 
     // --rules: list all cops with tier, implementation status, baseline presence
     if args.rules {
-        let rule_list = rules::build_rules(&registry, &tier_map, args.tier.as_deref());
+        promote_configured_preview_cops(&args, target_dir, &mut tier_map);
+        let rule_list = rules::build_rules(&registry, &tier_map, rule_filter);
         if args.format == "json" {
             rules::print_json(&rule_list);
         } else {
@@ -259,6 +502,26 @@ pub fn run(args: Args) -> Result<i32> {
         return Ok(0);
     }
 
+    // --emit-schema: write a JSON Schema for .rubocop.yml
+    if let Some(ref path) = args.emit_schema {
+        // Cop options come from the installed gems; the lockfile only saves
+        // `bundle info` calls.
+        let working_dir = target_dir.unwrap_or(std::path::Path::new("."));
+        let lock = if args.no_cache {
+            None
+        } else {
+            config::lockfile::read_lock(working_dir).ok()
+        };
+        let cop_options =
+            config_schema::load_cop_options(working_dir, lock.as_ref().map(|l| &l.gems));
+        if cop_options.is_empty() {
+            eprintln!("warning: rubocop gem not found; the schema has no cop options");
+        }
+        let schema = config_schema::build_schema(&registry, &cop_options);
+        config_schema::write_schema(&schema, path)?;
+        return Ok(0);
+    }
+
     // --cache-clear: remove result cache directory and exit
     if args.cache_clear {
         match cache::clear_cache() {
@@ -275,7 +538,8 @@ pub fn run(args: Args) -> Result<i32> {
     // --init: resolve gem paths and write lockfile
     if args.init {
         let config_start = std::time::Instant::now();
-        let _config = load_config(args.config.as_deref(), target_dir, None)?;
+        let config = load_config(args.config.as_deref(), target_dir, None)?;
+        print_config_warnings(&config);
         let config_elapsed = config_start.elapsed();
 
         let gem_paths = config::gem_path::drain_resolved_paths();
@@ -305,10 +569,43 @@ pub fn run(args: Args) -> Result<i32> {
         && !args.force_default_config
         && args.stdin.is_none();
 
+    // --validate-config: check the config chain without linting
+    if args.validate_config {
+        let Some(config_path) = config::find_config_path(args.config.as_deref(), target_dir) else {
+            eprintln!("No config file found.");
+            return Ok(0);
+        };
+        // The lockfile is optional here: it only saves `bundle info` calls
+        // when resolving inherit_gem.
+        let lock = if use_cache {
+            let lock_dir = target_dir.unwrap_or(std::path::Path::new("."));
+            config::lockfile::read_lock(lock_dir).ok()
+        } else {
+            None
+        };
+        let report = validate_config::validate_config(
+            &config_path,
+            &registry,
+            lock.as_ref().map(|l| &l.gems),
+        );
+        if args.format == "json" {
+            validate_config::print_json(&report);
+        } else {
+            validate_config::print_text(&report);
+        }
+        return Ok(if report.has_errors() { 2 } else { 0 });
+    }
+
+    // --show-cops, and the warnings for --only/--except cops that will not
+    // run: record which file set each setting while loading
+    if args.show_cops.is_some() || !args.only.is_empty() || !args.except.is_empty() {
+        config::provenance::enable();
+    }
+
     // Load config — use lockfile if available
     let config_start = std::time::Instant::now();
-    let config = if args.force_default_config {
-        let mut cfg = config::ResolvedConfig::empty();
+    let mut config = if args.force_default_config {
+        let mut cfg = config::ResolvedConfig::defaults_only();
         // When --only targets plugin-department cops (RSpec, Rails, etc.),
         // register those departments so the cops aren't silently disabled.
         // Without this, --force-default-config creates an empty config with
@@ -324,6 +621,13 @@ pub fn run(args: Args) -> Result<i32> {
                 if args.debug {
                     eprintln!("debug: using lockfile ({} cached gems)", lock.gems.len());
                 }
+                debug_log::emit(
+                    "lockfile_used",
+                    serde_json::json!({
+                        "path": config::lockfile::lockfile_path(lock_dir).display().to_string(),
+                        "gems": lock.gems.len(),
+                    }),
+                );
                 load_config(args.config.as_deref(), target_dir, Some(&lock.gems))?
             }
             Err(e) => {
@@ -334,6 +638,17 @@ pub fn run(args: Args) -> Result<i32> {
     } else {
         load_config(args.config.as_deref(), target_dir, None)?
     };
+    print_config_warnings(&config);
+    if args.erb {
+        config.enable_erb();
+    }
+    if args.markdown {
+        config.enable_markdown();
+    }
+    config.add_preview_cops(&args.preview_cop);
+    for name in config.preview_cops() {
+        tier_map.promote(name);
+    }
     let config_elapsed = config_start.elapsed();
 
     if args.debug {
@@ -373,7 +688,35 @@ pub fn run(args: Args) -> Result<i32> {
 
     // --doctor: debug/support output
     if args.doctor {
-        doctor::run_doctor(&config, &registry, &tier_map, target_dir);
+        doctor::run_doctor(
+            &config,
+            &registry,
+            &tier_map,
+            target_dir,
+            &formatter::RunInfo::new(&config, &args),
+        );
+        return Ok(0);
+    }
+
+    // --show-cops: effective cop config with the file behind each setting
+    if let Some(ref cops) = args.show_cops {
+        let names: Vec<String> = cops
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect();
+        show_cops::print_show_cops(&config, &registry, &names);
+        return Ok(0);
+    }
+
+    // --show-config-chain: every config file loaded, in load order
+    if args.show_config_chain {
+        if args.format == "json" {
+            show_config_chain::print_json(&config);
+        } else {
+            show_config_chain::print_text(&config);
+        }
         return Ok(0);
     }
 
@@ -406,16 +749,46 @@ pub fn run(args: Args) -> Result<i32> {
         eprintln!("warning: autocorrect is not supported with --stdin, ignoring");
     }
 
+    // --baseline: load up front so a bad file fails before linting
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+
     // --stdin: read from stdin and lint a single file
     if let Some(ref display_path) = args.stdin {
         let mut input = String::new();
         std::io::stdin().read_to_string(&mut input)?;
-        let source = SourceFile::from_string(display_path.clone(), input);
-        let result = lint_source(&source, &config, &registry, &args, &tier_map, &allowlist);
-        let mut formatter = create_formatter(&args.format);
+        let mut sources = SourceCache::new();
+        sources.insert(&display_path.to_string_lossy(), input.clone().into_bytes());
+        let result = api::lint_with(
+            input,
+            display_path,
+            &config,
+            &registry,
+            &args,
+            &tier_map,
+            &allowlist,
+        );
+        let (diagnostics, baseline_summary) = apply_baseline(
+            &args,
+            baseline.as_ref(),
+            result.diagnostics,
+            std::slice::from_ref(display_path),
+            &mut sources,
+        )?;
+        let mut formatter = configured_formatter(&args, &config);
         formatter.set_skip_summary(result.skip_summary.clone());
-        formatter.print(&result.diagnostics, std::slice::from_ref(display_path));
-        let has_lint_failure = result.diagnostics.iter().any(|d| d.severity >= fail_level);
+        formatter.set_pragma_files(result.pragma_files.clone());
+        if let Some(summary) = baseline_summary {
+            formatter.set_baseline_summary(summary);
+        }
+        print_report(
+            formatter.as_mut(),
+            &args,
+            fail_level,
+            &diagnostics,
+            std::slice::from_ref(display_path),
+        );
+        let has_lint_failure = args.baseline_generate.is_none()
+            && diagnostics.iter().any(|d| d.severity >= fail_level);
         let strict_failure = args.strict_scope().is_some_and(|scope| {
             let fails = strict_check_fails(scope, &result.skip_summary);
             if fails {
@@ -432,6 +805,7 @@ pub fn run(args: Args) -> Result<i32> {
         };
     }
 
+    let discover_start = std::time::Instant::now();
     let discovered = discover_files(&args.paths, &config)?;
 
     // Build cop filters once (reused for --list-target-files, file filtering, and linting).
@@ -459,8 +833,21 @@ pub fn run(args: Args) -> Result<i32> {
         .cloned()
         .collect();
 
+    debug_log::emit(
+        "files_discovered",
+        serde_json::json!({
+            "count": effective_files.len(),
+            "excluded": discovered.files.len() - effective_files.len(),
+            "duration_ms": debug_log::millis(discover_start.elapsed()),
+        }),
+    );
+
     // --list-target-files (-L): print files that would be linted, then exit
     if args.list_target_files {
+        if args.estimate {
+            estimate::print_estimate(&effective_files, &config, &registry, &args);
+            return Ok(0);
+        }
         for file in &effective_files {
             println!("{}", file.display());
         }
@@ -472,6 +859,18 @@ pub fn run(args: Args) -> Result<i32> {
         eprintln!("debug: {} cops registered", registry.len());
     }
 
+    let selection_warnings = cop_selection::warnings(
+        &args,
+        &config,
+        &registry,
+        &tier_map,
+        &cop_filters,
+        &effective_files,
+    );
+    for warning in &selection_warnings {
+        eprintln!("warning: {warning}");
+    }
+
     let effective_discovered = DiscoveredFiles {
         files: effective_files,
         explicit: discovered.explicit,
@@ -486,6 +885,10 @@ pub fn run(args: Args) -> Result<i32> {
         &allowlist,
     );
 
+    if let Some(ref summary) = result.copy_summary {
+        eprintln!("{}", summary.message());
+    }
+
     // Print skip summary to stderr unless suppressed
     if !args.quiet_skips && !result.skip_summary.is_empty() {
         let s = &result.skip_summary;
@@ -506,12 +909,59 @@ pub fn run(args: Args) -> Result<i32> {
         );
     }
 
+    let (diagnostics, baseline_summary) = apply_baseline(
+        &args,
+        baseline.as_ref(),
+        result.diagnostics,
+        &effective_discovered.files,
+        &mut SourceCache::new(),
+    )?;
+
     let skip_summary = result.skip_summary.clone();
-    let mut formatter = create_formatter(&args.format);
+    let mut formatter = configured_formatter(&args, &config);
     formatter.set_skip_summary(result.skip_summary);
-    formatter.print(&result.diagnostics, &effective_discovered.files);
+    formatter.set_pragma_files(result.pragma_files);
+    formatter.set_inspected_count(result.file_count);
+    formatter.set_skipped_contextual(result.skipped_contextual_count);
+    formatter.set_warnings(selection_warnings);
+    if let Some(summary) = baseline_summary {
+        formatter.set_baseline_summary(summary);
+    }
+    print_report(
+        formatter.as_mut(),
+        &args,
+        fail_level,
+        &diagnostics,
+        &effective_discovered.files,
+    );
+
+    // --history-file: append this run's counts for trend tracking
+    if let Some(ref path) = args.history_file {
+        let fingerprint =
+            cache::compute_config_fingerprint(&config.precompute_cop_configs(&registry), &args);
+        let record = history::Record::new(
+            &diagnostics,
+            result.file_count,
+            result.corrected_count,
+            fingerprint,
+            run_start.elapsed(),
+        );
+        let previous = args
+            .history_summary
+            .then(|| history::last_record(path))
+            .flatten();
+        match history::append(path, &record) {
+            Ok(()) if args.history_summary => {
+                eprintln!("{}", history::summary(previous.as_ref(), &record));
+            }
+            Ok(()) => {}
+            Err(e) => eprintln!("warning: --history-file: {e:#}"),
+        }
+    }
 
-    let has_lint_failure = result.diagnostics.iter().any(|d| d.severity >= fail_level);
+    // A freshly generated baseline grandfathers everything it just recorded.
+    let has_lint_failure =
+        args.baseline_generate.is_none() && diagnostics.iter().any(|d| d.severity >= fail_level);
     let strict_failure = args.strict_scope().is_some_and(|scope| {
         let fails = strict_check_fails(scope, &skip_summary);
         if fails {
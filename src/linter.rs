@@ -1,5 +1,5 @@
-use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
@@ -7,9 +7,12 @@ use rayon::prelude::*;
 use ruby_prism::Visit;
 
 use crate::cache::ResultCache;
+use crate::changed_lines::FileChanges;
 use crate::cli::Args;
 use crate::config::{CopFilterSet, ResolvedConfig};
-use crate::cop::lint::redundant_cop_disable_directive::allow_redundant_disable_flagging_for_known_gap_cop;
+use crate::cop::lint::redundant_cop_disable_directive::{
+    allow_redundant_disable_flagging_for_known_gap_cop, redundant_directive_removals,
+};
 use crate::cop::registry::CopRegistry;
 use crate::cop::tiers::{SkipSummary, TierMap};
 use crate::cop::walker::BatchedCopWalker;
@@ -66,6 +69,27 @@ impl PhaseTimers {
         eprintln!("debug:   accounted:      {accounted:.0?} (sum of per-thread time)");
         eprintln!("debug:   wall clock:     {total:.0?}");
     }
+
+    /// The `phase_totals` event for `--debug-json`; same counters as `print_summary`.
+    fn emit_totals(&self, total: std::time::Duration, file_count: usize) {
+        use crate::debug_log::millis;
+        let ms =
+            |ns: &AtomicU64| millis(std::time::Duration::from_nanos(ns.load(Ordering::Relaxed)));
+        crate::debug_log::emit(
+            "phase_totals",
+            serde_json::json!({
+                "files": file_count,
+                "wall_ms": millis(total),
+                "file_io_ms": ms(&self.file_io_ns),
+                "parse_ms": ms(&self.parse_ns),
+                "codemap_ms": ms(&self.codemap_ns),
+                "cop_exec_ms": ms(&self.cop_exec_ns),
+                "cop_filter_ms": ms(&self.cop_filter_ns),
+                "cop_ast_ms": ms(&self.cop_ast_ns),
+                "disable_ms": ms(&self.disable_ns),
+            }),
+        );
+    }
 }
 
 /// Renamed cops snapshot from src/resources/renamed_cops.yml.
@@ -124,7 +148,16 @@ pub struct LintResult {
     pub diagnostics: Vec<Diagnostic>,
     pub file_count: usize,
     pub corrected_count: usize,
+    /// Offenses of `AutoCorrect: contextual` cops left uncorrected by a bulk -a/-A.
+    pub skipped_contextual_count: usize,
     pub skip_summary: SkipSummary,
+    /// Files whose `# nitrocop:only`/`# nitrocop:except` pragma narrowed the cops run.
+    pub pragma_files: Vec<PathBuf>,
+    /// The corrected source, from `lint_source` with autocorrect on; `None`
+    /// when nothing was corrected. `run_linter` writes files instead.
+    pub corrected_source: Option<Vec<u8>>,
+    /// What `--copy-to` wrote, from `run_linter`.
+    pub copy_summary: Option<crate::copy_to::CopySummary>,
 }
 
 /// Lint a single SourceFile (already loaded into memory). Used for --stdin mode.
@@ -139,7 +172,7 @@ pub fn lint_source(
     let cop_filters = config.build_cop_filters(registry, tier_map, args.preview);
     let base_configs = config.precompute_cop_configs(registry);
     let has_dir_overrides = config.has_dir_overrides();
-    let (diagnostics, _corrected_bytes, corrected_count) = lint_source_inner(
+    let (diagnostics, corrected_source, counts) = lint_source_inner(
         source,
         config,
         registry,
@@ -149,16 +182,80 @@ pub fn lint_source(
         &base_configs,
         has_dir_overrides,
         None,
+        None,
         allowlist,
     );
     let mut sorted = diagnostics;
     sorted.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
     let skip_summary = config.compute_skip_summary(registry, tier_map, args.preview);
+    let pragma_files =
+        if crate::parse::pragma::CopSelection::from_source(source.as_bytes()).is_some() {
+            vec![source.path.clone()]
+        } else {
+            Vec::new()
+        };
     LintResult {
         diagnostics: sorted,
         file_count: 1,
-        corrected_count,
+        corrected_count: counts.corrected,
+        skipped_contextual_count: counts.skipped_contextual,
         skip_summary,
+        pragma_files,
+        corrected_source,
+        copy_summary: None,
+    }
+}
+
+/// Why the result cache is off for a run, or `None` when it is on. The cache
+/// is enabled by default; `--no-cache`, `--cache false`, and autocorrect
+/// disable it. ERB and Markdown modes change how those files are linted but
+/// aren't part of the cache key, so they disable it too.
+pub(crate) fn cache_disabled_reason(config: &ResolvedConfig, args: &Args) -> Option<&'static str> {
+    if args.no_cache {
+        Some("--no-cache")
+    } else if args.no_parallel {
+        Some("--no-parallel")
+    } else if args.cache != "true" {
+        Some("--cache false")
+    } else if args.stdin.is_some() {
+        Some("--stdin")
+    } else if args.autocorrect_mode() != crate::cli::AutocorrectMode::Off {
+        Some("autocorrect")
+    } else if config.has_dir_overrides() {
+        Some("directory-specific configs")
+    } else if config.erb_enabled() {
+        Some("ERB mode")
+    } else if config.markdown_enabled() {
+        Some("Markdown mode")
+    } else {
+        None
+    }
+}
+
+/// The result cache for a run, keyed by the precomputed cop configs.
+pub(crate) fn open_result_cache(
+    config: &ResolvedConfig,
+    args: &Args,
+    base_configs: &[CopConfig],
+) -> ResultCache {
+    match cache_disabled_reason(config, args) {
+        None => {
+            if args.debug {
+                eprintln!("debug: result cache enabled");
+            }
+            ResultCache::new(
+                env!("CARGO_PKG_VERSION"),
+                base_configs,
+                args,
+                config.preview_cops(),
+            )
+        }
+        Some(reason) => {
+            if args.debug {
+                eprintln!("debug: result cache disabled ({reason})");
+            }
+            ResultCache::disabled()
+        }
     }
 }
 
@@ -172,6 +269,13 @@ pub fn run_linter(
 ) -> LintResult {
     let files = &discovered.files;
     let wall_start = std::time::Instant::now();
+    let (unique_files, aliases) = group_by_canonical_path(files);
+    if args.debug && !aliases.is_empty() {
+        eprintln!(
+            "debug: {} paths reach an already-listed file, reusing its results",
+            aliases.len()
+        );
+    }
 
     // Initialize schema (db/schema.rb) for schema-aware cops
     crate::schema::init(config.config_dir());
@@ -182,31 +286,24 @@ pub fn run_linter(
     let base_configs = config.precompute_cop_configs(registry);
     let has_dir_overrides = config.has_dir_overrides();
 
-    // Result cache: enabled by default, disable with --no-cache, --cache false,
-    // or autocorrect.
-    let cache_enabled = args.cache == "true"
-        && !args.no_cache
-        && args.stdin.is_none()
-        && args.autocorrect_mode() == crate::cli::AutocorrectMode::Off;
-    let cache_enabled = cache_enabled && !has_dir_overrides;
-    let cache = if cache_enabled {
-        let c = ResultCache::new(env!("CARGO_PKG_VERSION"), &base_configs, args);
-        if args.debug {
-            eprintln!("debug: result cache enabled");
-        }
-        c
-    } else {
-        if args.debug && args.no_cache {
-            eprintln!("debug: result cache disabled (--no-cache)");
-        } else if args.debug && args.cache != "true" {
-            eprintln!("debug: result cache disabled (--cache false)");
-        } else if args.debug && has_dir_overrides {
-            eprintln!("debug: result cache disabled (directory-specific configs)");
-        }
-        ResultCache::disabled()
-    };
+    let cache = open_result_cache(config, args, &base_configs);
+
+    // --autocorrect-changed-lines: read the changed lines once. If git fails,
+    // fall back to no changed lines so nothing outside the diff is corrected.
+    let diff_changes = args
+        .autocorrect_changed_lines
+        .as_deref()
+        .filter(|_| args.autocorrect_mode() != crate::cli::AutocorrectMode::Off)
+        .map(|git_ref| {
+            crate::changed_lines::DiffChanges::from_git(git_ref).unwrap_or_else(|e| {
+                eprintln!(
+                    "warning: --autocorrect-changed-lines: {e:#}; no corrections will be applied"
+                );
+                crate::changed_lines::DiffChanges::default()
+            })
+        });
 
-    let timers = if args.debug {
+    let timers = if args.debug || crate::debug_log::enabled() {
         Some(PhaseTimers::new())
     } else {
         None
@@ -217,44 +314,112 @@ pub fn run_linter(
     let cache_misses = std::sync::atomic::AtomicUsize::new(0);
     let found_offense = AtomicBool::new(false);
     let total_corrected = std::sync::atomic::AtomicUsize::new(0);
+    let total_skipped_contextual = std::sync::atomic::AtomicUsize::new(0);
+    let pragma_files = std::sync::Mutex::new(Vec::new());
+    let copy_to = crate::copy_to::CopyTo::from_args(args)
+        .filter(|_| args.autocorrect_mode() != crate::cli::AutocorrectMode::Off);
+
+    if args.debug {
+        let cop_names: Vec<&str> = cop_filters
+            .universal_cop_indices()
+            .iter()
+            .chain(cop_filters.pattern_cop_indices())
+            .map(|&i| registry.cops()[i].name())
+            .filter(|name| {
+                (args.only.is_empty() || args.only.iter().any(|o| o == name))
+                    && !args.except.iter().any(|e| e == name)
+            })
+            .collect();
+        let fingerprint = crate::cache::compute_run_fingerprint(
+            env!("CARGO_PKG_VERSION"),
+            &base_configs,
+            args,
+            files,
+            &cop_names,
+        );
+        eprintln!(
+            "debug: run fingerprint: {fingerprint} ({} files, {} cops)",
+            files.len(),
+            cop_names.len()
+        );
+    }
 
-    let diagnostics: Vec<Diagnostic> = files
-        .par_iter()
-        .flat_map(|path| {
-            // --fail-fast: skip remaining files once an offense is found
-            if args.fail_fast && found_offense.load(Ordering::Relaxed) {
-                return Vec::new();
-            }
-            let result = lint_file(
-                path,
-                config,
-                registry,
-                args,
-                tier_map,
-                &cop_filters,
-                &base_configs,
-                has_dir_overrides,
-                timers.as_ref(),
-                &cache,
-                &cache_stat_hits,
-                &cache_content_hits,
-                &cache_misses,
-                &discovered.explicit,
-                &total_corrected,
-                allowlist,
+    let lint_one = |path: &PathBuf| {
+        // --fail-fast: skip remaining files once an offense is found
+        if args.fail_fast && found_offense.load(Ordering::Relaxed) {
+            return Vec::new();
+        }
+        let file_start = std::time::Instant::now();
+        let result = lint_file(
+            path,
+            config,
+            registry,
+            args,
+            tier_map,
+            &cop_filters,
+            &base_configs,
+            has_dir_overrides,
+            timers.as_ref(),
+            &cache,
+            &cache_stat_hits,
+            &cache_content_hits,
+            &cache_misses,
+            &discovered.explicit,
+            &total_corrected,
+            &total_skipped_contextual,
+            &pragma_files,
+            diff_changes.as_ref(),
+            copy_to.as_ref(),
+            allowlist,
+        );
+        if args.fail_fast && !result.is_empty() {
+            found_offense.store(true, Ordering::Relaxed);
+        }
+        if crate::debug_log::enabled() {
+            crate::debug_log::emit(
+                "file_linted",
+                serde_json::json!({
+                    "path": path.display().to_string(),
+                    "duration_ms": crate::debug_log::millis(file_start.elapsed()),
+                    "offense_count": result.len(),
+                }),
             );
-            if args.fail_fast && !result.is_empty() {
-                found_offense.store(true, Ordering::Relaxed);
-            }
-            result
-        })
-        .collect();
+        }
+        result
+    };
+
+    let diagnostics: Vec<Diagnostic> = if args.no_parallel {
+        // --no-parallel: one file at a time in sorted path order, so
+        // --fail-fast and any order-dependent behavior are reproducible.
+        let mut ordered = unique_files.clone();
+        ordered.sort();
+        let run =
+            || -> Vec<Diagnostic> { ordered.iter().flat_map(|&path| lint_one(path)).collect() };
+        // A single worker keeps the 32 MB stack the global pool is built with.
+        match rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .stack_size(32 * 1024 * 1024)
+            .build()
+        {
+            Ok(pool) => pool.install(run),
+            Err(_) => run(),
+        }
+    } else {
+        unique_files
+            .par_iter()
+            .flat_map(|&path| lint_one(path))
+            .collect()
+    };
 
     let mut sorted = diagnostics;
+    copy_to_aliases(&mut sorted, &aliases);
     sorted.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
 
     if let Some(ref t) = timers {
-        t.print_summary(wall_start.elapsed(), files.len());
+        if args.debug {
+            t.print_summary(wall_start.elapsed(), unique_files.len());
+        }
+        t.emit_totals(wall_start.elapsed(), unique_files.len());
     }
 
     if args.debug && cache.is_enabled() {
@@ -352,13 +517,83 @@ pub fn run_linter(
         );
     }
 
+    let copy_summary = copy_to.map(|copy_to| copy_to.finish(&unique_files));
     let corrected_count = total_corrected.load(std::sync::atomic::Ordering::Relaxed);
+    let skipped_contextual_count =
+        total_skipped_contextual.load(std::sync::atomic::Ordering::Relaxed);
     let skip_summary = config.compute_skip_summary(registry, tier_map, args.preview);
+    let mut pragma_files = pragma_files.into_inner().unwrap();
+    pragma_files.sort();
     LintResult {
         diagnostics: sorted,
-        file_count: files.len(),
+        file_count: unique_files.len(),
         corrected_count,
+        skipped_contextual_count,
         skip_summary,
+        pragma_files,
+        corrected_source: None,
+        copy_summary,
+    }
+}
+
+/// Split `files` into the paths to lint and the paths that reach an
+/// already-listed file another way (a symlink and its target, `a.rb` and
+/// `./a.rb`). Each alias is paired with the path whose results it reuses.
+fn group_by_canonical_path(files: &[PathBuf]) -> (Vec<&PathBuf>, Vec<(&PathBuf, &PathBuf)>) {
+    let canonical: Vec<PathBuf> = files
+        .par_iter()
+        .map(|path| path.canonicalize().unwrap_or_else(|_| path.clone()))
+        .collect();
+    let mut first_seen: HashMap<&Path, &PathBuf> = HashMap::with_capacity(files.len());
+    let mut unique = Vec::with_capacity(files.len());
+    let mut aliases = Vec::new();
+    for (path, canonical) in files.iter().zip(&canonical) {
+        match first_seen.get(canonical.as_path()) {
+            Some(&primary) => aliases.push((path, primary)),
+            None => {
+                first_seen.insert(canonical, path);
+                unique.push(path);
+            }
+        }
+    }
+    (unique, aliases)
+}
+
+/// Report the diagnostics of each linted file again under its alias paths.
+fn copy_to_aliases(diagnostics: &mut Vec<Diagnostic>, aliases: &[(&PathBuf, &PathBuf)]) {
+    if aliases.is_empty() {
+        return;
+    }
+    let mut aliases_by_primary: HashMap<String, Vec<String>> = HashMap::new();
+    for (alias, primary) in aliases {
+        aliases_by_primary
+            .entry(primary.display().to_string())
+            .or_default()
+            .push(alias.display().to_string());
+    }
+    let copies: Vec<Diagnostic> = diagnostics
+        .iter()
+        .flat_map(|d| {
+            aliases_by_primary
+                .get(&d.path)
+                .into_iter()
+                .flatten()
+                .map(|alias| Diagnostic {
+                    path: alias.clone(),
+                    ..d.clone()
+                })
+        })
+        .collect();
+    diagnostics.extend(copies);
+}
+
+/// `--debug-json` event for a result cache lookup.
+fn emit_cache_lookup(path: &Path, result: &str) {
+    if crate::debug_log::enabled() {
+        crate::debug_log::emit(
+            "cache_lookup",
+            serde_json::json!({ "path": path.display().to_string(), "result": result }),
+        );
     }
 }
 
@@ -379,6 +614,10 @@ fn lint_file(
     cache_misses: &std::sync::atomic::AtomicUsize,
     explicit_files: &HashSet<std::path::PathBuf>,
     total_corrected: &std::sync::atomic::AtomicUsize,
+    total_skipped_contextual: &std::sync::atomic::AtomicUsize,
+    pragma_files: &std::sync::Mutex<Vec<PathBuf>>,
+    diff_changes: Option<&crate::changed_lines::DiffChanges>,
+    copy_to: Option<&crate::copy_to::CopyTo>,
     allowlist: &crate::cop::autocorrect_allowlist::AutocorrectAllowlist,
 ) -> Vec<Diagnostic> {
     use crate::cache::CacheLookup;
@@ -403,10 +642,12 @@ fn lint_file(
         return Vec::new();
     }
 
-    // Tier 1: stat check (mtime + size) — no file read needed
-    if cache.is_enabled() {
+    // Tier 1: stat check (mtime + size) — no file read needed. `--paranoid`
+    // skips it, so every file is read and checked by content.
+    if cache.is_enabled() && !args.paranoid {
         if let CacheLookup::StatHit(cached) = cache.get_by_stat(path) {
             cache_stat_hits.fetch_add(1, Ordering::Relaxed);
+            emit_cache_lookup(path, "stat_hit");
             return cached;
         }
     }
@@ -432,6 +673,7 @@ fn lint_file(
     if std::str::from_utf8(source.as_bytes()).is_err()
         && !has_encoding_magic_comment(source.as_bytes())
     {
+        let lint_start = std::time::Instant::now();
         let result = emit_invalid_utf8_diagnostic(
             &source,
             config,
@@ -442,21 +684,33 @@ fn lint_file(
             args,
         );
         if cache.is_enabled() {
-            cache.put(path, source.as_bytes(), &result);
+            cache.put(path, source.as_bytes(), &result, lint_start.elapsed());
         }
         return result;
     }
 
+    // Files with a cop selection pragma are never cached, so the pragma is
+    // always seen and reported in the JSON metadata.
+    let has_pragma = crate::parse::pragma::CopSelection::from_source(source.as_bytes()).is_some();
+    if has_pragma {
+        pragma_files.lock().unwrap().push(path.to_path_buf());
+    }
+    let use_cache = cache.is_enabled() && !has_pragma;
+
     // Tier 2: content hash check — file was read, mtime didn't match
-    if cache.is_enabled() {
+    if use_cache {
         if let CacheLookup::ContentHit(cached) = cache.get_by_content(path, source.as_bytes()) {
             cache_content_hits.fetch_add(1, Ordering::Relaxed);
+            emit_cache_lookup(path, "content_hit");
             return cached;
         }
         cache_misses.fetch_add(1, Ordering::Relaxed);
+        emit_cache_lookup(path, "miss");
     }
 
-    let (result, corrected_bytes, corrected_count) = lint_source_inner(
+    let changed_lines = diff_changes.map(|changes| changes.for_file(path));
+    let lint_start = std::time::Instant::now();
+    let (result, corrected_bytes, counts) = lint_source_inner(
         &source,
         config,
         registry,
@@ -466,15 +720,24 @@ fn lint_file(
         base_configs,
         has_dir_overrides,
         timers,
+        changed_lines.as_ref(),
         allowlist,
     );
-    if corrected_count > 0 {
-        total_corrected.fetch_add(corrected_count, Ordering::Relaxed);
+    if counts.corrected > 0 {
+        total_corrected.fetch_add(counts.corrected, Ordering::Relaxed);
+    }
+    if counts.skipped_contextual > 0 {
+        total_skipped_contextual.fetch_add(counts.skipped_contextual, Ordering::Relaxed);
     }
 
-    // Write corrected bytes to disk if autocorrect produced changes
+    // Write corrected bytes to disk if autocorrect produced changes, or
+    // into the --copy-to tree
     if let Some(bytes) = corrected_bytes {
-        if let Err(e) = std::fs::write(path, &bytes) {
+        if let Some(copy_to) = copy_to {
+            if let Err(e) = copy_to.write_corrected(path, &bytes) {
+                eprintln!("error: {e:#}");
+            }
+        } else if let Err(e) = std::fs::write(path, &bytes) {
             eprintln!(
                 "error: failed to write corrected file {}: {e}",
                 path.display()
@@ -483,8 +746,8 @@ fn lint_file(
     }
 
     // Store result in cache
-    if cache.is_enabled() {
-        cache.put(path, source.as_bytes(), &result);
+    if use_cache {
+        cache.put(path, source.as_bytes(), &result, lint_start.elapsed());
     }
 
     result
@@ -578,7 +841,7 @@ const REDUNDANT_DISABLE_SKIP_COPS: &[&str] = &[
     // Rake
     "Rake/DuplicateNamespace", // FN=4
     // Security
-    "Security/YAMLLoad", // stub (never fires)
+    "Security/YAMLLoad", // only runs for TargetRubyVersion <= 3.0
     // Style
     "Style/AccessModifierDeclarations",   // FN=16
     "Style/ConditionalAssignment",        // FN=5951
@@ -785,8 +1048,17 @@ fn validate_corrected_bytes(
     Some(current_bytes)
 }
 
-/// Returns (diagnostics, corrected_bytes, corrected_count).
-/// corrected_count is the total number of offenses corrected across all iterations.
+/// Offense counts from an autocorrect run.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct CorrectionCounts {
+    /// Offenses corrected across all iterations.
+    pub corrected: usize,
+    /// Offenses of `AutoCorrect: contextual` cops whose correction was held
+    /// back because the cop was not targeted with `--autocorrect-cop`.
+    pub skipped_contextual: usize,
+}
+
+/// Returns (diagnostics, corrected_bytes, correction counts).
 #[allow(clippy::too_many_arguments)] // internal lint pipeline threading shared state
 pub(crate) fn lint_source_inner(
     source: &SourceFile,
@@ -798,8 +1070,73 @@ pub(crate) fn lint_source_inner(
     base_configs: &[CopConfig],
     has_dir_overrides: bool,
     timers: Option<&PhaseTimers>,
+    changed_lines: Option<&FileChanges>,
+    allowlist: &crate::cop::autocorrect_allowlist::AutocorrectAllowlist,
+) -> (Vec<Diagnostic>, Option<Vec<u8>>, CorrectionCounts) {
+    if config.erb_enabled() && crate::erb::is_erb_file(&source.path) {
+        let diags = lint_erb_source(
+            source,
+            config,
+            registry,
+            args,
+            tier_map,
+            cop_filters,
+            base_configs,
+            has_dir_overrides,
+            timers,
+            allowlist,
+        );
+        return (diags, None, CorrectionCounts::default());
+    }
+    if config.markdown_enabled() && crate::markdown::is_markdown_file(&source.path) {
+        return lint_markdown_source(
+            source,
+            config,
+            registry,
+            args,
+            tier_map,
+            cop_filters,
+            base_configs,
+            has_dir_overrides,
+            timers,
+            changed_lines,
+            allowlist,
+        );
+    }
+    lint_ruby_source(
+        source,
+        config,
+        registry,
+        args,
+        tier_map,
+        cop_filters,
+        base_configs,
+        has_dir_overrides,
+        timers,
+        changed_lines,
+        allowlist,
+    )
+}
+
+/// Upper bound on autocorrect passes over one file before giving up on
+/// reaching a fixed point.
+pub(crate) const MAX_AUTOCORRECT_PASSES: usize = 200;
+
+/// `lint_source_inner` for a Ruby source, including the autocorrect loop.
+#[allow(clippy::too_many_arguments)] // internal lint pipeline threading shared state
+fn lint_ruby_source(
+    source: &SourceFile,
+    config: &ResolvedConfig,
+    registry: &CopRegistry,
+    args: &Args,
+    tier_map: &TierMap,
+    cop_filters: &CopFilterSet,
+    base_configs: &[CopConfig],
+    has_dir_overrides: bool,
+    timers: Option<&PhaseTimers>,
+    changed_lines: Option<&FileChanges>,
     allowlist: &crate::cop::autocorrect_allowlist::AutocorrectAllowlist,
-) -> (Vec<Diagnostic>, Option<Vec<u8>>, usize) {
+) -> (Vec<Diagnostic>, Option<Vec<u8>>, CorrectionCounts) {
     let autocorrect_mode = args.autocorrect_mode();
 
     if autocorrect_mode == crate::cli::AutocorrectMode::Off {
@@ -817,7 +1154,7 @@ pub(crate) fn lint_source_inner(
             autocorrect_mode,
             allowlist,
         );
-        return (diags, None, 0);
+        return (diags, None, CorrectionCounts::default());
     }
 
     // Autocorrect iteration loop
@@ -825,12 +1162,23 @@ pub(crate) fn lint_source_inner(
     let mut current_bytes = original_bytes.to_vec();
     let path = source.path.clone();
     let mut corrected_diags: Vec<Diagnostic> = Vec::new();
+    // --autocorrect-changed-lines: the changed lines of `current_bytes`, and
+    // the corrections skipped in the latest pass for straddling them.
+    let mut changed_lines = changed_lines.cloned();
+    let mut skipped_notes: Vec<String> = Vec::new();
+    let held_back = held_back_cops(
+        source,
+        config,
+        registry,
+        args,
+        base_configs,
+        has_dir_overrides,
+    );
+    let mut skipped_contextual = 0;
 
-    const MAX_ITERATIONS: usize = 200;
-
-    for _iteration in 0..MAX_ITERATIONS {
+    for _iteration in 0..MAX_AUTOCORRECT_PASSES {
         let iter_source = SourceFile::from_vec(path.clone(), current_bytes.clone());
-        let (diags, corrections) = lint_source_once(
+        let (mut diags, mut corrections) = lint_source_once(
             &iter_source,
             config,
             registry,
@@ -843,15 +1191,26 @@ pub(crate) fn lint_source_inner(
             autocorrect_mode,
             allowlist,
         );
+        skipped_contextual = hold_back_corrections(&held_back, &mut diags, &mut corrections);
+        if let Some(ref changed) = changed_lines {
+            skipped_notes =
+                restrict_to_changed_lines(&iter_source, changed, &mut diags, &mut corrections);
+        }
 
         if corrections.is_empty() {
             // Converged — no more corrections. Merge corrected diagnostics from
             // earlier iterations with the remaining diagnostics from this pass.
+            for note in &skipped_notes {
+                eprintln!("{note}");
+            }
             let mut all_diags = corrected_diags;
             all_diags.extend(diags);
-            let total_corrected = all_diags.iter().filter(|d| d.corrected).count();
+            let counts = CorrectionCounts {
+                corrected: all_diags.iter().filter(|d| d.corrected).count(),
+                skipped_contextual,
+            };
             let corrected_bytes = validate_corrected_bytes(original_bytes, current_bytes, &path);
-            return (all_diags, corrected_bytes, total_corrected);
+            return (all_diags, corrected_bytes, counts);
         }
 
         // Collect corrected diagnostics from this iteration
@@ -859,11 +1218,17 @@ pub(crate) fn lint_source_inner(
 
         let correction_set = crate::correction::CorrectionSet::from_vec(corrections);
         let new_bytes = correction_set.apply(&current_bytes);
+        if let Some(ref mut changed) = changed_lines {
+            *changed = changed.after_corrections(&iter_source, &correction_set);
+        }
 
         if new_bytes == current_bytes {
             // Source unchanged despite corrections — bail to avoid infinite loop.
-            let total_corrected = corrected_diags.iter().filter(|d| d.corrected).count();
-            return (corrected_diags, None, total_corrected);
+            let counts = CorrectionCounts {
+                corrected: corrected_diags.iter().filter(|d| d.corrected).count(),
+                skipped_contextual,
+            };
+            return (corrected_diags, None, counts);
         }
 
         current_bytes = new_bytes;
@@ -886,9 +1251,256 @@ pub(crate) fn lint_source_inner(
     );
     let mut all_diags = corrected_diags;
     all_diags.extend(diags);
-    let total_corrected = all_diags.iter().filter(|d| d.corrected).count();
+    let counts = CorrectionCounts {
+        corrected: all_diags.iter().filter(|d| d.corrected).count(),
+        skipped_contextual,
+    };
     let corrected_bytes = validate_corrected_bytes(original_bytes, current_bytes, &path);
-    (all_diags, corrected_bytes, total_corrected)
+    (all_diags, corrected_bytes, counts)
+}
+
+/// `--autocorrect-changed-lines`: drop the corrections that do not lie fully
+/// on changed lines, and un-mark their offenses as corrected. Returns a note
+/// for each dropped correction that straddles changed and unchanged lines.
+fn restrict_to_changed_lines(
+    source: &SourceFile,
+    changed: &FileChanges,
+    diagnostics: &mut [Diagnostic],
+    corrections: &mut Vec<crate::correction::Correction>,
+) -> Vec<String> {
+    use crate::changed_lines::Coverage;
+
+    let mut kept_spans: Vec<(&'static str, (usize, usize))> = Vec::new();
+    let mut dropped_spans = Vec::new();
+    let mut notes = Vec::new();
+    corrections.retain(|c| {
+        let span = c.line_span(source);
+        match changed.coverage(span.0, span.1) {
+            Coverage::Full => {
+                kept_spans.push((c.cop_name, span));
+                true
+            }
+            coverage => {
+                if coverage == Coverage::Partial {
+                    notes.push(format!(
+                        "note: {}:{}: {} correction not applied: it spans changed and unchanged lines",
+                        source.path.display(),
+                        span.0,
+                        c.cop_name
+                    ));
+                }
+                dropped_spans.push((c.cop_name, span));
+                false
+            }
+        }
+    });
+    notes.dedup();
+    if dropped_spans.is_empty() {
+        return notes;
+    }
+    let in_span = |line: usize, (first, last): (usize, usize)| first <= line && line <= last;
+    for d in diagnostics.iter_mut().filter(|d| d.corrected) {
+        let line = d.location.line;
+        let dropped = dropped_spans
+            .iter()
+            .any(|&(cop, span)| cop == d.cop_name && in_span(line, span));
+        let kept = kept_spans
+            .iter()
+            .any(|&(cop, span)| cop == d.cop_name && in_span(line, span));
+        if dropped && !kept {
+            d.corrected = false;
+        }
+    }
+    notes
+}
+
+/// Cops whose corrections are reported but not applied, mapped to whether
+/// the cop is `AutoCorrect: contextual`. Contextual cops only apply their
+/// corrections when targeted with `--autocorrect-cop`; with
+/// `--autocorrect-cop`, every cop it does not name is held back too.
+fn held_back_cops(
+    source: &SourceFile,
+    config: &ResolvedConfig,
+    registry: &CopRegistry,
+    args: &Args,
+    base_configs: &[CopConfig],
+    has_dir_overrides: bool,
+) -> HashMap<&'static str, bool> {
+    let effective_config = if has_dir_overrides {
+        config.effective_config_for_file(&source.path)
+    } else {
+        None
+    };
+    let owned_base_configs;
+    let active_base_configs = if let Some(ref file_config) = effective_config {
+        owned_base_configs = file_config.precompute_cop_configs(registry);
+        owned_base_configs.as_slice()
+    } else {
+        base_configs
+    };
+    registry
+        .cops()
+        .iter()
+        .zip(active_base_configs)
+        .filter(|(cop, _)| cop.supports_autocorrect())
+        .filter_map(|(cop, cop_config)| {
+            let name = cop.name();
+            let contextual = cop_config.is_contextual_autocorrect();
+            let held = if args.autocorrect_cop.is_empty() {
+                contextual
+            } else {
+                !args.autocorrect_cop.iter().any(|c| c == name)
+            };
+            held.then_some((name, contextual))
+        })
+        .collect()
+}
+
+/// Drop the corrections of held-back cops and un-mark their offenses as
+/// corrected. Returns how many of those offenses belong to contextual cops.
+fn hold_back_corrections(
+    held_back: &HashMap<&'static str, bool>,
+    diagnostics: &mut [Diagnostic],
+    corrections: &mut Vec<crate::correction::Correction>,
+) -> usize {
+    if held_back.is_empty() {
+        return 0;
+    }
+    corrections.retain(|c| !held_back.contains_key(c.cop_name));
+    let mut skipped_contextual = 0;
+    for d in diagnostics.iter_mut().filter(|d| d.corrected) {
+        if let Some(&contextual) = held_back.get(d.cop_name.as_str()) {
+            d.corrected = false;
+            if contextual {
+                skipped_contextual += 1;
+            }
+        }
+    }
+    skipped_contextual
+}
+
+/// Lint the Ruby embedded in an ERB template. Cops run once (no autocorrect)
+/// on the virtual source from `erb::virtual_source`; only offenses from
+/// fragment-safe cops are kept, mapped back to template coordinates.
+#[allow(clippy::too_many_arguments)] // internal lint pipeline threading shared state
+fn lint_erb_source(
+    template: &SourceFile,
+    config: &ResolvedConfig,
+    registry: &CopRegistry,
+    args: &Args,
+    tier_map: &TierMap,
+    cop_filters: &CopFilterSet,
+    base_configs: &[CopConfig],
+    has_dir_overrides: bool,
+    timers: Option<&PhaseTimers>,
+    allowlist: &crate::cop::autocorrect_allowlist::AutocorrectAllowlist,
+) -> Vec<Diagnostic> {
+    let source = SourceFile::from_vec(
+        template.path.clone(),
+        crate::erb::virtual_source(template.as_bytes()),
+    );
+    let (mut diags, _) = lint_source_once(
+        &source,
+        config,
+        registry,
+        args,
+        tier_map,
+        cop_filters,
+        base_configs,
+        has_dir_overrides,
+        timers,
+        crate::cli::AutocorrectMode::Off,
+        allowlist,
+    );
+    diags.retain(|d| crate::erb::is_erb_cop(&d.cop_name));
+    for d in &mut diags {
+        d.location = crate::erb::template_location(&source, template, d.location.clone());
+        d.end_column = None;
+    }
+    diags
+}
+
+/// Lint the Ruby code blocks of a Markdown file as independent snippets.
+/// Offense lines (and columns, for indented fences) are shifted to the
+/// Markdown file, and autocorrected snippets are spliced back into their
+/// blocks. Snippets with syntax errors are skipped, as rubocop-md does.
+#[allow(clippy::too_many_arguments)] // internal lint pipeline threading shared state
+fn lint_markdown_source(
+    markdown: &SourceFile,
+    config: &ResolvedConfig,
+    registry: &CopRegistry,
+    args: &Args,
+    tier_map: &TierMap,
+    cop_filters: &CopFilterSet,
+    base_configs: &[CopConfig],
+    has_dir_overrides: bool,
+    timers: Option<&PhaseTimers>,
+    changed_lines: Option<&FileChanges>,
+    allowlist: &crate::cop::autocorrect_allowlist::AutocorrectAllowlist,
+) -> (Vec<Diagnostic>, Option<Vec<u8>>, CorrectionCounts) {
+    let bytes = markdown.as_bytes();
+    let mut diagnostics = Vec::new();
+    let mut counts = CorrectionCounts::default();
+    let mut corrected = Vec::with_capacity(bytes.len());
+    let mut copied_to = 0;
+    let mut changed = false;
+
+    for block in crate::markdown::extract_code_blocks(bytes) {
+        let (snippet_bytes, stripped) = crate::markdown::snippet(bytes, &block);
+        let has_structural_errors = crate::parse::parse_source(&snippet_bytes)
+            .errors()
+            .any(|err| !is_semantic_parse_error(err.message()));
+        if has_structural_errors {
+            if args.debug {
+                eprintln!(
+                    "debug: {}:{}: skipping invalid Ruby code block",
+                    markdown.path.display(),
+                    block.first_line - 1
+                );
+            }
+            continue;
+        }
+
+        let snippet = SourceFile::from_vec(markdown.path.clone(), snippet_bytes);
+        let snippet_changes = changed_lines.map(|c| c.starting_at(block.first_line));
+        let (diags, corrected_bytes, snippet_counts) = lint_ruby_source(
+            &snippet,
+            config,
+            registry,
+            args,
+            tier_map,
+            cop_filters,
+            base_configs,
+            has_dir_overrides,
+            timers,
+            snippet_changes.as_ref(),
+            allowlist,
+        );
+        for mut d in diags {
+            if crate::markdown::is_skipped_cop(&d.cop_name) {
+                continue;
+            }
+            let indent = stripped.get(d.location.line - 1).copied().unwrap_or(0);
+            d.location.column += indent;
+            d.end_column = d.end_column.map(|c| c + indent);
+            d.location.line += block.first_line - 1;
+            diagnostics.push(d);
+        }
+        counts.corrected += snippet_counts.corrected;
+        counts.skipped_contextual += snippet_counts.skipped_contextual;
+        if let Some(snippet_corrected) = corrected_bytes {
+            corrected.extend_from_slice(&bytes[copied_to..block.start]);
+            corrected.extend(crate::markdown::reindent(&block, &snippet_corrected));
+            copied_to = block.end;
+            changed = true;
+        }
+    }
+
+    if !changed {
+        return (diagnostics, None, counts);
+    }
+    corrected.extend_from_slice(&bytes[copied_to..]);
+    (diagnostics, Some(corrected), counts)
 }
 
 /// Check if a Prism parse error is "semantic" — meaning the AST structure is still
@@ -961,6 +1573,7 @@ fn emit_syntax_diagnostics(
     if args.except.iter().any(|e| e == SYNTAX_COP) {
         return Vec::new();
     }
+    let severity = syntax_severity(effective_config.as_ref().unwrap_or(config));
     let src_bytes = source.as_bytes();
     let src_len = src_bytes.len();
     let ends_with_newline = src_bytes.last() == Some(&b'\n');
@@ -991,15 +1604,25 @@ fn emit_syntax_diagnostics(
         diagnostics.push(Diagnostic {
             path: source.path.display().to_string(),
             location: Location { line, column },
-            severity: Severity::Fatal,
+            severity,
             cop_name: SYNTAX_COP.to_string(),
             message: err.message().to_string(),
             corrected: false,
+            end_column: None,
         });
     }
     diagnostics
 }
 
+/// Severity of the Lint/Syntax diagnostics emitted outside the normal cop
+/// pipeline: the configured `Severity:` if any, else Fatal.
+fn syntax_severity(config: &ResolvedConfig) -> Severity {
+    config
+        .cop_config("Lint/Syntax")
+        .severity
+        .unwrap_or(Severity::Fatal)
+}
+
 /// Emit a single Lint/Syntax diagnostic for files with invalid UTF-8 bytes.
 ///
 /// RuboCop treats files with invalid byte sequences (and no encoding magic
@@ -1054,10 +1677,11 @@ pub(crate) fn emit_invalid_utf8_diagnostic(
     vec![Diagnostic {
         path: source.path.display().to_string(),
         location: Location { line: 1, column: 0 },
-        severity: Severity::Fatal,
+        severity: syntax_severity(effective_config.as_ref().unwrap_or(config)),
         cop_name: SYNTAX_COP.to_string(),
         message: "Invalid byte sequence in utf-8.".to_string(),
         corrected: false,
+        end_column: None,
     }]
 }
 
@@ -1095,6 +1719,10 @@ fn lint_source_once(
     let has_structural_errors = parse_result
         .errors()
         .any(|err| !is_semantic_parse_error(err.message()));
+    // `# nitrocop:only` / `# nitrocop:except` narrow the cops for this file.
+    let selection = crate::parse::pragma::CopSelection::from_source(source.as_bytes());
+    let selected = |name: &str| selection.as_ref().is_none_or(|s| s.allows(name));
+
     if has_structural_errors {
         if let Some(t) = timers {
             t.codemap_ns.fetch_add(0, Ordering::Relaxed);
@@ -1102,7 +1730,7 @@ fn lint_source_once(
 
         // Emit Lint/Syntax diagnostics for each structural parse error,
         // matching RuboCop which reports parser errors as Lint/Syntax offenses.
-        let syntax_diagnostics = emit_syntax_diagnostics(
+        let mut syntax_diagnostics = emit_syntax_diagnostics(
             source,
             &parse_result,
             registry,
@@ -1112,6 +1740,7 @@ fn lint_source_once(
             tier_map,
             args,
         );
+        syntax_diagnostics.retain(|d| selected(&d.cop_name));
         return (syntax_diagnostics, Vec::new());
     }
 
@@ -1162,8 +1791,10 @@ fn lint_source_once(
     // All cops ran if: (a) run_all_for_redundant mode, or (b) normal mode
     // with no --only/--except filters. In these cases, every enabled cop that
     // matched the file executed, so unused disable directives are reliable
-    // indicators of redundancy (modulo REDUNDANT_DISABLE_SKIP_COPS).
-    let all_cops_ran = run_all_for_redundant || (!has_only && args.except.is_empty());
+    // indicators of redundancy (modulo REDUNDANT_DISABLE_SKIP_COPS). A file
+    // pragma narrows the cops just like --only/--except.
+    let all_cops_ran =
+        selection.is_none() && (run_all_for_redundant || (!has_only && args.except.is_empty()));
 
     // Pass 1: Universal cops
     for &i in active_filters.universal_cop_indices() {
@@ -1175,13 +1806,14 @@ fn lint_source_once(
         if has_only && !run_all_for_redundant && !args.only.iter().any(|o| o == name) {
             continue;
         }
-        if args.except.iter().any(|e| e == name) {
+        if args.except.iter().any(|e| e == name) || !selected(name) {
             continue;
         }
 
         let cop_config = &active_base_configs[i];
 
         let should_correct = autocorrect_mode != crate::cli::AutocorrectMode::Off
+            && !run_all_for_redundant
             && cop.supports_autocorrect()
             && cop_config.should_autocorrect(autocorrect_mode)
             && (autocorrect_mode == crate::cli::AutocorrectMode::All || allowlist.contains(name));
@@ -1210,8 +1842,8 @@ fn lint_source_once(
         ast_cop_indices.push(i);
     }
 
-    // Pass 2: Pattern cops
-    for &i in active_filters.pattern_cop_indices() {
+    // Pass 2: Pattern cops, minus groups whose Include can't match this path
+    for i in active_filters.pattern_cop_indices_for(&source.path) {
         let cop = &cops[i];
         let name = cop.name();
         if name == REDUNDANT_DISABLE_COP {
@@ -1220,7 +1852,7 @@ fn lint_source_once(
         if has_only && !run_all_for_redundant && !args.only.iter().any(|o| o == name) {
             continue;
         }
-        if args.except.iter().any(|e| e == name) {
+        if args.except.iter().any(|e| e == name) || !selected(name) {
             continue;
         }
 
@@ -1231,6 +1863,7 @@ fn lint_source_once(
         let cop_config = &active_base_configs[i];
 
         let should_correct = autocorrect_mode != crate::cli::AutocorrectMode::Off
+            && !run_all_for_redundant
             && cop.supports_autocorrect()
             && cop_config.should_autocorrect(autocorrect_mode)
             && (autocorrect_mode == crate::cli::AutocorrectMode::All || allowlist.contains(name));
@@ -1296,7 +1929,9 @@ fn lint_source_once(
             .map(|&i| (&*registry.cops()[i] as &dyn Cop, &active_base_configs[i]))
             .collect();
         let mut walker = BatchedCopWalker::new(ast_cops, source, &parse_result);
-        if autocorrect_mode != crate::cli::AutocorrectMode::Off {
+        // In run_all_for_redundant mode the other cops only run to mark
+        // directives as used, so their corrections are not applied either.
+        if autocorrect_mode != crate::cli::AutocorrectMode::Off && !run_all_for_redundant {
             walker = walker.with_corrections();
         }
         walker.visit(&parse_result.node());
@@ -1337,15 +1972,22 @@ fn lint_source_once(
     if !args.ignore_disable_comments
         && disabled.has_directives()
         && !args.except.iter().any(|e| e == REDUNDANT_DISABLE_COP)
+        && selected(REDUNDANT_DISABLE_COP)
     {
-        let cop_enabled = registry
+        let redundant_idx = registry
             .cops()
             .iter()
-            .enumerate()
-            .find(|(_, c)| c.name() == REDUNDANT_DISABLE_COP)
-            .is_some_and(|(idx, _)| active_filters.is_cop_match(idx, &source.path));
-
-        if cop_enabled {
+            .position(|c| c.name() == REDUNDANT_DISABLE_COP)
+            .filter(|&idx| active_filters.is_cop_match(idx, &source.path));
+
+        if let Some(redundant_idx) = redundant_idx {
+            let should_correct = autocorrect_mode != crate::cli::AutocorrectMode::Off
+                && active_base_configs[redundant_idx].should_autocorrect(autocorrect_mode)
+                && (autocorrect_mode == crate::cli::AutocorrectMode::All
+                    || allowlist.contains(REDUNDANT_DISABLE_COP));
+            // Redundant directives by comment, with the index of their diagnostic.
+            let mut redundant_by_comment: BTreeMap<usize, Vec<(&DisableDirective, usize)>> =
+                BTreeMap::new();
             let redundant_disable_explicitly_selected =
                 args.only.iter().any(|o| o == REDUNDANT_DISABLE_COP);
             let redundant_check = RedundantDirectiveCheck {
@@ -1377,6 +2019,10 @@ fn lint_source_once(
                     "Unnecessary disabling of `{}`{}.",
                     directive.cop_name, suffix
                 );
+                redundant_by_comment
+                    .entry(directive.comment.0)
+                    .or_default()
+                    .push((directive, diagnostics.len()));
                 diagnostics.push(Diagnostic {
                     path: source.path_str().to_string(),
                     location: Location {
@@ -1387,8 +2033,38 @@ fn lint_source_once(
                     cop_name: REDUNDANT_DISABLE_COP.to_string(),
                     message,
                     corrected: false,
+                    end_column: None,
                 });
             }
+
+            if should_correct {
+                for (comment_start, redundant) in &redundant_by_comment {
+                    let names: Vec<(usize, usize)> = disabled
+                        .directives_in_comment(*comment_start)
+                        .map(|d| d.name_range)
+                        .collect();
+                    let redundant_names: Vec<(usize, usize)> =
+                        redundant.iter().map(|(d, _)| d.name_range).collect();
+                    let removals = redundant_directive_removals(
+                        source.as_bytes(),
+                        redundant[0].0.comment,
+                        &names,
+                        &redundant_names,
+                    );
+                    for (start, end) in removals {
+                        corrections.push(crate::correction::Correction {
+                            start,
+                            end,
+                            replacement: String::new(),
+                            cop_name: REDUNDANT_DISABLE_COP,
+                            cop_index: 0,
+                        });
+                    }
+                    for &(_, diag_idx) in redundant {
+                        diagnostics[diag_idx].corrected = true;
+                    }
+                }
+            }
         }
     }
     if let Some(t) = timers {
@@ -1396,9 +2072,34 @@ fn lint_source_once(
             .fetch_add(disable_start.elapsed().as_nanos() as u64, Ordering::Relaxed);
     }
 
+    apply_severity_overrides(&mut diagnostics, registry, active_base_configs);
+
     (diagnostics, corrections)
 }
 
+/// Replace the default severity of each diagnostic with the `Severity:` the
+/// user configured for its cop or department, if any.
+fn apply_severity_overrides(
+    diagnostics: &mut [Diagnostic],
+    registry: &CopRegistry,
+    base_configs: &[CopConfig],
+) {
+    if base_configs.iter().all(|c| c.severity.is_none()) {
+        return;
+    }
+    let overrides: HashMap<&str, Severity> = registry
+        .cops()
+        .iter()
+        .zip(base_configs)
+        .filter_map(|(cop, config)| config.severity.map(|s| (cop.name(), s)))
+        .collect();
+    for diagnostic in diagnostics {
+        if let Some(&severity) = overrides.get(diagnostic.cop_name.as_str()) {
+            diagnostic.severity = severity;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,231 @@
+//! Linting Ruby code blocks in Markdown files (`require: rubocop-md` /
+//! `--markdown`), following rubocop-md.
+//!
+//! Fenced code blocks tagged `ruby`, `rb` or `jruby` are linted one by one as
+//! independent snippets; every other part of the file, including fences in
+//! other languages, is left alone. Offenses are reported at their line in
+//! the Markdown file, and autocorrected snippets are written back into their
+//! fence. Snippets that aren't valid Ruby are skipped, as are cops that
+//! inspect a file as a whole (see `SKIPPED_COPS`).
+
+use std::path::Path;
+
+/// Fence info strings (first word, case-insensitive) treated as Ruby.
+const RUBY_LANGUAGES: &[&str] = &["ruby", "rb", "jruby"];
+
+/// Cops that don't apply to snippets: magic comments, blank lines at the
+/// start/end of the file, and the file name all belong to the Markdown file.
+const SKIPPED_COPS: &[&str] = &[
+    "Layout/EmptyLineAfterMagicComment",
+    "Layout/LeadingEmptyLines",
+    "Layout/TrailingEmptyLines",
+    "Lint/EmptyFile",
+    "Lint/ScriptPermission",
+    "Naming/FileName",
+    "Style/Encoding",
+    "Style/FrozenStringLiteralComment",
+];
+
+/// A Ruby code block. `start..end` is the byte range of its content lines
+/// (including their newlines), excluding the fences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// 1-based line of the first content line.
+    pub first_line: usize,
+    pub start: usize,
+    pub end: usize,
+    /// Indentation of the opening fence; up to this many leading spaces are
+    /// stripped from each content line, as in CommonMark.
+    pub indent: usize,
+}
+
+/// Whether a path is a Markdown file (`*.md`, `*.markdown`).
+pub fn is_markdown_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"))
+}
+
+/// Whether a cop is skipped for Markdown snippets.
+pub fn is_skipped_cop(name: &str) -> bool {
+    SKIPPED_COPS.contains(&name)
+}
+
+/// An opening or closing fence: indentation, fence character, run length and
+/// the rest of the line.
+fn parse_fence(line: &[u8]) -> Option<(usize, u8, usize, &[u8])> {
+    let indent = line.iter().take_while(|&&b| b == b' ').count();
+    if indent > 3 {
+        return None;
+    }
+    let fence_char = *line.get(indent)?;
+    if fence_char != b'`' && fence_char != b'~' {
+        return None;
+    }
+    let len = line[indent..]
+        .iter()
+        .take_while(|&&b| b == fence_char)
+        .count();
+    if len < 3 {
+        return None;
+    }
+    Some((indent, fence_char, len, &line[indent + len..]))
+}
+
+fn is_ruby_info(info: &[u8]) -> bool {
+    let info = info.trim_ascii();
+    let lang = info
+        .split(|b| b.is_ascii_whitespace())
+        .next()
+        .unwrap_or_default();
+    let Ok(lang) = std::str::from_utf8(lang) else {
+        return false;
+    };
+    RUBY_LANGUAGES
+        .iter()
+        .any(|ruby| ruby.eq_ignore_ascii_case(lang))
+}
+
+/// Find the Ruby code blocks of a Markdown document. An unclosed fence runs
+/// to the end of the document.
+pub fn extract_code_blocks(markdown: &[u8]) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    // (fence char, fence length, indent, is ruby, first content line, start)
+    let mut open: Option<(u8, usize, usize, bool, usize, usize)> = None;
+    let mut offset = 0;
+    for (idx, raw_line) in markdown.split_inclusive(|&b| b == b'\n').enumerate() {
+        let line_start = offset;
+        offset += raw_line.len();
+        let line = raw_line.strip_suffix(b"\n").unwrap_or(raw_line);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let fence = parse_fence(line);
+
+        match open {
+            None => {
+                let Some((indent, fence_char, len, info)) = fence else {
+                    continue;
+                };
+                if fence_char == b'`' && info.contains(&b'`') {
+                    continue;
+                }
+                open = Some((fence_char, len, indent, is_ruby_info(info), idx + 2, offset));
+            }
+            Some((fence_char, len, indent, is_ruby, first_line, start)) => {
+                let closes = fence.is_some_and(|(_, c, l, rest)| {
+                    c == fence_char && l >= len && rest.trim_ascii().is_empty()
+                });
+                if closes {
+                    if is_ruby {
+                        blocks.push(CodeBlock {
+                            first_line,
+                            start,
+                            end: line_start,
+                            indent,
+                        });
+                    }
+                    open = None;
+                }
+            }
+        }
+    }
+    if let Some((_, _, indent, true, first_line, start)) = open {
+        blocks.push(CodeBlock {
+            first_line,
+            start,
+            end: markdown.len(),
+            indent,
+        });
+    }
+    blocks
+}
+
+/// The Ruby source of a block, with the fence indentation stripped. Also
+/// returns how many bytes were stripped from each line.
+pub fn snippet(markdown: &[u8], block: &CodeBlock) -> (Vec<u8>, Vec<usize>) {
+    let mut source = Vec::with_capacity(block.end - block.start);
+    let mut stripped = Vec::new();
+    for line in markdown[block.start..block.end].split_inclusive(|&b| b == b'\n') {
+        let strip = line
+            .iter()
+            .take(block.indent)
+            .take_while(|&&b| b == b' ')
+            .count();
+        source.extend_from_slice(&line[strip..]);
+        stripped.push(strip);
+    }
+    (source, stripped)
+}
+
+/// Re-indent a corrected snippet to the block's fence indentation, for
+/// writing it back in place of the block's content.
+pub fn reindent(block: &CodeBlock, corrected: &[u8]) -> Vec<u8> {
+    if block.indent == 0 {
+        return corrected.to_vec();
+    }
+    let mut out = Vec::with_capacity(corrected.len());
+    for line in corrected.split_inclusive(|&b| b == b'\n') {
+        if !line.trim_ascii().is_empty() {
+            out.extend(std::iter::repeat_n(b' ', block.indent));
+        }
+        out.extend_from_slice(line);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_sources(markdown: &str) -> Vec<String> {
+        extract_code_blocks(markdown.as_bytes())
+            .iter()
+            .map(|b| String::from_utf8(snippet(markdown.as_bytes(), b).0).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn extracts_only_ruby_fences() {
+        let md = "# Title\n\n```ruby\nfoo(1)\n```\n\n```js\nfoo(2)\n```\n\n~~~rb\nbar\n~~~\n\n```\nplain\n```\n";
+        assert_eq!(block_sources(md), vec!["foo(1)\n", "bar\n"]);
+        let blocks = extract_code_blocks(md.as_bytes());
+        assert_eq!(blocks[0].first_line, 4);
+        assert_eq!(blocks[1].first_line, 12);
+    }
+
+    #[test]
+    fn closing_fence_must_match() {
+        let md = "````ruby\nx = <<~MD\n  ```\nMD\n````\n";
+        assert_eq!(block_sources(md), vec!["x = <<~MD\n  ```\nMD\n"]);
+    }
+
+    #[test]
+    fn info_string_variants() {
+        let md = "``` Ruby\na\n```\n```ruby title=\"x\"\nb\n```\n```{ruby}\nc\n```\n";
+        assert_eq!(block_sources(md), vec!["a\n", "b\n"]);
+    }
+
+    #[test]
+    fn unclosed_fence_runs_to_end() {
+        assert_eq!(block_sources("```ruby\nfoo\nbar"), vec!["foo\nbar"]);
+    }
+
+    #[test]
+    fn indented_fence_strips_and_restores_indentation() {
+        let md = "1. Step\n\n   ```ruby\n   if x\n     y\n   end\n   ```\n";
+        let blocks = extract_code_blocks(md.as_bytes());
+        let (source, stripped) = snippet(md.as_bytes(), &blocks[0]);
+        assert_eq!(source, b"if x\n  y\nend\n");
+        assert_eq!(stripped, vec![3, 3, 3]);
+        assert_eq!(
+            reindent(&blocks[0], b"if x\n  y\n\nend\n"),
+            b"   if x\n     y\n\n   end\n"
+        );
+    }
+
+    #[test]
+    fn markdown_file_detection() {
+        assert!(is_markdown_file(Path::new("README.md")));
+        assert!(is_markdown_file(Path::new("docs/guide.markdown")));
+        assert!(!is_markdown_file(Path::new("lib/foo.rb")));
+    }
+}
@@ -82,6 +82,11 @@ pub struct DisableDirective {
     pub column: usize,
     /// Whether this directive is inline (code before the `#` on the same line).
     pub is_inline: bool,
+    /// Byte range of the whole directive comment.
+    pub comment: (usize, usize),
+    /// Byte range of this cop name as written in the comment, including any
+    /// annotation such as `(RuboCop)`.
+    pub name_range: (usize, usize),
     /// The line range this directive covers (start_line, end_line) inclusive, 1-indexed.
     pub range: (usize, usize),
     /// Whether this directive actually suppressed at least one diagnostic.
@@ -282,7 +287,8 @@ impl DisabledRanges {
                 None => cop_list_raw,
             };
 
-            let cop_names: Vec<&str> = cop_list
+            // Each cop name paired with its raw token in the comment.
+            let cop_names: Vec<(&str, &str)> = cop_list
                 .split(',')
                 .map(|s| s.trim())
                 .filter(|s| !s.is_empty())
@@ -297,30 +303,43 @@ impl DisabledRanges {
                     // Strip parenthesized annotations like (RuboCop) in
                     // `# rubocop:disable Metrics/BlockLength(RuboCop)`.
                     // RuboCop accepts this syntax and ignores the annotation.
-                    match s.find('(') {
+                    let name = match s.find('(') {
                         Some(idx) => &s[..idx],
                         None => s,
-                    }
+                    };
+                    (s, name)
                 })
-                .filter(|s| !s.is_empty())
-                .map(|s| {
+                .filter(|(_, s)| !s.is_empty())
+                .map(|(raw, s)| {
                     // Handle wildcard department patterns like `Style/*` before
                     // trimming, since `*` would be stripped as non-identifier.
                     // Normalize to just the department name.
                     if let Some(dept) = s.strip_suffix("/*") {
-                        return dept;
+                        return (raw, dept);
                     }
                     // Strip trailing non-identifier chars (e.g., trailing `?` in
                     // `Naming/PredicatePrefix?`). RuboCop's regex stops at
                     // `[A-Za-z]\w+(/[A-Za-z]\w+)*` so trailing punctuation is ignored.
-                    s.trim_end_matches(|c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '/')
+                    let name = s.trim_end_matches(|c: char| {
+                        !c.is_ascii_alphanumeric() && c != '_' && c != '/'
+                    });
+                    (raw, name)
                 })
-                .filter(|s| !s.is_empty())
+                .filter(|(_, s)| !s.is_empty())
                 .collect();
 
+            let comment_range = (loc.start_offset(), loc.end_offset());
+            // The raw tokens are slices of `comment_str`, so their offset in the
+            // comment is the pointer difference.
+            let name_range = |raw: &str| {
+                let start =
+                    loc.start_offset() + (raw.as_ptr() as usize - comment_str.as_ptr() as usize);
+                (start, start + raw.len())
+            };
+
             match action {
                 "disable" | "todo" => {
-                    for &cop in &cop_names {
+                    for &(raw, cop) in &cop_names {
                         // Normalize Department::CopName -> Department/CopName
                         let cop = normalize_directive_cop_name(cop);
                         let key = registry_info.qualify(cop.as_str());
@@ -334,6 +353,8 @@ impl DisabledRanges {
                                 line,
                                 column: col,
                                 is_inline: true,
+                                comment: comment_range,
+                                name_range: name_range(raw),
                                 range,
                                 used: false,
                             });
@@ -358,6 +379,8 @@ impl DisabledRanges {
                                 line,
                                 column: col,
                                 is_inline: false,
+                                comment: comment_range,
+                                name_range: name_range(raw),
                                 range: (line, usize::MAX), // placeholder, updated on enable/EOF
                                 used: false,
                             });
@@ -373,7 +396,7 @@ impl DisabledRanges {
                     if is_inline {
                         continue;
                     }
-                    for &cop in &cop_names {
+                    for &(_, cop) in &cop_names {
                         // Normalize Department::CopName -> Department/CopName
                         let cop = normalize_directive_cop_name(cop);
                         let key = registry_info.qualify(cop.as_str());
@@ -541,6 +564,16 @@ impl DisabledRanges {
         self.directives.iter().filter(|d| !d.used)
     }
 
+    /// All disable directives parsed from the comment starting at `comment_start`.
+    pub fn directives_in_comment(
+        &self,
+        comment_start: usize,
+    ) -> impl Iterator<Item = &DisableDirective> {
+        self.directives
+            .iter()
+            .filter(move |d| d.comment.0 == comment_start)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.empty
     }
@@ -608,6 +641,20 @@ mod tests {
         assert!(!dr.is_disabled("Foo/Bar", 2));
     }
 
+    #[test]
+    fn directive_records_comment_and_name_ranges() {
+        let src = "x = 1 # rubocop:disable Foo/Bar, Baz/Qux(RuboCop) -- why\n";
+        let dr = disabled_ranges(src);
+        let directives: Vec<_> = dr.directives_in_comment(6).collect();
+        assert_eq!(directives.len(), 2);
+        assert_eq!(directives[0].comment, (6, src.len() - 1));
+        let names: Vec<&str> = directives
+            .iter()
+            .map(|d| &src[d.name_range.0..d.name_range.1])
+            .collect();
+        assert_eq!(names, vec!["Foo/Bar", "Baz/Qux(RuboCop)"]);
+    }
+
     #[test]
     fn block_disable_enable() {
         let src = "# rubocop:disable Foo/Bar\nx = 1\ny = 2\n# rubocop:enable Foo/Bar\nz = 3\n";
@@ -1,5 +1,6 @@
 pub mod codemap;
 pub mod directives;
+pub mod pragma;
 pub mod source;
 
 /// Parse Ruby source bytes using Prism.
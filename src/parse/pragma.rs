@@ -0,0 +1,246 @@
+//! Per-file cop selection pragmas.
+//!
+//! A `# nitrocop:only <cops>` or `# nitrocop:except <cops>` comment in the
+//! leading comment block of a file restricts which cops run on that file.
+//! Entries are cop names (`Lint/Syntax`) or departments (`Layout`), separated
+//! by commas, with an optional `-- comment` suffix:
+//!
+//! ```ruby
+//! # frozen_string_literal: true
+//! # nitrocop:only Lint -- generated by protoc
+//! ```
+//!
+//! The pragma only narrows the set of cops that would otherwise run; it never
+//! enables a cop. It composes with `--only`/`--except` (a cop must pass both)
+//! and inline disable directives still apply on top of it.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static PRAGMA_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^#\s*nitrocop\s*:\s*(only|except)\b(.*)$").unwrap());
+
+/// Whether a pragma keeps or drops the cops it lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PragmaMode {
+    Only,
+    Except,
+}
+
+impl PragmaMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PragmaMode::Only => "only",
+            PragmaMode::Except => "except",
+        }
+    }
+}
+
+/// A `# nitrocop:only`/`# nitrocop:except` comment as written in the source.
+#[derive(Debug, Clone)]
+pub struct PragmaComment<'a> {
+    /// 1-indexed line number.
+    pub line: usize,
+    /// 0-indexed column of the `#`.
+    pub column: usize,
+    pub mode: PragmaMode,
+    /// Everything after the mode keyword, untrimmed.
+    pub cop_list: &'a str,
+    /// Whether the comment sits in the leading comment block, where it takes effect.
+    pub in_header: bool,
+}
+
+/// Find every full-line pragma comment in the file, including misplaced ones.
+pub fn pragma_comments(source: &[u8]) -> Vec<PragmaComment<'_>> {
+    let mut comments = Vec::new();
+    let mut in_header = true;
+    for (i, line) in source.split(|&b| b == b'\n').enumerate() {
+        let Ok(line) = std::str::from_utf8(line) else {
+            in_header = false;
+            continue;
+        };
+        let trimmed = line.trim_start_matches('\u{feff}').trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if !trimmed.starts_with('#') {
+            in_header = false;
+            continue;
+        }
+        if let Some(caps) = PRAGMA_RE.captures(trimmed) {
+            let mode = match &caps[1] {
+                "only" => PragmaMode::Only,
+                _ => PragmaMode::Except,
+            };
+            comments.push(PragmaComment {
+                line: i + 1,
+                column: line.len() - line.trim_start().len(),
+                mode,
+                cop_list: caps.get(2).map_or("", |m| m.as_str()),
+                in_header,
+            });
+        }
+    }
+    comments
+}
+
+/// Parse a pragma's cop list (`Lint, Style/Foo -- reason`).
+///
+/// Returns `None` when the list is empty or malformed: a missing comma, a
+/// trailing comma, or a name that is not a `Department` or `Department/Cop`.
+pub fn parse_cop_list(list: &str) -> Option<Vec<String>> {
+    let list = match list.find("--") {
+        Some(idx) => &list[..idx],
+        None => list,
+    };
+    let list = list.trim();
+    if list.is_empty() {
+        return None;
+    }
+    let mut names = Vec::new();
+    for part in list.split(',') {
+        let part = part.trim();
+        let valid = !part.is_empty()
+            && !part.starts_with('/')
+            && !part.ends_with('/')
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '/' || c == '_');
+        if !valid {
+            return None;
+        }
+        names.push(part.to_string());
+    }
+    Some(names)
+}
+
+/// The cop subset selected by a file's pragmas.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CopSelection {
+    /// Cops or departments to keep; `None` keeps everything.
+    only: Option<Vec<String>>,
+    /// Cops or departments to drop.
+    except: Vec<String>,
+}
+
+impl CopSelection {
+    /// Read the pragmas in the file's leading comment block.
+    ///
+    /// Returns `None` when the file has no well-formed pragma. Several pragmas
+    /// accumulate: all `only` lists are merged, as are all `except` lists.
+    pub fn from_source(source: &[u8]) -> Option<Self> {
+        let mut selection: Option<Self> = None;
+        for comment in pragma_comments(source) {
+            if !comment.in_header {
+                continue;
+            }
+            let Some(names) = parse_cop_list(comment.cop_list) else {
+                continue;
+            };
+            let selection = selection.get_or_insert_with(Self::default);
+            match comment.mode {
+                PragmaMode::Only => selection.only.get_or_insert_with(Vec::new).extend(names),
+                PragmaMode::Except => selection.except.extend(names),
+            }
+        }
+        selection
+    }
+
+    /// Whether the cop may run on this file.
+    pub fn allows(&self, cop_name: &str) -> bool {
+        let matches = |entry: &String| {
+            cop_name == entry
+                || cop_name
+                    .strip_prefix(entry.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'))
+        };
+        self.only
+            .as_ref()
+            .is_none_or(|only| only.iter().any(matches))
+            && !self.except.iter().any(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_pragma() {
+        assert_eq!(
+            CopSelection::from_source(b"# frozen_string_literal: true\nx = 1\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn only_department() {
+        let sel = CopSelection::from_source(b"# nitrocop:only Lint\nx = 1\n").unwrap();
+        assert!(sel.allows("Lint/Syntax"));
+        assert!(!sel.allows("Layout/LineLength"));
+        // Department prefixes must end at a `/`.
+        assert!(!sel.allows("LintExtra/Foo"));
+    }
+
+    #[test]
+    fn except_list_with_comment() {
+        let sel = CopSelection::from_source(
+            b"#!/usr/bin/env ruby\n\n# nitrocop:except Layout, Metrics/MethodLength -- generated\n",
+        )
+        .unwrap();
+        assert!(!sel.allows("Layout/LineLength"));
+        assert!(!sel.allows("Metrics/MethodLength"));
+        assert!(sel.allows("Metrics/AbcSize"));
+        assert!(sel.allows("Style/StringLiterals"));
+    }
+
+    #[test]
+    fn only_and_except_combine() {
+        let sel = CopSelection::from_source(
+            b"# nitrocop:only Lint, Style/FrozenStringLiteralComment\n# nitrocop: except Lint/UselessAssignment\n",
+        )
+        .unwrap();
+        assert!(sel.allows("Lint/Syntax"));
+        assert!(sel.allows("Style/FrozenStringLiteralComment"));
+        assert!(!sel.allows("Lint/UselessAssignment"));
+        assert!(!sel.allows("Style/StringLiterals"));
+    }
+
+    #[test]
+    fn pragma_after_code_is_ignored() {
+        assert_eq!(
+            CopSelection::from_source(b"x = 1\n# nitrocop:only Lint\n"),
+            None
+        );
+        let comments = pragma_comments(b"x = 1\n# nitrocop:only Lint\n");
+        assert_eq!(comments.len(), 1);
+        assert!(!comments[0].in_header);
+    }
+
+    #[test]
+    fn malformed_pragma_is_ignored() {
+        for source in [
+            &b"# nitrocop:only\n"[..],
+            b"# nitrocop:only Lint Layout\n",
+            b"# nitrocop:only Lint,\n",
+            b"# nitrocop:except Layout:LineLength\n",
+        ] {
+            assert_eq!(
+                CopSelection::from_source(source),
+                None,
+                "{}",
+                String::from_utf8_lossy(source)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_cop_list_accepts_names_and_departments() {
+        assert_eq!(
+            parse_cop_list(" Lint/Syntax,Layout -- why"),
+            Some(vec!["Lint/Syntax".to_string(), "Layout".to_string()])
+        );
+        assert_eq!(parse_cop_list(" -- only a comment"), None);
+    }
+}
@@ -1,6 +1,7 @@
 //! `--rules` command: list all cops nitrocop knows about.
 //!
 //! Shows name, tier, implementation status, baseline presence, and default enabled.
+//! `--list-cops --format json` prints the implemented entries of the same list.
 
 use std::collections::{BTreeMap, HashSet};
 
@@ -11,7 +12,7 @@ use crate::cop::tiers::{Tier, TierMap};
 
 /// Embedded baseline cops: cop name → default enabled (true/false).
 /// Parsed from vendor config/default.yml files.
-fn load_baseline_cops() -> BTreeMap<String, bool> {
+pub(crate) fn load_baseline_cops() -> BTreeMap<String, bool> {
     serde_json::from_str(include_str!("resources/baseline_cops.json"))
         .expect("resources/baseline_cops.json should be valid JSON")
 }
@@ -19,17 +20,42 @@ fn load_baseline_cops() -> BTreeMap<String, bool> {
 #[derive(Debug, Serialize)]
 pub struct RuleEntry {
     pub name: String,
+    /// Everything before the last `/`, e.g. `Style` or `RSpec/Rails`.
+    pub department: String,
     pub tier: String,
+    /// A preview cop that runs without `--preview` (AllCops.PreviewCops or
+    /// `--preview-cop`).
+    pub promoted: bool,
     pub implemented: bool,
     pub in_baseline: bool,
     pub default_enabled: bool,
+    pub supports_autocorrect: bool,
+}
+
+/// The `--tier` and `--department` filters.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuleFilter<'a> {
+    pub tier: Option<&'a str>,
+    pub department: Option<&'a str>,
+}
+
+impl RuleFilter<'_> {
+    fn matches(&self, tier: &str, department: &str) -> bool {
+        self.tier.is_none_or(|t| t == tier)
+            && self.department.is_none_or(|d| {
+                department == d
+                    || department
+                        .strip_prefix(d)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+    }
 }
 
 /// Build the full rules list (union of registry + baseline cops).
 pub fn build_rules(
     registry: &CopRegistry,
     tier_map: &TierMap,
-    tier_filter: Option<&str>,
+    filter: RuleFilter<'_>,
 ) -> Vec<RuleEntry> {
     let baseline = load_baseline_cops();
     let registry_names: HashSet<&str> = registry.cops().iter().map(|c| c.name()).collect();
@@ -55,11 +81,9 @@ pub fn build_rules(
             Tier::Preview => "preview",
         };
 
-        // Apply tier filter
-        if let Some(filter) = tier_filter {
-            if tier_str != filter {
-                continue;
-            }
+        let department = name.rsplit_once('/').map_or("", |(dept, _)| dept);
+        if !filter.matches(tier_str, department) {
+            continue;
         }
 
         let implemented = registry_names.contains(name.as_str());
@@ -68,10 +92,15 @@ pub fn build_rules(
 
         rules.push(RuleEntry {
             name: name.clone(),
+            department: department.to_string(),
             tier: tier_str.to_string(),
+            promoted: tier_map.is_promoted(name),
             implemented,
             in_baseline,
             default_enabled,
+            supports_autocorrect: registry
+                .get(name)
+                .is_some_and(|cop| cop.supports_autocorrect()),
         });
     }
 
@@ -88,12 +117,17 @@ pub fn print_table(rules: &[RuleEntry]) {
     println!("{}", "-".repeat(85));
 
     for rule in rules {
+        let tier = if rule.promoted {
+            format!("{}*", rule.tier)
+        } else {
+            rule.tier.clone()
+        };
         let impl_mark = if rule.implemented { "yes" } else { "-" };
         let baseline_mark = if rule.in_baseline { "yes" } else { "-" };
         let default_mark = if rule.default_enabled { "yes" } else { "-" };
         println!(
             "{:<45} {:<8} {:<12} {:<10} {}",
-            rule.name, rule.tier, impl_mark, baseline_mark, default_mark
+            rule.name, tier, impl_mark, baseline_mark, default_mark
         );
     }
 
@@ -106,6 +140,10 @@ pub fn print_table(rules: &[RuleEntry]) {
     println!(
         "{total} cops total, {implemented} implemented, {in_baseline} in baseline, {preview} preview-tier"
     );
+    let promoted = rules.iter().filter(|r| r.promoted).count();
+    if promoted > 0 {
+        println!("* promoted to run without --preview ({promoted})");
+    }
 }
 
 /// Print rules as JSON to stdout.
@@ -115,3 +153,28 @@ pub fn print_json(rules: &[RuleEntry]) {
         serde_json::to_string_pretty(rules).expect("RuleEntry should be serializable")
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn department_filter_matches_whole_segments() {
+        let filter = RuleFilter {
+            tier: None,
+            department: Some("RSpec"),
+        };
+        assert!(filter.matches("stable", "RSpec"));
+        assert!(filter.matches("preview", "RSpec/Rails"));
+        assert!(!filter.matches("stable", "RSpecRails"));
+        assert!(!filter.matches("stable", "Style"));
+
+        let filter = RuleFilter {
+            tier: Some("preview"),
+            department: Some("Style"),
+        };
+        assert!(filter.matches("preview", "Style"));
+        assert!(!filter.matches("stable", "Style"));
+        assert!(RuleFilter::default().matches("stable", "Lint"));
+    }
+}
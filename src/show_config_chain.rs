@@ -0,0 +1,96 @@
+//! `--show-config-chain` command: list every config file behind the resolved
+//! config, in the order they were loaded.
+//!
+//! Each line names what pulled the file in (`default`, `local`, `require`,
+//! `plugins`, `inherit_gem`, `inherit_from`, `nested`), its absolute path, and
+//! how many cop sections it contains itself. Files are indented under the
+//! file that inherits them; a file overrides everything indented beneath it,
+//! and later siblings override earlier ones.
+
+use std::fmt::Write as _;
+
+use serde::Serialize;
+
+use crate::config::ResolvedConfig;
+use crate::config::chain::ChainEntry;
+
+#[derive(Serialize)]
+struct ChainReport<'a> {
+    files: &'a [ChainEntry],
+}
+
+/// The text listing of `entries`.
+pub fn render(entries: &[ChainEntry]) -> String {
+    if entries.is_empty() {
+        return "No config files loaded.\n".to_string();
+    }
+    let mut out = String::new();
+    for entry in entries {
+        let indent = "  ".repeat(entry.depth);
+        let label = format!("{indent}{}", entry.mechanism.label());
+        let _ = write!(out, "{label:<16} {}", entry.path.display());
+        if entry.skipped {
+            out.push_str(" (already loaded, skipped)\n");
+        } else {
+            let plural = if entry.cop_keys == 1 { "" } else { "s" };
+            let _ = writeln!(out, " ({} cop{plural})", entry.cop_keys);
+        }
+    }
+    out
+}
+
+pub fn print_text(config: &ResolvedConfig) {
+    print!("{}", render(config.config_chain()));
+}
+
+pub fn print_json(config: &ResolvedConfig) {
+    let report = ChainReport {
+        files: config.config_chain(),
+    };
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&report).expect("ChainReport should be serializable")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::chain::LoadMechanism;
+    use std::path::PathBuf;
+
+    fn entry(path: &str, mechanism: LoadMechanism, depth: usize, cop_keys: usize) -> ChainEntry {
+        ChainEntry {
+            path: PathBuf::from(path),
+            mechanism,
+            depth,
+            cop_keys,
+            skipped: false,
+        }
+    }
+
+    #[test]
+    fn indents_inherited_files_under_their_parent() {
+        let entries = [
+            entry(
+                "/gems/rubocop/config/default.yml",
+                LoadMechanism::Default,
+                0,
+                590,
+            ),
+            entry("/app/.rubocop.yml", LoadMechanism::Local, 0, 2),
+            entry("/app/.rubocop_todo.yml", LoadMechanism::InheritFrom, 1, 1),
+            ChainEntry {
+                skipped: true,
+                ..entry("/app/.rubocop.yml", LoadMechanism::InheritFrom, 2, 0)
+            },
+        ];
+        assert_eq!(
+            render(&entries),
+            "default          /gems/rubocop/config/default.yml (590 cops)\n\
+             local            /app/.rubocop.yml (2 cops)\n  \
+             inherit_from   /app/.rubocop_todo.yml (1 cop)\n    \
+             inherit_from /app/.rubocop.yml (already loaded, skipped)\n"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! `--show-cops` command: print the effective configuration of cops.
+//!
+//! Output is YAML in `.rubocop.yml` shape. Each setting that a config file
+//! wrote ends with a `# set by <path>` comment naming the last file that set
+//! it, so a surprising value can be traced to its layer (RuboCop's defaults,
+//! a gem config, `.rubocop_todo.yml`, or the local file). Values are written
+//! as JSON, which YAML reads as flow style.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use crate::config::ResolvedConfig;
+use crate::cop::EnabledState;
+use crate::cop::registry::CopRegistry;
+use crate::diagnostic::Severity;
+
+/// Render the configuration of the named cops, or of every registered cop
+/// when `names` is empty. Unknown names are reported on stderr and skipped.
+pub fn render(config: &ResolvedConfig, registry: &CopRegistry, names: &[String]) -> String {
+    let mut selected: Vec<&str> = if names.is_empty() {
+        registry.names()
+    } else {
+        names
+            .iter()
+            .filter_map(|name| {
+                let cop = registry.get(name);
+                if cop.is_none() {
+                    eprintln!("warning: unknown cop {name}");
+                }
+                cop.map(|c| c.name())
+            })
+            .collect()
+    };
+    selected.sort_unstable();
+    selected.dedup();
+
+    let mut out = String::new();
+    for (i, name) in selected.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        render_cop(&mut out, config, name);
+    }
+    out
+}
+
+fn render_cop(out: &mut String, config: &ResolvedConfig, name: &str) {
+    let cop_config = config.cop_config(name);
+    let provenance = config.cop_provenance(name);
+    let setting = |key: &str| set_by(provenance.and_then(|p| p.settings.get(key)));
+
+    let _ = writeln!(out, "{name}:");
+    let enabled = match cop_config.enabled {
+        EnabledState::True => Some("true"),
+        EnabledState::False => Some("false"),
+        EnabledState::Pending => Some("pending"),
+        EnabledState::Unset => None,
+    };
+    if let Some(enabled) = enabled {
+        let _ = writeln!(out, "  Enabled: {enabled}{}", setting("Enabled"));
+    }
+    if let Some(severity) = cop_config.severity {
+        let severity = match severity {
+            Severity::Convention => "convention",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+            Severity::Fatal => "fatal",
+        };
+        let _ = writeln!(out, "  Severity: {severity}{}", setting("Severity"));
+    }
+    let mut keys: Vec<&String> = cop_config.options.keys().collect();
+    keys.sort();
+    for key in keys {
+        let value = serde_json::to_string(&cop_config.options[key]).unwrap_or_default();
+        let _ = writeln!(out, "  {key}: {value}{}", setting(key));
+    }
+    render_patterns(
+        out,
+        "Include",
+        &cop_config.include,
+        provenance.map(|p| &p.include),
+    );
+    render_patterns(
+        out,
+        "Exclude",
+        &cop_config.exclude,
+        provenance.map(|p| &p.exclude),
+    );
+}
+
+fn render_patterns(
+    out: &mut String,
+    key: &str,
+    patterns: &[String],
+    provenance: Option<&HashMap<String, PathBuf>>,
+) {
+    if patterns.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "  {key}:");
+    for pattern in patterns {
+        let quoted = serde_json::to_string(pattern).unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "    - {quoted}{}",
+            set_by(provenance.and_then(|p| p.get(pattern)))
+        );
+    }
+}
+
+/// The trailing `# set by` comment for a setting, if a file set it.
+fn set_by(path: Option<&PathBuf>) -> String {
+    path.map(|p| format!("  # set by {}", p.display()))
+        .unwrap_or_default()
+}
+
+/// Print `render`'s output to stdout.
+pub fn print_show_cops(config: &ResolvedConfig, registry: &CopRegistry, names: &[String]) {
+    print!("{}", render(config, registry, names));
+}
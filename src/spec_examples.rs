@@ -0,0 +1,437 @@
+//! Extraction of `expect_offense` / `expect_no_offenses` examples from the
+//! vendored RuboCop spec files, for measuring parity against upstream's own
+//! test cases.
+//!
+//! Each example's heredoc is converted to our fixture annotation format
+//! (`^^^ Department/CopName: Message`), so it can be checked with the same
+//! machinery as `tests/fixtures`. Examples whose source is built at runtime
+//! (interpolation, `%{}` / `^{}` / `_{}` templates) are skipped.
+//!
+//! The parity test itself lives in this module's tests and is `#[ignore]`d,
+//! since it needs the vendor submodules:
+//!
+//! ```text
+//! cargo test spec_examples -- --ignored --nocapture
+//! ```
+//!
+//! Known divergences are recorded in `tests/fixtures/rubocop_spec_baseline.json`;
+//! run with `NITROCOP_UPDATE_SPEC_BASELINE=1` to rewrite it.
+
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::node_pattern::cop_name_from_path;
+
+/// Vendor gems whose specs are scanned, relative to `vendor/`.
+const SPEC_GEMS: &[&str] = &[
+    "rubocop",
+    "rubocop-rails",
+    "rubocop-rspec",
+    "rubocop-performance",
+    "rubocop-factory_bot",
+    "rubocop-rspec_rails",
+];
+
+/// Markers of source that RuboCop's spec helpers fill in at runtime.
+const TEMPLATE_MARKERS: &[&str] = &["#{", "%{", "^{", "_{"];
+
+/// `expect_offense(<<~RUBY` / `expect_no_offenses(<<-'RUBY', 'file.rb')`.
+static HEREDOC_EXPECTATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"\bexpect_(offense|no_offenses)\(\s*<<([~-]?)(['"]?)([A-Z_]+)['"]?(?:\s*,\s*['"]([^'"]+)['"])?"#,
+    )
+    .unwrap()
+});
+
+/// `expect_no_offenses('foo.bar')` on a single line.
+static INLINE_NO_OFFENSES: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\bexpect_no_offenses\(\s*(?:'([^'\\]*)'|"([^"\\#]*)")\s*\)"#).unwrap()
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecExampleKind {
+    Offense,
+    NoOffense,
+}
+
+/// One `expect_offense` / `expect_no_offenses` call from a spec file.
+#[derive(Debug, Clone)]
+pub struct SpecExample {
+    pub cop_name: String,
+    /// `<spec path relative to vendor/>:<line>`, stable across runs.
+    pub id: String,
+    pub kind: SpecExampleKind,
+    /// Source in fixture format: offense annotations carry the cop name.
+    pub fixture: String,
+    /// Filename passed as the second argument, if a string literal.
+    pub filename: Option<String>,
+}
+
+/// The cop a spec file covers, e.g.
+/// `spec/rubocop/cop/style/nil_comparison_spec.rb` → `Style/NilComparison`.
+pub fn cop_name_from_spec_path(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?.strip_suffix("_spec")?;
+    cop_name_from_path(&path.with_file_name(format!("{stem}.rb")))
+}
+
+/// Extract the examples of one spec file. `id_prefix` is the spec path used
+/// in example ids.
+pub fn extract_spec_examples(source: &str, cop_name: &str, id_prefix: &str) -> Vec<SpecExample> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut examples = Vec::new();
+    let mut idx = 0;
+    while idx < lines.len() {
+        let line = lines[idx];
+        let id = format!("{id_prefix}:{}", idx + 1);
+
+        if let Some(caps) = HEREDOC_EXPECTATION.captures(line) {
+            let kind = if &caps[1] == "offense" {
+                SpecExampleKind::Offense
+            } else {
+                SpecExampleKind::NoOffense
+            };
+            let squiggly = &caps[2] == "~";
+            let indented_end = !caps[2].is_empty();
+            let tag = &caps[4];
+            let filename = caps.get(5).map(|m| m.as_str().to_string());
+
+            let body_start = idx + 1;
+            let mut end = body_start;
+            while end < lines.len() {
+                let candidate = if indented_end {
+                    lines[end].trim()
+                } else {
+                    lines[end]
+                };
+                if candidate == tag {
+                    break;
+                }
+                end += 1;
+            }
+            idx = end + 1;
+            if end >= lines.len() {
+                continue;
+            }
+
+            let body = &lines[body_start..end];
+            if body
+                .iter()
+                .any(|l| TEMPLATE_MARKERS.iter().any(|m| l.contains(m)))
+            {
+                continue;
+            }
+            let body = if squiggly {
+                dedent(body)
+            } else {
+                body.iter().map(|l| l.to_string()).collect()
+            };
+            examples.push(SpecExample {
+                cop_name: cop_name.to_string(),
+                id,
+                kind,
+                fixture: to_fixture(&body, cop_name),
+                filename,
+            });
+            continue;
+        }
+
+        if let Some(caps) = INLINE_NO_OFFENSES.captures(line) {
+            let code = caps.get(1).or_else(|| caps.get(2)).unwrap().as_str();
+            examples.push(SpecExample {
+                cop_name: cop_name.to_string(),
+                id,
+                kind: SpecExampleKind::NoOffense,
+                fixture: format!("{code}\n"),
+                filename: None,
+            });
+        }
+        idx += 1;
+    }
+    examples
+}
+
+/// Strip the common indentation of non-blank lines, as `<<~` does.
+fn dedent(lines: &[&str]) -> Vec<String> {
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|l| l.get(indent..).unwrap_or("").to_string())
+        .collect()
+}
+
+/// Convert RuboCop annotations (`^^^ Message`) to ours
+/// (`^^^ Department/CopName: Message`).
+fn to_fixture(lines: &[String], cop_name: &str) -> String {
+    let mut out = String::new();
+    for line in lines {
+        let trimmed = line.trim_start();
+        let carets = trimmed.bytes().take_while(|&b| b == b'^').count();
+        let is_annotation = carets > 0 && trimmed[carets..].starts_with(' ');
+        let message = trimmed.get(carets + 1..).unwrap_or("");
+        if is_annotation && !message.starts_with(&format!("{cop_name}: ")) {
+            let prefix = &line[..line.len() - trimmed.len() + carets];
+            out.push_str(&format!("{prefix} {cop_name}: {message}"));
+        } else {
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Walk the vendored spec directories and extract the examples of every
+/// spec file whose cop `include_cop` accepts.
+pub fn walk_vendor_spec_examples(
+    vendor_root: &Path,
+    include_cop: impl Fn(&str) -> bool,
+) -> Vec<SpecExample> {
+    let mut files = Vec::new();
+    for gem in SPEC_GEMS {
+        collect_spec_files(&vendor_root.join(gem).join("spec/rubocop/cop"), &mut files);
+    }
+    files.sort();
+
+    let mut examples = Vec::new();
+    for path in files {
+        let Some(cop_name) = cop_name_from_spec_path(&path) else {
+            continue;
+        };
+        if !include_cop(&cop_name) {
+            continue;
+        }
+        let Ok(source) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let relative = path.strip_prefix(vendor_root).unwrap_or(&path);
+        examples.extend(extract_spec_examples(
+            &source,
+            &cop_name,
+            &relative.to_string_lossy(),
+        ));
+    }
+    examples
+}
+
+fn collect_spec_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_spec_files(&path, files);
+        } else if path.to_string_lossy().ends_with("_spec.rb") {
+            files.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::cop::CopConfig;
+    use crate::cop::registry::CopRegistry;
+    use crate::testutil::{message_matches, parse_fixture, run_cop_full_internal};
+
+    const SPEC: &str = r#"
+RSpec.describe RuboCop::Cop::Style::Foo, :config do
+  it 'registers an offense' do
+    expect_offense(<<~RUBY)
+      foo(bar)
+      ^^^^^^^^ Use `baz`.
+    RUBY
+
+    expect_correction(<<~RUBY)
+      baz
+    RUBY
+  end
+
+  it 'accepts plain code' do
+    expect_no_offenses(<<~'RUBY', 'app/models/x.rb')
+      baz
+    RUBY
+  end
+
+  it 'accepts a one-liner' do
+    expect_no_offenses('qux')
+  end
+
+  it 'skips templated sources' do
+    expect_offense(<<~RUBY, method: method)
+      %{method}(bar)
+      ^{method}^^^^^ Use `baz`.
+    RUBY
+  end
+end
+"#;
+
+    #[test]
+    fn extracts_heredoc_and_inline_examples() {
+        let examples = extract_spec_examples(SPEC, "Style/Foo", "spec/foo_spec.rb");
+        assert_eq!(examples.len(), 3);
+
+        assert_eq!(examples[0].kind, SpecExampleKind::Offense);
+        assert_eq!(examples[0].id, "spec/foo_spec.rb:4");
+        assert_eq!(
+            examples[0].fixture,
+            "foo(bar)\n^^^^^^^^ Style/Foo: Use `baz`.\n"
+        );
+
+        assert_eq!(examples[1].kind, SpecExampleKind::NoOffense);
+        assert_eq!(examples[1].fixture, "baz\n");
+        assert_eq!(examples[1].filename.as_deref(), Some("app/models/x.rb"));
+
+        assert_eq!(examples[2].kind, SpecExampleKind::NoOffense);
+        assert_eq!(examples[2].fixture, "qux\n");
+    }
+
+    #[test]
+    fn converted_annotations_parse_as_fixtures() {
+        let examples = extract_spec_examples(SPEC, "Style/Foo", "spec/foo_spec.rb");
+        let parsed = parse_fixture(examples[0].fixture.as_bytes());
+        assert_eq!(parsed.source, b"foo(bar)\n");
+        assert_eq!(parsed.expected.len(), 1);
+        assert_eq!(parsed.expected[0].cop_name, "Style/Foo");
+        assert_eq!(parsed.expected[0].message, "Use `baz`.");
+    }
+
+    #[test]
+    fn spec_path_maps_to_cop_name() {
+        assert_eq!(
+            cop_name_from_spec_path(Path::new(
+                "vendor/rubocop/spec/rubocop/cop/style/nil_comparison_spec.rb"
+            )),
+            Some("Style/NilComparison".to_string())
+        );
+        assert_eq!(
+            cop_name_from_spec_path(Path::new(
+                "vendor/rubocop-rspec/spec/rubocop/cop/rspec/named_subject_spec.rb"
+            )),
+            Some("RSpec/NamedSubject".to_string())
+        );
+        assert_eq!(cop_name_from_spec_path(Path::new("spec/foo.rb")), None);
+    }
+
+    /// Whether our cop reproduces an example exactly. Panics inside the cop
+    /// count as failures.
+    fn example_passes(registry: &CopRegistry, example: &SpecExample) -> bool {
+        let cop = registry.get(&example.cop_name).unwrap();
+        let parsed = parse_fixture(example.fixture.as_bytes());
+        let filename = example.filename.as_deref().unwrap_or("test.rb");
+        let run = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            run_cop_full_internal(cop, &parsed.source, CopConfig::default(), filename)
+        }));
+        let Ok(mut diagnostics) = run else {
+            return false;
+        };
+        if example.kind == SpecExampleKind::NoOffense {
+            return diagnostics.is_empty();
+        }
+
+        let mut expected = parsed.expected;
+        expected.sort_by_key(|e| (e.line, e.column));
+        diagnostics.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+        diagnostics.len() == expected.len()
+            && diagnostics.iter().zip(&expected).all(|(d, e)| {
+                d.location.line == e.line
+                    && d.location.column == e.column
+                    && d.cop_name == e.cop_name
+                    && message_matches(&e.message, &d.message)
+            })
+    }
+
+    #[test]
+    #[ignore = "needs the vendor submodules; run with --ignored"]
+    fn vendor_spec_examples_match_baseline() {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let vendor_root = manifest.join("vendor");
+        assert!(
+            vendor_root.join("rubocop/spec").is_dir(),
+            "vendor/rubocop/spec not found. Run: git submodule update --init"
+        );
+        let baseline_path = manifest.join("tests/fixtures/rubocop_spec_baseline.json");
+        let baseline: BTreeMap<String, Vec<String>> =
+            serde_json::from_str(&std::fs::read_to_string(&baseline_path).unwrap()).unwrap();
+
+        let registry = CopRegistry::default_registry();
+        let examples = walk_vendor_spec_examples(&vendor_root, |name| registry.get(name).is_some());
+
+        // Per cop: (passed, failed) counts and failing example ids.
+        let mut counts: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+        let mut failures: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for example in &examples {
+            let entry = counts.entry(example.cop_name.as_str()).or_default();
+            if example_passes(&registry, example) {
+                entry.0 += 1;
+            } else {
+                entry.1 += 1;
+                failures
+                    .entry(example.cop_name.clone())
+                    .or_default()
+                    .push(example.id.clone());
+            }
+        }
+
+        let (passed, failed) = counts
+            .values()
+            .fold((0, 0), |(p, f), &(cp, cf)| (p + cp, f + cf));
+        eprintln!("{:<50} {:>6} {:>6}", "cop", "pass", "fail");
+        for (cop, (p, f)) in &counts {
+            eprintln!("{cop:<50} {p:>6} {f:>6}");
+        }
+        eprintln!(
+            "{} cops, {passed} passed, {failed} failed ({:.1}% parity)",
+            counts.len(),
+            100.0 * passed as f64 / (passed + failed).max(1) as f64
+        );
+
+        if std::env::var("NITROCOP_UPDATE_SPEC_BASELINE").is_ok() {
+            let json = serde_json::to_string_pretty(&failures).unwrap();
+            std::fs::write(&baseline_path, json + "\n").unwrap();
+            eprintln!("Wrote {}", baseline_path.display());
+            return;
+        }
+
+        let failures = &failures;
+        let known = |cop: &str, id: &str| {
+            baseline
+                .get(cop)
+                .is_some_and(|ids| ids.iter().any(|k| k == id))
+        };
+        let regressions: Vec<&String> = failures
+            .iter()
+            .flat_map(|(cop, ids)| ids.iter().filter(move |id| !known(cop, id)))
+            .collect();
+        let fixed = baseline
+            .iter()
+            .flat_map(|(cop, ids)| {
+                ids.iter()
+                    .filter(move |id| !failures.get(cop).is_some_and(|f| f.contains(id)))
+            })
+            .count();
+        if fixed > 0 {
+            eprintln!(
+                "{fixed} baseline example(s) now pass; update the baseline with NITROCOP_UPDATE_SPEC_BASELINE=1"
+            );
+        }
+        assert!(
+            regressions.is_empty(),
+            "{} spec example(s) regressed against the baseline:\n  {}",
+            regressions.len(),
+            regressions
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .join("\n  ")
+        );
+    }
+}
@@ -11,6 +11,8 @@ use crate::parse::source::SourceFile;
 pub struct ExpectedOffense {
     pub line: usize,
     pub column: usize,
+    /// Column just past the last `^`; `None` for `# nitrocop-expect:`.
+    pub end_column: Option<usize>,
     pub cop_name: String,
     pub message: String,
 }
@@ -25,6 +27,7 @@ pub struct ParsedFixture {
 
 struct RawAnnotation {
     column: usize,
+    width: usize,
     cop_name: String,
     message: String,
 }
@@ -34,7 +37,8 @@ struct RawAnnotation {
 /// Annotation format: optional leading whitespace, then one or more `^` characters,
 /// then a space, then `Department/CopName: Message`.
 ///
-/// The column of the offense is the byte position of the first `^` in the line.
+/// The column of the offense is the byte position of the first `^` in the line,
+/// and the number of `^` characters is its width.
 ///
 /// This intentionally rejects lines that merely contain `^` in other contexts
 /// (e.g., Ruby XOR `x ^ y`, caret in strings) because:
@@ -67,6 +71,7 @@ fn try_parse_annotation(line: &str) -> Option<RawAnnotation> {
 
     Some(RawAnnotation {
         column,
+        width: caret_count,
         cop_name: cop_name.to_string(),
         message: message.to_string(),
     })
@@ -109,6 +114,7 @@ fn try_parse_expect_annotation(line: &str) -> Option<ExpectedOffense> {
     Some(ExpectedOffense {
         line: line_num,
         column,
+        end_column: None,
         cop_name: cop_name.to_string(),
         message: message.to_string(),
     })
@@ -122,6 +128,10 @@ fn try_parse_expect_annotation(line: &str) -> Option<ExpectedOffense> {
 /// - `# nitrocop-expect: L:C Department/CopName: Message` — explicit offense location
 /// - `^ Department/CopName: Message` — positional annotation (after the source line)
 ///
+/// Messages are compared exactly, except that `[...]` matches any text
+/// (see `message_matches`). The number of `^` is checked against the
+/// offense's width for cops that report it with `Cop::diagnostic_for_range`.
+///
 /// # Convention
 ///
 /// Positional (`^`) annotations must appear *after* the source line they reference.
@@ -167,6 +177,7 @@ pub fn parse_fixture(raw: &[u8]) -> ParsedFixture {
             expected.push(ExpectedOffense {
                 line: source_line_number,
                 column: annotation.column,
+                end_column: Some(annotation.column + annotation.width),
                 cop_name: annotation.cop_name,
                 message: annotation.message,
             });
@@ -226,61 +237,7 @@ pub fn assert_cop_offenses_with_config(cop: &dyn Cop, fixture_bytes: &[u8], conf
     expected.sort_by_key(|e| (e.line, e.column));
     diagnostics.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
 
-    assert_eq!(
-        diagnostics.len(),
-        expected.len(),
-        "Expected {} offense(s) but got {}.\nExpected:\n{}\nActual:\n{}",
-        expected.len(),
-        diagnostics.len(),
-        format_expected(&expected),
-        format_diagnostics(&diagnostics),
-    );
-
-    for (i, (diag, exp)) in diagnostics.iter().zip(expected.iter()).enumerate() {
-        assert_eq!(
-            diag.location.line,
-            exp.line,
-            "Offense #{}: line mismatch (expected {} got {})\n  expected: {}:{} {}: {}\n  actual:   {d}",
-            i + 1,
-            exp.line,
-            diag.location.line,
-            exp.line,
-            exp.column,
-            exp.cop_name,
-            exp.message,
-            d = diag,
-        );
-        assert_eq!(
-            diag.location.column,
-            exp.column,
-            "Offense #{}: column mismatch (expected {} got {})\n  expected: {}:{} {}: {}\n  actual:   {d}",
-            i + 1,
-            exp.column,
-            diag.location.column,
-            exp.line,
-            exp.column,
-            exp.cop_name,
-            exp.message,
-            d = diag,
-        );
-        assert_eq!(
-            diag.cop_name,
-            exp.cop_name,
-            "Offense #{}: cop name mismatch\n  expected: {}\n  actual:   {}",
-            i + 1,
-            exp.cop_name,
-            diag.cop_name,
-        );
-        assert_eq!(
-            diag.message,
-            exp.message,
-            "Offense #{}: message mismatch for {}\n  expected: {:?}\n  actual:   {:?}",
-            i + 1,
-            exp.cop_name,
-            exp.message,
-            diag.message,
-        );
-    }
+    assert_offenses_match(&expected, &diagnostics);
 }
 
 /// Assert a cop produces no offenses on the given source bytes.
@@ -390,61 +347,30 @@ pub fn assert_cop_offenses_full_with_config(
     expected.sort_by_key(|e| (e.line, e.column));
     diagnostics.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
 
-    assert_eq!(
-        diagnostics.len(),
-        expected.len(),
-        "Expected {} offense(s) but got {}.\nExpected:\n{}\nActual:\n{}",
-        expected.len(),
-        diagnostics.len(),
-        format_expected(&expected),
-        format_diagnostics(&diagnostics),
-    );
+    assert_offenses_match(&expected, &diagnostics);
+}
 
-    for (i, (diag, exp)) in diagnostics.iter().zip(expected.iter()).enumerate() {
-        assert_eq!(
-            diag.location.line,
-            exp.line,
-            "Offense #{}: line mismatch (expected {} got {})\n  expected: {}:{} {}: {}\n  actual:   {d}",
-            i + 1,
-            exp.line,
-            diag.location.line,
-            exp.line,
-            exp.column,
-            exp.cop_name,
-            exp.message,
-            d = diag,
-        );
-        assert_eq!(
-            diag.location.column,
-            exp.column,
-            "Offense #{}: column mismatch (expected {} got {})\n  expected: {}:{} {}: {}\n  actual:   {d}",
-            i + 1,
-            exp.column,
-            diag.location.column,
-            exp.line,
-            exp.column,
-            exp.cop_name,
-            exp.message,
-            d = diag,
-        );
-        assert_eq!(
-            diag.cop_name,
-            exp.cop_name,
-            "Offense #{}: cop name mismatch\n  expected: {}\n  actual:   {}",
-            i + 1,
-            exp.cop_name,
-            diag.cop_name,
-        );
-        assert_eq!(
-            diag.message,
-            exp.message,
-            "Offense #{}: message mismatch for {}\n  expected: {:?}\n  actual:   {:?}",
-            i + 1,
-            exp.cop_name,
-            exp.message,
-            diag.message,
-        );
-    }
+/// Run several cops, each with its own config, on one fixture and assert the
+/// combined offenses match. Use this for fixtures that cover how two cops
+/// interact on the same code, with annotations for both.
+pub fn assert_cops_offenses_full_with_configs(
+    cops: &[(&dyn Cop, CopConfig)],
+    fixture_bytes: &[u8],
+) {
+    let parsed = parse_fixture(fixture_bytes);
+    let filename = parsed.filename.as_deref().unwrap_or("test.rb");
+    let mut expected = parsed.expected;
+    let mut diagnostics: Vec<Diagnostic> = cops
+        .iter()
+        .flat_map(|(cop, config)| {
+            run_cop_full_internal(*cop, &parsed.source, config.clone(), filename)
+        })
+        .collect();
+
+    expected.sort_by(|a, b| (a.line, a.column, &a.cop_name).cmp(&(b.line, b.column, &b.cop_name)));
+    diagnostics.sort_by(|a, b| (a.sort_key(), &a.cop_name).cmp(&(b.sort_key(), &b.cop_name)));
+
+    assert_offenses_match(&expected, &diagnostics);
 }
 
 /// Assert a cop produces no offenses using the full pipeline.
@@ -470,6 +396,50 @@ pub fn assert_cop_no_offenses_full_with_config(
     );
 }
 
+// ---- Inline YAML config helpers ----
+
+/// Build a cop config from the cop's YAML block, i.e. what goes under
+/// `Department/CopName:` in `.rubocop.yml`, parsed the same way as a config
+/// file:
+///
+/// ```ignore
+/// let config = cop_config_from_yaml("EnforcedStyle: n_times\nExplicitOnly: true\n");
+/// ```
+///
+/// # Panics
+///
+/// Panics if `yaml` is not valid YAML.
+pub fn cop_config_from_yaml(yaml: &str) -> CopConfig {
+    let value: serde_yml::Value =
+        serde_yml::from_str(yaml).unwrap_or_else(|e| panic!("invalid cop YAML {yaml:?}: {e}"));
+    crate::config::parse_cop_config(&value)
+}
+
+/// Like `cop_config_from_yaml`, with AllCops `TargetRubyVersion` and
+/// `TargetRailsVersion` injected the way `ResolvedConfig::cop_config` does,
+/// for testing version-gated cops.
+pub fn cop_config_from_yaml_with_targets(
+    yaml: &str,
+    target_ruby_version: Option<f64>,
+    target_rails_version: Option<f64>,
+) -> CopConfig {
+    let mut config = cop_config_from_yaml(yaml);
+    crate::config::inject_target_versions(&mut config, target_ruby_version, target_rails_version);
+    config
+}
+
+/// Run all three cop methods with a YAML cop config on fixture bytes and
+/// assert offenses match.
+pub fn assert_cop_offenses_with_yaml(cop: &dyn Cop, yaml: &str, fixture_bytes: &[u8]) {
+    assert_cop_offenses_full_with_config(cop, fixture_bytes, cop_config_from_yaml(yaml));
+}
+
+/// Assert a cop produces no offenses using the full pipeline with a YAML
+/// cop config.
+pub fn assert_cop_no_offenses_with_yaml(cop: &dyn Cop, yaml: &str, source_bytes: &[u8]) {
+    assert_cop_no_offenses_full_with_config(cop, source_bytes, cop_config_from_yaml(yaml));
+}
+
 // ---- Autocorrect testing helpers ----
 
 /// Run all three cop methods with corrections enabled. Returns (diagnostics, corrections).
@@ -577,6 +547,263 @@ pub fn assert_cop_autocorrect_with_config(
     }
 }
 
+/// Assert that a cop's corrections leave nothing for the cop to do where
+/// they were applied.
+///
+/// Applies the cop's corrections to the offense fixture once, then runs the
+/// cop again on the result: it must neither flag nor correct anything on the
+/// lines the first pass rewrote. Catches corrections that produce code the
+/// same cop flags again, which ping-pongs under `-a`. Offenses elsewhere
+/// (e.g. ones deliberately left for a later pass) are not considered.
+pub fn assert_cop_autocorrect_idempotent(cop: &dyn Cop, fixture_bytes: &[u8]) {
+    assert_cop_autocorrect_idempotent_with_config(cop, fixture_bytes, CopConfig::default());
+}
+
+/// Assert autocorrect idempotency with a specific config.
+pub fn assert_cop_autocorrect_idempotent_with_config(
+    cop: &dyn Cop,
+    fixture_bytes: &[u8],
+    config: CopConfig,
+) {
+    let parsed = parse_fixture(fixture_bytes);
+    let filename = parsed.filename.as_deref().unwrap_or("test.rb");
+    let (_diagnostics, corrections) =
+        run_cop_autocorrect_internal(cop, &parsed.source, config.clone(), filename);
+    let correction_set = crate::correction::CorrectionSet::from_vec(corrections);
+    let pass1 = correction_set.apply(&parsed.source);
+
+    let pass1_source = SourceFile::from_bytes(filename, pass1.clone());
+    let line_of = |offset: usize| pass1_source.offset_to_line_col(offset).0;
+    let corrected_lines: Vec<(usize, usize)> = correction_set
+        .output_ranges()
+        .into_iter()
+        .map(|(start, end)| (line_of(start), line_of(end.saturating_sub(1).max(start))))
+        .collect();
+    let in_corrected_lines = |line: usize| {
+        corrected_lines
+            .iter()
+            .any(|&(first, last)| (first..=last).contains(&line))
+    };
+
+    let (diagnostics, corrections) = run_cop_autocorrect_internal(cop, &pass1, config, filename);
+    let remaining: Vec<Diagnostic> = diagnostics
+        .into_iter()
+        .filter(|d| in_corrected_lines(d.location.line))
+        .collect();
+    let recorrections: Vec<crate::correction::Correction> = corrections
+        .into_iter()
+        .filter(|c| in_corrected_lines(line_of(c.start)))
+        .collect();
+
+    if remaining.is_empty() && recorrections.is_empty() {
+        return;
+    }
+
+    let pass2 = crate::correction::CorrectionSet::from_vec(recorrections).apply(&pass1);
+    let pass1_str = String::from_utf8_lossy(&pass1);
+    let pass2_str = String::from_utf8_lossy(&pass2);
+    panic!(
+        "Autocorrect for {} is not idempotent: a second pass still flags or \
+         corrects lines rewritten by the first.\n\
+         === Offenses on corrected lines after pass 1 ===\n{}\n\
+         === Diff (pass 1 -> pass 2) ===\n{}",
+        cop.name(),
+        format_diagnostics(&remaining),
+        simple_diff(&pass1_str, &pass2_str),
+    );
+}
+
+/// Run several cops' corrections together the way `-A` does: each pass runs
+/// every cop, merges their corrections (earlier cops in `cops` win
+/// conflicts) and applies them, until a pass has nothing left to correct.
+///
+/// Returns the source after each pass, starting with the input. Stops early
+/// if a pass reproduces the source of an earlier pass (the cops oscillate).
+pub fn run_autocorrect_pipeline(cops: &[&dyn Cop], source_bytes: &[u8]) -> Vec<Vec<u8>> {
+    let cops: Vec<(&dyn Cop, CopConfig)> = cops
+        .iter()
+        .map(|cop| (*cop, CopConfig::default()))
+        .collect();
+    run_autocorrect_pipeline_with_configs(&cops, source_bytes)
+}
+
+/// `run_autocorrect_pipeline` with a config for each cop.
+pub fn run_autocorrect_pipeline_with_configs(
+    cops: &[(&dyn Cop, CopConfig)],
+    source_bytes: &[u8],
+) -> Vec<Vec<u8>> {
+    let mut passes = vec![source_bytes.to_vec()];
+    for _ in 0..crate::linter::MAX_AUTOCORRECT_PASSES {
+        let current = passes.last().unwrap();
+        let mut corrections = Vec::new();
+        for (cop_index, (cop, config)) in cops.iter().enumerate() {
+            let (_, cop_corrections) =
+                run_cop_autocorrect_internal(*cop, current, config.clone(), "test.rb");
+            corrections.extend(cop_corrections.into_iter().map(|mut c| {
+                c.cop_index = cop_index;
+                c
+            }));
+        }
+        if corrections.is_empty() {
+            break;
+        }
+        let next = crate::correction::CorrectionSet::from_vec(corrections).apply(current);
+        if next == *current {
+            break;
+        }
+        let oscillates = passes.contains(&next);
+        passes.push(next);
+        if oscillates {
+            break;
+        }
+    }
+    passes
+}
+
+/// Assert that running `cops` together on a fixture converges: correction
+/// passes must reach a source with nothing left to correct rather than
+/// cycling between cops. Returns the final corrected source.
+///
+/// On failure, shows a diff between the last two passes.
+pub fn assert_autocorrect_converges(cops: &[&dyn Cop], fixture_bytes: &[u8]) -> Vec<u8> {
+    let cops: Vec<(&dyn Cop, CopConfig)> = cops
+        .iter()
+        .map(|cop| (*cop, CopConfig::default()))
+        .collect();
+    assert_autocorrect_converges_with_configs(&cops, fixture_bytes)
+}
+
+/// `assert_autocorrect_converges` with a config for each cop.
+pub fn assert_autocorrect_converges_with_configs(
+    cops: &[(&dyn Cop, CopConfig)],
+    fixture_bytes: &[u8],
+) -> Vec<u8> {
+    let parsed = parse_fixture(fixture_bytes);
+    let mut passes = run_autocorrect_pipeline_with_configs(cops, &parsed.source);
+    let last = passes.pop().unwrap();
+    let oscillates = passes.contains(&last);
+    if oscillates || passes.len() >= crate::linter::MAX_AUTOCORRECT_PASSES {
+        let names: Vec<&str> = cops.iter().map(|(c, _)| c.name()).collect();
+        let previous = String::from_utf8_lossy(passes.last().unwrap());
+        let last_str = String::from_utf8_lossy(&last);
+        panic!(
+            "Autocorrect for {} did not converge after {} pass(es){}.\n\
+             === Diff (pass {} -> pass {}) ===\n{}",
+            names.join(", "),
+            passes.len(),
+            if oscillates {
+                ": a pass reproduced an earlier source"
+            } else {
+                ""
+            },
+            passes.len() - 1,
+            passes.len(),
+            simple_diff(&previous, &last_str),
+        );
+    }
+    last
+}
+
+/// Assert sorted diagnostics match sorted expected offenses one-to-one:
+/// line, column, cop name and message (see `message_matches`), plus the
+/// caret width for diagnostics that carry an end column.
+fn assert_offenses_match(expected: &[ExpectedOffense], diagnostics: &[Diagnostic]) {
+    assert_eq!(
+        diagnostics.len(),
+        expected.len(),
+        "Expected {} offense(s) but got {}.\nExpected:\n{}\nActual:\n{}",
+        expected.len(),
+        diagnostics.len(),
+        format_expected(expected),
+        format_diagnostics(diagnostics),
+    );
+
+    for (i, (diag, exp)) in diagnostics.iter().zip(expected.iter()).enumerate() {
+        assert_eq!(
+            diag.location.line,
+            exp.line,
+            "Offense #{}: line mismatch (expected {} got {})\n  expected: {}:{} {}: {}\n  actual:   {d}",
+            i + 1,
+            exp.line,
+            diag.location.line,
+            exp.line,
+            exp.column,
+            exp.cop_name,
+            exp.message,
+            d = diag,
+        );
+        assert_eq!(
+            diag.location.column,
+            exp.column,
+            "Offense #{}: column mismatch (expected {} got {})\n  expected: {}:{} {}: {}\n  actual:   {d}",
+            i + 1,
+            exp.column,
+            diag.location.column,
+            exp.line,
+            exp.column,
+            exp.cop_name,
+            exp.message,
+            d = diag,
+        );
+        if let (Some(actual_end), Some(expected_end)) = (diag.end_column, exp.end_column) {
+            assert_eq!(
+                actual_end,
+                expected_end,
+                "Offense #{}: caret width mismatch (expected columns {}..{} got {}..{})\n  expected: {}:{} {}: {}\n  actual:   {d}",
+                i + 1,
+                exp.column,
+                expected_end,
+                diag.location.column,
+                actual_end,
+                exp.line,
+                exp.column,
+                exp.cop_name,
+                exp.message,
+                d = diag,
+            );
+        }
+        assert_eq!(
+            diag.cop_name,
+            exp.cop_name,
+            "Offense #{}: cop name mismatch\n  expected: {}\n  actual:   {}",
+            i + 1,
+            exp.cop_name,
+            diag.cop_name,
+        );
+        assert!(
+            message_matches(&exp.message, &diag.message),
+            "Offense #{}: message mismatch for {}\n  expected: {:?}\n  actual:   {:?}",
+            i + 1,
+            exp.cop_name,
+            exp.message,
+            diag.message,
+        );
+    }
+}
+
+/// Whether an actual message matches an annotation message. Each `[...]` in
+/// the annotation matches any run of text, so long fixed wording can be
+/// abbreviated while the interpolated parts around it are still checked.
+/// Without `[...]` the messages must be equal.
+pub(crate) fn message_matches(expected: &str, actual: &str) -> bool {
+    let mut segments = expected.split("[...]");
+    let first = segments.next().unwrap_or_default();
+    let Some(mut rest) = actual.strip_prefix(first) else {
+        return false;
+    };
+    let segments: Vec<&str> = segments.collect();
+    let Some((last, middle)) = segments.split_last() else {
+        return rest.is_empty();
+    };
+    for segment in middle {
+        match rest.find(segment) {
+            Some(idx) => rest = &rest[idx + segment.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
 /// Simple line-by-line diff for test failure output.
 fn simple_diff(expected: &str, actual: &str) -> String {
     let exp_lines: Vec<&str> = expected.lines().collect();
@@ -663,6 +890,28 @@ mod tests {
         assert_eq!(ann.message, "Use `bar` instead of 'baz'.");
     }
 
+    #[test]
+    fn message_matches_exact_without_wildcard() {
+        assert!(message_matches("Use `bar`.", "Use `bar`."));
+        assert!(!message_matches("Use `bar`.", "Use `bar`. Extra"));
+        assert!(!message_matches("Use `bar`.", "Use `baz`."));
+    }
+
+    #[test]
+    fn message_matches_wildcard_segments() {
+        let actual = "Beware of `before(:all)` as it may leak. Records in `before(:all)` stay.";
+        assert!(message_matches("Beware of `before(:all)` [...]", actual));
+        assert!(message_matches("[...] in `before(:all)` stay.", actual));
+        assert!(message_matches(
+            "Beware of `before(:all)` [...] Records [...] stay.",
+            actual
+        ));
+        assert!(message_matches("Beware[...]", "Beware"));
+        assert!(!message_matches("Beware of `after(:all)` [...]", actual));
+        assert!(!message_matches("[...] in `after(:all)` stay.", actual));
+        assert!(!message_matches("Beware [...] missing [...] stay.", actual));
+    }
+
     // ---- False-positive rejection tests ----
 
     #[test]
@@ -721,6 +970,7 @@ mod tests {
         assert_eq!(parsed.expected[0].column, 5);
         assert_eq!(parsed.expected[0].cop_name, "Layout/Foo");
         assert_eq!(parsed.expected[0].message, "msg");
+        assert_eq!(parsed.expected[0].end_column, Some(8));
         assert!(parsed.filename.is_none());
     }
 
@@ -775,6 +1025,32 @@ mod tests {
         assert_eq!(parsed.expected[0].column, 8);
     }
 
+    fn diagnostic_at(column: usize, end_column: Option<usize>) -> Diagnostic {
+        Diagnostic {
+            path: "test.rb".to_string(),
+            location: crate::diagnostic::Location { line: 1, column },
+            severity: crate::diagnostic::Severity::Convention,
+            cop_name: "Layout/Foo".to_string(),
+            message: "msg".to_string(),
+            corrected: false,
+            end_column,
+        }
+    }
+
+    #[test]
+    fn caret_width_checked_when_end_column_reported() {
+        let parsed = parse_fixture(b"foo(bar)\n    ^^^ Layout/Foo: msg\n");
+        assert_offenses_match(&parsed.expected, &[diagnostic_at(4, Some(7))]);
+        assert_offenses_match(&parsed.expected, &[diagnostic_at(4, None)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "caret width mismatch")]
+    fn caret_width_mismatch_panics() {
+        let parsed = parse_fixture(b"foo(bar)\n    ^^^^ Layout/Foo: msg\n");
+        assert_offenses_match(&parsed.expected, &[diagnostic_at(4, Some(7))]);
+    }
+
     #[test]
     fn parse_fixture_empty_source_lines_preserved() {
         // Empty lines in source (e.g., blank lines) must be kept
@@ -914,4 +1190,134 @@ mod tests {
         };
         assert_cop_no_offenses_full_with_config(&LineLength, b"short line\n", config);
     }
+
+    // ---- Inline YAML config helper tests ----
+
+    #[test]
+    fn cop_config_from_yaml_uses_config_file_parsing() {
+        let config = cop_config_from_yaml(
+            "Enabled: false\nSeverity: warning\nMax: 10\nAllowedMethods:\n  - foo\n",
+        );
+        assert_eq!(config.enabled, crate::cop::EnabledState::False);
+        assert_eq!(config.severity, Some(crate::diagnostic::Severity::Warning));
+        assert_eq!(config.get_usize("Max", 80), 10);
+        assert_eq!(
+            config.get_string_array("AllowedMethods"),
+            Some(vec!["foo".to_string()])
+        );
+        assert!(cop_config_from_yaml("").options.is_empty());
+    }
+
+    #[test]
+    fn cop_config_from_yaml_with_targets_keeps_explicit_versions() {
+        let config = cop_config_from_yaml_with_targets("Max: 3\n", Some(3.1), Some(7.0));
+        assert_eq!(config.options["TargetRubyVersion"].as_f64(), Some(3.1));
+        assert_eq!(config.options["TargetRailsVersion"].as_f64(), Some(7.0));
+
+        let config = cop_config_from_yaml_with_targets("TargetRubyVersion: 2.7\n", Some(3.1), None);
+        assert_eq!(config.options["TargetRubyVersion"].as_f64(), Some(2.7));
+        assert!(!config.options.contains_key("TargetRailsVersion"));
+    }
+
+    #[test]
+    fn yaml_config_assertions_work() {
+        use crate::cop::layout::line_length::LineLength;
+        let fixture = b"short\nlonger than ten\n          ^^^^^ Layout/LineLength: Line is too long. [15/10]\n";
+        assert_cop_offenses_with_yaml(&LineLength, "Max: 10\n", fixture);
+        assert_cop_no_offenses_with_yaml(&LineLength, "Max: 200\n", b"short line\n");
+    }
+
+    // ---- Autocorrect idempotency and pipeline tests ----
+
+    /// Test-only cop that flags every occurrence of `from` and corrects it
+    /// to `to`.
+    struct Rewrite {
+        name: &'static str,
+        from: &'static str,
+        to: &'static str,
+    }
+
+    impl Cop for Rewrite {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn supports_autocorrect(&self) -> bool {
+            true
+        }
+
+        fn check_lines(
+            &self,
+            source: &SourceFile,
+            _config: &CopConfig,
+            diagnostics: &mut Vec<Diagnostic>,
+            mut corrections: Option<&mut Vec<crate::correction::Correction>>,
+        ) {
+            let text = std::str::from_utf8(source.as_bytes()).unwrap();
+            for (start, _) in text.match_indices(self.from) {
+                let (line, column) = source.offset_to_line_col(start);
+                let mut diagnostic =
+                    self.diagnostic(source, line, column, format!("Use `{}`.", self.to));
+                if let Some(corrections) = corrections.as_deref_mut() {
+                    corrections.push(crate::correction::Correction {
+                        start,
+                        end: start + self.from.len(),
+                        replacement: self.to.to_string(),
+                        cop_name: self.name,
+                        cop_index: 0,
+                    });
+                    diagnostic.corrected = true;
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    const A_TO_B: Rewrite = Rewrite {
+        name: "Test/AToB",
+        from: "a",
+        to: "b",
+    };
+
+    #[test]
+    fn autocorrect_idempotent_passes_for_stable_correction() {
+        assert_cop_autocorrect_idempotent(&A_TO_B, b"a = 1\nx = 2\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "not idempotent")]
+    fn autocorrect_idempotent_detects_reflagged_correction() {
+        let cop = Rewrite {
+            name: "Test/Double",
+            from: "a",
+            to: "aa",
+        };
+        assert_cop_autocorrect_idempotent(&cop, b"a = 1\n");
+    }
+
+    #[test]
+    fn autocorrect_pipeline_converges() {
+        let c_to_d = Rewrite {
+            name: "Test/CToD",
+            from: "c",
+            to: "d",
+        };
+        let corrected = assert_autocorrect_converges(&[&A_TO_B as &dyn Cop, &c_to_d], b"a + c\n");
+        assert_eq!(corrected, b"b + d\n");
+        assert_eq!(
+            run_autocorrect_pipeline(&[&A_TO_B as &dyn Cop, &c_to_d], b"a + c\n").len(),
+            2
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "did not converge")]
+    fn autocorrect_pipeline_detects_oscillation() {
+        let b_to_a = Rewrite {
+            name: "Test/BToA",
+            from: "b",
+            to: "a",
+        };
+        assert_autocorrect_converges(&[&A_TO_B as &dyn Cop, &b_to_a], b"a\n");
+    }
 }
@@ -0,0 +1,459 @@
+//! `--validate-config` command: check a project's config chain without linting.
+//!
+//! Walks the config file and everything it pulls in through `inherit_from`
+//! and `inherit_gem`, validates each file against the generated JSON Schema
+//! (see `config_schema`), and runs semantic checks the schema can't express:
+//! renamed cops, unknown cops in known departments, and unknown options.
+//! `require:`/`plugins:` gem defaults are not validated — they aren't part of
+//! the project's own configuration.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::config::gem_path;
+use crate::config_schema;
+use crate::cop::registry::CopRegistry;
+use crate::linter::RENAMED_COPS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Finding {
+    pub level: Level,
+    /// Config file the finding is in.
+    pub file: PathBuf,
+    /// Dotted YAML path within `file`; empty for the whole document.
+    pub yaml_path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ValidationReport {
+    /// Every config file that was read, in load order.
+    pub files: Vec<PathBuf>,
+    pub findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+    pub fn has_errors(&self) -> bool {
+        self.findings.iter().any(|f| f.level == Level::Error)
+    }
+}
+
+/// Validate `config_path` and every config it inherits from. Gem roots for
+/// `inherit_gem` come from `gem_cache` (the lockfile) when available, falling
+/// back to `bundle info`.
+pub fn validate_config(
+    config_path: &Path,
+    registry: &CopRegistry,
+    gem_cache: Option<&HashMap<String, PathBuf>>,
+) -> ValidationReport {
+    let working_dir = config_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .to_path_buf();
+    let cop_options = config_schema::load_cop_options(&working_dir, gem_cache);
+    let mut validator = Validator {
+        schema: config_schema::build_schema(registry, &cop_options),
+        working_dir,
+        gem_cache,
+        visited: HashSet::new(),
+        report: ValidationReport {
+            files: Vec::new(),
+            findings: Vec::new(),
+        },
+    };
+    validator.validate_file(config_path);
+    validator.report
+}
+
+struct Validator<'a> {
+    schema: Value,
+    working_dir: PathBuf,
+    gem_cache: Option<&'a HashMap<String, PathBuf>>,
+    visited: HashSet<PathBuf>,
+    report: ValidationReport,
+}
+
+impl Validator<'_> {
+    fn push(&mut self, level: Level, file: &Path, yaml_path: &str, message: String) {
+        self.report.findings.push(Finding {
+            level,
+            file: file.to_path_buf(),
+            yaml_path: yaml_path.to_string(),
+            message,
+        });
+    }
+
+    fn validate_file(&mut self, path: &Path) {
+        let key = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        if !self.visited.insert(key) {
+            return;
+        }
+        self.report.files.push(path.to_path_buf());
+
+        let contents = match std::fs::read_to_string(path) {
+            Ok(c) => c.replace("!ruby/regexp ", ""),
+            Err(e) => {
+                self.push(Level::Error, path, "", format!("cannot read file: {e}"));
+                return;
+            }
+        };
+        let yaml: serde_yml::Value = match serde_yml::from_str(&contents) {
+            Ok(v) => v,
+            Err(e) => {
+                self.push(Level::Error, path, "", format!("invalid YAML: {e}"));
+                return;
+            }
+        };
+        if yaml.is_null() {
+            return;
+        }
+        let config = match serde_json::to_value(&yaml) {
+            Ok(v) => v,
+            Err(e) => {
+                self.push(Level::Error, path, "", format!("unsupported YAML: {e}"));
+                return;
+            }
+        };
+
+        for violation in config_schema::validate(&self.schema, &config) {
+            self.push(Level::Error, path, &violation.path, violation.message);
+        }
+        let Some(map) = config.as_object() else {
+            return;
+        };
+        self.check_keys(path, map);
+
+        let dir = path.parent().unwrap_or_else(|| Path::new("."));
+        if let Some(gems) = map.get("inherit_gem").and_then(Value::as_object) {
+            for (gem_name, rel_paths) in gems {
+                self.follow_inherit_gem(path, gem_name, rel_paths);
+            }
+        }
+        for (yaml_path, rel_path) in string_list(map.get("inherit_from"), "inherit_from") {
+            if rel_path.starts_with("http://") || rel_path.starts_with("https://") {
+                continue;
+            }
+            let inherited = dir.join(&rel_path);
+            if inherited.is_file() {
+                self.validate_file(&inherited);
+            } else {
+                self.push(
+                    Level::Error,
+                    path,
+                    &yaml_path,
+                    format!("inherited config not found: {}", inherited.display()),
+                );
+            }
+        }
+    }
+
+    fn follow_inherit_gem(&mut self, path: &Path, gem_name: &str, rel_paths: &Value) {
+        let yaml_path = format!("inherit_gem.{gem_name}");
+        let gem_root = match self.gem_cache.and_then(|c| c.get(gem_name)) {
+            Some(root) => root.clone(),
+            None => match gem_path::resolve_gem_path(gem_name, &self.working_dir) {
+                Ok(root) => root,
+                Err(e) => {
+                    self.push(
+                        Level::Error,
+                        path,
+                        &yaml_path,
+                        format!("cannot resolve gem '{gem_name}': {e:#}"),
+                    );
+                    return;
+                }
+            },
+        };
+        for (item_path, rel_path) in string_list(Some(rel_paths), &yaml_path) {
+            let inherited = gem_root.join(&rel_path);
+            if inherited.is_file() {
+                self.validate_file(&inherited);
+            } else {
+                self.push(
+                    Level::Error,
+                    path,
+                    &item_path,
+                    format!(
+                        "config file not found in gem '{gem_name}': {}",
+                        inherited.display()
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Semantic checks on top-level keys: renamed cops, unknown cops in
+    /// known departments, and unknown options for `AllCops` and cops whose
+    /// defaults are known.
+    fn check_keys(&mut self, path: &Path, map: &serde_json::Map<String, Value>) {
+        let schema = std::mem::take(&mut self.schema);
+        let properties = &schema["properties"];
+        let common_keys = &schema["definitions"]["cop"]["properties"];
+
+        for (key, value) in map {
+            if key == "AllCops" {
+                let known = &schema["definitions"]["allCops"]["properties"];
+                self.check_options(path, key, value, &[known]);
+                continue;
+            }
+            let Some((department, _)) = key.split_once('/') else {
+                continue;
+            };
+            if let Some(new_name) = RENAMED_COPS.get(key) {
+                self.push(
+                    Level::Error,
+                    path,
+                    key,
+                    format!("`{key}` has been renamed to `{new_name}`"),
+                );
+            } else if let Some(cop_schema) = properties.get(key) {
+                if let Some(options) = cop_schema.get("properties") {
+                    self.check_options(path, key, value, &[common_keys, options]);
+                }
+            } else if properties.get(department).is_some() {
+                self.push(Level::Warning, path, key, format!("unknown cop `{key}`"));
+            }
+        }
+        self.schema = schema;
+    }
+
+    fn check_options(&mut self, path: &Path, key: &str, value: &Value, known: &[&Value]) {
+        let Some(options) = value.as_object() else {
+            return;
+        };
+        for option in options.keys() {
+            if !known.iter().any(|k| k.get(option).is_some()) {
+                self.push(
+                    Level::Warning,
+                    path,
+                    &format!("{key}.{option}"),
+                    format!("unknown option `{option}` for `{key}`"),
+                );
+            }
+        }
+    }
+}
+
+/// The strings in a string-or-list value, paired with their YAML paths.
+fn string_list(value: Option<&Value>, yaml_path: &str) -> Vec<(String, String)> {
+    match value {
+        Some(Value::String(s)) => vec![(yaml_path.to_string(), s.clone())],
+        Some(Value::Array(items)) => items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| Some((format!("{yaml_path}[{i}]"), v.as_str()?.to_string())))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub fn print_text(report: &ValidationReport) {
+    for finding in &report.findings {
+        let level = match finding.level {
+            Level::Warning => "warning",
+            Level::Error => "error",
+        };
+        if finding.yaml_path.is_empty() {
+            println!("{}: {level}: {}", finding.file.display(), finding.message);
+        } else {
+            println!(
+                "{}: {level}: {}: {}",
+                finding.file.display(),
+                finding.yaml_path,
+                finding.message
+            );
+        }
+    }
+    let errors = report
+        .findings
+        .iter()
+        .filter(|f| f.level == Level::Error)
+        .count();
+    let warnings = report.findings.len() - errors;
+    println!(
+        "{} config file(s) checked: {errors} error(s), {warnings} warning(s)",
+        report.files.len()
+    );
+}
+
+pub fn print_json(report: &ValidationReport) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(report).expect("ValidationReport should be serializable")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn findings(report: &ValidationReport) -> Vec<(Level, String, String)> {
+        report
+            .findings
+            .iter()
+            .map(|f| {
+                let file = f.file.file_name().unwrap().to_string_lossy().into_owned();
+                (f.level, file, f.yaml_path.clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_config_has_no_findings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write(
+            dir.path(),
+            ".rubocop.yml",
+            "AllCops:\n  NewCops: enable\nLayout/LineLength:\n  Max: 120\n",
+        );
+        let report = validate_config(&path, &CopRegistry::default_registry(), None);
+        assert!(report.findings.is_empty(), "{:?}", report.findings);
+        assert!(!report.has_errors());
+    }
+
+    #[test]
+    fn reports_semantic_findings_with_yaml_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write(
+            dir.path(),
+            ".rubocop.yml",
+            "AllCops:\n  Bogus: true\n\
+             Layout/Tab:\n  Enabled: false\n\
+             Layout/NoSuchCop:\n  Enabled: false\n\
+             Custom/Cop:\n  Enabled: true\n\
+             Style/StringLiterals:\n  Enabled: maybe\n",
+        );
+        let report = validate_config(&path, &CopRegistry::default_registry(), None);
+        let rubocop = ".rubocop.yml".to_string();
+        assert_eq!(
+            findings(&report),
+            vec![
+                (
+                    Level::Error,
+                    rubocop.clone(),
+                    "Style/StringLiterals.Enabled".to_string()
+                ),
+                (Level::Warning, rubocop.clone(), "AllCops.Bogus".to_string()),
+                (
+                    Level::Warning,
+                    rubocop.clone(),
+                    "Layout/NoSuchCop".to_string()
+                ),
+                (Level::Error, rubocop, "Layout/Tab".to_string()),
+            ]
+        );
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn checks_cop_options_against_gem_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write(
+            dir.path(),
+            ".rubocop.yml",
+            "Style/StringLiterals:\n  EnforcedStyle: bogus\n  ConsistentQuotesInMultiline: true\n  \
+             NoSuchOption: 1\n",
+        );
+        let gems = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config_schema/gems");
+        let gem_cache = HashMap::from([("rubocop".to_string(), gems.join("rubocop"))]);
+        let report = validate_config(&path, &CopRegistry::default_registry(), Some(&gem_cache));
+        let rubocop = ".rubocop.yml".to_string();
+        assert_eq!(
+            findings(&report),
+            vec![
+                (
+                    Level::Error,
+                    rubocop.clone(),
+                    "Style/StringLiterals.EnforcedStyle".to_string()
+                ),
+                (
+                    Level::Warning,
+                    rubocop,
+                    "Style/StringLiterals.NoSuchOption".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            report.findings[0].message,
+            r#""bogus" is not one of "single_quotes", "double_quotes""#
+        );
+        assert_eq!(
+            report.findings[1].message,
+            "unknown option `NoSuchOption` for `Style/StringLiterals`"
+        );
+    }
+
+    #[test]
+    fn follows_inherit_from_and_reports_missing_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "broken.yml", "Layout/LineLength: [\n");
+        let path = write(
+            dir.path(),
+            ".rubocop.yml",
+            "inherit_from:\n  - broken.yml\n  - missing.yml\n",
+        );
+        let report = validate_config(&path, &CopRegistry::default_registry(), None);
+        assert_eq!(report.files.len(), 2);
+        assert_eq!(
+            findings(&report),
+            vec![
+                (Level::Error, "broken.yml".to_string(), String::new()),
+                (
+                    Level::Error,
+                    ".rubocop.yml".to_string(),
+                    "inherit_from[1]".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn inherit_gem_uses_gem_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let gem_root = dir.path().join("gems/company-style");
+        std::fs::create_dir_all(gem_root.join("config")).unwrap();
+        write(
+            &gem_root.join("config"),
+            "base.yml",
+            "Metrics/MethodLength:\n  Enabled: maybe\n",
+        );
+        let path = write(
+            dir.path(),
+            ".rubocop.yml",
+            "inherit_gem:\n  company-style:\n    - config/base.yml\n    - config/missing.yml\n",
+        );
+        let gem_cache = HashMap::from([("company-style".to_string(), gem_root)]);
+        let report = validate_config(&path, &CopRegistry::default_registry(), Some(&gem_cache));
+        assert_eq!(
+            findings(&report),
+            vec![
+                (
+                    Level::Error,
+                    "base.yml".to_string(),
+                    "Metrics/MethodLength.Enabled".to_string()
+                ),
+                (
+                    Level::Error,
+                    ".rubocop.yml".to_string(),
+                    "inherit_gem.company-style[1]".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -352,6 +352,7 @@ mod tests {
             cop_name: "Style/Test".to_string(),
             message: "msg".to_string(),
             corrected: false,
+            end_column: None,
         }];
         let counter = diagnostics_to_counter(&diags);
         assert_eq!(counter.len(), 1);
@@ -372,6 +373,7 @@ mod tests {
                 cop_name: "Naming/MethodParameterName".to_string(),
                 message: "param a too short".to_string(),
                 corrected: false,
+                end_column: None,
             },
             Diagnostic {
                 path: "test.rb".to_string(),
@@ -380,6 +382,7 @@ mod tests {
                 cop_name: "Naming/MethodParameterName".to_string(),
                 message: "param b too short".to_string(),
                 corrected: false,
+                end_column: None,
             },
         ];
         let counter = diagnostics_to_counter(&diags);
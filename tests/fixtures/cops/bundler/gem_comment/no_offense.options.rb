@@ -0,0 +1,15 @@
+source 'https://rubygems.org'
+
+gem 'puma'
+gem 'rails', '~> 7.1'
+gem 'bootsnap', require: false
+gem 'rubocop', github: 'rubocop/rubocop'
+
+# Carries our fork's SAML fixes
+gem 'devise', github: 'acme/devise'
+gem 'private_gem', source: 'https://gems.example.com' # Only published internally
+
+gem 'omniauth',
+    # The 2.x line still has the CSRF fix unreleased
+    github: 'omniauth/omniauth',
+    branch: 'main'
@@ -0,0 +1,13 @@
+source 'https://rubygems.org'
+
+gem 'puma'
+gem 'pg', '>= 1.1'
+gem 'devise', github: 'heartcombo/devise'
+gem 'bootsnap', '!= 1.18.0', require: false
+
+# Pinned until the 8.0 upgrade lands
+gem 'rails', '~> 7.1'
+
+gem 'sidekiq',
+    '~> 7.2', # Capsules changed in 7.3
+    require: false
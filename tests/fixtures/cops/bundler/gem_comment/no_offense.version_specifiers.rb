@@ -0,0 +1,12 @@
+source 'https://rubygems.org'
+
+gem 'puma'
+gem 'bootsnap', require: false
+gem 'devise', github: 'heartcombo/devise'
+
+# Pinned until the 8.0 upgrade lands
+gem 'rails', '~> 7.1'
+gem 'pg', '>= 1.1' # The oldest release with Ruby 3.3 support
+
+gem 'sidekiq',
+    '>= 7.0' # Needed for capsules
@@ -0,0 +1,18 @@
+source 'https://rubygems.org'
+
+gem 'devise', github: 'heartcombo/devise'
+^ Bundler/GemComment: Missing gem description comment.
+
+gem 'internal_api', git: 'https://git.example.com/internal_api.git', branch: 'main'
+^ Bundler/GemComment: Missing gem description comment.
+
+gem 'private_gem', source: 'https://gems.example.com'
+^ Bundler/GemComment: Missing gem description comment.
+
+gem 'wdm', '>= 0.1.0', platforms: [:mingw, :x64_mingw]
+^ Bundler/GemComment: Missing gem description comment.
+
+gem 'omniauth',
+^ Bundler/GemComment: Missing gem description comment.
+    github: 'omniauth/omniauth',
+    branch: 'main'
@@ -0,0 +1,14 @@
+source 'https://rubygems.org'
+
+gem 'rails', '~> 7.1'
+^ Bundler/GemComment: Missing gem description comment.
+
+gem 'pg', '>= 1.1', '< 2.0'
+^ Bundler/GemComment: Missing gem description comment.
+
+gem 'nokogiri', '1.16.2', require: false
+^ Bundler/GemComment: Missing gem description comment.
+
+gem 'sidekiq',
+^ Bundler/GemComment: Missing gem description comment.
+    '>= 7.0'
@@ -0,0 +1,15 @@
+# XML parsing
+# with libxml2
+gem 'nokogiri'
+# Web server
+gem 'puma'
+
+group :test do
+  # Browser testing
+  gem 'capybara'
+  gem 'rspec'
+end
+
+gem 'bcrypt'
+# Authentication
+gem 'devise'
@@ -0,0 +1,18 @@
+# Web server
+gem 'puma'
+# XML parsing
+# with libxml2
+gem 'nokogiri'
+^^^^^^^^^^^^^^ Bundler/OrderedGems: Gems should be sorted in an alphabetical order within their section of the Gemfile. Gem `nokogiri` should appear before `puma`.
+
+group :test do
+  gem 'rspec'
+  # Browser testing
+  gem 'capybara'
+  ^^^^^^^^^^^^^^ Bundler/OrderedGems: Gems should be sorted in an alphabetical order within their section of the Gemfile. Gem `capybara` should appear before `rspec`.
+end
+
+# Authentication
+gem 'devise'
+gem 'bcrypt'
+^^^^^^^^^^^^ Bundler/OrderedGems: Gems should be sorted in an alphabetical order within their section of the Gemfile. Gem `bcrypt` should appear before `devise`.
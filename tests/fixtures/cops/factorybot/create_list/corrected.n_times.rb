@@ -0,0 +1,6 @@
+3.times.map { create :user }
+5.times.map { create(:user, :trait, name: "Ann") }
+2.times.map { FactoryBot.create(:user) }
+2.times.map { create(:user) { |user| user.confirm! } }
+create_list(:user, 2) { |user, i| user.update(position: i) }
+2.times.map { create(:user) }
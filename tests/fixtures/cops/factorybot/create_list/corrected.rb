@@ -0,0 +1,23 @@
+create_list :user, 3
+create_list :user, 3
+create_list(:user, 5, :trait)
+create_list(:user, 2)
+create_list(:user, 3, :admin)
+2.times.map { create(:user, point: rand) }
+create_list(:user, 3)
+create_list(:player, 5)
+FactoryBot.create_list(:user, 3)
+# Value omission with local variable in scope: person IS a local var,
+# so Prism wraps LocalVariableReadNode (not CallNode). RuboCop flags this.
+person = create(:person)
+create_list(:role_appointment, 2, person:)
+create_list :user, 3, name: "Ann"
+create_list(:user, 2) { |user| create(:post, author: user) }
+describe "users" do
+  let(:users) do
+    FactoryBot.create_list(:user, 3, :admin) do |user|
+        user.confirm!
+        user.touch
+    end
+  end
+end
@@ -0,0 +1,5 @@
+3.times { create :user }
+3.times.map { create(:user, :trait) }
+Array.new(3) { create(:user) }
+[create(:user), create(:user)]
+3.times { FactoryBot.build :user }
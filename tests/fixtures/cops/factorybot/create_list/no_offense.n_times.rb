@@ -0,0 +1,6 @@
+3.times { create :user }
+3.times.map { create(:user, :trait) }
+Array.new(3) { create(:user) }
+create_list :user, 1
+create_list :user, count
+create_list user_factory, 3
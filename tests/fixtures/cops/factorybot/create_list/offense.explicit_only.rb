@@ -0,0 +1,6 @@
+3.times { FactoryBot.create :user }
+^^^^^^^ FactoryBot/CreateList: Prefer create_list.
+Array.new(2) { ::FactoryBot.create(:user, :admin) }
+^^^^^^^^^^^^ FactoryBot/CreateList: Prefer create_list.
+[FactoryBot.create(:user), FactoryBot.create(:user)]
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ FactoryBot/CreateList: Prefer create_list.
@@ -0,0 +1,12 @@
+create_list :user, 3
+^^^^^^^^^^^ FactoryBot/CreateList: Prefer 3.times.map.
+create_list(:user, 5, :trait, name: "Ann")
+^^^^^^^^^^^ FactoryBot/CreateList: Prefer 5.times.map.
+FactoryBot.create_list(:user, 2)
+           ^^^^^^^^^^^ FactoryBot/CreateList: Prefer 2.times.map.
+create_list(:user, 2) { |user| user.confirm! }
+^^^^^^^^^^^ FactoryBot/CreateList: Prefer 2.times.map.
+create_list(:user, 2) { |user, i| user.update(position: i) }
+^^^^^^^^^^^ FactoryBot/CreateList: Prefer 2.times.map.
+[create(:user), create(:user)]
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ FactoryBot/CreateList: Prefer 2.times.map.
@@ -23,3 +23,20 @@ person = create(:person)
 ^^^^^^^^^^^ FactoryBot/CreateList: Prefer create_list.
   create(:role_appointment, person:)
 end
+3.times { |n| create :user, name: "Ann" }
+^^^^^^^ FactoryBot/CreateList: Prefer create_list.
+2.times do
+^^^^^^^ FactoryBot/CreateList: Prefer create_list.
+  create(:user) { |user| create(:post, author: user) }
+end
+describe "users" do
+  let(:users) do
+    3.times.map do
+    ^^^^^^^^^^^ FactoryBot/CreateList: Prefer create_list.
+      FactoryBot.create(:user, :admin) do |user|
+        user.confirm!
+        user.touch
+      end
+    end
+  end
+end
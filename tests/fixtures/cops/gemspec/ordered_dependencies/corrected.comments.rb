@@ -0,0 +1,17 @@
+Gem::Specification.new do |spec|
+  # JSON parsing
+  # with the C extension
+  spec.add_dependency 'json'
+  # Rack interface
+  spec.add_dependency 'rack'
+
+  # Coverage
+  spec.add_development_dependency 'minitest'
+  spec.add_development_dependency 'rspec'
+  # Linting
+  spec.add_development_dependency 'rubocop'
+
+  s.add_runtime_dependency(%q<erubi>, ["~> 1.0"])
+  # Templates
+  s.add_runtime_dependency(%q<tilt>, ["~> 1.4"])
+end
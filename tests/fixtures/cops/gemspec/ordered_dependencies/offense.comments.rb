@@ -0,0 +1,21 @@
+# nitrocop-filename: example.gemspec
+Gem::Specification.new do |spec|
+  # Rack interface
+  spec.add_dependency 'rack'
+  # JSON parsing
+  # with the C extension
+  spec.add_dependency 'json'
+       ^^^^^^^^^^^^^^ Gemspec/OrderedDependencies: Dependencies should be sorted in an alphabetical order within their section of the gemspec. Dependency `json` should appear before `rack`.
+
+  spec.add_development_dependency 'rspec'
+  # Linting
+  spec.add_development_dependency 'rubocop'
+  # Coverage
+  spec.add_development_dependency 'minitest'
+       ^^^^^^^^^^^^^^^^^^^^^^^^^^ Gemspec/OrderedDependencies: Dependencies should be sorted in an alphabetical order within their section of the gemspec. Dependency `minitest` should appear before `rubocop`.
+
+  # Templates
+  s.add_runtime_dependency(%q<tilt>, ["~> 1.4"])
+  s.add_runtime_dependency(%q<erubi>, ["~> 1.0"])
+    ^^^^^^^^^^^^^^^^^^^^^^ Gemspec/OrderedDependencies: Dependencies should be sorted in an alphabetical order within their section of the gemspec. Dependency `erubi` should appear before `tilt`.
+end
@@ -0,0 +1,8 @@
+# nitrocop-filename: example.gemspec
+Gem::Specification.new do |spec|
+  spec.name = 'example'
+  spec.version = '1.0'
+  spec.required_ruby_version = ['< 4', '>= 3.1']
+  spec.required_ruby_version = Gem::Requirement.new('< 4', '>= 3.1')
+  spec.summary = 'A gem with a bounded Ruby version range'
+end
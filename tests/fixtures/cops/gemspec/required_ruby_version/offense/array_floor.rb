@@ -0,0 +1,12 @@
+# nitrocop-filename: example.gemspec
+# nitrocop-expect: 2:31 Gemspec/RequiredRubyVersion: `required_ruby_version` and `TargetRubyVersion` (3.1, which may be specified in .rubocop.yml) should be equal.
+# nitrocop-expect: 5:31 Gemspec/RequiredRubyVersion: `required_ruby_version` and `TargetRubyVersion` (3.1, which may be specified in .rubocop.yml) should be equal.
+Gem::Specification.new do |spec|
+  spec.required_ruby_version = ['< 4', '>= 2.7']
+  spec.name = 'example'
+  spec.version = '1.0'
+  spec.required_ruby_version = Gem::Requirement.new(
+    '>= 2.7',
+    '< 4'
+  )
+end
@@ -0,0 +1,18 @@
+x = case y
+when 1
+      :a
+when 2
+      :b
+end
+
+result = case value
+in Integer
+           :int
+         else
+           :other
+end
+
+case z
+when 1
+    :c
+end
@@ -0,0 +1,14 @@
+x = case y
+  when 1
+      :a
+end
+
+case x
+  when 1
+  :a
+end
+
+result = case value
+  in Integer
+             :int
+end
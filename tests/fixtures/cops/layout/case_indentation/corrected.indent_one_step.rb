@@ -0,0 +1,14 @@
+case x
+  when 1
+  :a
+end
+
+x = case y
+      when 1
+      :a
+    end
+
+case z
+  in [a]
+      a
+end
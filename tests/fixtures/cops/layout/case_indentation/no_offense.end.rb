@@ -0,0 +1,22 @@
+x = case y
+when 1
+  :a
+when 2
+  :b
+end
+
+result = case value
+in Integer
+  :int
+else
+  :other
+end
+
+case z
+when 1
+  :c
+end
+
+label = case kind
+        when :a then "A"
+        else "other" end
@@ -0,0 +1,16 @@
+x = case y
+  when 1
+    :a
+end
+
+case x
+  when 1
+    :a
+  else
+    :b
+end
+
+result = case value
+  in Integer
+    :int
+end
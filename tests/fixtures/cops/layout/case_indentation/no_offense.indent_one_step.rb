@@ -0,0 +1,16 @@
+case x
+  when 1
+    :a
+  when 2
+    :b
+end
+
+x = case y
+      when 1
+        :a
+    end
+
+case z
+  in [a]
+    a
+end
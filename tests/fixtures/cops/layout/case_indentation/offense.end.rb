@@ -0,0 +1,22 @@
+x = case y
+    when 1
+    ^^^^ Layout/CaseIndentation: Indent `when` as deep as `end`.
+      :a
+    when 2
+    ^^^^ Layout/CaseIndentation: Indent `when` as deep as `end`.
+      :b
+end
+
+result = case value
+         in Integer
+         ^^ Layout/CaseIndentation: Indent `in` as deep as `end`.
+           :int
+         else
+           :other
+end
+
+case z
+  when 1
+  ^^^^ Layout/CaseIndentation: Indent `when` as deep as `end`.
+    :c
+end
@@ -0,0 +1,17 @@
+x = case y
+    when 1
+    ^^^^ Layout/CaseIndentation: Indent `when` one step more than `end`.
+      :a
+end
+
+case x
+when 1
+^^^^ Layout/CaseIndentation: Indent `when` one step more than `end`.
+  :a
+end
+
+result = case value
+           in Integer
+           ^^ Layout/CaseIndentation: Indent `in` one step more than `end`.
+             :int
+end
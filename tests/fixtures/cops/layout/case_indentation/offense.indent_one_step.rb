@@ -0,0 +1,17 @@
+case x
+when 1
+^^^^ Layout/CaseIndentation: Indent `when` one step more than `case`.
+  :a
+end
+
+x = case y
+    when 1
+    ^^^^ Layout/CaseIndentation: Indent `when` one step more than `case`.
+      :a
+    end
+
+case z
+    in [a]
+    ^^ Layout/CaseIndentation: Indent `in` one step more than `case`.
+      a
+end
@@ -22,3 +22,10 @@ case x
   ^^ Layout/CaseIndentation: Indent `in` as deep as `case`.
     :c
 end
+
+# The case is the value of an assignment: `when` follows `case`, not `end`
+x = case y
+when 1
+^^^^ Layout/CaseIndentation: Indent `when` as deep as `case`.
+  :a
+end
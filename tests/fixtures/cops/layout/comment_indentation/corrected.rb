@@ -0,0 +1,28 @@
+def foo
+  # misaligned comment
+  x = 1
+  # over-indented comment
+  y = 2
+  # way over-indented
+  z = 3
+end
+
+%&"& #=> '"' 
+%Q;\
+ #{2**2}; #=> " 4"
+%Q<<b>\u0062old</b>> #=> "<b>bold</b>"
+
+def bar
+  x = 1
+  # closing block line 1
+  # closing block line 2
+end
+
+if condition
+  a = 1
+# about the else branch
+else
+  b = 2
+end
+
+# trailing top-level comment
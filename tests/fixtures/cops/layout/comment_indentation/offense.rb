@@ -15,3 +15,21 @@ end
  #{2**2}; #=> " 4"
           ^ Layout/CommentIndentation: Incorrect indentation detected (column 10 instead of column 0).
 %Q<<b>\u0062old</b>> #=> "<b>bold</b>"
+
+def bar
+  x = 1
+      # closing block line 1
+      # closing block line 2
+      ^ Layout/CommentIndentation: Incorrect indentation detected (column 6 instead of column 2).
+end
+
+if condition
+  a = 1
+    # about the else branch
+    ^ Layout/CommentIndentation: Incorrect indentation detected (column 4 instead of column 0).
+else
+  b = 2
+end
+
+  # trailing top-level comment
+  ^ Layout/CommentIndentation: Incorrect indentation detected (column 2 instead of column 0).
@@ -0,0 +1,25 @@
+something
+  .method_call
+
+something
+  .other
+
+foo
+  .bar
+
+user
+  &.name
+
+items.map(&:to_s) # stringify
+  .join
+
+query
+  .where(id: 1)
+
+value
+  .to_s
+
+foo(<<~SQL)
+  SELECT 1
+SQL
+  .strip
@@ -0,0 +1,22 @@
+foo.
+  bar.
+  baz
+
+something.
+  chain.
+  another
+
+user&.
+  name
+
+foo. # note
+  bar
+
+relation.
+  # narrow it down
+  where(active: true)
+
+foo(<<~SQL).
+  SELECT 1
+SQL
+  strip
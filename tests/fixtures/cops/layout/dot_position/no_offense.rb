@@ -15,3 +15,14 @@ Foo::
 
 Foo::
   Bar
+
+# Heredoc receiver: the dot follows the terminator
+foo(<<~SQL)
+  SELECT 1
+SQL
+  .strip
+
+# A comment between the dot and the method name is left alone
+foo.
+  # explain bar
+  bar
@@ -11,3 +11,9 @@ foo.bar.baz
 
 # Scope resolution is not a method call
 Foo::Bar
+
+# Heredoc receiver: the dot follows the receiver, before the body
+foo(<<~SQL).
+  SELECT 1
+SQL
+  strip
@@ -9,3 +9,26 @@ something.
 foo.
    ^ Layout/DotPosition: Place the `.` on the next line, together with the method name.
   bar
+
+user&.
+    ^^ Layout/DotPosition: Place the `&.` on the next line, together with the method name.
+  name
+
+items.map(&:to_s). # stringify
+                 ^ Layout/DotPosition: Place the `.` on the next line, together with the method name.
+  join
+
+query
+  .
+  ^ Layout/DotPosition: Place the `.` on the next line, together with the method name.
+  where(id: 1)
+
+value .
+      ^ Layout/DotPosition: Place the `.` on the next line, together with the method name.
+  to_s
+
+foo(<<~SQL).
+           ^ Layout/DotPosition: Place the `.` on the next line, together with the method name.
+  SELECT 1
+SQL
+  strip
@@ -9,3 +9,22 @@ something
   ^ Layout/DotPosition: Place the `.` on the previous line, together with the method call receiver.
   .another
   ^ Layout/DotPosition: Place the `.` on the previous line, together with the method call receiver.
+
+user
+  &.name
+  ^^ Layout/DotPosition: Place the `&.` on the previous line, together with the method call receiver.
+
+foo # note
+  .bar
+  ^ Layout/DotPosition: Place the `.` on the previous line, together with the method call receiver.
+
+relation
+  # narrow it down
+  .where(active: true)
+  ^ Layout/DotPosition: Place the `.` on the previous line, together with the method call receiver.
+
+foo(<<~SQL)
+  SELECT 1
+SQL
+  .strip
+  ^ Layout/DotPosition: Place the `.` on the previous line, together with the method call receiver.
@@ -0,0 +1,10 @@
+class Foo
+
+  def bar; end
+end
+
+class Baz
+
+  X = 1
+  Y = 2
+end
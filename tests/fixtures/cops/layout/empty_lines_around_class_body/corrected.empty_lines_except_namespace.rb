@@ -0,0 +1,7 @@
+class Outer
+  class Inner
+
+    def foo; end
+
+  end
+end
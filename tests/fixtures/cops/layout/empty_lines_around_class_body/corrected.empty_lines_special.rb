@@ -0,0 +1,28 @@
+class Foo
+  include Bar
+
+  def baz; end
+
+end
+
+class Qux
+
+  def quux; end
+
+end
+
+class Ns
+  class Inner
+
+    def foo; end
+
+  end
+end
+
+class Documented
+  X = 1
+
+  # Helper
+  def self.helper; end
+
+end
@@ -0,0 +1,16 @@
+class Outer
+  class Inner
+
+    def foo; end
+
+  end
+end
+
+class Plain
+
+  X = 1
+
+end
+
+class Empty
+end
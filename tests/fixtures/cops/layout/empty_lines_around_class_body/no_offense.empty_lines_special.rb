@@ -0,0 +1,29 @@
+class Foo
+  include Bar
+
+  # Docs
+  def baz; end
+
+end
+
+class Qux
+
+  def quux; end
+
+end
+
+class Plain
+  X = 1
+
+end
+
+class Ns
+  class Inner
+
+    def foo; end
+
+  end
+end
+
+class Empty
+end
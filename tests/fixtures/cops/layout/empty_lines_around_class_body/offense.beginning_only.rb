@@ -0,0 +1,12 @@
+class Foo
+  def bar; end
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body beginning.
+
+^ Layout/EmptyLinesAroundClassBody: Extra empty line detected at class body end.
+end
+
+class Baz
+  X = 1
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body beginning.
+  Y = 2
+end
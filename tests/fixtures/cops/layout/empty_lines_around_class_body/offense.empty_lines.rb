@@ -0,0 +1,12 @@
+class Foo
+  def bar; end
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body beginning.
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body end.
+end
+
+class Baz
+
+  X = 1
+  Y = 2
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body end.
+end
@@ -0,0 +1,11 @@
+class Outer
+
+^ Layout/EmptyLinesAroundClassBody: Extra empty line detected at class body beginning.
+  class Inner
+    def foo; end
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body beginning.
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body end.
+  end
+
+^ Layout/EmptyLinesAroundClassBody: Extra empty line detected at class body end.
+end
@@ -0,0 +1,30 @@
+class Foo
+  include Bar
+  def baz; end
+^ Layout/EmptyLinesAroundClassBody: Empty line missing before first def definition.
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body end.
+end
+
+class Qux
+  def quux; end
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body beginning.
+^ Layout/EmptyLinesAroundClassBody: Empty line missing at class body end.
+end
+
+class Ns
+
+^ Layout/EmptyLinesAroundClassBody: Extra empty line detected at class body beginning.
+  class Inner
+
+    def foo; end
+
+  end
+end
+
+class Documented
+  X = 1
+  # Helper
+^ Layout/EmptyLinesAroundClassBody: Empty line missing before first defs definition.
+  def self.helper; end
+
+end
@@ -0,0 +1,7 @@
+module Outer
+  module Inner
+
+    def foo; end
+
+  end
+end
@@ -0,0 +1,18 @@
+module Foo
+  extend self
+
+  def bar; end
+
+end
+
+module Ns
+  class Inner; end
+end
+
+module Util
+  X = 1
+
+  # Helper
+  def self.helper; end
+
+end
@@ -0,0 +1,19 @@
+module Foo
+  extend self
+
+  def bar; end
+
+end
+
+module Ns
+  module Inner
+
+    def foo; end
+
+  end
+end
+
+module Plain
+  X = 1
+
+end
@@ -0,0 +1,11 @@
+module Outer
+
+^ Layout/EmptyLinesAroundModuleBody: Extra empty line detected at module body beginning.
+  module Inner
+    def foo; end
+^ Layout/EmptyLinesAroundModuleBody: Empty line missing at module body beginning.
+^ Layout/EmptyLinesAroundModuleBody: Empty line missing at module body end.
+  end
+
+^ Layout/EmptyLinesAroundModuleBody: Extra empty line detected at module body end.
+end
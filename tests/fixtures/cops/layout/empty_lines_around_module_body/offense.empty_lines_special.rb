@@ -0,0 +1,22 @@
+module Foo
+  extend self
+  def bar; end
+^ Layout/EmptyLinesAroundModuleBody: Empty line missing before first def definition.
+^ Layout/EmptyLinesAroundModuleBody: Empty line missing at module body end.
+end
+
+module Ns
+
+^ Layout/EmptyLinesAroundModuleBody: Extra empty line detected at module body beginning.
+  class Inner; end
+
+^ Layout/EmptyLinesAroundModuleBody: Extra empty line detected at module body end.
+end
+
+module Util
+  X = 1
+  # Helper
+^ Layout/EmptyLinesAroundModuleBody: Empty line missing before first defs definition.
+  def self.helper; end
+^ Layout/EmptyLinesAroundModuleBody: Empty line missing at module body end.
+end
@@ -0,0 +1,13 @@
+website = "example.org"
+name    = "Jill"
+age     = 30
+
+counter += 1
+limit  ||= 10
+x        = 1
+
+def configure
+  host         = "localhost"
+  port         = 8080
+  self.timeout = 5
+end
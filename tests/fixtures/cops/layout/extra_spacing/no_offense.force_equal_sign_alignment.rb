@@ -0,0 +1,15 @@
+website = "example.org"
+name    = "Jill"
+age     = 30
+
+def method(foo = 1, barbaz = 2)
+  foo + barbaz
+end
+
+total   = 0
+# a comment does not break the group
+count  += 1
+options = {
+  key: 1,
+}
+flag = true
@@ -0,0 +1,18 @@
+website = "example.org"
+name = "Jill"
+     ^ Layout/ExtraSpacing: `=` is not aligned with the preceding assignment.
+age  = 30
+
+counter += 1
+limit ||= 10
+      ^^^ Layout/ExtraSpacing: `=` is not aligned with the preceding assignment.
+x = 1
+  ^ Layout/ExtraSpacing: `=` is not aligned with the preceding assignment.
+
+def configure
+  host = "localhost"
+  port   = 8080
+         ^ Layout/ExtraSpacing: `=` is not aligned with the preceding assignment.
+  self.timeout = 5
+               ^ Layout/ExtraSpacing: `=` is not aligned with the preceding assignment.
+end
@@ -0,0 +1,8 @@
+foo = some_method(
+  :a,
+        :b
+      )
+
+expect(foo.bar(
+  :baz
+       ))
@@ -0,0 +1,7 @@
+foo = some_method(
+        :a
+)
+
+obj.method(
+  :arg
+)
@@ -0,0 +1,3 @@
+expect foo.bar(
+         :baz
+)
@@ -0,0 +1,12 @@
+some_method(
+  first_param,
+  second_param
+)
+
+foo = some_method(
+  :a
+)
+
+expect(foo.bar(
+  :baz
+))
@@ -0,0 +1,7 @@
+expect foo.bar(
+         :baz
+       )
+
+some_method(
+  :a
+)
@@ -0,0 +1,10 @@
+foo = some_method(
+        :a,
+        ^^ Layout/FirstArgumentIndentation: Indent the first argument one step more than the start of the previous line.
+        :b
+      )
+
+expect(foo.bar(
+         :baz
+         ^^^^ Layout/FirstArgumentIndentation: Indent the first argument one step more than the start of the previous line.
+       ))
@@ -0,0 +1,9 @@
+foo = some_method(
+  :a
+  ^^ Layout/FirstArgumentIndentation: Indent the first argument one step more than `some_method(`.
+)
+
+obj.method(
+      :arg
+      ^^^^ Layout/FirstArgumentIndentation: Indent the first argument one step more than `obj.method(`.
+)
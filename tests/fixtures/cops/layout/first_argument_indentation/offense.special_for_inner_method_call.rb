@@ -0,0 +1,4 @@
+expect foo.bar(
+  :baz
+  ^^^^ Layout/FirstArgumentIndentation: Indent the first argument one step more than `foo.bar(`.
+)
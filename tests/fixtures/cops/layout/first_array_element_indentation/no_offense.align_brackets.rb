@@ -0,0 +1,7 @@
+a = [
+      1
+    ]
+
+foo([
+      :a
+    ])
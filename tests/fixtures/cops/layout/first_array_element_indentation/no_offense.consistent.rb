@@ -0,0 +1,8 @@
+foo([
+  :a
+])
+
+a = [
+  1,
+  2
+]
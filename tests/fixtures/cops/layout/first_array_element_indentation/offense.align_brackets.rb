@@ -0,0 +1,5 @@
+a = [
+  1
+  ^ Layout/FirstArrayElementIndentation: Use 2 spaces for indentation in an array, relative to the position of the opening bracket.
+]
+^ Layout/FirstArrayElementIndentation: Indent the right bracket the same as the left bracket.
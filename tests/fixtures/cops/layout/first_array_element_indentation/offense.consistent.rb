@@ -0,0 +1,5 @@
+foo([
+      :a
+      ^^ Layout/FirstArrayElementIndentation: Use 2 spaces for indentation in an array, relative to the start of the line where the left square bracket is.
+    ])
+    ^ Layout/FirstArrayElementIndentation: Indent the right bracket the same as the start of the line where the left bracket is.
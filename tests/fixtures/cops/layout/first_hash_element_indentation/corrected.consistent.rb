@@ -0,0 +1,4 @@
+foo({
+  a: 1,
+      b: 2,
+})
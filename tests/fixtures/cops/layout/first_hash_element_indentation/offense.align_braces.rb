@@ -0,0 +1,5 @@
+a = {
+  b: 1
+  ^^^^ Layout/FirstHashElementIndentation: Use 2 (not 0) spaces for indentation of the first element.
+}
+^ Layout/FirstHashElementIndentation: Indent the right brace the same as the left brace.
@@ -0,0 +1,50 @@
+class Account
+  def name
+  end
+
+  def email
+  end
+
+  private
+
+    def secret
+    end
+
+    def token
+    end
+
+  protected
+
+    def compare
+    end
+
+  public
+
+  def visible
+  end
+end
+
+module Billing
+  class Invoice
+    def total
+    end
+
+    private
+
+      def lines
+      end
+
+      def tax
+      end
+  end
+end
+
+Helpers = Module.new do
+  def shared
+  end
+
+  private
+
+    def internal
+    end
+end
@@ -0,0 +1,44 @@
+class Account
+  def name
+  end
+
+  private
+
+    def secret
+    end
+
+      def token
+      ^^^ Layout/IndentationConsistency: Inconsistent indentation detected.
+      end
+
+  protected
+
+    def compare
+    end
+
+  def other
+  ^^^ Layout/IndentationConsistency: Inconsistent indentation detected.
+  end
+end
+
+class Outer
+  class Inner
+    private
+
+      def first
+      end
+    def second
+    ^^^ Layout/IndentationConsistency: Inconsistent indentation detected.
+    end
+  end
+end
+
+items.each do
+  private
+
+    def helper
+    end
+  def other
+  ^^^ Layout/IndentationConsistency: Inconsistent indentation detected.
+  end
+end
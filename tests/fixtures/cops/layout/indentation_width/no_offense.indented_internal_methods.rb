@@ -0,0 +1,53 @@
+class Account
+  def name
+    @name
+  end
+
+  private
+
+    def secret
+      @secret
+    end
+
+    def token
+      @token
+    end
+
+  protected
+
+    def compare(other)
+      other.name == name
+    end
+end
+
+module Billing
+  class Invoice
+    def total
+      lines.sum(&:amount)
+    end
+
+    private
+
+      def lines
+        @lines ||= []
+      end
+  end
+end
+
+class Settings
+  public
+
+  def visible
+    true
+  end
+end
+
+Helpers = Module.new do
+  def shared
+  end
+
+  private
+
+    def internal
+    end
+end
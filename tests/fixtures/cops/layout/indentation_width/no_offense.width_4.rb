@@ -0,0 +1,31 @@
+class Report
+    def title
+        @title
+    end
+
+    private
+
+        def rows
+            @rows
+        end
+
+    protected
+
+        def compare(other)
+            other.title == title
+        end
+end
+
+module Outer
+    class Inner
+        private
+
+            def hidden
+                true
+            end
+    end
+end
+
+if ready
+    start
+end
@@ -0,0 +1,50 @@
+class Account
+  def name
+  end
+
+  private
+
+  def secret
+  ^^^ Layout/IndentationWidth: Use 2 (not 0) spaces for indented_internal_methods indentation.
+  end
+end
+
+class Widget
+  def render
+  end
+
+  private
+
+    def build
+    end
+
+  public
+
+  def refresh
+  end
+
+  protected
+
+      def compare
+      ^^^ Layout/IndentationWidth: Use 2 (not 4) spaces for indented_internal_methods indentation.
+      end
+end
+
+class Outer
+  class Inner
+    def visible
+    end
+
+    private
+
+    def hidden
+    ^^^ Layout/IndentationWidth: Use 2 (not 0) spaces for indented_internal_methods indentation.
+    end
+  end
+
+  protected
+
+  def helper
+  ^^^ Layout/IndentationWidth: Use 2 (not 0) spaces for indented_internal_methods indentation.
+  end
+end
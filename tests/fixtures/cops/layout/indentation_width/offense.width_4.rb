@@ -0,0 +1,23 @@
+class Report
+    def title
+      @title
+      ^^^ Layout/IndentationWidth: Use 4 (not 2) spaces for indentation.
+    end
+
+    private
+
+    def rows
+    ^^^ Layout/IndentationWidth: Use 4 (not 0) spaces for indented_internal_methods indentation.
+    end
+
+    protected
+
+      def compare
+      ^^^ Layout/IndentationWidth: Use 4 (not 2) spaces for indented_internal_methods indentation.
+      end
+end
+
+def summary
+  total
+  ^^^ Layout/IndentationWidth: Use 4 (not 2) spaces for indentation.
+end
@@ -0,0 +1,9 @@
+# Extra space before an operator is left to Layout/ExtraSpacing when
+# AllowForAlignment is false.
+a   = 1
+bb  = 2
+ccc = 3
+
+total  += value
+width   = left  + right
+x = 1
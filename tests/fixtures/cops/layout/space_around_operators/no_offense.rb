@@ -206,3 +206,8 @@ projects  = 3.times.map { |i| i }
 # Plain = followed by non-assignment line with = only inside a string
 rel  = '/test'
 expect(foo).to eq("test?name2=val2")
+
+# Aligned right-hand sides (AllowForAlignment: true)
+a =   1
+bb =  2
+ccc = 3
@@ -0,0 +1,10 @@
+a =   1
+  ^ Layout/SpaceAroundOperators: Operator `=` should be surrounded by a single space.
+bb =  2
+   ^ Layout/SpaceAroundOperators: Operator `=` should be surrounded by a single space.
+ccc = 3
+
+total +=  value
+      ^^ Layout/SpaceAroundOperators: Operator `+=` should be surrounded by a single space.
+result = x ==  y
+           ^^ Layout/SpaceAroundOperators: Operator `==` should be surrounded by a single space.
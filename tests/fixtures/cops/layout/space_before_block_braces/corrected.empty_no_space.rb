@@ -0,0 +1,4 @@
+items.each{}
+items.each{}
+->{}
+items.map { |x| x }
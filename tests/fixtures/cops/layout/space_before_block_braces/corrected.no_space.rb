@@ -0,0 +1,3 @@
+items.each{ |x| x }
+items.each{}
+run = ->{ work }
@@ -0,0 +1,7 @@
+items.each{ |x| x }
+items.each{}
+run = ->{ work }
+# Multiline blocks are left to Style/BlockDelimiters (line_count_based)
+items.each {
+  run
+}
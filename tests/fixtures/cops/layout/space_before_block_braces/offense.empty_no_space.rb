@@ -0,0 +1,8 @@
+items.each {}
+          ^ Layout/SpaceBeforeBlockBraces: Space detected to the left of {.
+items.each  {}
+          ^^ Layout/SpaceBeforeBlockBraces: Space detected to the left of {.
+->	{}
+  ^ Layout/SpaceBeforeBlockBraces: Space detected to the left of {.
+items.map{ |x| x }
+         ^ Layout/SpaceBeforeBlockBraces: Space missing to the left of {.
@@ -0,0 +1,6 @@
+items.each { |x| x }
+          ^ Layout/SpaceBeforeBlockBraces: Space detected to the left of {.
+items.each   {}
+          ^^^ Layout/SpaceBeforeBlockBraces: Space detected to the left of {.
+run = -> { work }
+        ^ Layout/SpaceBeforeBlockBraces: Space detected to the left of {.
@@ -0,0 +1,6 @@
+[1, 2].each {|x| puts x}
+items.map {x * 2}
+items.each { }
+items.each {|x|
+  puts x}
+run = -> {work}
@@ -0,0 +1,14 @@
+[1, 2].each { |x| puts x }
+             ^ Layout/SpaceInsideBlockBraces: Space between { and | detected.
+                        ^ Layout/SpaceInsideBlockBraces: Space inside } detected.
+items.map { x * 2 }
+           ^ Layout/SpaceInsideBlockBraces: Space inside { detected.
+                 ^ Layout/SpaceInsideBlockBraces: Space inside } detected.
+items.each {}
+           ^^ Layout/SpaceInsideBlockBraces: Space missing inside empty braces.
+items.each {|x|
+  puts x }
+        ^ Layout/SpaceInsideBlockBraces: Space inside } detected.
+run = -> {  work  }
+          ^^ Layout/SpaceInsideBlockBraces: Space inside { detected.
+                ^^ Layout/SpaceInsideBlockBraces: Space inside } detected.
@@ -0,0 +1,11 @@
+if x == compute
+  use(x)
+end
+
+process(line) while line == gets
+
+if (cache ||= build_cache)
+end
+
+while (value &&= value.next)
+end
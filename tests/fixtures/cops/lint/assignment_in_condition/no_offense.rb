@@ -118,3 +118,11 @@ class Example
     assert_equal 'OtherTransaction/Rake/invoke/default', event['name']
   end
 end
+
+# parenthesized operator assignment is a safe assignment
+if (count += 1)
+end
+
+# modifier forms with parenthesized assignments
+process(line) while (line = gets)
+retry_job until (job = queue.pop)
@@ -0,0 +1,15 @@
+if (x = compute)
+      ^ Lint/AssignmentInCondition: Use `==` if you meant to do a comparison or move the assignment up out of the condition.
+  use(x)
+end
+
+process(line) while (line = gets)
+                          ^ Lint/AssignmentInCondition: Use `==` if you meant to do a comparison or move the assignment up out of the condition.
+
+if (count += 1)
+          ^ Lint/AssignmentInCondition: Use `==` if you meant to do a comparison or move the assignment up out of the condition.
+end
+
+unless (@cache[key] = fetch(key))
+                    ^ Lint/AssignmentInCondition: Use `==` if you meant to do a comparison or move the assignment up out of the condition.
+end
@@ -176,3 +176,14 @@ if should_install? &&
       end
     end
 end
+
+if count += 1
+         ^ Lint/AssignmentInCondition: Use `==` if you meant to do a comparison or wrap the expression in parentheses to indicate you meant to assign in a condition.
+end
+
+while buffer << chunk && total -= chunk.size
+                               ^ Lint/AssignmentInCondition: Use `==` if you meant to do a comparison or wrap the expression in parentheses to indicate you meant to assign in a condition.
+end
+
+retry_job until attempts[job] += 1
+                              ^ Lint/AssignmentInCondition: Use `==` if you meant to do a comparison or wrap the expression in parentheses to indicate you meant to assign in a condition.
@@ -0,0 +1,12 @@
+#!/usr/bin/env ruby
+# frozen_string_literal: true
+
+# nitrocop:only Lint, Style/FrozenStringLiteralComment -- generated code
+# nitrocop: except Lint/UselessAssignment
+
+TEMPLATE = <<~RUBY
+  # nitrocop:only Layout
+RUBY
+
+x = "# nitrocop:except Style"
+# Mention nitrocop:only in prose is fine.
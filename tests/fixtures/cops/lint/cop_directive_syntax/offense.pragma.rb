@@ -0,0 +1,17 @@
+# nitrocop:only
+^^^^^^^^^^^^^^^ Lint/CopDirectiveSyntax: Malformed directive comment detected. The cop name is missing.
+# nitrocop:except Layout Metrics
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Lint/CopDirectiveSyntax: Malformed directive comment detected. Cop names must be separated by commas. Comment in the directive must start with `--`.
+# nitrocop:only Lint:Syntax
+^^^^^^^^^^^^^^^^^^^^^^^^^^^ Lint/CopDirectiveSyntax: Malformed directive comment detected. Cop names must be separated by commas. Comment in the directive must start with `--`.
+# nitrocop:except -- generated
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Lint/CopDirectiveSyntax: Malformed directive comment detected. The cop name is missing.
+
+require "json"
+
+# nitrocop:only Lint
+^^^^^^^^^^^^^^^^^^^^ Lint/CopDirectiveSyntax: Misplaced directive comment detected. `nitrocop:only` and `nitrocop:except` must appear before the first line of code.
+def foo
+  # nitrocop:except Layout
+  ^^^^^^^^^^^^^^^^^^^^^^^^ Lint/CopDirectiveSyntax: Misplaced directive comment detected. `nitrocop:only` and `nitrocop:except` must appear before the first line of code.
+end
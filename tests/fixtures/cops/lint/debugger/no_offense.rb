@@ -27,3 +27,9 @@ x = debugger
 self.lib_options.debugger = debugger
 # debugger as keyword argument value
 invoke "1", debugger: debugger
+# receivers the method table does not name
+obj.pry
+Foo::Kernel.binding.pry
+session.binding.irb
+# safe navigation is not a debugger call
+binding&.pry
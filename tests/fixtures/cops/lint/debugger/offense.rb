@@ -6,3 +6,11 @@ byebug
 ^^^^^^ Lint/Debugger: Remove debugger entry point `byebug`.
 binding.irb
 ^^^^^^^^^^^ Lint/Debugger: Remove debugger entry point `binding.irb`.
+def show
+  binding.break
+  ^^^^^^^^^^^^^ Lint/Debugger: Remove debugger entry point `binding.break`.
+end
+x = 1; Kernel.binding.pry
+       ^^^^^^^^^^^^^^^^^^ Lint/Debugger: Remove debugger entry point `Kernel.binding.pry`.
+if ready then ::Kernel.binding.pry end
+              ^^^^^^^^^^^^^^^^^^^^ Lint/Debugger: Remove debugger entry point `::Kernel.binding.pry`.
@@ -351,3 +351,13 @@ class LegacyAttrInIf
     end
   end
 end
+
+# Class.new assigned to a constant is that constant's body
+Account = ::Class.new do
+  attr_accessor :owner
+  def owner; @owner || "nobody"; end
+  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Lint/DuplicateMethods: Method `Account#owner` is defined at both test.rb:319 and test.rb:320.
+  def self.build; new; end
+  def self.build; new; end
+  ^^^^^^^^^^^^^^^^^^^^^^^^ Lint/DuplicateMethods: Method `Account.build` is defined at both test.rb:321 and test.rb:322.
+end
@@ -0,0 +1,21 @@
+require 'json'
+require 'yaml'
+
+require_relative 'foo'
+require_relative 'bar'
+
+require 'net/http'
+
+feature = 'json'
+require feature
+
+require(fullpath){ Kernel.require fullpath }
+
+# Logging
+require 'logger'
+# Still logging
+
+require 'set'
+
+x = require 'csv'
+require 'csv'
@@ -19,3 +19,22 @@ require feature
 
 require(fullpath){ Kernel.require fullpath }
                    ^^^^^^^^^^^^^^^^^^^^^^^ Lint/DuplicateRequire: Duplicate `require` detected.
+
+# Logging
+require 'logger'
+# Still logging
+require 'logger' # again
+^^^^^^^^^^^^^^^^ Lint/DuplicateRequire: Duplicate `require` detected.
+
+require 'set'
+
+require 'set'
+^^^^^^^^^^^^^ Lint/DuplicateRequire: Duplicate `require` detected.
+
+require 'set'
+^^^^^^^^^^^^^ Lint/DuplicateRequire: Duplicate `require` detected.
+
+x = require 'csv'
+require 'csv'
+require 'csv'
+^^^^^^^^^^^^^ Lint/DuplicateRequire: Duplicate `require` detected.
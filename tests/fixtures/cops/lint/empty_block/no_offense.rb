@@ -38,3 +38,12 @@ Context.create_table(:users) do |t|
   t.timestamps null: false
 end.define_model do
 end
+
+# Empty lambdas as default arguments (AllowEmptyLambdas default true)
+def on_success(callback = -> {})
+  callback.call
+end
+
+def on_failure(callback = lambda {}, fallback = proc {})
+  callback.call || fallback.call
+end
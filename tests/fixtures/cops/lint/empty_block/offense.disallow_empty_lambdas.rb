@@ -0,0 +1,19 @@
+def on_success(callback = -> {})
+                          ^ Lint/EmptyBlock: Empty block detected.
+  callback.call
+end
+
+handler = lambda {}
+          ^ Lint/EmptyBlock: Empty block detected.
+
+cleanup = proc do
+          ^ Lint/EmptyBlock: Empty block detected.
+end
+
+Proc.new {}
+^ Lint/EmptyBlock: Empty block detected.
+
+# A comment still exempts an empty lambda (AllowComments)
+noop = -> {
+  # intentionally empty
+}
@@ -37,3 +37,11 @@ elsif second_check &&
         # Silently ignore errors
       end
 end
+# Comment-only elsif branch documents an intentional no-op
+if first
+  work
+elsif second
+  # nothing to do for second yet
+else
+  other
+end
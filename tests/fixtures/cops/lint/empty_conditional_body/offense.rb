@@ -9,3 +9,10 @@ if another
 else
   do_work
 end
+if first
+  work
+elsif second
+^^^^^ Lint/EmptyConditionalBody: Avoid empty `elsif` conditions.
+else
+  other
+end
@@ -1,6 +1,10 @@
 do_something { it() }
 do_something { self.it }
 do_something { it(1) }
-foo.each { |x| x }
+do_something { || it }
+foo.each { |x| it }
 it { is_expected.to eq(1) }
 items.map { |item| item.name }
+def it_helper
+  it
+end
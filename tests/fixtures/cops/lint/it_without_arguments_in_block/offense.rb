@@ -1,2 +1,10 @@
-# No-op cop: `it` is a block parameter in Ruby 3.4+, making this cop obsolete.
-# This file is intentionally empty of offense annotations.
+0.times { it }
+          ^^ Lint/ItWithoutArgumentsInBlock: `it` calls without arguments will refer to the first block param in Ruby 3.4; use `it()` or `self.it`.
+do_something { puts it }
+                    ^^ Lint/ItWithoutArgumentsInBlock: `it` calls without arguments will refer to the first block param in Ruby 3.4; use `it()` or `self.it`.
+foo.each do
+  bar(it)
+      ^^ Lint/ItWithoutArgumentsInBlock: `it` calls without arguments will refer to the first block param in Ruby 3.4; use `it()` or `self.it`.
+end
+-> { it.to_s }
+     ^^ Lint/ItWithoutArgumentsInBlock: `it` calls without arguments will refer to the first block param in Ruby 3.4; use `it()` or `self.it`.
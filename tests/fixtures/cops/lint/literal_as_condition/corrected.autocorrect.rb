@@ -0,0 +1,25 @@
+top
+top
+bar
+value = top
+top
+result = foo
+foo
+1 && return
+while true
+  top
+end
+top while true
+until false
+  top
+end
+begin
+  top
+end while nil
+value = if true
+  top
+  bar
+end
+if false # keep me
+  top
+end
@@ -126,3 +126,14 @@ if false
   123
 else
 end.should == nil
+
+# Modifier loops on the infinite-loop literal are left to Style/InfiniteLoop
+top while true
+top until false
+
+# A literal operand of &&/|| that does not decide the result, under `!` or `case`
+!(x && 1)
+!(a || nil)
+case !(a || 1)
+when b then top
+end
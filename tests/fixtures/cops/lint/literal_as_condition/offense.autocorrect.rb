@@ -0,0 +1,65 @@
+if true
+   ^^^^ Lint/LiteralAsCondition: Literal `true` appeared as a condition.
+  top
+end
+if 1
+   ^ Lint/LiteralAsCondition: Literal `1` appeared as a condition.
+  top
+else
+  bar
+end
+if nil
+   ^^^ Lint/LiteralAsCondition: Literal `nil` appeared as a condition.
+  top
+else
+  bar
+end
+if false
+   ^^^^^ Lint/LiteralAsCondition: Literal `false` appeared as a condition.
+  top
+end
+unless :sym
+       ^^^^ Lint/LiteralAsCondition: Literal `:sym` appeared as a condition.
+  top
+end
+value = 42 ? top : bar
+        ^^ Lint/LiteralAsCondition: Literal `42` appeared as a condition.
+top if 42
+       ^^ Lint/LiteralAsCondition: Literal `42` appeared as a condition.
+top unless 42
+           ^^ Lint/LiteralAsCondition: Literal `42` appeared as a condition.
+result = 1 && foo
+         ^ Lint/LiteralAsCondition: Literal `1` appeared as a condition.
+nil || foo
+^^^ Lint/LiteralAsCondition: Literal `nil` appeared as a condition.
+1 && return
+^ Lint/LiteralAsCondition: Literal `1` appeared as a condition.
+while 1
+      ^ Lint/LiteralAsCondition: Literal `1` appeared as a condition.
+  top
+end
+top while :sym
+          ^^^^ Lint/LiteralAsCondition: Literal `:sym` appeared as a condition.
+until nil
+      ^^^ Lint/LiteralAsCondition: Literal `nil` appeared as a condition.
+  top
+end
+while false
+      ^^^^^ Lint/LiteralAsCondition: Literal `false` appeared as a condition.
+  top
+end
+top until 1
+          ^ Lint/LiteralAsCondition: Literal `1` appeared as a condition.
+begin
+  top
+end while nil
+          ^^^ Lint/LiteralAsCondition: Literal `nil` appeared as a condition.
+value = if true
+           ^^^^ Lint/LiteralAsCondition: Literal `true` appeared as a condition.
+  top
+  bar
+end
+if false # keep me
+   ^^^^^ Lint/LiteralAsCondition: Literal `false` appeared as a condition.
+  top
+end
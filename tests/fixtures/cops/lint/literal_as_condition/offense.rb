@@ -184,3 +184,36 @@ if true;
 else
 end
 
+# case predicate through parentheses and `!` (RuboCop's check_case)
+case (1)
+      ^ Lint/LiteralAsCondition: Literal `1` appeared as a condition.
+when x then top
+end
+case !nil
+      ^^^ Lint/LiteralAsCondition: Literal `nil` appeared as a condition.
+when x then top
+end
+case (:sym)
+      ^^^^ Lint/LiteralAsCondition: Literal `:sym` appeared as a condition.
+in Symbol then top
+end
+
+# `!` through nested negation and parentheses
+!(!1)
+   ^ Lint/LiteralAsCondition: Literal `1` appeared as a condition.
+!((nil))
+   ^^^ Lint/LiteralAsCondition: Literal `nil` appeared as a condition.
+if !(!:sym)
+      ^^^^ Lint/LiteralAsCondition: Literal `:sym` appeared as a condition.
+  top
+end
+
+# Modifier while/until
+top while 1
+          ^ Lint/LiteralAsCondition: Literal `1` appeared as a condition.
+top while false
+          ^^^^^ Lint/LiteralAsCondition: Literal `false` appeared as a condition.
+top until nil
+          ^^^ Lint/LiteralAsCondition: Literal `nil` appeared as a condition.
+top until true
+          ^^^^ Lint/LiteralAsCondition: Literal `true` appeared as a condition.
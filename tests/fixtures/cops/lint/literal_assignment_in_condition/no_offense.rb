@@ -30,3 +30,11 @@ if has_terminal?(@regexps[gr = '\A[
     ]'] ||= Regexp.new(gr), :regexp, index)
   do_something
 end
+
+if (a, b = *list)
+  do_something
+end
+
+case value = 42
+when 42 then do_something
+end
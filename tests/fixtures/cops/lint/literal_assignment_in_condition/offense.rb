@@ -64,3 +64,20 @@ end
 
 if instance_variable_defined?(ivar = :"@#{type}_fields")
                               ^^^^^^^^^^^^^^^^^^^^^^^^ Lint/LiteralAssignmentInCondition: Don't use literal assignment `= :"@#{type}_fields"` in conditional, should be `==` or non-literal operand.
+
+if Config::LIMIT = 10
+   ^^^^^^^^^^^^^^^^^^ Lint/LiteralAssignmentInCondition: Don't use literal assignment `= 10` in conditional, should be `==` or non-literal operand.
+  do_something
+end
+
+if (a, b = 1, 2)
+    ^^^^^^^^^^^ Lint/LiteralAssignmentInCondition: Don't use literal assignment `= 1, 2` in conditional, should be `==` or non-literal operand.
+  do_something
+end
+
+do_something while line = "next"
+                   ^^^^^^^^^^^^^ Lint/LiteralAssignmentInCondition: Don't use literal assignment `= "next"` in conditional, should be `==` or non-literal operand.
+do_something until done = true
+                   ^^^^^^^^^^^ Lint/LiteralAssignmentInCondition: Don't use literal assignment `= true` in conditional, should be `==` or non-literal operand.
+(ready = false) ? start : stop
+ ^^^^^^^^^^^^^ Lint/LiteralAssignmentInCondition: Don't use literal assignment `= false` in conditional, should be `==` or non-literal operand.
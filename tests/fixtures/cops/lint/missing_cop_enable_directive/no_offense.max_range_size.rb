@@ -0,0 +1,12 @@
+# rubocop:disable Layout/SpaceAroundOperators
+x =   0
+y =   1
+# rubocop:enable Layout/SpaceAroundOperators
+
+# rubocop:disable Layout
+x =   0
+# rubocop:enable Layout
+
+x = 1 # rubocop:disable Layout/LineLength
+y = 2
+z = 3 # rubocop:disable Style/NumericLiterals
@@ -0,0 +1,18 @@
+# rubocop:disable Layout/SpaceAroundOperators
+^ Lint/MissingCopEnableDirective: Re-enable Layout/SpaceAroundOperators cop within 2 lines after disabling it.
+x =   0
+y =   1
+z =   2
+# rubocop:enable Layout/SpaceAroundOperators
+
+# rubocop:disable Layout
+^ Lint/MissingCopEnableDirective: Re-enable Layout department within 2 lines after disabling it.
+x =   0
+y =   1
+z =   2
+# rubocop:enable Layout
+
+x = 1 # rubocop:disable Style/NumericLiterals
+# rubocop:disable Style/NumericLiterals
+^ Lint/MissingCopEnableDirective: Re-enable Style/NumericLiterals cop within 2 lines after disabling it.
+y = 10000
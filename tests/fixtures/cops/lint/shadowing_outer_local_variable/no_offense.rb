@@ -415,3 +415,35 @@ def get_login_info(sources)
     end
   end
 end
+
+# Variable assigned only in the then branch; the block is in the else branch
+def branch_only_assignment(records, fallbacks)
+  if records.one?
+    record = records.first
+  else
+    fallbacks.each { |record| puts record }
+  end
+end
+
+# Pattern binding in one `in` clause, block in another
+def render(node)
+  case node
+  in { type: :text, value: }
+    value
+  in { type: :list, items: }
+    items.map { |value| render(value) }
+  end
+end
+
+# Pattern binding in the same `in` clause as the block
+def names(payload)
+  case payload
+  in { users: [*, { name: }, *] }
+    payload[:users].map { |name| name.upcase }
+  end
+end
+
+# Underscore-prefixed block params are exempt
+def ignored(_item, items)
+  items.each { |_item| count += 1 }
+end
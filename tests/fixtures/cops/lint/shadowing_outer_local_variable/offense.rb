@@ -507,3 +507,17 @@ def total_sum_at_index(index)
                                                    ^^^^^ Lint/ShadowingOuterLocalVariable: Shadowing outer local variable - `total`.
   total
 end
+
+def for_loop_variable(list)
+  for entry in list
+    puts entry
+  end
+  list.each { |entry| puts entry }
+               ^^^^^ Lint/ShadowingOuterLocalVariable: Shadowing outer local variable - `entry`.
+end
+
+def pattern_binding_outside_case(config)
+  config => { name: }
+  config.fetch(:aliases).each { |name| puts name }
+                                 ^^^^ Lint/ShadowingOuterLocalVariable: Shadowing outer local variable - `name`.
+end
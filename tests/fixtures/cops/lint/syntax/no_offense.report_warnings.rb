@@ -0,0 +1,9 @@
+def check(x)
+  if x == 1
+    puts x
+  end
+  at_exit { puts x }
+end
+
+puts "yes" if ENV["YES"]
+check({ a: 1, b: 2 })
@@ -0,0 +1,13 @@
+def check(x)
+  if x = 1
+         ^ Lint/Syntax: found [...] in conditional, should be ==
+    puts x
+  end
+  END { puts x }
+  ^^^ Lint/Syntax: END in method; use at_exit
+end
+
+puts "yes" if "yes"
+              ^^^^^ Lint/Syntax: string literal in condition
+check({ a: 1, a: 2 })
+        ^^ Lint/Syntax: key :a is duplicated and overwritten on line 9
@@ -0,0 +1,57 @@
+class Post < ApplicationRecord
+  private
+
+  def slug; end
+
+  concerning :Publishing do
+    private
+
+    def publish!; end
+  end
+end
+
+module Searchable
+  extend ActiveSupport::Concern
+
+  private
+
+  def normalize(term); end
+
+  class_methods do
+    private
+
+    def search_scope; end
+  end
+end
+
+module Taggable
+  extend ActiveSupport::Concern
+
+  private
+
+  def tag_list; end
+
+  ActiveSupport::Concern.class_methods do
+    private
+
+    def tag_scope; end
+  end
+end
+
+class Order
+  def total; end
+
+  private
+
+  delegate :currency, to: :account
+end
+
+class WorkQueue
+  extend Forwardable
+
+  def size; end
+
+  private
+
+  def_delegator :@items, :first, :peek
+end
@@ -0,0 +1,37 @@
+class Post < ApplicationRecord
+  concerning :Publishing do
+    public
+    ^^^^^^ Lint/UselessAccessModifier: Useless `public` access modifier.
+
+    def publish!; end
+  end
+end
+
+module Searchable
+  class_methods do
+    def search; end
+
+    private
+    ^^^^^^^ Lint/UselessAccessModifier: Useless `private` access modifier.
+  end
+end
+
+class Order
+  private
+
+  delegate :total, to: :cart
+
+  private
+  ^^^^^^^ Lint/UselessAccessModifier: Useless `private` access modifier.
+
+  delegate :items, to: :cart
+end
+
+class WorkQueue
+  extend Forwardable
+
+  def_delegator :@items, :size
+
+  protected
+  ^^^^^^^^^ Lint/UselessAccessModifier: Useless `protected` access modifier.
+end
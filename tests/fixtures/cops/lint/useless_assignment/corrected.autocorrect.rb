@@ -0,0 +1,29 @@
+def literal_value
+  do_something
+end
+
+def method_call_value
+  compute_value
+  do_something
+end
+
+def last_statement
+  'hello'
+end
+
+def overwritten
+  foo = 3
+  puts foo
+end
+
+def trailing_comment
+  1 # keep me
+  bar
+end
+
+def heredoc_value
+  <<~TEXT
+    body
+  TEXT
+  done
+end
@@ -0,0 +1,37 @@
+def literal_value
+  unused = 1
+  ^^^^^^ Lint/UselessAssignment: Useless assignment to variable - `unused`.
+  do_something
+end
+
+def method_call_value
+  result = compute_value
+  ^^^^^^ Lint/UselessAssignment: Useless assignment to variable - `result`.
+  do_something
+end
+
+def last_statement
+  value = 'hello'
+  ^^^^^ Lint/UselessAssignment: Useless assignment to variable - `value`.
+end
+
+def overwritten
+  foo = [1, :two, BAR]
+  ^^^ Lint/UselessAssignment: Useless assignment to variable - `foo`.
+  foo = 3
+  puts foo
+end
+
+def trailing_comment
+  foo = 1 # keep me
+  ^^^ Lint/UselessAssignment: Useless assignment to variable - `foo`.
+  bar
+end
+
+def heredoc_value
+  text = <<~TEXT
+  ^^^^ Lint/UselessAssignment: Useless assignment to variable - `text`.
+    body
+  TEXT
+  done
+end
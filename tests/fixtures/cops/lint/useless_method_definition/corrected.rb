@@ -0,0 +1,18 @@
+class Foo
+
+  # def self.method with bare super
+
+  # def self.method with explicit super(arg)
+
+  # method with keyword rest (**kwargs) just calling super
+
+  # method with keyword rest forwarding super(**kwargs)
+
+  # method with block arg forwarding
+
+  # access modifier wrapper — still flagged
+
+  class << self
+
+  end
+end
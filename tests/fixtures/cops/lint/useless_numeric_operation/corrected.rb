@@ -0,0 +1,8 @@
+x
+x
+x
+x
+x
+x = x
+x = x
+total = count + offset
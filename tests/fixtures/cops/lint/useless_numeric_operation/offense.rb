@@ -12,3 +12,5 @@ x += 0
 ^^^^^^ Lint/UselessNumericOperation: Do not apply inconsequential numeric operations to variables.
 x *= 1
 ^^^^^^ Lint/UselessNumericOperation: Do not apply inconsequential numeric operations to variables.
+total = count ** 1 + offset
+        ^^^^^^^^^^ Lint/UselessNumericOperation: Do not apply inconsequential numeric operations to variables.
@@ -0,0 +1,22 @@
+something
+1.times
+something
+
+def run_once
+  log(0)
+  [1, 2].each { |n| retry_with(n, 0) }
+end
+
+puts 0
+
+# Not corrected: locals assigned in the block would leak into this scope
+1.times do
+  result = compute
+  use(result)
+end
+
+# Not corrected: the return value is used
+value = 1.times { work }
+
+# Not corrected: the parameter is reassigned
+1.times { |i| i += 1; work(i) }
@@ -10,3 +10,34 @@
 ^^^^^^^^^^^^^^^^^^^^ Lint/UselessTimes: Useless call to `0.times` detected.
 1.times(&:something)
 ^^^^^^^^^^^^^^^^^^^^ Lint/UselessTimes: Useless call to `1.times` detected.
+
+def run_once
+  1.times do |attempt|
+  ^^^^^^^^^^^^^^^^^^^^ Lint/UselessTimes: Useless call to `1.times` detected.
+    log(attempt)
+    [1, 2].each { |n| retry_with(n, attempt) }
+  end
+end
+
+0.times do
+^^^^^^^^^^ Lint/UselessTimes: Useless call to `0.times` detected.
+  never_runs
+end
+
+1.times { |i| puts i }
+^^^^^^^^^^^^^^^^^^^^^^ Lint/UselessTimes: Useless call to `1.times` detected.
+
+# Not corrected: locals assigned in the block would leak into this scope
+1.times do
+^^^^^^^^^^ Lint/UselessTimes: Useless call to `1.times` detected.
+  result = compute
+  use(result)
+end
+
+# Not corrected: the return value is used
+value = 1.times { work }
+        ^^^^^^^^^^^^^^^^ Lint/UselessTimes: Useless call to `1.times` detected.
+
+# Not corrected: the parameter is reassigned
+1.times { |i| i += 1; work(i) }
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Lint/UselessTimes: Useless call to `1.times` detected.
@@ -238,6 +238,24 @@ def power_operator
   do_something
 end
 
+# begin as a block's return value: only the last expression is used
+result = foo.map do |x|
+  begin
+    bar(x)
+    x == 1
+  end
+end
+
+# begin with rescue in an each block — the statements belong to the rescue
+foo.each do
+  begin
+    bar
+    baz == 1
+  rescue StandardError
+    nil
+  end
+end
+
 # proc with numbered parameters — RuboCop's proc? uses (block ...) which
 # doesn't match numblock/itblock in Parser gem. Not flagged.
 proc { _1 + _2 }
@@ -313,3 +313,23 @@ def void_hash_range_values
   ^^^^^^^^^^^^^^ Lint/Void: Void value expression detected.
   top
 end
+
+# Explicit begin as the sole body of a void context is itself void context,
+# so its last expression is checked as well
+def initialize
+  begin
+    @value = 1
+    @value == 1
+           ^^ Lint/Void: Void value expression detected.
+    @value
+    ^^^^^^ Lint/Void: Void value expression detected.
+  end
+end
+
+foo.tap do
+  begin
+    bar
+    42
+    ^^ Lint/Void: Void value expression detected.
+  end
+end
@@ -0,0 +1,56 @@
+RSpec.describe User do
+  let(:user) { build(:user) }
+
+  context 'when active' do
+    before { user.activate! }
+
+    it { is_expected.to be_active }
+    it { is_expected.to be_persisted }
+  end
+
+  describe '#name' do
+    subject { user.name }
+
+    it { is_expected.to eq('Alice') }
+    it { is_expected.not_to be_empty }
+  end
+end
+
+describe 'legacy behaviour' do
+  shared_examples 'a record' do
+    it { is_expected.to be_valid }
+    it { is_expected.to be_persisted }
+    it { is_expected.to respond_to(:id) }
+    it { is_expected.to respond_to(:name) }
+  end
+
+  feature 'signing in' do
+    visit root_path
+    click_on 'Sign in'
+    fill_in 'Email', with: 'a@example.com'
+    click_on 'Submit'
+  end
+
+  scenario_outline 'signing out' do
+    visit root_path
+    click_on 'Sign out'
+    expect(page).to have_content('Bye')
+    expect(page).to have_current_path(root_path)
+  end
+end
+
+namespace :db do
+  task :seed do
+    load_seeds
+    report
+    cleanup
+    notify
+  end
+end
+
+Foo.configure do |config|
+  config.a = 1
+  config.b = 2
+  config.c = 3
+  config.d = 4
+end
@@ -0,0 +1,23 @@
+Bar.configure do |config|
+^ Metrics/BlockLength: Block has too many lines. [4/3]
+  config.a = 1
+  config.b = 2
+  config.c = 3
+  config.d = 4
+end
+
+configure do |config|
+^ Metrics/BlockLength: Block has too many lines. [4/3]
+  config.a = 1
+  config.b = 2
+  config.c = 3
+  config.d = 4
+end
+
+it 'does many things' do
+^ Metrics/BlockLength: Block has too many lines. [4/3]
+  visit root_path
+  click_on 'Sign in'
+  click_on 'Submit'
+  expect(page).to have_content('Welcome')
+end
@@ -0,0 +1,36 @@
+class Report
+  def to_h
+    a = 1
+    b = 2
+    c = 3
+    { a: a, b: b, c: c }
+  end
+
+  def as_json
+    a = 1
+    b = 2
+    c = 3
+    { a: a, b: b, c: c }
+  end
+
+  def render
+    a = 1
+    b = 2
+    c = 3
+    [a, b, c].join
+  end
+
+  def build_rows
+    a = 1
+    b = 2
+    c = 3
+    [a, b, c]
+  end
+
+  define_method(:render) do
+    a = 1
+    b = 2
+    c = 3
+    [a, b, c].join
+  end
+end
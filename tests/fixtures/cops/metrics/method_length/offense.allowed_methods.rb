@@ -0,0 +1,25 @@
+class Report
+  def serialize
+  ^^^ Metrics/MethodLength: Method has too many lines. [4/3]
+    a = 1
+    b = 2
+    c = 3
+    [a, b, c]
+  end
+
+  def rebuild_rows
+  ^^^ Metrics/MethodLength: Method has too many lines. [4/3]
+    a = 1
+    b = 2
+    c = 3
+    [a, b, c]
+  end
+
+  define_method(:export) do
+  ^^^^^^^^^^^^^ Metrics/MethodLength: Method has too many lines. [4/3]
+    a = 1
+    b = 2
+    c = 3
+    [a, b, c]
+  end
+end
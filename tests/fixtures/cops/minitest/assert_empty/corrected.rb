@@ -0,0 +1,5 @@
+assert_empty(somestuff)
+assert_empty(somestuff, 'message')
+assert_empty(somestuff)
+assert_empty(self)
+assert_empty foo.bar
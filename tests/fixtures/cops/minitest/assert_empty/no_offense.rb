@@ -0,0 +1,6 @@
+assert_empty(somestuff)
+assert_empty(somestuff, 'message')
+assert(somestuff.any?)
+refute(somestuff.empty?)
+assert(somestuff)
+foo.assert(somestuff.empty?)
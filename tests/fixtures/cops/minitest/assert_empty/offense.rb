@@ -0,0 +1,10 @@
+assert(somestuff.empty?)
+^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertEmpty: Prefer using `assert_empty(somestuff)`.
+assert(somestuff.empty?, 'message')
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertEmpty: Prefer using `assert_empty(somestuff, 'message')`.
+assert((somestuff.empty?))
+^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertEmpty: Prefer using `assert_empty(somestuff)`.
+assert(empty?)
+^^^^^^^^^^^^^^ Minitest/AssertEmpty: Prefer using `assert_empty(self)`.
+assert foo.bar.empty?
+^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertEmpty: Prefer using `assert_empty(foo.bar)`.
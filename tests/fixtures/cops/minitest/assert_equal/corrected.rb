@@ -0,0 +1,6 @@
+assert_equal('rubocop-minitest', actual)
+assert_equal(expected, actual, 'message')
+assert_equal foo.size, 3
+assert_equal(
+  expected, actual
+)
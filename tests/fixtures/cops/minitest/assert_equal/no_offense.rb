@@ -0,0 +1,7 @@
+assert_equal('rubocop-minitest', actual)
+assert_equal(expected, actual, 'message')
+assert(expected != actual)
+assert(actual)
+assert(expected.eql?(actual))
+refute(expected == actual)
+foo.assert(expected == actual)
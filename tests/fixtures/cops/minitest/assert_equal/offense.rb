@@ -0,0 +1,10 @@
+assert('rubocop-minitest' == actual)
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertEqual: Prefer using `assert_equal('rubocop-minitest', actual)`.
+assert(expected == actual, 'message')
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertEqual: Prefer using `assert_equal(expected, actual, 'message')`.
+assert foo.size == 3
+^^^^^^^^^^^^^^^^^^^^ Minitest/AssertEqual: Prefer using `assert_equal(foo.size, 3)`.
+assert(
+^^^^^^ Minitest/AssertEqual: Prefer using `assert_equal(expected, actual)`.
+  expected == actual
+)
@@ -0,0 +1,4 @@
+assert_includes(collection, object)
+assert_includes(collection, object, 'message')
+assert_includes(collection, object)
+assert_includes self, object
@@ -0,0 +1,6 @@
+assert_includes(collection, object)
+assert_includes(collection, object, 'message')
+refute(collection.include?(object))
+assert(collection.member?(object))
+assert(collection.empty?)
+foo.assert(collection.include?(object))
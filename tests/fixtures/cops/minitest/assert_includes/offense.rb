@@ -0,0 +1,8 @@
+assert(collection.include?(object))
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertIncludes: Prefer using `assert_includes(collection, object)`.
+assert(collection.include?(object), 'message')
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertIncludes: Prefer using `assert_includes(collection, object, 'message')`.
+assert((collection.include?(object)))
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertIncludes: Prefer using `assert_includes(collection, object)`.
+assert include?(object)
+^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertIncludes: Prefer using `assert_includes(self, object)`.
@@ -0,0 +1,6 @@
+assert_nil(somestuff)
+assert_nil(somestuff, 'message')
+assert_nil(somestuff)
+assert_nil(obj.foo, 'message')
+assert_nil(somestuff)
+assert_nil somestuff
@@ -0,0 +1,8 @@
+assert_nil(somestuff)
+assert_nil(somestuff, 'message')
+assert_equal(somestuff, nil)
+assert_equal(false, somestuff)
+assert(somestuff)
+assert_predicate(somestuff, :empty?)
+refute(somestuff.nil?)
+foo.assert_equal(nil, somestuff)
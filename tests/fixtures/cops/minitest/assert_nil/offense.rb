@@ -0,0 +1,12 @@
+assert_equal(nil, somestuff)
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertNil: Prefer using `assert_nil(somestuff)`.
+assert_equal(nil, somestuff, 'message')
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertNil: Prefer using `assert_nil(somestuff, 'message')`.
+assert(somestuff.nil?)
+^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertNil: Prefer using `assert_nil(somestuff)`.
+assert(obj.foo.nil?, 'message')
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertNil: Prefer using `assert_nil(obj.foo, 'message')`.
+assert_predicate(somestuff, :nil?)
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertNil: Prefer using `assert_nil(somestuff)`.
+assert_equal nil, somestuff
+^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertNil: Prefer using `assert_nil(somestuff)`.
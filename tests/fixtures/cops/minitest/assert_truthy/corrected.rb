@@ -0,0 +1,3 @@
+assert(actual)
+assert(actual, 'message')
+assert foo.valid?
@@ -0,0 +1,6 @@
+assert(actual)
+assert(actual, 'message')
+assert_equal(actual, true)
+assert_equal(false, actual)
+assert_equal(expected, actual)
+foo.assert_equal(true, actual)
@@ -0,0 +1,6 @@
+assert_equal(true, actual)
+^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertTruthy: Prefer using `assert(actual)`.
+assert_equal(true, actual, 'message')
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertTruthy: Prefer using `assert(actual, 'message')`.
+assert_equal true, foo.valid?
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/AssertTruthy: Prefer using `assert(foo.valid?)`.
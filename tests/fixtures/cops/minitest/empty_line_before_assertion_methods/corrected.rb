@@ -0,0 +1,35 @@
+def test_do_something
+  do_something
+
+  assert_equal(expected, actual)
+end
+
+def test_with_block
+  do_something
+
+  assert_raises(CustomError) do
+    raise CustomError
+  end
+end
+
+def test_after_multiline_statement
+  result = do_something(
+    foo
+  )
+
+  refute_nil(result)
+end
+
+def test_after_heredoc
+  do_something(<<~EOS)
+    text
+  EOS
+
+  assert(ok)
+end
+
+def test_flunk
+  do_something
+
+  flunk
+end
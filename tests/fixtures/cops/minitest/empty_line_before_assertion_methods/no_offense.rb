@@ -0,0 +1,45 @@
+def test_do_something
+  do_something
+
+  assert_equal(expected, actual)
+end
+
+def test_consecutive_assertions
+  assert_equal(expected, actual)
+  assert_nil(foo)
+  refute(bar)
+end
+
+def test_assertion_first
+  assert(foo)
+end
+
+def test_after_assignment_of_assertion
+  error = assert_raises(CustomError) { raise CustomError }
+  assert_equal('message', error.message)
+end
+
+def test_after_block_ending_with_assertion
+  items.each do |item|
+    assert(item.valid?)
+  end
+  assert_equal(3, items.size)
+end
+
+def test_with_comment_between
+  do_something
+  # the result is cached
+  assert(cached?)
+end
+
+def test_same_line
+  do_something; assert(ok)
+end
+
+def test_after_heredoc
+  do_something(<<~EOS)
+    text
+  EOS
+
+  assert(ok)
+end
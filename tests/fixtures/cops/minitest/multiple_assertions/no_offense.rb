@@ -0,0 +1,58 @@
+class FooTest < Minitest::Test
+  def test_asserts_three_times
+    assert_equal(foo, bar)
+    assert_empty(array)
+    assert_nil(baz)
+  end
+
+  def test_only_counts_busiest_branch
+    if condition
+      assert(foo)
+      assert(bar)
+    else
+      assert(baz)
+      assert(qux)
+    end
+  end
+
+  def test_with_argument(value)
+    assert(foo)
+    assert(bar)
+    assert(baz)
+    assert(qux)
+  end
+
+  def helper_method
+    assert(foo)
+    assert(bar)
+    assert(baz)
+    assert(qux)
+  end
+
+  private
+
+  def test_private_helper
+    assert(foo)
+    assert(bar)
+    assert(baz)
+    assert(qux)
+  end
+end
+
+class FooHelper < Base
+  def test_not_in_a_test_class
+    assert(foo)
+    assert(bar)
+    assert(baz)
+    assert(qux)
+  end
+end
+
+class FooTest
+  def test_without_superclass
+    assert(foo)
+    assert(bar)
+    assert(baz)
+    assert(qux)
+  end
+end
@@ -0,0 +1,51 @@
+class FooTest < Minitest::Test
+  def test_asserts_four_times
+  ^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/MultipleAssertions: Test case has too many assertions [4/3].
+    assert_equal(foo, bar)
+    assert_empty(array)
+    assert_nil(baz)
+    refute(qux)
+  end
+
+  def test_counts_nested_assertions
+  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/MultipleAssertions: Test case has too many assertions [4/3].
+    assert_raises(CustomError) do
+      assert(foo)
+      assert(bar)
+      assert(baz)
+    end
+  end
+
+  def test_counts_busiest_branch
+  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/MultipleAssertions: Test case has too many assertions [5/3].
+    assert(setup)
+    if condition
+      assert(foo)
+      assert(bar)
+      assert(baz)
+      assert(qux)
+    else
+      assert(other)
+    end
+  end
+
+  test "declarative test case" do
+  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/MultipleAssertions: Test case has too many assertions [4/3].
+    assert(foo)
+    assert(bar)
+    assert(baz)
+    assert(qux)
+  end
+end
+
+class Admin::UserTest < ActiveSupport::TestCase
+  def test_rescue_counts_body_plus_branch
+  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/MultipleAssertions: Test case has too many assertions [4/3].
+    assert(foo)
+    assert(bar)
+    do_something
+  rescue StandardError
+    assert(baz)
+    assert(qux)
+  end
+end
@@ -0,0 +1,5 @@
+refute_equal('rubocop-minitest', actual)
+refute_equal(expected, actual, 'message')
+refute_equal(expected, actual)
+refute_equal(expected, actual)
+refute_equal foo.size, 3
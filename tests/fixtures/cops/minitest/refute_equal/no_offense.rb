@@ -0,0 +1,7 @@
+refute_equal('rubocop-minitest', actual)
+refute_equal(expected, actual, 'message')
+assert(expected == actual)
+refute(expected != actual)
+refute(actual)
+assert(!actual)
+foo.refute(expected == actual)
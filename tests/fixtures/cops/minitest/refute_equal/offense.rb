@@ -0,0 +1,10 @@
+assert('rubocop-minitest' != actual)
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/RefuteEqual: Prefer using `refute_equal('rubocop-minitest', actual)`.
+assert(expected != actual, 'message')
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/RefuteEqual: Prefer using `refute_equal(expected, actual, 'message')`.
+refute(expected == actual)
+^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/RefuteEqual: Prefer using `refute_equal(expected, actual)`.
+assert(!expected == actual)
+^^^^^^^^^^^^^^^^^^^^^^^^^^^ Minitest/RefuteEqual: Prefer using `refute_equal(expected, actual)`.
+refute foo.size == 3
+^^^^^^^^^^^^^^^^^^^^ Minitest/RefuteEqual: Prefer using `refute_equal(foo.size, 3)`.
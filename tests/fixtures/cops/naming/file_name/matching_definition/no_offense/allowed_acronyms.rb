@@ -0,0 +1,6 @@
+# nitrocop-filename: lib/my_api/http_client.rb
+module MyAPI
+  class HTTPClient
+    def get(path); end
+  end
+end
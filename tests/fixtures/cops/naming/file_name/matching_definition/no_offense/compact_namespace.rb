@@ -0,0 +1,6 @@
+# nitrocop-filename: lib/foo/bar_baz.rb
+require "foo"
+
+class Foo::BarBaz
+  def call; end
+end
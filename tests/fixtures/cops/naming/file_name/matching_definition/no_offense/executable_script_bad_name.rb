@@ -0,0 +1,5 @@
+# nitrocop-filename: bin/MyTool
+#!/usr/bin/env ruby
+require "my_tool"
+
+MyTool.run(ARGV)
@@ -0,0 +1,6 @@
+# nitrocop-filename: app/models/billing/invoice.rb
+module Billing
+  Invoice = Class.new(ApplicationRecord) do
+    self.table_name = "invoices"
+  end
+end
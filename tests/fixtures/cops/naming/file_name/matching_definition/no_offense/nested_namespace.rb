@@ -0,0 +1,6 @@
+# nitrocop-filename: lib/foo/bar_baz.rb
+module Foo
+  class BarBaz
+    def call; end
+  end
+end
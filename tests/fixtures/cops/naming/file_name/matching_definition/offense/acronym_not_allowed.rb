@@ -0,0 +1,6 @@
+# nitrocop-filename: lib/my_gem/json_parser.rb
+module MyGem
+^ Naming/FileName: `json_parser` should define a class or module called `MyGem::JsonParser`.
+  class JSONParser
+  end
+end
@@ -0,0 +1,5 @@
+# nitrocop-filename: bin/deploy
+#!/usr/bin/env ruby
+^ Naming/FileName: `deploy` should define a class or module called `Deploy`.
+require "deployer"
+Deployer.run(ARGV)
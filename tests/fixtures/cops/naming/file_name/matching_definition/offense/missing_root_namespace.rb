@@ -0,0 +1,4 @@
+# nitrocop-filename: app/models/billing/invoice.rb
+class Invoice < ApplicationRecord
+^ Naming/FileName: `invoice` should define a class or module called `Billing::Invoice`.
+end
@@ -0,0 +1,6 @@
+# nitrocop-filename: lib/foo/bar_baz.rb
+module Foo
+^ Naming/FileName: `bar_baz` should define a class or module called `Foo::BarBaz`.
+  class Other
+  end
+end
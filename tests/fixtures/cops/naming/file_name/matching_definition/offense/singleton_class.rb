@@ -0,0 +1,7 @@
+# nitrocop-filename: lib/foo/bar.rb
+module Foo
+^ Naming/FileName: `bar` should define a class or module called `Foo::Bar`.
+  class << self
+    Bar = Class.new
+  end
+end
@@ -0,0 +1,15 @@
+def js_modules
+  @js_modules ||= compute_modules
+end
+def other_modules
+  @_other_modules ||= compute_modules
+end
+def ready?
+  @_ready ||= check_ready
+end
+def _strategies
+  @strategies ||= build_strategies
+end
+define_method(:values) do
+  @_values ||= do_something
+end
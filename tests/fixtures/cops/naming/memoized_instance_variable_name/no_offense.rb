@@ -216,3 +216,12 @@ def self.included(base)
     @title_field ||= find_field(@model)
   end
 end
+
+# Predicate and bang methods memoize without the punctuation
+define_method(:ready?) do
+  @ready ||= check_ready
+end
+define_method(:save!) { @save ||= persist }
+def enabled?
+  @enabled ||= settings.fetch(:enabled)
+end
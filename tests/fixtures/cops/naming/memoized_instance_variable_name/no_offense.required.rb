@@ -0,0 +1,17 @@
+def js_modules
+  @_js_modules ||= compute_modules
+end
+def ready?
+  @_ready ||= check_ready
+end
+# A method that already starts with `_` may use its own name
+def _strategies
+  @_strategies ||= build_strategies
+end
+define_method(:values) do
+  @_values ||= do_something
+end
+def token
+  return @_token if defined?(@_token)
+  @_token = create_token
+end
@@ -0,0 +1,12 @@
+def js_modules
+  @modules ||= compute_modules
+  ^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@modules` does not match method name `js_modules`. Use `@js_modules` instead.
+end
+def ready?
+  @__ready ||= check_ready
+  ^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@__ready` does not match method name `ready?`. Use `@ready` instead.
+end
+define_method(:values) do
+  @_vals ||= do_something
+  ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@_vals` does not match method name `values`. Use `@values` instead.
+end
@@ -1,14 +1,14 @@
 def foo
   @bar ||= compute
-  ^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@bar` does not match method name `foo`.
+  ^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@bar` does not match method name `foo`. Use `@foo` instead.
 end
 def something
   @other ||= calculate
-  ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@other` does not match method name `something`.
+  ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@other` does not match method name `something`. Use `@something` instead.
 end
 def value
   @cached ||= fetch
-  ^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@cached` does not match method name `value`.
+  ^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@cached` does not match method name `value`. Use `@value` instead.
 end
 def issue_token!
   return @token if defined?(@token)
@@ -19,31 +19,31 @@ def issue_token!
 end
 define_method(:values) do
   @foo ||= do_something
-  ^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@foo` does not match method name `values`.
+  ^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@foo` does not match method name `values`. Use `@values` instead.
 end
 klass.define_method(:values) do
   @bar ||= do_something
-  ^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@bar` does not match method name `values`.
+  ^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@bar` does not match method name `values`. Use `@values` instead.
 end
 define_singleton_method(:values) do
   @baz ||= do_something
-  ^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@baz` does not match method name `values`.
+  ^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@baz` does not match method name `values`. Use `@values` instead.
 end
 def self.records
   @other ||= fetch_records
-  ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@other` does not match method name `records`.
+  ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@other` does not match method name `records`. Use `@records` instead.
 end
 def url_helpers
   if supports_path
     generate_url_helpers(true)
   else
     @helpers_without_paths ||= generate_url_helpers(false)
-    ^^^^^^^^^^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@helpers_without_paths` does not match method name `url_helpers`.
+    ^^^^^^^^^^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@helpers_without_paths` does not match method name `url_helpers`. Use `@url_helpers` instead.
   end
 end
 def list_users
   @username_cache ||= @user_provider.list_users unless @user_provider.nil?
-  ^^^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@username_cache` does not match method name `list_users`.
+  ^^^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@username_cache` does not match method name `list_users`. Use `@list_users` instead.
 end
 def size
   case @value
@@ -51,13 +51,13 @@ def size
     @value.bytesize
   else
     @cached_size ||= Marshal.dump(@value).bytesize
-    ^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@cached_size` does not match method name `size`.
+    ^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@cached_size` does not match method name `size`. Use `@size` instead.
   end
 end
 def ensure_subscribed
   @mutex.synchronize do
     @subscriber ||= ActiveSupport::Notifications.subscribe(self)
-    ^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@subscriber` does not match method name `ensure_subscribed`.
+    ^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@subscriber` does not match method name `ensure_subscribed`. Use `@ensure_subscribed` instead.
   end
 end
 def infer_decoder
@@ -66,43 +66,53 @@ rescue => e
   warn(e)
 ensure
   @decoder ||= :itself.to_proc
-  ^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@decoder` does not match method name `infer_decoder`.
+  ^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@decoder` does not match method name `infer_decoder`. Use `@infer_decoder` instead.
 end
 def param_encode_language(value = nil)
   value ? @encode_language = value : @encode_language ||= 'en'
-                                     ^^^^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@encode_language` does not match method name `param_encode_language`.
+                                     ^^^^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@encode_language` does not match method name `param_encode_language`. Use `@param_encode_language` instead.
 end
 def set_items exp = nil
   if exp
     @items ||= []
-    ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@items` does not match method name `set_items`.
+    ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@items` does not match method name `set_items`. Use `@set_items` instead.
     @items.concat [exp]
   else
     @items ||= []
-    ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@items` does not match method name `set_items`.
+    ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@items` does not match method name `set_items`. Use `@set_items` instead.
   end
 end
 def supported?
   return @data ||= begin
-         ^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@data` does not match method name `supported?`.
+         ^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@data` does not match method name `supported?`. Use `@supported` instead.
     compute_support
   end
 end
 def configure
   yield @data ||= Configuration.new
-        ^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@data` does not match method name `configure`.
+        ^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@data` does not match method name `configure`. Use `@configure` instead.
 end
 def find_or_create_service
   @service = @service ||= RequestService.new
-             ^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@service` does not match method name `find_or_create_service`.
+             ^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@service` does not match method name `find_or_create_service`. Use `@find_or_create_service` instead.
 end
 def expensive_value
   @expensive_value ||= @cached_value ||= begin
-                       ^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@cached_value` does not match method name `expensive_value`.
+                       ^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@cached_value` does not match method name `expensive_value`. Use `@expensive_value` instead.
     compute_expensive
   end
 end
 define_method "accept_type" do
   return @_accept_type ||= "text/html"
-         ^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@_accept_type` does not match method name `accept_type`.
+         ^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@_accept_type` does not match method name `accept_type`. Use `@accept_type` instead.
+end
+define_method(:ready?) do
+  @is_ready ||= check_ready
+  ^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@is_ready` does not match method name `ready?`. Use `@ready` instead.
+end
+define_method(:save!) { @saved ||= persist }
+                        ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@saved` does not match method name `save!`. Use `@save` instead.
+def enabled?
+  @enabled_flag ||= settings.fetch(:enabled)
+  ^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@enabled_flag` does not match method name `enabled?`. Use `@enabled` instead.
 end
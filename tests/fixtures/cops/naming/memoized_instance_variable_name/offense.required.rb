@@ -0,0 +1,23 @@
+def js_modules
+  @js_modules ||= compute_modules
+  ^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@js_modules` does not start with `_`. Use `@_js_modules` instead.
+end
+def ready?
+  @ready ||= check_ready
+  ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@ready` does not start with `_`. Use `@_ready` instead.
+end
+def records
+  @_cached_records ||= fetch_records
+  ^^^^^^^^^^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@_cached_records` does not match method name `records`. Use `@_records` instead.
+end
+define_method(:values) do
+  @values ||= do_something
+  ^^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@values` does not start with `_`. Use `@_values` instead.
+end
+def token
+  return @token if defined?(@token)
+                            ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@token` does not start with `_`. Use `@_token` instead.
+         ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@token` does not start with `_`. Use `@_token` instead.
+  @token = create_token
+  ^^^^^^ Naming/MemoizedInstanceVariableName: Memoized variable `@token` does not start with `_`. Use `@_token` instead.
+end
@@ -0,0 +1,18 @@
+def GET_users
+end
+
+def POST_users(params)
+end
+
+def self.GET_status
+end
+
+define_method(:POST_items) { nil }
+attr_reader :GET_cache
+alias_method :POST_orders, :create_order
+Request = Struct.new(:GET_path)
+
+def toJSON
+end
+
+alias toJSON to_json
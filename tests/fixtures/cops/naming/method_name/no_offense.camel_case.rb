@@ -0,0 +1,32 @@
+def myMethod
+end
+
+def self.otherMethod
+end
+
+def valid?
+end
+
+def save!
+end
+
+def value=(other)
+end
+
+def setValue=(other)
+end
+
+def _
+end
+
+def ==(other)
+end
+
+def [](index)
+end
+
+attr_accessor :someValue
+define_method(:doThing) { nil }
+alias newName oldName
+alias_method :otherName, :oldName
+Point = Struct.new(:xCoord, :y)
@@ -0,0 +1,13 @@
+def GET_users
+end
+
+def PUT_users
+    ^^^^^^^^^ Naming/MethodName: Use snake_case for method names.
+end
+
+define_method :DELETE_users do
+              ^^^^^^^^^^^^^ Naming/MethodName: Use snake_case for method names.
+end
+
+attr_reader :otherName
+            ^^^^^^^^^^ Naming/MethodName: Use snake_case for method names.
@@ -0,0 +1,24 @@
+def my_method
+    ^^^^^^^^^ Naming/MethodName: Use camelCase for method names.
+end
+
+def self.other_method
+         ^^^^^^^^^^^^ Naming/MethodName: Use camelCase for method names.
+end
+
+def MyMethod
+    ^^^^^^^^ Naming/MethodName: Use camelCase for method names.
+end
+
+attr_reader :some_value
+            ^^^^^^^^^^^ Naming/MethodName: Use camelCase for method names.
+
+define_method :do_thing do
+              ^^^^^^^^^ Naming/MethodName: Use camelCase for method names.
+end
+
+alias_method :new_name, :oldName
+             ^^^^^^^^^ Naming/MethodName: Use camelCase for method names.
+
+Point = Struct.new(:x_coord)
+                   ^^^^^^^^ Naming/MethodName: Use camelCase for method names.
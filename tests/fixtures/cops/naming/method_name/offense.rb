@@ -100,3 +100,16 @@ class HighLine
   class HighLine::String < ::String
   end
 end
+
+# Struct.new and Data.define members define methods too
+Point = Struct.new(:xCoord, :y)
+                   ^^^^^^^ Naming/MethodName: Use snake_case for method names.
+Pair = Struct.new("Pair", :leftSide, :right)
+                          ^^^^^^^^^ Naming/MethodName: Use snake_case for method names.
+Coord = Data.define(:latValue)
+                    ^^^^^^^^^ Naming/MethodName: Use snake_case for method names.
+
+# Setter with a camelCase base name
+def fooBar=(value)
+    ^^^^^^^ Naming/MethodName: Use snake_case for method names.
+end
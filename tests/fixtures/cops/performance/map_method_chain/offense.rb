@@ -1,15 +1,18 @@
 x.map(&:foo).map(&:bar)
-  ^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map` with a block instead of chaining multiple `map` calls with symbol arguments.
+  ^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map { |x| x.foo.bar }` instead of `map` method chain.
 arr.map(&:to_s).map(&:upcase)
-    ^^^^^^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map` with a block instead of chaining multiple `map` calls with symbol arguments.
+    ^^^^^^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map { |x| x.to_s.upcase }` instead of `map` method chain.
 items.map(&:name).map(&:downcase)
-      ^^^^^^^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map` with a block instead of chaining multiple `map` calls with symbol arguments.
+      ^^^^^^^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map { |x| x.name.downcase }` instead of `map` method chain.
 # Triple chain should fire only once
 items.map(&:a).map(&:b).map(&:c)
-      ^^^^^^^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map` with a block instead of chaining multiple `map` calls with symbol arguments.
+      ^^^^^^^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map { |x| x.a.b.c }` instead of `map` method chain.
 # Without receiver
 map(&:foo).map(&:bar)
-^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map` with a block instead of chaining multiple `map` calls with symbol arguments.
+^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map { |x| x.foo.bar }` instead of `map` method chain.
 # Safe navigation on first call
 items&.map(&:foo).map(&:bar)
-       ^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map` with a block instead of chaining multiple `map` calls with symbol arguments.
+       ^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `map { |x| x.foo.bar }` instead of `map` method chain.
+# The message names the method at the start of the chain
+items.collect(&:foo).map(&:bar)
+      ^^^^^^^^^^^^^^^^^^^^^^^^^ Performance/MapMethodChain: Use `collect { |x| x.foo.bar }` instead of `collect` method chain.
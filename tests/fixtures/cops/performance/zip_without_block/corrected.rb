@@ -0,0 +1,8 @@
+[1, 2, 3].zip
+[1, 2, 3].zip
+(1..3).zip
+[1, 2, 3].zip
+foo.zip
+[1, 2, 3].zip
+[1, 2, 3].zip
+[1, 2, 3].zip
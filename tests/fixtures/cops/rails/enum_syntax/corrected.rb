@@ -0,0 +1,7 @@
+enum :status, { active: 0, archived: 1 }
+enum :role, [:admin, :user]
+enum :priority, { low: 0, medium: 1, high: 2 }
+enum :state, { draft: 0, published: 1 }, prefix: true, scopes: false
+enum :visibility, { open: 0, hidden: 1 }, prefix: true, default: :open
+# Several enums in one call are flagged but not corrected
+enum color: { red: 0 }, size: { small: 0 }
@@ -3,3 +3,7 @@ enum :role, [:admin, :user]
 enum :priority, { low: 0, medium: 1, high: 2 }
 enum :color, [:red, :green, :blue]
 enum :size, { small: 0, large: 1 }
+enum :status, { active: 0 }, prefix: true, scopes: false
+enum :kind, %i[a b], suffix: true
+enum(statuses)
+Model.enum status: { active: 0 }
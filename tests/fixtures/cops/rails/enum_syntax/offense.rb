@@ -1,6 +1,15 @@
 enum status: { active: 0, archived: 1 }
-^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/EnumSyntax: Use Rails 7+ enum syntax: `enum :status, { active: 0 }`.
+             ^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/EnumSyntax: Enum defined with keyword arguments in `status` enum declaration. Use positional arguments instead.
 enum role: [:admin, :user]
-^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/EnumSyntax: Use Rails 7+ enum syntax: `enum :status, { active: 0 }`.
+           ^^^^^^^^^^^^^^^ Rails/EnumSyntax: Enum defined with keyword arguments in `role` enum declaration. Use positional arguments instead.
 enum priority: { low: 0, medium: 1, high: 2 }
-^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/EnumSyntax: Use Rails 7+ enum syntax: `enum :status, { active: 0 }`.
+               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/EnumSyntax: Enum defined with keyword arguments in `priority` enum declaration. Use positional arguments instead.
+enum state: { draft: 0, published: 1 }, _prefix: true, _scopes: false
+            ^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/EnumSyntax: Enum defined with keyword arguments in `state` enum declaration. Use positional arguments instead.
+enum :visibility, { open: 0, hidden: 1 }, _prefix: true, _default: :open
+                                          ^^^^^^^ Rails/EnumSyntax: Enum defined with deprecated options in `visibility` enum declaration. Remove the `_` prefix.
+                                                         ^^^^^^^^ Rails/EnumSyntax: Enum defined with deprecated options in `visibility` enum declaration. Remove the `_` prefix.
+# Several enums in one call are flagged but not corrected
+enum color: { red: 0 }, size: { small: 0 }
+            ^^^^^^^^^^ Rails/EnumSyntax: Enum defined with keyword arguments in `color` enum declaration. Use positional arguments instead.
+                              ^^^^^^^^^^^^ Rails/EnumSyntax: Enum defined with keyword arguments in `size` enum declaration. Use positional arguments instead.
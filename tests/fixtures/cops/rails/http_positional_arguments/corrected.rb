@@ -0,0 +1,15 @@
+get :index, params: { user_id: 1 }, session: { "ACCEPT" => "text/html" }
+
+post :create, params: { name: "foo" }, session: { "X-TOKEN" => "abc" }
+
+put :update, params: { id: 1 }, session: { "Authorization" => "Bearer xyz" }
+
+patch :update, params: { id: 1, name: "bar" }
+
+delete(:destroy, params: { id: 1 })
+
+head :show, params: request_params
+
+get :new, session: { user_id: 2 }, flash: { notice: "hi" }
+
+get :index
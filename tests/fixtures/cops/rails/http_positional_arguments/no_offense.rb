@@ -3,9 +3,13 @@ get :index
 post :create, params: { name: "foo" }
 put :update, params: { id: 1, name: "bar" }
 delete :destroy, params: { id: 1 }
+patch :update, params: { id: 1 }, xhr: true
+head :show, format: :json
+post :create, **request_options
+get :show, session: { user_id: 1 }, flash: { notice: "hi" }
+client.get :index, { user_id: 1 }
 
-# Rack::Test::Methods — positional args are correct for Rack tests
-class RedirectorTest
-  include Rack::Test::Methods
-  get "/specs.4.8.gz", {}, "HTTP_HOST" => "example.com"
+Rails.application.routes.draw do
+  get "photos/:id", to: "photos#show"
+  post "photos", to: "photos#create", as: :photos
 end
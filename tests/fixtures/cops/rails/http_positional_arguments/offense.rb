@@ -1,8 +1,23 @@
 get :index, { user_id: 1 }, { "ACCEPT" => "text/html" }
-^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments for HTTP request methods.
+            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments instead of positional arguments for http call: `get`.
 
 post :create, { name: "foo" }, { "X-TOKEN" => "abc" }
-^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments for HTTP request methods.
+              ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments instead of positional arguments for http call: `post`.
 
 put :update, { id: 1 }, { "Authorization" => "Bearer xyz" }
-^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments for HTTP request methods.
+             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments instead of positional arguments for http call: `put`.
+
+patch :update, id: 1, name: "bar"
+               ^^^^^^^^^^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments instead of positional arguments for http call: `patch`.
+
+delete(:destroy, { id: 1 })
+                 ^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments instead of positional arguments for http call: `delete`.
+
+head :show, request_params
+            ^^^^^^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments instead of positional arguments for http call: `head`.
+
+get :new, {}, { user_id: 2 }, { notice: "hi" }
+          ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/HttpPositionalArguments: Use keyword arguments instead of positional arguments for http call: `get`.
+
+get :index, {}
+            ^^ Rails/HttpPositionalArguments: Use keyword arguments instead of positional arguments for http call: `get`.
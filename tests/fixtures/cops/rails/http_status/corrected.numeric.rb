@@ -0,0 +1,7 @@
+render status: 200
+render json: data, status: 404
+head 204
+assert_response 422
+redirect_to root_path, status: 301
+assert_redirected_to root_path, status: 303
+render json: errors, status: 422
@@ -0,0 +1,10 @@
+render status: 200
+render json: data, status: 404
+head 204
+assert_response :success
+assert_response :error
+assert_response :missing
+assert_redirected_to root_path, status: :redirect
+render status: :teapot_brewing
+response.head :ok
+render status: '200'
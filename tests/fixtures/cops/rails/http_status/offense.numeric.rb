@@ -0,0 +1,14 @@
+render status: :ok
+               ^^^ Rails/HttpStatus: Prefer `200` over `:ok` to define HTTP status code.
+render json: data, status: :not_found
+                           ^^^^^^^^^^ Rails/HttpStatus: Prefer `404` over `:not_found` to define HTTP status code.
+head :no_content
+     ^^^^^^^^^^^ Rails/HttpStatus: Prefer `204` over `:no_content` to define HTTP status code.
+assert_response :unprocessable_entity
+                ^^^^^^^^^^^^^^^^^^^^^ Rails/HttpStatus: Prefer `422` over `:unprocessable_entity` to define HTTP status code.
+redirect_to root_path, status: :moved_permanently
+                               ^^^^^^^^^^^^^^^^^^ Rails/HttpStatus: Prefer `301` over `:moved_permanently` to define HTTP status code.
+assert_redirected_to root_path, status: :see_other
+                                        ^^^^^^^^^^ Rails/HttpStatus: Prefer `303` over `:see_other` to define HTTP status code.
+render json: errors, status: :unprocessable_content
+                             ^^^^^^^^^^^^^^^^^^^^^^ Rails/HttpStatus: Prefer `422` over `:unprocessable_content` to define HTTP status code.
@@ -16,3 +16,7 @@ render plain: "hello", status: "404 Not Found"
                                ^^^^^^^^^^^^^^^ Rails/HttpStatus: Prefer `:not_found` over `404 Not Found` to define HTTP status code.
 render plain: "hello", status: "401 Unauthorized"
                                ^^^^^^^^^^^^^^^^^ Rails/HttpStatus: Prefer `:unauthorized` over `401 Unauthorized` to define HTTP status code.
+assert_redirected_to root_path, status: 302
+                                        ^^^ Rails/HttpStatus: Prefer `:found` over `302` to define HTTP status code.
+render json: { error: "slow down" }, status: 509
+                                             ^^^ Rails/HttpStatus: Prefer `:bandwidth_limit_exceeded` over `509` to define HTTP status code.
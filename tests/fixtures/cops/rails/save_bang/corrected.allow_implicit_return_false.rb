@@ -0,0 +1,9 @@
+def bar
+  object.save!
+end
+
+items.each do |item|
+  item.update!(name: 'Tom')
+end
+
+callback = -> { record.destroy! }
@@ -0,0 +1,16 @@
+def foo
+  return object.save
+end
+
+def bar
+  saved = object.save
+  saved
+end
+
+def baz
+  object.save || raise("failed")
+end
+
+items.each do |item|
+  next item.update(name: 'Tom')
+end
@@ -0,0 +1,6 @@
+merchant.gateway.save
+merchant.gateway.update(name: 'Tom')
+API::Client.create
+::API::Client.create(name: 'x')
+cache.save
+ENV.update(other)
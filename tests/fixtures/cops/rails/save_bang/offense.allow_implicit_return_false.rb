@@ -0,0 +1,12 @@
+def bar
+  object.save
+         ^^^^ Rails/SaveBang: Use `save!` instead of `save` if the return value is not checked.
+end
+
+items.each do |item|
+  item.update(name: 'Tom')
+       ^^^^^^ Rails/SaveBang: Use `update!` instead of `update` if the return value is not checked.
+end
+
+callback = -> { record.destroy }
+                       ^^^^^^^ Rails/SaveBang: Use `destroy!` instead of `destroy` if the return value is not checked.
@@ -0,0 +1,6 @@
+gateway.save
+        ^^^^ Rails/SaveBang: Use `save!` instead of `save` if the return value is not checked.
+other.gateway.update(name: 'Tom')
+              ^^^^^^ Rails/SaveBang: Use `update!` instead of `update` if the return value is not checked.
+Client.create
+       ^^^^^^ Rails/SaveBang: Use `create!` instead of `create` if the return value is not checked.
@@ -0,0 +1,9 @@
+params.expect(user: [:name, :age])
+
+params.expect(user: [:name, some_ids: []])
+
+params.expect(user: [:name, :age])
+
+params.expect(post: [:title])
+
+params.expect(resource_key => [:title])
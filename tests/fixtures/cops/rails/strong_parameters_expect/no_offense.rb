@@ -4,3 +4,11 @@ params.permit(:name)
 params.require(:target).permit
 params[:name]
 params.fetch(:name)
+params.permit(unmatch_key: [:name, :age]).require(:user)
+params.permit(resource_key => [:name]).require(resource_key)
+params.permit(:name, user: [:age]).require(:user)
+request.params.require(:user).permit(:name)
+
+def update(params)
+  params.require(:user).permit(:name)
+end
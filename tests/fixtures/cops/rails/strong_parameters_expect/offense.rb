@@ -1,8 +1,14 @@
 params.require(:user).permit(:name, :age)
-       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/StrongParametersExpect: Use `expect(...)` instead.
+       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/StrongParametersExpect: Use `expect(user: [:name, :age])` instead.
 
 params.require(:user).permit(:name, some_ids: [])
-       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/StrongParametersExpect: Use `expect(...)` instead.
+       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/StrongParametersExpect: Use `expect(user: [:name, some_ids: []])` instead.
 
 params.permit(user: [:name, :age]).require(:user)
-       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/StrongParametersExpect: Use `expect(...)` instead.
+       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/StrongParametersExpect: Use `expect(user: [:name, :age])` instead.
+
+params.require("post").permit(:title)
+       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/StrongParametersExpect: Use `expect(post: [:title])` instead.
+
+params.require(resource_key).permit(:title)
+       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/StrongParametersExpect: Use `expect(resource_key => [:title])` instead.
@@ -0,0 +1,13 @@
+User.where(age: 18..)
+
+Order.where(total: ..max_amount)
+
+Product.where(price: low...high)
+
+User.where.not(age: ...18)
+
+User.where(age: min..max)
+
+User.where(users: { age: 18.. })
+
+User.where(age: 18...65)
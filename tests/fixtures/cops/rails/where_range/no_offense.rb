@@ -6,3 +6,8 @@ Post.where(id: 1..100)
 User.where('COALESCE(status_stats.reblogs_count, 0) < ?', min_reblogs)
 scope.where('id > ?', oldest_id)
 scope.where('created_at + MAKE_INTERVAL(secs => expires_in) < NOW()')
+User.where("age >= ?")
+User.where("age >= :min", max: 18)
+User.where("age >= ? AND height < ?", min, max)
+User.where("age >= ? AND age < ?", min)
+Foo.not("age >= ?", 18)
@@ -1,8 +1,20 @@
 User.where("age >= ?", 18)
-^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use a range in `where` instead of manually constructing SQL conditions.
+     ^^^^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use `where(age: 18..)` instead of manually constructing SQL.
 
 Order.where("total <= ?", max_amount)
-^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use a range in `where` instead of manually constructing SQL conditions.
+      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use `where(total: ..max_amount)` instead of manually constructing SQL.
 
 Product.where("price >= ? AND price < ?", low, high)
-^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use a range in `where` instead of manually constructing SQL conditions.
+        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use `where(price: low...high)` instead of manually constructing SQL.
+
+User.where.not("age < ?", 18)
+           ^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use `not(age: ...18)` instead of manually constructing SQL.
+
+User.where(["age >= ? and age <= ?", min, max])
+     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use `where(age: min..max)` instead of manually constructing SQL.
+
+User.where("users.age >= :min", min: 18)
+     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use `where(users: { age: 18.. })` instead of manually constructing SQL.
+
+User.where("age >= :min AND age < :max", min: 18, max: 65)
+     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Rails/WhereRange: Use `where(age: 18...65)` instead of manually constructing SQL.
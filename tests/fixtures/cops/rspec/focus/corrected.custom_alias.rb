@@ -0,0 +1,8 @@
+check 'renders' do
+end
+
+check 'renders' do
+end
+
+check 'updates', js: true do
+end
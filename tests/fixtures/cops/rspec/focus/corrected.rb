@@ -0,0 +1,31 @@
+it 'test' do
+end
+describe 'test' do
+end
+context 'test' do
+end
+it 'test' do
+end
+describe 'test' do
+end
+::RSpec.describe 'test' do
+end
+focus 'test' do
+end
+described_class.new(name:, focus:)
+subject { described_class.new(command_runner:, focus:) }
+Pane.new(commands: commands, focus: focus, layout: layout)
+it 'works', :slow do
+end
+specify 'works', retry: 3 do
+end
+it do
+end
+describe 'Widget' do
+end
+context 'when ready' do
+end
+scenario 'signs in' do
+end
+feature 'Login' do
+end
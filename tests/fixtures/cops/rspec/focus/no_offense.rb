@@ -20,3 +20,6 @@ end
 def self.create_focused
   fit(params)
 end
+# Custom aliases only count when configured under `RSpec: Language:`
+check 'renders', :focus do; end
+fcheck 'renders' do; end
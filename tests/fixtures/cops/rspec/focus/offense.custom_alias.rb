@@ -0,0 +1,11 @@
+fcheck 'renders' do
+^^^^^^^^^^^^^^^^^^^ RSpec/Focus: Focused spec found.
+end
+
+check 'renders', :focus do
+                 ^^^^^^ RSpec/Focus: Focused spec found.
+end
+
+check 'updates', focus: true, js: true do
+                 ^^^^^^^^^^^ RSpec/Focus: Focused spec found.
+end
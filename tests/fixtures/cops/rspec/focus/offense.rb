@@ -25,3 +25,24 @@ subject { described_class.new(command_runner:, focus:) }
                                                ^^^^^ RSpec/Focus: Focused spec found.
 Pane.new(commands: commands, focus: focus, layout: layout)
                                     ^^^^^ RSpec/Focus: Focused spec found.
+it 'works', :focus, :slow do
+            ^^^^^^ RSpec/Focus: Focused spec found.
+end
+specify 'works', retry: 3, focus: true do
+                           ^^^^^^^^^^^ RSpec/Focus: Focused spec found.
+end
+it focus: true do
+   ^^^^^^^^^^^ RSpec/Focus: Focused spec found.
+end
+describe :focus, 'Widget' do
+         ^^^^^^ RSpec/Focus: Focused spec found.
+end
+fcontext 'when ready' do
+^^^^^^^^^^^^^^^^^^^^^ RSpec/Focus: Focused spec found.
+end
+fscenario 'signs in' do
+^^^^^^^^^^^^^^^^^^^^ RSpec/Focus: Focused spec found.
+end
+ffeature 'Login' do
+^^^^^^^^^^^^^^^^ RSpec/Focus: Focused spec found.
+end
@@ -0,0 +1,29 @@
+RSpec.describe User do
+  subject { described_class.new }
+
+  it "is valid" do
+    expect(subject).to be_valid
+  end
+
+  context "with a named subject in an inner context" do
+    subject(:user) { described_class.new(name: "Alice") }
+
+    context "and an unnamed one deeper" do
+      subject { described_class.new(name: "Bob") }
+
+      it { expect(subject.name).to eq("Bob") }
+    end
+  end
+
+  shared_examples "a valid record" do
+    subject(:record) { described_class.new }
+
+    it { expect(subject).to be_valid }
+  end
+end
+
+RSpec.describe Account do
+  it "has no subject definition at all" do
+    expect(subject).to be_a(Account)
+  end
+end
@@ -0,0 +1,32 @@
+RSpec.describe User do
+  subject(:user) { described_class.new }
+
+  it "is valid" do
+    expect(subject).to be_valid
+           ^^^^^^^ RSpec/NamedSubject: Name your test subject if you need to reference it explicitly.
+  end
+
+  context "when nested" do
+    context "two levels deep" do
+      before { subject.activate! }
+               ^^^^^^^ RSpec/NamedSubject: Name your test subject if you need to reference it explicitly.
+
+      it "is active" do
+        expect(subject).to be_active
+               ^^^^^^^ RSpec/NamedSubject: Name your test subject if you need to reference it explicitly.
+      end
+    end
+  end
+
+  describe "#name" do
+    subject!(:name) { user.name }
+
+    it { expect(subject).to eq("Alice") }
+                ^^^^^^^ RSpec/NamedSubject: Name your test subject if you need to reference it explicitly.
+  end
+
+  shared_context "with a saved user" do
+    before { subject.save! }
+             ^^^^^^^ RSpec/NamedSubject: Name your test subject if you need to reference it explicitly.
+  end
+end
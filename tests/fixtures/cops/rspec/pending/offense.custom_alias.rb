@@ -0,0 +1,14 @@
+xcheck 'renders' do
+^^^^^^^^^^^^^^^^ RSpec/Pending: Pending spec found.
+end
+
+check 'updates', :skip do
+^^^^^^^^^^^^^^^^^^^^^^ RSpec/Pending: Pending spec found.
+end
+
+check 'deletes'
+^^^^^^^^^^^^^^^ RSpec/Pending: Pending spec found.
+
+check 'creates' do
+  expect(widget).to be_valid
+end
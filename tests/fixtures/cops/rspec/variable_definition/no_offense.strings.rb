@@ -0,0 +1,8 @@
+RSpec.describe Foo do
+  let('user_name') { 'Adam' }
+  let!("count") { 42 }
+  subject('result') { described_class.new }
+  subject!('record') { create(:record) }
+  let("item_#{index}") { items[index] }
+  subject { described_class.new }
+end
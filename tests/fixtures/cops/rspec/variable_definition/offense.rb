@@ -5,6 +5,12 @@ RSpec.describe Foo do
       ^^^^^^^ RSpec/VariableDefinition: Use symbols for variable names.
   let!('count') { 42 }
        ^^^^^^^ RSpec/VariableDefinition: Use symbols for variable names.
+  subject("result") { described_class.new }
+          ^^^^^^^^ RSpec/VariableDefinition: Use symbols for variable names.
+  subject!('record') { create(:record) }
+           ^^^^^^^^ RSpec/VariableDefinition: Use symbols for variable names.
+  let('valid?') { true }
+      ^^^^^^^^ RSpec/VariableDefinition: Use symbols for variable names.
 end
 
 # Mail DSL subject with string arg inside an example group IS flagged
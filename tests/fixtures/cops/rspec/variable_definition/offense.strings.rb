@@ -0,0 +1,12 @@
+RSpec.describe Foo do
+  let(:user_name) { 'Adam' }
+      ^^^^^^^^^^ RSpec/VariableDefinition: Use strings for variable names.
+  let!(:count) { 42 }
+       ^^^^^^ RSpec/VariableDefinition: Use strings for variable names.
+  subject(:result) { described_class.new }
+          ^^^^^^^ RSpec/VariableDefinition: Use strings for variable names.
+  subject!(:"record") { create(:record) }
+           ^^^^^^^^^ RSpec/VariableDefinition: Use strings for variable names.
+  let(:"item_#{index}") { items[index] }
+      ^^^^^^^^^^^^^^^^ RSpec/VariableDefinition: Use strings for variable names.
+end
@@ -0,0 +1,7 @@
+RSpec.describe TokenIssuer do
+  let(:JWT_SECRET) { 'secret' }
+  let!(:JWT_ALGORITHM) { 'HS256' }
+  let('JWT_ISSUER') { 'nitrocop' }
+  subject!("JWT_TOKEN") { described_class.issue }
+  let(:token_ttl) { 3600 }
+end
@@ -0,0 +1,9 @@
+RSpec.describe TokenIssuer do
+  let(:JWT_SECRET) { 'secret' }
+  let(:jwtSecret) { 'secret' }
+      ^^^^^^^^^^ RSpec/VariableName: Use snake_case for variable names.
+  let!(:API_KEY) { 'key' }
+       ^^^^^^^^ RSpec/VariableName: Use snake_case for variable names.
+  let('MY_JWT_TOKEN') { 'token' }
+      ^^^^^^^^^^^^^^ RSpec/VariableName: Use snake_case for variable names.
+end
@@ -7,4 +7,12 @@ RSpec.describe User do
       ^^^^^^^^ RSpec/VariableName: Use snake_case for variable names.
   subject(:==) { event == other }
           ^^^ RSpec/VariableName: Use snake_case for variable names.
+  let!(:userEmail) { 'adam@example.com' }
+       ^^^^^^^^^^ RSpec/VariableName: Use snake_case for variable names.
+  subject!(:savedUser) { User.create! }
+           ^^^^^^^^^^ RSpec/VariableName: Use snake_case for variable names.
+  let('userRole') { :admin }
+      ^^^^^^^^^^ RSpec/VariableName: Use snake_case for variable names.
+  let!("userToken") { 'secret' }
+       ^^^^^^^^^^^ RSpec/VariableName: Use snake_case for variable names.
 end
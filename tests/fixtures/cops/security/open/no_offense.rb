@@ -12,3 +12,10 @@ page.open
 open("safe #{interpolated}")
 open(__FILE__)
 open(__FILE__) { |f| f.read }
+Tempfile.open(path)
+Storage.open(path)
+MyApp::Storage.open(path) { |io| io.read }
+Foo::URI.open(url)
+URI.open("https://example.com/feeds/#{feed_id}.xml")
+URI.open("https://api.example.com" + path)
+open("config/#{name}.yml")
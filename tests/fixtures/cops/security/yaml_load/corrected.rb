@@ -0,0 +1,4 @@
+YAML.safe_load(data)
+::YAML.safe_load(File.read(path))
+settings = YAML.safe_load(io)
+YAML.safe_load("--- :foo", aliases: true)
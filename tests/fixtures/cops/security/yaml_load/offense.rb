@@ -1,2 +1,8 @@
-# No-op cop: YAML.load is safe since Ruby 3.1 (Psych 4 default), making this cop obsolete.
-# This file is intentionally empty of offense annotations.
+YAML.load(data)
+     ^^^^ Security/YAMLLoad: Prefer using `YAML.safe_load` over `YAML.load`.
+::YAML.load(File.read(path))
+       ^^^^ Security/YAMLLoad: Prefer using `YAML.safe_load` over `YAML.load`.
+settings = YAML.load(io)
+                ^^^^ Security/YAMLLoad: Prefer using `YAML.safe_load` over `YAML.load`.
+YAML.load("--- :foo", aliases: true)
+     ^^^^ Security/YAMLLoad: Prefer using `YAML.safe_load` over `YAML.load`.
@@ -0,0 +1,4 @@
+# frozen_string_literal: true
+^ Sorbet/FalseSigil: No Sorbet sigil found in file. Try a `typed: false` to start (you can also use `rubocop -a` to automatically add this).
+
+puts 'hello'
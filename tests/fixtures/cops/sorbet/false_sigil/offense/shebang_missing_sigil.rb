@@ -0,0 +1,5 @@
+#!/usr/bin/env ruby
+^ Sorbet/FalseSigil: No Sorbet sigil found in file. Try a `typed: false` to start (you can also use `rubocop -a` to automatically add this).
+# frozen_string_literal: true
+
+puts 'hello'
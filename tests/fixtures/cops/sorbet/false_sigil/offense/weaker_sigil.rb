@@ -0,0 +1,5 @@
+# frozen_string_literal: true
+# typed: ignore
+^ Sorbet/FalseSigil: Sorbet sigil should be at least `false` got `ignore`.
+
+puts 'hello'
@@ -0,0 +1,9 @@
+#!/usr/bin/env ruby
+# typed: true
+# frozen_string_literal: true
+
+class Greeter
+  def greet
+    puts 'hello'
+  end
+end
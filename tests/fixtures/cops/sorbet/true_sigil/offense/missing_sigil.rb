@@ -0,0 +1,4 @@
+# frozen_string_literal: true
+^ Sorbet/TrueSigil: No Sorbet sigil found in file. Try a `typed: true` to start (you can also use `rubocop -a` to automatically add this).
+
+puts 'hello'
@@ -0,0 +1,5 @@
+# frozen_string_literal: true
+# typed: false
+^ Sorbet/TrueSigil: Sorbet sigil should be at least `true` got `false`.
+
+puts 'hello'
@@ -0,0 +1,12 @@
+#!/usr/bin/env ruby
+# encoding: utf-8
+# typed: strict
+# frozen_string_literal: true
+
+class Greeter
+  def greet
+    puts 'hello'
+  end
+end
+
+# typed: bogus (not a leading comment, so not a sigil)
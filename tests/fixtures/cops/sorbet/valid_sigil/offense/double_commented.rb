@@ -0,0 +1,5 @@
+# # typed: true
+^ Sorbet/ValidSigil: Sorbet sigil should not be double commented.
+# frozen_string_literal: true
+
+puts 'hello'
@@ -0,0 +1,4 @@
+#!/usr/bin/env ruby
+# typed:
+^ Sorbet/ValidSigil: Sorbet sigil should not be empty.
+puts 'hello'
@@ -0,0 +1,5 @@
+# frozen_string_literal: true
+# typed: bogus
+^ Sorbet/ValidSigil: Invalid Sorbet sigil `bogus`.
+
+puts 'hello'
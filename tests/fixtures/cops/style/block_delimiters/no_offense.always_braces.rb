@@ -0,0 +1,9 @@
+items.each { |x|
+  puts x
+}
+items.each { |x| puts x }
+foo do next unless bar; rescue StandardError; end
+lambda do |x|
+  x
+end
+items.map { |x| x * 2 }
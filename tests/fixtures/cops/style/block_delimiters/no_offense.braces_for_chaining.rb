@@ -0,0 +1,10 @@
+items.map { |x|
+  x.to_s
+}.join(", ")
+items.each do |x|
+  puts x
+end
+items.each { |x| puts x }
+result = items.select { |x| x.valid? }.map { |x|
+  x.name
+}.sort
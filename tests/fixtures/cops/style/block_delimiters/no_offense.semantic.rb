@@ -0,0 +1,19 @@
+items.each do |x|
+  puts x
+end
+result = items.map { |x| x * 2 }
+total = items.map { |x|
+  x * 2
+}.sum
+let(:user) { create(:user) }
+expect(items.map { |x| x.name }).to eq(names)
+user = User.new do |u|
+  u.name = "x"
+end
+if items.any? { |x| x.nil? }
+  warn "nil"
+end
+def names
+  items.map { |x| x.name }
+end
+items.each { |x| puts x }
@@ -0,0 +1,12 @@
+items.each do |x|
+           ^^ Style/BlockDelimiters: Prefer `{...}` over `do...end` for blocks.
+  puts x
+end
+
+items.each do |x| puts x end
+           ^^ Style/BlockDelimiters: Prefer `{...}` over `do...end` for blocks.
+
+foo.bar(1) do
+           ^^ Style/BlockDelimiters: Prefer `{...}` over `do...end` for blocks.
+  baz
+end
@@ -0,0 +1,12 @@
+items.map do |x|
+          ^^ Style/BlockDelimiters: Prefer `{...}` over `do...end` for multi-line chained blocks.
+  x.to_s
+end.join(", ")
+
+items.each { |x|
+           ^ Style/BlockDelimiters: Prefer `do...end` for multi-line blocks without chaining.
+  puts x
+}
+
+items.each do |x| puts x end
+           ^^ Style/BlockDelimiters: Prefer `{...}` over `do...end` for single-line blocks.
@@ -0,0 +1,24 @@
+items.each { |x| puts x }
+           ^ Style/BlockDelimiters: Prefer `do...end` over `{...}` for procedural blocks.
+
+items.each { |x|
+           ^ Style/BlockDelimiters: Prefer `do...end` over `{...}` for procedural blocks.
+  puts x
+}
+
+result = items.map do |x|
+                   ^^ Style/BlockDelimiters: Prefer `{...}` over `do...end` for functional blocks.
+  x * 2
+end
+
+items.map do |x|
+          ^^ Style/BlockDelimiters: Prefer `{...}` over `do...end` for functional blocks.
+  x.to_s
+end.join(", ")
+
+puts(items.select do |x|
+                  ^^ Style/BlockDelimiters: Prefer `{...}` over `do...end` for functional blocks.
+  x.valid?
+end)
+
+done
@@ -0,0 +1,129 @@
+module Foo
+  class Bar
+  end
+end
+
+module Foo
+  class Bar
+    module Baz
+    end
+  end
+end
+
+module FooClass
+  class BarClass
+  end
+end
+
+module FooModule
+  module BarModule
+  end
+end
+
+module Foo
+  class Bar < Super
+  end
+end
+
+module Foo
+  class Bar
+    class Baz
+    end
+  end
+end
+
+module Foo
+  module Bar
+    module Baz
+    end
+  end
+end
+
+# Compact-style class inside multi-statement module body
+module Outer
+  CONSTANT = 1
+  module Inner
+    class Name
+    end
+  end
+end
+
+# Compact-style module inside multi-statement module body
+module Container
+  require 'something'
+  module Nested
+    module Path
+    end
+  end
+end
+
+# Multiple compact-style inside same module body
+module Multi
+  CONST = true
+  module Alpha
+    class Beta
+    end
+  end
+  module Gamma
+    module Delta
+    end
+  end
+end
+
+# Compact-style with cbase prefix (::) — still flagged if multi-segment
+class ::Rack::MiniProfiler::SnapshotsTransporter
+end
+
+module ::FFI::Library
+end
+
+class ::FFI::Pointer
+end
+
+module ::FFI::WIN32
+end
+
+class ::PuppetSpec::DataTypes::MyTest
+end
+
+class ::ActiveRecord::Base
+end
+
+class ::ActionView::Base
+end
+
+# Compact-style inside a block within a single-statement module body
+module PuppetSpec
+  describe "something" do
+    before(:each) do
+      class ::PuppetSpec::DataTypes::MyTest
+      end
+    end
+  end
+end
+
+# Compact-style class inside an if within a single-statement module body
+module Underscore
+  module Rails
+    if defined?(::Rails) and Gem::Requirement.new('>= 3.1').satisfied_by?(Gem::Version.new(::Rails.version))
+      module Rails
+        class Engine < ::Rails::Engine
+          # this class enables the asset pipeline
+        end
+      end
+    end
+  end
+end
+
+# Deep compact path with a superclass and a body
+module Api
+  module V1
+    class UsersController < ApplicationController
+      def index
+        render plain: <<~TEXT
+          users
+        TEXT
+      end
+    end
+  end
+end
@@ -108,3 +108,13 @@ module Underscore
     end
   end
 end
+
+# Deep compact path with a superclass and a body
+class Api::V1::UsersController < ApplicationController
+      ^^^^^^^^^^^^^^^^^^^^^^^^ Style/ClassAndModuleChildren: Use nested module/class definitions instead of compact style.
+  def index
+    render plain: <<~TEXT
+      users
+    TEXT
+  end
+end
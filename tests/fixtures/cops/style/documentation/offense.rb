@@ -212,3 +212,11 @@ module RailsERD
     end
   end
 end
+
+# Macro-only class bodies still need documentation
+
+class Membership
+^^^^^ Style/Documentation: Missing top-level documentation comment for `class`.
+  belongs_to :user
+  has_many :roles
+end
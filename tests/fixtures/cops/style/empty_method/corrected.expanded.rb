@@ -0,0 +1,22 @@
+def foo
+end
+
+def self.bar(x, y)
+end
+
+def run a, b
+end
+
+class Point
+  def ==(other)
+  end
+
+  def x=(value)
+  end
+
+  def -@
+  end
+
+  private def helper
+          end
+end
@@ -0,0 +1,25 @@
+def foo; end
+
+def bar(x); end
+
+def bar(x, y); end
+
+def self.foo; end
+
+def self.foo(bar, baz); end
+
+def baz; end
+
+def reset; end
+
+class Point
+  def ==(other); end
+
+  def x=(value); end
+
+  def [](index); end
+
+  def -@; end
+
+  def run a, b = 1, *rest, key:, **opts, &blk; end
+end
@@ -0,0 +1,16 @@
+def foo
+end
+
+def self.bar(x, y)
+end
+
+def baz; 42; end
+
+def with_comment; end # :nodoc:
+
+def endless = 42
+
+class Point
+  def ==(other)
+  end
+end
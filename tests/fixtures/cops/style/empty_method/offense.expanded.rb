@@ -0,0 +1,22 @@
+def foo; end
+^^^ Style/EmptyMethod: Put the `end` on the next line.
+
+def self.bar(x, y); end
+^^^ Style/EmptyMethod: Put the `end` on the next line.
+
+def run a, b; end
+^^^ Style/EmptyMethod: Put the `end` on the next line.
+
+class Point
+  def ==(other); end
+  ^^^ Style/EmptyMethod: Put the `end` on the next line.
+
+  def x=(value); end
+  ^^^ Style/EmptyMethod: Put the `end` on the next line.
+
+  def -@; end
+  ^^^ Style/EmptyMethod: Put the `end` on the next line.
+
+  private def helper; end
+          ^^^ Style/EmptyMethod: Put the `end` on the next line.
+end
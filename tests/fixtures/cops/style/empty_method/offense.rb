@@ -22,3 +22,29 @@ def baz
 ^^^ Style/EmptyMethod: Put empty method definitions on a single line.
 
 end
+
+def reset()
+^^^ Style/EmptyMethod: Put empty method definitions on a single line.
+end
+
+class Point
+  def ==(other)
+  ^^^ Style/EmptyMethod: Put empty method definitions on a single line.
+  end
+
+  def x=(value)
+  ^^^ Style/EmptyMethod: Put empty method definitions on a single line.
+  end
+
+  def [](index)
+  ^^^ Style/EmptyMethod: Put empty method definitions on a single line.
+  end
+
+  def -@
+  ^^^ Style/EmptyMethod: Put empty method definitions on a single line.
+  end
+
+  def run a, b = 1, *rest, key:, **opts, &blk
+  ^^^ Style/EmptyMethod: Put empty method definitions on a single line.
+  end
+end
@@ -357,3 +357,14 @@ def test_short_comment_only_else_in_case
     work
   end
 end
+
+def test
+  if foo
+    work
+  end
+
+  if bar
+  ^^ Style/GuardClause: Use a guard clause (`return unless bar`) instead of wrapping the code inside a conditional expression.
+    work
+  end
+end
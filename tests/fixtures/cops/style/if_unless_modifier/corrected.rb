@@ -0,0 +1,108 @@
+do_something if x
+
+do_something unless x
+
+foo if condition
+
+retry unless finished?
+
+# Parenthesized condition (non-assignment) should still be flagged
+do_something if (x > 0)
+
+# Blank line between condition and body should still be flagged
+do_something if condition
+
+# Short comment on condition line should still be flagged
+do_something if condition # short comment
+
+# One-line form should be flagged
+bar if foo
+
+if scan_progress_resp[:products].any? { |p| p[:status] == 'F' }
+  raise 'ERROR: BDBA Scan Failed - Check BDBA Logs for More Info...'
+end
+
+if json_sitemap.nil? || json_sitemap.empty?
+  raise "ERROR: Failed to import OpenAPI/Swagger spec #{openapi_spec} into Burp Suite Pro's Sitemap."
+end
+
+if include_http_response_codes && exclude_http_response_codes
+  raise 'ERROR: Flags --include-response-codes and --exclude-response-codes cannot be used together.'
+end
+
+if additional_http_headers.is_a?(String)
+  additional_http_headers = JSON.parse(additional_http_headers, symbolize_names: true)
+end
+
+if engine.nil?
+  raise 'ERROR: Jira Server Hash not found in PWN::Env.  Run i`pwn -Y default.yaml`, then `PWN::Env` for usage.'
+end
+
+if blockchain.nil?
+  raise 'ERROR: Jira Server Hash not found in PWN::Env.  Run i`pwn -Y default.yaml`, then `PWN::Env` for usage.'
+end
+
+if defined?(@@logger)
+  @@logger.warn("Omitting unlicensed fields: #{unlicensed_field_keys.join(', ')} (attempt #{create_attempts}/#{max_create_attempts}). Retrying issue creation.")
+end
+
+s.add_runtime_dependency 'oj', '>= 2.12' unless defined?(JRUBY_VERSION)
+
+if !defined?(@@import_swt_packages) || (defined?(@@import_swt_packages) && @@import_swt_packages == true)
+  @@import_swt_packages = DEFAULT_IMPORT_SWT_PACKAGES
+end
+
+@@logger_type = :logger unless defined? @@logger_type
+
+@@logging_devices = [:stdout, :syslog] unless defined? @@logging_devices
+
+unless defined? @@logging_device_file_options
+  @@logging_device_file_options = {size: 1_000_000, age: 'daily', roll_by: 'number'}
+end
+
+baz if(/foo/ =~ bar)
+
+baz if /#{foo}/ =~ bar
+
+unless /Process.*\s(event|command)/ =~ evh_docstr
+  documentation_stream.puts "Processes a very descriptive generated event entry for {#{fullname}::#{evt_type}} in the API reference."
+end
+
+after_save { user.update_contribution_count if user }
+
+after_destroy { user.update_contribution_count if user }
+
+options ||= ({body: options_or_body} unless options_or_body.is_a?(Hash)) || options_or_body || {}
+
+((@instrumented_integrations&.dup if defined?(@instrumented_integrations)) || {}).freeze
+
+@error = "未登录状态只能查看100条记录，登录后可查看1000条记录！" if @page && @page.to_i>10 && !current_user
+
+result = "#{property.name}: #{property.type.to_s}#{" = " + "#{default_value}" if has_default_value}"
+
+redirect_to admin_root_path, alert: 'ここから先は管理者限定です！' unless current_user.partner? || current_user.admin?
+
+track(data, subject: "指手受信", body: "OK → #{data['to_user_name'].inspect}", emoji: ":OK:") if data["debug_mode_p"]
+
+# Only the trailing comment makes the line too long: it moves above the statement
+# explains why this only runs when the service is ready
+do_something_with_a_fairly_long_method_name(argument_one, argument_two) if ready?
+
+# Too long even without the comment: block form keeps the comment on the body line
+if subscribers_enabled?(event_name)
+  notify_all_subscribers_of_the_change(event_name, payload: serialized_payload, priority: :high) # TODO
+end
+
+def check(value)
+  unless valid_value?(value)
+    raise ArgumentError, 'the configured value is outside the range this integration supports right now'
+  end
+end
+
+# Assignment in the condition: block form would change how the body parses, not corrected
+process_the_incoming_record_with_all_of_its_nested_associations(record, include_deleted: true) if (record = find_record_by_id(id))
+
+# Comment on the condition line followed by code after `end`: not corrected
+value = if enabled? # toggled per tenant
+  compute
+end || fallback
@@ -129,3 +129,26 @@ if data["debug_mode_p"]
 ^ Style/IfUnlessModifier: Favor modifier `if` usage when having a single-line body. Another good alternative is the usage of control flow `&&`/`||`.
   track(data, subject: "指手受信", body: "OK → #{data['to_user_name'].inspect}", emoji: ":OK:")
 end
+
+# Only the trailing comment makes the line too long: it moves above the statement
+do_something_with_a_fairly_long_method_name(argument_one, argument_two) if ready? # explains why this only runs when the service is ready
+^ Style/IfUnlessModifier: Modifier form of `if` makes the line too long.
+
+# Too long even without the comment: block form keeps the comment on the body line
+notify_all_subscribers_of_the_change(event_name, payload: serialized_payload, priority: :high) if subscribers_enabled?(event_name) # TODO
+^ Style/IfUnlessModifier: Modifier form of `if` makes the line too long.
+
+def check(value)
+  raise ArgumentError, 'the configured value is outside the range this integration supports right now' unless valid_value?(value)
+  ^ Style/IfUnlessModifier: Modifier form of `unless` makes the line too long.
+end
+
+# Assignment in the condition: block form would change how the body parses, not corrected
+process_the_incoming_record_with_all_of_its_nested_associations(record, include_deleted: true) if (record = find_record_by_id(id))
+^ Style/IfUnlessModifier: Modifier form of `if` makes the line too long.
+
+# Comment on the condition line followed by code after `end`: not corrected
+value = if enabled? # toggled per tenant
+        ^^ Style/IfUnlessModifier: Favor modifier `if` usage when having a single-line body. Another good alternative is the usage of control flow `&&`/`||`.
+  compute
+end || fallback
@@ -0,0 +1,9 @@
+f = lambda { |x| x + 1 }
+
+g = lambda { puts "hello" }
+
+h = lambda do |a, b|
+  a + b
+end
+
+i = lambda { 42 }
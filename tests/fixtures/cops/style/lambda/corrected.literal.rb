@@ -0,0 +1,11 @@
+f = ->(x) { x + 1 }
+
+g = ->(a, b) do
+  a + b
+end
+
+h = -> do
+  puts "hello"
+end
+
+i = -> { 42 }
@@ -0,0 +1,36 @@
+f = ->(x) { x + 1 }
+
+g = -> { puts "hello" }
+
+h = ->(a, b) { a + b }
+
+j = lambda do
+  something
+end
+
+k = lambda do
+  if condition
+    action
+  end
+end
+
+render html lambda { <<~HTML
+  <h1>#{text->{ title }}</h1>
+HTML
+}
+
+m = ->(x, y; z) { z = x + y }
+
+n = lambda do |x|
+  x
+end
+
+# Immediately called or passed as a block: reported, not corrected
+lambda { |x| x }.(1)
+
+items.each(&lambda { |x| puts x })
+
+# `lambda do` would bind to `register`
+register :name, ->(x) do
+  x
+end
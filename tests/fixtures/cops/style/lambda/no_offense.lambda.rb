@@ -0,0 +1,9 @@
+f = lambda { |x| x + 1 }
+
+g = lambda do |a, b|
+  a + b
+end
+
+h = lambda { puts "hello" }
+
+i = proc { |x| x }
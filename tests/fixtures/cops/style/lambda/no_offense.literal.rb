@@ -0,0 +1,9 @@
+f = ->(x) { x + 1 }
+
+g = ->(a, b) do
+  a + b
+end
+
+h = -> { puts "hello" }
+
+obj.lambda { |x| x }
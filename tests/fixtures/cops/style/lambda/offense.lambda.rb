@@ -0,0 +1,13 @@
+f = ->(x) { x + 1 }
+    ^^ Style/Lambda: Use the `lambda` method for all lambdas.
+
+g = -> { puts "hello" }
+    ^^ Style/Lambda: Use the `lambda` method for all lambdas.
+
+h = -> a, b do
+    ^^ Style/Lambda: Use the `lambda` method for all lambdas.
+  a + b
+end
+
+i = ->{ 42 }
+    ^^ Style/Lambda: Use the `lambda` method for all lambdas.
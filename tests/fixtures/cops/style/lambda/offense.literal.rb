@@ -0,0 +1,15 @@
+f = lambda { |x| x + 1 }
+    ^^^^^^ Style/Lambda: Use the `-> {}` lambda literal syntax for all lambdas.
+
+g = lambda do |a, b|
+    ^^^^^^ Style/Lambda: Use the `-> {}` lambda literal syntax for all lambdas.
+  a + b
+end
+
+h = lambda do
+    ^^^^^^ Style/Lambda: Use the `-> {}` lambda literal syntax for all lambdas.
+  puts "hello"
+end
+
+i = lambda { || 42 }
+    ^^^^^^ Style/Lambda: Use the `-> {}` lambda literal syntax for all lambdas.
@@ -24,3 +24,24 @@ render html->{ <<~HTML
   <h1>#{text->{ title }}</h1>
 HTML
 }
+
+m = lambda { |x, y; z| z = x + y }
+    ^^^^^^ Style/Lambda: Use the `-> {}` lambda literal syntax for single-line lambdas.
+
+n = -> x do
+    ^^ Style/Lambda: Use the `lambda` method for multiline lambdas.
+  x
+end
+
+# Immediately called or passed as a block: reported, not corrected
+lambda { |x| x }.(1)
+^^^^^^ Style/Lambda: Use the `-> {}` lambda literal syntax for single-line lambdas.
+
+items.each(&lambda { |x| puts x })
+            ^^^^^^ Style/Lambda: Use the `-> {}` lambda literal syntax for single-line lambdas.
+
+# `lambda do` would bind to `register`
+register :name, ->(x) do
+                ^^ Style/Lambda: Use the `lambda` method for multiline lambdas.
+  x
+end
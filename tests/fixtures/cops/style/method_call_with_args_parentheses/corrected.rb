@@ -0,0 +1,178 @@
+# require_parentheses style (default)
+
+# Method calls with receiver and args but no parens
+foo.bar(1, 2)
+
+obj.method("arg")
+
+x.send(:message, "data")
+
+# Receiverless calls inside method defs are NOT macros
+def foo
+  test(a, b)
+end
+
+# Safe navigation operator also flags
+top&.test(a, b)
+
+# Multiline chained method calls — offense is at start of full expression
+expect(described_class.new)
+  .to(match_array(y))
+
+custom_fields
+  .include?(attribute)
+
+# Receiverless call nested as argument to another call in class body
+# is NOT a macro (parent in AST is send, not a wrapper)
+class MyClass
+  foo bar(:baz)
+end
+
+# Receiverless calls inside case/when in class body are NOT macros
+# (case/when are not wrappers in RuboCop's in_macro_scope?)
+class MyClass
+  case type
+  when :foo
+    test(a, b)
+  end
+end
+
+# Receiverless calls inside while/until in class body are NOT macros
+class MyClass
+  while running
+    process_item(a)
+  end
+end
+
+# Receiverless calls inside rescue in class body are NOT macros
+# (rescue is not a wrapper in RuboCop's in_macro_scope?)
+class MyClass
+  begin
+    test(a, b)
+  rescue
+    handle_error(a)
+  end
+end
+
+# Receiverless calls inside ensure in class body are NOT macros
+class MyClass
+  begin
+    test(a, b)
+  ensure
+    cleanup(a)
+  end
+end
+
+# yield with args and no parens in method body
+def each_item
+  yield(element)
+end
+
+# yield with multiple args
+def traverse(tree, &block)
+  tree.each do |item|
+    yield(item, tree)
+  end
+end
+
+# Ordinary method-call blocks do not inherit macro scope when the block
+# expression is nested inside assignment or chaining.
+trip = Trip.new(%i[call]) { require("pry") }
+
+projects = 3.times.map { create(:project, submitted_by: user) }
+
+expect {
+  raise(subject)
+}.to(raise_error(subject.class, message))
+
+# Ternary branches are macros only when the ternary expression itself is.
+if condition ? (yes_wizard?("yes")) : (yes_wizard?("no"))
+  puts "x"
+end
+
+scope :alive,       -> { where(alive: true) }
+
+scope :unreachable, -> { where(alive: false) }
+
+scope :global,      -> { where(global: true) }
+
+scope :fixed, -> { where(fixed: true) }
+
+scope :by_severity, -> { order(order_by_severity) }
+
+scope :read,   -> { where(read: true) }
+
+scope :unread, -> { where(read: false) }
+
+scope :global, -> { where(global: true) }
+
+# Block argument only (no regular args) in method body — NOT a macro
+def run_test(&block)
+  instance_eval(&block)
+end
+
+# Block argument with receiver — flagged
+obj.instance_eval(&block)
+
+# Rescue modifier breaks macro scope — receiverless calls are NOT macros
+require("objspace") rescue nil
+
+class MyClass
+  allow_ip!("::1/128") rescue nil
+end
+
+# Flow-control nodes are not wrappers; their argument calls are NOT macros
+class Server
+  get "/x" do
+    next send_file(static_file) if static_file
+  end
+end
+
+# Parallel assignment breaks macro scope for ordinary block bodies
+describe "x" do
+  planned, running = plan.sub_plans.partition { |sub| planned?(sub) }
+end
+
+describe "x" do
+  _out, err = capture_subprocess_io do
+    call_event("hello", event, globals: { firestore_client: firestore_client })
+  end
+end
+
+# Receiverless calls inside string interpolation are NOT macros
+# (interpolated string is not a wrapper in RuboCop's in_macro_scope?)
+"text #{bar(:baz)}"
+
+# BEGIN {} is not a wrapper — receiverless calls inside are NOT macros
+BEGIN {
+  require('ostruct')
+}
+
+# case/in (pattern matching) is not a wrapper — calls inside are NOT macros
+case foo
+in { a: 1 }
+  puts("a")
+end
+
+# Operator assignment (+=) breaks macro scope for receiverless calls
+describe "x" do
+  count += process_item(arg)
+end
+
+# Pure begin only preserves macro scope when the whole expression is.
+value = begin
+  require('rubygems/specification')
+end
+
+logger ||= begin
+  require('active_support/tagged_logging') unless defined?(ActiveSupport::TaggedLogging)
+end
+
+# `or begin` is also a non-wrapper parent for the begin body.
+ready || begin
+  warn("Automatic creation of the sandbox app failed")
+  exit(1)
+end
+
+# Interpolated x-strings are not macro wrappers.
+%x{#{raise(TypeError, "x")}}
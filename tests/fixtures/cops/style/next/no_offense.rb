@@ -43,3 +43,25 @@ rows.each do |row|
     end
   end
 end
+
+# modifier-if bodies are skipped with EnforcedStyle: skip_modifier_ifs
+items.each do |item|
+  process(item) if item.valid?
+end
+
+items.each do |item|
+  archive(item) unless item.active?
+end
+
+# a body that only breaks out of the loop is not converted
+items.each do |item|
+  if item.last?
+    break
+  end
+end
+
+while running
+  unless connected?
+    break
+  end
+end
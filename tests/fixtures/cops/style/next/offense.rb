@@ -266,3 +266,18 @@ end
     end
   end
 end
+
+# nested blocks are checked independently
+groups.each do |group|
+  if group.active?
+  ^^ Style/Next: Use `next` to skip iteration.
+    group.members.each do |member|
+      if member.enabled?
+      ^^ Style/Next: Use `next` to skip iteration.
+        notify(member)
+        log(member)
+        track(member)
+      end
+    end
+  end
+end
@@ -0,0 +1,6 @@
+population = 1_234_567
+negative = -1_000_000
+ratio = 12_345.6789
+scientific = 12_345e10
+precise = -123_456.5E-3
+misgrouped = 123_456
@@ -0,0 +1,4 @@
+big = 1_234_567
+schema = 20_211_212_143_544
+cents = 1_000_000
+float = 1_234_567.25
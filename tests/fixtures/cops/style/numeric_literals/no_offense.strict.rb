@@ -0,0 +1,6 @@
+short = 123456
+grouped = 1_234_567
+hex = 0xFFFFFFFF
+float = 12_345_678.5
+string = "1234567"
+# 1234567 in a comment
@@ -0,0 +1,12 @@
+population = 1234567
+             ^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
+negative = -1000000
+           ^^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
+ratio = 12345.6789
+        ^^^^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
+scientific = 12345e10
+             ^^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
+precise = -123456.5E-3
+          ^^^^^^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
+misgrouped = 12_34_56
+             ^^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
@@ -0,0 +1,8 @@
+big = 1234567
+      ^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
+schema = 2021_12_12_143544
+         ^^^^^^^^^^^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
+cents = 10_000_00
+        ^^^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
+float = 1234567.25
+        ^^^^^^^^^^ Style/NumericLiterals: Use underscores(_) as thousands separator and separate every 3 digits with them.
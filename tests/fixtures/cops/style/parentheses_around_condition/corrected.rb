@@ -48,3 +48,27 @@ def image_entries(cur_manifest)
     end
   end
 end
+
+if a
+  b
+elsif c
+  d
+end
+
+if a and b
+  go
+end
+
+if ready? && valid?
+  go
+end
+
+while queue.any? &&
+       running?
+  work
+end
+
+# Unwrapping would regroup the modifier: reported, not corrected
+foo if (a or b)
+
+process while (ready and pending)
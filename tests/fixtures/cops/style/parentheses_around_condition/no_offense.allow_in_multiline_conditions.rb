@@ -0,0 +1,19 @@
+if (x > 10 &&
+    y > 10)
+  puts 'hi'
+end
+
+while (
+  queue.any? && running?
+)
+  work
+end
+
+until (done? ||
+       cancelled?)
+  wait
+end
+
+if x > 10
+  puts 'single line without parens'
+end
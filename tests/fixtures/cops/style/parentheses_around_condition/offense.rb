@@ -59,3 +59,35 @@ def image_entries(cur_manifest)
     end
   end
 end
+
+if a
+  b
+elsif (c)
+      ^ Style/ParenthesesAroundCondition: Don't use parentheses around the condition of an `elsif`.
+  d
+end
+
+if (a and b)
+   ^ Style/ParenthesesAroundCondition: Don't use parentheses around the condition of an `if`.
+  go
+end
+
+if (
+   ^ Style/ParenthesesAroundCondition: Don't use parentheses around the condition of an `if`.
+  ready? && valid?
+)
+  go
+end
+
+while (queue.any? &&
+      ^ Style/ParenthesesAroundCondition: Don't use parentheses around the condition of a `while`.
+       running?)
+  work
+end
+
+# Unwrapping would regroup the modifier: reported, not corrected
+foo if (a or b)
+       ^ Style/ParenthesesAroundCondition: Don't use parentheses around the condition of an `if`.
+
+process while (ready and pending)
+              ^ Style/ParenthesesAroundCondition: Don't use parentheses around the condition of a `while`.
@@ -0,0 +1,12 @@
+# Compact style: flag raise/fail with a class and a message
+raise RuntimeError.new("message")
+
+fail ArgumentError.new("bad argument")
+
+raise(Foo::Bar.new("message"))
+
+# Reported but not corrected: a backtrace argument
+raise MyError, "message", caller
+
+# Reported but not corrected: a splat
+raise MyError, *args
@@ -0,0 +1,10 @@
+# Listed types may use the constructor form
+raise MyWrappedError.new(obj)
+
+raise ::MyWrappedError.new("message")
+
+fail MyWrappedError.new
+
+raise Errors::Wrapped.new("message")
+
+raise StandardError, "message"
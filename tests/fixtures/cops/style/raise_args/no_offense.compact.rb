@@ -0,0 +1,12 @@
+# Compact style: these are all fine
+raise RuntimeError.new("message")
+
+raise "something went wrong"
+
+raise
+
+fail ArgumentError
+
+raise MyError.new(key: "value"), "ignored"
+
+obj.raise RuntimeError, "ok"
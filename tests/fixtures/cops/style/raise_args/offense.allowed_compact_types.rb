@@ -0,0 +1,9 @@
+# Listed types may use the constructor form; others may not
+raise StandardError.new("message")
+^^^^^ Style/RaiseArgs: Provide an exception class and message as arguments to `raise`.
+
+raise Other::MyWrappedError.new("message")
+^^^^^ Style/RaiseArgs: Provide an exception class and message as arguments to `raise`.
+
+fail error_class.new("message")
+^^^^ Style/RaiseArgs: Provide an exception class and message as arguments to `fail`.
@@ -0,0 +1,17 @@
+# Compact style: flag raise/fail with a class and a message
+raise RuntimeError, "message"
+^^^^^ Style/RaiseArgs: Provide an exception object as an argument to `raise`.
+
+fail ArgumentError, "bad argument"
+^^^^ Style/RaiseArgs: Provide an exception object as an argument to `fail`.
+
+raise(Foo::Bar, "message")
+^^^^^ Style/RaiseArgs: Provide an exception object as an argument to `raise`.
+
+# Reported but not corrected: a backtrace argument
+raise MyError, "message", caller
+^^^^^ Style/RaiseArgs: Provide an exception object as an argument to `raise`.
+
+# Reported but not corrected: a splat
+raise MyError, *args
+^^^^^ Style/RaiseArgs: Provide an exception object as an argument to `raise`.
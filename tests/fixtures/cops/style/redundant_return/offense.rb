@@ -159,3 +159,29 @@ def with_case_in(x)
     ^^^^^^^^ Style/RedundantReturn: Redundant `return` detected.
   end
 end
+
+# multiple return values become an array
+def multiple_values
+  return first, second
+  ^^^^^^^^^^^^^^^^^^^^ Style/RedundantReturn: Redundant `return` detected. To return multiple values, use an array.
+end
+
+# modifier if as the last statement
+def modifier_if(x)
+  do_something
+  return x if x.positive?
+  ^^^^^^^^ Style/RedundantReturn: Redundant `return` detected.
+end
+
+# braceless hash gets braces
+def hash_value
+  return key: 1, other: 2
+  ^^^^^^^^^^^^^^^^^^^^^^^ Style/RedundantReturn: Redundant `return` detected.
+end
+
+# bare return as the last statement
+def bare_return
+  do_something
+  return
+  ^^^^^^ Style/RedundantReturn: Redundant `return` detected.
+end
@@ -0,0 +1,34 @@
+x = begin
+      something
+    rescue
+      nil
+    end
+
+y = begin
+      foo.bar
+    rescue
+      false
+    end
+
+z = begin
+      JSON.parse(str)
+    rescue
+      {}
+    end
+
+def cleanup
+  begin
+    File.delete(path)
+  rescue
+    nil
+  end
+end
+
+@cache ||= begin
+             load_cache
+           rescue
+             {}
+           end
+
+# Inside parentheses: reported, but left for a human to expand
+value = (Integer(input) rescue 0)
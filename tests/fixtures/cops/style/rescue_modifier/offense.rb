@@ -6,3 +6,15 @@ y = foo.bar rescue false
 
 z = JSON.parse(str) rescue {}
     ^^^^^^^^^^^^^^^^^^^^^^^^^ Style/RescueModifier: Avoid rescuing without specifying an error class.
+
+def cleanup
+  File.delete(path) rescue nil
+  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Style/RescueModifier: Avoid rescuing without specifying an error class.
+end
+
+@cache ||= load_cache rescue {}
+           ^^^^^^^^^^^^^^^^^^^^ Style/RescueModifier: Avoid rescuing without specifying an error class.
+
+# Inside parentheses: reported, but left for a human to expand
+value = (Integer(input) rescue 0)
+         ^^^^^^^^^^^^^^^^^^^^^^^ Style/RescueModifier: Avoid rescuing without specifying an error class.
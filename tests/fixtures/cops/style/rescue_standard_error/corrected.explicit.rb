@@ -0,0 +1,11 @@
+begin
+  do_something
+rescue StandardError
+  handle_error
+end
+
+begin
+  do_something
+rescue StandardError => e
+  handle_error(e)
+end
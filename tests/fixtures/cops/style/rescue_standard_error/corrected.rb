@@ -0,0 +1,37 @@
+begin
+  foo
+rescue
+  bar
+end
+
+begin
+  baz
+rescue => e
+  handle(e)
+end
+
+begin
+  one
+rescue
+  two
+end
+
+begin
+  three
+rescue
+  four
+end
+
+begin
+  five
+rescue ArgumentError
+  six
+rescue => e
+  seven(e)
+end
+
+def load
+  read
+rescue
+  nil
+end
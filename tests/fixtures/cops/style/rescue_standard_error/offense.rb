@@ -25,3 +25,19 @@ rescue ::StandardError
 ^^^^^^ Style/RescueStandardError: Omit the error class when rescuing `StandardError` by itself.
   four
 end
+
+begin
+  five
+rescue ArgumentError
+  six
+rescue StandardError => e
+^^^^^^ Style/RescueStandardError: Omit the error class when rescuing `StandardError` by itself.
+  seven(e)
+end
+
+def load
+  read
+rescue StandardError
+^^^^^^ Style/RescueStandardError: Omit the error class when rescuing `StandardError` by itself.
+  nil
+end
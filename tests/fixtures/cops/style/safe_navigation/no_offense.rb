@@ -175,3 +175,14 @@ before_save :inherit_restricted_status,
 # Ternary inside nil-method call arguments — ancestor walk finds
 # instance_variable_set as a nil-responding method
 instance_variable_set("@bar", baz.nil? ? nil : baz.to_s)
+
+# Comparisons on the checked call
+foo && foo.bar > 2
+foo && foo.bar == baz
+foo && foo < bar
+
+# Not-nil checks need ConvertCodeThatCanStartToReturnNil
+!foo.nil? && foo.bar
+
+# The receiver is assigned in the condition
+x = foo.bar and x.baz
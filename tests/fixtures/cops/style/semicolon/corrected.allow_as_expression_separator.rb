@@ -0,0 +1,7 @@
+x = 1; y = 2
+
+foo { bar }
+
+baz
+
+a = 1; b = 2
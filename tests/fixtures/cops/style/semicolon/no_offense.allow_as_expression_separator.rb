@@ -0,0 +1,6 @@
+x = 1; y = 2
+a = 1; b = 2; c = 3
+foo; bar
+def guard; log('guard'); !@fail_guard; end
+x = "a;b"; y = 2
+old_fs = $;; other = 1
@@ -0,0 +1,11 @@
+x = 1; y = 2;
+            ^ Style/Semicolon: Do not use semicolons to terminate expressions.
+
+foo { bar; }
+         ^ Style/Semicolon: Do not use semicolons to terminate expressions.
+
+; baz
+^ Style/Semicolon: Do not use semicolons to terminate expressions.
+
+a = 1;; b = 2
+      ^ Style/Semicolon: Do not use semicolons to terminate expressions.
@@ -40,7 +40,6 @@ x = "#{foo;}"
    ^ Style/Semicolon: Do not use semicolons to terminate expressions.
 
 x = "a;b"; y = 2
-      ^ Style/Semicolon: Do not use semicolons to terminate expressions.
          ^ Style/Semicolon: Do not use semicolons to terminate expressions.
 
 def prx; end
@@ -80,3 +79,12 @@ begin
 rescue error => e
   puts e
 end
+
+text = <<~TEXT; count = 2
+              ^ Style/Semicolon: Do not use semicolons to terminate expressions.
+  a;b
+TEXT
+
+foo;; bar
+   ^ Style/Semicolon: Do not use semicolons to terminate expressions.
+    ^ Style/Semicolon: Do not use semicolons to terminate expressions.
@@ -0,0 +1,11 @@
+fail RuntimeError, "message"
+
+fail
+
+Kernel.fail "kernel call"
+
+begin
+  work
+rescue => e
+  fail e
+end
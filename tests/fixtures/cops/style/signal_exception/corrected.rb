@@ -0,0 +1,17 @@
+raise RuntimeError, "message"
+
+raise "something went wrong"
+
+raise ArgumentError, "bad argument"
+
+raise "Could not find running ssh agent - Is config.ssh.forward_agent enabled in Vagrantfile?" unless ENV['SSH_AUTH_SOCK']
+
+Kernel.raise "kernel call"
+
+::Kernel.raise "top-level kernel call"
+
+begin
+  work
+rescue => e
+  raise e
+end
@@ -0,0 +1,22 @@
+def load(path)
+  fail ArgumentError, "missing" unless path
+  read(path)
+rescue Errno::ENOENT => e
+  raise LoadError, e.message
+end
+
+begin
+  work
+rescue => e
+  items.each do |item|
+    raise e if item.broken?
+  end
+else
+  fail "no error"
+ensure
+  fail "cleanup failed" unless cleaned_up?
+end
+
+value = compute rescue raise("fallback failed")
+
+Kernel.fail "signal"
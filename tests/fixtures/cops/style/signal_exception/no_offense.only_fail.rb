@@ -0,0 +1,13 @@
+fail RuntimeError, "message"
+
+fail
+
+Kernel.fail "kernel call"
+
+obj.raise "not bare"
+
+begin
+  work
+rescue => e
+  fail e
+end
@@ -0,0 +1,31 @@
+fail ArgumentError, "bad" unless valid?
+
+begin
+  work
+rescue StandardError => e
+  log(e)
+  raise
+end
+
+def retry_all
+  items.each do |item|
+    process(item)
+  rescue Timeout::Error => e
+    errors.each { |err| raise err if err.fatal? }
+    raise e
+  end
+end
+
+begin
+  connect
+rescue IOError
+  begin
+    fail "still down" unless reconnect
+  rescue IOError => e
+    raise e
+  end
+  raise
+end
+
+obj.raise "custom"
+value = compute rescue raise
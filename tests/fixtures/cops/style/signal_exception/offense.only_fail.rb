@@ -0,0 +1,15 @@
+raise RuntimeError, "message"
+^^^^^ Style/SignalException: Use `fail` instead of `raise` to signal exceptions.
+
+raise
+^^^^^ Style/SignalException: Use `fail` instead of `raise` to signal exceptions.
+
+Kernel.raise "kernel call"
+       ^^^^^ Style/SignalException: Use `fail` instead of `raise` to signal exceptions.
+
+begin
+  work
+rescue => e
+  raise e
+  ^^^^^ Style/SignalException: Use `fail` instead of `raise` to signal exceptions.
+end
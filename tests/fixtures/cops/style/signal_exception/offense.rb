@@ -9,3 +9,16 @@ fail ArgumentError, "bad argument"
 
 fail "Could not find running ssh agent - Is config.ssh.forward_agent enabled in Vagrantfile?" unless ENV['SSH_AUTH_SOCK']
 ^^^^ Style/SignalException: Use `raise` instead of `fail` to rethrow exceptions.
+
+Kernel.fail "kernel call"
+       ^^^^ Style/SignalException: Use `raise` instead of `fail` to rethrow exceptions.
+
+::Kernel.fail "top-level kernel call"
+         ^^^^ Style/SignalException: Use `raise` instead of `fail` to rethrow exceptions.
+
+begin
+  work
+rescue => e
+  fail e
+  ^^^^ Style/SignalException: Use `raise` instead of `fail` to rethrow exceptions.
+end
@@ -0,0 +1,29 @@
+def load(path)
+  raise ArgumentError, "missing" unless path
+  ^^^^^ Style/SignalException: Use `fail` instead of `raise` to signal exceptions.
+  read(path)
+rescue Errno::ENOENT => e
+  fail LoadError, e.message
+  ^^^^ Style/SignalException: Use `raise` instead of `fail` to rethrow exceptions.
+end
+
+begin
+  work
+rescue => e
+  items.each do |item|
+    fail e if item.broken?
+    ^^^^ Style/SignalException: Use `raise` instead of `fail` to rethrow exceptions.
+  end
+else
+  raise "no error"
+  ^^^^^ Style/SignalException: Use `fail` instead of `raise` to signal exceptions.
+ensure
+  raise "cleanup failed" unless cleaned_up?
+  ^^^^^ Style/SignalException: Use `fail` instead of `raise` to signal exceptions.
+end
+
+value = compute rescue fail("fallback failed")
+                       ^^^^ Style/SignalException: Use `raise` instead of `fail` to rethrow exceptions.
+
+Kernel.raise "signal"
+       ^^^^^ Style/SignalException: Use `fail` instead of `raise` to signal exceptions.
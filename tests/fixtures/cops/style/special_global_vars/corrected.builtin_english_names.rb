@@ -0,0 +1,7 @@
+puts $!
+
+puts $$
+
+puts "exited with #{$?}"
+
+$INPUT_RECORD_SEPARATOR = "\n" if $_
@@ -0,0 +1,11 @@
+puts $!
+
+puts $$
+
+$:.unshift File.expand_path('lib', __dir__)
+
+puts $0
+
+puts "failed: #{$!}"
+
+puts "status #$?"
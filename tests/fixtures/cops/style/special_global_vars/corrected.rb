@@ -1,3 +1,4 @@
+require 'English'
 puts $PROGRAM_NAME
 
 puts $ERROR_INFO
@@ -25,3 +26,7 @@ $LOAD_PATH.push File.expand_path('lib', __dir__)
 $LOAD_PATH.push File.expand_path('lib', __dir__)
 
 $LOAD_PATH.unshift File.expand_path('../../../lib', __dir__)
+
+puts "failed: #{$ERROR_INFO}"
+
+puts "pid #{$PROCESS_ID}"
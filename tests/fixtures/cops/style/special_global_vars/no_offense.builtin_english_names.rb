@@ -0,0 +1,13 @@
+$LOAD_PATH.unshift File.expand_path('lib', __dir__)
+
+puts $PROGRAM_NAME
+
+puts $LOADED_FEATURES.size
+
+$:.unshift File.expand_path('lib', __dir__)
+
+puts $0
+
+puts $!
+
+puts "failed: #{$!}"
@@ -0,0 +1,11 @@
+puts $!
+
+puts $0
+
+$:.unshift File.expand_path('lib', __dir__)
+
+puts $foo
+
+puts ARGV
+
+puts "failed: #{$!}"
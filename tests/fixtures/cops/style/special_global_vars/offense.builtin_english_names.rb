@@ -0,0 +1,11 @@
+puts $ERROR_INFO
+     ^^^^^^^^^^^ Style/SpecialGlobalVars: Prefer `$!` over `$ERROR_INFO`.
+
+puts $PID
+     ^^^^ Style/SpecialGlobalVars: Prefer `$$` over `$PID`.
+
+puts "exited with #{$CHILD_STATUS}"
+                    ^^^^^^^^^^^^^ Style/SpecialGlobalVars: Prefer `$?` over `$CHILD_STATUS`.
+
+$INPUT_RECORD_SEPARATOR = "\n" if $LAST_READ_LINE
+                                  ^^^^^^^^^^^^^^^ Style/SpecialGlobalVars: Prefer `$_` over `$LAST_READ_LINE`.
@@ -0,0 +1,17 @@
+puts $ERROR_INFO
+     ^^^^^^^^^^^ Style/SpecialGlobalVars: Prefer `$!` over `$ERROR_INFO`.
+
+puts $PID
+     ^^^^ Style/SpecialGlobalVars: Prefer `$$` over `$PID`.
+
+$LOAD_PATH.unshift File.expand_path('lib', __dir__)
+^^^^^^^^^^ Style/SpecialGlobalVars: Prefer `$:` over `$LOAD_PATH`.
+
+puts $PROGRAM_NAME
+     ^^^^^^^^^^^^^ Style/SpecialGlobalVars: Prefer `$0` over `$PROGRAM_NAME`.
+
+puts "failed: #{$ERROR_INFO}"
+                ^^^^^^^^^^^ Style/SpecialGlobalVars: Prefer `$!` over `$ERROR_INFO`.
+
+puts "status #$CHILD_STATUS"
+              ^^^^^^^^^^^^^ Style/SpecialGlobalVars: Prefer `$?` over `$CHILD_STATUS`.
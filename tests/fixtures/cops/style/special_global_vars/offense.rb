@@ -39,3 +39,9 @@ $:.push File.expand_path('lib', __dir__)
 
 $:.unshift File.expand_path('../../../lib', __dir__)
 ^ Style/SpecialGlobalVars: Prefer `$LOAD_PATH` over `$:`.
+
+puts "failed: #{$!}"
+                ^^ Style/SpecialGlobalVars: Prefer `$ERROR_INFO` over `$!`. Use `require 'English'` to access it.
+
+puts "pid #$$"
+           ^^ Style/SpecialGlobalVars: Prefer `$PROCESS_ID` over `$$`. Use `require 'English'` to access it.
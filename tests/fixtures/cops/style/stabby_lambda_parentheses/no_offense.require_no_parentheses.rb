@@ -0,0 +1,9 @@
+f = ->x { x + 1 }
+
+g = -> x, y { x + y }
+
+h = -> { 42 }
+
+i = ->() { true }
+
+j = -> { _1 + _2 }
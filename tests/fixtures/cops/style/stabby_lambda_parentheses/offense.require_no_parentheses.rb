@@ -0,0 +1,10 @@
+f = ->(x) { x + 1 }
+    ^^ Style/StabbyLambdaParentheses: Do not use parentheses for stabby lambda arguments.
+
+g = ->(x, y){ x + y }
+    ^^ Style/StabbyLambdaParentheses: Do not use parentheses for stabby lambda arguments.
+
+h = ->(a) do
+    ^^ Style/StabbyLambdaParentheses: Do not use parentheses for stabby lambda arguments.
+  a * 2
+end
@@ -0,0 +1,29 @@
+foo.map(&:to_s)
+
+bar.select(&:valid?)
+
+items.reject(&:nil?)
+
+# Ruby 3.4 it-block patterns
+items.map(&:to_s)
+
+records.select(&:visible)
+
+servers.any?(&:needs_recycling?)
+
+# Numbered parameter _1 patterns
+items.map(&:to_s)
+
+records.select(&:active?)
+
+# super blocks (FN cases)
+super(&:call_on_yielded)
+
+super(headers, &:mjml)
+
+# Outer call arguments, with and without parentheses
+do_something(one, two, &:test)
+
+do_something(one, two, &:test)
+
+items.map(&:name)
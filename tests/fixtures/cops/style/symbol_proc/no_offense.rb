@@ -53,3 +53,5 @@ something { _2.first }
 proc { |x| x.foo }
 lambda { |x| x.foo }
 Proc.new { |x| x.foo }
+# define_method is allowed by default
+define_method(:name) { |x| x.name }
@@ -32,3 +32,15 @@ super(headers) do |format|
                ^^^^^^^^^^^^^^ Style/SymbolProc: Pass `&:mjml` as an argument to `super` instead of a block.
   format.mjml
 end
+
+# Outer call arguments, with and without parentheses
+do_something(one, two) { |x| x.test }
+                       ^^^^^^^^^^^^^^ Style/SymbolProc: Pass `&:test` as an argument to the method instead of a block.
+
+do_something one, two do |x|
+                      ^^^^^^^^ Style/SymbolProc: Pass `&:test` as an argument to the method instead of a block.
+  x.test
+end
+
+items.map() { |x| x.name }
+            ^^^^^^^^^^^^^^ Style/SymbolProc: Pass `&:name` as an argument to the method instead of a block.
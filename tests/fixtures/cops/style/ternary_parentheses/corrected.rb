@@ -0,0 +1,16 @@
+x > 1 ? a : b
+
+foo ? bar : baz
+
+x && y ? 1 : 0
+
+x ? a : b
+
+# Unwrapping would change how these parse: reported, not corrected
+(defined? foo) ? foo : bar
+
+(include? item) ? a : b
+
+(a or b) ? c : d
+
+(not done) ? c : d
@@ -0,0 +1,11 @@
+(x > 1) ? a : b
+
+result = (x && !y) ? 1 : 0
+
+foo ? bar : baz
+
+foo.bar?(1) ? a : b
+
+@value ? a : b
+
+(include? item) ? a : b
@@ -0,0 +1,9 @@
+(x > 1) ? a : b
+
+(foo) ? bar : baz
+
+result = (x && y) ? 1 : 0
+
+(foo.bar?(1)) ? a : b
+
+(x = compute) ? x : default
@@ -0,0 +1,13 @@
+(x > 1) ? a : b
+
+foo ? bar : baz
+
+foo.bar?(1) ? a : b
+
+(x && y) ? 1 : 0
+
+(foo.empty? || bar) ? a : b
+
+defined?(Foo) ? Foo : nil
+
+(x = compute) ? x : default
@@ -0,0 +1,9 @@
+(match = pattern.match(line)) ? match[1] : nil
+
+(@user = find_user) ? @user.name : "guest"
+
+(@cache[key] = compute) ? true : false
+
+(x > 1) ? a : b
+
+foo ? bar : baz
@@ -6,3 +6,19 @@
 
 (x && y) ? 1 : 0
 ^ Style/TernaryParentheses: Ternary conditions should not be wrapped in parentheses.
+
+(x)? a : b
+^ Style/TernaryParentheses: Ternary conditions should not be wrapped in parentheses.
+
+# Unwrapping would change how these parse: reported, not corrected
+(defined? foo) ? foo : bar
+^ Style/TernaryParentheses: Ternary conditions should not be wrapped in parentheses.
+
+(include? item) ? a : b
+^ Style/TernaryParentheses: Ternary conditions should not be wrapped in parentheses.
+
+(a or b) ? c : d
+^ Style/TernaryParentheses: Ternary conditions should not be wrapped in parentheses.
+
+(not done) ? c : d
+^ Style/TernaryParentheses: Ternary conditions should not be wrapped in parentheses.
@@ -0,0 +1,11 @@
+x > 1 ? a : b
+^ Style/TernaryParentheses: Use parentheses for ternary conditions.
+
+foo ? bar : baz
+^ Style/TernaryParentheses: Use parentheses for ternary conditions.
+
+result = x && y ? 1 : 0
+         ^ Style/TernaryParentheses: Use parentheses for ternary conditions.
+
+foo.bar?(1) ? a : b
+^ Style/TernaryParentheses: Use parentheses for ternary conditions.
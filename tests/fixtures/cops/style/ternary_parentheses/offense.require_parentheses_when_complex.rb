@@ -0,0 +1,17 @@
+x > 1 ? a : b
+^ Style/TernaryParentheses: Use parentheses for ternary expressions with complex conditions.
+
+result = x && !y ? 1 : 0
+         ^ Style/TernaryParentheses: Use parentheses for ternary expressions with complex conditions.
+
+(foo) ? bar : baz
+^ Style/TernaryParentheses: Only use parentheses for ternary expressions with complex conditions.
+
+(foo.bar?(1)) ? a : b
+^ Style/TernaryParentheses: Only use parentheses for ternary expressions with complex conditions.
+
+(@value)? a : b
+^ Style/TernaryParentheses: Only use parentheses for ternary expressions with complex conditions.
+
+(include? item) ? a : b
+^ Style/TernaryParentheses: Only use parentheses for ternary expressions with complex conditions.
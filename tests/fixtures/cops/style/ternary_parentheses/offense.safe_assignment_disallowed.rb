@@ -0,0 +1,10 @@
+(match = pattern.match(line)) ? match[1] : nil
+^ Style/TernaryParentheses: Only use parentheses for ternary expressions with complex conditions.
+       ^ Lint/AssignmentInCondition: Use `==` if you meant to do a comparison or move the assignment up out of the condition.
+
+(@user = find_user) ? @user.name : "guest"
+^ Style/TernaryParentheses: Only use parentheses for ternary expressions with complex conditions.
+       ^ Lint/AssignmentInCondition: Use `==` if you meant to do a comparison or move the assignment up out of the condition.
+
+x > 1 ? a : b
+^ Style/TernaryParentheses: Use parentheses for ternary expressions with complex conditions.
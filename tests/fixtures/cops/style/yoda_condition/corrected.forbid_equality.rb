@@ -0,0 +1,3 @@
+x == 42
+obj != nil
+return if role == "admin"
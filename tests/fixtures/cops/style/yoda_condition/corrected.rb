@@ -0,0 +1,41 @@
+x == 42
+
+y != "hello"
+
+obj == nil
+
+flag == true
+
+bar == :foo
+
+done != false
+
+value == CONST
+
+count <= MAX_SIZE
+
+total != Config::LIMIT
+
+value == `cmd`
+
+method_name == :"#{name}="
+
+text == /pattern/
+
+params == {"foo" => ["bar"], "baz" => ["quux"]}
+
+if found == [query]
+
+if braid_spec.nil? || braid_spec.gem_dir + '/lib/braid/check_gem.rb' != __FILE__
+
+job_args == [mailer.to_s, method.to_s, "deliver_now"] &&
+
+raise ArgumentError, "bad currency pair" if query[:symbols] == [query[:base]]
+
+auth == [username, password]
+
+if target_info == [event.button, event.event_type] and
+
+job_args == [mailer.to_s, method.to_s, "deliver_now"] &&
+
+Redmon.config.secure.split(':') == [username, password]
@@ -0,0 +1,4 @@
+42 == x
+MAX_SIZE <= count
+10 > value
+__FILE__ == $0
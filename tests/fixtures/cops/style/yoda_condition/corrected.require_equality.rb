@@ -0,0 +1,3 @@
+42 == x
+nil != obj
+return if :admin == role
@@ -0,0 +1,6 @@
+1 < x
+MAX_SIZE >= count
+0 <= index
+x == 1
+1 == 1
+__FILE__ == $0
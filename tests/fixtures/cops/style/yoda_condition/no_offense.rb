@@ -35,3 +35,6 @@ ConstPathRef <= { scope: (ConstPathRef | Const), name: Const }
 
 # Interpolated regexp on LHS — also skipped by interpolation? check
 /#{pattern}/ == text
+
+# Safe navigation is never reported
+1&.==(x)
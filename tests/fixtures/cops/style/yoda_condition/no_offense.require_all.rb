@@ -0,0 +1,7 @@
+42 == x
+MAX_SIZE <= count
+nil != obj
+1 == 1
+x == y
+foo&.==(1)
+"#{prefix}" == name
@@ -0,0 +1,5 @@
+count >= MAX_SIZE
+value < 10
+42 == x
+nil != obj
+x == y
@@ -0,0 +1,6 @@
+42 == x
+^^^^^^^ Style/YodaCondition: Prefer non-Yoda conditions.
+nil != obj
+^^^^^^^^^^ Style/YodaCondition: Prefer non-Yoda conditions.
+return if "admin" == role
+          ^^^^^^^^^^^^^^^ Style/YodaCondition: Prefer non-Yoda conditions.
@@ -0,0 +1,8 @@
+x == 42
+^^^^^^^ Style/YodaCondition: Prefer Yoda conditions.
+count >= MAX_SIZE
+^^^^^^^^^^^^^^^^^ Style/YodaCondition: Prefer Yoda conditions.
+value < 10
+^^^^^^^^^^ Style/YodaCondition: Prefer Yoda conditions.
+$0 == __FILE__
+^^^^^^^^^^^^^^ Style/YodaCondition: Prefer Yoda conditions.
@@ -0,0 +1,6 @@
+x == 42
+^^^^^^^ Style/YodaCondition: Prefer Yoda conditions.
+obj != nil
+^^^^^^^^^^ Style/YodaCondition: Prefer Yoda conditions.
+return if role == :admin
+          ^^^^^^^^^^^^^^ Style/YodaCondition: Prefer Yoda conditions.
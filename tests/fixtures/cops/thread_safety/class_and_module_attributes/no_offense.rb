@@ -0,0 +1,19 @@
+module Config
+  mattr_reader :endpoint
+  cattr_reader :current
+end
+
+class Account
+  attr_accessor :name
+  attr_writer :email
+
+  class << self
+    attr_reader :registry
+
+    def build
+      attr_accessor :dynamic
+    end
+  end
+end
+
+record.class_attribute :settings
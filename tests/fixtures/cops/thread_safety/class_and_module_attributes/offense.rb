@@ -0,0 +1,20 @@
+module Config
+  mattr_accessor :endpoint
+  ^^^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/ClassAndModuleAttributes: Avoid mutating class and module attributes.
+  mattr_writer :token
+  ^^^^^^^^^^^^^^^^^^^ ThreadSafety/ClassAndModuleAttributes: Avoid mutating class and module attributes.
+end
+
+class Account
+  cattr_accessor :current
+  ^^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/ClassAndModuleAttributes: Avoid mutating class and module attributes.
+  class_attribute :settings
+  ^^^^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/ClassAndModuleAttributes: Avoid mutating class and module attributes.
+
+  class << self
+    attr_accessor :registry
+    ^^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/ClassAndModuleAttributes: Avoid mutating class and module attributes.
+    attr_writer :logger
+    ^^^^^^^^^^^^^^^^^^^ ThreadSafety/ClassAndModuleAttributes: Avoid mutating class and module attributes.
+  end
+end
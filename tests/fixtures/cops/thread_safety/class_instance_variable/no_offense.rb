@@ -0,0 +1,46 @@
+class Cache
+  @store = {}
+
+  def store
+    @store ||= {}
+  end
+
+  def self.build
+    define_method(:value) do
+      @value
+    end
+  end
+
+  def self.safe_store
+    MUTEX.synchronize do
+      @store ||= {}
+    end
+  end
+
+  def self.anonymous
+    Class.new do
+      def initialize
+        @name = "anon"
+      end
+    end
+  end
+
+  class << self
+    attr_reader :store
+  end
+end
+
+User.class_eval do
+  def name
+    @name
+  end
+end
+
+module Helpers
+  def config
+    @config
+  end
+end
+
+items.each { @count += 1 }
+instance_variable_get(:@foo)
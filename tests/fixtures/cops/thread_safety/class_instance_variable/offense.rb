@@ -0,0 +1,66 @@
+class Cache
+  def self.store
+    @store ||= {}
+    ^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+  end
+
+  def self.reset!
+    @store = nil
+    ^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+    instance_variable_set(:@hits, 0)
+    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+  end
+
+  class << self
+    def hits
+      instance_variable_get(:@hits)
+      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+    end
+  end
+end
+
+module Trackable
+  extend ActiveSupport::Concern
+
+  class_methods do
+    def tracker
+      @tracker
+      ^^^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+    end
+  end
+
+  module ClassMethods
+    def registry
+      @registry
+      ^^^^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+    end
+  end
+end
+
+module Helpers
+  module_function
+
+  def config
+    @config
+    ^^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+  end
+end
+
+module Counters
+  def count
+    @count += 1
+    ^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+  end
+  module_function :count
+end
+
+User.class_eval do
+  @cached = true
+  ^^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+end
+
+define_singleton_method(:setup) do
+  @ready, @loaded = true, false
+  ^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+          ^^^^^^^ ThreadSafety/ClassInstanceVariable: Avoid class instance variables.
+end
@@ -0,0 +1,16 @@
+class Registry
+  @handlers = [].freeze
+  @options ||= { retries: 3 }.freeze
+  @prefix = "registry".freeze
+  @ids = [1, 2, 3].freeze
+  @window = (1..10).freeze
+  @levels = (1..5).to_a.freeze
+end
+
+module Settings
+  @defaults, @count = {}.freeze, 0
+
+  class << self
+    @cache = {}.freeze
+  end
+end
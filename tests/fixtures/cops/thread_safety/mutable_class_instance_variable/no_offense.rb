@@ -0,0 +1,21 @@
+class Registry
+  @handlers = [].freeze
+  @limit = 10
+  @name = :registry
+  @queue = Queue.new
+  @loader = Loader.new
+
+  def initialize
+    @handlers = []
+  end
+
+  def self.reset!
+    @handlers = []
+  end
+
+  define_method(:clear) do
+    @handlers = {}
+  end
+end
+
+@top_level = []
@@ -0,0 +1,24 @@
+class Registry
+  @handlers = []
+              ^^ ThreadSafety/MutableClassInstanceVariable: Freeze mutable objects assigned to class instance variables.
+  @options ||= { retries: 3 }
+               ^^^^^^^^^^^^^^ ThreadSafety/MutableClassInstanceVariable: Freeze mutable objects assigned to class instance variables.
+  @prefix = "registry"
+            ^^^^^^^^^^ ThreadSafety/MutableClassInstanceVariable: Freeze mutable objects assigned to class instance variables.
+  @ids = 1, 2, 3
+         ^^^^^^^ ThreadSafety/MutableClassInstanceVariable: Freeze mutable objects assigned to class instance variables.
+  @window = (1..10)
+            ^^^^^^^ ThreadSafety/MutableClassInstanceVariable: Freeze mutable objects assigned to class instance variables.
+  @levels = [*1..5]
+            ^^^^^^^ ThreadSafety/MutableClassInstanceVariable: Freeze mutable objects assigned to class instance variables.
+end
+
+module Settings
+  @defaults, @count = {}, 0
+                      ^^ ThreadSafety/MutableClassInstanceVariable: Freeze mutable objects assigned to class instance variables.
+
+  class << self
+    @cache = {}
+             ^^ ThreadSafety/MutableClassInstanceVariable: Freeze mutable objects assigned to class instance variables.
+  end
+end
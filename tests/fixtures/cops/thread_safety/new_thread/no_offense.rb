@@ -0,0 +1,6 @@
+Thread.current[:request_id] = id
+Thread.main
+Foo::Thread.new { do_work }
+thread = Thread
+Process.fork { do_work }
+Concurrent::Future.execute { do_work }
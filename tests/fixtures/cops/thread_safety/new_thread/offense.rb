@@ -0,0 +1,13 @@
+Thread.new { do_work }
+^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/NewThread: Avoid starting new threads.
+
+::Thread.new(arg) { |a| process(a) }
+^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/NewThread: Avoid starting new threads.
+
+Thread.fork { do_work }
+^^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/NewThread: Avoid starting new threads.
+
+def run
+  Thread.start { do_work }
+  ^^^^^^^^^^^^^^^^^^^^^^^^ ThreadSafety/NewThread: Avoid starting new threads.
+end
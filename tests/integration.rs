@@ -52,36 +52,59 @@ fn default_args() -> Args {
         only: vec![],
         except: vec![],
         no_color: false,
+        sort_severity: false,
         debug: false,
+        debug_json: false,
         rubocop_only: false,
         list_cops: false,
         list_autocorrectable_cops: false,
+        show_cops: None,
         migrate: false,
         doctor: false,
         rules: false,
         tier: None,
+        department: None,
+        history_file: None,
+        history_summary: false,
+        emit_schema: None,
+        validate_config: false,
         stdin: None,
         init: false,
         no_cache: false,
         cache: "true".to_string(),
         cache_clear: false,
+        paranoid: false,
         fail_level: "convention".to_string(),
+        display_only_fail_level_offenses: false,
+        max_reported_offenses: None,
         fail_fast: false,
         force_exclusion: false,
         list_target_files: false,
+        estimate: false,
+        preview_cop: Vec::new(),
+        show_config_chain: false,
         display_cop_names: false,
         parallel: false,
+        no_parallel: false,
         require_libs: vec![],
         ignore_disable_comments: false,
         force_default_config: false,
         autocorrect: false,
         autocorrect_all: false,
+        autocorrect_changed_lines: None,
+        autocorrect_cop: Vec::new(),
+        copy_to: None,
+        copy_all: false,
         preview: true,
+        erb: false,
+        markdown: false,
         quiet_skips: false,
         strict: None,
         verify: false,
         rubocop_cmd: "bundle exec rubocop".to_string(),
         corpus_check: None,
+        baseline: None,
+        baseline_generate: None,
     }
 }
 
@@ -205,6 +228,75 @@ fn lint_multiple_files() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[cfg(unix)]
+#[test]
+fn symlinked_file_is_linted_once() {
+    let dir = temp_dir("symlinked_file_once");
+    let real = write_file(&dir, "real.rb", b"y = 2  \n");
+    let link = dir.join("link.rb");
+    std::os::unix::fs::symlink("real.rb", &link).unwrap();
+
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let mut args = default_args();
+    args.only = vec!["Layout/TrailingWhitespace".to_string()];
+
+    let result = run_linter(
+        &discovered(&[link.clone(), real.clone()]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    assert_eq!(result.file_count, 1, "both paths name the same file");
+    let paths: Vec<&str> = result.diagnostics.iter().map(|d| d.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![link.to_str().unwrap(), real.to_str().unwrap()],
+        "each requested path reports the offense"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn same_file_passed_twice_is_inspected_once() {
+    let dir = temp_dir("same_file_twice");
+    let file = write_file(&dir, "a.rb", b"y = 2  \n");
+    fs::create_dir_all(dir.join("sub")).unwrap();
+    let other_spelling = dir.join("sub").join("..").join("a.rb");
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .args([
+            "--format",
+            "text",
+            "--only",
+            "Layout/TrailingWhitespace",
+            "--no-cache",
+            file.to_str().unwrap(),
+            other_spelling.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to execute nitrocop");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("1 file inspected, 2 offenses detected"),
+        "the file counts once, but both paths list the offense: {stdout}"
+    );
+    assert!(
+        stdout.contains(&format!("{}:1:", file.display())),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains(&format!("{}:1:", other_spelling.display())),
+        "{stdout}"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 // ---------- Filtering tests ----------
 
 #[test]
@@ -282,6 +374,220 @@ fn except_filter_excludes_cops() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn only_pragma_limits_cops_for_file() {
+    let dir = temp_dir("only_pragma");
+    // Trailing whitespace and a missing frozen_string_literal comment would
+    // normally fire, but the pragma keeps only the Lint department.
+    let pragma = write_file(
+        &dir,
+        "pragma.rb",
+        b"# nitrocop:only Lint -- generated
+x = 1  
+x = 2
+",
+    );
+    let plain = write_file(
+        &dir,
+        "plain.rb",
+        b"x = 1  
+",
+    );
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = default_args();
+
+    let result = run_linter(
+        &discovered(&[pragma.clone(), plain.clone()]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+
+    let pragma_cops: Vec<&str> = result
+        .diagnostics
+        .iter()
+        .filter(|d| d.path == pragma.to_string_lossy())
+        .map(|d| d.cop_name.as_str())
+        .collect();
+    assert!(
+        pragma_cops.iter().all(|c| c.starts_with("Lint/")),
+        "Only Lint cops should fire on the pragma file, got: {pragma_cops:?}"
+    );
+    assert!(
+        result
+            .diagnostics
+            .iter()
+            .any(|d| d.path == plain.to_string_lossy() && d.cop_name == "Layout/TrailingWhitespace"),
+        "Files without a pragma are unaffected"
+    );
+    assert_eq!(result.pragma_files, vec![pragma]);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn except_pragma_drops_departments() {
+    let dir = temp_dir("except_pragma");
+    let file = write_file(
+        &dir,
+        "test.rb",
+        b"# nitrocop:except Layout, Style/FrozenStringLiteralComment
+x = 1  
+",
+    );
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = default_args();
+
+    let result = run_linter(
+        &discovered(&[file]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+
+    for d in &result.diagnostics {
+        assert!(
+            !d.cop_name.starts_with("Layout/") && d.cop_name != "Style/FrozenStringLiteralComment",
+            "Cop {} should be dropped by the pragma",
+            d.cop_name,
+        );
+    }
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn pragma_intersects_with_only_filter() {
+    let dir = temp_dir("pragma_and_only");
+    let file = write_file(
+        &dir,
+        "test.rb",
+        b"# nitrocop:only Style
+x = 1  
+",
+    );
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    // --only selects a Layout cop, the pragma only Style: nothing may run.
+    let args = Args {
+        only: vec!["Layout/TrailingWhitespace".to_string()],
+        ..default_args()
+    };
+
+    let result = run_linter(
+        &discovered(&[file]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+
+    assert!(
+        result.diagnostics.is_empty(),
+        "Expected no offenses, got: {:?}",
+        result
+            .diagnostics
+            .iter()
+            .map(|d| format!("{d}"))
+            .collect::<Vec<_>>()
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn pragma_keeps_inline_disable_directives() {
+    let dir = temp_dir("pragma_inline_disable");
+    let file = write_file(
+        &dir,
+        "test.rb",
+        b"# nitrocop:only Layout/TrailingWhitespace
+x = 1  
+y = 2   # rubocop:disable Layout/TrailingWhitespace
+",
+    );
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = default_args();
+
+    let result = run_linter(
+        &discovered(&[file]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+
+    let lines: Vec<(usize, &str)> = result
+        .diagnostics
+        .iter()
+        .map(|d| (d.location.line, d.cop_name.as_str()))
+        .collect();
+    assert_eq!(lines, vec![(2, "Layout/TrailingWhitespace")]);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn baseline_suppresses_existing_offenses_only() {
+    use nitrocop::baseline::{Baseline, SourceCache};
+
+    let dir = temp_dir("baseline");
+    let file = write_file(&dir, "test.rb", b"x = 1  \n");
+    let baseline_path = dir.join("nitrocop_baseline.json");
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = Args {
+        only: vec!["Layout/TrailingWhitespace".to_string()],
+        ..default_args()
+    };
+    let lint = || {
+        run_linter(
+            &discovered(std::slice::from_ref(&file)),
+            &config,
+            &registry,
+            &args,
+            &TierMap::load(),
+            &AutocorrectAllowlist::load(),
+        )
+    };
+
+    let before = lint();
+    assert_eq!(before.diagnostics.len(), 1);
+    Baseline::generate(&before.diagnostics, &baseline_path, &mut SourceCache::new())
+        .write(&baseline_path)
+        .unwrap();
+
+    // The grandfathered line moves down and a new offense appears.
+    fs::write(&file, b"# comment\nx = 1  \ny = 2  \n").unwrap();
+    let after = lint();
+    assert_eq!(after.diagnostics.len(), 2);
+    let outcome = Baseline::load(&baseline_path).unwrap().apply(
+        after.diagnostics,
+        std::slice::from_ref(&file),
+        |_| true,
+        &mut SourceCache::new(),
+    );
+    assert_eq!(outcome.suppressed, 1);
+    assert!(outcome.stale.is_empty());
+    let remaining: Vec<usize> = outcome
+        .diagnostics
+        .iter()
+        .map(|d| d.location.line)
+        .collect();
+    assert_eq!(remaining, vec![3]);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn only_with_single_cop_on_clean_file() {
     let dir = temp_dir("only_clean");
@@ -345,6 +651,119 @@ fn config_disables_cop() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn erb_mode_lints_template_fragments() {
+    let dir = temp_dir("erb_mode");
+    let file = write_file(
+        &dir,
+        "show.html.erb",
+        "<ul>\n<% items.each do |item| %>\n  <li><%= item.name %></li>\n<% end %>\n<p>héllo <%= link_to(\"a\" , path) %></p>\n"
+            .as_bytes(),
+    );
+    let config_path = write_file(&dir, ".rubocop.yml", b"AllCops:\n  Erb: true\n");
+    let config = load_config(Some(config_path.as_path()), None, None).unwrap();
+    assert!(config.erb_enabled());
+    let registry = CopRegistry::default_registry();
+    let args = default_args();
+
+    let result = run_linter(
+        &discovered(&[file]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+
+    let offenses: Vec<(&str, usize, usize)> = result
+        .diagnostics
+        .iter()
+        .map(|d| (d.cop_name.as_str(), d.location.line, d.location.column))
+        .collect();
+    // Columns count characters of the template, including the `é`.
+    assert!(
+        offenses.contains(&("Style/StringLiterals", 5, 21)),
+        "got: {offenses:?}"
+    );
+    assert!(
+        offenses
+            .iter()
+            .any(|&(cop, line, _)| cop == "Layout/SpaceBeforeComma" && line == 5),
+        "got: {offenses:?}"
+    );
+    // Whole-file cops and empty-body checks don't apply to fragments.
+    for (cop, _, _) in &offenses {
+        assert!(
+            !matches!(
+                *cop,
+                "Style/FrozenStringLiteralComment"
+                    | "Layout/TrailingEmptyLines"
+                    | "Layout/ExtraSpacing"
+                    | "Lint/EmptyBlock"
+                    | "Lint/Syntax"
+            ),
+            "unexpected {cop}: {offenses:?}"
+        );
+    }
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn markdown_mode_lints_ruby_code_blocks() {
+    let dir = temp_dir("markdown_mode");
+    let file = write_file(
+        &dir,
+        "README.md",
+        b"# Usage\n\nTrailing spaces here are fine.  \n\n```ruby\nclient = Client.new\nclient.call  \n```\n\n```js\nconst x = 1;  \n```\n\n   ```rb\n   foo(1 , 2)\n   ```\n\n```ruby\nthis is not ruby (\n```\n",
+    );
+    let config_path = write_file(&dir, ".rubocop.yml", b"require:\n  - rubocop-md\n");
+    let config = load_config(Some(config_path.as_path()), None, None).unwrap();
+    assert!(config.markdown_enabled());
+    let registry = CopRegistry::default_registry();
+    let args = default_args();
+
+    let result = run_linter(
+        &discovered(&[file]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+
+    let offenses: Vec<(&str, usize, usize)> = result
+        .diagnostics
+        .iter()
+        .map(|d| (d.cop_name.as_str(), d.location.line, d.location.column))
+        .collect();
+    assert!(
+        offenses.contains(&("Layout/TrailingWhitespace", 7, 11)),
+        "got: {offenses:?}"
+    );
+    // Columns include the fence indentation.
+    assert!(
+        offenses.contains(&("Layout/SpaceBeforeComma", 15, 8)),
+        "got: {offenses:?}"
+    );
+    for (cop, line, _) in &offenses {
+        // Prose, the `js` fence and the invalid snippet are left alone.
+        assert!(
+            matches!(line, 6..=8 | 14..=16),
+            "unexpected {cop}: {offenses:?}"
+        );
+        assert!(
+            !matches!(
+                *cop,
+                "Style/FrozenStringLiteralComment" | "Layout/TrailingEmptyLines" | "Lint/Syntax"
+            ),
+            "unexpected {cop}: {offenses:?}"
+        );
+    }
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn nested_config_disables_cop_for_subdir() {
     let dir = temp_dir("nested_disable_cop");
@@ -516,6 +935,72 @@ fn config_line_length_max_override() {
     fs::remove_dir_all(&dir).ok();
 }
 
+/// Severity letter per cop for a file mixing Layout, Style, Lint and
+/// Security offenses, linted with the given config.
+fn mixed_offense_severities(test_name: &str, config_yaml: Option<&[u8]>) -> Vec<(String, char)> {
+    let dir = temp_dir(test_name);
+    let file = write_file(&dir, "test.rb", b"x = 1  \neval(x)\nbinding.pry\n");
+    let config_path = config_yaml.map(|yaml| write_file(&dir, ".rubocop.yml", yaml));
+    let config = load_config(config_path.as_deref(), None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = Args {
+        only: vec![
+            "Layout/TrailingWhitespace".to_string(),
+            "Style/FrozenStringLiteralComment".to_string(),
+            "Lint/Debugger".to_string(),
+            "Security/Eval".to_string(),
+        ],
+        ..default_args()
+    };
+
+    let result = run_linter(
+        &discovered(&[file]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    fs::remove_dir_all(&dir).ok();
+
+    let mut severities: Vec<(String, char)> = result
+        .diagnostics
+        .iter()
+        .map(|d| (d.cop_name.clone(), d.severity.letter()))
+        .collect();
+    severities.sort();
+    severities
+}
+
+#[test]
+fn default_severity_follows_department() {
+    // Matches RuboCop's text output: Lint and Security cops report `W`,
+    // everything else `C`.
+    assert_eq!(
+        mixed_offense_severities("severity_defaults", None),
+        vec![
+            ("Layout/TrailingWhitespace".to_string(), 'C'),
+            ("Lint/Debugger".to_string(), 'W'),
+            ("Security/Eval".to_string(), 'W'),
+            ("Style/FrozenStringLiteralComment".to_string(), 'C'),
+        ]
+    );
+}
+
+#[test]
+fn config_severity_overrides_default() {
+    let yaml = b"Lint:\n  Severity: error\nSecurity/Eval:\n  Severity: fatal\nLayout/TrailingWhitespace:\n  Severity: warning\n";
+    assert_eq!(
+        mixed_offense_severities("severity_overrides", Some(yaml)),
+        vec![
+            ("Layout/TrailingWhitespace".to_string(), 'W'),
+            ("Lint/Debugger".to_string(), 'E'),
+            ("Security/Eval".to_string(), 'F'),
+            ("Style/FrozenStringLiteralComment".to_string(), 'C'),
+        ]
+    );
+}
+
 #[test]
 fn default_line_length_allows_120() {
     let dir = temp_dir("default_max");
@@ -730,8 +1215,8 @@ fn all_registered_cops_can_fire() {
 #[test]
 fn registry_has_expected_cop_count() {
     let registry = CopRegistry::default_registry();
-    // 915 supported + 5 no-ops (obsolete on Ruby 3.4+)
-    assert_eq!(registry.len(), 915 + 5);
+    // 931 supported + 4 no-ops (obsolete on Ruby 3.4+)
+    assert_eq!(registry.len(), 931 + 4);
 
     let names = registry.names();
     let expected = [
@@ -997,6 +1482,15 @@ fn registry_has_expected_cop_count() {
         "Metrics/PerceivedComplexity",
         // Migration (1)
         "Migration/DepartmentName",
+        // Minitest (8)
+        "Minitest/AssertEmpty",
+        "Minitest/AssertEqual",
+        "Minitest/AssertIncludes",
+        "Minitest/AssertNil",
+        "Minitest/AssertTruthy",
+        "Minitest/EmptyLineBeforeAssertionMethods",
+        "Minitest/MultipleAssertions",
+        "Minitest/RefuteEqual",
         // Naming (19)
         "Naming/AccessorMethodName",
         "Naming/AsciiIdentifiers",
@@ -1017,7 +1511,7 @@ fn registry_has_expected_cop_count() {
         "Naming/RescuedExceptionsVariableName",
         "Naming/VariableName",
         "Naming/VariableNumber",
-        // Performance (47)
+        // Performance (48)
         "Performance/AncestorsInclude",
         "Performance/ArraySemiInfiniteRangeSlice",
         "Performance/BigDecimalWithNumericArgument",
@@ -1065,6 +1559,7 @@ fn registry_has_expected_cop_count() {
         "Performance/TimesMap",
         "Performance/UnfreezeString",
         "Performance/UriDefaultParser",
+        "Performance/ZipWithoutBlock",
         // RSpec (113)
         "RSpec/AlignLeftLetBrace",
         "RSpec/AlignRightLetBrace",
@@ -1305,6 +1800,10 @@ fn registry_has_expected_cop_count() {
         "Security/MarshalLoad",
         "Security/Open",
         "Security/YAMLLoad",
+        // Sorbet (3)
+        "Sorbet/FalseSigil",
+        "Sorbet/TrueSigil",
+        "Sorbet/ValidSigil",
         // Style (80)
         "Style/AndOr",
         "Style/BlockDelimiters",
@@ -1357,7 +1856,6 @@ fn registry_has_expected_cop_count() {
         "Style/RedundantSort",
         "Style/RescueModifier",
         "Style/RescueStandardError",
-        "Style/SafeNavigation",
         "Style/Sample",
         "Style/SelectByRegexp",
         "Style/SelfAssignment",
@@ -1384,6 +1882,11 @@ fn registry_has_expected_cop_count() {
         "Style/WordArray",
         "Style/YodaCondition",
         "Style/ZeroLengthPredicate",
+        // ThreadSafety (4)
+        "ThreadSafety/ClassAndModuleAttributes",
+        "ThreadSafety/ClassInstanceVariable",
+        "ThreadSafety/MutableClassInstanceVariable",
+        "ThreadSafety/NewThread",
     ];
     for name in &expected {
         assert!(
@@ -1651,9 +2154,68 @@ fn global_exclude_skips_file() {
         vendor_offenses.is_empty(),
         "Global Exclude should prevent offenses on vendor/ files"
     );
-    assert!(
-        !app_offenses.is_empty(),
-        "Non-excluded files should still have offenses"
+    assert!(
+        !app_offenses.is_empty(),
+        "Non-excluded files should still have offenses"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn plugin_all_cops_exclude_applies_to_plugin_cops_only() {
+    let dir = temp_dir("plugin_all_cops_exclude");
+    // A vendored mini rubocop-rails: AllCops.Exclude for generated files, one
+    // cop relying on the department and one with an Exclude of its own.
+    let gem_root = dir.join("gems/rubocop-rails");
+    write_file(
+        &gem_root,
+        "config/default.yml",
+        b"inherit_mode:\n  merge:\n    - Exclude\n\nAllCops:\n  Exclude:\n    - bin/*\n    - db/*schema.rb\n\nRails:\n  Enabled: true\n\nRails/Blank:\n  Enabled: true\n\nRails/Present:\n  Enabled: true\n  Exclude:\n    - node_modules/**/*\n",
+    );
+    let gem_cache = std::collections::HashMap::from([("rubocop-rails".to_string(), gem_root)]);
+    let config_path = write_file(&dir, ".rubocop.yml", b"plugins:\n  - rubocop-rails\n");
+    let source = b"a = foo.nil? || foo.empty?  \nb = !foo.blank?\n";
+    let schema = write_file(&dir, "db/schema.rb", source);
+    let model = write_file(&dir, "app/models/user.rb", source);
+
+    let config = load_config(Some(config_path.as_path()), None, Some(&gem_cache)).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = Args {
+        only: vec![
+            "Rails/Blank".to_string(),
+            "Rails/Present".to_string(),
+            "Layout/TrailingWhitespace".to_string(),
+        ],
+        ..default_args()
+    };
+    let result = run_linter(
+        &discovered(&[schema, model]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+
+    let cops_for = |file: &str| -> Vec<&str> {
+        let mut cops: Vec<&str> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.path.ends_with(file))
+            .map(|d| d.cop_name.as_str())
+            .collect();
+        cops.sort_unstable();
+        cops
+    };
+    assert_eq!(
+        cops_for("db/schema.rb"),
+        vec!["Layout/TrailingWhitespace"],
+        "the plugin's AllCops.Exclude should skip its own cops only"
+    );
+    assert_eq!(
+        cops_for("app/models/user.rb"),
+        vec!["Layout/TrailingWhitespace", "Rails/Blank", "Rails/Present"]
     );
 
     fs::remove_dir_all(&dir).ok();
@@ -1800,11 +2362,10 @@ fn all_cops_have_minimum_test_coverage() {
         "Lint/RedundantCopDisableDirective", // requires post-processing after all cops run
         "Lint/Syntax",                       // syntax errors reported by parser, not this cop
         // Unsupported cops — obsolete under modern Ruby, kept as no-ops for config compatibility
-        "Lint/ItWithoutArgumentsInBlock", // `it` is a block parameter in Ruby 3.4+
         "Lint/NonDeterministicRequireOrder", // Dir sorts since Ruby 3.0
-        "Lint/NumberedParameterAssignment", // `_1 = x` is a syntax error in Ruby 3.4+
-        "Lint/UselessElseWithoutRescue",  // `else` without `rescue` is a syntax error in Ruby 3.4+
-        "Security/YAMLLoad",              // YAML.load is safe since Ruby 3.1
+        "Lint/NumberedParameterAssignment",  // `_1 = x` is a syntax error in Ruby 3.4+
+        "Lint/UselessElseWithoutRescue", // `else` without `rescue` is a syntax error in Ruby 3.4+
+        "Security/YAMLLoad",             // YAML.load is safe since Ruby 3.1
     ];
 
     let mut failures = Vec::new();
@@ -1936,7 +2497,6 @@ fn offense_fixtures_have_no_unannotated_blocks() {
     let stub_cops: &[&str] = &[
         "Lint/RedundantCopDisableDirective",
         "Lint/Syntax",
-        "Lint/ItWithoutArgumentsInBlock",
         "Lint/NonDeterministicRequireOrder",
         "Lint/NumberedParameterAssignment",
         "Lint/UselessElseWithoutRescue",
@@ -2543,6 +3103,90 @@ fn lint_source_directly() {
     assert_eq!(result.diagnostics[0].path, "test.rb");
 }
 
+#[test]
+fn lint_string_returns_diagnostics_and_corrected_source() {
+    use nitrocop::api::{ConfigSource, LintOptions, lint_string};
+    use nitrocop::cli::AutocorrectMode;
+
+    let options = || LintOptions {
+        only: vec!["Layout/TrailingWhitespace".to_string()],
+        preview: true,
+        ..LintOptions::default()
+    };
+    let outcome = lint_string("x = 1   \ny = 2\n", "lib/a.rb", options()).unwrap();
+    assert_eq!(outcome.diagnostics.len(), 1);
+    assert_eq!(outcome.diagnostics[0].path, "lib/a.rb");
+    assert_eq!(outcome.diagnostics[0].location.line, 1);
+    assert!(outcome.corrected_source.is_none());
+
+    let outcome = lint_string(
+        "x = 1   \ny = 2\n",
+        "lib/a.rb",
+        LintOptions {
+            autocorrect: AutocorrectMode::Safe,
+            ..options()
+        },
+    )
+    .unwrap();
+    assert!(outcome.diagnostics[0].corrected);
+    assert_eq!(outcome.corrected_source.as_deref(), Some("x = 1\ny = 2\n"));
+
+    // A config from a directory applies its Exclude to the path hint.
+    let dir = temp_dir("lint_string_config");
+    write_file(
+        &dir,
+        ".rubocop.yml",
+        b"Layout/TrailingWhitespace:\n  Exclude:\n    - 'vendor/**/*'\n",
+    );
+    let outcome = lint_string(
+        "x = 1   \n",
+        dir.join("vendor/a.rb").to_str().unwrap(),
+        LintOptions {
+            config: ConfigSource::Discover(&dir),
+            ..options()
+        },
+    )
+    .unwrap();
+    assert!(outcome.diagnostics.is_empty(), "{:?}", outcome.diagnostics);
+    assert!(
+        outcome.config_warnings.is_empty(),
+        "{:?}",
+        outcome.config_warnings
+    );
+}
+
+#[test]
+fn lint_string_returns_config_warnings() {
+    use nitrocop::api::{ConfigSource, LintOptions, lint_string};
+
+    let dir = temp_dir("lint_string_config_warnings");
+    let config_path = write_file(&dir, ".rubocop.yml", b"inherit_from: missing.yml\n");
+    let outcome = lint_string(
+        "x = 1\n",
+        "lib/a.rb",
+        LintOptions {
+            config: ConfigSource::File(&config_path),
+            only: vec!["Layout/TrailingWhitespace".to_string()],
+            ..LintOptions::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        outcome.config_warnings.len(),
+        1,
+        "{:?}",
+        outcome.config_warnings
+    );
+    assert!(
+        outcome.config_warnings[0].starts_with("inherit_from target not found: "),
+        "{:?}",
+        outcome.config_warnings
+    );
+    assert!(outcome.config_warnings[0].contains("missing.yml"));
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 // ---------- --list-cops CLI tests ----------
 
 #[test]
@@ -2597,6 +3241,66 @@ fn list_cops_prints_all_registered_cops() {
     assert!(lines.contains(&"RSpec/Focus"), "Should contain RSpec/Focus");
 }
 
+#[test]
+fn list_cops_json_carries_metadata() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .args(["--list-cops", "--format", "json"])
+        .output()
+        .expect("Failed to execute nitrocop");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).expect("Should be valid JSON");
+    let arr = parsed.as_array().expect("Should be a JSON array");
+    assert_eq!(arr.len(), 920, "Should list every registered cop");
+    assert!(arr.iter().all(|c| c["implemented"] == true));
+
+    let tw = arr
+        .iter()
+        .find(|c| c["name"] == "Layout/TrailingWhitespace")
+        .expect("Should contain Layout/TrailingWhitespace");
+    assert_eq!(tw["department"], "Layout");
+    assert_eq!(tw["supports_autocorrect"], true);
+    assert_eq!(tw["default_enabled"], true);
+    assert_eq!(tw["tier"], "stable");
+    assert_eq!(tw["promoted"], false);
+}
+
+#[test]
+fn list_cops_department_filter() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .args(["--list-cops", "--department", "Style"])
+        .output()
+        .expect("Failed to execute nitrocop");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(lines.contains(&"Style/FrozenStringLiteralComment"));
+    assert!(
+        lines.iter().all(|l| l.starts_with("Style/")),
+        "Should only list Style cops: {stdout}"
+    );
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .args(["--rules", "--department", "RSpec", "--format", "json"])
+        .output()
+        .expect("Failed to execute nitrocop");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).expect("Should be valid JSON");
+    let departments: Vec<&str> = parsed
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["department"].as_str().unwrap())
+        .collect();
+    assert!(!departments.is_empty());
+    assert!(
+        departments.iter().all(|d| *d == "RSpec"),
+        "--department RSpec should not include RSpecRails: {departments:?}"
+    );
+}
+
 // ---------- Config audit ----------
 
 #[test]
@@ -3012,7 +3716,9 @@ fn collect_rb_files(dir: &Path, files: &mut Vec<PathBuf>) {
 
 /// Ensure every nitrocop cop that has a `minimum_target_ruby_version` or
 /// `maximum_target_ruby_version` in the vendor RuboCop source also has a
-/// corresponding `TargetRubyVersion` check in its Rust implementation.
+/// corresponding `TargetRubyVersion` check in its Rust implementation, and
+/// that a `ruby_version_at_least`/`ruby_version_at_most` gate uses the same
+/// version.
 ///
 /// This is a zero-tolerance test like `config_audit` and `prism_pitfalls`.
 /// Cops in the `KNOWN_MISSING` allowlist are temporarily exempt — the goal
@@ -3045,49 +3751,21 @@ fn ruby_version_gates() {
         "Layout/HeredocIndentation",
         "Lint/DuplicateMatchPattern",
         "Lint/EmptyInPattern",
-        "Lint/ErbNewArguments",
-        // No-op cops — obsolete under modern Ruby, kept for config compatibility
-        "Lint/ItWithoutArgumentsInBlock",
+        // No-op cop — obsolete under modern Ruby, kept for config compatibility
         "Lint/NonDeterministicRequireOrder",
         "Lint/SafeNavigationChain",
         "Lint/SuppressedExceptionInNumberConversion",
         "Lint/UselessElseWithoutRescue",
         "Performance/ArraySemiInfiniteRangeSlice",
         "Performance/BigDecimalWithNumericArgument",
-        "Performance/BindCall",
-        "Performance/DeletePrefix",
-        "Performance/DeleteSuffix",
-        "Performance/MapCompact",
-        "Performance/RedundantEqualityComparisonBlock",
-        "Performance/SelectMap",
-        "Performance/Sum",
-        "Performance/UnfreezeString",
-        "Rails/EnumSyntax",
         "Rails/SafeNavigation",
         "Rails/StripHeredoc",
-        "Rails/WhereRange",
         "Style/BitwisePredicate",
-        "Style/CollectionCompact",
-        "Style/ComparableClamp",
-        "Style/DataInheritance",
         "Style/Dir",
         "Style/DirEmpty",
         "Style/FileEmpty",
         "Style/FrozenStringLiteralComment",
-        "Style/HashExcept",
-        "Style/HashFetchChain",
-        "Style/HashSlice",
-        "Style/HashTransformKeys",
-        "Style/HashTransformValues",
-        "Style/InPatternThen",
-        "Style/MultilineInPatternThen",
-        "Style/NumberedParameters",
-        "Style/NumberedParametersLimit",
-        "Style/ObjectThen",
-        "Style/SafeNavigation",
-        "Style/SlicingWithRange",
         "Style/SymbolArray",
-        "Style/UnpackFirst",
         // No-op cop — YAML.load is safe since Ruby 3.1
         "Security/YamlLoad",
     ]
@@ -3158,23 +3836,50 @@ fn ruby_version_gates() {
             };
 
             let rust_content = fs::read_to_string(&rust_path).unwrap();
-            if !rust_content.contains("TargetRubyVersion") {
+            let display_path = rust_path
+                .strip_prefix(&manifest)
+                .unwrap_or(&rust_path)
+                .display()
+                .to_string();
+            let gate_type = if has_min { "minimum" } else { "maximum" };
+            let version = extract_ruby_version(&content, gate_type);
+            if !rust_content.contains("TargetRubyVersion")
+                && !rust_content.contains("ruby_version_at_")
+            {
                 if known_missing.contains(cop_name.as_str()) {
                     known_missing_found.insert(cop_name.clone());
                     continue; // Allowed for now
                 }
 
-                let gate_type = if has_min { "minimum" } else { "maximum" };
-                let version = extract_ruby_version(&content, gate_type);
-
                 failures.push(format!(
                     "{cop_name}: vendor has {gate_type}_target_ruby_version {version} \
-                     but {} has no TargetRubyVersion check",
-                    rust_path
-                        .strip_prefix(&manifest)
-                        .unwrap_or(&rust_path)
-                        .display()
+                     but {display_path} has no TargetRubyVersion check"
                 ));
+                continue;
+            }
+
+            // A whole-cop gate through the `CopConfig` helper must use the
+            // vendor's version.
+            let helper = if has_min {
+                "ruby_version_at_least("
+            } else {
+                "ruby_version_at_most("
+            };
+            let rust_version = rust_content
+                .split_once(helper)
+                .and_then(|(_, rest)| rest.split_once(')'))
+                .map(|(arg, _)| arg.trim().to_string());
+            if let Some(rust_version) = rust_version {
+                let same = matches!(
+                    (rust_version.parse::<f64>(), version.parse::<f64>()),
+                    (Ok(a), Ok(b)) if a == b
+                );
+                if !same {
+                    failures.push(format!(
+                        "{cop_name}: vendor has {gate_type}_target_ruby_version {version} \
+                         but {display_path} gates on {rust_version}"
+                    ));
+                }
             }
         }
     }
@@ -4398,6 +5103,44 @@ fn no_cache_flag_disables_result_cache_writes() {
     let _ = fs::remove_dir_all(&dir);
 }
 
+#[test]
+fn list_target_files_estimate_reports_cache_hits_and_misses() {
+    let dir = temp_dir("list_target_files_estimate");
+    write_file(&dir, "src/a.rb", b"x = 1 \n");
+    write_file(&dir, "src/b.rb", b"y = 2\n");
+    let cache_dir = dir.join("cache");
+    fs::create_dir_all(&cache_dir).unwrap();
+
+    let run = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .env("NITROCOP_CACHE_DIR", &cache_dir)
+            .args([
+                "--force-default-config",
+                "--only",
+                "Layout/TrailingWhitespace",
+            ])
+            .args(extra)
+            .arg(dir.join("src"))
+            .output()
+            .expect("Failed to execute nitrocop")
+    };
+    run(&[]);
+
+    fs::write(dir.join("src/b.rb"), b"y = 22\n").unwrap();
+    fs::write(dir.join("src/c.rb"), b"z = 3\n").unwrap();
+    let output = run(&["-L", "--estimate"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(0), "{stdout}");
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 4, "{stdout}");
+    assert!(lines[0].starts_with("hit   ") && lines[0].ends_with("a.rb"));
+    assert!(lines[1].starts_with("miss  ") && lines[1].ends_with("b.rb (changed)"));
+    assert!(lines[2].starts_with("miss  ") && lines[2].ends_with("c.rb (not cached)"));
+    assert!(lines[3].starts_with("1 cached, 2 to lint, "), "{stdout}");
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
 #[test]
 fn cache_invalidated_by_file_change() {
     let dir = temp_dir("cache_file_change");
@@ -4509,6 +5252,60 @@ fn cache_invalidated_by_config_change() {
     let _ = fs::remove_dir_all(&dir);
 }
 
+#[test]
+fn repeat_runs_relint_only_changed_files() {
+    let dir = temp_dir("cache_incremental");
+    write_file(&dir, ".rubocop.yml", b"AllCops:\n  NewCops: enable\n");
+    write_file(&dir, "a.rb", b"x = 1 \n");
+    write_file(&dir, "b.rb", b"y = 2\n");
+    write_file(&dir, "c.rb", b"z = 3\n");
+    let cache_dir = dir.join("cache");
+    fs::create_dir_all(&cache_dir).unwrap();
+
+    // (stat hits, content hits, misses) from the --debug summary
+    let run = |extra: &[&str]| -> (usize, usize, usize) {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .env("NITROCOP_CACHE_DIR", &cache_dir)
+            .current_dir(&dir)
+            .args(["--debug", "--only", "Layout/TrailingWhitespace"])
+            .args(extra)
+            .args(["a.rb", "b.rb", "c.rb"])
+            .output()
+            .expect("Failed to execute nitrocop");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let line = stderr
+            .lines()
+            .find_map(|l| l.strip_prefix("debug: cache stat_hits: "))
+            .unwrap_or_else(|| panic!("no cache summary in: {stderr}"));
+        let counts: Vec<usize> = line
+            .split(", ")
+            .map(|part| part.rsplit(' ').next().unwrap().parse().unwrap())
+            .collect();
+        (counts[0], counts[1], counts[2])
+    };
+
+    assert_eq!(run(&[]), (0, 0, 3));
+    assert_eq!(run(&[]), (3, 0, 0));
+
+    // Editing one file re-lints exactly that file
+    fs::write(dir.join("b.rb"), b"y = 22\n").unwrap();
+    assert_eq!(run(&[]), (2, 0, 1));
+
+    // --paranoid hashes every file instead of trusting mtime and size
+    assert_eq!(run(&["--paranoid"]), (0, 3, 0));
+
+    // Changing the config invalidates everything
+    fs::write(
+        dir.join(".rubocop.yml"),
+        b"AllCops:\n  NewCops: enable\nLayout/TrailingWhitespace:\n  AllowInHeredoc: true\n",
+    )
+    .unwrap();
+    assert_eq!(run(&[]), (0, 0, 3));
+    assert_eq!(run(&[]), (3, 0, 0));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
 #[test]
 fn cache_preserves_all_severity_types() {
     use nitrocop::cache::{CacheLookup, ResultCache};
@@ -4532,6 +5329,7 @@ fn cache_preserves_all_severity_types() {
             cop_name: "Style/A".to_string(),
             message: "convention".to_string(),
             corrected: false,
+            end_column: None,
         },
         Diagnostic {
             path: rb_file.to_string_lossy().to_string(),
@@ -4540,6 +5338,7 @@ fn cache_preserves_all_severity_types() {
             cop_name: "Lint/B".to_string(),
             message: "warning".to_string(),
             corrected: false,
+            end_column: None,
         },
         Diagnostic {
             path: rb_file.to_string_lossy().to_string(),
@@ -4551,6 +5350,7 @@ fn cache_preserves_all_severity_types() {
             cop_name: "Security/C".to_string(),
             message: "error".to_string(),
             corrected: false,
+            end_column: None,
         },
         Diagnostic {
             path: rb_file.to_string_lossy().to_string(),
@@ -4559,6 +5359,7 @@ fn cache_preserves_all_severity_types() {
             cop_name: "Lint/D".to_string(),
             message: "fatal".to_string(),
             corrected: false,
+            end_column: None,
         },
     ];
 
@@ -5096,6 +5897,88 @@ fn list_target_files_exits_without_linting() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn list_target_files_honors_absolute_and_home_excludes() {
+    let dir = temp_dir("list_target_abs_excludes");
+    let project = dir.canonicalize().unwrap();
+    write_file(&project, "app/a.rb", b"x = 1\n");
+    write_file(&project, "vendor/b.rb", b"x = 1\n");
+    write_file(&project, "shared/c.rb", b"x = 1\n");
+    fs::write(
+        project.join(".rubocop.yml"),
+        format!(
+            "AllCops:\n  Exclude:\n    - '{}/vendor/**/*'\n    - '~/shared/**/*'\n",
+            project.display()
+        ),
+    )
+    .unwrap();
+
+    // Relative target, so discovered paths (`./vendor/b.rb`) only match the
+    // absolute patterns in their canonical form.
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .args(["-L", "--no-cache", "."])
+        .current_dir(&project)
+        .env("HOME", &project)
+        .output()
+        .expect("Failed to execute nitrocop");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 1, "only app/a.rb should remain, got: {stdout}");
+    assert!(lines[0].ends_with("app/a.rb"), "got: {stdout}");
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn list_target_files_skips_default_excluded_directories() {
+    let dir = temp_dir("list_target_default_excludes");
+    let project = dir.canonicalize().unwrap();
+    write_file(&project, "app/a.rb", b"x = 1\n");
+    write_file(&project, "db/schema.rb", b"x = 1\n");
+    write_file(&project, "vendor/bundle/gems/b.rb", b"x = 1\n");
+    write_file(&project, "node_modules/pkg/c.rb", b"x = 1\n");
+    write_file(&project, "tmp/cache/d.rb", b"x = 1\n");
+
+    let list = |extra: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args(["-L", "--no-cache"])
+            .args(extra)
+            .arg(".")
+            .current_dir(&project)
+            .env("HOME", &project)
+            .output()
+            .expect("Failed to execute nitrocop");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success(), "stderr: {stderr}");
+        let mut files: Vec<String> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| line.trim_start_matches("./").to_string())
+            .collect();
+        files.sort();
+        files
+    };
+
+    // No project config: RuboCop's default AllCops.Exclude still applies.
+    assert_eq!(list(&[]), ["app/a.rb", "db/schema.rb"]);
+    assert_eq!(
+        list(&["--force-default-config"]),
+        ["app/a.rb", "db/schema.rb"]
+    );
+
+    // Project entries are appended to the defaults, not replacing them.
+    fs::write(
+        project.join(".rubocop.yml"),
+        "AllCops:\n  Exclude:\n    - 'db/schema.rb'\n",
+    )
+    .unwrap();
+    assert_eq!(list(&[]), ["app/a.rb"]);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 // ---------- -D / --display-cop-names CLI tests ----------
 
 #[test]
@@ -5301,6 +6184,87 @@ fn fail_level_convention_catches_conventions() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn fail_level_warning_catches_syntax_warnings() {
+    let dir = temp_dir("fail_level_syntax_w");
+    // Parses cleanly, but Prism warns about the string literal in the condition.
+    fs::write(dir.join("test.rb"), "puts 'yes' if 'yes'\n").unwrap();
+    fs::write(
+        dir.join(".rubocop.yml"),
+        "Lint/Syntax:\n  ReportWarnings: true\n",
+    )
+    .unwrap();
+
+    let run = |fail_level: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args([
+                "--fail-level",
+                fail_level,
+                "--only",
+                "Lint/Syntax",
+                "--no-cache",
+                "--config",
+                dir.join(".rubocop.yml").to_str().unwrap(),
+                dir.join("test.rb").to_str().unwrap(),
+            ])
+            .output()
+            .expect("Failed to execute nitrocop")
+    };
+
+    let output = run("warning");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("W: Lint/Syntax: string literal in condition"),
+        "expected a warning-severity Lint/Syntax offense, got: {stdout}"
+    );
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "--fail-level warning should exit 1 for parser warnings"
+    );
+
+    let output = run("error");
+    assert!(
+        output.status.success(),
+        "--fail-level error should exit 0 for parser warnings"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn syntax_error_honors_configured_severity() {
+    use nitrocop::diagnostic::Severity;
+
+    let dir = temp_dir("syntax_error_severity");
+    let file = write_file(&dir, "bad_syntax.rb", b"def foo(\n  x = 1\n");
+    let config_path = write_file(&dir, ".rubocop.yml", b"Lint/Syntax:\n  Severity: error\n");
+    let config = load_config(Some(config_path.as_path()), None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let mut args = default_args();
+    args.only = vec!["Lint/Syntax".to_string()];
+
+    let result = run_linter(
+        &discovered(&[file]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    assert!(!result.diagnostics.is_empty());
+    assert!(
+        result
+            .diagnostics
+            .iter()
+            .all(|d| d.cop_name == "Lint/Syntax" && d.severity == Severity::Error),
+        "got {:?}",
+        result.diagnostics
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn fail_level_invalid_value_errors() {
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
@@ -5317,6 +6281,69 @@ fn fail_level_invalid_value_errors() {
     );
 }
 
+#[test]
+fn max_reported_offenses_keeps_most_severe_and_full_exit_status() {
+    let dir = temp_dir("max_reported");
+    // 1 FrozenStringLiteralComment (C), 2 TrailingWhitespace (C), 2 UselessAssignment (W)
+    fs::write(dir.join("test.rb"), "def foo\n  x = 1 \n  y = 2 \nend\n").unwrap();
+
+    let run = |extra: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args([
+                "--preview",
+                "--format",
+                "json",
+                "--only",
+                "Style/FrozenStringLiteralComment,Layout/TrailingWhitespace,Lint/UselessAssignment",
+                "--no-cache",
+            ])
+            .args(extra)
+            .arg(dir.to_str().unwrap())
+            .output()
+            .expect("Failed to execute nitrocop");
+        let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        (output, parsed)
+    };
+
+    let (output, parsed) = run(&["--max-reported-offenses", "2"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(parsed["metadata"]["reported_offense_count"], 2);
+    assert_eq!(parsed["metadata"]["total_offense_count"], 5);
+    let cops: Vec<&str> = parsed["offenses"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|o| o["cop_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(cops, ["Lint/UselessAssignment", "Lint/UselessAssignment"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("reported the 2 most severe of 5 offenses"),
+        "stderr: {stderr}"
+    );
+
+    // The cap applies to what --display-only-fail-level-offenses leaves, and
+    // the exit status still counts the hidden offenses
+    let (output, parsed) = run(&[
+        "--fail-level",
+        "W",
+        "--display-only-fail-level-offenses",
+        "--max-reported-offenses",
+        "1",
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(parsed["metadata"]["reported_offense_count"], 1);
+    assert_eq!(parsed["metadata"]["total_offense_count"], 2);
+
+    // Under the cap: nothing truncated, no notice
+    let (output, parsed) = run(&["--max-reported-offenses", "10"]);
+    assert_eq!(parsed["metadata"]["total_offense_count"], 5);
+    assert_eq!(parsed["offenses"].as_array().unwrap().len(), 5);
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("--max-reported-offenses"));
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 // ---------- --force-exclusion CLI tests ----------
 
 #[test]
@@ -5508,22 +6535,64 @@ fn force_default_config_ignores_config_file() {
     fs::remove_dir_all(&dir).ok();
 }
 
-// ---------- Autocorrect tests ----------
-
+// ---------- Autocorrect tests ----------
+
+#[test]
+fn autocorrect_fixes_file_on_disk() {
+    let dir = temp_dir("autocorrect_disk");
+    // File has: leading blank lines, trailing whitespace, trailing blank lines
+    let file = write_file(&dir, "fixme.rb", b"\n\nx = 1  \ny = 2\n\n\n");
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = Args {
+        autocorrect: true,
+        only: vec![
+            "Layout/LeadingEmptyLines".to_string(),
+            "Layout/TrailingWhitespace".to_string(),
+            "Layout/TrailingEmptyLines".to_string(),
+        ],
+        preview: true,
+        ..default_args()
+    };
+
+    let result = run_linter(
+        &discovered(&[file.clone()]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    assert!(
+        result.corrected_count > 0,
+        "Expected corrected_count > 0, got {}",
+        result.corrected_count
+    );
+
+    // Verify file was corrected on disk
+    let corrected = fs::read(&file).unwrap();
+    assert_eq!(
+        corrected, b"x = 1\ny = 2\n",
+        "File should be corrected: leading blanks removed, trailing whitespace removed, trailing blank lines removed"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
-fn autocorrect_fixes_file_on_disk() {
-    let dir = temp_dir("autocorrect_disk");
-    // File has: leading blank lines, trailing whitespace, trailing blank lines
-    let file = write_file(&dir, "fixme.rb", b"\n\nx = 1  \ny = 2\n\n\n");
-    let config = load_config(None, None, None).unwrap();
+fn autocorrect_writes_fixes_into_markdown_code_blocks() {
+    let dir = temp_dir("autocorrect_markdown");
+    let file = write_file(
+        &dir,
+        "guide.md",
+        b"Intro  \n\n```ruby\nx = 1  \n```\n\n```sh\necho hi  \n```\n\n  ```ruby\n  if x  \n    y\n  end\n  ```\n",
+    );
+    let mut config = load_config(None, None, None).unwrap();
+    config.enable_markdown();
     let registry = CopRegistry::default_registry();
     let args = Args {
         autocorrect: true,
-        only: vec![
-            "Layout/LeadingEmptyLines".to_string(),
-            "Layout/TrailingWhitespace".to_string(),
-            "Layout/TrailingEmptyLines".to_string(),
-        ],
+        only: vec!["Layout/TrailingWhitespace".to_string()],
         preview: true,
         ..default_args()
     };
@@ -5536,17 +6605,62 @@ fn autocorrect_fixes_file_on_disk() {
         &TierMap::load(),
         &AutocorrectAllowlist::load(),
     );
-    assert!(
-        result.corrected_count > 0,
-        "Expected corrected_count > 0, got {}",
-        result.corrected_count
-    );
+    assert_eq!(result.corrected_count, 2);
 
-    // Verify file was corrected on disk
     let corrected = fs::read(&file).unwrap();
     assert_eq!(
-        corrected, b"x = 1\ny = 2\n",
-        "File should be corrected: leading blanks removed, trailing whitespace removed, trailing blank lines removed"
+        corrected,
+        b"Intro  \n\n```ruby\nx = 1\n```\n\n```sh\necho hi  \n```\n\n  ```ruby\n  if x\n    y\n  end\n  ```\n".to_vec(),
+        "Only the Ruby code blocks should change"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn autocorrect_changed_lines_fixes_only_changed_offense() {
+    let dir = temp_dir("autocorrect_changed_lines");
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@example.com"])
+            .args(args)
+            .current_dir(&dir)
+            .status()
+            .expect("Failed to execute git");
+        assert!(status.success(), "git {args:?} failed");
+    };
+    git(&["init", "-q"]);
+    let file = write_file(&dir, "a.rb", b"x = 1  \ny = 2\nx = 1  \n");
+    git(&["add", "a.rb"]);
+    git(&["commit", "-q", "-m", "init"]);
+    // Same offense on lines 1 and 3; only line 3 is touched on this branch.
+    fs::write(&file, b"x = 1  \ny = 2\nx = 3  \n").unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .args([
+            "-a",
+            "--autocorrect-changed-lines",
+            "--format",
+            "text",
+            "--only",
+            "Layout/TrailingWhitespace",
+            "--no-cache",
+            "a.rb",
+        ])
+        .current_dir(&dir)
+        .output()
+        .expect("Failed to execute nitrocop");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(
+        fs::read_to_string(&file).unwrap(),
+        "x = 1  \ny = 2\nx = 3\n",
+        "only the changed line is corrected, got: {stdout}"
+    );
+    assert!(stdout.contains("[Corrected] a.rb:3:"), "{stdout}");
+    assert!(
+        stdout.lines().any(|line| line.starts_with("a.rb:1:")),
+        "the unchanged line's offense is still reported: {stdout}"
     );
 
     fs::remove_dir_all(&dir).ok();
@@ -5680,6 +6794,50 @@ fn autocorrect_all_mode_includes_unsafe_cops() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn autocorrect_duplicate_require_reaches_fix_point() {
+    let dir = temp_dir("autocorrect_duplicate_require");
+    // The third copy's deletion overlaps the second's and waits for the
+    // next iteration.
+    let file = write_file(
+        &dir,
+        "deps.rb",
+        b"require 'a'\n\nrequire 'a'\n\nrequire 'a'\n",
+    );
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = Args {
+        autocorrect_all: true,
+        only: vec![
+            "Lint/DuplicateRequire".to_string(),
+            "Layout/EmptyLines".to_string(),
+            "Layout/TrailingEmptyLines".to_string(),
+        ],
+        preview: true,
+        ..default_args()
+    };
+
+    let result = run_linter(
+        &discovered(&[file.clone()]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    assert_eq!(fs::read(&file).unwrap(), b"require 'a'\n");
+    assert!(
+        result
+            .diagnostics
+            .iter()
+            .all(|d| d.cop_name == "Lint/DuplicateRequire"),
+        "no blank-line offenses: {:?}",
+        result.diagnostics
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn autocorrect_clean_file_unchanged() {
     let dir = temp_dir("autocorrect_clean");
@@ -5711,6 +6869,154 @@ fn autocorrect_clean_file_unchanged() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn autocorrect_copy_to_leaves_originals_untouched() {
+    let dir = temp_dir("autocorrect_copy_to");
+    let dirty = b"x = 1  \ny = 2\n";
+    let clean = b"z = 3\n";
+    let dirty_file = write_file(&dir, "lib/app/dirty.rb", dirty);
+    let clean_file = write_file(&dir, "lib/clean.rb", clean);
+
+    let run = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args([
+                "-a",
+                "--format",
+                "text",
+                "--only",
+                "Layout/TrailingWhitespace",
+                "--no-cache",
+            ])
+            .args(extra)
+            .arg("lib")
+            .current_dir(&dir)
+            .output()
+            .expect("Failed to execute nitrocop")
+    };
+
+    let output = run(&["--copy-to", "out"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(fs::read(&dirty_file).unwrap(), dirty);
+    assert_eq!(fs::read(&clean_file).unwrap(), clean);
+    assert_eq!(
+        fs::read(dir.join("out/lib/app/dirty.rb")).unwrap(),
+        b"x = 1\ny = 2\n"
+    );
+    assert!(
+        !dir.join("out/lib/clean.rb").exists(),
+        "files without corrections are not copied without --copy-all"
+    );
+    assert!(stderr.contains("Wrote 1 file to out."), "{stderr}");
+
+    let output = run(&["--copy-to", "full", "--copy-all"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(fs::read(&dirty_file).unwrap(), dirty);
+    assert_eq!(fs::read(&clean_file).unwrap(), clean);
+    assert_eq!(
+        fs::read(dir.join("full/lib/app/dirty.rb")).unwrap(),
+        b"x = 1\ny = 2\n"
+    );
+    assert_eq!(fs::read(dir.join("full/lib/clean.rb")).unwrap(), clean);
+    assert!(
+        stderr.contains("Wrote 2 files to full (1 corrected, 1 unchanged)."),
+        "{stderr}"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn copy_to_requires_autocorrect() {
+    let dir = temp_dir("copy_to_requires_autocorrect");
+    let file = write_file(&dir, "a.rb", b"x = 1  \n");
+
+    let run = |args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args(args)
+            .arg("a.rb")
+            .current_dir(&dir)
+            .output()
+            .expect("Failed to execute nitrocop")
+    };
+
+    let output = run(&["--copy-to", "out"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("--copy-to requires -a, -A or --autocorrect-cop"),
+        "{stderr}"
+    );
+
+    let output = run(&["-a", "--copy-all"]);
+    assert!(!output.status.success(), "--copy-all needs --copy-to");
+
+    assert_eq!(fs::read(&file).unwrap(), b"x = 1  \n");
+    assert!(!dir.join("out").exists());
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+// ---------- --history-file ----------
+
+#[test]
+fn history_file_appends_a_record_per_run() {
+    let dir = temp_dir("history_file_appends");
+    let file = write_file(&dir, "a.rb", b"x = 1  \n");
+    let run = || {
+        std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args([
+                "--only",
+                "Layout/TrailingWhitespace",
+                "--history-file",
+                "history.jsonl",
+                "--history-summary",
+                "a.rb",
+            ])
+            .current_dir(&dir)
+            .output()
+            .expect("Failed to execute nitrocop")
+    };
+
+    let output = run();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("History: first run recorded (1 offense)."),
+        "{stderr}"
+    );
+
+    fs::write(&file, b"x = 1\n").unwrap();
+    let output = run();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("History: -1 Layout since last run (0 offenses)."),
+        "{stderr}"
+    );
+
+    let content = fs::read_to_string(dir.join("history.jsonl")).unwrap();
+    let records: Vec<serde_json::Value> = content
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is JSON"))
+        .collect();
+    assert_eq!(records.len(), 2);
+    let first = &records[0];
+    assert_eq!(first["schema"], 1);
+    assert_eq!(first["files_inspected"], 1);
+    assert_eq!(first["offenses"], 1);
+    assert_eq!(first["by_severity"]["convention"], 1);
+    assert_eq!(first["by_department"]["Layout"], 1);
+    assert_eq!(first["corrected"], 0);
+    assert!(first["timestamp"].as_str().unwrap().ends_with('Z'));
+    assert!(first["duration_ms"].is_u64());
+    assert_eq!(
+        first["config_fingerprint"],
+        records[1]["config_fingerprint"]
+    );
+    assert_eq!(records[1]["offenses"], 0);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn autocorrect_multi_iteration_converges() {
     let dir = temp_dir("autocorrect_multi_iter");
@@ -5834,6 +7140,107 @@ fn autocorrect_idempotent_second_run() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn autocorrect_equal_sign_alignment_with_space_around_operators_converges() {
+    let dir = temp_dir("autocorrect_equal_sign_alignment");
+    let file = write_file(&dir, "align.rb", b"a = 1\nbbb  =  2\ncc = 3\n");
+    let config_path = write_file(
+        &dir,
+        ".rubocop.yml",
+        b"Layout/ExtraSpacing:\n  ForceEqualSignAlignment: true\n",
+    );
+    let config = load_config(Some(config_path.as_path()), None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = Args {
+        autocorrect_all: true,
+        only: vec![
+            "Layout/ExtraSpacing".to_string(),
+            "Layout/SpaceAroundOperators".to_string(),
+        ],
+        preview: true,
+        ..default_args()
+    };
+
+    // ExtraSpacing pads the `=` signs while SpaceAroundOperators collapses
+    // the extra space around them; the fix-point loop must settle on the
+    // aligned form instead of undoing each other's corrections.
+    let result = run_linter(
+        &discovered(&[file.clone()]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    assert!(result.corrected_count > 0);
+    let corrected = fs::read(&file).unwrap();
+    assert_eq!(
+        String::from_utf8(corrected).unwrap(),
+        "a   = 1\nbbb = 2\ncc  = 3\n"
+    );
+
+    let second = run_linter(
+        &discovered(&[file.clone()]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    assert_eq!(second.corrected_count, 0);
+    assert!(second.diagnostics.is_empty(), "{:?}", second.diagnostics);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn autocorrect_redundant_disable_directives_relints_clean() {
+    let dir = temp_dir("autocorrect_redundant_disable");
+    let file = write_file(
+        &dir,
+        "directives.rb",
+        b"# frozen_string_literal: true\n\nx = 1 # rubocop:disable Layout/TrailingWhitespace\n# rubocop:disable Layout/TrailingWhitespace, Style/StringLiterals\ny = 2   \n# rubocop:enable Layout/TrailingWhitespace\nputs x\n# rubocop:disable Style/NumericLiterals\nputs y\n",
+    );
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = Args {
+        autocorrect_all: true,
+        only: vec!["Lint/RedundantCopDisableDirective".to_string()],
+        ..default_args()
+    };
+
+    // The end-of-line directive and the own-line directive are removed
+    // entirely; from the list only the unused `Style/StringLiterals` goes.
+    // Other cops run to mark directives as used but don't correct anything.
+    let result = run_linter(
+        &discovered(&[file.clone()]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    assert!(result.corrected_count > 0);
+    let corrected = fs::read(&file).unwrap();
+    assert_eq!(
+        String::from_utf8(corrected).unwrap(),
+        "# frozen_string_literal: true\n\nx = 1\n# rubocop:disable Layout/TrailingWhitespace\ny = 2   \n# rubocop:enable Layout/TrailingWhitespace\nputs x\nputs y\n"
+    );
+
+    let second = run_linter(
+        &discovered(&[file.clone()]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    assert_eq!(second.corrected_count, 0);
+    assert!(second.diagnostics.is_empty(), "{:?}", second.diagnostics);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn autocorrect_empty_file_no_crash() {
     let dir = temp_dir("autocorrect_empty");
@@ -6113,27 +7520,117 @@ fn strict_coverage_exits_two_for_preview_gated() {
 
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert_eq!(
-        output.status.code(),
-        Some(2),
-        "--strict should exit 2 when preview-gated cops exist, stderr: {stderr}"
+        output.status.code(),
+        Some(2),
+        "--strict should exit 2 when preview-gated cops exist, stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("--strict=coverage"),
+        "Should print strict warning, stderr: {stderr}"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn strict_coverage_with_preview_exits_zero() {
+    // Same as above but with --preview, so the cop is no longer preview-gated → exit 0.
+    let dir = temp_dir("strict_coverage_with_preview");
+    let tiers = write_preview_tiers(&dir, "Performance/BigDecimalWithNumericArgument");
+    fs::write(dir.join("test.rb"), "x = 1\n").unwrap();
+    fs::write(
+        dir.join(".rubocop.yml"),
+        "Performance/BigDecimalWithNumericArgument:\n  Enabled: true\n",
+    )
+    .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .env("NITROCOP_TIERS_FILE", &tiers)
+        .args([
+            "--strict",
+            "--preview",
+            "--only",
+            "Layout/TrailingWhitespace",
+            "--no-cache",
+            "--config",
+            dir.join(".rubocop.yml").to_str().unwrap(),
+            dir.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to execute nitrocop");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "--strict with --preview should exit 0 when all preview cops run, stderr: {stderr}"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn preview_cop_flag_and_config_lift_the_preview_gate() {
+    let dir = temp_dir("preview_cop_promotion");
+    let tiers = write_preview_tiers(&dir, "Style/NegatedIf");
+    fs::write(dir.join("test.rb"), "foo if !bar\n").unwrap();
+    let config = dir.join(".rubocop.yml");
+    let run = |yaml: &str, extra: &[&str]| {
+        fs::write(&config, yaml).unwrap();
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .env("NITROCOP_TIERS_FILE", &tiers)
+            .args([
+                "--no-cache",
+                "--format",
+                "json",
+                "--only",
+                "Style/NegatedIf",
+            ])
+            .args(["--config", config.to_str().unwrap()])
+            .args(extra)
+            .arg(dir.join("test.rb"))
+            .output()
+            .expect("Failed to execute nitrocop");
+        let parsed: serde_json::Value = serde_json::from_slice(&output.stdout)
+            .unwrap_or_else(|e| panic!("Should be valid JSON: {e}"));
+        let gated = parsed["skipped"]["preview_gated"]
+            .as_array()
+            .is_some_and(|cops| cops.iter().any(|c| c == "Style/NegatedIf"));
+        (parsed["offenses"].as_array().unwrap().len(), gated)
+    };
+    let enabled = "Style/NegatedIf:\n  Enabled: true\n";
+
+    assert_eq!(run(enabled, &[]), (0, true), "gated without promotion");
+    assert_eq!(
+        run(enabled, &["--preview-cop", "Style/NegatedIf"]),
+        (1, false),
+        "--preview-cop should run the cop under --only"
     );
-    assert!(
-        stderr.contains("--strict=coverage"),
-        "Should print strict warning, stderr: {stderr}"
+    assert_eq!(
+        run(
+            &format!("{enabled}AllCops:\n  PreviewCops:\n    - Style/NegatedIf\n"),
+            &[]
+        ),
+        (1, false),
+        "AllCops.PreviewCops should run the cop under --only"
+    );
+    assert_eq!(
+        run(enabled, &["--preview-cop", "Style/Other"]),
+        (0, true),
+        "promoting another cop leaves this one gated"
     );
 
     fs::remove_dir_all(&dir).ok();
 }
 
 #[test]
-fn strict_coverage_with_preview_exits_zero() {
-    // Same as above but with --preview, so the cop is no longer preview-gated → exit 0.
-    let dir = temp_dir("strict_coverage_with_preview");
+fn strict_coverage_ignores_promoted_preview_cops() {
+    let dir = temp_dir("strict_coverage_promoted");
     let tiers = write_preview_tiers(&dir, "Performance/BigDecimalWithNumericArgument");
     fs::write(dir.join("test.rb"), "x = 1\n").unwrap();
     fs::write(
         dir.join(".rubocop.yml"),
-        "Performance/BigDecimalWithNumericArgument:\n  Enabled: true\n",
+        "AllCops:\n  PreviewCops:\n    - Performance/BigDecimalWithNumericArgument\n\
+         Performance/BigDecimalWithNumericArgument:\n  Enabled: true\n",
     )
     .unwrap();
 
@@ -6141,7 +7638,6 @@ fn strict_coverage_with_preview_exits_zero() {
         .env("NITROCOP_TIERS_FILE", &tiers)
         .args([
             "--strict",
-            "--preview",
             "--only",
             "Layout/TrailingWhitespace",
             "--no-cache",
@@ -6155,7 +7651,7 @@ fn strict_coverage_with_preview_exits_zero() {
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
         output.status.success(),
-        "--strict with --preview should exit 0 when all preview cops run, stderr: {stderr}"
+        "--strict should not count a promoted preview cop as skipped, stderr: {stderr}"
     );
 
     fs::remove_dir_all(&dir).ok();
@@ -6431,6 +7927,199 @@ fn migrate_clean_config_no_skips() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn migrate_classifies_minitest_plugin_cops() {
+    use nitrocop::migrate::{CopStatus, build_report};
+
+    let dir = temp_dir("migrate_minitest_plugin");
+    // A vendored mini rubocop-minitest: one implemented cop, one not.
+    let gem_root = dir.join("gems/rubocop-minitest");
+    write_file(
+        &gem_root,
+        "config/default.yml",
+        b"Minitest/AssertNil:\n  Enabled: true\n\nMinitest/AssertOperator:\n  Enabled: true\n",
+    );
+    let gem_cache = std::collections::HashMap::from([("rubocop-minitest".to_string(), gem_root)]);
+    let config_path = write_file(&dir, ".rubocop.yml", b"plugins:\n  - rubocop-minitest\n");
+    let config = load_config(Some(config_path.as_path()), None, Some(&gem_cache)).unwrap();
+    let registry = CopRegistry::default_registry();
+    let tier_map = TierMap::load();
+
+    let summary = config.compute_skip_summary(&registry, &tier_map, false);
+    assert!(
+        summary
+            .preview_gated
+            .contains(&"Minitest/AssertNil".to_string())
+    );
+    assert!(
+        summary
+            .unimplemented
+            .contains(&"Minitest/AssertOperator".to_string())
+    );
+    assert!(
+        !summary
+            .outside_baseline
+            .iter()
+            .any(|name| name.starts_with("Minitest/"))
+    );
+
+    let report = build_report(&config, &registry, &tier_map);
+    let status = |name: &str| {
+        report
+            .cops
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| c.status)
+    };
+    assert_eq!(status("Minitest/AssertNil"), Some(CopStatus::Preview));
+    assert_eq!(
+        status("Minitest/AssertOperator"),
+        Some(CopStatus::Unimplemented)
+    );
+    assert!(report.baseline.contains_key("rubocop-minitest"));
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+// ---------- --show-cops CLI tests ----------
+
+#[test]
+fn show_cops_names_the_file_behind_each_setting() {
+    let dir = temp_dir("show_cops_provenance");
+    let todo = write_file(
+        &dir,
+        ".rubocop_todo.yml",
+        b"Layout/LineLength:\n  Max: 150\n  AllowURI: false\n  Exclude:\n    - 'db/**/*'\n",
+    );
+    let local = write_file(
+        &dir,
+        ".rubocop.yml",
+        b"inherit_from: .rubocop_todo.yml\n\nLayout/LineLength:\n  Max: 120\n  Exclude:\n    - 'spec/**/*'\n",
+    );
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .args([
+            "--show-cops",
+            "Layout/LineLength",
+            "--no-cache",
+            "--config",
+            local.to_str().unwrap(),
+            dir.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to execute nitrocop");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "--show-cops should exit 0, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let local_note = format!("# set by {}", local.display());
+    let todo_note = format!("# set by {}", todo.display());
+    let line_for = |prefix: &str| {
+        stdout
+            .lines()
+            .find(|l| l.trim_start().starts_with(prefix))
+            .unwrap_or_else(|| panic!("no `{prefix}` line: {stdout}"))
+            .to_string()
+    };
+    assert!(stdout.starts_with("Layout/LineLength:\n"), "{stdout}");
+    assert!(line_for("Max: 120").ends_with(&local_note), "{stdout}");
+    assert!(
+        line_for("AllowURI: false").ends_with(&todo_note),
+        "{stdout}"
+    );
+    assert!(
+        line_for("- \"spec/**/*\"").ends_with(&local_note),
+        "{stdout}"
+    );
+    assert!(line_for("- \"db/**/*\"").ends_with(&todo_note), "{stdout}");
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+// ---------- --show-config-chain CLI tests ----------
+
+#[test]
+fn show_config_chain_lists_files_in_load_order_and_names_cycles() {
+    let dir = temp_dir("show_config_chain");
+    let todo = write_file(
+        &dir,
+        ".rubocop_todo.yml",
+        b"Layout/LineLength:\n  Max: 150\nStyle/Documentation:\n  Enabled: false\n",
+    );
+    let base = write_file(&dir, "base.yml", b"inherit_from: .rubocop.yml\n");
+    let local = write_file(
+        &dir,
+        ".rubocop.yml",
+        b"inherit_from:\n  - .rubocop_todo.yml\n  - base.yml\n\nLayout/LineLength:\n  Max: 120\n",
+    );
+
+    let run = |format: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args([
+                "--show-config-chain",
+                "--format",
+                format,
+                "--no-cache",
+                "--config",
+                local.to_str().unwrap(),
+                dir.to_str().unwrap(),
+            ])
+            .output()
+            .expect("Failed to execute nitrocop")
+    };
+
+    let output = run("text");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    let ours: Vec<&str> = stdout
+        .lines()
+        .filter(|l| l.contains(dir.to_str().unwrap()))
+        .collect();
+    assert_eq!(
+        ours,
+        [
+            format!("local            {} (1 cop)", local.display()),
+            format!("  inherit_from   {} (2 cops)", todo.display()),
+            format!("  inherit_from   {} (0 cops)", base.display()),
+            format!(
+                "    inherit_from {} (already loaded, skipped)",
+                local.display()
+            ),
+        ],
+        "{stdout}"
+    );
+    let cycle = format!(
+        "{} -> {} -> {}",
+        local.display(),
+        base.display(),
+        local.display()
+    );
+    assert!(
+        stderr.contains("warning: circular config inheritance") && stderr.contains(&cycle),
+        "{stderr}"
+    );
+
+    let output = run("json");
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let todo_entry = report["files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["path"] == todo.to_str().unwrap())
+        .expect("todo file in the chain");
+    assert_eq!(todo_entry["mechanism"], "inherit_from");
+    assert_eq!(todo_entry["depth"], 1);
+    assert_eq!(todo_entry["cop_keys"], 2);
+    assert_eq!(todo_entry["skipped"], false);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 // ---------- --doctor CLI tests ----------
 
 #[test]
@@ -6499,6 +8188,51 @@ fn doctor_shows_config_root() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn doctor_and_json_report_the_same_run_info() {
+    let dir = temp_dir("doctor_run_info");
+    fs::write(dir.join("test.rb"), "x = 1\n").unwrap();
+    let config = dir.join(".rubocop.yml");
+    fs::write(
+        &config,
+        "AllCops:\n  TargetRubyVersion: 3.2\n  TargetRailsVersion: 7.1\n",
+    )
+    .unwrap();
+    let run = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args(["--no-cache", "--config", config.to_str().unwrap()])
+            .args(extra)
+            .arg(&dir)
+            .output()
+            .expect("Failed to execute nitrocop")
+    };
+
+    let doctor = run(&["--doctor", "-a"]);
+    let stdout = String::from_utf8_lossy(&doctor.stdout);
+    assert!(doctor.status.success());
+    let expected = format!(
+        "nitrocop version: {}\nConfig file: {}\nTarget Ruby version: 3.2\n\
+         Target Rails version: 7.1\nAutocorrect mode: safe\n",
+        env!("CARGO_PKG_VERSION"),
+        config.display()
+    );
+    assert!(
+        stdout.starts_with(&expected),
+        "--doctor should open with the run info block: {stdout}"
+    );
+
+    let json = run(&["--format", "json", "--only", "Style/StringLiterals"]);
+    let parsed: serde_json::Value = serde_json::from_slice(&json.stdout).unwrap();
+    let metadata = &parsed["metadata"];
+    assert_eq!(metadata["nitrocop_version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(metadata["config_path"], config.display().to_string());
+    assert_eq!(metadata["target_ruby_version"], 3.2);
+    assert_eq!(metadata["target_rails_version"], 7.1);
+    assert_eq!(metadata["autocorrect_mode"], "off");
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn doctor_shows_skip_summary() {
     let dir = temp_dir("doctor_skip_summary");
@@ -6629,6 +8363,39 @@ fn rules_tier_filter_preview() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn rules_marks_promoted_preview_cops() {
+    let dir = temp_dir("rules_promoted_preview");
+    let tiers = write_preview_tiers(&dir, "Performance/BigDecimalWithNumericArgument");
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .env("NITROCOP_TIERS_FILE", &tiers)
+        .args([
+            "--rules",
+            "--tier",
+            "preview",
+            "--preview-cop",
+            "Performance/BigDecimalWithNumericArgument",
+        ])
+        .output()
+        .expect("Failed to execute nitrocop");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(
+        stdout.lines().any(
+            |l| l.starts_with("Performance/BigDecimalWithNumericArgument")
+                && l.contains("preview*")
+        ),
+        "Promoted cop should be marked: {stdout}"
+    );
+    assert!(
+        stdout.contains("* promoted to run without --preview (1)"),
+        "Should explain the marker: {stdout}"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn rules_json_output() {
     let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
@@ -6657,6 +8424,91 @@ fn rules_json_output() {
     assert_eq!(tw["in_baseline"], true);
 }
 
+// ---------- --emit-schema CLI tests ----------
+
+#[test]
+fn emit_schema_writes_file() {
+    let dir = temp_dir("emit_schema_writes_file");
+    let path = dir.join("rubocop-schema.json");
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .arg("--emit-schema")
+        .arg(&path)
+        .output()
+        .expect("Failed to execute nitrocop");
+
+    assert!(
+        output.status.success(),
+        "--emit-schema should exit 0, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let schema: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).expect("valid JSON schema");
+    let properties = &schema["properties"];
+    assert!(properties["AllCops"].is_object());
+    assert!(properties["Layout"].is_object());
+    assert!(properties["Layout/TrailingWhitespace"].is_object());
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn emit_schema_defaults_to_stdout() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .arg("--emit-schema")
+        .output()
+        .expect("Failed to execute nitrocop");
+
+    assert!(output.status.success());
+    let schema: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a JSON schema");
+    assert_eq!(schema["$schema"], "http://json-schema.org/draft-07/schema#");
+}
+
+// ---------- --validate-config CLI tests ----------
+
+#[test]
+fn validate_config_exit_codes() {
+    let dir = temp_dir("validate_config_exit_codes");
+    let valid = write_file(
+        &dir,
+        "valid.yml",
+        b"AllCops:\n  NewCops: enable\nLayout/NoSuchCop:\n  Enabled: false\n",
+    );
+    let invalid = write_file(
+        &dir,
+        "invalid.yml",
+        b"Style/StringLiterals:\n  Enabled: maybe\n",
+    );
+
+    let run = |config: &PathBuf| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args(["--validate-config", "--no-cache", "--config"])
+            .arg(config)
+            .arg(&dir)
+            .output()
+            .expect("Failed to execute nitrocop")
+    };
+
+    // Warnings alone don't fail the check.
+    let output = run(&valid);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(0), "stdout: {stdout}");
+    assert!(
+        stdout.contains("warning: Layout/NoSuchCop: unknown cop `Layout/NoSuchCop`"),
+        "stdout: {stdout}"
+    );
+
+    let output = run(&invalid);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(2), "stdout: {stdout}");
+    assert!(
+        stdout.contains("error: Style/StringLiterals.Enabled:"),
+        "stdout: {stdout}"
+    );
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn autocorrect_safe_allowlist_permits_listed_cop() {
     let dir = temp_dir("autocorrect_allowlist_safe");
@@ -6723,6 +8575,111 @@ fn autocorrect_all_bypasses_allowlist() {
     fs::remove_dir_all(&dir).ok();
 }
 
+#[test]
+fn contextual_autocorrect_applies_only_when_targeted() {
+    // (AutoCorrect setting, mode) -> whether the correction is applied.
+    let cases: &[(&str, &str, bool)] = &[
+        ("always", "-a", true),
+        ("always", "-A", true),
+        ("always", "--autocorrect-cop", true),
+        ("contextual", "-a", false),
+        ("contextual", "-A", false),
+        ("contextual", "--autocorrect-cop", true),
+        ("false", "-a", false),
+        ("false", "-A", false),
+        ("false", "--autocorrect-cop", false),
+    ];
+    let registry = CopRegistry::default_registry();
+    for &(setting, mode, applied) in cases {
+        let dir = temp_dir(&format!(
+            "contextual_autocorrect_{setting}_{}",
+            mode.trim_start_matches('-')
+        ));
+        let file = write_file(&dir, "test.rb", b"x = 1  \n");
+        let config_path = write_file(
+            &dir,
+            ".rubocop.yml",
+            format!("Layout/TrailingWhitespace:\n  AutoCorrect: {setting}\n").as_bytes(),
+        );
+        let config = load_config(Some(&config_path), None, None).unwrap();
+        let args = Args {
+            autocorrect: mode == "-a",
+            autocorrect_all: mode == "-A",
+            autocorrect_cop: if mode == "--autocorrect-cop" {
+                vec!["Layout/TrailingWhitespace".to_string()]
+            } else {
+                Vec::new()
+            },
+            only: vec!["Layout/TrailingWhitespace".to_string()],
+            preview: true,
+            ..default_args()
+        };
+        let result = run_linter(
+            &discovered(&[file.clone()]),
+            &config,
+            &registry,
+            &args,
+            &TierMap::load(),
+            &AutocorrectAllowlist::load(),
+        );
+
+        let case = format!("AutoCorrect: {setting} with {mode}");
+        assert_eq!(result.diagnostics.len(), 1, "{case}");
+        assert_eq!(result.diagnostics[0].corrected, applied, "{case}");
+        assert_eq!(result.corrected_count, usize::from(applied), "{case}");
+        let skipped = setting == "contextual" && !applied;
+        assert_eq!(
+            result.skipped_contextual_count,
+            usize::from(skipped),
+            "{case}"
+        );
+        let expected: &[u8] = if applied { b"x = 1\n" } else { b"x = 1  \n" };
+        assert_eq!(fs::read(&file).unwrap(), expected, "{case}");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
+
+#[test]
+fn autocorrect_cop_leaves_other_cops_uncorrected() {
+    let dir = temp_dir("autocorrect_cop_targeted");
+    let file = write_file(&dir, "test.rb", b"x = \"a\"  \n");
+    let config = load_config(None, None, None).unwrap();
+    let registry = CopRegistry::default_registry();
+    let args = Args {
+        autocorrect_all: true,
+        autocorrect_cop: vec!["Layout/TrailingWhitespace".to_string()],
+        only: vec![
+            "Layout/TrailingWhitespace".to_string(),
+            "Style/StringLiterals".to_string(),
+        ],
+        preview: true,
+        ..default_args()
+    };
+
+    let result = run_linter(
+        &discovered(&[file.clone()]),
+        &config,
+        &registry,
+        &args,
+        &TierMap::load(),
+        &AutocorrectAllowlist::load(),
+    );
+    assert_eq!(result.corrected_count, 1);
+    assert_eq!(result.skipped_contextual_count, 0);
+    assert!(
+        result
+            .diagnostics
+            .iter()
+            .any(|d| d.cop_name == "Style/StringLiterals" && !d.corrected),
+        "{:?}",
+        result.diagnostics
+    );
+    assert_eq!(fs::read(&file).unwrap(), b"x = \"a\"\n");
+
+    fs::remove_dir_all(&dir).ok();
+}
+
 // ── NodePattern Verifier ──────────────────────────────────────────────────
 
 /// Verify that all patterns in the pattern database parse successfully.
@@ -7226,3 +9183,103 @@ fn shared_module_usage_lint() {
         panic!("{msg}");
     }
 }
+
+#[test]
+fn files0_lists_only_fail_level_files_nul_separated() {
+    let dir = temp_dir("files0_fail_level");
+    let dup = b"class Foo\n  def bar; end\n  def bar; end\nend\n";
+    let warned = write_file(&dir, "has space/warn me.rb", dup);
+    let warned_too = write_file(&dir, "a b.rb", dup);
+    write_file(&dir, "style only.rb", b"x = 1 \n");
+
+    let run = |extra: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+            .args([
+                "--no-cache",
+                "--only",
+                "Lint/DuplicateMethods,Layout/TrailingWhitespace",
+                "--fail-level",
+                "warning",
+            ])
+            .args(extra)
+            .arg(&dir)
+            .output()
+            .expect("Failed to execute nitrocop");
+        assert_eq!(
+            output.status.code(),
+            Some(1),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let stdout = run(&["--format", "files0", "--display-only-fail-level-offenses"]);
+    let paths: Vec<&str> = stdout.split_terminator('\0').collect();
+    assert_eq!(paths.len(), 2, "{stdout:?}");
+    assert!(paths[0].ends_with("a b.rb"), "{stdout:?}");
+    assert!(paths[1].ends_with("has space/warn me.rb"), "{stdout:?}");
+    assert!(Path::new(paths[0]).ends_with(warned_too.strip_prefix(&dir).unwrap()));
+    assert!(Path::new(paths[1]).ends_with(warned.strip_prefix(&dir).unwrap()));
+    assert!(!stdout.contains('\n'), "{stdout:?}");
+
+    let stdout = run(&["--format", "files"]);
+    assert_eq!(stdout.lines().count(), 3, "{stdout}");
+    assert!(stdout.contains("style only.rb\n"), "{stdout}");
+}
+
+#[test]
+fn only_warns_about_cops_that_will_not_run() {
+    let dir = temp_dir("only_will_not_run");
+    fs::write(
+        dir.join(".rubocop.yml"),
+        "Style/FrozenStringLiteralComment:\n  Enabled: false\n\
+         Layout/TrailingWhitespace:\n  Include:\n    - 'db/**/*.rb'\n",
+    )
+    .unwrap();
+    fs::write(dir.join("test.rb"), "x = 1 \n").unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_nitrocop"))
+        .args([
+            "--preview",
+            "--format",
+            "json",
+            "--no-cache",
+            "--only",
+            "Rails/Blank,Style/FrozenStringLiteralComment,Layout/TrailingWhitespace,Style/Nope",
+        ])
+        .arg(dir.to_str().unwrap())
+        .output()
+        .expect("Failed to execute nitrocop");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let warnings: Vec<&str> = stderr
+        .lines()
+        .filter_map(|line| line.strip_prefix("warning: "))
+        .collect();
+    assert_eq!(warnings.len(), 4, "stderr: {stderr}");
+    assert_eq!(
+        warnings[0],
+        "--only Rails/Blank will not run: the Rails department is not loaded; \
+         add rubocop-rails to `plugins:` in the config"
+    );
+    assert!(
+        warnings[1].starts_with(
+            "--only Style/FrozenStringLiteralComment will not run: `Enabled: false` in "
+        ) && warnings[1].ends_with(".rubocop.yml"),
+        "{}",
+        warnings[1]
+    );
+    assert_eq!(
+        warnings[2],
+        "--only Layout/TrailingWhitespace will not run: its Include/Exclude patterns \
+         match none of the 1 target files"
+    );
+    assert_eq!(warnings[3], "--only Style/Nope: no such cop");
+
+    // The JSON report carries the same warnings
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["metadata"]["warnings"], serde_json::json!(warnings));
+    assert_eq!(parsed["metadata"]["offense_count"], 0);
+
+    fs::remove_dir_all(&dir).ok();
+}